@@ -0,0 +1,137 @@
+// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+use breadx::auto::AsByteSequence;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+#[doc = r" Measure how long it takes to size, serialize and parse a value. `finish` is run on the"]
+#[doc = r" bytes before they are parsed, to fill in what the connection or the server would."]
+fn bench_item<T: AsByteSequence>(
+    c: &mut Criterion,
+    name: &str,
+    value: T,
+    finish: fn(&mut Vec<u8>),
+) {
+    let mut bytes = vec![0; value.size()];
+    let len = value.as_bytes(&mut bytes);
+    bytes.truncate(len);
+    finish(&mut bytes);
+    let mut group = c.benchmark_group(name);
+    group.bench_function("size", |b| b.iter(|| black_box(&value).size()));
+    let mut buffer = vec![0; len];
+    group.bench_function("as_bytes", |b| {
+        b.iter(|| black_box(&value).as_bytes(black_box(&mut buffer)))
+    });
+    group.bench_function("from_bytes", |b| {
+        b.iter(|| T::from_bytes(black_box(&bytes)).expect("Unable to parse item"))
+    });
+    group.finish();
+}
+#[doc = r" Pad a request to four bytes and write its length, the way the connection does before"]
+#[doc = r" sending it."]
+fn finish_request(bytes: &mut Vec<u8>) {
+    bytes.resize((bytes.len() + 3) & !3, 0);
+    let len = ((bytes.len() / 4) as u16).to_ne_bytes();
+    bytes[2..4].copy_from_slice(&len);
+}
+#[doc = r" Write the length of a reply, the way the server does before sending it. This takes a"]
+#[doc = r" vector so that it can be used in the place of `finish_request`."]
+#[allow(clippy::ptr_arg)]
+fn finish_reply(bytes: &mut Vec<u8>) {
+    let len = ((bytes.len().saturating_sub(32) / 4) as u32).to_ne_bytes();
+    bytes[4..8].copy_from_slice(&len);
+}
+fn serialization(c: &mut Criterion) {
+    bench_item(
+        c,
+        "Rectangle",
+        breadx::auto::xproto::Rectangle::default(),
+        |_| (),
+    );
+    bench_item(
+        c,
+        "Setup",
+        breadx::auto::xproto::Setup {
+            vendor: "x".repeat(200),
+            pixmap_formats: (0..200).map(|_| Default::default()).collect(),
+            roots: (0..200).map(|_| Default::default()).collect(),
+            ..Default::default()
+        },
+        |_| (),
+    );
+    bench_item(
+        c,
+        "CreateWindowRequest",
+        breadx::auto::xproto::CreateWindowRequest::builder().build(),
+        finish_request,
+    );
+    bench_item(
+        c,
+        "InternAtomRequest",
+        breadx::auto::xproto::InternAtomRequest {
+            name: "x".repeat(200),
+            ..breadx::auto::xproto::InternAtomRequest::builder().build()
+        },
+        finish_request,
+    );
+    bench_item(
+        c,
+        "PolyLineRequest",
+        breadx::auto::xproto::PolyLineRequest {
+            points: (0..200).map(|_| Default::default()).collect(),
+            ..breadx::auto::xproto::PolyLineRequest::builder().build()
+        },
+        finish_request,
+    );
+    bench_item(
+        c,
+        "PutImageRequest",
+        breadx::auto::xproto::PutImageRequest {
+            data: (0..16384).map(|_| Default::default()).collect(),
+            ..breadx::auto::xproto::PutImageRequest::builder().build()
+        },
+        finish_request,
+    );
+    bench_item(
+        c,
+        "QueryTreeReply",
+        breadx::auto::xproto::QueryTreeReply {
+            children: (0..200).map(|_| Default::default()).collect(),
+            ..Default::default()
+        },
+        finish_reply,
+    );
+    bench_item(
+        c,
+        "GetKeyboardMappingReply",
+        breadx::auto::xproto::GetKeyboardMappingReply {
+            keysyms: (0..200).map(|_| Default::default()).collect(),
+            length: 200,
+            ..Default::default()
+        },
+        finish_reply,
+    );
+    bench_item(
+        c,
+        "ListFontsWithInfoReply",
+        breadx::auto::xproto::ListFontsWithInfoReply {
+            properties: (0..200).map(|_| Default::default()).collect(),
+            name: "x".repeat(200),
+            ..Default::default()
+        },
+        finish_reply,
+    );
+    bench_item(
+        c,
+        "KeyPressEvent",
+        breadx::auto::xproto::KeyPressEvent::default(),
+        |_| (),
+    );
+    bench_item(
+        c,
+        "ClientMessageEvent",
+        breadx::auto::xproto::ClientMessageEvent::default(),
+        |_| (),
+    );
+}
+criterion_group!(benches, serialization);
+criterion_main!(benches);
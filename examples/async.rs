@@ -117,20 +117,12 @@ async fn x_process(receiver: Receiver<[u8; 3]>) -> breadx::Result<()> {
                 .await?;
 
                 // send an exposure event to force the window to redraw itself
-                conn.send_event_async(
-                    win,
-                    EventMask::EXPOSURE,
-                    Event::Expose(ExposeEvent {
-                        window: win,
-                        x: 0,
-                        y: 0,
-                        width,
-                        height,
-                        count: 0,
-                        ..Default::default()
-                    }),
-                )
-                .await?;
+                let mut expose = ExposeEvent::default();
+                expose.window = win;
+                expose.width = width;
+                expose.height = height;
+                conn.send_event_async(win, EventMask::EXPOSURE, Event::Expose(expose))
+                    .await?;
             }
             MainLoopDirective::ProcessingEvent(ev) => match ev? {
                 Event::ClientMessage(cme) => {
@@ -0,0 +1,202 @@
+// MIT/Apache2 License
+
+use crate::{
+    lvl2::{Field, List, MaybeString, StructureItem},
+    lvl3::{self, Item, RStruct, Trait},
+};
+use proc_macro2::{Ident, Literal, Span};
+use quote::ToTokens;
+use std::{error::Error, io::prelude::*};
+
+/// The types that the benchmarks are run on, if the namespace defines them. Between them, they cover
+/// fixed-size items, nested lists, strings, lists whose lengths are carried by other fields, large
+/// buffers of bytes, requests, replies and events.
+///
+/// Each one is picked as the most commonly sent item of the core protocol with its shape, like
+/// `PutImageRequest` for large buffers, so the benchmarks measure what clients spend their time on.
+/// A type is only worth adding if no type here has its shape. Since they all come from the core
+/// protocol, the benchmarks build with the default features, e.g. `cargo bench --no-run`.
+const REPRESENTATIVE_TYPES: &[&str] = &[
+    "Rectangle",
+    "Setup",
+    "CreateWindowRequest",
+    "InternAtomRequest",
+    "PolyLineRequest",
+    "PutImageRequest",
+    "QueryTreeReply",
+    "GetKeyboardMappingReply",
+    "ListFontsWithInfoReply",
+    "KeyPressEvent",
+    "ClientMessageEvent",
+];
+
+/// The number of items put into each list, and the number of characters put into each string.
+const LIST_LEN: usize = 200;
+/// The number of bytes put into each buffer of bytes whose length isn't held by another field.
+const BUFFER_LEN: usize = 16384;
+
+/// A value of a structure whose lists are filled out, so that serializing it does a meaningful
+/// amount of work. Lists whose lengths are held by a field are given that length as well; lists
+/// whose lengths are worked out some other way are left empty.
+#[inline]
+fn representative_value(module: &Ident, rs: &RStruct) -> syn::Expr {
+    let tyname = Ident::new(&rs.name, Span::call_site());
+    let mut assignments: Vec<(Ident, syn::Expr)> = vec![];
+
+    rs.fields.iter().for_each(|item| {
+        if let StructureItem::List(List {
+            name,
+            ty,
+            list_length,
+            ..
+        }) = item
+        {
+            let has_slot = rs.fields.iter().any(
+                |f| matches!(f, StructureItem::LenSlot { owning_list, .. } if owning_list == name),
+            );
+            // the field that holds the length of the list, if it's a field of its own
+            let length_field = list_length.single_item().filter(|len| {
+                rs.fields
+                    .iter()
+                    .any(|f| matches!(f, StructureItem::Field(Field { name, .. }) if name == len))
+            });
+            if !has_slot && !list_length.is_remainder() && length_field.is_none() {
+                return;
+            }
+
+            let len = Literal::usize_unsuffixed(
+                match length_field.is_none() && lvl3::is_byte_buffer(item) {
+                    true => BUFFER_LEN,
+                    false => LIST_LEN,
+                },
+            );
+            let value: syn::Expr = match ty {
+                MaybeString::IsAString => syn::parse_quote! { "x".repeat(#len) },
+                MaybeString::NotAString(_) => {
+                    syn::parse_quote! { (0..#len).map(|_| Default::default()).collect() }
+                }
+            };
+            assignments.push((Ident::new(name, Span::call_site()), value));
+
+            if let Some(length_field) = length_field {
+                assignments.push((
+                    Ident::new(length_field, Span::call_site()),
+                    syn::parse_quote! { #len },
+                ));
+            }
+        }
+    });
+
+    // requests that don't implement `Default` start from the values their builders start from
+    let from_builder = rs.traits.iter().any(|t| matches!(t, Trait::Request(..)))
+        && !rs.derives.contains(&"Default");
+    let base: syn::Expr = match (from_builder, assignments.is_empty()) {
+        (true, _) => syn::parse_quote! { breadx::auto::#module::#tyname::builder().build() },
+        (false, true) => syn::parse_quote! { breadx::auto::#module::#tyname::default() },
+        (false, false) => syn::parse_quote! { Default::default() },
+    };
+    if assignments.is_empty() {
+        return base;
+    }
+
+    let (names, values): (Vec<Ident>, Vec<syn::Expr>) = assignments.into_iter().unzip();
+    syn::parse_quote! {
+        breadx::auto::#module::#tyname {
+            #(#names: #values,)*
+            ..#base
+        }
+    }
+}
+
+/// Write a benchmark harness, run by `criterion`, that measures how long it takes to size,
+/// serialize and parse the representative types of a namespace.
+#[inline]
+pub fn generate_bench<W: Write>(
+    items: &[Item],
+    module: &str,
+    outfile: &mut W,
+) -> Result<(), Box<dyn Error>> {
+    let module = Ident::new(module, Span::call_site());
+    let benches = REPRESENTATIVE_TYPES.iter().filter_map(|name| {
+        let rs = items.iter().find_map(|item| match item {
+            Item::RStruct(rs) if &*rs.name == *name => Some(rs),
+            _ => None,
+        })?;
+        let value = representative_value(&module, rs);
+        let finish: syn::Expr = if rs.traits.iter().any(|t| matches!(t, Trait::Request(..))) {
+            syn::parse_quote! { finish_request }
+        } else if lvl3::is_reply(rs) {
+            syn::parse_quote! { finish_reply }
+        } else {
+            syn::parse_quote! { |_| () }
+        };
+
+        Some(quote::quote! {
+            bench_item(c, #name, #value, #finish);
+        })
+    });
+
+    let file: syn::File = syn::parse_quote! {
+        use breadx::auto::AsByteSequence;
+        use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+        /// Measure how long it takes to size, serialize and parse a value. `finish` is run on the
+        /// bytes before they are parsed, to fill in what the connection or the server would.
+        fn bench_item<T: AsByteSequence>(
+            c: &mut Criterion,
+            name: &str,
+            value: T,
+            finish: fn(&mut Vec<u8>),
+        ) {
+            let mut bytes = vec![0; value.size()];
+            let len = value.as_bytes(&mut bytes);
+            bytes.truncate(len);
+            finish(&mut bytes);
+
+            let mut group = c.benchmark_group(name);
+            group.bench_function("size", |b| b.iter(|| black_box(&value).size()));
+            let mut buffer = vec![0; len];
+            group.bench_function("as_bytes", |b| {
+                b.iter(|| black_box(&value).as_bytes(black_box(&mut buffer)))
+            });
+            group.bench_function("from_bytes", |b| {
+                b.iter(|| T::from_bytes(black_box(&bytes)).expect("Unable to parse item"))
+            });
+            group.finish();
+        }
+
+        /// Pad a request to four bytes and write its length, the way the connection does before
+        /// sending it.
+        fn finish_request(bytes: &mut Vec<u8>) {
+            bytes.resize((bytes.len() + 3) & !3, 0);
+            let len = ((bytes.len() / 4) as u16).to_ne_bytes();
+            bytes[2..4].copy_from_slice(&len);
+        }
+
+        /// Write the length of a reply, the way the server does before sending it. This takes a
+        /// vector so that it can be used in the place of `finish_request`.
+        #[allow(clippy::ptr_arg)]
+        fn finish_reply(bytes: &mut Vec<u8>) {
+            let len = ((bytes.len().saturating_sub(32) / 4) as u32).to_ne_bytes();
+            bytes[4..8].copy_from_slice(&len);
+        }
+
+        fn serialization(c: &mut Criterion) {
+            #(#benches)*
+        }
+
+        criterion_group!(benches, serialization);
+        criterion_main!(benches);
+    };
+
+    write!(
+        outfile,
+        "// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+{}",
+        file.into_token_stream()
+    )?;
+
+    Ok(())
+}
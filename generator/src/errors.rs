@@ -0,0 +1,155 @@
+// MIT/Apache2 License
+
+use crate::{
+    features::{feature_name, EXTENSION_FEATURES},
+    lvl3::{self, error_variant, syn_util::doc_attrs, Trait},
+    read_lvl3, Lvl3Namespace,
+};
+use proc_macro2::{Ident, Span};
+use quote::ToTokens;
+use std::{error::Error, io::prelude::*, iter, path::Path};
+
+/// One of the variants of `XError`.
+#[derive(Debug)]
+struct ErrorVariant {
+    variant: Ident,
+    namespace: Ident,
+    error: Ident,
+    /// The feature that the namespace of the error is compiled behind, if any.
+    feature: Option<&'static str>,
+    docs: String,
+}
+
+/// The errors of a namespace, sorted by their codes.
+#[inline]
+fn errors_of(xml_dir: &Path, namespace: &str) -> Result<Vec<ErrorVariant>, Box<dyn Error>> {
+    let Lvl3Namespace {
+        items, ext_name, ..
+    } = read_lvl3(&xml_dir.join(format!("{}.xml", namespace)))?;
+
+    let mut errors: Vec<(&lvl3::RStruct, u64)> = items
+        .iter()
+        .filter_map(|item| match item {
+            lvl3::Item::RStruct(rs) => rs.traits.iter().find_map(|t| match t {
+                Trait::Error(code) => Some((rs, *code)),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .collect();
+    errors.sort_by_key(|(_, code)| *code);
+
+    Ok(errors
+        .into_iter()
+        .map(|(rs, _)| {
+            let name = rs.name.strip_suffix("Error").unwrap_or(&rs.name);
+            let variant = error_variant(namespace, ext_name.is_some(), &rs.name);
+            let origin = match ext_name {
+                Some(ref ext_name) => format!("the `{}` extension", ext_name),
+                None => "the core protocol".to_string(),
+            };
+            let article = match name.chars().next() {
+                Some('A') | Some('E') | Some('I') | Some('O') | Some('U') => "An",
+                _ => "A",
+            };
+
+            ErrorVariant {
+                variant: Ident::new(&variant, Span::call_site()),
+                namespace: Ident::new(namespace, Span::call_site()),
+                error: Ident::new(&rs.name, Span::call_site()),
+                feature: feature_name(namespace),
+                docs: format!("{} `{}` error of {}.", article, name, origin),
+            }
+        })
+        .collect())
+}
+
+/// Write out the `XError` enum, with a variant for each of the errors described in `xml_dir`. The
+/// variants of an extension's errors are compiled behind the extension's feature.
+#[inline]
+pub fn generate_errors<W: Write>(xml_dir: &Path, outfile: &mut W) -> Result<(), Box<dyn Error>> {
+    let mut errors = vec![];
+    for namespace in iter::once("xproto").chain(EXTENSION_FEATURES.iter().map(|(ns, _)| *ns)) {
+        errors.extend(errors_of(xml_dir, namespace)?);
+    }
+
+    let cfgs: Vec<Vec<syn::Attribute>> = errors
+        .iter()
+        .map(|e| match e.feature {
+            Some(feature) => vec![syn::parse_quote! { #[cfg(feature = #feature)] }],
+            None => vec![],
+        })
+        .collect();
+    let variants = errors.iter().zip(&cfgs).map(|(e, cfgs)| -> syn::Variant {
+        let docs = doc_attrs(&e.docs);
+        let ErrorVariant {
+            variant,
+            namespace,
+            error,
+            ..
+        } = e;
+        syn::parse_quote! {
+            #(#docs)*
+            #(#cfgs)*
+            #variant(super::#namespace::#error)
+        }
+    });
+    let arms = errors.iter().zip(&cfgs).map(|(e, cfgs)| -> syn::Arm {
+        let variant = &e.variant;
+        syn::parse_quote! {
+            #(#cfgs)*
+            Self::#variant(e) => core::fmt::Display::fmt(e, f),
+        }
+    });
+
+    let file = syn::File {
+        shebang: None,
+        attrs: vec![],
+        items: vec![
+            syn::parse_quote! {
+                /// An error sent by the X server. The errors of an extension are only parsed into
+                /// their structures if the extension's feature is enabled.
+                #[derive(Debug, Clone)]
+                pub enum XError {
+                    #(#variants,)*
+                    /// An error that isn't one of the above, kept as its code and its bytes.
+                    Unknown {
+                        /// The code of the error.
+                        code: u8,
+                        /// The bytes of the error.
+                        bytes: TinyVec<[u8; 32]>,
+                    },
+                }
+            },
+            syn::parse_quote! {
+                impl core::fmt::Display for XError {
+                    #[inline]
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        match self {
+                            #(#arms)*
+                            Self::Unknown { code, .. } => write!(
+                                f,
+                                "X error: {} (code {})",
+                                crate::ErrorCode(*code),
+                                code
+                            ),
+                        }
+                    }
+                }
+            },
+        ],
+    };
+
+    write!(
+        outfile,
+        "// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+use tinyvec::TinyVec;
+
+{}",
+        file.into_token_stream()
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+// MIT/Apache2 License
+
+use crate::{lvl1, read_lvl1};
+use std::{error::Error, fs, io::prelude::*, path::Path};
+
+/// The Cargo features that the extension namespaces are compiled behind, as pairs of the namespace
+/// and its feature. Namespaces that aren't listed here, like the core protocol, are always compiled.
+pub const EXTENSION_FEATURES: &[(&str, &str)] = &[
+    ("bigreq", "bigreq"),
+    ("composite", "composite"),
+    ("damage", "damage"),
+    ("dpms", "dpms"),
+    ("dri2", "dri2"),
+    ("dri3", "dri3"),
+    ("ge", "ge"),
+    ("glx", "glx"),
+    ("present", "present"),
+    ("randr", "randr"),
+    ("record", "record"),
+    ("render", "render"),
+    ("res", "res"),
+    ("screensaver", "screensaver"),
+    ("shape", "shape"),
+    ("shm", "shm"),
+    ("sync", "sync"),
+    ("xevie", "xevie"),
+    ("xf86dri", "xf86dri"),
+    ("xf86vidmode", "xf86vidmode"),
+    ("xfixes", "fixes"),
+    ("xinerama", "xinerama"),
+    ("xinput", "input"),
+    ("xkb", "xkb"),
+    ("xprint", "print"),
+    ("xselinux", "selinux"),
+    ("xtest", "xtest"),
+    ("xv", "xv"),
+    ("xvmc", "xvmc"),
+];
+
+/// The line of the manifest that starts the features of the extensions. They run until the next
+/// empty line.
+const SECTION_HEADER: &str = "# Extensions";
+
+/// The feature that a namespace is compiled behind, if any.
+#[inline]
+pub fn feature_name(namespace: &str) -> Option<&'static str> {
+    EXTENSION_FEATURES
+        .iter()
+        .find(|(ns, _)| *ns == namespace)
+        .map(|(_, feature)| *feature)
+}
+
+/// The attributes and items that go at the top of a namespace's module, after its own lint
+/// attributes. A namespace with a feature is compiled out when that feature is off, and fails to
+/// compile with an error that names the missing feature when a namespace it imports is compiled out.
+#[inline]
+pub fn module_header(namespace: &str, imports: &[String]) -> (String, String) {
+    let feature = match feature_name(namespace) {
+        Some(feature) => feature,
+        None => return (String::new(), String::new()),
+    };
+
+    let gate = format!("#![cfg(feature = \"{}\")]\n", feature);
+    let checks = imports
+        .iter()
+        .filter_map(|import| feature_name(import))
+        .map(|import| {
+            format!(
+                "#[cfg(not(feature = \"{}\"))]
+compile_error!(\"The `{}` feature requires the `{}` feature, since it uses the types that it defines\");
+",
+                import, feature, import
+            )
+        })
+        .collect();
+    (gate, checks)
+}
+
+/// The names of the namespaces that a description file imports.
+#[inline]
+fn imports_of(fname: &Path) -> Result<Vec<String>, Box<dyn Error>> {
+    let (items, _) = read_lvl1(fname)?;
+    Ok(items
+        .into_iter()
+        .filter_map(|item| match item {
+            lvl1::Item::Import(lvl1::Import(name)) => Some(name),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Rewrite the features of the extensions in a Cargo manifest, using the descriptions in `xml_dir`.
+/// Every extension is given a feature that enables the features of the extensions it imports, and
+/// `all-extensions` enables all of them. The rest of the manifest is left as it is.
+#[inline]
+pub fn generate_features(xml_dir: &Path, manifest: &Path) -> Result<(), Box<dyn Error>> {
+    let mut features: Vec<(&str, Vec<&str>)> = vec![];
+    for (namespace, feature) in EXTENSION_FEATURES {
+        let imports = imports_of(&xml_dir.join(format!("{}.xml", namespace)))?;
+        let mut deps: Vec<&str> = imports.iter().filter_map(|i| feature_name(i)).collect();
+        deps.sort_unstable();
+        features.push((*feature, deps));
+    }
+    features.sort_unstable_by_key(|(feature, _)| *feature);
+
+    let mut section = format!("{}\n", SECTION_HEADER);
+    features.iter().for_each(|(feature, deps)| {
+        section.push_str(&format!("{} = [{}]\n", feature, quoted_list(deps)));
+    });
+    let all: Vec<&str> = features.iter().map(|(feature, _)| *feature).collect();
+    section.push_str(&format!("all-extensions = [{}]\n", quoted_list(&all)));
+
+    // replace the old section with the new one
+    let old = fs::read_to_string(manifest)?;
+    let start = old
+        .find(SECTION_HEADER)
+        .ok_or_else(|| format!("{:?} has no \"{}\" section", manifest, SECTION_HEADER))?;
+    let end = old[start..]
+        .find("\n\n")
+        .map_or(old.len(), |end| start + end + 1);
+
+    let mut outfile = fs::File::create(manifest)?;
+    write!(outfile, "{}{}{}", &old[..start], section, &old[end..])?;
+    Ok(())
+}
+
+#[inline]
+fn quoted_list(items: &[&str]) -> String {
+    items
+        .iter()
+        .map(|item| format!("\"{}\"", item))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
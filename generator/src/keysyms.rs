@@ -0,0 +1,137 @@
+// MIT/Apache2 License
+
+use proc_macro2::{Ident, Literal, Span};
+use quote::ToTokens;
+use regex::Regex;
+use std::{collections::HashSet, error::Error, fs, io::prelude::*, path::Path};
+
+/// A keysym defined in the keysym definition header.
+#[derive(Debug)]
+struct KeysymDef {
+    /// The name of the keysym, without the `XK_` prefix.
+    name: String,
+    value: u32,
+    /// The comment that follows the definition, if there is one.
+    comment: Option<String>,
+}
+
+/// Read the `#define XK_[name] [value]` lines out of a keysym definition header, in the order they
+/// are written in.
+#[inline]
+fn read_keysyms(fname: &Path) -> Result<Vec<KeysymDef>, Box<dyn Error>> {
+    let header = fs::read_to_string(fname)?;
+    let re = Regex::new(r"^#define XK_(\w+)\s+0x([0-9a-fA-F]+)\s*(?:/\*\s*(.*?)\s*\*/)?").unwrap();
+
+    header
+        .lines()
+        .filter_map(|line| re.captures(line))
+        .map(|caps| {
+            Ok(KeysymDef {
+                name: caps[1].to_string(),
+                value: u32::from_str_radix(&caps[2], 16)?,
+                comment: caps.get(3).map(|c| c.as_str().to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Put the names in the comment of a keysym into code spans, so that they aren't read as prose.
+/// Comments often name other keysyms, as in "Alias for mode_switch", and those are written the way
+/// their constants are, like `XK_Mode_switch`, even if the comment doesn't match their case.
+#[inline]
+fn comment_to_markdown(comment: &str, keysyms: &[KeysymDef]) -> String {
+    let re = Regex::new(r"\b[A-Za-z][A-Za-z0-9]*(?:_[A-Za-z0-9]+)+\b").unwrap();
+    re.replace_all(comment, |caps: &regex::Captures<'_>| {
+        let word = &caps[0];
+        let keysym = keysyms
+            .iter()
+            .find(|keysym| keysym.name == word)
+            .or_else(|| {
+                keysyms
+                    .iter()
+                    .find(|keysym| keysym.name.eq_ignore_ascii_case(word))
+            });
+        match keysym {
+            Some(keysym) => format!("`XK_{}`", keysym.name),
+            None => format!("`{}`", word),
+        }
+    })
+    .into_owned()
+}
+
+/// Convert a keysym definition header into a module with a `pub const XK_[name]: Keysym` for every
+/// keysym, and a `keysym_name` function that looks up the name of a keysym. When several names are
+/// given to one keysym, the header lists the preferred one first, so that's the one the lookup
+/// returns.
+#[inline]
+pub fn generate_keysyms<W: Write>(fname: &Path, outfile: &mut W) -> Result<(), Box<dyn Error>> {
+    let keysyms = read_keysyms(fname)?;
+
+    let consts = keysyms.iter().map(|keysym| -> syn::Item {
+        let docs = keysym
+            .comment
+            .as_deref()
+            .map(|comment| format!(" {}", comment_to_markdown(comment, &keysyms)));
+        let docs = docs.iter();
+        let name = Ident::new(&format!("XK_{}", keysym.name), Span::call_site());
+        let value = syn::LitInt::new(&format!("{:#06x}", keysym.value), Span::call_site());
+
+        syn::parse_quote! {
+            #(#[doc = #docs])*
+            pub const #name: Keysym = #value;
+        }
+    });
+
+    let mut seen = HashSet::new();
+    let mut names: Vec<(u32, &str)> = keysyms
+        .iter()
+        .filter(|keysym| seen.insert(keysym.value))
+        .map(|keysym| (keysym.value, keysym.name.as_str()))
+        .collect();
+    names.sort_unstable();
+    let pairs = names.iter().map(|(value, name)| -> syn::Expr {
+        let value = Literal::u32_unsuffixed(*value);
+        syn::parse_quote! { (#value, #name) }
+    });
+
+    let file = syn::File {
+        shebang: None,
+        attrs: vec![],
+        items: consts
+            .chain(vec![
+                syn::parse_quote! {
+                    /// The preferred name of every keysym, without the `XK_` prefix, sorted by the
+                    /// keysyms.
+                    pub const KEYSYM_NAMES: &[(Keysym, &str)] = &[#(#pairs),*];
+                },
+                syn::parse_quote! {
+                    /// The name of a keysym, without the `XK_` prefix, if it is one of the defined
+                    /// keysyms.
+                    #[inline]
+                    #[must_use]
+                    pub fn keysym_name(keysym: Keysym) -> Option<&'static str> {
+                        KEYSYM_NAMES
+                            .binary_search_by_key(&keysym, |(keysym, _)| *keysym)
+                            .ok()
+                            .map(|index| KEYSYM_NAMES[index].1)
+                    }
+                },
+            ])
+            .collect(),
+    };
+
+    write!(
+        outfile,
+        "// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+#![allow(non_upper_case_globals, clippy::unreadable_literal)]
+
+use crate::auto::xproto::Keysym;
+
+{}",
+        file.into_token_stream()
+    )?;
+
+    Ok(())
+}
@@ -4,4 +4,3 @@ mod state;
 mod xml;
 
 pub use state::*;
-pub use xml::*;
@@ -2,9 +2,10 @@
 
 use super::xml::get_attributes;
 use crate::lvl1::{
-    Case, Docs, EnumVariant, Expression, Field, Item, List, StructureItem, Switch, XStruct,
+    Case, Docs, EnumVariant, ErrorDoc, Expression, FieldDoc, Item, List, StructureItem, Switch,
+    Version, XStruct,
 };
-use quick_xml::events::{attributes::Attribute, BytesStart, Event};
+use quick_xml::events::{BytesStart, Event};
 use std::{mem, ops::DerefMut};
 use tinyvec::{tiny_vec, TinyVec};
 
@@ -17,6 +18,21 @@ pub enum ExprPurpose {
     Switch,
 }
 
+/// The element of a documentation block whose text is being read in.
+#[derive(Debug)]
+pub enum DocsTarget {
+    /// The brief summary of the item.
+    Brief,
+    /// The long-form description of the item.
+    Description,
+    /// The description of one of the item's fields.
+    Field(String),
+    /// A reason why an error may be raised.
+    Error(String),
+    /// An element whose text we don't care about, such as an example.
+    Ignored,
+}
+
 /// The current state of the parser. Describes which element is currently being parsed.
 #[derive(Debug)]
 pub enum Lvl0State {
@@ -40,6 +56,8 @@ pub enum Lvl0State {
     Expr(ExprPurpose, Option<Expression>, Box<Lvl0State>),
     /// Calculating the expression, given a fieldref.
     ExprFieldRef(Box<Lvl0State>),
+    /// Calculating the expression, given a paramref.
+    ExprParamRef(Box<Lvl0State>),
     /// Calculating the expression, given a value.
     ExprValue(Box<Lvl0State>),
     /// Calculating the expression, given a unary operation.
@@ -69,6 +87,39 @@ pub enum Lvl0State {
     SwitchFieldGlobbingEnumref(Box<Lvl0State>),
     /// Beginning documentation of an item.
     Docs(Docs, Box<Lvl0State>),
+    /// Reading in the text of an element of the documentation.
+    DocsText(DocsTarget, Box<Lvl0State>),
+}
+
+impl DocsTarget {
+    /// Append a bit of text to the documentation this target refers to.
+    #[inline]
+    fn push_text(&self, base: &mut Lvl0State, text: String) {
+        let docs = match base {
+            Lvl0State::Docs(docs, _) => docs,
+            _ => return,
+        };
+
+        match self {
+            Self::Brief => docs.brief.push_str(&text),
+            Self::Description => docs.descr.get_or_insert_with(String::new).push_str(&text),
+            Self::Field(name) => match docs.fields.iter_mut().find(|f| &f.name == name) {
+                Some(field) => field.desc.push_str(&text),
+                None => docs.fields.push(FieldDoc {
+                    name: name.clone(),
+                    desc: text,
+                }),
+            },
+            Self::Error(ty) => match docs.errors.iter_mut().find(|e| &e.ty == ty) {
+                Some(error) => error.reason.push_str(&text),
+                None => docs.errors.push(ErrorDoc {
+                    ty: ty.clone(),
+                    reason: text,
+                }),
+            },
+            Self::Ignored => (),
+        }
+    }
 }
 
 impl Default for Lvl0State {
@@ -83,8 +134,11 @@ impl Default for Lvl0State {
 pub enum StructLike {
     /// Reading in a basic struct.
     Struct(String),
-    /// Reading in an event.
-    Event(String, u64, bool),
+    /// Reading in a union, whose fields all overlay the same bytes.
+    Union(String),
+    /// Reading in an event, with whether it skips the sequence number and whether it's sent as a
+    /// generic event.
+    Event(String, u64, bool, bool),
     /// Reading in an error.
     Error(String, u64),
     /// Reading in a request.
@@ -123,6 +177,16 @@ impl Lvl0State {
         }
     }
 
+    /// Get the reference to the documentation slot.
+    #[inline]
+    fn docs_ref(&mut self) -> Option<&mut Option<Docs>> {
+        match self {
+            Self::StructLike(_, _, docs) => Some(docs),
+            Self::Enum(_, _, docs) => Some(docs),
+            _ => None,
+        }
+    }
+
     /// Try to resolve a list.
     #[inline]
     fn try_resolve_expr(&mut self) {
@@ -176,7 +240,7 @@ impl Lvl0State {
                 // we're waiting for text
                 match event {
                     Event::Text(t) => {
-                        let target = std::str::from_utf8(&*t.unescaped().ok()?).ok()?.to_owned();
+                        let target = std::str::from_utf8(&t.unescaped().ok()?).ok()?.to_owned();
                         *self = Self::AwaitingTopLevel;
                         Some(Item::Import(crate::lvl1::Import(target)))
                     }
@@ -214,7 +278,7 @@ impl Lvl0State {
             }
             Self::XidUnionType(name, sublist) => {
                 if let Event::Text(t) = event {
-                    sublist.push(std::str::from_utf8(&*t.unescaped().ok()?).ok()?.to_owned());
+                    sublist.push(std::str::from_utf8(&t.unescaped().ok()?).ok()?.to_owned());
                     let name = mem::take(name);
                     let sublist = mem::take(sublist);
                     *self = Self::XidUnion(name, sublist);
@@ -222,48 +286,39 @@ impl Lvl0State {
 
                 None
             }
-            Self::Enum(name, variants, docs) => {
+            Self::Enum(name, variants, _) => {
                 // searching for variants, the <item> tag
                 match event {
                     Event::Start(b) | Event::Empty(b) => {
                         if b.name() == b"item" {
                             let name = get_attributes(&b, &[b"name".as_ref()], &[true])?
                                 .remove(b"name".as_ref())?;
-                            let mut state = Self::EnumItem(name, Box::new(Default::default()));
+                            let mut state = Self::EnumItem(name, Box::default());
                             mem::swap(self, &mut state);
                             if let Self::EnumItem(_, base) = self {
-                                *base = Box::new(state);
+                                **base = state;
                             }
                         } else if b.name() == b"doc" {
                             // begin doc mode
-                            let mut base = mem::replace(
-                                self,
-                                Self::Docs(Default::default(), Box::new(Default::default())),
-                            );
+                            let mut base =
+                                mem::replace(self, Self::Docs(Default::default(), Box::default()));
                             if let Self::Docs(_, sbase) = self {
                                 mem::swap(&mut base, &mut *sbase);
                             }
                         }
                     }
-                    Event::End(e) => {
-                        if e.name() == b"enum" {
-                            let name = mem::take(name);
-                            let variants = mem::take(variants);
-                            let docs = mem::take(docs);
-                            *self = Self::AwaitingTopLevel;
-                            return Some(Item::Enum(crate::lvl1::XEnum {
-                                name,
-                                variants,
-                                docs,
-                            }));
-                        }
+                    Event::End(e) if e.name() == b"enum" => {
+                        let name = mem::take(name);
+                        let variants = mem::take(variants);
+                        *self = Self::AwaitingTopLevel;
+                        return Some(Item::Enum(crate::lvl1::XEnum { name, variants }));
                     }
                     _ => (),
                 }
 
                 None
             }
-            Self::EnumItem(name, base) => {
+            Self::EnumItem(..) => {
                 // searching for <value> or <bits>
                 if let Event::Start(b) = event {
                     let t = match b.name() {
@@ -272,10 +327,10 @@ impl Lvl0State {
                         _ => return None,
                     };
 
-                    let mut state = Self::EnumItemValue(t, Box::new(Default::default()));
+                    let mut state = Self::EnumItemValue(t, Box::default());
                     mem::swap(self, &mut state);
                     if let Self::EnumItemValue(_, base) = self {
-                        *base = Box::new(state);
+                        **base = state;
                     }
                 }
 
@@ -286,7 +341,7 @@ impl Lvl0State {
                 if let Event::Text(t) = event {
                     if let Self::EnumItem(vname, base) = base.deref_mut() {
                         if let Self::Enum(ename, variants, docs) = base.deref_mut() {
-                            let number = std::str::from_utf8(&*t.unescaped().ok()?)
+                            let number = std::str::from_utf8(&t.unescaped().ok()?)
                                 .ok()?
                                 .parse()
                                 .ok()?;
@@ -334,7 +389,7 @@ impl Lvl0State {
                             if e.name() == b"reply" {
                                 let fields = mem::take(fields);
                                 let docs = mem::take(docs);
-                                let mut reply = Some(XStruct {
+                                let reply = Some(XStruct {
                                     name: String::new(),
                                     fields,
                                     docs,
@@ -363,12 +418,23 @@ impl Lvl0State {
                                 return Some(Item::Struct(XStruct { name, fields, docs }));
                             }
                         }
-                        StructLike::Event(name, number, nsn) => {
+                        StructLike::Union(name) => {
+                            if e.name() == b"union" {
+                                let name = mem::take(name);
+                                let fields = mem::take(fields);
+                                let docs = mem::take(docs);
+
+                                *self = Self::AwaitingTopLevel;
+                                return Some(Item::Union(XStruct { name, fields, docs }));
+                            }
+                        }
+                        StructLike::Event(name, number, nsn, xge) => {
                             if e.name() == b"event" {
                                 let name = mem::take(name);
                                 let fields = mem::take(fields);
                                 let docs = mem::take(docs);
                                 let nsn = mem::take(nsn);
+                                let xge = mem::take(xge);
                                 let opcode = *number;
 
                                 *self = Self::AwaitingTopLevel;
@@ -376,6 +442,7 @@ impl Lvl0State {
                                     base: XStruct { name, fields, docs },
                                     opcode,
                                     skip_sequence: nsn,
+                                    xge,
                                 }));
                             }
                         }
@@ -398,7 +465,7 @@ impl Lvl0State {
 
                 self.struct_like(event)
             }
-            Self::Expr(purpose, ll, base) => {
+            Self::Expr(..) => {
                 // we are constructing a list field
                 match event {
                     Event::Start(b) => self.handle_expr_item(b, false)?,
@@ -411,33 +478,39 @@ impl Lvl0State {
             }
             Self::ExprFieldRef(base) => {
                 // check for text
-                match event {
-                    Event::Text(t) => {
-                        let t = std::str::from_utf8(&*t.unescaped().ok()?).ok()?.to_owned();
-                        let mut base = mem::take(base);
-                        *base.list_length_ref() = Some(crate::lvl1::Expression::FieldReference(t));
-                        *self = *base;
-                        self.try_resolve_expr();
-                    }
-                    _ => (),
+                if let Event::Text(t) = event {
+                    let t = std::str::from_utf8(&t.unescaped().ok()?).ok()?.to_owned();
+                    let mut base = mem::take(base);
+                    *base.list_length_ref() = Some(crate::lvl1::Expression::FieldReference(t));
+                    *self = *base;
+                    self.try_resolve_expr();
+                }
+
+                None
+            }
+            Self::ExprParamRef(base) => {
+                // check for text
+                if let Event::Text(t) = event {
+                    let t = std::str::from_utf8(&t.unescaped().ok()?).ok()?.to_owned();
+                    let mut base = mem::take(base);
+                    *base.list_length_ref() = Some(crate::lvl1::Expression::ParamReference(t));
+                    *self = *base;
+                    self.try_resolve_expr();
                 }
 
                 None
             }
             Self::ExprValue(base) => {
                 // check for a number
-                match event {
-                    Event::Text(t) => {
-                        let v = std::str::from_utf8(&*t.unescaped().ok()?)
-                            .ok()?
-                            .parse()
-                            .ok()?;
-                        let mut base = mem::take(base);
-                        *base.list_length_ref() = Some(crate::lvl1::Expression::Value(v));
-                        *self = *base;
-                        self.try_resolve_expr();
-                    }
-                    _ => (),
+                if let Event::Text(t) = event {
+                    let v = std::str::from_utf8(&t.unescaped().ok()?)
+                        .ok()?
+                        .parse()
+                        .ok()?;
+                    let mut base = mem::take(base);
+                    *base.list_length_ref() = Some(crate::lvl1::Expression::Value(v));
+                    *self = *base;
+                    self.try_resolve_expr();
                 }
 
                 None
@@ -446,27 +519,25 @@ impl Lvl0State {
                 match event {
                     Event::Start(b) => self.handle_expr_item(b, false)?,
                     Event::Empty(b) => self.handle_expr_item(b, true)?,
-                    Event::End(e) => {
-                        if e.name() == b"op" {
-                            if ll2.is_some() {
-                                let op = mem::take(op);
-                                let ll1 = ll1.take().expect("Failed to construct LL operation");
-                                let ll2 = ll2.take().expect("Failed to construct LL operation");
-                                let mut base = mem::take(base);
-
-                                // read our operation into the base
-                                *base.list_length_ref() = Some(crate::lvl1::Expression::BinaryOp {
-                                    op,
-                                    left: Box::new(ll1),
-                                    right: Box::new(ll2),
-                                });
+                    Event::End(e) if e.name() == b"op" => {
+                        if ll2.is_some() {
+                            let op = mem::take(op);
+                            let ll1 = ll1.take().expect("Failed to construct LL operation");
+                            let ll2 = ll2.take().expect("Failed to construct LL operation");
+                            let mut base = mem::take(base);
 
-                                *self = *base;
-                                self.try_resolve_expr();
-                            } else {
-                                log::error!("Failed to construct binary LL operation");
-                                return None;
-                            }
+                            // read our operation into the base
+                            *base.list_length_ref() = Some(crate::lvl1::Expression::BinaryOp {
+                                op,
+                                left: Box::new(ll1),
+                                right: Box::new(ll2),
+                            });
+
+                            *self = *base;
+                            self.try_resolve_expr();
+                        } else {
+                            log::error!("Failed to construct binary LL operation");
+                            return None;
                         }
                     }
                     _ => (),
@@ -478,20 +549,18 @@ impl Lvl0State {
                 match event {
                     Event::Start(b) => self.handle_expr_item(b, false)?,
                     Event::Empty(b) => self.handle_expr_item(b, true)?,
-                    Event::End(e) => {
-                        if e.name() == b"unop" {
-                            let op = mem::take(op);
-                            let ll = ll.take().expect("Failed to construct unary operation");
-                            let mut base = mem::take(base);
+                    Event::End(e) if e.name() == b"unop" => {
+                        let op = mem::take(op);
+                        let ll = ll.take().expect("Failed to construct unary operation");
+                        let mut base = mem::take(base);
 
-                            // read out operation into the base
-                            *base.list_length_ref() = Some(crate::lvl1::Expression::UnaryOp {
-                                op,
-                                target: Box::new(ll),
-                            });
+                        // read out operation into the base
+                        *base.list_length_ref() = Some(crate::lvl1::Expression::UnaryOp {
+                            op,
+                            target: Box::new(ll),
+                        });
 
-                            *self = *base;
-                        }
+                        *self = *base;
                     }
                     _ => (),
                 }
@@ -502,14 +571,12 @@ impl Lvl0State {
                 match event {
                     Event::Start(b) => self.handle_expr_item(b, false)?,
                     Event::Empty(b) => self.handle_expr_item(b, true)?,
-                    Event::End(e) => {
-                        if e.name() == b"popcount" {
-                            let ll = ll.take().expect("Failed to construct popcount operation");
-                            let mut base = mem::take(base);
-                            *base.list_length_ref() =
-                                Some(crate::lvl1::Expression::OneCount(Box::new(ll)));
-                            *self = *base;
-                        }
+                    Event::End(e) if e.name() == b"popcount" => {
+                        let ll = ll.take().expect("Failed to construct popcount operation");
+                        let mut base = mem::take(base);
+                        *base.list_length_ref() =
+                            Some(crate::lvl1::Expression::OneCount(Box::new(ll)));
+                        *self = *base;
                     }
                     _ => (),
                 }
@@ -519,15 +586,13 @@ impl Lvl0State {
                 match event {
                     Event::Start(b) => self.handle_expr_item(b, false)?,
                     Event::Empty(b) => self.handle_expr_item(b, true)?,
-                    Event::End(e) => {
-                        if e.name() == b"sumof" {
-                            let reff = mem::take(reff);
-                            let ll = ll.take().expect("Failed to construct sumof operation");
-                            let mut base = mem::take(base);
-                            *base.list_length_ref() =
-                                Some(crate::lvl1::Expression::SumOf(reff, Some(Box::new(ll))));
-                            *self = *base;
-                        }
+                    Event::End(e) if e.name() == b"sumof" => {
+                        let reff = mem::take(reff);
+                        let ll = ll.take().expect("Failed to construct sumof operation");
+                        let mut base = mem::take(base);
+                        *base.list_length_ref() =
+                            Some(crate::lvl1::Expression::SumOf(reff, Some(Box::new(ll))));
+                        *self = *base;
                     }
                     _ => (),
                 }
@@ -536,19 +601,17 @@ impl Lvl0State {
             }
             Self::SwitchField(name, cases, expr, base) => {
                 match event {
-                    Event::End(e) => {
-                        if e.name() == b"switch" {
-                            let name = mem::take(name);
-                            let cases = mem::take(cases);
-                            let expr = expr.take().expect("Failed to create switch expr");
-                            let mut base = *mem::take(base);
-
-                            if let Some(fields) = base.fields_ref() {
-                                fields.push(StructureItem::Switch(Switch { name, cases, expr }));
-                            }
+                    Event::End(e) if e.name() == b"switch" => {
+                        let name = mem::take(name);
+                        let cases = mem::take(cases);
+                        let expr = expr.take().expect("Failed to create switch expr");
+                        let mut base = *mem::take(base);
 
-                            *self = base;
+                        if let Some(fields) = base.fields_ref() {
+                            fields.push(StructureItem::Switch(Switch { name, cases, expr }));
                         }
+
+                        *self = base;
                     }
                     Event::Start(b) => {
                         let is_bitcase = match b.name() {
@@ -614,35 +677,74 @@ impl Lvl0State {
                 None
             }
             Self::SwitchFieldGlobbingEnumref(base) => {
-                match event {
-                    Event::Text(t) => {
-                        let t = std::str::from_utf8(&*t.unescaped().ok()?).ok()?.to_string();
-                        let mut base = *mem::take(base);
-                        if let Self::SwitchFieldCase(_, _, ref mut enum_item, _, _) = base {
-                            *enum_item = t;
-                        }
-
-                        *self = base;
+                if let Event::Text(t) = event {
+                    let t = std::str::from_utf8(&t.unescaped().ok()?).ok()?.to_string();
+                    let mut base = *mem::take(base);
+                    if let Self::SwitchFieldCase(_, _, ref mut enum_item, _, _) = base {
+                        *enum_item = t;
                     }
-                    _ => (),
+
+                    *self = base;
                 }
 
                 None
             }
 
             Self::Docs(docs, base) => {
-                // TODO: not skip the docs
                 match event {
-                    Event::End(e) => {
-                        if e.name() == b"doc" {
-                            let mut mbase: Self = Default::default();
-                            mem::swap(&mut mbase, &mut *base);
-                            *self = mbase;
+                    Event::Start(ref b) => {
+                        let target = match b.name() {
+                            b"brief" => DocsTarget::Brief,
+                            b"description" => DocsTarget::Description,
+                            b"field" => DocsTarget::Field(
+                                get_attributes(b, &[b"name".as_ref()], &[true])?
+                                    .remove(b"name".as_ref())
+                                    .unwrap(),
+                            ),
+                            b"error" => DocsTarget::Error(
+                                get_attributes(b, &[b"type".as_ref()], &[true])?
+                                    .remove(b"type".as_ref())
+                                    .unwrap(),
+                            ),
+                            _ => DocsTarget::Ignored,
+                        };
+
+                        let base = Box::new(mem::take(self));
+                        *self = Self::DocsText(target, base);
+                    }
+                    Event::End(e) if e.name() == b"doc" => {
+                        let docs = mem::take(docs);
+                        let mut mbase: Self = Default::default();
+                        mem::swap(&mut mbase, &mut *base);
+                        *self = mbase;
+
+                        // attach the documentation to whatever item we were reading in
+                        if let Some(slot) = self.docs_ref() {
+                            *slot = Some(docs);
                         }
                     }
                     _ => (),
                 }
 
+                None
+            }
+            Self::DocsText(target, base) => {
+                match event {
+                    Event::Text(t) => {
+                        let t = std::str::from_utf8(&t.unescaped().ok()?).ok()?.to_owned();
+                        target.push_text(base, t);
+                    }
+                    Event::CData(t) => {
+                        let t = std::str::from_utf8(&t).ok()?.to_owned();
+                        target.push_text(base, t);
+                    }
+                    Event::End(_) => {
+                        let base = mem::take(base);
+                        *self = *base;
+                    }
+                    _ => (),
+                }
+
                 None
             }
         }
@@ -658,21 +760,23 @@ impl Lvl0State {
                         b"field" => {
                             // this is an ordinary field, get the name and type
                             let mut map = get_attributes(
-                                &b,
+                                b,
                                 &[
                                     b"name".as_ref(),
                                     b"type".as_ref(),
                                     b"enum".as_ref(),
                                     b"mask".as_ref(),
                                     b"altenum".as_ref(),
+                                    b"default".as_ref(),
                                 ],
-                                &[true, true, false, false, false],
+                                &[true, true, false, false, false, false],
                             )?;
                             let name = map.remove(b"name".as_ref()).unwrap();
                             let ty = map.remove(b"type".as_ref()).unwrap();
                             let enumeration = map.remove(b"enum".as_ref());
                             let mask = map.remove(b"mask".as_ref());
                             let alt_enum = map.remove(b"altenum".as_ref());
+                            let default = map.remove(b"default".as_ref());
 
                             fields.push(StructureItem::Field(crate::lvl1::Field {
                                 name,
@@ -680,12 +784,14 @@ impl Lvl0State {
                                 enumeration,
                                 mask,
                                 alt_enum,
+                                min_version: None,
+                                default,
                             }));
                         }
                         b"pad" => {
                             // this is padding, get the number of bytes
                             let mut map = get_attributes(
-                                &b,
+                                b,
                                 &[b"bytes".as_ref(), b"align".as_ref()],
                                 &[false, false],
                             )?;
@@ -712,7 +818,7 @@ impl Lvl0State {
                         b"valueparam" => {
                             // this is a mask value parameter
                             let mut map = get_attributes(
-                                &b,
+                                b,
                                 &[
                                     b"value-mask-type".as_ref(),
                                     b"value-mask-name".as_ref(),
@@ -732,7 +838,7 @@ impl Lvl0State {
                         }
                         b"fd" => {
                             // get the name of the file descriptor
-                            let name = get_attributes(&b, &[b"name".as_ref()], &[true])?
+                            let name = get_attributes(b, &[b"name".as_ref()], &[true])?
                                 .remove(b"name".as_ref())
                                 .unwrap();
                             fields.push(StructureItem::Fd { name });
@@ -740,7 +846,7 @@ impl Lvl0State {
                         b"list" => {
                             // we're starting a list field, shame.
                             let mut map = get_attributes(
-                                &b,
+                                b,
                                 &[b"name".as_ref(), b"type".as_ref()],
                                 &[true, true],
                             )?;
@@ -760,7 +866,7 @@ impl Lvl0State {
                         }
                         b"switch" => {
                             // switches and bitcases
-                            let name = get_attributes(&b, &[b"name".as_ref()], &[true])?
+                            let name = get_attributes(b, &[b"name".as_ref()], &[true])?
                                 .remove(b"name".as_ref())
                                 .unwrap();
 
@@ -781,7 +887,7 @@ impl Lvl0State {
                             }
                         }
                         b"required_start_align" => {
-                            let align = get_attributes(&b, &[b"align".as_ref()], &[true])?
+                            let align = get_attributes(b, &[b"align".as_ref()], &[true])?
                                 .remove(b"align".as_ref())
                                 .unwrap();
                             fields.push(StructureItem::RequiredStartAlign {
@@ -790,10 +896,8 @@ impl Lvl0State {
                         }
                         b"doc" => {
                             // begin documentation mode
-                            let mut base = mem::replace(
-                                self,
-                                Self::Docs(Default::default(), Box::new(Default::default())),
-                            );
+                            let mut base =
+                                mem::replace(self, Self::Docs(Default::default(), Box::default()));
                             if let Self::Docs(_, sbase) = self {
                                 mem::swap(&mut base, &mut *sbase);
                             }
@@ -801,6 +905,15 @@ impl Lvl0State {
                         _ => (),
                     }
                 }
+                // the descriptions mark a field that was added to a structure later with a comment
+                // after it, e.g. `<!-- new in version 0.6 -->`
+                Event::Comment(ref c) => {
+                    if let Some(StructureItem::Field(field)) = fields.last_mut() {
+                        if field.min_version.is_none() {
+                            field.min_version = version_in_comment(c);
+                        }
+                    }
+                }
                 _ => (),
             }
         }
@@ -813,13 +926,19 @@ impl Lvl0State {
         match b.name() {
             // replace self with various list types
             b"fieldref" => {
-                let mut base = mem::replace(self, Self::ExprFieldRef(Box::new(Default::default())));
+                let mut base = mem::replace(self, Self::ExprFieldRef(Box::default()));
                 if let Self::ExprFieldRef(sbase) = self {
                     mem::swap(&mut base, &mut *sbase);
                 }
             }
+            b"paramref" => {
+                let mut base = mem::replace(self, Self::ExprParamRef(Box::default()));
+                if let Self::ExprParamRef(sbase) = self {
+                    mem::swap(&mut base, &mut *sbase);
+                }
+            }
             b"value" => {
-                let mut base = mem::replace(self, Self::ExprValue(Box::new(Default::default())));
+                let mut base = mem::replace(self, Self::ExprValue(Box::default()));
                 if let Self::ExprValue(sbase) = self {
                     mem::swap(&mut base, &mut *sbase);
                 }
@@ -828,10 +947,7 @@ impl Lvl0State {
                 let op = get_attributes(&b, &[b"op".as_ref()], &[true])?
                     .remove(b"op".as_ref())
                     .unwrap();
-                let mut base = mem::replace(
-                    self,
-                    Self::ExprBinary(op, None, None, Box::new(Default::default())),
-                );
+                let mut base = mem::replace(self, Self::ExprBinary(op, None, None, Box::default()));
                 if let Self::ExprBinary(_, _, _, sbase) = self {
                     mem::swap(&mut base, &mut *sbase);
                 }
@@ -840,24 +956,18 @@ impl Lvl0State {
                 let op = get_attributes(&b, &[b"op".as_ref()], &[true])?
                     .remove(b"op".as_ref())
                     .unwrap();
-                let mut base = mem::replace(
-                    self,
-                    Self::ExprUnary(op, None, Box::new(Default::default())),
-                );
+                let mut base = mem::replace(self, Self::ExprUnary(op, None, Box::default()));
                 if let Self::ExprUnary(_, _, sbase) = self {
                     mem::swap(&mut base, &mut *sbase);
                 }
             }
             b"sumof" => {
-                let mut reff = get_attributes(&b, &[b"ref".as_ref()], &[true])?
+                let reff = get_attributes(&b, &[b"ref".as_ref()], &[true])?
                     .remove(b"ref".as_ref())
                     .unwrap();
 
                 if !is_single {
-                    let mut base = mem::replace(
-                        self,
-                        Self::ExprSumof(reff, None, Box::new(Default::default())),
-                    );
+                    let base = mem::replace(self, Self::ExprSumof(reff, None, Box::default()));
                     if let Self::ExprSumof(_, _, sbase) = self {
                         **sbase = base;
                     }
@@ -871,8 +981,7 @@ impl Lvl0State {
                 *(self.list_length_ref()) = Some(Expression::ListExpression);
             }
             b"popcount" => {
-                let mut base =
-                    mem::replace(self, Self::ExprPopcount(None, Box::new(Default::default())));
+                let mut base = mem::replace(self, Self::ExprPopcount(None, Box::default()));
                 if let Self::ExprPopcount(_, sbase) = self {
                     mem::swap(&mut base, &mut *sbase);
                 }
@@ -898,16 +1007,25 @@ impl Lvl0State {
     ) -> Option<Item> {
         match b.name() {
             b"xcb" => {
-                // get the extension name, if applicable
-                let ename = get_attributes(&b, &[b"extension-xname".as_ref()], &[false])
-                    .unwrap()
-                    .remove(b"extension-xname".as_ref());
+                // get the extension name and version, if applicable
+                let mut map = get_attributes(
+                    &b,
+                    &[
+                        b"extension-xname".as_ref(),
+                        b"major-version".as_ref(),
+                        b"minor-version".as_ref(),
+                    ],
+                    &[false, false, false],
+                )
+                .unwrap();
 
-                if let Some(ename) = ename {
+                if let Some(ename) = map.remove(b"extension-xname".as_ref()) {
                     *ext_name = Some(ename);
                 }
 
-                None
+                let major = map.remove(b"major-version".as_ref())?.parse().ok()?;
+                let minor = map.remove(b"minor-version".as_ref())?.parse().ok()?;
+                Some(Item::Version(Version { major, minor }))
             }
             b"typedef" => {
                 // type definition element
@@ -947,6 +1065,14 @@ impl Lvl0State {
                 *self = Lvl0State::StructLike(StructLike::Struct(name), tiny_vec![], None);
                 None
             }
+            b"union" => {
+                // unions hold fields just like structs do
+                let name = get_attributes(&b, &[b"name".as_ref()], &[true])?
+                    .remove(b"name".as_ref())
+                    .unwrap();
+                *self = Lvl0State::StructLike(StructLike::Union(name), tiny_vec![], None);
+                None
+            }
             b"enum" => {
                 // change state machine to await enum variants
                 let name = get_attributes(&b, &[b"name".as_ref()], &[true])?
@@ -963,8 +1089,9 @@ impl Lvl0State {
                         b"name".as_ref(),
                         b"number".as_ref(),
                         b"no-sequence-number".as_ref(),
+                        b"xge".as_ref(),
                     ],
-                    &[true, true, false],
+                    &[true, true, false, false],
                 )?;
                 let name = map.remove(b"name".as_ref()).unwrap();
                 let number = map.remove(b"number".as_ref()).unwrap().parse().ok()?;
@@ -972,9 +1099,13 @@ impl Lvl0State {
                     .remove(b"no-sequence-number".as_ref())
                     .map(|nsn| nsn.to_lowercase().as_str() == "true")
                     .unwrap_or(false);
+                let xge = map
+                    .remove(b"xge".as_ref())
+                    .map(|xge| xge.to_lowercase().as_str() == "true")
+                    .unwrap_or(false);
 
                 *self = Lvl0State::StructLike(
-                    StructLike::Event(name, number, no_sequence_number),
+                    StructLike::Event(name, number, no_sequence_number, xge),
                     tiny_vec![],
                     None,
                 );
@@ -1065,3 +1196,19 @@ impl Lvl0State {
         }
     }
 }
+
+/// Get the version out of a comment that says a field is new in that version.
+#[inline]
+fn version_in_comment(comment: &[u8]) -> Option<String> {
+    let comment = std::str::from_utf8(comment).ok()?.trim().to_lowercase();
+    let version = comment
+        .strip_prefix("new in version ")
+        .or_else(|| comment.strip_prefix("added in version "))?
+        .trim();
+
+    if !version.is_empty() && version.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        Some(version.to_string())
+    } else {
+        None
+    }
+}
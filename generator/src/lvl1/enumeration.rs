@@ -1,6 +1,5 @@
 // MIT/Apache2 License
 
-use super::Docs;
 use tinyvec::TinyVec;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -26,5 +25,4 @@ pub struct EnumVariant {
 pub struct XEnum {
     pub name: String,
     pub variants: TinyVec<[EnumVariant; 4]>,
-    pub docs: Option<Docs>,
 }
@@ -1,13 +1,14 @@
 // MIT/Apache2 License
 
-use super::{NonenumTypenames, XStruct};
-use tinyvec::TinyVec;
+use super::XStruct;
 
 #[derive(Default, Debug)]
 pub struct Event {
     pub base: XStruct,
     pub opcode: u64,
     pub skip_sequence: bool,
+    /// Whether this event is sent as an X Generic Event, where the opcode is its event type.
+    pub xge: bool,
 }
 
 #[derive(Default, Debug)]
@@ -16,17 +17,3 @@ pub struct EventCopy {
     pub opcode: u64,
     pub base: String,
 }
-
-impl NonenumTypenames for Event {
-    #[inline]
-    fn typename(&self) -> TinyVec<[String; 1]> {
-        TinyVec::from([self.base.name.clone()])
-    }
-}
-
-impl NonenumTypenames for EventCopy {
-    #[inline]
-    fn typename(&self) -> TinyVec<[String; 1]> {
-        TinyVec::from([self.name.clone()])
-    }
-}
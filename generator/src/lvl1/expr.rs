@@ -5,6 +5,8 @@
 pub enum Expression {
     Value(i64),
     FieldReference(String),
+    /// A parameter supplied by the context that encloses the structure, rather than a field of it.
+    ParamReference(String),
     BinaryOp {
         op: String,
         left: Box<Expression>,
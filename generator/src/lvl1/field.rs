@@ -27,6 +27,8 @@ pub struct Field {
     pub mask: Option<String>,
     pub enumeration: Option<String>,
     pub alt_enum: Option<String>,
+    pub min_version: Option<String>,
+    pub default: Option<String>,
 }
 
 #[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
@@ -9,9 +9,11 @@ pub enum Item {
     XidUnion(super::XidUnion),
     Enum(super::XEnum),
     Struct(super::XStruct),
+    Union(super::XStruct),
     Request(super::Request),
     Event(super::Event),
     Error(super::XError),
     EventCopy(super::EventCopy),
     ErrorCopy(super::XErrorCopy),
+    Version(super::Version),
 }
@@ -12,6 +12,7 @@ mod request;
 mod structure;
 mod switch;
 mod typedef;
+mod version;
 mod xidtype;
 mod xidunion;
 
@@ -27,5 +28,6 @@ pub use request::*;
 pub use structure::*;
 pub use switch::*;
 pub use typedef::*;
+pub use version::*;
 pub use xidtype::*;
 pub use xidunion::*;
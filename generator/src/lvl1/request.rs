@@ -1,7 +1,6 @@
 // MIT/Apache2 License
 
-use super::{NonenumTypenames, StructureItem, XStruct};
-use tinyvec::TinyVec;
+use super::XStruct;
 
 #[derive(Default, Debug)]
 pub struct Request {
@@ -9,13 +8,3 @@ pub struct Request {
     pub opcode: u64,
     pub reply: Option<XStruct>,
 }
-
-impl NonenumTypenames for Request {
-    #[inline]
-    fn typename(&self) -> TinyVec<[String; 1]> {
-        TinyVec::Heap(vec![
-            format!("{}Request", &self.base.name),
-            format!("{}Reply", &self.base.name),
-        ])
-    }
-}
@@ -1,6 +1,6 @@
 // MIT/Apache2 License
 
-use super::{Expression, Field, StructureItem};
+use super::{Expression, StructureItem};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Switch {
@@ -0,0 +1,8 @@
+// MIT/Apache2 License
+
+/// The version of an extension that the description file describes.
+#[derive(Default, Debug, Copy, Clone)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+}
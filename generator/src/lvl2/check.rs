@@ -0,0 +1,290 @@
+// MIT/Apache2 License
+
+use super::{Field, Item, List, Struct, StructSpecial, StructureItem, Switch};
+use std::{collections::BTreeMap, error::Error, fmt};
+
+/// A length slot or a list that doesn't line up with the other.
+#[derive(Debug)]
+pub struct LenSlotMismatch {
+    /// The structure the item is in.
+    pub sname: Box<str>,
+    /// The list that the item names or is named by.
+    pub list: String,
+    /// The number of length slots that claim the list. This should be one.
+    pub slots: usize,
+    /// Whether the list exists.
+    pub found_list: bool,
+}
+
+/// Every mismatch between the length slots and the lists of a series of structures.
+pub struct LenSlotErrors(pub Vec<LenSlotMismatch>);
+
+impl fmt::Display for LenSlotErrors {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Length slots don't match the lists they describe:")?;
+        self.0.iter().try_for_each(|m| match m.found_list {
+            false => writeln!(
+                f,
+                "  \"{}\" has a length slot for \"{}\", which is not one of its lists",
+                &m.sname, &m.list
+            ),
+            true => writeln!(
+                f,
+                "  \"{}\" has {} length slots for its list \"{}\"",
+                &m.sname, m.slots, &m.list
+            ),
+        })
+    }
+}
+
+// `main` prints its error with `Debug`, so this shows the whole list
+impl fmt::Debug for LenSlotErrors {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for LenSlotErrors {}
+
+/// The names of the lists in a series of items, including the ones in switch cases.
+#[inline]
+fn list_names<'a>(items: &'a [StructureItem], names: &mut Vec<&'a str>) {
+    items.iter().for_each(|item| match item {
+        StructureItem::List(List { name, .. }) => names.push(name),
+        StructureItem::Switch(Switch { cases, .. }) => {
+            cases.iter().for_each(|c| list_names(&c.items, names))
+        }
+        _ => (),
+    });
+}
+
+/// Check the length slots in a series of items against the lists they describe. A slot is read
+/// before its list, so it can describe a list in its own scope or in a switch case within it, but
+/// not one in a case outside of it.
+#[inline]
+fn check_scope(sname: &str, items: &[StructureItem], mismatches: &mut Vec<LenSlotMismatch>) {
+    let mut lists = vec![];
+    list_names(items, &mut lists);
+
+    let mut slots: BTreeMap<&str, usize> = BTreeMap::new();
+    items.iter().for_each(|item| {
+        if let StructureItem::LenSlot { owning_list, .. } = item {
+            *slots.entry(owning_list).or_default() += 1;
+        }
+    });
+
+    mismatches.extend(
+        slots
+            .into_iter()
+            .map(|(list, slots)| LenSlotMismatch {
+                sname: sname.into(),
+                list: list.to_string(),
+                slots,
+                found_list: lists.contains(&list),
+            })
+            .filter(|m| !m.found_list || m.slots > 1),
+    );
+
+    items.iter().for_each(|item| {
+        if let StructureItem::Switch(Switch { cases, .. }) = item {
+            cases
+                .iter()
+                .for_each(|c| check_scope(sname, &c.items, mismatches));
+        }
+    });
+}
+
+#[inline]
+fn check_struct(s: &Struct, mismatches: &mut Vec<LenSlotMismatch>) {
+    check_scope(&s.name, &s.fields, mismatches);
+    if let StructSpecial::Request(_, Some(ref reply)) = s.special {
+        check_struct(reply, mismatches);
+    }
+}
+
+/// Check that every length slot in a series of items names a list that it can describe, and that
+/// no list is described by more than one slot. Every mismatch is collected before this fails, so
+/// that a broken description file can be fixed in one go.
+#[inline]
+pub fn check_len_slots(items: &[Item]) -> Result<(), LenSlotErrors> {
+    let mut mismatches = vec![];
+    items.iter().for_each(|item| {
+        if let Item::Struct(s) = item {
+            check_struct(s, &mut mismatches);
+        }
+    });
+
+    match mismatches.is_empty() {
+        true => Ok(()),
+        false => Err(LenSlotErrors(mismatches)),
+    }
+}
+
+/// An expression in a structure that refers to a field the structure doesn't have.
+#[derive(Debug)]
+pub struct UnresolvedFieldRef {
+    /// The structure the expression is in.
+    pub sname: Box<str>,
+    /// The field that the expression refers to.
+    pub field: String,
+}
+
+/// Every reference to a missing field in a series of structures.
+pub struct FieldRefErrors(pub Vec<UnresolvedFieldRef>);
+
+impl fmt::Display for FieldRefErrors {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Expressions refer to fields that don't exist:")?;
+        self.0.iter().try_for_each(|r| {
+            writeln!(
+                f,
+                "  \"{}\" refers to \"{}\", which is not one of its fields",
+                &r.sname, &r.field
+            )
+        })
+    }
+}
+
+impl fmt::Debug for FieldRefErrors {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for FieldRefErrors {}
+
+/// The names of the fields, lists and switches in a series of items, including the ones in switch
+/// cases.
+#[inline]
+fn field_names<'a>(items: &'a [StructureItem], names: &mut Vec<&'a str>) {
+    items.iter().for_each(|item| match item {
+        StructureItem::Field(Field { name, .. })
+        | StructureItem::List(List { name, .. })
+        | StructureItem::Fd { name } => names.push(name),
+        StructureItem::Switch(Switch { name, cases, .. }) => {
+            names.push(name);
+            cases.iter().for_each(|c| field_names(&c.items, names));
+        }
+        _ => (),
+    });
+}
+
+/// Check the expressions in a series of items against the names of the fields of their structure.
+/// A field that only describes the length of a list has been replaced by a length slot, so one field
+/// in the length of a list with a slot is taken to be that slot. Like a field, a slot can be used by
+/// the switch cases within its scope, so `slots` holds the lists that the enclosing scopes have slots
+/// for.
+#[inline]
+fn check_refs<'a>(
+    sname: &str,
+    items: &'a [StructureItem],
+    names: &[&str],
+    mut slots: Vec<&'a str>,
+    unresolved: &mut Vec<UnresolvedFieldRef>,
+) {
+    slots.extend(items.iter().filter_map(|item| match item {
+        StructureItem::LenSlot { owning_list, .. } => Some(owning_list.as_str()),
+        _ => None,
+    }));
+
+    let mut refs = vec![];
+    items.iter().for_each(|item| match item {
+        StructureItem::List(List {
+            name, list_length, ..
+        }) => {
+            let mut missing = list_length
+                .field_refs()
+                .into_iter()
+                .filter(|field| !names.contains(field))
+                .collect::<Vec<_>>();
+            if slots.contains(&name.as_str()) && !missing.is_empty() {
+                missing.remove(0);
+            }
+            refs.extend(missing);
+        }
+        StructureItem::Switch(Switch { expr, cases, .. }) => {
+            refs.extend(expr.field_refs());
+            cases.iter().for_each(|c| {
+                refs.extend(c.condition.expr.field_refs());
+                check_refs(sname, &c.items, names, slots.clone(), unresolved);
+            });
+        }
+        _ => (),
+    });
+
+    unresolved.extend(
+        refs.into_iter()
+            .filter(|field| !names.contains(field))
+            .map(|field| UnresolvedFieldRef {
+                sname: sname.into(),
+                field: field.to_string(),
+            }),
+    );
+}
+
+#[inline]
+fn check_struct_refs(sname: &str, s: &Struct, unresolved: &mut Vec<UnresolvedFieldRef>) {
+    let mut names = vec![];
+    field_names(&s.fields, &mut names);
+    check_refs(sname, &s.fields, &names, vec![], unresolved);
+    if let StructSpecial::Request(_, Some(ref reply)) = s.special {
+        check_struct_refs(&format!("{}Reply", sname), reply, unresolved);
+    }
+}
+
+/// Check that every field that an expression refers to is a field of the structure the expression
+/// is in. Like the length slots, every missing field is collected before this fails.
+#[inline]
+pub fn check_field_refs(items: &[Item]) -> Result<(), FieldRefErrors> {
+    let mut unresolved = vec![];
+    items.iter().for_each(|item| {
+        if let Item::Struct(s) = item {
+            check_struct_refs(&s.name, s, &mut unresolved);
+        }
+    });
+
+    match unresolved.is_empty() {
+        true => Ok(()),
+        false => Err(FieldRefErrors(unresolved)),
+    }
+}
+
+#[test]
+fn refuse_unresolved_field_refs() {
+    use super::{Expression, MaybeString, Type};
+    use crate::lvl1::Expression as Lvl1Expression;
+
+    let structure = |fields| {
+        vec![Item::Struct(Struct {
+            name: "Counted".into(),
+            brief: None,
+            desc: None,
+            fields,
+            special: StructSpecial::Regular,
+        })]
+    };
+    let list = StructureItem::List(List {
+        name: "items".into(),
+        ty: MaybeString::NotAString(Type::BasicType("u8".into())),
+        doc: None,
+        list_length: Expression::from(Lvl1Expression::FieldReference("count".into())),
+        padding: None,
+        element_padding: None,
+    });
+    let count = StructureItem::Field(Field {
+        name: "count".into(),
+        ty: Type::BasicType("u8".into()),
+        ..Default::default()
+    });
+
+    let errors = check_field_refs(&structure(vec![list.clone()])).unwrap_err();
+    assert_eq!(errors.0.len(), 1);
+    assert_eq!(&*errors.0[0].sname, "Counted");
+    assert_eq!(errors.0[0].field, "count");
+
+    assert!(check_field_refs(&structure(vec![count, list])).is_ok());
+}
@@ -0,0 +1,63 @@
+// MIT/Apache2 License
+
+use super::{Item, List, MaybeString, StructSpecial, StructureItem, Switch, Type};
+use std::collections::HashMap;
+
+/// The largest alignment that a series of items pads itself out to, if it pads itself at all.
+#[inline]
+fn alignment(items: &[StructureItem]) -> Option<usize> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            StructureItem::AlignPad { align } => Some(*align),
+            StructureItem::List(List { padding, .. }) => *padding,
+            StructureItem::Switch(Switch { cases, .. }) => {
+                cases.iter().filter_map(|c| alignment(&c.items)).max()
+            }
+            _ => None,
+        })
+        .max()
+}
+
+#[inline]
+fn pad_elements(items: &mut [StructureItem], aligns: &HashMap<Box<str>, usize>) {
+    items.iter_mut().for_each(|item| match item {
+        // the lists that take up the rest of a request are read until they run out of bytes, so
+        // they're left as they are
+        StructureItem::List(List {
+            ty: MaybeString::NotAString(Type::BasicType(elem)),
+            list_length,
+            element_padding,
+            ..
+        }) if !list_length.is_remainder() => *element_padding = aligns.get(&**elem).copied(),
+        StructureItem::Switch(Switch { cases, .. }) => cases
+            .iter_mut()
+            .for_each(|c| pad_elements(&mut c.items, aligns)),
+        _ => (),
+    });
+}
+
+/// Pad out every element of the lists of structures that align the items inside of them. Those
+/// alignments are worked out from the start of the structure, so each one has to start on a
+/// multiple of the alignment as well.
+#[inline]
+pub fn pad_list_elements(items: &mut [Item]) {
+    let aligns: HashMap<Box<str>, usize> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(s) if matches!(s.special, StructSpecial::Regular) => {
+                alignment(&s.fields).map(|align| (s.name.clone(), align))
+            }
+            _ => None,
+        })
+        .collect();
+
+    items.iter_mut().for_each(|item| {
+        if let Item::Struct(s) = item {
+            pad_elements(&mut s.fields, &aligns);
+            if let StructSpecial::Request(_, Some(ref mut reply)) = s.special {
+                pad_elements(&mut reply.fields, &aligns);
+            }
+        }
+    });
+}
@@ -122,7 +122,7 @@ pub fn create_generator(state: &Lvl2State, lvl1: Lvl1Enum) -> (String, EnumReprG
                 })
                 .collect::<Vec<_>>();
 
-            return (
+            (
                 matching_name,
                 Box::new(move |underlying| {
                     EnumRepr::Bitflags(Bitflags {
@@ -131,7 +131,7 @@ pub fn create_generator(state: &Lvl2State, lvl1: Lvl1Enum) -> (String, EnumReprG
                         underlying,
                     })
                 }),
-            );
+            )
         }
         false => {
             let name = safe_name(name.to_camel_case());
@@ -150,7 +150,7 @@ pub fn create_generator(state: &Lvl2State, lvl1: Lvl1Enum) -> (String, EnumReprG
                 })
                 .collect();
 
-            return (
+            (
                 matching_name,
                 Box::new(move |underlying| {
                     EnumRepr::TrueEnum(TrueEnum {
@@ -159,7 +159,7 @@ pub fn create_generator(state: &Lvl2State, lvl1: Lvl1Enum) -> (String, EnumReprG
                         underlying,
                     })
                 }),
-            );
+            )
         }
     }
 }
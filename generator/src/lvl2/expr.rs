@@ -3,7 +3,7 @@
 use super::safe_name;
 use crate::lvl1::Expression as Lvl1Expression;
 use heck::SnakeCase;
-use std::{ops::Deref, str::FromStr};
+use std::{collections::HashMap, convert::TryFrom, ops::Deref, str::FromStr};
 use tinyvec::{tiny_vec, TinyVec};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11,8 +11,11 @@ pub enum BinaryOp {
     Add,
     Sub,
     Mult,
+    /// Integer division, which truncates towards zero just like the X server's division does.
     Div,
     And,
+    Shl,
+    Shr,
 }
 
 impl FromStr for BinaryOp {
@@ -26,6 +29,8 @@ impl FromStr for BinaryOp {
             "*" => Self::Mult,
             "/" => Self::Div,
             "&" => Self::And,
+            "<<" => Self::Shl,
+            ">>" => Self::Shr,
             _ => return Err(()),
         })
     }
@@ -53,6 +58,8 @@ impl FromStr for UnaryOp {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ExpressionItem {
     FieldRef(Box<str>),
+    /// A parameter supplied by the context that encloses the structure.
+    ParamRef(Box<str>),
     Value(i64),
     BinaryOp(BinaryOp),
     UnaryOp(UnaryOp),
@@ -112,6 +119,38 @@ impl Expression {
         })
     }
 
+    /// The fields that this expression refers to. The expression that a `SumOf` applies to each item
+    /// of its list refers to the fields of those items instead, so it's left out.
+    #[inline]
+    pub fn field_refs(&self) -> Vec<&str> {
+        let mut refs = vec![];
+        let mut i = 0;
+        while i < self.postfix.len() {
+            match &self.postfix[i] {
+                ExpressionItem::FieldRef(f) => refs.push(&**f),
+                ExpressionItem::SumOf(_, true) => {
+                    i += subexpression_len(&self.postfix[i + 1..]);
+                }
+                _ => (),
+            }
+            i += 1;
+        }
+        refs
+    }
+
+    /// Replace references to fields that have been renamed.
+    #[inline]
+    pub fn rename_fields(&mut self, renames: &HashMap<String, String>) {
+        self.postfix.iter_mut().for_each(|t| match t {
+            ExpressionItem::FieldRef(ft) | ExpressionItem::SumOf(ft, _) => {
+                if let Some(new) = renames.get(&**ft) {
+                    *ft = new.as_str().into();
+                }
+            }
+            _ => (),
+        });
+    }
+
     /// If this is a one-field list, return that one field.
     #[inline]
     pub fn single_item(&self) -> Option<&str> {
@@ -125,16 +164,38 @@ impl Expression {
         }
     }
 
-    /// If this is a fixed-value list, return that value.
+    /// If this counts the bits that are set in one field, return that field.
     #[inline]
-    pub fn fixed_size(&self) -> Option<i64> {
-        if self.postfix.len() != 1 {
-            None
-        } else {
-            match &self.postfix[0] {
-                ExpressionItem::Value(v) => Some(*v),
-                _ => None,
+    pub fn one_count_item(&self) -> Option<&str> {
+        match &self.postfix[..] {
+            [ExpressionItem::UnaryOp(UnaryOp::OneCount), ExpressionItem::FieldRef(s)] => Some(s),
+            _ => None,
+        }
+    }
+
+    /// If this is the length in bytes of items that are as wide as the `format` field says, like
+    /// `value_len * (format / 8)`, return the field that holds the number of items.
+    #[inline]
+    pub fn formatted_count(&self) -> Option<&str> {
+        match &self.postfix[..] {
+            [ExpressionItem::BinaryOp(BinaryOp::Mult), ExpressionItem::FieldRef(count), ExpressionItem::BinaryOp(BinaryOp::Div), ExpressionItem::FieldRef(format), ExpressionItem::Value(8)]
+                if &**format == "format" =>
+            {
+                Some(count)
             }
+            _ => None,
+        }
+    }
+
+    /// If this is a fixed-value list, return that value. Expressions that only combine values are
+    /// worked out, so that a length like `4 * 8` is known ahead of time as well.
+    #[inline]
+    pub fn fixed_size(&self) -> Option<i64> {
+        let mut items = self.postfix.iter();
+        let value = fold_constant(&mut items)?;
+        match items.next() {
+            Some(_) => None,
+            None => Some(value),
         }
     }
 
@@ -154,6 +215,48 @@ impl From<Lvl1Expression> for Expression {
     }
 }
 
+/// The number of items that make up the expression at the start of these items.
+#[inline]
+fn subexpression_len(items: &[ExpressionItem]) -> usize {
+    match items.first() {
+        Some(ExpressionItem::BinaryOp(_)) => {
+            let left = subexpression_len(&items[1..]);
+            1 + left + subexpression_len(&items[1 + left..])
+        }
+        Some(ExpressionItem::UnaryOp(_)) | Some(ExpressionItem::SumOf(_, true)) => {
+            1 + subexpression_len(&items[1..])
+        }
+        Some(_) => 1,
+        None => 0,
+    }
+}
+
+/// Work out the value of the expression at the start of these items, if it only involves values.
+#[inline]
+fn fold_constant<'a, I: Iterator<Item = &'a ExpressionItem>>(items: &mut I) -> Option<i64> {
+    match items.next()? {
+        ExpressionItem::Value(v) => Some(*v),
+        ExpressionItem::BinaryOp(op) => {
+            let left = fold_constant(items)?;
+            let right = fold_constant(items)?;
+            match op {
+                BinaryOp::Add => left.checked_add(right),
+                BinaryOp::Sub => left.checked_sub(right),
+                BinaryOp::Mult => left.checked_mul(right),
+                BinaryOp::Div => left.checked_div(right),
+                BinaryOp::And => Some(left & right),
+                BinaryOp::Shl => left.checked_shl(u32::try_from(right).ok()?),
+                BinaryOp::Shr => left.checked_shr(u32::try_from(right).ok()?),
+            }
+        }
+        ExpressionItem::UnaryOp(UnaryOp::Not) => Some(!fold_constant(items)?),
+        ExpressionItem::UnaryOp(UnaryOp::OneCount) => {
+            Some(fold_constant(items)?.count_ones().into())
+        }
+        _ => None,
+    }
+}
+
 // helper recursive function to build a list from the lvl1 tree
 #[inline]
 fn convert_ll(length: Lvl1Expression) -> TinyVec<[ExpressionItem; 1]> {
@@ -162,6 +265,9 @@ fn convert_ll(length: Lvl1Expression) -> TinyVec<[ExpressionItem; 1]> {
         Lvl1Expression::FieldReference(f) => TinyVec::from([ExpressionItem::FieldRef(
             safe_name(f.to_snake_case()).into_boxed_str(),
         )]),
+        Lvl1Expression::ParamReference(p) => TinyVec::from([ExpressionItem::ParamRef(
+            safe_name(p.to_snake_case()).into_boxed_str(),
+        )]),
         Lvl1Expression::BinaryOp { op, left, right } => {
             // parse the op
             let op: BinaryOp = op.parse().unwrap();
@@ -202,3 +308,52 @@ fn convert_ll(length: Lvl1Expression) -> TinyVec<[ExpressionItem; 1]> {
         Lvl1Expression::ListExpression => TinyVec::from([ExpressionItem::ListExprRef]),
     }
 }
+
+#[test]
+fn convert_shifts_and_division() {
+    let binop = |op: &str, left, right| Lvl1Expression::BinaryOp {
+        op: op.to_string(),
+        left: Box::new(left),
+        right: Box::new(right),
+    };
+
+    // (numItems << 2) / 3
+    let length = Expression::from(binop(
+        "/",
+        binop(
+            "<<",
+            Lvl1Expression::FieldReference("numItems".into()),
+            Lvl1Expression::Value(2),
+        ),
+        Lvl1Expression::Value(3),
+    ));
+    assert_eq!(
+        length.iter().cloned().collect::<Vec<_>>(),
+        vec![
+            ExpressionItem::BinaryOp(BinaryOp::Div),
+            ExpressionItem::BinaryOp(BinaryOp::Shl),
+            ExpressionItem::FieldRef("num_items".into()),
+            ExpressionItem::Value(2),
+            ExpressionItem::Value(3),
+        ]
+    );
+    assert_eq!(length.field_refs(), vec!["num_items"]);
+    assert_eq!(length.fixed_size(), None);
+
+    // division truncates towards zero, on either side of it
+    let fixed = |op, left, right| {
+        Expression::from(binop(
+            "/",
+            binop(
+                op,
+                Lvl1Expression::Value(left),
+                Lvl1Expression::Value(right),
+            ),
+            Lvl1Expression::Value(4),
+        ))
+        .fixed_size()
+    };
+    assert_eq!(fixed("<<", 7, 1), Some(3));
+    assert_eq!(fixed(">>", 15, 1), Some(1));
+    assert_eq!(fixed("<<", -7, 1), Some(-3));
+}
@@ -3,7 +3,7 @@
 use super::{safe_name, Expression, Type};
 use crate::lvl1::StructureItem as Lvl1StructureItem;
 use heck::{CamelCase, SnakeCase};
-use std::{borrow::Cow, rc::Rc};
+use std::rc::Rc;
 use tinyvec::TinyVec;
 
 /// Check variant or ==
@@ -29,7 +29,17 @@ pub struct Field {
     pub name: String,
     pub ty: Type,
     pub doc: Option<String>,
-    pub condition: Option<Rc<UseCondition>>,
+    /// The version of the protocol that added this field, if it was added after the structure
+    /// was. Older servers send the structure without this field or any of the ones after it.
+    pub min_version: Option<String>,
+    /// The value the protocol gives this field when nothing else is specified, if it isn't zero.
+    pub default: Option<i128>,
+    /// The enumeration that also describes the values of this field, which is stored as its
+    /// original type anyways since it can hold values outside of the enumeration.
+    pub alt_enum: Option<String>,
+    /// For data whose items are as wide as the `format` field of the structure says, the field
+    /// that holds the number of items. The width is only known once `format` is read.
+    pub formatted_count: Option<String>,
 }
 
 /// The list might be a string in disguise!
@@ -47,15 +57,48 @@ pub struct List {
     pub doc: Option<String>,
     pub list_length: Expression,
     pub padding: Option<usize>,
+    /// The alignment that each element of the list is padded out to, if the elements are padded.
+    pub element_padding: Option<usize>,
+}
+
+/// A case of a switch. Its items are only present if the condition is met.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SwitchCase {
+    pub condition: Rc<UseCondition>,
+    pub items: Vec<StructureItem>,
+}
+
+/// A set of items that are present or absent depending on the value of an expression, which is
+/// usually a bitmask.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Switch {
+    pub name: String,
+    pub expr: Rc<Expression>,
+    pub cases: Vec<SwitchCase>,
 }
 
 /// An item in a structure.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum StructureItem {
     Field(Field),
-    Padding { bytes: usize },
+    Padding {
+        bytes: usize,
+    },
+    /// Padding out to the next multiple of the alignment, so its size depends on where it falls.
+    AlignPad {
+        align: usize,
+    },
     List(List),
-    LenSlot { ty: Type, owning_list: String },
+    LenSlot {
+        ty: Type,
+        owning_list: String,
+        min_version: Option<String>,
+    },
+    Switch(Switch),
+    // a file descriptor takes up no space in the byte sequence, and is sent alongside it instead
+    Fd {
+        name: String,
+    },
 }
 
 impl Default for StructureItem {
@@ -72,7 +115,6 @@ impl StructureItem {
     pub fn from_lvl1(
         lvl1: Lvl1StructureItem,
         resolution: &mut Option<(String, String)>,
-        fds: &mut Vec<String>,
     ) -> TinyVec<[Self; 1]> {
         match lvl1 {
             Lvl1StructureItem::Field(f) => {
@@ -83,6 +125,8 @@ impl StructureItem {
                         mask,
                         enumeration,
                         alt_enum,
+                        min_version,
+                        default,
                     } = f;
 
                     // if mask or enum is set, that's a setting resolution
@@ -98,19 +142,42 @@ impl StructureItem {
                     let name = safe_name(name.to_snake_case());
 
                     // is alt_enum is set, it's a resolution that doesn't change our type
-                    if let Some(alt_enum) = alt_enum {
-                        *resolution = Some((alt_enum, ty.clone()));
+                    if let Some(ref alt_enum) = alt_enum {
+                        *resolution = Some((alt_enum.clone(), ty.clone()));
                     }
 
+                    let default = default.and_then(|default| match default.parse() {
+                        Ok(0) => None,
+                        Ok(default) => Some(default),
+                        Err(_) => {
+                            log::warn!(
+                                "Default value of \"{}\" is not a number: {}",
+                                &name,
+                                default
+                            );
+                            None
+                        }
+                    });
+
                     Field {
                         name,
                         ty: Type::BasicType(ty.into()),
                         doc: None,
-                        condition: None,
+                        min_version,
+                        default,
+                        alt_enum: alt_enum.map(|e| e.to_camel_case()),
+                        formatted_count: None,
                     }
                 })])
             }
-            Lvl1StructureItem::Padding { bytes, .. } => TinyVec::from([Self::Padding { bytes }]),
+            Lvl1StructureItem::Padding {
+                bytes,
+                is_align: false,
+            } => TinyVec::from([Self::Padding { bytes }]),
+            Lvl1StructureItem::Padding {
+                bytes,
+                is_align: true,
+            } => TinyVec::from([Self::AlignPad { align: bytes }]),
             Lvl1StructureItem::List(l) => {
                 TinyVec::from([StructureItem::List({
                     let crate::lvl1::List {
@@ -132,13 +199,13 @@ impl StructureItem {
                         doc: None,
                         list_length,
                         padding: None,
+                        element_padding: None,
                     }
                 })])
             }
-            Lvl1StructureItem::Fd { name } => {
-                fds.push(name);
-                TinyVec::new()
-            }
+            Lvl1StructureItem::Fd { name } => TinyVec::from([StructureItem::Fd {
+                name: safe_name(name.to_snake_case()),
+            }]),
             Lvl1StructureItem::ValueParam(v) => {
                 let crate::lvl1::ValueParam {
                     mask_ty,
@@ -158,22 +225,24 @@ impl StructureItem {
                         list_length: Expression::one_count(mask_name),
                         doc: None,
                         padding: None,
+                        element_padding: None,
                     }),
                 ])
             }
             Lvl1StructureItem::Switch(crate::lvl1::Switch { name, cases, expr }) => {
                 let expr: Rc<Expression> = Rc::new(expr.into());
-                cases
+                let cases = cases
                     .into_iter()
-                    .flat_map(|c| {
-                        // create an item for the condition
+                    .map(|c| {
                         let crate::lvl1::Case {
                             is_bitcase,
                             enum_ref,
                             enum_item,
                             fields,
                         } = c;
-                        let cond = Rc::new(UseCondition {
+
+                        // create an item for the condition
+                        let condition = Rc::new(UseCondition {
                             expr: expr.clone(),
                             variant: if is_bitcase {
                                 ConditionVariant::BitflagVariant
@@ -184,24 +253,20 @@ impl StructureItem {
                             enum_value: safe_name(enum_item),
                         });
 
-                        fields.into_iter().flat_map(move |f| {
-                            let cond = cond.clone();
-                            let mut _dummy = vec![]; // fds in switch cases are not yet supported
-                            StructureItem::from_lvl1(f, &mut Default::default(), &mut _dummy)
-                                .into_iter()
-                                .map(move |mut f| {
-                                    if let StructureItem::Field(Field {
-                                        ref mut condition, ..
-                                    }) = f
-                                    {
-                                        *condition = Some(cond.clone());
-                                    }
-
-                                    f
-                                })
-                        })
+                        let items = fields
+                            .into_iter()
+                            .flat_map(|f| StructureItem::from_lvl1(f, &mut Default::default()))
+                            .collect();
+
+                        SwitchCase { condition, items }
                     })
-                    .collect()
+                    .collect();
+
+                TinyVec::from([StructureItem::Switch(Switch {
+                    name: safe_name(name.to_snake_case()),
+                    expr,
+                    cases,
+                })])
             }
             Lvl1StructureItem::RequiredStartAlign { .. } => {
                 log::error!("Required start align ignored - we need to fix this!");
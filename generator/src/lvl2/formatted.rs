@@ -0,0 +1,46 @@
+// MIT/Apache2 License
+
+use super::{Field, Item, List, MaybeString, StructSpecial, StructureItem, Type};
+
+#[inline]
+fn format_lists(items: &mut [StructureItem]) {
+    items.iter_mut().for_each(|item| {
+        let field = match item {
+            StructureItem::List(List {
+                name,
+                ty: MaybeString::NotAString(Type::BasicType(elem)),
+                list_length,
+                doc,
+                ..
+            }) if matches!(&**elem, "Void" | "Byte" | "Card8") => {
+                list_length.formatted_count().map(|count| Field {
+                    name: name.clone(),
+                    ty: Type::BasicType("PropertyData".into()),
+                    doc: doc.clone(),
+                    formatted_count: Some(count.to_string()),
+                    ..Default::default()
+                })
+            }
+            _ => None,
+        };
+
+        if let Some(field) = field {
+            *item = StructureItem::Field(field);
+        }
+    });
+}
+
+/// Store the data of replies whose items are as wide as their `format` field says, like the value
+/// of a property, as `PropertyData`. The width is checked against the format when the reply is
+/// read, instead of leaving the bytes for the caller to split up. Requests that send this kind of
+/// data are left as they are.
+#[inline]
+pub fn format_reply_data(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| {
+        if let Item::Struct(s) = item {
+            if let StructSpecial::Request(_, Some(ref mut reply)) = s.special {
+                format_lists(&mut reply.fields);
+            }
+        }
+    });
+}
@@ -1,7 +1,7 @@
 // MIT/Apache2 License
 
 use super::{EnumRepr, Struct, XidType};
-use crate::lvl1::{Import, Typedef};
+use crate::lvl1::{Import, Typedef, Version};
 
 /// Level 2 Items include a subset of Level 1's items, along with resolved versions of those items.
 #[derive(Debug)]
@@ -11,6 +11,7 @@ pub enum Item {
     XidType(XidType),
     Enum(EnumRepr),
     Struct(Struct),
+    Version(Version),
 }
 
 impl Default for Item {
@@ -1,19 +1,27 @@
 // MIT/Apache2 License
 
+mod check;
+mod element_pad;
 mod enumeration;
 mod expr;
 mod field;
+mod formatted;
 mod item;
+mod namespace;
 mod specific_field;
 mod state;
 mod structure;
 mod ty;
 mod xidtype;
 
+pub use check::*;
+pub use element_pad::*;
 pub use enumeration::*;
 pub use expr::*;
 pub use field::*;
+pub use formatted::*;
 pub use item::*;
+pub use namespace::*;
 pub use specific_field::*;
 pub use state::*;
 pub use structure::*;
@@ -98,9 +106,6 @@ pub fn safe_name(mut name: String) -> String {
         "32bits" => {
             name = "ThirtyTwoBits".to_owned();
         }
-        "type" => {
-            name = "type_".to_owned();
-        }
         _ => (),
     }
 
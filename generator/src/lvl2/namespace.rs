@@ -0,0 +1,62 @@
+// MIT/Apache2 License
+
+use super::safe_name;
+use crate::lvl1::{Item as Lvl1Item, Typedef, XEnum, XStruct, XidUnion, Xidtype};
+use heck::CamelCase;
+use std::collections::{HashMap, HashSet};
+
+/// The types defined by a namespace, as they are named in Rust. This is used to refer to the types
+/// of the namespaces that another namespace imports.
+#[derive(Debug, Default)]
+pub struct Namespace {
+    pub name: Box<str>,
+    types: HashSet<Box<str>>,
+    /// Types that are defined in terms of another type, mapped to that type.
+    underlying: HashMap<Box<str>, Box<str>>,
+}
+
+impl Namespace {
+    /// Collect the types defined by a series of Level 1 items.
+    #[inline]
+    pub fn from_lvl1(name: &str, items: &[Lvl1Item]) -> Self {
+        let mut ns = Self {
+            name: name.into(),
+            ..Default::default()
+        };
+
+        items.iter().for_each(|item| match item {
+            Lvl1Item::Typedef(Typedef { oldname, newname }) => {
+                let newname = newname.to_camel_case().into_boxed_str();
+                ns.underlying
+                    .insert(newname.clone(), oldname.to_camel_case().into_boxed_str());
+                ns.types.insert(newname);
+            }
+            Lvl1Item::Xidtype(Xidtype { name }) | Lvl1Item::XidUnion(XidUnion { name, .. }) => {
+                let name = safe_name(name.to_camel_case()).into_boxed_str();
+                ns.underlying.insert(name.clone(), "Card32".into());
+                ns.types.insert(name);
+            }
+            Lvl1Item::Enum(XEnum { name, .. })
+            | Lvl1Item::Struct(XStruct { name, .. })
+            | Lvl1Item::Union(XStruct { name, .. }) => {
+                ns.types
+                    .insert(safe_name(name.to_camel_case()).into_boxed_str());
+            }
+            _ => (),
+        });
+
+        ns
+    }
+
+    /// Tell if this namespace defines a type.
+    #[inline]
+    pub fn defines(&self, ty: &str) -> bool {
+        self.types.contains(ty)
+    }
+
+    /// The type that this type is defined in terms of, if it is a typedef or an XID type.
+    #[inline]
+    pub fn underlying(&self, ty: &str) -> Option<&str> {
+        self.underlying.get(ty).map(|u| &**u)
+    }
+}
@@ -5,7 +5,6 @@ use std::{
     borrow::Cow,
     hash::{Hash, Hasher},
     mem,
-    ops::Deref,
     sync::atomic::{AtomicBool, Ordering},
 };
 use tinyvec::{ArrayVec, TinyVec};
@@ -15,7 +14,8 @@ pub enum StructVariant {
     Reply,
     Request,
     Error,
-    Event(bool),
+    /// An event, with whether it skips the sequence number and whether it's a generic event.
+    Event(bool, bool),
 }
 
 static IS_EXTENSION: AtomicBool = AtomicBool::new(false);
@@ -36,10 +36,10 @@ fn doesnt_need_pad_pop(field: &StructureItem) -> bool {
         _ => return false,
     };
 
-    match s {
-        "u32" | "Card32" | "CARD32" | "Drawable" | "DRAWABLE" | "Window" | "WINDOW" => true,
-        _ => false,
-    }
+    matches!(
+        s,
+        "u32" | "Card32" | "CARD32" | "Drawable" | "DRAWABLE" | "Window" | "WINDOW"
+    )
 }
 
 /// Configure a set of fields, given its struct variant.
@@ -66,10 +66,10 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
     const ONE_PAD: Option<StructureItem> = Some(StructureItem::Padding { bytes: 1 });
     let opt_field = if let StructVariant::Request
     | StructVariant::Reply
-    | StructVariant::Event(false) = variant
+    | StructVariant::Event(false, false) = variant
     {
         if fields.is_empty()
-            || matches!(fields[0], StructureItem::List(_))
+            || matches!(fields[0], StructureItem::List(_) | StructureItem::Fd { .. })
             || (is_extension && matches!(variant, StructVariant::Request))
             || doesnt_need_pad_pop(&fields[0])
         {
@@ -84,7 +84,7 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
     match variant {
         StructVariant::No => (),
         StructVariant::Reply => {
-            let mut header: ArrayVec<[StructureItem; 4]> = ArrayVec::from([
+            let header: ArrayVec<[StructureItem; 4]> = ArrayVec::from([
                 // type field, this is always X_Reply
                 StructureItem::Field(Field {
                     name: "reply_type".to_string(),
@@ -108,7 +108,7 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
             ]);
 
             let f = mem::take(fields);
-            *fields = header.into_iter().chain(f.into_iter()).collect();
+            *fields = header.into_iter().chain(f).collect();
 
             if !fields.iter().any(|f| {
                 if let StructureItem::Field(Field { name, .. }) = f {
@@ -120,7 +120,42 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
                 panic!("how");
             }
         }
-        StructVariant::Event(skip_sequence) => {
+        StructVariant::Event(_, true) => {
+            // generic events carry the extension they come from and their length in their header,
+            // and their event type comes after that
+            let header: ArrayVec<[StructureItem; 5]> = ArrayVec::from([
+                StructureItem::Field(Field {
+                    name: "event_type".to_string(),
+                    ty: Type::BasicType("u8".into()),
+                    ..Default::default()
+                }),
+                StructureItem::Field(Field {
+                    name: "extension".to_string(),
+                    ty: Type::BasicType("u8".into()),
+                    ..Default::default()
+                }),
+                StructureItem::Field(Field {
+                    name: "sequence".to_string(),
+                    ty: Type::BasicType("u16".into()),
+                    ..Default::default()
+                }),
+                // length in four-bytes, beyond the first 32 bytes
+                StructureItem::Field(Field {
+                    name: "length".to_string(),
+                    ty: Type::BasicType("u32".into()),
+                    ..Default::default()
+                }),
+                StructureItem::Field(Field {
+                    name: "evtype".to_string(),
+                    ty: Type::BasicType("u16".into()),
+                    ..Default::default()
+                }),
+            ]);
+
+            let f = mem::take(fields);
+            *fields = header.into_iter().chain(f).collect();
+        }
+        StructVariant::Event(skip_sequence, false) => {
             let mut header: ArrayVec<[StructureItem; 3]> = ArrayVec::from_array_len(
                 [
                     // type field, as always
@@ -148,9 +183,11 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
             }
 
             let f = mem::take(fields);
-            *fields = header.into_iter().chain(f.into_iter()).collect();
+            *fields = header.into_iter().chain(f).collect();
         }
         StructVariant::Error => {
+            // the type of the response, which is always 0 for errors, the error code and the
+            // sequence number of the request that caused the error
             fields.insert(
                 0,
                 StructureItem::Field(Field {
@@ -159,22 +196,6 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
                     ..Default::default()
                 }),
             );
-            fields.insert(
-                0,
-                StructureItem::Field(Field {
-                    name: "minor_code".to_string(),
-                    ty: Type::BasicType("u8".into()),
-                    ..Default::default()
-                }),
-            );
-            fields.insert(
-                0,
-                StructureItem::Field(Field {
-                    name: "major_code".to_string(),
-                    ty: Type::BasicType("u8".into()),
-                    ..Default::default()
-                }),
-            );
             fields.insert(
                 0,
                 StructureItem::Field(Field {
@@ -210,7 +231,7 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
                 }),
             ]);
             let f = mem::take(fields);
-            *fields = header.into_iter().chain(f.into_iter()).collect();
+            *fields = header.into_iter().chain(f).collect();
         }
     }
 }
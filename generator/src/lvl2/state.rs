@@ -1,19 +1,17 @@
 // MIT/Apache2 License
 
 use super::{
-    configure_fields, create_generator, safe_name, set_is_extension, EnumRepr, EnumReprGenerator,
-    Expression, Field, Item as Lvl2Item, Item, List, MaybeString, Struct, StructSpecial,
-    StructVariant, StructureItem, Type, XidType,
-};
-use crate::lvl1::{
-    Item as Lvl1Item, NonenumTypenames, StructureItem as Lvl1StructureItem, XStruct,
+    configure_fields, create_generator, format_reply_data, pad_list_elements, safe_name,
+    set_is_extension, EnumReprGenerator, Expression, Field, Item as Lvl2Item, Item, List,
+    MaybeString, Namespace, Struct, StructSpecial, StructVariant, StructureItem, Switch, Type,
+    XidType,
 };
+use crate::lvl1::{Docs, FieldDoc, Item as Lvl1Item, StructureItem as Lvl1StructureItem, XStruct};
 use heck::{CamelCase, SnakeCase};
 use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
-    iter, mem,
-    ops::Deref,
+    mem,
     rc::Rc,
 };
 use tinyvec::{tiny_vec, TinyVec};
@@ -30,6 +28,10 @@ pub struct Lvl2State {
     events: HashMap<Box<str>, Struct>,
     // output: list of XIDs
     pub xidtypes: Vec<Box<str>>,
+    // the types defined in this namespace
+    local: Namespace,
+    // the namespaces that this namespace imports, in the order they are imported
+    imports: Vec<Namespace>,
 }
 
 #[inline]
@@ -51,6 +53,8 @@ impl Lvl2State {
             errors: HashMap::<Box<str>, Struct>::new(),
             events: HashMap::new(),
             xidtypes: vec![],
+            local: Default::default(),
+            imports: vec![],
         }
     }
 
@@ -69,7 +73,7 @@ impl Lvl2State {
                 self.nonenum_typenames
                     .insert(x.name.clone().into_boxed_str());
             }
-            Lvl1Item::Struct(s) => {
+            Lvl1Item::Struct(s) | Lvl1Item::Union(s) => {
                 self.nonenum_typenames
                     .insert(s.name.clone().into_boxed_str());
             }
@@ -95,6 +99,59 @@ impl Lvl2State {
         self.nonenum_typenames.contains(tn)
     }
 
+    /// Qualify a type name with the namespace it is imported from, if it isn't defined in this
+    /// namespace and more than one of the imported namespaces defines it. In that case, the
+    /// namespace that was imported first is used.
+    #[inline]
+    fn qualify_type(&self, ty: &mut Cow<'static, str>) {
+        if ty.contains(':') || self.local.defines(ty) {
+            return;
+        }
+
+        let mut owners = self.imports.iter().filter(|ns| ns.defines(ty));
+        if let (Some(owner), Some(other)) = (owners.next(), owners.next()) {
+            log::warn!(
+                "Type \"{}\" is defined by both \"{}\" and \"{}\", using the former",
+                ty,
+                &owner.name,
+                &other.name
+            );
+            *ty = Cow::Owned(format!("{}:{}", &owner.name, ty));
+        }
+    }
+
+    /// Qualify the types of a set of fields, including those in switch cases.
+    #[inline]
+    fn qualify_fields(&self, fields: &mut [StructureItem]) {
+        fields.iter_mut().for_each(|f| match f {
+            StructureItem::Field(Field { ty, .. })
+            | StructureItem::LenSlot { ty, .. }
+            | StructureItem::List(List {
+                ty: MaybeString::NotAString(ty),
+                ..
+            }) => match ty {
+                Type::BasicType(name) | Type::Array(name, _) => self.qualify_type(name),
+            },
+            StructureItem::Switch(Switch { cases, .. }) => cases
+                .iter_mut()
+                .for_each(|c| self.qualify_fields(&mut c.items)),
+            _ => (),
+        });
+    }
+
+    /// Follow a type through the typedefs and XID types of the imported namespaces, down to the
+    /// type that it is defined in terms of.
+    #[inline]
+    fn imported_underlying(&self, mut ty: String) -> String {
+        while !self.local.defines(&ty) {
+            match self.imports.iter().find_map(|ns| ns.underlying(&ty)) {
+                Some(underlying) if underlying != ty => ty = underlying.to_string(),
+                _ => break,
+            }
+        }
+        ty
+    }
+
     /// Recognize the enums as unresolved.
     #[inline]
     pub fn load_enums(&mut self, items_source: &mut Vec<Lvl1Item>) {
@@ -116,8 +173,12 @@ impl Lvl2State {
     /// Instantly resolve all enums.
     #[inline]
     pub fn resolve_enums(&mut self) -> Vec<Lvl2Item> {
-        self.unresolved_enums
-            .drain()
+        let mut unresolved: Vec<(String, EnumReprGenerator)> =
+            self.unresolved_enums.drain().collect();
+        // sort so the output stays the same between runs
+        unresolved.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        unresolved
+            .into_iter()
             .map(|(_, ue)| Lvl2Item::Enum((ue)(Type::BasicType("i32".into()))))
             .collect()
     }
@@ -128,21 +189,28 @@ impl Lvl2State {
         &mut self,
         mut fields: TinyVec<[crate::lvl1::StructureItem; 6]>,
         variant: StructVariant,
-        fds: &mut Vec<String>,
+        field_docs: &[FieldDoc],
     ) -> (TinyVec<[StructureItem; 6]>, TinyVec<[Lvl2Item; 1]>) {
         let mut side_effect_enums = TinyVec::new();
         let mut align_indices: HashMap<usize, usize> = HashMap::new();
         let mut index: usize = 0;
+        let mut after_list = false;
 
+        // an alignment pad right after a list pads out that list, and any other one is kept as an item
         fields.retain(|i| {
-            if let Lvl1StructureItem::Padding {
-                is_align: true,
-                bytes,
-            } = i
+            if let (
+                Lvl1StructureItem::Padding {
+                    is_align: true,
+                    bytes,
+                },
+                true,
+            ) = (i, after_list)
             {
                 align_indices.insert(index - 1, *bytes);
+                after_list = false;
                 false
             } else {
+                after_list = matches!(i, Lvl1StructureItem::List(_));
                 index += 1;
                 true
             }
@@ -153,10 +221,11 @@ impl Lvl2State {
             .enumerate()
             .flat_map(|(i, f)| {
                 let mut resolution = None;
-                let res = StructureItem::from_lvl1(f, &mut resolution, fds);
+                let res = StructureItem::from_lvl1(f, &mut resolution);
 
                 if let Some((idname, ty)) = resolution {
                     if let Some(gen) = self.unresolved_enums.remove(&idname) {
+                        let ty = self.imported_underlying(ty);
                         side_effect_enums
                             .push(Item::Enum((gen)(Type::BasicType(enum_repr_conv(ty)))));
                     }
@@ -177,26 +246,37 @@ impl Lvl2State {
         // configure the fields
         configure_fields(&mut fields, variant);
 
+        // attach documentation to the fields
+        document_fields(&mut fields, field_docs);
+
         // normalize the fields
         normalize_fields(&mut fields);
+        normalize_case_lengths(&mut fields);
 
         // uniqueify the fields
         uniquify_fields(&mut fields);
 
+        // refer to imported types through their namespace where it's ambiguous
+        self.qualify_fields(&mut fields);
+
         (fields, side_effect_enums)
     }
 
     /// Convert a Lvl1 Item to an Lvl2 Item.
     #[inline]
     pub fn convert_item(&mut self, item: Lvl1Item) -> Option<TinyVec<[Item; 1]>> {
-        let mut fds: Vec<String> = vec![];
         match item {
             // imports and typedefs are directly used in lvl2
             Lvl1Item::Import(i) => Some(TinyVec::from([Item::Import(i)])),
-            // however, make sure typedef names are camel-case'd
+            // so is the version of the extension
+            Lvl1Item::Version(v) => Some(TinyVec::from([Item::Version(v)])),
+            // however, make sure typedef names are camel-case'd, and that the type they alias is
+            // qualified in the same way as the types of fields
             Lvl1Item::Typedef(crate::lvl1::Typedef { oldname, newname }) => {
+                let mut oldname = Cow::Owned(oldname.to_camel_case());
+                self.qualify_type(&mut oldname);
                 Some(TinyVec::from([Item::Typedef(crate::lvl1::Typedef {
-                    oldname: oldname.to_camel_case(),
+                    oldname: oldname.into_owned(),
                     newname: newname.to_camel_case(),
                 })]))
             }
@@ -225,13 +305,12 @@ impl Lvl2State {
             Lvl1Item::Enum(_) => unreachable!(),
             // structs translate pretty directly
             Lvl1Item::Struct(XStruct { name, fields, docs }) => {
-                let (fields, se) = self.convert_fields(fields, StructVariant::No, &mut fds);
-                let (brief, desc) = (None, None);
+                let (brief, desc, field_docs) = split_docs(docs);
+                let (fields, se) = self.convert_fields(fields, StructVariant::No, &field_docs);
                 let name = safe_name(name.to_camel_case()).into_boxed_str();
                 let mut tv = TinyVec::from([Item::Struct(Struct {
                     name,
                     brief,
-                    fds,
                     desc,
                     fields: fields.to_vec(),
                     special: StructSpecial::Regular,
@@ -239,23 +318,37 @@ impl Lvl2State {
                 tv.extend(se);
                 Some(tv)
             }
+            // unions are structs whose fields overlap
+            Lvl1Item::Union(XStruct { name, fields, docs }) => {
+                let (brief, desc, field_docs) = split_docs(docs);
+                let (fields, se) = self.convert_fields(fields, StructVariant::No, &field_docs);
+                let name = safe_name(name.to_camel_case()).into_boxed_str();
+                let mut tv = TinyVec::from([Item::Struct(Struct {
+                    name,
+                    brief,
+                    desc,
+                    fields: fields.to_vec(),
+                    special: StructSpecial::Union,
+                })]);
+                tv.extend(se);
+                Some(tv)
+            }
             // requests translate to structs
             Lvl1Item::Request(crate::lvl1::Request {
                 base: XStruct { name, fields, docs },
                 opcode,
                 reply,
             }) => {
+                let (brief, desc, field_docs) = split_docs(docs);
                 let (fields, mut se) =
-                    self.convert_fields(fields, StructVariant::Request, &mut fds);
-                let (brief, desc) = (None, None);
+                    self.convert_fields(fields, StructVariant::Request, &field_docs);
                 let name = safe_name(name.to_camel_case());
 
                 let reply = match reply {
                     Some(XStruct { name, fields, docs }) => {
-                        let mut fds2: Vec<String> = vec![];
+                        let (brief, desc, field_docs) = split_docs(docs);
                         let (fields2, se2) =
-                            self.convert_fields(fields, StructVariant::Reply, &mut fds2);
-                        let (brief, desc) = (None, None);
+                            self.convert_fields(fields, StructVariant::Reply, &field_docs);
 
                         se.extend(se2);
 
@@ -265,7 +358,6 @@ impl Lvl2State {
                             name: name.to_camel_case().into_boxed_str(),
                             brief,
                             desc,
-                            fds: fds2,
                             fields: fields2.to_vec(),
                             special: StructSpecial::Regular,
                         }))
@@ -277,7 +369,6 @@ impl Lvl2State {
                     name: name.into_boxed_str(),
                     brief,
                     desc,
-                    fds,
                     fields: fields.to_vec(),
                     special: StructSpecial::Request(opcode, reply),
                 })]);
@@ -288,10 +379,14 @@ impl Lvl2State {
                 base: XStruct { name, docs, fields },
                 opcode,
                 skip_sequence,
+                xge,
             }) => {
-                let (brief, desc) = (None, None);
-                let (fields, se) =
-                    self.convert_fields(fields, StructVariant::Event(skip_sequence), &mut fds);
+                let (brief, desc, field_docs) = split_docs(docs);
+                let (fields, se) = self.convert_fields(
+                    fields,
+                    StructVariant::Event(skip_sequence, xge),
+                    &field_docs,
+                );
                 let sname = safe_name(name.to_camel_case()).into_boxed_str();
 
                 self.events.insert(
@@ -300,9 +395,8 @@ impl Lvl2State {
                         name: sname,
                         brief,
                         desc,
-                        fds,
                         fields: fields.to_vec(),
-                        special: StructSpecial::Event(opcode, skip_sequence),
+                        special: StructSpecial::Event(opcode, skip_sequence, xge),
                     },
                 );
                 Some(se)
@@ -310,7 +404,7 @@ impl Lvl2State {
             Lvl1Item::EventCopy(crate::lvl1::EventCopy { name, opcode, base }) => {
                 if let Some(event) = self.events.get(base.as_str()) {
                     let mut event = event.clone();
-                    if let StructSpecial::Event(ref mut o, _) = event.special {
+                    if let StructSpecial::Event(ref mut o, ..) = event.special {
                         *o = opcode;
                     }
                     event.name = safe_name(name.to_camel_case()).into_boxed_str();
@@ -322,8 +416,8 @@ impl Lvl2State {
                 base: XStruct { name, fields, docs },
                 number,
             }) => {
-                let (brief, desc) = (None, None);
-                let (fields, se) = self.convert_fields(fields, StructVariant::Error, &mut fds);
+                let (brief, desc, field_docs) = split_docs(docs);
+                let (fields, se) = self.convert_fields(fields, StructVariant::Error, &field_docs);
                 let sname = safe_name(name.to_camel_case()).into_boxed_str();
                 self.errors.insert(
                     name.into_boxed_str(),
@@ -331,7 +425,6 @@ impl Lvl2State {
                         name: sname,
                         fields: fields.to_vec(),
                         brief,
-                        fds,
                         desc,
                         special: StructSpecial::Error(number),
                     },
@@ -354,15 +447,92 @@ impl Lvl2State {
     }
 }
 
+/// Drain a map of structures in the order of their names, so the output stays the same between
+/// runs.
+#[inline]
+fn sorted_drain(map: &mut HashMap<Box<str>, Struct>) -> impl Iterator<Item = Struct> {
+    let mut items: Vec<(Box<str>, Struct)> = map.drain().collect();
+    items.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    items.into_iter().map(|(_, v)| v)
+}
+
+/// Split a documentation block into its brief, its description and the documentation for its
+/// fields.
+#[inline]
+fn split_docs(docs: Option<Docs>) -> (Option<Box<str>>, Option<Box<str>>, Vec<FieldDoc>) {
+    #[inline]
+    fn non_empty(s: &str) -> Option<Box<str>> {
+        match s.trim() {
+            "" => None,
+            s => Some(s.into()),
+        }
+    }
+
+    match docs {
+        None => (None, None, vec![]),
+        Some(Docs {
+            brief,
+            descr,
+            fields,
+            ..
+        }) => (
+            non_empty(&brief),
+            descr.as_deref().and_then(non_empty),
+            fields.into_iter().collect(),
+        ),
+    }
+}
+
+/// Attach field documentation to the fields it describes.
+#[inline]
+fn document_fields(fields: &mut [StructureItem], field_docs: &[FieldDoc]) {
+    fields.iter_mut().for_each(|f| match f {
+        StructureItem::Field(Field {
+            ref name,
+            ref mut doc,
+            ..
+        })
+        | StructureItem::List(List {
+            ref name,
+            ref mut doc,
+            ..
+        }) => {
+            if let Some(FieldDoc { desc, .. }) = field_docs.iter().find(|fd| {
+                !fd.desc.trim().is_empty() && safe_name(fd.name.to_snake_case()) == *name
+            }) {
+                *doc = Some(desc.trim().to_string());
+            }
+        }
+        StructureItem::Switch(Switch { cases, .. }) => cases
+            .iter_mut()
+            .for_each(|c| document_fields(&mut c.items, field_docs)),
+        _ => (),
+    });
+}
+
+/// The longest array that `AsByteSequence` is implemented for.
+const MAX_ARRAY_LEN: i64 = 32;
+
 /// Go over each field an eliminate unnecessary ones, such as fields expressing the
 /// length of vectors.
 #[inline]
 fn normalize_fields(fields: &mut [StructureItem]) {
     for i in 0..fields.len() {
-        // if the item is a non-zero fixed-size list, replace it with an array type
+        // the items in switch cases are normalized separately
+        if let StructureItem::Switch(Switch { cases, .. }) = &mut fields[i] {
+            cases
+                .iter_mut()
+                .for_each(|c| normalize_fields(&mut c.items));
+            continue;
+        }
+
+        // if the item is a non-zero fixed-size list, replace it with an array type; lists longer than
+        // the arrays that can be serialized are left as lists of a fixed length
         if let StructureItem::List(l) = &mut fields[i] {
             if let Some(array_length) = l.list_length.fixed_size() {
-                if array_length != 0 {
+                if array_length > MAX_ARRAY_LEN {
+                    continue;
+                } else if array_length != 0 {
                     let list = if let StructureItem::List(list) = mem::take(&mut fields[i]) {
                         list
                     } else {
@@ -410,12 +580,20 @@ fn normalize_fields(fields: &mut [StructureItem]) {
                 // if this is a single-item list length, axe that single item and
                 // just use Vec::len() to calculate length
                 fields.iter_mut().any(move |f| {
-                    if let StructureItem::Field(Field { name, ty, .. }) = f {
+                    if let StructureItem::Field(Field {
+                        name,
+                        ty,
+                        min_version,
+                        ..
+                    }) = f
+                    {
                         if name.as_str() == item {
                             let ty = mem::take(ty);
+                            let min_version = min_version.take();
                             *f = StructureItem::LenSlot {
                                 ty,
                                 owning_list: lname.to_snake_case(),
+                                min_version,
                             };
 
                             true
@@ -431,46 +609,167 @@ fn normalize_fields(fields: &mut [StructureItem]) {
     }
 }
 
-/// Iterate over a set of fields and ensure all of the names are unique (for bitcases).
+/// Collect the expressions and the list names in a set of fields, including those in switch cases.
 #[inline]
-pub fn uniquify_fields(fields: &mut [StructureItem]) {
-    for i in 0..fields.len() {
-        let fname = match fields[i] {
-            StructureItem::Field(Field { ref name, .. })
-            | StructureItem::List(List { ref name, .. }) => name.clone(),
-            _ => continue,
-        };
-
-        fields.iter_mut().fold(0, |mut field_index, field| {
-            match field {
-                StructureItem::Field(Field { ref mut name, .. })
-                | StructureItem::List(List { ref mut name, .. }) => {
-                    if name.as_str() == fname.as_str() {
-                        *name = fname
-                            .chars()
-                            .chain(iter::repeat('_').take(field_index))
-                            .collect::<String>();
-                        field_index += 1;
-                    }
-                }
-                _ => (),
+fn collect_expressions<'a>(
+    fields: &'a [StructureItem],
+    expressions: &mut Vec<&'a Expression>,
+    list_names: &mut Vec<&'a str>,
+) {
+    fields.iter().for_each(|f| match f {
+        StructureItem::List(List {
+            name, list_length, ..
+        }) => {
+            expressions.push(list_length);
+            list_names.push(name);
+        }
+        StructureItem::Switch(Switch { expr, cases, .. }) => {
+            expressions.push(expr);
+            cases
+                .iter()
+                .for_each(|c| collect_expressions(&c.items, expressions, list_names));
+        }
+        _ => (),
+    });
+}
+
+/// Collect the lists in switch cases whose lengths are a single field, along with that field.
+#[inline]
+fn case_list_lengths(
+    fields: &[StructureItem],
+    in_switch: bool,
+    lengths: &mut Vec<(String, String)>,
+) {
+    fields.iter().for_each(|f| match f {
+        StructureItem::List(List {
+            name, list_length, ..
+        }) if in_switch => {
+            if let Some(item) = list_length.single_item() {
+                lengths.push((item.to_owned(), name.clone()));
             }
+        }
+        StructureItem::Switch(Switch { cases, .. }) => cases
+            .iter()
+            .for_each(|c| case_list_lengths(&c.items, true, lengths)),
+        _ => (),
+    });
+}
 
-            field_index
-        });
+/// Lists in switch cases are often sized by a field outside of the switch. If nothing else depends
+/// on that field, replace it with a length slot, the same way `normalize_fields` does for lists
+/// outside of switches.
+#[inline]
+fn normalize_case_lengths(fields: &mut [StructureItem]) {
+    let mut lengths = vec![];
+    case_list_lengths(fields, false, &mut lengths);
+
+    let lengths: Vec<(String, String)> = {
+        let mut expressions = vec![];
+        let mut list_names = vec![];
+        collect_expressions(fields, &mut expressions, &mut list_names);
+
+        lengths
+            .into_iter()
+            .filter(|(item, lname)| {
+                // the owning list's name has to be unique, since the names in switch cases are not
+                // uniquified yet
+                item != "length"
+                    && expressions
+                        .iter()
+                        .filter(|e| e.involves_field(item))
+                        .count()
+                        == 1
+                    && list_names.iter().filter(|n| **n == lname).count() == 1
+            })
+            .collect()
+    };
+
+    lengths.into_iter().for_each(|(item, lname)| {
+        if let Some(f) = fields.iter_mut().find(
+            |f| matches!(f, StructureItem::Field(Field { name, .. }) if name.as_str() == item),
+        ) {
+            let (ty, min_version) = match f {
+                StructureItem::Field(Field {
+                    ty, min_version, ..
+                }) => (mem::take(ty), min_version.take()),
+                _ => unreachable!(),
+            };
+            *f = StructureItem::LenSlot {
+                ty,
+                owning_list: lname,
+                min_version,
+            };
+        }
+    });
+}
+
+/// Iterate over a set of fields and ensure all of the names are unique (for bitcases). References to
+/// a renamed field from within its switch case are renamed along with it.
+#[inline]
+pub fn uniquify_fields(fields: &mut [StructureItem]) {
+    uniquify_scope(fields, &mut HashMap::new(), HashMap::new());
+}
+
+#[inline]
+fn uniquify_scope(
+    fields: &mut [StructureItem],
+    counts: &mut HashMap<String, usize>,
+    mut renames: HashMap<String, String>,
+) {
+    #[inline]
+    fn uniquify_name(
+        name: &mut String,
+        counts: &mut HashMap<String, usize>,
+        renames: &mut HashMap<String, String>,
+    ) {
+        let count = counts.entry(name.clone()).or_insert(0);
+        if *count > 0 {
+            let new_name = name
+                .chars()
+                .chain(std::iter::repeat_n('_', *count))
+                .collect::<String>();
+            renames.insert(mem::replace(name, new_name.clone()), new_name);
+        }
+        *count += 1;
+    }
+
+    for f in fields.iter_mut() {
+        match f {
+            StructureItem::Field(Field { name, .. }) | StructureItem::Fd { name } => {
+                uniquify_name(name, counts, &mut renames);
+            }
+            StructureItem::List(List {
+                name, list_length, ..
+            }) => {
+                list_length.rename_fields(&renames);
+                uniquify_name(name, counts, &mut renames);
+            }
+            StructureItem::Switch(Switch { expr, cases, .. }) => {
+                Rc::make_mut(expr).rename_fields(&renames);
+                cases.iter_mut().for_each(|c| {
+                    Rc::make_mut(&mut Rc::make_mut(&mut c.condition).expr).rename_fields(&renames);
+                    uniquify_scope(&mut c.items, counts, renames.clone());
+                });
+            }
+            _ => (),
+        }
     }
 }
 
-/// Convert a series of Level 2 items to Level 1 items.
+/// Convert a series of Level 1 items to Level 2 items. Types that aren't defined in the series are
+/// looked up in the namespaces that it imports.
 #[inline]
 pub fn convert_series(
     mut series: Vec<Lvl1Item>,
+    imports: Vec<Namespace>,
     is_extension: bool,
 ) -> (Vec<Lvl2Item>, Vec<Box<str>>) {
     set_is_extension(is_extension);
 
     // first, glob all of the enums
     let mut state = Lvl2State::new();
+    state.local = Namespace::from_lvl1("", &series);
+    state.imports = imports;
     state.register_typenames(&series);
     state.load_enums(&mut series);
 
@@ -483,8 +782,10 @@ pub fn convert_series(
 
     // now, take all of the aux. stuff
     res.extend(state.resolve_enums());
-    res.extend(state.errors.drain().map(|(_k, v)| Item::Struct(v)));
-    res.extend(state.events.drain().map(|(_k, v)| Item::Struct(v)));
+    res.extend(sorted_drain(&mut state.errors).map(Item::Struct));
+    res.extend(sorted_drain(&mut state.events).map(Item::Struct));
+    pad_list_elements(&mut res);
+    format_reply_data(&mut res);
 
     (res, state.xidtypes)
 }
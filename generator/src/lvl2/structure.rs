@@ -9,7 +9,6 @@ pub struct Struct {
     pub brief: Option<Box<str>>,
     pub desc: Option<Box<str>>,
     pub fields: Vec<StructureItem>,
-    pub fds: Vec<String>,
     pub special: StructSpecial,
 }
 
@@ -18,8 +17,11 @@ pub struct Struct {
 pub enum StructSpecial {
     /// Just a regular struct
     Regular,
-    /// This is an event with an associated opcode.
-    Event(u64, bool),
+    /// A union, where every field starts at the same byte.
+    Union,
+    /// This is an event with an associated opcode, whether it skips the sequence number, and
+    /// whether it's sent as a generic event.
+    Event(u64, bool, bool),
     /// This is an error with an associated number.
     Error(u64),
     /// This is a request with an associated opcode and reply.
@@ -1,24 +1,27 @@
 // MIT/Apache2 License
 
 use super::{
-    syn_util::{str_to_path, str_to_ty},
-    InputParameter, Method, ParameterUsage, Statement, SumOfSizes, SumStatement, ToSyn, Type,
+    syn_util::{int_litexpr_int, str_to_exprpath, str_to_path, str_to_ty},
+    EmitContext, InputParameter, Method, ParameterUsage, SumOfSizes, SumStatement, Trait, Type,
 };
+use std::iter;
 
 /// An implementation of the as-byte-sequence trait.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Asb {
     pub is_none: bool,
     /// Collection of statements defining the as_bytes function to create a vector of bytes representing
     /// this item.
     pub as_bytes_stmts: Vec<SumStatement>,
     /// Collection of statements defining the from_bytes function to create an instance of this object
-    /// from a series of bytes.
+    /// from a series of bytes. These are bounds-checked, and return a `Result`.
     pub from_bytes_stmts: Vec<SumStatement>,
     /// Sum of sizes for the size.
     pub size: SumOfSizes,
-    /// Name of the field used for FD getting.
-    pub fd_getting: Option<String>,
+    /// Names of the file descriptor fields, in the order they are sent.
+    pub fds: Vec<Box<str>>,
+    /// The alignment that the byte sequence is padded out to, if any.
+    pub align: Option<usize>,
 }
 
 impl Asb {
@@ -31,13 +34,14 @@ impl Asb {
     }
 
     #[inline]
-    pub fn to_syn_item(self, tyname: &str) -> Vec<syn::Item> {
+    pub fn to_syn_item(self, tyname: &str, ctx: &EmitContext) -> Vec<syn::Item> {
         let Self {
             is_none,
             as_bytes_stmts,
             from_bytes_stmts,
-            fd_getting,
+            fds,
             size,
+            align,
         } = self;
 
         if is_none {
@@ -63,7 +67,7 @@ impl Asb {
                 ty: Type::Slice(Box::new(Type::Basic("u8".into()))),
                 usage: ParameterUsage::Ref,
             }],
-            Some(Type::Opt(Box::new(Type::Tuple(vec![
+            Some(Type::ParseRes(Box::new(Type::Tuple(vec![
                 Type::Basic("Self".into()),
                 Type::Basic("usize".into()),
             ])))),
@@ -75,25 +79,78 @@ impl Asb {
             vec![],
             Some(Type::Basic("usize".into())),
         );
-        size_method.statements = vec![size.into()];
-        let mut file_descriptors_method = match fd_getting {
-            None => None,
-            Some(ref fd_getting) => Some({
-                let mut fdm = Method::new(
-                    "file_descriptors".into(),
+        // the lists of plain data don't need to be visited to find out how big the item is
+        let size_hint_method = size.hint().map(|hint| {
+            let mut size_hint_method = Method::new(
+                "size_hint".into(),
+                Some(ParameterUsage::Ref),
+                vec![],
+                Some(Type::Basic("usize".into())),
+            );
+            size_hint_method.statements = match align {
+                Some(align) => vec![super::AlignedSumOfSizes(hint, align).into()],
+                None => vec![hint.into()],
+            };
+            size_hint_method.to_syn_impl_item(true, ctx)
+        });
+        size_method.statements = match align {
+            Some(align) => vec![super::AlignedSumOfSizes(size, align).into()],
+            None => vec![size.into()],
+        };
+        let fd_methods = match fds.is_empty() {
+            true => vec![],
+            false => {
+                let mut as_fds_method = Method::new(
+                    "as_fds".into(),
+                    Some(ParameterUsage::Ref),
+                    vec![InputParameter {
+                        name: "fds".into(),
+                        ty: Type::Vector(Box::new(Type::Basic("Fd".into()))),
+                        usage: ParameterUsage::MutRef,
+                    }],
+                    None,
+                );
+                as_fds_method.statements = fds
+                    .iter()
+                    .map(|name| super::AppendFd(name.clone()).into())
+                    .collect();
+                let mut take_fds_method = Method::new(
+                    "take_fds".into(),
                     Some(ParameterUsage::MutRef),
-                    vec![],
-                    Some(Type::Opt(Box::new(Type::Ref(
-                        Box::new(Type::Vector(Box::new(Type::Basic("Fd".into())))),
-                        true,
-                        None,
-                    )))),
+                    vec![InputParameter {
+                        name: "fds".into(),
+                        ty: Type::Slice(Box::new(Type::Basic("Fd".into()))),
+                        usage: ParameterUsage::Ref,
+                    }],
+                    Some(Type::Opt(Box::new(Type::Basic("usize".into())))),
                 );
-                fdm.statements = vec![super::GetFdRef(fd_getting.clone()).into()];
-                fdm.to_syn_impl_item(true)
-            }),
+                take_fds_method.statements = fds
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| {
+                        super::LoadFd {
+                            name: name.clone(),
+                            index,
+                        }
+                        .into()
+                    })
+                    .chain(iter::once(
+                        super::ExprWrapper(syn::Expr::Call(syn::ExprCall {
+                            attrs: vec![],
+                            func: Box::new(str_to_exprpath("Some")),
+                            paren_token: Default::default(),
+                            args: iter::once(int_litexpr_int(fds.len())).collect(),
+                        }))
+                        .into(),
+                    ))
+                    .collect();
+                vec![
+                    as_fds_method.to_syn_impl_item(true, ctx),
+                    take_fds_method.to_syn_impl_item(true, ctx),
+                ]
+            }
         };
-        vec![syn::Item::Impl(syn::ItemImpl {
+        iter::once(syn::Item::Impl(syn::ItemImpl {
             attrs: vec![],
             defaultness: None,
             unsafety: None,
@@ -104,13 +161,17 @@ impl Asb {
             brace_token: Default::default(),
             items: {
                 let mut v = vec![
-                    as_bytes_method.to_syn_impl_item(true),
-                    from_bytes_method.to_syn_impl_item(true),
-                    size_method.to_syn_impl_item(true),
+                    as_bytes_method.to_syn_impl_item(true, ctx),
+                    from_bytes_method.to_syn_impl_item(true, ctx),
+                    size_method.to_syn_impl_item(true, ctx),
                 ];
-                v.extend(file_descriptors_method.into_iter());
+                v.extend(size_hint_method);
+                v.extend(fd_methods);
                 v
             },
-        })]
+        }))
+        // parsing from a slice of bytes is also available through the standard conversion traits
+        .chain(Trait::TryFromBytes.to_syn_item(tyname, ctx))
+        .collect()
     }
 }
@@ -2,9 +2,9 @@
 
 use super::{
     syn_util::{item_field, pub_vis, str_to_path, str_to_ty},
-    Asb, InputParameter, Method, ParameterUsage, RStruct, SizeSumPart, SumOfSizes, Trait, Type,
+    InputParameter, Method, ParameterUsage, RStruct, SizeSumPart, SumOfSizes, Trait, Type,
 };
-use crate::lvl2::{Bitflags, Field, StructureItem, Type as Lvl2Type};
+use crate::lvl2::{Bitflags, Field, StructureItem};
 use heck::ShoutySnakeCase;
 use proc_macro2::Span;
 use std::{iter, rc::Rc};
@@ -19,6 +19,7 @@ pub fn bitflags_to_lvl3(bitflags: Bitflags) -> Vec<RStruct> {
 
     let mut rstruct = RStruct {
         name: name.clone().into_boxed_str(),
+        docs: None,
         derives: vec![
             "Copy",
             "Clone",
@@ -28,9 +29,11 @@ pub fn bitflags_to_lvl3(bitflags: Bitflags) -> Vec<RStruct> {
             "Eq",
             "PartialOrd",
             "Ord",
+            "Hash",
         ],
-        fds: vec![],
         is_transparent: true,
+        is_repr_c: false,
+        defaults: vec![],
         fields: vec![StructureItem::Field(Field {
             name: "inner".to_string(),
             ty: underlying.clone(),
@@ -45,6 +48,7 @@ pub fn bitflags_to_lvl3(bitflags: Bitflags) -> Vec<RStruct> {
             Trait::BitflagsXor(name.clone().into_boxed_str()),
         ],
         asb: Default::default(),
+        shared_asb: None,
     };
 
     // iterate over the bits
@@ -92,7 +96,7 @@ pub fn bitflags_to_lvl3(bitflags: Bitflags) -> Vec<RStruct> {
         // const
         rstruct.other_impl_items.push(bitflags_const_field(
             &bitname.to_shouty_snake_case(),
-            1u64 << (*bitval as u64),
+            1u64 << *bitval,
         ));
     });
 
@@ -101,13 +105,10 @@ pub fn bitflags_to_lvl3(bitflags: Bitflags) -> Vec<RStruct> {
         "new".into(),
         None,
         bits.iter()
-            .map(|(bitname, bitval)| {
-                InputParameter {
-                    name: bitname.to_string().into(),
-                    ty: Type::Basic("bool".into()),
-                    usage: ParameterUsage::Owned,
-                }
-                .into()
+            .map(|(bitname, _bitval)| InputParameter {
+                name: bitname.to_string().into(),
+                ty: Type::Basic("bool".into()),
+                usage: ParameterUsage::Owned,
             })
             .collect(),
         Some(Type::Basic("Self".into())),
@@ -160,8 +161,7 @@ pub fn bitflags_to_lvl3(bitflags: Bitflags) -> Vec<RStruct> {
     rstruct
         .other_impl_items
         .push(bitflags_const_field("COMPLETE", {
-            bits.iter()
-                .fold(0u64, |d, (_, bitval)| d | (1 << *bitval as u64))
+            bits.iter().fold(0u64, |d, (_, bitval)| d | (1 << *bitval))
         }));
 
     // asb should forward to inner
@@ -0,0 +1,508 @@
+// MIT/Apache2 License
+
+use super::{
+    syn_util::{default_call, doc_attrs, item_field, str_to_exprpath},
+    Asb, InputParameter, Item, Method, ParameterUsage, RStruct, Trait, Type,
+};
+use crate::lvl2::{
+    ConditionVariant, Field, List, MaybeString, StructureItem, Switch, Type as Lvl2Type,
+    UseCondition,
+};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+use std::{error::Error, fmt, rc::Rc};
+
+/// The values that the protocol allows a numeric field to hold.
+enum Bound {
+    /// One of the listed values.
+    OneOf(&'static [u32]),
+    /// A value between the two values, inclusive.
+    Between(u32, u32),
+}
+
+/// The fields of requests whose values are limited by the protocol, along with the values they can
+/// hold, keyed by the name of the extension that the request belongs to (`None` for the core
+/// protocol), the request and the field. The protocol descriptions don't describe these limits, so
+/// they are kept here by hand, and requests that are added to the descriptions later aren't limited
+/// until they're listed. Entries that don't name a field of a request fail the generator, through
+/// `check_bounded_fields`. Fields whose values come from an enum are already limited by their
+/// types, so they aren't listed here.
+const BOUNDED_FIELDS: &[(Option<&str>, &str, &str, Bound)] = &[
+    (
+        None,
+        "ChangePropertyRequest",
+        "format",
+        Bound::OneOf(&[8, 16, 32]),
+    ),
+    (None, "CreatePixmapRequest", "depth", Bound::Between(1, 32)),
+    (None, "PutImageRequest", "depth", Bound::Between(1, 32)),
+    (
+        Some("RANDR"),
+        "ChangeOutputPropertyRequest",
+        "format",
+        Bound::OneOf(&[8, 16, 32]),
+    ),
+    (
+        Some("RANDR"),
+        "ChangeProviderPropertyRequest",
+        "format",
+        Bound::OneOf(&[8, 16, 32]),
+    ),
+    (
+        Some("MIT-SHM"),
+        "CreatePixmapRequest",
+        "depth",
+        Bound::Between(1, 32),
+    ),
+    (
+        Some("MIT-SHM"),
+        "PutImageRequest",
+        "depth",
+        Bound::Between(1, 32),
+    ),
+];
+
+/// An entry of `BOUNDED_FIELDS` that doesn't name a field of a request.
+#[derive(Debug)]
+pub struct UnresolvedBound {
+    /// The request that the entry names.
+    pub request: &'static str,
+    /// The field that the entry names.
+    pub field: &'static str,
+}
+
+/// Every entry of `BOUNDED_FIELDS` that doesn't name a field of a request in a namespace.
+pub struct BoundErrors(pub Vec<UnresolvedBound>);
+
+impl fmt::Display for BoundErrors {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Bounded fields don't name fields of requests:")?;
+        self.0.iter().try_for_each(|b| {
+            writeln!(
+                f,
+                "  \"{}\" is not a field that a builder of \"{}\" can set",
+                b.field, b.request
+            )
+        })
+    }
+}
+
+// `main` prints its error with `Debug`, so this shows the whole list
+impl fmt::Debug for BoundErrors {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for BoundErrors {}
+
+/// Check that every bounded field of the given extension (`None` for the core protocol) names a
+/// field of a request in a series of items, which a builder sets without wrapping it. Otherwise a
+/// request or field that was renamed in the descriptions would silently lose its bound.
+#[inline]
+pub fn check_bounded_fields(items: &[Item], ext_name: Option<&str>) -> Result<(), BoundErrors> {
+    let unresolved: Vec<UnresolvedBound> = BOUNDED_FIELDS
+        .iter()
+        .filter(|(ext, ..)| *ext == ext_name)
+        .filter(|(_, request, field, _)| {
+            !items.iter().any(|item| match item {
+                Item::RStruct(rs)
+                    if &*rs.name == *request
+                        && rs.traits.iter().any(|t| matches!(t, Trait::Request(..))) =>
+                {
+                    let mut fields = vec![];
+                    settable_fields(&rs.fields, false, None, &mut fields);
+                    fields.iter().any(|f| &*f.name == *field && !f.wrap)
+                }
+                _ => false,
+            })
+        })
+        .map(|(_, request, field, _)| UnresolvedBound { request, field })
+        .collect();
+
+    match unresolved.is_empty() {
+        true => Ok(()),
+        false => Err(BoundErrors(unresolved)),
+    }
+}
+
+/// A field that can be set through a builder.
+struct Settable {
+    name: Box<str>,
+    /// The type taken by the setter.
+    ty: Type,
+    /// Whether the field is stored in an `Option`.
+    wrap: bool,
+    /// The field and condition of the switch case this field is in, if any.
+    condition: Option<(Box<str>, Rc<UseCondition>)>,
+}
+
+/// Collect the fields that can be set through a builder. The header of the request is set when it
+/// is sent, so it is skipped.
+#[inline]
+fn settable_fields(
+    items: &[StructureItem],
+    in_switch: bool,
+    condition: Option<&(Box<str>, Rc<UseCondition>)>,
+    fields: &mut Vec<Settable>,
+) {
+    items.iter().for_each(|f| match f {
+        StructureItem::Field(Field { name, ty, .. }) => {
+            if !in_switch && (name == "req_type" || name == "length") {
+                return;
+            }
+
+            fields.push(Settable {
+                name: name.clone().into_boxed_str(),
+                ty: Type::from_lvl2(ty.clone()),
+                wrap: in_switch,
+                condition: condition.cloned(),
+            });
+        }
+        StructureItem::List(List { name, ty, .. }) => fields.push(Settable {
+            name: name.clone().into_boxed_str(),
+            ty: match ty {
+                MaybeString::IsAString => Type::Basic("String".into()),
+                MaybeString::NotAString(ty) => Type::Vector(Box::new(Type::from_lvl2(ty.clone()))),
+            },
+            wrap: false,
+            condition: condition.cloned(),
+        }),
+        StructureItem::Switch(Switch { expr, cases, .. }) => cases.iter().for_each(|c| {
+            // only meet the case's condition if it depends on a field that is always present
+            let condition = match (in_switch, expr.single_item()) {
+                (false, Some(field)) => Some((field.into(), c.condition.clone())),
+                _ => None,
+            };
+            settable_fields(&c.items, true, condition.as_ref(), fields)
+        }),
+        StructureItem::Fd { name } => fields.push(Settable {
+            name: name.clone().into_boxed_str(),
+            ty: Type::Basic("Fd".into()),
+            wrap: false,
+            condition: None,
+        }),
+        _ => (),
+    });
+}
+
+/// `Extend` and `FromIterator` for a request builder, which push the items onto the request's list.
+#[inline]
+pub fn extend_list_impls(bname: &str, list: &str, elem: Type) -> Vec<syn::Item> {
+    let bname = Ident::new(bname, Span::call_site());
+    let list = Ident::new(list, Span::call_site());
+    let elem = elem.to_syn_ty();
+
+    vec![
+        syn::parse_quote! {
+            impl core::iter::Extend<#elem> for #bname {
+                #[inline]
+                fn extend<I: IntoIterator<Item = #elem>>(&mut self, iter: I) {
+                    self.inner.#list.extend(iter);
+                }
+            }
+        },
+        syn::parse_quote! {
+            impl core::iter::FromIterator<#elem> for #bname {
+                #[inline]
+                fn from_iter<I: IntoIterator<Item = #elem>>(iter: I) -> Self {
+                    let mut builder = Self::default();
+                    builder.extend(iter);
+                    builder
+                }
+            }
+        },
+    ]
+}
+
+/// `pub fn set_[name](&mut self, value)` and `pub fn clear_[name](&mut self)`, which set or clear a
+/// field in a bitcase along with the bit of the mask that says it is present, so that a request can
+/// be changed one value at a time.
+#[inline]
+fn masked_setters(
+    name: &str,
+    ty: &Type,
+    mask: &str,
+    condition: &UseCondition,
+) -> Vec<syn::ImplItem> {
+    let set_docs = doc_attrs(&format!(
+        "Set `{}`, and set the bit of `{}` that says it is present.",
+        name, mask
+    ));
+    let clear_docs = doc_attrs(&format!(
+        "Leave out `{}`, and clear the bit of `{}` that says it is present.",
+        name, mask
+    ));
+    let set = Ident::new(&format!("set_{}", name), Span::call_site());
+    let clear = Ident::new(&format!("clear_{}", name), Span::call_site());
+    let field = Ident::new(name, Span::call_site());
+    let ty = ty.to_syn_ty();
+    let mask = item_field(str_to_exprpath("self"), mask);
+    let set_bit = condition.to_set_bit_expr(mask.clone(), str_to_exprpath("true"));
+    let clear_bit = condition.to_set_bit_expr(mask, str_to_exprpath("false"));
+
+    vec![
+        syn::parse_quote! {
+            #(#set_docs)*
+            #[inline]
+            pub fn #set(&mut self, #field: #ty) -> &mut Self {
+                #set_bit;
+                self.#field = Some(#field);
+                self
+            }
+        },
+        syn::parse_quote! {
+            #(#clear_docs)*
+            #[inline]
+            pub fn #clear(&mut self) -> &mut Self {
+                #clear_bit;
+                self.#field = None;
+                self
+            }
+        },
+    ]
+}
+
+/// `pub fn [name](self, value) -> Result<Self, BuildError>`, a builder setter for a field whose
+/// values are limited by the protocol, which refuses the values that the field can't hold.
+#[inline]
+fn bounded_setter(name: &str, ty: &Type, bound: &Bound) -> syn::ImplItem {
+    let (allowed, pattern): (String, TokenStream) = match bound {
+        Bound::OneOf(values) => {
+            let (last, rest) = values.split_last().expect("Bound without values");
+            let rest: Vec<String> = rest.iter().map(ToString::to_string).collect();
+            let values = values.iter().map(|v| Literal::u32_unsuffixed(*v));
+            (
+                format!("{} or {}", rest.join(", "), last),
+                quote! { #(#values)|* },
+            )
+        }
+        Bound::Between(low, high) => {
+            let (low_lit, high_lit) = (
+                Literal::u32_unsuffixed(*low),
+                Literal::u32_unsuffixed(*high),
+            );
+            (
+                format!("between {} and {}", low, high),
+                quote! { #low_lit..=#high_lit },
+            )
+        }
+    };
+    let docs = doc_attrs(&format!(
+        "Set `{}`, which must be {}. Other values are refused, rather than sent to the server.",
+        name, allowed
+    ));
+    let field = Ident::new(name, Span::call_site());
+    let ty = ty.to_syn_ty();
+
+    syn::parse_quote! {
+        #(#docs)*
+        #[inline]
+        pub fn #field(mut self, #field: #ty) -> Result<Self, BuildError> {
+            match #field {
+                #pattern => {
+                    self.inner.#field = #field;
+                    Ok(self)
+                }
+                _ => Err(BuildError {
+                    field: #name,
+                    value: #field.into(),
+                }),
+            }
+        }
+    }
+}
+
+impl RStruct {
+    /// The only list of this request, and the type of its items, if the request is mostly made of
+    /// it. Its length has to be written from the list itself, since a length described by other
+    /// fields wouldn't follow the list as it grows.
+    #[inline]
+    fn only_list(&self) -> Option<(&str, &Lvl2Type)> {
+        let mut lists = self.fields.iter().filter_map(|item| match item {
+            StructureItem::List(list) => Some(list),
+            _ => None,
+        });
+        let list = lists.next()?;
+        if lists.next().is_some() {
+            return None;
+        }
+
+        let owned = self.fields.iter().any(|item| {
+            matches!(item, StructureItem::LenSlot { owning_list, .. } if *owning_list == list.name)
+        });
+        match &list.ty {
+            MaybeString::NotAString(ty) if owned || list.list_length.is_remainder() => {
+                Some((&list.name, ty))
+            }
+            _ => None,
+        }
+    }
+
+    /// Create a builder for this request, and add a `builder` method to this request that creates
+    /// it. The builder starts from the request's default value. Requests that don't implement
+    /// `Default` are still given a builder, along with a `Default` implementation for it that starts
+    /// from the same values. Fields in bitcases can also be set on the request itself, through
+    /// `set_[name]` and `clear_[name]` methods.
+    #[inline]
+    pub fn request_builder(&mut self) -> (RStruct, Option<syn::Item>) {
+        let bname = format!("{}Builder", &self.name).into_boxed_str();
+
+        let mut fields = vec![];
+        settable_fields(&self.fields, false, None, &mut fields);
+        let debug_by_hand = self
+            .traits
+            .iter()
+            .any(|t| matches!(t, Trait::ElidedDebug(..)));
+
+        let setters = fields.iter().flat_map(|field| match field {
+            Settable {
+                name,
+                ty,
+                wrap: true,
+                condition: Some((mask, condition)),
+            } if matches!(condition.variant, ConditionVariant::BitflagVariant) => {
+                masked_setters(name, ty, mask, condition)
+            }
+            _ => vec![],
+        });
+        self.other_impl_items.extend(setters);
+
+        // fields whose values are limited are set through setters that can refuse them
+        let extension = self.traits.iter().find_map(|t| match t {
+            Trait::Request(_, _, ext_name, ..) => Some(ext_name.as_deref()),
+            _ => None,
+        });
+        let mut bounded_setters = vec![];
+        fields.retain(|field| {
+            let bound = BOUNDED_FIELDS.iter().find(|(ext, rs, name, _)| {
+                Some(*ext) == extension && *rs == &*self.name && *name == &*field.name
+            });
+            match (bound, field.wrap) {
+                (Some((_, _, _, bound)), false) => {
+                    bounded_setters.push(bounded_setter(&field.name, &field.ty, bound));
+                    false
+                }
+                _ => true,
+            }
+        });
+
+        let mut methods: Vec<Method> = fields
+            .into_iter()
+            .map(
+                |Settable {
+                     name,
+                     ty,
+                     wrap,
+                     condition,
+                 }| {
+                    let mut setter = Method::new(
+                        name.to_string().into(),
+                        Some(ParameterUsage::MutOwned),
+                        vec![InputParameter {
+                            name: name.to_string().into(),
+                            ty,
+                            usage: ParameterUsage::Owned,
+                        }],
+                        Some(Type::Basic("Self".into())),
+                    );
+                    setter.statements = vec![
+                        super::SetInnerField {
+                            name,
+                            wrap,
+                            condition,
+                        }
+                        .into(),
+                        super::JustReturnSelf.into(),
+                    ];
+                    setter
+                },
+            )
+            .collect();
+
+        let mut build = Method::new(
+            "build".into(),
+            Some(ParameterUsage::Owned),
+            vec![],
+            Some(Type::Basic(self.name.to_string().into())),
+        );
+        build.must_use = Some(super::REQUEST_MUST_USE);
+        build.statements =
+            vec![super::ExprWrapper(item_field(str_to_exprpath("self"), "inner")).into()];
+        methods.push(build);
+
+        let mut builder = Method::new(
+            "builder".into(),
+            None,
+            vec![],
+            Some(Type::Basic(bname.to_string().into())),
+        );
+        builder.statements = vec![super::ExprWrapper(default_call()).into()];
+        self.methods.push(builder);
+
+        let has_default = self.derives.contains(&"Default");
+        let default_impl = match has_default {
+            true => None,
+            false => {
+                let name = Ident::new(&self.name, Span::call_site());
+                let builder = Ident::new(&bname, Span::call_site());
+                let fields = self.default_field_values();
+                Some(syn::parse_quote! {
+                    impl Default for #builder {
+                        #[inline]
+                        fn default() -> Self {
+                            Self {
+                                inner: #name {
+                                    #(#fields),*
+                                },
+                            }
+                        }
+                    }
+                })
+            }
+        };
+
+        let builder = RStruct {
+            docs: Some(format!("A builder for `{}`.", &self.name).into_boxed_str()),
+            name: bname,
+            // the builder can be cloned or debugged if and only if the request can
+            derives: ["Clone", "Debug", "Default"]
+                .iter()
+                .copied()
+                .filter(|d| match *d {
+                    "Default" => has_default,
+                    "Debug" if debug_by_hand => true,
+                    d => self.derives.contains(&d),
+                })
+                .collect(),
+            is_transparent: false,
+            is_repr_c: false,
+            defaults: vec![],
+            fields: vec![StructureItem::Field(Field {
+                name: "inner".to_string(),
+                ty: Lvl2Type::BasicType(self.name.to_string().into()),
+                ..Default::default()
+            })],
+            methods,
+            other_impl_items: bounded_setters,
+            traits: self
+                .only_list()
+                .map(|(list, ty)| Trait::ExtendList(list.into(), Type::from_lvl2(ty.clone())))
+                .into_iter()
+                .collect(),
+            asb: Asb::none(),
+            shared_asb: None,
+        };
+        (builder, default_impl)
+    }
+}
+
+#[test]
+fn refuse_bounds_without_requests() {
+    let errors = check_bounded_fields(&[], None).unwrap_err();
+    assert_eq!(errors.0.len(), 3);
+    assert!(errors.to_string().contains("\"ChangePropertyRequest\""));
+    assert!(check_bounded_fields(&[], Some("XFIXES")).is_ok());
+}
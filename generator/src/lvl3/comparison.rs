@@ -0,0 +1,157 @@
+// MIT/Apache2 License
+
+use super::Item;
+use crate::lvl2::{Field, List, MaybeString, StructureItem, Switch, Type as Lvl2Type};
+use std::collections::HashMap;
+
+/// How values of a type can be compared.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Comparison {
+    /// The type can't be compared at all.
+    None,
+    /// The type implements `PartialEq`, but not `Eq`.
+    Partial,
+    /// The type implements `PartialEq` and `Eq`, as well as `Hash`.
+    Total,
+}
+
+/// Get the comparison for a type that is not defined in this file.
+#[inline]
+fn builtin_comparison(name: &str) -> Comparison {
+    match name {
+        "f32" | "f64" | "Float" | "Double" => Comparison::Partial,
+        // hand-written types that do not implement PartialEq
+        "Action" | "Behavior" => Comparison::None,
+        _ => Comparison::Total,
+    }
+}
+
+#[inline]
+fn type_name(ty: &Lvl2Type) -> &str {
+    let name = match ty {
+        Lvl2Type::BasicType(name) => name,
+        Lvl2Type::Array(name, _) => name,
+    };
+
+    // types imported from other modules are named by their module
+    match name.rfind(':') {
+        Some(posn) => &name[posn + 1..],
+        None => name,
+    }
+}
+
+/// Tell whether a type is an integer, looking through the aliases defined in this file.
+#[inline]
+fn is_integer(aliases: &[(Box<str>, Box<str>)], name: &str) -> bool {
+    match name {
+        "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "Card8" | "Card16"
+        | "Card32" | "Card64" | "Int8" | "Int16" | "Int32" | "Int64" | "Byte" | "XID" => true,
+        name => aliases
+            .iter()
+            .find(|(newname, _)| &**newname == name)
+            .is_some_and(|(_, oldname)| is_integer(aliases, oldname)),
+    }
+}
+
+#[inline]
+fn lookup(comparisons: &HashMap<Box<str>, Comparison>, name: &str) -> Comparison {
+    match comparisons.get(name) {
+        Some(c) => *c,
+        None => builtin_comparison(name),
+    }
+}
+
+#[inline]
+fn items_comparison(
+    items: &[StructureItem],
+    comparisons: &HashMap<Box<str>, Comparison>,
+) -> Comparison {
+    items
+        .iter()
+        .map(|f| match f {
+            StructureItem::Field(Field { ty, .. }) => lookup(comparisons, type_name(ty)),
+            StructureItem::List(List {
+                ty: MaybeString::NotAString(ty),
+                ..
+            }) => lookup(comparisons, type_name(ty)),
+            StructureItem::Switch(Switch { cases, .. }) => cases
+                .iter()
+                .map(|c| items_comparison(&c.items, comparisons))
+                .min()
+                .unwrap_or(Comparison::Total),
+            _ => Comparison::Total,
+        })
+        .min()
+        .unwrap_or(Comparison::Total)
+}
+
+/// Add `PartialEq`, `Eq` and `Hash` to the derives of every structure whose fields can be compared.
+/// Types containing floating point numbers only get `PartialEq`, and can't be hashed. Transparent
+/// wrappers around a single integer can be sorted as well, so they also get `PartialOrd` and `Ord`.
+#[inline]
+pub fn derive_comparisons(items: &mut [Item]) {
+    let mut comparisons: HashMap<Box<str>, Comparison> = HashMap::new();
+    let mut aliases: Vec<(Box<str>, Box<str>)> = vec![];
+
+    // start by assuming that every structure is totally comparable; the loop below then lowers
+    // that assumption until nothing else changes
+    items.iter().for_each(|item| match item {
+        Item::RStruct(rs) => {
+            comparisons.insert(rs.name.clone(), Comparison::Total);
+        }
+        Item::Typedef(td) => {
+            aliases.push((
+                td.newname.clone().into_boxed_str(),
+                td.oldname.clone().into_boxed_str(),
+            ));
+        }
+        _ => (),
+    });
+
+    loop {
+        let mut changed = false;
+
+        aliases.iter().for_each(|(newname, oldname)| {
+            let c = lookup(&comparisons, oldname);
+            if comparisons.insert(newname.clone(), c) != Some(c) {
+                changed = true;
+            }
+        });
+
+        items.iter().for_each(|item| {
+            if let Item::RStruct(rs) = item {
+                let c = items_comparison(&rs.fields, &comparisons);
+                if comparisons.insert(rs.name.clone(), c) != Some(c) {
+                    changed = true;
+                }
+            }
+        });
+
+        if !changed {
+            break;
+        }
+    }
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if !rs.derives.contains(&"PartialEq") {
+                match comparisons[&rs.name] {
+                    Comparison::None => (),
+                    Comparison::Partial => rs.derives.push("PartialEq"),
+                    Comparison::Total => rs.derives.extend(&["PartialEq", "Eq", "Hash"]),
+                }
+            }
+
+            if let (true, [StructureItem::Field(Field { ty, .. })]) =
+                (rs.is_transparent, &*rs.fields)
+            {
+                if matches!(ty, Lvl2Type::BasicType(_))
+                    && is_integer(&aliases, type_name(ty))
+                    && !rs.derives.contains(&"Ord")
+                {
+                    rs.derives.extend(&["PartialOrd", "Ord"]);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,183 @@
+// MIT/Apache2 License
+
+use super::{
+    is_reply,
+    syn_util::{default_call, str_to_exprpath, str_to_path},
+    InputParameter, Item, Method, ParameterUsage, RStruct, Trait, Type,
+};
+use crate::lvl2::{Field, List, MaybeString, StructureItem, Type as Lvl2Type};
+use proc_macro2::Span;
+use std::iter;
+
+/// Tell whether a list holds a string of two-byte characters.
+#[inline]
+fn is_string16(ty: &MaybeString) -> bool {
+    matches!(ty, MaybeString::NotAString(Lvl2Type::BasicType(name)) if name == "Char2b")
+}
+
+/// `Self { name: expr, ... }`
+#[inline]
+fn self_struct(values: Vec<(&str, syn::Expr)>) -> syn::Expr {
+    syn::Expr::Struct(syn::ExprStruct {
+        attrs: vec![],
+        path: str_to_path("Self"),
+        brace_token: Default::default(),
+        fields: values
+            .into_iter()
+            .map(|(name, expr)| syn::FieldValue {
+                attrs: vec![],
+                member: syn::Member::Named(syn::Ident::new(name, Span::call_site())),
+                colon_token: Some(Default::default()),
+                expr,
+            })
+            .collect(),
+        dot2_token: None,
+        rest: None,
+    })
+}
+
+/// `func(arg)`
+#[inline]
+fn call(func: &str, arg: &str) -> syn::Expr {
+    syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(str_to_exprpath(func)),
+        paren_token: Default::default(),
+        args: iter::once(str_to_exprpath(arg)).collect(),
+    })
+}
+
+impl RStruct {
+    /// For requests that hold a string, add a `new` function that takes each of the request's fields
+    /// in order. Strings are taken as a `&str`, and strings of two-byte characters are encoded into
+    /// them. The header and the lengths of the lists are filled in when the request is sent.
+    #[inline]
+    pub fn populate_string_constructor(&mut self) {
+        if !self.traits.iter().any(|t| matches!(t, Trait::Request(..)))
+            || !self.fields.iter().any(|item| {
+                matches!(item, StructureItem::List(List { ty, .. })
+                    if matches!(ty, MaybeString::IsAString) || is_string16(ty))
+            })
+            // a switch's fields depend on each other, so they're better off set with the builder
+            || self
+                .fields
+                .iter()
+                .any(|item| matches!(item, StructureItem::Switch(_)))
+        {
+            return;
+        }
+
+        let mut parameters = vec![];
+        let mut values = vec![];
+        self.fields.iter().for_each(|item| match item {
+            StructureItem::Field(Field { name, .. }) if name == "req_type" || name == "length" => {
+                values.push((name.as_str(), default_call()))
+            }
+            StructureItem::Field(Field { name, ty, .. }) => {
+                parameters.push(InputParameter {
+                    name: name.clone().into(),
+                    ty: Type::from_lvl2(ty.clone()),
+                    usage: ParameterUsage::Owned,
+                });
+                values.push((name.as_str(), str_to_exprpath(name)));
+            }
+            StructureItem::List(List { name, ty, .. }) => match ty {
+                MaybeString::IsAString => {
+                    parameters.push(InputParameter {
+                        name: name.clone().into(),
+                        ty: Type::Basic("str".into()),
+                        usage: ParameterUsage::Ref,
+                    });
+                    values.push((name.as_str(), call("String::from", name)));
+                }
+                ty if is_string16(ty) => {
+                    parameters.push(InputParameter {
+                        name: name.clone().into(),
+                        ty: Type::Basic("str".into()),
+                        usage: ParameterUsage::Ref,
+                    });
+                    values.push((name.as_str(), call("string16", name)));
+                }
+                MaybeString::NotAString(ty) => {
+                    parameters.push(InputParameter {
+                        name: name.clone().into(),
+                        ty: Type::Vector(Box::new(Type::from_lvl2(ty.clone()))),
+                        usage: ParameterUsage::Owned,
+                    });
+                    values.push((name.as_str(), str_to_exprpath(name)));
+                }
+            },
+            StructureItem::Fd { name } => {
+                parameters.push(InputParameter {
+                    name: name.clone().into(),
+                    ty: Type::Basic("Fd".into()),
+                    usage: ParameterUsage::Owned,
+                });
+                values.push((name.as_str(), str_to_exprpath(name)));
+            }
+            _ => (),
+        });
+
+        let expr = self_struct(values);
+
+        let mut method = Method::new(
+            "new".into(),
+            None,
+            parameters,
+            Some(Type::Basic("Self".into())),
+        );
+        method.must_use = Some(super::REQUEST_MUST_USE);
+        method.statements = vec![super::ExprWrapper(expr).into()];
+        self.methods.push(method);
+    }
+}
+
+/// Add a `const fn new` that takes each of the structure's fields in order to every plain structure
+/// that is `Copy`, so that they can be built in const contexts, like static tables of rectangles.
+/// Structures that hold vectors can't be `Copy`, so they're left out, as are requests, replies, events
+/// and errors, whose headers are filled in for them.
+#[inline]
+pub fn populate_const_constructors(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| {
+        let rs = match item {
+            Item::RStruct(rs) => rs,
+            _ => return,
+        };
+        if !rs.derives.contains(&"Copy")
+            || is_reply(rs)
+            || rs.methods.iter().any(|m| m.name == "new")
+            || rs
+                .traits
+                .iter()
+                .any(|t| matches!(t, Trait::Request(..) | Trait::Event(..) | Trait::Error(..)))
+        {
+            return;
+        }
+
+        let (parameters, values) = rs
+            .fields
+            .iter()
+            .filter_map(|item| match item {
+                StructureItem::Field(Field { name, ty, .. }) => Some((
+                    InputParameter {
+                        name: name.clone().into(),
+                        ty: Type::from_lvl2(ty.clone()),
+                        usage: ParameterUsage::Owned,
+                    },
+                    (name.as_str(), str_to_exprpath(name)),
+                )),
+                _ => None,
+            })
+            .unzip();
+
+        let mut method = Method::new(
+            "new".into(),
+            None,
+            parameters,
+            Some(Type::Basic("Self".into())),
+        );
+        method.is_const = true;
+        method.statements = vec![super::ExprWrapper(self_struct(values)).into()];
+        rs.methods.push(method);
+    });
+}
@@ -0,0 +1,127 @@
+// MIT/Apache2 License
+
+use super::{
+    output_names, syn_util::doc_attrs, EmitContext, InputParameter, Item, Method, ParameterUsage,
+    RStruct, Type,
+};
+use heck::CamelCase;
+
+/// A request that reads part of a larger value, and can be sent again to read the part of the value that
+/// comes next.
+struct Continuation {
+    /// The name of the request, as it is in the XML.
+    request: &'static str,
+    /// The field of the request that holds the offset to read from, in units of four bytes.
+    offset: &'static str,
+    /// The field of the reply that holds the number of items that were read.
+    count: &'static str,
+}
+
+/// The requests that properties are read in parts with. The replies to these all hold "bytes_after" and
+/// "format" fields.
+const CONTINUATIONS: &[Continuation] = &[
+    Continuation {
+        request: "GetProperty",
+        offset: "long_offset",
+        count: "value_len",
+    },
+    Continuation {
+        request: "GetOutputProperty",
+        offset: "long_offset",
+        count: "num_items",
+    },
+    Continuation {
+        request: "GetProviderProperty",
+        offset: "long_offset",
+        count: "num_items",
+    },
+    Continuation {
+        request: "GetDeviceProperty",
+        offset: "offset",
+        count: "num_items",
+    },
+    Continuation {
+        request: "XIGetProperty",
+        offset: "offset",
+        count: "num_items",
+    },
+];
+
+#[inline]
+fn has_fields(rs: &RStruct, names: &[&str]) -> bool {
+    let fields = output_names(&rs.fields);
+    names.iter().all(|name| fields.iter().any(|f| f == name))
+}
+
+impl Continuation {
+    /// Create the `continue_request` method for the request's reply.
+    #[inline]
+    fn method(&self, request_name: &str, ctx: &EmitContext) -> syn::ImplItem {
+        let mut method = Method::new(
+            "continue_request".into(),
+            Some(ParameterUsage::Ref),
+            vec![InputParameter {
+                name: "request".into(),
+                ty: Type::Basic(request_name.to_string().into()),
+                usage: ParameterUsage::Ref,
+            }],
+            Some(Type::Opt(Box::new(Type::Basic(
+                request_name.to_string().into(),
+            )))),
+        );
+        method.statements = vec![super::ContinueRequest {
+            request: request_name.into(),
+            offset: self.offset,
+            count: self.count,
+        }
+        .into()];
+
+        let mut item = method.to_syn_impl_item(false, ctx);
+        if let syn::ImplItem::Method(ref mut m) = item {
+            m.attrs.splice(
+                0..0,
+                doc_attrs(&format!(
+                    "Create the request that reads the next part of the value, given the\n`{}` \
+                     that this is a reply to. Returns `None` once\nthere is nothing left to read.",
+                    request_name
+                )),
+            );
+        }
+        item
+    }
+}
+
+/// Give the replies to requests that read values in parts a method that creates the request for the next
+/// part.
+#[inline]
+pub fn populate_continuations(items: &mut [Item], ctx: &EmitContext) {
+    CONTINUATIONS.iter().for_each(|cont| {
+        let base = cont.request.to_camel_case();
+        let request_name = format!("{}Request", base);
+        let reply_name = format!("{}Reply", base);
+
+        let request_ok = items.iter().any(|item| match item {
+            Item::RStruct(rs) => {
+                *rs.name == *request_name
+                    && rs.derives.contains(&"Clone")
+                    && has_fields(rs, &[cont.offset])
+            }
+            _ => false,
+        });
+        if !request_ok {
+            return;
+        }
+
+        items.iter_mut().for_each(|item| {
+            if let Item::RStruct(rs) = item {
+                if *rs.name == *reply_name {
+                    if has_fields(rs, &["bytes_after", "format", cont.count]) {
+                        rs.other_impl_items.push(cont.method(&request_name, ctx));
+                    } else {
+                        log::warn!("{} lacks the fields needed to continue it", &reply_name);
+                    }
+                }
+            }
+        });
+    });
+}
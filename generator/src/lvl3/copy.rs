@@ -0,0 +1,122 @@
+// MIT/Apache2 License
+
+use super::{known_sizes, Item};
+use crate::lvl2::{Field, StructureItem, Type as Lvl2Type};
+use std::collections::HashSet;
+
+/// The largest structure, in bytes, that is made `Copy`. Anything bigger is left to be cloned.
+const MAX_COPY_SIZE: usize = 32;
+
+/// Tell whether a type that is not defined in this file is `Copy`. Types imported from other files
+/// aren't known, so they are assumed not to be.
+#[inline]
+fn builtin_copy(name: &str) -> bool {
+    matches!(
+        name,
+        "u8" | "i8"
+            | "u16"
+            | "i16"
+            | "u32"
+            | "i32"
+            | "u64"
+            | "i64"
+            | "f32"
+            | "f64"
+            | "bool"
+            | "c_char"
+            | "Card8"
+            | "Card16"
+            | "Card32"
+            | "Card64"
+            | "Int8"
+            | "Int16"
+            | "Int32"
+            | "Int64"
+            | "Byte"
+            | "Char"
+            | "Float"
+            | "Double"
+            | "Void"
+            | "XID"
+    )
+}
+
+#[inline]
+fn is_copy(copies: &HashSet<Box<str>>, ty: &Lvl2Type) -> bool {
+    let name = match ty {
+        Lvl2Type::BasicType(name) | Lvl2Type::Array(name, _) => name,
+    };
+    builtin_copy(name) || copies.contains(&**name)
+}
+
+/// Add `Copy` to the derives of every small structure made only of `Copy` values. Structures with
+/// lists or switches hold vectors, and structures with file descriptors own them, so they can't be
+/// `Copy`.
+#[inline]
+pub fn derive_copies(items: &mut [Item]) {
+    let sizes = known_sizes(items);
+
+    // enums, resource IDs and bitflags are already `Copy`; aliases of them are found in the loop below
+    let mut copies: HashSet<Box<str>> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::REnum(re) => Some(re.name.clone()),
+            Item::RStruct(rs) if rs.derives.contains(&"Copy") => Some(rs.name.clone()),
+            _ => None,
+        })
+        .collect();
+
+    loop {
+        let mut changed = false;
+
+        items.iter().for_each(|item| match item {
+            Item::Typedef(td)
+                if !copies.contains(td.newname.as_str())
+                    && is_copy(&copies, &Lvl2Type::BasicType(td.oldname.clone().into())) =>
+            {
+                copies.insert(td.newname.clone().into_boxed_str());
+                changed = true;
+            }
+            Item::RStruct(rs) => {
+                if copies.contains(&rs.name)
+                    || !rs.derives.contains(&"Clone")
+                    || sizes.get(&rs.name).is_none_or(|size| *size > MAX_COPY_SIZE)
+                {
+                    return;
+                }
+
+                let all_copy = rs.fields.iter().all(|item| match item {
+                    StructureItem::Field(Field { ty, .. }) => is_copy(&copies, ty),
+                    StructureItem::Padding { .. }
+                    | StructureItem::AlignPad { .. }
+                    | StructureItem::LenSlot { .. } => true,
+                    StructureItem::List(_)
+                    | StructureItem::Switch(_)
+                    | StructureItem::Fd { .. } => false,
+                });
+                if all_copy {
+                    copies.insert(rs.name.clone());
+                    changed = true;
+                }
+            }
+            _ => (),
+        });
+
+        if !changed {
+            break;
+        }
+    }
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if copies.contains(&rs.name) && !rs.derives.contains(&"Copy") {
+                let posn = rs
+                    .derives
+                    .iter()
+                    .position(|d| *d == "Clone")
+                    .map_or(0, |p| p + 1);
+                rs.derives.insert(posn, "Copy");
+            }
+        }
+    });
+}
@@ -0,0 +1,148 @@
+// MIT/Apache2 License
+
+use super::{
+    primitive_size,
+    syn_util::{default_call, int_litexpr_int},
+    Item, RStruct, Trait,
+};
+use crate::lvl2::{Field, StructureItem, Type as Lvl2Type};
+use proc_macro2::{Ident, Span};
+
+/// Remove `Default` from the derives of every request, unless the request is named in `allow`.
+/// A default request is rarely meaningful (a window of zero, an empty value mask), so it's easy to
+/// send one with a required field left unset. Their builders still implement `Default`, since a
+/// builder is meant to have its fields set before it's built.
+#[inline]
+pub fn strip_request_defaults(items: &mut [Item], allow: &[String]) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if rs.traits.iter().any(|t| matches!(t, Trait::Request(..)))
+                && !allow.iter().any(|name| **name == *rs.name)
+            {
+                rs.derives.retain(|d| *d != "Default");
+            }
+        }
+    });
+}
+
+/// The fields of a structure that the protocol gives a value other than zero by default. Only
+/// integers and booleans can be given defaults; the others start at zero.
+#[inline]
+pub fn protocol_defaults(sname: &str, fields: &[StructureItem]) -> Vec<(Box<str>, i128)> {
+    fields
+        .iter()
+        .filter_map(|item| match item {
+            StructureItem::Field(Field {
+                name,
+                ty,
+                default: Some(default),
+                ..
+            }) => match default_value(ty, *default) {
+                Some(_) => Some((name.clone().into_boxed_str(), *default)),
+                None => {
+                    log::warn!(
+                        "\"{}.{}\" can't be given a default value of {}",
+                        sname,
+                        name,
+                        default
+                    );
+                    None
+                }
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The expression for the default value of a field of the given type.
+#[inline]
+fn default_value(ty: &Lvl2Type, default: i128) -> Option<syn::Expr> {
+    match ty {
+        Lvl2Type::BasicType(name) if name == "bool" => Some(syn::parse_quote! { true }),
+        Lvl2Type::BasicType(name)
+            if matches!(name.as_ref(), "f32" | "f64" | "Float" | "Double") =>
+        {
+            None
+        }
+        Lvl2Type::BasicType(_) => {
+            primitive_size(ty)?;
+            let val = int_litexpr_int(default.abs());
+            Some(match default < 0 {
+                true => syn::parse_quote! { -#val },
+                false => val,
+            })
+        }
+        Lvl2Type::Array(..) => None,
+    }
+}
+
+/// The longest array that implements `Default` by itself.
+const MAX_DEFAULT_ARRAY: u64 = 32;
+
+/// The default value of an array that's too long to implement `Default`. Arrays only hold
+/// primitives, which are `Copy`, so the default element can be repeated.
+#[inline]
+fn long_array_default(ty: &Lvl2Type) -> Option<syn::Expr> {
+    match ty {
+        Lvl2Type::Array(_, len) if *len > MAX_DEFAULT_ARRAY => {
+            let len = int_litexpr_int(len);
+            Some(syn::parse_quote! { [Default::default(); #len] })
+        }
+        _ => None,
+    }
+}
+
+impl RStruct {
+    /// The values of the fields of the default structure: the protocol's default values for the
+    /// fields that have them, and zeroes for the others.
+    #[inline]
+    pub fn default_field_values(&self) -> Vec<syn::FieldValue> {
+        self.fields
+            .iter()
+            .flat_map(|item| item.to_syn_fields(false))
+            .filter_map(|f| f.ident)
+            .map(|ident| -> syn::FieldValue {
+                let ty = self.fields.iter().find_map(|item| match item {
+                    StructureItem::Field(Field { name, ty, .. }) if ident == name => Some(ty),
+                    _ => None,
+                });
+                let default = self
+                    .defaults
+                    .iter()
+                    .find(|(name, _)| ident == **name)
+                    .and_then(|(_, default)| default_value(ty?, *default))
+                    .or_else(|| long_array_default(ty?))
+                    .unwrap_or_else(default_call);
+                syn::parse_quote! { #ident: #default }
+            })
+            .collect()
+    }
+
+    /// Implement `Default` by hand if the protocol gives any of the fields a default value, so that
+    /// the default structure uses those values instead of zeroes, or if one of the fields is an array
+    /// too long to derive it. The derive is removed to make room for it.
+    #[inline]
+    pub fn default_impl(&mut self) -> Option<syn::Item> {
+        let long_arrays = self.fields.iter().any(|item| {
+            matches!(item, StructureItem::Field(Field { ty, .. }) if long_array_default(ty).is_some())
+        });
+        if (self.defaults.is_empty() && !long_arrays) || !self.derives.contains(&"Default") {
+            return None;
+        }
+        self.derives.retain(|d| *d != "Default");
+
+        let fields = self.default_field_values();
+        let name = Ident::new(&self.name, Span::call_site());
+
+        Some(syn::parse_quote! {
+            impl Default for #name {
+                #[inline]
+                fn default() -> Self {
+                    Self {
+                        #(#fields),*
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,81 @@
+// MIT/Apache2 License
+
+use super::{is_reply, semantic_eq::HEADER_FIELDS, syn_util::doc_attrs, Item};
+use crate::lvl2::{Field, List, MaybeString, StructureItem, Switch, Type};
+use proc_macro2::{Ident, Span};
+
+/// The statements that record the changes to the fields of a structure, including those in switch
+/// cases. File descriptors can't be compared by their contents, so they're left out.
+#[inline]
+fn field_diffs(items: &[StructureItem], diffs: &mut Vec<syn::Stmt>) {
+    items.iter().for_each(|item| match item {
+        StructureItem::Field(Field { name, ty, .. }) if !HEADER_FIELDS.contains(&name.as_str()) => {
+            let field = Ident::new(name, Span::call_site());
+            diffs.push(match ty {
+                // property data can't be parsed without its format, so it isn't a byte sequence
+                Type::BasicType(ty) if ty == "PropertyData" => syn::parse_quote! {
+                    diff_property_data(#name, &self.#field, &other.#field, &mut changes);
+                },
+                _ => syn::parse_quote! {
+                    diff_value(#name, &self.#field, &other.#field, &mut changes);
+                },
+            });
+        }
+        StructureItem::List(List { name, ty, .. }) => {
+            let field = Ident::new(name, Span::call_site());
+            diffs.push(match ty {
+                MaybeString::IsAString => syn::parse_quote! {
+                    diff_list(
+                        #name,
+                        str::as_bytes(&self.#field),
+                        str::as_bytes(&other.#field),
+                        &mut changes,
+                    );
+                },
+                MaybeString::NotAString(_) => syn::parse_quote! {
+                    diff_list(#name, &self.#field, &other.#field, &mut changes);
+                },
+            });
+        }
+        StructureItem::Switch(Switch { cases, .. }) => {
+            cases.iter().for_each(|c| field_diffs(&c.items, diffs))
+        }
+        _ => (),
+    });
+}
+
+/// `pub fn diff(&self, other: &Self) -> Vec<FieldChange>`, which lists the fields that differ between
+/// two replies.
+#[inline]
+fn diff_method(diffs: Vec<syn::Stmt>) -> syn::ImplItem {
+    let docs = doc_attrs(
+        "List the fields whose values differ between this reply and `other`, in the order they \
+         are sent in. The type, sequence number and length in the header are left out.",
+    );
+
+    syn::parse_quote! {
+        #(#docs)*
+        #[inline]
+        #[must_use]
+        pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+            let mut changes = Vec::new();
+            #(#diffs)*
+            changes
+        }
+    }
+}
+
+/// Add a `diff` method to every reply that can be compared, so that the changes between two replies
+/// to the same request can be tracked without comparing each field by hand.
+#[inline]
+pub fn populate_diffs(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if is_reply(rs) && rs.derives.contains(&"PartialEq") {
+                let mut diffs = vec![];
+                field_diffs(&rs.fields, &mut diffs);
+                rs.other_impl_items.push(diff_method(diffs));
+            }
+        }
+    });
+}
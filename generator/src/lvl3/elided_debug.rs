@@ -0,0 +1,72 @@
+// MIT/Apache2 License
+
+use super::{is_byte_buffer, syn_util::int_litexpr_int, Item, Trait};
+use crate::lvl2::{Field, List, StructureItem, Switch};
+use proc_macro2::{Ident, Span};
+
+/// The number of bytes a buffer may hold and still be printed in full by `Debug`, if the generator
+/// isn't given another number.
+pub const DEFAULT_DEBUG_LIST_LIMIT: usize = 64;
+
+/// Collect the names of the fields of a structure, including those in switch cases, along with
+/// whether each one is a buffer of bytes.
+#[inline]
+fn debug_fields(items: &[StructureItem], fields: &mut Vec<(Box<str>, bool)>) {
+    items.iter().for_each(|item| match item {
+        StructureItem::Field(Field { name, .. }) | StructureItem::Fd { name } => {
+            fields.push((name.as_str().into(), false))
+        }
+        StructureItem::List(List { name, .. }) => {
+            fields.push((name.as_str().into(), is_byte_buffer(item)))
+        }
+        StructureItem::Switch(Switch { cases, .. }) => {
+            cases.iter().for_each(|c| debug_fields(&c.items, fields))
+        }
+        _ => (),
+    });
+}
+
+/// `impl core::fmt::Debug for Name`, which prints the buffers of bytes among the fields with
+/// `debug_bytes`, and every other field as it is.
+#[inline]
+pub fn elided_debug_impl(tyname: &str, fields: &[(Box<str>, bool)], limit: usize) -> syn::Item {
+    let limit = int_litexpr_int(limit);
+    let names = fields.iter().map(|(name, _)| &**name);
+    let values = fields.iter().map(|(name, is_buffer)| -> syn::Expr {
+        let field = Ident::new(name, Span::call_site());
+        match is_buffer {
+            true => syn::parse_quote! { &debug_bytes(&self.#field, #limit) },
+            false => syn::parse_quote! { &self.#field },
+        }
+    });
+    let name = tyname;
+    let tyname = Ident::new(tyname, Span::call_site());
+
+    syn::parse_quote! {
+        impl core::fmt::Debug for #tyname {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(#name)
+                    #(.field(#names, #values))*
+                    .finish()
+            }
+        }
+    }
+}
+
+/// Replace the derived `Debug` of every structure that carries a buffer of bytes with one that only
+/// prints the length of the buffer when it holds more than `limit` bytes. Requests like `PutImage`
+/// may carry megabytes of pixels, which would otherwise be printed in full.
+#[inline]
+pub fn elide_large_debugs(items: &mut [Item], limit: usize) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let mut fields = vec![];
+            debug_fields(&rs.fields, &mut fields);
+            if rs.derives.contains(&"Debug") && fields.iter().any(|(_, is_buffer)| *is_buffer) {
+                rs.derives.retain(|d| *d != "Debug");
+                rs.traits.push(Trait::ElidedDebug(fields, limit));
+            }
+        }
+    });
+}
@@ -0,0 +1,103 @@
+// MIT/Apache2 License
+
+use super::{syn_util::doc_attrs, Item};
+use crate::lvl2::{Field, StructureItem, Type as Lvl2Type};
+use proc_macro2::{Ident, Span};
+use std::collections::HashMap;
+
+/// The unsigned integer that a primitive type is stored as, if it is one.
+#[inline]
+fn unsigned_repr(name: &str) -> Option<&'static str> {
+    match name {
+        "u8" | "Card8" | "Byte" => Some("u8"),
+        "u16" | "Card16" => Some("u16"),
+        "u32" | "Card32" => Some("u32"),
+        _ => None,
+    }
+}
+
+/// The unsigned integer that a type is stored as, looking through the typedefs it's defined in
+/// terms of.
+#[inline]
+pub fn integer_repr<'a>(
+    typedefs: &'a HashMap<String, String>,
+    mut name: &'a str,
+) -> Option<&'static str> {
+    loop {
+        match unsigned_repr(name) {
+            Some(repr) => return Some(repr),
+            None => name = typedefs.get(name)?,
+        }
+    }
+}
+
+/// `pub fn [field]_enum(&self) -> Option<Enum>`, which interprets the field as the enumeration.
+#[inline]
+fn enum_accessor(field: &str, enum_name: &str) -> syn::ImplItem {
+    let docs = doc_attrs(&format!(
+        "Interpret `{}` as a `{}`. Returns `None` if it holds a value that isn't one of the \
+         enumeration's.",
+        field, enum_name
+    ));
+    let accessor = Ident::new(
+        &format!("{}_enum", field.trim_end_matches('_')),
+        Span::call_site(),
+    );
+    let field = Ident::new(field, Span::call_site());
+    let enum_name = Ident::new(enum_name, Span::call_site());
+
+    syn::parse_quote! {
+        #(#docs)*
+        #[inline]
+        pub fn #accessor(&self) -> Option<#enum_name> {
+            core::convert::TryFrom::try_from(self.#field).ok()
+        }
+    }
+}
+
+/// Add `[field]_enum` accessors to structures whose integer fields are also described by an
+/// enumeration. These fields are kept as integers, since they can hold values that the enumeration
+/// doesn't have, but the accessor reads them as the enumeration when they fit.
+#[inline]
+pub fn populate_enum_accessors(items: &mut [Item]) {
+    let enums: HashMap<Box<str>, Box<str>> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::REnum(re) => Some((re.name.clone(), re.underlying.as_ref().into())),
+            _ => None,
+        })
+        .collect();
+    let typedefs: HashMap<String, String> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Typedef(td) => Some((td.newname.clone(), td.oldname.clone())),
+            _ => None,
+        })
+        .collect();
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let accessors: Vec<syn::ImplItem> = rs
+                .fields
+                .iter()
+                .filter_map(|item| match item {
+                    StructureItem::Field(Field {
+                        name,
+                        ty: Lvl2Type::BasicType(ty),
+                        alt_enum: Some(alt_enum),
+                        ..
+                    }) => {
+                        let underlying = enums.get(alt_enum.as_str())?;
+                        if integer_repr(&typedefs, ty)? == &**underlying {
+                            Some(enum_accessor(name, alt_enum))
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                })
+                .collect();
+            rs.other_impl_items.extend(accessors);
+        }
+    });
+}
@@ -0,0 +1,118 @@
+// MIT/Apache2 License
+
+use super::{
+    syn_util::{doc_attrs, int_litexpr_int},
+    EmitContext, Item, ToSyn, Trait,
+};
+use heck::CamelCase;
+use proc_macro2::{Ident, Span};
+
+/// A function that parses an error out of its code, into the matching variant of `XError`.
+#[derive(Debug)]
+pub struct ErrorDispatcher {
+    /// The variant, the name of the error structure and the code of each error.
+    pub errors: Vec<(Box<str>, Box<str>, u64)>,
+    /// Whether the errors belong to an extension, whose codes count up from its first error code.
+    pub is_extension: bool,
+}
+
+/// The variant of `XError` that holds an error structure. The variants of an extension's errors are
+/// prefixed with the name of its namespace, since extensions reuse the names of errors.
+#[inline]
+pub fn error_variant(namespace: &str, is_extension: bool, error: &str) -> String {
+    let name = error.strip_suffix("Error").unwrap_or(error);
+    if is_extension {
+        format!("{}{}", namespace.to_camel_case(), name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Gather the errors in a series of items into an `error_from_code` function.
+#[inline]
+pub fn error_dispatcher(items: &[Item], namespace: &str, is_extension: bool) -> Option<Item> {
+    let mut errors: Vec<(Box<str>, Box<str>, u64)> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::RStruct(rs) => rs.traits.iter().find_map(|t| match t {
+                Trait::Error(code) => {
+                    let variant = error_variant(namespace, is_extension, &rs.name);
+                    Some((variant.into(), rs.name.clone(), *code))
+                }
+                _ => None,
+            }),
+            _ => None,
+        })
+        .collect();
+    if errors.is_empty() {
+        return None;
+    }
+    errors.sort_by_key(|(_, _, code)| *code);
+
+    Some(Item::ErrorDispatcher(ErrorDispatcher {
+        errors,
+        is_extension,
+    }))
+}
+
+impl ToSyn for ErrorDispatcher {
+    #[inline]
+    fn to_syn_item(self, _ctx: &EmitContext) -> Vec<syn::Item> {
+        let arms = self
+            .errors
+            .iter()
+            .map(|(variant, error, code)| -> syn::Arm {
+                let variant = Ident::new(variant, Span::call_site());
+                let error = Ident::new(error, Span::call_site());
+                let code = int_litexpr_int(code);
+                syn::parse_quote! {
+                    #code => XError::#variant(#error::from_bytes(bytes)?.0),
+                }
+            });
+        let unknown: syn::Arm = syn::parse_quote! {
+            _ => XError::Unknown {
+                code,
+                bytes: bytes.iter().copied().collect(),
+            },
+        };
+
+        let item = if self.is_extension {
+            let docs = doc_attrs(
+                "Parse an error of this extension out of its code and its bytes. The codes of the \
+                 extension's errors count up from `first_error`, the first error code that the \
+                 server gave it. Errors with other codes are kept as bytes.",
+            );
+            syn::parse_quote! {
+                #(#docs)*
+                #[inline]
+                pub fn error_from_code(
+                    code: u8,
+                    first_error: u8,
+                    bytes: &[u8],
+                ) -> Result<XError, ParseError> {
+                    Ok(match code.wrapping_sub(first_error) {
+                        #(#arms)*
+                        #unknown
+                    })
+                }
+            }
+        } else {
+            let docs = doc_attrs(
+                "Parse an error out of its code and its bytes. Errors with codes that aren't part \
+                 of the core protocol are kept as bytes.",
+            );
+            syn::parse_quote! {
+                #(#docs)*
+                #[inline]
+                pub fn error_from_code(code: u8, bytes: &[u8]) -> Result<XError, ParseError> {
+                    Ok(match code {
+                        #(#arms)*
+                        #unknown
+                    })
+                }
+            }
+        };
+
+        vec![item]
+    }
+}
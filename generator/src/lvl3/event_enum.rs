@@ -0,0 +1,191 @@
+// MIT/Apache2 License
+
+use super::{
+    syn_util::{derive_attrs, doc_attrs, pub_vis, str_to_ty},
+    EmitContext, InputParameter, Item, Method, ParameterUsage, RStruct, ToSyn, Trait, Type,
+};
+use proc_macro2::Span;
+use std::iter;
+
+/// The names that the event enum may take, in order of preference. Some modules already have an
+/// item called `Event`.
+const EVENT_ENUM_NAMES: &[&str] = &["Event", "AnyEvent"];
+
+/// An enum with one variant for each of the events in a module.
+#[derive(Debug)]
+pub struct EventEnum {
+    pub name: Box<str>,
+    pub derives: Vec<&'static str>,
+    /// The variant, the name of the event structure and the opcode of each event.
+    pub events: Vec<(Box<str>, Box<str>, u64)>,
+    /// The variant, the name of the event structure and the event type of each generic event.
+    pub generic_events: Vec<(Box<str>, Box<str>, u64)>,
+}
+
+/// The events in a series of items, along with their opcodes, that either are or aren't generic
+/// events.
+#[inline]
+fn events_of_kind(items: &[Item], generic: bool) -> Vec<(&RStruct, u64)> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::RStruct(rs) => rs.traits.iter().find_map(|t| match t {
+                Trait::Event(opcode, xge) if *xge == generic => Some((rs, *opcode)),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The variant, the name of the event structure and the opcode of each event.
+#[inline]
+fn variants(events: Vec<(&RStruct, u64)>) -> Vec<(Box<str>, Box<str>, u64)> {
+    events
+        .into_iter()
+        .map(|(rs, opcode)| {
+            let variant = rs.name.strip_suffix("Event").unwrap_or(&rs.name);
+            (variant.into(), rs.name.clone(), opcode)
+        })
+        .collect()
+}
+
+/// Gather the events in a series of items into an enum. Generic events are kept apart from the
+/// others, since their opcodes are event types that may clash with the opcodes of the other events.
+#[inline]
+pub fn event_enum(items: &[Item]) -> Option<Item> {
+    let events = events_of_kind(items, false);
+    let generic_events = events_of_kind(items, true);
+    if events.is_empty() && generic_events.is_empty() {
+        return None;
+    }
+
+    let name = EVENT_ENUM_NAMES.iter().find(|name| {
+        !items.iter().any(|item| match item {
+            Item::RStruct(rs) => *rs.name == ***name,
+            Item::REnum(re) => *re.name == ***name,
+            Item::Typedef(td) => td.newname == **name,
+            _ => false,
+        })
+    })?;
+
+    // the enum can only derive what every one of its events derives
+    let mut all_events = events.iter().chain(generic_events.iter());
+    let derives = all_events
+        .next()
+        .map(|(rs, _)| rs.derives.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|d| *d != "Default" && all_events.clone().all(|(rs, _)| rs.derives.contains(d)))
+        .collect();
+
+    Some(Item::EventEnum(EventEnum {
+        name: (*name).into(),
+        derives,
+        events: variants(events),
+        generic_events: variants(generic_events),
+    }))
+}
+
+impl ToSyn for EventEnum {
+    #[inline]
+    fn to_syn_item(self, ctx: &EmitContext) -> Vec<syn::Item> {
+        let e = syn::Item::Enum(syn::ItemEnum {
+            attrs: doc_attrs("One of the events in this module.")
+                .into_iter()
+                .chain(derive_attrs(&self.derives))
+                .collect(),
+            vis: pub_vis(),
+            enum_token: Default::default(),
+            ident: syn::Ident::new(&self.name, Span::call_site()),
+            generics: Default::default(),
+            brace_token: Default::default(),
+            variants: self
+                .events
+                .iter()
+                .chain(self.generic_events.iter())
+                .map(|(variant, event, _)| syn::Variant {
+                    attrs: vec![],
+                    ident: syn::Ident::new(variant, Span::call_site()),
+                    fields: syn::Fields::Unnamed(syn::FieldsUnnamed {
+                        paren_token: Default::default(),
+                        unnamed: iter::once(syn::Field {
+                            attrs: vec![],
+                            vis: syn::Visibility::Inherited,
+                            ident: None,
+                            colon_token: None,
+                            ty: str_to_ty(event),
+                        })
+                        .collect(),
+                    }),
+                    discriminant: None,
+                })
+                .collect(),
+        });
+
+        let mut parse = Method::new(
+            "parse".into(),
+            None,
+            vec![
+                InputParameter {
+                    name: "opcode".into(),
+                    ty: Type::Basic("u8".into()),
+                    usage: ParameterUsage::Owned,
+                },
+                InputParameter {
+                    name: "bytes".into(),
+                    ty: Type::Slice(Box::new(Type::Basic("u8".into()))),
+                    usage: ParameterUsage::Ref,
+                },
+            ],
+            Some(Type::ResWith(
+                Box::new(Type::Basic("Self".into())),
+                Box::new(Type::Basic("ParseError".into())),
+            )),
+        );
+        parse.statements = vec![super::MatchOpcodeToEvent {
+            events: self.events.clone(),
+            generic_events: self.generic_events.clone(),
+        }
+        .into()];
+
+        let mut response_type = Method::new(
+            "response_type".into(),
+            Some(ParameterUsage::Ref),
+            vec![],
+            Some(Type::Basic("u8".into())),
+        );
+        response_type.is_const = true;
+        response_type.statements = vec![super::MatchEventToResponseType {
+            events: self
+                .events
+                .iter()
+                .chain(self.generic_events.iter())
+                .map(|(variant, event, _)| (variant.clone(), event.clone()))
+                .collect(),
+        }
+        .into()];
+        let methods = syn::Item::Impl(syn::ItemImpl {
+            attrs: vec![],
+            defaultness: None,
+            unsafety: None,
+            impl_token: Default::default(),
+            generics: Default::default(),
+            trait_: None,
+            self_ty: Box::new(str_to_ty(&self.name)),
+            brace_token: Default::default(),
+            items: vec![
+                parse.to_syn_impl_item(false, ctx),
+                response_type.to_syn_impl_item(false, ctx),
+            ],
+        });
+
+        let name = self.name;
+        iter::once(e)
+            .chain(iter::once(methods))
+            .chain(self.events.into_iter().chain(self.generic_events).flat_map(
+                |(variant, event, _)| Trait::FromEvent(event, variant).to_syn_item(&name, ctx),
+            ))
+            .collect()
+    }
+}
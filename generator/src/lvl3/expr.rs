@@ -21,22 +21,75 @@ pub fn cast_to_usize(e: syn::Expr) -> syn::Expr {
 impl Expression {
     #[inline]
     pub fn to_length_expr(&self, with_self_fields: bool, cast: bool) -> syn::Expr {
+        self.to_length_expr_unwrapping(with_self_fields, cast, &[])
+    }
+
+    /// Convert this expression to a length expression, where the fields named in `optional` are
+    /// stored as `Option`s and need to be unwrapped first.
+    #[inline]
+    pub fn to_length_expr_unwrapping(
+        &self,
+        with_self_fields: bool,
+        cast: bool,
+        optional: &[Box<str>],
+    ) -> syn::Expr {
+        self.to_length_expr_with_params(with_self_fields, cast, optional, &[])
+    }
+
+    /// Convert this expression to a length expression, where the parameters it refers to are
+    /// resolved against the names in `params`, which are supplied by the enclosing context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression refers to a parameter that isn't in `params`.
+    #[inline]
+    pub fn to_length_expr_with_params(
+        &self,
+        with_self_fields: bool,
+        cast: bool,
+        optional: &[Box<str>],
+        params: &[Box<str>],
+    ) -> syn::Expr {
         #[inline]
         fn process_lli<T: Iterator<Item = ExpressionItem>>(
             iter: &mut T,
             with_self_fields: Option<&str>,
             cast: bool,
+            optional: &[Box<str>],
+            params: &[Box<str>],
         ) -> syn::Expr {
             syn::Expr::Paren(syn::ExprParen {
                 attrs: vec![],
                 paren_token: Default::default(),
                 expr: Box::new(match iter.next() {
                     None => panic!("Expected an operation where there was not one"),
-                    Some(ExpressionItem::FieldRef(s)) => {
-                        let b = if let Some(t) = with_self_fields {
-                            item_field(str_to_exprpath(t), &s)
+                    Some(ExpressionItem::ParamRef(s)) if !params.contains(&s) => {
+                        panic!("Parameter {} is not supplied by the enclosing context", s)
+                    }
+                    Some(item @ ExpressionItem::FieldRef(_))
+                    | Some(item @ ExpressionItem::ParamRef(_)) => {
+                        // parameters are held by the enclosing context, never by the item itself
+                        let (s, b) = match (item, with_self_fields) {
+                            (ExpressionItem::FieldRef(s), Some(t)) => {
+                                let b = item_field(str_to_exprpath(t), &s);
+                                (s, b)
+                            }
+                            (ExpressionItem::FieldRef(s), None)
+                            | (ExpressionItem::ParamRef(s), _) => {
+                                let b = str_to_exprpath(&s);
+                                (s, b)
+                            }
+                            _ => unreachable!(),
+                        };
+                        let b = if optional.iter().any(|o| **o == *s) {
+                            syn::Expr::Call(syn::ExprCall {
+                                attrs: vec![],
+                                func: Box::new(item_field(b, "unwrap_or_default")),
+                                paren_token: Default::default(),
+                                args: syn::punctuated::Punctuated::new(),
+                            })
                         } else {
-                            str_to_exprpath(&s)
+                            b
                         };
                         if cast {
                             cast_to_usize(b)
@@ -46,8 +99,8 @@ impl Expression {
                     }
                     Some(ExpressionItem::Value(i)) => int_litexpr_int(i),
                     Some(ExpressionItem::BinaryOp(b)) => {
-                        let e1 = process_lli(iter, with_self_fields, cast);
-                        let e2 = process_lli(iter, with_self_fields, cast);
+                        let e1 = process_lli(iter, with_self_fields, cast, optional, params);
+                        let e2 = process_lli(iter, with_self_fields, cast, optional, params);
 
                         syn::Expr::Binary(syn::ExprBinary {
                             attrs: vec![],
@@ -58,6 +111,8 @@ impl Expression {
                                 BinaryOp::Mult => syn::BinOp::Mul(Default::default()),
                                 BinaryOp::Div => syn::BinOp::Div(Default::default()),
                                 BinaryOp::And => syn::BinOp::BitAnd(Default::default()),
+                                BinaryOp::Shl => syn::BinOp::Shl(Default::default()),
+                                BinaryOp::Shr => syn::BinOp::Shr(Default::default()),
                             },
                             right: Box::new(e2),
                         })
@@ -66,7 +121,7 @@ impl Expression {
                         UnaryOp::OneCount => syn::Expr::Call(syn::ExprCall {
                             attrs: vec![],
                             func: Box::new(item_field(
-                                process_lli(iter, with_self_fields, false),
+                                process_lli(iter, with_self_fields, false, optional, params),
                                 "count_ones",
                             )),
                             paren_token: Default::default(),
@@ -74,7 +129,13 @@ impl Expression {
                         }),
                         u => syn::Expr::Unary(syn::ExprUnary {
                             attrs: vec![],
-                            expr: Box::new(process_lli(iter, with_self_fields, cast)),
+                            expr: Box::new(process_lli(
+                                iter,
+                                with_self_fields,
+                                cast,
+                                optional,
+                                params,
+                            )),
                             op: match u {
                                 UnaryOp::Not => syn::UnOp::Not(Default::default()),
                                 _ => unreachable!(),
@@ -98,10 +159,10 @@ impl Expression {
                     }),
                     Some(ExpressionItem::SumOf(slist, uses_extended)) => {
                         let sexpr = if uses_extended {
-                            process_lli(iter, Some("a"), cast)
+                            process_lli(iter, Some("a"), cast, optional, params)
                         } else {
                             let mut once = std::iter::once(ExpressionItem::ListExprRef);
-                            process_lli(&mut once, Some("a"), cast)
+                            process_lli(&mut once, Some("a"), cast, optional, params)
                         };
 
                         syn::Expr::MethodCall(syn::ExprMethodCall {
@@ -222,6 +283,8 @@ impl Expression {
             &mut i,
             if with_self_fields { Some("self") } else { None },
             cast,
+            optional,
+            params,
         )
     }
 }
@@ -1,23 +1,35 @@
 // MIT/Apache2 License
 
-use super::{syn_util::pub_vis, Type};
-use crate::lvl2::{Field, List, MaybeString, StructureItem};
+use super::{
+    syn_util::{doc_attrs, pub_vis},
+    Type,
+};
+use crate::lvl2::{Field, List, MaybeString, StructureItem, Switch};
 use proc_macro2::Span;
 use std::iter;
 
 impl StructureItem {
+    /// Get the fields of the structure this item translates to. Fields in switch cases are
+    /// optional, since they are only present if their case applies.
     #[inline]
-    pub fn to_syn_field(&self) -> Option<syn::Field> {
+    pub fn to_syn_fields(&self, in_switch: bool) -> Vec<syn::Field> {
         match self {
-            StructureItem::Field(Field { name, ty, doc, .. }) => Some(syn::Field {
-                attrs: vec![],
+            StructureItem::Field(Field { name, ty, doc, .. }) => vec![syn::Field {
+                attrs: doc.as_deref().map(doc_attrs).unwrap_or_default(),
                 vis: pub_vis(),
                 ident: Some(syn::Ident::new(name, Span::call_site())),
                 colon_token: Some(Default::default()),
-                ty: Type::from_lvl2(ty.clone()).to_syn_ty(),
-            }),
-            StructureItem::List(List { name, ty, doc, .. }) => Some(syn::Field {
-                attrs: vec![],
+                ty: {
+                    let ty = Type::from_lvl2(ty.clone());
+                    match in_switch {
+                        false => ty,
+                        true => Type::Opt(Box::new(ty)),
+                    }
+                }
+                .to_syn_ty(),
+            }],
+            StructureItem::List(List { name, ty, doc, .. }) => vec![syn::Field {
+                attrs: doc.as_deref().map(doc_attrs).unwrap_or_default(),
                 vis: pub_vis(),
                 ident: Some(syn::Ident::new(name, Span::call_site())),
                 colon_token: Some(Default::default()),
@@ -45,8 +57,19 @@ impl StructureItem {
                         },
                     }),
                 },
-            }),
-            _ => None,
+            }],
+            StructureItem::Switch(Switch { cases, .. }) => cases
+                .iter()
+                .flat_map(|c| c.items.iter().flat_map(|f| f.to_syn_fields(true)))
+                .collect(),
+            StructureItem::Fd { name } => vec![syn::Field {
+                attrs: vec![],
+                vis: pub_vis(),
+                ident: Some(syn::Ident::new(name, Span::call_site())),
+                colon_token: Some(Default::default()),
+                ty: Type::Basic("Fd".into()).to_syn_ty(),
+            }],
+            _ => vec![],
         }
     }
 }
@@ -0,0 +1,113 @@
+// MIT/Apache2 License
+
+use super::{known_sizes, type_size, Item, SizeSumPart, Type};
+use crate::lvl2::{Field, StructureItem, Type as Lvl2Type};
+use std::{collections::HashMap, mem};
+
+/// Replace the sizes of fields whose types always take up the same number of bytes with those
+/// numbers, so that `size()` only calls into the fields that contain lists. Every number is then
+/// added into one, so a structure made only of fixed-size fields is sized by one constant. Fields
+/// in switches are left alone, since they're only there some of the time.
+#[inline]
+pub fn fold_fixed_sizes(items: &mut [Item]) {
+    let sizes = known_sizes(items);
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let fixed: HashMap<&str, usize> = rs
+                .fields
+                .iter()
+                .filter_map(|item| match item {
+                    StructureItem::Field(Field { name, ty, .. }) => {
+                        type_size(&sizes, ty).map(|size| (name.as_str(), size))
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            let parts = mem::take(&mut rs.asb.size.0);
+            rs.asb.size.0 = fold_constants(
+                parts
+                    .into_iter()
+                    .map(|part| match part {
+                        SizeSumPart::SizeofField(ref name) => match fixed.get(&**name) {
+                            Some(size) => SizeSumPart::Bytes(*size),
+                            None => part,
+                        },
+                        SizeSumPart::SizeofType(Type::Basic(ref name)) => {
+                            match type_size(&sizes, &Lvl2Type::BasicType(name.clone())) {
+                                Some(size) => SizeSumPart::Bytes(size),
+                                None => part,
+                            }
+                        }
+                        part => part,
+                    })
+                    .collect(),
+            );
+        }
+    });
+}
+
+/// Add the numbers in a sum together. Padding depends on everything before it, so the parts
+/// between two paddings are the only ones that can be added in any order, and their numbers become
+/// one at the start of them. Padding that only follows numbers is a number itself.
+#[inline]
+fn fold_constants(parts: Vec<SizeSumPart>) -> Vec<SizeSumPart> {
+    let was_empty = parts.is_empty();
+    let mut folded = vec![];
+    let mut between = vec![];
+    let mut bytes = 0;
+
+    parts.into_iter().for_each(|part| match part {
+        SizeSumPart::Bytes(b) => bytes += b,
+        SizeSumPart::AlignTo(align) if folded.is_empty() && between.is_empty() => {
+            bytes += (align - bytes % align) % align;
+        }
+        part @ SizeSumPart::AlignTo(_) => {
+            if bytes != 0 {
+                folded.push(SizeSumPart::Bytes(mem::take(&mut bytes)));
+            }
+            folded.append(&mut between);
+            folded.push(part);
+        }
+        part => between.push(part),
+    });
+
+    // a sum of nothing but zeroes is still a sum
+    if bytes != 0 || (!was_empty && folded.is_empty() && between.is_empty()) {
+        folded.push(SizeSumPart::Bytes(bytes));
+    }
+    folded.append(&mut between);
+    folded
+}
+
+#[test]
+fn fold_every_number_between_paddings() {
+    let folded = fold_constants(vec![
+        SizeSumPart::Bytes(6),
+        SizeSumPart::SizeofField("ordering".into()),
+        SizeSumPart::Bytes(1),
+        SizeSumPart::AlignTo(4),
+        SizeSumPart::Bytes(2),
+        SizeSumPart::SizeofField("window".into()),
+        SizeSumPart::Bytes(4),
+    ]);
+    let folded: Vec<String> = folded.iter().map(|part| format!("{:?}", part)).collect();
+    assert_eq!(
+        folded,
+        [
+            "Bytes(7)",
+            "SizeofField(\"ordering\")",
+            "AlignTo(4)",
+            "Bytes(6)",
+            "SizeofField(\"window\")",
+        ]
+    );
+
+    let folded = fold_constants(vec![
+        SizeSumPart::Bytes(3),
+        SizeSumPart::AlignTo(4),
+        SizeSumPart::Bytes(2),
+    ]);
+    assert!(matches!(folded.as_slice(), [SizeSumPart::Bytes(6)]));
+}
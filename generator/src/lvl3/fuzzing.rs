@@ -0,0 +1,152 @@
+// MIT/Apache2 License
+
+use super::{cast_to_usize, output_names, Item, RStruct, Statement, SumStatement, Trait, Type};
+use crate::lvl2::{Field, List, MaybeString, StructureItem};
+use proc_macro2::{Ident, Span};
+
+/// The trait derived on items that don't have lists whose lengths are described by other fields.
+const ARBITRARY_DERIVE: &str = "arbitrary::Arbitrary";
+
+impl RStruct {
+    /// The fields whose items are as wide as the `format` field says, paired with the fields that
+    /// hold their numbers of items.
+    #[inline]
+    fn formatted_fields(&self) -> Vec<(&str, &Field)> {
+        self.fields
+            .iter()
+            .filter_map(|item| match item {
+                StructureItem::Field(Field {
+                    name,
+                    formatted_count: Some(count),
+                    ..
+                }) => self.fields.iter().find_map(|item| match item {
+                    StructureItem::Field(f) if f.name == *count => Some((name.as_str(), f)),
+                    _ => None,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The statements that create this structure out of fuzzing input. The lists whose lengths are
+    /// described by other fields are created last, so that those fields are known by then. Data
+    /// whose width is given by the `format` field is created first, and `format` and its number of
+    /// items are taken from it.
+    #[inline]
+    fn arbitrary_statements(
+        &self,
+        described: &[&List],
+        formatted: &[(&str, &Field)],
+    ) -> Vec<SumStatement> {
+        let is_described = |name: &str| described.iter().any(|list| list.name == name);
+        let is_formatted = |name: &str| {
+            !formatted.is_empty()
+                && (name == "format"
+                    || formatted
+                        .iter()
+                        .any(|(data, count)| *data == name || count.name == name))
+        };
+        let plain = |name: &str, ty: Option<Type>| -> SumStatement {
+            super::ArbitraryItem {
+                name: name.into(),
+                ty,
+                len: None,
+                string: false,
+            }
+            .into()
+        };
+
+        formatted
+            .iter()
+            .map(|(data, count)| {
+                super::ArbitraryFormatted {
+                    name: (*data).into(),
+                    count: count.name.as_str().into(),
+                    count_ty: Type::from_lvl2(count.ty.clone()),
+                }
+                .into()
+            })
+            .chain(self.fields.iter().flat_map(|item| -> Vec<SumStatement> {
+                match item {
+                    StructureItem::Field(Field { name, .. }) if is_formatted(name) => vec![],
+                    StructureItem::Field(Field { name, ty, .. }) => {
+                        vec![plain(name, Some(Type::from_lvl2(ty.clone())))]
+                    }
+                    StructureItem::List(List { name, .. }) if is_described(name) => vec![],
+                    StructureItem::List(List { name, .. }) => vec![plain(name, None)],
+                    // the fields in switches are stored as options, and don't need their types
+                    StructureItem::Switch(_) => output_names(std::slice::from_ref(item))
+                        .iter()
+                        .map(|name| plain(name, None))
+                        .collect(),
+                    _ => vec![],
+                }
+            }))
+            .chain(described.iter().map(
+                |List {
+                     name,
+                     ty,
+                     list_length,
+                     ..
+                 }| {
+                    super::ArbitraryItem {
+                        name: name.clone().into_boxed_str(),
+                        ty: None,
+                        len: Some(cast_to_usize(list_length.to_length_expr(false, true))),
+                        string: matches!(ty, MaybeString::IsAString),
+                    }
+                    .into()
+                },
+            ))
+            .chain(Some(
+                super::ReturnReadStruct {
+                    sname: "Self".into(),
+                    fields: output_names(&self.fields),
+                }
+                .into(),
+            ))
+            .collect()
+    }
+}
+
+/// `impl<'a> arbitrary::Arbitrary<'a> for Name`, whose `arbitrary` method runs the statements.
+#[inline]
+pub fn arbitrary_impl(tyname: &str, statements: Vec<SumStatement>) -> syn::Item {
+    let tyname = Ident::new(tyname, Span::call_site());
+    let statements = statements
+        .into_iter()
+        .flat_map(|s| s.to_syn_statement())
+        .collect::<Vec<_>>();
+
+    syn::parse_quote! {
+        #[cfg(feature = "fuzzing")]
+        impl<'a> arbitrary::Arbitrary<'a> for #tyname {
+            #[inline]
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                #(#statements)*
+            }
+        }
+    }
+}
+
+/// Implement `arbitrary::Arbitrary` on every structure and enumeration, behind the `fuzzing` feature.
+/// Structures with lists whose lengths are described by other fields are given an implementation that
+/// creates the lists with those lengths, so that they can be sent and parsed as they are, and the same
+/// goes for data whose width is given by a `format` field. Everything else derives it.
+#[inline]
+pub fn derive_arbitrary(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| match item {
+        Item::RStruct(rs) => {
+            let described = rs.described_lists();
+            let formatted = rs.formatted_fields();
+            if described.is_empty() && formatted.is_empty() {
+                rs.derives.push(ARBITRARY_DERIVE);
+            } else {
+                let statements = rs.arbitrary_statements(&described, &formatted);
+                rs.traits.push(Trait::Arbitrary(statements));
+            }
+        }
+        Item::REnum(re) => re.derives.push(ARBITRARY_DERIVE),
+        _ => (),
+    });
+}
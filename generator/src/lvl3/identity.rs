@@ -0,0 +1,78 @@
+// MIT/Apache2 License
+
+use super::{Item, Trait};
+use crate::lvl2::{Field, StructureItem};
+use proc_macro2::{Ident, Span};
+
+/// Structures that describe a resource, along with the fields that identify that resource. The rest
+/// of their fields are a snapshot of the resource's state, which may be out of date, so two values
+/// describing the same resource are considered equal even if those fields differ.
+const IDENTITY_FIELDS: &[(&str, &[&str])] = &[
+    ("Visualtype", &["visual_id"]),
+    ("ModeInfo", &["id"]),
+    ("MonitorInfo", &["name"]),
+    ("DeviceInfo", &["device_id"]),
+    ("XiDeviceInfo", &["deviceid"]),
+];
+
+/// `impl PartialEq`, `impl Eq` and `impl Hash` for a structure, which only look at the fields that
+/// identify it.
+#[inline]
+pub fn identity_comparison_impls(tyname: &str, fields: &[Box<str>]) -> Vec<syn::Item> {
+    let tyname = Ident::new(tyname, Span::call_site());
+    let fields: Vec<Ident> = fields
+        .iter()
+        .map(|field| Ident::new(field, Span::call_site()))
+        .collect();
+
+    vec![
+        syn::parse_quote! {
+            impl PartialEq for #tyname {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    #(self.#fields == other.#fields)&&*
+                }
+            }
+        },
+        syn::parse_quote! {
+            impl Eq for #tyname {}
+        },
+        syn::parse_quote! {
+            impl core::hash::Hash for #tyname {
+                #[inline]
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    #(core::hash::Hash::hash(&self.#fields, state);)*
+                }
+            }
+        },
+    ]
+}
+
+/// Replace the derived comparisons of the structures in `IDENTITY_FIELDS` with ones that only
+/// compare and hash the fields that identify them, so that they can be used as keys for the
+/// resources they describe. Only structures that could be hashed as a whole are changed.
+#[inline]
+pub fn compare_by_identity(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let identity = match IDENTITY_FIELDS.iter().find(|(name, _)| *name == &*rs.name) {
+                Some((_, identity)) => identity,
+                None => return,
+            };
+            let has_fields = identity.iter().all(|field| {
+                rs.fields
+                    .iter()
+                    .any(|f| matches!(f, StructureItem::Field(Field { name, .. }) if name == field))
+            });
+            if !has_fields || !rs.derives.contains(&"Hash") {
+                return;
+            }
+
+            rs.derives
+                .retain(|d| !matches!(*d, "PartialEq" | "Eq" | "Hash" | "PartialOrd" | "Ord"));
+            rs.traits.push(Trait::IdentityComparison(
+                identity.iter().map(|field| (*field).into()).collect(),
+            ));
+        }
+    });
+}
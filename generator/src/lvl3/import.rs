@@ -1,12 +1,12 @@
 // MIT/Apache2 License
 
-use super::ToSyn;
+use super::{EmitContext, ToSyn};
 use crate::lvl1::Import;
 use proc_macro2::Span;
 
 impl ToSyn for Import {
     #[inline]
-    fn to_syn_item(self) -> Vec<syn::Item> {
+    fn to_syn_item(self, _ctx: &EmitContext) -> Vec<syn::Item> {
         vec![syn::Item::Use(syn::ItemUse {
             attrs: vec![],
             vis: syn::Visibility::Inherited,
@@ -2,13 +2,12 @@
 
 use super::{
     syn_util::{int_litexpr_int, pub_vis, str_to_pathseg},
-    REnum, RStruct, ToSyn, Type,
+    EmitContext, ErrorDispatcher, EventEnum, OpcodeNames, REnum, RStruct, RUnion, ToSyn, Type,
 };
 use crate::{
-    lvl1::{Import, Typedef},
-    lvl2::{Bitflags, ConstItems, EnumRepr, Item as Lvl2Item, TrueEnum},
+    lvl1::{Import, Typedef, Version},
+    lvl2::{ConstItems, EnumRepr, Item as Lvl2Item, StructSpecial},
 };
-use heck::CamelCase;
 use proc_macro2::Span;
 use std::iter;
 
@@ -18,6 +17,10 @@ pub enum Item {
     Typedef(Typedef),
     RStruct(RStruct),
     REnum(REnum),
+    RUnion(RUnion),
+    EventEnum(EventEnum),
+    ErrorDispatcher(ErrorDispatcher),
+    OpcodeNames(OpcodeNames),
     ConstItem {
         name: Box<str>,
         ty: Type,
@@ -28,12 +31,16 @@ pub enum Item {
 
 impl ToSyn for Item {
     #[inline]
-    fn to_syn_item(self) -> Vec<syn::Item> {
+    fn to_syn_item(self, ctx: &EmitContext) -> Vec<syn::Item> {
         match self {
-            Self::Import(i) => i.to_syn_item(),
-            Self::Typedef(t) => t.to_syn_item(),
-            Self::RStruct(rs) => rs.to_syn_item(),
-            Self::REnum(re) => re.to_syn_item(),
+            Self::Import(i) => i.to_syn_item(ctx),
+            Self::Typedef(t) => t.to_syn_item(ctx),
+            Self::RStruct(rs) => rs.to_syn_item(ctx),
+            Self::REnum(re) => re.to_syn_item(ctx),
+            Self::EventEnum(ee) => ee.to_syn_item(ctx),
+            Self::ErrorDispatcher(ed) => ed.to_syn_item(ctx),
+            Self::OpcodeNames(on) => on.to_syn_item(ctx),
+            Self::RUnion(_) => unreachable!("Unions should be resolved into structures"),
             Self::ConstItem {
                 name,
                 ty,
@@ -86,16 +93,19 @@ impl Item {
         match lvl2 {
             Lvl2Item::Import(i) => vec![Item::Import(i)],
             Lvl2Item::Typedef(t) => vec![Item::Typedef(t)],
+            Lvl2Item::Struct(s) if matches!(s.special, StructSpecial::Union) => {
+                vec![Item::RUnion(RUnion::from(s))]
+            }
             Lvl2Item::Struct(s) => {
                 let (mut rs1, mut rs2) = RStruct::from_prev(s, ext_name);
                 rs1.populate_asb();
+                rs1.populate_slice_accessors();
+                rs1.populate_string_constructor();
                 if let Some(ref mut rs2) = rs2 {
                     rs2.populate_asb();
+                    rs2.populate_slice_accessors();
                 }
-                iter::once(rs1)
-                    .chain(rs2.into_iter())
-                    .map(|rs| Item::RStruct(rs))
-                    .collect()
+                iter::once(rs1).chain(rs2).map(Item::RStruct).collect()
             }
             Lvl2Item::Enum(EnumRepr::ConstItems(ConstItems { items, underlying })) => items
                 .into_iter()
@@ -113,11 +123,21 @@ impl Item {
                 .collect(),
             Lvl2Item::Enum(EnumRepr::Bitflags(bitflags)) => super::bitflags_to_lvl3(bitflags)
                 .into_iter()
-                .map(|r| Item::RStruct(r))
+                .map(Item::RStruct)
                 .collect(),
             Lvl2Item::Enum(EnumRepr::TrueEnum(te)) => vec![Item::REnum(REnum::from(te))],
             Lvl2Item::XidType(xty) => vec![Item::RStruct(RStruct::from(xty))],
-            _ => vec![],
+            Lvl2Item::Version(Version { major, minor }) => {
+                vec![("MAJOR_VERSION", major), ("MINOR_VERSION", minor)]
+                    .into_iter()
+                    .map(|(name, val)| Self::ConstItem {
+                        name: name.into(),
+                        ty: Type::Basic("u32".into()),
+                        val: val.into(),
+                        is_xidtype: false,
+                    })
+                    .collect()
+            }
         }
     }
 }
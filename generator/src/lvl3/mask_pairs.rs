@@ -0,0 +1,80 @@
+// MIT/Apache2 License
+
+use super::{integer_repr, syn_util::doc_attrs, Item, Type};
+use crate::lvl2::{Field, List, MaybeString, StructureItem, Type as Lvl2Type};
+use proc_macro2::{Ident, Span};
+use std::collections::{HashMap, HashSet};
+
+/// `pub fn [list]_by_mask(&self)`, which pairs each item of the list with the bit of the mask that
+/// says it is there.
+#[inline]
+fn mask_pairs(list: &str, mask: &str, elem: &Lvl2Type) -> syn::ImplItem {
+    let docs = doc_attrs(&format!(
+        "Pair each item of `{}` with the bit of `{}` that it stands for, from the lowest bit up.",
+        list, mask
+    ));
+    let method = Ident::new(&format!("{}_by_mask", list), Span::call_site());
+    let elem = Type::from_lvl2(elem.clone()).to_syn_ty();
+    let list = Ident::new(list, Span::call_site());
+    let mask = Ident::new(mask, Span::call_site());
+
+    syn::parse_quote! {
+        #(#docs)*
+        #[inline]
+        pub fn #method(&self) -> impl Iterator<Item = (u32, &#elem)> + '_ {
+            mask_bits(u32::from(self.#mask)).zip(self.#list.iter())
+        }
+    }
+}
+
+/// Add `[list]_by_mask` methods to structures with lists that hold one item for every bit that is
+/// set in an integer field, so that the items don't have to be matched up with the bits by hand.
+#[inline]
+pub fn populate_mask_pairs(items: &mut [Item]) {
+    let typedefs: HashMap<String, String> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Typedef(td) => Some((td.newname.clone(), td.oldname.clone())),
+            _ => None,
+        })
+        .collect();
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let masks: HashSet<&str> = rs
+                .fields
+                .iter()
+                .filter_map(|item| match item {
+                    StructureItem::Field(Field {
+                        name,
+                        ty: Lvl2Type::BasicType(ty),
+                        ..
+                    }) if integer_repr(&typedefs, ty).is_some() => Some(name.as_str()),
+                    _ => None,
+                })
+                .collect();
+
+            let pairs: Vec<syn::ImplItem> = rs
+                .fields
+                .iter()
+                .filter_map(|item| match item {
+                    StructureItem::List(List {
+                        name,
+                        ty: MaybeString::NotAString(elem),
+                        list_length,
+                        ..
+                    }) => {
+                        let mask = list_length.one_count_item()?;
+                        if masks.contains(mask) {
+                            Some(mask_pairs(name, mask, elem))
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                })
+                .collect();
+            rs.other_impl_items.extend(pairs);
+        }
+    });
+}
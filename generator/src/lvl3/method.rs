@@ -1,11 +1,27 @@
 // MIT/Apache2 License
 
 use super::{
-    syn_util::{inliner, pub_vis},
-    Statement, SumStatement, Type,
+    syn_util::{inliner, must_use, pub_vis},
+    EmitContext, Statement, SumStatement, Type,
 };
-use proc_macro2::Span;
-use std::{borrow::Cow, fmt};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::{borrow::Cow, iter};
+
+/// The number of tokens a method's body may hold before it stops being marked `#[inline]`.
+pub const DEFAULT_INLINE_THRESHOLD: usize = 100;
+
+/// Count the tokens in a token stream, including the ones inside of groups.
+#[inline]
+fn token_count(tokens: TokenStream) -> usize {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(g) => 1 + token_count(g.stream()),
+            _ => 1,
+        })
+        .sum()
+}
 
 /// An input parameter for a method.
 #[derive(Debug, Clone)]
@@ -19,6 +35,7 @@ pub struct InputParameter {
 #[derive(Debug, Copy, Clone)]
 pub enum ParameterUsage {
     Owned,
+    MutOwned,
     Ref,
     MutRef,
 }
@@ -28,6 +45,8 @@ pub enum ParameterUsage {
 pub struct Method {
     pub name: Cow<'static, str>,
     pub is_const: bool,
+    /// The reason given by `#[must_use]`, if the result of this method must be used.
+    pub must_use: Option<&'static str>,
     pub self_parameter: Option<ParameterUsage>,
     pub parameters: Vec<InputParameter>,
     pub statements: Vec<SumStatement>,
@@ -46,6 +65,7 @@ impl Method {
         Self {
             name,
             is_const: false,
+            must_use: None,
             self_parameter,
             parameters,
             statements: vec![],
@@ -55,9 +75,24 @@ impl Method {
 
     /// Convert this method to a syn ImplItem.
     #[inline]
-    pub fn to_syn_impl_item(&self, in_trait: bool) -> syn::ImplItem {
+    pub fn to_syn_impl_item(&self, in_trait: bool, ctx: &EmitContext) -> syn::ImplItem {
+        let block = syn::Block {
+            brace_token: Default::default(),
+            stmts: self
+                .statements
+                .iter()
+                .flat_map(|s| s.to_syn_statement())
+                .collect(),
+        };
+
+        // inlining large bodies only makes the crate slower to compile
+        let inline = token_count(block.to_token_stream()) <= ctx.inline_threshold;
+
         syn::ImplItem::Method(syn::ImplItemMethod {
-            attrs: vec![inliner()],
+            attrs: iter::once(inliner())
+                .filter(|_| inline)
+                .chain(self.must_use.map(must_use))
+                .collect(),
             vis: if in_trait {
                 syn::Visibility::Inherited
             } else {
@@ -86,14 +121,7 @@ impl Method {
                     None => syn::ReturnType::Default,
                 },
             },
-            block: syn::Block {
-                brace_token: Default::default(),
-                stmts: self
-                    .statements
-                    .iter()
-                    .flat_map(|s| s.to_syn_statement())
-                    .collect(),
-            },
+            block,
         })
     }
 
@@ -105,15 +133,15 @@ impl Method {
             .map(|c| {
                 syn::FnArg::Receiver(syn::Receiver {
                     attrs: vec![],
-                    reference: if let ParameterUsage::Owned = c {
-                        None
-                    } else {
-                        Some((Default::default(), None))
+                    reference: match c {
+                        ParameterUsage::Owned | ParameterUsage::MutOwned => None,
+                        _ => Some((Default::default(), None)),
                     },
-                    mutability: if let ParameterUsage::MutRef = c {
-                        Some(Default::default())
-                    } else {
-                        None
+                    mutability: match c {
+                        ParameterUsage::MutOwned | ParameterUsage::MutRef => {
+                            Some(Default::default())
+                        }
+                        _ => None,
                     },
                     self_token: Default::default(),
                 })
@@ -125,15 +153,18 @@ impl Method {
                     pat: Box::new(syn::Pat::Ident(syn::PatIdent {
                         attrs: vec![],
                         by_ref: None,
-                        mutability: None,
-                        ident: syn::Ident::new(&*p.name, Span::call_site()),
+                        mutability: match p.usage {
+                            ParameterUsage::MutOwned => Some(Default::default()),
+                            _ => None,
+                        },
+                        ident: syn::Ident::new(&p.name, Span::call_site()),
                         subpat: None,
                     })),
                     colon_token: Default::default(),
                     ty: Box::new({
                         let ty = p.ty.to_syn_ty();
                         match p.usage {
-                            ParameterUsage::Owned => ty,
+                            ParameterUsage::Owned | ParameterUsage::MutOwned => ty,
                             _ => syn::Type::Reference(syn::TypeReference {
                                 and_token: Default::default(),
                                 lifetime: None,
@@ -150,3 +181,25 @@ impl Method {
             .collect()
     }
 }
+
+#[test]
+fn inline_by_the_threshold_of_the_context() {
+    let mut method = Method::new(
+        "size".into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::Basic("usize".into())),
+    );
+    method.statements = vec![super::ExprWrapper(syn::parse_quote! { 4 }).into()];
+    let inlined = |inline_threshold| match method
+        .to_syn_impl_item(false, &EmitContext { inline_threshold })
+    {
+        syn::ImplItem::Method(m) => m.attrs.iter().any(|attr| attr.path.is_ident("inline")),
+        _ => unreachable!(),
+    };
+
+    // one context's threshold doesn't carry over into the next
+    assert!(!inlined(0));
+    assert!(inlined(DEFAULT_INLINE_THRESHOLD));
+    assert!(!inlined(0));
+}
@@ -0,0 +1,69 @@
+// MIT/Apache2 License
+
+use super::{Item, Trait};
+
+/// Remove `Debug` from every structure and enum. The formatting code for thousands
+/// of generated items adds up, and constrained targets rarely print any of them.
+#[inline]
+pub fn strip_debug_derives(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| match item {
+        Item::RStruct(rs) => {
+            rs.derives.retain(|d| *d != "Debug");
+            rs.traits
+                .retain(|t| !matches!(t, Trait::XidDebug | Trait::ElidedDebug(..)));
+        }
+        Item::REnum(re) => re.derives.retain(|d| *d != "Debug"),
+        _ => (),
+    });
+}
+
+#[test]
+fn strip_debug_from_derive_attrs() {
+    use super::{Asb, RStruct, ToSyn};
+    use crate::lvl2::{Field, StructureItem, Type};
+    use quote::ToTokens;
+
+    // the derive attributes written for a structure, with and without the minimal derives
+    let derive_attrs = |minimal: bool| -> Vec<String> {
+        let mut items = vec![Item::RStruct(RStruct {
+            name: "Point".into(),
+            docs: None,
+            derives: vec!["Clone", "Debug", "Default"],
+            is_transparent: false,
+            is_repr_c: false,
+            defaults: vec![],
+            fields: vec![StructureItem::Field(Field {
+                name: "x".to_string(),
+                ty: Type::BasicType("i16".into()),
+                ..Default::default()
+            })],
+            methods: vec![],
+            other_impl_items: vec![],
+            traits: vec![],
+            asb: Asb::none(),
+            shared_asb: None,
+        })];
+        if minimal {
+            strip_debug_derives(&mut items);
+        }
+
+        items
+            .into_iter()
+            .flat_map(|item| item.to_syn_item(&Default::default()))
+            .find_map(|item| match item {
+                syn::Item::Struct(s) => Some(s.attrs),
+                _ => None,
+            })
+            .unwrap()
+            .into_iter()
+            .filter(|attr| attr.path.is_ident("derive"))
+            .map(|attr| attr.into_token_stream().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        derive_attrs(false),
+        ["# [derive (Clone , Debug , Default ,)]"]
+    );
+    assert_eq!(derive_attrs(true), ["# [derive (Clone , Default ,)]"]);
+}
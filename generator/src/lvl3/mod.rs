@@ -2,38 +2,107 @@
 
 mod asb;
 mod bitflags;
+mod builder;
+mod comparison;
+mod constructor;
+mod continuation;
+mod copy;
+mod defaults;
+mod diff;
+mod elided_debug;
+mod enum_accessor;
+mod error_dispatch;
+mod event_enum;
 mod expr;
 mod field;
+mod fold_sizes;
+mod fuzzing;
+mod identity;
 mod import;
 mod item;
+mod mask_pairs;
 mod method;
+mod minimal;
+mod move_only;
+mod opcode_names;
+mod pod;
 mod renum;
+mod reply_or_error;
 mod rstruct;
 mod rtrait;
+mod runion;
+mod semantic_eq;
+mod serialize;
+mod shared_asb;
 mod statement;
+mod stream;
+mod strict;
 mod switch;
 mod ty;
 mod type_alias;
+mod validate;
+mod visitor;
+mod wire_fields;
 mod xidtype;
 
 pub mod syn_util;
 
 pub use asb::*;
 pub use bitflags::*;
+pub use builder::*;
+pub use comparison::*;
+pub use constructor::*;
+pub use continuation::*;
+pub use copy::*;
+pub use defaults::*;
+pub use diff::*;
+pub use elided_debug::*;
+pub use enum_accessor::*;
+pub use error_dispatch::*;
+pub use event_enum::*;
 pub use expr::*;
-pub use field::*;
-pub use import::*;
+pub use fold_sizes::*;
+pub use fuzzing::*;
+pub use identity::*;
 pub use item::*;
+pub use mask_pairs::*;
 pub use method::*;
+pub use minimal::*;
+pub use move_only::*;
+pub use opcode_names::*;
+pub use pod::*;
 pub use renum::*;
+pub use reply_or_error::*;
 pub use rstruct::*;
 pub use rtrait::*;
+pub use runion::*;
+pub use semantic_eq::*;
+pub use serialize::*;
+pub use shared_asb::*;
 pub use statement::*;
-pub use switch::*;
+pub use stream::*;
+pub use strict::*;
 pub use ty::*;
-pub use type_alias::*;
-pub use xidtype::*;
+pub use validate::*;
+pub use visitor::*;
+pub use wire_fields::*;
+
+/// The settings that items follow as they are converted to syn items.
+#[derive(Debug, Copy, Clone)]
+pub struct EmitContext {
+    /// The number of tokens a method's body may hold and still be marked `#[inline]`.
+    pub inline_threshold: usize,
+}
+
+impl Default for EmitContext {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inline_threshold: DEFAULT_INLINE_THRESHOLD,
+        }
+    }
+}
 
 pub trait ToSyn {
-    fn to_syn_item(self) -> Vec<syn::Item>;
+    fn to_syn_item(self, ctx: &EmitContext) -> Vec<syn::Item>;
 }
@@ -0,0 +1,98 @@
+// MIT/Apache2 License
+
+use super::{
+    syn_util::{item_field, str_to_exprpath, str_to_path},
+    Item, Method, ParameterUsage, RStruct, Trait, Type,
+};
+use crate::lvl2::{Field, List, MaybeString, StructureItem, Switch, Type as Lvl2Type};
+use proc_macro2::Span;
+
+/// Tell whether a list holds a buffer of bytes whose length isn't known ahead of time.
+#[inline]
+pub fn is_byte_buffer(item: &StructureItem) -> bool {
+    match item {
+        StructureItem::List(List {
+            ty: MaybeString::NotAString(Lvl2Type::BasicType(name)),
+            list_length,
+            ..
+        }) => {
+            matches!(
+                name.as_ref(),
+                "u8" | "i8" | "Card8" | "Int8" | "Byte" | "Char" | "c_char" | "Void"
+            ) && list_length.fixed_size().is_none()
+        }
+        _ => false,
+    }
+}
+
+/// Collect the names of the fields of a structure, including those in switch cases.
+#[inline]
+fn field_names<'a>(items: &'a [StructureItem], names: &mut Vec<&'a str>) {
+    items.iter().for_each(|item| match item {
+        StructureItem::Field(Field { name, .. })
+        | StructureItem::List(List { name, .. })
+        | StructureItem::Fd { name } => names.push(name),
+        StructureItem::Switch(Switch { cases, .. }) => {
+            cases.iter().for_each(|c| field_names(&c.items, names))
+        }
+        _ => (),
+    });
+}
+
+/// `Self { a: self.a.clone(), .. }`
+#[inline]
+fn deep_clone(rs: &RStruct) -> Method {
+    let mut names = vec![];
+    field_names(&rs.fields, &mut names);
+
+    let mut method = Method::new(
+        "deep_clone".into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::Basic("Self".into())),
+    );
+    method.statements = vec![super::ExprWrapper(syn::Expr::Struct(syn::ExprStruct {
+        attrs: vec![],
+        path: str_to_path("Self"),
+        brace_token: Default::default(),
+        fields: names
+            .into_iter()
+            .map(|name| syn::FieldValue {
+                attrs: vec![],
+                member: syn::Member::Named(syn::Ident::new(name, Span::call_site())),
+                colon_token: Some(Default::default()),
+                expr: syn::Expr::MethodCall(syn::ExprMethodCall {
+                    attrs: vec![],
+                    receiver: Box::new(item_field(str_to_exprpath("self"), name)),
+                    dot_token: Default::default(),
+                    method: syn::Ident::new("clone", Span::call_site()),
+                    turbofish: None,
+                    paren_token: Default::default(),
+                    args: Default::default(),
+                }),
+            })
+            .collect(),
+        dot2_token: None,
+        rest: None,
+    }))
+    .into()];
+    method
+}
+
+/// Remove `Clone` from the derives of every request that carries a buffer of bytes, which may be
+/// megabytes long for requests like `PutImage`. A `deep_clone` method is added in its place, so
+/// that copying the buffer is done on purpose.
+#[inline]
+pub fn strip_large_clones(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if rs.traits.iter().any(|t| matches!(t, Trait::Request(..)))
+                && rs.fields.iter().any(is_byte_buffer)
+            {
+                rs.derives.retain(|d| *d != "Clone");
+                let method = deep_clone(rs);
+                rs.methods.push(method);
+            }
+        }
+    });
+}
@@ -0,0 +1,120 @@
+// MIT/Apache2 License
+
+use super::{
+    syn_util::{doc_attrs, int_litexpr_int},
+    EmitContext, Item, ToSyn, Trait,
+};
+use proc_macro2::{Ident, Span};
+
+/// Tables of the names of the events, errors and requests of a namespace, by their opcodes.
+#[derive(Debug, Default)]
+pub struct OpcodeNames {
+    pub events: Vec<(u64, Box<str>)>,
+    pub generic_events: Vec<(u64, Box<str>)>,
+    pub errors: Vec<(u64, Box<str>)>,
+    pub requests: Vec<(u64, Box<str>)>,
+}
+
+/// Gather the opcodes of the events, errors and requests in a series of items into tables of their
+/// names. The names are the ones the protocol uses, without the suffix of the structure.
+#[inline]
+pub fn opcode_names(items: &[Item]) -> Item {
+    let mut names = OpcodeNames::default();
+    items.iter().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let name = |suffix: &str| -> Box<str> {
+                rs.name.strip_suffix(suffix).unwrap_or(&rs.name).into()
+            };
+            rs.traits.iter().for_each(|t| match t {
+                Trait::Event(opcode, false) => names.events.push((*opcode, name("Event"))),
+                Trait::Event(opcode, true) => names.generic_events.push((*opcode, name("Event"))),
+                Trait::Error(opcode) => names.errors.push((*opcode, name("Error"))),
+                Trait::Request(opcode, ..) => names.requests.push((*opcode, name("Request"))),
+                _ => (),
+            });
+        }
+    });
+
+    names.events.sort();
+    names.generic_events.sort();
+    names.errors.sort();
+    names.requests.sort();
+    Item::OpcodeNames(names)
+}
+
+/// `pub const [TABLE]: &[(ty, &str)]` and `pub fn [lookup](opcode: ty) -> Option<&'static str>`,
+/// which finds the name of an opcode in the table.
+#[inline]
+fn name_table(
+    table: &str,
+    lookup: &str,
+    ty: &str,
+    what: &str,
+    names: &[(u64, Box<str>)],
+) -> Vec<syn::Item> {
+    let table_docs = doc_attrs(&format!(
+        "The names of the {} in this namespace, sorted by their opcodes. {}",
+        what,
+        match what {
+            "requests" => "The requests of an extension are numbered by their minor opcodes.",
+            _ => "The opcodes of an extension count up from the first one the server gives it.",
+        }
+    ));
+    let lookup_docs = doc_attrs(&format!(
+        "The name of the {} with this opcode, if there is one in this namespace. This is meant \
+         for logging and error messages.",
+        what.trim_end_matches('s')
+    ));
+    let table = Ident::new(table, Span::call_site());
+    let lookup = Ident::new(lookup, Span::call_site());
+    let ty = Ident::new(ty, Span::call_site());
+    let pairs = names.iter().map(|(opcode, name)| -> syn::Expr {
+        let opcode = int_litexpr_int(opcode);
+        let name = &**name;
+        syn::parse_quote! { (#opcode, #name) }
+    });
+
+    vec![
+        syn::parse_quote! {
+            #(#table_docs)*
+            pub const #table: &[(#ty, &str)] = &[#(#pairs),*];
+        },
+        syn::parse_quote! {
+            #(#lookup_docs)*
+            #[inline]
+            pub fn #lookup(opcode: #ty) -> Option<&'static str> {
+                name_of_opcode(#table, opcode)
+            }
+        },
+    ]
+}
+
+impl ToSyn for OpcodeNames {
+    #[inline]
+    fn to_syn_item(self, _ctx: &EmitContext) -> Vec<syn::Item> {
+        name_table("EVENT_NAMES", "event_name", "u8", "events", &self.events)
+            .into_iter()
+            .chain(name_table(
+                "GENERIC_EVENT_NAMES",
+                "generic_event_name",
+                "u16",
+                "generic events",
+                &self.generic_events,
+            ))
+            .chain(name_table(
+                "ERROR_NAMES",
+                "error_name",
+                "u8",
+                "errors",
+                &self.errors,
+            ))
+            .chain(name_table(
+                "REQUEST_NAMES",
+                "request_name",
+                "u8",
+                "requests",
+                &self.requests,
+            ))
+            .collect()
+    }
+}
@@ -0,0 +1,85 @@
+// MIT/Apache2 License
+
+use super::{primitive_size, Item, RStruct, Trait};
+use crate::lvl2::{Field, StructureItem, Type as Lvl2Type};
+
+/// The traits derived on structures that can be cast to and from their bytes.
+const POD_DERIVES: &[&str] = &["bytemuck::Pod", "bytemuck::Zeroable"];
+
+/// The size of a field, if it is a number that is laid out the same way in Rust as on the wire.
+/// Booleans can't hold every byte, and arrays aren't `Pod` at every length, so they don't count.
+#[inline]
+fn pod_field_size(ty: &Lvl2Type) -> Option<usize> {
+    match ty {
+        Lvl2Type::BasicType(name) if name != "bool" => primitive_size(ty),
+        _ => None,
+    }
+}
+
+impl RStruct {
+    /// Tell whether a `#[repr(C)]` version of this structure has the same layout as its bytes. Every
+    /// item has to be a number at an offset that is a multiple of its size, so Rust never pads between
+    /// them; padding, lengths and lists aren't stored in the structure, so they rule it out.
+    #[inline]
+    fn has_wire_layout(&self) -> bool {
+        // `Copy` needs `Clone`, which the largest requests don't have
+        if self.is_transparent
+            || self.fields.is_empty()
+            || !self.derives.contains(&"Clone")
+            || self
+                .traits
+                .iter()
+                .any(|t| matches!(t, Trait::Xid | Trait::BitflagsNot(_)))
+        {
+            return false;
+        }
+
+        let sizes = self
+            .fields
+            .iter()
+            .map(|item| match item {
+                StructureItem::Field(Field { ty, .. }) => pod_field_size(ty),
+                _ => None,
+            })
+            .collect::<Option<Vec<usize>>>();
+        let sizes = match sizes {
+            Some(sizes) => sizes,
+            None => return false,
+        };
+
+        let mut offset = 0;
+        for size in &sizes {
+            if offset % size != 0 {
+                return false;
+            }
+            offset += size;
+        }
+
+        // there can't be padding at the end of the structure either
+        let largest = sizes.iter().copied().max().unwrap_or(1);
+        offset % largest == 0 && self.asb.align.is_none_or(|align| offset % align == 0)
+    }
+}
+
+/// Mark every structure whose layout can match its bytes exactly as `#[repr(C)]` and `Copy`, and derive
+/// `bytemuck::Pod` and `bytemuck::Zeroable` on it behind the `pod` feature. These structures can be cast
+/// to and from bytes without being parsed.
+#[inline]
+pub fn derive_pod(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if rs.has_wire_layout() {
+                rs.is_repr_c = true;
+                if !rs.derives.contains(&"Copy") {
+                    let posn = rs
+                        .derives
+                        .iter()
+                        .position(|d| *d == "Clone")
+                        .map_or(0, |p| p + 1);
+                    rs.derives.insert(posn, "Copy");
+                }
+                rs.derives.extend(POD_DERIVES);
+            }
+        }
+    });
+}
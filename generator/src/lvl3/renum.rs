@@ -1,8 +1,8 @@
 // MIT/Apache2 License
 
 use super::{
-    syn_util::{int_litexpr_int, pub_vis, str_to_path},
-    Asb, SizeSumPart, Statement, SumOfSizes, ToSyn, Trait, Type,
+    syn_util::{derive_attrs, int_litexpr_int, pub_vis, str_to_path},
+    Asb, EmitContext, SizeSumPart, SumOfSizes, ToSyn, Trait, Type,
 };
 use crate::lvl2::TrueEnum;
 use proc_macro2::{Span, TokenStream};
@@ -14,32 +14,23 @@ pub struct REnum {
     pub name: Box<str>,
     pub underlying: Cow<'static, str>,
     pub variants: Box<[(Box<str>, i64)]>,
+    pub derives: Vec<&'static str>,
     pub asb: Asb,
 }
 
 impl ToSyn for REnum {
     #[inline]
-    fn to_syn_item(self) -> Vec<syn::Item> {
+    fn to_syn_item(self, ctx: &EmitContext) -> Vec<syn::Item> {
         let s = syn::Item::Enum(syn::ItemEnum {
-            attrs: vec![
-                syn::Attribute {
-                    pound_token: Default::default(),
-                    style: syn::AttrStyle::Outer,
-                    bracket_token: Default::default(),
-                    path: str_to_path("repr"),
-                    tokens: TokenStream::from_str(&format!("({})", &self.underlying)).unwrap(),
-                },
-                syn::Attribute {
-                    pound_token: Default::default(),
-                    style: syn::AttrStyle::Outer,
-                    bracket_token: Default::default(),
-                    path: str_to_path("derive"),
-                    tokens: TokenStream::from_str(&format!(
-                        "(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)"
-                    ))
-                    .unwrap(),
-                },
-            ],
+            attrs: iter::once(syn::Attribute {
+                pound_token: Default::default(),
+                style: syn::AttrStyle::Outer,
+                bracket_token: Default::default(),
+                path: str_to_path("repr"),
+                tokens: TokenStream::from_str(&format!("({})", &self.underlying)).unwrap(),
+            })
+            .chain(derive_attrs(&self.derives))
+            .collect(),
             vis: pub_vis(),
             enum_token: Default::default(),
             ident: syn::Ident::new(&self.name, Span::call_site()),
@@ -48,10 +39,10 @@ impl ToSyn for REnum {
 
             variants: self
                 .variants
-                .into_iter()
+                .iter()
                 .map(|(name, value)| syn::Variant {
                     attrs: vec![],
-                    ident: syn::Ident::new(&name, Span::call_site()),
+                    ident: syn::Ident::new(name, Span::call_site()),
                     fields: syn::Fields::Unit,
                     discriminant: Some((Default::default(), int_litexpr_int(value))),
                 })
@@ -59,11 +50,17 @@ impl ToSyn for REnum {
         });
 
         let name = self.name.clone();
-        let asb = self.asb.to_syn_item(&name);
+        let asb = self.asb.to_syn_item(&name, ctx);
         iter::once(s)
-            .chain(asb.into_iter())
+            .chain(asb)
+            .chain(
+                Trait::EnumDefault(self.variants.first().unwrap().0.clone())
+                    .to_syn_item(&self.name, ctx),
+            )
+            .chain(Trait::FromEnum(self.name.clone()).to_syn_item(&self.underlying, ctx))
             .chain(
-                Trait::EnumDefault(self.variants.get(0).unwrap().0.clone()).to_syn_item(&self.name),
+                Trait::EnumTryFrom(self.underlying.clone(), self.variants.clone())
+                    .to_syn_item(&self.name, ctx),
             )
             .collect()
     }
@@ -95,7 +92,6 @@ impl From<TrueEnum> for REnum {
         .into()];
 
         let from_bytes_stmts = vec![super::MatchBytesToEnum {
-            name: name.clone(),
             underlying: underlying.clone(),
             variants: variants.clone(),
         }
@@ -105,12 +101,23 @@ impl From<TrueEnum> for REnum {
             name,
             underlying: underlying.clone(),
             variants,
+            derives: vec![
+                "Debug",
+                "Copy",
+                "Clone",
+                "PartialEq",
+                "Eq",
+                "PartialOrd",
+                "Ord",
+                "Hash",
+            ],
             asb: Asb {
                 is_none: false,
                 as_bytes_stmts,
                 from_bytes_stmts,
-                fd_getting: None,
+                fds: vec![],
                 size: SumOfSizes(vec![SizeSumPart::SizeofType(Type::Basic(underlying))]),
+                align: None,
             },
         }
     }
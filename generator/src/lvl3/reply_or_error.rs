@@ -0,0 +1,55 @@
+// MIT/Apache2 License
+
+use super::{
+    syn_util::doc_attrs, EmitContext, InputParameter, Item, Method, ParameterUsage, Trait, Type,
+};
+
+/// Create a `parse_reply_or_error` function that reads either the reply to a request or the error that
+/// was sent in its place.
+#[inline]
+fn reply_or_error_parser(reply: Type, ctx: &EmitContext) -> syn::ImplItem {
+    let mut method = Method::new(
+        "parse_reply_or_error".into(),
+        None,
+        vec![InputParameter {
+            name: "bytes".into(),
+            ty: Type::Slice(Box::new(Type::Basic("u8".into()))),
+            usage: ParameterUsage::Ref,
+        }],
+        Some(Type::ParseRes(Box::new(Type::ResWith(
+            Box::new(reply),
+            Box::new(Type::Basic("XError".into())),
+        )))),
+    );
+    method.statements =
+        vec![super::ExprWrapper(syn::parse_quote! { parse_reply_or_error(bytes) }).into()];
+
+    let mut item = method.to_syn_impl_item(false, ctx);
+    if let syn::ImplItem::Method(ref mut m) = item {
+        m.attrs.splice(
+            0..0,
+            doc_attrs(
+                "Read what the server sent in response to this request, which is either its reply \
+                 or an error.\nThe response type in the first byte tells the two apart.",
+            ),
+        );
+    }
+    item
+}
+
+/// Give every request that has a reply a `parse_reply_or_error` function.
+#[inline]
+pub fn populate_reply_or_error_parsers(items: &mut [Item], ctx: &EmitContext) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let reply = rs.traits.iter().find_map(|t| match t {
+                Trait::Request(_, Type::Tuple(_), ..) => None,
+                Trait::Request(_, reply, ..) => Some(reply.clone()),
+                _ => None,
+            });
+            if let Some(reply) = reply {
+                rs.other_impl_items.push(reply_or_error_parser(reply, ctx));
+            }
+        }
+    });
+}
@@ -1,35 +1,47 @@
 // MIT/Apache2 License
 
 use super::{
-    syn_util::{derive_attrs, pub_vis, repr_transparent, str_to_exprpath, str_to_ty},
-    Asb, Method, SizeSumPart, Statement, SumOfSizes, SumStatement, ToSyn, Trait, Type,
+    protocol_defaults,
+    syn_util::{
+        derive_attrs, doc_attrs, int_litexpr_int, item_field, must_use, non_exhaustive, pub_vis,
+        repr_c, repr_transparent, str_to_exprpath, str_to_path, str_to_ty,
+    },
+    walk_items, Asb, EmitContext, InputParameter, Method, ParameterUsage, SharedAsb, SizeSumPart,
+    StructureItemVisitor, SumOfSizes, SumStatement, ToSyn, Trait, Type,
 };
 use crate::lvl2::{
-    safe_name, Expression, Field, List, MaybeString, Struct as Lvl2Struct, StructSpecial,
-    StructureItem, Type as Lvl2Type, UseCondition,
+    ConditionVariant, Expression, ExpressionItem, Field, List, MaybeString, Struct as Lvl2Struct,
+    StructSpecial, StructureItem, Switch, Type as Lvl2Type, UseCondition,
 };
 use proc_macro2::Span;
 use std::{
-    collections::HashMap,
-    fmt,
-    hash::{Hash, Hasher},
-    iter, mem,
-    ops::Deref,
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt, iter, mem,
     rc::Rc,
+    slice,
 };
-use tinyvec::ArrayVec;
+
+/// The reason given for requests being `#[must_use]`.
+pub const REQUEST_MUST_USE: &str = "requests do nothing unless sent to the server";
 
 /// Rust structure.
 pub struct RStruct {
     pub name: Box<str>,
+    pub docs: Option<Box<str>>,
     pub derives: Vec<&'static str>,
     pub is_transparent: bool,
+    /// Whether the structure is laid out like a C structure, so that it matches its bytes.
+    pub is_repr_c: bool,
+    /// The fields that the protocol gives a value other than zero by default, and those values.
+    pub defaults: Vec<(Box<str>, i128)>,
     pub fields: Vec<StructureItem>,
     pub methods: Vec<Method>,
     pub other_impl_items: Vec<syn::ImplItem>,
     pub traits: Vec<Trait>,
-    pub fds: Vec<String>,
     pub asb: Asb,
+    /// The byte sequence implementation, if it is shared with other structures.
+    pub shared_asb: Option<SharedAsb>,
 }
 
 impl fmt::Debug for RStruct {
@@ -50,80 +62,1117 @@ impl fmt::Debug for RStruct {
 
         f.debug_struct("RStruct")
             .field("name", &self.name)
+            .field("docs", &self.docs)
             .field("derives", &self.derives)
             .field("is_transparent", &self.is_transparent)
+            .field("is_repr_c", &self.is_repr_c)
+            .field("defaults", &self.defaults)
             .field("fields", &self.fields)
             .field("methods", &self.methods)
             .field("other_impl_items", &Filler(self.other_impl_items.len()))
             .field("traits", &self.traits)
-            .field("fds", &self.fds)
             .field("asb", &self.asb)
+            .field("shared_asb", &self.shared_asb)
             .finish()
     }
 }
 
 #[inline]
 fn cond_vars(
-    condition: &Option<Rc<UseCondition>>,
+    condition: &Rc<UseCondition>,
     conds: &mut HashMap<Rc<Expression>, Box<str>>,
     last_cond_index: &mut usize,
     use_self: bool,
-) -> (Option<(Rc<UseCondition>, Box<str>)>, Option<SumStatement>) {
-    match condition {
-        None => (None, None),
-        Some(condition) => {
-            if let Some(condname) = conds.get(&condition.expr) {
-                (Some((condition.clone(), condname.clone())), None)
-            } else {
-                let condname = format!("cond{}", *last_cond_index).into_boxed_str();
-                *last_cond_index += 1;
-                conds.insert(condition.expr.clone(), condname.clone());
-                (
-                    Some((condition.clone(), condname.clone())),
-                    Some(
-                        super::InitializeCondition {
-                            name: condname,
-                            expression: condition.expr.clone(),
-                            has_self: use_self,
+    optional: &[Box<str>],
+) -> (Box<str>, Option<SumStatement>) {
+    if let Some(condname) = conds.get(&condition.expr) {
+        (condname.clone(), None)
+    } else {
+        let condname = format!("cond{}", *last_cond_index).into_boxed_str();
+        *last_cond_index += 1;
+        conds.insert(condition.expr.clone(), condname.clone());
+        (
+            condname.clone(),
+            Some(
+                super::InitializeCondition {
+                    name: condname,
+                    expression: condition.expr.clone(),
+                    has_self: use_self,
+                    optional: optional
+                        .iter()
+                        .filter(|o| {
+                            condition
+                                .expr
+                                .iter()
+                                .any(|i| matches!(i, ExpressionItem::FieldRef(f) if **o == *f))
+                        })
+                        .cloned()
+                        .collect(),
+                }
+                .into(),
+            ),
+        )
+    }
+}
+
+/// The names of the items that end up as fields in the structure, including those in switch cases.
+#[inline]
+pub fn output_names(items: &[StructureItem]) -> Vec<Cow<'static, str>> {
+    items
+        .iter()
+        .flat_map(|f| match f {
+            StructureItem::Field(Field { name, .. }) => vec![name.clone().into()],
+            StructureItem::List(List { name, .. }) => vec![name.clone().into()],
+            StructureItem::Switch(Switch { cases, .. }) => {
+                cases.iter().flat_map(|c| output_names(&c.items)).collect()
+            }
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// The names of the fields that only appear in switch cases, and are therefore wrapped in `Option`s.
+#[inline]
+fn optional_fields(items: &[StructureItem]) -> Vec<Box<str>> {
+    items
+        .iter()
+        .flat_map(|f| match f {
+            StructureItem::Switch(Switch { cases, .. }) => cases
+                .iter()
+                .flat_map(|c| {
+                    c.items
+                        .iter()
+                        .filter_map(|f| match f {
+                            StructureItem::Field(Field { name, .. }) => {
+                                Some(name.clone().into_boxed_str())
+                            }
+                            _ => None,
+                        })
+                        .chain(optional_fields(&c.items))
+                })
+                .collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// The names of the file descriptors in a series of structure items, in order.
+#[inline]
+pub fn fd_names(items: &[StructureItem]) -> Vec<Box<str>> {
+    items
+        .iter()
+        .flat_map(|f| match f {
+            StructureItem::Fd { name } => vec![name.clone().into_boxed_str()],
+            StructureItem::Switch(Switch { cases, .. }) => {
+                cases.iter().flat_map(|c| fd_names(&c.items)).collect()
+            }
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// Collects the sizes of a series of structure items.
+#[derive(Default)]
+struct SizeVisitor {
+    parts: Vec<SizeSumPart>,
+}
+
+impl StructureItemVisitor for SizeVisitor {
+    #[inline]
+    fn visit_field(&mut self, Field { name, .. }: &Field) {
+        self.parts
+            .push(SizeSumPart::SizeofField(name.clone().into_boxed_str()));
+    }
+
+    #[inline]
+    fn visit_padding(&mut self, bytes: usize) {
+        self.parts.push(SizeSumPart::Bytes(bytes));
+    }
+
+    #[inline]
+    fn visit_align_pad(&mut self, align: usize) {
+        self.parts.push(SizeSumPart::AlignTo(align));
+    }
+
+    #[inline]
+    fn visit_len_slot(&mut self, _owning_list: &str, ty: &Lvl2Type) {
+        self.parts
+            .push(SizeSumPart::SizeofType(Type::from_lvl2(ty.clone())));
+    }
+
+    #[inline]
+    fn visit_list(
+        &mut self,
+        List {
+            name,
+            ty,
+            padding,
+            element_padding,
+            ..
+        }: &List,
+    ) {
+        self.parts.push(SizeSumPart::ListTimesSize(
+            name.clone().into_boxed_str(),
+            ty.clone(),
+            *padding,
+            *element_padding,
+        ));
+    }
+
+    #[inline]
+    fn visit_switch(&mut self, Switch { cases, .. }: &Switch) {
+        cases.iter().for_each(|c| walk_items(&c.items, self));
+    }
+
+    // file descriptors are not a part of the byte sequence
+    #[inline]
+    fn visit_fd(&mut self, _name: &str) {}
+}
+
+/// The size of a primitive type, if this is one.
+#[inline]
+pub fn primitive_size(ty: &Lvl2Type) -> Option<usize> {
+    match ty {
+        Lvl2Type::BasicType(name) => match name.as_ref() {
+            "u8" | "i8" | "bool" | "Card8" | "Int8" | "Byte" | "Char" | "c_char" => Some(1),
+            "u16" | "i16" | "Card16" | "Int16" => Some(2),
+            "u32" | "i32" | "f32" | "Card32" | "Int32" | "Float" => Some(4),
+            "u64" | "i64" | "f64" | "Card64" | "Int64" | "Double" => Some(8),
+            _ => None,
+        },
+        Lvl2Type::Array(name, len) => {
+            Some(primitive_size(&Lvl2Type::BasicType(name.clone()))? * (*len as usize))
+        }
+    }
+}
+
+/// The size of a series of structure items, if it only contains primitives.
+#[inline]
+fn fixed_size(items: &[StructureItem]) -> Option<usize> {
+    items.iter().try_fold(0, |sum, item| {
+        Some(
+            sum + match item {
+                StructureItem::Field(Field { ty, .. }) | StructureItem::LenSlot { ty, .. } => {
+                    primitive_size(ty)?
+                }
+                StructureItem::Padding { bytes } => *bytes,
+                StructureItem::AlignPad { align } => (align - sum % align) % align,
+                StructureItem::Fd { .. } => 0,
+                StructureItem::List(_) | StructureItem::Switch(_) => return None,
+            },
+        )
+    })
+}
+
+/// The `FIXED_SIZE` associated constant, for structures whose size never changes.
+#[inline]
+pub fn fixed_size_const(size: usize) -> syn::ImplItem {
+    syn::ImplItem::Const(syn::ImplItemConst {
+        attrs: doc_attrs("The size of this structure in bytes, which never changes."),
+        vis: pub_vis(),
+        defaultness: None,
+        const_token: Default::default(),
+        ident: syn::Ident::new("FIXED_SIZE", Span::call_site()),
+        colon_token: Default::default(),
+        ty: str_to_ty("usize"),
+        eq_token: Default::default(),
+        expr: int_litexpr_int(size),
+        semi_token: Default::default(),
+    })
+}
+
+/// The minor opcode of an extension request, if this is one.
+#[inline]
+fn minor_opcode(traits: &[Trait]) -> Option<u64> {
+    traits.iter().find_map(|t| match t {
+        Trait::Request(opcode, _, Some(_), ..) => Some(*opcode),
+        _ => None,
+    })
+}
+
+/// The response type that every generic event is sent with.
+pub const GENERIC_EVENT: u64 = 35;
+
+/// The `RESPONSE_TYPE` associated constant, for events.
+#[inline]
+fn response_type_const(response_type: u64, in_extension: bool) -> syn::ImplItem {
+    syn::ImplItem::Const(syn::ImplItemConst {
+        attrs: doc_attrs(if in_extension {
+            "The response type of this event. This is relative to the first event of the \
+             extension, which is assigned by the server when the extension is queried."
+        } else {
+            "The response type of this event."
+        }),
+        vis: pub_vis(),
+        defaultness: None,
+        const_token: Default::default(),
+        ident: syn::Ident::new("RESPONSE_TYPE", Span::call_site()),
+        colon_token: Default::default(),
+        ty: str_to_ty("u8"),
+        eq_token: Default::default(),
+        expr: int_litexpr_int(response_type),
+        semi_token: Default::default(),
+    })
+}
+
+/// `const fn response_type(&self) -> u8 { Self::RESPONSE_TYPE }`
+#[inline]
+fn response_type_method() -> Method {
+    let mut method = Method::new(
+        "response_type".into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::Basic("u8".into())),
+    );
+    method.is_const = true;
+    method.statements = vec![super::ExprWrapper(str_to_exprpath("Self::RESPONSE_TYPE")).into()];
+    method
+}
+
+/// `const fn size(&self) -> usize { Self::FIXED_SIZE }`, which shadows `AsByteSequence::size` so that
+/// the size of a fixed-size structure can be used in const contexts.
+#[inline]
+fn fixed_size_method() -> Method {
+    let mut method = Method::new(
+        "size".into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::Basic("usize".into())),
+    );
+    method.is_const = true;
+    method.statements = vec![super::ExprWrapper(str_to_exprpath("Self::FIXED_SIZE")).into()];
+    method
+}
+
+/// `const fn sequence(&self) -> u16 { self.sequence }`
+#[inline]
+fn sequence_method() -> Method {
+    let mut method = Method::new(
+        "sequence".into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::Basic("u16".into())),
+    );
+    method.is_const = true;
+    method.statements =
+        vec![super::ExprWrapper(item_field(str_to_exprpath("self"), "sequence")).into()];
+    method
+}
+
+/// The size of every event that isn't a generic event, according to the protocol.
+const EVENT_SIZE: usize = 32;
+
+/// The size of every error, according to the protocol. The XML leaves out the padding at the end of
+/// errors, so their fields only have to fit within this size.
+const ERROR_SIZE: usize = 32;
+
+/// The size that the protocol expects a structure to be.
+#[derive(Copy, Clone, Debug)]
+enum ExpectedSize {
+    /// The structure is exactly this many bytes long.
+    Exactly(usize),
+    /// The structure is padded out to this many bytes, so it may be shorter.
+    AtMost(usize),
+}
+
+/// The size that the protocol expects a structure with these traits to be, if it expects one.
+#[inline]
+fn expected_size(traits: &[Trait]) -> Option<ExpectedSize> {
+    traits.iter().find_map(|t| match t {
+        Trait::Event(_, false) => Some(ExpectedSize::Exactly(EVENT_SIZE)),
+        Trait::Error(_) => Some(ExpectedSize::AtMost(ERROR_SIZE)),
+        _ => None,
+    })
+}
+
+/// A constant that fails to compile unless the `FIXED_SIZE` of the structure is the size that the
+/// protocol expects it to be.
+#[inline]
+fn size_assertion(name: &str, expected: ExpectedSize) -> syn::Item {
+    let name = syn::Ident::new(name, Span::call_site());
+    const_assertion(match expected {
+        ExpectedSize::Exactly(size) => {
+            let size = proc_macro2::Literal::usize_unsuffixed(size);
+            quote::quote! { #name::FIXED_SIZE == #size }
+        }
+        ExpectedSize::AtMost(size) => {
+            let size = proc_macro2::Literal::usize_unsuffixed(size);
+            quote::quote! { #name::FIXED_SIZE <= #size }
+        }
+    })
+}
+
+/// A constant that fails to compile unless the `MINOR_OPCODE` of the request fits into the byte it
+/// is written into.
+#[inline]
+fn minor_opcode_assertion(name: &str) -> syn::Item {
+    let name = syn::Ident::new(name, Span::call_site());
+    const_assertion(quote::quote! { <#name as Request>::MINOR_OPCODE < 0x100 })
+}
+
+/// `const _: () = assert!(condition);`, which fails to compile unless the condition holds.
+#[inline]
+fn const_assertion(condition: proc_macro2::TokenStream) -> syn::Item {
+    syn::Item::Const(syn::ItemConst {
+        attrs: vec![],
+        vis: syn::Visibility::Inherited,
+        const_token: Default::default(),
+        ident: syn::Ident::new("_", Span::call_site()),
+        colon_token: Default::default(),
+        ty: Box::new(syn::Type::Tuple(syn::TypeTuple {
+            paren_token: Default::default(),
+            elems: Default::default(),
+        })),
+        eq_token: Default::default(),
+        expr: Box::new(syn::Expr::Macro(syn::ExprMacro {
+            attrs: vec![],
+            mac: syn::Macro {
+                path: str_to_path("assert"),
+                bang_token: Default::default(),
+                delimiter: syn::MacroDelimiter::Paren(Default::default()),
+                tokens: condition,
+            },
+        })),
+        semi_token: Default::default(),
+    })
+}
+
+/// `impl Deref` and `impl AsRef` for a transparent structure, which give its only field, so that
+/// the methods of that field can be called on the structure directly.
+#[inline]
+fn transparent_impls(name: &str, field: &syn::Field) -> Vec<syn::Item> {
+    let name = syn::Ident::new(name, Span::call_site());
+    let syn::Field { ident, ty, .. } = field;
+
+    vec![
+        syn::parse_quote! {
+            impl core::ops::Deref for #name {
+                type Target = #ty;
+                #[inline]
+                fn deref(&self) -> &#ty {
+                    &self.#ident
+                }
+            }
+        },
+        syn::parse_quote! {
+            impl AsRef<#ty> for #name {
+                #[inline]
+                fn as_ref(&self) -> &#ty {
+                    &self.#ident
+                }
+            }
+        },
+    ]
+}
+
+/// A bitmask that a switch depends on, and the cases of that switch that hold a single field.
+struct SyncedMask {
+    expr: Rc<Expression>,
+    cases: Vec<(Rc<UseCondition>, Box<str>)>,
+}
+
+/// Find the top-level fields of a structure that are the bitmask of a switch. The bits for cases
+/// holding a single field are set according to whether that field is present, so that the
+/// bitmask can't disagree with the values that are actually sent.
+#[inline]
+fn synced_masks(items: &[StructureItem]) -> HashMap<&str, SyncedMask> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            StructureItem::Switch(Switch { expr, cases, .. }) => {
+                let mask = expr.single_item()?;
+                if !items
+                    .iter()
+                    .any(|i| matches!(i, StructureItem::Field(Field { name, .. }) if name == mask))
+                {
+                    return None;
+                }
+
+                let cases: Vec<(Rc<UseCondition>, Box<str>)> = cases
+                    .iter()
+                    .filter(|c| matches!(c.condition.variant, ConditionVariant::BitflagVariant))
+                    .filter_map(|c| {
+                        let mut fields = c
+                            .items
+                            .iter()
+                            .filter(|i| !matches!(i, StructureItem::Padding { .. }));
+                        match (fields.next(), fields.next()) {
+                            (Some(StructureItem::Field(Field { name, .. })), None) => {
+                                Some((c.condition.clone(), name.as_str().into()))
+                            }
+                            _ => None,
                         }
-                        .into(),
-                    ),
-                )
+                    })
+                    .collect();
+
+                if cases.is_empty() {
+                    None
+                } else {
+                    Some((
+                        mask,
+                        SyncedMask {
+                            expr: expr.clone(),
+                            cases,
+                        },
+                    ))
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects the as_bytes statements for a series of structure items.
+struct AsBytesVisitor<'a> {
+    synced: &'a HashMap<&'a str, SyncedMask>,
+    conds: &'a mut HashMap<Rc<Expression>, Box<str>>,
+    last_cond_index: &'a mut usize,
+    optional: &'a [Box<str>],
+    /// Whether the next padding is the byte of an extension request's header that holds its minor
+    /// opcode. It always comes right after the major opcode, so it's the first padding visited.
+    minor_opcode: bool,
+    stmts: Vec<SumStatement>,
+}
+
+impl<'a> StructureItemVisitor for AsBytesVisitor<'a> {
+    #[inline]
+    fn visit_field(&mut self, Field { name, ty, .. }: &Field) {
+        match self.synced.get(name.as_str()) {
+            Some(SyncedMask { expr, cases }) => {
+                // the synced mask doubles as the condition for its switch
+                let condname = format!("cond{}", *self.last_cond_index).into_boxed_str();
+                *self.last_cond_index += 1;
+                self.conds.insert(expr.clone(), condname.clone());
+                self.stmts.push(
+                    super::SyncMask {
+                        name: name.clone().into_boxed_str(),
+                        ty: Type::from_lvl2(ty.clone()),
+                        condname,
+                        cases: cases.clone(),
+                    }
+                    .into(),
+                );
             }
+            None => self.stmts.push(
+                super::AppendToIndexStatement {
+                    name: name.clone().into_boxed_str(),
+                }
+                .into(),
+            ),
+        }
+    }
+
+    #[inline]
+    fn visit_padding(&mut self, bytes: usize) {
+        match mem::take(&mut self.minor_opcode) {
+            true => self.stmts.push(super::AppendMinorOpcode.into()),
+            false => self.stmts.push(super::PadIndexStatement(bytes).into()),
+        }
+    }
+
+    #[inline]
+    fn visit_align_pad(&mut self, align: usize) {
+        self.stmts.push(super::ZeroAlignPad(align).into());
+    }
+
+    #[inline]
+    fn visit_len_slot(&mut self, owning_list: &str, ty: &Lvl2Type) {
+        self.stmts.push(
+            super::AppendLengthToIndex {
+                owner: owning_list.into(),
+                ty: Type::from_lvl2(ty.clone()),
+            }
+            .into(),
+        );
+    }
+
+    #[inline]
+    fn visit_list(
+        &mut self,
+        List {
+            name,
+            ty,
+            padding,
+            element_padding,
+            ..
+        }: &List,
+    ) {
+        self.stmts.push(
+            super::AsBytesList {
+                name: name.clone().into_boxed_str(),
+                ty: ty.clone(),
+                pad: *padding,
+                element_pad: *element_padding,
+            }
+            .into(),
+        );
+    }
+
+    #[inline]
+    fn visit_switch(&mut self, Switch { cases, .. }: &Switch) {
+        // fields within a case are optional, so their masks can't be synced
+        let unsynced = HashMap::new();
+
+        for c in cases {
+            let (condname, cond_init) = cond_vars(
+                &c.condition,
+                self.conds,
+                self.last_cond_index,
+                true,
+                self.optional,
+            );
+            self.stmts.extend(cond_init);
+
+            // conditions initialized within the case are not visible outside of it
+            let mut case_conds = self.conds.clone();
+            let mut case_visitor = AsBytesVisitor {
+                synced: &unsynced,
+                conds: &mut case_conds,
+                last_cond_index: self.last_cond_index,
+                optional: self.optional,
+                minor_opcode: false,
+                stmts: vec![],
+            };
+            walk_items(&c.items, &mut case_visitor);
+
+            self.stmts.push(
+                super::SwitchCaseAsBytes {
+                    condition: c.condition.clone(),
+                    condname,
+                    statements: case_visitor.stmts,
+                }
+                .into(),
+            );
         }
     }
+
+    #[inline]
+    fn visit_fd(&mut self, _name: &str) {}
 }
 
-impl RStruct {
-    /// Populate the ASB structure based on field information.
+/// The names of the length slots that have been read, keyed by the lists that own them. Each switch case
+/// gets a scope of its own, since the slots read in a case are local to its block: the lists in the
+/// case can use the slots in the scopes around it, but the lists after the case can't use its slots.
+#[derive(Debug)]
+struct LenScopes(Vec<HashMap<String, Box<str>>>);
+
+impl LenScopes {
     #[inline]
-    pub fn populate_asb(&mut self) {
-        self.populate_as_bytes();
-        self.populate_from_bytes();
+    fn new() -> Self {
+        Self(vec![HashMap::new()])
+    }
 
-        // populate size
-        self.asb.size = SumOfSizes(
-            self.fields
+    #[inline]
+    fn push(&mut self) {
+        self.0.push(HashMap::new());
+    }
+
+    #[inline]
+    fn pop(&mut self) {
+        self.0.pop();
+    }
+
+    #[inline]
+    fn insert(&mut self, owning_list: String, len_name: Box<str>) {
+        if let Some(scope) = self.0.last_mut() {
+            scope.insert(owning_list, len_name);
+        }
+    }
+
+    /// Find the length slot of a list, starting from the innermost scope.
+    #[inline]
+    fn get(&self, owning_list: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(owning_list))
+            .map(|len_name| &**len_name)
+    }
+}
+
+/// Collects the from_bytes statements for a series of structure items.
+struct FromBytesVisitor<'a> {
+    conds: &'a mut HashMap<Rc<Expression>, Box<str>>,
+    last_cond_index: &'a mut usize,
+    len_map: &'a mut LenScopes,
+    last_len_index: &'a mut usize,
+    optional: &'a mut Vec<Box<str>>,
+    /// The names supplied by the enclosing context, which parameters may refer to. The items of a
+    /// switch case are given the fields that have been read before the switch.
+    params: Vec<Box<str>>,
+    /// The fields that these items have read so far.
+    read: Vec<Box<str>>,
+    /// Whether these are the items of a reply or a generic event, whose length bounds the bytes
+    /// that are read.
+    bounded_by_length: bool,
+    /// Whether the reply has fields that older servers leave out.
+    has_trailing: bool,
+    /// Whether a field that older servers leave out has been reached.
+    trailing: bool,
+    stmts: Vec<SumStatement>,
+}
+
+impl<'a> FromBytesVisitor<'a> {
+    /// Push the statements that load a variable. Past the first field that older servers leave out,
+    /// the variable is only loaded if the reply is long enough to contain it.
+    #[inline]
+    fn push_load(&mut self, name: &str, ty: Type, statements: Vec<SumStatement>) {
+        match self.trailing {
+            false => self.stmts.extend(statements),
+            true => self.stmts.push(
+                super::LoadIfPresent {
+                    name: name.into(),
+                    ty,
+                    statements,
+                }
+                .into(),
+            ),
+        }
+    }
+
+    /// Push the statement that skips padding. Past the first field that older servers leave out, the
+    /// padding is only skipped if the reply is long enough to contain it.
+    #[inline]
+    fn push_skip(&mut self, statement: SumStatement) {
+        if self.trailing {
+            self.stmts
+                .push(super::SkipIfPresent(vec![statement]).into());
+        } else {
+            self.stmts.push(statement);
+        }
+    }
+
+    /// The length of a list, in terms of the values that have already been read.
+    #[inline]
+    fn list_length(&self, name: &str, list_length: &Expression) -> syn::Expr {
+        if list_length.is_remainder() {
+            return super::remainder_len(name);
+        }
+
+        // if the list length is a single item, get that length slot
+        match list_length.single_item().and(self.len_map.get(name)) {
+            Some(name) => str_to_exprpath(name),
+            None => {
+                list_length.to_length_expr_with_params(false, true, self.optional, &self.params)
+            }
+        }
+    }
+}
+
+impl<'a> StructureItemVisitor for FromBytesVisitor<'a> {
+    #[inline]
+    fn visit_field(
+        &mut self,
+        Field {
+            name,
+            ty,
+            formatted_count,
+            ..
+        }: &Field,
+    ) {
+        let ty = Type::from_lvl2(ty.clone());
+        let load = match formatted_count {
+            Some(count) => super::FromBytesFormatted {
+                name: name.clone().into(),
+                count: count.clone().into(),
+            }
+            .into(),
+            None => super::LoadStatementVariable {
+                name: name.clone().into(),
+                ty: ty.clone(),
+                use_slice: true,
+            }
+            .into(),
+        };
+        self.push_load(name, ty, vec![load]);
+        self.read.push(name.as_str().into());
+
+        if self.bounded_by_length && name == "length" {
+            self.stmts.push(super::TruncateToReplyLength.into());
+        }
+        if self.has_trailing && name == "length" {
+            self.stmts.push(super::DefineReplyEnd.into());
+        }
+    }
+
+    #[inline]
+    fn visit_padding(&mut self, bytes: usize) {
+        self.push_skip(super::IncrementIndex::Number(bytes).into());
+    }
+
+    #[inline]
+    fn visit_align_pad(&mut self, align: usize) {
+        self.push_skip(super::AlignIndex(align).into());
+    }
+
+    #[inline]
+    fn visit_len_slot(&mut self, owning_list: &str, ty: &Lvl2Type) {
+        // create a random name
+        let len_name = format!("len{}", *self.last_len_index);
+        *self.last_len_index += 1;
+
+        self.len_map
+            .insert(owning_list.to_string(), len_name.clone().into_boxed_str());
+
+        let ty = Type::from_lvl2(ty.clone());
+        let load = super::LoadStatementVariable {
+            name: len_name.clone().into(),
+            ty: ty.clone(),
+            use_slice: true,
+        };
+        self.push_load(&len_name, ty, vec![load.into()]);
+    }
+
+    #[inline]
+    fn visit_list(
+        &mut self,
+        List {
+            name,
+            ty,
+            list_length,
+            padding,
+            element_padding,
+            ..
+        }: &List,
+    ) {
+        let length_expr = self.list_length(name, list_length);
+        let load = super::FromBytesList {
+            name: name.clone().into_boxed_str(),
+            ty: ty.clone(),
+            len: length_expr,
+            pad: *padding,
+            element_pad: *element_padding,
+            in_bytes: list_length.is_remainder(),
+        };
+        let ty = match ty {
+            MaybeString::IsAString => Type::Basic("String".into()),
+            MaybeString::NotAString(ty) => Type::Vector(Box::new(Type::from_lvl2(ty.clone()))),
+        };
+        self.push_load(name, ty, vec![load.into()]);
+    }
+
+    #[inline]
+    fn visit_switch(&mut self, Switch { cases, .. }: &Switch) {
+        for c in cases {
+            let (condname, cond_init) = cond_vars(
+                &c.condition,
+                self.conds,
+                self.last_cond_index,
+                false,
+                self.optional,
+            );
+            self.stmts.extend(cond_init);
+
+            // fields are wrapped in options, while lists and the contents of inner
+            // switches are taken as they are
+            let outputs = c
+                .items
                 .iter()
-                .map(|f| match f {
+                .flat_map(|f| match f {
                     StructureItem::Field(Field { name, .. }) => {
-                        SizeSumPart::SizeofField(name.clone().into_boxed_str())
+                        vec![(name.clone().into_boxed_str(), true)]
                     }
-                    StructureItem::Padding { bytes } => SizeSumPart::Bytes(*bytes),
-                    StructureItem::List(List {
-                        name, ty, padding, ..
-                    }) => SizeSumPart::ListTimesSize(
-                        name.clone().into_boxed_str(),
-                        ty.clone(),
-                        *padding,
-                    ),
-                    StructureItem::LenSlot { ty, .. } => {
-                        SizeSumPart::SizeofType(Type::from_lvl2(ty.clone()))
+                    StructureItem::List(List { name, .. }) => {
+                        vec![(name.clone().into_boxed_str(), false)]
                     }
+                    StructureItem::Switch(_) => output_names(slice::from_ref(f))
+                        .into_iter()
+                        .map(|name| (name.into_owned().into_boxed_str(), false))
+                        .collect(),
+                    _ => vec![],
                 })
-                .collect(),
+                .collect::<Vec<_>>();
+
+            let mut case_conds = self.conds.clone();
+            let mut case_visitor = FromBytesVisitor {
+                conds: &mut case_conds,
+                last_cond_index: self.last_cond_index,
+                len_map: self.len_map,
+                last_len_index: self.last_len_index,
+                optional: self.optional,
+                params: self.params.iter().chain(&self.read).cloned().collect(),
+                read: vec![],
+                bounded_by_length: false,
+                has_trailing: false,
+                trailing: false,
+                stmts: vec![],
+            };
+            case_visitor.len_map.push();
+            walk_items(&c.items, &mut case_visitor);
+            case_visitor.len_map.pop();
+            let statements = case_visitor.stmts;
+
+            // once the case is over, its fields are wrapped
+            self.optional.extend(
+                outputs
+                    .iter()
+                    .filter(|(_, wrap)| *wrap)
+                    .map(|(name, _)| name.clone()),
+            );
+
+            self.stmts.push(
+                super::SwitchCaseFromBytes {
+                    condition: c.condition.clone(),
+                    condname,
+                    statements,
+                    outputs,
+                }
+                .into(),
+            );
+        }
+    }
+
+    // file descriptors are received alongside the bytes, and are filled in later
+    #[inline]
+    fn visit_fd(&mut self, _name: &str) {}
+
+    #[inline]
+    fn visit_min_version(&mut self, _version: &str) {
+        if self.has_trailing {
+            self.trailing = true;
+        }
+    }
+}
+
+/// Reads a series of structure items up to a list, and then borrows that list. If `iterate` is set, an
+/// iterator that parses the list is returned instead.
+struct BorrowListVisitor<'a> {
+    target: &'a str,
+    iterate: bool,
+    found: bool,
+    inner: FromBytesVisitor<'a>,
+}
+
+impl<'a> StructureItemVisitor for BorrowListVisitor<'a> {
+    #[inline]
+    fn visit_field(&mut self, field: &Field) {
+        if !self.found {
+            self.inner.visit_field(field);
+        }
+    }
+
+    #[inline]
+    fn visit_padding(&mut self, bytes: usize) {
+        if !self.found {
+            self.inner.visit_padding(bytes);
+        }
+    }
+
+    #[inline]
+    fn visit_align_pad(&mut self, align: usize) {
+        if !self.found {
+            self.inner.visit_align_pad(align);
+        }
+    }
+
+    #[inline]
+    fn visit_len_slot(&mut self, owning_list: &str, ty: &Lvl2Type) {
+        if !self.found {
+            self.inner.visit_len_slot(owning_list, ty);
+        }
+    }
+
+    #[inline]
+    fn visit_list(&mut self, list: &List) {
+        if self.found {
+            return;
+        }
+
+        match (&list.ty, list.name == self.target) {
+            (MaybeString::NotAString(_), true) if self.iterate => {
+                let len = self.inner.list_length(&list.name, &list.list_length);
+                self.inner.stmts.push(
+                    super::ReturnListIter {
+                        name: list.name.clone().into_boxed_str(),
+                        len,
+                        element_pad: list.element_padding,
+                    }
+                    .into(),
+                );
+                self.found = true;
+            }
+            (MaybeString::NotAString(ty), true) => {
+                let len = self.inner.list_length(&list.name, &list.list_length);
+                self.inner.stmts.push(
+                    super::ReturnBorrowedList {
+                        name: list.name.clone().into_boxed_str(),
+                        ty: Type::from_lvl2(ty.clone()),
+                        len,
+                    }
+                    .into(),
+                );
+                self.found = true;
+            }
+            _ => self.inner.visit_list(list),
+        }
+    }
+
+    #[inline]
+    fn visit_switch(&mut self, switch: &Switch) {
+        if !self.found {
+            self.inner.visit_switch(switch);
+        }
+    }
+
+    #[inline]
+    fn visit_fd(&mut self, _name: &str) {}
+
+    #[inline]
+    fn visit_min_version(&mut self, version: &str) {
+        self.inner.visit_min_version(version);
+    }
+}
+
+/// Tell whether a series of structure items has fields that were added in a later version of the
+/// protocol.
+#[inline]
+fn has_trailing_fields(items: &[StructureItem]) -> bool {
+    items.iter().any(|item| {
+        matches!(
+            item,
+            StructureItem::Field(Field {
+                min_version: Some(_),
+                ..
+            }) | StructureItem::LenSlot {
+                min_version: Some(_),
+                ..
+            }
+        )
+    })
+}
+
+/// Tell whether a list of this type can be borrowed directly from the bytes it's read from.
+#[inline]
+pub fn is_plain_data(ty: &MaybeString) -> bool {
+    match ty {
+        MaybeString::NotAString(ty @ Lvl2Type::BasicType(name)) => {
+            name != "bool" && primitive_size(ty).is_some()
+        }
+        _ => false,
+    }
+}
+
+impl RStruct {
+    /// For each top-level list of plain data, add a `[list]_slice` function that borrows that list
+    /// from the bytes of this structure instead of copying it into a `Vec`. If the list isn't aligned
+    /// in those bytes, it is copied instead.
+    #[inline]
+    pub fn populate_slice_accessors(&mut self) {
+        let targets: Vec<(String, Lvl2Type)> = self
+            .fields
+            .iter()
+            .filter_map(|item| match item {
+                StructureItem::List(List {
+                    name,
+                    ty: ty @ MaybeString::NotAString(elem),
+                    ..
+                }) if is_plain_data(ty) => Some((name.clone(), elem.clone())),
+                _ => None,
+            })
+            .collect();
+
+        for (target, elem) in targets {
+            let elem = Type::from_lvl2(elem);
+            let method = self.list_accessor(
+                format!("{}_slice", &target),
+                &target,
+                false,
+                Type::CowSlice(Box::new(elem)),
+            );
+            self.methods.push(method);
+        }
+    }
+
+    /// For each top-level list of the structures named in `structs`, add a `[list]_iter` function that
+    /// parses the items of that list from the bytes of this structure one at a time, instead of all at
+    /// once.
+    #[inline]
+    pub fn populate_list_iters(&mut self, structs: &HashSet<Box<str>>) {
+        let targets: Vec<(String, Lvl2Type)> = self
+            .fields
+            .iter()
+            .filter_map(|item| match item {
+                StructureItem::List(List {
+                    name,
+                    ty: MaybeString::NotAString(elem @ Lvl2Type::BasicType(elem_name)),
+                    list_length,
+                    ..
+                }) if structs.contains(&**elem_name) && !list_length.is_remainder() => {
+                    Some((name.clone(), elem.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        for (target, elem) in targets {
+            let elem = Type::from_lvl2(elem);
+            let method = self.list_accessor(
+                format!("{}_iter", &target),
+                &target,
+                true,
+                Type::ListIter(Box::new(elem)),
+            );
+            self.methods.push(method);
+        }
+    }
+
+    /// A function that reads the bytes of this structure up to a list, and then borrows the list or
+    /// iterates over it.
+    #[inline]
+    fn list_accessor(&self, name: String, target: &str, iterate: bool, output: Type) -> Method {
+        let mut len_map = LenScopes::new();
+        let mut last_len_index: usize = 0;
+        let mut cond_map = HashMap::<Rc<Expression>, Box<str>>::new();
+        let mut last_cond_index: usize = 0;
+        let mut optional = vec![];
+        let mut visitor = BorrowListVisitor {
+            target,
+            iterate,
+            found: false,
+            inner: FromBytesVisitor {
+                conds: &mut cond_map,
+                last_cond_index: &mut last_cond_index,
+                len_map: &mut len_map,
+                last_len_index: &mut last_len_index,
+                optional: &mut optional,
+                params: vec![],
+                read: vec![],
+                bounded_by_length: super::is_reply(self) || super::is_generic_event(self),
+                has_trailing: has_trailing_fields(&self.fields),
+                trailing: false,
+                stmts: vec![],
+            },
+        };
+        walk_items(&self.fields, &mut visitor);
+        let stmts = visitor.inner.stmts;
+
+        let mut method = Method::new(
+            name.into(),
+            None,
+            vec![InputParameter {
+                name: "bytes".into(),
+                ty: Type::Slice(Box::new(Type::Basic("u8".into()))),
+                usage: ParameterUsage::Ref,
+            }],
+            Some(Type::ParseRes(Box::new(output))),
         );
-        self.asb.fd_getting = self.fds.get(0).map(|s| s.to_owned());
+        method.statements = iter::once(super::CreateIndexVariable.into())
+            .chain(stmts)
+            .collect();
+        method
+    }
+
+    /// Populate the ASB structure based on field information.
+    #[inline]
+    pub fn populate_asb(&mut self) {
+        self.populate_as_bytes();
+        self.populate_from_bytes();
+
+        // populate size
+        let mut sizes = SizeVisitor::default();
+        self.walk(&mut sizes);
+        self.asb.size = SumOfSizes(sizes.parts);
+        self.asb.fds = fd_names(&self.fields);
     }
 
     /// Populate the as_bytes statements.
@@ -131,45 +1180,25 @@ impl RStruct {
     pub fn populate_as_bytes(&mut self) {
         let mut last_cond_index: usize = 0;
         let mut conds: HashMap<Rc<Expression>, Box<str>> = HashMap::new();
-        let stmts = iter::once(super::CreateIndexVariable.into())
-            .chain(self.fields.iter().flat_map(|f| match f {
-                StructureItem::Field(Field {
-                    name, condition, ..
-                }) => {
-                    let (cond_pass, cond_init) =
-                        cond_vars(condition, &mut conds, &mut last_cond_index, true);
-
-                    cond_init
-                        .into_iter()
-                        .chain(iter::once(
-                            super::AppendToIndexStatement {
-                                name: name.clone().into_boxed_str(),
-                                condition: cond_pass,
-                            }
-                            .into(),
-                        ))
-                        .collect()
-                }
-                StructureItem::Padding { bytes } => {
-                    vec![super::PadIndexStatement(*bytes).into()]
-                }
-                StructureItem::LenSlot { owning_list, ty } => {
-                    vec![super::AppendLengthToIndex {
-                        owner: owning_list.clone().into_boxed_str(),
-                        ty: Type::from_lvl2(ty.clone()),
-                    }
-                    .into()]
-                }
+        let synced = synced_masks(&self.fields);
+        let optional = optional_fields(&self.fields);
+        let mut visitor = AsBytesVisitor {
+            synced: &synced,
+            conds: &mut conds,
+            last_cond_index: &mut last_cond_index,
+            optional: &optional,
+            minor_opcode: minor_opcode(&self.traits).is_some(),
+            stmts: vec![],
+        };
+        self.walk(&mut visitor);
 
-                StructureItem::List(List {
-                    name, ty, padding, ..
-                }) => vec![super::AsBytesList {
-                            name: name.clone().into_boxed_str(),
-                            ty: ty.clone(),
-                            pad: *padding,
-                        }
-                        .into()],
-            }))
+        let stmts = iter::once(super::CreateIndexVariable.into())
+            .chain(visitor.stmts)
+            .chain(
+                self.asb
+                    .align
+                    .map(|align| super::ZeroAlignPad(align).into()),
+            )
             .chain(iter::once(super::ReturnIndexStatement.into()))
             .collect();
         self.asb.as_bytes_stmts = stmts;
@@ -178,98 +1207,39 @@ impl RStruct {
     /// Populate the from_bytes statements.
     #[inline]
     pub fn populate_from_bytes(&mut self) {
-        let mut len_map = HashMap::<String, Box<str>>::with_capacity(self.fields.len());
-        let mut i: u32 = 0;
+        let mut len_map = LenScopes::new();
+        let mut last_len_index: usize = 0;
         let mut cond_map = HashMap::<Rc<Expression>, Box<str>>::new();
         let mut last_cond_index: usize = 0;
+        let mut optional = vec![];
+        let mut visitor = FromBytesVisitor {
+            conds: &mut cond_map,
+            last_cond_index: &mut last_cond_index,
+            len_map: &mut len_map,
+            last_len_index: &mut last_len_index,
+            optional: &mut optional,
+            params: vec![],
+            read: vec![],
+            bounded_by_length: super::is_reply(self) || super::is_generic_event(self),
+            has_trailing: has_trailing_fields(&self.fields),
+            trailing: false,
+            stmts: vec![],
+        };
+        self.walk(&mut visitor);
 
         let stmts = vec![
             super::CreateIndexVariable.into(),
             super::DeserTraceMarker(self.name.clone()).into(),
         ]
         .into_iter()
-        .chain(self.fields.iter().flat_map(|f| match f {
-            StructureItem::Field(Field {
-                name,
-                ty,
-                condition,
-                ..
-            }) => {
-                let (cond_pass, cond_init) =
-                    cond_vars(condition, &mut cond_map, &mut last_cond_index, false);
-
-                cond_init
-                    .into_iter()
-                    .chain(iter::once(
-                        super::LoadStatementVariable {
-                            name: name.clone().into(),
-                            ty: Type::from_lvl2(ty.clone()),
-                            use_slice: true,
-                            condition: cond_pass,
-                        }
-                        .into(),
-                    ))
-                    .collect()
-            }
-            StructureItem::Padding { bytes } => {
-                vec![super::IncrementIndex::Number(*bytes).into()]
-            }
-            StructureItem::LenSlot { ty, owning_list } => {
-                // create a random name
-                let len_name = format!("len{}", i);
-                i += 1;
-
-                len_map.insert(owning_list.clone(), len_name.clone().into_boxed_str());
-
-                vec![super::LoadStatementVariable {
-                    name: len_name.into(),
-                    ty: Type::from_lvl2(ty.clone()),
-                    use_slice: true,
-                    condition: None,
-                }
-                .into()]
-            }
-            StructureItem::List(List {
-                name,
-                ty,
-                list_length,
-                padding,
-                ..
-            }) => {
-                // if the list length is a single item, get that length slot
-                let length_expr = if let Some(_) = list_length.single_item() {
-                    match len_map.get(name) {
-                        Some(name) => str_to_exprpath(name),
-                        None => list_length.to_length_expr(false, true),
-                    }
-                } else {
-                    // just get the length expr
-                    list_length.to_length_expr(false, true)
-                };
-
-                vec![super::FromBytesList {
-                    name: name.clone().into_boxed_str(),
-                    ty: ty.clone(),
-                    len: length_expr,
-                    pad: padding.clone(),
-                }
-                .into()]
-            }
-        }))
+        .chain(visitor.stmts)
+        .chain(self.asb.align.map(|align| super::AlignIndex(align).into()))
         .chain(iter::once(
             super::ReturnStruct {
                 last_index: "index",
                 sname: self.name.clone(),
-                fields: self
-                    .fields
-                    .iter()
-                    .filter_map(|f| match f {
-                        StructureItem::Field(Field { name, .. }) => Some(name.clone().into()),
-                        StructureItem::List(List { name, .. }) => Some(name.clone().into()),
-                        _ => None,
-                    })
-                    .collect(),
-                fds: self.fds.clone(),
+                fields: output_names(&self.fields),
+                fds: fd_names(&self.fields),
             }
             .into(),
         ))
@@ -280,41 +1250,75 @@ impl RStruct {
 
 impl ToSyn for RStruct {
     #[inline]
-    fn to_syn_item(mut self) -> Vec<syn::Item> {
+    fn to_syn_item(mut self, ctx: &EmitContext) -> Vec<syn::Item> {
+        let builder = match self.traits.iter().any(|t| matches!(t, Trait::Request(..))) {
+            true => Some(self.request_builder()),
+            false => None,
+        };
+        let default_impl = self.default_impl();
+
+        let syn_fields: Vec<syn::Field> = self
+            .fields
+            .iter()
+            .flat_map(|f| f.to_syn_fields(false))
+            .collect();
         let s = syn::Item::Struct(syn::ItemStruct {
-            attrs: (match self.is_transparent {
-                false => None,
-                true => Some(repr_transparent()),
-            })
-            .into_iter()
-            .chain(match self.derives.len() {
-                0 => None,
-                _ => Some(derive_attrs(&self.derives)),
-            })
-            .collect(),
+            attrs: self
+                .docs
+                .as_deref()
+                .map(doc_attrs)
+                .into_iter()
+                .flatten()
+                .chain(match (self.is_transparent, self.is_repr_c) {
+                    (true, _) => Some(repr_transparent()),
+                    (false, true) => Some(repr_c()),
+                    (false, false) => None,
+                })
+                .chain(derive_attrs(&self.derives))
+                .chain(
+                    match self.traits.iter().any(|t| matches!(t, Trait::Request(..))) {
+                        false => None,
+                        true => Some(must_use(REQUEST_MUST_USE)),
+                    },
+                )
+                // events and errors may gain fields in later versions of the protocol
+                .chain(
+                    match self
+                        .traits
+                        .iter()
+                        .any(|t| matches!(t, Trait::Event(..) | Trait::Error(_)))
+                    {
+                        false => None,
+                        true => Some(non_exhaustive()),
+                    },
+                )
+                .collect(),
             vis: pub_vis(),
             struct_token: Default::default(),
             ident: syn::Ident::new(&self.name, Span::call_site()),
             generics: Default::default(),
             fields: syn::FieldsNamed {
                 brace_token: Default::default(),
-                named: self
-                    .fields
-                    .iter()
-                    .filter_map(|f| f.to_syn_field())
-                    .chain(self.fds.iter().map(|fd| syn::Field {
-                        attrs: vec![],
-                        vis: pub_vis(),
-                        ident: Some(syn::Ident::new(fd, Span::call_site())),
-                        colon_token: Some(Default::default()),
-                        ty: Type::Vector(Box::new(Type::Basic("Fd".into()))).to_syn_ty(),
-                    }))
-                    .collect(),
+                named: syn_fields.iter().cloned().collect(),
             }
             .into(),
             semi_token: None,
         });
 
+        let transparent = match self.is_transparent {
+            true => syn_fields
+                .first()
+                .map(|field| transparent_impls(&self.name, field)),
+            false => None,
+        };
+
+        let assertion = expected_size(&self.traits)
+            .filter(|_| fixed_size(&self.fields).is_some())
+            .map(|expected| size_assertion(&self.name, expected))
+            .into_iter()
+            .chain(minor_opcode(&self.traits).map(|_| minor_opcode_assertion(&self.name)))
+            .collect::<Vec<_>>();
+
         let other_impl_items = mem::take(&mut self.other_impl_items);
         let methods = syn::Item::Impl(syn::ItemImpl {
             attrs: vec![],
@@ -328,18 +1332,30 @@ impl ToSyn for RStruct {
             items: self
                 .methods
                 .iter()
-                .map(|m| m.to_syn_impl_item(false))
+                .map(|m| m.to_syn_impl_item(false, ctx))
                 .chain(other_impl_items)
                 .collect(),
         });
 
         let Self {
-            name, asb, traits, ..
+            name,
+            asb,
+            shared_asb,
+            traits,
+            ..
         } = self;
-        let asb = asb.to_syn_item(&name);
+        let asb = asb.to_syn_item(&name, ctx);
         let mut s = vec![s, methods];
+        s.extend(assertion);
+        s.extend(transparent.into_iter().flatten());
+        s.extend(default_impl);
         s.extend(asb);
-        s.extend(traits.into_iter().flat_map(|t| t.to_syn_item(&name)));
+        s.extend(shared_asb.into_iter().flat_map(|sa| sa.to_syn_item(&name)));
+        s.extend(traits.into_iter().flat_map(|t| t.to_syn_item(&name, ctx)));
+        if let Some((builder, builder_default)) = builder {
+            s.extend(builder.to_syn_item(ctx));
+            s.extend(builder_default);
+        }
         s
     }
 }
@@ -352,11 +1368,14 @@ fn from_lvl2(s: Lvl2Struct, is_reply: bool, ext_name: Option<&str>) -> (RStruct,
         mut name,
         brief,
         desc,
-        fields,
-        fds,
+        mut fields,
         special,
     } = s;
     let mut traits = vec![];
+    let mut response_type = None;
+    // replies, errors and most events hold the sequence number of the last request the server
+    // processed
+    let mut has_sequence = is_reply;
 
     // special-dependent stuff
     let other: Option<RStruct> = if is_reply {
@@ -377,13 +1396,35 @@ fn from_lvl2(s: Lvl2Struct, is_reply: bool, ext_name: Option<&str>) -> (RStruct,
     } else {
         match special {
             StructSpecial::Regular => None,
-            StructSpecial::Event(opcode, _) => {
-                traits.push(Trait::Event(opcode));
+            StructSpecial::Union => unreachable!("Unions should be converted to RUnion"),
+            StructSpecial::Event(opcode, skip_sequence, xge) => {
+                traits.push(Trait::Event(opcode, xge));
+                has_sequence = !skip_sequence;
+                // generic events are all sent with the same response type
+                response_type = Some(match xge {
+                    true => (GENERIC_EVENT, false),
+                    false => (opcode, ext_name.is_some()),
+                });
                 name = format!("{}Event", name).into_boxed_str();
                 None
             }
             StructSpecial::Error(opcode) => {
                 traits.push(Trait::Error(opcode));
+                has_sequence = true;
+                traits.push(Trait::ErrorDisplay(
+                    name.clone(),
+                    opcode,
+                    ["bad_value", "major_opcode", "minor_opcode"]
+                        .iter()
+                        .copied()
+                        .filter(|field| {
+                            fields.iter().any(|f| {
+                                matches!(f, StructureItem::Field(Field { name, .. }) if name == field)
+                            })
+                        })
+                        .collect(),
+                ));
+                traits.push(Trait::StdError);
                 name = format!("{}Error", name).into_boxed_str();
                 None
             }
@@ -392,14 +1433,15 @@ fn from_lvl2(s: Lvl2Struct, is_reply: bool, ext_name: Option<&str>) -> (RStruct,
                 traits.push(Trait::Request(
                     opcode,
                     match reply {
-                        Some(ref reply) => Type::from_name(reply_name.clone().into()),
+                        Some(_) => Type::from_name(reply_name.clone()),
                         None => Type::Tuple(vec![]),
                     },
                     ext_name.map(|s| s.to_string()),
                     match reply {
-                        Some(ref reply) => !reply.fds.is_empty(),
+                        Some(ref reply) => !fd_names(&reply.fields).is_empty(),
                         None => false,
                     },
+                    false,
                 ));
                 name = format!("{}Request", name).into_boxed_str();
                 match reply {
@@ -414,21 +1456,86 @@ fn from_lvl2(s: Lvl2Struct, is_reply: bool, ext_name: Option<&str>) -> (RStruct,
         }
     };
 
+    // only replies announce their length, so only they can leave out fields at the end
+    if !is_reply && has_trailing_fields(&fields) {
+        log::warn!(
+            "\"{}\" is not a reply, so its fields must always be present",
+            &name
+        );
+        fields.iter_mut().for_each(|f| match f {
+            StructureItem::Field(Field { min_version, .. })
+            | StructureItem::LenSlot { min_version, .. } => *min_version = None,
+            _ => (),
+        });
+    }
+
+    // the brief and the description are separated by an empty line
+    let docs = match (brief, desc) {
+        (None, None) => None,
+        (Some(doc), None) | (None, Some(doc)) => Some(doc),
+        (Some(brief), Some(desc)) => Some(format!("{}\n\n{}", brief, desc).into_boxed_str()),
+    };
+
+    // requests and replies are padded out to a multiple of four bytes
+    let align = match is_reply || traits.iter().any(|t| matches!(t, Trait::Request(..))) {
+        true => Some(4),
+        false => None,
+    };
+    let size = fixed_size(&fields);
+    if let (Some(align), Some(size)) = (align, size) {
+        if size % align != 0 {
+            log::warn!(
+                "\"{}\" is {} bytes long, which is not a multiple of {}; padding it automatically",
+                &name,
+                size,
+                align
+            );
+        }
+    }
+    let size = size.map(|size| match align {
+        Some(align) => size + (align - size % align) % align,
+        None => size,
+    });
+
+    let defaults = protocol_defaults(&name, &fields);
+
     let res = RStruct {
         name,
+        docs,
         derives: vec!["Clone", "Debug", "Default"],
-        is_transparent: false, // TODO: monkey patch
+        is_transparent: is_transparent(&fields),
+        is_repr_c: false,
+        defaults,
         fields,
-        fds,
-        methods: vec![],
-        other_impl_items: vec![],
+        methods: response_type
+            .map(|_| response_type_method())
+            .into_iter()
+            .chain(iter::once(sequence_method()).filter(|_| has_sequence))
+            .chain(size.map(|_| fixed_size_method()))
+            .collect(),
+        other_impl_items: size
+            .map(fixed_size_const)
+            .into_iter()
+            .chain(response_type.map(|(rt, in_ext)| response_type_const(rt, in_ext)))
+            .collect(),
         traits,
-        asb: Default::default(),
+        asb: Asb {
+            align,
+            ..Default::default()
+        },
+        shared_asb: None,
     };
 
     (res, other)
 }
 
+/// Tell whether a structure is a transparent wrapper around its only item. Padding and length slots
+/// don't count as real fields, so a structure containing them is never transparent.
+#[inline]
+fn is_transparent(fields: &[StructureItem]) -> bool {
+    matches!(fields, [StructureItem::Field(_)] | [StructureItem::List(_)])
+}
+
 // From a level 2 struct
 // Note: this potentially produces 2 items
 impl RStruct {
@@ -2,25 +2,80 @@
 
 use super::{
     syn_util::{
-        int_litexpr_int, item_field, str_to_exprpath, str_to_path, str_to_pathseg, str_to_ty,
+        cfg_feature, int_litexpr_int, item_field, str_to_exprpath, str_to_path, str_to_pathseg,
+        str_to_ty,
     },
-    InputParameter, Method, ParameterUsage, ToSyn, Type,
+    EmitContext, InputParameter, Method, ParameterUsage, SumStatement, Type,
 };
 use proc_macro2::Span;
 use std::{borrow::Cow, iter, ops::Deref, rc::Rc};
 
 #[derive(Debug)]
 pub enum Trait {
-    Event(u64),
+    /// An event, with its opcode and whether it's sent as a generic event.
+    Event(u64, bool),
     Error(u64),
-    Request(u64, Type, Option<String>, bool),
+    /// A human-readable message for an error, with its name, its opcode and the names of the
+    /// fields describing what caused it.
+    ErrorDisplay(Box<str>, u64, Vec<&'static str>),
+    StdError,
+    /// A request, with its opcode, its reply, its extension, whether its reply has file descriptors
+    /// and whether it has a `validate` method that checks its lists.
+    Request(u64, Type, Option<String>, bool, bool),
     Xid,
+    /// Format a resource ID in hexadecimal, the way the server and other tools print it.
+    XidDebug,
     EnumDefault(Box<str>),
+    FromEnum(Box<str>),
+    EnumTryFrom(Cow<'static, str>, Box<[(Box<str>, i64)]>),
+    TryFromBytes,
     FromXid(Box<str>),
+    /// Create a resource ID type from a plain XID, if the "xid-conversions" feature is enabled.
+    XidFromRaw,
+    /// Convert a resource ID type into a plain XID, if the "xid-conversions" feature is enabled. This
+    /// is implemented on the XID rather than on the type.
+    XidIntoRaw,
+    /// Wrap an event, named first, in the variant of the event enum named second.
+    FromEvent(Box<str>, Box<str>),
     BitflagsNot(Box<str>),
     BitflagsAnd(Box<str>),
     BitflagsOr(Box<str>),
     BitflagsXor(Box<str>),
+    /// Create the structure out of fuzzing input with these statements, if the "fuzzing" feature is
+    /// enabled.
+    Arbitrary(Vec<SumStatement>),
+    /// Extend the list of a request, named first, with items of the type second, through the
+    /// request's builder. The builder can also be collected out of those items.
+    ExtendList(Box<str>, Type),
+    /// Format the structure with these fields, printing the buffers of bytes among them in full only
+    /// if they hold at most the given number of bytes.
+    ElidedDebug(Vec<(Box<str>, bool)>, usize),
+    /// Compare and hash the structure by these fields alone, which identify the resource it
+    /// describes.
+    IdentityComparison(Vec<Box<str>>),
+}
+
+/// `fn validate(&self) -> Result<(), ValidationError> { Self::validate(self) }`, which calls the
+/// inherent method of the same name.
+#[inline]
+fn forward_validate(ctx: &EmitContext) -> syn::ImplItem {
+    let mut method = Method::new(
+        "validate".into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::ResWith(
+            Box::new(Type::Tuple(vec![])),
+            Box::new(Type::Basic("ValidationError".into())),
+        )),
+    );
+    method.statements = vec![super::ExprWrapper(syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(str_to_exprpath("Self::validate")),
+        paren_token: Default::default(),
+        args: iter::once(str_to_exprpath("self")).collect(),
+    }))
+    .into()];
+    method.to_syn_impl_item(true, ctx)
 }
 
 #[inline]
@@ -39,6 +94,13 @@ fn opcode_const(op: u64) -> syn::ImplItem {
     })
 }
 
+/// `const MINOR_OPCODE: u16`, the opcode of a request within its extension.
+#[inline]
+fn minor_opcode_const(op: u64) -> syn::ImplItem {
+    let op = int_litexpr_int(op);
+    syn::parse_quote! { const MINOR_OPCODE: u16 = #op; }
+}
+
 #[inline]
 fn ref_const(val: bool) -> syn::ImplItem {
     syn::ImplItem::Const(syn::ImplItemConst {
@@ -61,6 +123,12 @@ fn ref_const(val: bool) -> syn::ImplItem {
     })
 }
 
+/// `const NAME: &'static str`, the name of a request without the suffix of its structure.
+#[inline]
+fn name_const(name: &str) -> syn::ImplItem {
+    syn::parse_quote! { const NAME: &'static str = #name; }
+}
+
 #[inline]
 fn extension_const(ext: Option<&str>) -> syn::ImplItem {
     syn::ImplItem::Const(syn::ImplItemConst {
@@ -96,12 +164,17 @@ fn extension_const(ext: Option<&str>) -> syn::ImplItem {
 
 #[inline]
 fn bitflags_output_ty(ty: &str) -> syn::ImplItem {
+    assoc_ty("Output", ty)
+}
+
+#[inline]
+fn assoc_ty(name: &str, ty: &str) -> syn::ImplItem {
     syn::ImplItem::Type(syn::ImplItemType {
         attrs: vec![],
         vis: syn::Visibility::Inherited,
         defaultness: None,
         type_token: Default::default(),
-        ident: syn::Ident::new("Output", Span::call_site()),
+        ident: syn::Ident::new(name, Span::call_site()),
         generics: Default::default(),
         eq_token: Default::default(),
         semi_token: Default::default(),
@@ -109,11 +182,54 @@ fn bitflags_output_ty(ty: &str) -> syn::ImplItem {
     })
 }
 
+/// A path to a trait with a single generic type argument.
+#[inline]
+fn generic_trait_path(segments: &[&str], arg: Type) -> syn::Path {
+    let (last, rest) = segments.split_last().unwrap();
+    syn::Path {
+        leading_colon: None,
+        segments: rest
+            .iter()
+            .map(|s| str_to_pathseg(s))
+            .chain(iter::once(syn::PathSegment {
+                ident: syn::Ident::new(last, Span::call_site()),
+                arguments: syn::PathArguments::AngleBracketed(
+                    syn::AngleBracketedGenericArguments {
+                        colon2_token: None,
+                        lt_token: Default::default(),
+                        args: iter::once(syn::GenericArgument::Type(arg.to_syn_ty())).collect(),
+                        gt_token: Default::default(),
+                    },
+                ),
+            }))
+            .collect(),
+    }
+}
+
 impl Trait {
     #[inline]
-    pub fn to_syn_item(self, tyname: &str) -> Vec<syn::Item> {
+    pub fn to_syn_item(self, tyname: &str, ctx: &EmitContext) -> Vec<syn::Item> {
+        // this trait has a lifetime, so it's put together on its own
+        if let Self::Arbitrary(statements) = self {
+            return vec![super::arbitrary_impl(tyname, statements)];
+        }
+        // these traits have generic methods, so they're put together on their own too
+        if let Self::ExtendList(list, elem) = self {
+            return super::builder::extend_list_impls(tyname, &list, elem);
+        }
+        if let Self::ElidedDebug(fields, limit) = self {
+            return vec![super::elided_debug_impl(tyname, &fields, limit)];
+        }
+        if let Self::IdentityComparison(fields) = self {
+            return super::identity_comparison_impls(tyname, &fields);
+        }
+
         vec![syn::Item::Impl(syn::ItemImpl {
-            attrs: vec![],
+            attrs: match self {
+                Self::StdError => vec![cfg_feature("std")],
+                Self::XidFromRaw | Self::XidIntoRaw => vec![cfg_feature("xid-conversions")],
+                _ => vec![],
+            },
             defaultness: None,
             unsafety: None,
             impl_token: Default::default(),
@@ -121,7 +237,7 @@ impl Trait {
             trait_: Some((
                 None,
                 match self {
-                    Self::Event(_) => syn::Path {
+                    Self::Event(..) => syn::Path {
                         leading_colon: None,
                         segments: vec![
                             str_to_pathseg("crate"),
@@ -141,28 +257,33 @@ impl Trait {
                         .into_iter()
                         .collect(),
                     },
-                    Self::Request(_, _, _, _) => str_to_path("Request"),
+                    Self::ErrorDisplay(..) => str_to_path("core::fmt::Display"),
+                    Self::XidDebug => str_to_path("core::fmt::Debug"),
+                    Self::StdError => str_to_path("std::error::Error"),
+                    Self::Request(..) => str_to_path("Request"),
                     Self::Xid => str_to_path("XidType"),
                     Self::EnumDefault(_) => str_to_path("Default"),
-                    Self::FromXid(ref from) => syn::Path {
-                        leading_colon: None,
-                        segments: vec![syn::PathSegment {
-                            ident: syn::Ident::new("From", Span::call_site()),
-                            arguments: syn::PathArguments::AngleBracketed(
-                                syn::AngleBracketedGenericArguments {
-                                    colon2_token: None,
-                                    lt_token: Default::default(),
-                                    args: iter::once(syn::GenericArgument::Type(
-                                        Type::from_name(from.to_string()).to_syn_ty(),
-                                    ))
-                                    .collect(),
-                                    gt_token: Default::default(),
-                                },
-                            ),
-                        }]
-                        .into_iter()
-                        .collect(),
-                    },
+                    Self::FromEnum(ref from)
+                    | Self::FromXid(ref from)
+                    | Self::FromEvent(ref from, _) => {
+                        generic_trait_path(&["From"], Type::from_name(from.to_string()))
+                    }
+                    Self::XidFromRaw => generic_trait_path(&["From"], Type::Basic("XID".into())),
+                    Self::XidIntoRaw => {
+                        generic_trait_path(&["From"], Type::from_name(tyname.to_string()))
+                    }
+                    Self::EnumTryFrom(ref underlying, _) => generic_trait_path(
+                        &["core", "convert", "TryFrom"],
+                        Type::Basic(underlying.clone()),
+                    ),
+                    Self::TryFromBytes => generic_trait_path(
+                        &["core", "convert", "TryFrom"],
+                        Type::Ref(
+                            Box::new(Type::Slice(Box::new(Type::Basic("u8".into())))),
+                            false,
+                            None,
+                        ),
+                    ),
                     Self::BitflagsNot(_) => syn::Path {
                         leading_colon: None,
                         segments: vec![
@@ -203,18 +324,74 @@ impl Trait {
                         .into_iter()
                         .collect(),
                     },
+                    Self::Arbitrary(_)
+                    | Self::ExtendList(..)
+                    | Self::ElidedDebug(..)
+                    | Self::IdentityComparison(_) => {
+                        unreachable!()
+                    }
                 },
                 Default::default(),
             )),
-            self_ty: Box::new(Type::from_name(tyname.to_string()).to_syn_ty()),
+            self_ty: Box::new(match self {
+                Self::XidIntoRaw => Type::Basic("XID".into()).to_syn_ty(),
+                _ => Type::from_name(tyname.to_string()).to_syn_ty(),
+            }),
             brace_token: Default::default(),
             items: match self {
-                Self::Event(opcode) => vec![opcode_const(opcode)],
+                Self::Event(opcode, _) => vec![opcode_const(opcode)],
                 Self::Error(opcode) => vec![opcode_const(opcode)],
-                Self::Request(opcode, reply_name, ext_name, expects_fds) => vec![
-                    opcode_const(opcode),
+                Self::StdError => vec![],
+                Self::XidDebug => vec![{
+                    let mut method = Method::new(
+                        "fmt".into(),
+                        Some(ParameterUsage::Ref),
+                        vec![InputParameter {
+                            name: "f".into(),
+                            ty: Type::Basic("core::fmt::Formatter".into()),
+                            usage: ParameterUsage::MutRef,
+                        }],
+                        Some(Type::Basic("core::fmt::Result".into())),
+                    );
+                    method.statements = vec![super::WriteXidDebug {
+                        name: tyname.into(),
+                    }
+                    .into()];
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::ErrorDisplay(name, opcode, fields) => vec![{
+                    let mut method = Method::new(
+                        "fmt".into(),
+                        Some(ParameterUsage::Ref),
+                        vec![InputParameter {
+                            name: "f".into(),
+                            ty: Type::Basic("core::fmt::Formatter".into()),
+                            usage: ParameterUsage::MutRef,
+                        }],
+                        Some(Type::Basic("core::fmt::Result".into())),
+                    );
+                    method.statements = vec![super::WriteErrorMessage {
+                        name,
+                        opcode,
+                        fields,
+                    }
+                    .into()];
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::Request(opcode, reply_name, ext_name, expects_fds, validates) => vec![
+                    // the server assigns the major opcode of an extension request, so it's left at
+                    // zero, and the opcode from the description is the minor opcode
+                    match ext_name {
+                        Some(_) => opcode_const(0),
+                        None => opcode_const(opcode),
+                    },
                     extension_const(ext_name.as_deref()),
+                    match ext_name {
+                        Some(_) => minor_opcode_const(opcode),
+                        None => minor_opcode_const(0),
+                    },
                     ref_const(expects_fds),
+                    name_const(tyname.strip_suffix("Request").unwrap_or(tyname)),
                     syn::ImplItem::Type(syn::ImplItemType {
                         attrs: vec![],
                         vis: syn::Visibility::Inherited,
@@ -226,7 +403,13 @@ impl Trait {
                         ty: reply_name.to_syn_ty(),
                         semi_token: Default::default(),
                     }),
-                ],
+                ]
+                .into_iter()
+                .chain(match validates {
+                    false => None,
+                    true => Some(forward_validate(ctx)),
+                })
+                .collect(),
                 Self::Xid => vec![
                     {
                         let mut method = Method::new(
@@ -236,7 +419,7 @@ impl Trait {
                             Some(Type::Basic("XID".into())),
                         );
                         method.statements = vec![super::GetXidStatement.into()];
-                        method.to_syn_impl_item(true)
+                        method.to_syn_impl_item(true, ctx)
                     },
                     {
                         let mut method = Method::new(
@@ -250,7 +433,7 @@ impl Trait {
                             Some(Type::Basic("Self".into())),
                         );
                         method.statements = vec![super::CreateXidTypeStatement.into()];
-                        method.to_syn_impl_item(true)
+                        method.to_syn_impl_item(true, ctx)
                     },
                 ],
                 Self::EnumDefault(variant) => vec![{
@@ -258,14 +441,65 @@ impl Trait {
                         "default".into(),
                         None,
                         vec![],
-                        Some(Type::from_name(tyname.to_string().into())),
+                        Some(Type::from_name(tyname.to_string())),
                     );
                     method.statements = vec![super::ReturnEnumVariant(
                         tyname.to_string().into_boxed_str(),
                         variant,
                     )
                     .into()];
-                    method.to_syn_impl_item(true)
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::FromEnum(from) => vec![{
+                    let mut method = Method::new(
+                        "from".into(),
+                        None,
+                        vec![InputParameter {
+                            name: "base".into(),
+                            ty: Type::from_name(from.to_string()),
+                            usage: ParameterUsage::Owned,
+                        }],
+                        Some(Type::Basic("Self".into())),
+                    );
+                    method.statements = vec![super::ExprWrapper(syn::Expr::Cast(syn::ExprCast {
+                        attrs: vec![],
+                        expr: Box::new(str_to_exprpath("base")),
+                        as_token: Default::default(),
+                        ty: Box::new(str_to_ty(tyname)),
+                    }))
+                    .into()];
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::EnumTryFrom(underlying, variants) => vec![assoc_ty("Error", &underlying), {
+                    let mut method = Method::new(
+                        "try_from".into(),
+                        None,
+                        vec![InputParameter {
+                            name: "value".into(),
+                            ty: Type::Basic(underlying.clone()),
+                            usage: ParameterUsage::Owned,
+                        }],
+                        Some(Type::ResWith(
+                            Box::new(Type::Basic("Self".into())),
+                            Box::new(Type::Basic(underlying)),
+                        )),
+                    );
+                    method.statements = vec![super::MatchValueToEnum { variants }.into()];
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::TryFromBytes => vec![assoc_ty("Error", "ParseError"), {
+                    let mut method = Method::new(
+                        "try_from".into(),
+                        None,
+                        vec![InputParameter {
+                            name: "bytes".into(),
+                            ty: Type::Slice(Box::new(Type::Basic("u8".into()))),
+                            usage: ParameterUsage::Ref,
+                        }],
+                        Some(Type::ParseRes(Box::new(Type::Basic("Self".into())))),
+                    );
+                    method.statements = vec![super::ForwardTryFrom.into()];
+                    method.to_syn_impl_item(true, ctx)
                 }],
                 Self::FromXid(from) => vec![{
                     let mut method = Method::new(
@@ -273,17 +507,59 @@ impl Trait {
                         None,
                         vec![InputParameter {
                             name: "base".into(),
-                            ty: Type::from_name(from.deref().to_string().into()),
+                            ty: Type::from_name(from.deref().to_string()),
                             usage: ParameterUsage::Owned,
                         }],
                         Some(Type::Basic("Self".into())),
                     );
                     method.statements = vec![super::ConvertXids {
-                        oldname: from.clone(),
                         newname: tyname.to_string().into_boxed_str(),
                     }
                     .into()];
-                    method.to_syn_impl_item(true)
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::XidFromRaw => vec![{
+                    let mut method = Method::new(
+                        "from".into(),
+                        None,
+                        vec![InputParameter {
+                            name: "xid".into(),
+                            ty: Type::Basic("XID".into()),
+                            usage: ParameterUsage::Owned,
+                        }],
+                        Some(Type::Basic("Self".into())),
+                    );
+                    method.statements = vec![super::CreateXidTypeStatement.into()];
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::XidIntoRaw => vec![{
+                    let mut method = Method::new(
+                        "from".into(),
+                        None,
+                        vec![InputParameter {
+                            name: "base".into(),
+                            ty: Type::from_name(tyname.to_string()),
+                            usage: ParameterUsage::Owned,
+                        }],
+                        Some(Type::Basic("Self".into())),
+                    );
+                    method.statements =
+                        vec![super::ExprWrapper(item_field(str_to_exprpath("base"), "xid")).into()];
+                    method.to_syn_impl_item(true, ctx)
+                }],
+                Self::FromEvent(event, variant) => vec![{
+                    let mut method = Method::new(
+                        "from".into(),
+                        None,
+                        vec![InputParameter {
+                            name: "event".into(),
+                            ty: Type::from_name(event.to_string()),
+                            usage: ParameterUsage::Owned,
+                        }],
+                        Some(Type::Basic("Self".into())),
+                    );
+                    method.statements = vec![super::WrapEvent { variant }.into()];
+                    method.to_syn_impl_item(true, ctx)
                 }],
                 Self::BitflagsNot(ty) => vec![bitflags_output_ty(&ty), {
                     let mut method = Method::new(
@@ -316,7 +592,7 @@ impl Trait {
                         })
                     }))
                     .into()];
-                    method.to_syn_impl_item(true)
+                    method.to_syn_impl_item(true, ctx)
                 }],
 
                 Self::BitflagsAnd(ty) => vec![bitflags_output_ty(&ty), {
@@ -355,7 +631,7 @@ impl Trait {
                         })
                     }))
                     .into()];
-                    method.to_syn_impl_item(true)
+                    method.to_syn_impl_item(true, ctx)
                 }],
                 Self::BitflagsOr(ty) => vec![bitflags_output_ty(&ty), {
                     let mut method = Method::new(
@@ -393,7 +669,7 @@ impl Trait {
                         })
                     }))
                     .into()];
-                    method.to_syn_impl_item(true)
+                    method.to_syn_impl_item(true, ctx)
                 }],
                 Self::BitflagsXor(ty) => vec![bitflags_output_ty(&ty), {
                     let mut method = Method::new(
@@ -431,8 +707,14 @@ impl Trait {
                         })
                     }))
                     .into()];
-                    method.to_syn_impl_item(true)
+                    method.to_syn_impl_item(true, ctx)
                 }],
+                Self::Arbitrary(_)
+                | Self::ExtendList(..)
+                | Self::ElidedDebug(..)
+                | Self::IdentityComparison(_) => {
+                    unreachable!()
+                }
             },
         })]
     }
@@ -0,0 +1,278 @@
+// MIT/Apache2 License
+
+use super::{
+    fixed_size_const, primitive_size,
+    syn_util::{item_field, str_to_exprpath, str_to_pathseg},
+    Asb, Item, Method, ParameterUsage, RStruct, Type,
+};
+use crate::lvl2::{Field, Struct as Lvl2Struct, StructureItem, Type as Lvl2Type};
+use heck::SnakeCase;
+use proc_macro2::Span;
+use std::{borrow::Cow, collections::HashMap, iter};
+
+/// Unions that are replaced by hand-written types in the crate proper.
+const HANDWRITTEN: &[&str] = &["ClientMessageData", "NotifyData", "Action", "Behavior"];
+
+/// The largest byte array that still implements `Default`.
+const MAX_DEFAULT_ARRAY: usize = 32;
+
+/// A union of several members that all start at the same byte. This becomes a structure wrapping
+/// the raw bytes, with an accessor that parses each member out of them.
+#[derive(Debug)]
+pub struct RUnion {
+    pub name: Box<str>,
+    pub docs: Option<Box<str>>,
+    pub members: Vec<(String, Lvl2Type)>,
+}
+
+impl From<Lvl2Struct> for RUnion {
+    #[inline]
+    fn from(s: Lvl2Struct) -> Self {
+        let Lvl2Struct {
+            name,
+            brief,
+            desc,
+            fields,
+            ..
+        } = s;
+
+        let members = fields
+            .into_iter()
+            .filter_map(|f| match f {
+                StructureItem::Field(Field { name, ty, .. }) => Some((name, ty)),
+                f => {
+                    log::warn!("Union \"{}\" has a non-field member: {:?}", &name, f);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            docs: match (brief, desc) {
+                (None, None) => None,
+                (Some(doc), None) | (None, Some(doc)) => Some(doc),
+                (Some(brief), Some(desc)) => {
+                    Some(format!("{}\n\n{}", brief, desc).into_boxed_str())
+                }
+            },
+            name,
+            members,
+        }
+    }
+}
+
+#[inline]
+pub fn type_size(sizes: &HashMap<Box<str>, usize>, ty: &Lvl2Type) -> Option<usize> {
+    match primitive_size(ty) {
+        Some(size) => Some(size),
+        None => match ty {
+            Lvl2Type::BasicType(name) => sizes.get(name.as_ref()).copied(),
+            Lvl2Type::Array(name, len) => {
+                Some(sizes.get(name.as_ref()).copied()? * (*len as usize))
+            }
+        },
+    }
+}
+
+#[inline]
+fn struct_size(sizes: &HashMap<Box<str>, usize>, fields: &[StructureItem]) -> Option<usize> {
+    fields.iter().try_fold(0, |sum, item| {
+        Some(
+            sum + match item {
+                StructureItem::Field(Field { ty, .. }) | StructureItem::LenSlot { ty, .. } => {
+                    type_size(sizes, ty)?
+                }
+                StructureItem::Padding { bytes } => *bytes,
+                StructureItem::AlignPad { align } => (align - sum % align) % align,
+                StructureItem::Fd { .. } => 0,
+                StructureItem::List(_) | StructureItem::Switch(_) => return None,
+            },
+        )
+    })
+}
+
+#[inline]
+fn union_size(sizes: &HashMap<Box<str>, usize>, ru: &RUnion) -> Option<usize> {
+    ru.members.iter().try_fold(0, |size, (_, ty)| {
+        Some(std::cmp::max(size, type_size(sizes, ty)?))
+    })
+}
+
+/// Collect the sizes of every type in this module that always takes up the same number of bytes.
+/// Types may be defined after the types that use them, so this runs until nothing new is found.
+#[inline]
+pub fn known_sizes(items: &[Item]) -> HashMap<Box<str>, usize> {
+    let mut sizes: HashMap<Box<str>, usize> = iter::once(("XID".into(), 4)).collect();
+
+    loop {
+        let found = items
+            .iter()
+            .filter_map(|item| {
+                let (name, size) = match item {
+                    Item::RStruct(rs) => (&rs.name, struct_size(&sizes, &rs.fields)),
+                    Item::REnum(re) => (
+                        &re.name,
+                        primitive_size(&Lvl2Type::BasicType(re.underlying.clone())),
+                    ),
+                    Item::RUnion(ru) => (&ru.name, union_size(&sizes, ru)),
+                    Item::Typedef(td) => {
+                        return match sizes.contains_key(td.newname.as_str()) {
+                            true => None,
+                            false => type_size(
+                                &sizes,
+                                &Lvl2Type::BasicType(Cow::Owned(td.oldname.clone())),
+                            )
+                            .map(|size| (td.newname.clone().into_boxed_str(), size)),
+                        };
+                    }
+                    _ => return None,
+                };
+
+                match sizes.contains_key(name) {
+                    true => None,
+                    false => size.map(|size| (name.clone(), size)),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if found.is_empty() {
+            return sizes;
+        }
+        sizes.extend(found);
+    }
+}
+
+/// `Ok(T::from_bytes(&self.bytes)?.0)`
+#[inline]
+fn accessor(name: &str, ty: &Lvl2Type) -> Method {
+    let ty = Type::from_lvl2(ty.clone());
+    let mut method = Method::new(
+        format!("as_{}", name.to_snake_case()).into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::ParseRes(Box::new(ty.clone()))),
+    );
+    let from_bytes = syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(match ty {
+            Type::Basic(ref name) => str_to_exprpath(&format!("{}::from_bytes", name)),
+            ref ty => syn::Expr::Path(syn::ExprPath {
+                attrs: vec![],
+                qself: Some(syn::QSelf {
+                    lt_token: Default::default(),
+                    ty: Box::new(ty.to_syn_ty()),
+                    position: 0,
+                    as_token: None,
+                    gt_token: Default::default(),
+                }),
+                path: syn::Path {
+                    leading_colon: Some(Default::default()),
+                    segments: iter::once(str_to_pathseg("from_bytes")).collect(),
+                },
+            }),
+        }),
+        paren_token: Default::default(),
+        args: iter::once(syn::Expr::Reference(syn::ExprReference {
+            attrs: vec![],
+            and_token: Default::default(),
+            raw: Default::default(),
+            mutability: None,
+            expr: Box::new(item_field(str_to_exprpath("self"), "bytes")),
+        }))
+        .collect(),
+    });
+    method.statements = vec![super::ExprWrapper(syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(str_to_exprpath("Ok")),
+        paren_token: Default::default(),
+        args: iter::once(syn::Expr::Field(syn::ExprField {
+            attrs: vec![],
+            base: Box::new(syn::Expr::Try(syn::ExprTry {
+                attrs: vec![],
+                expr: Box::new(from_bytes),
+                question_token: Default::default(),
+            })),
+            dot_token: Default::default(),
+            member: syn::Member::Unnamed(syn::Index {
+                index: 0,
+                span: Span::call_site(),
+            }),
+        }))
+        .collect(),
+    }))
+    .into()];
+    method
+}
+
+#[inline]
+fn to_rstruct(ru: RUnion, size: usize) -> RStruct {
+    let RUnion {
+        name,
+        docs,
+        members,
+    } = ru;
+
+    let mut rs = RStruct {
+        name,
+        docs,
+        derives: vec!["Clone", "Debug", "Default"],
+        is_transparent: true,
+        is_repr_c: false,
+        defaults: vec![],
+        fields: vec![StructureItem::Field(Field {
+            name: "bytes".into(),
+            ty: Lvl2Type::Array("Card8".into(), size as u64),
+            ..Default::default()
+        })],
+        methods: members
+            .iter()
+            .map(|(name, ty)| accessor(name, ty))
+            .collect(),
+        other_impl_items: vec![fixed_size_const(size)],
+        traits: vec![],
+        asb: Asb::default(),
+        shared_asb: None,
+    };
+    rs.populate_asb();
+    rs
+}
+
+/// Turn each union into a structure wrapping its bytes. Unions that are replaced by hand-written
+/// types, or whose size can't be known ahead of time, are left out.
+#[inline]
+pub fn resolve_unions(items: &mut Vec<Item>) {
+    let sizes = known_sizes(items);
+
+    *items = items
+        .drain(..)
+        .filter_map(|item| match item {
+            Item::RUnion(ru) => {
+                if HANDWRITTEN.contains(&&*ru.name) {
+                    return None;
+                }
+
+                match sizes.get(&ru.name).copied() {
+                    Some(size) if size <= MAX_DEFAULT_ARRAY => {
+                        Some(Item::RStruct(to_rstruct(ru, size)))
+                    }
+                    Some(size) => {
+                        log::warn!(
+                            "Union \"{}\" is {} bytes long, which is too long to be defaulted",
+                            &ru.name,
+                            size
+                        );
+                        None
+                    }
+                    None => {
+                        log::warn!(
+                            "Union \"{}\" does not have a fixed size, so it can't be generated",
+                            &ru.name
+                        );
+                        None
+                    }
+                }
+            }
+            item => Some(item),
+        })
+        .collect();
+}
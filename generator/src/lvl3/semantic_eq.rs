@@ -0,0 +1,65 @@
+// MIT/Apache2 License
+
+use super::{is_reply, syn_util::doc_attrs, Item};
+use crate::lvl2::{Field, List, StructureItem, Switch};
+use proc_macro2::{Ident, Span};
+
+/// The fields of a reply's header that describe how it was sent, rather than what it holds.
+pub(super) const HEADER_FIELDS: &[&str] = &["reply_type", "sequence", "length"];
+
+/// Collect the names of the fields of a structure that hold its contents, including those in switch
+/// cases.
+#[inline]
+fn content_fields(items: &[StructureItem], fields: &mut Vec<Ident>) {
+    items.iter().for_each(|item| match item {
+        StructureItem::Field(Field { name, .. })
+        | StructureItem::List(List { name, .. })
+        | StructureItem::Fd { name }
+            if !HEADER_FIELDS.contains(&name.as_str()) =>
+        {
+            fields.push(Ident::new(name, Span::call_site()));
+        }
+        StructureItem::Switch(Switch { cases, .. }) => {
+            cases.iter().for_each(|c| content_fields(&c.items, fields))
+        }
+        _ => (),
+    });
+}
+
+/// `pub fn semantically_eq(&self, other: &Self) -> bool`, which compares every field but those in
+/// `HEADER_FIELDS`.
+#[inline]
+fn semantically_eq(fields: &[Ident]) -> syn::ImplItem {
+    let docs = doc_attrs(
+        "Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the \
+         type, sequence number and length in the header, which depend on how the reply was sent.",
+    );
+    let body: syn::Expr = match fields.is_empty() {
+        true => syn::parse_quote! { true },
+        false => syn::parse_quote! { #(self.#fields == other.#fields)&&* },
+    };
+
+    syn::parse_quote! {
+        #(#docs)*
+        #[inline]
+        #[must_use]
+        pub fn semantically_eq(&self, other: &Self) -> bool {
+            #body
+        }
+    }
+}
+
+/// Add a `semantically_eq` method to every reply that can be compared, so that replies that were
+/// parsed from the server can be checked against replies that were built by hand.
+#[inline]
+pub fn populate_semantic_eqs(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if is_reply(rs) && rs.derives.contains(&"PartialEq") {
+                let mut fields = vec![];
+                content_fields(&rs.fields, &mut fields);
+                rs.other_impl_items.push(semantically_eq(&fields));
+            }
+        }
+    });
+}
@@ -0,0 +1,17 @@
+// MIT/Apache2 License
+
+use super::Item;
+
+/// The traits derived on every item when `serde` support is enabled.
+const SERDE_DERIVES: &[&str] = &["serde::Serialize", "serde::Deserialize"];
+
+/// Add `serde::Serialize` and `serde::Deserialize` to the derives of every structure and
+/// enumeration. These are put behind the `serde` feature when the items are converted to syn.
+#[inline]
+pub fn derive_serde(items: &mut [Item]) {
+    items.iter_mut().for_each(|item| match item {
+        Item::RStruct(rs) => rs.derives.extend(SERDE_DERIVES),
+        Item::REnum(re) => re.derives.extend(SERDE_DERIVES),
+        _ => (),
+    });
+}
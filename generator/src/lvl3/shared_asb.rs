@@ -0,0 +1,277 @@
+// MIT/Apache2 License
+
+use super::{is_reply, Asb, EmitContext, Item, RStruct, Trait};
+use crate::lvl2::{Field, List, StructureItem, Switch, Type as Lvl2Type};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use std::collections::{HashMap, HashSet};
+
+/// A byte sequence implementation that is identical to that of at least one other structure, save for
+/// the name of the structure. It is generated by a macro that all of these structures invoke.
+#[derive(Debug, Clone)]
+pub enum SharedAsb {
+    /// This structure is the first to use the implementation, so it defines the macro.
+    Define {
+        macro_name: Box<str>,
+        body: TokenStream,
+    },
+    /// This structure invokes a macro that an earlier structure defined.
+    Invoke(Box<str>),
+}
+
+impl SharedAsb {
+    #[inline]
+    fn macro_name(&self) -> &str {
+        match self {
+            SharedAsb::Define { macro_name, .. } | SharedAsb::Invoke(macro_name) => macro_name,
+        }
+    }
+
+    #[inline]
+    pub fn to_syn_item(&self, tyname: &str) -> Vec<syn::Item> {
+        let macro_name = Ident::new(self.macro_name(), Span::call_site());
+        let tyname = Ident::new(tyname, Span::call_site());
+        let definition = match self {
+            SharedAsb::Define { body, .. } => Some(quote! {
+                macro_rules! #macro_name {
+                    ($name:ident) => { #body };
+                }
+            }),
+            SharedAsb::Invoke(_) => None,
+        };
+
+        definition
+            .into_iter()
+            .chain(Some(quote! { #macro_name! { #tyname } }))
+            .map(|tokens| syn::parse2(tokens).expect("Macro should be a valid item"))
+            .collect()
+    }
+}
+
+/// `$name`, the variable that holds the name of the structure in the macro.
+#[inline]
+fn name_var() -> [TokenTree; 2] {
+    [
+        Punct::new('$', Spacing::Alone).into(),
+        Ident::new("name", Span::call_site()).into(),
+    ]
+}
+
+/// Replace every mention of the structure's name with the macro variable, so that the implementations of
+/// two structures can be compared and shared.
+#[inline]
+fn replace_name(tokens: TokenStream, tyname: &str) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|tt| -> Vec<TokenTree> {
+            match tt {
+                TokenTree::Ident(ref ident) if ident == tyname => name_var().to_vec(),
+                TokenTree::Group(group) => {
+                    let mut new_group =
+                        Group::new(group.delimiter(), replace_name(group.stream(), tyname));
+                    new_group.set_span(group.span());
+                    vec![new_group.into()]
+                }
+                TokenTree::Literal(lit) => match name_in_literal(&lit, tyname) {
+                    Some(pieces) => concat_with_name(pieces),
+                    None => vec![lit.into()],
+                },
+                tt => vec![tt],
+            }
+        })
+        .collect()
+}
+
+/// If the literal is a string that contains the structure's name, split it around the name.
+#[inline]
+fn name_in_literal(lit: &Literal, tyname: &str) -> Option<Vec<String>> {
+    let value = syn::parse_str::<syn::LitStr>(&lit.to_string())
+        .ok()?
+        .value();
+    if value.contains(tyname) {
+        Some(value.split(tyname).map(String::from).collect())
+    } else {
+        None
+    }
+}
+
+/// `concat!("a", stringify!($name), "b")`
+#[inline]
+fn concat_with_name(pieces: Vec<String>) -> Vec<TokenTree> {
+    let stringify: TokenStream = quote! { stringify! };
+    let stringify_name = stringify
+        .into_iter()
+        .chain(Some(
+            Group::new(Delimiter::Parenthesis, name_var().iter().cloned().collect()).into(),
+        ))
+        .collect::<TokenStream>();
+
+    let last = pieces.len() - 1;
+    let args: TokenStream = pieces
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, piece)| {
+            let mut args = vec![];
+            if !piece.is_empty() {
+                args.push(Literal::string(&piece).into_token_stream());
+            }
+            if i != last {
+                args.push(stringify_name.clone());
+            }
+            args
+        })
+        .enumerate()
+        .flat_map(|(i, arg)| {
+            let comma = match i {
+                0 => None,
+                _ => Some(TokenTree::from(Punct::new(',', Spacing::Alone))),
+            };
+            comma.into_iter().chain(arg)
+        })
+        .collect();
+
+    quote! { concat!(#args) }.into_iter().collect()
+}
+
+/// Find the structures whose byte sequence implementations are identical save for their names, and have
+/// them share one implementation generated by a macro.
+#[inline]
+pub fn share_byte_sequences(items: &mut [Item], ctx: &EmitContext) {
+    let mut bodies: HashMap<usize, TokenStream> = HashMap::new();
+    let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+
+    items.iter().enumerate().for_each(|(i, item)| {
+        if let Item::RStruct(rs) = item {
+            if rs.asb.is_none || rs.shared_asb.is_some() {
+                return;
+            }
+
+            let body = replace_name(rendered_asb(rs, ctx), &rs.name);
+            groups.entry(body.to_string()).or_default().push(i);
+            bodies.insert(i, body);
+        }
+    });
+
+    let mut groups: Vec<Vec<usize>> = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect();
+    // keep the output stable between runs
+    groups.sort_unstable();
+
+    let mut used_names: HashSet<Box<str>> = HashSet::new();
+    groups.into_iter().for_each(|group| {
+        let body = bodies.remove(&group[0]).unwrap();
+        let macro_name = match &items[group[0]] {
+            Item::RStruct(rs) => {
+                let name = shape_name(rs).unwrap_or_else(|| hashed_name(rs, &body));
+                if used_names.contains(&name) {
+                    hashed_name(rs, &body)
+                } else {
+                    name
+                }
+            }
+            _ => unreachable!(),
+        };
+        used_names.insert(macro_name.clone());
+
+        group.into_iter().enumerate().for_each(|(n, i)| {
+            if let Item::RStruct(rs) = &mut items[i] {
+                rs.asb = Asb::none();
+                rs.shared_asb = Some(match n {
+                    0 => SharedAsb::Define {
+                        macro_name: macro_name.clone(),
+                        body: body.clone(),
+                    },
+                    _ => SharedAsb::Invoke(macro_name.clone()),
+                });
+            }
+        });
+    });
+}
+
+/// Fields that every structure of a kind begins with, which say nothing about its shape.
+const HEADER_FIELDS: &[&str] = &[
+    "req_type",
+    "length",
+    "reply_type",
+    "sequence",
+    "event_type",
+    "extension",
+    "evtype",
+    "error_type",
+    "error_code",
+];
+
+/// What kind of structure this is, for the name of its macro.
+#[inline]
+fn kind(rs: &RStruct) -> &'static str {
+    rs.traits
+        .iter()
+        .find_map(|t| match t {
+            Trait::Request(..) => Some("request"),
+            Trait::Event(..) => Some("event"),
+            Trait::Error(_) => Some("error"),
+            Trait::BitflagsNot(_) => Some("flags"),
+            _ => None,
+        })
+        .unwrap_or(if is_reply(rs) { "reply" } else { "struct" })
+}
+
+/// Name the macro after the fields of the structures that share it, e.g. `impl_window_only_request_asb`.
+/// Structures can only share an implementation if their fields have the same names, so the name is the
+/// same whichever structure comes first. This gives up if there are too many fields to name.
+#[inline]
+fn shape_name(rs: &RStruct) -> Option<Box<str>> {
+    let kind = kind(rs);
+    if kind == "flags" {
+        return match rs.fields.first() {
+            Some(StructureItem::Field(Field {
+                ty: Lvl2Type::BasicType(ty),
+                ..
+            })) => Some(format!("impl_{}_flags_asb", ty).into_boxed_str()),
+            _ => None,
+        };
+    }
+
+    let fields: Vec<&str> = rs
+        .fields
+        .iter()
+        .filter_map(|item| match item {
+            StructureItem::Field(Field { name, .. })
+            | StructureItem::List(List { name, .. })
+            | StructureItem::Switch(Switch { name, .. })
+            | StructureItem::Fd { name } => Some(name.trim_start_matches('_')),
+            _ => None,
+        })
+        .filter(|name| !HEADER_FIELDS.contains(name))
+        .collect();
+    let shape = match fields.len() {
+        0 => "empty".to_string(),
+        1 => format!("{}_only", fields[0]),
+        2 | 3 => fields.join("_"),
+        _ => return None,
+    };
+    Some(format!("impl_{}_{}_asb", shape, kind).into_boxed_str())
+}
+
+/// Name the macro after a hash of the implementation it generates, for structures with too many fields
+/// to name it after. The hash is FNV-1a, so it's stable between runs and compiler versions.
+#[inline]
+fn hashed_name(rs: &RStruct, body: &TokenStream) -> Box<str> {
+    let hash = body.to_string().bytes().fold(0x811c_9dc5u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("impl_{}_asb_{:08x}", kind(rs), hash).into_boxed_str()
+}
+
+/// The byte sequence implementation of a structure, as tokens.
+#[inline]
+fn rendered_asb(rs: &RStruct, ctx: &EmitContext) -> TokenStream {
+    rs.asb
+        .clone()
+        .to_syn_item(&rs.name, ctx)
+        .into_iter()
+        .map(|item| item.into_token_stream())
+        .collect()
+}
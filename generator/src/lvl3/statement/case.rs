@@ -0,0 +1,191 @@
+// MIT/Apache2 License
+
+use super::{bytes_slice, index_plus_equal, let_statement, Statement, SumStatement};
+use crate::{
+    lvl2::UseCondition,
+    lvl3::{
+        syn_util::{default_call, item_field, str_to_exprpath},
+        Type,
+    },
+};
+use proc_macro2::Span;
+use std::{iter, rc::Rc};
+
+/// Serialize the items of a switch case if its condition is met.
+#[derive(Debug, Clone)]
+pub struct SwitchCaseAsBytes {
+    pub condition: Rc<UseCondition>,
+    pub condname: Box<str>,
+    pub statements: Vec<SumStatement>,
+}
+
+/// Deserialize the items of a switch case if its condition is met. Each output is the name of an
+/// item, and whether or not it needs to be wrapped in `Some`. If the condition is not met, every
+/// output is set to its default value.
+#[derive(Debug, Clone)]
+pub struct SwitchCaseFromBytes {
+    pub condition: Rc<UseCondition>,
+    pub condname: Box<str>,
+    pub statements: Vec<SumStatement>,
+    pub outputs: Vec<(Box<str>, bool)>,
+}
+
+/// Serialize a bitmask that a switch depends on, after setting each of the `cases` bits according
+/// to whether or not the field in that case is present. `condname` is bound to the serialized
+/// bitmask, so the switch's cases agree with it.
+#[derive(Debug, Clone)]
+pub struct SyncMask {
+    pub name: Box<str>,
+    pub ty: Type,
+    pub condname: Box<str>,
+    pub cases: Vec<(Rc<UseCondition>, Box<str>)>,
+}
+
+impl Statement for SyncMask {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let call = |target: syn::Expr, method: &str, args| {
+            syn::Expr::Call(syn::ExprCall {
+                attrs: vec![],
+                func: Box::new(item_field(target, method)),
+                paren_token: Default::default(),
+                args,
+            })
+        };
+
+        iter::once(let_statement(
+            &self.condname,
+            self.ty.clone(),
+            item_field(str_to_exprpath("self"), &self.name),
+            true,
+        ))
+        .chain(self.cases.iter().map(|(condition, field)| {
+            condition.to_set_bit_expr(
+                str_to_exprpath(&self.condname),
+                call(
+                    item_field(str_to_exprpath("self"), field),
+                    "is_some",
+                    Default::default(),
+                ),
+            )
+        }))
+        .chain(iter::once(index_plus_equal(call(
+            str_to_exprpath(&self.condname),
+            "as_bytes",
+            iter::once(bytes_slice(true)).collect(),
+        ))))
+        .map(|e| syn::Stmt::Semi(e, Default::default()))
+        .collect()
+    }
+}
+
+impl Statement for SwitchCaseAsBytes {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Expr(syn::Expr::If(syn::ExprIf {
+            attrs: vec![],
+            if_token: Default::default(),
+            cond: Box::new(self.condition.to_cond_expr(&self.condname)),
+            then_branch: syn::Block {
+                brace_token: Default::default(),
+                stmts: self
+                    .statements
+                    .iter()
+                    .flat_map(|s| s.to_syn_statement())
+                    .collect(),
+            },
+            else_branch: None,
+        }))]
+    }
+}
+
+#[inline]
+fn tuple_of<I: IntoIterator<Item = T>, T, P>(items: I) -> syn::punctuated::Punctuated<T, P>
+where
+    P: Default,
+{
+    let mut p: syn::punctuated::Punctuated<T, P> = items.into_iter().collect();
+    // a tuple with a single item needs a trailing comma
+    if p.len() == 1 {
+        p.push_punct(Default::default());
+    }
+    p
+}
+
+impl Statement for SwitchCaseFromBytes {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let present = self.outputs.iter().map(|(name, wrap)| {
+            let e = str_to_exprpath(name);
+            if *wrap {
+                syn::Expr::Call(syn::ExprCall {
+                    attrs: vec![],
+                    func: Box::new(str_to_exprpath("Some")),
+                    paren_token: Default::default(),
+                    args: iter::once(e).collect(),
+                })
+            } else {
+                e
+            }
+        });
+
+        let if_expr = syn::Expr::If(syn::ExprIf {
+            attrs: vec![],
+            if_token: Default::default(),
+            cond: Box::new(self.condition.to_cond_expr(&self.condname)),
+            then_branch: syn::Block {
+                brace_token: Default::default(),
+                stmts: self
+                    .statements
+                    .iter()
+                    .flat_map(|s| s.to_syn_statement())
+                    .chain(iter::once(syn::Stmt::Expr(syn::Expr::Tuple(
+                        syn::ExprTuple {
+                            attrs: vec![],
+                            paren_token: Default::default(),
+                            elems: tuple_of(present),
+                        },
+                    ))))
+                    .collect(),
+            },
+            else_branch: Some((
+                Default::default(),
+                Box::new(syn::Expr::Block(syn::ExprBlock {
+                    attrs: vec![],
+                    label: None,
+                    block: syn::Block {
+                        brace_token: Default::default(),
+                        stmts: vec![syn::Stmt::Expr(syn::Expr::Tuple(syn::ExprTuple {
+                            attrs: vec![],
+                            paren_token: Default::default(),
+                            elems: tuple_of(self.outputs.iter().map(|_| default_call())),
+                        }))],
+                    },
+                })),
+            )),
+        });
+
+        vec![syn::Stmt::Semi(
+            syn::Expr::Let(syn::ExprLet {
+                attrs: vec![],
+                let_token: Default::default(),
+                pat: syn::Pat::Tuple(syn::PatTuple {
+                    attrs: vec![],
+                    paren_token: Default::default(),
+                    elems: tuple_of(self.outputs.iter().map(|(name, _)| {
+                        syn::Pat::Ident(syn::PatIdent {
+                            attrs: vec![],
+                            by_ref: None,
+                            mutability: None,
+                            ident: syn::Ident::new(name, Span::call_site()),
+                            subpat: None,
+                        })
+                    })),
+                }),
+                eq_token: Default::default(),
+                expr: Box::new(if_expr),
+            }),
+            Default::default(),
+        )]
+    }
+}
@@ -0,0 +1,36 @@
+// MIT/Apache2 License
+
+use super::Statement;
+use proc_macro2::{Ident, Span};
+
+/// Return `None` if there is nothing left to read, or else a copy of the request whose offset has moved
+/// past the items in this reply.
+#[derive(Debug, Clone)]
+pub struct ContinueRequest {
+    pub request: Box<str>,
+    /// The field of the request holding the offset, in units of four bytes.
+    pub offset: &'static str,
+    /// The field of the reply holding the number of items it carries.
+    pub count: &'static str,
+}
+
+impl Statement for ContinueRequest {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let request = Ident::new(&self.request, Span::call_site());
+        let offset = Ident::new(self.offset, Span::call_site());
+        let count = Ident::new(self.count, Span::call_site());
+
+        let block: syn::Block = syn::parse_quote! {{
+            if self.bytes_after == 0 {
+                return None;
+            }
+            let read = (self.#count as u32) * (self.format as u32 / 8);
+            Some(#request {
+                #offset: request.#offset + (read / 4),
+                ..request.clone()
+            })
+        }};
+        block.stmts
+    }
+}
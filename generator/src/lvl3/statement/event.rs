@@ -0,0 +1,266 @@
+// MIT/Apache2 License
+
+use super::Statement;
+use crate::lvl3::{
+    syn_util::{int_litexpr_int, str_to_exprpath, str_to_pathseg},
+    GENERIC_EVENT,
+};
+use proc_macro2::Span;
+use std::iter;
+
+/// `Self::Variant(arg)`
+#[inline]
+fn variant_call(variant: &str, arg: syn::Expr) -> syn::Expr {
+    syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(syn::Expr::Path(syn::ExprPath {
+            attrs: vec![],
+            qself: None,
+            path: syn::Path {
+                leading_colon: None,
+                segments: vec![str_to_pathseg("Self"), str_to_pathseg(variant)]
+                    .into_iter()
+                    .collect(),
+            },
+        })),
+        paren_token: Default::default(),
+        args: iter::once(arg).collect(),
+    })
+}
+
+/// Wrap the "event" variable in the given variant of the event enum.
+#[derive(Debug, Clone)]
+pub struct WrapEvent {
+    pub variant: Box<str>,
+}
+
+impl Statement for WrapEvent {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Expr(variant_call(
+            &self.variant,
+            str_to_exprpath("event"),
+        ))]
+    }
+}
+
+/// Match the "opcode" variable to the event it belongs to, and parse that event from the "bytes"
+/// variable. An opcode without an event is reported as a parse error. If there are generic events,
+/// the bytes are checked for one first, and it is matched by its event type instead.
+#[derive(Debug, Clone)]
+pub struct MatchOpcodeToEvent {
+    /// The variant, the name of the event structure and the opcode of each event.
+    pub events: Vec<(Box<str>, Box<str>, u64)>,
+    /// The variant, the name of the event structure and the event type of each generic event.
+    pub generic_events: Vec<(Box<str>, Box<str>, u64)>,
+}
+
+impl Statement for MatchOpcodeToEvent {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        // Ok(Self::Variant(FooEvent::from_bytes(bytes)?.0))
+        let parse_event = |variant: &str, event: &str| {
+            let parsed = syn::Expr::Try(syn::ExprTry {
+                attrs: vec![],
+                expr: Box::new(syn::Expr::Call(syn::ExprCall {
+                    attrs: vec![],
+                    func: Box::new(syn::Expr::Path(syn::ExprPath {
+                        attrs: vec![],
+                        qself: None,
+                        path: syn::Path {
+                            leading_colon: None,
+                            segments: vec![str_to_pathseg(event), str_to_pathseg("from_bytes")]
+                                .into_iter()
+                                .collect(),
+                        },
+                    })),
+                    paren_token: Default::default(),
+                    args: iter::once(str_to_exprpath("bytes")).collect(),
+                })),
+                question_token: Default::default(),
+            });
+
+            syn::Expr::Call(syn::ExprCall {
+                attrs: vec![],
+                func: Box::new(str_to_exprpath("Ok")),
+                paren_token: Default::default(),
+                args: iter::once(variant_call(
+                    variant,
+                    syn::Expr::Field(syn::ExprField {
+                        attrs: vec![],
+                        base: Box::new(parsed),
+                        dot_token: Default::default(),
+                        member: syn::Member::Unnamed(syn::Index {
+                            index: 0,
+                            span: Span::call_site(),
+                        }),
+                    }),
+                ))
+                .collect(),
+            })
+        };
+
+        // Err(ParseError { field: "opcode", offset: 0 })
+        let unknown_opcode = |field: &str, offset: usize| {
+            syn::Expr::Call(syn::ExprCall {
+                attrs: vec![],
+                func: Box::new(str_to_exprpath("Err")),
+                paren_token: Default::default(),
+                args: iter::once(syn::Expr::Struct(syn::ExprStruct {
+                    attrs: vec![],
+                    path: syn::Path::from(str_to_pathseg("ParseError")),
+                    brace_token: Default::default(),
+                    fields: vec![
+                        syn::FieldValue {
+                            attrs: vec![],
+                            member: syn::Member::Named(syn::Ident::new("field", Span::call_site())),
+                            colon_token: Some(Default::default()),
+                            expr: syn::Expr::Lit(syn::ExprLit {
+                                attrs: vec![],
+                                lit: syn::Lit::Str(syn::LitStr::new(field, Span::call_site())),
+                            }),
+                        },
+                        syn::FieldValue {
+                            attrs: vec![],
+                            member: syn::Member::Named(syn::Ident::new(
+                                "offset",
+                                Span::call_site(),
+                            )),
+                            colon_token: Some(Default::default()),
+                            expr: int_litexpr_int(offset),
+                        },
+                    ]
+                    .into_iter()
+                    .collect(),
+                    dot2_token: None,
+                    rest: None,
+                }))
+                .collect(),
+            })
+        };
+
+        // match [scrutinee] { [opcode] => Ok(Self::Variant(..)), _ => Err(..) }
+        let match_events =
+            |scrutinee: &str, events: &[(Box<str>, Box<str>, u64)], unknown: syn::Expr| {
+                syn::Expr::Match(syn::ExprMatch {
+                    attrs: vec![],
+                    match_token: Default::default(),
+                    expr: Box::new(str_to_exprpath(scrutinee)),
+                    brace_token: Default::default(),
+                    arms: events
+                        .iter()
+                        .map(|(variant, event, opcode)| syn::Arm {
+                            attrs: vec![],
+                            pat: syn::Pat::Lit(syn::PatLit {
+                                attrs: vec![],
+                                expr: Box::new(int_litexpr_int(opcode)),
+                            }),
+                            guard: None,
+                            fat_arrow_token: Default::default(),
+                            body: Box::new(parse_event(variant, event)),
+                            comma: Some(Default::default()),
+                        })
+                        .chain(iter::once(syn::Arm {
+                            attrs: vec![],
+                            pat: syn::Pat::Wild(syn::PatWild {
+                                attrs: vec![],
+                                underscore_token: Default::default(),
+                            }),
+                            guard: None,
+                            fat_arrow_token: Default::default(),
+                            body: Box::new(unknown),
+                            comma: None,
+                        }))
+                        .collect(),
+                })
+            };
+
+        let match_opcode = syn::Stmt::Expr(match_events(
+            "opcode",
+            &self.events,
+            unknown_opcode("opcode", 0),
+        ));
+        if self.generic_events.is_empty() {
+            return vec![match_opcode];
+        }
+
+        // the generic event of the core protocol is numbered by its response type, and stands in for
+        // every generic event that isn't otherwise known
+        let (catch_all, generic_events): (Vec<_>, Vec<_>) = self
+            .generic_events
+            .iter()
+            .cloned()
+            .partition(|(_, _, opcode)| *opcode == GENERIC_EVENT);
+        let unknown_evtype = match catch_all.first() {
+            Some((variant, event, _)) => parse_event(variant, event),
+            None => unknown_opcode("evtype", 8),
+        };
+
+        // if let Some((evtype, bytes)) = generic_event_bytes(bytes)? { return match evtype { .. }; }
+        let check_generic: syn::Stmt = match generic_events.is_empty() {
+            true => syn::parse_quote! {
+                if let Some((_, bytes)) = generic_event_bytes(bytes)? {
+                    return #unknown_evtype;
+                }
+            },
+            false => {
+                let match_evtype = match_events("evtype", &generic_events, unknown_evtype);
+                syn::parse_quote! {
+                    if let Some((evtype, bytes)) = generic_event_bytes(bytes)? {
+                        return #match_evtype;
+                    }
+                }
+            }
+        };
+        vec![check_generic, match_opcode]
+    }
+}
+
+/// Match on the variant of the event enum, and give the response type of the event it holds.
+#[derive(Debug, Clone)]
+pub struct MatchEventToResponseType {
+    /// The variant and the name of the event structure of each event.
+    pub events: Vec<(Box<str>, Box<str>)>,
+}
+
+impl Statement for MatchEventToResponseType {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Expr(syn::Expr::Match(syn::ExprMatch {
+            attrs: vec![],
+            match_token: Default::default(),
+            expr: Box::new(str_to_exprpath("self")),
+            brace_token: Default::default(),
+            arms: self
+                .events
+                .iter()
+                .map(|(variant, event)| syn::Arm {
+                    attrs: vec![],
+                    // Self::Variant(_) => FooEvent::RESPONSE_TYPE
+                    pat: syn::Pat::TupleStruct(syn::PatTupleStruct {
+                        attrs: vec![],
+                        path: syn::Path {
+                            leading_colon: None,
+                            segments: vec![str_to_pathseg("Self"), str_to_pathseg(variant)]
+                                .into_iter()
+                                .collect(),
+                        },
+                        pat: syn::PatTuple {
+                            attrs: vec![],
+                            paren_token: Default::default(),
+                            elems: iter::once(syn::Pat::Wild(syn::PatWild {
+                                attrs: vec![],
+                                underscore_token: Default::default(),
+                            }))
+                            .collect(),
+                        },
+                    }),
+                    guard: None,
+                    fat_arrow_token: Default::default(),
+                    body: Box::new(str_to_exprpath(&format!("{}::RESPONSE_TYPE", event))),
+                    comma: Some(Default::default()),
+                })
+                .collect(),
+        }))]
+    }
+}
@@ -0,0 +1,81 @@
+// MIT/Apache2 License
+
+use super::Statement;
+use crate::lvl3::Type;
+use proc_macro2::{Ident, Span};
+use std::fmt;
+
+/// Create an item of a structure out of fuzzing input. If the item is a list or a string whose length
+/// is described by the other fields of the structure, it is created with that length.
+#[derive(Clone)]
+pub struct ArbitraryItem {
+    pub name: Box<str>,
+    pub ty: Option<Type>,
+    pub len: Option<syn::Expr>,
+    pub string: bool,
+}
+
+impl fmt::Debug for ArbitraryItem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArbitraryItem")
+            .field("name", &self.name)
+            .field("ty", &self.ty)
+            .field("string", &self.string)
+            .finish()
+    }
+}
+
+impl Statement for ArbitraryItem {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let name = Ident::new(&self.name, Span::call_site());
+        let stmt: syn::Stmt = match (&self.len, &self.ty) {
+            (Some(len), _) if self.string => syn::parse_quote! {
+                let #name = arbitrary_string(u, #len)?;
+            },
+            (Some(len), _) => syn::parse_quote! {
+                let #name = arbitrary_list(u, #len)?;
+            },
+            (None, Some(ty)) => {
+                let ty = ty.to_syn_ty();
+                syn::parse_quote! {
+                    let #name: #ty = u.arbitrary()?;
+                }
+            }
+            (None, None) => syn::parse_quote! {
+                let #name = u.arbitrary()?;
+            },
+        };
+        vec![stmt]
+    }
+}
+
+/// Create data whose items are as wide as the `format` field says out of fuzzing input, and set
+/// `format` and the field that holds the number of items to agree with it.
+#[derive(Debug, Clone)]
+pub struct ArbitraryFormatted {
+    pub name: Box<str>,
+    pub count: Box<str>,
+    pub count_ty: Type,
+}
+
+impl Statement for ArbitraryFormatted {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let name = Ident::new(&self.name, Span::call_site());
+        let count = Ident::new(&self.count, Span::call_site());
+        let count_ty = self.count_ty.to_syn_ty();
+        vec![
+            syn::parse_quote! {
+                let #name: PropertyData = u.arbitrary()?;
+            },
+            syn::parse_quote! {
+                let format = #name.format();
+            },
+            syn::parse_quote! {
+                let #count = #name.len() as #count_ty;
+            },
+        ]
+    }
+}
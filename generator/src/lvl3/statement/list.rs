@@ -1,22 +1,24 @@
 // MIT/Apache2 License
 
-use super::{bytes_slice, index_plus_equal, let_statement, Statement};
+use super::{bytes_slice, checked_parse, index_plus_equal, let_statement, Statement};
 use crate::{
     lvl2::MaybeString,
     lvl3::{
         cast_to_usize,
-        syn_util::{item_field, str_to_exprpath},
+        syn_util::{int_litexpr_int, item_field, str_to_exprpath},
         Type,
     },
 };
 use proc_macro2::Span;
-use std::{borrow::Cow, fmt, iter};
+use std::{fmt, iter};
 
 #[derive(Debug, Clone)]
 pub struct AsBytesList {
     pub name: Box<str>,
     pub ty: MaybeString,
     pub pad: Option<usize>,
+    /// The alignment that each element is padded out to, if the elements are padded.
+    pub element_pad: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -25,21 +27,59 @@ pub struct FromBytesList {
     pub ty: MaybeString,
     pub len: syn::Expr,
     pub pad: Option<usize>,
+    /// The alignment that each element is padded out to, if the elements are padded.
+    pub element_pad: Option<usize>,
+    /// The length is a number of bytes rather than a number of items, as it is for lists that take up
+    /// the rest of a request.
+    pub in_bytes: bool,
 }
 
-#[derive(Debug, Clone)]
-pub struct FromBytesListRemainder {
+impl fmt::Debug for FromBytesList {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FromBytesList")
+    }
+}
+
+/// Borrow a list of plain data from the bytes without copying it, and return the borrowed list.
+#[derive(Clone)]
+pub struct ReturnBorrowedList {
     pub name: Box<str>,
-    pub ty: MaybeString,
+    pub ty: Type,
+    pub len: syn::Expr,
 }
 
-impl fmt::Debug for FromBytesList {
+impl fmt::Debug for ReturnBorrowedList {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("FromBytesList")
+        f.write_str("ReturnBorrowedList")
     }
 }
 
+/// Return an iterator that parses the items of a list as it goes.
+#[derive(Clone)]
+pub struct ReturnListIter {
+    pub name: Box<str>,
+    pub len: syn::Expr,
+    pub element_pad: Option<usize>,
+}
+
+impl fmt::Debug for ReturnListIter {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ReturnListIter")
+    }
+}
+
+/// Read data whose items are as wide as the `format` field says, which has already been read, and
+/// refuse formats that the items can't have.
+#[derive(Debug, Clone)]
+pub struct FromBytesFormatted {
+    pub name: Box<str>,
+    /// The field that holds the number of items.
+    pub count: Box<str>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AppendLengthToIndex {
     pub owner: Box<str>,
@@ -66,9 +106,10 @@ impl Statement for AsBytesList {
                 Type::Basic("usize".into()),
                 syn::Expr::Call(syn::ExprCall {
                     attrs: vec![],
-                    func: Box::new(str_to_exprpath(match self.ty {
-                        MaybeString::IsAString => "string_as_bytes",
-                        MaybeString::NotAString(_) => "vector_as_bytes",
+                    func: Box::new(str_to_exprpath(match (&self.ty, self.element_pad) {
+                        (MaybeString::IsAString, _) => "string_as_bytes",
+                        (MaybeString::NotAString(_), None) => "vector_as_bytes",
+                        (MaybeString::NotAString(_), Some(_)) => "padded_vector_as_bytes",
                     })),
                     paren_token: Default::default(),
                     args: vec![
@@ -82,6 +123,7 @@ impl Statement for AsBytesList {
                         bytes_slice(true),
                     ]
                     .into_iter()
+                    .chain(self.element_pad.map(int_litexpr_int))
                     .collect(),
                 }),
                 false,
@@ -98,7 +140,7 @@ impl Statement for AsBytesList {
 
         iter::once(s)
             .chain(iter::once(i))
-            .chain(p.to_syn_statement())
+            .chain(p.to_zeroing_statement())
             .collect()
     }
 }
@@ -151,21 +193,25 @@ impl Statement for FromBytesList {
                     })),
                 }),
                 eq_token: Default::default(),
-                expr: Box::new(syn::Expr::Try(syn::ExprTry {
-                    attrs: vec![],
-                    expr: Box::new(syn::Expr::Call(syn::ExprCall {
-                        attrs: vec![],
-                        func: Box::new(str_to_exprpath(match self.ty {
-                            MaybeString::IsAString => "string_from_bytes",
-                            MaybeString::NotAString(_) => "vector_from_bytes",
-                        })),
-                        paren_token: Default::default(),
-                        args: vec![bytes_slice(false), cast_to_usize(self.len.clone())]
-                            .into_iter()
-                            .collect(),
-                    })),
-                    question_token: Default::default(),
-                })),
+                expr: Box::new(match (&self.ty, self.in_bytes, self.element_pad) {
+                    (MaybeString::NotAString(_), false, Some(align)) => {
+                        let len = cast_to_usize(self.len.clone());
+                        let align = int_litexpr_int(align);
+                        let name = &*self.name;
+                        syn::parse_quote! {
+                            parse_padded_vector(bytes, index, #len, #align, #name)?
+                        }
+                    }
+                    (ty, in_bytes, _) => checked_parse(
+                        match (ty, in_bytes) {
+                            (MaybeString::IsAString, _) => "parse_string",
+                            (MaybeString::NotAString(_), false) => "parse_vector",
+                            (MaybeString::NotAString(_), true) => "parse_remainder",
+                        },
+                        Some(cast_to_usize(self.len.clone())),
+                        &self.name,
+                    ),
+                }),
             }),
             Default::default(),
         );
@@ -184,6 +230,99 @@ impl Statement for FromBytesList {
     }
 }
 
+impl Statement for ReturnListIter {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let len = cast_to_usize(self.len.clone());
+        let name = &*self.name;
+        vec![syn::Stmt::Expr(match self.element_pad {
+            Some(align) => {
+                let align = int_litexpr_int(align);
+                syn::parse_quote! {
+                    Ok(ListIter::padded(bytes, index, #len, #align, #name))
+                }
+            }
+            None => syn::parse_quote! {
+                Ok(ListIter::new(bytes, index, #len, #name))
+            },
+        })]
+    }
+}
+
+impl Statement for FromBytesFormatted {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let name = syn::Ident::new(&self.name, Span::call_site());
+        let count = syn::Ident::new(&self.count, Span::call_site());
+        let field = &*self.name;
+        vec![
+            syn::parse_quote! {
+                let (#name, sz): (PropertyData, usize) =
+                    parse_property_data(bytes, index, format, #count as usize, #field)?;
+            },
+            syn::parse_quote! { index += sz; },
+        ]
+    }
+}
+
+impl Statement for ReturnBorrowedList {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let s = syn::Stmt::Semi(
+            syn::Expr::Let(syn::ExprLet {
+                attrs: vec![],
+                let_token: Default::default(),
+                pat: syn::Pat::Type(syn::PatType {
+                    attrs: vec![],
+                    pat: Box::new(syn::Pat::Tuple(syn::PatTuple {
+                        attrs: vec![],
+                        paren_token: Default::default(),
+                        elems: vec![
+                            syn::Pat::Ident(syn::PatIdent {
+                                attrs: vec![],
+                                by_ref: None,
+                                mutability: None,
+                                ident: syn::Ident::new(&self.name, Span::call_site()),
+                                subpat: None,
+                            }),
+                            syn::Pat::Wild(syn::PatWild {
+                                attrs: vec![],
+                                underscore_token: Default::default(),
+                            }),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    })),
+                    colon_token: Default::default(),
+                    ty: Box::new(
+                        Type::Tuple(vec![
+                            Type::CowSlice(Box::new(self.ty.clone())),
+                            Type::Basic("usize".into()),
+                        ])
+                        .to_syn_ty(),
+                    ),
+                }),
+                eq_token: Default::default(),
+                expr: Box::new(checked_parse(
+                    "parse_slice",
+                    Some(cast_to_usize(self.len.clone())),
+                    &self.name,
+                )),
+            }),
+            Default::default(),
+        );
+
+        let r = syn::Stmt::Expr(syn::Expr::Call(syn::ExprCall {
+            attrs: vec![],
+            func: Box::new(str_to_exprpath("Ok")),
+            paren_token: Default::default(),
+            args: iter::once(str_to_exprpath(&self.name)).collect(),
+        }));
+
+        vec![s, r]
+    }
+}
+
 impl Statement for AppendLengthToIndex {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
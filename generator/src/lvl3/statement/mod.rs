@@ -1,17 +1,34 @@
 // MIT/Apache2 License
 
 use super::{
-    syn_util::{int_litexpr_int, item_field, str_to_exprpath, str_to_path, str_to_pathseg},
+    is_plain_data,
+    syn_util::{
+        default_call, int_litexpr_int, item_field, str_to_exprpath, str_to_path, str_to_pathseg,
+    },
     Type,
 };
 use crate::lvl2::{Expression, MaybeString, UseCondition};
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::Span;
 use std::{borrow::Cow, fmt, iter, ops::Deref, rc::Rc};
 
+mod case;
+pub use case::*;
+mod continuation;
+pub use continuation::*;
+mod event;
+pub use event::*;
+mod fuzzing;
+pub use fuzzing::*;
 mod list;
 pub use list::*;
 mod padding;
 pub use padding::*;
+mod stream;
+pub use stream::*;
+mod trailing;
+pub use trailing::*;
+mod validate;
+pub use validate::*;
 
 /// Translates into a syn statement.
 pub trait Statement {
@@ -57,13 +74,12 @@ impl Statement for ReturnIndexStatement {
 #[derive(Clone, Debug)]
 pub struct AppendToIndexStatement {
     pub name: Box<str>,
-    pub condition: Option<(Rc<UseCondition>, Box<str>)>,
 }
 
 impl Statement for AppendToIndexStatement {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
-        let inn = syn::Stmt::Semi(
+        vec![syn::Stmt::Semi(
             index_plus_equal(syn::Expr::Call(syn::ExprCall {
                 attrs: vec![],
                 func: Box::new(item_field(
@@ -74,24 +90,11 @@ impl Statement for AppendToIndexStatement {
                 args: iter::once(bytes_slice(true)).collect(),
             })),
             Default::default(),
-        );
-        vec![match self.condition {
-            Some((ref condition, ref condname)) => syn::Stmt::Expr(syn::Expr::If(syn::ExprIf {
-                attrs: vec![],
-                if_token: Default::default(),
-                cond: Box::new(condition.to_cond_expr(condname)),
-                then_branch: syn::Block {
-                    brace_token: Default::default(),
-                    stmts: vec![inn],
-                },
-                else_branch: None,
-            })),
-            None => inn,
-        }]
+        )]
     }
 }
 
-/// Append a padding to "index" with a number of bytes.
+/// Append a padding to "index" with a number of bytes, writing zeroes into those bytes.
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
 pub struct PadIndexStatement(pub usize);
@@ -100,12 +103,37 @@ impl Statement for PadIndexStatement {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
         vec![syn::Stmt::Semi(
-            index_plus_equal(int_litexpr_int(self.0)),
+            index_plus_equal(zero_pad_call(int_litexpr_int(self.0))),
             Default::default(),
         )]
     }
 }
 
+/// `zero_pad(&mut bytes[index..], len)`, which writes `len` bytes of padding and gives that length.
+#[inline]
+pub fn zero_pad_call(len: syn::Expr) -> syn::Expr {
+    syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(str_to_exprpath("zero_pad")),
+        paren_token: Default::default(),
+        args: vec![bytes_slice(true), len].into_iter().collect(),
+    })
+}
+
+/// A statement to call `index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);`
+#[derive(Copy, Clone, Debug)]
+pub struct AppendMinorOpcode;
+
+impl Statement for AppendMinorOpcode {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let bytes = bytes_slice(true);
+        vec![syn::parse_quote! {
+            index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(#bytes);
+        }]
+    }
+}
+
 #[inline]
 fn index_plus_equal(e: syn::Expr) -> syn::Expr {
     syn::Expr::AssignOp(syn::ExprAssignOp {
@@ -141,12 +169,15 @@ pub struct InitializeCondition {
     pub name: Box<str>,
     pub expression: Rc<Expression>,
     pub has_self: bool,
+    pub optional: Vec<Box<str>>,
 }
 
 impl Statement for InitializeCondition {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
-        let b = self.expression.to_length_expr(self.has_self, false);
+        let b = self
+            .expression
+            .to_length_expr_unwrapping(self.has_self, false, &self.optional);
 
         vec![syn::Stmt::Semi(
             syn::Expr::Let(syn::ExprLet {
@@ -172,8 +203,9 @@ impl Statement for InitializeCondition {
 pub struct LoadStatementVariable {
     pub name: Box<str>,
     pub ty: Type,
+    /// Whether to read from `bytes` at `index`, with bounds checking, rather than from the start of
+    /// `bytes`.
     pub use_slice: bool,
-    pub condition: Option<(Rc<UseCondition>, Box<str>)>,
 }
 
 impl Statement for LoadStatementVariable {
@@ -194,7 +226,7 @@ impl Statement for LoadStatementVariable {
                                 attrs: vec![],
                                 by_ref: None,
                                 mutability: None,
-                                ident: syn::Ident::new(&*self.name, Span::call_site()),
+                                ident: syn::Ident::new(&self.name, Span::call_site()),
                                 subpat: None,
                             }),
                             syn::Pat::Ident(syn::PatIdent {
@@ -213,7 +245,9 @@ impl Statement for LoadStatementVariable {
                         Type::Tuple(vec![self.ty.clone(), Type::Basic("usize".into())]).to_syn_ty(),
                     ),
                 }),
-                expr: Box::new({
+                expr: Box::new(if self.use_slice {
+                    checked_parse("parse_item", None, &self.name)
+                } else {
                     let a = syn::Expr::Call(syn::ExprCall {
                         attrs: vec![],
                         func: Box::new(syn::Expr::Path(syn::ExprPath {
@@ -231,12 +265,7 @@ impl Statement for LoadStatementVariable {
                             },
                         })),
                         paren_token: Default::default(),
-                        args: iter::once(if self.use_slice {
-                            bytes_slice(false)
-                        } else {
-                            str_to_exprpath("bytes")
-                        })
-                        .collect(),
+                        args: iter::once(str_to_exprpath("bytes")).collect(),
                     });
 
                     syn::Expr::Try(syn::ExprTry {
@@ -254,57 +283,7 @@ impl Statement for LoadStatementVariable {
             false => vec![],
         };
 
-        match self.condition {
-            Some((ref condition, ref condname)) => vec![syn::Stmt::Semi(
-                let_statement(
-                    &self.name,
-                    self.ty.clone(),
-                    syn::Expr::If(syn::ExprIf {
-                        attrs: vec![],
-                        if_token: Default::default(),
-                        cond: Box::new(condition.to_cond_expr(&condname)),
-                        then_branch: syn::Block {
-                            brace_token: Default::default(),
-                            stmts: iter::once(inn)
-                                .chain(do_increment)
-                                .chain(iter::once(syn::Stmt::Expr(str_to_exprpath(&self.name))))
-                                .collect(),
-                        },
-                        else_branch: Some((
-                            Default::default(),
-                            Box::new(syn::Expr::Block(syn::ExprBlock {
-                                attrs: vec![],
-                                label: None,
-                                block: syn::Block {
-                                    brace_token: Default::default(),
-                                    stmts: vec![syn::Stmt::Expr(syn::Expr::Call(syn::ExprCall {
-                                        attrs: vec![],
-                                        func: Box::new(syn::Expr::Path(syn::ExprPath {
-                                            attrs: vec![],
-                                            qself: None,
-                                            path: syn::Path {
-                                                segments: vec![
-                                                    str_to_pathseg("Default"),
-                                                    str_to_pathseg("default"),
-                                                ]
-                                                .into_iter()
-                                                .collect(),
-                                                leading_colon: None,
-                                            },
-                                        })),
-                                        paren_token: Default::default(),
-                                        args: syn::punctuated::Punctuated::new(),
-                                    }))],
-                                },
-                            })),
-                        )),
-                    }),
-                    false,
-                ),
-                Default::default(),
-            )],
-            None => iter::once(inn).chain(do_increment).collect(),
-        }
+        iter::once(inn).chain(do_increment).collect()
     }
 }
 
@@ -333,6 +312,41 @@ pub fn bytes_slice(is_mut: bool) -> syn::Expr {
     })
 }
 
+/// Call a bounds-checked parsing function, such as `parse_item(bytes, index, "field")?`.
+#[inline]
+pub fn checked_parse(func: &str, len: Option<syn::Expr>, field: &str) -> syn::Expr {
+    syn::Expr::Try(syn::ExprTry {
+        attrs: vec![],
+        expr: Box::new(syn::Expr::Call(syn::ExprCall {
+            attrs: vec![],
+            func: Box::new(str_to_exprpath(func)),
+            paren_token: Default::default(),
+            args: vec![str_to_exprpath("bytes"), str_to_exprpath("index")]
+                .into_iter()
+                .chain(len)
+                .chain(iter::once(syn::Expr::Lit(syn::ExprLit {
+                    attrs: vec![],
+                    lit: syn::Lit::Str(syn::LitStr::new(field, Span::call_site())),
+                })))
+                .collect(),
+        })),
+        question_token: Default::default(),
+    })
+}
+
+/// The number of bytes left in a request after `index`, according to its `length` field, as in
+/// `(length as usize * 4).checked_sub(index).ok_or(ParseError { .. })?`. A `length` too short to hold
+/// the fields already read is an error, rather than an underflow.
+#[inline]
+pub fn remainder_len(field: &str) -> syn::Expr {
+    syn::parse_quote! {
+        (length as usize * 4).checked_sub(index).ok_or(ParseError {
+            field: #field,
+            offset: index,
+        })?
+    }
+}
+
 /// Increment the "index" variable by either a set number or "sz".
 #[derive(Copy, Clone, Debug)]
 pub enum IncrementIndex {
@@ -364,7 +378,7 @@ pub struct ReturnStruct {
     pub sname: Box<str>,
     pub fields: Vec<Cow<'static, str>>,
     pub last_index: &'static str,
-    pub fds: Vec<String>,
+    pub fds: Vec<Box<str>>,
 }
 
 impl Statement for ReturnStruct {
@@ -372,30 +386,23 @@ impl Statement for ReturnStruct {
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
         let struct_expr = syn::Expr::Struct(syn::ExprStruct {
             attrs: vec![],
-            path: str_to_path(&*self.sname),
+            path: str_to_path(&self.sname),
             brace_token: Default::default(),
             fields: self
                 .fields
                 .iter()
                 .map(|f| syn::FieldValue {
                     attrs: vec![],
-                    member: syn::Member::Named(syn::Ident::new(&*f, Span::call_site())),
+                    member: syn::Member::Named(syn::Ident::new(f, Span::call_site())),
                     colon_token: Some(Default::default()),
-                    expr: str_to_exprpath(&*f),
+                    expr: str_to_exprpath(f),
                 })
                 .chain(self.fds.iter().map(|f| syn::FieldValue {
                     attrs: vec![],
-                    member: syn::Member::Named(syn::Ident::new(&*f, Span::call_site())),
+                    member: syn::Member::Named(syn::Ident::new(f, Span::call_site())),
                     colon_token: Some(Default::default()),
-                    expr: syn::Expr::Macro(syn::ExprMacro {
-                        attrs: vec![],
-                        mac: syn::Macro {
-                            path: str_to_path("vec"),
-                            bang_token: Default::default(),
-                            delimiter: syn::MacroDelimiter::Bracket(Default::default()),
-                            tokens: TokenStream::new(),
-                        },
-                    }),
+                    // file descriptors are filled in once they are received
+                    expr: default_call(),
                 }))
                 .collect(),
             dot2_token: None,
@@ -403,7 +410,7 @@ impl Statement for ReturnStruct {
         });
         vec![syn::Stmt::Expr(syn::Expr::Call(syn::ExprCall {
             attrs: vec![],
-            func: Box::new(str_to_exprpath("Some")),
+            func: Box::new(str_to_exprpath("Ok")),
             paren_token: Default::default(),
             args: iter::once(syn::Expr::Tuple(syn::ExprTuple {
                 attrs: vec![],
@@ -456,7 +463,6 @@ impl Statement for MatchSelfToBytes {
 /// Match a number to a series of variants.
 #[derive(Clone, Debug)]
 pub struct MatchBytesToEnum {
-    pub name: Box<str>,
     pub underlying: Cow<'static, str>,
     pub variants: Box<[(Box<str>, i64)]>,
 }
@@ -468,7 +474,6 @@ impl Statement for MatchBytesToEnum {
             name: "underlying".into(),
             ty: Type::Basic(self.underlying.clone()),
             use_slice: false,
-            condition: None,
         }
         .to_syn_statement();
 
@@ -491,7 +496,7 @@ impl Statement for MatchBytesToEnum {
                         fat_arrow_token: Default::default(),
                         body: Box::new(syn::Expr::Call(syn::ExprCall {
                             attrs: vec![],
-                            func: Box::new(str_to_exprpath("Some")),
+                            func: Box::new(str_to_exprpath("Ok")),
                             paren_token: Default::default(),
                             args: iter::once(syn::Expr::Tuple(syn::ExprTuple {
                                 attrs: vec![],
@@ -527,7 +532,8 @@ impl Statement for MatchBytesToEnum {
                         }),
                         guard: None,
                         fat_arrow_token: Default::default(),
-                        body: Box::new(str_to_exprpath("None")),
+                        // values that aren't variants are reported as the enum itself
+                        body: Box::new(syn::parse_quote! { Err(unreadable::<Self>()) }),
                         comma: None,
                     }))
                     .collect(),
@@ -536,6 +542,71 @@ impl Statement for MatchBytesToEnum {
     }
 }
 
+/// Match the "value" variable to a series of variants, returning it as an error if it matches none
+/// of them.
+#[derive(Clone, Debug)]
+pub struct MatchValueToEnum {
+    pub variants: Box<[(Box<str>, i64)]>,
+}
+
+impl Statement for MatchValueToEnum {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Expr(syn::Expr::Match(syn::ExprMatch {
+            attrs: vec![],
+            match_token: Default::default(),
+            expr: Box::new(str_to_exprpath("value")),
+            brace_token: Default::default(),
+            arms: self
+                .variants
+                .iter()
+                .map(|(name, value)| syn::Arm {
+                    attrs: vec![],
+                    pat: syn::Pat::Lit(syn::PatLit {
+                        attrs: vec![],
+                        expr: Box::new(int_litexpr_int(value)),
+                    }),
+                    guard: None,
+                    fat_arrow_token: Default::default(),
+                    body: Box::new(syn::Expr::Call(syn::ExprCall {
+                        attrs: vec![],
+                        func: Box::new(str_to_exprpath("Ok")),
+                        paren_token: Default::default(),
+                        args: iter::once(syn::Expr::Path(syn::ExprPath {
+                            attrs: vec![],
+                            qself: None,
+                            path: syn::Path {
+                                segments: vec![str_to_pathseg("Self"), str_to_pathseg(name)]
+                                    .into_iter()
+                                    .collect(),
+                                leading_colon: None,
+                            },
+                        }))
+                        .collect(),
+                    })),
+                    comma: Some(Default::default()),
+                })
+                .chain(iter::once(syn::Arm {
+                    attrs: vec![],
+                    pat: syn::Pat::Wild(syn::PatWild {
+                        attrs: vec![],
+                        underscore_token: Default::default(),
+                    }),
+                    guard: None,
+                    fat_arrow_token: Default::default(),
+                    body: Box::new(syn::Expr::Call(syn::ExprCall {
+                        attrs: vec![],
+                        func: Box::new(str_to_exprpath("Err")),
+                        paren_token: Default::default(),
+                        args: iter::once(str_to_exprpath("value")).collect(),
+                    })),
+                    comma: None,
+                }))
+                .collect(),
+        }))]
+    }
+}
+
 #[derive(Clone)]
 #[repr(transparent)]
 pub struct ExprWrapper(pub syn::Expr);
@@ -592,7 +663,14 @@ pub enum SizeSumPart {
     Bytes(usize),
     SizeofField(Box<str>),
     SizeofType(Type),
-    ListTimesSize(Box<str>, MaybeString, Option<usize>),
+    /// The size of a list and its padding. The last number is the alignment each of its elements is
+    /// padded out to, if they're padded.
+    ListTimesSize(Box<str>, MaybeString, Option<usize>, Option<usize>),
+    /// The size of a list of plain data, found from its length without visiting its elements.
+    PlainListTimesSize(Box<str>, MaybeString, Option<usize>),
+    /// Padding out to the next multiple of the alignment. This only makes sense as a part of a sum,
+    /// since it depends on the size of everything before it.
+    AlignTo(usize),
 }
 
 impl SizeSumPart {
@@ -600,6 +678,8 @@ impl SizeSumPart {
     pub fn to_expr(&self) -> syn::Expr {
         match self {
             Self::Bytes(bytes) => int_litexpr_int(bytes),
+            // on its own, the padding comes at the start and is empty
+            Self::AlignTo(_) => int_litexpr_int(0),
             Self::SizeofType(ty) => syn::Expr::Call(syn::ExprCall {
                 attrs: vec![],
                 func: Box::new(syn::Expr::Path(syn::ExprPath {
@@ -641,8 +721,29 @@ impl SizeSumPart {
                 paren_token: Default::default(),
                 args: syn::punctuated::Punctuated::new(),
             }),
-            Self::ListTimesSize(fname, ty, pad) => {
+            Self::ListTimesSize(fname, ty, pad, _) | Self::PlainListTimesSize(fname, ty, pad) => {
                 let list_size = match ty {
+                    // self.list.len() * core::mem::size_of::<T>()
+                    MaybeString::NotAString(elem)
+                        if matches!(self, Self::PlainListTimesSize(..)) =>
+                    {
+                        syn::Expr::Binary(syn::ExprBinary {
+                            attrs: vec![],
+                            left: Box::new(syn::Expr::Call(syn::ExprCall {
+                                attrs: vec![],
+                                func: Box::new(item_field(
+                                    item_field(str_to_exprpath("self"), fname),
+                                    "len",
+                                )),
+                                paren_token: Default::default(),
+                                args: syn::punctuated::Punctuated::new(),
+                            })),
+                            op: syn::BinOp::Mul(Default::default()),
+                            right: Box::new(
+                                SizeSumPart::SizeofType(Type::from_lvl2(elem.clone())).to_expr(),
+                            ),
+                        })
+                    }
                     MaybeString::NotAString(_) => syn::Expr::Call(syn::ExprCall {
                         attrs: vec![],
                         func: Box::new(item_field(
@@ -677,12 +778,26 @@ impl SizeSumPart {
                                     .collect(),
                                     or2_token: Default::default(),
                                     output: syn::ReturnType::Default,
-                                    body: Box::new(syn::Expr::Call(syn::ExprCall {
-                                        attrs: vec![],
-                                        func: Box::new(item_field(str_to_exprpath("i"), "size")),
-                                        paren_token: Default::default(),
-                                        args: syn::punctuated::Punctuated::new(),
-                                    })),
+                                    body: Box::new(match self {
+                                        // each element is followed by the padding that aligns
+                                        // the next one
+                                        Self::ListTimesSize(_, _, _, Some(align)) => {
+                                            let align = int_litexpr_int(align);
+                                            syn::parse_quote! {{
+                                                let size = i.size();
+                                                size + buffer_pad(size, #align)
+                                            }}
+                                        }
+                                        _ => syn::Expr::Call(syn::ExprCall {
+                                            attrs: vec![],
+                                            func: Box::new(item_field(
+                                                str_to_exprpath("i"),
+                                                "size",
+                                            )),
+                                            paren_token: Default::default(),
+                                            args: syn::punctuated::Punctuated::new(),
+                                        }),
+                                    }),
                                 }))
                                 .collect(),
                             }),
@@ -754,22 +869,64 @@ impl SizeSumPart {
 #[derive(Debug, Clone, Default)]
 pub struct SumOfSizes(pub Vec<SizeSumPart>);
 
+impl SumOfSizes {
+    /// A sum that finds the size of each list of plain data from the list's length, or `None` if
+    /// there are no such lists. The elements of these lists are all the same size, so the sum is
+    /// still exact.
+    #[inline]
+    pub fn hint(&self) -> Option<SumOfSizes> {
+        let mut found = false;
+        let parts = self
+            .0
+            .iter()
+            .map(|part| match part {
+                SizeSumPart::ListTimesSize(name, ty, pad, None) if is_plain_data(ty) => {
+                    found = true;
+                    SizeSumPart::PlainListTimesSize(name.clone(), ty.clone(), *pad)
+                }
+                part => part.clone(),
+            })
+            .collect();
+        match found {
+            true => Some(SumOfSizes(parts)),
+            false => None,
+        }
+    }
+}
+
 impl Statement for SumOfSizes {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
         vec![{
-            let first = match self.0.get(0) {
+            let first = match self.0.first() {
                 Some(f) => f.to_expr(),
                 None => return vec![],
             };
-            syn::Stmt::Expr(self.0.iter().skip(1).fold(first, |sum, current| {
-                syn::Expr::Binary(syn::ExprBinary {
-                    attrs: vec![],
-                    left: Box::new(sum),
-                    op: syn::BinOp::Add(Default::default()),
-                    right: Box::new(current.to_expr()),
-                })
-            }))
+            syn::Stmt::Expr(
+                self.0
+                    .iter()
+                    .skip(1)
+                    .fold(first, |sum, current| match current {
+                        SizeSumPart::AlignTo(align) => syn::Expr::Paren(syn::ExprParen {
+                            attrs: vec![],
+                            paren_token: Default::default(),
+                            expr: Box::new(syn::Expr::Block(syn::ExprBlock {
+                                attrs: vec![],
+                                label: None,
+                                block: syn::Block {
+                                    brace_token: Default::default(),
+                                    stmts: align_size(sum, *align),
+                                },
+                            })),
+                        }),
+                        current => syn::Expr::Binary(syn::ExprBinary {
+                            attrs: vec![],
+                            left: Box::new(sum),
+                            op: syn::BinOp::Add(Default::default()),
+                            right: Box::new(current.to_expr()),
+                        }),
+                    }),
+            )
         }]
     }
 }
@@ -956,7 +1113,7 @@ impl Statement for ForwardAsBytes {
         vec![syn::Stmt::Expr(syn::Expr::Call(syn::ExprCall {
             attrs: vec![],
             func: Box::new(item_field(
-                item_field(str_to_exprpath("self"), &*self.0),
+                item_field(str_to_exprpath("self"), &self.0),
                 "as_bytes",
             )),
             paren_token: Default::default(),
@@ -978,7 +1135,6 @@ impl Statement for ForwardFromBytes {
             name: "inner".into(),
             ty: self.inner_ty.clone(),
             use_slice: false,
-            condition: None,
         }
         .to_syn_statement();
 
@@ -996,6 +1152,83 @@ impl Statement for ForwardFromBytes {
     }
 }
 
+/// `Self::from_bytes(bytes)`
+#[inline]
+fn from_bytes_call() -> syn::Expr {
+    syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(syn::Expr::Path(syn::ExprPath {
+            attrs: vec![],
+            qself: None,
+            path: syn::Path {
+                leading_colon: None,
+                segments: vec![str_to_pathseg("Self"), str_to_pathseg("from_bytes")]
+                    .into_iter()
+                    .collect(),
+            },
+        })),
+        paren_token: Default::default(),
+        args: iter::once(str_to_exprpath("bytes")).collect(),
+    })
+}
+
+/// Forward `try_from` to `from_bytes`, discarding the number of bytes read.
+#[derive(Copy, Clone, Debug)]
+pub struct ForwardTryFrom;
+
+impl Statement for ForwardTryFrom {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let pat_ident = |name: &str| {
+            syn::Pat::Ident(syn::PatIdent {
+                attrs: vec![],
+                by_ref: None,
+                mutability: None,
+                ident: syn::Ident::new(name, Span::call_site()),
+                subpat: None,
+            })
+        };
+
+        // |(value, _)| value
+        let discard_len = syn::Expr::Closure(syn::ExprClosure {
+            attrs: vec![],
+            asyncness: None,
+            movability: None,
+            capture: None,
+            or1_token: Default::default(),
+            inputs: iter::once(syn::Pat::Tuple(syn::PatTuple {
+                attrs: vec![],
+                paren_token: Default::default(),
+                elems: vec![
+                    pat_ident("value"),
+                    syn::Pat::Wild(syn::PatWild {
+                        attrs: vec![],
+                        underscore_token: Default::default(),
+                    }),
+                ]
+                .into_iter()
+                .collect(),
+            }))
+            .collect(),
+            or2_token: Default::default(),
+            output: syn::ReturnType::Default,
+            body: Box::new(str_to_exprpath("value")),
+        });
+
+        vec![syn::Stmt::Expr(syn::Expr::MethodCall(
+            syn::ExprMethodCall {
+                attrs: vec![],
+                receiver: Box::new(from_bytes_call()),
+                dot_token: Default::default(),
+                method: syn::Ident::new("map", Span::call_site()),
+                turbofish: None,
+                paren_token: Default::default(),
+                args: iter::once(discard_len).collect(),
+            },
+        ))]
+    }
+}
+
 #[derive(Debug, Clone)]
 #[repr(transparent)]
 pub struct DeserTraceMarker(pub Box<str>);
@@ -1025,9 +1258,80 @@ impl Statement for DeserTraceMarker {
     }
 }
 
+/// Write a message describing an error to the formatter `f`. The error is described by `fields`,
+/// which are some of `bad_value`, `major_opcode` and `minor_opcode`.
+#[derive(Debug, Clone)]
+pub struct WriteErrorMessage {
+    pub name: Box<str>,
+    pub opcode: u64,
+    pub fields: Vec<&'static str>,
+}
+
+impl Statement for WriteErrorMessage {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let has = |field| self.fields.contains(&field);
+        // errors are conventionally named "BadSomething", and a few already are
+        let name = match self.name.starts_with("Bad") {
+            true => self.name.to_string(),
+            false => format!("Bad{}", &self.name),
+        };
+        let mut message = format!("X error: {} (code {})", name, self.opcode);
+        let mut args = String::new();
+
+        if has("bad_value") {
+            message.push_str(" for resource {:#x}");
+            args.push_str(", self.bad_value");
+        }
+        match (has("major_opcode"), has("minor_opcode")) {
+            (true, true) => {
+                message.push_str(" on request {}:{}");
+                args.push_str(", self.major_opcode, self.minor_opcode");
+            }
+            (true, false) => {
+                message.push_str(" on request {}");
+                args.push_str(", self.major_opcode");
+            }
+            _ => (),
+        }
+
+        vec![syn::Stmt::Expr(syn::Expr::Macro(syn::ExprMacro {
+            attrs: vec![],
+            mac: syn::Macro {
+                path: str_to_path("write"),
+                bang_token: Default::default(),
+                delimiter: syn::MacroDelimiter::Paren(Default::default()),
+                tokens: format!("f, \"{}\"{}", message, args).parse().unwrap(),
+            },
+        }))]
+    }
+}
+
+/// Write the XID of this resource to the "f" formatter in hexadecimal, e.g. `Window(0x400001)`.
+#[derive(Debug, Clone)]
+pub struct WriteXidDebug {
+    pub name: Box<str>,
+}
+
+impl Statement for WriteXidDebug {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Expr(syn::Expr::Macro(syn::ExprMacro {
+            attrs: vec![],
+            mac: syn::Macro {
+                path: str_to_path("write"),
+                bang_token: Default::default(),
+                delimiter: syn::MacroDelimiter::Paren(Default::default()),
+                tokens: format!("f, \"{}({{:#x}})\", self.xid", &self.name)
+                    .parse()
+                    .unwrap(),
+            },
+        }))]
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ConvertXids {
-    pub oldname: Box<str>,
     pub newname: Box<str>,
 }
 
@@ -1056,25 +1360,109 @@ impl Statement for ConvertXids {
     }
 }
 
+/// Append a file descriptor field to the "fds" list.
 #[derive(Debug, Clone)]
-pub struct GetFdRef(pub String);
+pub struct AppendFd(pub Box<str>);
 
-impl Statement for GetFdRef {
+impl Statement for AppendFd {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
-        vec![syn::Stmt::Expr(syn::Expr::Call(syn::ExprCall {
-            attrs: vec![],
-            func: Box::new(str_to_exprpath("Some")),
-            paren_token: Default::default(),
-            args: iter::once(syn::Expr::Reference(syn::ExprReference {
+        vec![syn::Stmt::Semi(
+            syn::Expr::MethodCall(syn::ExprMethodCall {
                 attrs: vec![],
-                and_token: Default::default(),
-                raw: Default::default(),
-                mutability: Some(Default::default()),
-                expr: Box::new(item_field(str_to_exprpath("self"), &self.0)),
-            }))
-            .collect(),
-        }))]
+                receiver: Box::new(str_to_exprpath("fds")),
+                dot_token: Default::default(),
+                method: syn::Ident::new("push", Span::call_site()),
+                turbofish: None,
+                paren_token: Default::default(),
+                args: iter::once(item_field(str_to_exprpath("self"), &self.0)).collect(),
+            }),
+            Default::default(),
+        )]
+    }
+}
+
+/// Set a file descriptor field to the file descriptor at an index of the "fds" slice, returning
+/// if it isn't there.
+#[derive(Debug, Clone)]
+pub struct LoadFd {
+    pub name: Box<str>,
+    pub index: usize,
+}
+
+impl Statement for LoadFd {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            syn::Expr::Assign(syn::ExprAssign {
+                attrs: vec![],
+                left: Box::new(item_field(str_to_exprpath("self"), &self.name)),
+                eq_token: Default::default(),
+                right: Box::new(syn::Expr::Unary(syn::ExprUnary {
+                    attrs: vec![],
+                    op: syn::UnOp::Deref(Default::default()),
+                    expr: Box::new(syn::Expr::Try(syn::ExprTry {
+                        attrs: vec![],
+                        expr: Box::new(syn::Expr::MethodCall(syn::ExprMethodCall {
+                            attrs: vec![],
+                            receiver: Box::new(str_to_exprpath("fds")),
+                            dot_token: Default::default(),
+                            method: syn::Ident::new("get", Span::call_site()),
+                            turbofish: None,
+                            paren_token: Default::default(),
+                            args: iter::once(int_litexpr_int(self.index)).collect(),
+                        })),
+                        question_token: Default::default(),
+                    })),
+                })),
+            }),
+            Default::default(),
+        )]
+    }
+}
+
+/// Set a field of the inner item, wrapping the value in `Some` if the field is optional. If the
+/// field is in a switch case, the field the case depends on is modified so that the case applies.
+#[derive(Debug, Clone)]
+pub struct SetInnerField {
+    pub name: Box<str>,
+    pub wrap: bool,
+    pub condition: Option<(Box<str>, Rc<UseCondition>)>,
+}
+
+impl Statement for SetInnerField {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let inner = item_field(str_to_exprpath("self"), "inner");
+        let value = str_to_exprpath(&self.name);
+
+        self.condition
+            .iter()
+            .map(|(field, condition)| {
+                syn::Stmt::Semi(
+                    condition.to_meet_expr(item_field(inner.clone(), field)),
+                    Default::default(),
+                )
+            })
+            .chain(iter::once(syn::Stmt::Semi(
+                syn::Expr::Assign(syn::ExprAssign {
+                    attrs: vec![],
+                    left: Box::new(item_field(inner.clone(), &self.name)),
+                    eq_token: Default::default(),
+                    right: Box::new(if self.wrap {
+                        syn::Expr::Call(syn::ExprCall {
+                            attrs: vec![],
+                            func: Box::new(str_to_exprpath("Some")),
+                            paren_token: Default::default(),
+                            args: iter::once(value).collect(),
+                        })
+                    } else {
+                        value
+                    }),
+                }),
+                Default::default(),
+            )))
+            .collect()
     }
 }
 
@@ -1103,12 +1491,14 @@ pub enum SumStatement {
     ReturnIndex(ReturnIndexStatement),
     AppendToIndex(AppendToIndexStatement),
     PadIndex(PadIndexStatement),
+    AppendMinorOpcode(AppendMinorOpcode),
     CreateIndexVariable(CreateIndexVariable),
     LoadStatementVariable(LoadStatementVariable),
     IncrementIndex(IncrementIndex),
     ReturnStruct(ReturnStruct),
     MatchSelfToBytes(MatchSelfToBytes),
     MatchBytesToEnum(MatchBytesToEnum),
+    MatchValueToEnum(MatchValueToEnum),
     ExprWrapper(ExprWrapper),
     GetXid(GetXidStatement),
     CreateXidType(CreateXidTypeStatement),
@@ -1121,16 +1511,47 @@ pub enum SumStatement {
     ReturnBitflag(ReturnBitflag),
     ForwardAsBytes(ForwardAsBytes),
     ForwardFromBytes(ForwardFromBytes),
+    ForwardTryFrom(ForwardTryFrom),
     CreateAlignToAndBlockLen(CreateAlignToAndBlockLen),
     SetAlignAndAddPadding(SetAlignAndAddPadding),
     FromBytesList(FromBytesList),
+    ReturnBorrowedList(ReturnBorrowedList),
     AsBytesList(AsBytesList),
     AppendLengthToIndex(AppendLengthToIndex),
     InitializeCondition(InitializeCondition),
     DeserTraceMarker(DeserTraceMarker),
     ConvertXids(ConvertXids),
-    GetFdRef(GetFdRef),
+    AppendFd(AppendFd),
+    LoadFd(LoadFd),
     ForwardToInner(ForwardToInner),
+    SetInnerField(SetInnerField),
+    SwitchCaseAsBytes(SwitchCaseAsBytes),
+    SyncMask(SyncMask),
+    WriteErrorMessage(WriteErrorMessage),
+    WriteXidDebug(WriteXidDebug),
+    AlignIndex(AlignIndex),
+    ZeroAlignPad(ZeroAlignPad),
+    AlignedSumOfSizes(AlignedSumOfSizes),
+    SwitchCaseFromBytes(SwitchCaseFromBytes),
+    DefineReplyEnd(DefineReplyEnd),
+    TruncateToReplyLength(TruncateToReplyLength),
+    LoadIfPresent(LoadIfPresent),
+    SkipIfPresent(SkipIfPresent),
+    WrapEvent(WrapEvent),
+    MatchOpcodeToEvent(MatchOpcodeToEvent),
+    MatchEventToResponseType(MatchEventToResponseType),
+    ReadItem(ReadItem),
+    SkipBytes(SkipBytes),
+    SkipAlignPad(SkipAlignPad),
+    ReadList(ReadList),
+    SkipToReplyEnd(SkipToReplyEnd),
+    ReturnReadStruct(ReturnReadStruct),
+    ContinueRequest(ContinueRequest),
+    CheckListLength(CheckListLength),
+    ArbitraryItem(ArbitraryItem),
+    ReturnListIter(ReturnListIter),
+    FromBytesFormatted(FromBytesFormatted),
+    ArbitraryFormatted(ArbitraryFormatted),
 }
 
 macro_rules! sst_from_impl {
@@ -1147,12 +1568,14 @@ macro_rules! sst_from_impl {
 sst_from_impl! { ReturnIndex, ReturnIndexStatement }
 sst_from_impl! { AppendToIndex, AppendToIndexStatement }
 sst_from_impl! { PadIndex, PadIndexStatement }
+sst_from_impl! { AppendMinorOpcode, AppendMinorOpcode }
 sst_from_impl! { CreateIndexVariable, CreateIndexVariable }
 sst_from_impl! { LoadStatementVariable, LoadStatementVariable }
 sst_from_impl! { IncrementIndex, IncrementIndex }
 sst_from_impl! { ReturnStruct, ReturnStruct }
 sst_from_impl! { MatchSelfToBytes, MatchSelfToBytes }
 sst_from_impl! { MatchBytesToEnum, MatchBytesToEnum }
+sst_from_impl! { MatchValueToEnum, MatchValueToEnum }
 sst_from_impl! { ExprWrapper, ExprWrapper }
 sst_from_impl! { GetXid, GetXidStatement }
 sst_from_impl! { CreateXidType, CreateXidTypeStatement }
@@ -1165,16 +1588,47 @@ sst_from_impl! { DefineInnerAccumulator, DefineInnerAccumulator }
 sst_from_impl! { ReturnBitflag, ReturnBitflag }
 sst_from_impl! { ForwardAsBytes, ForwardAsBytes }
 sst_from_impl! { ForwardFromBytes, ForwardFromBytes }
+sst_from_impl! { ForwardTryFrom, ForwardTryFrom }
 sst_from_impl! { CreateAlignToAndBlockLen, CreateAlignToAndBlockLen }
 sst_from_impl! { SetAlignAndAddPadding, SetAlignAndAddPadding }
 sst_from_impl! { FromBytesList, FromBytesList }
+sst_from_impl! { ReturnBorrowedList, ReturnBorrowedList }
+sst_from_impl! { DefineReplyEnd, DefineReplyEnd }
+sst_from_impl! { TruncateToReplyLength, TruncateToReplyLength }
+sst_from_impl! { LoadIfPresent, LoadIfPresent }
+sst_from_impl! { SkipIfPresent, SkipIfPresent }
 sst_from_impl! { AsBytesList, AsBytesList }
 sst_from_impl! { AppendLengthToIndex, AppendLengthToIndex }
 sst_from_impl! { InitializeCondition, InitializeCondition }
 sst_from_impl! { DeserTraceMarker, DeserTraceMarker }
 sst_from_impl! { ConvertXids, ConvertXids }
-sst_from_impl! { GetFdRef, GetFdRef }
+sst_from_impl! { AppendFd, AppendFd }
+sst_from_impl! { LoadFd, LoadFd }
 sst_from_impl! { ForwardToInner, ForwardToInner }
+sst_from_impl! { SetInnerField, SetInnerField }
+sst_from_impl! { SwitchCaseAsBytes, SwitchCaseAsBytes }
+sst_from_impl! { SyncMask, SyncMask }
+sst_from_impl! { WriteErrorMessage, WriteErrorMessage }
+sst_from_impl! { WriteXidDebug, WriteXidDebug }
+sst_from_impl! { AlignIndex, AlignIndex }
+sst_from_impl! { ZeroAlignPad, ZeroAlignPad }
+sst_from_impl! { AlignedSumOfSizes, AlignedSumOfSizes }
+sst_from_impl! { SwitchCaseFromBytes, SwitchCaseFromBytes }
+sst_from_impl! { WrapEvent, WrapEvent }
+sst_from_impl! { MatchOpcodeToEvent, MatchOpcodeToEvent }
+sst_from_impl! { MatchEventToResponseType, MatchEventToResponseType }
+sst_from_impl! { ReadItem, ReadItem }
+sst_from_impl! { SkipBytes, SkipBytes }
+sst_from_impl! { SkipAlignPad, SkipAlignPad }
+sst_from_impl! { ReadList, ReadList }
+sst_from_impl! { SkipToReplyEnd, SkipToReplyEnd }
+sst_from_impl! { ReturnReadStruct, ReturnReadStruct }
+sst_from_impl! { ContinueRequest, ContinueRequest }
+sst_from_impl! { CheckListLength, CheckListLength }
+sst_from_impl! { ArbitraryItem, ArbitraryItem }
+sst_from_impl! { ReturnListIter, ReturnListIter }
+sst_from_impl! { FromBytesFormatted, FromBytesFormatted }
+sst_from_impl! { ArbitraryFormatted, ArbitraryFormatted }
 
 impl Statement for SumStatement {
     #[inline]
@@ -1183,12 +1637,14 @@ impl Statement for SumStatement {
             Self::ReturnIndex(rr) => rr.to_syn_statement(),
             Self::AppendToIndex(atrs) => atrs.to_syn_statement(),
             Self::PadIndex(prs) => prs.to_syn_statement(),
+            Self::AppendMinorOpcode(amo) => amo.to_syn_statement(),
             Self::CreateIndexVariable(civ) => civ.to_syn_statement(),
             Self::LoadStatementVariable(lsv) => lsv.to_syn_statement(),
             Self::IncrementIndex(ii) => ii.to_syn_statement(),
             Self::ReturnStruct(rs) => rs.to_syn_statement(),
             Self::MatchSelfToBytes(mstb) => mstb.to_syn_statement(),
             Self::MatchBytesToEnum(mbte) => mbte.to_syn_statement(),
+            Self::MatchValueToEnum(mvte) => mvte.to_syn_statement(),
             Self::ExprWrapper(ew) => ew.to_syn_statement(),
             Self::GetXid(gx) => gx.to_syn_statement(),
             Self::CreateXidType(cxt) => cxt.to_syn_statement(),
@@ -1201,16 +1657,47 @@ impl Statement for SumStatement {
             Self::ReturnBitflag(rb) => rb.to_syn_statement(),
             Self::ForwardAsBytes(fab) => fab.to_syn_statement(),
             Self::ForwardFromBytes(ffb) => ffb.to_syn_statement(),
+            Self::ForwardTryFrom(ftf) => ftf.to_syn_statement(),
             Self::CreateAlignToAndBlockLen(c) => c.to_syn_statement(),
             Self::SetAlignAndAddPadding(saaap) => saaap.to_syn_statement(),
             Self::FromBytesList(fbl) => fbl.to_syn_statement(),
+            Self::ReturnBorrowedList(rbl) => rbl.to_syn_statement(),
+            Self::DefineReplyEnd(dre) => dre.to_syn_statement(),
+            Self::TruncateToReplyLength(ttrl) => ttrl.to_syn_statement(),
+            Self::LoadIfPresent(lip) => lip.to_syn_statement(),
+            Self::SkipIfPresent(sip) => sip.to_syn_statement(),
             Self::AsBytesList(asl) => asl.to_syn_statement(),
             Self::AppendLengthToIndex(ai) => ai.to_syn_statement(),
             Self::InitializeCondition(ic) => ic.to_syn_statement(),
             Self::DeserTraceMarker(dtm) => dtm.to_syn_statement(),
             Self::ConvertXids(cx) => cx.to_syn_statement(),
-            Self::GetFdRef(gfr) => gfr.to_syn_statement(),
+            Self::AppendFd(af) => af.to_syn_statement(),
+            Self::LoadFd(lf) => lf.to_syn_statement(),
             Self::ForwardToInner(fti) => fti.to_syn_statement(),
+            Self::SetInnerField(sif) => sif.to_syn_statement(),
+            Self::SwitchCaseAsBytes(scab) => scab.to_syn_statement(),
+            Self::SyncMask(sm) => sm.to_syn_statement(),
+            Self::WriteErrorMessage(wem) => wem.to_syn_statement(),
+            Self::WriteXidDebug(wxd) => wxd.to_syn_statement(),
+            Self::AlignIndex(ai) => ai.to_syn_statement(),
+            Self::ZeroAlignPad(zap) => zap.to_syn_statement(),
+            Self::AlignedSumOfSizes(asos) => asos.to_syn_statement(),
+            Self::SwitchCaseFromBytes(scfb) => scfb.to_syn_statement(),
+            Self::WrapEvent(we) => we.to_syn_statement(),
+            Self::MatchOpcodeToEvent(mote) => mote.to_syn_statement(),
+            Self::MatchEventToResponseType(metrt) => metrt.to_syn_statement(),
+            Self::ReadItem(ri) => ri.to_syn_statement(),
+            Self::SkipBytes(sb) => sb.to_syn_statement(),
+            Self::SkipAlignPad(sap) => sap.to_syn_statement(),
+            Self::ReadList(rl) => rl.to_syn_statement(),
+            Self::SkipToReplyEnd(stre) => stre.to_syn_statement(),
+            Self::ReturnReadStruct(rrs) => rrs.to_syn_statement(),
+            Self::ContinueRequest(cr) => cr.to_syn_statement(),
+            Self::CheckListLength(cll) => cll.to_syn_statement(),
+            Self::ArbitraryItem(ai) => ai.to_syn_statement(),
+            Self::ReturnListIter(rli) => rli.to_syn_statement(),
+            Self::FromBytesFormatted(fbf) => fbf.to_syn_statement(),
+            Self::ArbitraryFormatted(af) => af.to_syn_statement(),
         }
     }
 }
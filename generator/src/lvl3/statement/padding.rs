@@ -1,6 +1,6 @@
 // MIT/Apache2 License
 
-use super::{index_plus_equal, let_statement, Statement};
+use super::{index_plus_equal, let_statement, zero_pad_call, Statement, SumOfSizes};
 use crate::lvl3::{
     syn_util::{int_litexpr_int, str_to_exprpath, str_to_pathseg},
     Type,
@@ -79,25 +79,113 @@ pub enum SetAlignAndAddPadding {
     Number(u64),
 }
 
+impl SetAlignAndAddPadding {
+    /// `buffer_pad(block_len, align)`
+    #[inline]
+    fn padding(&self) -> syn::Expr {
+        syn::Expr::Call(syn::ExprCall {
+            attrs: vec![],
+            func: Box::new(str_to_exprpath("buffer_pad")),
+            paren_token: Default::default(),
+            args: vec![
+                str_to_exprpath("block_len"),
+                match self {
+                    Self::Number(num) => int_litexpr_int(num),
+                    Self::AlignType(ty) => get_pad_align(ty),
+                },
+            ]
+            .into_iter()
+            .collect(),
+        })
+    }
+
+    /// Add the padding to "index", writing zeroes into the padding bytes as well.
+    #[inline]
+    pub fn to_zeroing_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            index_plus_equal(zero_pad_call(self.padding())),
+            Default::default(),
+        )]
+    }
+}
+
 impl Statement for SetAlignAndAddPadding {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
         vec![syn::Stmt::Semi(
-            index_plus_equal(syn::Expr::Call(syn::ExprCall {
-                attrs: vec![],
-                func: Box::new(str_to_exprpath("buffer_pad")),
-                paren_token: Default::default(),
-                args: vec![
-                    str_to_exprpath("block_len"),
-                    match self {
-                        Self::Number(num) => int_litexpr_int(num),
-                        Self::AlignType(ty) => get_pad_align(ty),
-                    },
-                ]
-                .into_iter()
-                .collect(),
-            })),
+            index_plus_equal(self.padding()),
             Default::default(),
         )]
     }
 }
+
+#[inline]
+pub fn buffer_pad_call(block_len: &str, align: usize) -> syn::Expr {
+    syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(str_to_exprpath("buffer_pad")),
+        paren_token: Default::default(),
+        args: vec![str_to_exprpath(block_len), int_litexpr_int(align)]
+            .into_iter()
+            .collect(),
+    })
+}
+
+/// Pad "index" out to a multiple of the alignment.
+#[derive(Debug, Clone, Copy)]
+pub struct AlignIndex(pub usize);
+
+impl Statement for AlignIndex {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            index_plus_equal(buffer_pad_call("index", self.0)),
+            Default::default(),
+        )]
+    }
+}
+
+/// Pad "index" out to a multiple of the alignment, writing zeroes into the padding bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroAlignPad(pub usize);
+
+impl Statement for ZeroAlignPad {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            index_plus_equal(zero_pad_call(buffer_pad_call("index", self.0))),
+            Default::default(),
+        )]
+    }
+}
+
+/// The sum of sizes, padded out to a multiple of the alignment.
+#[derive(Debug, Clone)]
+pub struct AlignedSumOfSizes(pub SumOfSizes, pub usize);
+
+impl Statement for AlignedSumOfSizes {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        match self.0.to_syn_statement().pop() {
+            Some(syn::Stmt::Expr(sum)) => align_size(sum, self.1),
+            _ => vec![],
+        }
+    }
+}
+
+/// `let size: usize = sum; size + buffer_pad(size, align)`
+#[inline]
+pub fn align_size(sum: syn::Expr, align: usize) -> Vec<syn::Stmt> {
+    vec![
+        syn::Stmt::Semi(
+            let_statement("size", Type::Basic("usize".into()), sum, false),
+            Default::default(),
+        ),
+        syn::Stmt::Expr(syn::Expr::Binary(syn::ExprBinary {
+            attrs: vec![],
+            left: Box::new(str_to_exprpath("size")),
+            op: syn::BinOp::Add(Default::default()),
+            right: Box::new(buffer_pad_call("size", align)),
+        })),
+    ]
+}
@@ -0,0 +1,261 @@
+// MIT/Apache2 License
+
+use super::{buffer_pad_call, get_pad_align, let_statement, Statement};
+use crate::{
+    lvl2::MaybeString,
+    lvl3::{
+        cast_to_usize,
+        syn_util::{int_litexpr_int, str_to_exprpath, str_to_path},
+        Type,
+    },
+};
+use proc_macro2::Span;
+use std::{borrow::Cow, fmt, iter};
+
+/// `func(reader, &mut index, args..)?`
+#[inline]
+fn checked_read(func: &str, args: Vec<syn::Expr>) -> syn::Expr {
+    syn::Expr::Try(syn::ExprTry {
+        attrs: vec![],
+        expr: Box::new(syn::Expr::Call(syn::ExprCall {
+            attrs: vec![],
+            func: Box::new(str_to_exprpath(func)),
+            paren_token: Default::default(),
+            args: vec![
+                str_to_exprpath("reader"),
+                syn::Expr::Reference(syn::ExprReference {
+                    attrs: vec![],
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability: Some(Default::default()),
+                    expr: Box::new(str_to_exprpath("index")),
+                }),
+            ]
+            .into_iter()
+            .chain(args)
+            .collect(),
+        })),
+        question_token: Default::default(),
+    })
+}
+
+#[inline]
+fn str_lit(s: &str) -> syn::Expr {
+    syn::Expr::Lit(syn::ExprLit {
+        attrs: vec![],
+        lit: syn::Lit::Str(syn::LitStr::new(s, Span::call_site())),
+    })
+}
+
+/// Read a variable that always takes up the same number of bytes out of the reader.
+#[derive(Debug, Clone)]
+pub struct ReadItem {
+    pub name: Box<str>,
+    pub ty: Type,
+    pub size: usize,
+}
+
+impl Statement for ReadItem {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            let_statement(
+                &self.name,
+                self.ty.clone(),
+                checked_read(
+                    "read_item",
+                    vec![int_litexpr_int(self.size), str_lit(&self.name)],
+                ),
+                false,
+            ),
+            Default::default(),
+        )]
+    }
+}
+
+/// Skip over a set number of bytes in the reader.
+#[derive(Debug, Clone, Copy)]
+pub struct SkipBytes(pub usize);
+
+impl Statement for SkipBytes {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            checked_read("skip_bytes", vec![int_litexpr_int(self.0)]),
+            Default::default(),
+        )]
+    }
+}
+
+/// Skip over the padding that aligns the index to a multiple of a number of bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct SkipAlignPad(pub usize);
+
+impl Statement for SkipAlignPad {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        // the padding is worked out before the index is borrowed
+        vec![
+            syn::Stmt::Semi(
+                let_statement(
+                    "pad",
+                    Type::Basic("usize".into()),
+                    buffer_pad_call("index", self.0),
+                    false,
+                ),
+                Default::default(),
+            ),
+            syn::Stmt::Semi(
+                checked_read("skip_bytes", vec![str_to_exprpath("pad")]),
+                Default::default(),
+            ),
+        ]
+    }
+}
+
+/// Read a list out of the reader, one chunk of elements at a time, and then skip its padding.
+#[derive(Clone)]
+pub struct ReadList {
+    pub name: Box<str>,
+    pub ty: MaybeString,
+    /// The size of each element, if this is not a string.
+    pub size: usize,
+    pub len: syn::Expr,
+    pub pad: Option<usize>,
+}
+
+impl fmt::Debug for ReadList {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ReadList")
+    }
+}
+
+impl Statement for ReadList {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let len = cast_to_usize(self.len.clone());
+        let (ty, read) = match self.ty {
+            MaybeString::IsAString => (
+                Type::Basic("String".into()),
+                checked_read("read_string", vec![len, str_lit(&self.name)]),
+            ),
+            MaybeString::NotAString(ref ty) => (
+                Type::Vector(Box::new(Type::from_lvl2(ty.clone()))),
+                checked_read(
+                    "read_vector",
+                    vec![len, int_litexpr_int(self.size), str_lit(&self.name)],
+                ),
+            ),
+        };
+
+        let load = syn::Stmt::Semi(
+            syn::Expr::Let(syn::ExprLet {
+                attrs: vec![],
+                let_token: Default::default(),
+                pat: syn::Pat::Type(syn::PatType {
+                    attrs: vec![],
+                    pat: Box::new(syn::Pat::Tuple(syn::PatTuple {
+                        attrs: vec![],
+                        paren_token: Default::default(),
+                        elems: vec![
+                            syn::Pat::Ident(syn::PatIdent {
+                                attrs: vec![],
+                                by_ref: None,
+                                mutability: None,
+                                ident: syn::Ident::new(&self.name, Span::call_site()),
+                                subpat: None,
+                            }),
+                            syn::Pat::Ident(syn::PatIdent {
+                                attrs: vec![],
+                                by_ref: None,
+                                mutability: None,
+                                ident: syn::Ident::new("block_len", Span::call_site()),
+                                subpat: None,
+                            }),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    })),
+                    colon_token: Default::default(),
+                    ty: Box::new(Type::Tuple(vec![ty, Type::Basic("usize".into())]).to_syn_ty()),
+                }),
+                eq_token: Default::default(),
+                expr: Box::new(read),
+            }),
+            Default::default(),
+        );
+
+        let align = match (self.pad, &self.ty) {
+            (Some(pad), _) => int_litexpr_int(pad),
+            (None, MaybeString::IsAString) => get_pad_align(&Type::Basic("c_char".into())),
+            (None, MaybeString::NotAString(ty)) => get_pad_align(&Type::from_lvl2(ty.clone())),
+        };
+        let pad = syn::Stmt::Semi(
+            checked_read(
+                "skip_bytes",
+                vec![syn::Expr::Call(syn::ExprCall {
+                    attrs: vec![],
+                    func: Box::new(str_to_exprpath("buffer_pad")),
+                    paren_token: Default::default(),
+                    args: vec![str_to_exprpath("block_len"), align]
+                        .into_iter()
+                        .collect(),
+                })],
+            ),
+            Default::default(),
+        );
+
+        vec![load, pad]
+    }
+}
+
+/// Skip over whatever is left of the reply in the reader. Requires the "length" variable to be set.
+#[derive(Debug, Clone, Copy)]
+pub struct SkipToReplyEnd;
+
+impl Statement for SkipToReplyEnd {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            checked_read("skip_to_reply_end", vec![str_to_exprpath("length")]),
+            Default::default(),
+        )]
+    }
+}
+
+/// Return a struct containing all of the listed fields, without the index.
+#[derive(Clone, Debug)]
+pub struct ReturnReadStruct {
+    pub sname: Box<str>,
+    pub fields: Vec<Cow<'static, str>>,
+}
+
+impl Statement for ReturnReadStruct {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Expr(syn::Expr::Call(syn::ExprCall {
+            attrs: vec![],
+            func: Box::new(str_to_exprpath("Ok")),
+            paren_token: Default::default(),
+            args: iter::once(syn::Expr::Struct(syn::ExprStruct {
+                attrs: vec![],
+                path: str_to_path(&self.sname),
+                brace_token: Default::default(),
+                fields: self
+                    .fields
+                    .iter()
+                    .map(|f| syn::FieldValue {
+                        attrs: vec![],
+                        member: syn::Member::Named(syn::Ident::new(f, Span::call_site())),
+                        colon_token: Some(Default::default()),
+                        expr: str_to_exprpath(f),
+                    })
+                    .collect(),
+                dot2_token: None,
+                rest: None,
+            }))
+            .collect(),
+        }))]
+    }
+}
@@ -0,0 +1,149 @@
+// MIT/Apache2 License
+
+use super::{let_statement, Statement, SumStatement};
+use crate::lvl3::{
+    syn_util::{default_call, int_litexpr_int, str_to_exprpath},
+    Type,
+};
+use std::iter;
+
+/// The name of the variable holding the index where the reply ends.
+const REPLY_END: &str = "reply_end";
+
+/// Define where the reply ends, according to the length that the reply announces. Requires the
+/// "length" variable to be set.
+#[derive(Debug, Clone, Copy)]
+pub struct DefineReplyEnd;
+
+impl Statement for DefineReplyEnd {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        // replies are 32 bytes long, plus the length in four-byte units
+        let extra = syn::Expr::Binary(syn::ExprBinary {
+            attrs: vec![],
+            left: Box::new(syn::Expr::Paren(syn::ExprParen {
+                attrs: vec![],
+                paren_token: Default::default(),
+                expr: Box::new(syn::Expr::Cast(syn::ExprCast {
+                    attrs: vec![],
+                    expr: Box::new(str_to_exprpath("length")),
+                    as_token: Default::default(),
+                    ty: Box::new(Type::Basic("usize".into()).to_syn_ty()),
+                })),
+            })),
+            op: syn::BinOp::Mul(Default::default()),
+            right: Box::new(int_litexpr_int(4)),
+        });
+
+        vec![syn::Stmt::Semi(
+            let_statement(
+                REPLY_END,
+                Type::Basic("usize".into()),
+                syn::Expr::Binary(syn::ExprBinary {
+                    attrs: vec![],
+                    left: Box::new(int_litexpr_int(32)),
+                    op: syn::BinOp::Add(Default::default()),
+                    right: Box::new(extra),
+                }),
+                false,
+            ),
+            Default::default(),
+        )]
+    }
+}
+
+/// Shorten the bytes being read to the length that the reply announces, so that a reply is never read
+/// past its end when it is followed by other bytes. Requires the "length" variable to be set.
+#[derive(Debug, Clone, Copy)]
+pub struct TruncateToReplyLength;
+
+impl Statement for TruncateToReplyLength {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::parse_quote! {
+            let bytes: &[u8] = reply_bytes(bytes, length);
+        }]
+    }
+}
+
+/// Load a variable only if the reply hasn't ended yet, and use its default value otherwise. Older
+/// servers send shorter replies that lack the fields added in later versions of the protocol.
+#[derive(Debug, Clone)]
+pub struct LoadIfPresent {
+    pub name: Box<str>,
+    pub ty: Type,
+    /// The statements that load the variable.
+    pub statements: Vec<SumStatement>,
+}
+
+/// Tell whether the reply hasn't ended before the current index.
+#[inline]
+fn present() -> syn::Expr {
+    syn::Expr::Binary(syn::ExprBinary {
+        attrs: vec![],
+        left: Box::new(str_to_exprpath("index")),
+        op: syn::BinOp::Lt(Default::default()),
+        right: Box::new(str_to_exprpath(REPLY_END)),
+    })
+}
+
+impl Statement for LoadIfPresent {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let load = syn::Block {
+            brace_token: Default::default(),
+            stmts: self
+                .statements
+                .iter()
+                .flat_map(|s| s.to_syn_statement())
+                .chain(iter::once(syn::Stmt::Expr(str_to_exprpath(&self.name))))
+                .collect(),
+        };
+
+        let default = syn::Expr::Block(syn::ExprBlock {
+            attrs: vec![],
+            label: None,
+            block: syn::Block {
+                brace_token: Default::default(),
+                stmts: vec![syn::Stmt::Expr(default_call())],
+            },
+        });
+
+        vec![syn::Stmt::Semi(
+            let_statement(
+                &self.name,
+                self.ty.clone(),
+                syn::Expr::If(syn::ExprIf {
+                    attrs: vec![],
+                    if_token: Default::default(),
+                    cond: Box::new(present()),
+                    then_branch: load,
+                    else_branch: Some((Default::default(), Box::new(default))),
+                }),
+                false,
+            ),
+            Default::default(),
+        )]
+    }
+}
+
+/// Skip padding only if the reply hasn't ended yet. The padding after a field that older servers
+/// leave out is left out along with it.
+#[derive(Debug, Clone)]
+pub struct SkipIfPresent(pub Vec<SumStatement>);
+
+impl Statement for SkipIfPresent {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Expr(syn::Expr::If(syn::ExprIf {
+            attrs: vec![],
+            if_token: Default::default(),
+            cond: Box::new(present()),
+            then_branch: syn::Block {
+                brace_token: Default::default(),
+                stmts: self.0.iter().flat_map(|s| s.to_syn_statement()).collect(),
+            },
+            else_branch: None,
+        }))]
+    }
+}
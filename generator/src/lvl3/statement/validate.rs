@@ -0,0 +1,43 @@
+// MIT/Apache2 License
+
+use super::Statement;
+use proc_macro2::{Ident, Span};
+use std::fmt;
+
+/// Return an error if a list does not have the length that the other fields of the structure
+/// describe.
+#[derive(Clone)]
+pub struct CheckListLength {
+    pub list: Box<str>,
+    pub expected: syn::Expr,
+}
+
+impl fmt::Debug for CheckListLength {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CheckListLength")
+    }
+}
+
+impl Statement for CheckListLength {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let list = Ident::new(&self.list, Span::call_site());
+        let name = &*self.list;
+        let expected = &self.expected;
+
+        let check: syn::Stmt = syn::parse_quote! {
+            {
+                let expected: usize = #expected;
+                if self.#list.len() != expected {
+                    return Err(ValidationError {
+                        list: #name,
+                        expected,
+                        found: self.#list.len(),
+                    });
+                }
+            }
+        };
+        vec![check]
+    }
+}
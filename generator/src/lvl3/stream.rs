@@ -0,0 +1,255 @@
+// MIT/Apache2 License
+
+use super::{
+    fd_names, known_sizes, output_names,
+    syn_util::{cfg_feature, doc_attrs, str_to_exprpath},
+    type_size, walk_items, EmitContext, InputParameter, Item, Method, ParameterUsage, RStruct,
+    StructureItemVisitor, SumStatement, Trait, Type,
+};
+use crate::lvl2::{Expression, Field, List, MaybeString, StructureItem, Switch, Type as Lvl2Type};
+use std::collections::{HashMap, HashSet};
+
+/// Collects the statements that read a reply out of a reader. If the reply has an item that can't
+/// be read without knowing what comes after it, no statements are collected.
+struct ReadVisitor<'a> {
+    sizes: &'a HashMap<Box<str>, usize>,
+    len_map: HashMap<String, Box<str>>,
+    last_len_index: usize,
+    stmts: Option<Vec<SumStatement>>,
+}
+
+impl<'a> ReadVisitor<'a> {
+    #[inline]
+    fn push_read(&mut self, name: &str, ty: &Lvl2Type) {
+        let size = type_size(self.sizes, ty);
+        match (self.stmts.as_mut(), size) {
+            (Some(stmts), Some(size)) => stmts.push(
+                super::ReadItem {
+                    name: name.into(),
+                    ty: Type::from_lvl2(ty.clone()),
+                    size,
+                }
+                .into(),
+            ),
+            _ => self.stmts = None,
+        }
+    }
+
+    /// The length of a list, in terms of the values that have already been read.
+    #[inline]
+    fn list_length(&self, name: &str, list_length: &Expression) -> syn::Expr {
+        match list_length.single_item().and(self.len_map.get(name)) {
+            Some(name) => str_to_exprpath(name),
+            None => list_length.to_length_expr_unwrapping(false, true, &[]),
+        }
+    }
+}
+
+impl<'a> StructureItemVisitor for ReadVisitor<'a> {
+    #[inline]
+    fn visit_field(&mut self, Field { name, ty, .. }: &Field) {
+        self.push_read(name, ty);
+    }
+
+    #[inline]
+    fn visit_padding(&mut self, bytes: usize) {
+        if let Some(ref mut stmts) = self.stmts {
+            stmts.push(super::SkipBytes(bytes).into());
+        }
+    }
+
+    #[inline]
+    fn visit_align_pad(&mut self, align: usize) {
+        if let Some(ref mut stmts) = self.stmts {
+            stmts.push(super::SkipAlignPad(align).into());
+        }
+    }
+
+    #[inline]
+    fn visit_len_slot(&mut self, owning_list: &str, ty: &Lvl2Type) {
+        let len_name = format!("len{}", self.last_len_index);
+        self.last_len_index += 1;
+
+        self.len_map
+            .insert(owning_list.to_string(), len_name.clone().into_boxed_str());
+        self.push_read(&len_name, ty);
+    }
+
+    #[inline]
+    fn visit_list(
+        &mut self,
+        List {
+            name,
+            ty,
+            list_length,
+            padding,
+            element_padding,
+            ..
+        }: &List,
+    ) {
+        // padded elements aren't all the same size, so they can't be read in chunks
+        if element_padding.is_some() {
+            self.stmts = None;
+            return;
+        }
+
+        let size = match ty {
+            MaybeString::IsAString => Some(1),
+            MaybeString::NotAString(ty) => type_size(self.sizes, ty),
+        };
+        let len = self.list_length(name, list_length);
+
+        match (self.stmts.as_mut(), size) {
+            (Some(stmts), Some(size)) => stmts.push(
+                super::ReadList {
+                    name: name.clone().into_boxed_str(),
+                    ty: ty.clone(),
+                    size,
+                    len,
+                    pad: *padding,
+                }
+                .into(),
+            ),
+            _ => self.stmts = None,
+        }
+    }
+
+    // the items in a switch can't be told apart without reading ahead
+    #[inline]
+    fn visit_switch(&mut self, _switch: &Switch) {
+        self.stmts = None;
+    }
+
+    #[inline]
+    fn visit_fd(&mut self, _name: &str) {
+        self.stmts = None;
+    }
+
+    // older servers send shorter replies that lack these items
+    #[inline]
+    fn visit_min_version(&mut self, _version: &str) {
+        self.stmts = None;
+    }
+}
+
+/// Tell whether this structure is a reply.
+#[inline]
+pub fn is_reply(rs: &RStruct) -> bool {
+    matches!(
+        rs.fields.first(),
+        Some(StructureItem::Field(Field { name, .. })) if name == "reply_type"
+    )
+}
+
+/// Tell whether this structure is a generic event, which may be longer than 32 bytes.
+#[inline]
+pub fn is_generic_event(rs: &RStruct) -> bool {
+    rs.traits.iter().any(|t| matches!(t, Trait::Event(_, true)))
+}
+
+impl RStruct {
+    /// Create a `read_from` method that reads this reply out of a reader, a few fields at a time.
+    #[inline]
+    fn stream_parser(
+        &self,
+        sizes: &HashMap<Box<str>, usize>,
+        ctx: &EmitContext,
+    ) -> Option<syn::ImplItem> {
+        if !fd_names(&self.fields).is_empty() {
+            return None;
+        }
+
+        let mut visitor = ReadVisitor {
+            sizes,
+            len_map: HashMap::new(),
+            last_len_index: 0,
+            stmts: Some(vec![]),
+        };
+        walk_items(&self.fields, &mut visitor);
+
+        let mut method = Method::new(
+            "read_from".into(),
+            None,
+            vec![InputParameter {
+                name: "reader".into(),
+                ty: Type::Dyn("std::io::Read".into()),
+                usage: ParameterUsage::MutRef,
+            }],
+            Some(Type::ResWith(
+                Box::new(Type::Basic("Self".into())),
+                Box::new(Type::Basic("std::io::Error".into())),
+            )),
+        );
+        method.statements = vec![super::CreateIndexVariable.into()]
+            .into_iter()
+            .chain(visitor.stmts?)
+            .chain(vec![
+                super::SkipToReplyEnd.into(),
+                super::ReturnReadStruct {
+                    sname: self.name.clone(),
+                    fields: output_names(&self.fields),
+                }
+                .into(),
+            ])
+            .collect();
+
+        let mut item = method.to_syn_impl_item(false, ctx);
+        if let syn::ImplItem::Method(ref mut m) = item {
+            m.attrs.splice(
+                0..0,
+                doc_attrs(
+                    "Read this reply out of a reader, without waiting for all of it to arrive. \
+                     Use `from_bytes` instead if the reply is already in memory.",
+                )
+                .into_iter()
+                .chain(Some(cfg_feature("std"))),
+            );
+        }
+        Some(item)
+    }
+}
+
+/// Add a `read_from` method to every reply that can be read one field at a time.
+#[inline]
+pub fn populate_stream_parsers(items: &mut [Item], ctx: &EmitContext) {
+    let sizes = known_sizes(items);
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if is_reply(rs) {
+                if let Some(parser) = rs.stream_parser(&sizes, ctx) {
+                    rs.other_impl_items.push(parser);
+                }
+            }
+        }
+    });
+}
+
+/// Add `[list]_iter` functions to every reply with lists of the structures defined alongside it, so that
+/// large lists can be parsed one item at a time.
+#[inline]
+pub fn populate_list_iters(items: &mut [Item]) {
+    // resource IDs and bitflags are structures in Rust, but are read as single numbers
+    let structs: HashSet<Box<str>> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::RStruct(rs)
+                if !rs
+                    .traits
+                    .iter()
+                    .any(|t| matches!(t, Trait::Xid | Trait::BitflagsNot(_))) =>
+            {
+                Some(rs.name.clone())
+            }
+            _ => None,
+        })
+        .collect();
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if is_reply(rs) {
+                rs.populate_list_iters(&structs);
+            }
+        }
+    });
+}
@@ -0,0 +1,54 @@
+// MIT/Apache2 License
+
+use super::{
+    is_reply, syn_util::doc_attrs, EmitContext, InputParameter, Item, Method, ParameterUsage, Type,
+};
+
+/// Create a `try_from_bytes_strict` method that reads a reply, and fails if its fields don't take up
+/// the number of bytes that its length describes.
+#[inline]
+fn strict_parser(ctx: &EmitContext) -> syn::ImplItem {
+    let mut method = Method::new(
+        "try_from_bytes_strict".into(),
+        None,
+        vec![InputParameter {
+            name: "bytes".into(),
+            ty: Type::Slice(Box::new(Type::Basic("u8".into()))),
+            usage: ParameterUsage::Ref,
+        }],
+        Some(Type::ResWith(
+            Box::new(Type::Tuple(vec![
+                Type::Basic("Self".into()),
+                Type::Basic("usize".into()),
+            ])),
+            Box::new(Type::Basic("StrictParseError".into())),
+        )),
+    );
+    method.statements =
+        vec![super::ExprWrapper(syn::parse_quote! { parse_reply_strict(bytes) }).into()];
+
+    let mut item = method.to_syn_impl_item(false, ctx);
+    if let syn::ImplItem::Method(ref mut m) = item {
+        m.attrs.splice(
+            0..0,
+            doc_attrs(
+                "Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the \
+                 reply is longer or shorter than its fields.\nServers may add fields to replies in \
+                 newer versions, so this is meant for checking the description of the reply.",
+            ),
+        );
+    }
+    item
+}
+
+/// Give every reply a `try_from_bytes_strict` method, which reports the bytes that its fields don't read.
+#[inline]
+pub fn populate_strict_parsers(items: &mut [Item], ctx: &EmitContext) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if is_reply(rs) {
+                rs.other_impl_items.push(strict_parser(ctx));
+            }
+        }
+    });
+}
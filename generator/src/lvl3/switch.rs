@@ -3,6 +3,7 @@
 use super::syn_util::{item_field, str_to_exprpath, str_to_pathseg};
 use crate::lvl2::{safe_name, ConditionVariant, UseCondition};
 use heck::{CamelCase, SnakeCase};
+use std::iter;
 
 impl UseCondition {
     #[inline]
@@ -21,20 +22,56 @@ impl UseCondition {
                 attrs: vec![],
                 left: Box::new(str_to_exprpath(exprname)),
                 op: syn::BinOp::Eq(Default::default()),
-                right: Box::new(syn::Expr::Path(syn::ExprPath {
-                    attrs: vec![],
-                    qself: None,
-                    path: syn::Path {
-                        leading_colon: None,
-                        segments: vec![
-                            str_to_pathseg(&self.enum_name.to_camel_case()),
-                            str_to_pathseg(&self.enum_value.to_camel_case()),
-                        ]
-                        .into_iter()
-                        .collect(),
-                    },
-                })),
+                right: Box::new(self.variant_path()),
             }),
         }
     }
+
+    /// Create an expression that modifies `target` so that this condition is met.
+    #[inline]
+    pub fn to_meet_expr(&self, target: syn::Expr) -> syn::Expr {
+        match self.variant {
+            ConditionVariant::BitflagVariant => {
+                self.to_set_bit_expr(target, str_to_exprpath("true"))
+            }
+            ConditionVariant::Equal => syn::Expr::Assign(syn::ExprAssign {
+                attrs: vec![],
+                left: Box::new(target),
+                eq_token: Default::default(),
+                right: Box::new(self.variant_path()),
+            }),
+        }
+    }
+
+    /// Create an expression that sets the bit in `target` this condition checks to `value`. This
+    /// only makes sense for bitflag conditions.
+    #[inline]
+    pub fn to_set_bit_expr(&self, target: syn::Expr, value: syn::Expr) -> syn::Expr {
+        syn::Expr::Call(syn::ExprCall {
+            attrs: vec![],
+            func: Box::new(item_field(
+                target,
+                &format!("set_{}", self.enum_value.to_snake_case()),
+            )),
+            paren_token: Default::default(),
+            args: iter::once(value).collect(),
+        })
+    }
+
+    #[inline]
+    fn variant_path(&self) -> syn::Expr {
+        syn::Expr::Path(syn::ExprPath {
+            attrs: vec![],
+            qself: None,
+            path: syn::Path {
+                leading_colon: None,
+                segments: vec![
+                    str_to_pathseg(&self.enum_name.to_camel_case()),
+                    str_to_pathseg(&safe_name(self.enum_value.to_camel_case())),
+                ]
+                .into_iter()
+                .collect(),
+            },
+        })
+    }
 }
@@ -3,7 +3,7 @@
 //! Syn utilities.
 
 use proc_macro2::{Span, TokenStream};
-use std::{fmt, iter, str::FromStr};
+use std::{collections::BTreeMap, fmt, str::FromStr};
 use syn::Expr;
 
 /// Convert a string to a path segment.
@@ -15,12 +15,12 @@ pub fn str_to_pathseg(s: &str) -> syn::PathSegment {
     }
 }
 
-/// Convert a string to a path.
+/// Convert a string to a path. The segments of the path are separated by `::`.
 #[inline]
 pub fn str_to_path(s: &str) -> syn::Path {
     syn::Path {
         leading_colon: None,
-        segments: iter::once(str_to_pathseg(s)).collect(),
+        segments: s.split("::").map(str_to_pathseg).collect(),
     }
 }
 
@@ -84,30 +84,163 @@ pub fn item_field(name: Expr, fieldname: &str) -> syn::Expr {
     })
 }
 
+/// A call to `Default::default()`.
 #[inline]
-pub fn derive_attrs(d: &[&'static str]) -> syn::Attribute {
-    let mut tokens = String::from("(");
-    d.iter().for_each(|de| {
-        tokens.push_str(de);
-        tokens.push_str(", ");
+pub fn default_call() -> syn::Expr {
+    syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(syn::Expr::Path(syn::ExprPath {
+            attrs: vec![],
+            qself: None,
+            path: syn::Path {
+                leading_colon: None,
+                segments: vec![str_to_pathseg("Default"), str_to_pathseg("default")]
+                    .into_iter()
+                    .collect(),
+            },
+        })),
+        paren_token: Default::default(),
+        args: syn::punctuated::Punctuated::new(),
+    })
+}
+
+/// The feature that enables the traits from a crate. This is usually the crate's name.
+#[inline]
+fn crate_feature(krate: &'static str) -> &'static str {
+    match krate {
+        "arbitrary" => "fuzzing",
+        "bytemuck" => "pod",
+        krate => krate,
+    }
+}
+
+/// Derive attributes for a list of traits. Traits from another crate (e.g. `serde::Serialize`) are
+/// only derived if the feature for that crate is enabled.
+#[inline]
+pub fn derive_attrs(d: &[&'static str]) -> Vec<syn::Attribute> {
+    #[inline]
+    fn derive_list(d: &[&'static str]) -> String {
+        let mut tokens = String::from("(");
+        d.iter().for_each(|de| {
+            tokens.push_str(de);
+            tokens.push_str(", ");
+        });
+        tokens.push(')');
+        tokens
+    }
+
+    let mut local: Vec<&'static str> = vec![];
+    let mut external: BTreeMap<&'static str, Vec<&'static str>> = BTreeMap::new();
+    d.iter().for_each(|de| match de.find("::") {
+        Some(posn) => external.entry(&de[..posn]).or_default().push(de),
+        None => local.push(de),
     });
-    tokens.push(')');
+
+    let local = match local.len() {
+        0 => None,
+        _ => Some(syn::Attribute {
+            pound_token: Default::default(),
+            style: syn::AttrStyle::Outer,
+            bracket_token: Default::default(),
+            path: str_to_path("derive"),
+            tokens: TokenStream::from_str(&derive_list(&local)).unwrap(),
+        }),
+    };
+
+    local
+        .into_iter()
+        .chain(external.into_iter().map(|(krate, d)| {
+            syn::Attribute {
+                pound_token: Default::default(),
+                style: syn::AttrStyle::Outer,
+                bracket_token: Default::default(),
+                path: str_to_path("cfg_attr"),
+                tokens: TokenStream::from_str(&format!(
+                    "(feature = \"{}\", derive{})",
+                    crate_feature(krate),
+                    derive_list(&d)
+                ))
+                .unwrap(),
+            }
+        }))
+        .collect()
+}
+
+#[inline]
+pub fn repr_transparent() -> syn::Attribute {
     syn::Attribute {
         pound_token: Default::default(),
         style: syn::AttrStyle::Outer,
         bracket_token: Default::default(),
-        path: str_to_path("derive"),
-        tokens: TokenStream::from_str(&tokens).unwrap(),
+        path: str_to_path("repr"),
+        tokens: TokenStream::from_str("(transparent)").unwrap(),
     }
 }
 
 #[inline]
-pub fn repr_transparent() -> syn::Attribute {
+pub fn repr_c() -> syn::Attribute {
     syn::Attribute {
         pound_token: Default::default(),
         style: syn::AttrStyle::Outer,
         bracket_token: Default::default(),
         path: str_to_path("repr"),
-        tokens: TokenStream::from_str("(transparent)").unwrap(),
+        tokens: TokenStream::from_str("(C)").unwrap(),
+    }
+}
+
+#[inline]
+pub fn non_exhaustive() -> syn::Attribute {
+    syn::Attribute {
+        pound_token: Default::default(),
+        style: syn::AttrStyle::Outer,
+        bracket_token: Default::default(),
+        path: str_to_path("non_exhaustive"),
+        tokens: TokenStream::new(),
+    }
+}
+
+/// Warn when a value is left unused, for the given reason.
+#[inline]
+pub fn must_use(reason: &str) -> syn::Attribute {
+    syn::Attribute {
+        pound_token: Default::default(),
+        style: syn::AttrStyle::Outer,
+        bracket_token: Default::default(),
+        path: str_to_path("must_use"),
+        tokens: TokenStream::from_str(&format!("= \"{}\"", reason)).unwrap(),
     }
 }
+
+/// Only compile an item if a feature is enabled.
+#[inline]
+pub fn cfg_feature(feature: &str) -> syn::Attribute {
+    syn::Attribute {
+        pound_token: Default::default(),
+        style: syn::AttrStyle::Outer,
+        bracket_token: Default::default(),
+        path: str_to_path("cfg"),
+        tokens: TokenStream::from_str(&format!("(feature = \"{}\")", feature)).unwrap(),
+    }
+}
+
+/// Documentation attributes, one for each line of the documentation.
+#[inline]
+pub fn doc_attrs(docs: &str) -> Vec<syn::Attribute> {
+    docs.lines()
+        .map(|line| {
+            let line = match line.trim() {
+                "" => String::new(),
+                line => format!(" {}", line),
+            };
+            let line = syn::LitStr::new(&line, Span::call_site());
+
+            syn::Attribute {
+                pound_token: Default::default(),
+                style: syn::AttrStyle::Outer,
+                bracket_token: Default::default(),
+                path: str_to_path("doc"),
+                tokens: quote::quote! { = #line },
+            }
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 // MIT/Apache2 License
 
-use super::syn_util::{int_litexpr_int, str_to_pathseg, str_to_ty};
+use super::syn_util::{int_litexpr_int, str_to_path, str_to_pathseg, str_to_ty};
 use crate::lvl2::Type as Lvl2Type;
 use proc_macro2::Span;
 use std::{borrow::Cow, iter};
@@ -15,18 +15,24 @@ pub enum Type {
     Array(Box<Type>, u64),
     /// Vector type.
     Vector(Box<Type>),
-    /// TinyVec type.
-    TinyVec(Box<Type>, u64),
     /// Option type.
     Opt(Box<Type>),
-    /// crate::Result type
-    Res(Box<Type>),
+    /// Result type with a ParseError
+    ParseRes(Box<Type>),
+    /// Result type with another error type.
+    ResWith(Box<Type>, Box<Type>),
     /// Tuple container type.
     Tuple(Vec<Type>),
     /// Reference to another type.
     Ref(Box<Type>, bool, Option<&'static str>),
     /// Slice of a type.
     Slice(Box<Type>),
+    /// Trait object of a trait.
+    Dyn(Cow<'static, str>),
+    /// An iterator that parses the items of a list as it goes, borrowing the bytes they're parsed from.
+    ListIter(Box<Type>),
+    /// A slice that is borrowed from the bytes it's read from if they're aligned, or copied otherwise.
+    CowSlice(Box<Type>),
 }
 
 impl Type {
@@ -53,7 +59,7 @@ impl Type {
     #[inline]
     pub fn to_syn_ty(&self) -> syn::Type {
         match self {
-            Self::Basic(c) => str_to_ty(&*c),
+            Self::Basic(c) => str_to_ty(c),
             Self::Path { owner, name } => syn::Type::Path(syn::TypePath {
                 qself: None,
                 path: syn::Path {
@@ -74,41 +80,18 @@ impl Type {
                 len: int_litexpr_int(len),
             }),
             Self::Vector(c) => syn_container_type_ty("Vec", c.to_syn_ty()),
-            Self::TinyVec(c, len) => syn_container_type_ty(
-                "TinyVec",
-                syn::Type::Array(syn::TypeArray {
-                    bracket_token: Default::default(),
-                    elem: Box::new(c.to_syn_ty()),
-                    semi_token: Default::default(),
-                    len: int_litexpr_int(len),
-                }),
-            ),
             Self::Opt(c) => syn_container_type_ty("Option", c.to_syn_ty()),
-            Self::Res(c) => syn::Type::Path(syn::TypePath {
-                qself: None,
-                path: syn::Path {
-                    leading_colon: None,
-                    segments: vec![
-                        str_to_pathseg("crate"),
-                        syn::PathSegment {
-                            ident: syn::Ident::new("Result", Span::call_site()),
-                            arguments: generic_argument(c),
-                        },
-                    ]
-                    .into_iter()
-                    .collect(),
-                },
-            }),
+            Self::ParseRes(c) => result_ty(c.to_syn_ty(), str_to_ty("ParseError")),
+            Self::ResWith(c, e) => result_ty(c.to_syn_ty(), e.to_syn_ty()),
             Self::Tuple(tys) => syn::Type::Tuple(syn::TypeTuple {
                 paren_token: Default::default(),
                 elems: tys.iter().map(|t| t.to_syn_ty()).collect(),
             }),
             Self::Ref(r, is_mut, lifetime) => syn::Type::Reference(syn::TypeReference {
                 and_token: Default::default(),
-                lifetime: match lifetime {
-                    None => None,
-                    Some(lifetime) => Some(syn::Lifetime::new(lifetime, Span::call_site())),
-                },
+                lifetime: lifetime
+                    .as_ref()
+                    .map(|lifetime| syn::Lifetime::new(lifetime, Span::call_site())),
                 mutability: if *is_mut {
                     Some(Default::default())
                 } else {
@@ -120,6 +103,24 @@ impl Type {
                 bracket_token: Default::default(),
                 elem: Box::new(r.to_syn_ty()),
             }),
+            Self::Dyn(t) => syn::Type::TraitObject(syn::TypeTraitObject {
+                dyn_token: Some(Default::default()),
+                bounds: iter::once(syn::TypeParamBound::Trait(syn::TraitBound {
+                    paren_token: None,
+                    modifier: syn::TraitBoundModifier::None,
+                    lifetimes: None,
+                    path: str_to_path(t),
+                }))
+                .collect(),
+            }),
+            Self::ListIter(c) => {
+                let elem = c.to_syn_ty();
+                syn::parse_quote! { ListIter<'_, #elem> }
+            }
+            Self::CowSlice(c) => {
+                let elem = c.to_syn_ty();
+                syn::parse_quote! { Cow<'_, [#elem]> }
+            }
         }
     }
 
@@ -148,11 +149,6 @@ fn syn_container_type_ty(name: &str, inner: syn::Type) -> syn::Type {
     })
 }
 
-#[inline]
-fn generic_argument(inner: &Type) -> syn::PathArguments {
-    generic_argument_ty(inner.to_syn_ty())
-}
-
 #[inline]
 fn generic_argument_ty(ty: syn::Type) -> syn::PathArguments {
     syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments {
@@ -162,3 +158,30 @@ fn generic_argument_ty(ty: syn::Type) -> syn::PathArguments {
         gt_token: Default::default(),
     })
 }
+
+#[inline]
+fn result_ty(ok: syn::Type, err: syn::Type) -> syn::Type {
+    syn::Type::Path(syn::TypePath {
+        qself: None,
+        path: syn::Path {
+            leading_colon: None,
+            segments: iter::once(syn::PathSegment {
+                ident: syn::Ident::new("Result", Span::call_site()),
+                arguments: syn::PathArguments::AngleBracketed(
+                    syn::AngleBracketedGenericArguments {
+                        colon2_token: None,
+                        lt_token: Default::default(),
+                        args: vec![
+                            syn::GenericArgument::Type(ok),
+                            syn::GenericArgument::Type(err),
+                        ]
+                        .into_iter()
+                        .collect(),
+                        gt_token: Default::default(),
+                    },
+                ),
+            })
+            .collect(),
+        },
+    })
+}
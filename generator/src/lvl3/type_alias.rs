@@ -1,16 +1,14 @@
 // MIT/Apache2 License
 
-use super::{
-    syn_util::{pub_vis, str_to_ty},
-    ToSyn,
-};
+use super::{syn_util::pub_vis, EmitContext, ToSyn, Type};
 use crate::lvl1::Typedef;
 use proc_macro2::Span;
 
-// convert a level 1 type alias into a syn typedef item
+// convert a level 1 type alias into a syn typedef item; aliases of types from other namespaces, like
+// "xproto:RECTANGLE", refer to the module of that namespace
 impl ToSyn for Typedef {
     #[inline]
-    fn to_syn_item(self) -> Vec<syn::Item> {
+    fn to_syn_item(self, _ctx: &EmitContext) -> Vec<syn::Item> {
         vec![syn::Item::Type(syn::ItemType {
             attrs: vec![],
             vis: pub_vis(),
@@ -18,7 +16,7 @@ impl ToSyn for Typedef {
             ident: syn::Ident::new(&self.newname, Span::call_site()),
             generics: Default::default(),
             eq_token: Default::default(),
-            ty: Box::new(str_to_ty(&self.oldname)),
+            ty: Box::new(Type::from_name(self.oldname).to_syn_ty()),
             semi_token: Default::default(),
         })]
     }
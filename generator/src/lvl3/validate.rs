@@ -0,0 +1,151 @@
+// MIT/Apache2 License
+
+use super::{
+    cast_to_usize, primitive_size,
+    syn_util::{doc_attrs, str_to_exprpath},
+    EmitContext, Item, Method, ParameterUsage, RStruct, SumStatement, Trait, Type,
+};
+use crate::lvl2::{Expression, ExpressionItem, Field, List, StructureItem, UnaryOp};
+use std::{collections::HashSet, iter};
+
+/// Tell whether the length of a list can be found from the fields of a structure. Each of the fields
+/// it refers to has to be a number that the user sets, rather than a length slot or an optional field.
+#[inline]
+fn is_checkable(expr: &Expression, fields: &HashSet<&str>) -> bool {
+    expr.iter().all(|item| match item {
+        ExpressionItem::FieldRef(name) => fields.contains(&**name),
+        ExpressionItem::Value(_) | ExpressionItem::BinaryOp(_) => true,
+        ExpressionItem::UnaryOp(op) => matches!(op, UnaryOp::OneCount | UnaryOp::Not),
+        ExpressionItem::ParamRef(_)
+        | ExpressionItem::Remainder
+        | ExpressionItem::SumOf(..)
+        | ExpressionItem::ListExprRef => false,
+    })
+}
+
+impl RStruct {
+    /// The lists of this structure whose lengths are described by its other fields. Lists whose lengths
+    /// are written from the lists themselves can't disagree with anything, and aren't included.
+    #[inline]
+    pub fn described_lists(&self) -> Vec<&List> {
+        let fields: HashSet<&str> = self
+            .fields
+            .iter()
+            .filter_map(|item| match item {
+                StructureItem::Field(Field { name, ty, .. }) if primitive_size(ty).is_some() => {
+                    Some(name.as_str())
+                }
+                _ => None,
+            })
+            .collect();
+
+        let owned: HashSet<&str> = self
+            .fields
+            .iter()
+            .filter_map(|item| match item {
+                StructureItem::LenSlot { owning_list, .. } => Some(owning_list.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        self.fields
+            .iter()
+            .filter_map(|item| match item {
+                StructureItem::List(list)
+                    if !owned.contains(list.name.as_str())
+                        && is_checkable(&list.list_length, &fields) =>
+                {
+                    Some(list)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The checks that the lists of this structure have the lengths that its fields describe.
+    #[inline]
+    fn list_checks(&self) -> Vec<SumStatement> {
+        self.described_lists()
+            .into_iter()
+            .map(
+                |List {
+                     name, list_length, ..
+                 }| {
+                    super::CheckListLength {
+                        list: name.clone().into_boxed_str(),
+                        expected: cast_to_usize(list_length.to_length_expr(true, true)),
+                    }
+                    .into()
+                },
+            )
+            .collect()
+    }
+
+    /// Create a `validate` method that runs the list checks.
+    #[inline]
+    fn validate_method(checks: Vec<SumStatement>, ctx: &EmitContext) -> syn::ImplItem {
+        let mut method = Method::new(
+            "validate".into(),
+            Some(ParameterUsage::Ref),
+            vec![],
+            Some(Type::ResWith(
+                Box::new(Type::Tuple(vec![])),
+                Box::new(Type::Basic("ValidationError".into())),
+            )),
+        );
+        method.statements = checks
+            .into_iter()
+            .chain(iter::once(
+                super::ExprWrapper(syn::Expr::Call(syn::ExprCall {
+                    attrs: vec![],
+                    func: Box::new(str_to_exprpath("Ok")),
+                    paren_token: Default::default(),
+                    args: iter::once(syn::Expr::Tuple(syn::ExprTuple {
+                        attrs: vec![],
+                        paren_token: Default::default(),
+                        elems: Default::default(),
+                    }))
+                    .collect(),
+                }))
+                .into(),
+            ))
+            .collect();
+
+        let mut item = method.to_syn_impl_item(false, ctx);
+        if let syn::ImplItem::Method(ref mut m) = item {
+            m.attrs.splice(
+                0..0,
+                doc_attrs(
+                    "Check that the lists of this request have the lengths that its other fields \
+                     describe.\nThis is done before the request is sent.",
+                ),
+            );
+        }
+        item
+    }
+}
+
+/// Give the requests whose lists have lengths described by other fields a `validate` method that
+/// checks them.
+#[inline]
+pub fn populate_validators(items: &mut [Item], ctx: &EmitContext) {
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            let checks = match rs.traits.iter().any(|t| matches!(t, Trait::Request(..))) {
+                true => rs.list_checks(),
+                false => return,
+            };
+            if checks.is_empty() {
+                return;
+            }
+
+            rs.other_impl_items
+                .push(RStruct::validate_method(checks, ctx));
+            rs.traits.iter_mut().for_each(|t| {
+                if let Trait::Request(_, _, _, _, ref mut validates) = t {
+                    *validates = true;
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,56 @@
+// MIT/Apache2 License
+
+use super::RStruct;
+use crate::lvl2::{Field, List, StructureItem, Switch, Type};
+
+/// Something that walks over a series of structure items, with one method for each kind of item.
+/// Adding a new kind of item means adding a method here, so no visitor can forget about it.
+pub trait StructureItemVisitor {
+    fn visit_field(&mut self, field: &Field);
+    fn visit_padding(&mut self, bytes: usize);
+    fn visit_align_pad(&mut self, align: usize);
+    fn visit_len_slot(&mut self, owning_list: &str, ty: &Type);
+    fn visit_list(&mut self, list: &List);
+    fn visit_switch(&mut self, switch: &Switch);
+    fn visit_fd(&mut self, name: &str);
+
+    /// Called before visiting an item that was added in a later version of the protocol.
+    #[inline]
+    fn visit_min_version(&mut self, _version: &str) {}
+}
+
+/// Visit each of the items in a series, in order.
+#[inline]
+pub fn walk_items<V: StructureItemVisitor + ?Sized>(items: &[StructureItem], visitor: &mut V) {
+    items.iter().for_each(|item| match item {
+        StructureItem::Field(field) => {
+            if let Some(ref version) = field.min_version {
+                visitor.visit_min_version(version);
+            }
+            visitor.visit_field(field)
+        }
+        StructureItem::Padding { bytes } => visitor.visit_padding(*bytes),
+        StructureItem::AlignPad { align } => visitor.visit_align_pad(*align),
+        StructureItem::LenSlot {
+            owning_list,
+            ty,
+            min_version,
+        } => {
+            if let Some(ref version) = min_version {
+                visitor.visit_min_version(version);
+            }
+            visitor.visit_len_slot(owning_list, ty)
+        }
+        StructureItem::List(list) => visitor.visit_list(list),
+        StructureItem::Switch(switch) => visitor.visit_switch(switch),
+        StructureItem::Fd { name } => visitor.visit_fd(name),
+    })
+}
+
+impl RStruct {
+    /// Visit each of the fields of this structure, in order.
+    #[inline]
+    pub fn walk<V: StructureItemVisitor + ?Sized>(&self, visitor: &mut V) {
+        walk_items(&self.fields, visitor)
+    }
+}
@@ -0,0 +1,99 @@
+// MIT/Apache2 License
+
+use super::{known_sizes, syn_util::doc_attrs, type_size, Item};
+use crate::lvl2::{Field, StructureItem, Type as Lvl2Type};
+use proc_macro2::Literal;
+use std::collections::HashMap;
+
+/// The size of the type of a field, if it never changes. Booleans are still named `Bool` at this
+/// point, and are sent as one byte.
+#[inline]
+fn field_size(sizes: &HashMap<Box<str>, usize>, ty: &Lvl2Type) -> Option<usize> {
+    match ty {
+        Lvl2Type::BasicType(name) if name == "Bool" => Some(1),
+        ty => type_size(sizes, ty),
+    }
+}
+
+/// The name, offset and size of each of the fields of a structure, up to the first item whose size
+/// isn't known ahead of time, along with whether every field was reached. Length slots are named
+/// after the lists they hold the lengths of, and padding is left out.
+#[inline]
+fn wire_fields(
+    sizes: &HashMap<Box<str>, usize>,
+    items: &[StructureItem],
+) -> (Vec<(String, usize, usize)>, bool) {
+    let mut offset = 0;
+    let mut fields = vec![];
+    for item in items {
+        match item {
+            StructureItem::Field(Field { name, ty, .. }) => match field_size(sizes, ty) {
+                Some(size) => {
+                    fields.push((name.clone(), offset, size));
+                    offset += size;
+                }
+                None => return (fields, false),
+            },
+            StructureItem::LenSlot {
+                ty, owning_list, ..
+            } => match field_size(sizes, ty) {
+                Some(size) => {
+                    fields.push((format!("{}_len", owning_list), offset, size));
+                    offset += size;
+                }
+                None => return (fields, false),
+            },
+            StructureItem::Padding { bytes } => offset += bytes,
+            StructureItem::AlignPad { align } => offset += (align - offset % align) % align,
+            StructureItem::Fd { .. } => (),
+            StructureItem::List(_) | StructureItem::Switch(_) => return (fields, false),
+        }
+    }
+    (fields, true)
+}
+
+/// `pub const WIRE_FIELDS: &[(&str, usize, usize)]`, which describes where each
+/// field lies in the bytes of the structure.
+#[inline]
+fn wire_fields_const(fields: &[(String, usize, usize)], is_complete: bool) -> syn::ImplItem {
+    let docs = doc_attrs(match is_complete {
+        true => {
+            "The name, offset and size in bytes of each field, as they are laid out in the bytes \
+             of this structure."
+        }
+        false => {
+            "The name, offset and size in bytes of each field, as they are laid out in the bytes \
+             of this structure. Past the first item whose size isn't known ahead of time, the \
+             offsets depend on the value, so those fields are left out."
+        }
+    });
+    let entries = fields.iter().map(|(name, offset, size)| -> syn::Expr {
+        let offset = Literal::usize_unsuffixed(*offset);
+        let size = Literal::usize_unsuffixed(*size);
+        syn::parse_quote! { (#name, #offset, #size) }
+    });
+
+    syn::parse_quote! {
+        #(#docs)*
+        pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[#(#entries),*];
+    }
+}
+
+/// Give every structure a `WIRE_FIELDS` constant that describes how its fields are laid out in its
+/// bytes, so that tools can label the bytes of a structure without knowing its type ahead of time.
+/// Newtypes are left out, since their one field covers all of their bytes.
+#[inline]
+pub fn populate_wire_fields(items: &mut [Item]) {
+    let sizes = known_sizes(items);
+
+    items.iter_mut().for_each(|item| {
+        if let Item::RStruct(rs) = item {
+            if rs.is_transparent {
+                return;
+            }
+            let (fields, is_complete) = wire_fields(&sizes, &rs.fields);
+            rs.other_impl_items
+                .push(wire_fields_const(&fields, is_complete));
+        }
+    });
+}
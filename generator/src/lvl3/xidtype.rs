@@ -1,9 +1,6 @@
 // MIT/Apache2 License
 
-use super::{
-    syn_util::{item_field, str_to_exprpath},
-    Asb, ExprWrapper, InputParameter, Method, ParameterUsage, RStruct, Trait, Type as Lvl3Type,
-};
+use super::{Asb, InputParameter, Method, ParameterUsage, RStruct, Trait, Type as Lvl3Type};
 use crate::lvl2::{Field, StructureItem, Type, XidType};
 
 impl From<XidType> for RStruct {
@@ -14,19 +11,20 @@ impl From<XidType> for RStruct {
         // general struct defn
         let mut rstr = RStruct {
             name,
+            docs: None,
             derives: vec![
                 "Default",
                 "Copy",
                 "Clone",
-                "Debug",
                 "PartialEq",
                 "Eq",
                 "PartialOrd",
                 "Ord",
                 "Hash",
             ],
-            fds: vec![],
             is_transparent: true,
+            is_repr_c: false,
+            defaults: vec![],
             fields: vec![StructureItem::Field(Field {
                 name: "xid".into(),
                 ty: Type::BasicType("XID".into()),
@@ -36,6 +34,7 @@ impl From<XidType> for RStruct {
             other_impl_items: vec![],
             traits: vec![],
             asb: Asb::none(),
+            shared_asb: None,
         };
 
         // it needs a const. method for initialization of constants
@@ -54,6 +53,9 @@ impl From<XidType> for RStruct {
         rstr.methods.push(method);
 
         rstr.traits.push(Trait::Xid);
+        rstr.traits.push(Trait::XidDebug);
+        rstr.traits.push(Trait::XidFromRaw);
+        rstr.traits.push(Trait::XidIntoRaw);
         rstr.traits
             .extend(from_impls.into_iter().map(|f| Trait::FromXid(f.unwrap())));
 
@@ -1,7 +1,16 @@
 // MIT/Apache2 License
 
 #![recursion_limit = "256"]
+#![allow(
+    clippy::large_enum_variant, // the parser states are short-lived
+    clippy::enum_variant_names,
+    clippy::wrong_self_convention // `to_syn_*` consumes the items it converts
+)]
 
+mod bench;
+mod errors;
+mod features;
+mod keysyms;
 mod lvl0;
 mod lvl1;
 mod lvl2;
@@ -16,29 +25,100 @@ use std::{
     error::Error,
     fs,
     io::{prelude::*, BufReader},
+    path::Path,
 };
 
-fn main() -> Result<(), Box<dyn Error>> {
-    env_logger::builder()
-        .filter_level(log::LevelFilter::Error)
-        .init();
+/// Options for the generator, set by the flags that follow the input and output files.
+#[derive(Debug, Default)]
+struct GeneratorOptions {
+    /// Derive `serde::Serialize` and `serde::Deserialize` on the generated items, behind the
+    /// `serde` feature.
+    serde: bool,
+    /// Implement `arbitrary::Arbitrary` on the generated items, behind the `fuzzing` feature.
+    arbitrary: bool,
+    /// Keep `Default` derived on requests. When this is off, only the requests named by
+    /// `--default-on=<Name>` keep it.
+    default_on_requests: bool,
+    /// Requests that keep `Default` even when `default_on_requests` is off.
+    default_on: Vec<String>,
+    /// Lay out the structures that match their bytes exactly as `#[repr(C)]`, and derive
+    /// `bytemuck::Pod` on them behind the `pod` feature.
+    pod_structs: bool,
+    /// Give replies a `semantically_eq` method, which compares them without their headers.
+    semantic_eq: bool,
+    /// Leave `Debug` off of the generated items, to keep the formatting code out of small binaries.
+    minimal_derives: bool,
+    /// The number of tokens a method's body may hold and still be marked `#[inline]`.
+    inline_threshold: Option<usize>,
+    /// The number of bytes a buffer may hold and still be printed in full by `Debug`.
+    debug_list_limit: Option<usize>,
+    /// Write a benchmark harness for the representative types of the namespace, rather than the
+    /// module itself.
+    bench: bool,
+    /// Read the input as a keysym definition header rather than an XML description, and write out
+    /// the keysym constants and names it defines.
+    keysyms: bool,
+    /// Read the input as a directory of XML descriptions, and rewrite the features of the extensions
+    /// in the Cargo manifest given as the output.
+    features: bool,
+    /// Read the input as a directory of XML descriptions, and write out the `XError` enum over the
+    /// errors of all of them.
+    errors: bool,
+}
 
-    // open the file
-    let fname = env::args_os()
-        .nth(1)
-        .unwrap_or_else(|| panic!("Generator requires at least 2 arguments"));
-    let outname = env::args_os()
-        .nth(2)
-        .unwrap_or_else(|| panic!("Generator requires at least 2 arguments"));
-    let file = fs::File::open(&fname)?;
+impl GeneratorOptions {
+    #[inline]
+    fn from_args() -> Self {
+        Self::from_flags(env::args().skip(3))
+    }
+
+    #[inline]
+    fn from_flags<I: IntoIterator<Item = String>>(flags: I) -> Self {
+        let mut options: Self = Default::default();
+        flags.into_iter().for_each(|arg| match arg.as_str() {
+            "--serde" => options.serde = true,
+            "--arbitrary" => options.arbitrary = true,
+            "--default-on-requests" => options.default_on_requests = true,
+            "--minimal-derives" => options.minimal_derives = true,
+            "--pod-structs" => options.pod_structs = true,
+            "--semantic-eq" => options.semantic_eq = true,
+            "--bench" => options.bench = true,
+            "--keysyms" => options.keysyms = true,
+            "--features" => options.features = true,
+            "--errors" => options.errors = true,
+            arg if arg.starts_with("--default-on=") => options
+                .default_on
+                .push(arg["--default-on=".len()..].to_string()),
+            arg if arg.starts_with("--debug-list-limit=") => {
+                options.debug_list_limit = Some(
+                    arg["--debug-list-limit=".len()..]
+                        .parse()
+                        .unwrap_or_else(|_| panic!("Invalid debug list limit: {}", arg)),
+                )
+            }
+            arg if arg.starts_with("--inline-threshold=") => {
+                options.inline_threshold = Some(
+                    arg["--inline-threshold=".len()..]
+                        .parse()
+                        .unwrap_or_else(|_| panic!("Invalid inline threshold: {}", arg)),
+                )
+            }
+            arg => panic!("Unrecognized generator flag: {}", arg),
+        });
+        options
+    }
+}
+
+/// Read an XML description file into a series of Level 1 items, along with the name of the
+/// extension it describes, if any.
+#[inline]
+fn read_lvl1(fname: &Path) -> Result<(Vec<lvl1::Item>, Option<String>), Box<dyn Error>> {
+    let file = fs::File::open(fname)?;
     let file = BufReader::new(file);
-    let mut outfile = fs::File::create(&outname)?;
 
     // open the XML reader
     let mut reader = Reader::from_reader(file);
 
-    // Stage 1: Read from Level 0 representation (XML) into Level 1 representation. Result is a
-    //          vector of Level 1 items.
     let mut buf = vec![];
     let mut lvl1_items = vec![];
     let mut lvl0_state: lvl0::Lvl0State = Default::default();
@@ -63,21 +143,189 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     assert!(matches!(lvl0_state, lvl0::Lvl0State::AwaitingTopLevel));
+    Ok((lvl1_items, ext_name))
+}
+
+/// A description file, carried through the first stages.
+struct Lvl3Namespace {
+    items: Vec<lvl3::Item>,
+    /// The name of the extension it describes, if it describes one.
+    ext_name: Option<String>,
+    /// The names of the namespaces it imports.
+    import_names: Vec<String>,
+}
+
+/// Read a description file and carry it through the first stages, up to the basic Rust
+/// representation that the passes of stage 3 work on.
+fn read_lvl3(fname: &Path) -> Result<Lvl3Namespace, Box<dyn Error>> {
+    // Stage 1: Read from Level 0 representation (XML) into Level 1 representation. Result is a
+    //          vector of Level 1 items.
+    let (lvl1_items, ext_name) = read_lvl1(fname)?;
+
+    // The namespaces that this one imports are read as well, so that the types they define can be
+    // referred to. They are expected to sit next to this one.
+    let import_names: Vec<String> = lvl1_items
+        .iter()
+        .filter_map(|item| match item {
+            lvl1::Item::Import(lvl1::Import(name)) => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+    let imports = import_names
+        .iter()
+        .filter_map(|name| {
+            let path = fname.with_file_name(format!("{}.xml", name));
+            match read_lvl1(&path) {
+                Ok((items, _)) => Some(lvl2::Namespace::from_lvl1(name, &items)),
+                Err(e) => {
+                    log::warn!("Unable to read imported namespace \"{}\": {}", name, e);
+                    None
+                }
+            }
+        })
+        .collect();
 
     // Stage 2: Normalize from Level 1 representation to Level 2 representation. This expands some of the copying,
     //          converts enums to what they're represented as in Rust, and preforms some other optimizations.
-    let (lvl2_items, xidtypes) = lvl2::convert_series(lvl1_items, ext_name.is_some());
+    let (lvl2_items, xidtypes) = lvl2::convert_series(lvl1_items, imports, ext_name.is_some());
+    lvl2::check_len_slots(&lvl2_items)?;
+    lvl2::check_field_refs(&lvl2_items)?;
 
     // Stage 3: Normalize to a basic Rust representation.
     let lvl3_items: Vec<lvl3::Item> = lvl2_items
         .into_iter()
         .flat_map(|lvl2| lvl3::Item::from_lvl2(lvl2, &xidtypes, ext_name.as_deref()))
         .collect();
+    lvl3::check_bounded_fields(&lvl3_items, ext_name.as_deref())?;
+
+    Ok(Lvl3Namespace {
+        items: lvl3_items,
+        ext_name,
+        import_names,
+    })
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::builder()
+        .filter_level(log::LevelFilter::Error)
+        .init();
+
+    // open the file
+    let fname = env::args_os()
+        .nth(1)
+        .unwrap_or_else(|| panic!("Generator requires at least 2 arguments"));
+    let outname = env::args_os()
+        .nth(2)
+        .unwrap_or_else(|| panic!("Generator requires at least 2 arguments"));
+    let options = GeneratorOptions::from_args();
+
+    // the manifest is edited rather than replaced, so it's opened by the feature generator itself
+    if options.features {
+        return features::generate_features(Path::new(&fname), Path::new(&outname));
+    }
+
+    let mut outfile = fs::File::create(&outname)?;
+
+    // keysyms aren't described by the protocol XML, so they skip all of the stages below
+    if options.keysyms {
+        return keysyms::generate_keysyms(Path::new(&fname), &mut outfile);
+    }
+
+    // the errors of every namespace are gathered into one enum, so they're read by the error
+    // generator
+    if options.errors {
+        return errors::generate_errors(Path::new(&fname), &mut outfile);
+    }
+
+    generate_module(Path::new(&fname), &options, &mut outfile)
+}
+
+/// Write out the module for the XML description `fname`, or its benchmark harness if the options
+/// ask for one.
+#[inline]
+fn generate_module<W: Write>(
+    fname: &Path,
+    options: &GeneratorOptions,
+    outfile: &mut W,
+) -> Result<(), Box<dyn Error>> {
+    // Stages 1 through 3 read the description into a basic Rust representation, which the passes
+    // that follow refine.
+    let Lvl3Namespace {
+        items: mut lvl3_items,
+        ext_name,
+        import_names,
+    } = read_lvl3(fname)?;
+    let namespace = fname
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_else(|| panic!("Invalid file name: {:?}", &fname));
+    let ctx = lvl3::EmitContext {
+        inline_threshold: options
+            .inline_threshold
+            .unwrap_or(lvl3::DEFAULT_INLINE_THRESHOLD),
+    };
+
+    lvl3::resolve_unions(&mut lvl3_items);
+    lvl3::fold_fixed_sizes(&mut lvl3_items);
+    lvl3::populate_stream_parsers(&mut lvl3_items, &ctx);
+    lvl3::populate_strict_parsers(&mut lvl3_items, &ctx);
+    lvl3::populate_reply_or_error_parsers(&mut lvl3_items, &ctx);
+    lvl3::populate_list_iters(&mut lvl3_items);
+    lvl3::populate_enum_accessors(&mut lvl3_items);
+    lvl3::populate_mask_pairs(&mut lvl3_items);
+    lvl3::populate_wire_fields(&mut lvl3_items);
+    lvl3::derive_comparisons(&mut lvl3_items);
+    lvl3::compare_by_identity(&mut lvl3_items);
+    lvl3::strip_large_clones(&mut lvl3_items);
+    lvl3::elide_large_debugs(
+        &mut lvl3_items,
+        options
+            .debug_list_limit
+            .unwrap_or(lvl3::DEFAULT_DEBUG_LIST_LIMIT),
+    );
+    lvl3::derive_copies(&mut lvl3_items);
+    lvl3::populate_const_constructors(&mut lvl3_items);
+    lvl3::populate_continuations(&mut lvl3_items, &ctx);
+    lvl3::populate_validators(&mut lvl3_items, &ctx);
+    if !options.default_on_requests {
+        lvl3::strip_request_defaults(&mut lvl3_items, &options.default_on);
+    }
+    if options.pod_structs {
+        lvl3::derive_pod(&mut lvl3_items);
+    }
+    if options.semantic_eq {
+        lvl3::populate_semantic_eqs(&mut lvl3_items);
+    }
+    lvl3::populate_diffs(&mut lvl3_items);
+    if options.minimal_derives {
+        lvl3::strip_debug_derives(&mut lvl3_items);
+    }
+    if options.serde {
+        lvl3::derive_serde(&mut lvl3_items);
+    }
+    if options.arbitrary {
+        lvl3::derive_arbitrary(&mut lvl3_items);
+    }
+    // this goes last, so that the enum derives what all of the events have been given
+    lvl3_items.extend(lvl3::event_enum(&lvl3_items));
+    lvl3_items.extend(lvl3::error_dispatcher(
+        &lvl3_items,
+        namespace,
+        ext_name.is_some(),
+    ));
+    lvl3_items.push(lvl3::opcode_names(&lvl3_items));
+
+    // the benchmarks are written in terms of the items, so they're written instead of the items
+    if options.bench {
+        return bench::generate_bench(&lvl3_items, namespace, outfile);
+    }
 
     // Stage 4: Convert to syn items
+    // byte sequence implementations are compared as they will be written
+    lvl3::share_byte_sequences(&mut lvl3_items, &ctx);
     let lvl4_items: Vec<syn::Item> = lvl3_items
         .into_iter()
-        .flat_map(|lvl3| lvl3.to_syn_item())
+        .flat_map(|lvl3| lvl3.to_syn_item(&ctx))
         .collect();
     let lvl4_file = syn::File {
         shebang: None,
@@ -90,17 +338,18 @@ fn main() -> Result<(), Box<dyn Error>> {
     let output_pre_regex = format!("{}", tokens);
     let re = Regex::new(r"\bBool\b").unwrap();
     let output = re.replace_all(&output_pre_regex, "bool");
+    let (gate, checks) = features::module_header(namespace, &import_names);
     write!(
         outfile,
         "// This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
-#![allow(warnings)]
+{}#![allow(warnings)]
 
 use super::prelude::*;
 
-{}",
-        output
+{}{}",
+        gate, checks, output
     )?;
 
     Ok(())
@@ -108,3 +357,44 @@ use super::prelude::*;
 
 #[inline]
 pub fn any_field_length(_fields: &[lvl2::StructureItem]) {}
+
+/// The module written for the core protocol, with the flags that the Makefile passes, is the one
+/// checked into the crate, so changes to the generator that shouldn't change its output can be
+/// checked against it. The module is formatted by `rustfmt` first, as the Makefile does.
+#[test]
+fn generate_checked_in_core_protocol() {
+    use std::process::{Command, Stdio};
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let flags = Command::new("make")
+        .arg("-s")
+        .arg("-C")
+        .arg(&root)
+        .args([
+            "--eval",
+            "print-flags: ; @echo $(GENR_FLAGS)",
+            "print-flags",
+        ])
+        .output()
+        .expect("Unable to read the flags from the Makefile");
+    let flags = String::from_utf8(flags.stdout).unwrap();
+    let options = GeneratorOptions::from_flags(flags.split_whitespace().map(String::from));
+
+    let mut generated = vec![];
+    generate_module(&root.join("xml/xproto.xml"), &options, &mut generated).unwrap();
+
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Unable to run rustfmt");
+    rustfmt.stdin.take().unwrap().write_all(&generated).unwrap();
+    let formatted = rustfmt.wait_with_output().unwrap().stdout;
+    let checked_in = fs::read(root.join("src/auto/xproto.rs")).unwrap();
+
+    assert!(
+        formatted == checked_in,
+        "The generated core protocol differs from src/auto/xproto.rs; run `make` to regenerate it"
+    );
+}
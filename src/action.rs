@@ -9,11 +9,13 @@ use super::auto::{
         SaNoAction, SaPtrBtn, SaRedirectKey, SaSetControls, SaSetGroup, SaSetMods, SaSetPtrDflt,
         SaSwitchScreen, SaTerminate, SaType,
     },
-    AsByteSequence,
+    AsByteSequence, ParseError,
 };
 
 /// An action generated by XKB.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Action {
     NoAction(SaNoAction),
     SetMods(SaSetMods),
@@ -81,7 +83,7 @@ impl AsByteSequence for Action {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let ty = SaType::from_bytes(bytes)?.0;
 
         let this = match ty {
@@ -108,6 +110,6 @@ impl AsByteSequence for Action {
             SaType::DeviceValuator => Self::DeviceValuator(SaDeviceValuator::from_bytes(bytes)?.0),
         };
 
-        Some((this, ACTION_SIZE))
+        Ok((this, ACTION_SIZE))
     }
 }
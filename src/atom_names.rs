@@ -0,0 +1,50 @@
+// MIT/Apache2 License
+
+//! The names of atoms that are commonly interned by clients and window managers. These atoms don't have
+//! IDs until they are interned; pass them to `Display::intern_all` to intern several at once. The
+//! predefined atoms with fixed IDs, like `PRIMARY` or `WM_NAME`, are the `ATOM_*` consts in
+//! `auto::xproto` and don't need a round trip.
+
+// ICCCM
+pub const WM_PROTOCOLS: &str = "WM_PROTOCOLS";
+pub const WM_DELETE_WINDOW: &str = "WM_DELETE_WINDOW";
+pub const WM_TAKE_FOCUS: &str = "WM_TAKE_FOCUS";
+pub const WM_STATE: &str = "WM_STATE";
+pub const WM_CHANGE_STATE: &str = "WM_CHANGE_STATE";
+pub const WM_CLIENT_LEADER: &str = "WM_CLIENT_LEADER";
+pub const WM_WINDOW_ROLE: &str = "WM_WINDOW_ROLE";
+pub const WM_COLORMAP_WINDOWS: &str = "WM_COLORMAP_WINDOWS";
+pub const CLIPBOARD: &str = "CLIPBOARD";
+pub const TARGETS: &str = "TARGETS";
+pub const MULTIPLE: &str = "MULTIPLE";
+pub const TIMESTAMP: &str = "TIMESTAMP";
+pub const INCR: &str = "INCR";
+pub const UTF8_STRING: &str = "UTF8_STRING";
+
+// EWMH
+pub const NET_SUPPORTED: &str = "_NET_SUPPORTED";
+pub const NET_SUPPORTING_WM_CHECK: &str = "_NET_SUPPORTING_WM_CHECK";
+pub const NET_CLIENT_LIST: &str = "_NET_CLIENT_LIST";
+pub const NET_CLIENT_LIST_STACKING: &str = "_NET_CLIENT_LIST_STACKING";
+pub const NET_NUMBER_OF_DESKTOPS: &str = "_NET_NUMBER_OF_DESKTOPS";
+pub const NET_CURRENT_DESKTOP: &str = "_NET_CURRENT_DESKTOP";
+pub const NET_DESKTOP_NAMES: &str = "_NET_DESKTOP_NAMES";
+pub const NET_ACTIVE_WINDOW: &str = "_NET_ACTIVE_WINDOW";
+pub const NET_WORKAREA: &str = "_NET_WORKAREA";
+pub const NET_CLOSE_WINDOW: &str = "_NET_CLOSE_WINDOW";
+pub const NET_WM_NAME: &str = "_NET_WM_NAME";
+pub const NET_WM_ICON_NAME: &str = "_NET_WM_ICON_NAME";
+pub const NET_WM_DESKTOP: &str = "_NET_WM_DESKTOP";
+pub const NET_WM_WINDOW_TYPE: &str = "_NET_WM_WINDOW_TYPE";
+pub const NET_WM_WINDOW_TYPE_NORMAL: &str = "_NET_WM_WINDOW_TYPE_NORMAL";
+pub const NET_WM_WINDOW_TYPE_DIALOG: &str = "_NET_WM_WINDOW_TYPE_DIALOG";
+pub const NET_WM_WINDOW_TYPE_DOCK: &str = "_NET_WM_WINDOW_TYPE_DOCK";
+pub const NET_WM_STATE: &str = "_NET_WM_STATE";
+pub const NET_WM_STATE_FULLSCREEN: &str = "_NET_WM_STATE_FULLSCREEN";
+pub const NET_WM_STATE_HIDDEN: &str = "_NET_WM_STATE_HIDDEN";
+pub const NET_WM_STATE_MAXIMIZED_VERT: &str = "_NET_WM_STATE_MAXIMIZED_VERT";
+pub const NET_WM_STATE_MAXIMIZED_HORZ: &str = "_NET_WM_STATE_MAXIMIZED_HORZ";
+pub const NET_WM_STRUT: &str = "_NET_WM_STRUT";
+pub const NET_WM_STRUT_PARTIAL: &str = "_NET_WM_STRUT_PARTIAL";
+pub const NET_WM_ICON: &str = "_NET_WM_ICON";
+pub const NET_WM_PID: &str = "_NET_WM_PID";
@@ -1,35 +1,63 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "bigreq")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
-#[derive(Clone, Debug, Default)]
+pub const MAJOR_VERSION: u32 = 0;
+pub const MINOR_VERSION: u32 = 0;
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct EnableRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl EnableRequest {}
+impl EnableRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> EnableRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<EnableReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<EnableRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for EnableRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             EnableRequest {
                 req_type: req_type,
                 length: length,
@@ -39,48 +67,135 @@ impl AsByteSequence for EnableRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for EnableRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for EnableRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("BIG-REQUESTS");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Enable";
     type Reply = EnableReply;
 }
+#[doc = " A builder for `EnableRequest`."]
 #[derive(Clone, Debug, Default)]
+pub struct EnableRequestBuilder {
+    pub inner: EnableRequest,
+}
+impl EnableRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> EnableRequest {
+        self.inner
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct EnableReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub maximum_request_length: Card32,
 }
-impl EnableReply {}
-impl AsByteSequence for EnableReply {
+impl EnableReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let maximum_request_length: Card32 =
+            read_item(reader, &mut index, 4, "maximum_request_length")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(EnableReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            maximum_request_length: maximum_request_length,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("maximum_request_length", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.maximum_request_length == other.maximum_request_length
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "maximum_request_length",
+            &self.maximum_request_length,
+            &other.maximum_request_length,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for EnableReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.maximum_request_length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (maximum_request_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (maximum_request_length, sz): (Card32, usize) =
+            parse_item(bytes, index, "maximum_request_length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             EnableReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -92,10 +207,42 @@ impl AsByteSequence for EnableReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.maximum_request_length.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for EnableReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[(0, "Enable")];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
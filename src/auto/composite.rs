@@ -1,45 +1,84 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "composite")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+#[cfg(not(feature = "fixes"))]
+compile_error!(
+    "The `composite` feature requires the `fixes` feature, since it uses the types that it defines"
+);
+pub const MAJOR_VERSION: u32 = 0;
+pub const MINOR_VERSION: u32 = 4;
 use super::xfixes::*;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major_version: Card32,
     pub client_minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major_version", 4, 4),
+        ("client_minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major_version.as_bytes(&mut bytes[index..]);
         index += self.client_minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_major_version")?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -51,20 +90,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major_version.size()
-            + self.client_minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn client_major_version(mut self, client_major_version: Card32) -> Self {
+        self.inner.client_major_version = client_major_version;
+        self
+    }
+    #[inline]
+    pub fn client_minor_version(mut self, client_minor_version: Card32) -> Self {
+        self.inner.client_minor_version = client_minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major_version: Default::default(),
+                client_minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -72,37 +154,108 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_bytes(reader, &mut index, 16)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
         index += 16;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -115,72 +268,141 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
-            + 16
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct RedirectWindowRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub update: Redirect,
 }
-impl RedirectWindowRequest {}
-impl AsByteSequence for RedirectWindowRequest {
+impl RedirectWindowRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.update.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing RedirectWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        Some((
-            RedirectWindowRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-                update: update,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.update.size() + 3
+    pub fn builder() -> RedirectWindowRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<RedirectWindowRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_window_update_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.window.as_bytes(&mut bytes[index..]);
+                index += self.update.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 3);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+                index += sz;
+                let (update, sz): (Redirect, usize) = parse_item(bytes, index, "update")?;
+                index += sz;
+                index += 3;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        window: window,
+                        update: update,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8 + self.window.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_window_update_request_asb! { RedirectWindowRequest }
 impl Request for RedirectWindowRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RedirectWindow";
     type Reply = ();
 }
+#[doc = " A builder for `RedirectWindowRequest`."]
+#[derive(Clone, Debug)]
+pub struct RedirectWindowRequestBuilder {
+    pub inner: RedirectWindowRequest,
+}
+impl RedirectWindowRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn update(mut self, update: Redirect) -> Self {
+        self.inner.update = update;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> RedirectWindowRequest {
+        self.inner
+    }
+}
+impl Default for RedirectWindowRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: RedirectWindowRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                update: Default::default(),
+            },
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Redirect {
     Automatic = 0,
     Manual = 1,
@@ -191,12 +413,12 @@ impl AsByteSequence for Redirect {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Automatic, sz)),
-            1 => Some((Self::Manual, sz)),
-            _ => None,
+            0 => Ok((Self::Automatic, sz)),
+            1 => Ok((Self::Manual, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -204,210 +426,269 @@ impl AsByteSequence for Redirect {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Redirect {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Redirect {
     #[inline]
     fn default() -> Redirect {
         Redirect::Automatic
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Redirect> for u8 {
+    #[inline]
+    fn from(base: Redirect) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for Redirect {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Automatic),
+            1 => Ok(Self::Manual),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct RedirectSubwindowsRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub update: Redirect,
 }
-impl RedirectSubwindowsRequest {}
-impl AsByteSequence for RedirectSubwindowsRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.update.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing RedirectSubwindowsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        Some((
-            RedirectSubwindowsRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-                update: update,
-            },
-            index,
-        ))
-    }
+impl RedirectSubwindowsRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.update.size() + 3
+    pub fn builder() -> RedirectSubwindowsRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<RedirectSubwindowsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_update_request_asb! { RedirectSubwindowsRequest }
 impl Request for RedirectSubwindowsRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RedirectSubwindows";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct UnredirectWindowRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub window: Window,
-    pub update: Redirect,
+#[doc = " A builder for `RedirectSubwindowsRequest`."]
+#[derive(Clone, Debug)]
+pub struct RedirectSubwindowsRequestBuilder {
+    pub inner: RedirectSubwindowsRequest,
 }
-impl UnredirectWindowRequest {}
-impl AsByteSequence for UnredirectWindowRequest {
+impl RedirectSubwindowsRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.update.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing UnredirectWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        Some((
-            UnredirectWindowRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-                update: update,
+    pub fn update(mut self, update: Redirect) -> Self {
+        self.inner.update = update;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> RedirectSubwindowsRequest {
+        self.inner
+    }
+}
+impl Default for RedirectSubwindowsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: RedirectSubwindowsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                update: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct UnredirectWindowRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub window: Window,
+    pub update: Redirect,
+}
+impl UnredirectWindowRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.update.size() + 3
+    pub fn builder() -> UnredirectWindowRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<UnredirectWindowRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_update_request_asb! { UnredirectWindowRequest }
 impl Request for UnredirectWindowRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnredirectWindow";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct UnredirectSubwindowsRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub window: Window,
-    pub update: Redirect,
+#[doc = " A builder for `UnredirectWindowRequest`."]
+#[derive(Clone, Debug)]
+pub struct UnredirectWindowRequestBuilder {
+    pub inner: UnredirectWindowRequest,
 }
-impl UnredirectSubwindowsRequest {}
-impl AsByteSequence for UnredirectSubwindowsRequest {
+impl UnredirectWindowRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.update.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing UnredirectSubwindowsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        Some((
-            UnredirectSubwindowsRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-                update: update,
+    pub fn update(mut self, update: Redirect) -> Self {
+        self.inner.update = update;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> UnredirectWindowRequest {
+        self.inner
+    }
+}
+impl Default for UnredirectWindowRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnredirectWindowRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                update: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct UnredirectSubwindowsRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub window: Window,
+    pub update: Redirect,
+}
+impl UnredirectSubwindowsRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.update.size() + 3
+    pub fn builder() -> UnredirectSubwindowsRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<UnredirectSubwindowsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_update_request_asb! { UnredirectSubwindowsRequest }
 impl Request for UnredirectSubwindowsRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnredirectSubwindows";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `UnredirectSubwindowsRequest`."]
+#[derive(Clone, Debug)]
+pub struct UnredirectSubwindowsRequestBuilder {
+    pub inner: UnredirectSubwindowsRequest,
+}
+impl UnredirectSubwindowsRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn update(mut self, update: Redirect) -> Self {
+        self.inner.update = update;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> UnredirectSubwindowsRequest {
+        self.inner
+    }
+}
+impl Default for UnredirectSubwindowsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnredirectSubwindowsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                update: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRegionFromBorderClipRequest {
     pub req_type: u8,
     pub length: u16,
     pub region: Region,
     pub window: Window,
 }
-impl CreateRegionFromBorderClipRequest {}
-impl AsByteSequence for CreateRegionFromBorderClipRequest {
+impl CreateRegionFromBorderClipRequest {
     #[inline]
+    pub fn builder() -> CreateRegionFromBorderClipRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateRegionFromBorderClipRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateRegionFromBorderClipRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromBorderClipRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateRegionFromBorderClipRequest {
                 req_type: req_type,
                 length: length,
@@ -419,48 +700,104 @@ impl AsByteSequence for CreateRegionFromBorderClipRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size() + self.window.size()
+        let size: usize = 4 + self.region.size() + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateRegionFromBorderClipRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateRegionFromBorderClipRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromBorderClip";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateRegionFromBorderClipRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRegionFromBorderClipRequestBuilder {
+    pub inner: CreateRegionFromBorderClipRequest,
+}
+impl CreateRegionFromBorderClipRequestBuilder {
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRegionFromBorderClipRequest {
+        self.inner
+    }
+}
+impl Default for CreateRegionFromBorderClipRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRegionFromBorderClipRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct NameWindowPixmapRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub pixmap: Pixmap,
 }
-impl NameWindowPixmapRequest {}
-impl AsByteSequence for NameWindowPixmapRequest {
+impl NameWindowPixmapRequest {
     #[inline]
+    pub fn builder() -> NameWindowPixmapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<NameWindowPixmapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for NameWindowPixmapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.pixmap.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing NameWindowPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = parse_item(bytes, index, "pixmap")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             NameWindowPixmapRequest {
                 req_type: req_type,
                 length: length,
@@ -472,98 +809,246 @@ impl AsByteSequence for NameWindowPixmapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.pixmap.size()
+        let size: usize = 4 + self.window.size() + self.pixmap.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for NameWindowPixmapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for NameWindowPixmapRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "NameWindowPixmap";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `NameWindowPixmapRequest`."]
+#[derive(Clone, Debug)]
+pub struct NameWindowPixmapRequestBuilder {
+    pub inner: NameWindowPixmapRequest,
+}
+impl NameWindowPixmapRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn pixmap(mut self, pixmap: Pixmap) -> Self {
+        self.inner.pixmap = pixmap;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> NameWindowPixmapRequest {
+        self.inner
+    }
+}
+impl Default for NameWindowPixmapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: NameWindowPixmapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                pixmap: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetOverlayWindowRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetOverlayWindowRequest {}
-impl AsByteSequence for GetOverlayWindowRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetOverlayWindowRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetOverlayWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetOverlayWindowRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    pub fn builder() -> GetOverlayWindowRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetOverlayWindowReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetOverlayWindowRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_window_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.window.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        window: window,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.window.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_window_only_request_asb! { GetOverlayWindowRequest }
 impl Request for GetOverlayWindowRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOverlayWindow";
     type Reply = GetOverlayWindowReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetOverlayWindowRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetOverlayWindowRequestBuilder {
+    pub inner: GetOverlayWindowRequest,
+}
+impl GetOverlayWindowRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetOverlayWindowRequest {
+        self.inner
+    }
+}
+impl Default for GetOverlayWindowRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetOverlayWindowRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetOverlayWindowReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub overlay_win: Window,
 }
-impl GetOverlayWindowReply {}
-impl AsByteSequence for GetOverlayWindowReply {
+impl GetOverlayWindowReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.overlay_win == other.overlay_win
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "overlay_win",
+            &self.overlay_win,
+            &other.overlay_win,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetOverlayWindowReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.overlay_win.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOverlayWindowReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (overlay_win, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (overlay_win, sz): (Window, usize) = parse_item(bytes, index, "overlay_win")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetOverlayWindowReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -575,59 +1060,108 @@ impl AsByteSequence for GetOverlayWindowReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.overlay_win.size()
-            + 20
+        let size: usize = 28 + self.overlay_win.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetOverlayWindowReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ReleaseOverlayWindowRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl ReleaseOverlayWindowRequest {}
-impl AsByteSequence for ReleaseOverlayWindowRequest {
+impl ReleaseOverlayWindowRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ReleaseOverlayWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            ReleaseOverlayWindowRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn builder() -> ReleaseOverlayWindowRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<ReleaseOverlayWindowRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { ReleaseOverlayWindowRequest }
 impl Request for ReleaseOverlayWindowRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ReleaseOverlayWindow";
     type Reply = ();
 }
+#[doc = " A builder for `ReleaseOverlayWindowRequest`."]
+#[derive(Clone, Debug)]
+pub struct ReleaseOverlayWindowRequestBuilder {
+    pub inner: ReleaseOverlayWindowRequest,
+}
+impl ReleaseOverlayWindowRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ReleaseOverlayWindowRequest {
+        self.inner
+    }
+}
+impl Default for ReleaseOverlayWindowRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ReleaseOverlayWindowRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "RedirectWindow"),
+    (2, "RedirectSubwindows"),
+    (3, "UnredirectWindow"),
+    (4, "UnredirectSubwindows"),
+    (5, "CreateRegionFromBorderClip"),
+    (6, "NameWindowPixmap"),
+    (7, "GetOverlayWindow"),
+    (8, "ReleaseOverlayWindow"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,14 +1,23 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "damage")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+#[cfg(not(feature = "fixes"))]
+compile_error!(
+    "The `damage` feature requires the `fixes` feature, since it uses the types that it defines"
+);
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
 use super::xfixes::*;
 use super::xproto::*;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Damage {
     pub xid: XID,
 }
@@ -17,6 +26,23 @@ impl Damage {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Damage {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Damage {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Damage {
     #[inline]
@@ -28,39 +54,91 @@ impl XidType for Damage {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Damage {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Damage({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Damage {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Damage> for XID {
+    #[inline]
+    fn from(base: Damage) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major_version: Card32,
     pub client_minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major_version", 4, 4),
+        ("client_minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major_version.as_bytes(&mut bytes[index..]);
         index += self.client_minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_major_version")?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -72,20 +150,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major_version.size()
-            + self.client_minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn client_major_version(mut self, client_major_version: Card32) -> Self {
+        self.inner.client_major_version = client_major_version;
+        self
+    }
+    #[inline]
+    pub fn client_minor_version(mut self, client_minor_version: Card32) -> Self {
+        self.inner.client_minor_version = client_minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major_version: Default::default(),
+                client_minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -93,37 +214,108 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_bytes(reader, &mut index, 16)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
         index += 16;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -136,16 +328,21 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
-            + 16
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRequest {
     pub req_type: u8,
     pub length: u16,
@@ -153,37 +350,46 @@ pub struct CreateRequest {
     pub drawable: Drawable,
     pub level: ReportLevel,
 }
-impl CreateRequest {}
-impl AsByteSequence for CreateRequest {
+impl CreateRequest {
     #[inline]
+    pub fn builder() -> CreateRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("damage", 4, 4)];
+}
+const _: () = assert!(<CreateRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.damage.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.level.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = parse_item(bytes, index, "damage")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (level, sz): (ReportLevel, usize) = <ReportLevel>::from_bytes(&bytes[index..])?;
+        let (level, sz): (ReportLevel, usize) = parse_item(bytes, index, "level")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateRequest {
                 req_type: req_type,
                 length: length,
@@ -196,23 +402,70 @@ impl AsByteSequence for CreateRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.damage.size()
-            + self.drawable.size()
-            + self.level.size()
-            + 3
+        let size: usize = 12 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Create";
     type Reply = ();
 }
+#[doc = " A builder for `CreateRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRequestBuilder {
+    pub inner: CreateRequest,
+}
+impl CreateRequestBuilder {
+    #[inline]
+    pub fn damage(mut self, damage: Damage) -> Self {
+        self.inner.damage = damage;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn level(mut self, level: ReportLevel) -> Self {
+        self.inner.level = level;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRequest {
+        self.inner
+    }
+}
+impl Default for CreateRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                damage: Default::default(),
+                drawable: Default::default(),
+                level: Default::default(),
+            },
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum ReportLevel {
     RawRectangles = 0,
     DeltaRectangles = 1,
@@ -225,14 +478,14 @@ impl AsByteSequence for ReportLevel {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::RawRectangles, sz)),
-            1 => Some((Self::DeltaRectangles, sz)),
-            2 => Some((Self::BoundingBox, sz)),
-            3 => Some((Self::NonEmpty, sz)),
-            _ => None,
+            0 => Ok((Self::RawRectangles, sz)),
+            1 => Ok((Self::DeltaRectangles, sz)),
+            2 => Ok((Self::BoundingBox, sz)),
+            3 => Ok((Self::NonEmpty, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -240,41 +493,79 @@ impl AsByteSequence for ReportLevel {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for ReportLevel {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for ReportLevel {
     #[inline]
     fn default() -> ReportLevel {
         ReportLevel::RawRectangles
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<ReportLevel> for u8 {
+    #[inline]
+    fn from(base: ReportLevel) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for ReportLevel {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::RawRectangles),
+            1 => Ok(Self::DeltaRectangles),
+            2 => Ok(Self::BoundingBox),
+            3 => Ok(Self::NonEmpty),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DestroyRequest {
     pub req_type: u8,
     pub length: u16,
     pub damage: Damage,
 }
-impl DestroyRequest {}
-impl AsByteSequence for DestroyRequest {
+impl DestroyRequest {
     #[inline]
+    pub fn builder() -> DestroyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("damage", 4, 4)];
+}
+const _: () = assert!(<DestroyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DestroyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.damage.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = parse_item(bytes, index, "damage")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DestroyRequest {
                 req_type: req_type,
                 length: length,
@@ -285,16 +576,58 @@ impl AsByteSequence for DestroyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.damage.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DestroyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DestroyRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Destroy";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyRequestBuilder {
+    pub inner: DestroyRequest,
+}
+impl DestroyRequestBuilder {
+    #[inline]
+    pub fn damage(mut self, damage: Damage) -> Self {
+        self.inner.damage = damage;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyRequest {
+        self.inner
+    }
+}
+impl Default for DestroyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                damage: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SubtractRequest {
     pub req_type: u8,
     pub length: u16,
@@ -302,35 +635,44 @@ pub struct SubtractRequest {
     pub repair: Region,
     pub parts: Region,
 }
-impl SubtractRequest {}
-impl AsByteSequence for SubtractRequest {
+impl SubtractRequest {
     #[inline]
+    pub fn builder() -> SubtractRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("damage", 4, 4)];
+}
+const _: () = assert!(<SubtractRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SubtractRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.damage.as_bytes(&mut bytes[index..]);
         index += self.repair.as_bytes(&mut bytes[index..]);
         index += self.parts.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SubtractRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = parse_item(bytes, index, "damage")?;
         index += sz;
-        let (repair, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (repair, sz): (Region, usize) = parse_item(bytes, index, "repair")?;
         index += sz;
-        let (parts, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (parts, sz): (Region, usize) = parse_item(bytes, index, "parts")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SubtractRequest {
                 req_type: req_type,
                 length: length,
@@ -343,53 +685,110 @@ impl AsByteSequence for SubtractRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.damage.size()
-            + self.repair.size()
-            + self.parts.size()
+        let size: usize = 8 + self.repair.size() + self.parts.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SubtractRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SubtractRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Subtract";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SubtractRequest`."]
+#[derive(Clone, Debug)]
+pub struct SubtractRequestBuilder {
+    pub inner: SubtractRequest,
+}
+impl SubtractRequestBuilder {
+    #[inline]
+    pub fn damage(mut self, damage: Damage) -> Self {
+        self.inner.damage = damage;
+        self
+    }
+    #[inline]
+    pub fn repair(mut self, repair: Region) -> Self {
+        self.inner.repair = repair;
+        self
+    }
+    #[inline]
+    pub fn parts(mut self, parts: Region) -> Self {
+        self.inner.parts = parts;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SubtractRequest {
+        self.inner
+    }
+}
+impl Default for SubtractRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SubtractRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                damage: Default::default(),
+                repair: Default::default(),
+                parts: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AddRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub region: Region,
 }
-impl AddRequest {}
-impl AsByteSequence for AddRequest {
+impl AddRequest {
     #[inline]
+    pub fn builder() -> AddRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<AddRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AddRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AddRequest {
                 req_type: req_type,
                 length: length,
@@ -401,16 +800,64 @@ impl AsByteSequence for AddRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.region.size()
+        let size: usize = 4 + self.drawable.size() + self.region.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AddRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AddRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Add";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AddRequest`."]
+#[derive(Clone, Debug)]
+pub struct AddRequestBuilder {
+    pub inner: AddRequest,
+}
+impl AddRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AddRequest {
+        self.inner
+    }
+}
+impl Default for AddRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AddRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                region: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct NotifyEvent {
     pub event_type: u8,
     pub level: ReportLevel,
@@ -421,9 +868,22 @@ pub struct NotifyEvent {
     pub area: Rectangle,
     pub geometry: Rectangle,
 }
-impl NotifyEvent {}
-impl AsByteSequence for NotifyEvent {
+impl NotifyEvent {
     #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("level", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for NotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -436,27 +896,26 @@ impl AsByteSequence for NotifyEvent {
         index += self.geometry.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (level, sz): (ReportLevel, usize) = <ReportLevel>::from_bytes(&bytes[index..])?;
+        let (level, sz): (ReportLevel, usize) = parse_item(bytes, index, "level")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = parse_item(bytes, index, "damage")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (area, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let (area, sz): (Rectangle, usize) = parse_item(bytes, index, "area")?;
         index += sz;
-        let (geometry, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let (geometry, sz): (Rectangle, usize) = parse_item(bytes, index, "geometry")?;
         index += sz;
-        Some((
+        Ok((
             NotifyEvent {
                 event_type: event_type,
                 level: level,
@@ -472,16 +931,81 @@ impl AsByteSequence for NotifyEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.level.size()
-            + self.sequence.size()
-            + self.drawable.size()
-            + self.damage.size()
-            + self.timestamp.size()
-            + self.area.size()
-            + self.geometry.size()
+        8 + self.drawable.size() + self.timestamp.size() + self.area.size() + self.geometry.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for NotifyEvent {
     const OPCODE: u8 = 0;
 }
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    Notify(NotifyEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            0 => Ok(Self::Notify(NotifyEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::Notify(_) => NotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<NotifyEvent> for Event {
+    #[inline]
+    fn from(event: NotifyEvent) -> Self {
+        Self::Notify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "Notify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "Create"),
+    (2, "Destroy"),
+    (3, "Subtract"),
+    (4, "Add"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,43 +1,78 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "dpms")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
-#[derive(Clone, Debug, Default)]
+pub const MAJOR_VERSION: u32 = 0;
+pub const MINOR_VERSION: u32 = 0;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major_version: Card16,
     pub client_minor_version: Card16,
 }
-impl GetVersionRequest {}
-impl AsByteSequence for GetVersionRequest {
+impl GetVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> GetVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major_version", 4, 2),
+        ("client_minor_version", 6, 2),
+    ];
+}
+const _: () = assert!(<GetVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major_version.as_bytes(&mut bytes[index..]);
         index += self.client_minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "client_major_version")?;
         index += sz;
-        let (client_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "client_minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -49,20 +84,63 @@ impl AsByteSequence for GetVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major_version.size()
-            + self.client_minor_version.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVersion";
     type Reply = GetVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetVersionRequestBuilder {
+    pub inner: GetVersionRequest,
+}
+impl GetVersionRequestBuilder {
+    #[inline]
+    pub fn client_major_version(mut self, client_major_version: Card16) -> Self {
+        self.inner.client_major_version = client_major_version;
+        self
+    }
+    #[inline]
+    pub fn client_minor_version(mut self, client_minor_version: Card16) -> Self {
+        self.inner.client_minor_version = client_minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetVersionRequest {
+        self.inner
+    }
+}
+impl Default for GetVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major_version: Default::default(),
+                client_minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -70,35 +148,110 @@ pub struct GetVersionReply {
     pub server_major_version: Card16,
     pub server_minor_version: Card16,
 }
-impl GetVersionReply {}
-impl AsByteSequence for GetVersionReply {
+impl GetVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let server_major_version: Card16 =
+            read_item(reader, &mut index, 2, "server_major_version")?;
+        let server_minor_version: Card16 =
+            read_item(reader, &mut index, 2, "server_minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            server_major_version: server_major_version,
+            server_minor_version: server_minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("server_major_version", 8, 2),
+        ("server_minor_version", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.server_major_version == other.server_major_version
+            && self.server_minor_version == other.server_minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "server_major_version",
+            &self.server_major_version,
+            &other.server_major_version,
+            &mut changes,
+        );
+        diff_value(
+            "server_minor_version",
+            &self.server_minor_version,
+            &other.server_minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.server_major_version.as_bytes(&mut bytes[index..]);
         index += self.server_minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (server_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (server_major_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "server_major_version")?;
         index += sz;
-        let (server_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "server_minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -111,92 +264,197 @@ impl AsByteSequence for GetVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.server_major_version.size()
-            + self.server_minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CapableRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl CapableRequest {}
-impl AsByteSequence for CapableRequest {
+impl CapableRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CapableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            CapableRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> CapableRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<CapableReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CapableRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_empty_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_empty_request_asb! { CapableRequest }
 impl Request for CapableRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Capable";
     type Reply = CapableReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CapableRequest`."]
+#[derive(Clone, Debug)]
+pub struct CapableRequestBuilder {
+    pub inner: CapableRequest,
+}
+impl CapableRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CapableRequest {
+        self.inner
+    }
+}
+impl Default for CapableRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CapableRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CapableReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub capable: bool,
 }
-impl CapableReply {}
-impl AsByteSequence for CapableReply {
+impl CapableReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("capable", 8, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.capable == other.capable
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("capable", &self.capable, &other.capable, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for CapableReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.capable.as_bytes(&mut bytes[index..]);
-        index += 23;
+        index += zero_pad(&mut bytes[index..], 23);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CapableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (capable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (capable, sz): (bool, usize) = parse_item(bytes, index, "capable")?;
         index += sz;
         index += 23;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CapableReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -208,58 +466,83 @@ impl AsByteSequence for CapableReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.capable.size()
-            + 23
+        let size: usize = 31 + self.capable.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for CapableReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetTimeoutsRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetTimeoutsRequest {}
-impl AsByteSequence for GetTimeoutsRequest {
+impl GetTimeoutsRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetTimeoutsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetTimeoutsRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetTimeoutsRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetTimeoutsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetTimeoutsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { GetTimeoutsRequest }
 impl Request for GetTimeoutsRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTimeouts";
     type Reply = GetTimeoutsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetTimeoutsRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetTimeoutsRequestBuilder {
+    pub inner: GetTimeoutsRequest,
+}
+impl GetTimeoutsRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetTimeoutsRequest {
+        self.inner
+    }
+}
+impl Default for GetTimeoutsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetTimeoutsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetTimeoutsReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -268,40 +551,122 @@ pub struct GetTimeoutsReply {
     pub suspend_timeout: Card16,
     pub off_timeout: Card16,
 }
-impl GetTimeoutsReply {}
-impl AsByteSequence for GetTimeoutsReply {
+impl GetTimeoutsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let standby_timeout: Card16 = read_item(reader, &mut index, 2, "standby_timeout")?;
+        let suspend_timeout: Card16 = read_item(reader, &mut index, 2, "suspend_timeout")?;
+        let off_timeout: Card16 = read_item(reader, &mut index, 2, "off_timeout")?;
+        skip_bytes(reader, &mut index, 18)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetTimeoutsReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            standby_timeout: standby_timeout,
+            suspend_timeout: suspend_timeout,
+            off_timeout: off_timeout,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("standby_timeout", 8, 2),
+        ("suspend_timeout", 10, 2),
+        ("off_timeout", 12, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.standby_timeout == other.standby_timeout
+            && self.suspend_timeout == other.suspend_timeout
+            && self.off_timeout == other.off_timeout
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "standby_timeout",
+            &self.standby_timeout,
+            &other.standby_timeout,
+            &mut changes,
+        );
+        diff_value(
+            "suspend_timeout",
+            &self.suspend_timeout,
+            &other.suspend_timeout,
+            &mut changes,
+        );
+        diff_value(
+            "off_timeout",
+            &self.off_timeout,
+            &other.off_timeout,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetTimeoutsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.standby_timeout.as_bytes(&mut bytes[index..]);
         index += self.suspend_timeout.as_bytes(&mut bytes[index..]);
         index += self.off_timeout.as_bytes(&mut bytes[index..]);
-        index += 18;
+        index += zero_pad(&mut bytes[index..], 18);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTimeoutsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (standby_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (standby_timeout, sz): (Card16, usize) = parse_item(bytes, index, "standby_timeout")?;
         index += sz;
-        let (suspend_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (suspend_timeout, sz): (Card16, usize) = parse_item(bytes, index, "suspend_timeout")?;
         index += sz;
-        let (off_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (off_timeout, sz): (Card16, usize) = parse_item(bytes, index, "off_timeout")?;
         index += sz;
         index += 18;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetTimeoutsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -315,17 +680,21 @@ impl AsByteSequence for GetTimeoutsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.standby_timeout.size()
-            + self.suspend_timeout.size()
-            + self.off_timeout.size()
-            + 18
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetTimeoutsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetTimeoutsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -333,35 +702,55 @@ pub struct SetTimeoutsRequest {
     pub suspend_timeout: Card16,
     pub off_timeout: Card16,
 }
-impl SetTimeoutsRequest {}
-impl AsByteSequence for SetTimeoutsRequest {
+impl SetTimeoutsRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> SetTimeoutsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("standby_timeout", 4, 2),
+        ("suspend_timeout", 6, 2),
+        ("off_timeout", 8, 2),
+    ];
+}
+const _: () = assert!(<SetTimeoutsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetTimeoutsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.standby_timeout.as_bytes(&mut bytes[index..]);
         index += self.suspend_timeout.as_bytes(&mut bytes[index..]);
         index += self.off_timeout.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetTimeoutsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (standby_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (standby_timeout, sz): (Card16, usize) = parse_item(bytes, index, "standby_timeout")?;
         index += sz;
-        let (suspend_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (suspend_timeout, sz): (Card16, usize) = parse_item(bytes, index, "suspend_timeout")?;
         index += sz;
-        let (off_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (off_timeout, sz): (Card16, usize) = parse_item(bytes, index, "off_timeout")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetTimeoutsRequest {
                 req_type: req_type,
                 length: length,
@@ -374,135 +763,206 @@ impl AsByteSequence for SetTimeoutsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.standby_timeout.size()
-            + self.suspend_timeout.size()
-            + self.off_timeout.size()
+        let size: usize = 10;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetTimeoutsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetTimeoutsRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetTimeouts";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct EnableRequest {
-    pub req_type: u8,
-    pub length: u16,
+#[doc = " A builder for `SetTimeoutsRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetTimeoutsRequestBuilder {
+    pub inner: SetTimeoutsRequest,
 }
-impl EnableRequest {}
-impl AsByteSequence for EnableRequest {
+impl SetTimeoutsRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub fn standby_timeout(mut self, standby_timeout: Card16) -> Self {
+        self.inner.standby_timeout = standby_timeout;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing EnableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            EnableRequest {
-                req_type: req_type,
-                length: length,
+    pub fn suspend_timeout(mut self, suspend_timeout: Card16) -> Self {
+        self.inner.suspend_timeout = suspend_timeout;
+        self
+    }
+    #[inline]
+    pub fn off_timeout(mut self, off_timeout: Card16) -> Self {
+        self.inner.off_timeout = off_timeout;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetTimeoutsRequest {
+        self.inner
+    }
+}
+impl Default for SetTimeoutsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetTimeoutsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                standby_timeout: Default::default(),
+                suspend_timeout: Default::default(),
+                off_timeout: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct EnableRequest {
+    pub req_type: u8,
+    pub length: u16,
+}
+impl EnableRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> EnableRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<EnableRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { EnableRequest }
 impl Request for EnableRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Enable";
     type Reply = ();
 }
+#[doc = " A builder for `EnableRequest`."]
 #[derive(Clone, Debug, Default)]
+pub struct EnableRequestBuilder {
+    pub inner: EnableRequest,
+}
+impl EnableRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> EnableRequest {
+        self.inner
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DisableRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl DisableRequest {}
-impl AsByteSequence for DisableRequest {
+impl DisableRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DisableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DisableRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn builder() -> DisableRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<DisableRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { DisableRequest }
 impl Request for DisableRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Disable";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DisableRequest`."]
+#[derive(Clone, Debug)]
+pub struct DisableRequestBuilder {
+    pub inner: DisableRequest,
+}
+impl DisableRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DisableRequest {
+        self.inner
+    }
+}
+impl Default for DisableRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DisableRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ForceLevelRequest {
     pub req_type: u8,
     pub length: u16,
     pub power_level: DpmsMode,
 }
-impl ForceLevelRequest {}
-impl AsByteSequence for ForceLevelRequest {
+impl ForceLevelRequest {
     #[inline]
+    pub fn builder() -> ForceLevelRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("power_level", 4, 2)];
+}
+const _: () = assert!(<ForceLevelRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ForceLevelRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.power_level.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ForceLevelRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (power_level, sz): (DpmsMode, usize) = <DpmsMode>::from_bytes(&bytes[index..])?;
+        let (power_level, sz): (DpmsMode, usize) = parse_item(bytes, index, "power_level")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ForceLevelRequest {
                 req_type: req_type,
                 length: length,
@@ -513,17 +973,58 @@ impl AsByteSequence for ForceLevelRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.power_level.size()
+        let size: usize = 6;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ForceLevelRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ForceLevelRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ForceLevel";
     type Reply = ();
 }
+#[doc = " A builder for `ForceLevelRequest`."]
+#[derive(Clone, Debug)]
+pub struct ForceLevelRequestBuilder {
+    pub inner: ForceLevelRequest,
+}
+impl ForceLevelRequestBuilder {
+    #[inline]
+    pub fn power_level(mut self, power_level: DpmsMode) -> Self {
+        self.inner.power_level = power_level;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ForceLevelRequest {
+        self.inner
+    }
+}
+impl Default for ForceLevelRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ForceLevelRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                power_level: Default::default(),
+            },
+        }
+    }
+}
 #[repr(u16)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum DpmsMode {
     On = 0,
     Standby = 1,
@@ -536,14 +1037,14 @@ impl AsByteSequence for DpmsMode {
         (*self as u16).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::On, sz)),
-            1 => Some((Self::Standby, sz)),
-            2 => Some((Self::Suspend, sz)),
-            3 => Some((Self::Off, sz)),
-            _ => None,
+            0 => Ok((Self::On, sz)),
+            1 => Ok((Self::Standby, sz)),
+            2 => Ok((Self::Suspend, sz)),
+            3 => Ok((Self::Off, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -551,56 +1052,102 @@ impl AsByteSequence for DpmsMode {
         ::core::mem::size_of::<u16>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for DpmsMode {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for DpmsMode {
     #[inline]
     fn default() -> DpmsMode {
         DpmsMode::On
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<DpmsMode> for u16 {
+    #[inline]
+    fn from(base: DpmsMode) -> Self {
+        base as u16
+    }
+}
+impl core::convert::TryFrom<u16> for DpmsMode {
+    type Error = u16;
+    #[inline]
+    fn try_from(value: u16) -> Result<Self, u16> {
+        match value {
+            0 => Ok(Self::On),
+            1 => Ok(Self::Standby),
+            2 => Ok(Self::Suspend),
+            3 => Ok(Self::Off),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct InfoRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl InfoRequest {}
-impl AsByteSequence for InfoRequest {
+impl InfoRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing InfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            InfoRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> InfoRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<InfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<InfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { InfoRequest }
 impl Request for InfoRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Info";
     type Reply = InfoReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `InfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder {
+    pub inner: InfoRequest,
+}
+impl InfoRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> InfoRequest {
+        self.inner
+    }
+}
+impl Default for InfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: InfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct InfoReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -608,37 +1155,77 @@ pub struct InfoReply {
     pub power_level: DpmsMode,
     pub state: bool,
 }
-impl InfoReply {}
-impl AsByteSequence for InfoReply {
+impl InfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("power_level", 8, 2),
+        ("state", 10, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.power_level == other.power_level && self.state == other.state
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "power_level",
+            &self.power_level,
+            &other.power_level,
+            &mut changes,
+        );
+        diff_value("state", &self.state, &other.state, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for InfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.power_level.as_bytes(&mut bytes[index..]);
         index += self.state.as_bytes(&mut bytes[index..]);
-        index += 21;
+        index += zero_pad(&mut bytes[index..], 21);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing InfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (power_level, sz): (DpmsMode, usize) = <DpmsMode>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (power_level, sz): (DpmsMode, usize) = parse_item(bytes, index, "power_level")?;
         index += sz;
-        let (state, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (state, sz): (bool, usize) = parse_item(bytes, index, "state")?;
         index += sz;
         index += 21;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             InfoReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -651,12 +1238,51 @@ impl AsByteSequence for InfoReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.power_level.size()
-            + self.state.size()
-            + 21
+        let size: usize = 31 + self.state.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for InfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "GetVersion"),
+    (1, "Capable"),
+    (2, "GetTimeouts"),
+    (3, "SetTimeouts"),
+    (4, "Enable"),
+    (5, "Disable"),
+    (6, "ForceLevel"),
+    (7, "Info"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,12 +1,17 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "dri2")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 4;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Dri2Buffer {
     pub attachment: Attachment,
     pub name: Card32,
@@ -14,7 +19,32 @@ pub struct Dri2Buffer {
     pub cpp: Card32,
     pub flags: Card32,
 }
-impl Dri2Buffer {}
+impl Dri2Buffer {
+    #[inline]
+    pub const fn new(
+        attachment: Attachment,
+        name: Card32,
+        pitch: Card32,
+        cpp: Card32,
+        flags: Card32,
+    ) -> Self {
+        Self {
+            attachment: attachment,
+            name: name,
+            pitch: pitch,
+            cpp: cpp,
+            flags: flags,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("attachment", 0, 4),
+        ("name", 4, 4),
+        ("pitch", 8, 4),
+        ("cpp", 12, 4),
+        ("flags", 16, 4),
+    ];
+}
 impl AsByteSequence for Dri2Buffer {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -26,21 +56,20 @@ impl AsByteSequence for Dri2Buffer {
         index += self.flags.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Dri2Buffer from byte buffer");
-        let (attachment, sz): (Attachment, usize) = <Attachment>::from_bytes(&bytes[index..])?;
+        let (attachment, sz): (Attachment, usize) = parse_item(bytes, index, "attachment")?;
         index += sz;
-        let (name, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Card32, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (pitch, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Card32, usize) = parse_item(bytes, index, "pitch")?;
         index += sz;
-        let (cpp, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (cpp, sz): (Card32, usize) = parse_item(bytes, index, "cpp")?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        Some((
+        Ok((
             Dri2Buffer {
                 attachment: attachment,
                 name: name,
@@ -53,15 +82,20 @@ impl AsByteSequence for Dri2Buffer {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.attachment.size()
-            + self.name.size()
-            + self.pitch.size()
-            + self.cpp.size()
-            + self.flags.size()
+        20
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Dri2Buffer {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Attachment {
     BufferFrontLeft = 0,
     BufferBackLeft = 1,
@@ -80,22 +114,21 @@ impl AsByteSequence for Attachment {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u32).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::BufferFrontLeft, sz)),
-            1 => Some((Self::BufferBackLeft, sz)),
-            2 => Some((Self::BufferFrontRight, sz)),
-            3 => Some((Self::BufferBackRight, sz)),
-            4 => Some((Self::BufferDepth, sz)),
-            5 => Some((Self::BufferStencil, sz)),
-            6 => Some((Self::BufferAccum, sz)),
-            7 => Some((Self::BufferFakeFrontLeft, sz)),
-            8 => Some((Self::BufferFakeFrontRight, sz)),
-            9 => Some((Self::BufferDepthStencil, sz)),
-            10 => Some((Self::BufferHiz, sz)),
-            _ => None,
+            0 => Ok((Self::BufferFrontLeft, sz)),
+            1 => Ok((Self::BufferBackLeft, sz)),
+            2 => Ok((Self::BufferFrontRight, sz)),
+            3 => Ok((Self::BufferBackRight, sz)),
+            4 => Ok((Self::BufferDepth, sz)),
+            5 => Ok((Self::BufferStencil, sz)),
+            6 => Ok((Self::BufferAccum, sz)),
+            7 => Ok((Self::BufferFakeFrontLeft, sz)),
+            8 => Ok((Self::BufferFakeFrontRight, sz)),
+            9 => Ok((Self::BufferDepthStencil, sz)),
+            10 => Ok((Self::BufferHiz, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -103,18 +136,62 @@ impl AsByteSequence for Attachment {
         ::core::mem::size_of::<u32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Attachment {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Attachment {
     #[inline]
     fn default() -> Attachment {
         Attachment::BufferFrontLeft
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Attachment> for u32 {
+    #[inline]
+    fn from(base: Attachment) -> Self {
+        base as u32
+    }
+}
+impl core::convert::TryFrom<u32> for Attachment {
+    type Error = u32;
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::BufferFrontLeft),
+            1 => Ok(Self::BufferBackLeft),
+            2 => Ok(Self::BufferFrontRight),
+            3 => Ok(Self::BufferBackRight),
+            4 => Ok(Self::BufferDepth),
+            5 => Ok(Self::BufferStencil),
+            6 => Ok(Self::BufferAccum),
+            7 => Ok(Self::BufferFakeFrontLeft),
+            8 => Ok(Self::BufferFakeFrontRight),
+            9 => Ok(Self::BufferDepthStencil),
+            10 => Ok(Self::BufferHiz),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AttachFormat {
     pub attachment: Attachment,
     pub format: Card32,
 }
-impl AttachFormat {}
+impl AttachFormat {
+    #[inline]
+    pub const fn new(attachment: Attachment, format: Card32) -> Self {
+        Self {
+            attachment: attachment,
+            format: format,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("attachment", 0, 4), ("format", 4, 4)];
+}
 impl AsByteSequence for AttachFormat {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -124,14 +201,14 @@ impl AsByteSequence for AttachFormat {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttachFormat from byte buffer");
-        let (attachment, sz): (Attachment, usize) = <Attachment>::from_bytes(&bytes[index..])?;
+        let (attachment, sz): (Attachment, usize) = parse_item(bytes, index, "attachment")?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        Some((
+        Ok((
             AttachFormat {
                 attachment: attachment,
                 format: format,
@@ -141,42 +218,79 @@ impl AsByteSequence for AttachFormat {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.attachment.size() + self.format.size()
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AttachFormat {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("major_version", 4, 4),
+        ("minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -188,20 +302,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn major_version(mut self, major_version: Card32) -> Self {
+        self.inner.major_version = major_version;
+        self
+    }
+    #[inline]
+    pub fn minor_version(mut self, minor_version: Card32) -> Self {
+        self.inner.minor_version = minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                major_version: Default::default(),
+                minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -209,35 +366,105 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -250,47 +477,67 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ConnectRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub driver_type: DriverType,
 }
-impl ConnectRequest {}
-impl AsByteSequence for ConnectRequest {
+impl ConnectRequest {
+    #[inline]
+    pub fn builder() -> ConnectRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<ConnectReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<ConnectRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ConnectRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.driver_type.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConnectRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (driver_type, sz): (DriverType, usize) = <DriverType>::from_bytes(&bytes[index..])?;
+        let (driver_type, sz): (DriverType, usize) = parse_item(bytes, index, "driver_type")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ConnectRequest {
                 req_type: req_type,
                 length: length,
@@ -302,16 +549,62 @@ impl AsByteSequence for ConnectRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.driver_type.size()
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ConnectRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ConnectRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Connect";
     type Reply = ConnectReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ConnectRequest`."]
+#[derive(Clone, Debug)]
+pub struct ConnectRequestBuilder {
+    pub inner: ConnectRequest,
+}
+impl ConnectRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn driver_type(mut self, driver_type: DriverType) -> Self {
+        self.inner.driver_type = driver_type;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ConnectRequest {
+        self.inner
+    }
+}
+impl Default for ConnectRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ConnectRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                driver_type: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ConnectReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -321,61 +614,137 @@ pub struct ConnectReply {
     pub alignment_pad: Vec<Void>,
     pub device_name: String,
 }
-impl ConnectReply {}
+impl ConnectReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("driver_name_length", 8, 4),
+        ("device_name_len", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.driver_name_length == other.driver_name_length
+            && self.driver_name == other.driver_name
+            && self.alignment_pad == other.alignment_pad
+            && self.device_name == other.device_name
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "driver_name_length",
+            &self.driver_name_length,
+            &other.driver_name_length,
+            &mut changes,
+        );
+        diff_list(
+            "driver_name",
+            str::as_bytes(&self.driver_name),
+            str::as_bytes(&other.driver_name),
+            &mut changes,
+        );
+        diff_list(
+            "alignment_pad",
+            &self.alignment_pad,
+            &other.alignment_pad,
+            &mut changes,
+        );
+        diff_list(
+            "device_name",
+            str::as_bytes(&self.device_name),
+            str::as_bytes(&other.device_name),
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for ConnectReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.driver_name_length.as_bytes(&mut bytes[index..]);
         index += (self.device_name.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         let block_len: usize = string_as_bytes(&self.driver_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
         let block_len: usize = vector_as_bytes(&self.alignment_pad, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Void>()),
+        );
         let block_len: usize = string_as_bytes(&self.device_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConnectReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (driver_name_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (driver_name_length, sz): (Card32, usize) =
+            parse_item(bytes, index, "driver_name_length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 16;
-        let (driver_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], (driver_name_length as usize) as usize)?;
+        let (driver_name, block_len): (String, usize) = parse_string(
+            bytes,
+            index,
+            (driver_name_length as usize) as usize,
+            "driver_name",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        let (alignment_pad, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (alignment_pad, block_len): (Vec<Void>, usize) = parse_vector(
+            bytes,
+            index,
             ((((driver_name_length as usize) + (3)) & (!(3))) - (driver_name_length as usize))
                 as usize,
+            "alignment_pad",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
         let (device_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "device_name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ConnectReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -388,34 +757,75 @@ impl AsByteSequence for ConnectReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.driver_name_length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 16
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.driver_name.len();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.alignment_pad.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.device_name.len();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ConnectReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for ConnectReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConnectReply")
+            .field("reply_type", &self.reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("driver_name_length", &self.driver_name_length)
+            .field("driver_name", &self.driver_name)
+            .field("alignment_pad", &debug_bytes(&self.alignment_pad, 64))
+            .field("device_name", &self.device_name)
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for ConnectReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let driver_name_length: Card32 = u.arbitrary()?;
+        let device_name = u.arbitrary()?;
+        let driver_name = arbitrary_string(u, (driver_name_length as usize) as usize)?;
+        let alignment_pad = arbitrary_list(
+            u,
+            ((((driver_name_length as usize) + (3)) & (!(3))) - (driver_name_length as usize))
+                as usize,
+        )?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            driver_name_length: driver_name_length,
+            driver_name: driver_name,
+            alignment_pad: alignment_pad,
+            device_name: device_name,
+        })
     }
 }
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum DriverType {
     Dri = 0,
     Vdpau = 1,
@@ -426,12 +836,12 @@ impl AsByteSequence for DriverType {
         (*self as u32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Dri, sz)),
-            1 => Some((Self::Vdpau, sz)),
-            _ => None,
+            0 => Ok((Self::Dri, sz)),
+            1 => Ok((Self::Vdpau, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -439,45 +849,88 @@ impl AsByteSequence for DriverType {
         ::core::mem::size_of::<u32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for DriverType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for DriverType {
     #[inline]
     fn default() -> DriverType {
         DriverType::Dri
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<DriverType> for u32 {
+    #[inline]
+    fn from(base: DriverType) -> Self {
+        base as u32
+    }
+}
+impl core::convert::TryFrom<u32> for DriverType {
+    type Error = u32;
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::Dri),
+            1 => Ok(Self::Vdpau),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AuthenticateRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub magic: Card32,
 }
-impl AuthenticateRequest {}
-impl AsByteSequence for AuthenticateRequest {
+impl AuthenticateRequest {
+    #[inline]
+    pub fn builder() -> AuthenticateRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<AuthenticateReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<AuthenticateRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AuthenticateRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.magic.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthenticateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (magic, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (magic, sz): (Card32, usize) = parse_item(bytes, index, "magic")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AuthenticateRequest {
                 req_type: req_type,
                 length: length,
@@ -489,48 +942,156 @@ impl AsByteSequence for AuthenticateRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.magic.size()
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AuthenticateRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AuthenticateRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Authenticate";
     type Reply = AuthenticateReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AuthenticateRequest`."]
+#[derive(Clone, Debug)]
+pub struct AuthenticateRequestBuilder {
+    pub inner: AuthenticateRequest,
+}
+impl AuthenticateRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn magic(mut self, magic: Card32) -> Self {
+        self.inner.magic = magic;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AuthenticateRequest {
+        self.inner
+    }
+}
+impl Default for AuthenticateRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AuthenticateRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                magic: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AuthenticateReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub authenticated: Card32,
 }
-impl AuthenticateReply {}
-impl AsByteSequence for AuthenticateReply {
+impl AuthenticateReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let authenticated: Card32 = read_item(reader, &mut index, 4, "authenticated")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(AuthenticateReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            authenticated: authenticated,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("authenticated", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.authenticated == other.authenticated
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "authenticated",
+            &self.authenticated,
+            &other.authenticated,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for AuthenticateReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.authenticated.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthenticateReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (authenticated, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (authenticated, sz): (Card32, usize) = parse_item(bytes, index, "authenticated")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AuthenticateReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -542,110 +1103,184 @@ impl AsByteSequence for AuthenticateReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.authenticated.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AuthenticateReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateDrawableRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
 }
-impl CreateDrawableRequest {}
-impl AsByteSequence for CreateDrawableRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CreateDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            CreateDrawableRequest {
-                req_type: req_type,
-                length: length,
-                drawable: drawable,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size()
-    }
+impl CreateDrawableRequest {
+    #[inline]
+    pub fn builder() -> CreateDrawableRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateDrawableRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_drawable_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.drawable.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        drawable: drawable,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.drawable.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_drawable_only_request_asb! { CreateDrawableRequest }
 impl Request for CreateDrawableRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateDrawable";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct DestroyDrawableRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub drawable: Drawable,
+#[doc = " A builder for `CreateDrawableRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateDrawableRequestBuilder {
+    pub inner: CreateDrawableRequest,
 }
-impl DestroyDrawableRequest {}
-impl AsByteSequence for DestroyDrawableRequest {
+impl CreateDrawableRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DestroyDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DestroyDrawableRequest {
-                req_type: req_type,
-                length: length,
-                drawable: drawable,
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateDrawableRequest {
+        self.inner
+    }
+}
+impl Default for CreateDrawableRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateDrawableRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct DestroyDrawableRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub drawable: Drawable,
+}
+impl DestroyDrawableRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size()
+    pub fn builder() -> DestroyDrawableRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<DestroyDrawableRequest as Request>::MINOR_OPCODE < 0x100);
+impl_drawable_only_request_asb! { DestroyDrawableRequest }
 impl Request for DestroyDrawableRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyDrawable";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyDrawableRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyDrawableRequestBuilder {
+    pub inner: DestroyDrawableRequest,
+}
+impl DestroyDrawableRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyDrawableRequest {
+        self.inner
+    }
+}
+impl Default for DestroyDrawableRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyDrawableRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetBuffersRequest {
     pub req_type: u8,
     pub length: u16,
@@ -653,39 +1288,87 @@ pub struct GetBuffersRequest {
     pub count: Card32,
     pub attachments: Vec<Card32>,
 }
-impl GetBuffersRequest {}
-impl AsByteSequence for GetBuffersRequest {
+impl GetBuffersRequest {
+    pub fn attachments_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
+        index += sz;
+        let (count, sz): (Card32, usize) = parse_item(bytes, index, "count")?;
+        index += sz;
+        let (attachments, _): (Cow<'_, [Card32]>, usize) = parse_slice(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "attachments",
+                offset: index,
+            })? as usize,
+            "attachments",
+        )?;
+        Ok(attachments)
+    }
     #[inline]
+    pub fn builder() -> GetBuffersRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetBuffersReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetBuffersRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetBuffersRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.count.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.attachments, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBuffersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = parse_item(bytes, index, "count")?;
         index += sz;
-        let (attachments, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (attachments, block_len): (Vec<Card32>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "attachments",
+                offset: index,
+            })? as usize,
+            "attachments",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetBuffersRequest {
                 req_type: req_type,
                 length: length,
@@ -698,20 +1381,96 @@ impl AsByteSequence for GetBuffersRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.count.size() + {
+        let size: usize = 8 + self.drawable.size() + {
             let block_len: usize = self.attachments.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 8 + self.drawable.size() + {
+            let block_len: usize = self.attachments.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetBuffersRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetBuffersRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetBuffers";
     type Reply = GetBuffersReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetBuffersRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetBuffersRequestBuilder {
+    pub inner: GetBuffersRequest,
+}
+impl GetBuffersRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn count(mut self, count: Card32) -> Self {
+        self.inner.count = count;
+        self
+    }
+    #[inline]
+    pub fn attachments(mut self, attachments: Vec<Card32>) -> Self {
+        self.inner.attachments = attachments;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetBuffersRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card32> for GetBuffersRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card32>>(&mut self, iter: I) {
+        self.inner.attachments.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card32> for GetBuffersRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card32>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for GetBuffersRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetBuffersRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                count: Default::default(),
+                attachments: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetBuffersReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -720,76 +1479,176 @@ pub struct GetBuffersReply {
     pub height: Card32,
     pub buffers: Vec<Dri2Buffer>,
 }
-impl GetBuffersReply {}
-impl AsByteSequence for GetBuffersReply {
+impl GetBuffersReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.width.as_bytes(&mut bytes[index..]);
-        index += self.height.as_bytes(&mut bytes[index..]);
-        index += (self.buffers.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 12;
-        let block_len: usize = vector_as_bytes(&self.buffers, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub fn buffers_iter(bytes: &[u8]) -> Result<ListIter<'_, Dri2Buffer>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetBuffersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width, sz): (Card32, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card32, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 12;
-        let (buffers, block_len): (Vec<Dri2Buffer>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
-        Some((
-            GetBuffersReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                width: width,
-                height: height,
-                buffers: buffers,
-            },
-            index,
-        ))
+        Ok(ListIter::new(bytes, index, len0 as usize, "buffers"))
     }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.width.size()
-            + self.height.size()
-            + ::core::mem::size_of::<Card32>()
-            + 12
-            + {
-                let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
-                block_len + pad
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let width: Card32 = read_item(reader, &mut index, 4, "width")?;
+        let height: Card32 = read_item(reader, &mut index, 4, "height")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let (buffers, block_len): (Vec<Dri2Buffer>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 20, "buffers")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetBuffersReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            width: width,
+            height: height,
+            buffers: buffers,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("width", 8, 4),
+        ("height", 12, 4),
+        ("buffers_len", 16, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.buffers == other.buffers
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_list("buffers", &self.buffers, &other.buffers, &mut changes);
+        changes
+    }
+}
+macro_rules! impl_width_height_buffers_reply_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.reply_type.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 1);
+                index += self.sequence.as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.width.as_bytes(&mut bytes[index..]);
+                index += self.height.as_bytes(&mut bytes[index..]);
+                index += (self.buffers.len() as Card32).as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 12);
+                let block_len: usize = vector_as_bytes(&self.buffers, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
             }
-    }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+                index += sz;
+                index += 1;
+                let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+                index += sz;
+                let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let bytes: &[u8] = reply_bytes(bytes, length);
+                let (width, sz): (Card32, usize) = parse_item(bytes, index, "width")?;
+                index += sz;
+                let (height, sz): (Card32, usize) = parse_item(bytes, index, "height")?;
+                index += sz;
+                let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+                index += sz;
+                index += 12;
+                let (buffers, block_len): (Vec<Dri2Buffer>, usize) =
+                    parse_vector(bytes, index, len0 as usize, "buffers")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        reply_type: reply_type,
+                        sequence: sequence,
+                        length: length,
+                        width: width,
+                        height: height,
+                        buffers: buffers,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 32 + {
+                    let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
-#[derive(Clone, Debug, Default)]
+impl_width_height_buffers_reply_asb! { GetBuffersReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CopyRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -798,38 +1657,54 @@ pub struct CopyRegionRequest {
     pub dest: Card32,
     pub src: Card32,
 }
-impl CopyRegionRequest {}
-impl AsByteSequence for CopyRegionRequest {
+impl CopyRegionRequest {
+    #[inline]
+    pub fn builder() -> CopyRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CopyRegionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CopyRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CopyRegionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.dest.as_bytes(&mut bytes[index..]);
         index += self.src.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (region, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Card32, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (dest, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dest, sz): (Card32, usize) = parse_item(bytes, index, "dest")?;
         index += sz;
-        let (src, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Card32, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CopyRegionRequest {
                 req_type: req_type,
                 length: length,
@@ -843,50 +1718,154 @@ impl AsByteSequence for CopyRegionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.region.size()
-            + self.dest.size()
-            + self.src.size()
+        let size: usize = 16 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CopyRegionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CopyRegionRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyRegion";
     type Reply = CopyRegionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CopyRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct CopyRegionRequestBuilder {
+    pub inner: CopyRegionRequest,
+}
+impl CopyRegionRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn region(mut self, region: Card32) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn dest(mut self, dest: Card32) -> Self {
+        self.inner.dest = dest;
+        self
+    }
+    #[inline]
+    pub fn src(mut self, src: Card32) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CopyRegionRequest {
+        self.inner
+    }
+}
+impl Default for CopyRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CopyRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                region: Default::default(),
+                dest: Default::default(),
+                src: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CopyRegionReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
 }
-impl CopyRegionReply {}
+impl CopyRegionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(CopyRegionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        true
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        changes
+    }
+}
 impl AsByteSequence for CopyRegionReply {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyRegionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        index += buffer_pad(index, 4);
+        Ok((
             CopyRegionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -897,10 +1876,21 @@ impl AsByteSequence for CopyRegionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + 1 + self.sequence.size() + self.length.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CopyRegionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetBuffersWithFormatRequest {
     pub req_type: u8,
     pub length: u16,
@@ -908,39 +1898,65 @@ pub struct GetBuffersWithFormatRequest {
     pub count: Card32,
     pub attachments: Vec<AttachFormat>,
 }
-impl GetBuffersWithFormatRequest {}
-impl AsByteSequence for GetBuffersWithFormatRequest {
+impl GetBuffersWithFormatRequest {
     #[inline]
+    pub fn builder() -> GetBuffersWithFormatRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetBuffersWithFormatReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetBuffersWithFormatRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetBuffersWithFormatRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.count.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.attachments, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<AttachFormat>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<AttachFormat>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBuffersWithFormatRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = parse_item(bytes, index, "count")?;
         index += sz;
-        let (attachments, block_len): (Vec<AttachFormat>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (attachments, block_len): (Vec<AttachFormat>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "attachments",
+                offset: index,
+            })? as usize,
+            "attachments",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AttachFormat>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetBuffersWithFormatRequest {
                 req_type: req_type,
                 length: length,
@@ -953,20 +1969,87 @@ impl AsByteSequence for GetBuffersWithFormatRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.count.size() + {
+        let size: usize = 8 + self.drawable.size() + {
             let block_len: usize = self.attachments.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<AttachFormat>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetBuffersWithFormatRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetBuffersWithFormatRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetBuffersWithFormat";
     type Reply = GetBuffersWithFormatReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetBuffersWithFormatRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetBuffersWithFormatRequestBuilder {
+    pub inner: GetBuffersWithFormatRequest,
+}
+impl GetBuffersWithFormatRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn count(mut self, count: Card32) -> Self {
+        self.inner.count = count;
+        self
+    }
+    #[inline]
+    pub fn attachments(mut self, attachments: Vec<AttachFormat>) -> Self {
+        self.inner.attachments = attachments;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetBuffersWithFormatRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<AttachFormat> for GetBuffersWithFormatRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = AttachFormat>>(&mut self, iter: I) {
+        self.inner.attachments.extend(iter);
+    }
+}
+impl core::iter::FromIterator<AttachFormat> for GetBuffersWithFormatRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = AttachFormat>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for GetBuffersWithFormatRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetBuffersWithFormatRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                count: Default::default(),
+                attachments: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetBuffersWithFormatReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -975,76 +2058,96 @@ pub struct GetBuffersWithFormatReply {
     pub height: Card32,
     pub buffers: Vec<Dri2Buffer>,
 }
-impl GetBuffersWithFormatReply {}
-impl AsByteSequence for GetBuffersWithFormatReply {
+impl GetBuffersWithFormatReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.width.as_bytes(&mut bytes[index..]);
-        index += self.height.as_bytes(&mut bytes[index..]);
-        index += (self.buffers.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 12;
-        let block_len: usize = vector_as_bytes(&self.buffers, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub fn buffers_iter(bytes: &[u8]) -> Result<ListIter<'_, Dri2Buffer>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetBuffersWithFormatReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width, sz): (Card32, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card32, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 12;
-        let (buffers, block_len): (Vec<Dri2Buffer>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
-        Some((
-            GetBuffersWithFormatReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                width: width,
-                height: height,
-                buffers: buffers,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.width.size()
-            + self.height.size()
-            + ::core::mem::size_of::<Card32>()
-            + 12
-            + {
-                let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
-                block_len + pad
-            }
+        Ok(ListIter::new(bytes, index, len0 as usize, "buffers"))
     }
-}
-#[derive(Clone, Debug, Default)]
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let width: Card32 = read_item(reader, &mut index, 4, "width")?;
+        let height: Card32 = read_item(reader, &mut index, 4, "height")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let (buffers, block_len): (Vec<Dri2Buffer>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 20, "buffers")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetBuffersWithFormatReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            width: width,
+            height: height,
+            buffers: buffers,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("width", 8, 4),
+        ("height", 12, 4),
+        ("buffers_len", 16, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.buffers == other.buffers
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_list("buffers", &self.buffers, &other.buffers, &mut changes);
+        changes
+    }
+}
+impl_width_height_buffers_reply_asb! { GetBuffersWithFormatReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SwapBuffersRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1056,82 +2159,177 @@ pub struct SwapBuffersRequest {
     pub remainder_hi: Card32,
     pub remainder_lo: Card32,
 }
-impl SwapBuffersRequest {}
-impl AsByteSequence for SwapBuffersRequest {
+impl SwapBuffersRequest {
+    #[inline]
+    pub fn builder() -> SwapBuffersRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<SwapBuffersReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SwapBuffersRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_request_asb_5bd517b3 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.drawable.as_bytes(&mut bytes[index..]);
+                index += self.target_msc_hi.as_bytes(&mut bytes[index..]);
+                index += self.target_msc_lo.as_bytes(&mut bytes[index..]);
+                index += self.divisor_hi.as_bytes(&mut bytes[index..]);
+                index += self.divisor_lo.as_bytes(&mut bytes[index..]);
+                index += self.remainder_hi.as_bytes(&mut bytes[index..]);
+                index += self.remainder_lo.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
+                index += sz;
+                let (target_msc_hi, sz): (Card32, usize) =
+                    parse_item(bytes, index, "target_msc_hi")?;
+                index += sz;
+                let (target_msc_lo, sz): (Card32, usize) =
+                    parse_item(bytes, index, "target_msc_lo")?;
+                index += sz;
+                let (divisor_hi, sz): (Card32, usize) = parse_item(bytes, index, "divisor_hi")?;
+                index += sz;
+                let (divisor_lo, sz): (Card32, usize) = parse_item(bytes, index, "divisor_lo")?;
+                index += sz;
+                let (remainder_hi, sz): (Card32, usize) = parse_item(bytes, index, "remainder_hi")?;
+                index += sz;
+                let (remainder_lo, sz): (Card32, usize) = parse_item(bytes, index, "remainder_lo")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        drawable: drawable,
+                        target_msc_hi: target_msc_hi,
+                        target_msc_lo: target_msc_lo,
+                        divisor_hi: divisor_hi,
+                        divisor_lo: divisor_lo,
+                        remainder_hi: remainder_hi,
+                        remainder_lo: remainder_lo,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 28 + self.drawable.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_request_asb_5bd517b3! { SwapBuffersRequest }
+impl Request for SwapBuffersRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 8;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwapBuffers";
+    type Reply = SwapBuffersReply;
+}
+#[doc = " A builder for `SwapBuffersRequest`."]
+#[derive(Clone, Debug)]
+pub struct SwapBuffersRequestBuilder {
+    pub inner: SwapBuffersRequest,
+}
+impl SwapBuffersRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index += self.target_msc_hi.as_bytes(&mut bytes[index..]);
-        index += self.target_msc_lo.as_bytes(&mut bytes[index..]);
-        index += self.divisor_hi.as_bytes(&mut bytes[index..]);
-        index += self.divisor_lo.as_bytes(&mut bytes[index..]);
-        index += self.remainder_hi.as_bytes(&mut bytes[index..]);
-        index += self.remainder_lo.as_bytes(&mut bytes[index..]);
-        index
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SwapBuffersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (target_msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (target_msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (divisor_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (divisor_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (remainder_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (remainder_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            SwapBuffersRequest {
-                req_type: req_type,
-                length: length,
-                drawable: drawable,
-                target_msc_hi: target_msc_hi,
-                target_msc_lo: target_msc_lo,
-                divisor_hi: divisor_hi,
-                divisor_lo: divisor_lo,
-                remainder_hi: remainder_hi,
-                remainder_lo: remainder_lo,
-            },
-            index,
-        ))
+    pub fn target_msc_hi(mut self, target_msc_hi: Card32) -> Self {
+        self.inner.target_msc_hi = target_msc_hi;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.target_msc_hi.size()
-            + self.target_msc_lo.size()
-            + self.divisor_hi.size()
-            + self.divisor_lo.size()
-            + self.remainder_hi.size()
-            + self.remainder_lo.size()
+    pub fn target_msc_lo(mut self, target_msc_lo: Card32) -> Self {
+        self.inner.target_msc_lo = target_msc_lo;
+        self
+    }
+    #[inline]
+    pub fn divisor_hi(mut self, divisor_hi: Card32) -> Self {
+        self.inner.divisor_hi = divisor_hi;
+        self
+    }
+    #[inline]
+    pub fn divisor_lo(mut self, divisor_lo: Card32) -> Self {
+        self.inner.divisor_lo = divisor_lo;
+        self
+    }
+    #[inline]
+    pub fn remainder_hi(mut self, remainder_hi: Card32) -> Self {
+        self.inner.remainder_hi = remainder_hi;
+        self
+    }
+    #[inline]
+    pub fn remainder_lo(mut self, remainder_lo: Card32) -> Self {
+        self.inner.remainder_lo = remainder_lo;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SwapBuffersRequest {
+        self.inner
     }
 }
-impl Request for SwapBuffersRequest {
-    const OPCODE: u8 = 8;
-    const EXTENSION: Option<&'static str> = Some("DRI2");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = SwapBuffersReply;
+impl Default for SwapBuffersRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SwapBuffersRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                target_msc_hi: Default::default(),
+                target_msc_lo: Default::default(),
+                divisor_hi: Default::default(),
+                divisor_lo: Default::default(),
+                remainder_hi: Default::default(),
+                remainder_lo: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SwapBuffersReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1139,35 +2337,95 @@ pub struct SwapBuffersReply {
     pub swap_hi: Card32,
     pub swap_lo: Card32,
 }
-impl SwapBuffersReply {}
-impl AsByteSequence for SwapBuffersReply {
+impl SwapBuffersReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let swap_hi: Card32 = read_item(reader, &mut index, 4, "swap_hi")?;
+        let swap_lo: Card32 = read_item(reader, &mut index, 4, "swap_lo")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(SwapBuffersReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            swap_hi: swap_hi,
+            swap_lo: swap_lo,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("swap_hi", 8, 4),
+        ("swap_lo", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.swap_hi == other.swap_hi && self.swap_lo == other.swap_lo
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("swap_hi", &self.swap_hi, &other.swap_hi, &mut changes);
+        diff_value("swap_lo", &self.swap_lo, &other.swap_lo, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for SwapBuffersReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.swap_hi.as_bytes(&mut bytes[index..]);
         index += self.swap_lo.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwapBuffersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (swap_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (swap_hi, sz): (Card32, usize) = parse_item(bytes, index, "swap_hi")?;
         index += sz;
-        let (swap_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (swap_lo, sz): (Card32, usize) = parse_item(bytes, index, "swap_lo")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SwapBuffersReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1180,63 +2438,82 @@ impl AsByteSequence for SwapBuffersReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.swap_hi.size()
-            + self.swap_lo.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SwapBuffersReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetMscRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
 }
-impl GetMscRequest {}
-impl AsByteSequence for GetMscRequest {
+impl GetMscRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> GetMscRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetMscRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetMscRequest {
-                req_type: req_type,
-                length: length,
-                drawable: drawable,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size()
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GetMscReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetMscRequest as Request>::MINOR_OPCODE < 0x100);
+impl_drawable_only_request_asb! { GetMscRequest }
 impl Request for GetMscRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMsc";
     type Reply = GetMscReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetMscRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetMscRequestBuilder {
+    pub inner: GetMscRequest,
+}
+impl GetMscRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetMscRequest {
+        self.inner
+    }
+}
+impl Default for GetMscRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetMscRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetMscReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1245,79 +2522,171 @@ pub struct GetMscReply {
     pub ust_lo: Card32,
     pub msc_hi: Card32,
     pub msc_lo: Card32,
-    pub sbc_hi: Card32,
-    pub sbc_lo: Card32,
-}
-impl GetMscReply {}
-impl AsByteSequence for GetMscReply {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.ust_hi.as_bytes(&mut bytes[index..]);
-        index += self.ust_lo.as_bytes(&mut bytes[index..]);
-        index += self.msc_hi.as_bytes(&mut bytes[index..]);
-        index += self.msc_lo.as_bytes(&mut bytes[index..]);
-        index += self.sbc_hi.as_bytes(&mut bytes[index..]);
-        index += self.sbc_lo.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetMscReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetMscReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                ust_hi: ust_hi,
-                ust_lo: ust_lo,
-                msc_hi: msc_hi,
-                msc_lo: msc_lo,
-                sbc_hi: sbc_hi,
-                sbc_lo: sbc_lo,
-            },
-            index,
-        ))
+    pub sbc_hi: Card32,
+    pub sbc_lo: Card32,
+}
+impl GetMscReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.ust_hi.size()
-            + self.ust_lo.size()
-            + self.msc_hi.size()
-            + self.msc_lo.size()
-            + self.sbc_hi.size()
-            + self.sbc_lo.size()
-    }
-}
-#[derive(Clone, Debug, Default)]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let ust_hi: Card32 = read_item(reader, &mut index, 4, "ust_hi")?;
+        let ust_lo: Card32 = read_item(reader, &mut index, 4, "ust_lo")?;
+        let msc_hi: Card32 = read_item(reader, &mut index, 4, "msc_hi")?;
+        let msc_lo: Card32 = read_item(reader, &mut index, 4, "msc_lo")?;
+        let sbc_hi: Card32 = read_item(reader, &mut index, 4, "sbc_hi")?;
+        let sbc_lo: Card32 = read_item(reader, &mut index, 4, "sbc_lo")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetMscReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            ust_hi: ust_hi,
+            ust_lo: ust_lo,
+            msc_hi: msc_hi,
+            msc_lo: msc_lo,
+            sbc_hi: sbc_hi,
+            sbc_lo: sbc_lo,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("ust_hi", 8, 4),
+        ("ust_lo", 12, 4),
+        ("msc_hi", 16, 4),
+        ("msc_lo", 20, 4),
+        ("sbc_hi", 24, 4),
+        ("sbc_lo", 28, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.ust_hi == other.ust_hi
+            && self.ust_lo == other.ust_lo
+            && self.msc_hi == other.msc_hi
+            && self.msc_lo == other.msc_lo
+            && self.sbc_hi == other.sbc_hi
+            && self.sbc_lo == other.sbc_lo
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("ust_hi", &self.ust_hi, &other.ust_hi, &mut changes);
+        diff_value("ust_lo", &self.ust_lo, &other.ust_lo, &mut changes);
+        diff_value("msc_hi", &self.msc_hi, &other.msc_hi, &mut changes);
+        diff_value("msc_lo", &self.msc_lo, &other.msc_lo, &mut changes);
+        diff_value("sbc_hi", &self.sbc_hi, &other.sbc_hi, &mut changes);
+        diff_value("sbc_lo", &self.sbc_lo, &other.sbc_lo, &mut changes);
+        changes
+    }
+}
+macro_rules! impl_reply_asb_e6165c73 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.reply_type.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 1);
+                index += self.sequence.as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.ust_hi.as_bytes(&mut bytes[index..]);
+                index += self.ust_lo.as_bytes(&mut bytes[index..]);
+                index += self.msc_hi.as_bytes(&mut bytes[index..]);
+                index += self.msc_lo.as_bytes(&mut bytes[index..]);
+                index += self.sbc_hi.as_bytes(&mut bytes[index..]);
+                index += self.sbc_lo.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+                index += sz;
+                index += 1;
+                let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+                index += sz;
+                let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let bytes: &[u8] = reply_bytes(bytes, length);
+                let (ust_hi, sz): (Card32, usize) = parse_item(bytes, index, "ust_hi")?;
+                index += sz;
+                let (ust_lo, sz): (Card32, usize) = parse_item(bytes, index, "ust_lo")?;
+                index += sz;
+                let (msc_hi, sz): (Card32, usize) = parse_item(bytes, index, "msc_hi")?;
+                index += sz;
+                let (msc_lo, sz): (Card32, usize) = parse_item(bytes, index, "msc_lo")?;
+                index += sz;
+                let (sbc_hi, sz): (Card32, usize) = parse_item(bytes, index, "sbc_hi")?;
+                index += sz;
+                let (sbc_lo, sz): (Card32, usize) = parse_item(bytes, index, "sbc_lo")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        reply_type: reply_type,
+                        sequence: sequence,
+                        length: length,
+                        ust_hi: ust_hi,
+                        ust_lo: ust_lo,
+                        msc_hi: msc_hi,
+                        msc_lo: msc_lo,
+                        sbc_hi: sbc_hi,
+                        sbc_lo: sbc_lo,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 32;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_reply_asb_e6165c73! { GetMscReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct WaitMscRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1329,82 +2698,98 @@ pub struct WaitMscRequest {
     pub remainder_hi: Card32,
     pub remainder_lo: Card32,
 }
-impl WaitMscRequest {}
-impl AsByteSequence for WaitMscRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index += self.target_msc_hi.as_bytes(&mut bytes[index..]);
-        index += self.target_msc_lo.as_bytes(&mut bytes[index..]);
-        index += self.divisor_hi.as_bytes(&mut bytes[index..]);
-        index += self.divisor_lo.as_bytes(&mut bytes[index..]);
-        index += self.remainder_hi.as_bytes(&mut bytes[index..]);
-        index += self.remainder_lo.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl WaitMscRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing WaitMscRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (target_msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (target_msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (divisor_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (divisor_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (remainder_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (remainder_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            WaitMscRequest {
-                req_type: req_type,
-                length: length,
-                drawable: drawable,
-                target_msc_hi: target_msc_hi,
-                target_msc_lo: target_msc_lo,
-                divisor_hi: divisor_hi,
-                divisor_lo: divisor_lo,
-                remainder_hi: remainder_hi,
-                remainder_lo: remainder_lo,
-            },
-            index,
-        ))
+    pub fn builder() -> WaitMscRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.target_msc_hi.size()
-            + self.target_msc_lo.size()
-            + self.divisor_hi.size()
-            + self.divisor_lo.size()
-            + self.remainder_hi.size()
-            + self.remainder_lo.size()
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<WaitMscReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<WaitMscRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_5bd517b3! { WaitMscRequest }
 impl Request for WaitMscRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "WaitMsc";
     type Reply = WaitMscReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `WaitMscRequest`."]
+#[derive(Clone, Debug)]
+pub struct WaitMscRequestBuilder {
+    pub inner: WaitMscRequest,
+}
+impl WaitMscRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn target_msc_hi(mut self, target_msc_hi: Card32) -> Self {
+        self.inner.target_msc_hi = target_msc_hi;
+        self
+    }
+    #[inline]
+    pub fn target_msc_lo(mut self, target_msc_lo: Card32) -> Self {
+        self.inner.target_msc_lo = target_msc_lo;
+        self
+    }
+    #[inline]
+    pub fn divisor_hi(mut self, divisor_hi: Card32) -> Self {
+        self.inner.divisor_hi = divisor_hi;
+        self
+    }
+    #[inline]
+    pub fn divisor_lo(mut self, divisor_lo: Card32) -> Self {
+        self.inner.divisor_lo = divisor_lo;
+        self
+    }
+    #[inline]
+    pub fn remainder_hi(mut self, remainder_hi: Card32) -> Self {
+        self.inner.remainder_hi = remainder_hi;
+        self
+    }
+    #[inline]
+    pub fn remainder_lo(mut self, remainder_lo: Card32) -> Self {
+        self.inner.remainder_lo = remainder_lo;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> WaitMscRequest {
+        self.inner
+    }
+}
+impl Default for WaitMscRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: WaitMscRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                target_msc_hi: Default::default(),
+                target_msc_lo: Default::default(),
+                divisor_hi: Default::default(),
+                divisor_lo: Default::default(),
+                remainder_hi: Default::default(),
+                remainder_lo: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct WaitMscReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1416,76 +2801,92 @@ pub struct WaitMscReply {
     pub sbc_hi: Card32,
     pub sbc_lo: Card32,
 }
-impl WaitMscReply {}
-impl AsByteSequence for WaitMscReply {
+impl WaitMscReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.ust_hi.as_bytes(&mut bytes[index..]);
-        index += self.ust_lo.as_bytes(&mut bytes[index..]);
-        index += self.msc_hi.as_bytes(&mut bytes[index..]);
-        index += self.msc_lo.as_bytes(&mut bytes[index..]);
-        index += self.sbc_hi.as_bytes(&mut bytes[index..]);
-        index += self.sbc_lo.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing WaitMscReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            WaitMscReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                ust_hi: ust_hi,
-                ust_lo: ust_lo,
-                msc_hi: msc_hi,
-                msc_lo: msc_lo,
-                sbc_hi: sbc_hi,
-                sbc_lo: sbc_lo,
-            },
-            index,
-        ))
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.ust_hi.size()
-            + self.ust_lo.size()
-            + self.msc_hi.size()
-            + self.msc_lo.size()
-            + self.sbc_hi.size()
-            + self.sbc_lo.size()
-    }
-}
-#[derive(Clone, Debug, Default)]
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let ust_hi: Card32 = read_item(reader, &mut index, 4, "ust_hi")?;
+        let ust_lo: Card32 = read_item(reader, &mut index, 4, "ust_lo")?;
+        let msc_hi: Card32 = read_item(reader, &mut index, 4, "msc_hi")?;
+        let msc_lo: Card32 = read_item(reader, &mut index, 4, "msc_lo")?;
+        let sbc_hi: Card32 = read_item(reader, &mut index, 4, "sbc_hi")?;
+        let sbc_lo: Card32 = read_item(reader, &mut index, 4, "sbc_lo")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(WaitMscReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            ust_hi: ust_hi,
+            ust_lo: ust_lo,
+            msc_hi: msc_hi,
+            msc_lo: msc_lo,
+            sbc_hi: sbc_hi,
+            sbc_lo: sbc_lo,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("ust_hi", 8, 4),
+        ("ust_lo", 12, 4),
+        ("msc_hi", 16, 4),
+        ("msc_lo", 20, 4),
+        ("sbc_hi", 24, 4),
+        ("sbc_lo", 28, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.ust_hi == other.ust_hi
+            && self.ust_lo == other.ust_lo
+            && self.msc_hi == other.msc_hi
+            && self.msc_lo == other.msc_lo
+            && self.sbc_hi == other.sbc_hi
+            && self.sbc_lo == other.sbc_lo
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("ust_hi", &self.ust_hi, &other.ust_hi, &mut changes);
+        diff_value("ust_lo", &self.ust_lo, &other.ust_lo, &mut changes);
+        diff_value("msc_hi", &self.msc_hi, &other.msc_hi, &mut changes);
+        diff_value("msc_lo", &self.msc_lo, &other.msc_lo, &mut changes);
+        diff_value("sbc_hi", &self.sbc_hi, &other.sbc_hi, &mut changes);
+        diff_value("sbc_lo", &self.sbc_lo, &other.sbc_lo, &mut changes);
+        changes
+    }
+}
+impl_reply_asb_e6165c73! { WaitMscReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct WaitSbcRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1493,35 +2894,49 @@ pub struct WaitSbcRequest {
     pub target_sbc_hi: Card32,
     pub target_sbc_lo: Card32,
 }
-impl WaitSbcRequest {}
-impl AsByteSequence for WaitSbcRequest {
+impl WaitSbcRequest {
+    #[inline]
+    pub fn builder() -> WaitSbcRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<WaitSbcReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<WaitSbcRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for WaitSbcRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.target_sbc_hi.as_bytes(&mut bytes[index..]);
         index += self.target_sbc_lo.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing WaitSbcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (target_sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_sbc_hi, sz): (Card32, usize) = parse_item(bytes, index, "target_sbc_hi")?;
         index += sz;
-        let (target_sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_sbc_lo, sz): (Card32, usize) = parse_item(bytes, index, "target_sbc_lo")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             WaitSbcRequest {
                 req_type: req_type,
                 length: length,
@@ -1534,21 +2949,69 @@ impl AsByteSequence for WaitSbcRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.target_sbc_hi.size()
-            + self.target_sbc_lo.size()
+        let size: usize = 12 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for WaitSbcRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for WaitSbcRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "WaitSbc";
     type Reply = WaitSbcReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `WaitSbcRequest`."]
+#[derive(Clone, Debug)]
+pub struct WaitSbcRequestBuilder {
+    pub inner: WaitSbcRequest,
+}
+impl WaitSbcRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn target_sbc_hi(mut self, target_sbc_hi: Card32) -> Self {
+        self.inner.target_sbc_hi = target_sbc_hi;
+        self
+    }
+    #[inline]
+    pub fn target_sbc_lo(mut self, target_sbc_lo: Card32) -> Self {
+        self.inner.target_sbc_lo = target_sbc_lo;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> WaitSbcRequest {
+        self.inner
+    }
+}
+impl Default for WaitSbcRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: WaitSbcRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                target_sbc_hi: Default::default(),
+                target_sbc_lo: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct WaitSbcReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1560,108 +3023,132 @@ pub struct WaitSbcReply {
     pub sbc_hi: Card32,
     pub sbc_lo: Card32,
 }
-impl WaitSbcReply {}
-impl AsByteSequence for WaitSbcReply {
+impl WaitSbcReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.ust_hi.as_bytes(&mut bytes[index..]);
-        index += self.ust_lo.as_bytes(&mut bytes[index..]);
-        index += self.msc_hi.as_bytes(&mut bytes[index..]);
-        index += self.msc_lo.as_bytes(&mut bytes[index..]);
-        index += self.sbc_hi.as_bytes(&mut bytes[index..]);
-        index += self.sbc_lo.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing WaitSbcReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            WaitSbcReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                ust_hi: ust_hi,
-                ust_lo: ust_lo,
-                msc_hi: msc_hi,
-                msc_lo: msc_lo,
-                sbc_hi: sbc_hi,
-                sbc_lo: sbc_lo,
-            },
-            index,
-        ))
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.ust_hi.size()
-            + self.ust_lo.size()
-            + self.msc_hi.size()
-            + self.msc_lo.size()
-            + self.sbc_hi.size()
-            + self.sbc_lo.size()
-    }
-}
-#[derive(Clone, Debug, Default)]
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let ust_hi: Card32 = read_item(reader, &mut index, 4, "ust_hi")?;
+        let ust_lo: Card32 = read_item(reader, &mut index, 4, "ust_lo")?;
+        let msc_hi: Card32 = read_item(reader, &mut index, 4, "msc_hi")?;
+        let msc_lo: Card32 = read_item(reader, &mut index, 4, "msc_lo")?;
+        let sbc_hi: Card32 = read_item(reader, &mut index, 4, "sbc_hi")?;
+        let sbc_lo: Card32 = read_item(reader, &mut index, 4, "sbc_lo")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(WaitSbcReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            ust_hi: ust_hi,
+            ust_lo: ust_lo,
+            msc_hi: msc_hi,
+            msc_lo: msc_lo,
+            sbc_hi: sbc_hi,
+            sbc_lo: sbc_lo,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("ust_hi", 8, 4),
+        ("ust_lo", 12, 4),
+        ("msc_hi", 16, 4),
+        ("msc_lo", 20, 4),
+        ("sbc_hi", 24, 4),
+        ("sbc_lo", 28, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.ust_hi == other.ust_hi
+            && self.ust_lo == other.ust_lo
+            && self.msc_hi == other.msc_hi
+            && self.msc_lo == other.msc_lo
+            && self.sbc_hi == other.sbc_hi
+            && self.sbc_lo == other.sbc_lo
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("ust_hi", &self.ust_hi, &other.ust_hi, &mut changes);
+        diff_value("ust_lo", &self.ust_lo, &other.ust_lo, &mut changes);
+        diff_value("msc_hi", &self.msc_hi, &other.msc_hi, &mut changes);
+        diff_value("msc_lo", &self.msc_lo, &other.msc_lo, &mut changes);
+        diff_value("sbc_hi", &self.sbc_hi, &other.sbc_hi, &mut changes);
+        diff_value("sbc_lo", &self.sbc_lo, &other.sbc_lo, &mut changes);
+        changes
+    }
+}
+impl_reply_asb_e6165c73! { WaitSbcReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SwapIntervalRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub interval: Card32,
 }
-impl SwapIntervalRequest {}
-impl AsByteSequence for SwapIntervalRequest {
+impl SwapIntervalRequest {
     #[inline]
+    pub fn builder() -> SwapIntervalRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SwapIntervalRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SwapIntervalRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.interval.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwapIntervalRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (interval, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (interval, sz): (Card32, usize) = parse_item(bytes, index, "interval")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SwapIntervalRequest {
                 req_type: req_type,
                 length: length,
@@ -1673,48 +3160,110 @@ impl AsByteSequence for SwapIntervalRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.interval.size()
+        let size: usize = 8 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SwapIntervalRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SwapIntervalRequest {
-    const OPCODE: u8 = 12;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 12;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwapInterval";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SwapIntervalRequest`."]
+#[derive(Clone, Debug)]
+pub struct SwapIntervalRequestBuilder {
+    pub inner: SwapIntervalRequest,
+}
+impl SwapIntervalRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn interval(mut self, interval: Card32) -> Self {
+        self.inner.interval = interval;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SwapIntervalRequest {
+        self.inner
+    }
+}
+impl Default for SwapIntervalRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SwapIntervalRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                interval: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetParamRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub param: Card32,
 }
-impl GetParamRequest {}
-impl AsByteSequence for GetParamRequest {
+impl GetParamRequest {
+    #[inline]
+    pub fn builder() -> GetParamRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GetParamReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetParamRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetParamRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.param.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetParamRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (param, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (param, sz): (Card32, usize) = parse_item(bytes, index, "param")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetParamRequest {
                 req_type: req_type,
                 length: length,
@@ -1726,16 +3275,63 @@ impl AsByteSequence for GetParamRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.param.size()
+        let size: usize = 8 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetParamRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetParamRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetParam";
     type Reply = GetParamReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetParamRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetParamRequestBuilder {
+    pub inner: GetParamRequest,
+}
+impl GetParamRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn param(mut self, param: Card32) -> Self {
+        self.inner.param = param;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetParamRequest {
+        self.inner
+    }
+}
+impl Default for GetParamRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetParamRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                param: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetParamReply {
     pub reply_type: u8,
     pub is_param_recognized: bool,
@@ -1744,9 +3340,51 @@ pub struct GetParamReply {
     pub value_hi: Card32,
     pub value_lo: Card32,
 }
-impl GetParamReply {}
+impl GetParamReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("is_param_recognized", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("value_hi", 8, 4),
+        ("value_lo", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.is_param_recognized == other.is_param_recognized
+            && self.value_hi == other.value_hi
+            && self.value_lo == other.value_lo
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "is_param_recognized",
+            &self.is_param_recognized,
+            &other.is_param_recognized,
+            &mut changes,
+        );
+        diff_value("value_hi", &self.value_hi, &other.value_hi, &mut changes);
+        diff_value("value_lo", &self.value_lo, &other.value_lo, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for GetParamReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -1755,25 +3393,28 @@ impl AsByteSequence for GetParamReply {
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.value_hi.as_bytes(&mut bytes[index..]);
         index += self.value_lo.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetParamReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (is_param_recognized, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (is_param_recognized, sz): (bool, usize) =
+            parse_item(bytes, index, "is_param_recognized")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (value_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (value_hi, sz): (Card32, usize) = parse_item(bytes, index, "value_hi")?;
         index += sz;
-        let (value_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (value_lo, sz): (Card32, usize) = parse_item(bytes, index, "value_lo")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetParamReply {
                 reply_type: reply_type,
                 is_param_recognized: is_param_recognized,
@@ -1787,16 +3428,21 @@ impl AsByteSequence for GetParamReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.is_param_recognized.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.value_hi.size()
-            + self.value_lo.size()
+        let size: usize = 15 + self.is_param_recognized.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetParamReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u16)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum EventType {
     ExchangeComplete = 1,
     BlitComplete = 2,
@@ -1808,13 +3454,13 @@ impl AsByteSequence for EventType {
         (*self as u16).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
         match underlying {
-            1 => Some((Self::ExchangeComplete, sz)),
-            2 => Some((Self::BlitComplete, sz)),
-            3 => Some((Self::FlipComplete, sz)),
-            _ => None,
+            1 => Ok((Self::ExchangeComplete, sz)),
+            2 => Ok((Self::BlitComplete, sz)),
+            3 => Ok((Self::FlipComplete, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -1822,13 +3468,41 @@ impl AsByteSequence for EventType {
         ::core::mem::size_of::<u16>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for EventType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for EventType {
     #[inline]
     fn default() -> EventType {
         EventType::ExchangeComplete
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<EventType> for u16 {
+    #[inline]
+    fn from(base: EventType) -> Self {
+        base as u16
+    }
+}
+impl core::convert::TryFrom<u16> for EventType {
+    type Error = u16;
+    #[inline]
+    fn try_from(value: u16) -> Result<Self, u16> {
+        match value {
+            1 => Ok(Self::ExchangeComplete),
+            2 => Ok(Self::BlitComplete),
+            3 => Ok(Self::FlipComplete),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct BufferSwapCompleteEvent {
     pub event_type: u8,
     pub sequence: u16,
@@ -1840,16 +3514,32 @@ pub struct BufferSwapCompleteEvent {
     pub msc_lo: Card32,
     pub sbc: Card32,
 }
-impl BufferSwapCompleteEvent {}
-impl AsByteSequence for BufferSwapCompleteEvent {
+impl BufferSwapCompleteEvent {
     #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("sequence", 2, 2),
+        ("event_type_", 4, 2),
+    ];
+}
+impl AsByteSequence for BufferSwapCompleteEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.event_type_.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.ust_hi.as_bytes(&mut bytes[index..]);
         index += self.ust_lo.as_bytes(&mut bytes[index..]);
@@ -1858,31 +3548,30 @@ impl AsByteSequence for BufferSwapCompleteEvent {
         index += self.sbc.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing BufferSwapCompleteEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (event_type_, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (event_type_, sz): (EventType, usize) = parse_item(bytes, index, "event_type_")?;
         index += sz;
         index += 2;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_hi, sz): (Card32, usize) = parse_item(bytes, index, "ust_hi")?;
         index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_lo, sz): (Card32, usize) = parse_item(bytes, index, "ust_lo")?;
         index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_hi, sz): (Card32, usize) = parse_item(bytes, index, "msc_hi")?;
         index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_lo, sz): (Card32, usize) = parse_item(bytes, index, "msc_lo")?;
         index += sz;
-        let (sbc, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc, sz): (Card32, usize) = parse_item(bytes, index, "sbc")?;
         index += sz;
-        Some((
+        Ok((
             BufferSwapCompleteEvent {
                 event_type: event_type,
                 sequence: sequence,
@@ -1899,51 +3588,63 @@ impl AsByteSequence for BufferSwapCompleteEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + 1
-            + self.sequence.size()
-            + self.event_type_.size()
-            + 2
-            + self.drawable.size()
-            + self.ust_hi.size()
-            + self.ust_lo.size()
-            + self.msc_hi.size()
-            + self.msc_lo.size()
-            + self.sbc.size()
+        28 + self.drawable.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for BufferSwapCompleteEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for BufferSwapCompleteEvent {
     const OPCODE: u8 = 0;
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct InvalidateBuffersEvent {
     pub event_type: u8,
     pub sequence: u16,
     pub drawable: Drawable,
 }
-impl InvalidateBuffersEvent {}
+impl InvalidateBuffersEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 1;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("event_type", 0, 1), ("sequence", 2, 2)];
+}
 impl AsByteSequence for InvalidateBuffersEvent {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing InvalidateBuffersEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        Some((
+        Ok((
             InvalidateBuffersEvent {
                 event_type: event_type,
                 sequence: sequence,
@@ -1954,9 +3655,103 @@ impl AsByteSequence for InvalidateBuffersEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size() + 1 + self.sequence.size() + self.drawable.size()
+        4 + self.drawable.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for InvalidateBuffersEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for InvalidateBuffersEvent {
     const OPCODE: u8 = 1;
 }
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    BufferSwapComplete(BufferSwapCompleteEvent),
+    InvalidateBuffers(InvalidateBuffersEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            0 => Ok(Self::BufferSwapComplete(
+                BufferSwapCompleteEvent::from_bytes(bytes)?.0,
+            )),
+            1 => Ok(Self::InvalidateBuffers(
+                InvalidateBuffersEvent::from_bytes(bytes)?.0,
+            )),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::BufferSwapComplete(_) => BufferSwapCompleteEvent::RESPONSE_TYPE,
+            Self::InvalidateBuffers(_) => InvalidateBuffersEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<BufferSwapCompleteEvent> for Event {
+    #[inline]
+    fn from(event: BufferSwapCompleteEvent) -> Self {
+        Self::BufferSwapComplete(event)
+    }
+}
+impl From<InvalidateBuffersEvent> for Event {
+    #[inline]
+    fn from(event: InvalidateBuffersEvent) -> Self {
+        Self::InvalidateBuffers(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "BufferSwapComplete"), (1, "InvalidateBuffers")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "Connect"),
+    (2, "Authenticate"),
+    (3, "CreateDrawable"),
+    (4, "DestroyDrawable"),
+    (5, "GetBuffers"),
+    (6, "CopyRegion"),
+    (7, "GetBuffersWithFormat"),
+    (8, "SwapBuffers"),
+    (9, "GetMsc"),
+    (10, "WaitMsc"),
+    (11, "WaitSbc"),
+    (12, "SwapInterval"),
+    (13, "GetParam"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
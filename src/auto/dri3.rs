@@ -1,44 +1,77 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "dri3")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 2;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("major_version", 4, 4),
+        ("minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -50,20 +83,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn major_version(mut self, major_version: Card32) -> Self {
+        self.inner.major_version = major_version;
+        self
+    }
+    #[inline]
+    pub fn minor_version(mut self, minor_version: Card32) -> Self {
+        self.inner.minor_version = minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                major_version: Default::default(),
+                minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -71,35 +147,105 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -112,47 +258,67 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct OpenRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub provider: Card32,
 }
-impl OpenRequest {}
-impl AsByteSequence for OpenRequest {
+impl OpenRequest {
+    #[inline]
+    pub fn builder() -> OpenRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<OpenReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<OpenRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for OpenRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (provider, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Card32, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             OpenRequest {
                 req_type: req_type,
                 length: length,
@@ -164,69 +330,171 @@ impl AsByteSequence for OpenRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.provider.size()
+        let size: usize = 8 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for OpenRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for OpenRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "Open";
     type Reply = OpenReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `OpenRequest`."]
+#[derive(Clone, Debug)]
+pub struct OpenRequestBuilder {
+    pub inner: OpenRequest,
+}
+impl OpenRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn provider(mut self, provider: Card32) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> OpenRequest {
+        self.inner
+    }
+}
+impl Default for OpenRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: OpenRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                provider: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct OpenReply {
     pub reply_type: u8,
     pub nfd: Card8,
     pub sequence: u16,
     pub length: u32,
-    pub device_fd: Vec<Fd>,
+    pub device_fd: Fd,
 }
-impl OpenReply {}
-impl AsByteSequence for OpenReply {
+impl OpenReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("nfd", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.nfd == other.nfd && self.device_fd == other.device_fd
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("nfd", &self.nfd, &other.nfd, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for OpenReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
         index += self.nfd.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
-        index += 24;
+        index += zero_pad(&mut bytes[index..], 24);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         index += 24;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             OpenReply {
                 reply_type: reply_type,
                 nfd: nfd,
                 sequence: sequence,
                 length: length,
-                device_fd: vec![],
+                device_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + self.nfd.size() + self.sequence.size() + self.length.size() + 24
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.device_fd)
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.device_fd);
+    }
+    #[inline]
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.device_fd = *fds.get(0)?;
+        Some(1)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for OpenReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PixmapFromBufferRequest {
     pub req_type: u8,
     pub length: u16,
@@ -238,15 +506,22 @@ pub struct PixmapFromBufferRequest {
     pub stride: Card16,
     pub depth: Card8,
     pub bpp: Card8,
-    pub pixmap_fd: Vec<Fd>,
+    pub pixmap_fd: Fd,
 }
-impl PixmapFromBufferRequest {}
-impl AsByteSequence for PixmapFromBufferRequest {
+impl PixmapFromBufferRequest {
     #[inline]
+    pub fn builder() -> PixmapFromBufferRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<PixmapFromBufferRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PixmapFromBufferRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.pixmap.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
@@ -256,34 +531,35 @@ impl AsByteSequence for PixmapFromBufferRequest {
         index += self.stride.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += self.bpp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixmapFromBufferRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = parse_item(bytes, index, "pixmap")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card32, usize) = parse_item(bytes, index, "size")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (stride, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (stride, sz): (Card16, usize) = parse_item(bytes, index, "stride")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PixmapFromBufferRequest {
                 req_type: req_type,
                 length: length,
@@ -295,85 +571,236 @@ impl AsByteSequence for PixmapFromBufferRequest {
                 stride: stride,
                 depth: depth,
                 bpp: bpp,
-                pixmap_fd: vec![],
+                pixmap_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.pixmap.size()
-            + self.drawable.size()
-            + self.size.size()
-            + self.width.size()
-            + self.height.size()
-            + self.stride.size()
-            + self.depth.size()
-            + self.bpp.size()
+        let size: usize = 16 + self.pixmap.size() + self.drawable.size();
+        size + buffer_pad(size, 4)
     }
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.pixmap_fd)
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.pixmap_fd);
+    }
+    #[inline]
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.pixmap_fd = *fds.get(0)?;
+        Some(1)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PixmapFromBufferRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PixmapFromBufferRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PixmapFromBuffer";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PixmapFromBufferRequest`."]
+#[derive(Clone, Debug)]
+pub struct PixmapFromBufferRequestBuilder {
+    pub inner: PixmapFromBufferRequest,
+}
+impl PixmapFromBufferRequestBuilder {
+    #[inline]
+    pub fn pixmap(mut self, pixmap: Pixmap) -> Self {
+        self.inner.pixmap = pixmap;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn size(mut self, size: Card32) -> Self {
+        self.inner.size = size;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn stride(mut self, stride: Card16) -> Self {
+        self.inner.stride = stride;
+        self
+    }
+    #[inline]
+    pub fn depth(mut self, depth: Card8) -> Self {
+        self.inner.depth = depth;
+        self
+    }
+    #[inline]
+    pub fn bpp(mut self, bpp: Card8) -> Self {
+        self.inner.bpp = bpp;
+        self
+    }
+    #[inline]
+    pub fn pixmap_fd(mut self, pixmap_fd: Fd) -> Self {
+        self.inner.pixmap_fd = pixmap_fd;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PixmapFromBufferRequest {
+        self.inner
+    }
+}
+impl Default for PixmapFromBufferRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PixmapFromBufferRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                pixmap: Default::default(),
+                drawable: Default::default(),
+                size: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                stride: Default::default(),
+                depth: Default::default(),
+                bpp: Default::default(),
+                pixmap_fd: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct BufferFromPixmapRequest {
     pub req_type: u8,
     pub length: u16,
     pub pixmap: Pixmap,
 }
-impl BufferFromPixmapRequest {}
-impl AsByteSequence for BufferFromPixmapRequest {
+impl BufferFromPixmapRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.pixmap.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> BufferFromPixmapRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing BufferFromPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            BufferFromPixmapRequest {
-                req_type: req_type,
-                length: length,
-                pixmap: pixmap,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.pixmap.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<BufferFromPixmapReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<BufferFromPixmapRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_pixmap_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.pixmap.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (pixmap, sz): (Pixmap, usize) = parse_item(bytes, index, "pixmap")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        pixmap: pixmap,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.pixmap.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_pixmap_only_request_asb! { BufferFromPixmapRequest }
 impl Request for BufferFromPixmapRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "BufferFromPixmap";
     type Reply = BufferFromPixmapReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `BufferFromPixmapRequest`."]
+#[derive(Clone, Debug)]
+pub struct BufferFromPixmapRequestBuilder {
+    pub inner: BufferFromPixmapRequest,
+}
+impl BufferFromPixmapRequestBuilder {
+    #[inline]
+    pub fn pixmap(mut self, pixmap: Pixmap) -> Self {
+        self.inner.pixmap = pixmap;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> BufferFromPixmapRequest {
+        self.inner
+    }
+}
+impl Default for BufferFromPixmapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: BufferFromPixmapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                pixmap: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct BufferFromPixmapReply {
     pub reply_type: u8,
     pub nfd: Card8,
@@ -385,11 +812,67 @@ pub struct BufferFromPixmapReply {
     pub stride: Card16,
     pub depth: Card8,
     pub bpp: Card8,
-    pub pixmap_fd: Vec<Fd>,
+    pub pixmap_fd: Fd,
 }
-impl BufferFromPixmapReply {}
-impl AsByteSequence for BufferFromPixmapReply {
+impl BufferFromPixmapReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("nfd", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("size", 8, 4),
+        ("width", 12, 2),
+        ("height", 14, 2),
+        ("stride", 16, 2),
+        ("depth", 18, 1),
+        ("bpp", 19, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.nfd == other.nfd
+            && self.size == other.size
+            && self.width == other.width
+            && self.height == other.height
+            && self.stride == other.stride
+            && self.depth == other.depth
+            && self.bpp == other.bpp
+            && self.pixmap_fd == other.pixmap_fd
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("nfd", &self.nfd, &other.nfd, &mut changes);
+        diff_value("size", &self.size, &other.size, &mut changes);
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_value("stride", &self.stride, &other.stride, &mut changes);
+        diff_value("depth", &self.depth, &other.depth, &mut changes);
+        diff_value("bpp", &self.bpp, &other.bpp, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for BufferFromPixmapReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -402,35 +885,37 @@ impl AsByteSequence for BufferFromPixmapReply {
         index += self.stride.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += self.bpp.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing BufferFromPixmapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card32, usize) = parse_item(bytes, index, "size")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (stride, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (stride, sz): (Card16, usize) = parse_item(bytes, index, "stride")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
         index += sz;
         index += 12;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             BufferFromPixmapReply {
                 reply_type: reply_type,
                 nfd: nfd,
@@ -442,135 +927,225 @@ impl AsByteSequence for BufferFromPixmapReply {
                 stride: stride,
                 depth: depth,
                 bpp: bpp,
-                pixmap_fd: vec![],
+                pixmap_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.nfd.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.size.size()
-            + self.width.size()
-            + self.height.size()
-            + self.stride.size()
-            + self.depth.size()
-            + self.bpp.size()
-            + 12
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.pixmap_fd);
     }
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.pixmap_fd)
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.pixmap_fd = *fds.get(0)?;
+        Some(1)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for BufferFromPixmapReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FenceFromFdRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub fence: Card32,
     pub initially_triggered: bool,
-    pub fence_fd: Vec<Fd>,
+    pub fence_fd: Fd,
 }
-impl FenceFromFdRequest {}
-impl AsByteSequence for FenceFromFdRequest {
+impl FenceFromFdRequest {
     #[inline]
+    pub fn builder() -> FenceFromFdRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<FenceFromFdRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FenceFromFdRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.fence.as_bytes(&mut bytes[index..]);
         index += self.initially_triggered.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FenceFromFdRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (fence, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Card32, usize) = parse_item(bytes, index, "fence")?;
         index += sz;
-        let (initially_triggered, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (initially_triggered, sz): (bool, usize) =
+            parse_item(bytes, index, "initially_triggered")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FenceFromFdRequest {
                 req_type: req_type,
                 length: length,
                 drawable: drawable,
                 fence: fence,
                 initially_triggered: initially_triggered,
-                fence_fd: vec![],
+                fence_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.fence.size()
-            + self.initially_triggered.size()
-            + 3
+        let size: usize = 11 + self.drawable.size() + self.initially_triggered.size();
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.fence_fd);
     }
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.fence_fd)
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.fence_fd = *fds.get(0)?;
+        Some(1)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FenceFromFdRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FenceFromFdRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FenceFromFd";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FenceFromFdRequest`."]
+#[derive(Clone, Debug)]
+pub struct FenceFromFdRequestBuilder {
+    pub inner: FenceFromFdRequest,
+}
+impl FenceFromFdRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn fence(mut self, fence: Card32) -> Self {
+        self.inner.fence = fence;
+        self
+    }
+    #[inline]
+    pub fn initially_triggered(mut self, initially_triggered: bool) -> Self {
+        self.inner.initially_triggered = initially_triggered;
+        self
+    }
+    #[inline]
+    pub fn fence_fd(mut self, fence_fd: Fd) -> Self {
+        self.inner.fence_fd = fence_fd;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FenceFromFdRequest {
+        self.inner
+    }
+}
+impl Default for FenceFromFdRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FenceFromFdRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                fence: Default::default(),
+                initially_triggered: Default::default(),
+                fence_fd: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FdFromFenceRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub fence: Card32,
 }
-impl FdFromFenceRequest {}
-impl AsByteSequence for FdFromFenceRequest {
+impl FdFromFenceRequest {
+    #[inline]
+    pub fn builder() -> FdFromFenceRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<FdFromFenceReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<FdFromFenceRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FdFromFenceRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.fence.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FdFromFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (fence, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Card32, usize) = parse_item(bytes, index, "fence")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FdFromFenceRequest {
                 req_type: req_type,
                 length: length,
@@ -582,69 +1157,171 @@ impl AsByteSequence for FdFromFenceRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.fence.size()
+        let size: usize = 8 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FdFromFenceRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FdFromFenceRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "FdFromFence";
     type Reply = FdFromFenceReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FdFromFenceRequest`."]
+#[derive(Clone, Debug)]
+pub struct FdFromFenceRequestBuilder {
+    pub inner: FdFromFenceRequest,
+}
+impl FdFromFenceRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn fence(mut self, fence: Card32) -> Self {
+        self.inner.fence = fence;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FdFromFenceRequest {
+        self.inner
+    }
+}
+impl Default for FdFromFenceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FdFromFenceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                fence: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct FdFromFenceReply {
     pub reply_type: u8,
     pub nfd: Card8,
     pub sequence: u16,
     pub length: u32,
-    pub fence_fd: Vec<Fd>,
+    pub fence_fd: Fd,
 }
-impl FdFromFenceReply {}
-impl AsByteSequence for FdFromFenceReply {
+impl FdFromFenceReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("nfd", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.nfd == other.nfd && self.fence_fd == other.fence_fd
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("nfd", &self.nfd, &other.nfd, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for FdFromFenceReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
         index += self.nfd.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
-        index += 24;
+        index += zero_pad(&mut bytes[index..], 24);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FdFromFenceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         index += 24;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FdFromFenceReply {
                 reply_type: reply_type,
                 nfd: nfd,
                 sequence: sequence,
                 length: length,
-                fence_fd: vec![],
+                fence_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + self.nfd.size() + self.sequence.size() + self.length.size() + 24
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.fence_fd)
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.fence_fd);
+    }
+    #[inline]
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.fence_fd = *fds.get(0)?;
+        Some(1)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FdFromFenceReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetSupportedModifiersRequest {
     pub req_type: u8,
     pub length: u16,
@@ -652,37 +1329,65 @@ pub struct GetSupportedModifiersRequest {
     pub depth: Card8,
     pub bpp: Card8,
 }
-impl GetSupportedModifiersRequest {}
-impl AsByteSequence for GetSupportedModifiersRequest {
+impl GetSupportedModifiersRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> GetSupportedModifiersRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetSupportedModifiersReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("window", 4, 4),
+        ("depth", 8, 1),
+        ("bpp", 9, 1),
+    ];
+}
+const _: () = assert!(<GetSupportedModifiersRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetSupportedModifiersRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += self.bpp.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSupportedModifiersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Card32, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetSupportedModifiersRequest {
                 req_type: req_type,
                 length: length,
@@ -695,22 +1400,69 @@ impl AsByteSequence for GetSupportedModifiersRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.window.size()
-            + self.depth.size()
-            + self.bpp.size()
-            + 2
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetSupportedModifiersRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetSupportedModifiersRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSupportedModifiers";
     type Reply = GetSupportedModifiersReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetSupportedModifiersRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetSupportedModifiersRequestBuilder {
+    pub inner: GetSupportedModifiersRequest,
+}
+impl GetSupportedModifiersRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Card32) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn depth(mut self, depth: Card8) -> Self {
+        self.inner.depth = depth;
+        self
+    }
+    #[inline]
+    pub fn bpp(mut self, bpp: Card8) -> Self {
+        self.inner.bpp = bpp;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetSupportedModifiersRequest {
+        self.inner
+    }
+}
+impl Default for GetSupportedModifiersRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetSupportedModifiersRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                depth: Default::default(),
+                bpp: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetSupportedModifiersReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -718,51 +1470,179 @@ pub struct GetSupportedModifiersReply {
     pub window_modifiers: Vec<Card64>,
     pub screen_modifiers: Vec<Card64>,
 }
-impl GetSupportedModifiersReply {}
-impl AsByteSequence for GetSupportedModifiersReply {
+impl GetSupportedModifiersReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn window_modifiers_slice(bytes: &[u8]) -> Result<Cow<'_, [Card64]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        index += 16;
+        let (window_modifiers, _): (Cow<'_, [Card64]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "window_modifiers")?;
+        Ok(window_modifiers)
+    }
+    pub fn screen_modifiers_slice(bytes: &[u8]) -> Result<Cow<'_, [Card64]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        index += 16;
+        let (window_modifiers, block_len): (Vec<Card64>, usize) =
+            parse_vector(bytes, index, len0 as usize, "window_modifiers")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card64>());
+        let (screen_modifiers, _): (Cow<'_, [Card64]>, usize) =
+            parse_slice(bytes, index, len1 as usize, "screen_modifiers")?;
+        Ok(screen_modifiers)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        let len1: Card32 = read_item(reader, &mut index, 4, "len1")?;
+        skip_bytes(reader, &mut index, 16)?;
+        let (window_modifiers, block_len): (Vec<Card64>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 8, "window_modifiers")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card64>()),
+        )?;
+        let (screen_modifiers, block_len): (Vec<Card64>, usize) =
+            read_vector(reader, &mut index, len1 as usize, 8, "screen_modifiers")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card64>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetSupportedModifiersReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            window_modifiers: window_modifiers,
+            screen_modifiers: screen_modifiers,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("window_modifiers_len", 8, 4),
+        ("screen_modifiers_len", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.window_modifiers == other.window_modifiers
+            && self.screen_modifiers == other.screen_modifiers
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "window_modifiers",
+            &self.window_modifiers,
+            &other.window_modifiers,
+            &mut changes,
+        );
+        diff_list(
+            "screen_modifiers",
+            &self.screen_modifiers,
+            &other.screen_modifiers,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetSupportedModifiersReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.window_modifiers.len() as Card32).as_bytes(&mut bytes[index..]);
         index += (self.screen_modifiers.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         let block_len: usize = vector_as_bytes(&self.window_modifiers, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card64>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card64>()),
+        );
         let block_len: usize = vector_as_bytes(&self.screen_modifiers, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card64>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card64>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSupportedModifiersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         index += 16;
         let (window_modifiers, block_len): (Vec<Card64>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "window_modifiers")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card64>());
         let (screen_modifiers, block_len): (Vec<Card64>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "screen_modifiers")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card64>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetSupportedModifiersReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -773,28 +1653,46 @@ impl AsByteSequence for GetSupportedModifiersReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
-            + 16
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.window_modifiers.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card64>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.screen_modifiers.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card64>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            32 + {
+                let block_len: usize =
+                    self.window_modifiers.len() * ::core::mem::size_of::<Card64>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card64>());
+                block_len + pad
+            } + {
+                let block_len: usize =
+                    self.screen_modifiers.len() * ::core::mem::size_of::<Card64>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card64>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetSupportedModifiersReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PixmapFromBuffersRequest {
     pub req_type: u8,
     pub length: u16,
@@ -815,18 +1713,25 @@ pub struct PixmapFromBuffersRequest {
     pub modifier: Card64,
     pub buffers: Vec<Fd>,
 }
-impl PixmapFromBuffersRequest {}
-impl AsByteSequence for PixmapFromBuffersRequest {
+impl PixmapFromBuffersRequest {
     #[inline]
+    pub fn builder() -> PixmapFromBuffersRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<PixmapFromBuffersRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PixmapFromBuffersRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.pixmap.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += (self.buffers.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.stride0.as_bytes(&mut bytes[index..]);
@@ -839,61 +1744,65 @@ impl AsByteSequence for PixmapFromBuffersRequest {
         index += self.offset3.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += self.bpp.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.modifier.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.buffers, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fd>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixmapFromBuffersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = parse_item(bytes, index, "pixmap")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 3;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (stride0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride0, sz): (Card32, usize) = parse_item(bytes, index, "stride0")?;
         index += sz;
-        let (offset0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset0, sz): (Card32, usize) = parse_item(bytes, index, "offset0")?;
         index += sz;
-        let (stride1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride1, sz): (Card32, usize) = parse_item(bytes, index, "stride1")?;
         index += sz;
-        let (offset1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset1, sz): (Card32, usize) = parse_item(bytes, index, "offset1")?;
         index += sz;
-        let (stride2, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride2, sz): (Card32, usize) = parse_item(bytes, index, "stride2")?;
         index += sz;
-        let (offset2, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset2, sz): (Card32, usize) = parse_item(bytes, index, "offset2")?;
         index += sz;
-        let (stride3, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride3, sz): (Card32, usize) = parse_item(bytes, index, "stride3")?;
         index += sz;
-        let (offset3, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset3, sz): (Card32, usize) = parse_item(bytes, index, "offset3")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
         index += sz;
         index += 2;
-        let (modifier, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (modifier, sz): (Card64, usize) = parse_item(bytes, index, "modifier")?;
         index += sz;
         let (buffers, block_len): (Vec<Fd>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "buffers")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PixmapFromBuffersRequest {
                 req_type: req_type,
                 length: length,
@@ -919,89 +1828,228 @@ impl AsByteSequence for PixmapFromBuffersRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.pixmap.size()
-            + self.window.size()
-            + ::core::mem::size_of::<Card8>()
-            + 3
-            + self.width.size()
-            + self.height.size()
-            + self.stride0.size()
-            + self.offset0.size()
-            + self.stride1.size()
-            + self.offset1.size()
-            + self.stride2.size()
-            + self.offset2.size()
-            + self.stride3.size()
-            + self.offset3.size()
-            + self.depth.size()
-            + self.bpp.size()
-            + 2
-            + self.modifier.size()
-            + {
-                let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fd>());
-                block_len + pad
-            }
+        let size: usize = 56 + self.pixmap.size() + self.window.size() + {
+            let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fd>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PixmapFromBuffersRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PixmapFromBuffersRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PixmapFromBuffers";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PixmapFromBuffersRequest`."]
+#[derive(Clone, Debug)]
+pub struct PixmapFromBuffersRequestBuilder {
+    pub inner: PixmapFromBuffersRequest,
+}
+impl PixmapFromBuffersRequestBuilder {
+    #[inline]
+    pub fn pixmap(mut self, pixmap: Pixmap) -> Self {
+        self.inner.pixmap = pixmap;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn stride0(mut self, stride0: Card32) -> Self {
+        self.inner.stride0 = stride0;
+        self
+    }
+    #[inline]
+    pub fn offset0(mut self, offset0: Card32) -> Self {
+        self.inner.offset0 = offset0;
+        self
+    }
+    #[inline]
+    pub fn stride1(mut self, stride1: Card32) -> Self {
+        self.inner.stride1 = stride1;
+        self
+    }
+    #[inline]
+    pub fn offset1(mut self, offset1: Card32) -> Self {
+        self.inner.offset1 = offset1;
+        self
+    }
+    #[inline]
+    pub fn stride2(mut self, stride2: Card32) -> Self {
+        self.inner.stride2 = stride2;
+        self
+    }
+    #[inline]
+    pub fn offset2(mut self, offset2: Card32) -> Self {
+        self.inner.offset2 = offset2;
+        self
+    }
+    #[inline]
+    pub fn stride3(mut self, stride3: Card32) -> Self {
+        self.inner.stride3 = stride3;
+        self
+    }
+    #[inline]
+    pub fn offset3(mut self, offset3: Card32) -> Self {
+        self.inner.offset3 = offset3;
+        self
+    }
+    #[inline]
+    pub fn depth(mut self, depth: Card8) -> Self {
+        self.inner.depth = depth;
+        self
+    }
+    #[inline]
+    pub fn bpp(mut self, bpp: Card8) -> Self {
+        self.inner.bpp = bpp;
+        self
+    }
+    #[inline]
+    pub fn modifier(mut self, modifier: Card64) -> Self {
+        self.inner.modifier = modifier;
+        self
+    }
+    #[inline]
+    pub fn buffers(mut self, buffers: Vec<Fd>) -> Self {
+        self.inner.buffers = buffers;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PixmapFromBuffersRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Fd> for PixmapFromBuffersRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Fd>>(&mut self, iter: I) {
+        self.inner.buffers.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Fd> for PixmapFromBuffersRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Fd>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for PixmapFromBuffersRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PixmapFromBuffersRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                pixmap: Default::default(),
+                window: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                stride0: Default::default(),
+                offset0: Default::default(),
+                stride1: Default::default(),
+                offset1: Default::default(),
+                stride2: Default::default(),
+                offset2: Default::default(),
+                stride3: Default::default(),
+                offset3: Default::default(),
+                depth: Default::default(),
+                bpp: Default::default(),
+                modifier: Default::default(),
+                buffers: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct BuffersFromPixmapRequest {
     pub req_type: u8,
     pub length: u16,
     pub pixmap: Pixmap,
 }
-impl BuffersFromPixmapRequest {}
-impl AsByteSequence for BuffersFromPixmapRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.pixmap.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl BuffersFromPixmapRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing BuffersFromPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            BuffersFromPixmapRequest {
-                req_type: req_type,
-                length: length,
-                pixmap: pixmap,
-            },
-            index,
-        ))
+    pub fn builder() -> BuffersFromPixmapRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.pixmap.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<BuffersFromPixmapReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<BuffersFromPixmapRequest as Request>::MINOR_OPCODE < 0x100);
+impl_pixmap_only_request_asb! { BuffersFromPixmapRequest }
 impl Request for BuffersFromPixmapRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "BuffersFromPixmap";
     type Reply = BuffersFromPixmapReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `BuffersFromPixmapRequest`."]
+#[derive(Clone, Debug)]
+pub struct BuffersFromPixmapRequestBuilder {
+    pub inner: BuffersFromPixmapRequest,
+}
+impl BuffersFromPixmapRequestBuilder {
+    #[inline]
+    pub fn pixmap(mut self, pixmap: Pixmap) -> Self {
+        self.inner.pixmap = pixmap;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> BuffersFromPixmapRequest {
+        self.inner
+    }
+}
+impl Default for BuffersFromPixmapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: BuffersFromPixmapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                pixmap: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct BuffersFromPixmapReply {
     pub reply_type: u8,
     pub nfd: Card8,
@@ -1016,9 +2064,119 @@ pub struct BuffersFromPixmapReply {
     pub offsets: Vec<Card32>,
     pub buffers: Vec<Fd>,
 }
-impl BuffersFromPixmapReply {}
-impl AsByteSequence for BuffersFromPixmapReply {
+impl BuffersFromPixmapReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn strides_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        index += 4;
+        let (modifier, sz): (Card64, usize) = parse_item(bytes, index, "modifier")?;
+        index += sz;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
+        index += sz;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
+        index += sz;
+        index += 6;
+        let (strides, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (nfd as usize) as usize, "strides")?;
+        Ok(strides)
+    }
+    pub fn offsets_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        index += 4;
+        let (modifier, sz): (Card64, usize) = parse_item(bytes, index, "modifier")?;
+        index += sz;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
+        index += sz;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
+        index += sz;
+        index += 6;
+        let (strides, block_len): (Vec<Card32>, usize) =
+            parse_vector(bytes, index, (nfd as usize) as usize, "strides")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        let (offsets, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (nfd as usize) as usize, "offsets")?;
+        Ok(offsets)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("nfd", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("width", 8, 2),
+        ("height", 10, 2),
+        ("modifier", 16, 8),
+        ("depth", 24, 1),
+        ("bpp", 25, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.nfd == other.nfd
+            && self.width == other.width
+            && self.height == other.height
+            && self.modifier == other.modifier
+            && self.depth == other.depth
+            && self.bpp == other.bpp
+            && self.strides == other.strides
+            && self.offsets == other.offsets
+            && self.buffers == other.buffers
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("nfd", &self.nfd, &other.nfd, &mut changes);
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_value("modifier", &self.modifier, &other.modifier, &mut changes);
+        diff_value("depth", &self.depth, &other.depth, &mut changes);
+        diff_value("bpp", &self.bpp, &other.bpp, &mut changes);
+        diff_list("strides", &self.strides, &other.strides, &mut changes);
+        diff_list("offsets", &self.offsets, &other.offsets, &mut changes);
+        diff_list("buffers", &self.buffers, &other.buffers, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for BuffersFromPixmapReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -1027,59 +2185,70 @@ impl AsByteSequence for BuffersFromPixmapReply {
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index += self.modifier.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += self.bpp.as_bytes(&mut bytes[index..]);
-        index += 6;
+        index += zero_pad(&mut bytes[index..], 6);
         let block_len: usize = vector_as_bytes(&self.strides, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = vector_as_bytes(&self.offsets, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = vector_as_bytes(&self.buffers, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fd>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing BuffersFromPixmapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
         index += 4;
-        let (modifier, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (modifier, sz): (Card64, usize) = parse_item(bytes, index, "modifier")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
         index += sz;
         index += 6;
         let (strides, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (nfd as usize) as usize)?;
+            parse_vector(bytes, index, (nfd as usize) as usize, "strides")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (offsets, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (nfd as usize) as usize)?;
+            parse_vector(bytes, index, (nfd as usize) as usize, "offsets")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (buffers, block_len): (Vec<Fd>, usize) =
-            vector_from_bytes(&bytes[index..], (nfd as usize) as usize)?;
+            parse_vector(bytes, index, (nfd as usize) as usize, "buffers")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             BuffersFromPixmapReply {
                 reply_type: reply_type,
                 nfd: nfd,
@@ -1097,33 +2266,115 @@ impl AsByteSequence for BuffersFromPixmapReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.nfd.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.width.size()
-            + self.height.size()
-            + 4
-            + self.modifier.size()
-            + self.depth.size()
-            + self.bpp.size()
-            + 6
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.strides.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.offsets.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fd>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            32 + {
+                let block_len: usize = self.strides.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.offsets.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fd>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for BuffersFromPixmapReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for BuffersFromPixmapReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let nfd: Card8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let width: Card16 = u.arbitrary()?;
+        let height: Card16 = u.arbitrary()?;
+        let modifier: Card64 = u.arbitrary()?;
+        let depth: Card8 = u.arbitrary()?;
+        let bpp: Card8 = u.arbitrary()?;
+        let strides = arbitrary_list(u, (nfd as usize) as usize)?;
+        let offsets = arbitrary_list(u, (nfd as usize) as usize)?;
+        let buffers = arbitrary_list(u, (nfd as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            nfd: nfd,
+            sequence: sequence,
+            length: length,
+            width: width,
+            height: height,
+            modifier: modifier,
+            depth: depth,
+            bpp: bpp,
+            strides: strides,
+            offsets: offsets,
+            buffers: buffers,
+        })
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "Open"),
+    (2, "PixmapFromBuffer"),
+    (3, "BufferFromPixmap"),
+    (4, "FenceFromFd"),
+    (5, "FdFromFence"),
+    (6, "GetSupportedModifiers"),
+    (7, "PixmapFromBuffers"),
+    (8, "BuffersFromPixmap"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
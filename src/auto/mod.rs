@@ -7,21 +7,44 @@
 
 #![allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
 
-use super::Fd;
+use super::{property_data::PropertyData, Fd, XError};
 use alloc::{
+    borrow::Cow,
     string::{String, ToString},
     vec::Vec,
 };
-use core::mem;
+use core::{cmp, fmt, iter::FusedIterator, marker::PhantomData, mem};
+#[cfg(feature = "std")]
+use std::{
+    error::Error as StdError,
+    io::{self, Read},
+};
+#[cfg(feature = "std")]
+use tinyvec::TinyVec;
 
 #[allow(dead_code)]
 pub(crate) mod prelude {
+    #[cfg(feature = "fuzzing")]
+    pub(crate) use super::{arbitrary_list, arbitrary_string};
     pub(crate) use super::{
-        buffer_pad, string_as_bytes, string_from_bytes, vector_as_bytes, vector_from_bytes,
-        AsByteSequence,
+        buffer_pad, debug_bytes, diff_list, diff_property_data, diff_value, generic_event_bytes,
+        name_of_opcode, padded_vector_as_bytes, parse_item, parse_padded_vector,
+        parse_property_data, parse_remainder, parse_reply_or_error, parse_reply_strict,
+        parse_slice, parse_string, parse_vector, reply_bytes, string16, string_as_bytes,
+        unreadable, vector_as_bytes, zero_pad, AsByteSequence, BuildError, FieldChange, ListIter,
+        ParseError, StrictParseError, ValidationError,
+    };
+    pub(crate) use alloc::borrow::Cow;
+    // only XKB has lists with one item for every bit of a mask
+    #[cfg(feature = "xkb")]
+    pub(crate) use super::mask_bits;
+    #[cfg(feature = "std")]
+    pub(crate) use super::{read_item, read_string, read_vector, skip_bytes, skip_to_reply_end};
+    pub use crate::{
+        client_message_data::ClientMessageData, property_data::PropertyData, Fd, Request, XError,
+        XidType, XID,
     };
-    pub use crate::{client_message_data::ClientMessageData, Fd, Request, XidType, XID};
-    pub use alloc::{string::String, vec, vec::Vec};
+    pub use alloc::{string::String, vec::Vec};
     pub use core::convert::TryInto;
     pub use cty::c_char;
     pub type Card8 = u8;
@@ -54,16 +77,222 @@ pub trait AsByteSequence: Sized {
     /// Get the size needed to store this item in terms of bytes. Higher is better than lower here,
     /// since this is mostly used to allocate buffers for items.
     fn size(&self) -> usize;
+    /// Get the size needed to store this item in terms of bytes, without visiting lists whose
+    /// elements all have the same size. This is the same as `size`, but may be cheaper to find.
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.size()
+    }
     /// Append this item to a sequence of bytes.
     fn as_bytes(&self, bytes: &mut [u8]) -> usize;
-    /// Convert a sequence of bytes into this item.
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)>;
-    /// If this item has any file descriptors stored within, this function returns them.
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        None
+    /// Write this item into the start of a buffer, returning the number of bytes written. Unlike
+    /// `as_bytes`, this checks that the buffer is large enough to hold the item first, so one
+    /// buffer can be reused for many items.
+    #[inline]
+    fn serialize_into(&self, bytes: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let needed = self.size();
+        if bytes.len() < needed {
+            return Err(BufferTooSmall {
+                needed,
+                available: bytes.len(),
+            });
+        }
+
+        Ok(self.as_bytes(bytes))
+    }
+    /// Convert a sequence of bytes into this item, reporting where the conversion failed if it
+    /// could not be completed. The generated structures report the field that failed and its
+    /// offset; items without fields report their type name at offset zero.
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError>;
+    /// Append the file descriptors stored in this item to a list of file descriptors. These are not a
+    /// part of the sequence of bytes, and are sent alongside it.
+    #[inline]
+    fn as_fds(&self, _fds: &mut Vec<Fd>) {}
+    /// Take the file descriptors received alongside this item's sequence of bytes, in order. Returns
+    /// the number of file descriptors taken, or `None` if there are fewer than this item needs.
+    #[inline]
+    fn take_fds(&mut self, _fds: &[Fd]) -> Option<usize> {
+        Some(0)
+    }
+}
+
+/// An error that occurred while converting a sequence of bytes into an item.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The name of the field that could not be read.
+    pub field: &'static str,
+    /// The offset into the sequence of bytes where the field begins.
+    pub offset: usize,
+}
+
+/// Internal use function to report that an item without fields couldn't be read from the start of a
+/// sequence of bytes. There is no field to name, so the item's type is named instead.
+#[inline]
+pub(crate) fn unreadable<T>() -> ParseError {
+    ParseError {
+        field: core::any::type_name::<T>(),
+        offset: 0,
+    }
+}
+
+impl fmt::Display for ParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unable to read field \"{}\" at byte offset {}",
+            self.field, self.offset
+        )
     }
 }
 
+#[cfg(feature = "std")]
+impl StdError for ParseError {}
+
+/// An error that occurred while converting an item into a sequence of bytes, because the buffer it
+/// was written into was too small to hold it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// The number of bytes needed to hold the item.
+    pub needed: usize,
+    /// The number of bytes in the buffer.
+    pub available: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Buffer of {} bytes is too small to hold an item of {} bytes",
+            self.available, self.needed
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for BufferTooSmall {}
+
+/// An error that occurred while checking an item before converting it into a sequence of bytes,
+/// because one of its lists does not have the length that its other fields describe.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The name of the list.
+    pub list: &'static str,
+    /// The length that the other fields describe.
+    pub expected: usize,
+    /// The length of the list.
+    pub found: usize,
+}
+
+impl fmt::Display for ValidationError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "List \"{}\" should have {} elements, but it has {}",
+            self.list, self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for ValidationError {}
+
+/// An error that occurred while setting a field of a builder, because the protocol does not allow the
+/// field to hold the given value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BuildError {
+    /// The name of the field.
+    pub field: &'static str,
+    /// The value that the field was given.
+    pub value: u32,
+}
+
+impl fmt::Display for BuildError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Field \"{}\" cannot be set to {}",
+            self.field, self.value
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for BuildError {}
+
+/// A difference between two values of the same reply, as found by the reply's `diff` method.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldChange {
+    /// A field holds a different value. The values are given as the bytes they are sent as, so that
+    /// fields of all types can be described the same way.
+    Value {
+        /// The name of the field.
+        field: &'static str,
+        /// The bytes of the field's value in the first reply.
+        old: Vec<u8>,
+        /// The bytes of the field's value in the second reply.
+        new: Vec<u8>,
+    },
+    /// A list holds a different number of items.
+    Length {
+        /// The name of the list.
+        field: &'static str,
+        /// The number of items in the list of the first reply.
+        old: usize,
+        /// The number of items in the list of the second reply.
+        new: usize,
+    },
+    /// A list holds as many items as before, but some of them are different.
+    Items {
+        /// The name of the list.
+        field: &'static str,
+        /// The indices of the items that are different, from lowest to highest.
+        indices: Vec<usize>,
+    },
+}
+
+/// An error that occurred while strictly converting a sequence of bytes into a reply.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StrictParseError {
+    /// The reply could not be read at all.
+    Parse(ParseError),
+    /// The reply was read, but the number of bytes that its fields take up is not the number of bytes
+    /// that its length describes. This usually means that the reply's description is missing a field.
+    TrailingBytes {
+        /// The number of bytes that the reply's length describes.
+        expected: usize,
+        /// The number of bytes that the reply's fields take up.
+        actual: usize,
+    },
+}
+
+impl From<ParseError> for StrictParseError {
+    #[inline]
+    fn from(pe: ParseError) -> Self {
+        Self::Parse(pe)
+    }
+}
+
+impl fmt::Display for StrictParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(pe) => fmt::Display::fmt(pe, f),
+            Self::TrailingBytes { expected, actual } => write!(
+                f,
+                "Reply should be {} bytes long, but its fields take up {} bytes",
+                expected, actual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for StrictParseError {}
+
 /// An error.
 pub trait Error: AsByteSequence {
     const OPCODE: u8;
@@ -85,13 +314,13 @@ pub(crate) fn vector_from_bytes<T: AsByteSequence>(
     #[cfg(debug_assertions)]
     log::trace!("Deserializing vector of byte length {} from bytes", len);
 
-    // allocate the vector
-    let mut items: Vec<T> = Vec::with_capacity(len);
+    // allocate the vector, but no further than the bytes can back up, since the length may be corrupt
+    let mut items: Vec<T> = Vec::with_capacity(cmp::min(len, bytes.len()));
 
     // pull items from the bytes vector and create elements
     let mut current_index = 0;
     for _ in 0..len {
-        let (item, sz) = T::from_bytes(&bytes[current_index..])?;
+        let (item, sz) = T::from_bytes(bytes.get(current_index..)?).ok()?;
         items.push(item);
         current_index += sz;
     }
@@ -122,6 +351,388 @@ pub(crate) fn string_from_bytes(bytes: &[u8], len: usize) -> Option<(String, usi
     }
 }
 
+/// Internal use function to read a single item starting at `index`, checking that the index is in
+/// bounds.
+#[inline]
+pub(crate) fn parse_item<T: AsByteSequence>(
+    bytes: &[u8],
+    index: usize,
+    field: &'static str,
+) -> Result<(T, usize), ParseError> {
+    bytes
+        .get(index..)
+        .and_then(|bytes| T::from_bytes(bytes).ok())
+        .ok_or(ParseError {
+            field,
+            offset: index,
+        })
+}
+
+/// Internal use function to read a vector of `len` items starting at `index`, checking that the
+/// items are in bounds. Errors point at the item that couldn't be read.
+#[inline]
+pub(crate) fn parse_vector<T: AsByteSequence>(
+    bytes: &[u8],
+    index: usize,
+    len: usize,
+    field: &'static str,
+) -> Result<(Vec<T>, usize), ParseError> {
+    parse_padded_vector(bytes, index, len, 1, field)
+}
+
+/// Internal use function to read `len` items starting at `index`, which are as wide as `format`
+/// says. Formats that items can't have are refused, as are items that run past the bytes.
+#[inline]
+pub(crate) fn parse_property_data(
+    bytes: &[u8],
+    index: usize,
+    format: u8,
+    len: usize,
+    field: &'static str,
+) -> Result<(PropertyData, usize), ParseError> {
+    bytes
+        .get(index..)
+        .and_then(|bytes| PropertyData::from_bytes_with_format(bytes, format, len))
+        .ok_or(ParseError {
+            field,
+            offset: index,
+        })
+}
+
+/// Internal use function to read a vector of `len` items starting at `index`, where each item is
+/// padded out to a multiple of `align` bytes.
+#[inline]
+pub(crate) fn parse_padded_vector<T: AsByteSequence>(
+    bytes: &[u8],
+    index: usize,
+    len: usize,
+    align: usize,
+    field: &'static str,
+) -> Result<(Vec<T>, usize), ParseError> {
+    let mut items: Vec<T> = Vec::with_capacity(cmp::min(len, bytes.len().saturating_sub(index)));
+    let mut current_index = index;
+    for _ in 0..len {
+        let (item, sz) = parse_item(bytes, current_index, field)?;
+        items.push(item);
+        current_index += sz + buffer_pad(sz, align);
+    }
+
+    Ok((items, current_index - index))
+}
+
+/// Internal use function to read items starting at `index` until `len` bytes have been read, checking
+/// that the items are in bounds. This is used for the lists that take up the rest of a request. An item
+/// that is read from no bytes would be read forever, so it is an error.
+#[inline]
+pub(crate) fn parse_remainder<T: AsByteSequence>(
+    bytes: &[u8],
+    index: usize,
+    len: usize,
+    field: &'static str,
+) -> Result<(Vec<T>, usize), ParseError> {
+    let error = |offset| ParseError { field, offset };
+    let bytes = bytes.get(index..index + len).ok_or_else(|| error(index))?;
+
+    let mut items = Vec::new();
+    let mut read = 0;
+    while read < len {
+        let (item, sz) = T::from_bytes(&bytes[read..])
+            .ok()
+            .filter(|(_, sz)| *sz != 0)
+            .ok_or_else(|| error(index + read))?;
+        items.push(item);
+        read += sz;
+    }
+
+    Ok((items, read))
+}
+
+/// Internal use function to borrow `len` items starting at `index` without copying them, checking
+/// that the items are in bounds. If the items aren't properly aligned for `T`, they are copied instead.
+#[inline]
+pub(crate) fn parse_slice<'a, T: bytemuck::Pod>(
+    bytes: &'a [u8],
+    index: usize,
+    len: usize,
+    field: &'static str,
+) -> Result<(Cow<'a, [T]>, usize), ParseError> {
+    let block_len = len * mem::size_of::<T>();
+    let block = bytes.get(index..index + block_len).ok_or(ParseError {
+        field,
+        offset: index,
+    })?;
+    let slice = match bytemuck::try_cast_slice(block) {
+        Ok(slice) => Cow::Borrowed(slice),
+        Err(_) => {
+            let mut items = alloc::vec![T::zeroed(); len];
+            bytemuck::cast_slice_mut(&mut items).copy_from_slice(block);
+            Cow::Owned(items)
+        }
+    };
+    Ok((slice, block_len))
+}
+
+/// An iterator over the items of a list, which parses each item out of the bytes of its structure when
+/// it is reached. A list of large structures can be read this way without collecting all of them into
+/// a `Vec` first. If an item can't be parsed, the error is returned and the iterator ends.
+#[derive(Debug, Clone)]
+pub struct ListIter<'a, T> {
+    bytes: &'a [u8],
+    index: usize,
+    remaining: usize,
+    align: usize,
+    field: &'static str,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> ListIter<'a, T> {
+    #[inline]
+    pub(crate) fn new(bytes: &'a [u8], index: usize, len: usize, field: &'static str) -> Self {
+        Self::padded(bytes, index, len, 1, field)
+    }
+
+    /// Iterate over a list whose items are each padded out to a multiple of `align` bytes.
+    #[inline]
+    pub(crate) fn padded(
+        bytes: &'a [u8],
+        index: usize,
+        len: usize,
+        align: usize,
+        field: &'static str,
+    ) -> Self {
+        Self {
+            bytes,
+            index,
+            remaining: len,
+            align,
+            field,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: AsByteSequence> Iterator for ListIter<'a, T> {
+    type Item = Result<T, ParseError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        match parse_item(self.bytes, self.index, self.field) {
+            Ok((item, sz)) => {
+                self.index += sz + buffer_pad(sz, self.align);
+                self.remaining -= 1;
+                Some(Ok(item))
+            }
+            Err(e) => {
+                self.remaining = 0;
+                Some(Err(e))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<'a, T: AsByteSequence> FusedIterator for ListIter<'a, T> {}
+
+/// Internal use function to read a string of length `len` starting at `index`, checking that the
+/// string is in bounds.
+#[inline]
+pub(crate) fn parse_string(
+    bytes: &[u8],
+    index: usize,
+    len: usize,
+    field: &'static str,
+) -> Result<(String, usize), ParseError> {
+    match bytes.get(index..) {
+        Some(bytes) if bytes.len() >= len => string_from_bytes(bytes, len),
+        _ => None,
+    }
+    .ok_or(ParseError {
+        field,
+        offset: index,
+    })
+}
+
+/// Internal use function to cut a sequence of bytes down to the reply at its start, according to the
+/// `length` in the reply's header. Bytes past the end of the reply are never read as a part of it, even
+/// if the fields of the reply say that they should be; those fields fail to parse instead. If there
+/// are fewer bytes than the reply announces, they are left as they are.
+#[inline]
+pub(crate) fn reply_bytes(bytes: &[u8], length: u32) -> &[u8] {
+    let end = (length as usize)
+        .checked_mul(4)
+        .and_then(|len| len.checked_add(32))
+        .unwrap_or(usize::MAX);
+    &bytes[..end.min(bytes.len())]
+}
+
+/// The response type of every generic event. An extension tells its generic events apart by the
+/// event type that follows their length.
+const GENERIC_EVENT: u8 = 35;
+
+/// Internal use function to tell whether a sequence of bytes holds a generic event. If it does, this
+/// gives the event type of the event and the bytes it occupies according to the `length` in its
+/// header, since generic events may be longer than the 32 bytes other events take up.
+#[inline]
+pub(crate) fn generic_event_bytes(bytes: &[u8]) -> Result<Option<(u16, &[u8])>, ParseError> {
+    match bytes.first() {
+        Some(response_type) if response_type & 0x7f == GENERIC_EVENT => {
+            let (length, _): (u32, usize) = parse_item(bytes, 4, "length")?;
+            let (evtype, _): (u16, usize) = parse_item(bytes, 8, "evtype")?;
+            Ok(Some((evtype, reply_bytes(bytes, length))))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Internal use function to read a reply, and check that its fields take up all of the bytes that the
+/// length in its header describes. Replies are 32 bytes long, plus `length` 4-byte units; fields that
+/// end before the first 32 bytes do are followed by padding.
+#[inline]
+pub(crate) fn parse_reply_strict<T: AsByteSequence>(
+    bytes: &[u8],
+) -> Result<(T, usize), StrictParseError> {
+    let (length, _): (u32, usize) = parse_item(bytes, 4, "length")?;
+    let (reply, sz) = T::from_bytes(bytes)?;
+
+    let expected = 32 + (length as usize * 4);
+    let actual = sz.max(32);
+    if actual == expected {
+        Ok((reply, sz))
+    } else {
+        Err(StrictParseError::TrailingBytes { expected, actual })
+    }
+}
+
+/// Internal use function to read what the server sent in response to a request, which is either the
+/// reply or an error. Errors have a response type of 0, while replies have a response type of 1.
+#[inline]
+pub(crate) fn parse_reply_or_error<T: AsByteSequence>(
+    bytes: &[u8],
+) -> Result<Result<T, XError>, ParseError> {
+    match bytes.first() {
+        Some(0) => XError::from_bytes(bytes).map(Err).ok_or(ParseError {
+            field: "error_code",
+            offset: 1,
+        }),
+        Some(_) => T::from_bytes(bytes).map(|(reply, _)| Ok(reply)),
+        None => Err(ParseError {
+            field: "response_type",
+            offset: 0,
+        }),
+    }
+}
+
+/// The number of bytes that the streaming parsers read from the reader at once, at most.
+#[cfg(feature = "std")]
+const READ_CHUNK_SIZE: usize = 4096;
+
+#[cfg(feature = "std")]
+#[inline]
+fn read_error(field: &'static str, offset: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, ParseError { field, offset })
+}
+
+/// Internal use function to read an item that always takes up `size` bytes out of a reader, and
+/// advance `index` past it.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn read_item<T: AsByteSequence>(
+    reader: &mut dyn Read,
+    index: &mut usize,
+    size: usize,
+    field: &'static str,
+) -> io::Result<T> {
+    let mut buffer: TinyVec<[u8; 32]> = TinyVec::new();
+    buffer.resize(size, 0);
+    reader.read_exact(&mut buffer)?;
+
+    let (item, _) = T::from_bytes(&buffer).map_err(|_| read_error(field, *index))?;
+    *index += size;
+    Ok(item)
+}
+
+/// Internal use function to read `len` items that each take up `size` bytes out of a reader. Only
+/// a small chunk of the list is buffered at a time.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn read_vector<T: AsByteSequence>(
+    reader: &mut dyn Read,
+    index: &mut usize,
+    len: usize,
+    size: usize,
+    field: &'static str,
+) -> io::Result<(Vec<T>, usize)> {
+    let per_chunk = cmp::max(READ_CHUNK_SIZE / cmp::max(size, 1), 1);
+    let mut items = Vec::with_capacity(cmp::min(len, per_chunk));
+    let mut buffer = alloc::vec![0; per_chunk * size];
+
+    while items.len() < len {
+        let count = cmp::min(per_chunk, len - items.len());
+        let chunk = &mut buffer[..count * size];
+        reader.read_exact(chunk)?;
+
+        for i in 0..count {
+            let (item, _) = T::from_bytes(&chunk[i * size..])
+                .map_err(|_| read_error(field, *index + (i * size)))?;
+            items.push(item);
+        }
+        *index += count * size;
+    }
+
+    Ok((items, len * size))
+}
+
+/// Internal use function to read a string of length `len` out of a reader.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn read_string(
+    reader: &mut dyn Read,
+    index: &mut usize,
+    len: usize,
+    field: &'static str,
+) -> io::Result<(String, usize)> {
+    let mut buffer = alloc::vec![0; len];
+    reader.read_exact(&mut buffer)?;
+
+    let (string, _) = string_from_bytes(&buffer, len).ok_or_else(|| read_error(field, *index))?;
+    *index += len;
+    Ok((string, len))
+}
+
+/// Internal use function to skip over `len` bytes in a reader.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn skip_bytes(reader: &mut dyn Read, index: &mut usize, len: usize) -> io::Result<()> {
+    let skipped = io::copy(&mut reader.take(len as u64), &mut io::sink())?;
+    if skipped < len as u64 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    *index += len;
+    Ok(())
+}
+
+/// Internal use function to skip over the rest of a reply in a reader, so that the next read starts
+/// at whatever the server sent after it. Replies are 32 bytes long, plus `length` 4-byte units.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn skip_to_reply_end(
+    reader: &mut dyn Read,
+    index: &mut usize,
+    length: u32,
+) -> io::Result<()> {
+    let end = 32 + (length as usize * 4);
+    skip_bytes(reader, index, end.saturating_sub(*index))
+}
+
 /// Internal use function to convert a vector of `AsByteSequence` types to bytes.
 /// TODO: specialization
 #[inline]
@@ -136,18 +747,198 @@ pub(crate) fn vector_as_bytes<T: AsByteSequence>(vector: &[T], bytes: &mut [u8])
     current_index
 }
 
+/// Internal use function to convert a vector of `AsByteSequence` types to bytes, padding each one
+/// out to a multiple of `align` bytes.
+#[inline]
+pub(crate) fn padded_vector_as_bytes<T: AsByteSequence>(
+    vector: &[T],
+    bytes: &mut [u8],
+    align: usize,
+) -> usize {
+    vector.iter().fold(0, |current_index, item| {
+        let sz = item.as_bytes(&mut bytes[current_index..]);
+        let pad = zero_pad(&mut bytes[current_index + sz..], buffer_pad(sz, align));
+        current_index + sz + pad
+    })
+}
+
+/// Internal use function to create a list of a known length out of fuzzing input. Lengths that the
+/// input couldn't possibly fill are refused, rather than allocated.
+#[cfg(feature = "fuzzing")]
+#[inline]
+pub(crate) fn arbitrary_list<'a, T: arbitrary::Arbitrary<'a>>(
+    u: &mut arbitrary::Unstructured<'a>,
+    len: usize,
+) -> arbitrary::Result<Vec<T>> {
+    if len > u.len() {
+        return Err(arbitrary::Error::NotEnoughData);
+    }
+
+    (0..len).map(|_| u.arbitrary()).collect()
+}
+
+/// Internal use function to create a string of a known length out of fuzzing input. Only ASCII is
+/// used, so that every character takes up one byte.
+#[cfg(feature = "fuzzing")]
+#[inline]
+pub(crate) fn arbitrary_string(
+    u: &mut arbitrary::Unstructured<'_>,
+    len: usize,
+) -> arbitrary::Result<String> {
+    Ok(u.bytes(len)?.iter().map(|b| char::from(b & 0x7F)).collect())
+}
+
 /// Internal use function to convert a String to bytes.
 #[inline]
 pub(crate) fn string_as_bytes(string: &str, bytes: &mut [u8]) -> usize {
     vector_as_bytes(string.as_bytes(), bytes)
 }
 
+/// Internal use function to encode a string into two-byte characters. The most significant byte
+/// of each character always comes first, whatever the byte order of the connection is.
+#[inline]
+pub(crate) fn string16(string: &str) -> Vec<xproto::Char2b> {
+    string
+        .encode_utf16()
+        .map(|c| {
+            let [byte1, byte2] = c.to_be_bytes();
+            xproto::Char2b { byte1, byte2 }
+        })
+        .collect()
+}
+
+/// Internal use function to iterate over the bits that are set in a mask, from the lowest bit up.
+#[cfg(feature = "xkb")]
+#[inline]
+pub(crate) fn mask_bits(mask: u32) -> impl Iterator<Item = u32> {
+    (0..32).map(|i| 1 << i).filter(move |bit| mask & bit != 0)
+}
+
+/// Internal use function to look up the name of an opcode in a table sorted by opcode.
+#[inline]
+pub(crate) fn name_of_opcode<T: Copy + Ord>(
+    table: &[(T, &'static str)],
+    opcode: T,
+) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&opcode, |(opcode, _)| *opcode)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// Internal use function to format a buffer of bytes for `Debug`. Buffers of up to `limit` bytes are
+/// printed in full, while longer ones are printed as their length, e.g. `[<4096 bytes>]`.
+#[inline]
+pub(crate) fn debug_bytes<T: fmt::Debug>(bytes: &[T], limit: usize) -> impl fmt::Debug + '_ {
+    struct DebugBytes<'a, T>(&'a [T], usize);
+
+    impl<'a, T: fmt::Debug> fmt::Debug for DebugBytes<'a, T> {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.0.len() > self.1 {
+                write!(f, "[<{} bytes>]", self.0.len())
+            } else {
+                fmt::Debug::fmt(self.0, f)
+            }
+        }
+    }
+
+    DebugBytes(bytes, limit)
+}
+
+/// Internal use function to record a change to a field between two replies, if its value is different.
+#[inline]
+pub(crate) fn diff_value<T: AsByteSequence + PartialEq>(
+    field: &'static str,
+    old: &T,
+    new: &T,
+    changes: &mut Vec<FieldChange>,
+) {
+    fn bytes_of<T: AsByteSequence>(item: &T) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; item.size()];
+        let len = item.as_bytes(&mut bytes);
+        bytes.truncate(len);
+        bytes
+    }
+
+    if old != new {
+        changes.push(FieldChange::Value {
+            field,
+            old: bytes_of(old),
+            new: bytes_of(new),
+        });
+    }
+}
+
+/// Internal use function to record a change to the property data of two replies, if it is different.
+#[inline]
+pub(crate) fn diff_property_data(
+    field: &'static str,
+    old: &PropertyData,
+    new: &PropertyData,
+    changes: &mut Vec<FieldChange>,
+) {
+    fn bytes_of(data: &PropertyData) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; data.size()];
+        let len = data.as_bytes(&mut bytes);
+        bytes.truncate(len);
+        bytes
+    }
+
+    if old != new {
+        changes.push(FieldChange::Value {
+            field,
+            old: bytes_of(old),
+            new: bytes_of(new),
+        });
+    }
+}
+
+/// Internal use function to record a change to a list between two replies. A list that changed length
+/// is reported as such, while a list of the same length reports the items that were changed.
+#[inline]
+pub(crate) fn diff_list<T: PartialEq>(
+    field: &'static str,
+    old: &[T],
+    new: &[T],
+    changes: &mut Vec<FieldChange>,
+) {
+    if old.len() != new.len() {
+        changes.push(FieldChange::Length {
+            field,
+            old: old.len(),
+            new: new.len(),
+        });
+        return;
+    }
+
+    let indices: Vec<usize> = old
+        .iter()
+        .zip(new)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(i, _)| i)
+        .collect();
+    if !indices.is_empty() {
+        changes.push(FieldChange::Items { field, indices });
+    }
+}
+
 /// The addition necessary to pad out the buffer, given the align and the current block length.
 #[inline]
 pub(crate) const fn buffer_pad(block_len: usize, align_to: usize) -> usize {
     block_len.wrapping_neg() & align_to.wrapping_sub(1)
 }
 
+/// Internal use function to write `len` bytes of padding at the start of `bytes`. Padding is always
+/// written as zeroes, so that whatever a reused buffer held before isn't sent to the server. Bytes
+/// past the end of the buffer are left out.
+#[inline]
+pub(crate) fn zero_pad(bytes: &mut [u8], len: usize) -> usize {
+    bytes.iter_mut().take(len).for_each(|byte| *byte = 0);
+    len
+}
+
 impl AsByteSequence for u8 {
     #[inline]
     fn size(&self) -> usize {
@@ -161,8 +952,9 @@ impl AsByteSequence for u8 {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(u8, usize)> {
-        Some((bytes[0], 1))
+    fn from_bytes(bytes: &[u8]) -> Result<(u8, usize), ParseError> {
+        let byte = bytes.first().ok_or_else(unreadable::<u8>)?;
+        Ok((*byte, 1))
     }
 }
 
@@ -179,8 +971,9 @@ impl AsByteSequence for i8 {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(i8, usize)> {
-        Some((bytes[0] as i8, 1))
+    fn from_bytes(bytes: &[u8]) -> Result<(i8, usize), ParseError> {
+        let byte = bytes.first().ok_or_else(unreadable::<i8>)?;
+        Ok((*byte as i8, 1))
     }
 }
 
@@ -197,10 +990,10 @@ impl AsByteSequence for bool {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        match bytes[0] {
-            0 => Some((false, 1)),
-            _ => Some((true, 1)),
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        match bytes.first().ok_or_else(unreadable::<bool>)? {
+            0 => Ok((false, 1)),
+            _ => Ok((true, 1)),
         }
     }
 }
@@ -217,11 +1010,14 @@ impl AsByteSequence for () {
     }
 
     #[inline]
-    fn from_bytes(_bytes: &[u8]) -> Option<(Self, usize)> {
-        Some(((), 0))
+    fn from_bytes(_bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        Ok(((), 0))
     }
 }
 
+// Numbers are always serialized in native byte order. The byte order of a connection is chosen by
+// the client rather than the server (see `endian_byte` in the `display` module), and we always pick
+// the native one, so the X server converts to and from it on our behalf.
 macro_rules! impl_fundamental_num {
     ($(($t:ty, $sz:expr))*) => {$(
         impl AsByteSequence for $t {
@@ -238,22 +1034,19 @@ macro_rules! impl_fundamental_num {
             }
 
             #[inline]
-            fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
                 #[cfg(debug_assertions)]
                 log::trace!("Deserializing {} from bytes", stringify!($t));
 
-                cfg_if::cfg_if! {
-                    if #[cfg(debug_assertions)] {
-                        if bytes.len() < $sz {
-                            log::error!("There are {} bytes in the slice, expected {}", bytes.len(), $sz);
-                            return None;
-                        }
-                    }
+                if bytes.len() < $sz {
+                    #[cfg(debug_assertions)]
+                    log::error!("There are {} bytes in the slice, expected {}", bytes.len(), $sz);
+                    return Err(unreadable::<Self>());
                 }
 
                 let mut bytes_array: [u8; $sz] = [0; $sz];
                 bytes_array.copy_from_slice(&bytes[0..$sz]);
-                Some((Self::from_ne_bytes(bytes_array), $sz))
+                Ok((Self::from_ne_bytes(bytes_array), $sz))
             }
         }
     )*}
@@ -285,21 +1078,21 @@ macro_rules! impl_array {
             }
 
             #[inline]
-            fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
                 let mut index = 0;
                 let mut v: tinyvec::ArrayVec<Self> = Default::default();
                 log::trace!("Deserializing an array of length {}", $len);
 
                 for _ in 0..($len) {
-                    let (item, sz) = T::from_bytes(&bytes[index..])?;
+                    let (item, sz) = T::from_bytes(bytes.get(index..).ok_or_else(unreadable::<Self>)?)?;
                     if let Some(_) = v.try_push(item) {
                         log::error!("Array of {} elements overflowed", $len);
-                        return None;
+                        return Err(unreadable::<Self>());
                     }
                     index += sz;
                 }
 
-                Some((v.into_inner(), index))
+                Ok((v.into_inner(), index))
             }
         }
     )*}
@@ -309,6 +1102,28 @@ impl_array! {
     1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32
 }
 
+/// Items that only appear in a switch case are stored as `Option`s. If the case isn't present,
+/// nothing is written or read.
+impl<T: AsByteSequence> AsByteSequence for Option<T> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.as_ref().map_or(0, AsByteSequence::size)
+    }
+
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        match self {
+            Some(t) => t.as_bytes(bytes),
+            None => 0,
+        }
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        T::from_bytes(bytes).map(|(t, sz)| (Some(t), sz))
+    }
+}
+
 impl AsByteSequence for String {
     #[inline]
     fn size(&self) -> usize {
@@ -323,22 +1138,25 @@ impl AsByteSequence for String {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         log::trace!("Deserializing string of unknown length");
 
         let end = match memchr::memrchr(0, bytes) {
-            Some(posn) => posn - 1,
-            None => bytes.len() - 1,
-        };
+            Some(posn) => posn.checked_sub(1),
+            None => bytes.len().checked_sub(1),
+        }
+        .ok_or_else(unreadable::<String>)?;
 
         let s = String::from_utf8_lossy(&bytes[..end]).to_string();
         let len = s.len();
-        Some((s, len))
+        Ok((s, len))
     }
 }
 
 #[cfg(feature = "bigreq")]
 pub mod bigreq;
+#[cfg(feature = "composite")]
+pub mod composite;
 #[cfg(feature = "damage")]
 pub mod damage;
 #[cfg(feature = "dpms")]
@@ -372,6 +1190,8 @@ pub mod shm;
 pub mod sync;
 /// Miscellaneous additions to the X11 protocol.
 pub mod xc_misc;
+/// The errors of all of the namespaces, gathered into one enum.
+pub mod xerror;
 #[cfg(feature = "xevie")]
 pub mod xevie;
 #[cfg(feature = "xf86dri")]
@@ -398,3 +1218,1114 @@ pub mod xtest;
 pub mod xv;
 #[cfg(feature = "xvmc")]
 pub mod xvmc;
+
+#[test]
+fn hash_requests() {
+    use std::collections::HashSet;
+
+    // equal requests should only be stored once
+    let mut requests: HashSet<xproto::GetInputFocusRequest> = HashSet::new();
+    requests.insert(Default::default());
+    requests.insert(Default::default());
+    assert_eq!(requests.len(), 1);
+}
+
+#[test]
+fn compare_structures() {
+    assert_eq!(
+        xproto::GetGeometryReply::default(),
+        xproto::GetGeometryReply::default()
+    );
+
+    // tell whether a type implements `Eq` without requiring it to; the inherent method is picked
+    // over the trait method whenever its bound holds
+    struct Probe<T>(PhantomData<T>);
+    trait NotEq {
+        fn is_eq(&self) -> bool {
+            false
+        }
+    }
+    impl<T> NotEq for Probe<T> {}
+    impl<T: Eq> Probe<T> {
+        fn is_eq(&self) -> bool {
+            true
+        }
+    }
+
+    assert!(Probe::<xproto::GetGeometryReply>(PhantomData).is_eq());
+
+    // floats, and lists of them, can only be compared partially
+    #[cfg(feature = "glx")]
+    {
+        assert!(!Probe::<glx::GetFloatvReply>(PhantomData).is_eq());
+        assert!(!Probe::<glx::GetClipPlaneReply>(PhantomData).is_eq());
+        assert_eq!(
+            glx::GetClipPlaneReply::default(),
+            glx::GetClipPlaneReply::default()
+        );
+    }
+}
+
+#[test]
+fn errors_are_std_errors() {
+    use std::{boxed::Box, error::Error};
+
+    let _: Box<dyn Error> = Box::new(xproto::WindowError::default());
+    let _: Box<dyn Error> = Box::new(crate::XError::Window(Default::default()));
+}
+
+#[cfg(feature = "dri3")]
+#[test]
+fn reply_fds() {
+    // the file descriptor is sent alongside the reply, rather than in its bytes
+    let mut bytes = [0; 32];
+    bytes[0] = 1;
+    bytes[1] = 1;
+    let (mut reply, _) = dri3::OpenReply::from_bytes(&bytes).unwrap();
+    assert_eq!(reply.take_fds(&[7, 8]), Some(1));
+    assert_eq!(reply.nfd, 1);
+    assert_eq!(reply.device_fd, 7);
+
+    // the reply can't be completed without its descriptor
+    assert_eq!(reply.take_fds(&[]), None);
+}
+
+#[cfg(feature = "shape")]
+#[test]
+fn extension_minor_opcodes() {
+    // the second byte of an extension request is its minor opcode
+    let request = shape::RectanglesRequest::builder().build();
+    let mut bytes = [0; 16];
+    request.as_bytes(&mut bytes);
+    assert_eq!(
+        u16::from(bytes[1]),
+        <shape::RectanglesRequest as crate::Request>::MINOR_OPCODE
+    );
+}
+
+#[test]
+fn string_constructors() {
+    // the length of the name is filled in from the string
+    let request = xproto::InternAtomRequest::new(false, "WM_PROTOCOLS");
+    let mut bytes = [0; 20];
+    request.as_bytes(&mut bytes);
+    assert_eq!(u16::from_ne_bytes([bytes[4], bytes[5]]), 12);
+    assert_eq!(&bytes[8..20], b"WM_PROTOCOLS");
+
+    // two-byte characters are big-endian
+    let request =
+        xproto::ImageText16Request::new(Default::default(), Default::default(), 0, 0, "\u{263a}");
+    assert_eq!(request.string[0].byte1, 0x26);
+    assert_eq!(request.string[0].byte2, 0x3a);
+}
+
+#[test]
+fn sort_newtypes() {
+    // transparent wrappers around a single integer sort by that integer
+    let mut windows: Vec<xproto::Window> = [3, 1, 2]
+        .iter()
+        .map(|xid| xproto::Window::const_from_xid(*xid))
+        .collect();
+    windows.sort();
+    assert_eq!(windows.iter().map(|w| w.xid).collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn validate_list_lengths() {
+    // two keysyms for each of two keycodes
+    let mut request = xproto::ChangeKeyboardMappingRequest::builder()
+        .keycode_count(2)
+        .keysyms_per_keycode(2)
+        .keysyms(alloc::vec![0; 4])
+        .build();
+    assert_eq!(request.validate(), Ok(()));
+
+    request.keysyms.pop();
+    assert_eq!(
+        request.validate(),
+        Err(ValidationError {
+            list: "keysyms",
+            expected: 4,
+            found: 3,
+        })
+    );
+}
+
+#[cfg(feature = "fuzzing")]
+#[test]
+fn arbitrary_list_lengths() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    // two keysyms for each of two keycodes, then the bytes of the keysyms
+    let mut data = [0xAB; 64];
+    data[..6].copy_from_slice(&[0, 2, 0, 0, 0, 2]);
+    let request =
+        xproto::ChangeKeyboardMappingRequest::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert_eq!(request.keysyms.len(), 4);
+    assert_eq!(request.validate(), Ok(()));
+}
+
+/// Create arbitrary values of a type, serialize them, parse them back and check that they serialize to
+/// the same bytes. The bytes are compared rather than the values, since floats may be NaN. `finish` is
+/// run on the bytes before they are parsed, to fill in what the connection would. At least a quarter of
+/// the thousand inputs have to make a value, so a type can't pass by rejecting all of them.
+#[cfg(all(test, feature = "fuzzing"))]
+fn check_round_trips<T: AsByteSequence + for<'a> arbitrary::Arbitrary<'a>>(
+    finish: fn(&mut Vec<u8>),
+) {
+    use arbitrary::Unstructured;
+
+    let name = core::any::type_name::<T>();
+    let mut data = alloc::vec![0; 512];
+    let mut checked = 0;
+    (0..1000).for_each(|_| {
+        // lengths are read from the input as well, and random ones rarely fit in it, so some of the
+        // inputs are mostly zeroes
+        let zeroes = fastrand::u8(..);
+        data.iter_mut().for_each(|b| {
+            *b = if fastrand::u8(..) < zeroes {
+                0
+            } else {
+                fastrand::u8(..)
+            }
+        });
+        let value = match T::arbitrary(&mut Unstructured::new(&data)) {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+        checked += 1;
+
+        let mut bytes = alloc::vec![0; value.size()];
+        let len = value.as_bytes(&mut bytes);
+        bytes.truncate(len);
+        finish(&mut bytes);
+
+        let (parsed, parsed_len) = T::from_bytes(&bytes)
+            .unwrap_or_else(|e| panic!("{} could not be parsed from {:?}: {}", name, &bytes, e));
+        assert_eq!(
+            len, parsed_len,
+            "{} was not parsed from all of its bytes",
+            name
+        );
+
+        let mut reserialized = alloc::vec![0; parsed.size()];
+        let reserialized_len = parsed.as_bytes(&mut reserialized);
+        reserialized.truncate(reserialized_len);
+        finish(&mut reserialized);
+        assert_eq!(bytes, reserialized, "{} did not round trip", name);
+    });
+
+    assert!(
+        checked >= 250,
+        "only {} arbitrary values of {} were checked",
+        checked,
+        name
+    );
+}
+
+/// Pad a request to four bytes and write its length, the way the connection does before sending it.
+#[cfg(all(test, feature = "fuzzing"))]
+fn finish_request(bytes: &mut Vec<u8>) {
+    bytes.resize((bytes.len() + 3) & !3, 0);
+    let len = ((bytes.len() / 4) as u16).to_ne_bytes();
+    bytes[2..4].copy_from_slice(&len);
+}
+
+/// Pad a reply to four bytes and write the length of what follows its first 32 bytes, the way the
+/// server does before sending it.
+#[cfg(all(test, feature = "fuzzing"))]
+fn finish_reply(bytes: &mut Vec<u8>) {
+    bytes.resize((bytes.len() + 3) & !3, 0);
+    let len = ((bytes.len().saturating_sub(32) / 4) as u32).to_ne_bytes();
+    bytes[4..8].copy_from_slice(&len);
+}
+
+#[cfg(feature = "fuzzing")]
+#[test]
+fn round_trips() {
+    fastrand::seed(0x5EED);
+
+    check_round_trips::<xproto::Rectangle>(|_| ());
+    check_round_trips::<xproto::Str>(|_| ());
+    check_round_trips::<xproto::Screen>(|_| ());
+    check_round_trips::<xproto::Setup>(|_| ());
+    check_round_trips::<xproto::SetupAuthenticate>(|_| ());
+    check_round_trips::<xproto::CreateWindowRequest>(finish_request);
+    check_round_trips::<xproto::ChangePropertyRequest>(finish_request);
+    check_round_trips::<xproto::InternAtomRequest>(finish_request);
+    check_round_trips::<xproto::ChangeKeyboardMappingRequest>(finish_request);
+    check_round_trips::<xproto::PolyLineRequest>(finish_request);
+    check_round_trips::<xproto::ConfigureWindowRequest>(finish_request);
+    check_round_trips::<xproto::ChangeGcRequest>(finish_request);
+    check_round_trips::<xproto::GetPropertyReply>(finish_reply);
+    check_round_trips::<xproto::QueryTreeReply>(finish_reply);
+    check_round_trips::<xproto::ListFontsWithInfoReply>(finish_reply);
+    check_round_trips::<xproto::KeyPressEvent>(|_| ());
+    check_round_trips::<xproto::ClientMessageEvent>(|_| ());
+    check_round_trips::<xproto::ValueError>(|_| ());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips() {
+    let mut request = xproto::ConfigureWindowRequest::builder().build();
+    request.window = xproto::Window::const_from_xid(0x0040_0001);
+    request
+        .set_x(-10)
+        .set_width(640)
+        .set_stack_mode(xproto::StackMode::Above);
+
+    let json = serde_json::to_string(&request).unwrap();
+    let parsed: xproto::ConfigureWindowRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, request);
+}
+
+#[test]
+fn iterate_over_lists() {
+    let properties = alloc::vec![
+        xproto::Fontprop {
+            name: xproto::Atom::const_from_xid(1),
+            value: 2,
+        },
+        xproto::Fontprop {
+            name: xproto::Atom::const_from_xid(3),
+            value: 4,
+        },
+    ];
+    let mut reply = xproto::ListFontsWithInfoReply {
+        properties: properties.clone(),
+        name: "fixed".into(),
+        ..Default::default()
+    };
+    reply.length = ((reply.size() - 32) / 4) as u32;
+    let mut bytes = alloc::vec![0; reply.size()];
+    reply.as_bytes(&mut bytes);
+
+    let iterated = xproto::ListFontsWithInfoReply::properties_iter(&bytes)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(iterated, properties);
+
+    // the iterator stops at the first item that isn't there
+    let mut iter = xproto::ListFontsWithInfoReply::properties_iter(&bytes[..64]).unwrap();
+    assert!(matches!(iter.next(), Some(Ok(_))));
+    assert!(matches!(iter.next(), Some(Err(_))));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn refuse_remainders_of_empty_items() {
+    #[derive(Debug)]
+    struct Empty;
+
+    impl AsByteSequence for Empty {
+        fn size(&self) -> usize {
+            0
+        }
+
+        fn as_bytes(&self, _bytes: &mut [u8]) -> usize {
+            0
+        }
+
+        fn from_bytes(_bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+            Ok((Empty, 0))
+        }
+    }
+
+    let bytes = [0; 8];
+    assert_eq!(
+        parse_remainder::<Empty>(&bytes, 4, 4, "empty").unwrap_err(),
+        ParseError {
+            field: "empty",
+            offset: 4
+        }
+    );
+    assert!(parse_remainder::<Empty>(&bytes, 4, 0, "empty")
+        .unwrap()
+        .0
+        .is_empty());
+}
+
+#[test]
+fn refuse_lengths_shorter_than_the_header() {
+    // the length says that the request takes up no bytes, but the fields before the string don't fit
+    let bytes = [48, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(
+        xproto::QueryTextExtentsRequest::from_bytes(&bytes).unwrap_err(),
+        ParseError {
+            field: "string",
+            offset: 7
+        }
+    );
+}
+
+#[test]
+fn read_lists_of_corrupt_lengths() {
+    // the third item runs past the end, and the length would take more memory than there is
+    let bytes = [0, 1, 0, 2, 0];
+    assert_eq!(
+        parse_vector::<u16>(&bytes, 1, 3, "items").unwrap_err(),
+        ParseError {
+            field: "items",
+            offset: 5
+        }
+    );
+    assert!(parse_vector::<u32>(&bytes, 0, usize::MAX, "items").is_err());
+    assert!(vector_from_bytes::<u32>(&bytes, usize::MAX).is_none());
+
+    // the offset is kept once the error becomes a `BreadError`
+    assert!(matches!(
+        crate::BreadError::from(ParseError {
+            field: "items",
+            offset: 5
+        }),
+        crate::BreadError::BadFieldRead {
+            field: "items",
+            offset: 5
+        }
+    ));
+}
+
+#[test]
+fn borrow_lists_at_any_alignment() {
+    let mut reply = xproto::AllocColorCellsReply {
+        pixels: alloc::vec![1, 2, 3],
+        masks: alloc::vec![4],
+        ..Default::default()
+    };
+    reply.length = ((reply.size() - 32) / 4) as u32;
+
+    // a buffer of words is aligned for the lists at its start, but not one byte into it
+    let mut words = alloc::vec![0u32; reply.size() / 4 + 1];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+
+    reply.as_bytes(bytes);
+    let pixels = xproto::AllocColorCellsReply::pixels_slice(bytes).unwrap();
+    assert!(matches!(pixels, Cow::Borrowed(_)));
+    assert_eq!(&*pixels, &[1, 2, 3]);
+
+    reply.as_bytes(&mut bytes[1..]);
+    let pixels = xproto::AllocColorCellsReply::pixels_slice(&bytes[1..]).unwrap();
+    assert!(matches!(pixels, Cow::Owned(_)));
+    assert_eq!(&*pixels, &[1, 2, 3]);
+    let masks = xproto::AllocColorCellsReply::masks_slice(&bytes[1..]).unwrap();
+    assert_eq!(&*masks, &[4]);
+}
+
+#[test]
+fn strict_reply_lengths() {
+    let mut bytes = alloc::vec![0; 32];
+    xproto::GetInputFocusReply::default().as_bytes(&mut bytes);
+    assert!(xproto::GetInputFocusReply::try_from_bytes_strict(&bytes).is_ok());
+
+    // the reply claims four more bytes than its fields read
+    bytes[4..8].copy_from_slice(&1u32.to_ne_bytes());
+    bytes.extend_from_slice(&[0; 4]);
+    assert!(xproto::GetInputFocusReply::from_bytes(&bytes).is_ok());
+    assert_eq!(
+        xproto::GetInputFocusReply::try_from_bytes_strict(&bytes).map(|_| ()),
+        Err(StrictParseError::TrailingBytes {
+            expected: 36,
+            actual: 32
+        })
+    );
+}
+
+#[cfg(feature = "pod")]
+#[test]
+fn cast_pod_structures() {
+    let rect = xproto::Rectangle {
+        x: 1,
+        y: -2,
+        width: 3,
+        height: 4,
+    };
+    let mut bytes = [0; 8];
+    rect.as_bytes(&mut bytes);
+
+    assert_eq!(bytemuck::bytes_of(&rect), &bytes[..]);
+    assert_eq!(bytemuck::cast::<[u8; 8], xproto::Rectangle>(bytes), rect);
+}
+
+#[test]
+fn small_structures_are_copy() {
+    let p1 = xproto::Point { x: 1, y: 2 };
+    let p2 = p1;
+    assert_eq!(p1, p2);
+}
+
+#[test]
+fn tell_replies_from_errors() {
+    let mut bytes = [0; 32];
+    let reply = xproto::GetInputFocusReply {
+        reply_type: 1,
+        ..Default::default()
+    };
+    reply.as_bytes(&mut bytes);
+    assert!(matches!(
+        xproto::GetInputFocusRequest::parse_reply_or_error(&bytes),
+        Ok(Ok(_))
+    ));
+
+    // a BadWindow error
+    bytes[0] = 0;
+    bytes[1] = xproto::WindowError::OPCODE;
+    assert!(matches!(
+        xproto::GetInputFocusRequest::parse_reply_or_error(&bytes),
+        Ok(Err(XError::Window(_)))
+    ));
+
+    assert!(xproto::GetInputFocusRequest::parse_reply_or_error(&[]).is_err());
+}
+
+#[test]
+fn parse_errors_by_code() {
+    let mut bytes = [0; 32];
+    bytes[1] = xproto::AtomError::OPCODE;
+    assert!(matches!(
+        xproto::error_from_code(bytes[1], &bytes),
+        Ok(XError::Atom(_))
+    ));
+
+    // codes past the core protocol's belong to extensions
+    bytes[1] = 150;
+    assert!(matches!(
+        xproto::error_from_code(bytes[1], &bytes),
+        Ok(XError::Unknown { code: 150, .. })
+    ));
+}
+
+#[test]
+fn read_errors_at_their_wire_offsets() {
+    // errors begin with their type, their code and a sequence number, and the value that caused
+    // them comes right after
+    let mut bytes = [0; 32];
+    bytes[1] = xproto::AtomError::OPCODE;
+    bytes[2..4].copy_from_slice(&7u16.to_ne_bytes());
+    bytes[4..8].copy_from_slice(&0x1234u32.to_ne_bytes());
+    bytes[8..10].copy_from_slice(&2u16.to_ne_bytes());
+    bytes[10] = 16;
+
+    let (error, _) = xproto::AtomError::from_bytes(&bytes).unwrap();
+    assert_eq!(error.sequence, 7);
+    assert_eq!(error.bad_value, 0x1234);
+    assert_eq!(error.minor_opcode, 2);
+    assert_eq!(error.major_opcode, 16);
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn parse_extension_errors_by_code() {
+    // the codes of an extension's errors count up from the first one the server gives it
+    let mut bytes = [0; 32];
+    bytes[1] = 155;
+    assert!(matches!(
+        sync::error_from_code(bytes[1], 154, &bytes),
+        Ok(XError::SyncAlarm(_))
+    ));
+
+    bytes[1] = 160;
+    assert!(matches!(
+        sync::error_from_code(bytes[1], 154, &bytes),
+        Ok(XError::Unknown { code: 160, .. })
+    ));
+    bytes[1] = 3;
+    assert!(matches!(
+        sync::error_from_code(bytes[1], 154, &bytes),
+        Ok(XError::Unknown { code: 3, .. })
+    ));
+}
+
+#[test]
+fn folded_sizes() {
+    assert_eq!(xproto::GetGeometryReply::default().size(), 24);
+
+    // only the list is sized at runtime
+    let request = xproto::PolyRectangleRequest::builder()
+        .rectangles(alloc::vec![Default::default(); 2])
+        .build();
+    assert_eq!(request.size(), 12 + 2 * 8);
+    let mut bytes = [0; 28];
+    assert_eq!(request.as_bytes(&mut bytes), request.size());
+}
+
+#[test]
+fn collect_request_lists() {
+    let points = [xproto::Point { x: 1, y: 2 }, xproto::Point { x: 3, y: 4 }];
+    let mut builder: xproto::PolyLineRequestBuilder = points.iter().copied().collect();
+    builder.extend(points.iter().copied());
+    let request = builder.build();
+    assert_eq!(request.points.len(), 4);
+    assert_eq!(request.points[2], points[0]);
+}
+
+#[test]
+fn decode_alt_enum_fields() {
+    let mut request = xproto::KillClientRequest::builder().resource(0).build();
+    assert_eq!(request.resource_enum(), Some(xproto::Kill::AllTemporary));
+
+    // any other value is a resource, not a member of the enumeration
+    request.resource = 0x0040_0001;
+    assert_eq!(request.resource_enum(), None);
+}
+
+#[test]
+fn padded_list_elements() {
+    let host = xproto::Host {
+        family: xproto::Family::Internet,
+        address: alloc::vec![127, 0, 1],
+    };
+    let reply = xproto::ListHostsReply {
+        length: 4,
+        hosts: alloc::vec![host.clone(), host],
+        ..Default::default()
+    };
+
+    // each host is padded out to four bytes, even though its address isn't
+    assert_eq!(reply.size(), 32 + 2 * 8);
+    let mut bytes = [0; 48];
+    assert_eq!(reply.as_bytes(&mut bytes), 48);
+    assert_eq!(&bytes[44..48], &[127, 0, 1, 0]);
+
+    let (parsed, len) = xproto::ListHostsReply::from_bytes(&bytes).unwrap();
+    assert_eq!(len, 48);
+    assert_eq!(parsed.hosts, reply.hosts);
+    let iterated: Vec<xproto::Host> = xproto::ListHostsReply::hosts_iter(&bytes)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(iterated, reply.hosts);
+}
+
+#[test]
+fn bound_replies_by_length() {
+    let reply = xproto::GetPropertyReply {
+        format: 8,
+        length: 1,
+        value_len: 4,
+        value: PropertyData::Eight(alloc::vec![1, 2, 3, 4]),
+        ..Default::default()
+    };
+    let mut bytes = alloc::vec![0; 40];
+    assert_eq!(reply.as_bytes(&mut bytes), 36);
+
+    // the bytes past the end of the reply are left alone
+    let (parsed, len) = xproto::GetPropertyReply::from_bytes(&bytes).unwrap();
+    assert_eq!(len, 36);
+    assert_eq!(parsed.value, reply.value);
+
+    // a reply that announces less than its fields need isn't read into the bytes that follow it
+    bytes[4..8].copy_from_slice(&0u32.to_ne_bytes());
+    assert!(xproto::GetPropertyReply::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn elide_large_buffers_in_debug() {
+    let mut request = xproto::PutImageRequest::builder()
+        .width(1)
+        .data(alloc::vec![1, 2, 3])
+        .build();
+    let small = alloc::format!("{:?}", request);
+    assert!(small.contains("width: 1"));
+    assert!(small.contains("data: [1, 2, 3]"));
+
+    request.data = alloc::vec![0; 4096];
+    let large = alloc::format!("{:?}", request);
+    assert!(large.contains("width: 1"));
+    assert!(large.contains("data: [<4096 bytes>]"));
+}
+
+#[cfg(feature = "xkb")]
+#[test]
+fn pair_lists_with_mask_bits() {
+    let reply = xkb::GetIndicatorMapReply {
+        which: 0b1010,
+        maps: alloc::vec![Default::default(); 2],
+        ..Default::default()
+    };
+    let pairs: Vec<(u32, &xkb::IndicatorMap)> = reply.maps_by_mask().collect();
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs[0].0, 0b10);
+    assert!(core::ptr::eq(pairs[0].1, &reply.maps[0]));
+    assert_eq!(pairs[1].0, 0b1000);
+    assert!(core::ptr::eq(pairs[1].1, &reply.maps[1]));
+}
+
+#[test]
+fn name_opcodes() {
+    assert_eq!(xproto::event_name(2), Some("KeyPress"));
+    assert_eq!(xproto::error_name(xproto::AtomError::OPCODE), Some("Atom"));
+    assert_eq!(xproto::request_name(1), Some("CreateWindow"));
+    assert_eq!(xproto::request_name(200), None);
+}
+
+#[cfg(feature = "present")]
+#[test]
+fn parse_generic_events_by_length() {
+    let event = present::ConfigureNotifyEvent {
+        event_type: 35,
+        length: 2,
+        evtype: 0,
+        width: 640,
+        pixmap_flags: 7,
+        ..Default::default()
+    };
+    assert_eq!(event.size(), 40);
+    let mut bytes = alloc::vec![0xFF; 48];
+    event.as_bytes(&mut bytes);
+
+    match present::AnyEvent::parse(0, &bytes) {
+        Ok(present::AnyEvent::ConfigureNotify(parsed)) => {
+            assert_eq!(parsed.width, 640);
+            assert_eq!(parsed.pixmap_flags, 7);
+        }
+        other => panic!("Expected a ConfigureNotify event, got {:?}", other),
+    }
+    match xproto::Event::parse(0, &bytes) {
+        Ok(xproto::Event::GeGeneric(parsed)) => assert_eq!(parsed.length, 2),
+        other => panic!("Expected a generic event, got {:?}", other),
+    }
+
+    // the event is longer than the bytes that hold it
+    assert!(present::AnyEvent::parse(0, &bytes[..36]).is_err());
+    bytes[8] = 9;
+    assert_eq!(
+        present::AnyEvent::parse(0, &bytes).unwrap_err(),
+        ParseError {
+            field: "evtype",
+            offset: 8
+        }
+    );
+}
+
+#[test]
+fn set_masked_fields_in_place() {
+    let mut request = xproto::ConfigureWindowRequest::builder().build();
+    request.set_width(100).set_x(5);
+    assert!(request.value_mask.width() && request.value_mask.x());
+    assert_eq!(request.width, Some(100));
+
+    // the values are written in the order of their bits, whatever order they were set in
+    let mut bytes = alloc::vec![0; request.size()];
+    let len = request.as_bytes(&mut bytes);
+    assert_eq!(len, 20);
+    assert_eq!(bytes[12..16], 5i32.to_ne_bytes());
+    assert_eq!(bytes[16..20], 100u32.to_ne_bytes());
+
+    request.set_width(200).clear_x();
+    assert!(!request.value_mask.x());
+    assert_eq!(request.x, None);
+    assert_eq!(request.width, Some(200));
+}
+
+#[test]
+fn compare_resources_by_identity() {
+    use std::collections::HashSet;
+
+    // two descriptions of the same visual are the same key, even if one of them is out of date
+    let visual = xproto::Visualtype {
+        visual_id: 33,
+        bits_per_rgb_value: 8,
+        ..Default::default()
+    };
+    let changed = xproto::Visualtype {
+        bits_per_rgb_value: 10,
+        ..visual
+    };
+    assert_eq!(visual, changed);
+
+    let mut visuals = HashSet::new();
+    visuals.insert(visual);
+    assert!(!visuals.insert(changed));
+    assert!(visuals.insert(xproto::Visualtype {
+        visual_id: 34,
+        ..Default::default()
+    }));
+
+    // screens hold more than a snapshot of their root window, so they're compared as a whole
+    let screen = xproto::Screen {
+        root: xproto::Window::const_from_xid(1),
+        width_in_pixels: 640,
+        ..Default::default()
+    };
+    let resized = xproto::Screen {
+        width_in_pixels: 1280,
+        ..screen.clone()
+    };
+    assert_ne!(screen, resized);
+}
+
+#[test]
+fn requests_as_trait_objects() {
+    use crate::AnyRequest;
+    use alloc::boxed::Box;
+
+    let requests: Vec<Box<dyn AnyRequest>> = alloc::vec![
+        Box::new(xproto::NoOperationRequest::builder().build()),
+        Box::new(xproto::InternAtomRequest::new(false, "WM_NAME")),
+    ];
+    assert_eq!(requests[0].debug_name(), "NoOperation");
+    assert_eq!(requests[1].debug_name(), "InternAtom");
+    assert_eq!(requests[1].opcode(), Some(16));
+    assert_eq!(requests[1].minor_opcode(), None);
+    assert_eq!(requests[1].extension(), None);
+
+    let mut bytes = alloc::vec![0; requests[1].serialized_size()];
+    assert_eq!(requests[1].serialize_into(&mut bytes), Ok(16));
+    assert_eq!(&bytes[8..15], b"WM_NAME");
+    assert_eq!(
+        requests[1].serialize_into(&mut bytes[..8]),
+        Err(BufferTooSmall {
+            needed: 16,
+            available: 8,
+        })
+    );
+
+    // `AsByteSequence` is in scope here as well, so the call on a known type is qualified
+    let request = xproto::NoOperationRequest::builder().build();
+    let mut bytes = alloc::vec![0; 4];
+    assert_eq!(AnyRequest::serialize_into(&request, &mut bytes), Ok(4));
+}
+
+#[test]
+fn route_requests_by_their_opcodes() {
+    use crate::Request;
+
+    fn opcodes<R: Request>(_request: &R) -> (u8, Option<&'static str>, u16) {
+        (R::OPCODE, R::EXTENSION, R::MINOR_OPCODE)
+    }
+
+    let request = xproto::InternAtomRequest::builder().build();
+    assert_eq!(opcodes(&request), (16, None, 0));
+
+    // the server assigns the major opcode of an extension request
+    #[cfg(feature = "fixes")]
+    {
+        let request = xfixes::ChangeSaveSetRequest::builder().build();
+        assert_eq!(opcodes(&request), (0, Some("XFIXES"), 1));
+    }
+}
+
+#[test]
+fn zero_padding_in_reused_buffers() {
+    let mut request = xproto::ConfigureWindowRequest::builder().build();
+    request.set_width(100);
+    let mut bytes = alloc::vec![0xFF; request.size()];
+    request.as_bytes(&mut bytes);
+    assert_eq!(bytes[1], 0);
+    assert_eq!(bytes[10..12], [0, 0]);
+
+    // the padding after a list, as well as the padding between fields
+    let request = xproto::InternAtomRequest::new(false, "WM_NAME");
+    let mut bytes = alloc::vec![0xFF; request.size()];
+    request.as_bytes(&mut bytes);
+    assert_eq!(bytes[6..8], [0, 0]);
+    assert_eq!(&bytes[8..15], b"WM_NAME");
+    assert_eq!(bytes[15], 0);
+}
+
+#[test]
+fn wire_fields_match_serialized_offsets() {
+    assert_eq!(
+        xproto::Rectangle::WIRE_FIELDS,
+        [("x", 0, 2), ("y", 2, 2), ("width", 4, 2), ("height", 6, 2)]
+    );
+
+    let request = xproto::CreateWindowRequest::builder()
+        .wid(xproto::Window::const_from_xid(0x1234_5678))
+        .border_width(0x9ABC)
+        .build();
+    let mut bytes = alloc::vec![0; request.size()];
+    request.as_bytes(&mut bytes);
+    let field = |name| {
+        let (_, offset, size) = xproto::CreateWindowRequest::WIRE_FIELDS
+            .iter()
+            .find(|(field, _, _)| *field == name)
+            .copied()
+            .unwrap();
+        &bytes[offset..offset + size]
+    };
+    assert_eq!(field("wid"), 0x1234_5678u32.to_ne_bytes());
+    assert_eq!(field("border_width"), 0x9ABCu16.to_ne_bytes());
+
+    // the fields past the first list are left out, but the length slot before it is there
+    assert_eq!(
+        xproto::InternAtomRequest::WIRE_FIELDS.last(),
+        Some(&("name_len", 4, 2))
+    );
+}
+
+#[test]
+fn compare_replies_without_headers() {
+    let expected = xproto::GetGeometryReply {
+        depth: 24,
+        width: 640,
+        height: 480,
+        ..Default::default()
+    };
+    let mut bytes = alloc::vec![0; expected.size()];
+    expected.as_bytes(&mut bytes);
+    bytes[0] = 1;
+    bytes[2..4].copy_from_slice(&7u16.to_ne_bytes());
+    let (parsed, _) = xproto::GetGeometryReply::from_bytes(&bytes).unwrap();
+
+    assert_ne!(parsed, expected);
+    assert!(parsed.semantically_eq(&expected));
+
+    let resized = xproto::GetGeometryReply {
+        width: 800,
+        ..expected
+    };
+    assert!(!parsed.semantically_eq(&resized));
+}
+
+#[test]
+fn deref_transparent_wrappers() {
+    let window = xproto::Window::const_from_xid(0x0040_0001);
+    assert_eq!(*window, 0x0040_0001);
+    assert_eq!(window.leading_zeros(), 9);
+    let xid: &crate::XID = window.as_ref();
+    assert_eq!(*xid, 0x0040_0001);
+
+    let mut mask = xproto::EventMask::default();
+    mask.set_exposure(true);
+    mask.set_key_press(true);
+    assert_eq!(mask.count_ones(), 2);
+}
+
+#[test]
+fn sparse_enum_values() {
+    use core::convert::TryFrom;
+
+    assert_eq!(xproto::Family::ServerInterpreted as u8, 5);
+    assert_eq!(xproto::Family::try_from(6), Ok(xproto::Family::Internet6));
+    assert_eq!(xproto::Family::try_from(3), Err(3));
+    assert!(xproto::Family::from_bytes(&[4]).is_err());
+
+    let mut bytes = [0; 1];
+    xproto::Family::ServerInterpreted.as_bytes(&mut bytes);
+    assert_eq!(
+        xproto::Family::from_bytes(&bytes),
+        Ok((xproto::Family::ServerInterpreted, 1))
+    );
+}
+
+#[test]
+fn parse_server_setup() {
+    // a setup with one pixmap format and one screen, laid out the way the server sends it
+    let mut bytes = alloc::vec![1, 0];
+    bytes.extend_from_slice(&11u16.to_ne_bytes());
+    bytes.extend_from_slice(&0u16.to_ne_bytes());
+    bytes.extend_from_slice(&29u16.to_ne_bytes());
+    bytes.extend_from_slice(&12_101_004u32.to_ne_bytes());
+    bytes.extend_from_slice(&0x0040_0000u32.to_ne_bytes());
+    bytes.extend_from_slice(&0x001F_FFFFu32.to_ne_bytes());
+    bytes.extend_from_slice(&256u32.to_ne_bytes());
+    bytes.extend_from_slice(&4u16.to_ne_bytes());
+    bytes.extend_from_slice(&65535u16.to_ne_bytes());
+    bytes.extend_from_slice(&[1, 1, 0, 0, 32, 32, 8, 255, 0, 0, 0, 0]);
+    bytes.extend_from_slice(b"Test");
+    bytes.extend_from_slice(&[24, 32, 32, 0, 0, 0, 0, 0]);
+    // the screen
+    for value in &[0x0000_0512u32, 0x20, 0x00FF_FFFF, 0, 0] {
+        bytes.extend_from_slice(&value.to_ne_bytes());
+    }
+    for value in &[1920u16, 1080, 508, 286, 1, 1] {
+        bytes.extend_from_slice(&value.to_ne_bytes());
+    }
+    bytes.extend_from_slice(&0x21u32.to_ne_bytes());
+    bytes.extend_from_slice(&[0, 0, 24, 1]);
+    // the screen's depth, and its visual
+    bytes.extend_from_slice(&[24, 0]);
+    bytes.extend_from_slice(&1u16.to_ne_bytes());
+    bytes.extend_from_slice(&[0, 0, 0, 0]);
+    bytes.extend_from_slice(&0x21u32.to_ne_bytes());
+    bytes.extend_from_slice(&[4, 8]);
+    bytes.extend_from_slice(&256u16.to_ne_bytes());
+    for value in &[0x00FF_0000u32, 0xFF00, 0xFF, 0] {
+        bytes.extend_from_slice(&value.to_ne_bytes());
+    }
+    assert_eq!(bytes.len(), 8 + 29 * 4);
+
+    let (setup, len) = xproto::Setup::from_bytes(&bytes).unwrap();
+    assert_eq!(len, bytes.len());
+    assert_eq!(setup.vendor, "Test");
+    assert_eq!(setup.resource_id_base, 0x0040_0000);
+    assert_eq!(setup.maximum_request_length, 65535);
+    assert_eq!(setup.pixmap_formats.len(), 1);
+    assert_eq!(setup.pixmap_formats[0].bits_per_pixel, 32);
+
+    let screen = &setup.roots[0];
+    assert_eq!(setup.roots.len(), 1);
+    assert_eq!(screen.root, xproto::Window::const_from_xid(0x512));
+    assert_eq!(screen.width_in_pixels, 1920);
+    assert_eq!(screen.root_depth, 24);
+    let visual = &screen.allowed_depths[0].visuals[0];
+    assert_eq!(visual.visual_id, 0x21);
+    assert_eq!(visual.class, xproto::VisualClass::TrueColor);
+    assert_eq!(visual.red_mask, 0x00FF_0000);
+
+    // writing it back gives the same bytes
+    let mut written = alloc::vec![0; setup.size()];
+    assert_eq!(setup.as_bytes(&mut written), bytes.len());
+    assert_eq!(written, bytes);
+}
+
+#[test]
+fn value_lists_in_mask_order() {
+    // set every value, starting from the one with the highest bit
+    let mut request = xproto::ConfigureWindowRequest::builder().build();
+    request
+        .set_stack_mode(xproto::StackMode::Opposite)
+        .set_sibling(xproto::Window::const_from_xid(0x60))
+        .set_border_width(6)
+        .set_height(5)
+        .set_width(4)
+        .set_y(3)
+        .set_x(2);
+
+    let mut bytes = alloc::vec![0; request.size()];
+    assert_eq!(request.as_bytes(&mut bytes), 40);
+    assert_eq!(bytes[8..10], 0x7Fu16.to_ne_bytes());
+    let values: alloc::vec::Vec<u32> = bytes[12..40]
+        .chunks(4)
+        .map(|value| u32::from_ne_bytes([value[0], value[1], value[2], value[3]]))
+        .collect();
+    assert_eq!(values, [2, 3, 4, 5, 6, 0x60, 4]);
+}
+
+#[test]
+fn refuse_values_out_of_bounds() {
+    let request = xproto::ChangePropertyRequest::builder()
+        .format(32)
+        .unwrap()
+        .build();
+    assert_eq!(request.format, 32);
+
+    assert_eq!(
+        xproto::ChangePropertyRequest::builder().format(12).err(),
+        Some(BuildError {
+            field: "format",
+            value: 12
+        })
+    );
+    assert!(xproto::CreatePixmapRequest::builder().depth(24).is_ok());
+    assert!(xproto::CreatePixmapRequest::builder().depth(0).is_err());
+    assert!(xproto::CreatePixmapRequest::builder().depth(33).is_err());
+}
+
+#[test]
+fn diff_replies_by_field() {
+    let old = xproto::GetModifierMappingReply {
+        keycodes_per_modifier: 1,
+        sequence: 1,
+        keycodes: alloc::vec![1, 2, 3, 4, 5, 6, 7, 8],
+        ..Default::default()
+    };
+    let mut new = xproto::GetModifierMappingReply {
+        keycodes_per_modifier: 2,
+        sequence: 2,
+        keycodes: alloc::vec![1, 9, 3, 4, 5, 6, 7, 10],
+        ..old.clone()
+    };
+    assert!(old.diff(&old).is_empty());
+    assert_eq!(
+        old.diff(&new),
+        [
+            FieldChange::Value {
+                field: "keycodes_per_modifier",
+                old: alloc::vec![1],
+                new: alloc::vec![2],
+            },
+            FieldChange::Items {
+                field: "keycodes",
+                indices: alloc::vec![1, 7],
+            },
+        ]
+    );
+
+    new.keycodes_per_modifier = 1;
+    new.keycodes.push(11);
+    assert_eq!(
+        old.diff(&new),
+        [FieldChange::Length {
+            field: "keycodes",
+            old: 8,
+            new: 9,
+        }]
+    );
+}
+
+#[test]
+fn construct_fixed_size_structs_in_consts() {
+    const RECTANGLES: [xproto::Rectangle; 2] = [
+        xproto::Rectangle::new(0, 0, 100, 100),
+        xproto::Rectangle::new(-5, 10, 20, 30),
+    ];
+    assert_eq!(RECTANGLES[0].width, 100);
+    assert_eq!(
+        RECTANGLES[1],
+        xproto::Rectangle {
+            x: -5,
+            y: 10,
+            width: 20,
+            height: 30,
+        }
+    );
+}
+
+#[test]
+fn read_property_data_with_its_format() {
+    let reply = xproto::GetPropertyReply {
+        format: 16,
+        length: 2,
+        value_len: 3,
+        value: PropertyData::Sixteen(alloc::vec![1, 0x200, 3]),
+        ..Default::default()
+    };
+    let mut bytes = alloc::vec![0; reply.size()];
+    assert_eq!(reply.as_bytes(&mut bytes), 40);
+    let (parsed, _) = xproto::GetPropertyReply::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        parsed.value,
+        PropertyData::Sixteen(alloc::vec![1, 0x200, 3])
+    );
+
+    // the properties that don't exist have no format
+    bytes[1] = 0;
+    bytes[16..20].copy_from_slice(&0u32.to_ne_bytes());
+    let (parsed, _) = xproto::GetPropertyReply::from_bytes(&bytes).unwrap();
+    assert!(parsed.value.is_empty());
+
+    // items can't be 12 bits wide, and items that run past the reply can't be read
+    bytes[1] = 12;
+    bytes[16..20].copy_from_slice(&3u32.to_ne_bytes());
+    assert_eq!(
+        xproto::GetPropertyReply::from_bytes(&bytes).map(|_| ()),
+        Err(ParseError {
+            field: "value",
+            offset: 32,
+        })
+    );
+    bytes[1] = 32;
+    bytes[16..20].copy_from_slice(&3u32.to_ne_bytes());
+    assert!(xproto::GetPropertyReply::from_bytes(&bytes).is_err());
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn leave_out_fields_of_older_versions() {
+    // a reply from before RENDER 0.6, where the number of subpixel orders was padding and the
+    // orders themselves aren't sent, followed by the bytes of something else
+    let mut bytes = [0; 36];
+    bytes[0] = 1;
+    bytes[24..28].copy_from_slice(&3u32.to_ne_bytes());
+    bytes[32..36].copy_from_slice(&[0xFF; 4]);
+
+    let (reply, sz) = render::QueryPictFormatsReply::from_bytes(&bytes).unwrap();
+    assert_eq!(sz, 32);
+    assert!(reply.formats.is_empty());
+    assert!(reply.screens.is_empty());
+    assert!(reply.subpixels.is_empty());
+}
@@ -1,20 +1,40 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "present")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+#[cfg(not(feature = "randr"))]
+compile_error!(
+    "The `present` feature requires the `randr` feature, since it uses the types that it defines"
+);
+#[cfg(not(feature = "fixes"))]
+compile_error!(
+    "The `present` feature requires the `fixes` feature, since it uses the types that it defines"
+);
+#[cfg(not(feature = "sync"))]
+compile_error!(
+    "The `present` feature requires the `sync` feature, since it uses the types that it defines"
+);
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 2;
 use super::randr::*;
 use super::sync::*;
 use super::xfixes::*;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Notify {
     pub window: Window,
     pub serial: Card32,
 }
-impl Notify {}
+impl Notify {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for Notify {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -24,14 +44,14 @@ impl AsByteSequence for Notify {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Notify from byte buffer");
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = parse_item(bytes, index, "serial")?;
         index += sz;
-        Some((
+        Ok((
             Notify {
                 window: window,
                 serial: serial,
@@ -41,42 +61,79 @@ impl AsByteSequence for Notify {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.window.size() + self.serial.size()
+        4 + self.window.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Notify {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("major_version", 4, 4),
+        ("minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -88,20 +145,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Present");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn major_version(mut self, major_version: Card32) -> Self {
+        self.inner.major_version = major_version;
+        self
+    }
+    #[inline]
+    pub fn minor_version(mut self, minor_version: Card32) -> Self {
+        self.inner.minor_version = minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                major_version: Default::default(),
+                minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -109,35 +209,105 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -150,15 +320,21 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PixmapRequest {
     pub req_type: u8,
     pub length: u16,
@@ -178,13 +354,20 @@ pub struct PixmapRequest {
     pub remainder: Card64,
     pub notifies: Vec<Notify>,
 }
-impl PixmapRequest {}
-impl AsByteSequence for PixmapRequest {
+impl PixmapRequest {
     #[inline]
+    pub fn builder() -> PixmapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<PixmapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PixmapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.pixmap.as_bytes(&mut bytes[index..]);
@@ -197,58 +380,69 @@ impl AsByteSequence for PixmapRequest {
         index += self.wait_fence.as_bytes(&mut bytes[index..]);
         index += self.idle_fence.as_bytes(&mut bytes[index..]);
         index += self.options.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index += self.target_msc.as_bytes(&mut bytes[index..]);
         index += self.divisor.as_bytes(&mut bytes[index..]);
         index += self.remainder.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.notifies, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Notify>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Notify>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = parse_item(bytes, index, "pixmap")?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = parse_item(bytes, index, "serial")?;
         index += sz;
-        let (valid, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (valid, sz): (Region, usize) = parse_item(bytes, index, "valid")?;
         index += sz;
-        let (update, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (update, sz): (Region, usize) = parse_item(bytes, index, "update")?;
         index += sz;
-        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_off, sz): (Int16, usize) = parse_item(bytes, index, "x_off")?;
         index += sz;
-        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_off, sz): (Int16, usize) = parse_item(bytes, index, "y_off")?;
         index += sz;
-        let (target_crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (target_crtc, sz): (Crtc, usize) = parse_item(bytes, index, "target_crtc")?;
         index += sz;
-        let (wait_fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (wait_fence, sz): (Fence, usize) = parse_item(bytes, index, "wait_fence")?;
         index += sz;
-        let (idle_fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (idle_fence, sz): (Fence, usize) = parse_item(bytes, index, "idle_fence")?;
         index += sz;
-        let (options, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (options, sz): (Card32, usize) = parse_item(bytes, index, "options")?;
         index += sz;
         index += 4;
-        let (target_msc, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (target_msc, sz): (Card64, usize) = parse_item(bytes, index, "target_msc")?;
         index += sz;
-        let (divisor, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (divisor, sz): (Card64, usize) = parse_item(bytes, index, "divisor")?;
         index += sz;
-        let (remainder, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (remainder, sz): (Card64, usize) = parse_item(bytes, index, "remainder")?;
         index += sz;
-        let (notifies, block_len): (Vec<Notify>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (notifies, block_len): (Vec<Notify>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "notifies",
+                offset: index,
+            })? as usize,
+            "notifies",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Notify>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PixmapRequest {
                 req_type: req_type,
                 length: length,
@@ -271,40 +465,169 @@ impl AsByteSequence for PixmapRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
+        let size: usize = 44
             + self.window.size()
             + self.pixmap.size()
-            + self.serial.size()
             + self.valid.size()
             + self.update.size()
-            + self.x_off.size()
-            + self.y_off.size()
             + self.target_crtc.size()
             + self.wait_fence.size()
             + self.idle_fence.size()
-            + self.options.size()
-            + 4
-            + self.target_msc.size()
-            + self.divisor.size()
-            + self.remainder.size()
             + {
                 let block_len: usize = self.notifies.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Notify>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PixmapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PixmapRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Present");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Pixmap";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PixmapRequest`."]
+#[derive(Clone, Debug)]
+pub struct PixmapRequestBuilder {
+    pub inner: PixmapRequest,
+}
+impl PixmapRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn pixmap(mut self, pixmap: Pixmap) -> Self {
+        self.inner.pixmap = pixmap;
+        self
+    }
+    #[inline]
+    pub fn serial(mut self, serial: Card32) -> Self {
+        self.inner.serial = serial;
+        self
+    }
+    #[inline]
+    pub fn valid(mut self, valid: Region) -> Self {
+        self.inner.valid = valid;
+        self
+    }
+    #[inline]
+    pub fn update(mut self, update: Region) -> Self {
+        self.inner.update = update;
+        self
+    }
+    #[inline]
+    pub fn x_off(mut self, x_off: Int16) -> Self {
+        self.inner.x_off = x_off;
+        self
+    }
+    #[inline]
+    pub fn y_off(mut self, y_off: Int16) -> Self {
+        self.inner.y_off = y_off;
+        self
+    }
+    #[inline]
+    pub fn target_crtc(mut self, target_crtc: Crtc) -> Self {
+        self.inner.target_crtc = target_crtc;
+        self
+    }
+    #[inline]
+    pub fn wait_fence(mut self, wait_fence: Fence) -> Self {
+        self.inner.wait_fence = wait_fence;
+        self
+    }
+    #[inline]
+    pub fn idle_fence(mut self, idle_fence: Fence) -> Self {
+        self.inner.idle_fence = idle_fence;
+        self
+    }
+    #[inline]
+    pub fn options(mut self, options: Card32) -> Self {
+        self.inner.options = options;
+        self
+    }
+    #[inline]
+    pub fn target_msc(mut self, target_msc: Card64) -> Self {
+        self.inner.target_msc = target_msc;
+        self
+    }
+    #[inline]
+    pub fn divisor(mut self, divisor: Card64) -> Self {
+        self.inner.divisor = divisor;
+        self
+    }
+    #[inline]
+    pub fn remainder(mut self, remainder: Card64) -> Self {
+        self.inner.remainder = remainder;
+        self
+    }
+    #[inline]
+    pub fn notifies(mut self, notifies: Vec<Notify>) -> Self {
+        self.inner.notifies = notifies;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PixmapRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Notify> for PixmapRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Notify>>(&mut self, iter: I) {
+        self.inner.notifies.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Notify> for PixmapRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Notify>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for PixmapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PixmapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                pixmap: Default::default(),
+                serial: Default::default(),
+                valid: Default::default(),
+                update: Default::default(),
+                x_off: Default::default(),
+                y_off: Default::default(),
+                target_crtc: Default::default(),
+                wait_fence: Default::default(),
+                idle_fence: Default::default(),
+                options: Default::default(),
+                target_msc: Default::default(),
+                divisor: Default::default(),
+                remainder: Default::default(),
+                notifies: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct NotifyMscRequest {
     pub req_type: u8,
     pub length: u16,
@@ -314,43 +637,51 @@ pub struct NotifyMscRequest {
     pub divisor: Card64,
     pub remainder: Card64,
 }
-impl NotifyMscRequest {}
-impl AsByteSequence for NotifyMscRequest {
+impl NotifyMscRequest {
     #[inline]
+    pub fn builder() -> NotifyMscRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<NotifyMscRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for NotifyMscRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.serial.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index += self.target_msc.as_bytes(&mut bytes[index..]);
         index += self.divisor.as_bytes(&mut bytes[index..]);
         index += self.remainder.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyMscRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = parse_item(bytes, index, "serial")?;
         index += sz;
         index += 4;
-        let (target_msc, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (target_msc, sz): (Card64, usize) = parse_item(bytes, index, "target_msc")?;
         index += sz;
-        let (divisor, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (divisor, sz): (Card64, usize) = parse_item(bytes, index, "divisor")?;
         index += sz;
-        let (remainder, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (remainder, sz): (Card64, usize) = parse_item(bytes, index, "remainder")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             NotifyMscRequest {
                 req_type: req_type,
                 length: length,
@@ -365,25 +696,82 @@ impl AsByteSequence for NotifyMscRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.window.size()
-            + self.serial.size()
-            + 4
-            + self.target_msc.size()
-            + self.divisor.size()
-            + self.remainder.size()
+        let size: usize = 36 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for NotifyMscRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for NotifyMscRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Present");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "NotifyMsc";
     type Reply = ();
 }
+#[doc = " A builder for `NotifyMscRequest`."]
+#[derive(Clone, Debug)]
+pub struct NotifyMscRequestBuilder {
+    pub inner: NotifyMscRequest,
+}
+impl NotifyMscRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn serial(mut self, serial: Card32) -> Self {
+        self.inner.serial = serial;
+        self
+    }
+    #[inline]
+    pub fn target_msc(mut self, target_msc: Card64) -> Self {
+        self.inner.target_msc = target_msc;
+        self
+    }
+    #[inline]
+    pub fn divisor(mut self, divisor: Card64) -> Self {
+        self.inner.divisor = divisor;
+        self
+    }
+    #[inline]
+    pub fn remainder(mut self, remainder: Card64) -> Self {
+        self.inner.remainder = remainder;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> NotifyMscRequest {
+        self.inner
+    }
+}
+impl Default for NotifyMscRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: NotifyMscRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                serial: Default::default(),
+                target_msc: Default::default(),
+                divisor: Default::default(),
+                remainder: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Event {
     pub xid: XID,
 }
@@ -392,6 +780,23 @@ impl Event {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Event {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Event {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Event {
     #[inline]
@@ -403,7 +808,30 @@ impl XidType for Event {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Event {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Event({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Event {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Event> for XID {
+    #[inline]
+    fn from(base: Event) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectInputRequest {
     pub req_type: u8,
     pub length: u16,
@@ -411,35 +839,44 @@ pub struct SelectInputRequest {
     pub window: Window,
     pub event_mask: EventMask,
 }
-impl SelectInputRequest {}
-impl AsByteSequence for SelectInputRequest {
+impl SelectInputRequest {
     #[inline]
+    pub fn builder() -> SelectInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("eid", 4, 4)];
+}
+const _: () = assert!(<SelectInputRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectInputRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.eid.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (eid, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (eid, sz): (Event, usize) = parse_item(bytes, index, "eid")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (EventMask, usize) = parse_item(bytes, index, "event_mask")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectInputRequest {
                 req_type: req_type,
                 length: length,
@@ -452,22 +889,70 @@ impl AsByteSequence for SelectInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.eid.size()
-            + self.window.size()
-            + self.event_mask.size()
+        let size: usize = 12 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectInputRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Present");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
+#[doc = " A builder for `SelectInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectInputRequestBuilder {
+    pub inner: SelectInputRequest,
+}
+impl SelectInputRequestBuilder {
+    #[inline]
+    pub fn eid(mut self, eid: Event) -> Self {
+        self.inner.eid = eid;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn event_mask(mut self, event_mask: EventMask) -> Self {
+        self.inner.event_mask = event_mask;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectInputRequest {
+        self.inner
+    }
+}
+impl Default for SelectInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                eid: Default::default(),
+                window: Default::default(),
+                event_mask: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct EventMask {
     pub inner: u32,
 }
@@ -556,19 +1041,39 @@ impl EventMask {
     pub const REDIRECT_NOTIFY: Self = Self { inner: 8 };
     pub const COMPLETE: Self = Self { inner: 15 };
 }
+impl core::ops::Deref for EventMask {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &u32 {
+        &self.inner
+    }
+}
+impl AsRef<u32> for EventMask {
+    #[inline]
+    fn as_ref(&self) -> &u32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for EventMask {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((EventMask { inner: inner }, sz))
+        Ok((EventMask { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for EventMask {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for EventMask {
@@ -605,35 +1110,61 @@ impl core::ops::BitXor for EventMask {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryCapabilitiesRequest {
     pub req_type: u8,
     pub length: u16,
     pub target: Card32,
 }
-impl QueryCapabilitiesRequest {}
-impl AsByteSequence for QueryCapabilitiesRequest {
+impl QueryCapabilitiesRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryCapabilitiesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryCapabilitiesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("target", 4, 4)];
+}
+const _: () = assert!(<QueryCapabilitiesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryCapabilitiesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.target.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryCapabilitiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = parse_item(bytes, index, "target")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryCapabilitiesRequest {
                 req_type: req_type,
                 length: length,
@@ -644,48 +1175,150 @@ impl AsByteSequence for QueryCapabilitiesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.target.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryCapabilitiesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryCapabilitiesRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Present");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryCapabilities";
     type Reply = QueryCapabilitiesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryCapabilitiesRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryCapabilitiesRequestBuilder {
+    pub inner: QueryCapabilitiesRequest,
+}
+impl QueryCapabilitiesRequestBuilder {
+    #[inline]
+    pub fn target(mut self, target: Card32) -> Self {
+        self.inner.target = target;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryCapabilitiesRequest {
+        self.inner
+    }
+}
+impl Default for QueryCapabilitiesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryCapabilitiesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                target: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryCapabilitiesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub capabilities: Card32,
 }
-impl QueryCapabilitiesReply {}
-impl AsByteSequence for QueryCapabilitiesReply {
+impl QueryCapabilitiesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let capabilities: Card32 = read_item(reader, &mut index, 4, "capabilities")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryCapabilitiesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            capabilities: capabilities,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("capabilities", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.capabilities == other.capabilities
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "capabilities",
+            &self.capabilities,
+            &other.capabilities,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryCapabilitiesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.capabilities.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryCapabilitiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (capabilities, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (capabilities, sz): (Card32, usize) = parse_item(bytes, index, "capabilities")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryCapabilitiesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -697,15 +1330,21 @@ impl AsByteSequence for QueryCapabilitiesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.capabilities.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryCapabilitiesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum CompleteKind {
     Pixmap = 0,
     NotifyMsc = 1,
@@ -716,12 +1355,12 @@ impl AsByteSequence for CompleteKind {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Pixmap, sz)),
-            1 => Some((Self::NotifyMsc, sz)),
-            _ => None,
+            0 => Ok((Self::Pixmap, sz)),
+            1 => Ok((Self::NotifyMsc, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -729,17 +1368,43 @@ impl AsByteSequence for CompleteKind {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for CompleteKind {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for CompleteKind {
     #[inline]
     fn default() -> CompleteKind {
         CompleteKind::Pixmap
     }
 }
-#[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum CompleteMode {
-    Copy = 0,
-    Flip = 1,
+impl From<CompleteKind> for u8 {
+    #[inline]
+    fn from(base: CompleteKind) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for CompleteKind {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Pixmap),
+            1 => Ok(Self::NotifyMsc),
+            _ => Err(value),
+        }
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum CompleteMode {
+    Copy = 0,
+    Flip = 1,
     Skip = 2,
     SuboptimalCopy = 3,
 }
@@ -749,14 +1414,14 @@ impl AsByteSequence for CompleteMode {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Copy, sz)),
-            1 => Some((Self::Flip, sz)),
-            2 => Some((Self::Skip, sz)),
-            3 => Some((Self::SuboptimalCopy, sz)),
-            _ => None,
+            0 => Ok((Self::Copy, sz)),
+            1 => Ok((Self::Flip, sz)),
+            2 => Ok((Self::Skip, sz)),
+            3 => Ok((Self::SuboptimalCopy, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -764,18 +1429,42 @@ impl AsByteSequence for CompleteMode {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for CompleteMode {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for CompleteMode {
     #[inline]
     fn default() -> CompleteMode {
         CompleteMode::Copy
     }
 }
-pub const EVENT_CONFIGURE_NOTIFY: Event = <Event>::const_from_xid(0);
-pub const EVENT_COMPLETE_NOTIFY: Event = <Event>::const_from_xid(1);
-pub const EVENT_IDLE_NOTIFY: Event = <Event>::const_from_xid(2);
-pub const EVENT_REDIRECT_NOTIFY: Event = <Event>::const_from_xid(3);
+impl From<CompleteMode> for u8 {
+    #[inline]
+    fn from(base: CompleteMode) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for CompleteMode {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Copy),
+            1 => Ok(Self::Flip),
+            2 => Ok(Self::Skip),
+            3 => Ok(Self::SuboptimalCopy),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Capability {
     pub inner: i32,
 }
@@ -842,21 +1531,46 @@ impl Capability {
     pub const UST: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 7 };
 }
-impl AsByteSequence for Capability {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for Capability {
+    type Target = i32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((Capability { inner: inner }, sz))
+    fn deref(&self) -> &i32 {
+        &self.inner
     }
+}
+impl AsRef<i32> for Capability {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &i32 {
+        &self.inner
     }
 }
+macro_rules! impl_i32_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                4
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_i32_flags_asb! { Capability }
 impl core::ops::Not for Capability {
     type Output = Capability;
     #[inline]
@@ -891,8 +1605,14 @@ impl core::ops::BitXor for Capability {
         }
     }
 }
+pub const EVENT_CONFIGURE_NOTIFY: Event = <Event>::const_from_xid(0);
+pub const EVENT_COMPLETE_NOTIFY: Event = <Event>::const_from_xid(1);
+pub const EVENT_IDLE_NOTIFY: Event = <Event>::const_from_xid(2);
+pub const EVENT_REDIRECT_NOTIFY: Event = <Event>::const_from_xid(3);
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Option_ {
     pub inner: i32,
 }
@@ -976,21 +1696,20 @@ impl Option_ {
     pub const SUBOPTIMAL: Self = Self { inner: 8 };
     pub const COMPLETE: Self = Self { inner: 15 };
 }
-impl AsByteSequence for Option_ {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for Option_ {
+    type Target = i32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((Option_ { inner: inner }, sz))
+    fn deref(&self) -> &i32 {
+        &self.inner
     }
+}
+impl AsRef<i32> for Option_ {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &i32 {
+        &self.inner
     }
 }
+impl_i32_flags_asb! { Option_ }
 impl core::ops::Not for Option_ {
     type Output = Option_;
     #[inline]
@@ -1025,18 +1744,48 @@ impl core::ops::BitXor for Option_ {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
-pub struct GenericEvent {
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct CompleteNotifyEvent {
     pub event_type: u8,
-    pub extension: Card8,
+    pub extension: u8,
     pub sequence: u16,
-    pub length: Card32,
-    pub evtype: Card16,
+    pub length: u32,
+    pub evtype: u16,
+    pub kind: CompleteKind,
+    pub mode: CompleteMode,
     pub event: Event,
+    pub window: Window,
+    pub serial: Card32,
+    pub ust: Card64,
+    pub msc: Card64,
 }
-impl GenericEvent {}
-impl AsByteSequence for GenericEvent {
-    #[inline]
+impl CompleteNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event."]
+    pub const RESPONSE_TYPE: u8 = 35;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("extension", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("evtype", 8, 2),
+        ("kind", 10, 1),
+        ("mode", 11, 1),
+        ("event", 12, 4),
+    ];
+}
+impl AsByteSequence for CompleteNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -1044,311 +1793,516 @@ impl AsByteSequence for GenericEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.evtype.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += self.kind.as_bytes(&mut bytes[index..]);
+        index += self.mode.as_bytes(&mut bytes[index..]);
         index += self.event.as_bytes(&mut bytes[index..]);
+        index += self.window.as_bytes(&mut bytes[index..]);
+        index += self.serial.as_bytes(&mut bytes[index..]);
+        index += self.ust.as_bytes(&mut bytes[index..]);
+        index += self.msc.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GenericEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing CompleteNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (extension, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (extension, sz): (u8, usize) = parse_item(bytes, index, "extension")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (evtype, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (evtype, sz): (u16, usize) = parse_item(bytes, index, "evtype")?;
         index += sz;
-        index += 2;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (CompleteKind, usize) = parse_item(bytes, index, "kind")?;
         index += sz;
-        Some((
-            GenericEvent {
+        let (mode, sz): (CompleteMode, usize) = parse_item(bytes, index, "mode")?;
+        index += sz;
+        let (event, sz): (Event, usize) = parse_item(bytes, index, "event")?;
+        index += sz;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+        index += sz;
+        let (serial, sz): (Card32, usize) = parse_item(bytes, index, "serial")?;
+        index += sz;
+        let (ust, sz): (Card64, usize) = parse_item(bytes, index, "ust")?;
+        index += sz;
+        let (msc, sz): (Card64, usize) = parse_item(bytes, index, "msc")?;
+        index += sz;
+        Ok((
+            CompleteNotifyEvent {
                 event_type: event_type,
                 extension: extension,
                 sequence: sequence,
                 length: length,
                 evtype: evtype,
+                kind: kind,
+                mode: mode,
                 event: event,
+                window: window,
+                serial: serial,
+                ust: ust,
+                msc: msc,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.extension.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.evtype.size()
-            + 2
-            + self.event.size()
+        36 + self.window.size()
     }
 }
-impl crate::auto::Event for GenericEvent {
-    const OPCODE: u8 = 0;
+impl core::convert::TryFrom<&[u8]> for CompleteNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct CompleteNotifyEvent {
+impl crate::auto::Event for CompleteNotifyEvent {
+    const OPCODE: u8 = 1;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct ConfigureNotifyEvent {
     pub event_type: u8,
-    pub kind: CompleteKind,
+    pub extension: u8,
     pub sequence: u16,
-    pub mode: CompleteMode,
+    pub length: u32,
+    pub evtype: u16,
     pub event: Event,
     pub window: Window,
-    pub serial: Card32,
-    pub ust: Card64,
-    pub msc: Card64,
+    pub x: Int16,
+    pub y: Int16,
+    pub width: Card16,
+    pub height: Card16,
+    pub off_x: Int16,
+    pub off_y: Int16,
+    pub pixmap_width: Card16,
+    pub pixmap_height: Card16,
+    pub pixmap_flags: Card32,
 }
-impl CompleteNotifyEvent {}
-impl AsByteSequence for CompleteNotifyEvent {
-    #[inline]
+impl ConfigureNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event."]
+    pub const RESPONSE_TYPE: u8 = 35;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("extension", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("evtype", 8, 2),
+        ("event", 12, 4),
+    ];
+}
+impl AsByteSequence for ConfigureNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += self.kind.as_bytes(&mut bytes[index..]);
+        index += self.extension.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.mode.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.evtype.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.event.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.serial.as_bytes(&mut bytes[index..]);
-        index += self.ust.as_bytes(&mut bytes[index..]);
-        index += self.msc.as_bytes(&mut bytes[index..]);
+        index += self.x.as_bytes(&mut bytes[index..]);
+        index += self.y.as_bytes(&mut bytes[index..]);
+        index += self.width.as_bytes(&mut bytes[index..]);
+        index += self.height.as_bytes(&mut bytes[index..]);
+        index += self.off_x.as_bytes(&mut bytes[index..]);
+        index += self.off_y.as_bytes(&mut bytes[index..]);
+        index += self.pixmap_width.as_bytes(&mut bytes[index..]);
+        index += self.pixmap_height.as_bytes(&mut bytes[index..]);
+        index += self.pixmap_flags.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing CompleteNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing ConfigureNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (kind, sz): (CompleteKind, usize) = <CompleteKind>::from_bytes(&bytes[index..])?;
+        let (extension, sz): (u8, usize) = parse_item(bytes, index, "extension")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (mode, sz): (CompleteMode, usize) = <CompleteMode>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (evtype, sz): (u16, usize) = parse_item(bytes, index, "evtype")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        index += 2;
+        let (event, sz): (Event, usize) = parse_item(bytes, index, "event")?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (ust, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (msc, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        Some((
-            CompleteNotifyEvent {
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        let (off_x, sz): (Int16, usize) = parse_item(bytes, index, "off_x")?;
+        index += sz;
+        let (off_y, sz): (Int16, usize) = parse_item(bytes, index, "off_y")?;
+        index += sz;
+        let (pixmap_width, sz): (Card16, usize) = parse_item(bytes, index, "pixmap_width")?;
+        index += sz;
+        let (pixmap_height, sz): (Card16, usize) = parse_item(bytes, index, "pixmap_height")?;
+        index += sz;
+        let (pixmap_flags, sz): (Card32, usize) = parse_item(bytes, index, "pixmap_flags")?;
+        index += sz;
+        Ok((
+            ConfigureNotifyEvent {
                 event_type: event_type,
-                kind: kind,
+                extension: extension,
                 sequence: sequence,
-                mode: mode,
+                length: length,
+                evtype: evtype,
                 event: event,
                 window: window,
-                serial: serial,
-                ust: ust,
-                msc: msc,
+                x: x,
+                y: y,
+                width: width,
+                height: height,
+                off_x: off_x,
+                off_y: off_y,
+                pixmap_width: pixmap_width,
+                pixmap_height: pixmap_height,
+                pixmap_flags: pixmap_flags,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.kind.size()
-            + self.sequence.size()
-            + self.mode.size()
-            + self.event.size()
-            + self.window.size()
-            + self.serial.size()
-            + self.ust.size()
-            + self.msc.size()
+        36 + self.window.size()
     }
 }
-impl crate::auto::Event for CompleteNotifyEvent {
-    const OPCODE: u8 = 1;
+impl core::convert::TryFrom<&[u8]> for ConfigureNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct IdleNotifyEvent {
+impl crate::auto::Event for ConfigureNotifyEvent {
+    const OPCODE: u8 = 0;
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct GenericEvent {
     pub event_type: u8,
+    pub extension: Card8,
     pub sequence: u16,
+    pub length: Card32,
+    pub evtype: Card16,
     pub event: Event,
-    pub window: Window,
-    pub serial: Card32,
-    pub pixmap: Pixmap,
-    pub idle_fence: Fence,
 }
-impl IdleNotifyEvent {}
-impl AsByteSequence for IdleNotifyEvent {
-    #[inline]
+impl GenericEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("extension", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("evtype", 8, 2),
+        ("event", 12, 4),
+    ];
+}
+impl AsByteSequence for GenericEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += self.extension.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.evtype.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.event.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.serial.as_bytes(&mut bytes[index..]);
-        index += self.pixmap.as_bytes(&mut bytes[index..]);
-        index += self.idle_fence.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing IdleNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing GenericEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (extension, sz): (Card8, usize) = parse_item(bytes, index, "extension")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (evtype, sz): (Card16, usize) = parse_item(bytes, index, "evtype")?;
         index += sz;
-        let (idle_fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        index += 2;
+        let (event, sz): (Event, usize) = parse_item(bytes, index, "event")?;
         index += sz;
-        Some((
-            IdleNotifyEvent {
+        Ok((
+            GenericEvent {
                 event_type: event_type,
+                extension: extension,
                 sequence: sequence,
+                length: length,
+                evtype: evtype,
                 event: event,
-                window: window,
-                serial: serial,
-                pixmap: pixmap,
-                idle_fence: idle_fence,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + 2
-            + self.sequence.size()
-            + self.event.size()
-            + self.window.size()
-            + self.serial.size()
-            + self.pixmap.size()
-            + self.idle_fence.size()
+        16
     }
 }
-impl crate::auto::Event for IdleNotifyEvent {
-    const OPCODE: u8 = 2;
+impl core::convert::TryFrom<&[u8]> for GenericEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct ConfigureNotifyEvent {
+impl crate::auto::Event for GenericEvent {
+    const OPCODE: u8 = 0;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct IdleNotifyEvent {
     pub event_type: u8,
+    pub extension: u8,
     pub sequence: u16,
+    pub length: u32,
+    pub evtype: u16,
     pub event: Event,
     pub window: Window,
-    pub x: Int16,
-    pub y: Int16,
-    pub width: Card16,
-    pub height: Card16,
-    pub off_x: Int16,
-    pub off_y: Int16,
-    pub pixmap_width: Card16,
-    pub pixmap_height: Card16,
-    pub pixmap_flags: Card32,
+    pub serial: Card32,
+    pub pixmap: Pixmap,
+    pub idle_fence: Fence,
 }
-impl ConfigureNotifyEvent {}
-impl AsByteSequence for ConfigureNotifyEvent {
-    #[inline]
+impl IdleNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event."]
+    pub const RESPONSE_TYPE: u8 = 35;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("extension", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("evtype", 8, 2),
+        ("event", 12, 4),
+    ];
+}
+impl AsByteSequence for IdleNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += self.extension.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.evtype.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.event.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.x.as_bytes(&mut bytes[index..]);
-        index += self.y.as_bytes(&mut bytes[index..]);
-        index += self.width.as_bytes(&mut bytes[index..]);
-        index += self.height.as_bytes(&mut bytes[index..]);
-        index += self.off_x.as_bytes(&mut bytes[index..]);
-        index += self.off_y.as_bytes(&mut bytes[index..]);
-        index += self.pixmap_width.as_bytes(&mut bytes[index..]);
-        index += self.pixmap_height.as_bytes(&mut bytes[index..]);
-        index += self.pixmap_flags.as_bytes(&mut bytes[index..]);
+        index += self.serial.as_bytes(&mut bytes[index..]);
+        index += self.pixmap.as_bytes(&mut bytes[index..]);
+        index += self.idle_fence.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing ConfigureNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing IdleNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (extension, sz): (u8, usize) = parse_item(bytes, index, "extension")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (evtype, sz): (u16, usize) = parse_item(bytes, index, "evtype")?;
         index += sz;
-        let (off_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        index += 2;
+        let (event, sz): (Event, usize) = parse_item(bytes, index, "event")?;
         index += sz;
-        let (off_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (pixmap_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = parse_item(bytes, index, "serial")?;
         index += sz;
-        let (pixmap_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = parse_item(bytes, index, "pixmap")?;
         index += sz;
-        let (pixmap_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (idle_fence, sz): (Fence, usize) = parse_item(bytes, index, "idle_fence")?;
         index += sz;
-        Some((
-            ConfigureNotifyEvent {
+        Ok((
+            IdleNotifyEvent {
                 event_type: event_type,
+                extension: extension,
                 sequence: sequence,
+                length: length,
+                evtype: evtype,
                 event: event,
                 window: window,
-                x: x,
-                y: y,
-                width: width,
-                height: height,
-                off_x: off_x,
-                off_y: off_y,
-                pixmap_width: pixmap_width,
-                pixmap_height: pixmap_height,
-                pixmap_flags: pixmap_flags,
+                serial: serial,
+                pixmap: pixmap,
+                idle_fence: idle_fence,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + 2
-            + self.sequence.size()
-            + self.event.size()
-            + self.window.size()
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
-            + self.off_x.size()
-            + self.off_y.size()
-            + self.pixmap_width.size()
-            + self.pixmap_height.size()
-            + self.pixmap_flags.size()
+        20 + self.window.size() + self.pixmap.size() + self.idle_fence.size()
     }
 }
-impl crate::auto::Event for ConfigureNotifyEvent {
-    const OPCODE: u8 = 0;
+impl core::convert::TryFrom<&[u8]> for IdleNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl crate::auto::Event for IdleNotifyEvent {
+    const OPCODE: u8 = 2;
+}
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum AnyEvent {
+    Generic(GenericEvent),
+    CompleteNotify(CompleteNotifyEvent),
+    ConfigureNotify(ConfigureNotifyEvent),
+    IdleNotify(IdleNotifyEvent),
+}
+impl AnyEvent {
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        if let Some((evtype, bytes)) = generic_event_bytes(bytes)? {
+            return match evtype {
+                1 => Ok(Self::CompleteNotify(
+                    CompleteNotifyEvent::from_bytes(bytes)?.0,
+                )),
+                0 => Ok(Self::ConfigureNotify(
+                    ConfigureNotifyEvent::from_bytes(bytes)?.0,
+                )),
+                2 => Ok(Self::IdleNotify(IdleNotifyEvent::from_bytes(bytes)?.0)),
+                _ => Err(ParseError {
+                    field: "evtype",
+                    offset: 8,
+                }),
+            };
+        }
+        match opcode {
+            0 => Ok(Self::Generic(GenericEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::Generic(_) => GenericEvent::RESPONSE_TYPE,
+            Self::CompleteNotify(_) => CompleteNotifyEvent::RESPONSE_TYPE,
+            Self::ConfigureNotify(_) => ConfigureNotifyEvent::RESPONSE_TYPE,
+            Self::IdleNotify(_) => IdleNotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<GenericEvent> for AnyEvent {
+    #[inline]
+    fn from(event: GenericEvent) -> Self {
+        Self::Generic(event)
+    }
+}
+impl From<CompleteNotifyEvent> for AnyEvent {
+    #[inline]
+    fn from(event: CompleteNotifyEvent) -> Self {
+        Self::CompleteNotify(event)
+    }
+}
+impl From<ConfigureNotifyEvent> for AnyEvent {
+    #[inline]
+    fn from(event: ConfigureNotifyEvent) -> Self {
+        Self::ConfigureNotify(event)
+    }
+}
+impl From<IdleNotifyEvent> for AnyEvent {
+    #[inline]
+    fn from(event: IdleNotifyEvent) -> Self {
+        Self::IdleNotify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "Generic")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[
+    (0, "ConfigureNotify"),
+    (1, "CompleteNotify"),
+    (2, "IdleNotify"),
+];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "Pixmap"),
+    (2, "NotifyMsc"),
+    (3, "SelectInput"),
+    (4, "QueryCapabilities"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
 }
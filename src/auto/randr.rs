@@ -1,14 +1,23 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "randr")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+#[cfg(not(feature = "render"))]
+compile_error!(
+    "The `randr` feature requires the `render` feature, since it uses the types that it defines"
+);
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 6;
 use super::render::*;
 use super::xproto::*;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Mode {
     pub xid: XID,
 }
@@ -17,6 +26,23 @@ impl Mode {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Mode {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Mode {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Mode {
     #[inline]
@@ -28,8 +54,30 @@ impl XidType for Mode {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Mode {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Mode({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Mode {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Mode> for XID {
+    #[inline]
+    fn from(base: Mode) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Crtc {
     pub xid: XID,
 }
@@ -38,6 +86,23 @@ impl Crtc {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Crtc {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Crtc {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Crtc {
     #[inline]
@@ -49,8 +114,30 @@ impl XidType for Crtc {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Crtc {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Crtc({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Crtc {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Crtc> for XID {
+    #[inline]
+    fn from(base: Crtc) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Output {
     pub xid: XID,
 }
@@ -59,6 +146,23 @@ impl Output {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Output {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Output {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Output {
     #[inline]
@@ -70,8 +174,30 @@ impl XidType for Output {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Output {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Output({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Output {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Output> for XID {
+    #[inline]
+    fn from(base: Output) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Provider {
     pub xid: XID,
 }
@@ -80,6 +206,23 @@ impl Provider {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Provider {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Provider {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Provider {
     #[inline]
@@ -91,8 +234,30 @@ impl XidType for Provider {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Provider {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Provider({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Provider {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Provider> for XID {
+    #[inline]
+    fn from(base: Provider) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Lease {
     pub xid: XID,
 }
@@ -101,6 +266,23 @@ impl Lease {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Lease {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Lease {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Lease {
     #[inline]
@@ -112,14 +294,61 @@ impl XidType for Lease {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Lease {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Lease({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Lease {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Lease> for XID {
+    #[inline]
+    fn from(base: Lease) -> Self {
+        base.xid
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ScreenSize {
     pub width: Card16,
     pub height: Card16,
     pub mwidth: Card16,
     pub mheight: Card16,
 }
-impl ScreenSize {}
+impl ScreenSize {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(width: Card16, height: Card16, mwidth: Card16, mheight: Card16) -> Self {
+        Self {
+            width: width,
+            height: height,
+            mwidth: mwidth,
+            mheight: mheight,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("width", 0, 2),
+        ("height", 2, 2),
+        ("mwidth", 4, 2),
+        ("mheight", 6, 2),
+    ];
+}
 impl AsByteSequence for ScreenSize {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -130,19 +359,18 @@ impl AsByteSequence for ScreenSize {
         index += self.mheight.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ScreenSize from byte buffer");
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (mwidth, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mwidth, sz): (Card16, usize) = parse_item(bytes, index, "mwidth")?;
         index += sz;
-        let (mheight, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mheight, sz): (Card16, usize) = parse_item(bytes, index, "mheight")?;
         index += sz;
-        Some((
+        Ok((
             ScreenSize {
                 width: width,
                 height: height,
@@ -154,14 +382,35 @@ impl AsByteSequence for ScreenSize {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.width.size() + self.height.size() + self.mwidth.size() + self.mheight.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ScreenSize {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct RefreshRates {
     pub rates: Vec<Card16>,
 }
-impl RefreshRates {}
+impl RefreshRates {
+    #[inline]
+    pub fn rates_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (rates, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "rates")?;
+        Ok(rates)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("rates_len", 0, 2)];
+}
 impl AsByteSequence for RefreshRates {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -169,63 +418,110 @@ impl AsByteSequence for RefreshRates {
         index += (self.rates.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.rates, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing RefreshRates from byte buffer");
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (rates, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "rates")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        Some((RefreshRates { rates: rates }, index))
+        Ok((RefreshRates { rates: rates }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        ::core::mem::size_of::<Card16>() + {
+        2 + {
             let block_len: usize = self.rates.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
             block_len + pad
         }
     }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        2 + {
+            let block_len: usize = self.rates.len() * ::core::mem::size_of::<Card16>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+            block_len + pad
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for RefreshRates {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("major_version", 4, 4),
+        ("minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -237,20 +533,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn major_version(mut self, major_version: Card32) -> Self {
+        self.inner.major_version = major_version;
+        self
+    }
+    #[inline]
+    pub fn minor_version(mut self, minor_version: Card32) -> Self {
+        self.inner.minor_version = minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                major_version: Default::default(),
+                minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -258,37 +597,108 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_bytes(reader, &mut index, 16)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for QueryVersionReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
         index += 16;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -301,16 +711,21 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
-            + 16
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetScreenConfigRequest {
     pub req_type: u8,
     pub length: u16,
@@ -321,13 +736,28 @@ pub struct SetScreenConfigRequest {
     pub rotation: Rotation,
     pub rate: Card16,
 }
-impl SetScreenConfigRequest {}
-impl AsByteSequence for SetScreenConfigRequest {
+impl SetScreenConfigRequest {
+    #[inline]
+    pub fn builder() -> SetScreenConfigRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<SetScreenConfigReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetScreenConfigRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetScreenConfigRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
@@ -335,32 +765,34 @@ impl AsByteSequence for SetScreenConfigRequest {
         index += self.size_id.as_bytes(&mut bytes[index..]);
         index += self.rotation.as_bytes(&mut bytes[index..]);
         index += self.rate.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetScreenConfigRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size_id, sz): (Card16, usize) = parse_item(bytes, index, "size_id")?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
         index += sz;
-        let (rate, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (rate, sz): (Card16, usize) = parse_item(bytes, index, "rate")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetScreenConfigRequest {
                 req_type: req_type,
                 length: length,
@@ -376,25 +808,88 @@ impl AsByteSequence for SetScreenConfigRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.window.size()
-            + self.timestamp.size()
-            + self.config_timestamp.size()
-            + self.size_id.size()
-            + self.rotation.size()
-            + self.rate.size()
-            + 2
+        let size: usize =
+            12 + self.window.size() + self.timestamp.size() + self.config_timestamp.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetScreenConfigRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetScreenConfigRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetScreenConfig";
     type Reply = SetScreenConfigReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetScreenConfigRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetScreenConfigRequestBuilder {
+    pub inner: SetScreenConfigRequest,
+}
+impl SetScreenConfigRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.inner.timestamp = timestamp;
+        self
+    }
+    #[inline]
+    pub fn config_timestamp(mut self, config_timestamp: Timestamp) -> Self {
+        self.inner.config_timestamp = config_timestamp;
+        self
+    }
+    #[inline]
+    pub fn size_id(mut self, size_id: Card16) -> Self {
+        self.inner.size_id = size_id;
+        self
+    }
+    #[inline]
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.inner.rotation = rotation;
+        self
+    }
+    #[inline]
+    pub fn rate(mut self, rate: Card16) -> Self {
+        self.inner.rate = rate;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetScreenConfigRequest {
+        self.inner
+    }
+}
+impl Default for SetScreenConfigRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetScreenConfigRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                timestamp: Default::default(),
+                config_timestamp: Default::default(),
+                size_id: Default::default(),
+                rotation: Default::default(),
+                rate: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetScreenConfigReply {
     pub reply_type: u8,
     pub status: SetConfig,
@@ -405,9 +900,63 @@ pub struct SetScreenConfigReply {
     pub root: Window,
     pub subpixel_order: SubPixel,
 }
-impl SetScreenConfigReply {}
+impl SetScreenConfigReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.new_timestamp == other.new_timestamp
+            && self.config_timestamp == other.config_timestamp
+            && self.root == other.root
+            && self.subpixel_order == other.subpixel_order
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value(
+            "new_timestamp",
+            &self.new_timestamp,
+            &other.new_timestamp,
+            &mut changes,
+        );
+        diff_value(
+            "config_timestamp",
+            &self.config_timestamp,
+            &other.config_timestamp,
+            &mut changes,
+        );
+        diff_value("root", &self.root, &other.root, &mut changes);
+        diff_value(
+            "subpixel_order",
+            &self.subpixel_order,
+            &other.subpixel_order,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for SetScreenConfigReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -418,31 +967,34 @@ impl AsByteSequence for SetScreenConfigReply {
         index += self.config_timestamp.as_bytes(&mut bytes[index..]);
         index += self.root.as_bytes(&mut bytes[index..]);
         index += self.subpixel_order.as_bytes(&mut bytes[index..]);
-        index += 10;
+        index += zero_pad(&mut bytes[index..], 10);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetScreenConfigReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (new_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (new_timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "new_timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = parse_item(bytes, index, "subpixel_order")?;
         index += sz;
         index += 10;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetScreenConfigReply {
                 reply_type: reply_type,
                 status: status,
@@ -458,19 +1010,25 @@ impl AsByteSequence for SetScreenConfigReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
+        let size: usize = 18
             + self.new_timestamp.size()
             + self.config_timestamp.size()
             + self.root.size()
-            + self.subpixel_order.size()
-            + 10
+            + self.subpixel_order.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetScreenConfigReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Rotation {
     pub inner: u16,
 }
@@ -595,21 +1153,46 @@ impl Rotation {
     pub const REFLECT_Y: Self = Self { inner: 32 };
     pub const COMPLETE: Self = Self { inner: 63 };
 }
-impl AsByteSequence for Rotation {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for Rotation {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((Rotation { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for Rotation {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+macro_rules! impl_u16_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                2
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_u16_flags_asb! { Rotation }
 impl core::ops::Not for Rotation {
     type Output = Rotation;
     #[inline]
@@ -645,7 +1228,9 @@ impl core::ops::BitXor for Rotation {
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SetConfig {
     Success = 0,
     InvalidConfigTime = 1,
@@ -658,14 +1243,14 @@ impl AsByteSequence for SetConfig {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Success, sz)),
-            1 => Some((Self::InvalidConfigTime, sz)),
-            2 => Some((Self::InvalidTime, sz)),
-            3 => Some((Self::Failed, sz)),
-            _ => None,
+            0 => Ok((Self::Success, sz)),
+            1 => Ok((Self::InvalidConfigTime, sz)),
+            2 => Ok((Self::InvalidTime, sz)),
+            3 => Ok((Self::Failed, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -673,47 +1258,84 @@ impl AsByteSequence for SetConfig {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SetConfig {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SetConfig {
     #[inline]
     fn default() -> SetConfig {
         SetConfig::Success
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<SetConfig> for u8 {
+    #[inline]
+    fn from(base: SetConfig) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SetConfig {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Success),
+            1 => Ok(Self::InvalidConfigTime),
+            2 => Ok(Self::InvalidTime),
+            3 => Ok(Self::Failed),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectInputRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub enable: NotifyMask,
 }
-impl SelectInputRequest {}
-impl AsByteSequence for SelectInputRequest {
+impl SelectInputRequest {
     #[inline]
+    pub fn builder() -> SelectInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SelectInputRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectInputRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.enable.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (enable, sz): (NotifyMask, usize) = <NotifyMask>::from_bytes(&bytes[index..])?;
+        let (enable, sz): (NotifyMask, usize) = parse_item(bytes, index, "enable")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectInputRequest {
                 req_type: req_type,
                 length: length,
@@ -725,24 +1347,71 @@ impl AsByteSequence for SelectInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.enable.size() + 2
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectInputRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct NotifyMask {
-    pub inner: u16,
+#[doc = " A builder for `SelectInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectInputRequestBuilder {
+    pub inner: SelectInputRequest,
 }
-impl NotifyMask {
+impl SelectInputRequestBuilder {
     #[inline]
-    pub fn screen_change(&self) -> bool {
-        self.inner & (1 << 0) != 0
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn enable(mut self, enable: NotifyMask) -> Self {
+        self.inner.enable = enable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectInputRequest {
+        self.inner
+    }
+}
+impl Default for SelectInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                enable: Default::default(),
+            },
+        }
+    }
+}
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct NotifyMask {
+    pub inner: u16,
+}
+impl NotifyMask {
+    #[inline]
+    pub fn screen_change(&self) -> bool {
+        self.inner & (1 << 0) != 0
     }
     #[inline]
     pub fn set_screen_change(&mut self, val: bool) -> &mut Self {
@@ -844,7 +1513,6 @@ impl NotifyMask {
         }
         self
     }
-    #[inline]
     pub fn new(
         screen_change: bool,
         crtc_change: bool,
@@ -896,21 +1564,20 @@ impl NotifyMask {
     pub const LEASE: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 255 };
 }
-impl AsByteSequence for NotifyMask {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for NotifyMask {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((NotifyMask { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for NotifyMask {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { NotifyMask }
 impl core::ops::Not for NotifyMask {
     type Output = NotifyMask;
     #[inline]
@@ -945,55 +1612,123 @@ impl core::ops::BitXor for NotifyMask {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetScreenInfoRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetScreenInfoRequest {}
-impl AsByteSequence for GetScreenInfoRequest {
+impl GetScreenInfoRequest {
+    #[inline]
+    pub fn builder() -> GetScreenInfoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetScreenInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetScreenInfoRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_window_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.window.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        window: window,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.window.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_window_only_request_asb! { GetScreenInfoRequest }
+impl Request for GetScreenInfoRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 5;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenInfo";
+    type Reply = GetScreenInfoReply;
+}
+#[doc = " A builder for `GetScreenInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetScreenInfoRequestBuilder {
+    pub inner: GetScreenInfoRequest,
+}
+impl GetScreenInfoRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetScreenInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetScreenInfoRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetScreenInfoRequest {
+        self.inner
     }
+}
+impl Default for GetScreenInfoRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    fn default() -> Self {
+        Self {
+            inner: GetScreenInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
     }
 }
-impl Request for GetScreenInfoRequest {
-    const OPCODE: u8 = 5;
-    const EXTENSION: Option<&'static str> = Some("RANDR");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = GetScreenInfoReply;
-}
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetScreenInfoReply {
     pub reply_type: u8,
     pub rotations: Rotation,
@@ -1010,9 +1745,141 @@ pub struct GetScreenInfoReply {
     pub sizes: Vec<ScreenSize>,
     pub rates: Vec<RefreshRates>,
 }
-impl GetScreenInfoReply {}
-impl AsByteSequence for GetScreenInfoReply {
+impl GetScreenInfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn sizes_iter(bytes: &[u8]) -> Result<ListIter<'_, ScreenSize>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (rotations, sz): (Rotation, usize) = parse_item(bytes, index, "rotations")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
+        index += sz;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+        index += sz;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
+        index += sz;
+        let (n_sizes, sz): (Card16, usize) = parse_item(bytes, index, "n_sizes")?;
+        index += sz;
+        let (size_id, sz): (Card16, usize) = parse_item(bytes, index, "size_id")?;
+        index += sz;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
+        index += sz;
+        let (rate, sz): (Card16, usize) = parse_item(bytes, index, "rate")?;
+        index += sz;
+        let (n_info, sz): (Card16, usize) = parse_item(bytes, index, "n_info")?;
+        index += sz;
+        index += 2;
+        Ok(ListIter::new(
+            bytes,
+            index,
+            (n_sizes as usize) as usize,
+            "sizes",
+        ))
+    }
+    pub fn rates_iter(bytes: &[u8]) -> Result<ListIter<'_, RefreshRates>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (rotations, sz): (Rotation, usize) = parse_item(bytes, index, "rotations")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
+        index += sz;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+        index += sz;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
+        index += sz;
+        let (n_sizes, sz): (Card16, usize) = parse_item(bytes, index, "n_sizes")?;
+        index += sz;
+        let (size_id, sz): (Card16, usize) = parse_item(bytes, index, "size_id")?;
+        index += sz;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
+        index += sz;
+        let (rate, sz): (Card16, usize) = parse_item(bytes, index, "rate")?;
+        index += sz;
+        let (n_info, sz): (Card16, usize) = parse_item(bytes, index, "n_info")?;
+        index += sz;
+        index += 2;
+        let (sizes, block_len): (Vec<ScreenSize>, usize) =
+            parse_vector(bytes, index, (n_sizes as usize) as usize, "sizes")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<ScreenSize>());
+        Ok(ListIter::new(
+            bytes,
+            index,
+            ((n_info as usize) - (n_sizes as usize)) as usize,
+            "rates",
+        ))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("rotations", 1, 2),
+        ("sequence", 3, 2),
+        ("length", 5, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.rotations == other.rotations
+            && self.root == other.root
+            && self.timestamp == other.timestamp
+            && self.config_timestamp == other.config_timestamp
+            && self.n_sizes == other.n_sizes
+            && self.size_id == other.size_id
+            && self.rotation == other.rotation
+            && self.rate == other.rate
+            && self.n_info == other.n_info
+            && self.sizes == other.sizes
+            && self.rates == other.rates
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("rotations", &self.rotations, &other.rotations, &mut changes);
+        diff_value("root", &self.root, &other.root, &mut changes);
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value(
+            "config_timestamp",
+            &self.config_timestamp,
+            &other.config_timestamp,
+            &mut changes,
+        );
+        diff_value("n_sizes", &self.n_sizes, &other.n_sizes, &mut changes);
+        diff_value("size_id", &self.size_id, &other.size_id, &mut changes);
+        diff_value("rotation", &self.rotation, &other.rotation, &mut changes);
+        diff_value("rate", &self.rate, &other.rate, &mut changes);
+        diff_value("n_info", &self.n_info, &other.n_info, &mut changes);
+        diff_list("sizes", &self.sizes, &other.sizes, &mut changes);
+        diff_list("rates", &self.rates, &other.rates, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetScreenInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -1027,55 +1894,66 @@ impl AsByteSequence for GetScreenInfoReply {
         index += self.rotation.as_bytes(&mut bytes[index..]);
         index += self.rate.as_bytes(&mut bytes[index..]);
         index += self.n_info.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.sizes, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ScreenSize>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ScreenSize>()),
+        );
         let block_len: usize = vector_as_bytes(&self.rates, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<RefreshRates>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<RefreshRates>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (rotations, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotations, sz): (Rotation, usize) = parse_item(bytes, index, "rotations")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (n_sizes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_sizes, sz): (Card16, usize) = parse_item(bytes, index, "n_sizes")?;
         index += sz;
-        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size_id, sz): (Card16, usize) = parse_item(bytes, index, "size_id")?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
         index += sz;
-        let (rate, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (rate, sz): (Card16, usize) = parse_item(bytes, index, "rate")?;
         index += sz;
-        let (n_info, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_info, sz): (Card16, usize) = parse_item(bytes, index, "n_info")?;
         index += sz;
         index += 2;
         let (sizes, block_len): (Vec<ScreenSize>, usize) =
-            vector_from_bytes(&bytes[index..], (n_sizes as usize) as usize)?;
+            parse_vector(bytes, index, (n_sizes as usize) as usize, "sizes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ScreenSize>());
-        let (rates, block_len): (Vec<RefreshRates>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (rates, block_len): (Vec<RefreshRates>, usize) = parse_vector(
+            bytes,
+            index,
             ((n_info as usize) - (n_sizes as usize)) as usize,
+            "rates",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<RefreshRates>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetScreenInfoReply {
                 reply_type: reply_type,
                 rotations: rotations,
@@ -1095,21 +1973,11 @@ impl AsByteSequence for GetScreenInfoReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.rotations.size()
-            + self.sequence.size()
-            + self.length.size()
+        let size: usize = 21
             + self.root.size()
             + self.timestamp.size()
             + self.config_timestamp.size()
-            + self.n_sizes.size()
-            + self.size_id.size()
-            + self.rotation.size()
-            + self.rate.size()
-            + self.n_info.size()
-            + 2
             + {
                 let block_len: usize = self.sizes.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ScreenSize>());
@@ -1119,58 +1987,120 @@ impl AsByteSequence for GetScreenInfoReply {
                 let block_len: usize = self.rates.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<RefreshRates>());
                 block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetScreenInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetScreenInfoReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let rotations: Rotation = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let root: Window = u.arbitrary()?;
+        let timestamp: Timestamp = u.arbitrary()?;
+        let config_timestamp: Timestamp = u.arbitrary()?;
+        let n_sizes: Card16 = u.arbitrary()?;
+        let size_id: Card16 = u.arbitrary()?;
+        let rotation: Rotation = u.arbitrary()?;
+        let rate: Card16 = u.arbitrary()?;
+        let n_info: Card16 = u.arbitrary()?;
+        let sizes = arbitrary_list(u, (n_sizes as usize) as usize)?;
+        let rates = arbitrary_list(u, ((n_info as usize) - (n_sizes as usize)) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            rotations: rotations,
+            sequence: sequence,
+            length: length,
+            root: root,
+            timestamp: timestamp,
+            config_timestamp: config_timestamp,
+            n_sizes: n_sizes,
+            size_id: size_id,
+            rotation: rotation,
+            rate: rate,
+            n_info: n_info,
+            sizes: sizes,
+            rates: rates,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetScreenSizeRangeRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetScreenSizeRangeRequest {}
-impl AsByteSequence for GetScreenSizeRangeRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetScreenSizeRangeRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetScreenSizeRangeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetScreenSizeRangeRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    pub fn builder() -> GetScreenSizeRangeRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetScreenSizeRangeReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetScreenSizeRangeRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { GetScreenSizeRangeRequest }
 impl Request for GetScreenSizeRangeRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenSizeRange";
     type Reply = GetScreenSizeRangeReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetScreenSizeRangeRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetScreenSizeRangeRequestBuilder {
+    pub inner: GetScreenSizeRangeRequest,
+}
+impl GetScreenSizeRangeRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetScreenSizeRangeRequest {
+        self.inner
+    }
+}
+impl Default for GetScreenSizeRangeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetScreenSizeRangeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetScreenSizeRangeReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1180,43 +2110,125 @@ pub struct GetScreenSizeRangeReply {
     pub max_width: Card16,
     pub max_height: Card16,
 }
-impl GetScreenSizeRangeReply {}
+impl GetScreenSizeRangeReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let min_width: Card16 = read_item(reader, &mut index, 2, "min_width")?;
+        let min_height: Card16 = read_item(reader, &mut index, 2, "min_height")?;
+        let max_width: Card16 = read_item(reader, &mut index, 2, "max_width")?;
+        let max_height: Card16 = read_item(reader, &mut index, 2, "max_height")?;
+        skip_bytes(reader, &mut index, 16)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetScreenSizeRangeReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            min_width: min_width,
+            min_height: min_height,
+            max_width: max_width,
+            max_height: max_height,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("min_width", 8, 2),
+        ("min_height", 10, 2),
+        ("max_width", 12, 2),
+        ("max_height", 14, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.min_width == other.min_width
+            && self.min_height == other.min_height
+            && self.max_width == other.max_width
+            && self.max_height == other.max_height
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("min_width", &self.min_width, &other.min_width, &mut changes);
+        diff_value(
+            "min_height",
+            &self.min_height,
+            &other.min_height,
+            &mut changes,
+        );
+        diff_value("max_width", &self.max_width, &other.max_width, &mut changes);
+        diff_value(
+            "max_height",
+            &self.max_height,
+            &other.max_height,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for GetScreenSizeRangeReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.min_width.as_bytes(&mut bytes[index..]);
         index += self.min_height.as_bytes(&mut bytes[index..]);
         index += self.max_width.as_bytes(&mut bytes[index..]);
         index += self.max_height.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenSizeRangeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (min_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (min_width, sz): (Card16, usize) = parse_item(bytes, index, "min_width")?;
         index += sz;
-        let (min_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (min_height, sz): (Card16, usize) = parse_item(bytes, index, "min_height")?;
         index += sz;
-        let (max_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_width, sz): (Card16, usize) = parse_item(bytes, index, "max_width")?;
         index += sz;
-        let (max_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_height, sz): (Card16, usize) = parse_item(bytes, index, "max_height")?;
         index += sz;
         index += 16;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetScreenSizeRangeReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1231,18 +2243,21 @@ impl AsByteSequence for GetScreenSizeRangeReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.min_width.size()
-            + self.min_height.size()
-            + self.max_width.size()
-            + self.max_height.size()
-            + 16
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetScreenSizeRangeReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetScreenSizeRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1252,41 +2267,49 @@ pub struct SetScreenSizeRequest {
     pub mm_width: Card32,
     pub mm_height: Card32,
 }
-impl SetScreenSizeRequest {}
-impl AsByteSequence for SetScreenSizeRequest {
+impl SetScreenSizeRequest {
     #[inline]
+    pub fn builder() -> SetScreenSizeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetScreenSizeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetScreenSizeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.mm_width.as_bytes(&mut bytes[index..]);
         index += self.mm_height.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetScreenSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (mm_width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_width, sz): (Card32, usize) = parse_item(bytes, index, "mm_width")?;
         index += sz;
-        let (mm_height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_height, sz): (Card32, usize) = parse_item(bytes, index, "mm_height")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetScreenSizeRequest {
                 req_type: req_type,
                 length: length,
@@ -1301,23 +2324,81 @@ impl AsByteSequence for SetScreenSizeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.window.size()
-            + self.width.size()
-            + self.height.size()
-            + self.mm_width.size()
-            + self.mm_height.size()
+        let size: usize = 16 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetScreenSizeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetScreenSizeRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetScreenSize";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetScreenSizeRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetScreenSizeRequestBuilder {
+    pub inner: SetScreenSizeRequest,
+}
+impl SetScreenSizeRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn mm_width(mut self, mm_width: Card32) -> Self {
+        self.inner.mm_width = mm_width;
+        self
+    }
+    #[inline]
+    pub fn mm_height(mut self, mm_height: Card32) -> Self {
+        self.inner.mm_height = mm_height;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetScreenSizeRequest {
+        self.inner
+    }
+}
+impl Default for SetScreenSizeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetScreenSizeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                mm_width: Default::default(),
+                mm_height: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ModeInfo {
     pub id: Card32,
     pub width: Card16,
@@ -1333,9 +2414,57 @@ pub struct ModeInfo {
     pub name_len: Card16,
     pub mode_flags: ModeFlag,
 }
-impl ModeInfo {}
+impl ModeInfo {
+    #[inline]
+    pub const fn new(
+        id: Card32,
+        width: Card16,
+        height: Card16,
+        dot_clock: Card32,
+        hsync_start: Card16,
+        hsync_end: Card16,
+        htotal: Card16,
+        hskew: Card16,
+        vsync_start: Card16,
+        vsync_end: Card16,
+        vtotal: Card16,
+        name_len: Card16,
+        mode_flags: ModeFlag,
+    ) -> Self {
+        Self {
+            id: id,
+            width: width,
+            height: height,
+            dot_clock: dot_clock,
+            hsync_start: hsync_start,
+            hsync_end: hsync_end,
+            htotal: htotal,
+            hskew: hskew,
+            vsync_start: vsync_start,
+            vsync_end: vsync_end,
+            vtotal: vtotal,
+            name_len: name_len,
+            mode_flags: mode_flags,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("id", 0, 4),
+        ("width", 4, 2),
+        ("height", 6, 2),
+        ("dot_clock", 8, 4),
+        ("hsync_start", 12, 2),
+        ("hsync_end", 14, 2),
+        ("htotal", 16, 2),
+        ("hskew", 18, 2),
+        ("vsync_start", 20, 2),
+        ("vsync_end", 22, 2),
+        ("vtotal", 24, 2),
+        ("name_len", 26, 2),
+        ("mode_flags", 28, 4),
+    ];
+}
 impl AsByteSequence for ModeInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.id.as_bytes(&mut bytes[index..]);
@@ -1353,37 +2482,36 @@ impl AsByteSequence for ModeInfo {
         index += self.mode_flags.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModeInfo from byte buffer");
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (dot_clock, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dot_clock, sz): (Card32, usize) = parse_item(bytes, index, "dot_clock")?;
         index += sz;
-        let (hsync_start, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsync_start, sz): (Card16, usize) = parse_item(bytes, index, "hsync_start")?;
         index += sz;
-        let (hsync_end, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsync_end, sz): (Card16, usize) = parse_item(bytes, index, "hsync_end")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vsync_start, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsync_start, sz): (Card16, usize) = parse_item(bytes, index, "vsync_start")?;
         index += sz;
-        let (vsync_end, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsync_end, sz): (Card16, usize) = parse_item(bytes, index, "vsync_end")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
-        let (name_len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (name_len, sz): (Card16, usize) = parse_item(bytes, index, "name_len")?;
         index += sz;
-        let (mode_flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (mode_flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "mode_flags")?;
         index += sz;
-        Some((
+        Ok((
             ModeInfo {
                 id: id,
                 width: width,
@@ -1404,23 +2532,33 @@ impl AsByteSequence for ModeInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.id.size()
-            + self.width.size()
-            + self.height.size()
-            + self.dot_clock.size()
-            + self.hsync_start.size()
-            + self.hsync_end.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vsync_start.size()
-            + self.vsync_end.size()
-            + self.vtotal.size()
-            + self.name_len.size()
-            + self.mode_flags.size()
+        32
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ModeInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl PartialEq for ModeInfo {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+impl Eq for ModeInfo {}
+impl core::hash::Hash for ModeInfo {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.id, state);
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ModeFlag {
     pub inner: u32,
 }
@@ -1607,7 +2745,6 @@ impl ModeFlag {
         }
         self
     }
-    #[inline]
     pub fn new(
         hsync_positive: bool,
         hsync_negative: bool,
@@ -1689,21 +2826,46 @@ impl ModeFlag {
     pub const HALVE_CLOCK: Self = Self { inner: 8192 };
     pub const COMPLETE: Self = Self { inner: 16383 };
 }
-impl AsByteSequence for ModeFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for ModeFlag {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((ModeFlag { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for ModeFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+macro_rules! impl_u32_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                4
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_u32_flags_asb! { ModeFlag }
 impl core::ops::Not for ModeFlag {
     type Output = ModeFlag;
     #[inline]
@@ -1738,55 +2900,73 @@ impl core::ops::BitXor for ModeFlag {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetScreenResourcesRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetScreenResourcesRequest {}
-impl AsByteSequence for GetScreenResourcesRequest {
+impl GetScreenResourcesRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> GetScreenResourcesRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetScreenResourcesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetScreenResourcesRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetScreenResourcesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetScreenResourcesRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { GetScreenResourcesRequest }
 impl Request for GetScreenResourcesRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenResources";
     type Reply = GetScreenResourcesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetScreenResourcesRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetScreenResourcesRequestBuilder {
+    pub inner: GetScreenResourcesRequest,
+}
+impl GetScreenResourcesRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetScreenResourcesRequest {
+        self.inner
+    }
+}
+impl Default for GetScreenResourcesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetScreenResourcesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetScreenResourcesReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1798,159 +2978,361 @@ pub struct GetScreenResourcesReply {
     pub modes: Vec<ModeInfo>,
     pub names: Vec<Byte>,
 }
-impl GetScreenResourcesReply {}
-impl AsByteSequence for GetScreenResourcesReply {
+impl GetScreenResourcesReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn names_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
         let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
         index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.timestamp.as_bytes(&mut bytes[index..]);
-        index += self.config_timestamp.as_bytes(&mut bytes[index..]);
-        index += (self.crtcs.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += (self.outputs.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += (self.modes.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += (self.names.len() as Card16).as_bytes(&mut bytes[index..]);
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+        index += sz;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
         index += 8;
-        let block_len: usize = vector_as_bytes(&self.crtcs, &mut bytes[index..]);
+        let (crtcs, block_len): (Vec<Crtc>, usize) =
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
-        let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
+        let (outputs, block_len): (Vec<Output>, usize) =
+            parse_vector(bytes, index, len1 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        let block_len: usize = vector_as_bytes(&self.modes, &mut bytes[index..]);
+        let (modes, block_len): (Vec<ModeInfo>, usize) =
+            parse_vector(bytes, index, len2 as usize, "modes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-        let block_len: usize = vector_as_bytes(&self.names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        index
+        let (names, _): (Cow<'_, [Byte]>, usize) =
+            parse_slice(bytes, index, len3 as usize, "names")?;
+        Ok(names)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub fn modes_iter(bytes: &[u8]) -> Result<ListIter<'_, ModeInfo>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetScreenResourcesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
         index += sz;
         index += 8;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        let (modes, block_len): (Vec<ModeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-        let (names, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
-            GetScreenResourcesReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                timestamp: timestamp,
-                config_timestamp: config_timestamp,
-                crtcs: crtcs,
-                outputs: outputs,
-                modes: modes,
-                names: names,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.timestamp.size()
-            + self.config_timestamp.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + 8
-            + {
-                let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
-                block_len + pad
+        Ok(ListIter::new(bytes, index, len2 as usize, "modes"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp
+            && self.config_timestamp == other.config_timestamp
+            && self.crtcs == other.crtcs
+            && self.outputs == other.outputs
+            && self.modes == other.modes
+            && self.names == other.names
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value(
+            "config_timestamp",
+            &self.config_timestamp,
+            &other.config_timestamp,
+            &mut changes,
+        );
+        diff_list("crtcs", &self.crtcs, &other.crtcs, &mut changes);
+        diff_list("outputs", &self.outputs, &other.outputs, &mut changes);
+        diff_list("modes", &self.modes, &other.modes, &mut changes);
+        diff_list("names", &self.names, &other.names, &mut changes);
+        changes
+    }
+}
+macro_rules! impl_reply_asb_5845fe3c {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.reply_type.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 1);
+                index += self.sequence.as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.timestamp.as_bytes(&mut bytes[index..]);
+                index += self.config_timestamp.as_bytes(&mut bytes[index..]);
+                index += (self.crtcs.len() as Card16).as_bytes(&mut bytes[index..]);
+                index += (self.outputs.len() as Card16).as_bytes(&mut bytes[index..]);
+                index += (self.modes.len() as Card16).as_bytes(&mut bytes[index..]);
+                index += (self.names.len() as Card16).as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 8);
+                let block_len: usize = vector_as_bytes(&self.crtcs, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Crtc>()),
+                );
+                let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+                );
+                let block_len: usize = vector_as_bytes(&self.modes, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>()),
+                );
+                let block_len: usize = vector_as_bytes(&self.names, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
             }
-            + {
-                let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
-                block_len + pad
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+                index += sz;
+                index += 1;
+                let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+                index += sz;
+                let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let bytes: &[u8] = reply_bytes(bytes, length);
+                let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+                index += sz;
+                let (config_timestamp, sz): (Timestamp, usize) =
+                    parse_item(bytes, index, "config_timestamp")?;
+                index += sz;
+                let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+                index += sz;
+                let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+                index += sz;
+                let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+                index += sz;
+                let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+                index += sz;
+                index += 8;
+                let (crtcs, block_len): (Vec<Crtc>, usize) =
+                    parse_vector(bytes, index, len0 as usize, "crtcs")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+                let (outputs, block_len): (Vec<Output>, usize) =
+                    parse_vector(bytes, index, len1 as usize, "outputs")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+                let (modes, block_len): (Vec<ModeInfo>, usize) =
+                    parse_vector(bytes, index, len2 as usize, "modes")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
+                let (names, block_len): (Vec<Byte>, usize) =
+                    parse_vector(bytes, index, len3 as usize, "names")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        reply_type: reply_type,
+                        sequence: sequence,
+                        length: length,
+                        timestamp: timestamp,
+                        config_timestamp: config_timestamp,
+                        crtcs: crtcs,
+                        outputs: outputs,
+                        modes: modes,
+                        names: names,
+                    },
+                    index,
+                ))
             }
-            + {
-                let block_len: usize = self.modes.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-                block_len + pad
+            fn size(&self) -> usize {
+                let size: usize = 24
+                    + self.timestamp.size()
+                    + self.config_timestamp.size()
+                    + {
+                        let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.modes.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.names.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
             }
-            + {
-                let block_len: usize = self.names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
+            fn size_hint(&self) -> usize {
+                let size: usize = 24
+                    + self.timestamp.size()
+                    + self.config_timestamp.size()
+                    + {
+                        let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.modes.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.names.len() * ::core::mem::size_of::<Byte>();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
             }
-    }
-}
-#[derive(Clone, Debug, Default)]
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_reply_asb_5845fe3c! { GetScreenResourcesReply }
+impl core::fmt::Debug for GetScreenResourcesReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetScreenResourcesReply")
+            .field("reply_type", &self.reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("timestamp", &self.timestamp)
+            .field("config_timestamp", &self.config_timestamp)
+            .field("crtcs", &self.crtcs)
+            .field("outputs", &self.outputs)
+            .field("modes", &self.modes)
+            .field("names", &debug_bytes(&self.names, 64))
+            .finish()
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetOutputInfoRequest {
     pub req_type: u8,
     pub length: u16,
     pub output: Output,
     pub config_timestamp: Timestamp,
 }
-impl GetOutputInfoRequest {}
-impl AsByteSequence for GetOutputInfoRequest {
+impl GetOutputInfoRequest {
+    #[inline]
+    pub fn builder() -> GetOutputInfoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetOutputInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output", 4, 4)];
+}
+const _: () = assert!(<GetOutputInfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetOutputInfoRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.output.as_bytes(&mut bytes[index..]);
         index += self.config_timestamp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetOutputInfoRequest {
                 req_type: req_type,
                 length: length,
@@ -1962,20 +3344,63 @@ impl AsByteSequence for GetOutputInfoRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.output.size()
-            + self.config_timestamp.size()
+        let size: usize = 8 + self.config_timestamp.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetOutputInfoRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetOutputInfoRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOutputInfo";
     type Reply = GetOutputInfoReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetOutputInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetOutputInfoRequestBuilder {
+    pub inner: GetOutputInfoRequest,
+}
+impl GetOutputInfoRequestBuilder {
+    #[inline]
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
+    }
+    #[inline]
+    pub fn config_timestamp(mut self, config_timestamp: Timestamp) -> Self {
+        self.inner.config_timestamp = config_timestamp;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetOutputInfoRequest {
+        self.inner
+    }
+}
+impl Default for GetOutputInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetOutputInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                config_timestamp: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetOutputInfoReply {
     pub reply_type: u8,
     pub status: SetConfig,
@@ -1993,9 +3418,125 @@ pub struct GetOutputInfoReply {
     pub clones: Vec<Output>,
     pub name: Vec<Byte>,
 }
-impl GetOutputInfoReply {}
-impl AsByteSequence for GetOutputInfoReply {
+impl GetOutputInfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn name_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (status, sz): (SetConfig, usize) = parse_item(bytes, index, "status")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+        index += sz;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
+        index += sz;
+        let (mm_width, sz): (Card32, usize) = parse_item(bytes, index, "mm_width")?;
+        index += sz;
+        let (mm_height, sz): (Card32, usize) = parse_item(bytes, index, "mm_height")?;
+        index += sz;
+        let (connection, sz): (Connection, usize) = parse_item(bytes, index, "connection")?;
+        index += sz;
+        let (subpixel_order, sz): (SubPixel, usize) = parse_item(bytes, index, "subpixel_order")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (num_preferred, sz): (Card16, usize) = parse_item(bytes, index, "num_preferred")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
+        let (crtcs, block_len): (Vec<Crtc>, usize) =
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+        let (modes, block_len): (Vec<Mode>, usize) =
+            parse_vector(bytes, index, len1 as usize, "modes")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Mode>());
+        let (clones, block_len): (Vec<Output>, usize) =
+            parse_vector(bytes, index, len2 as usize, "clones")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        let (name, _): (Cow<'_, [Byte]>, usize) = parse_slice(bytes, index, len3 as usize, "name")?;
+        Ok(name)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.timestamp == other.timestamp
+            && self.crtc == other.crtc
+            && self.mm_width == other.mm_width
+            && self.mm_height == other.mm_height
+            && self.connection == other.connection
+            && self.subpixel_order == other.subpixel_order
+            && self.num_preferred == other.num_preferred
+            && self.crtcs == other.crtcs
+            && self.modes == other.modes
+            && self.clones == other.clones
+            && self.name == other.name
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value("crtc", &self.crtc, &other.crtc, &mut changes);
+        diff_value("mm_width", &self.mm_width, &other.mm_width, &mut changes);
+        diff_value("mm_height", &self.mm_height, &other.mm_height, &mut changes);
+        diff_value(
+            "connection",
+            &self.connection,
+            &other.connection,
+            &mut changes,
+        );
+        diff_value(
+            "subpixel_order",
+            &self.subpixel_order,
+            &other.subpixel_order,
+            &mut changes,
+        );
+        diff_value(
+            "num_preferred",
+            &self.num_preferred,
+            &other.num_preferred,
+            &mut changes,
+        );
+        diff_list("crtcs", &self.crtcs, &other.crtcs, &mut changes);
+        diff_list("modes", &self.modes, &other.modes, &mut changes);
+        diff_list("clones", &self.clones, &other.clones, &mut changes);
+        diff_list("name", &self.name, &other.name, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetOutputInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -2015,69 +3556,83 @@ impl AsByteSequence for GetOutputInfoReply {
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.crtcs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Crtc>()),
+        );
         let block_len: usize = vector_as_bytes(&self.modes, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Mode>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Mode>()),
+        );
         let block_len: usize = vector_as_bytes(&self.clones, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+        );
         let block_len: usize = vector_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (mm_width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_width, sz): (Card32, usize) = parse_item(bytes, index, "mm_width")?;
         index += sz;
-        let (mm_height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_height, sz): (Card32, usize) = parse_item(bytes, index, "mm_height")?;
         index += sz;
-        let (connection, sz): (Connection, usize) = <Connection>::from_bytes(&bytes[index..])?;
+        let (connection, sz): (Connection, usize) = parse_item(bytes, index, "connection")?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = parse_item(bytes, index, "subpixel_order")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (num_preferred, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_preferred, sz): (Card16, usize) = parse_item(bytes, index, "num_preferred")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
         index += sz;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (modes, block_len): (Vec<Mode>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "modes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Mode>());
         let (clones, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            parse_vector(bytes, index, len2 as usize, "clones")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         let (name, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            parse_vector(bytes, index, len3 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetOutputInfoReply {
                 reply_type: reply_type,
                 status: status,
@@ -2098,23 +3653,10 @@ impl AsByteSequence for GetOutputInfoReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
+        let size: usize = 31
             + self.timestamp.size()
-            + self.crtc.size()
-            + self.mm_width.size()
-            + self.mm_height.size()
-            + self.connection.size()
             + self.subpixel_order.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + self.num_preferred.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
             + {
                 let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
@@ -2134,11 +3676,69 @@ impl AsByteSequence for GetOutputInfoReply {
                 let block_len: usize = self.name.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
                 block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = 31
+            + self.timestamp.size()
+            + self.subpixel_order.size()
+            + {
+                let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+                block_len + pad
+            }
+            + {
+                let block_len: usize = self.modes.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Mode>());
+                block_len + pad
+            }
+            + {
+                let block_len: usize = self.clones.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
+                block_len + pad
             }
+            + {
+                let block_len: usize = self.name.len() * ::core::mem::size_of::<Byte>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetOutputInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for GetOutputInfoReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetOutputInfoReply")
+            .field("reply_type", &self.reply_type)
+            .field("status", &self.status)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("timestamp", &self.timestamp)
+            .field("crtc", &self.crtc)
+            .field("mm_width", &self.mm_width)
+            .field("mm_height", &self.mm_height)
+            .field("connection", &self.connection)
+            .field("subpixel_order", &self.subpixel_order)
+            .field("num_preferred", &self.num_preferred)
+            .field("crtcs", &self.crtcs)
+            .field("modes", &self.modes)
+            .field("clones", &self.clones)
+            .field("name", &debug_bytes(&self.name, 64))
+            .finish()
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Connection {
     Connected = 0,
     Disconnected = 1,
@@ -2150,13 +3750,13 @@ impl AsByteSequence for Connection {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Connected, sz)),
-            1 => Some((Self::Disconnected, sz)),
-            2 => Some((Self::Unknown, sz)),
-            _ => None,
+            0 => Ok((Self::Connected, sz)),
+            1 => Ok((Self::Disconnected, sz)),
+            2 => Ok((Self::Unknown, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -2164,41 +3764,86 @@ impl AsByteSequence for Connection {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Connection {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Connection {
     #[inline]
     fn default() -> Connection {
         Connection::Connected
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Connection> for u8 {
+    #[inline]
+    fn from(base: Connection) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for Connection {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Connected),
+            1 => Ok(Self::Disconnected),
+            2 => Ok(Self::Unknown),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListOutputPropertiesRequest {
     pub req_type: u8,
     pub length: u16,
     pub output: Output,
 }
-impl ListOutputPropertiesRequest {}
-impl AsByteSequence for ListOutputPropertiesRequest {
+impl ListOutputPropertiesRequest {
     #[inline]
+    pub fn builder() -> ListOutputPropertiesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListOutputPropertiesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output", 4, 4)];
+}
+const _: () = assert!(<ListOutputPropertiesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ListOutputPropertiesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.output.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListOutputPropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListOutputPropertiesRequest {
                 req_type: req_type,
                 length: length,
@@ -2209,135 +3854,298 @@ impl AsByteSequence for ListOutputPropertiesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.output.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListOutputPropertiesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ListOutputPropertiesRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListOutputProperties";
     type Reply = ListOutputPropertiesReply;
 }
-#[derive(Clone, Debug, Default)]
-pub struct ListOutputPropertiesReply {
-    pub reply_type: u8,
-    pub sequence: u16,
-    pub length: u32,
-    pub atoms: Vec<Atom>,
+#[doc = " A builder for `ListOutputPropertiesRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListOutputPropertiesRequestBuilder {
+    pub inner: ListOutputPropertiesRequest,
 }
-impl ListOutputPropertiesReply {}
-impl AsByteSequence for ListOutputPropertiesReply {
+impl ListOutputPropertiesRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.atoms.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 22;
-        let block_len: usize = vector_as_bytes(&self.atoms, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        index
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ListOutputPropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 22;
-        let (atoms, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        Some((
-            ListOutputPropertiesReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                atoms: atoms,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListOutputPropertiesRequest {
+        self.inner
     }
+}
+impl Default for ListOutputPropertiesRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card16>()
-            + 22
-            + {
-                let block_len: usize = self.atoms.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
+    fn default() -> Self {
+        Self {
+            inner: ListOutputPropertiesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+            },
+        }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct ListOutputPropertiesReply {
+    pub reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms: Vec<Atom>,
+}
+impl ListOutputPropertiesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("atoms_len", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.atoms == other.atoms
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("atoms", &self.atoms, &other.atoms, &mut changes);
+        changes
+    }
+}
+macro_rules! impl_atoms_only_reply_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.reply_type.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 1);
+                index += self.sequence.as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += (self.atoms.len() as Card16).as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 22);
+                let block_len: usize = vector_as_bytes(&self.atoms, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+                index += sz;
+                index += 1;
+                let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+                index += sz;
+                let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let bytes: &[u8] = reply_bytes(bytes, length);
+                let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+                index += sz;
+                index += 22;
+                let (atoms, block_len): (Vec<Atom>, usize) =
+                    parse_vector(bytes, index, len0 as usize, "atoms")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        reply_type: reply_type,
+                        sequence: sequence,
+                        length: length,
+                        atoms: atoms,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 32 + {
+                    let block_len: usize = self.atoms.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_atoms_only_reply_asb! { ListOutputPropertiesReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryOutputPropertyRequest {
     pub req_type: u8,
     pub length: u16,
     pub output: Output,
     pub property: Atom,
 }
-impl QueryOutputPropertyRequest {}
-impl AsByteSequence for QueryOutputPropertyRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.output.as_bytes(&mut bytes[index..]);
-        index += self.property.as_bytes(&mut bytes[index..]);
-        index
+impl QueryOutputPropertyRequest {
+    #[inline]
+    pub fn builder() -> QueryOutputPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryOutputPropertyReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output", 4, 4)];
+}
+const _: () = assert!(<QueryOutputPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_output_property_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.output.as_bytes(&mut bytes[index..]);
+                index += self.property.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
+                index += sz;
+                let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        output: output,
+                        property: property,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8 + self.property.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_output_property_request_asb! { QueryOutputPropertyRequest }
+impl Request for QueryOutputPropertyRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 11;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryOutputProperty";
+    type Reply = QueryOutputPropertyReply;
+}
+#[doc = " A builder for `QueryOutputPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryOutputPropertyRequestBuilder {
+    pub inner: QueryOutputPropertyRequest,
+}
+impl QueryOutputPropertyRequestBuilder {
+    #[inline]
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryOutputPropertyRequest {
-                req_type: req_type,
-                length: length,
-                output: output,
-                property: property,
-            },
-            index,
-        ))
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.output.size() + self.property.size()
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryOutputPropertyRequest {
+        self.inner
     }
 }
-impl Request for QueryOutputPropertyRequest {
-    const OPCODE: u8 = 11;
-    const EXTENSION: Option<&'static str> = Some("RANDR");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = QueryOutputPropertyReply;
+impl Default for QueryOutputPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryOutputPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                property: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryOutputPropertyReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2347,77 +4155,193 @@ pub struct QueryOutputPropertyReply {
     pub immutable: bool,
     pub valid_values: Vec<Int32>,
 }
-impl QueryOutputPropertyReply {}
-impl AsByteSequence for QueryOutputPropertyReply {
+impl QueryOutputPropertyReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.pending.as_bytes(&mut bytes[index..]);
-        index += self.range.as_bytes(&mut bytes[index..]);
-        index += self.immutable.as_bytes(&mut bytes[index..]);
-        index += 21;
-        let block_len: usize = vector_as_bytes(&self.valid_values, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub fn valid_values_slice(bytes: &[u8]) -> Result<Cow<'_, [Int32]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing QueryOutputPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = parse_item(bytes, index, "range")?;
         index += sz;
-        let (immutable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (immutable, sz): (bool, usize) = parse_item(bytes, index, "immutable")?;
         index += sz;
         index += 21;
-        let (valid_values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-        Some((
-            QueryOutputPropertyReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                pending: pending,
-                range: range,
-                immutable: immutable,
-                valid_values: valid_values,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.pending.size()
-            + self.range.size()
-            + self.immutable.size()
-            + 21
-            + {
-                let block_len: usize = self.valid_values.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-                block_len + pad
+        let (valid_values, _): (Cow<'_, [Int32]>, usize) =
+            parse_slice(bytes, index, (length as usize) as usize, "valid_values")?;
+        Ok(valid_values)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("pending", 8, 1),
+        ("range", 9, 1),
+        ("immutable", 10, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.pending == other.pending
+            && self.range == other.range
+            && self.immutable == other.immutable
+            && self.valid_values == other.valid_values
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("pending", &self.pending, &other.pending, &mut changes);
+        diff_value("range", &self.range, &other.range, &mut changes);
+        diff_value("immutable", &self.immutable, &other.immutable, &mut changes);
+        diff_list(
+            "valid_values",
+            &self.valid_values,
+            &other.valid_values,
+            &mut changes,
+        );
+        changes
+    }
+}
+macro_rules! impl_reply_asb_ac5d3680 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.reply_type.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 1);
+                index += self.sequence.as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.pending.as_bytes(&mut bytes[index..]);
+                index += self.range.as_bytes(&mut bytes[index..]);
+                index += self.immutable.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 21);
+                let block_len: usize = vector_as_bytes(&self.valid_values, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Int32>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
             }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+                index += sz;
+                index += 1;
+                let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+                index += sz;
+                let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let bytes: &[u8] = reply_bytes(bytes, length);
+                let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
+                index += sz;
+                let (range, sz): (bool, usize) = parse_item(bytes, index, "range")?;
+                index += sz;
+                let (immutable, sz): (bool, usize) = parse_item(bytes, index, "immutable")?;
+                index += sz;
+                index += 21;
+                let (valid_values, block_len): (Vec<Int32>, usize) =
+                    parse_vector(bytes, index, (length as usize) as usize, "valid_values")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        reply_type: reply_type,
+                        sequence: sequence,
+                        length: length,
+                        pending: pending,
+                        range: range,
+                        immutable: immutable,
+                        valid_values: valid_values,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize =
+                    29 + self.pending.size() + self.range.size() + self.immutable.size() + {
+                        let block_len: usize = self.valid_values.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }
+            #[inline]
+            fn size_hint(&self) -> usize {
+                let size: usize =
+                    29 + self.pending.size() + self.range.size() + self.immutable.size() + {
+                        let block_len: usize =
+                            self.valid_values.len() * ::core::mem::size_of::<Int32>();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_reply_asb_ac5d3680! { QueryOutputPropertyReply }
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryOutputPropertyReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let pending: bool = u.arbitrary()?;
+        let range: bool = u.arbitrary()?;
+        let immutable: bool = u.arbitrary()?;
+        let valid_values = arbitrary_list(u, (length as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            pending: pending,
+            range: range,
+            immutable: immutable,
+            valid_values: valid_values,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ConfigureOutputPropertyRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2427,47 +4351,93 @@ pub struct ConfigureOutputPropertyRequest {
     pub range: bool,
     pub values: Vec<Int32>,
 }
-impl ConfigureOutputPropertyRequest {}
-impl AsByteSequence for ConfigureOutputPropertyRequest {
+impl ConfigureOutputPropertyRequest {
+    pub fn values_slice(bytes: &[u8]) -> Result<Cow<'_, [Int32]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
+        index += sz;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
+        index += sz;
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
+        index += sz;
+        let (range, sz): (bool, usize) = parse_item(bytes, index, "range")?;
+        index += sz;
+        index += 2;
+        let (values, _): (Cow<'_, [Int32]>, usize) = parse_slice(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "values",
+                offset: index,
+            })? as usize,
+            "values",
+        )?;
+        Ok(values)
+    }
     #[inline]
+    pub fn builder() -> ConfigureOutputPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output", 4, 4)];
+}
+const _: () = assert!(<ConfigureOutputPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ConfigureOutputPropertyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.output.as_bytes(&mut bytes[index..]);
         index += self.property.as_bytes(&mut bytes[index..]);
         index += self.pending.as_bytes(&mut bytes[index..]);
         index += self.range.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.values, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Int32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = parse_item(bytes, index, "range")?;
         index += sz;
         index += 2;
-        let (values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (values, block_len): (Vec<Int32>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "values",
+                offset: index,
+            })? as usize,
+            "values",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ConfigureOutputPropertyRequest {
                 req_type: req_type,
                 length: length,
@@ -2482,28 +4452,108 @@ impl AsByteSequence for ConfigureOutputPropertyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.output.size()
-            + self.property.size()
-            + self.pending.size()
-            + self.range.size()
-            + 2
-            + {
-                let block_len: usize = self.values.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-                block_len + pad
-            }
+        let size: usize = 10 + self.property.size() + self.pending.size() + self.range.size() + {
+            let block_len: usize = self.values.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 10 + self.property.size() + self.pending.size() + self.range.size() + {
+            let block_len: usize = self.values.len() * ::core::mem::size_of::<Int32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ConfigureOutputPropertyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ConfigureOutputPropertyRequest {
-    const OPCODE: u8 = 12;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 12;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ConfigureOutputProperty";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ConfigureOutputPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct ConfigureOutputPropertyRequestBuilder {
+    pub inner: ConfigureOutputPropertyRequest,
+}
+impl ConfigureOutputPropertyRequestBuilder {
+    #[inline]
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    pub fn pending(mut self, pending: bool) -> Self {
+        self.inner.pending = pending;
+        self
+    }
+    #[inline]
+    pub fn range(mut self, range: bool) -> Self {
+        self.inner.range = range;
+        self
+    }
+    #[inline]
+    pub fn values(mut self, values: Vec<Int32>) -> Self {
+        self.inner.values = values;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ConfigureOutputPropertyRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Int32> for ConfigureOutputPropertyRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Int32>>(&mut self, iter: I) {
+        self.inner.values.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Int32> for ConfigureOutputPropertyRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Int32>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for ConfigureOutputPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ConfigureOutputPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                property: Default::default(),
+                pending: Default::default(),
+                range: Default::default(),
+                values: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangeOutputPropertyRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2515,55 +4565,100 @@ pub struct ChangeOutputPropertyRequest {
     pub num_units: Card32,
     pub data: Vec<Void>,
 }
-impl ChangeOutputPropertyRequest {}
-impl AsByteSequence for ChangeOutputPropertyRequest {
+impl ChangeOutputPropertyRequest {
+    #[inline]
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            output: self.output.clone(),
+            property: self.property.clone(),
+            ty: self.ty.clone(),
+            format: self.format.clone(),
+            mode: self.mode.clone(),
+            num_units: self.num_units.clone(),
+            data: self.data.clone(),
+        }
+    }
     #[inline]
+    pub fn builder() -> ChangeOutputPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output", 4, 4)];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    #[inline]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize =
+                (((self.num_units as usize) * (self.format as usize)) / (8)) as usize;
+            if self.data.len() != expected {
+                return Err(ValidationError {
+                    list: "data",
+                    expected,
+                    found: self.data.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<ChangeOutputPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ChangeOutputPropertyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.output.as_bytes(&mut bytes[index..]);
         index += self.property.as_bytes(&mut bytes[index..]);
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
         index += self.mode.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.num_units.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Void>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (PropMode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
         index += 2;
-        let (num_units, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_units, sz): (Card32, usize) = parse_item(bytes, index, "num_units")?;
         index += sz;
-        let (data, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (data, block_len): (Vec<Void>, usize) = parse_vector(
+            bytes,
+            index,
             (((num_units as usize) * (format as usize)) / (8)) as usize,
+            "data",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ChangeOutputPropertyRequest {
                 req_type: req_type,
                 length: length,
@@ -2580,83 +4675,220 @@ impl AsByteSequence for ChangeOutputPropertyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.output.size()
-            + self.property.size()
-            + self.ty.size()
-            + self.format.size()
-            + self.mode.size()
-            + 2
-            + self.num_units.size()
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
-                block_len + pad
-            }
+        let size: usize = 15 + self.property.size() + self.ty.size() + self.mode.size() + {
+            let block_len: usize = self.data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ChangeOutputPropertyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ChangeOutputPropertyRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeOutputProperty";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+impl core::fmt::Debug for ChangeOutputPropertyRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChangeOutputPropertyRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("output", &self.output)
+            .field("property", &self.property)
+            .field("ty", &self.ty)
+            .field("format", &self.format)
+            .field("mode", &self.mode)
+            .field("num_units", &self.num_units)
+            .field("data", &debug_bytes(&self.data, 64))
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for ChangeOutputPropertyRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let output: Output = u.arbitrary()?;
+        let property: Atom = u.arbitrary()?;
+        let ty: Atom = u.arbitrary()?;
+        let format: Card8 = u.arbitrary()?;
+        let mode: PropMode = u.arbitrary()?;
+        let num_units: Card32 = u.arbitrary()?;
+        let data = arbitrary_list(
+            u,
+            (((num_units as usize) * (format as usize)) / (8)) as usize,
+        )?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            output: output,
+            property: property,
+            ty: ty,
+            format: format,
+            mode: mode,
+            num_units: num_units,
+            data: data,
+        })
+    }
+}
+#[doc = " A builder for `ChangeOutputPropertyRequest`."]
+#[derive(Debug)]
+pub struct ChangeOutputPropertyRequestBuilder {
+    pub inner: ChangeOutputPropertyRequest,
+}
+impl ChangeOutputPropertyRequestBuilder {
+    #[inline]
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    pub fn ty(mut self, ty: Atom) -> Self {
+        self.inner.ty = ty;
+        self
+    }
+    #[inline]
+    pub fn mode(mut self, mode: PropMode) -> Self {
+        self.inner.mode = mode;
+        self
+    }
+    #[inline]
+    pub fn num_units(mut self, num_units: Card32) -> Self {
+        self.inner.num_units = num_units;
+        self
+    }
+    #[inline]
+    pub fn data(mut self, data: Vec<Void>) -> Self {
+        self.inner.data = data;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangeOutputPropertyRequest {
+        self.inner
+    }
+    #[doc = " Set `format`, which must be 8, 16 or 32. Other values are refused, rather than sent to the server."]
+    #[inline]
+    pub fn format(mut self, format: Card8) -> Result<Self, BuildError> {
+        match format {
+            8 | 16 | 32 => {
+                self.inner.format = format;
+                Ok(self)
+            }
+            _ => Err(BuildError {
+                field: "format",
+                value: format.into(),
+            }),
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl Default for ChangeOutputPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangeOutputPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                property: Default::default(),
+                ty: Default::default(),
+                format: Default::default(),
+                mode: Default::default(),
+                num_units: Default::default(),
+                data: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DeleteOutputPropertyRequest {
     pub req_type: u8,
     pub length: u16,
     pub output: Output,
     pub property: Atom,
 }
-impl DeleteOutputPropertyRequest {}
-impl AsByteSequence for DeleteOutputPropertyRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.output.as_bytes(&mut bytes[index..]);
-        index += self.property.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DeleteOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DeleteOutputPropertyRequest {
-                req_type: req_type,
-                length: length,
-                output: output,
-                property: property,
-            },
-            index,
-        ))
-    }
+impl DeleteOutputPropertyRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.output.size() + self.property.size()
+    pub fn builder() -> DeleteOutputPropertyRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output", 4, 4)];
 }
+const _: () = assert!(<DeleteOutputPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl_output_property_request_asb! { DeleteOutputPropertyRequest }
 impl Request for DeleteOutputPropertyRequest {
-    const OPCODE: u8 = 14;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 14;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteOutputProperty";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DeleteOutputPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct DeleteOutputPropertyRequestBuilder {
+    pub inner: DeleteOutputPropertyRequest,
+}
+impl DeleteOutputPropertyRequestBuilder {
+    #[inline]
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DeleteOutputPropertyRequest {
+        self.inner
+    }
+}
+impl Default for DeleteOutputPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DeleteOutputPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                property: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetOutputPropertyRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2668,13 +4900,29 @@ pub struct GetOutputPropertyRequest {
     pub delete: bool,
     pub pending: bool,
 }
-impl GetOutputPropertyRequest {}
-impl AsByteSequence for GetOutputPropertyRequest {
+impl GetOutputPropertyRequest {
     #[inline]
+    pub fn builder() -> GetOutputPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetOutputPropertyReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output", 4, 4)];
+}
+const _: () = assert!(<GetOutputPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetOutputPropertyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.output.as_bytes(&mut bytes[index..]);
         index += self.property.as_bytes(&mut bytes[index..]);
@@ -2683,34 +4931,35 @@ impl AsByteSequence for GetOutputPropertyRequest {
         index += self.long_length.as_bytes(&mut bytes[index..]);
         index += self.delete.as_bytes(&mut bytes[index..]);
         index += self.pending.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_offset, sz): (Card32, usize) = parse_item(bytes, index, "long_offset")?;
         index += sz;
-        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_length, sz): (Card32, usize) = parse_item(bytes, index, "long_length")?;
         index += sz;
-        let (delete, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (delete, sz): (bool, usize) = parse_item(bytes, index, "delete")?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetOutputPropertyRequest {
                 req_type: req_type,
                 length: length,
@@ -2727,111 +4976,268 @@ impl AsByteSequence for GetOutputPropertyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.output.size()
-            + self.property.size()
-            + self.ty.size()
-            + self.long_offset.size()
-            + self.long_length.size()
-            + self.delete.size()
-            + self.pending.size()
-            + 2
+        let size: usize =
+            18 + self.property.size() + self.ty.size() + self.delete.size() + self.pending.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetOutputPropertyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetOutputPropertyRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOutputProperty";
     type Reply = GetOutputPropertyReply;
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetOutputPropertyReply {
-    pub reply_type: u8,
-    pub format: Card8,
-    pub sequence: u16,
-    pub length: u32,
-    pub ty: Atom,
-    pub bytes_after: Card32,
-    pub num_items: Card32,
-    pub data: Vec<Byte>,
+#[doc = " A builder for `GetOutputPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetOutputPropertyRequestBuilder {
+    pub inner: GetOutputPropertyRequest,
 }
-impl GetOutputPropertyReply {}
-impl AsByteSequence for GetOutputPropertyReply {
+impl GetOutputPropertyRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += self.format.as_bytes(&mut bytes[index..]);
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.ty.as_bytes(&mut bytes[index..]);
-        index += self.bytes_after.as_bytes(&mut bytes[index..]);
-        index += self.num_items.as_bytes(&mut bytes[index..]);
-        index += 12;
-        let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        index
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetOutputPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 12;
-        let (data, block_len): (Vec<Byte>, usize) = vector_from_bytes(
-            &bytes[index..],
-            ((num_items as usize) * ((format as usize) / (8))) as usize,
-        )?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
-            GetOutputPropertyReply {
-                reply_type: reply_type,
-                format: format,
-                sequence: sequence,
-                length: length,
-                ty: ty,
-                bytes_after: bytes_after,
-                num_items: num_items,
-                data: data,
-            },
-            index,
-        ))
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.format.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.ty.size()
-            + self.bytes_after.size()
-            + self.num_items.size()
-            + 12
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
-            }
+    pub fn ty(mut self, ty: Atom) -> Self {
+        self.inner.ty = ty;
+        self
+    }
+    #[inline]
+    pub fn long_offset(mut self, long_offset: Card32) -> Self {
+        self.inner.long_offset = long_offset;
+        self
+    }
+    #[inline]
+    pub fn long_length(mut self, long_length: Card32) -> Self {
+        self.inner.long_length = long_length;
+        self
+    }
+    #[inline]
+    pub fn delete(mut self, delete: bool) -> Self {
+        self.inner.delete = delete;
+        self
+    }
+    #[inline]
+    pub fn pending(mut self, pending: bool) -> Self {
+        self.inner.pending = pending;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetOutputPropertyRequest {
+        self.inner
     }
 }
-#[derive(Clone, Debug, Default)]
+impl Default for GetOutputPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetOutputPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                property: Default::default(),
+                ty: Default::default(),
+                long_offset: Default::default(),
+                long_length: Default::default(),
+                delete: Default::default(),
+                pending: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct GetOutputPropertyReply {
+    pub reply_type: u8,
+    pub format: Card8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ty: Atom,
+    pub bytes_after: Card32,
+    pub num_items: Card32,
+    pub data: PropertyData,
+}
+impl GetOutputPropertyReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("format", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Create the request that reads the next part of the value, given the"]
+    #[doc = " `GetOutputPropertyRequest` that this is a reply to. Returns `None` once"]
+    #[doc = " there is nothing left to read."]
+    #[inline]
+    pub fn continue_request(
+        &self,
+        request: &GetOutputPropertyRequest,
+    ) -> Option<GetOutputPropertyRequest> {
+        if self.bytes_after == 0 {
+            return None;
+        }
+        let read = (self.num_items as u32) * (self.format as u32 / 8);
+        Some(GetOutputPropertyRequest {
+            long_offset: request.long_offset + (read / 4),
+            ..request.clone()
+        })
+    }
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.format == other.format
+            && self.ty == other.ty
+            && self.bytes_after == other.bytes_after
+            && self.num_items == other.num_items
+            && self.data == other.data
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("format", &self.format, &other.format, &mut changes);
+        diff_value("ty", &self.ty, &other.ty, &mut changes);
+        diff_value(
+            "bytes_after",
+            &self.bytes_after,
+            &other.bytes_after,
+            &mut changes,
+        );
+        diff_value("num_items", &self.num_items, &other.num_items, &mut changes);
+        diff_property_data("data", &self.data, &other.data, &mut changes);
+        changes
+    }
+}
+macro_rules! impl_reply_asb_3f6925dc {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.reply_type.as_bytes(&mut bytes[index..]);
+                index += self.format.as_bytes(&mut bytes[index..]);
+                index += self.sequence.as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.ty.as_bytes(&mut bytes[index..]);
+                index += self.bytes_after.as_bytes(&mut bytes[index..]);
+                index += self.num_items.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 12);
+                index += self.data.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+                index += sz;
+                let (format, sz): (Card8, usize) = parse_item(bytes, index, "format")?;
+                index += sz;
+                let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+                index += sz;
+                let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let bytes: &[u8] = reply_bytes(bytes, length);
+                let (ty, sz): (Atom, usize) = parse_item(bytes, index, "ty")?;
+                index += sz;
+                let (bytes_after, sz): (Card32, usize) = parse_item(bytes, index, "bytes_after")?;
+                index += sz;
+                let (num_items, sz): (Card32, usize) = parse_item(bytes, index, "num_items")?;
+                index += sz;
+                index += 12;
+                let (data, sz): (PropertyData, usize) =
+                    parse_property_data(bytes, index, format, num_items as usize, "data")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        reply_type: reply_type,
+                        format: format,
+                        sequence: sequence,
+                        length: length,
+                        ty: ty,
+                        bytes_after: bytes_after,
+                        num_items: num_items,
+                        data: data,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 28 + self.ty.size() + self.data.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_reply_asb_3f6925dc! { GetOutputPropertyReply }
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetOutputPropertyReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let data: PropertyData = u.arbitrary()?;
+        let format = data.format();
+        let num_items = data.len() as Card32;
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let ty: Atom = u.arbitrary()?;
+        let bytes_after: Card32 = u.arbitrary()?;
+        Ok(Self {
+            reply_type: reply_type,
+            format: format,
+            sequence: sequence,
+            length: length,
+            ty: ty,
+            bytes_after: bytes_after,
+            num_items: num_items,
+            data: data,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateModeRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2839,39 +5245,76 @@ pub struct CreateModeRequest {
     pub mode_info: ModeInfo,
     pub name: String,
 }
-impl CreateModeRequest {}
-impl AsByteSequence for CreateModeRequest {
+impl CreateModeRequest {
     #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(window: Window, mode_info: ModeInfo, name: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            window: window,
+            mode_info: mode_info,
+            name: String::from(name),
+        }
+    }
+    #[inline]
+    pub fn builder() -> CreateModeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateModeReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateModeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateModeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.mode_info.as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (mode_info, sz): (ModeInfo, usize) = <ModeInfo>::from_bytes(&bytes[index..])?;
+        let (mode_info, sz): (ModeInfo, usize) = parse_item(bytes, index, "mode_info")?;
         index += sz;
-        let (name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (name, block_len): (String, usize) = parse_string(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "name",
+                offset: index,
+            })? as usize,
+            "name",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateModeRequest {
                 req_type: req_type,
                 length: length,
@@ -2884,59 +5327,158 @@ impl AsByteSequence for CreateModeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.window.size()
-            + self.mode_info.size()
-            + {
-                let block_len: usize = self.name.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+        let size: usize = 36 + self.window.size() + {
+            let block_len: usize = self.name.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateModeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateModeRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateMode";
     type Reply = CreateModeReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateModeRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateModeRequestBuilder {
+    pub inner: CreateModeRequest,
+}
+impl CreateModeRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn mode_info(mut self, mode_info: ModeInfo) -> Self {
+        self.inner.mode_info = mode_info;
+        self
+    }
+    #[inline]
+    pub fn name(mut self, name: String) -> Self {
+        self.inner.name = name;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateModeRequest {
+        self.inner
+    }
+}
+impl Default for CreateModeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateModeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                mode_info: Default::default(),
+                name: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateModeReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub mode: Mode,
 }
-impl CreateModeReply {}
+impl CreateModeReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let mode: Mode = read_item(reader, &mut index, 4, "mode")?;
+        skip_bytes(reader, &mut index, 20)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(CreateModeReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            mode: mode,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("mode", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.mode == other.mode
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("mode", &self.mode, &other.mode, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for CreateModeReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.mode.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateModeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (mode, sz): (Mode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateModeReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2948,43 +5490,58 @@ impl AsByteSequence for CreateModeReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.mode.size()
-            + 20
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for CreateModeReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DestroyModeRequest {
     pub req_type: u8,
     pub length: u16,
     pub mode: Mode,
 }
-impl DestroyModeRequest {}
-impl AsByteSequence for DestroyModeRequest {
+impl DestroyModeRequest {
     #[inline]
+    pub fn builder() -> DestroyModeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("mode", 4, 4)];
+}
+const _: () = assert!(<DestroyModeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DestroyModeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.mode.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DestroyModeRequest {
                 req_type: req_type,
                 length: length,
@@ -2995,154 +5552,299 @@ impl AsByteSequence for DestroyModeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.mode.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DestroyModeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DestroyModeRequest {
-    const OPCODE: u8 = 17;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 17;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyMode";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyModeRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyModeRequestBuilder {
+    pub inner: DestroyModeRequest,
+}
+impl DestroyModeRequestBuilder {
+    #[inline]
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.inner.mode = mode;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyModeRequest {
+        self.inner
+    }
+}
+impl Default for DestroyModeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyModeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                mode: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AddOutputModeRequest {
     pub req_type: u8,
     pub length: u16,
     pub output: Output,
     pub mode: Mode,
 }
-impl AddOutputModeRequest {}
-impl AsByteSequence for AddOutputModeRequest {
+impl AddOutputModeRequest {
+    #[inline]
+    pub fn builder() -> AddOutputModeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("output", 4, 4),
+        ("mode", 8, 4),
+    ];
+}
+const _: () = assert!(<AddOutputModeRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_output_mode_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.output.as_bytes(&mut bytes[index..]);
+                index += self.mode.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
+                index += sz;
+                let (mode, sz): (Mode, usize) = parse_item(bytes, index, "mode")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        output: output,
+                        mode: mode,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 12;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_output_mode_request_asb! { AddOutputModeRequest }
+impl Request for AddOutputModeRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 18;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddOutputMode";
+    type Reply = ();
+}
+#[doc = " A builder for `AddOutputModeRequest`."]
+#[derive(Clone, Debug)]
+pub struct AddOutputModeRequestBuilder {
+    pub inner: AddOutputModeRequest,
+}
+impl AddOutputModeRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.output.as_bytes(&mut bytes[index..]);
-        index += self.mode.as_bytes(&mut bytes[index..]);
-        index
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing AddOutputModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            AddOutputModeRequest {
-                req_type: req_type,
-                length: length,
-                output: output,
-                mode: mode,
-            },
-            index,
-        ))
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.inner.mode = mode;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.output.size() + self.mode.size()
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AddOutputModeRequest {
+        self.inner
     }
 }
-impl Request for AddOutputModeRequest {
-    const OPCODE: u8 = 18;
-    const EXTENSION: Option<&'static str> = Some("RANDR");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
+impl Default for AddOutputModeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AddOutputModeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                mode: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DeleteOutputModeRequest {
     pub req_type: u8,
     pub length: u16,
     pub output: Output,
     pub mode: Mode,
 }
-impl DeleteOutputModeRequest {}
-impl AsByteSequence for DeleteOutputModeRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.output.as_bytes(&mut bytes[index..]);
-        index += self.mode.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl DeleteOutputModeRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DeleteOutputModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DeleteOutputModeRequest {
-                req_type: req_type,
-                length: length,
-                output: output,
-                mode: mode,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.output.size() + self.mode.size()
+    pub fn builder() -> DeleteOutputModeRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("output", 4, 4),
+        ("mode", 8, 4),
+    ];
 }
+const _: () = assert!(<DeleteOutputModeRequest as Request>::MINOR_OPCODE < 0x100);
+impl_output_mode_request_asb! { DeleteOutputModeRequest }
 impl Request for DeleteOutputModeRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteOutputMode";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DeleteOutputModeRequest`."]
+#[derive(Clone, Debug)]
+pub struct DeleteOutputModeRequestBuilder {
+    pub inner: DeleteOutputModeRequest,
+}
+impl DeleteOutputModeRequestBuilder {
+    #[inline]
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
+    }
+    #[inline]
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.inner.mode = mode;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DeleteOutputModeRequest {
+        self.inner
+    }
+}
+impl Default for DeleteOutputModeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DeleteOutputModeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output: Default::default(),
+                mode: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCrtcInfoRequest {
     pub req_type: u8,
     pub length: u16,
     pub crtc: Crtc,
     pub config_timestamp: Timestamp,
 }
-impl GetCrtcInfoRequest {}
-impl AsByteSequence for GetCrtcInfoRequest {
+impl GetCrtcInfoRequest {
+    #[inline]
+    pub fn builder() -> GetCrtcInfoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCrtcInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("crtc", 4, 4)];
+}
+const _: () = assert!(<GetCrtcInfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetCrtcInfoRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.crtc.as_bytes(&mut bytes[index..]);
         index += self.config_timestamp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCrtcInfoRequest {
                 req_type: req_type,
                 length: length,
@@ -3154,20 +5856,63 @@ impl AsByteSequence for GetCrtcInfoRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.crtc.size()
-            + self.config_timestamp.size()
+        let size: usize = 8 + self.config_timestamp.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetCrtcInfoRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetCrtcInfoRequest {
-    const OPCODE: u8 = 20;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 20;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcInfo";
     type Reply = GetCrtcInfoReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetCrtcInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCrtcInfoRequestBuilder {
+    pub inner: GetCrtcInfoRequest,
+}
+impl GetCrtcInfoRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    pub fn config_timestamp(mut self, config_timestamp: Timestamp) -> Self {
+        self.inner.config_timestamp = config_timestamp;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCrtcInfoRequest {
+        self.inner
+    }
+}
+impl Default for GetCrtcInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetCrtcInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+                config_timestamp: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCrtcInfoReply {
     pub reply_type: u8,
     pub status: SetConfig,
@@ -3184,9 +5929,60 @@ pub struct GetCrtcInfoReply {
     pub outputs: Vec<Output>,
     pub possible: Vec<Output>,
 }
-impl GetCrtcInfoReply {}
+impl GetCrtcInfoReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.timestamp == other.timestamp
+            && self.x == other.x
+            && self.y == other.y
+            && self.width == other.width
+            && self.height == other.height
+            && self.mode == other.mode
+            && self.rotation == other.rotation
+            && self.rotations == other.rotations
+            && self.outputs == other.outputs
+            && self.possible == other.possible
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value("x", &self.x, &other.x, &mut changes);
+        diff_value("y", &self.y, &other.y, &mut changes);
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_value("mode", &self.mode, &other.mode, &mut changes);
+        diff_value("rotation", &self.rotation, &other.rotation, &mut changes);
+        diff_value("rotations", &self.rotations, &other.rotations, &mut changes);
+        diff_list("outputs", &self.outputs, &other.outputs, &mut changes);
+        diff_list("possible", &self.possible, &other.possible, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for GetCrtcInfoReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -3205,53 +6001,61 @@ impl AsByteSequence for GetCrtcInfoReply {
         index += (self.possible.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+        );
         let block_len: usize = vector_as_bytes(&self.possible, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
         index += sz;
-        let (rotations, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotations, sz): (Rotation, usize) = parse_item(bytes, index, "rotations")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         let (possible, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "possible")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCrtcInfoReply {
                 reply_type: reply_type,
                 status: status,
@@ -3271,22 +6075,9 @@ impl AsByteSequence for GetCrtcInfoReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
+        let size: usize = 28
             + self.timestamp.size()
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
-            + self.mode.size()
-            + self.rotation.size()
-            + self.rotations.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
             + {
                 let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
@@ -3296,10 +6087,21 @@ impl AsByteSequence for GetCrtcInfoReply {
                 let block_len: usize = self.possible.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetCrtcInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetCrtcConfigRequest {
     pub req_type: u8,
     pub length: u16,
@@ -3312,13 +6114,29 @@ pub struct SetCrtcConfigRequest {
     pub rotation: Rotation,
     pub outputs: Vec<Output>,
 }
-impl SetCrtcConfigRequest {}
-impl AsByteSequence for SetCrtcConfigRequest {
+impl SetCrtcConfigRequest {
+    #[inline]
+    pub fn builder() -> SetCrtcConfigRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<SetCrtcConfigReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("crtc", 4, 4)];
+}
+const _: () = assert!(<SetCrtcConfigRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetCrtcConfigRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.crtc.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
@@ -3327,41 +6145,53 @@ impl AsByteSequence for SetCrtcConfigRequest {
         index += self.y.as_bytes(&mut bytes[index..]);
         index += self.mode.as_bytes(&mut bytes[index..]);
         index += self.rotation.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcConfigRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
         index += sz;
         index += 2;
-        let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (outputs, block_len): (Vec<Output>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "outputs",
+                offset: index,
+            })? as usize,
+            "outputs",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetCrtcConfigRequest {
                 req_type: req_type,
                 length: length,
@@ -3379,31 +6209,117 @@ impl AsByteSequence for SetCrtcConfigRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.crtc.size()
-            + self.timestamp.size()
-            + self.config_timestamp.size()
-            + self.x.size()
-            + self.y.size()
-            + self.mode.size()
-            + self.rotation.size()
-            + 2
-            + {
-                let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
-                block_len + pad
-            }
+        let size: usize = 20 + self.timestamp.size() + self.config_timestamp.size() + {
+            let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetCrtcConfigRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetCrtcConfigRequest {
-    const OPCODE: u8 = 21;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 21;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCrtcConfig";
     type Reply = SetCrtcConfigReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetCrtcConfigRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetCrtcConfigRequestBuilder {
+    pub inner: SetCrtcConfigRequest,
+}
+impl SetCrtcConfigRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.inner.timestamp = timestamp;
+        self
+    }
+    #[inline]
+    pub fn config_timestamp(mut self, config_timestamp: Timestamp) -> Self {
+        self.inner.config_timestamp = config_timestamp;
+        self
+    }
+    #[inline]
+    pub fn x(mut self, x: Int16) -> Self {
+        self.inner.x = x;
+        self
+    }
+    #[inline]
+    pub fn y(mut self, y: Int16) -> Self {
+        self.inner.y = y;
+        self
+    }
+    #[inline]
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.inner.mode = mode;
+        self
+    }
+    #[inline]
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.inner.rotation = rotation;
+        self
+    }
+    #[inline]
+    pub fn outputs(mut self, outputs: Vec<Output>) -> Self {
+        self.inner.outputs = outputs;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetCrtcConfigRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Output> for SetCrtcConfigRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Output>>(&mut self, iter: I) {
+        self.inner.outputs.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Output> for SetCrtcConfigRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Output>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for SetCrtcConfigRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetCrtcConfigRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+                timestamp: Default::default(),
+                config_timestamp: Default::default(),
+                x: Default::default(),
+                y: Default::default(),
+                mode: Default::default(),
+                rotation: Default::default(),
+                outputs: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetCrtcConfigReply {
     pub reply_type: u8,
     pub status: SetConfig,
@@ -3411,9 +6327,41 @@ pub struct SetCrtcConfigReply {
     pub length: u32,
     pub timestamp: Timestamp,
 }
-impl SetCrtcConfigReply {}
-impl AsByteSequence for SetCrtcConfigReply {
+impl SetCrtcConfigReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status && self.timestamp == other.timestamp
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for SetCrtcConfigReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -3421,25 +6369,27 @@ impl AsByteSequence for SetCrtcConfigReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcConfigReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetCrtcConfigReply {
                 reply_type: reply_type,
                 status: status,
@@ -3452,97 +6402,227 @@ impl AsByteSequence for SetCrtcConfigReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.timestamp.size()
-            + 20
+        let size: usize = 28 + self.timestamp.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SetCrtcConfigReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCrtcGammaSizeRequest {
     pub req_type: u8,
     pub length: u16,
     pub crtc: Crtc,
 }
-impl GetCrtcGammaSizeRequest {}
-impl AsByteSequence for GetCrtcGammaSizeRequest {
+impl GetCrtcGammaSizeRequest {
+    #[inline]
+    pub fn builder() -> GetCrtcGammaSizeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCrtcGammaSizeReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("crtc", 4, 4)];
+}
+const _: () = assert!(<GetCrtcGammaSizeRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_crtc_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.crtc.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        crtc: crtc,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_crtc_only_request_asb! { GetCrtcGammaSizeRequest }
+impl Request for GetCrtcGammaSizeRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 22;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcGammaSize";
+    type Reply = GetCrtcGammaSizeReply;
+}
+#[doc = " A builder for `GetCrtcGammaSizeRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCrtcGammaSizeRequestBuilder {
+    pub inner: GetCrtcGammaSizeRequest,
+}
+impl GetCrtcGammaSizeRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.crtc.as_bytes(&mut bytes[index..]);
-        index
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetCrtcGammaSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetCrtcGammaSizeRequest {
-                req_type: req_type,
-                length: length,
-                crtc: crtc,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCrtcGammaSizeRequest {
+        self.inner
     }
+}
+impl Default for GetCrtcGammaSizeRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.crtc.size()
+    fn default() -> Self {
+        Self {
+            inner: GetCrtcGammaSizeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+            },
+        }
     }
 }
-impl Request for GetCrtcGammaSizeRequest {
-    const OPCODE: u8 = 22;
-    const EXTENSION: Option<&'static str> = Some("RANDR");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = GetCrtcGammaSizeReply;
-}
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCrtcGammaSizeReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub size: Card16,
 }
-impl GetCrtcGammaSizeReply {}
+impl GetCrtcGammaSizeReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let size: Card16 = read_item(reader, &mut index, 2, "size")?;
+        skip_bytes(reader, &mut index, 22)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetCrtcGammaSizeReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            size: size,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("size", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.size == other.size
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("size", &self.size, &other.size, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for GetCrtcGammaSizeReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
-        index += 22;
+        index += zero_pad(&mut bytes[index..], 22);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcGammaSizeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
         index += sz;
         index += 22;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCrtcGammaSizeReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -3554,63 +6634,84 @@ impl AsByteSequence for GetCrtcGammaSizeReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.size.size()
-            + 22
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetCrtcGammaSizeReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCrtcGammaRequest {
     pub req_type: u8,
     pub length: u16,
     pub crtc: Crtc,
 }
-impl GetCrtcGammaRequest {}
-impl AsByteSequence for GetCrtcGammaRequest {
+impl GetCrtcGammaRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.crtc.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetCrtcGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetCrtcGammaRequest {
-                req_type: req_type,
-                length: length,
-                crtc: crtc,
-            },
-            index,
-        ))
+    pub fn builder() -> GetCrtcGammaRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.crtc.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCrtcGammaReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("crtc", 4, 4)];
 }
+const _: () = assert!(<GetCrtcGammaRequest as Request>::MINOR_OPCODE < 0x100);
+impl_crtc_only_request_asb! { GetCrtcGammaRequest }
 impl Request for GetCrtcGammaRequest {
-    const OPCODE: u8 = 23;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 23;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcGamma";
     type Reply = GetCrtcGammaReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetCrtcGammaRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCrtcGammaRequestBuilder {
+    pub inner: GetCrtcGammaRequest,
+}
+impl GetCrtcGammaRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCrtcGammaRequest {
+        self.inner
+    }
+}
+impl Default for GetCrtcGammaRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetCrtcGammaRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCrtcGammaReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -3620,55 +6721,208 @@ pub struct GetCrtcGammaReply {
     pub green: Vec<Card16>,
     pub blue: Vec<Card16>,
 }
-impl GetCrtcGammaReply {}
-impl AsByteSequence for GetCrtcGammaReply {
+impl GetCrtcGammaReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn red_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 22;
+        let (red, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, (size as usize) as usize, "red")?;
+        Ok(red)
+    }
+    pub fn green_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 22;
+        let (red, block_len): (Vec<Card16>, usize) =
+            parse_vector(bytes, index, (size as usize) as usize, "red")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, (size as usize) as usize, "green")?;
+        Ok(green)
+    }
+    pub fn blue_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 22;
+        let (red, block_len): (Vec<Card16>, usize) =
+            parse_vector(bytes, index, (size as usize) as usize, "red")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, block_len): (Vec<Card16>, usize) =
+            parse_vector(bytes, index, (size as usize) as usize, "green")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (blue, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, (size as usize) as usize, "blue")?;
+        Ok(blue)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let size: Card16 = read_item(reader, &mut index, 2, "size")?;
+        skip_bytes(reader, &mut index, 22)?;
+        let (red, block_len): (Vec<Card16>, usize) =
+            read_vector(reader, &mut index, (size as usize) as usize, 2, "red")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        )?;
+        let (green, block_len): (Vec<Card16>, usize) =
+            read_vector(reader, &mut index, (size as usize) as usize, 2, "green")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        )?;
+        let (blue, block_len): (Vec<Card16>, usize) =
+            read_vector(reader, &mut index, (size as usize) as usize, 2, "blue")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetCrtcGammaReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            size: size,
+            red: red,
+            green: green,
+            blue: blue,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("size", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.red == other.red
+            && self.green == other.green
+            && self.blue == other.blue
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("size", &self.size, &other.size, &mut changes);
+        diff_list("red", &self.red, &other.red, &mut changes);
+        diff_list("green", &self.green, &other.green, &mut changes);
+        diff_list("blue", &self.blue, &other.blue, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetCrtcGammaReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
-        index += 22;
+        index += zero_pad(&mut bytes[index..], 22);
         let block_len: usize = vector_as_bytes(&self.red, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.green, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.blue, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcGammaReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
         index += sz;
         index += 22;
         let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            parse_vector(bytes, index, (size as usize) as usize, "red")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            parse_vector(bytes, index, (size as usize) as usize, "green")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            parse_vector(bytes, index, (size as usize) as usize, "blue")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCrtcGammaReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -3681,32 +6935,73 @@ impl AsByteSequence for GetCrtcGammaReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.size.size()
-            + 22
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.red.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.green.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.blue.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
-}
-#[derive(Clone, Debug, Default)]
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            32 + {
+                let block_len: usize = self.red.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.green.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.blue.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetCrtcGammaReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCrtcGammaReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let size: Card16 = u.arbitrary()?;
+        let red = arbitrary_list(u, (size as usize) as usize)?;
+        let green = arbitrary_list(u, (size as usize) as usize)?;
+        let blue = arbitrary_list(u, (size as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            size: size,
+            red: red,
+            green: green,
+            blue: blue,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetCrtcGammaRequest {
     pub req_type: u8,
     pub length: u16,
@@ -3716,55 +7011,172 @@ pub struct SetCrtcGammaRequest {
     pub green: Vec<Card16>,
     pub blue: Vec<Card16>,
 }
-impl SetCrtcGammaRequest {}
+impl SetCrtcGammaRequest {
+    pub fn red_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
+        index += sz;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 2;
+        let (red, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, (size as usize) as usize, "red")?;
+        Ok(red)
+    }
+    pub fn green_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
+        index += sz;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 2;
+        let (red, block_len): (Vec<Card16>, usize) =
+            parse_vector(bytes, index, (size as usize) as usize, "red")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, (size as usize) as usize, "green")?;
+        Ok(green)
+    }
+    pub fn blue_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
+        index += sz;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 2;
+        let (red, block_len): (Vec<Card16>, usize) =
+            parse_vector(bytes, index, (size as usize) as usize, "red")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, block_len): (Vec<Card16>, usize) =
+            parse_vector(bytes, index, (size as usize) as usize, "green")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (blue, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, (size as usize) as usize, "blue")?;
+        Ok(blue)
+    }
+    #[inline]
+    pub fn builder() -> SetCrtcGammaRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("crtc", 4, 4),
+        ("size", 8, 2),
+    ];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize = (self.size as usize) as usize;
+            if self.red.len() != expected {
+                return Err(ValidationError {
+                    list: "red",
+                    expected,
+                    found: self.red.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (self.size as usize) as usize;
+            if self.green.len() != expected {
+                return Err(ValidationError {
+                    list: "green",
+                    expected,
+                    found: self.green.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (self.size as usize) as usize;
+            if self.blue.len() != expected {
+                return Err(ValidationError {
+                    list: "blue",
+                    expected,
+                    found: self.blue.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<SetCrtcGammaRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for SetCrtcGammaRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.crtc.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.red, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.green, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.blue, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
         index += sz;
         index += 2;
         let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            parse_vector(bytes, index, (size as usize) as usize, "red")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            parse_vector(bytes, index, (size as usize) as usize, "green")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            parse_vector(bytes, index, (size as usize) as usize, "blue")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetCrtcGammaRequest {
                 req_type: req_type,
                 length: length,
@@ -3777,86 +7189,202 @@ impl AsByteSequence for SetCrtcGammaRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.crtc.size()
-            + self.size.size()
-            + 2
-            + {
+        let size: usize =
+            12 + {
                 let block_len: usize = self.red.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.green.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.blue.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            12 + {
+                let block_len: usize = self.red.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.green.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.blue.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetCrtcGammaRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetCrtcGammaRequest {
-    const OPCODE: u8 = 24;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 24;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCrtcGamma";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for SetCrtcGammaRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let crtc: Crtc = u.arbitrary()?;
+        let size: Card16 = u.arbitrary()?;
+        let red = arbitrary_list(u, (size as usize) as usize)?;
+        let green = arbitrary_list(u, (size as usize) as usize)?;
+        let blue = arbitrary_list(u, (size as usize) as usize)?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            crtc: crtc,
+            size: size,
+            red: red,
+            green: green,
+            blue: blue,
+        })
+    }
+}
+#[doc = " A builder for `SetCrtcGammaRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetCrtcGammaRequestBuilder {
+    pub inner: SetCrtcGammaRequest,
+}
+impl SetCrtcGammaRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    pub fn size(mut self, size: Card16) -> Self {
+        self.inner.size = size;
+        self
+    }
+    #[inline]
+    pub fn red(mut self, red: Vec<Card16>) -> Self {
+        self.inner.red = red;
+        self
+    }
+    #[inline]
+    pub fn green(mut self, green: Vec<Card16>) -> Self {
+        self.inner.green = green;
+        self
+    }
+    #[inline]
+    pub fn blue(mut self, blue: Vec<Card16>) -> Self {
+        self.inner.blue = blue;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetCrtcGammaRequest {
+        self.inner
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl Default for SetCrtcGammaRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetCrtcGammaRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+                size: Default::default(),
+                red: Default::default(),
+                green: Default::default(),
+                blue: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetScreenResourcesCurrentRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetScreenResourcesCurrentRequest {}
-impl AsByteSequence for GetScreenResourcesCurrentRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetScreenResourcesCurrentRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetScreenResourcesCurrentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetScreenResourcesCurrentRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    pub fn builder() -> GetScreenResourcesCurrentRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetScreenResourcesCurrentReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetScreenResourcesCurrentRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { GetScreenResourcesCurrentRequest }
 impl Request for GetScreenResourcesCurrentRequest {
-    const OPCODE: u8 = 25;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 25;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenResourcesCurrent";
     type Reply = GetScreenResourcesCurrentReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetScreenResourcesCurrentRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetScreenResourcesCurrentRequestBuilder {
+    pub inner: GetScreenResourcesCurrentRequest,
+}
+impl GetScreenResourcesCurrentRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetScreenResourcesCurrentRequest {
+        self.inner
+    }
+}
+impl Default for GetScreenResourcesCurrentRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetScreenResourcesCurrentRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetScreenResourcesCurrentReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -3868,127 +7396,145 @@ pub struct GetScreenResourcesCurrentReply {
     pub modes: Vec<ModeInfo>,
     pub names: Vec<Byte>,
 }
-impl GetScreenResourcesCurrentReply {}
-impl AsByteSequence for GetScreenResourcesCurrentReply {
+impl GetScreenResourcesCurrentReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn names_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
         let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
         index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.timestamp.as_bytes(&mut bytes[index..]);
-        index += self.config_timestamp.as_bytes(&mut bytes[index..]);
-        index += (self.crtcs.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += (self.outputs.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += (self.modes.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += (self.names.len() as Card16).as_bytes(&mut bytes[index..]);
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+        index += sz;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
         index += 8;
-        let block_len: usize = vector_as_bytes(&self.crtcs, &mut bytes[index..]);
+        let (crtcs, block_len): (Vec<Crtc>, usize) =
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
-        let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
+        let (outputs, block_len): (Vec<Output>, usize) =
+            parse_vector(bytes, index, len1 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        let block_len: usize = vector_as_bytes(&self.modes, &mut bytes[index..]);
+        let (modes, block_len): (Vec<ModeInfo>, usize) =
+            parse_vector(bytes, index, len2 as usize, "modes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-        let block_len: usize = vector_as_bytes(&self.names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        index
+        let (names, _): (Cow<'_, [Byte]>, usize) =
+            parse_slice(bytes, index, len3 as usize, "names")?;
+        Ok(names)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub fn modes_iter(bytes: &[u8]) -> Result<ListIter<'_, ModeInfo>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetScreenResourcesCurrentReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
         index += sz;
         index += 8;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        let (modes, block_len): (Vec<ModeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-        let (names, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
-            GetScreenResourcesCurrentReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                timestamp: timestamp,
-                config_timestamp: config_timestamp,
-                crtcs: crtcs,
-                outputs: outputs,
-                modes: modes,
-                names: names,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.timestamp.size()
-            + self.config_timestamp.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + 8
-            + {
-                let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.modes.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+        Ok(ListIter::new(bytes, index, len2 as usize, "modes"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp
+            && self.config_timestamp == other.config_timestamp
+            && self.crtcs == other.crtcs
+            && self.outputs == other.outputs
+            && self.modes == other.modes
+            && self.names == other.names
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value(
+            "config_timestamp",
+            &self.config_timestamp,
+            &other.config_timestamp,
+            &mut changes,
+        );
+        diff_list("crtcs", &self.crtcs, &other.crtcs, &mut changes);
+        diff_list("outputs", &self.outputs, &other.outputs, &mut changes);
+        diff_list("modes", &self.modes, &other.modes, &mut changes);
+        diff_list("names", &self.names, &other.names, &mut changes);
+        changes
+    }
+}
+impl_reply_asb_5845fe3c! { GetScreenResourcesCurrentReply }
+impl core::fmt::Debug for GetScreenResourcesCurrentReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetScreenResourcesCurrentReply")
+            .field("reply_type", &self.reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("timestamp", &self.timestamp)
+            .field("config_timestamp", &self.config_timestamp)
+            .field("crtcs", &self.crtcs)
+            .field("outputs", &self.outputs)
+            .field("modes", &self.modes)
+            .field("names", &debug_bytes(&self.names, 64))
+            .finish()
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetCrtcTransformRequest {
     pub req_type: u8,
     pub length: u16,
@@ -3997,51 +7543,92 @@ pub struct SetCrtcTransformRequest {
     pub filter_name: String,
     pub filter_params: Vec<Fixed>,
 }
-impl SetCrtcTransformRequest {}
-impl AsByteSequence for SetCrtcTransformRequest {
+impl SetCrtcTransformRequest {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(
+        crtc: Crtc,
+        transform: Transform,
+        filter_name: &str,
+        filter_params: Vec<Fixed>,
+    ) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            crtc: crtc,
+            transform: transform,
+            filter_name: String::from(filter_name),
+            filter_params: filter_params,
+        }
+    }
     #[inline]
+    pub fn builder() -> SetCrtcTransformRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("crtc", 4, 4),
+        ("transform", 8, 4),
+        ("filter_name_len", 12, 2),
+    ];
+}
+const _: () = assert!(<SetCrtcTransformRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetCrtcTransformRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.crtc.as_bytes(&mut bytes[index..]);
         index += self.transform.as_bytes(&mut bytes[index..]);
         index += (self.filter_name.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = string_as_bytes(&self.filter_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         let block_len: usize = vector_as_bytes(&self.filter_params, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fixed>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcTransformRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let (transform, sz): (Transform, usize) = parse_item(bytes, index, "transform")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
         let (filter_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "filter_name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        let (filter_params, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (filter_params, block_len): (Vec<Fixed>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "filter_params",
+                offset: index,
+            })? as usize,
+            "filter_params",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetCrtcTransformRequest {
                 req_type: req_type,
                 length: length,
@@ -4055,80 +7642,149 @@ impl AsByteSequence for SetCrtcTransformRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.crtc.size()
-            + self.transform.size()
-            + ::core::mem::size_of::<Card16>()
-            + 2
-            + {
+        let size: usize =
+            16 + {
                 let block_len: usize = self.filter_name.len();
                 let pad: usize = buffer_pad(block_len, 4);
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.filter_params.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetCrtcTransformRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetCrtcTransformRequest {
-    const OPCODE: u8 = 26;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 26;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCrtcTransform";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetCrtcTransformRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetCrtcTransformRequestBuilder {
+    pub inner: SetCrtcTransformRequest,
+}
+impl SetCrtcTransformRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.inner.transform = transform;
+        self
+    }
+    #[inline]
+    pub fn filter_name(mut self, filter_name: String) -> Self {
+        self.inner.filter_name = filter_name;
+        self
+    }
+    #[inline]
+    pub fn filter_params(mut self, filter_params: Vec<Fixed>) -> Self {
+        self.inner.filter_params = filter_params;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetCrtcTransformRequest {
+        self.inner
+    }
+}
+impl Default for SetCrtcTransformRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetCrtcTransformRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+                transform: Default::default(),
+                filter_name: Default::default(),
+                filter_params: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCrtcTransformRequest {
     pub req_type: u8,
     pub length: u16,
     pub crtc: Crtc,
 }
-impl GetCrtcTransformRequest {}
-impl AsByteSequence for GetCrtcTransformRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.crtc.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetCrtcTransformRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetCrtcTransformRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetCrtcTransformRequest {
-                req_type: req_type,
-                length: length,
-                crtc: crtc,
-            },
-            index,
-        ))
+    pub fn builder() -> GetCrtcTransformRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.crtc.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCrtcTransformReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("crtc", 4, 4)];
 }
+const _: () = assert!(<GetCrtcTransformRequest as Request>::MINOR_OPCODE < 0x100);
+impl_crtc_only_request_asb! { GetCrtcTransformRequest }
 impl Request for GetCrtcTransformRequest {
-    const OPCODE: u8 = 27;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 27;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcTransform";
     type Reply = GetCrtcTransformReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetCrtcTransformRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCrtcTransformRequestBuilder {
+    pub inner: GetCrtcTransformRequest,
+}
+impl GetCrtcTransformRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCrtcTransformRequest {
+        self.inner
+    }
+}
+impl Default for GetCrtcTransformRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetCrtcTransformRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCrtcTransformReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -4141,82 +7797,176 @@ pub struct GetCrtcTransformReply {
     pub current_filter_name: String,
     pub current_params: Vec<Fixed>,
 }
-impl GetCrtcTransformReply {}
+impl GetCrtcTransformReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("pending_transform", 8, 4),
+        ("has_transforms", 12, 1),
+        ("current_transform", 16, 4),
+        ("pending_filter_name_len", 24, 2),
+        ("pending_params_len", 26, 2),
+        ("current_filter_name_len", 28, 2),
+        ("current_params_len", 30, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.pending_transform == other.pending_transform
+            && self.has_transforms == other.has_transforms
+            && self.current_transform == other.current_transform
+            && self.pending_filter_name == other.pending_filter_name
+            && self.pending_params == other.pending_params
+            && self.current_filter_name == other.current_filter_name
+            && self.current_params == other.current_params
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "pending_transform",
+            &self.pending_transform,
+            &other.pending_transform,
+            &mut changes,
+        );
+        diff_value(
+            "has_transforms",
+            &self.has_transforms,
+            &other.has_transforms,
+            &mut changes,
+        );
+        diff_value(
+            "current_transform",
+            &self.current_transform,
+            &other.current_transform,
+            &mut changes,
+        );
+        diff_list(
+            "pending_filter_name",
+            str::as_bytes(&self.pending_filter_name),
+            str::as_bytes(&other.pending_filter_name),
+            &mut changes,
+        );
+        diff_list(
+            "pending_params",
+            &self.pending_params,
+            &other.pending_params,
+            &mut changes,
+        );
+        diff_list(
+            "current_filter_name",
+            str::as_bytes(&self.current_filter_name),
+            str::as_bytes(&other.current_filter_name),
+            &mut changes,
+        );
+        diff_list(
+            "current_params",
+            &self.current_params,
+            &other.current_params,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for GetCrtcTransformReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.pending_transform.as_bytes(&mut bytes[index..]);
         index += self.has_transforms.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.current_transform.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index += (self.pending_filter_name.len() as Card16).as_bytes(&mut bytes[index..]);
         index += (self.pending_params.len() as Card16).as_bytes(&mut bytes[index..]);
         index += (self.current_filter_name.len() as Card16).as_bytes(&mut bytes[index..]);
         index += (self.current_params.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.pending_filter_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         let block_len: usize = vector_as_bytes(&self.pending_params, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fixed>()),
+        );
         let block_len: usize = string_as_bytes(&self.current_filter_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         let block_len: usize = vector_as_bytes(&self.current_params, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fixed>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcTransformReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pending_transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (pending_transform, sz): (Transform, usize) =
+            parse_item(bytes, index, "pending_transform")?;
         index += sz;
-        let (has_transforms, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (has_transforms, sz): (bool, usize) = parse_item(bytes, index, "has_transforms")?;
         index += sz;
         index += 3;
-        let (current_transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let (current_transform, sz): (Transform, usize) =
+            parse_item(bytes, index, "current_transform")?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
         index += sz;
         let (pending_filter_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "pending_filter_name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (pending_params, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "pending_params")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (current_filter_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len2 as usize)?;
+            parse_string(bytes, index, len2 as usize, "current_filter_name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (current_params, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            parse_vector(bytes, index, len3 as usize, "current_params")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCrtcTransformReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -4232,21 +7982,9 @@ impl AsByteSequence for GetCrtcTransformReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.pending_transform.size()
+        let size: usize = 31
             + self.has_transforms.size()
-            + 3
-            + self.current_transform.size()
-            + 4
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
             + {
                 let block_len: usize = self.pending_filter_name.len();
                 let pad: usize = buffer_pad(block_len, 4);
@@ -4266,58 +8004,85 @@ impl AsByteSequence for GetCrtcTransformReply {
                 let block_len: usize = self.current_params.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetCrtcTransformReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPanningRequest {
     pub req_type: u8,
     pub length: u16,
     pub crtc: Crtc,
 }
-impl GetPanningRequest {}
-impl AsByteSequence for GetPanningRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.crtc.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetPanningRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetPanningRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetPanningRequest {
-                req_type: req_type,
-                length: length,
-                crtc: crtc,
-            },
-            index,
-        ))
+    pub fn builder() -> GetPanningRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.crtc.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPanningReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("crtc", 4, 4)];
 }
+const _: () = assert!(<GetPanningRequest as Request>::MINOR_OPCODE < 0x100);
+impl_crtc_only_request_asb! { GetPanningRequest }
 impl Request for GetPanningRequest {
-    const OPCODE: u8 = 28;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 28;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPanning";
     type Reply = GetPanningReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetPanningRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPanningRequestBuilder {
+    pub inner: GetPanningRequest,
+}
+impl GetPanningRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPanningRequest {
+        self.inner
+    }
+}
+impl Default for GetPanningRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPanningRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPanningReply {
     pub reply_type: u8,
     pub status: SetConfig,
@@ -4337,9 +8102,101 @@ pub struct GetPanningReply {
     pub border_right: Int16,
     pub border_bottom: Int16,
 }
-impl GetPanningReply {}
+impl GetPanningReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.timestamp == other.timestamp
+            && self.left == other.left
+            && self.top == other.top
+            && self.width == other.width
+            && self.height == other.height
+            && self.track_left == other.track_left
+            && self.track_top == other.track_top
+            && self.track_width == other.track_width
+            && self.track_height == other.track_height
+            && self.border_left == other.border_left
+            && self.border_top == other.border_top
+            && self.border_right == other.border_right
+            && self.border_bottom == other.border_bottom
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value("left", &self.left, &other.left, &mut changes);
+        diff_value("top", &self.top, &other.top, &mut changes);
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_value(
+            "track_left",
+            &self.track_left,
+            &other.track_left,
+            &mut changes,
+        );
+        diff_value("track_top", &self.track_top, &other.track_top, &mut changes);
+        diff_value(
+            "track_width",
+            &self.track_width,
+            &other.track_width,
+            &mut changes,
+        );
+        diff_value(
+            "track_height",
+            &self.track_height,
+            &other.track_height,
+            &mut changes,
+        );
+        diff_value(
+            "border_left",
+            &self.border_left,
+            &other.border_left,
+            &mut changes,
+        );
+        diff_value(
+            "border_top",
+            &self.border_top,
+            &other.border_top,
+            &mut changes,
+        );
+        diff_value(
+            "border_right",
+            &self.border_right,
+            &other.border_right,
+            &mut changes,
+        );
+        diff_value(
+            "border_bottom",
+            &self.border_bottom,
+            &other.border_bottom,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for GetPanningReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -4359,47 +8216,49 @@ impl AsByteSequence for GetPanningReply {
         index += self.border_top.as_bytes(&mut bytes[index..]);
         index += self.border_right.as_bytes(&mut bytes[index..]);
         index += self.border_bottom.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPanningReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Card16, usize) = parse_item(bytes, index, "left")?;
         index += sz;
-        let (top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Card16, usize) = parse_item(bytes, index, "top")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (track_left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_left, sz): (Card16, usize) = parse_item(bytes, index, "track_left")?;
         index += sz;
-        let (track_top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_top, sz): (Card16, usize) = parse_item(bytes, index, "track_top")?;
         index += sz;
-        let (track_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_width, sz): (Card16, usize) = parse_item(bytes, index, "track_width")?;
         index += sz;
-        let (track_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_height, sz): (Card16, usize) = parse_item(bytes, index, "track_height")?;
         index += sz;
-        let (border_left, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_left, sz): (Int16, usize) = parse_item(bytes, index, "border_left")?;
         index += sz;
-        let (border_top, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_top, sz): (Int16, usize) = parse_item(bytes, index, "border_top")?;
         index += sz;
-        let (border_right, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_right, sz): (Int16, usize) = parse_item(bytes, index, "border_right")?;
         index += sz;
-        let (border_bottom, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_bottom, sz): (Int16, usize) = parse_item(bytes, index, "border_bottom")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetPanningReply {
                 reply_type: reply_type,
                 status: status,
@@ -4424,26 +8283,21 @@ impl AsByteSequence for GetPanningReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.timestamp.size()
-            + self.left.size()
-            + self.top.size()
-            + self.width.size()
-            + self.height.size()
-            + self.track_left.size()
-            + self.track_top.size()
-            + self.track_width.size()
-            + self.track_height.size()
-            + self.border_left.size()
-            + self.border_top.size()
-            + self.border_right.size()
-            + self.border_bottom.size()
+        let size: usize = 32 + self.timestamp.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetPanningReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPanningRequest {
     pub req_type: u8,
     pub length: u16,
@@ -4462,13 +8316,29 @@ pub struct SetPanningRequest {
     pub border_right: Int16,
     pub border_bottom: Int16,
 }
-impl SetPanningRequest {}
-impl AsByteSequence for SetPanningRequest {
+impl SetPanningRequest {
     #[inline]
+    pub fn builder() -> SetPanningRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<SetPanningReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("crtc", 4, 4)];
+}
+const _: () = assert!(<SetPanningRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetPanningRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.crtc.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
@@ -4484,46 +8354,47 @@ impl AsByteSequence for SetPanningRequest {
         index += self.border_top.as_bytes(&mut bytes[index..]);
         index += self.border_right.as_bytes(&mut bytes[index..]);
         index += self.border_bottom.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPanningRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Card16, usize) = parse_item(bytes, index, "left")?;
         index += sz;
-        let (top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Card16, usize) = parse_item(bytes, index, "top")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (track_left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_left, sz): (Card16, usize) = parse_item(bytes, index, "track_left")?;
         index += sz;
-        let (track_top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_top, sz): (Card16, usize) = parse_item(bytes, index, "track_top")?;
         index += sz;
-        let (track_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_width, sz): (Card16, usize) = parse_item(bytes, index, "track_width")?;
         index += sz;
-        let (track_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_height, sz): (Card16, usize) = parse_item(bytes, index, "track_height")?;
         index += sz;
-        let (border_left, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_left, sz): (Int16, usize) = parse_item(bytes, index, "border_left")?;
         index += sz;
-        let (border_top, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_top, sz): (Int16, usize) = parse_item(bytes, index, "border_top")?;
         index += sz;
-        let (border_right, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_right, sz): (Int16, usize) = parse_item(bytes, index, "border_right")?;
         index += sz;
-        let (border_bottom, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_bottom, sz): (Int16, usize) = parse_item(bytes, index, "border_bottom")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPanningRequest {
                 req_type: req_type,
                 length: length,
@@ -4547,32 +8418,135 @@ impl AsByteSequence for SetPanningRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.crtc.size()
-            + self.timestamp.size()
-            + self.left.size()
-            + self.top.size()
-            + self.width.size()
-            + self.height.size()
-            + self.track_left.size()
-            + self.track_top.size()
-            + self.track_width.size()
-            + self.track_height.size()
-            + self.border_left.size()
-            + self.border_top.size()
-            + self.border_right.size()
-            + self.border_bottom.size()
+        let size: usize = 32 + self.timestamp.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetPanningRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetPanningRequest {
-    const OPCODE: u8 = 29;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 29;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPanning";
     type Reply = SetPanningReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPanningRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPanningRequestBuilder {
+    pub inner: SetPanningRequest,
+}
+impl SetPanningRequestBuilder {
+    #[inline]
+    pub fn crtc(mut self, crtc: Crtc) -> Self {
+        self.inner.crtc = crtc;
+        self
+    }
+    #[inline]
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.inner.timestamp = timestamp;
+        self
+    }
+    #[inline]
+    pub fn left(mut self, left: Card16) -> Self {
+        self.inner.left = left;
+        self
+    }
+    #[inline]
+    pub fn top(mut self, top: Card16) -> Self {
+        self.inner.top = top;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn track_left(mut self, track_left: Card16) -> Self {
+        self.inner.track_left = track_left;
+        self
+    }
+    #[inline]
+    pub fn track_top(mut self, track_top: Card16) -> Self {
+        self.inner.track_top = track_top;
+        self
+    }
+    #[inline]
+    pub fn track_width(mut self, track_width: Card16) -> Self {
+        self.inner.track_width = track_width;
+        self
+    }
+    #[inline]
+    pub fn track_height(mut self, track_height: Card16) -> Self {
+        self.inner.track_height = track_height;
+        self
+    }
+    #[inline]
+    pub fn border_left(mut self, border_left: Int16) -> Self {
+        self.inner.border_left = border_left;
+        self
+    }
+    #[inline]
+    pub fn border_top(mut self, border_top: Int16) -> Self {
+        self.inner.border_top = border_top;
+        self
+    }
+    #[inline]
+    pub fn border_right(mut self, border_right: Int16) -> Self {
+        self.inner.border_right = border_right;
+        self
+    }
+    #[inline]
+    pub fn border_bottom(mut self, border_bottom: Int16) -> Self {
+        self.inner.border_bottom = border_bottom;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPanningRequest {
+        self.inner
+    }
+}
+impl Default for SetPanningRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPanningRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                crtc: Default::default(),
+                timestamp: Default::default(),
+                left: Default::default(),
+                top: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                track_left: Default::default(),
+                track_top: Default::default(),
+                track_width: Default::default(),
+                track_height: Default::default(),
+                border_left: Default::default(),
+                border_top: Default::default(),
+                border_right: Default::default(),
+                border_bottom: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetPanningReply {
     pub reply_type: u8,
     pub status: SetConfig,
@@ -4580,9 +8554,41 @@ pub struct SetPanningReply {
     pub length: u32,
     pub timestamp: Timestamp,
 }
-impl SetPanningReply {}
-impl AsByteSequence for SetPanningReply {
+impl SetPanningReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status && self.timestamp == other.timestamp
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for SetPanningReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -4590,23 +8596,25 @@ impl AsByteSequence for SetPanningReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPanningReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPanningReply {
                 reply_type: reply_type,
                 status: status,
@@ -4619,46 +8627,61 @@ impl AsByteSequence for SetPanningReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.timestamp.size()
+        let size: usize = 8 + self.timestamp.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SetPanningReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetOutputPrimaryRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub output: Output,
 }
-impl SetOutputPrimaryRequest {}
-impl AsByteSequence for SetOutputPrimaryRequest {
+impl SetOutputPrimaryRequest {
     #[inline]
+    pub fn builder() -> SetOutputPrimaryRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetOutputPrimaryRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetOutputPrimaryRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.output.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetOutputPrimaryRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetOutputPrimaryRequest {
                 req_type: req_type,
                 length: length,
@@ -4670,96 +8693,209 @@ impl AsByteSequence for SetOutputPrimaryRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.output.size()
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetOutputPrimaryRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetOutputPrimaryRequest {
-    const OPCODE: u8 = 30;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 30;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetOutputPrimary";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetOutputPrimaryRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetOutputPrimaryRequestBuilder {
+    pub inner: SetOutputPrimaryRequest,
+}
+impl SetOutputPrimaryRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn output(mut self, output: Output) -> Self {
+        self.inner.output = output;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetOutputPrimaryRequest {
+        self.inner
+    }
+}
+impl Default for SetOutputPrimaryRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetOutputPrimaryRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                output: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetOutputPrimaryRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetOutputPrimaryRequest {}
-impl AsByteSequence for GetOutputPrimaryRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetOutputPrimaryRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetOutputPrimaryRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetOutputPrimaryRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    pub fn builder() -> GetOutputPrimaryRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetOutputPrimaryReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetOutputPrimaryRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { GetOutputPrimaryRequest }
 impl Request for GetOutputPrimaryRequest {
-    const OPCODE: u8 = 31;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 31;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOutputPrimary";
     type Reply = GetOutputPrimaryReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetOutputPrimaryRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetOutputPrimaryRequestBuilder {
+    pub inner: GetOutputPrimaryRequest,
+}
+impl GetOutputPrimaryRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetOutputPrimaryRequest {
+        self.inner
+    }
+}
+impl Default for GetOutputPrimaryRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetOutputPrimaryRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetOutputPrimaryReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub output: Output,
 }
-impl GetOutputPrimaryReply {}
-impl AsByteSequence for GetOutputPrimaryReply {
+impl GetOutputPrimaryReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let output: Output = read_item(reader, &mut index, 4, "output")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetOutputPrimaryReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            output: output,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("output", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.output == other.output
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("output", &self.output, &other.output, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetOutputPrimaryReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.output.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputPrimaryReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetOutputPrimaryReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -4771,58 +8907,84 @@ impl AsByteSequence for GetOutputPrimaryReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + 1 + self.sequence.size() + self.length.size() + self.output.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetOutputPrimaryReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetProvidersRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetProvidersRequest {}
-impl AsByteSequence for GetProvidersRequest {
+impl GetProvidersRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetProvidersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetProvidersRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    pub fn builder() -> GetProvidersRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetProvidersReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetProvidersRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { GetProvidersRequest }
 impl Request for GetProvidersRequest {
-    const OPCODE: u8 = 32;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 32;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetProviders";
     type Reply = GetProvidersReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetProvidersRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetProvidersRequestBuilder {
+    pub inner: GetProvidersRequest,
+}
+impl GetProvidersRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetProvidersRequest {
+        self.inner
+    }
+}
+impl Default for GetProvidersRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetProvidersRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetProvidersReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -4830,44 +8992,77 @@ pub struct GetProvidersReply {
     pub timestamp: Timestamp,
     pub providers: Vec<Provider>,
 }
-impl GetProvidersReply {}
-impl AsByteSequence for GetProvidersReply {
+impl GetProvidersReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp && self.providers == other.providers
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_list("providers", &self.providers, &other.providers, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetProvidersReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
         index += (self.providers.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 18;
+        index += zero_pad(&mut bytes[index..], 18);
         let block_len: usize = vector_as_bytes(&self.providers, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Provider>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Provider>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProvidersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 18;
         let (providers, block_len): (Vec<Provider>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "providers")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Provider>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetProvidersReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -4880,53 +9075,75 @@ impl AsByteSequence for GetProvidersReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.timestamp.size()
-            + ::core::mem::size_of::<Card16>()
-            + 18
-            + {
-                let block_len: usize = self.providers.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Provider>());
-                block_len + pad
-            }
+        let size: usize = 28 + self.timestamp.size() + {
+            let block_len: usize = self.providers.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Provider>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetProvidersReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetProviderInfoRequest {
     pub req_type: u8,
     pub length: u16,
     pub provider: Provider,
     pub config_timestamp: Timestamp,
 }
-impl GetProviderInfoRequest {}
-impl AsByteSequence for GetProviderInfoRequest {
+impl GetProviderInfoRequest {
     #[inline]
+    pub fn builder() -> GetProviderInfoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetProviderInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("provider", 4, 4)];
+}
+const _: () = assert!(<GetProviderInfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetProviderInfoRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
         index += self.config_timestamp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProviderInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetProviderInfoRequest {
                 req_type: req_type,
                 length: length,
@@ -4938,20 +9155,62 @@ impl AsByteSequence for GetProviderInfoRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.provider.size()
-            + self.config_timestamp.size()
+        let size: usize = 8 + self.config_timestamp.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetProviderInfoRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetProviderInfoRequest {
-    const OPCODE: u8 = 33;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 33;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetProviderInfo";
     type Reply = GetProviderInfoReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetProviderInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetProviderInfoRequestBuilder {
+    pub inner: GetProviderInfoRequest,
+}
+impl GetProviderInfoRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    pub fn config_timestamp(mut self, config_timestamp: Timestamp) -> Self {
+        self.inner.config_timestamp = config_timestamp;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetProviderInfoRequest {
+        self.inner
+    }
+}
+impl Default for GetProviderInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetProviderInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                config_timestamp: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetProviderInfoReply {
     pub reply_type: u8,
     pub status: Card8,
@@ -4966,9 +9225,131 @@ pub struct GetProviderInfoReply {
     pub associated_capability: Vec<Card32>,
     pub name: String,
 }
-impl GetProviderInfoReply {}
-impl AsByteSequence for GetProviderInfoReply {
+impl GetProviderInfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn associated_capability_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (status, sz): (Card8, usize) = parse_item(bytes, index, "status")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+        index += sz;
+        let (capabilities, sz): (ProviderCapability, usize) =
+            parse_item(bytes, index, "capabilities")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (num_associated_providers, sz): (Card16, usize) =
+            parse_item(bytes, index, "num_associated_providers")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        index += 8;
+        let (crtcs, block_len): (Vec<Crtc>, usize) =
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+        let (outputs, block_len): (Vec<Output>, usize) =
+            parse_vector(bytes, index, len1 as usize, "outputs")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        let (associated_providers, block_len): (Vec<Provider>, usize) = parse_vector(
+            bytes,
+            index,
+            (num_associated_providers as usize) as usize,
+            "associated_providers",
+        )?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Provider>());
+        let (associated_capability, _): (Cow<'_, [Card32]>, usize) = parse_slice(
+            bytes,
+            index,
+            (num_associated_providers as usize) as usize,
+            "associated_capability",
+        )?;
+        Ok(associated_capability)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.timestamp == other.timestamp
+            && self.capabilities == other.capabilities
+            && self.num_associated_providers == other.num_associated_providers
+            && self.crtcs == other.crtcs
+            && self.outputs == other.outputs
+            && self.associated_providers == other.associated_providers
+            && self.associated_capability == other.associated_capability
+            && self.name == other.name
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value(
+            "capabilities",
+            &self.capabilities,
+            &other.capabilities,
+            &mut changes,
+        );
+        diff_value(
+            "num_associated_providers",
+            &self.num_associated_providers,
+            &other.num_associated_providers,
+            &mut changes,
+        );
+        diff_list("crtcs", &self.crtcs, &other.crtcs, &mut changes);
+        diff_list("outputs", &self.outputs, &other.outputs, &mut changes);
+        diff_list(
+            "associated_providers",
+            &self.associated_providers,
+            &other.associated_providers,
+            &mut changes,
+        );
+        diff_list(
+            "associated_capability",
+            &self.associated_capability,
+            &other.associated_capability,
+            &mut changes,
+        );
+        diff_list(
+            "name",
+            str::as_bytes(&self.name),
+            str::as_bytes(&other.name),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetProviderInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -4981,75 +9362,96 @@ impl AsByteSequence for GetProviderInfoReply {
         index += (self.outputs.len() as Card16).as_bytes(&mut bytes[index..]);
         index += self.num_associated_providers.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += zero_pad(&mut bytes[index..], 8);
         let block_len: usize = vector_as_bytes(&self.crtcs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Crtc>()),
+        );
         let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+        );
         let block_len: usize = vector_as_bytes(&self.associated_providers, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Provider>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Provider>()),
+        );
         let block_len: usize = vector_as_bytes(&self.associated_capability, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProviderInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
         let (capabilities, sz): (ProviderCapability, usize) =
-            <ProviderCapability>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "capabilities")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (num_associated_providers, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "num_associated_providers")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
         index += 8;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        let (associated_providers, block_len): (Vec<Provider>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (associated_providers, block_len): (Vec<Provider>, usize) = parse_vector(
+            bytes,
+            index,
             (num_associated_providers as usize) as usize,
+            "associated_providers",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Provider>());
-        let (associated_capability, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (associated_capability, block_len): (Vec<Card32>, usize) = parse_vector(
+            bytes,
+            index,
             (num_associated_providers as usize) as usize,
+            "associated_capability",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len2 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len2 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetProviderInfoReply {
                 reply_type: reply_type,
                 status: status,
@@ -5067,19 +9469,9 @@ impl AsByteSequence for GetProviderInfoReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
+        let size: usize = 28
             + self.timestamp.size()
-            + self.capabilities.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + self.num_associated_providers.size()
-            + ::core::mem::size_of::<Card16>()
-            + 8
             + {
                 let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
@@ -5104,11 +9496,85 @@ impl AsByteSequence for GetProviderInfoReply {
                 let block_len: usize = self.name.len();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
                 block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = 28
+            + self.timestamp.size()
+            + {
+                let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+                block_len + pad
+            }
+            + {
+                let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
+                block_len + pad
+            }
+            + {
+                let block_len: usize = self.associated_providers.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Provider>());
+                block_len + pad
+            }
+            + {
+                let block_len: usize =
+                    self.associated_capability.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
             }
+            + {
+                let block_len: usize = self.name.len();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetProviderInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetProviderInfoReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let status: Card8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let timestamp: Timestamp = u.arbitrary()?;
+        let capabilities: ProviderCapability = u.arbitrary()?;
+        let num_associated_providers: Card16 = u.arbitrary()?;
+        let crtcs = u.arbitrary()?;
+        let outputs = u.arbitrary()?;
+        let name = u.arbitrary()?;
+        let associated_providers = arbitrary_list(u, (num_associated_providers as usize) as usize)?;
+        let associated_capability =
+            arbitrary_list(u, (num_associated_providers as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            status: status,
+            sequence: sequence,
+            length: length,
+            timestamp: timestamp,
+            capabilities: capabilities,
+            num_associated_providers: num_associated_providers,
+            crtcs: crtcs,
+            outputs: outputs,
+            associated_providers: associated_providers,
+            associated_capability: associated_capability,
+            name: name,
+        })
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ProviderCapability {
     pub inner: u32,
 }
@@ -5197,21 +9663,20 @@ impl ProviderCapability {
     pub const SINK_OFFLOAD: Self = Self { inner: 8 };
     pub const COMPLETE: Self = Self { inner: 15 };
 }
-impl AsByteSequence for ProviderCapability {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for ProviderCapability {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((ProviderCapability { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for ProviderCapability {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+impl_u32_flags_asb! { ProviderCapability }
 impl core::ops::Not for ProviderCapability {
     type Output = ProviderCapability;
     #[inline]
@@ -5246,7 +9711,10 @@ impl core::ops::BitXor for ProviderCapability {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetProviderOffloadSinkRequest {
     pub req_type: u8,
     pub length: u16,
@@ -5254,35 +9722,49 @@ pub struct SetProviderOffloadSinkRequest {
     pub sink_provider: Provider,
     pub config_timestamp: Timestamp,
 }
-impl SetProviderOffloadSinkRequest {}
-impl AsByteSequence for SetProviderOffloadSinkRequest {
+impl SetProviderOffloadSinkRequest {
     #[inline]
+    pub fn builder() -> SetProviderOffloadSinkRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("provider", 4, 4),
+        ("sink_provider", 8, 4),
+    ];
+}
+const _: () = assert!(<SetProviderOffloadSinkRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetProviderOffloadSinkRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
         index += self.sink_provider.as_bytes(&mut bytes[index..]);
         index += self.config_timestamp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetProviderOffloadSinkRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        let (sink_provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (sink_provider, sz): (Provider, usize) = parse_item(bytes, index, "sink_provider")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetProviderOffloadSinkRequest {
                 req_type: req_type,
                 length: length,
@@ -5295,21 +9777,70 @@ impl AsByteSequence for SetProviderOffloadSinkRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.provider.size()
-            + self.sink_provider.size()
-            + self.config_timestamp.size()
+        let size: usize = 12 + self.config_timestamp.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetProviderOffloadSinkRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetProviderOffloadSinkRequest {
-    const OPCODE: u8 = 34;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 34;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetProviderOffloadSink";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetProviderOffloadSinkRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetProviderOffloadSinkRequestBuilder {
+    pub inner: SetProviderOffloadSinkRequest,
+}
+impl SetProviderOffloadSinkRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    pub fn sink_provider(mut self, sink_provider: Provider) -> Self {
+        self.inner.sink_provider = sink_provider;
+        self
+    }
+    #[inline]
+    pub fn config_timestamp(mut self, config_timestamp: Timestamp) -> Self {
+        self.inner.config_timestamp = config_timestamp;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetProviderOffloadSinkRequest {
+        self.inner
+    }
+}
+impl Default for SetProviderOffloadSinkRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetProviderOffloadSinkRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                sink_provider: Default::default(),
+                config_timestamp: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetProviderOutputSourceRequest {
     pub req_type: u8,
     pub length: u16,
@@ -5317,35 +9848,49 @@ pub struct SetProviderOutputSourceRequest {
     pub source_provider: Provider,
     pub config_timestamp: Timestamp,
 }
-impl SetProviderOutputSourceRequest {}
-impl AsByteSequence for SetProviderOutputSourceRequest {
+impl SetProviderOutputSourceRequest {
     #[inline]
+    pub fn builder() -> SetProviderOutputSourceRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("provider", 4, 4),
+        ("source_provider", 8, 4),
+    ];
+}
+const _: () = assert!(<SetProviderOutputSourceRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetProviderOutputSourceRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
         index += self.source_provider.as_bytes(&mut bytes[index..]);
         index += self.config_timestamp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetProviderOutputSourceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        let (source_provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (source_provider, sz): (Provider, usize) = parse_item(bytes, index, "source_provider")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetProviderOutputSourceRequest {
                 req_type: req_type,
                 length: length,
@@ -5358,49 +9903,115 @@ impl AsByteSequence for SetProviderOutputSourceRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.provider.size()
-            + self.source_provider.size()
-            + self.config_timestamp.size()
+        let size: usize = 12 + self.config_timestamp.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetProviderOutputSourceRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetProviderOutputSourceRequest {
-    const OPCODE: u8 = 35;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 35;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetProviderOutputSource";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetProviderOutputSourceRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetProviderOutputSourceRequestBuilder {
+    pub inner: SetProviderOutputSourceRequest,
+}
+impl SetProviderOutputSourceRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    pub fn source_provider(mut self, source_provider: Provider) -> Self {
+        self.inner.source_provider = source_provider;
+        self
+    }
+    #[inline]
+    pub fn config_timestamp(mut self, config_timestamp: Timestamp) -> Self {
+        self.inner.config_timestamp = config_timestamp;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetProviderOutputSourceRequest {
+        self.inner
+    }
+}
+impl Default for SetProviderOutputSourceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetProviderOutputSourceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                source_provider: Default::default(),
+                config_timestamp: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListProviderPropertiesRequest {
     pub req_type: u8,
     pub length: u16,
     pub provider: Provider,
 }
-impl ListProviderPropertiesRequest {}
-impl AsByteSequence for ListProviderPropertiesRequest {
+impl ListProviderPropertiesRequest {
+    #[inline]
+    pub fn builder() -> ListProviderPropertiesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListProviderPropertiesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("provider", 4, 4)];
+}
+const _: () = assert!(<ListProviderPropertiesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ListProviderPropertiesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListProviderPropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListProviderPropertiesRequest {
                 req_type: req_type,
                 length: length,
@@ -5411,135 +10022,226 @@ impl AsByteSequence for ListProviderPropertiesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.provider.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListProviderPropertiesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ListProviderPropertiesRequest {
-    const OPCODE: u8 = 36;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 36;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListProviderProperties";
     type Reply = ListProviderPropertiesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ListProviderPropertiesRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListProviderPropertiesRequestBuilder {
+    pub inner: ListProviderPropertiesRequest,
+}
+impl ListProviderPropertiesRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListProviderPropertiesRequest {
+        self.inner
+    }
+}
+impl Default for ListProviderPropertiesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListProviderPropertiesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ListProviderPropertiesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub atoms: Vec<Atom>,
 }
-impl ListProviderPropertiesReply {}
-impl AsByteSequence for ListProviderPropertiesReply {
+impl ListProviderPropertiesReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.atoms.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 22;
-        let block_len: usize = vector_as_bytes(&self.atoms, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ListProviderPropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 22;
-        let (atoms, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        Some((
-            ListProviderPropertiesReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                atoms: atoms,
-            },
-            index,
-        ))
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("atoms_len", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card16>()
-            + 22
-            + {
-                let block_len: usize = self.atoms.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.atoms == other.atoms
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("atoms", &self.atoms, &other.atoms, &mut changes);
+        changes
     }
 }
-#[derive(Clone, Debug, Default)]
+impl_atoms_only_reply_asb! { ListProviderPropertiesReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryProviderPropertyRequest {
     pub req_type: u8,
     pub length: u16,
     pub provider: Provider,
     pub property: Atom,
 }
-impl QueryProviderPropertyRequest {}
-impl AsByteSequence for QueryProviderPropertyRequest {
+impl QueryProviderPropertyRequest {
+    #[inline]
+    pub fn builder() -> QueryProviderPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryProviderPropertyReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("provider", 4, 4)];
+}
+const _: () = assert!(<QueryProviderPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_provider_property_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.provider.as_bytes(&mut bytes[index..]);
+                index += self.property.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
+                index += sz;
+                let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        provider: provider,
+                        property: property,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8 + self.property.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_provider_property_request_asb! { QueryProviderPropertyRequest }
+impl Request for QueryProviderPropertyRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 37;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryProviderProperty";
+    type Reply = QueryProviderPropertyReply;
+}
+#[doc = " A builder for `QueryProviderPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryProviderPropertyRequestBuilder {
+    pub inner: QueryProviderPropertyRequest,
+}
+impl QueryProviderPropertyRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.provider.as_bytes(&mut bytes[index..]);
-        index += self.property.as_bytes(&mut bytes[index..]);
-        index
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryProviderPropertyRequest {
-                req_type: req_type,
-                length: length,
-                provider: provider,
-                property: property,
-            },
-            index,
-        ))
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.provider.size() + self.property.size()
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryProviderPropertyRequest {
+        self.inner
     }
 }
-impl Request for QueryProviderPropertyRequest {
-    const OPCODE: u8 = 37;
-    const EXTENSION: Option<&'static str> = Some("RANDR");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = QueryProviderPropertyReply;
+impl Default for QueryProviderPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryProviderPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                property: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryProviderPropertyReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -5549,77 +10251,100 @@ pub struct QueryProviderPropertyReply {
     pub immutable: bool,
     pub valid_values: Vec<Int32>,
 }
-impl QueryProviderPropertyReply {}
-impl AsByteSequence for QueryProviderPropertyReply {
+impl QueryProviderPropertyReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.pending.as_bytes(&mut bytes[index..]);
-        index += self.range.as_bytes(&mut bytes[index..]);
-        index += self.immutable.as_bytes(&mut bytes[index..]);
-        index += 21;
-        let block_len: usize = vector_as_bytes(&self.valid_values, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub fn valid_values_slice(bytes: &[u8]) -> Result<Cow<'_, [Int32]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing QueryProviderPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = parse_item(bytes, index, "range")?;
         index += sz;
-        let (immutable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (immutable, sz): (bool, usize) = parse_item(bytes, index, "immutable")?;
         index += sz;
         index += 21;
-        let (valid_values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-        Some((
-            QueryProviderPropertyReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                pending: pending,
-                range: range,
-                immutable: immutable,
-                valid_values: valid_values,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.pending.size()
-            + self.range.size()
-            + self.immutable.size()
-            + 21
-            + {
-                let block_len: usize = self.valid_values.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let (valid_values, _): (Cow<'_, [Int32]>, usize) =
+            parse_slice(bytes, index, (length as usize) as usize, "valid_values")?;
+        Ok(valid_values)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("pending", 8, 1),
+        ("range", 9, 1),
+        ("immutable", 10, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.pending == other.pending
+            && self.range == other.range
+            && self.immutable == other.immutable
+            && self.valid_values == other.valid_values
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("pending", &self.pending, &other.pending, &mut changes);
+        diff_value("range", &self.range, &other.range, &mut changes);
+        diff_value("immutable", &self.immutable, &other.immutable, &mut changes);
+        diff_list(
+            "valid_values",
+            &self.valid_values,
+            &other.valid_values,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_reply_asb_ac5d3680! { QueryProviderPropertyReply }
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryProviderPropertyReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let pending: bool = u.arbitrary()?;
+        let range: bool = u.arbitrary()?;
+        let immutable: bool = u.arbitrary()?;
+        let valid_values = arbitrary_list(u, (length as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            pending: pending,
+            range: range,
+            immutable: immutable,
+            valid_values: valid_values,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ConfigureProviderPropertyRequest {
     pub req_type: u8,
     pub length: u16,
@@ -5629,47 +10354,93 @@ pub struct ConfigureProviderPropertyRequest {
     pub range: bool,
     pub values: Vec<Int32>,
 }
-impl ConfigureProviderPropertyRequest {}
-impl AsByteSequence for ConfigureProviderPropertyRequest {
+impl ConfigureProviderPropertyRequest {
+    pub fn values_slice(bytes: &[u8]) -> Result<Cow<'_, [Int32]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
+        index += sz;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
+        index += sz;
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
+        index += sz;
+        let (range, sz): (bool, usize) = parse_item(bytes, index, "range")?;
+        index += sz;
+        index += 2;
+        let (values, _): (Cow<'_, [Int32]>, usize) = parse_slice(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "values",
+                offset: index,
+            })? as usize,
+            "values",
+        )?;
+        Ok(values)
+    }
     #[inline]
+    pub fn builder() -> ConfigureProviderPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("provider", 4, 4)];
+}
+const _: () = assert!(<ConfigureProviderPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ConfigureProviderPropertyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
         index += self.property.as_bytes(&mut bytes[index..]);
         index += self.pending.as_bytes(&mut bytes[index..]);
         index += self.range.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.values, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Int32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = parse_item(bytes, index, "range")?;
         index += sz;
         index += 2;
-        let (values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (values, block_len): (Vec<Int32>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "values",
+                offset: index,
+            })? as usize,
+            "values",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ConfigureProviderPropertyRequest {
                 req_type: req_type,
                 length: length,
@@ -5683,29 +10454,109 @@ impl AsByteSequence for ConfigureProviderPropertyRequest {
         ))
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.provider.size()
-            + self.property.size()
-            + self.pending.size()
-            + self.range.size()
-            + 2
-            + {
-                let block_len: usize = self.values.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
-                block_len + pad
-            }
+    fn size(&self) -> usize {
+        let size: usize = 10 + self.property.size() + self.pending.size() + self.range.size() + {
+            let block_len: usize = self.values.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 10 + self.property.size() + self.pending.size() + self.range.size() + {
+            let block_len: usize = self.values.len() * ::core::mem::size_of::<Int32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Int32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ConfigureProviderPropertyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Request for ConfigureProviderPropertyRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 38;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ConfigureProviderProperty";
+    type Reply = ();
+}
+#[doc = " A builder for `ConfigureProviderPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct ConfigureProviderPropertyRequestBuilder {
+    pub inner: ConfigureProviderPropertyRequest,
+}
+impl ConfigureProviderPropertyRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    pub fn pending(mut self, pending: bool) -> Self {
+        self.inner.pending = pending;
+        self
+    }
+    #[inline]
+    pub fn range(mut self, range: bool) -> Self {
+        self.inner.range = range;
+        self
+    }
+    #[inline]
+    pub fn values(mut self, values: Vec<Int32>) -> Self {
+        self.inner.values = values;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ConfigureProviderPropertyRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Int32> for ConfigureProviderPropertyRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Int32>>(&mut self, iter: I) {
+        self.inner.values.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Int32> for ConfigureProviderPropertyRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Int32>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
     }
 }
-impl Request for ConfigureProviderPropertyRequest {
-    const OPCODE: u8 = 38;
-    const EXTENSION: Option<&'static str> = Some("RANDR");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
+impl Default for ConfigureProviderPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ConfigureProviderPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                property: Default::default(),
+                pending: Default::default(),
+                range: Default::default(),
+                values: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangeProviderPropertyRequest {
     pub req_type: u8,
     pub length: u16,
@@ -5717,55 +10568,100 @@ pub struct ChangeProviderPropertyRequest {
     pub num_items: Card32,
     pub data: Vec<Void>,
 }
-impl ChangeProviderPropertyRequest {}
-impl AsByteSequence for ChangeProviderPropertyRequest {
+impl ChangeProviderPropertyRequest {
+    #[inline]
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            provider: self.provider.clone(),
+            property: self.property.clone(),
+            ty: self.ty.clone(),
+            format: self.format.clone(),
+            mode: self.mode.clone(),
+            num_items: self.num_items.clone(),
+            data: self.data.clone(),
+        }
+    }
     #[inline]
+    pub fn builder() -> ChangeProviderPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("provider", 4, 4)];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    #[inline]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize =
+                ((self.num_items as usize) * ((self.format as usize) / (8))) as usize;
+            if self.data.len() != expected {
+                return Err(ValidationError {
+                    list: "data",
+                    expected,
+                    found: self.data.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<ChangeProviderPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ChangeProviderPropertyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
         index += self.property.as_bytes(&mut bytes[index..]);
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
         index += self.mode.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.num_items.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Void>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        let (mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Card8, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
         index += 2;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = parse_item(bytes, index, "num_items")?;
         index += sz;
-        let (data, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (data, block_len): (Vec<Void>, usize) = parse_vector(
+            bytes,
+            index,
             ((num_items as usize) * ((format as usize) / (8))) as usize,
+            "data",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ChangeProviderPropertyRequest {
                 req_type: req_type,
                 length: length,
@@ -5782,83 +10678,220 @@ impl AsByteSequence for ChangeProviderPropertyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.provider.size()
-            + self.property.size()
-            + self.ty.size()
-            + self.format.size()
-            + self.mode.size()
-            + 2
-            + self.num_items.size()
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
-                block_len + pad
-            }
+        let size: usize = 16 + self.property.size() + self.ty.size() + {
+            let block_len: usize = self.data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ChangeProviderPropertyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ChangeProviderPropertyRequest {
-    const OPCODE: u8 = 39;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 39;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeProviderProperty";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+impl core::fmt::Debug for ChangeProviderPropertyRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChangeProviderPropertyRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("provider", &self.provider)
+            .field("property", &self.property)
+            .field("ty", &self.ty)
+            .field("format", &self.format)
+            .field("mode", &self.mode)
+            .field("num_items", &self.num_items)
+            .field("data", &debug_bytes(&self.data, 64))
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for ChangeProviderPropertyRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let provider: Provider = u.arbitrary()?;
+        let property: Atom = u.arbitrary()?;
+        let ty: Atom = u.arbitrary()?;
+        let format: Card8 = u.arbitrary()?;
+        let mode: Card8 = u.arbitrary()?;
+        let num_items: Card32 = u.arbitrary()?;
+        let data = arbitrary_list(
+            u,
+            ((num_items as usize) * ((format as usize) / (8))) as usize,
+        )?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            provider: provider,
+            property: property,
+            ty: ty,
+            format: format,
+            mode: mode,
+            num_items: num_items,
+            data: data,
+        })
+    }
+}
+#[doc = " A builder for `ChangeProviderPropertyRequest`."]
+#[derive(Debug)]
+pub struct ChangeProviderPropertyRequestBuilder {
+    pub inner: ChangeProviderPropertyRequest,
+}
+impl ChangeProviderPropertyRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    pub fn ty(mut self, ty: Atom) -> Self {
+        self.inner.ty = ty;
+        self
+    }
+    #[inline]
+    pub fn mode(mut self, mode: Card8) -> Self {
+        self.inner.mode = mode;
+        self
+    }
+    #[inline]
+    pub fn num_items(mut self, num_items: Card32) -> Self {
+        self.inner.num_items = num_items;
+        self
+    }
+    #[inline]
+    pub fn data(mut self, data: Vec<Void>) -> Self {
+        self.inner.data = data;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangeProviderPropertyRequest {
+        self.inner
+    }
+    #[doc = " Set `format`, which must be 8, 16 or 32. Other values are refused, rather than sent to the server."]
+    #[inline]
+    pub fn format(mut self, format: Card8) -> Result<Self, BuildError> {
+        match format {
+            8 | 16 | 32 => {
+                self.inner.format = format;
+                Ok(self)
+            }
+            _ => Err(BuildError {
+                field: "format",
+                value: format.into(),
+            }),
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl Default for ChangeProviderPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangeProviderPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                property: Default::default(),
+                ty: Default::default(),
+                format: Default::default(),
+                mode: Default::default(),
+                num_items: Default::default(),
+                data: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DeleteProviderPropertyRequest {
     pub req_type: u8,
     pub length: u16,
     pub provider: Provider,
     pub property: Atom,
 }
-impl DeleteProviderPropertyRequest {}
-impl AsByteSequence for DeleteProviderPropertyRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.provider.as_bytes(&mut bytes[index..]);
-        index += self.property.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DeleteProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DeleteProviderPropertyRequest {
-                req_type: req_type,
-                length: length,
-                provider: provider,
-                property: property,
-            },
-            index,
-        ))
-    }
+impl DeleteProviderPropertyRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.provider.size() + self.property.size()
+    pub fn builder() -> DeleteProviderPropertyRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("provider", 4, 4)];
 }
+const _: () = assert!(<DeleteProviderPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl_provider_property_request_asb! { DeleteProviderPropertyRequest }
 impl Request for DeleteProviderPropertyRequest {
-    const OPCODE: u8 = 40;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 40;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteProviderProperty";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DeleteProviderPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct DeleteProviderPropertyRequestBuilder {
+    pub inner: DeleteProviderPropertyRequest,
+}
+impl DeleteProviderPropertyRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DeleteProviderPropertyRequest {
+        self.inner
+    }
+}
+impl Default for DeleteProviderPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DeleteProviderPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                property: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetProviderPropertyRequest {
     pub req_type: u8,
     pub length: u16,
@@ -5870,13 +10903,29 @@ pub struct GetProviderPropertyRequest {
     pub delete: bool,
     pub pending: bool,
 }
-impl GetProviderPropertyRequest {}
-impl AsByteSequence for GetProviderPropertyRequest {
+impl GetProviderPropertyRequest {
+    #[inline]
+    pub fn builder() -> GetProviderPropertyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetProviderPropertyReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("provider", 4, 4)];
+}
+const _: () = assert!(<GetProviderPropertyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetProviderPropertyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
         index += self.property.as_bytes(&mut bytes[index..]);
@@ -5885,34 +10934,35 @@ impl AsByteSequence for GetProviderPropertyRequest {
         index += self.long_length.as_bytes(&mut bytes[index..]);
         index += self.delete.as_bytes(&mut bytes[index..]);
         index += self.pending.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_offset, sz): (Card32, usize) = parse_item(bytes, index, "long_offset")?;
         index += sz;
-        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_length, sz): (Card32, usize) = parse_item(bytes, index, "long_length")?;
         index += sz;
-        let (delete, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (delete, sz): (bool, usize) = parse_item(bytes, index, "delete")?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = parse_item(bytes, index, "pending")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetProviderPropertyRequest {
                 req_type: req_type,
                 length: length,
@@ -5929,26 +10979,93 @@ impl AsByteSequence for GetProviderPropertyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.provider.size()
-            + self.property.size()
-            + self.ty.size()
-            + self.long_offset.size()
-            + self.long_length.size()
-            + self.delete.size()
-            + self.pending.size()
-            + 2
+        let size: usize =
+            18 + self.property.size() + self.ty.size() + self.delete.size() + self.pending.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetProviderPropertyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetProviderPropertyRequest {
-    const OPCODE: u8 = 41;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 41;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetProviderProperty";
     type Reply = GetProviderPropertyReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetProviderPropertyRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetProviderPropertyRequestBuilder {
+    pub inner: GetProviderPropertyRequest,
+}
+impl GetProviderPropertyRequestBuilder {
+    #[inline]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.inner.provider = provider;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    pub fn ty(mut self, ty: Atom) -> Self {
+        self.inner.ty = ty;
+        self
+    }
+    #[inline]
+    pub fn long_offset(mut self, long_offset: Card32) -> Self {
+        self.inner.long_offset = long_offset;
+        self
+    }
+    #[inline]
+    pub fn long_length(mut self, long_length: Card32) -> Self {
+        self.inner.long_length = long_length;
+        self
+    }
+    #[inline]
+    pub fn delete(mut self, delete: bool) -> Self {
+        self.inner.delete = delete;
+        self
+    }
+    #[inline]
+    pub fn pending(mut self, pending: bool) -> Self {
+        self.inner.pending = pending;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetProviderPropertyRequest {
+        self.inner
+    }
+}
+impl Default for GetProviderPropertyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetProviderPropertyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                provider: Default::default(),
+                property: Default::default(),
+                ty: Default::default(),
+                long_offset: Default::default(),
+                long_length: Default::default(),
+                delete: Default::default(),
+                pending: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetProviderPropertyReply {
     pub reply_type: u8,
     pub format: Card8,
@@ -5957,84 +11074,100 @@ pub struct GetProviderPropertyReply {
     pub ty: Atom,
     pub bytes_after: Card32,
     pub num_items: Card32,
-    pub data: Vec<Void>,
-}
-impl GetProviderPropertyReply {}
-impl AsByteSequence for GetProviderPropertyReply {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += self.format.as_bytes(&mut bytes[index..]);
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.ty.as_bytes(&mut bytes[index..]);
-        index += self.bytes_after.as_bytes(&mut bytes[index..]);
-        index += self.num_items.as_bytes(&mut bytes[index..]);
-        index += 12;
-        let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetProviderPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 12;
-        let (data, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
-            ((num_items as usize) * ((format as usize) / (8))) as usize,
-        )?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
-        Some((
-            GetProviderPropertyReply {
-                reply_type: reply_type,
-                format: format,
-                sequence: sequence,
-                length: length,
-                ty: ty,
-                bytes_after: bytes_after,
-                num_items: num_items,
-                data: data,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.format.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.ty.size()
-            + self.bytes_after.size()
-            + self.num_items.size()
-            + 12
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
-                block_len + pad
-            }
+    pub data: PropertyData,
+}
+impl GetProviderPropertyReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("format", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Create the request that reads the next part of the value, given the"]
+    #[doc = " `GetProviderPropertyRequest` that this is a reply to. Returns `None` once"]
+    #[doc = " there is nothing left to read."]
+    #[inline]
+    pub fn continue_request(
+        &self,
+        request: &GetProviderPropertyRequest,
+    ) -> Option<GetProviderPropertyRequest> {
+        if self.bytes_after == 0 {
+            return None;
+        }
+        let read = (self.num_items as u32) * (self.format as u32 / 8);
+        Some(GetProviderPropertyRequest {
+            long_offset: request.long_offset + (read / 4),
+            ..request.clone()
+        })
+    }
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.format == other.format
+            && self.ty == other.ty
+            && self.bytes_after == other.bytes_after
+            && self.num_items == other.num_items
+            && self.data == other.data
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("format", &self.format, &other.format, &mut changes);
+        diff_value("ty", &self.ty, &other.ty, &mut changes);
+        diff_value(
+            "bytes_after",
+            &self.bytes_after,
+            &other.bytes_after,
+            &mut changes,
+        );
+        diff_value("num_items", &self.num_items, &other.num_items, &mut changes);
+        diff_property_data("data", &self.data, &other.data, &mut changes);
+        changes
+    }
+}
+impl_reply_asb_3f6925dc! { GetProviderPropertyReply }
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetProviderPropertyReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let data: PropertyData = u.arbitrary()?;
+        let format = data.format();
+        let num_items = data.len() as Card32;
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let ty: Atom = u.arbitrary()?;
+        let bytes_after: Card32 = u.arbitrary()?;
+        Ok(Self {
+            reply_type: reply_type,
+            format: format,
+            sequence: sequence,
+            length: length,
+            ty: ty,
+            bytes_after: bytes_after,
+            num_items: num_items,
+            data: data,
+        })
     }
 }
 pub type Notify = Card8;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CrtcChange {
     pub timestamp: Timestamp,
     pub window: Window,
@@ -6046,9 +11179,11 @@ pub struct CrtcChange {
     pub width: Card16,
     pub height: Card16,
 }
-impl CrtcChange {}
+impl CrtcChange {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for CrtcChange {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.timestamp.as_bytes(&mut bytes[index..]);
@@ -6056,37 +11191,36 @@ impl AsByteSequence for CrtcChange {
         index += self.crtc.as_bytes(&mut bytes[index..]);
         index += self.mode.as_bytes(&mut bytes[index..]);
         index += self.rotation.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.x.as_bytes(&mut bytes[index..]);
         index += self.y.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CrtcChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
         index += sz;
         index += 2;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        Some((
+        Ok((
             CrtcChange {
                 timestamp: timestamp,
                 window: window,
@@ -6103,19 +11237,19 @@ impl AsByteSequence for CrtcChange {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.timestamp.size()
-            + self.window.size()
-            + self.crtc.size()
-            + self.mode.size()
-            + self.rotation.size()
-            + 2
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
+        20 + self.timestamp.size() + self.window.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for CrtcChange {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct OutputChange {
     pub timestamp: Timestamp,
     pub config_timestamp: Timestamp,
@@ -6127,9 +11261,11 @@ pub struct OutputChange {
     pub connection: Connection,
     pub subpixel_order: SubPixel,
 }
-impl OutputChange {}
+impl OutputChange {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for OutputChange {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.timestamp.as_bytes(&mut bytes[index..]);
@@ -6143,29 +11279,29 @@ impl AsByteSequence for OutputChange {
         index += self.subpixel_order.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OutputChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = parse_item(bytes, index, "crtc")?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
         index += sz;
-        let (connection, sz): (Connection, usize) = <Connection>::from_bytes(&bytes[index..])?;
+        let (connection, sz): (Connection, usize) = parse_item(bytes, index, "connection")?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = parse_item(bytes, index, "subpixel_order")?;
         index += sz;
-        Some((
+        Ok((
             OutputChange {
                 timestamp: timestamp,
                 config_timestamp: config_timestamp,
@@ -6182,18 +11318,22 @@ impl AsByteSequence for OutputChange {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.timestamp.size()
+        15 + self.timestamp.size()
             + self.config_timestamp.size()
             + self.window.size()
-            + self.output.size()
-            + self.crtc.size()
-            + self.mode.size()
-            + self.rotation.size()
-            + self.connection.size()
             + self.subpixel_order.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for OutputChange {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct OutputProperty {
     pub window: Window,
     pub output: Output,
@@ -6201,9 +11341,11 @@ pub struct OutputProperty {
     pub timestamp: Timestamp,
     pub status: Property,
 }
-impl OutputProperty {}
+impl OutputProperty {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for OutputProperty {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.window.as_bytes(&mut bytes[index..]);
@@ -6211,25 +11353,24 @@ impl AsByteSequence for OutputProperty {
         index += self.atom.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
         index += self.status.as_bytes(&mut bytes[index..]);
-        index += 11;
+        index += zero_pad(&mut bytes[index..], 11);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OutputProperty from byte buffer");
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = parse_item(bytes, index, "output")?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = parse_item(bytes, index, "atom")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (status, sz): (Property, usize) = <Property>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Property, usize) = parse_item(bytes, index, "status")?;
         index += sz;
         index += 11;
-        Some((
+        Ok((
             OutputProperty {
                 window: window,
                 output: output,
@@ -6242,21 +11383,28 @@ impl AsByteSequence for OutputProperty {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.window.size()
-            + self.output.size()
-            + self.atom.size()
-            + self.timestamp.size()
-            + self.status.size()
-            + 11
+        15 + self.window.size() + self.atom.size() + self.timestamp.size() + self.status.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for OutputProperty {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ProviderChange {
     pub timestamp: Timestamp,
     pub window: Window,
     pub provider: Provider,
 }
-impl ProviderChange {}
+impl ProviderChange {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for ProviderChange {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -6264,21 +11412,20 @@ impl AsByteSequence for ProviderChange {
         index += self.timestamp.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.provider.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ProviderChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
         index += 16;
-        Some((
+        Ok((
             ProviderChange {
                 timestamp: timestamp,
                 window: window,
@@ -6289,10 +11436,19 @@ impl AsByteSequence for ProviderChange {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.timestamp.size() + self.window.size() + self.provider.size() + 16
+        20 + self.timestamp.size() + self.window.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ProviderChange {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ProviderProperty {
     pub window: Window,
     pub provider: Provider,
@@ -6300,9 +11456,11 @@ pub struct ProviderProperty {
     pub timestamp: Timestamp,
     pub state: Card8,
 }
-impl ProviderProperty {}
+impl ProviderProperty {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for ProviderProperty {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.window.as_bytes(&mut bytes[index..]);
@@ -6310,25 +11468,24 @@ impl AsByteSequence for ProviderProperty {
         index += self.atom.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
         index += self.state.as_bytes(&mut bytes[index..]);
-        index += 11;
+        index += zero_pad(&mut bytes[index..], 11);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ProviderProperty from byte buffer");
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = parse_item(bytes, index, "provider")?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = parse_item(bytes, index, "atom")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (state, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card8, usize) = parse_item(bytes, index, "state")?;
         index += sz;
         index += 11;
-        Some((
+        Ok((
             ProviderProperty {
                 window: window,
                 provider: provider,
@@ -6341,39 +11498,46 @@ impl AsByteSequence for ProviderProperty {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.window.size()
-            + self.provider.size()
-            + self.atom.size()
-            + self.timestamp.size()
-            + self.state.size()
-            + 11
+        16 + self.window.size() + self.atom.size() + self.timestamp.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ProviderProperty {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ResourceChange {
     pub timestamp: Timestamp,
     pub window: Window,
 }
-impl ResourceChange {}
+impl ResourceChange {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for ResourceChange {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.timestamp.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
         index += 20;
-        Some((
+        Ok((
             ResourceChange {
                 timestamp: timestamp,
                 window: window,
@@ -6383,10 +11547,19 @@ impl AsByteSequence for ResourceChange {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.timestamp.size() + self.window.size() + 20
+        20 + self.timestamp.size() + self.window.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ResourceChange {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct MonitorInfo {
     pub name: Atom,
     pub primary: bool,
@@ -6399,9 +11572,11 @@ pub struct MonitorInfo {
     pub height_in_millimeters: Card32,
     pub outputs: Vec<Output>,
 }
-impl MonitorInfo {}
+impl MonitorInfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for MonitorInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.name.as_bytes(&mut bytes[index..]);
@@ -6416,38 +11591,42 @@ impl AsByteSequence for MonitorInfo {
         index += self.height_in_millimeters.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing MonitorInfo from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (primary, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (primary, sz): (bool, usize) = parse_item(bytes, index, "primary")?;
         index += sz;
-        let (automatic, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (automatic, sz): (bool, usize) = parse_item(bytes, index, "automatic")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (width_in_millimeters, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (width_in_millimeters, sz): (Card32, usize) =
+            parse_item(bytes, index, "width_in_millimeters")?;
         index += sz;
-        let (height_in_millimeters, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (height_in_millimeters, sz): (Card32, usize) =
+            parse_item(bytes, index, "height_in_millimeters")?;
         index += sz;
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        Some((
+        Ok((
             MonitorInfo {
                 name: name,
                 primary: primary,
@@ -6464,57 +11643,86 @@ impl AsByteSequence for MonitorInfo {
         ))
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.name.size()
-            + self.primary.size()
-            + self.automatic.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
-            + self.width_in_millimeters.size()
-            + self.height_in_millimeters.size()
-            + {
-                let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
-                block_len + pad
-            }
+    fn size(&self) -> usize {
+        18 + self.name.size() + self.primary.size() + self.automatic.size() + {
+            let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
+            block_len + pad
+        }
+    }
+}
+impl core::convert::TryFrom<&[u8]> for MonitorInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl PartialEq for MonitorInfo {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+impl Eq for MonitorInfo {}
+impl core::hash::Hash for MonitorInfo {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.name, state);
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetMonitorsRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub get_active: bool,
 }
-impl GetMonitorsRequest {}
-impl AsByteSequence for GetMonitorsRequest {
+impl GetMonitorsRequest {
     #[inline]
+    pub fn builder() -> GetMonitorsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetMonitorsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetMonitorsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetMonitorsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.get_active.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMonitorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (get_active, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (get_active, sz): (bool, usize) = parse_item(bytes, index, "get_active")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetMonitorsRequest {
                 req_type: req_type,
                 length: length,
@@ -6526,16 +11734,63 @@ impl AsByteSequence for GetMonitorsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.get_active.size()
+        let size: usize = 4 + self.window.size() + self.get_active.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetMonitorsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetMonitorsRequest {
-    const OPCODE: u8 = 42;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 42;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMonitors";
     type Reply = GetMonitorsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetMonitorsRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetMonitorsRequestBuilder {
+    pub inner: GetMonitorsRequest,
+}
+impl GetMonitorsRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn get_active(mut self, get_active: bool) -> Self {
+        self.inner.get_active = get_active;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetMonitorsRequest {
+        self.inner
+    }
+}
+impl Default for GetMonitorsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetMonitorsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                get_active: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetMonitorsReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -6544,47 +11799,102 @@ pub struct GetMonitorsReply {
     pub n_outputs: Card32,
     pub monitors: Vec<MonitorInfo>,
 }
-impl GetMonitorsReply {}
-impl AsByteSequence for GetMonitorsReply {
+impl GetMonitorsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn monitors_iter(bytes: &[u8]) -> Result<ListIter<'_, MonitorInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (n_outputs, sz): (Card32, usize) = parse_item(bytes, index, "n_outputs")?;
+        index += sz;
+        index += 12;
+        Ok(ListIter::new(bytes, index, len0 as usize, "monitors"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp
+            && self.n_outputs == other.n_outputs
+            && self.monitors == other.monitors
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("timestamp", &self.timestamp, &other.timestamp, &mut changes);
+        diff_value("n_outputs", &self.n_outputs, &other.n_outputs, &mut changes);
+        diff_list("monitors", &self.monitors, &other.monitors, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetMonitorsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
         index += (self.monitors.len() as Card32).as_bytes(&mut bytes[index..]);
         index += self.n_outputs.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         let block_len: usize = vector_as_bytes(&self.monitors, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<MonitorInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<MonitorInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMonitorsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (n_outputs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (n_outputs, sz): (Card32, usize) = parse_item(bytes, index, "n_outputs")?;
         index += sz;
         index += 12;
         let (monitors, block_len): (Vec<MonitorInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "monitors")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<MonitorInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetMonitorsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -6598,54 +11908,65 @@ impl AsByteSequence for GetMonitorsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.timestamp.size()
-            + ::core::mem::size_of::<Card32>()
-            + self.n_outputs.size()
-            + 12
-            + {
-                let block_len: usize = self.monitors.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<MonitorInfo>());
-                block_len + pad
-            }
+        let size: usize = 28 + self.timestamp.size() + {
+            let block_len: usize = self.monitors.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<MonitorInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetMonitorsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetMonitorRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub monitorinfo: MonitorInfo,
 }
-impl SetMonitorRequest {}
-impl AsByteSequence for SetMonitorRequest {
+impl SetMonitorRequest {
     #[inline]
+    pub fn builder() -> SetMonitorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetMonitorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetMonitorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.monitorinfo.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetMonitorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (monitorinfo, sz): (MonitorInfo, usize) = <MonitorInfo>::from_bytes(&bytes[index..])?;
+        let (monitorinfo, sz): (MonitorInfo, usize) = parse_item(bytes, index, "monitorinfo")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetMonitorRequest {
                 req_type: req_type,
                 length: length,
@@ -6657,48 +11978,104 @@ impl AsByteSequence for SetMonitorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.monitorinfo.size()
+        let size: usize = 4 + self.window.size() + self.monitorinfo.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetMonitorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetMonitorRequest {
-    const OPCODE: u8 = 43;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 43;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetMonitor";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetMonitorRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetMonitorRequestBuilder {
+    pub inner: SetMonitorRequest,
+}
+impl SetMonitorRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn monitorinfo(mut self, monitorinfo: MonitorInfo) -> Self {
+        self.inner.monitorinfo = monitorinfo;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetMonitorRequest {
+        self.inner
+    }
+}
+impl Default for SetMonitorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetMonitorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                monitorinfo: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DeleteMonitorRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub name: Atom,
 }
-impl DeleteMonitorRequest {}
-impl AsByteSequence for DeleteMonitorRequest {
+impl DeleteMonitorRequest {
     #[inline]
+    pub fn builder() -> DeleteMonitorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<DeleteMonitorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DeleteMonitorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.name.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteMonitorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DeleteMonitorRequest {
                 req_type: req_type,
                 length: length,
@@ -6710,16 +12087,64 @@ impl AsByteSequence for DeleteMonitorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.name.size()
+        let size: usize = 4 + self.window.size() + self.name.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DeleteMonitorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DeleteMonitorRequest {
-    const OPCODE: u8 = 44;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 44;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteMonitor";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DeleteMonitorRequest`."]
+#[derive(Clone, Debug)]
+pub struct DeleteMonitorRequestBuilder {
+    pub inner: DeleteMonitorRequest,
+}
+impl DeleteMonitorRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn name(mut self, name: Atom) -> Self {
+        self.inner.name = name;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DeleteMonitorRequest {
+        self.inner
+    }
+}
+impl Default for DeleteMonitorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DeleteMonitorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                name: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateLeaseRequest {
     pub req_type: u8,
     pub length: u16,
@@ -6728,13 +12153,28 @@ pub struct CreateLeaseRequest {
     pub crtcs: Vec<Crtc>,
     pub outputs: Vec<Output>,
 }
-impl CreateLeaseRequest {}
-impl AsByteSequence for CreateLeaseRequest {
+impl CreateLeaseRequest {
+    #[inline]
+    pub fn builder() -> CreateLeaseRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateLeaseReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateLeaseRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateLeaseRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.lid.as_bytes(&mut bytes[index..]);
@@ -6742,38 +12182,45 @@ impl AsByteSequence for CreateLeaseRequest {
         index += (self.outputs.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.crtcs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Crtc>()),
+        );
         let block_len: usize = vector_as_bytes(&self.outputs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Output>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateLeaseRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (lid, sz): (Lease, usize) = <Lease>::from_bytes(&bytes[index..])?;
+        let (lid, sz): (Lease, usize) = parse_item(bytes, index, "lid")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "crtcs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "outputs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateLeaseRequest {
                 req_type: req_type,
                 length: length,
@@ -6785,15 +12232,9 @@ impl AsByteSequence for CreateLeaseRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
+        let size: usize = 12
             + self.window.size()
-            + self.lid.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
             + {
                 let block_len: usize = self.crtcs.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
@@ -6803,101 +12244,228 @@ impl AsByteSequence for CreateLeaseRequest {
                 let block_len: usize = self.outputs.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Output>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateLeaseRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateLeaseRequest {
-    const OPCODE: u8 = 45;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 45;
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "CreateLease";
     type Reply = CreateLeaseReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateLeaseRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateLeaseRequestBuilder {
+    pub inner: CreateLeaseRequest,
+}
+impl CreateLeaseRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn lid(mut self, lid: Lease) -> Self {
+        self.inner.lid = lid;
+        self
+    }
+    #[inline]
+    pub fn crtcs(mut self, crtcs: Vec<Crtc>) -> Self {
+        self.inner.crtcs = crtcs;
+        self
+    }
+    #[inline]
+    pub fn outputs(mut self, outputs: Vec<Output>) -> Self {
+        self.inner.outputs = outputs;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateLeaseRequest {
+        self.inner
+    }
+}
+impl Default for CreateLeaseRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateLeaseRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                lid: Default::default(),
+                crtcs: Default::default(),
+                outputs: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateLeaseReply {
     pub reply_type: u8,
     pub nfd: Card8,
     pub sequence: u16,
     pub length: u32,
-    pub master_fd: Vec<Fd>,
+    pub master_fd: Fd,
 }
-impl CreateLeaseReply {}
-impl AsByteSequence for CreateLeaseReply {
+impl CreateLeaseReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("nfd", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.nfd == other.nfd && self.master_fd == other.master_fd
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("nfd", &self.nfd, &other.nfd, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for CreateLeaseReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
         index += self.nfd.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
-        index += 24;
+        index += zero_pad(&mut bytes[index..], 24);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateLeaseReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         index += 24;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateLeaseReply {
                 reply_type: reply_type,
                 nfd: nfd,
                 sequence: sequence,
                 length: length,
-                master_fd: vec![],
+                master_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + self.nfd.size() + self.sequence.size() + self.length.size() + 24
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.master_fd)
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.master_fd);
+    }
+    #[inline]
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.master_fd = *fds.get(0)?;
+        Some(1)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for CreateLeaseReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FreeLeaseRequest {
     pub req_type: u8,
     pub length: u16,
     pub lid: Lease,
     pub terminate: Byte,
 }
-impl FreeLeaseRequest {}
-impl AsByteSequence for FreeLeaseRequest {
+impl FreeLeaseRequest {
     #[inline]
+    pub fn builder() -> FreeLeaseRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("lid", 4, 4),
+        ("terminate", 8, 1),
+    ];
+}
+const _: () = assert!(<FreeLeaseRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FreeLeaseRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.lid.as_bytes(&mut bytes[index..]);
         index += self.terminate.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeLeaseRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (lid, sz): (Lease, usize) = <Lease>::from_bytes(&bytes[index..])?;
+        let (lid, sz): (Lease, usize) = parse_item(bytes, index, "lid")?;
         index += sz;
-        let (terminate, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (terminate, sz): (Byte, usize) = parse_item(bytes, index, "terminate")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FreeLeaseRequest {
                 req_type: req_type,
                 length: length,
@@ -6909,23 +12477,73 @@ impl AsByteSequence for FreeLeaseRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.lid.size() + self.terminate.size()
+        let size: usize = 9;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FreeLeaseRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FreeLeaseRequest {
-    const OPCODE: u8 = 46;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
+    const MINOR_OPCODE: u16 = 46;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeLease";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FreeLeaseRequest`."]
+#[derive(Clone, Debug)]
+pub struct FreeLeaseRequestBuilder {
+    pub inner: FreeLeaseRequest,
+}
+impl FreeLeaseRequestBuilder {
+    #[inline]
+    pub fn lid(mut self, lid: Lease) -> Self {
+        self.inner.lid = lid;
+        self
+    }
+    #[inline]
+    pub fn terminate(mut self, terminate: Byte) -> Self {
+        self.inner.terminate = terminate;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FreeLeaseRequest {
+        self.inner
+    }
+}
+impl Default for FreeLeaseRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FreeLeaseRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                lid: Default::default(),
+                terminate: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct LeaseNotify {
     pub timestamp: Timestamp,
     pub window: Window,
     pub lease: Lease,
     pub created: Card8,
 }
-impl LeaseNotify {}
+impl LeaseNotify {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for LeaseNotify {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -6934,23 +12552,22 @@ impl AsByteSequence for LeaseNotify {
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.lease.as_bytes(&mut bytes[index..]);
         index += self.created.as_bytes(&mut bytes[index..]);
-        index += 15;
+        index += zero_pad(&mut bytes[index..], 15);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing LeaseNotify from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (lease, sz): (Lease, usize) = <Lease>::from_bytes(&bytes[index..])?;
+        let (lease, sz): (Lease, usize) = parse_item(bytes, index, "lease")?;
         index += sz;
-        let (created, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (created, sz): (Card8, usize) = parse_item(bytes, index, "created")?;
         index += sz;
         index += 15;
-        Some((
+        Ok((
             LeaseNotify {
                 timestamp: timestamp,
                 window: window,
@@ -6962,7 +12579,14 @@ impl AsByteSequence for LeaseNotify {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.timestamp.size() + self.window.size() + self.lease.size() + self.created.size() + 15
+        20 + self.timestamp.size() + self.window.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for LeaseNotify {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 pub const NOTIFY_CRTC_CHANGE: Notify = 0;
@@ -6973,7 +12597,9 @@ pub const NOTIFY_PROVIDER_PROPERTY: Notify = 4;
 pub const NOTIFY_RESOURCE_CHANGE: Notify = 5;
 pub const NOTIFY_LEASE: Notify = 6;
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Transform {
     pub inner: i32,
 }
@@ -7057,19 +12683,39 @@ impl Transform {
     pub const PROJECTIVE: Self = Self { inner: 8 };
     pub const COMPLETE: Self = Self { inner: 15 };
 }
+impl core::ops::Deref for Transform {
+    type Target = i32;
+    #[inline]
+    fn deref(&self) -> &i32 {
+        &self.inner
+    }
+}
+impl AsRef<i32> for Transform {
+    #[inline]
+    fn as_ref(&self) -> &i32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for Transform {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((Transform { inner: inner }, sz))
+        Ok((Transform { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Transform {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for Transform {
@@ -7106,14 +12752,31 @@ impl core::ops::BitXor for Transform {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct NotifyEvent {
     pub event_type: u8,
     pub sub_code: Notify,
     pub sequence: u16,
     pub u: NotifyData,
 }
-impl NotifyEvent {}
+impl NotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 1;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("sub_code", 1, 1), ("sequence", 2, 2)];
+}
 impl AsByteSequence for NotifyEvent {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -7124,19 +12787,18 @@ impl AsByteSequence for NotifyEvent {
         index += self.u.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (sub_code, sz): (Notify, usize) = <Notify>::from_bytes(&bytes[index..])?;
+        let (sub_code, sz): (Notify, usize) = parse_item(bytes, index, "sub_code")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (u, sz): (NotifyData, usize) = <NotifyData>::from_bytes(&bytes[index..])?;
+        let (u, sz): (NotifyData, usize) = parse_item(bytes, index, "u")?;
         index += sz;
-        Some((
+        Ok((
             NotifyEvent {
                 event_type: event_type,
                 sub_code: sub_code,
@@ -7148,13 +12810,23 @@ impl AsByteSequence for NotifyEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size() + self.sub_code.size() + self.sequence.size() + self.u.size()
+        4 + self.u.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for NotifyEvent {
     const OPCODE: u8 = 1;
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct ScreenChangeNotifyEvent {
     pub event_type: u8,
     pub rotation: Rotation,
@@ -7170,9 +12842,22 @@ pub struct ScreenChangeNotifyEvent {
     pub mwidth: Card16,
     pub mheight: Card16,
 }
-impl ScreenChangeNotifyEvent {}
-impl AsByteSequence for ScreenChangeNotifyEvent {
+impl ScreenChangeNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("rotation", 1, 2), ("sequence", 3, 2)];
+}
+impl AsByteSequence for ScreenChangeNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -7190,37 +12875,37 @@ impl AsByteSequence for ScreenChangeNotifyEvent {
         index += self.mheight.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ScreenChangeNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = parse_item(bytes, index, "rotation")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "config_timestamp")?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
         index += sz;
-        let (request_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (request_window, sz): (Window, usize) = parse_item(bytes, index, "request_window")?;
         index += sz;
-        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size_id, sz): (Card16, usize) = parse_item(bytes, index, "size_id")?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = parse_item(bytes, index, "subpixel_order")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (mwidth, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mwidth, sz): (Card16, usize) = parse_item(bytes, index, "mwidth")?;
         index += sz;
-        let (mheight, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mheight, sz): (Card16, usize) = parse_item(bytes, index, "mheight")?;
         index += sz;
-        Some((
+        Ok((
             ScreenChangeNotifyEvent {
                 event_type: event_type,
                 rotation: rotation,
@@ -7241,21 +12926,136 @@ impl AsByteSequence for ScreenChangeNotifyEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.rotation.size()
-            + self.sequence.size()
-            + self.timestamp.size()
+        15 + self.timestamp.size()
             + self.config_timestamp.size()
             + self.root.size()
             + self.request_window.size()
-            + self.size_id.size()
             + self.subpixel_order.size()
-            + self.width.size()
-            + self.height.size()
-            + self.mwidth.size()
-            + self.mheight.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ScreenChangeNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for ScreenChangeNotifyEvent {
     const OPCODE: u8 = 0;
 }
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    Notify(NotifyEvent),
+    ScreenChangeNotify(ScreenChangeNotifyEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            1 => Ok(Self::Notify(NotifyEvent::from_bytes(bytes)?.0)),
+            0 => Ok(Self::ScreenChangeNotify(
+                ScreenChangeNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::Notify(_) => NotifyEvent::RESPONSE_TYPE,
+            Self::ScreenChangeNotify(_) => ScreenChangeNotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<NotifyEvent> for Event {
+    #[inline]
+    fn from(event: NotifyEvent) -> Self {
+        Self::Notify(event)
+    }
+}
+impl From<ScreenChangeNotifyEvent> for Event {
+    #[inline]
+    fn from(event: ScreenChangeNotifyEvent) -> Self {
+        Self::ScreenChangeNotify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "ScreenChangeNotify"), (1, "Notify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (2, "SetScreenConfig"),
+    (4, "SelectInput"),
+    (5, "GetScreenInfo"),
+    (6, "GetScreenSizeRange"),
+    (7, "SetScreenSize"),
+    (8, "GetScreenResources"),
+    (9, "GetOutputInfo"),
+    (10, "ListOutputProperties"),
+    (11, "QueryOutputProperty"),
+    (12, "ConfigureOutputProperty"),
+    (13, "ChangeOutputProperty"),
+    (14, "DeleteOutputProperty"),
+    (15, "GetOutputProperty"),
+    (16, "CreateMode"),
+    (17, "DestroyMode"),
+    (18, "AddOutputMode"),
+    (19, "DeleteOutputMode"),
+    (20, "GetCrtcInfo"),
+    (21, "SetCrtcConfig"),
+    (22, "GetCrtcGammaSize"),
+    (23, "GetCrtcGamma"),
+    (24, "SetCrtcGamma"),
+    (25, "GetScreenResourcesCurrent"),
+    (26, "SetCrtcTransform"),
+    (27, "GetCrtcTransform"),
+    (28, "GetPanning"),
+    (29, "SetPanning"),
+    (30, "SetOutputPrimary"),
+    (31, "GetOutputPrimary"),
+    (32, "GetProviders"),
+    (33, "GetProviderInfo"),
+    (34, "SetProviderOffloadSink"),
+    (35, "SetProviderOutputSource"),
+    (36, "ListProviderProperties"),
+    (37, "QueryProviderProperty"),
+    (38, "ConfigureProviderProperty"),
+    (39, "ChangeProviderProperty"),
+    (40, "DeleteProviderProperty"),
+    (41, "GetProviderProperty"),
+    (42, "GetMonitors"),
+    (43, "SetMonitor"),
+    (44, "DeleteMonitor"),
+    (45, "CreateLease"),
+    (46, "FreeLease"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,12 +1,17 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "record")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 13;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Context {
     pub xid: XID,
 }
@@ -15,6 +20,23 @@ impl Context {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Context {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Context {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Context {
     #[inline]
@@ -26,12 +48,52 @@ impl XidType for Context {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Context {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Context({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Context {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Context> for XID {
+    #[inline]
+    fn from(base: Context) -> Self {
+        base.xid
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Range8 {
     pub first: Card8,
     pub last: Card8,
 }
-impl Range8 {}
+impl Range8 {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(first: Card8, last: Card8) -> Self {
+        Self {
+            first: first,
+            last: last,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 2;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("first", 0, 1), ("last", 1, 1)];
+}
 impl AsByteSequence for Range8 {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -41,14 +103,14 @@ impl AsByteSequence for Range8 {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Range8 from byte buffer");
-        let (first, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first, sz): (Card8, usize) = parse_item(bytes, index, "first")?;
         index += sz;
-        let (last, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (last, sz): (Card8, usize) = parse_item(bytes, index, "last")?;
         index += sz;
-        Some((
+        Ok((
             Range8 {
                 first: first,
                 last: last,
@@ -58,15 +120,42 @@ impl AsByteSequence for Range8 {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.first.size() + self.last.size()
+        2
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Range8 {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Range16 {
     pub first: Card16,
     pub last: Card16,
 }
-impl Range16 {}
+impl Range16 {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(first: Card16, last: Card16) -> Self {
+        Self {
+            first: first,
+            last: last,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("first", 0, 2), ("last", 2, 2)];
+}
 impl AsByteSequence for Range16 {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -76,14 +165,14 @@ impl AsByteSequence for Range16 {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Range16 from byte buffer");
-        let (first, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first, sz): (Card16, usize) = parse_item(bytes, index, "first")?;
         index += sz;
-        let (last, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (last, sz): (Card16, usize) = parse_item(bytes, index, "last")?;
         index += sz;
-        Some((
+        Ok((
             Range16 {
                 first: first,
                 last: last,
@@ -93,15 +182,34 @@ impl AsByteSequence for Range16 {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.first.size() + self.last.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Range16 {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ExtRange {
     pub major: Range8,
     pub minor: Range16,
 }
-impl ExtRange {}
+impl ExtRange {
+    #[inline]
+    pub const fn new(major: Range8, minor: Range16) -> Self {
+        Self {
+            major: major,
+            minor: minor,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("major", 0, 2), ("minor", 2, 4)];
+}
 impl AsByteSequence for ExtRange {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -111,14 +219,14 @@ impl AsByteSequence for ExtRange {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ExtRange from byte buffer");
-        let (major, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (major, sz): (Range8, usize) = parse_item(bytes, index, "major")?;
         index += sz;
-        let (minor, sz): (Range16, usize) = <Range16>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Range16, usize) = parse_item(bytes, index, "minor")?;
         index += sz;
-        Some((
+        Ok((
             ExtRange {
                 major: major,
                 minor: minor,
@@ -128,10 +236,19 @@ impl AsByteSequence for ExtRange {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.major.size() + self.minor.size()
+        6
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ExtRange {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Range {
     pub core_requests: Range8,
     pub core_replies: Range8,
@@ -143,9 +260,21 @@ pub struct Range {
     pub client_started: bool,
     pub client_died: bool,
 }
-impl Range {}
+impl Range {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("core_requests", 0, 2),
+        ("core_replies", 2, 2),
+        ("ext_requests", 4, 6),
+        ("ext_replies", 10, 6),
+        ("delivered_events", 16, 2),
+        ("device_events", 18, 2),
+        ("errors", 20, 2),
+        ("client_started", 22, 1),
+        ("client_died", 23, 1),
+    ];
+}
 impl AsByteSequence for Range {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.core_requests.as_bytes(&mut bytes[index..]);
@@ -159,29 +288,28 @@ impl AsByteSequence for Range {
         index += self.client_died.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Range from byte buffer");
-        let (core_requests, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (core_requests, sz): (Range8, usize) = parse_item(bytes, index, "core_requests")?;
         index += sz;
-        let (core_replies, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (core_replies, sz): (Range8, usize) = parse_item(bytes, index, "core_replies")?;
         index += sz;
-        let (ext_requests, sz): (ExtRange, usize) = <ExtRange>::from_bytes(&bytes[index..])?;
+        let (ext_requests, sz): (ExtRange, usize) = parse_item(bytes, index, "ext_requests")?;
         index += sz;
-        let (ext_replies, sz): (ExtRange, usize) = <ExtRange>::from_bytes(&bytes[index..])?;
+        let (ext_replies, sz): (ExtRange, usize) = parse_item(bytes, index, "ext_replies")?;
         index += sz;
-        let (delivered_events, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (delivered_events, sz): (Range8, usize) = parse_item(bytes, index, "delivered_events")?;
         index += sz;
-        let (device_events, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (device_events, sz): (Range8, usize) = parse_item(bytes, index, "device_events")?;
         index += sz;
-        let (errors, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (errors, sz): (Range8, usize) = parse_item(bytes, index, "errors")?;
         index += sz;
-        let (client_started, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (client_started, sz): (bool, usize) = parse_item(bytes, index, "client_started")?;
         index += sz;
-        let (client_died, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (client_died, sz): (bool, usize) = parse_item(bytes, index, "client_died")?;
         index += sz;
-        Some((
+        Ok((
             Range {
                 core_requests: core_requests,
                 core_replies: core_replies,
@@ -198,49 +326,56 @@ impl AsByteSequence for Range {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.core_requests.size()
-            + self.core_replies.size()
-            + self.ext_requests.size()
-            + self.ext_replies.size()
-            + self.delivered_events.size()
-            + self.device_events.size()
-            + self.errors.size()
-            + self.client_started.size()
-            + self.client_died.size()
+        22 + self.client_started.size() + self.client_died.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Range {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 pub type ElementHeader = Card8;
 pub type ClientSpec = Card32;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ClientInfo {
     pub client_resource: ClientSpec,
     pub ranges: Vec<Range>,
 }
-impl ClientInfo {}
+impl ClientInfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("client_resource", 0, 4), ("ranges_len", 4, 4)];
+}
 impl AsByteSequence for ClientInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.client_resource.as_bytes(&mut bytes[index..]);
         index += (self.ranges.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.ranges, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Range>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientInfo from byte buffer");
-        let (client_resource, sz): (ClientSpec, usize) = <ClientSpec>::from_bytes(&bytes[index..])?;
+        let (client_resource, sz): (ClientSpec, usize) =
+            parse_item(bytes, index, "client_resource")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (ranges, block_len): (Vec<Range>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "ranges")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
-        Some((
+        Ok((
             ClientInfo {
                 client_resource: client_resource,
                 ranges: ranges,
@@ -250,46 +385,83 @@ impl AsByteSequence for ClientInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.client_resource.size() + ::core::mem::size_of::<Card32>() + {
+        8 + {
             let block_len: usize = self.ranges.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Range>());
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ClientInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub major_version: Card16,
     pub minor_version: Card16,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("major_version", 4, 2),
+        ("minor_version", 6, 2),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -301,20 +473,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn major_version(mut self, major_version: Card16) -> Self {
+        self.inner.major_version = major_version;
+        self
+    }
+    #[inline]
+    pub fn minor_version(mut self, minor_version: Card16) -> Self {
+        self.inner.minor_version = minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                major_version: Default::default(),
+                minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -322,35 +537,105 @@ pub struct QueryVersionReply {
     pub major_version: Card16,
     pub minor_version: Card16,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card16 = read_item(reader, &mut index, 2, "major_version")?;
+        let minor_version: Card16 = read_item(reader, &mut index, 2, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 2),
+        ("minor_version", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card16, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -363,15 +648,21 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateContextRequest {
     pub req_type: u8,
     pub length: u16,
@@ -380,96 +671,173 @@ pub struct CreateContextRequest {
     pub client_specs: Vec<ClientSpec>,
     pub ranges: Vec<Range>,
 }
-impl CreateContextRequest {}
-impl AsByteSequence for CreateContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index += self.element_header.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += (self.client_specs.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += (self.ranges.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.client_specs, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-        let block_len: usize = vector_as_bytes(&self.ranges, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
-        index
-    }
+impl CreateContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (client_specs, block_len): (Vec<ClientSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-        let (ranges, block_len): (Vec<Range>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
-        Some((
-            CreateContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-                element_header: element_header,
-                client_specs: client_specs,
-                ranges: ranges,
-            },
-            index,
-        ))
+    pub fn builder() -> CreateContextRequestBuilder {
+        Default::default()
     }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context.size()
-            + self.element_header.size()
-            + 3
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.client_specs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-                block_len + pad
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_request_asb_254c11a6 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.context.as_bytes(&mut bytes[index..]);
+                index += self.element_header.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 3);
+                index += (self.client_specs.len() as Card32).as_bytes(&mut bytes[index..]);
+                index += (self.ranges.len() as Card32).as_bytes(&mut bytes[index..]);
+                let block_len: usize = vector_as_bytes(&self.client_specs, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>()),
+                );
+                let block_len: usize = vector_as_bytes(&self.ranges, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Range>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
             }
-            + {
-                let block_len: usize = self.ranges.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Range>());
-                block_len + pad
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (context, sz): (super::record::Context, usize) =
+                    parse_item(bytes, index, "context")?;
+                index += sz;
+                let (element_header, sz): (ElementHeader, usize) =
+                    parse_item(bytes, index, "element_header")?;
+                index += sz;
+                index += 3;
+                let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+                index += sz;
+                let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+                index += sz;
+                let (client_specs, block_len): (Vec<ClientSpec>, usize) =
+                    parse_vector(bytes, index, len0 as usize, "client_specs")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
+                let (ranges, block_len): (Vec<Range>, usize) =
+                    parse_vector(bytes, index, len1 as usize, "ranges")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        context: context,
+                        element_header: element_header,
+                        client_specs: client_specs,
+                        ranges: ranges,
+                    },
+                    index,
+                ))
             }
-    }
+            fn size(&self) -> usize {
+                let size: usize = 16
+                    + self.context.size()
+                    + {
+                        let block_len: usize = self.client_specs.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.ranges.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Range>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_request_asb_254c11a6! { CreateContextRequest }
 impl Request for CreateContextRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateContextRequestBuilder {
+    pub inner: CreateContextRequest,
+}
+impl CreateContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: super::record::Context) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn element_header(mut self, element_header: ElementHeader) -> Self {
+        self.inner.element_header = element_header;
+        self
+    }
+    #[inline]
+    pub fn client_specs(mut self, client_specs: Vec<ClientSpec>) -> Self {
+        self.inner.client_specs = client_specs;
+        self
+    }
+    #[inline]
+    pub fn ranges(mut self, ranges: Vec<Range>) -> Self {
+        self.inner.ranges = ranges;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateContextRequest {
+        self.inner
+    }
+}
+impl Default for CreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                element_header: Default::default(),
+                client_specs: Default::default(),
+                ranges: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct RegisterClientsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -478,136 +846,125 @@ pub struct RegisterClientsRequest {
     pub client_specs: Vec<ClientSpec>,
     pub ranges: Vec<Range>,
 }
-impl RegisterClientsRequest {}
-impl AsByteSequence for RegisterClientsRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index += self.element_header.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += (self.client_specs.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += (self.ranges.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.client_specs, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-        let block_len: usize = vector_as_bytes(&self.ranges, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing RegisterClientsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (client_specs, block_len): (Vec<ClientSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-        let (ranges, block_len): (Vec<Range>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
-        Some((
-            RegisterClientsRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-                element_header: element_header,
-                client_specs: client_specs,
-                ranges: ranges,
-            },
-            index,
-        ))
-    }
+impl RegisterClientsRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context.size()
-            + self.element_header.size()
-            + 3
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.client_specs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.ranges.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Range>());
-                block_len + pad
-            }
+    pub fn builder() -> RegisterClientsRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<RegisterClientsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_254c11a6! { RegisterClientsRequest }
 impl Request for RegisterClientsRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RegisterClients";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `RegisterClientsRequest`."]
+#[derive(Clone, Debug)]
+pub struct RegisterClientsRequestBuilder {
+    pub inner: RegisterClientsRequest,
+}
+impl RegisterClientsRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: super::record::Context) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn element_header(mut self, element_header: ElementHeader) -> Self {
+        self.inner.element_header = element_header;
+        self
+    }
+    #[inline]
+    pub fn client_specs(mut self, client_specs: Vec<ClientSpec>) -> Self {
+        self.inner.client_specs = client_specs;
+        self
+    }
+    #[inline]
+    pub fn ranges(mut self, ranges: Vec<Range>) -> Self {
+        self.inner.ranges = ranges;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> RegisterClientsRequest {
+        self.inner
+    }
+}
+impl Default for RegisterClientsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: RegisterClientsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                element_header: Default::default(),
+                client_specs: Default::default(),
+                ranges: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct UnregisterClientsRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: super::record::Context,
     pub client_specs: Vec<ClientSpec>,
 }
-impl UnregisterClientsRequest {}
-impl AsByteSequence for UnregisterClientsRequest {
+impl UnregisterClientsRequest {
     #[inline]
+    pub fn builder() -> UnregisterClientsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<UnregisterClientsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for UnregisterClientsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += (self.client_specs.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.client_specs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnregisterClientsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+        let (context, sz): (super::record::Context, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (client_specs, block_len): (Vec<ClientSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "client_specs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             UnregisterClientsRequest {
                 req_type: req_type,
                 length: length,
@@ -619,74 +976,197 @@ impl AsByteSequence for UnregisterClientsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context.size()
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.client_specs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
-                block_len + pad
-            }
+        let size: usize = 8 + self.context.size() + {
+            let block_len: usize = self.client_specs.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for UnregisterClientsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for UnregisterClientsRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnregisterClients";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `UnregisterClientsRequest`."]
+#[derive(Clone, Debug)]
+pub struct UnregisterClientsRequestBuilder {
+    pub inner: UnregisterClientsRequest,
+}
+impl UnregisterClientsRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: super::record::Context) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn client_specs(mut self, client_specs: Vec<ClientSpec>) -> Self {
+        self.inner.client_specs = client_specs;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> UnregisterClientsRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<ClientSpec> for UnregisterClientsRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = ClientSpec>>(&mut self, iter: I) {
+        self.inner.client_specs.extend(iter);
+    }
+}
+impl core::iter::FromIterator<ClientSpec> for UnregisterClientsRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = ClientSpec>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for UnregisterClientsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnregisterClientsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                client_specs: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: super::record::Context,
 }
-impl GetContextRequest {}
-impl AsByteSequence for GetContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    pub fn builder() -> GetContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_context_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.context.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (context, sz): (super::record::Context, usize) =
+                    parse_item(bytes, index, "context")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        context: context,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.context.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_context_only_request_asb! { GetContextRequest }
 impl Request for GetContextRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetContext";
     type Reply = GetContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetContextRequestBuilder {
+    pub inner: GetContextRequest,
+}
+impl GetContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: super::record::Context) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetContextRequest {
+        self.inner
+    }
+}
+impl Default for GetContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetContextReply {
     pub reply_type: u8,
     pub enabled: bool,
@@ -695,9 +1175,81 @@ pub struct GetContextReply {
     pub element_header: ElementHeader,
     pub intercepted_clients: Vec<ClientInfo>,
 }
-impl GetContextReply {}
-impl AsByteSequence for GetContextReply {
+impl GetContextReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn intercepted_clients_iter(bytes: &[u8]) -> Result<ListIter<'_, ClientInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (enabled, sz): (bool, usize) = parse_item(bytes, index, "enabled")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (element_header, sz): (ElementHeader, usize) =
+            parse_item(bytes, index, "element_header")?;
+        index += sz;
+        index += 3;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 16;
+        Ok(ListIter::new(
+            bytes,
+            index,
+            len0 as usize,
+            "intercepted_clients",
+        ))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("enabled", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("element_header", 8, 1),
+        ("intercepted_clients_len", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.enabled == other.enabled
+            && self.element_header == other.element_header
+            && self.intercepted_clients == other.intercepted_clients
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("enabled", &self.enabled, &other.enabled, &mut changes);
+        diff_value(
+            "element_header",
+            &self.element_header,
+            &other.element_header,
+            &mut changes,
+        );
+        diff_list(
+            "intercepted_clients",
+            &self.intercepted_clients,
+            &other.intercepted_clients,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetContextReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -705,38 +1257,43 @@ impl AsByteSequence for GetContextReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.element_header.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += (self.intercepted_clients.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         let block_len: usize = vector_as_bytes(&self.intercepted_clients, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ClientInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (enabled, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enabled, sz): (bool, usize) = parse_item(bytes, index, "enabled")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "element_header")?;
         index += sz;
         index += 3;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 16;
         let (intercepted_clients, block_len): (Vec<ClientInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "intercepted_clients")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetContextReply {
                 reply_type: reply_type,
                 enabled: enabled,
@@ -750,71 +1307,87 @@ impl AsByteSequence for GetContextReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.enabled.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.element_header.size()
-            + 3
-            + ::core::mem::size_of::<Card32>()
-            + 16
-            + {
-                let block_len: usize = self.intercepted_clients.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientInfo>());
-                block_len + pad
-            }
+        let size: usize = 31 + self.enabled.size() + {
+            let block_len: usize = self.intercepted_clients.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetContextReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct EnableContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: super::record::Context,
 }
-impl EnableContextRequest {}
-impl AsByteSequence for EnableContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl EnableContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing EnableContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            EnableContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    pub fn builder() -> EnableContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<EnableContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<EnableContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { EnableContextRequest }
 impl Request for EnableContextRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "EnableContext";
     type Reply = EnableContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `EnableContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct EnableContextRequestBuilder {
+    pub inner: EnableContextRequest,
+}
+impl EnableContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: super::record::Context) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> EnableContextRequest {
+        self.inner
+    }
+}
+impl Default for EnableContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: EnableContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct EnableContextReply {
     pub reply_type: u8,
     pub category: Card8,
@@ -827,9 +1400,105 @@ pub struct EnableContextReply {
     pub rec_sequence_num: Card32,
     pub data: Vec<Byte>,
 }
-impl EnableContextReply {}
-impl AsByteSequence for EnableContextReply {
+impl EnableContextReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn data_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (category, sz): (Card8, usize) = parse_item(bytes, index, "category")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (element_header, sz): (ElementHeader, usize) =
+            parse_item(bytes, index, "element_header")?;
+        index += sz;
+        let (client_swapped, sz): (bool, usize) = parse_item(bytes, index, "client_swapped")?;
+        index += sz;
+        index += 2;
+        let (xid_base, sz): (Card32, usize) = parse_item(bytes, index, "xid_base")?;
+        index += sz;
+        let (server_time, sz): (Card32, usize) = parse_item(bytes, index, "server_time")?;
+        index += sz;
+        let (rec_sequence_num, sz): (Card32, usize) = parse_item(bytes, index, "rec_sequence_num")?;
+        index += sz;
+        index += 8;
+        let (data, _): (Cow<'_, [Byte]>, usize) =
+            parse_slice(bytes, index, ((length as usize) * (4)) as usize, "data")?;
+        Ok(data)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("category", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("element_header", 8, 1),
+        ("client_swapped", 9, 1),
+        ("xid_base", 12, 4),
+        ("server_time", 16, 4),
+        ("rec_sequence_num", 20, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.category == other.category
+            && self.element_header == other.element_header
+            && self.client_swapped == other.client_swapped
+            && self.xid_base == other.xid_base
+            && self.server_time == other.server_time
+            && self.rec_sequence_num == other.rec_sequence_num
+            && self.data == other.data
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("category", &self.category, &other.category, &mut changes);
+        diff_value(
+            "element_header",
+            &self.element_header,
+            &other.element_header,
+            &mut changes,
+        );
+        diff_value(
+            "client_swapped",
+            &self.client_swapped,
+            &other.client_swapped,
+            &mut changes,
+        );
+        diff_value("xid_base", &self.xid_base, &other.xid_base, &mut changes);
+        diff_value(
+            "server_time",
+            &self.server_time,
+            &other.server_time,
+            &mut changes,
+        );
+        diff_value(
+            "rec_sequence_num",
+            &self.rec_sequence_num,
+            &other.rec_sequence_num,
+            &mut changes,
+        );
+        diff_list("data", &self.data, &other.data, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for EnableContextReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -838,46 +1507,51 @@ impl AsByteSequence for EnableContextReply {
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.element_header.as_bytes(&mut bytes[index..]);
         index += self.client_swapped.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.xid_base.as_bytes(&mut bytes[index..]);
         index += self.server_time.as_bytes(&mut bytes[index..]);
         index += self.rec_sequence_num.as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += zero_pad(&mut bytes[index..], 8);
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (category, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (category, sz): (Card8, usize) = parse_item(bytes, index, "category")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "element_header")?;
         index += sz;
-        let (client_swapped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (client_swapped, sz): (bool, usize) = parse_item(bytes, index, "client_swapped")?;
         index += sz;
         index += 2;
-        let (xid_base, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (xid_base, sz): (Card32, usize) = parse_item(bytes, index, "xid_base")?;
         index += sz;
-        let (server_time, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (server_time, sz): (Card32, usize) = parse_item(bytes, index, "server_time")?;
         index += sz;
-        let (rec_sequence_num, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (rec_sequence_num, sz): (Card32, usize) = parse_item(bytes, index, "rec_sequence_num")?;
         index += sz;
         index += 8;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            parse_vector(bytes, index, ((length as usize) * (4)) as usize, "data")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             EnableContextReply {
                 reply_type: reply_type,
                 category: category,
@@ -895,124 +1569,191 @@ impl AsByteSequence for EnableContextReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.category.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.element_header.size()
-            + self.client_swapped.size()
-            + 2
-            + self.xid_base.size()
-            + self.server_time.size()
-            + self.rec_sequence_num.size()
-            + 8
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
-            }
+        let size: usize = 31 + self.client_swapped.size() + {
+            let block_len: usize = self.data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 31 + self.client_swapped.size() + {
+            let block_len: usize = self.data.len() * ::core::mem::size_of::<Byte>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for EnableContextReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for EnableContextReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EnableContextReply")
+            .field("reply_type", &self.reply_type)
+            .field("category", &self.category)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("element_header", &self.element_header)
+            .field("client_swapped", &self.client_swapped)
+            .field("xid_base", &self.xid_base)
+            .field("server_time", &self.server_time)
+            .field("rec_sequence_num", &self.rec_sequence_num)
+            .field("data", &debug_bytes(&self.data, 64))
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for EnableContextReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let category: Card8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let element_header: ElementHeader = u.arbitrary()?;
+        let client_swapped: bool = u.arbitrary()?;
+        let xid_base: Card32 = u.arbitrary()?;
+        let server_time: Card32 = u.arbitrary()?;
+        let rec_sequence_num: Card32 = u.arbitrary()?;
+        let data = arbitrary_list(u, ((length as usize) * (4)) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            category: category,
+            sequence: sequence,
+            length: length,
+            element_header: element_header,
+            client_swapped: client_swapped,
+            xid_base: xid_base,
+            server_time: server_time,
+            rec_sequence_num: rec_sequence_num,
+            data: data,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DisableContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: super::record::Context,
 }
-impl DisableContextRequest {}
-impl AsByteSequence for DisableContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl DisableContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DisableContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DisableContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn builder() -> DisableContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<DisableContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { DisableContextRequest }
 impl Request for DisableContextRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DisableContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct FreeContextRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub context: super::record::Context,
+#[doc = " A builder for `DisableContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct DisableContextRequestBuilder {
+    pub inner: DisableContextRequest,
 }
-impl FreeContextRequest {}
-impl AsByteSequence for FreeContextRequest {
+impl DisableContextRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
+    pub fn context(mut self, context: super::record::Context) -> Self {
+        self.inner.context = context;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing FreeContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            FreeContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DisableContextRequest {
+        self.inner
+    }
+}
+impl Default for DisableContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DisableContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct FreeContextRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub context: super::record::Context,
+}
+impl FreeContextRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn builder() -> FreeContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<FreeContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { FreeContextRequest }
 impl Request for FreeContextRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeContext";
     type Reply = ();
 }
+#[doc = " A builder for `FreeContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct FreeContextRequestBuilder {
+    pub inner: FreeContextRequest,
+}
+impl FreeContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: super::record::Context) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FreeContextRequest {
+        self.inner
+    }
+}
+impl Default for FreeContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FreeContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Cs {
     CurrentClients = 1,
     FutureClients = 2,
@@ -1024,13 +1765,13 @@ impl AsByteSequence for Cs {
         (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            1 => Some((Self::CurrentClients, sz)),
-            2 => Some((Self::FutureClients, sz)),
-            3 => Some((Self::AllClients, sz)),
-            _ => None,
+            1 => Ok((Self::CurrentClients, sz)),
+            2 => Ok((Self::FutureClients, sz)),
+            3 => Ok((Self::AllClients, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -1038,14 +1779,41 @@ impl AsByteSequence for Cs {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Cs {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Cs {
     #[inline]
     fn default() -> Cs {
         Cs::CurrentClients
     }
 }
+impl From<Cs> for i32 {
+    #[inline]
+    fn from(base: Cs) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for Cs {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            1 => Ok(Self::CurrentClients),
+            2 => Ok(Self::FutureClients),
+            3 => Ok(Self::AllClients),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct HType {
     pub inner: i32,
 }
@@ -1112,19 +1880,39 @@ impl HType {
     pub const FROM_CLIENT_SEQUENCE: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 7 };
 }
+impl core::ops::Deref for HType {
+    type Target = i32;
+    #[inline]
+    fn deref(&self) -> &i32 {
+        &self.inner
+    }
+}
+impl AsRef<i32> for HType {
+    #[inline]
+    fn as_ref(&self) -> &i32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for HType {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((HType { inner: inner }, sz))
+        Ok((HType { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for HType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for HType {
@@ -1161,50 +1949,63 @@ impl core::ops::BitXor for HType {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct BadContextError {
     pub _error_type: u8,
     pub error_code: u8,
-    pub major_code: u8,
-    pub minor_code: u8,
     pub sequence: u16,
     pub invalid_record: Card32,
 }
-impl BadContextError {}
+impl BadContextError {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("_error_type", 0, 1),
+        ("error_code", 1, 1),
+        ("sequence", 2, 2),
+        ("invalid_record", 4, 4),
+    ];
+}
+const _: () = assert!(BadContextError::FIXED_SIZE <= 32);
 impl AsByteSequence for BadContextError {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self._error_type.as_bytes(&mut bytes[index..]);
         index += self.error_code.as_bytes(&mut bytes[index..]);
-        index += self.major_code.as_bytes(&mut bytes[index..]);
-        index += self.minor_code.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.invalid_record.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing BadContextError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = parse_item(bytes, index, "_error_type")?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = parse_item(bytes, index, "error_code")?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (invalid_record, sz): (Card32, usize) = parse_item(bytes, index, "invalid_record")?;
         index += sz;
-        let (invalid_record, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
+        Ok((
             BadContextError {
                 _error_type: _error_type,
                 error_code: error_code,
-                major_code: major_code,
-                minor_code: minor_code,
                 sequence: sequence,
                 invalid_record: invalid_record,
             },
@@ -1213,14 +2014,72 @@ impl AsByteSequence for BadContextError {
     }
     #[inline]
     fn size(&self) -> usize {
-        self._error_type.size()
-            + self.error_code.size()
-            + self.major_code.size()
-            + self.minor_code.size()
-            + self.sequence.size()
-            + self.invalid_record.size()
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for BadContextError {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Error for BadContextError {
     const OPCODE: u8 = 0;
 }
+impl core::fmt::Display for BadContextError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "X error: BadContext (code 0)")
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for BadContextError {}
+#[doc = " Parse an error of this extension out of its code and its bytes. The codes of the extension's errors count up from `first_error`, the first error code that the server gave it. Errors with other codes are kept as bytes."]
+#[inline]
+pub fn error_from_code(code: u8, first_error: u8, bytes: &[u8]) -> Result<XError, ParseError> {
+    Ok(match code.wrapping_sub(first_error) {
+        0 => XError::RecordBadContext(BadContextError::from_bytes(bytes)?.0),
+        _ => XError::Unknown {
+            code,
+            bytes: bytes.iter().copied().collect(),
+        },
+    })
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[(0, "BadContext")];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "CreateContext"),
+    (2, "RegisterClients"),
+    (3, "UnregisterClients"),
+    (4, "GetContext"),
+    (5, "EnableContext"),
+    (6, "DisableContext"),
+    (7, "FreeContext"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
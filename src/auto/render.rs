@@ -1,14 +1,19 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "render")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 0;
+pub const MINOR_VERSION: u32 = 11;
 use super::xproto::*;
 pub type Glyph = Card32;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Glyphset {
     pub xid: XID,
 }
@@ -17,6 +22,23 @@ impl Glyphset {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Glyphset {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Glyphset {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Glyphset {
     #[inline]
@@ -28,8 +50,30 @@ impl XidType for Glyphset {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Glyphset {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Glyphset({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Glyphset {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Glyphset> for XID {
+    #[inline]
+    fn from(base: Glyphset) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Picture {
     pub xid: XID,
 }
@@ -38,6 +82,23 @@ impl Picture {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Picture {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Picture {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Picture {
     #[inline]
@@ -49,8 +110,30 @@ impl XidType for Picture {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Picture {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Picture({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Picture {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Picture> for XID {
+    #[inline]
+    fn from(base: Picture) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Pictformat {
     pub xid: XID,
 }
@@ -59,6 +142,23 @@ impl Pictformat {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Pictformat {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Pictformat {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Pictformat {
     #[inline]
@@ -70,8 +170,32 @@ impl XidType for Pictformat {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Pictformat {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Pictformat({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Pictformat {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Pictformat> for XID {
+    #[inline]
+    fn from(base: Pictformat) -> Self {
+        base.xid
+    }
+}
 pub type Fixed = Int32;
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Directformat {
     pub red_shift: Card16,
     pub red_mask: Card16,
@@ -82,9 +206,48 @@ pub struct Directformat {
     pub alpha_shift: Card16,
     pub alpha_mask: Card16,
 }
-impl Directformat {}
-impl AsByteSequence for Directformat {
+impl Directformat {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub const fn new(
+        red_shift: Card16,
+        red_mask: Card16,
+        green_shift: Card16,
+        green_mask: Card16,
+        blue_shift: Card16,
+        blue_mask: Card16,
+        alpha_shift: Card16,
+        alpha_mask: Card16,
+    ) -> Self {
+        Self {
+            red_shift: red_shift,
+            red_mask: red_mask,
+            green_shift: green_shift,
+            green_mask: green_mask,
+            blue_shift: blue_shift,
+            blue_mask: blue_mask,
+            alpha_shift: alpha_shift,
+            alpha_mask: alpha_mask,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("red_shift", 0, 2),
+        ("red_mask", 2, 2),
+        ("green_shift", 4, 2),
+        ("green_mask", 6, 2),
+        ("blue_shift", 8, 2),
+        ("blue_mask", 10, 2),
+        ("alpha_shift", 12, 2),
+        ("alpha_mask", 14, 2),
+    ];
+}
+impl AsByteSequence for Directformat {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.red_shift.as_bytes(&mut bytes[index..]);
@@ -97,27 +260,26 @@ impl AsByteSequence for Directformat {
         index += self.alpha_mask.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Directformat from byte buffer");
-        let (red_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red_shift, sz): (Card16, usize) = parse_item(bytes, index, "red_shift")?;
         index += sz;
-        let (red_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red_mask, sz): (Card16, usize) = parse_item(bytes, index, "red_mask")?;
         index += sz;
-        let (green_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green_shift, sz): (Card16, usize) = parse_item(bytes, index, "green_shift")?;
         index += sz;
-        let (green_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green_mask, sz): (Card16, usize) = parse_item(bytes, index, "green_mask")?;
         index += sz;
-        let (blue_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue_shift, sz): (Card16, usize) = parse_item(bytes, index, "blue_shift")?;
         index += sz;
-        let (blue_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue_mask, sz): (Card16, usize) = parse_item(bytes, index, "blue_mask")?;
         index += sz;
-        let (alpha_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha_shift, sz): (Card16, usize) = parse_item(bytes, index, "alpha_shift")?;
         index += sz;
-        let (alpha_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha_mask, sz): (Card16, usize) = parse_item(bytes, index, "alpha_mask")?;
         index += sz;
-        Some((
+        Ok((
             Directformat {
                 red_shift: red_shift,
                 red_mask: red_mask,
@@ -133,17 +295,19 @@ impl AsByteSequence for Directformat {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.red_shift.size()
-            + self.red_mask.size()
-            + self.green_shift.size()
-            + self.green_mask.size()
-            + self.blue_shift.size()
-            + self.blue_mask.size()
-            + self.alpha_shift.size()
-            + self.alpha_mask.size()
+        16
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Directformat {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Pictforminfo {
     pub id: Pictformat,
     pub ty: PictType,
@@ -151,35 +315,41 @@ pub struct Pictforminfo {
     pub direct: Directformat,
     pub colormap: Colormap,
 }
-impl Pictforminfo {}
+impl Pictforminfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("id", 0, 4),
+        ("ty", 4, 1),
+        ("depth", 5, 1),
+        ("direct", 8, 16),
+    ];
+}
 impl AsByteSequence for Pictforminfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.id.as_bytes(&mut bytes[index..]);
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.direct.as_bytes(&mut bytes[index..]);
         index += self.colormap.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictforminfo from byte buffer");
-        let (id, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Pictformat, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (ty, sz): (PictType, usize) = <PictType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (PictType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
         index += 2;
-        let (direct, sz): (Directformat, usize) = <Directformat>::from_bytes(&bytes[index..])?;
+        let (direct, sz): (Directformat, usize) = parse_item(bytes, index, "direct")?;
         index += sz;
-        let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (colormap, sz): (Colormap, usize) = parse_item(bytes, index, "colormap")?;
         index += sz;
-        Some((
+        Ok((
             Pictforminfo {
                 id: id,
                 ty: ty,
@@ -192,16 +362,20 @@ impl AsByteSequence for Pictforminfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.id.size()
-            + self.ty.size()
-            + self.depth.size()
-            + 2
-            + self.direct.size()
-            + self.colormap.size()
+        24 + self.colormap.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Pictforminfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum PictType {
     Indexed = 0,
     Direct = 1,
@@ -212,12 +386,12 @@ impl AsByteSequence for PictType {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Indexed, sz)),
-            1 => Some((Self::Direct, sz)),
-            _ => None,
+            0 => Ok((Self::Indexed, sz)),
+            1 => Ok((Self::Direct, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -225,18 +399,47 @@ impl AsByteSequence for PictType {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for PictType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for PictType {
     #[inline]
     fn default() -> PictType {
         PictType::Indexed
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<PictType> for u8 {
+    #[inline]
+    fn from(base: PictType) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for PictType {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Indexed),
+            1 => Ok(Self::Direct),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Pictvisual {
     pub visual: Visualid,
     pub format: Pictformat,
 }
-impl Pictvisual {}
+impl Pictvisual {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for Pictvisual {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -246,14 +449,14 @@ impl AsByteSequence for Pictvisual {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictvisual from byte buffer");
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = parse_item(bytes, index, "visual")?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        Some((
+        Ok((
             Pictvisual {
                 visual: visual,
                 format: format,
@@ -263,43 +466,56 @@ impl AsByteSequence for Pictvisual {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.visual.size() + self.format.size()
+        4 + self.visual.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Pictvisual {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Pictdepth {
     pub depth: Card8,
     pub visuals: Vec<Pictvisual>,
 }
-impl Pictdepth {}
+impl Pictdepth {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("depth", 0, 1), ("visuals_len", 2, 2)];
+}
 impl AsByteSequence for Pictdepth {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.depth.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += (self.visuals.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         let block_len: usize = vector_as_bytes(&self.visuals, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pictvisual>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Pictvisual>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictdepth from byte buffer");
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
         index += 1;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 4;
         let (visuals, block_len): (Vec<Pictvisual>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "visuals")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pictvisual>());
-        Some((
+        Ok((
             Pictdepth {
                 depth: depth,
                 visuals: visuals,
@@ -309,43 +525,56 @@ impl AsByteSequence for Pictdepth {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.depth.size() + 1 + ::core::mem::size_of::<Card16>() + 4 + {
+        8 + {
             let block_len: usize = self.visuals.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pictvisual>());
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Pictdepth {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Pictscreen {
     pub fallback: Pictformat,
     pub depths: Vec<Pictdepth>,
 }
-impl Pictscreen {}
+impl Pictscreen {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("depths_len", 0, 4), ("fallback", 4, 4)];
+}
 impl AsByteSequence for Pictscreen {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += (self.depths.len() as Card32).as_bytes(&mut bytes[index..]);
         index += self.fallback.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.depths, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pictdepth>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Pictdepth>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictscreen from byte buffer");
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (fallback, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (fallback, sz): (Pictformat, usize) = parse_item(bytes, index, "fallback")?;
         index += sz;
         let (depths, block_len): (Vec<Pictdepth>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "depths")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pictdepth>());
-        Some((
+        Ok((
             Pictscreen {
                 fallback: fallback,
                 depths: depths,
@@ -355,14 +584,25 @@ impl AsByteSequence for Pictscreen {
     }
     #[inline]
     fn size(&self) -> usize {
-        ::core::mem::size_of::<Card32>() + self.fallback.size() + {
+        8 + {
             let block_len: usize = self.depths.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pictdepth>());
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Pictscreen {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Indexvalue {
     pub pixel: Card32,
     pub red: Card16,
@@ -370,7 +610,38 @@ pub struct Indexvalue {
     pub blue: Card16,
     pub alpha: Card16,
 }
-impl Indexvalue {}
+impl Indexvalue {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(
+        pixel: Card32,
+        red: Card16,
+        green: Card16,
+        blue: Card16,
+        alpha: Card16,
+    ) -> Self {
+        Self {
+            pixel: pixel,
+            red: red,
+            green: green,
+            blue: blue,
+            alpha: alpha,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("pixel", 0, 4),
+        ("red", 4, 2),
+        ("green", 6, 2),
+        ("blue", 8, 2),
+        ("alpha", 10, 2),
+    ];
+}
 impl AsByteSequence for Indexvalue {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -382,21 +653,20 @@ impl AsByteSequence for Indexvalue {
         index += self.alpha.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Indexvalue from byte buffer");
-        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pixel, sz): (Card32, usize) = parse_item(bytes, index, "pixel")?;
         index += sz;
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = parse_item(bytes, index, "red")?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = parse_item(bytes, index, "green")?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = parse_item(bytes, index, "blue")?;
         index += sz;
-        let (alpha, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha, sz): (Card16, usize) = parse_item(bytes, index, "alpha")?;
         index += sz;
-        Some((
+        Ok((
             Indexvalue {
                 pixel: pixel,
                 red: red,
@@ -409,21 +679,51 @@ impl AsByteSequence for Indexvalue {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.pixel.size()
-            + self.red.size()
-            + self.green.size()
-            + self.blue.size()
-            + self.alpha.size()
+        12
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Indexvalue {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Color {
     pub red: Card16,
     pub green: Card16,
     pub blue: Card16,
     pub alpha: Card16,
 }
-impl Color {}
+impl Color {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(red: Card16, green: Card16, blue: Card16, alpha: Card16) -> Self {
+        Self {
+            red: red,
+            green: green,
+            blue: blue,
+            alpha: alpha,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("red", 0, 2),
+        ("green", 2, 2),
+        ("blue", 4, 2),
+        ("alpha", 6, 2),
+    ];
+}
 impl AsByteSequence for Color {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -434,19 +734,18 @@ impl AsByteSequence for Color {
         index += self.alpha.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Color from byte buffer");
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = parse_item(bytes, index, "red")?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = parse_item(bytes, index, "green")?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = parse_item(bytes, index, "blue")?;
         index += sz;
-        let (alpha, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha, sz): (Card16, usize) = parse_item(bytes, index, "alpha")?;
         index += sz;
-        Some((
+        Ok((
             Color {
                 red: red,
                 green: green,
@@ -458,15 +757,31 @@ impl AsByteSequence for Color {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.red.size() + self.green.size() + self.blue.size() + self.alpha.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Color {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Pointfix {
     pub x: Fixed,
     pub y: Fixed,
 }
-impl Pointfix {}
+impl Pointfix {
+    #[inline]
+    pub const fn new(x: Fixed, y: Fixed) -> Self {
+        Self { x: x, y: y }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("x", 0, 4), ("y", 4, 4)];
+}
 impl AsByteSequence for Pointfix {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -476,26 +791,42 @@ impl AsByteSequence for Pointfix {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pointfix from byte buffer");
-        let (x, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Fixed, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Fixed, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        Some((Pointfix { x: x, y: y }, index))
+        Ok((Pointfix { x: x, y: y }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.x.size() + self.y.size()
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Pointfix {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Linefix {
     pub p1: Pointfix,
     pub p2: Pointfix,
 }
-impl Linefix {}
+impl Linefix {
+    #[inline]
+    pub const fn new(p1: Pointfix, p2: Pointfix) -> Self {
+        Self { p1: p1, p2: p2 }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("p1", 0, 8), ("p2", 8, 8)];
+}
 impl AsByteSequence for Linefix {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -505,27 +836,47 @@ impl AsByteSequence for Linefix {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Linefix from byte buffer");
-        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p1, sz): (Pointfix, usize) = parse_item(bytes, index, "p1")?;
         index += sz;
-        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p2, sz): (Pointfix, usize) = parse_item(bytes, index, "p2")?;
         index += sz;
-        Some((Linefix { p1: p1, p2: p2 }, index))
+        Ok((Linefix { p1: p1, p2: p2 }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.p1.size() + self.p2.size()
+        16
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Linefix {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Triangle {
     pub p1: Pointfix,
     pub p2: Pointfix,
     pub p3: Pointfix,
 }
-impl Triangle {}
+impl Triangle {
+    #[inline]
+    pub const fn new(p1: Pointfix, p2: Pointfix, p3: Pointfix) -> Self {
+        Self {
+            p1: p1,
+            p2: p2,
+            p3: p3,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("p1", 0, 8), ("p2", 8, 8), ("p3", 16, 8)];
+}
 impl AsByteSequence for Triangle {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -535,17 +886,16 @@ impl AsByteSequence for Triangle {
         index += self.p3.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Triangle from byte buffer");
-        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p1, sz): (Pointfix, usize) = parse_item(bytes, index, "p1")?;
         index += sz;
-        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p2, sz): (Pointfix, usize) = parse_item(bytes, index, "p2")?;
         index += sz;
-        let (p3, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p3, sz): (Pointfix, usize) = parse_item(bytes, index, "p3")?;
         index += sz;
-        Some((
+        Ok((
             Triangle {
                 p1: p1,
                 p2: p2,
@@ -556,17 +906,34 @@ impl AsByteSequence for Triangle {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.p1.size() + self.p2.size() + self.p3.size()
+        24
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Triangle {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Trapezoid {
     pub top: Fixed,
     pub bottom: Fixed,
     pub left: Linefix,
     pub right: Linefix,
 }
-impl Trapezoid {}
+impl Trapezoid {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("top", 0, 4),
+        ("bottom", 4, 4),
+        ("left", 8, 16),
+        ("right", 24, 16),
+    ];
+}
 impl AsByteSequence for Trapezoid {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -577,19 +944,18 @@ impl AsByteSequence for Trapezoid {
         index += self.right.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Trapezoid from byte buffer");
-        let (top, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Fixed, usize) = parse_item(bytes, index, "top")?;
         index += sz;
-        let (bottom, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (bottom, sz): (Fixed, usize) = parse_item(bytes, index, "bottom")?;
         index += sz;
-        let (left, sz): (Linefix, usize) = <Linefix>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Linefix, usize) = parse_item(bytes, index, "left")?;
         index += sz;
-        let (right, sz): (Linefix, usize) = <Linefix>::from_bytes(&bytes[index..])?;
+        let (right, sz): (Linefix, usize) = parse_item(bytes, index, "right")?;
         index += sz;
-        Some((
+        Ok((
             Trapezoid {
                 top: top,
                 bottom: bottom,
@@ -601,10 +967,21 @@ impl AsByteSequence for Trapezoid {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.top.size() + self.bottom.size() + self.left.size() + self.right.size()
+        40
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Trapezoid {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Glyphinfo {
     pub width: Card16,
     pub height: Card16,
@@ -613,9 +990,42 @@ pub struct Glyphinfo {
     pub x_off: Int16,
     pub y_off: Int16,
 }
-impl Glyphinfo {}
-impl AsByteSequence for Glyphinfo {
+impl Glyphinfo {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub const fn new(
+        width: Card16,
+        height: Card16,
+        x: Int16,
+        y: Int16,
+        x_off: Int16,
+        y_off: Int16,
+    ) -> Self {
+        Self {
+            width: width,
+            height: height,
+            x: x,
+            y: y,
+            x_off: x_off,
+            y_off: y_off,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("width", 0, 2),
+        ("height", 2, 2),
+        ("x", 4, 2),
+        ("y", 6, 2),
+        ("x_off", 8, 2),
+        ("y_off", 10, 2),
+    ];
+}
+impl AsByteSequence for Glyphinfo {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.width.as_bytes(&mut bytes[index..]);
@@ -626,23 +1036,22 @@ impl AsByteSequence for Glyphinfo {
         index += self.y_off.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Glyphinfo from byte buffer");
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_off, sz): (Int16, usize) = parse_item(bytes, index, "x_off")?;
         index += sz;
-        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_off, sz): (Int16, usize) = parse_item(bytes, index, "y_off")?;
         index += sz;
-        Some((
+        Ok((
             Glyphinfo {
                 width: width,
                 height: height,
@@ -656,47 +1065,81 @@ impl AsByteSequence for Glyphinfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.width.size()
-            + self.height.size()
-            + self.x.size()
-            + self.y.size()
-            + self.x_off.size()
-            + self.y_off.size()
+        12
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Glyphinfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major_version: Card32,
     pub client_minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major_version", 4, 4),
+        ("client_minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major_version.as_bytes(&mut bytes[index..]);
         index += self.client_minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_major_version")?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -708,20 +1151,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major_version.size()
-            + self.client_minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn client_major_version(mut self, client_major_version: Card32) -> Self {
+        self.inner.client_major_version = client_major_version;
+        self
+    }
+    #[inline]
+    pub fn client_minor_version(mut self, client_minor_version: Card32) -> Self {
+        self.inner.client_minor_version = client_minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major_version: Default::default(),
+                client_minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -729,37 +1215,108 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_bytes(reader, &mut index, 16)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
         index += 16;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -772,40 +1329,69 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
-            + 16
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryPictFormatsRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryPictFormatsRequest {}
+impl QueryPictFormatsRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryPictFormatsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryPictFormatsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryPictFormatsRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryPictFormatsRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPictFormatsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryPictFormatsRequest {
                 req_type: req_type,
                 length: length,
@@ -815,16 +1401,51 @@ impl AsByteSequence for QueryPictFormatsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryPictFormatsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryPictFormatsRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryPictFormats";
     type Reply = QueryPictFormatsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryPictFormatsRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryPictFormatsRequestBuilder {
+    pub inner: QueryPictFormatsRequest,
+}
+impl QueryPictFormatsRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryPictFormatsRequest {
+        self.inner
+    }
+}
+impl Default for QueryPictFormatsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryPictFormatsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryPictFormatsReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -835,67 +1456,276 @@ pub struct QueryPictFormatsReply {
     pub screens: Vec<Pictscreen>,
     pub subpixels: Vec<Card32>,
 }
-impl QueryPictFormatsReply {}
-impl AsByteSequence for QueryPictFormatsReply {
+impl QueryPictFormatsReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.formats.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += (self.screens.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += self.num_depths.as_bytes(&mut bytes[index..]);
-        index += self.num_visuals.as_bytes(&mut bytes[index..]);
-        index += (self.subpixels.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 4;
-        let block_len: usize = vector_as_bytes(&self.formats, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
-        let block_len: usize = vector_as_bytes(&self.screens, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>());
-        let block_len: usize = vector_as_bytes(&self.subpixels, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub fn subpixels_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing QueryPictFormatsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let reply_end: usize = 32 + (length as usize) * 4;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (num_depths, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_depths, sz): (Card32, usize) = parse_item(bytes, index, "num_depths")?;
         index += sz;
-        let (num_visuals, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_visuals, sz): (Card32, usize) = parse_item(bytes, index, "num_visuals")?;
         index += sz;
-        let (len2, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 4;
-        let (formats, block_len): (Vec<Pictforminfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
-        let (screens, block_len): (Vec<Pictscreen>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>());
-        let (subpixels, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        let len2: Card32 = if index < reply_end {
+            let (len2, sz): (Card32, usize) = parse_item(bytes, index, "len2")?;
+            index += sz;
+            len2
+        } else {
+            Default::default()
+        };
+        if index < reply_end {
+            index += 4;
+        }
+        let formats: Vec<Pictforminfo> = if index < reply_end {
+            let (formats, block_len): (Vec<Pictforminfo>, usize) =
+                parse_vector(bytes, index, len0 as usize, "formats")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
+            formats
+        } else {
+            Default::default()
+        };
+        let screens: Vec<Pictscreen> = if index < reply_end {
+            let (screens, block_len): (Vec<Pictscreen>, usize) =
+                parse_vector(bytes, index, len1 as usize, "screens")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>());
+            screens
+        } else {
+            Default::default()
+        };
+        let (subpixels, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, len2 as usize, "subpixels")?;
+        Ok(subpixels)
+    }
+    pub fn formats_iter(bytes: &[u8]) -> Result<ListIter<'_, Pictforminfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let reply_end: usize = 32 + (length as usize) * 4;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (num_depths, sz): (Card32, usize) = parse_item(bytes, index, "num_depths")?;
+        index += sz;
+        let (num_visuals, sz): (Card32, usize) = parse_item(bytes, index, "num_visuals")?;
+        index += sz;
+        let len2: Card32 = if index < reply_end {
+            let (len2, sz): (Card32, usize) = parse_item(bytes, index, "len2")?;
+            index += sz;
+            len2
+        } else {
+            Default::default()
+        };
+        if index < reply_end {
+            index += 4;
+        }
+        Ok(ListIter::new(bytes, index, len0 as usize, "formats"))
+    }
+    pub fn screens_iter(bytes: &[u8]) -> Result<ListIter<'_, Pictscreen>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let reply_end: usize = 32 + (length as usize) * 4;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (num_depths, sz): (Card32, usize) = parse_item(bytes, index, "num_depths")?;
+        index += sz;
+        let (num_visuals, sz): (Card32, usize) = parse_item(bytes, index, "num_visuals")?;
+        index += sz;
+        let len2: Card32 = if index < reply_end {
+            let (len2, sz): (Card32, usize) = parse_item(bytes, index, "len2")?;
+            index += sz;
+            len2
+        } else {
+            Default::default()
+        };
+        if index < reply_end {
+            index += 4;
+        }
+        let formats: Vec<Pictforminfo> = if index < reply_end {
+            let (formats, block_len): (Vec<Pictforminfo>, usize) =
+                parse_vector(bytes, index, len0 as usize, "formats")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
+            formats
+        } else {
+            Default::default()
+        };
+        Ok(ListIter::new(bytes, index, len1 as usize, "screens"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("formats_len", 8, 4),
+        ("screens_len", 12, 4),
+        ("num_depths", 16, 4),
+        ("num_visuals", 20, 4),
+        ("subpixels_len", 24, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.num_depths == other.num_depths
+            && self.num_visuals == other.num_visuals
+            && self.formats == other.formats
+            && self.screens == other.screens
+            && self.subpixels == other.subpixels
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "num_depths",
+            &self.num_depths,
+            &other.num_depths,
+            &mut changes,
+        );
+        diff_value(
+            "num_visuals",
+            &self.num_visuals,
+            &other.num_visuals,
+            &mut changes,
+        );
+        diff_list("formats", &self.formats, &other.formats, &mut changes);
+        diff_list("screens", &self.screens, &other.screens, &mut changes);
+        diff_list("subpixels", &self.subpixels, &other.subpixels, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryPictFormatsReply {
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.reply_type.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 1);
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += (self.formats.len() as Card32).as_bytes(&mut bytes[index..]);
+        index += (self.screens.len() as Card32).as_bytes(&mut bytes[index..]);
+        index += self.num_depths.as_bytes(&mut bytes[index..]);
+        index += self.num_visuals.as_bytes(&mut bytes[index..]);
+        index += (self.subpixels.len() as Card32).as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 4);
+        let block_len: usize = vector_as_bytes(&self.formats, &mut bytes[index..]);
+        index += block_len;
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>()),
+        );
+        let block_len: usize = vector_as_bytes(&self.screens, &mut bytes[index..]);
+        index += block_len;
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>()),
+        );
+        let block_len: usize = vector_as_bytes(&self.subpixels, &mut bytes[index..]);
+        index += block_len;
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+        index
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing QueryPictFormatsReply from byte buffer");
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let reply_end: usize = 32 + (length as usize) * 4;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (num_depths, sz): (Card32, usize) = parse_item(bytes, index, "num_depths")?;
+        index += sz;
+        let (num_visuals, sz): (Card32, usize) = parse_item(bytes, index, "num_visuals")?;
+        index += sz;
+        let len2: Card32 = if index < reply_end {
+            let (len2, sz): (Card32, usize) = parse_item(bytes, index, "len2")?;
+            index += sz;
+            len2
+        } else {
+            Default::default()
+        };
+        if index < reply_end {
+            index += 4;
+        }
+        let formats: Vec<Pictforminfo> = if index < reply_end {
+            let (formats, block_len): (Vec<Pictforminfo>, usize) =
+                parse_vector(bytes, index, len0 as usize, "formats")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
+            formats
+        } else {
+            Default::default()
+        };
+        let screens: Vec<Pictscreen> = if index < reply_end {
+            let (screens, block_len): (Vec<Pictscreen>, usize) =
+                parse_vector(bytes, index, len1 as usize, "screens")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>());
+            screens
+        } else {
+            Default::default()
+        };
+        let subpixels: Vec<Card32> = if index < reply_end {
+            let (subpixels, block_len): (Vec<Card32>, usize) =
+                parse_vector(bytes, index, len2 as usize, "subpixels")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            subpixels
+        } else {
+            Default::default()
+        };
+        index += buffer_pad(index, 4);
+        Ok((
             QueryPictFormatsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -909,64 +1739,97 @@ impl AsByteSequence for QueryPictFormatsReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
-            + self.num_depths.size()
-            + self.num_visuals.size()
-            + ::core::mem::size_of::<Card32>()
-            + 4
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.formats.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.screens.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.subpixels.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            32 + {
+                let block_len: usize = self.formats.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.screens.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.subpixels.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryPictFormatsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryPictIndexValuesRequest {
     pub req_type: u8,
     pub length: u16,
     pub format: Pictformat,
 }
-impl QueryPictIndexValuesRequest {}
-impl AsByteSequence for QueryPictIndexValuesRequest {
+impl QueryPictIndexValuesRequest {
+    #[inline]
+    pub fn builder() -> QueryPictIndexValuesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryPictIndexValuesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("format", 4, 4)];
+}
+const _: () = assert!(<QueryPictIndexValuesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryPictIndexValuesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPictIndexValuesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryPictIndexValuesRequest {
                 req_type: req_type,
                 length: length,
@@ -977,57 +1840,174 @@ impl AsByteSequence for QueryPictIndexValuesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.format.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryPictIndexValuesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryPictIndexValuesRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryPictIndexValues";
     type Reply = QueryPictIndexValuesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryPictIndexValuesRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryPictIndexValuesRequestBuilder {
+    pub inner: QueryPictIndexValuesRequest,
+}
+impl QueryPictIndexValuesRequestBuilder {
+    #[inline]
+    pub fn format(mut self, format: Pictformat) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryPictIndexValuesRequest {
+        self.inner
+    }
+}
+impl Default for QueryPictIndexValuesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryPictIndexValuesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                format: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryPictIndexValuesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub values: Vec<Indexvalue>,
 }
-impl QueryPictIndexValuesReply {}
-impl AsByteSequence for QueryPictIndexValuesReply {
+impl QueryPictIndexValuesReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn values_iter(bytes: &[u8]) -> Result<ListIter<'_, Indexvalue>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "values"))
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (values, block_len): (Vec<Indexvalue>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 12, "values")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Indexvalue>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryPictIndexValuesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            values: values,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("values_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("values", &self.values, &other.values, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryPictIndexValuesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.values.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.values, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Indexvalue>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Indexvalue>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPictIndexValuesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (values, block_len): (Vec<Indexvalue>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "values")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Indexvalue>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryPictIndexValuesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1039,20 +2019,25 @@ impl AsByteSequence for QueryPictIndexValuesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.values.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Indexvalue>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.values.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Indexvalue>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryPictIndexValuesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreatePictureRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1060,33 +2045,236 @@ pub struct CreatePictureRequest {
     pub drawable: Drawable,
     pub format: Pictformat,
     pub value_mask: Cp,
-    pub repeat: Repeat,
-    pub alphamap: Picture,
-    pub alphaxorigin: Int32,
-    pub alphayorigin: Int32,
-    pub clipxorigin: Int32,
-    pub clipyorigin: Int32,
-    pub clipmask: Pixmap,
-    pub graphicsexposure: Card32,
-    pub subwindowmode: SubwindowMode,
-    pub polyedge: PolyEdge,
-    pub polymode: PolyMode,
-    pub dither: Atom,
-    pub componentalpha: Card32,
-}
-impl CreatePictureRequest {}
-impl AsByteSequence for CreatePictureRequest {
+    pub repeat: Option<Repeat>,
+    pub alphamap: Option<Picture>,
+    pub alphaxorigin: Option<Int32>,
+    pub alphayorigin: Option<Int32>,
+    pub clipxorigin: Option<Int32>,
+    pub clipyorigin: Option<Int32>,
+    pub clipmask: Option<Pixmap>,
+    pub graphicsexposure: Option<Card32>,
+    pub subwindowmode: Option<SubwindowMode>,
+    pub polyedge: Option<PolyEdge>,
+    pub polymode: Option<PolyMode>,
+    pub dither: Option<Atom>,
+    pub componentalpha: Option<Card32>,
+}
+impl CreatePictureRequest {
+    #[inline]
+    pub fn builder() -> CreatePictureRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("pid", 4, 4)];
+    #[doc = " Set `repeat`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_repeat(&mut self, repeat: Repeat) -> &mut Self {
+        self.value_mask.set_repeat(true);
+        self.repeat = Some(repeat);
+        self
+    }
+    #[doc = " Leave out `repeat`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_repeat(&mut self) -> &mut Self {
+        self.value_mask.set_repeat(false);
+        self.repeat = None;
+        self
+    }
+    #[doc = " Set `alphamap`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_alphamap(&mut self, alphamap: Picture) -> &mut Self {
+        self.value_mask.set_alpha_map(true);
+        self.alphamap = Some(alphamap);
+        self
+    }
+    #[doc = " Leave out `alphamap`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_alphamap(&mut self) -> &mut Self {
+        self.value_mask.set_alpha_map(false);
+        self.alphamap = None;
+        self
+    }
+    #[doc = " Set `alphaxorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_alphaxorigin(&mut self, alphaxorigin: Int32) -> &mut Self {
+        self.value_mask.set_alpha_x_origin(true);
+        self.alphaxorigin = Some(alphaxorigin);
+        self
+    }
+    #[doc = " Leave out `alphaxorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_alphaxorigin(&mut self) -> &mut Self {
+        self.value_mask.set_alpha_x_origin(false);
+        self.alphaxorigin = None;
+        self
+    }
+    #[doc = " Set `alphayorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_alphayorigin(&mut self, alphayorigin: Int32) -> &mut Self {
+        self.value_mask.set_alpha_y_origin(true);
+        self.alphayorigin = Some(alphayorigin);
+        self
+    }
+    #[doc = " Leave out `alphayorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_alphayorigin(&mut self) -> &mut Self {
+        self.value_mask.set_alpha_y_origin(false);
+        self.alphayorigin = None;
+        self
+    }
+    #[doc = " Set `clipxorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_clipxorigin(&mut self, clipxorigin: Int32) -> &mut Self {
+        self.value_mask.set_clip_x_origin(true);
+        self.clipxorigin = Some(clipxorigin);
+        self
+    }
+    #[doc = " Leave out `clipxorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_clipxorigin(&mut self) -> &mut Self {
+        self.value_mask.set_clip_x_origin(false);
+        self.clipxorigin = None;
+        self
+    }
+    #[doc = " Set `clipyorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_clipyorigin(&mut self, clipyorigin: Int32) -> &mut Self {
+        self.value_mask.set_clip_y_origin(true);
+        self.clipyorigin = Some(clipyorigin);
+        self
+    }
+    #[doc = " Leave out `clipyorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_clipyorigin(&mut self) -> &mut Self {
+        self.value_mask.set_clip_y_origin(false);
+        self.clipyorigin = None;
+        self
+    }
+    #[doc = " Set `clipmask`, and set the bit of `value_mask` that says it is present."]
     #[inline]
+    pub fn set_clipmask(&mut self, clipmask: Pixmap) -> &mut Self {
+        self.value_mask.set_clip_mask(true);
+        self.clipmask = Some(clipmask);
+        self
+    }
+    #[doc = " Leave out `clipmask`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_clipmask(&mut self) -> &mut Self {
+        self.value_mask.set_clip_mask(false);
+        self.clipmask = None;
+        self
+    }
+    #[doc = " Set `graphicsexposure`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_graphicsexposure(&mut self, graphicsexposure: Card32) -> &mut Self {
+        self.value_mask.set_graphics_exposure(true);
+        self.graphicsexposure = Some(graphicsexposure);
+        self
+    }
+    #[doc = " Leave out `graphicsexposure`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_graphicsexposure(&mut self) -> &mut Self {
+        self.value_mask.set_graphics_exposure(false);
+        self.graphicsexposure = None;
+        self
+    }
+    #[doc = " Set `subwindowmode`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_subwindowmode(&mut self, subwindowmode: SubwindowMode) -> &mut Self {
+        self.value_mask.set_subwindow_mode(true);
+        self.subwindowmode = Some(subwindowmode);
+        self
+    }
+    #[doc = " Leave out `subwindowmode`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_subwindowmode(&mut self) -> &mut Self {
+        self.value_mask.set_subwindow_mode(false);
+        self.subwindowmode = None;
+        self
+    }
+    #[doc = " Set `polyedge`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_polyedge(&mut self, polyedge: PolyEdge) -> &mut Self {
+        self.value_mask.set_poly_edge(true);
+        self.polyedge = Some(polyedge);
+        self
+    }
+    #[doc = " Leave out `polyedge`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_polyedge(&mut self) -> &mut Self {
+        self.value_mask.set_poly_edge(false);
+        self.polyedge = None;
+        self
+    }
+    #[doc = " Set `polymode`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_polymode(&mut self, polymode: PolyMode) -> &mut Self {
+        self.value_mask.set_poly_mode(true);
+        self.polymode = Some(polymode);
+        self
+    }
+    #[doc = " Leave out `polymode`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_polymode(&mut self) -> &mut Self {
+        self.value_mask.set_poly_mode(false);
+        self.polymode = None;
+        self
+    }
+    #[doc = " Set `dither`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_dither(&mut self, dither: Atom) -> &mut Self {
+        self.value_mask.set_dither(true);
+        self.dither = Some(dither);
+        self
+    }
+    #[doc = " Leave out `dither`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_dither(&mut self) -> &mut Self {
+        self.value_mask.set_dither(false);
+        self.dither = None;
+        self
+    }
+    #[doc = " Set `componentalpha`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_componentalpha(&mut self, componentalpha: Card32) -> &mut Self {
+        self.value_mask.set_component_alpha(true);
+        self.componentalpha = Some(componentalpha);
+        self
+    }
+    #[doc = " Leave out `componentalpha`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_componentalpha(&mut self) -> &mut Self {
+        self.value_mask.set_component_alpha(false);
+        self.componentalpha = None;
+        self
+    }
+}
+const _: () = assert!(<CreatePictureRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreatePictureRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.pid.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
-        index += self.value_mask.as_bytes(&mut bytes[index..]);
-        let cond0 = (self.value_mask);
+        let mut cond0: Cp = self.value_mask;
+        cond0.set_repeat(self.repeat.is_some());
+        cond0.set_alpha_map(self.alphamap.is_some());
+        cond0.set_alpha_x_origin(self.alphaxorigin.is_some());
+        cond0.set_alpha_y_origin(self.alphayorigin.is_some());
+        cond0.set_clip_x_origin(self.clipxorigin.is_some());
+        cond0.set_clip_y_origin(self.clipyorigin.is_some());
+        cond0.set_clip_mask(self.clipmask.is_some());
+        cond0.set_graphics_exposure(self.graphicsexposure.is_some());
+        cond0.set_subwindow_mode(self.subwindowmode.is_some());
+        cond0.set_poly_edge(self.polyedge.is_some());
+        cond0.set_poly_mode(self.polymode.is_some());
+        cond0.set_dither(self.dither.is_some());
+        cond0.set_component_alpha(self.componentalpha.is_some());
+        index += cond0.as_bytes(&mut bytes[index..]);
         if cond0.repeat() {
             index += self.repeat.as_bytes(&mut bytes[index..]);
         }
@@ -1126,119 +2314,121 @@ impl AsByteSequence for CreatePictureRequest {
         if cond0.component_alpha() {
             index += self.componentalpha.as_bytes(&mut bytes[index..]);
         }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pid, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (pid, sz): (Picture, usize) = parse_item(bytes, index, "pid")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        let (value_mask, sz): (Cp, usize) = <Cp>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cp, usize) = parse_item(bytes, index, "value_mask")?;
         index += sz;
         let cond0 = (value_mask);
-        let repeat: Repeat = if cond0.repeat() {
-            let (repeat, sz): (Repeat, usize) = <Repeat>::from_bytes(&bytes[index..])?;
+        let (repeat,) = if cond0.repeat() {
+            let (repeat, sz): (Repeat, usize) = parse_item(bytes, index, "repeat")?;
             index += sz;
-            repeat
+            (Some(repeat),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let alphamap: Picture = if cond0.alpha_map() {
-            let (alphamap, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (alphamap,) = if cond0.alpha_map() {
+            let (alphamap, sz): (Picture, usize) = parse_item(bytes, index, "alphamap")?;
             index += sz;
-            alphamap
+            (Some(alphamap),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let alphaxorigin: Int32 = if cond0.alpha_x_origin() {
-            let (alphaxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (alphaxorigin,) = if cond0.alpha_x_origin() {
+            let (alphaxorigin, sz): (Int32, usize) = parse_item(bytes, index, "alphaxorigin")?;
             index += sz;
-            alphaxorigin
+            (Some(alphaxorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let alphayorigin: Int32 = if cond0.alpha_y_origin() {
-            let (alphayorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (alphayorigin,) = if cond0.alpha_y_origin() {
+            let (alphayorigin, sz): (Int32, usize) = parse_item(bytes, index, "alphayorigin")?;
             index += sz;
-            alphayorigin
+            (Some(alphayorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let clipxorigin: Int32 = if cond0.clip_x_origin() {
-            let (clipxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (clipxorigin,) = if cond0.clip_x_origin() {
+            let (clipxorigin, sz): (Int32, usize) = parse_item(bytes, index, "clipxorigin")?;
             index += sz;
-            clipxorigin
+            (Some(clipxorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let clipyorigin: Int32 = if cond0.clip_y_origin() {
-            let (clipyorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (clipyorigin,) = if cond0.clip_y_origin() {
+            let (clipyorigin, sz): (Int32, usize) = parse_item(bytes, index, "clipyorigin")?;
             index += sz;
-            clipyorigin
+            (Some(clipyorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let clipmask: Pixmap = if cond0.clip_mask() {
-            let (clipmask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (clipmask,) = if cond0.clip_mask() {
+            let (clipmask, sz): (Pixmap, usize) = parse_item(bytes, index, "clipmask")?;
             index += sz;
-            clipmask
+            (Some(clipmask),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let graphicsexposure: Card32 = if cond0.graphics_exposure() {
-            let (graphicsexposure, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (graphicsexposure,) = if cond0.graphics_exposure() {
+            let (graphicsexposure, sz): (Card32, usize) =
+                parse_item(bytes, index, "graphicsexposure")?;
             index += sz;
-            graphicsexposure
+            (Some(graphicsexposure),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let subwindowmode: SubwindowMode = if cond0.subwindow_mode() {
+        let (subwindowmode,) = if cond0.subwindow_mode() {
             let (subwindowmode, sz): (SubwindowMode, usize) =
-                <SubwindowMode>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "subwindowmode")?;
             index += sz;
-            subwindowmode
+            (Some(subwindowmode),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let polyedge: PolyEdge = if cond0.poly_edge() {
-            let (polyedge, sz): (PolyEdge, usize) = <PolyEdge>::from_bytes(&bytes[index..])?;
+        let (polyedge,) = if cond0.poly_edge() {
+            let (polyedge, sz): (PolyEdge, usize) = parse_item(bytes, index, "polyedge")?;
             index += sz;
-            polyedge
+            (Some(polyedge),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let polymode: PolyMode = if cond0.poly_mode() {
-            let (polymode, sz): (PolyMode, usize) = <PolyMode>::from_bytes(&bytes[index..])?;
+        let (polymode,) = if cond0.poly_mode() {
+            let (polymode, sz): (PolyMode, usize) = parse_item(bytes, index, "polymode")?;
             index += sz;
-            polymode
+            (Some(polymode),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let dither: Atom = if cond0.dither() {
-            let (dither, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (dither,) = if cond0.dither() {
+            let (dither, sz): (Atom, usize) = parse_item(bytes, index, "dither")?;
             index += sz;
-            dither
+            (Some(dither),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let componentalpha: Card32 = if cond0.component_alpha() {
-            let (componentalpha, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (componentalpha,) = if cond0.component_alpha() {
+            let (componentalpha, sz): (Card32, usize) = parse_item(bytes, index, "componentalpha")?;
             index += sz;
-            componentalpha
+            (Some(componentalpha),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreatePictureRequest {
                 req_type: req_type,
                 length: length,
@@ -1263,15 +2453,9 @@ impl AsByteSequence for CreatePictureRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.pid.size()
+        let size: usize = 16
             + self.drawable.size()
-            + self.format.size()
-            + self.value_mask.size()
             + self.repeat.size()
             + self.alphamap.size()
             + self.alphaxorigin.size()
@@ -1284,17 +2468,167 @@ impl AsByteSequence for CreatePictureRequest {
             + self.polyedge.size()
             + self.polymode.size()
             + self.dither.size()
-            + self.componentalpha.size()
+            + self.componentalpha.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreatePictureRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreatePictureRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePicture";
     type Reply = ();
 }
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[doc = " A builder for `CreatePictureRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreatePictureRequestBuilder {
+    pub inner: CreatePictureRequest,
+}
+impl CreatePictureRequestBuilder {
+    #[inline]
+    pub fn pid(mut self, pid: Picture) -> Self {
+        self.inner.pid = pid;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn format(mut self, format: Pictformat) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn value_mask(mut self, value_mask: Cp) -> Self {
+        self.inner.value_mask = value_mask;
+        self
+    }
+    #[inline]
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.inner.value_mask.set_repeat(true);
+        self.inner.repeat = Some(repeat);
+        self
+    }
+    #[inline]
+    pub fn alphamap(mut self, alphamap: Picture) -> Self {
+        self.inner.value_mask.set_alpha_map(true);
+        self.inner.alphamap = Some(alphamap);
+        self
+    }
+    #[inline]
+    pub fn alphaxorigin(mut self, alphaxorigin: Int32) -> Self {
+        self.inner.value_mask.set_alpha_x_origin(true);
+        self.inner.alphaxorigin = Some(alphaxorigin);
+        self
+    }
+    #[inline]
+    pub fn alphayorigin(mut self, alphayorigin: Int32) -> Self {
+        self.inner.value_mask.set_alpha_y_origin(true);
+        self.inner.alphayorigin = Some(alphayorigin);
+        self
+    }
+    #[inline]
+    pub fn clipxorigin(mut self, clipxorigin: Int32) -> Self {
+        self.inner.value_mask.set_clip_x_origin(true);
+        self.inner.clipxorigin = Some(clipxorigin);
+        self
+    }
+    #[inline]
+    pub fn clipyorigin(mut self, clipyorigin: Int32) -> Self {
+        self.inner.value_mask.set_clip_y_origin(true);
+        self.inner.clipyorigin = Some(clipyorigin);
+        self
+    }
+    #[inline]
+    pub fn clipmask(mut self, clipmask: Pixmap) -> Self {
+        self.inner.value_mask.set_clip_mask(true);
+        self.inner.clipmask = Some(clipmask);
+        self
+    }
+    #[inline]
+    pub fn graphicsexposure(mut self, graphicsexposure: Card32) -> Self {
+        self.inner.value_mask.set_graphics_exposure(true);
+        self.inner.graphicsexposure = Some(graphicsexposure);
+        self
+    }
+    #[inline]
+    pub fn subwindowmode(mut self, subwindowmode: SubwindowMode) -> Self {
+        self.inner.value_mask.set_subwindow_mode(true);
+        self.inner.subwindowmode = Some(subwindowmode);
+        self
+    }
+    #[inline]
+    pub fn polyedge(mut self, polyedge: PolyEdge) -> Self {
+        self.inner.value_mask.set_poly_edge(true);
+        self.inner.polyedge = Some(polyedge);
+        self
+    }
+    #[inline]
+    pub fn polymode(mut self, polymode: PolyMode) -> Self {
+        self.inner.value_mask.set_poly_mode(true);
+        self.inner.polymode = Some(polymode);
+        self
+    }
+    #[inline]
+    pub fn dither(mut self, dither: Atom) -> Self {
+        self.inner.value_mask.set_dither(true);
+        self.inner.dither = Some(dither);
+        self
+    }
+    #[inline]
+    pub fn componentalpha(mut self, componentalpha: Card32) -> Self {
+        self.inner.value_mask.set_component_alpha(true);
+        self.inner.componentalpha = Some(componentalpha);
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreatePictureRequest {
+        self.inner
+    }
+}
+impl Default for CreatePictureRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreatePictureRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                pid: Default::default(),
+                drawable: Default::default(),
+                format: Default::default(),
+                value_mask: Default::default(),
+                repeat: Default::default(),
+                alphamap: Default::default(),
+                alphaxorigin: Default::default(),
+                alphayorigin: Default::default(),
+                clipxorigin: Default::default(),
+                clipyorigin: Default::default(),
+                clipmask: Default::default(),
+                graphicsexposure: Default::default(),
+                subwindowmode: Default::default(),
+                polyedge: Default::default(),
+                polymode: Default::default(),
+                dither: Default::default(),
+                componentalpha: Default::default(),
+            },
+        }
+    }
+}
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Cp {
     pub inner: u32,
 }
@@ -1468,7 +2802,6 @@ impl Cp {
         }
         self
     }
-    #[inline]
     pub fn new(
         repeat: bool,
         alpha_map: bool,
@@ -1545,19 +2878,39 @@ impl Cp {
     pub const COMPONENT_ALPHA: Self = Self { inner: 4096 };
     pub const COMPLETE: Self = Self { inner: 8191 };
 }
+impl core::ops::Deref for Cp {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &u32 {
+        &self.inner
+    }
+}
+impl AsRef<u32> for Cp {
+    #[inline]
+    fn as_ref(&self) -> &u32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for Cp {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((Cp { inner: inner }, sz))
+        Ok((Cp { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Cp {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for Cp {
@@ -1594,37 +2947,247 @@ impl core::ops::BitXor for Cp {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangePictureRequest {
     pub req_type: u8,
     pub length: u16,
     pub picture: Picture,
     pub value_mask: Cp,
-    pub repeat: Repeat,
-    pub alphamap: Picture,
-    pub alphaxorigin: Int32,
-    pub alphayorigin: Int32,
-    pub clipxorigin: Int32,
-    pub clipyorigin: Int32,
-    pub clipmask: Pixmap,
-    pub graphicsexposure: Card32,
-    pub subwindowmode: SubwindowMode,
-    pub polyedge: PolyEdge,
-    pub polymode: PolyMode,
-    pub dither: Atom,
-    pub componentalpha: Card32,
-}
-impl ChangePictureRequest {}
-impl AsByteSequence for ChangePictureRequest {
+    pub repeat: Option<Repeat>,
+    pub alphamap: Option<Picture>,
+    pub alphaxorigin: Option<Int32>,
+    pub alphayorigin: Option<Int32>,
+    pub clipxorigin: Option<Int32>,
+    pub clipyorigin: Option<Int32>,
+    pub clipmask: Option<Pixmap>,
+    pub graphicsexposure: Option<Card32>,
+    pub subwindowmode: Option<SubwindowMode>,
+    pub polyedge: Option<PolyEdge>,
+    pub polymode: Option<PolyMode>,
+    pub dither: Option<Atom>,
+    pub componentalpha: Option<Card32>,
+}
+impl ChangePictureRequest {
+    #[inline]
+    pub fn builder() -> ChangePictureRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("value_mask", 8, 4),
+    ];
+    #[doc = " Set `repeat`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_repeat(&mut self, repeat: Repeat) -> &mut Self {
+        self.value_mask.set_repeat(true);
+        self.repeat = Some(repeat);
+        self
+    }
+    #[doc = " Leave out `repeat`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_repeat(&mut self) -> &mut Self {
+        self.value_mask.set_repeat(false);
+        self.repeat = None;
+        self
+    }
+    #[doc = " Set `alphamap`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_alphamap(&mut self, alphamap: Picture) -> &mut Self {
+        self.value_mask.set_alpha_map(true);
+        self.alphamap = Some(alphamap);
+        self
+    }
+    #[doc = " Leave out `alphamap`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_alphamap(&mut self) -> &mut Self {
+        self.value_mask.set_alpha_map(false);
+        self.alphamap = None;
+        self
+    }
+    #[doc = " Set `alphaxorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_alphaxorigin(&mut self, alphaxorigin: Int32) -> &mut Self {
+        self.value_mask.set_alpha_x_origin(true);
+        self.alphaxorigin = Some(alphaxorigin);
+        self
+    }
+    #[doc = " Leave out `alphaxorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_alphaxorigin(&mut self) -> &mut Self {
+        self.value_mask.set_alpha_x_origin(false);
+        self.alphaxorigin = None;
+        self
+    }
+    #[doc = " Set `alphayorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_alphayorigin(&mut self, alphayorigin: Int32) -> &mut Self {
+        self.value_mask.set_alpha_y_origin(true);
+        self.alphayorigin = Some(alphayorigin);
+        self
+    }
+    #[doc = " Leave out `alphayorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_alphayorigin(&mut self) -> &mut Self {
+        self.value_mask.set_alpha_y_origin(false);
+        self.alphayorigin = None;
+        self
+    }
+    #[doc = " Set `clipxorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_clipxorigin(&mut self, clipxorigin: Int32) -> &mut Self {
+        self.value_mask.set_clip_x_origin(true);
+        self.clipxorigin = Some(clipxorigin);
+        self
+    }
+    #[doc = " Leave out `clipxorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_clipxorigin(&mut self) -> &mut Self {
+        self.value_mask.set_clip_x_origin(false);
+        self.clipxorigin = None;
+        self
+    }
+    #[doc = " Set `clipyorigin`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_clipyorigin(&mut self, clipyorigin: Int32) -> &mut Self {
+        self.value_mask.set_clip_y_origin(true);
+        self.clipyorigin = Some(clipyorigin);
+        self
+    }
+    #[doc = " Leave out `clipyorigin`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_clipyorigin(&mut self) -> &mut Self {
+        self.value_mask.set_clip_y_origin(false);
+        self.clipyorigin = None;
+        self
+    }
+    #[doc = " Set `clipmask`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_clipmask(&mut self, clipmask: Pixmap) -> &mut Self {
+        self.value_mask.set_clip_mask(true);
+        self.clipmask = Some(clipmask);
+        self
+    }
+    #[doc = " Leave out `clipmask`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_clipmask(&mut self) -> &mut Self {
+        self.value_mask.set_clip_mask(false);
+        self.clipmask = None;
+        self
+    }
+    #[doc = " Set `graphicsexposure`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_graphicsexposure(&mut self, graphicsexposure: Card32) -> &mut Self {
+        self.value_mask.set_graphics_exposure(true);
+        self.graphicsexposure = Some(graphicsexposure);
+        self
+    }
+    #[doc = " Leave out `graphicsexposure`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_graphicsexposure(&mut self) -> &mut Self {
+        self.value_mask.set_graphics_exposure(false);
+        self.graphicsexposure = None;
+        self
+    }
+    #[doc = " Set `subwindowmode`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_subwindowmode(&mut self, subwindowmode: SubwindowMode) -> &mut Self {
+        self.value_mask.set_subwindow_mode(true);
+        self.subwindowmode = Some(subwindowmode);
+        self
+    }
+    #[doc = " Leave out `subwindowmode`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_subwindowmode(&mut self) -> &mut Self {
+        self.value_mask.set_subwindow_mode(false);
+        self.subwindowmode = None;
+        self
+    }
+    #[doc = " Set `polyedge`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_polyedge(&mut self, polyedge: PolyEdge) -> &mut Self {
+        self.value_mask.set_poly_edge(true);
+        self.polyedge = Some(polyedge);
+        self
+    }
+    #[doc = " Leave out `polyedge`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_polyedge(&mut self) -> &mut Self {
+        self.value_mask.set_poly_edge(false);
+        self.polyedge = None;
+        self
+    }
+    #[doc = " Set `polymode`, and set the bit of `value_mask` that says it is present."]
     #[inline]
+    pub fn set_polymode(&mut self, polymode: PolyMode) -> &mut Self {
+        self.value_mask.set_poly_mode(true);
+        self.polymode = Some(polymode);
+        self
+    }
+    #[doc = " Leave out `polymode`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_polymode(&mut self) -> &mut Self {
+        self.value_mask.set_poly_mode(false);
+        self.polymode = None;
+        self
+    }
+    #[doc = " Set `dither`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_dither(&mut self, dither: Atom) -> &mut Self {
+        self.value_mask.set_dither(true);
+        self.dither = Some(dither);
+        self
+    }
+    #[doc = " Leave out `dither`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_dither(&mut self) -> &mut Self {
+        self.value_mask.set_dither(false);
+        self.dither = None;
+        self
+    }
+    #[doc = " Set `componentalpha`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_componentalpha(&mut self, componentalpha: Card32) -> &mut Self {
+        self.value_mask.set_component_alpha(true);
+        self.componentalpha = Some(componentalpha);
+        self
+    }
+    #[doc = " Leave out `componentalpha`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_componentalpha(&mut self) -> &mut Self {
+        self.value_mask.set_component_alpha(false);
+        self.componentalpha = None;
+        self
+    }
+}
+const _: () = assert!(<ChangePictureRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ChangePictureRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
-        index += self.value_mask.as_bytes(&mut bytes[index..]);
-        let cond0 = (self.value_mask);
+        let mut cond0: Cp = self.value_mask;
+        cond0.set_repeat(self.repeat.is_some());
+        cond0.set_alpha_map(self.alphamap.is_some());
+        cond0.set_alpha_x_origin(self.alphaxorigin.is_some());
+        cond0.set_alpha_y_origin(self.alphayorigin.is_some());
+        cond0.set_clip_x_origin(self.clipxorigin.is_some());
+        cond0.set_clip_y_origin(self.clipyorigin.is_some());
+        cond0.set_clip_mask(self.clipmask.is_some());
+        cond0.set_graphics_exposure(self.graphicsexposure.is_some());
+        cond0.set_subwindow_mode(self.subwindowmode.is_some());
+        cond0.set_poly_edge(self.polyedge.is_some());
+        cond0.set_poly_mode(self.polymode.is_some());
+        cond0.set_dither(self.dither.is_some());
+        cond0.set_component_alpha(self.componentalpha.is_some());
+        index += cond0.as_bytes(&mut bytes[index..]);
         if cond0.repeat() {
             index += self.repeat.as_bytes(&mut bytes[index..]);
         }
@@ -1664,115 +3227,117 @@ impl AsByteSequence for ChangePictureRequest {
         if cond0.component_alpha() {
             index += self.componentalpha.as_bytes(&mut bytes[index..]);
         }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangePictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (value_mask, sz): (Cp, usize) = <Cp>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cp, usize) = parse_item(bytes, index, "value_mask")?;
         index += sz;
         let cond0 = (value_mask);
-        let repeat: Repeat = if cond0.repeat() {
-            let (repeat, sz): (Repeat, usize) = <Repeat>::from_bytes(&bytes[index..])?;
+        let (repeat,) = if cond0.repeat() {
+            let (repeat, sz): (Repeat, usize) = parse_item(bytes, index, "repeat")?;
             index += sz;
-            repeat
+            (Some(repeat),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let alphamap: Picture = if cond0.alpha_map() {
-            let (alphamap, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (alphamap,) = if cond0.alpha_map() {
+            let (alphamap, sz): (Picture, usize) = parse_item(bytes, index, "alphamap")?;
             index += sz;
-            alphamap
+            (Some(alphamap),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let alphaxorigin: Int32 = if cond0.alpha_x_origin() {
-            let (alphaxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (alphaxorigin,) = if cond0.alpha_x_origin() {
+            let (alphaxorigin, sz): (Int32, usize) = parse_item(bytes, index, "alphaxorigin")?;
             index += sz;
-            alphaxorigin
+            (Some(alphaxorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let alphayorigin: Int32 = if cond0.alpha_y_origin() {
-            let (alphayorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (alphayorigin,) = if cond0.alpha_y_origin() {
+            let (alphayorigin, sz): (Int32, usize) = parse_item(bytes, index, "alphayorigin")?;
             index += sz;
-            alphayorigin
+            (Some(alphayorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let clipxorigin: Int32 = if cond0.clip_x_origin() {
-            let (clipxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (clipxorigin,) = if cond0.clip_x_origin() {
+            let (clipxorigin, sz): (Int32, usize) = parse_item(bytes, index, "clipxorigin")?;
             index += sz;
-            clipxorigin
+            (Some(clipxorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let clipyorigin: Int32 = if cond0.clip_y_origin() {
-            let (clipyorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (clipyorigin,) = if cond0.clip_y_origin() {
+            let (clipyorigin, sz): (Int32, usize) = parse_item(bytes, index, "clipyorigin")?;
             index += sz;
-            clipyorigin
+            (Some(clipyorigin),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let clipmask: Pixmap = if cond0.clip_mask() {
-            let (clipmask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (clipmask,) = if cond0.clip_mask() {
+            let (clipmask, sz): (Pixmap, usize) = parse_item(bytes, index, "clipmask")?;
             index += sz;
-            clipmask
+            (Some(clipmask),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let graphicsexposure: Card32 = if cond0.graphics_exposure() {
-            let (graphicsexposure, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (graphicsexposure,) = if cond0.graphics_exposure() {
+            let (graphicsexposure, sz): (Card32, usize) =
+                parse_item(bytes, index, "graphicsexposure")?;
             index += sz;
-            graphicsexposure
+            (Some(graphicsexposure),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let subwindowmode: SubwindowMode = if cond0.subwindow_mode() {
+        let (subwindowmode,) = if cond0.subwindow_mode() {
             let (subwindowmode, sz): (SubwindowMode, usize) =
-                <SubwindowMode>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "subwindowmode")?;
             index += sz;
-            subwindowmode
+            (Some(subwindowmode),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let polyedge: PolyEdge = if cond0.poly_edge() {
-            let (polyedge, sz): (PolyEdge, usize) = <PolyEdge>::from_bytes(&bytes[index..])?;
+        let (polyedge,) = if cond0.poly_edge() {
+            let (polyedge, sz): (PolyEdge, usize) = parse_item(bytes, index, "polyedge")?;
             index += sz;
-            polyedge
+            (Some(polyedge),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let polymode: PolyMode = if cond0.poly_mode() {
-            let (polymode, sz): (PolyMode, usize) = <PolyMode>::from_bytes(&bytes[index..])?;
+        let (polymode,) = if cond0.poly_mode() {
+            let (polymode, sz): (PolyMode, usize) = parse_item(bytes, index, "polymode")?;
             index += sz;
-            polymode
+            (Some(polymode),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let dither: Atom = if cond0.dither() {
-            let (dither, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (dither,) = if cond0.dither() {
+            let (dither, sz): (Atom, usize) = parse_item(bytes, index, "dither")?;
             index += sz;
-            dither
+            (Some(dither),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let componentalpha: Card32 = if cond0.component_alpha() {
-            let (componentalpha, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (componentalpha,) = if cond0.component_alpha() {
+            let (componentalpha, sz): (Card32, usize) = parse_item(bytes, index, "componentalpha")?;
             index += sz;
-            componentalpha
+            (Some(componentalpha),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ChangePictureRequest {
                 req_type: req_type,
                 length: length,
@@ -1795,13 +3360,8 @@ impl AsByteSequence for ChangePictureRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + self.value_mask.size()
+        let size: usize = 12
             + self.repeat.size()
             + self.alphamap.size()
             + self.alphaxorigin.size()
@@ -1814,16 +3374,155 @@ impl AsByteSequence for ChangePictureRequest {
             + self.polyedge.size()
             + self.polymode.size()
             + self.dither.size()
-            + self.componentalpha.size()
+            + self.componentalpha.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ChangePictureRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ChangePictureRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangePicture";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ChangePictureRequest`."]
+#[derive(Clone, Debug)]
+pub struct ChangePictureRequestBuilder {
+    pub inner: ChangePictureRequest,
+}
+impl ChangePictureRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn value_mask(mut self, value_mask: Cp) -> Self {
+        self.inner.value_mask = value_mask;
+        self
+    }
+    #[inline]
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.inner.value_mask.set_repeat(true);
+        self.inner.repeat = Some(repeat);
+        self
+    }
+    #[inline]
+    pub fn alphamap(mut self, alphamap: Picture) -> Self {
+        self.inner.value_mask.set_alpha_map(true);
+        self.inner.alphamap = Some(alphamap);
+        self
+    }
+    #[inline]
+    pub fn alphaxorigin(mut self, alphaxorigin: Int32) -> Self {
+        self.inner.value_mask.set_alpha_x_origin(true);
+        self.inner.alphaxorigin = Some(alphaxorigin);
+        self
+    }
+    #[inline]
+    pub fn alphayorigin(mut self, alphayorigin: Int32) -> Self {
+        self.inner.value_mask.set_alpha_y_origin(true);
+        self.inner.alphayorigin = Some(alphayorigin);
+        self
+    }
+    #[inline]
+    pub fn clipxorigin(mut self, clipxorigin: Int32) -> Self {
+        self.inner.value_mask.set_clip_x_origin(true);
+        self.inner.clipxorigin = Some(clipxorigin);
+        self
+    }
+    #[inline]
+    pub fn clipyorigin(mut self, clipyorigin: Int32) -> Self {
+        self.inner.value_mask.set_clip_y_origin(true);
+        self.inner.clipyorigin = Some(clipyorigin);
+        self
+    }
+    #[inline]
+    pub fn clipmask(mut self, clipmask: Pixmap) -> Self {
+        self.inner.value_mask.set_clip_mask(true);
+        self.inner.clipmask = Some(clipmask);
+        self
+    }
+    #[inline]
+    pub fn graphicsexposure(mut self, graphicsexposure: Card32) -> Self {
+        self.inner.value_mask.set_graphics_exposure(true);
+        self.inner.graphicsexposure = Some(graphicsexposure);
+        self
+    }
+    #[inline]
+    pub fn subwindowmode(mut self, subwindowmode: SubwindowMode) -> Self {
+        self.inner.value_mask.set_subwindow_mode(true);
+        self.inner.subwindowmode = Some(subwindowmode);
+        self
+    }
+    #[inline]
+    pub fn polyedge(mut self, polyedge: PolyEdge) -> Self {
+        self.inner.value_mask.set_poly_edge(true);
+        self.inner.polyedge = Some(polyedge);
+        self
+    }
+    #[inline]
+    pub fn polymode(mut self, polymode: PolyMode) -> Self {
+        self.inner.value_mask.set_poly_mode(true);
+        self.inner.polymode = Some(polymode);
+        self
+    }
+    #[inline]
+    pub fn dither(mut self, dither: Atom) -> Self {
+        self.inner.value_mask.set_dither(true);
+        self.inner.dither = Some(dither);
+        self
+    }
+    #[inline]
+    pub fn componentalpha(mut self, componentalpha: Card32) -> Self {
+        self.inner.value_mask.set_component_alpha(true);
+        self.inner.componentalpha = Some(componentalpha);
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangePictureRequest {
+        self.inner
+    }
+}
+impl Default for ChangePictureRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangePictureRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                value_mask: Default::default(),
+                repeat: Default::default(),
+                alphamap: Default::default(),
+                alphaxorigin: Default::default(),
+                alphayorigin: Default::default(),
+                clipxorigin: Default::default(),
+                clipyorigin: Default::default(),
+                clipmask: Default::default(),
+                graphicsexposure: Default::default(),
+                subwindowmode: Default::default(),
+                polyedge: Default::default(),
+                polymode: Default::default(),
+                dither: Default::default(),
+                componentalpha: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPictureClipRectanglesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1832,42 +3531,66 @@ pub struct SetPictureClipRectanglesRequest {
     pub clip_y_origin: Int16,
     pub rectangles: Vec<Rectangle>,
 }
-impl SetPictureClipRectanglesRequest {}
-impl AsByteSequence for SetPictureClipRectanglesRequest {
+impl SetPictureClipRectanglesRequest {
     #[inline]
+    pub fn builder() -> SetPictureClipRectanglesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("clip_x_origin", 8, 2),
+        ("clip_y_origin", 10, 2),
+    ];
+}
+const _: () = assert!(<SetPictureClipRectanglesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetPictureClipRectanglesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.clip_x_origin.as_bytes(&mut bytes[index..]);
         index += self.clip_y_origin.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.rectangles, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Rectangle>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureClipRectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (clip_x_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_x_origin, sz): (Int16, usize) = parse_item(bytes, index, "clip_x_origin")?;
         index += sz;
-        let (clip_y_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_y_origin, sz): (Int16, usize) = parse_item(bytes, index, "clip_y_origin")?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "rectangles",
+                offset: index,
+            })? as usize,
+            "rectangles",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPictureClipRectanglesRequest {
                 req_type: req_type,
                 length: length,
@@ -1881,57 +3604,134 @@ impl AsByteSequence for SetPictureClipRectanglesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + self.clip_x_origin.size()
-            + self.clip_y_origin.size()
-            + {
-                let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-                block_len + pad
-            }
+        let size: usize = 12 + {
+            let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetPictureClipRectanglesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetPictureClipRectanglesRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureClipRectangles";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct FreePictureRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub picture: Picture,
+#[doc = " A builder for `SetPictureClipRectanglesRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPictureClipRectanglesRequestBuilder {
+    pub inner: SetPictureClipRectanglesRequest,
 }
-impl FreePictureRequest {}
-impl AsByteSequence for FreePictureRequest {
+impl SetPictureClipRectanglesRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.picture.as_bytes(&mut bytes[index..]);
-        index
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing FreePictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            FreePictureRequest {
-                req_type: req_type,
-                length: length,
+    pub fn clip_x_origin(mut self, clip_x_origin: Int16) -> Self {
+        self.inner.clip_x_origin = clip_x_origin;
+        self
+    }
+    #[inline]
+    pub fn clip_y_origin(mut self, clip_y_origin: Int16) -> Self {
+        self.inner.clip_y_origin = clip_y_origin;
+        self
+    }
+    #[inline]
+    pub fn rectangles(mut self, rectangles: Vec<Rectangle>) -> Self {
+        self.inner.rectangles = rectangles;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPictureClipRectanglesRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Rectangle> for SetPictureClipRectanglesRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Rectangle>>(&mut self, iter: I) {
+        self.inner.rectangles.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Rectangle> for SetPictureClipRectanglesRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Rectangle>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for SetPictureClipRectanglesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPictureClipRectanglesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                clip_x_origin: Default::default(),
+                clip_y_origin: Default::default(),
+                rectangles: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct FreePictureRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub picture: Picture,
+}
+impl FreePictureRequest {
+    #[inline]
+    pub fn builder() -> FreePictureRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("picture", 4, 4)];
+}
+const _: () = assert!(<FreePictureRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FreePictureRequest {
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.picture.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+        index
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing FreePictureRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
+        index += sz;
+        index += buffer_pad(index, 4);
+        Ok((
+            FreePictureRequest {
+                req_type: req_type,
+                length: length,
                 picture: picture,
             },
             index,
@@ -1939,16 +3739,58 @@ impl AsByteSequence for FreePictureRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.picture.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FreePictureRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FreePictureRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreePicture";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FreePictureRequest`."]
+#[derive(Clone, Debug)]
+pub struct FreePictureRequestBuilder {
+    pub inner: FreePictureRequest,
+}
+impl FreePictureRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FreePictureRequest {
+        self.inner
+    }
+}
+impl Default for FreePictureRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FreePictureRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CompositeRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1965,16 +3807,38 @@ pub struct CompositeRequest {
     pub width: Card16,
     pub height: Card16,
 }
-impl CompositeRequest {}
+impl CompositeRequest {
+    #[inline]
+    pub fn builder() -> CompositeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("mask", 12, 4),
+        ("dst", 16, 4),
+        ("src_x", 20, 2),
+        ("src_y", 22, 2),
+        ("mask_x", 24, 2),
+        ("mask_y", 26, 2),
+        ("dst_x", 28, 2),
+        ("dst_y", 30, 2),
+        ("width", 32, 2),
+        ("height", 34, 2),
+    ];
+}
+const _: () = assert!(<CompositeRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for CompositeRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.src.as_bytes(&mut bytes[index..]);
         index += self.mask.as_bytes(&mut bytes[index..]);
         index += self.dst.as_bytes(&mut bytes[index..]);
@@ -1986,43 +3850,44 @@ impl AsByteSequence for CompositeRequest {
         index += self.dst_y.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompositeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        let (mask, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (Picture, usize) = parse_item(bytes, index, "mask")?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (mask_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mask_x, sz): (Int16, usize) = parse_item(bytes, index, "mask_x")?;
         index += sz;
-        let (mask_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mask_y, sz): (Int16, usize) = parse_item(bytes, index, "mask_y")?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = parse_item(bytes, index, "dst_x")?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = parse_item(bytes, index, "dst_y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CompositeRequest {
                 req_type: req_type,
                 length: length,
@@ -2044,32 +3909,124 @@ impl AsByteSequence for CompositeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.mask.size()
-            + self.dst.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + self.mask_x.size()
-            + self.mask_y.size()
-            + self.dst_x.size()
-            + self.dst_y.size()
-            + self.width.size()
-            + self.height.size()
+        let size: usize = 36;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CompositeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CompositeRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Composite";
     type Reply = ();
 }
+#[doc = " A builder for `CompositeRequest`."]
+#[derive(Clone, Debug)]
+pub struct CompositeRequestBuilder {
+    pub inner: CompositeRequest,
+}
+impl CompositeRequestBuilder {
+    #[inline]
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
+    }
+    #[inline]
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    pub fn mask(mut self, mask: Picture) -> Self {
+        self.inner.mask = mask;
+        self
+    }
+    #[inline]
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn mask_x(mut self, mask_x: Int16) -> Self {
+        self.inner.mask_x = mask_x;
+        self
+    }
+    #[inline]
+    pub fn mask_y(mut self, mask_y: Int16) -> Self {
+        self.inner.mask_y = mask_y;
+        self
+    }
+    #[inline]
+    pub fn dst_x(mut self, dst_x: Int16) -> Self {
+        self.inner.dst_x = dst_x;
+        self
+    }
+    #[inline]
+    pub fn dst_y(mut self, dst_y: Int16) -> Self {
+        self.inner.dst_y = dst_y;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CompositeRequest {
+        self.inner
+    }
+}
+impl Default for CompositeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CompositeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                mask: Default::default(),
+                dst: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                mask_x: Default::default(),
+                mask_y: Default::default(),
+                dst_x: Default::default(),
+                dst_y: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+            },
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum PictOp {
     Clear = 0,
     Src = 1,
@@ -2130,64 +4087,63 @@ impl AsByteSequence for PictOp {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u8).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Clear, sz)),
-            1 => Some((Self::Src, sz)),
-            2 => Some((Self::Dst, sz)),
-            3 => Some((Self::Over, sz)),
-            4 => Some((Self::OverReverse, sz)),
-            5 => Some((Self::In, sz)),
-            6 => Some((Self::InReverse, sz)),
-            7 => Some((Self::Out, sz)),
-            8 => Some((Self::OutReverse, sz)),
-            9 => Some((Self::Atop, sz)),
-            10 => Some((Self::AtopReverse, sz)),
-            11 => Some((Self::Xor, sz)),
-            12 => Some((Self::Add, sz)),
-            13 => Some((Self::Saturate, sz)),
-            16 => Some((Self::DisjointClear, sz)),
-            17 => Some((Self::DisjointSrc, sz)),
-            18 => Some((Self::DisjointDst, sz)),
-            19 => Some((Self::DisjointOver, sz)),
-            20 => Some((Self::DisjointOverReverse, sz)),
-            21 => Some((Self::DisjointIn, sz)),
-            22 => Some((Self::DisjointInReverse, sz)),
-            23 => Some((Self::DisjointOut, sz)),
-            24 => Some((Self::DisjointOutReverse, sz)),
-            25 => Some((Self::DisjointAtop, sz)),
-            26 => Some((Self::DisjointAtopReverse, sz)),
-            27 => Some((Self::DisjointXor, sz)),
-            32 => Some((Self::ConjointClear, sz)),
-            33 => Some((Self::ConjointSrc, sz)),
-            34 => Some((Self::ConjointDst, sz)),
-            35 => Some((Self::ConjointOver, sz)),
-            36 => Some((Self::ConjointOverReverse, sz)),
-            37 => Some((Self::ConjointIn, sz)),
-            38 => Some((Self::ConjointInReverse, sz)),
-            39 => Some((Self::ConjointOut, sz)),
-            40 => Some((Self::ConjointOutReverse, sz)),
-            41 => Some((Self::ConjointAtop, sz)),
-            42 => Some((Self::ConjointAtopReverse, sz)),
-            43 => Some((Self::ConjointXor, sz)),
-            48 => Some((Self::Multiply, sz)),
-            49 => Some((Self::Screen, sz)),
-            50 => Some((Self::Overlay, sz)),
-            51 => Some((Self::Darken, sz)),
-            52 => Some((Self::Lighten, sz)),
-            53 => Some((Self::ColorDodge, sz)),
-            54 => Some((Self::ColorBurn, sz)),
-            55 => Some((Self::HardLight, sz)),
-            56 => Some((Self::SoftLight, sz)),
-            57 => Some((Self::Difference, sz)),
-            58 => Some((Self::Exclusion, sz)),
-            59 => Some((Self::HslHue, sz)),
-            60 => Some((Self::HslSaturation, sz)),
-            61 => Some((Self::HslColor, sz)),
-            62 => Some((Self::HslLuminosity, sz)),
-            _ => None,
+            0 => Ok((Self::Clear, sz)),
+            1 => Ok((Self::Src, sz)),
+            2 => Ok((Self::Dst, sz)),
+            3 => Ok((Self::Over, sz)),
+            4 => Ok((Self::OverReverse, sz)),
+            5 => Ok((Self::In, sz)),
+            6 => Ok((Self::InReverse, sz)),
+            7 => Ok((Self::Out, sz)),
+            8 => Ok((Self::OutReverse, sz)),
+            9 => Ok((Self::Atop, sz)),
+            10 => Ok((Self::AtopReverse, sz)),
+            11 => Ok((Self::Xor, sz)),
+            12 => Ok((Self::Add, sz)),
+            13 => Ok((Self::Saturate, sz)),
+            16 => Ok((Self::DisjointClear, sz)),
+            17 => Ok((Self::DisjointSrc, sz)),
+            18 => Ok((Self::DisjointDst, sz)),
+            19 => Ok((Self::DisjointOver, sz)),
+            20 => Ok((Self::DisjointOverReverse, sz)),
+            21 => Ok((Self::DisjointIn, sz)),
+            22 => Ok((Self::DisjointInReverse, sz)),
+            23 => Ok((Self::DisjointOut, sz)),
+            24 => Ok((Self::DisjointOutReverse, sz)),
+            25 => Ok((Self::DisjointAtop, sz)),
+            26 => Ok((Self::DisjointAtopReverse, sz)),
+            27 => Ok((Self::DisjointXor, sz)),
+            32 => Ok((Self::ConjointClear, sz)),
+            33 => Ok((Self::ConjointSrc, sz)),
+            34 => Ok((Self::ConjointDst, sz)),
+            35 => Ok((Self::ConjointOver, sz)),
+            36 => Ok((Self::ConjointOverReverse, sz)),
+            37 => Ok((Self::ConjointIn, sz)),
+            38 => Ok((Self::ConjointInReverse, sz)),
+            39 => Ok((Self::ConjointOut, sz)),
+            40 => Ok((Self::ConjointOutReverse, sz)),
+            41 => Ok((Self::ConjointAtop, sz)),
+            42 => Ok((Self::ConjointAtopReverse, sz)),
+            43 => Ok((Self::ConjointXor, sz)),
+            48 => Ok((Self::Multiply, sz)),
+            49 => Ok((Self::Screen, sz)),
+            50 => Ok((Self::Overlay, sz)),
+            51 => Ok((Self::Darken, sz)),
+            52 => Ok((Self::Lighten, sz)),
+            53 => Ok((Self::ColorDodge, sz)),
+            54 => Ok((Self::ColorBurn, sz)),
+            55 => Ok((Self::HardLight, sz)),
+            56 => Ok((Self::SoftLight, sz)),
+            57 => Ok((Self::Difference, sz)),
+            58 => Ok((Self::Exclusion, sz)),
+            59 => Ok((Self::HslHue, sz)),
+            60 => Ok((Self::HslSaturation, sz)),
+            61 => Ok((Self::HslColor, sz)),
+            62 => Ok((Self::HslLuminosity, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -2195,14 +4151,91 @@ impl AsByteSequence for PictOp {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for PictOp {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for PictOp {
     #[inline]
     fn default() -> PictOp {
         PictOp::Clear
     }
 }
+impl From<PictOp> for u8 {
+    #[inline]
+    fn from(base: PictOp) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for PictOp {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Clear),
+            1 => Ok(Self::Src),
+            2 => Ok(Self::Dst),
+            3 => Ok(Self::Over),
+            4 => Ok(Self::OverReverse),
+            5 => Ok(Self::In),
+            6 => Ok(Self::InReverse),
+            7 => Ok(Self::Out),
+            8 => Ok(Self::OutReverse),
+            9 => Ok(Self::Atop),
+            10 => Ok(Self::AtopReverse),
+            11 => Ok(Self::Xor),
+            12 => Ok(Self::Add),
+            13 => Ok(Self::Saturate),
+            16 => Ok(Self::DisjointClear),
+            17 => Ok(Self::DisjointSrc),
+            18 => Ok(Self::DisjointDst),
+            19 => Ok(Self::DisjointOver),
+            20 => Ok(Self::DisjointOverReverse),
+            21 => Ok(Self::DisjointIn),
+            22 => Ok(Self::DisjointInReverse),
+            23 => Ok(Self::DisjointOut),
+            24 => Ok(Self::DisjointOutReverse),
+            25 => Ok(Self::DisjointAtop),
+            26 => Ok(Self::DisjointAtopReverse),
+            27 => Ok(Self::DisjointXor),
+            32 => Ok(Self::ConjointClear),
+            33 => Ok(Self::ConjointSrc),
+            34 => Ok(Self::ConjointDst),
+            35 => Ok(Self::ConjointOver),
+            36 => Ok(Self::ConjointOverReverse),
+            37 => Ok(Self::ConjointIn),
+            38 => Ok(Self::ConjointInReverse),
+            39 => Ok(Self::ConjointOut),
+            40 => Ok(Self::ConjointOutReverse),
+            41 => Ok(Self::ConjointAtop),
+            42 => Ok(Self::ConjointAtopReverse),
+            43 => Ok(Self::ConjointXor),
+            48 => Ok(Self::Multiply),
+            49 => Ok(Self::Screen),
+            50 => Ok(Self::Overlay),
+            51 => Ok(Self::Darken),
+            52 => Ok(Self::Lighten),
+            53 => Ok(Self::ColorDodge),
+            54 => Ok(Self::ColorBurn),
+            55 => Ok(Self::HardLight),
+            56 => Ok(Self::SoftLight),
+            57 => Ok(Self::Difference),
+            58 => Ok(Self::Exclusion),
+            59 => Ok(Self::HslHue),
+            60 => Ok(Self::HslSaturation),
+            61 => Ok(Self::HslColor),
+            62 => Ok(Self::HslLuminosity),
+            _ => Err(value),
+        }
+    }
+}
 pub const PICTURE_NONE: Picture = <Picture>::const_from_xid(0);
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct TrapezoidsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2214,16 +4247,32 @@ pub struct TrapezoidsRequest {
     pub src_y: Int16,
     pub traps: Vec<Trapezoid>,
 }
-impl TrapezoidsRequest {}
-impl AsByteSequence for TrapezoidsRequest {
+impl TrapezoidsRequest {
     #[inline]
+    pub fn builder() -> TrapezoidsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("dst", 12, 4),
+        ("mask_format", 16, 4),
+        ("src_x", 20, 2),
+        ("src_y", 22, 2),
+    ];
+}
+const _: () = assert!(<TrapezoidsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for TrapezoidsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.src.as_bytes(&mut bytes[index..]);
         index += self.dst.as_bytes(&mut bytes[index..]);
         index += self.mask_format.as_bytes(&mut bytes[index..]);
@@ -2231,36 +4280,47 @@ impl AsByteSequence for TrapezoidsRequest {
         index += self.src_y.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.traps, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Trapezoid>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Trapezoid>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing TrapezoidsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = parse_item(bytes, index, "mask_format")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (traps, block_len): (Vec<Trapezoid>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (traps, block_len): (Vec<Trapezoid>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "traps",
+                offset: index,
+            })? as usize,
+            "traps",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Trapezoid>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             TrapezoidsRequest {
                 req_type: req_type,
                 length: length,
@@ -2277,30 +4337,112 @@ impl AsByteSequence for TrapezoidsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.dst.size()
-            + self.mask_format.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + {
-                let block_len: usize = self.traps.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Trapezoid>());
-                block_len + pad
-            }
+        let size: usize = 24 + {
+            let block_len: usize = self.traps.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Trapezoid>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for TrapezoidsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for TrapezoidsRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Trapezoids";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `TrapezoidsRequest`."]
+#[derive(Clone, Debug)]
+pub struct TrapezoidsRequestBuilder {
+    pub inner: TrapezoidsRequest,
+}
+impl TrapezoidsRequestBuilder {
+    #[inline]
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
+    }
+    #[inline]
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn mask_format(mut self, mask_format: Pictformat) -> Self {
+        self.inner.mask_format = mask_format;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn traps(mut self, traps: Vec<Trapezoid>) -> Self {
+        self.inner.traps = traps;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> TrapezoidsRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Trapezoid> for TrapezoidsRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Trapezoid>>(&mut self, iter: I) {
+        self.inner.traps.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Trapezoid> for TrapezoidsRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Trapezoid>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for TrapezoidsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: TrapezoidsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                dst: Default::default(),
+                mask_format: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                traps: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct TrianglesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2312,16 +4454,32 @@ pub struct TrianglesRequest {
     pub src_y: Int16,
     pub triangles: Vec<Triangle>,
 }
-impl TrianglesRequest {}
-impl AsByteSequence for TrianglesRequest {
+impl TrianglesRequest {
     #[inline]
+    pub fn builder() -> TrianglesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("dst", 12, 4),
+        ("mask_format", 16, 4),
+        ("src_x", 20, 2),
+        ("src_y", 22, 2),
+    ];
+}
+const _: () = assert!(<TrianglesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for TrianglesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.src.as_bytes(&mut bytes[index..]);
         index += self.dst.as_bytes(&mut bytes[index..]);
         index += self.mask_format.as_bytes(&mut bytes[index..]);
@@ -2329,36 +4487,47 @@ impl AsByteSequence for TrianglesRequest {
         index += self.src_y.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.triangles, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Triangle>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Triangle>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing TrianglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = parse_item(bytes, index, "mask_format")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (triangles, block_len): (Vec<Triangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (triangles, block_len): (Vec<Triangle>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "triangles",
+                offset: index,
+            })? as usize,
+            "triangles",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Triangle>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             TrianglesRequest {
                 req_type: req_type,
                 length: length,
@@ -2375,30 +4544,112 @@ impl AsByteSequence for TrianglesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.dst.size()
-            + self.mask_format.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + {
-                let block_len: usize = self.triangles.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Triangle>());
-                block_len + pad
-            }
+        let size: usize = 24 + {
+            let block_len: usize = self.triangles.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Triangle>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for TrianglesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for TrianglesRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Triangles";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `TrianglesRequest`."]
+#[derive(Clone, Debug)]
+pub struct TrianglesRequestBuilder {
+    pub inner: TrianglesRequest,
+}
+impl TrianglesRequestBuilder {
+    #[inline]
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
+    }
+    #[inline]
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn mask_format(mut self, mask_format: Pictformat) -> Self {
+        self.inner.mask_format = mask_format;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn triangles(mut self, triangles: Vec<Triangle>) -> Self {
+        self.inner.triangles = triangles;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> TrianglesRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Triangle> for TrianglesRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Triangle>>(&mut self, iter: I) {
+        self.inner.triangles.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Triangle> for TrianglesRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Triangle>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for TrianglesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: TrianglesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                dst: Default::default(),
+                mask_format: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                triangles: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct TriStripRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2410,93 +4661,212 @@ pub struct TriStripRequest {
     pub src_y: Int16,
     pub points: Vec<Pointfix>,
 }
-impl TriStripRequest {}
-impl AsByteSequence for TriStripRequest {
+impl TriStripRequest {
+    #[inline]
+    pub fn builder() -> TriStripRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("dst", 12, 4),
+        ("mask_format", 16, 4),
+        ("src_x", 20, 2),
+        ("src_y", 22, 2),
+    ];
+}
+const _: () = assert!(<TriStripRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_request_asb_bb2ac609 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.op.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 3);
+                index += self.src.as_bytes(&mut bytes[index..]);
+                index += self.dst.as_bytes(&mut bytes[index..]);
+                index += self.mask_format.as_bytes(&mut bytes[index..]);
+                index += self.src_x.as_bytes(&mut bytes[index..]);
+                index += self.src_y.as_bytes(&mut bytes[index..]);
+                let block_len: usize = vector_as_bytes(&self.points, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Pointfix>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
+                index += sz;
+                index += 3;
+                let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
+                index += sz;
+                let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
+                index += sz;
+                let (mask_format, sz): (Pictformat, usize) =
+                    parse_item(bytes, index, "mask_format")?;
+                index += sz;
+                let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
+                index += sz;
+                let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
+                index += sz;
+                let (points, block_len): (Vec<Pointfix>, usize) = parse_remainder(
+                    bytes,
+                    index,
+                    (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                        field: "points",
+                        offset: index,
+                    })? as usize,
+                    "points",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        op: op,
+                        src: src,
+                        dst: dst,
+                        mask_format: mask_format,
+                        src_x: src_x,
+                        src_y: src_y,
+                        points: points,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 24 + {
+                    let block_len: usize = self.points.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_request_asb_bb2ac609! { TriStripRequest }
+impl Request for TriStripRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 12;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TriStrip";
+    type Reply = ();
+}
+#[doc = " A builder for `TriStripRequest`."]
+#[derive(Clone, Debug)]
+pub struct TriStripRequestBuilder {
+    pub inner: TriStripRequest,
+}
+impl TriStripRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += self.src.as_bytes(&mut bytes[index..]);
-        index += self.dst.as_bytes(&mut bytes[index..]);
-        index += self.mask_format.as_bytes(&mut bytes[index..]);
-        index += self.src_x.as_bytes(&mut bytes[index..]);
-        index += self.src_y.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.points, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
-        index
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing TriStripRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (points, block_len): (Vec<Pointfix>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
-        Some((
-            TriStripRequest {
-                req_type: req_type,
-                length: length,
-                op: op,
-                src: src,
-                dst: dst,
-                mask_format: mask_format,
-                src_x: src_x,
-                src_y: src_y,
-                points: points,
-            },
-            index,
-        ))
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.dst.size()
-            + self.mask_format.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + {
-                let block_len: usize = self.points.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
-                block_len + pad
-            }
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn mask_format(mut self, mask_format: Pictformat) -> Self {
+        self.inner.mask_format = mask_format;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn points(mut self, points: Vec<Pointfix>) -> Self {
+        self.inner.points = points;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> TriStripRequest {
+        self.inner
     }
 }
-impl Request for TriStripRequest {
-    const OPCODE: u8 = 12;
-    const EXTENSION: Option<&'static str> = Some("RENDER");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
+impl core::iter::Extend<Pointfix> for TriStripRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Pointfix>>(&mut self, iter: I) {
+        self.inner.points.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Pointfix> for TriStripRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Pointfix>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for TriStripRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: TriStripRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                dst: Default::default(),
+                mask_format: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                points: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct TriFanRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2508,125 +4878,161 @@ pub struct TriFanRequest {
     pub src_y: Int16,
     pub points: Vec<Pointfix>,
 }
-impl TriFanRequest {}
-impl AsByteSequence for TriFanRequest {
+impl TriFanRequest {
+    #[inline]
+    pub fn builder() -> TriFanRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("dst", 12, 4),
+        ("mask_format", 16, 4),
+        ("src_x", 20, 2),
+        ("src_y", 22, 2),
+    ];
+}
+const _: () = assert!(<TriFanRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_bb2ac609! { TriFanRequest }
+impl Request for TriFanRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 13;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TriFan";
+    type Reply = ();
+}
+#[doc = " A builder for `TriFanRequest`."]
+#[derive(Clone, Debug)]
+pub struct TriFanRequestBuilder {
+    pub inner: TriFanRequest,
+}
+impl TriFanRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += self.src.as_bytes(&mut bytes[index..]);
-        index += self.dst.as_bytes(&mut bytes[index..]);
-        index += self.mask_format.as_bytes(&mut bytes[index..]);
-        index += self.src_x.as_bytes(&mut bytes[index..]);
-        index += self.src_y.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.points, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
-        index
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing TriFanRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (points, block_len): (Vec<Pointfix>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
-        Some((
-            TriFanRequest {
-                req_type: req_type,
-                length: length,
-                op: op,
-                src: src,
-                dst: dst,
-                mask_format: mask_format,
-                src_x: src_x,
-                src_y: src_y,
-                points: points,
-            },
-            index,
-        ))
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.dst.size()
-            + self.mask_format.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + {
-                let block_len: usize = self.points.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
-                block_len + pad
-            }
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn mask_format(mut self, mask_format: Pictformat) -> Self {
+        self.inner.mask_format = mask_format;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn points(mut self, points: Vec<Pointfix>) -> Self {
+        self.inner.points = points;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> TriFanRequest {
+        self.inner
     }
 }
-impl Request for TriFanRequest {
-    const OPCODE: u8 = 13;
-    const EXTENSION: Option<&'static str> = Some("RENDER");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
+impl core::iter::Extend<Pointfix> for TriFanRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Pointfix>>(&mut self, iter: I) {
+        self.inner.points.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Pointfix> for TriFanRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Pointfix>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl Default for TriFanRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: TriFanRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                dst: Default::default(),
+                mask_format: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                points: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateGlyphSetRequest {
     pub req_type: u8,
     pub length: u16,
     pub gsid: Glyphset,
     pub format: Pictformat,
 }
-impl CreateGlyphSetRequest {}
-impl AsByteSequence for CreateGlyphSetRequest {
+impl CreateGlyphSetRequest {
     #[inline]
+    pub fn builder() -> CreateGlyphSetRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("gsid", 4, 4),
+        ("format", 8, 4),
+    ];
+}
+const _: () = assert!(<CreateGlyphSetRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateGlyphSetRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.gsid.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateGlyphSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (gsid, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (gsid, sz): (Glyphset, usize) = parse_item(bytes, index, "gsid")?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateGlyphSetRequest {
                 req_type: req_type,
                 length: length,
@@ -2638,48 +5044,109 @@ impl AsByteSequence for CreateGlyphSetRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.gsid.size() + self.format.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateGlyphSetRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateGlyphSetRequest {
-    const OPCODE: u8 = 17;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 17;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateGlyphSet";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateGlyphSetRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateGlyphSetRequestBuilder {
+    pub inner: CreateGlyphSetRequest,
+}
+impl CreateGlyphSetRequestBuilder {
+    #[inline]
+    pub fn gsid(mut self, gsid: Glyphset) -> Self {
+        self.inner.gsid = gsid;
+        self
+    }
+    #[inline]
+    pub fn format(mut self, format: Pictformat) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateGlyphSetRequest {
+        self.inner
+    }
+}
+impl Default for CreateGlyphSetRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateGlyphSetRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                gsid: Default::default(),
+                format: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ReferenceGlyphSetRequest {
     pub req_type: u8,
     pub length: u16,
     pub gsid: Glyphset,
     pub existing: Glyphset,
 }
-impl ReferenceGlyphSetRequest {}
-impl AsByteSequence for ReferenceGlyphSetRequest {
+impl ReferenceGlyphSetRequest {
     #[inline]
+    pub fn builder() -> ReferenceGlyphSetRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("gsid", 4, 4),
+        ("existing", 8, 4),
+    ];
+}
+const _: () = assert!(<ReferenceGlyphSetRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ReferenceGlyphSetRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.gsid.as_bytes(&mut bytes[index..]);
         index += self.existing.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ReferenceGlyphSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (gsid, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (gsid, sz): (Glyphset, usize) = parse_item(bytes, index, "gsid")?;
         index += sz;
-        let (existing, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (existing, sz): (Glyphset, usize) = parse_item(bytes, index, "existing")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ReferenceGlyphSetRequest {
                 req_type: req_type,
                 length: length,
@@ -2691,44 +5158,101 @@ impl AsByteSequence for ReferenceGlyphSetRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.gsid.size() + self.existing.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ReferenceGlyphSetRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ReferenceGlyphSetRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ReferenceGlyphSet";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ReferenceGlyphSetRequest`."]
+#[derive(Clone, Debug)]
+pub struct ReferenceGlyphSetRequestBuilder {
+    pub inner: ReferenceGlyphSetRequest,
+}
+impl ReferenceGlyphSetRequestBuilder {
+    #[inline]
+    pub fn gsid(mut self, gsid: Glyphset) -> Self {
+        self.inner.gsid = gsid;
+        self
+    }
+    #[inline]
+    pub fn existing(mut self, existing: Glyphset) -> Self {
+        self.inner.existing = existing;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ReferenceGlyphSetRequest {
+        self.inner
+    }
+}
+impl Default for ReferenceGlyphSetRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ReferenceGlyphSetRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                gsid: Default::default(),
+                existing: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FreeGlyphSetRequest {
     pub req_type: u8,
     pub length: u16,
     pub glyphset: Glyphset,
 }
-impl FreeGlyphSetRequest {}
-impl AsByteSequence for FreeGlyphSetRequest {
+impl FreeGlyphSetRequest {
     #[inline]
+    pub fn builder() -> FreeGlyphSetRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("glyphset", 4, 4)];
+}
+const _: () = assert!(<FreeGlyphSetRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FreeGlyphSetRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.glyphset.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeGlyphSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FreeGlyphSetRequest {
                 req_type: req_type,
                 length: length,
@@ -2739,16 +5263,57 @@ impl AsByteSequence for FreeGlyphSetRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.glyphset.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FreeGlyphSetRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FreeGlyphSetRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeGlyphSet";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FreeGlyphSetRequest`."]
+#[derive(Clone, Debug)]
+pub struct FreeGlyphSetRequestBuilder {
+    pub inner: FreeGlyphSetRequest,
+}
+impl FreeGlyphSetRequestBuilder {
+    #[inline]
+    pub fn glyphset(mut self, glyphset: Glyphset) -> Self {
+        self.inner.glyphset = glyphset;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FreeGlyphSetRequest {
+        self.inner
+    }
+}
+impl Default for FreeGlyphSetRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FreeGlyphSetRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                glyphset: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AddGlyphsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2758,53 +5323,165 @@ pub struct AddGlyphsRequest {
     pub glyphs: Vec<Glyphinfo>,
     pub data: Vec<Byte>,
 }
-impl AddGlyphsRequest {}
-impl AsByteSequence for AddGlyphsRequest {
+impl AddGlyphsRequest {
+    pub fn glyphids_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
+        index += sz;
+        let (glyphs_len, sz): (Card32, usize) = parse_item(bytes, index, "glyphs_len")?;
+        index += sz;
+        let (glyphids, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (glyphs_len as usize) as usize, "glyphids")?;
+        Ok(glyphids)
+    }
+    pub fn data_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
+        index += sz;
+        let (glyphs_len, sz): (Card32, usize) = parse_item(bytes, index, "glyphs_len")?;
+        index += sz;
+        let (glyphids, block_len): (Vec<Card32>, usize) =
+            parse_vector(bytes, index, (glyphs_len as usize) as usize, "glyphids")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        let (glyphs, block_len): (Vec<Glyphinfo>, usize) =
+            parse_vector(bytes, index, (glyphs_len as usize) as usize, "glyphs")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Glyphinfo>());
+        let (data, _): (Cow<'_, [Byte]>, usize) = parse_slice(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "data",
+                offset: index,
+            })? as usize,
+            "data",
+        )?;
+        Ok(data)
+    }
+    #[inline]
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            glyphset: self.glyphset.clone(),
+            glyphs_len: self.glyphs_len.clone(),
+            glyphids: self.glyphids.clone(),
+            glyphs: self.glyphs.clone(),
+            data: self.data.clone(),
+        }
+    }
     #[inline]
+    pub fn builder() -> AddGlyphsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("glyphset", 4, 4),
+        ("glyphs_len", 8, 4),
+    ];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    #[inline]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize = (self.glyphs_len as usize) as usize;
+            if self.glyphids.len() != expected {
+                return Err(ValidationError {
+                    list: "glyphids",
+                    expected,
+                    found: self.glyphids.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (self.glyphs_len as usize) as usize;
+            if self.glyphs.len() != expected {
+                return Err(ValidationError {
+                    list: "glyphs",
+                    expected,
+                    found: self.glyphs.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<AddGlyphsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AddGlyphsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.glyphset.as_bytes(&mut bytes[index..]);
         index += self.glyphs_len.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.glyphids, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = vector_as_bytes(&self.glyphs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Glyphinfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Glyphinfo>()),
+        );
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddGlyphsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
         index += sz;
-        let (glyphs_len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (glyphs_len, sz): (Card32, usize) = parse_item(bytes, index, "glyphs_len")?;
         index += sz;
         let (glyphids, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (glyphs_len as usize) as usize)?;
+            parse_vector(bytes, index, (glyphs_len as usize) as usize, "glyphids")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (glyphs, block_len): (Vec<Glyphinfo>, usize) =
-            vector_from_bytes(&bytes[index..], (glyphs_len as usize) as usize)?;
+            parse_vector(bytes, index, (glyphs_len as usize) as usize, "glyphs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Glyphinfo>());
-        let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Byte>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "data",
+                offset: index,
+            })? as usize,
+            "data",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AddGlyphsRequest {
                 req_type: req_type,
                 length: length,
@@ -2817,73 +5494,208 @@ impl AsByteSequence for AddGlyphsRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.glyphset.size()
-            + self.glyphs_len.size()
-            + {
+        let size: usize =
+            12 + {
                 let block_len: usize = self.glyphids.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.glyphs.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Glyphinfo>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.data.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            12 + {
+                let block_len: usize = self.glyphids.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.glyphs.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Glyphinfo>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.data.len() * ::core::mem::size_of::<Byte>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AddGlyphsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AddGlyphsRequest {
-    const OPCODE: u8 = 20;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 20;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddGlyphs";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+impl core::fmt::Debug for AddGlyphsRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AddGlyphsRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("glyphset", &self.glyphset)
+            .field("glyphs_len", &self.glyphs_len)
+            .field("glyphids", &self.glyphids)
+            .field("glyphs", &self.glyphs)
+            .field("data", &debug_bytes(&self.data, 64))
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for AddGlyphsRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let glyphset: Glyphset = u.arbitrary()?;
+        let glyphs_len: Card32 = u.arbitrary()?;
+        let data = u.arbitrary()?;
+        let glyphids = arbitrary_list(u, (glyphs_len as usize) as usize)?;
+        let glyphs = arbitrary_list(u, (glyphs_len as usize) as usize)?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            glyphset: glyphset,
+            glyphs_len: glyphs_len,
+            glyphids: glyphids,
+            glyphs: glyphs,
+            data: data,
+        })
+    }
+}
+#[doc = " A builder for `AddGlyphsRequest`."]
+#[derive(Debug)]
+pub struct AddGlyphsRequestBuilder {
+    pub inner: AddGlyphsRequest,
+}
+impl AddGlyphsRequestBuilder {
+    #[inline]
+    pub fn glyphset(mut self, glyphset: Glyphset) -> Self {
+        self.inner.glyphset = glyphset;
+        self
+    }
+    #[inline]
+    pub fn glyphs_len(mut self, glyphs_len: Card32) -> Self {
+        self.inner.glyphs_len = glyphs_len;
+        self
+    }
+    #[inline]
+    pub fn glyphids(mut self, glyphids: Vec<Card32>) -> Self {
+        self.inner.glyphids = glyphids;
+        self
+    }
+    #[inline]
+    pub fn glyphs(mut self, glyphs: Vec<Glyphinfo>) -> Self {
+        self.inner.glyphs = glyphs;
+        self
+    }
+    #[inline]
+    pub fn data(mut self, data: Vec<Byte>) -> Self {
+        self.inner.data = data;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AddGlyphsRequest {
+        self.inner
+    }
+}
+impl Default for AddGlyphsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AddGlyphsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                glyphset: Default::default(),
+                glyphs_len: Default::default(),
+                glyphids: Default::default(),
+                glyphs: Default::default(),
+                data: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FreeGlyphsRequest {
     pub req_type: u8,
     pub length: u16,
     pub glyphset: Glyphset,
     pub glyphs: Vec<Glyph>,
 }
-impl FreeGlyphsRequest {}
-impl AsByteSequence for FreeGlyphsRequest {
+impl FreeGlyphsRequest {
     #[inline]
+    pub fn builder() -> FreeGlyphsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("glyphset", 4, 4)];
+}
+const _: () = assert!(<FreeGlyphsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FreeGlyphsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.glyphset.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.glyphs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Glyph>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Glyph>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeGlyphsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
         index += sz;
-        let (glyphs, block_len): (Vec<Glyph>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (glyphs, block_len): (Vec<Glyph>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "glyphs",
+                offset: index,
+            })? as usize,
+            "glyphs",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Glyph>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FreeGlyphsRequest {
                 req_type: req_type,
                 length: length,
@@ -2895,20 +5707,82 @@ impl AsByteSequence for FreeGlyphsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.glyphset.size() + {
+        let size: usize = 8 + {
             let block_len: usize = self.glyphs.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Glyph>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FreeGlyphsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FreeGlyphsRequest {
-    const OPCODE: u8 = 22;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 22;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeGlyphs";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FreeGlyphsRequest`."]
+#[derive(Clone, Debug)]
+pub struct FreeGlyphsRequestBuilder {
+    pub inner: FreeGlyphsRequest,
+}
+impl FreeGlyphsRequestBuilder {
+    #[inline]
+    pub fn glyphset(mut self, glyphset: Glyphset) -> Self {
+        self.inner.glyphset = glyphset;
+        self
+    }
+    #[inline]
+    pub fn glyphs(mut self, glyphs: Vec<Glyph>) -> Self {
+        self.inner.glyphs = glyphs;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FreeGlyphsRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Glyph> for FreeGlyphsRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Glyph>>(&mut self, iter: I) {
+        self.inner.glyphs.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Glyph> for FreeGlyphsRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Glyph>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for FreeGlyphsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FreeGlyphsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                glyphset: Default::default(),
+                glyphs: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CompositeGlyphs8Request {
     pub req_type: u8,
     pub length: u16,
@@ -2921,98 +5795,297 @@ pub struct CompositeGlyphs8Request {
     pub src_y: Int16,
     pub glyphcmds: Vec<Byte>,
 }
-impl CompositeGlyphs8Request {}
-impl AsByteSequence for CompositeGlyphs8Request {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += self.src.as_bytes(&mut bytes[index..]);
-        index += self.dst.as_bytes(&mut bytes[index..]);
-        index += self.mask_format.as_bytes(&mut bytes[index..]);
-        index += self.glyphset.as_bytes(&mut bytes[index..]);
-        index += self.src_x.as_bytes(&mut bytes[index..]);
-        index += self.src_y.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.glyphcmds, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+impl CompositeGlyphs8Request {
+    pub fn glyphcmds_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing CompositeGlyphs8Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = parse_item(bytes, index, "mask_format")?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (glyphcmds, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
-            CompositeGlyphs8Request {
-                req_type: req_type,
-                length: length,
-                op: op,
-                src: src,
-                dst: dst,
-                mask_format: mask_format,
-                glyphset: glyphset,
-                src_x: src_x,
-                src_y: src_y,
-                glyphcmds: glyphcmds,
-            },
+        let (glyphcmds, _): (Cow<'_, [Byte]>, usize) = parse_slice(
+            bytes,
             index,
-        ))
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "glyphcmds",
+                offset: index,
+            })? as usize,
+            "glyphcmds",
+        )?;
+        Ok(glyphcmds)
+    }
+    #[inline]
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            op: self.op.clone(),
+            src: self.src.clone(),
+            dst: self.dst.clone(),
+            mask_format: self.mask_format.clone(),
+            glyphset: self.glyphset.clone(),
+            src_x: self.src_x.clone(),
+            src_y: self.src_y.clone(),
+            glyphcmds: self.glyphcmds.clone(),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.dst.size()
-            + self.mask_format.size()
-            + self.glyphset.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + {
-                let block_len: usize = self.glyphcmds.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
+    pub fn builder() -> CompositeGlyphs8RequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("dst", 12, 4),
+        ("mask_format", 16, 4),
+        ("glyphset", 20, 4),
+        ("src_x", 24, 2),
+        ("src_y", 26, 2),
+    ];
+}
+const _: () = assert!(<CompositeGlyphs8Request as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_request_asb_08359830 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.op.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 3);
+                index += self.src.as_bytes(&mut bytes[index..]);
+                index += self.dst.as_bytes(&mut bytes[index..]);
+                index += self.mask_format.as_bytes(&mut bytes[index..]);
+                index += self.glyphset.as_bytes(&mut bytes[index..]);
+                index += self.src_x.as_bytes(&mut bytes[index..]);
+                index += self.src_y.as_bytes(&mut bytes[index..]);
+                let block_len: usize = vector_as_bytes(&self.glyphcmds, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
             }
-    }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
+                index += sz;
+                index += 3;
+                let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
+                index += sz;
+                let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
+                index += sz;
+                let (mask_format, sz): (Pictformat, usize) =
+                    parse_item(bytes, index, "mask_format")?;
+                index += sz;
+                let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
+                index += sz;
+                let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
+                index += sz;
+                let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
+                index += sz;
+                let (glyphcmds, block_len): (Vec<Byte>, usize) = parse_remainder(
+                    bytes,
+                    index,
+                    (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                        field: "glyphcmds",
+                        offset: index,
+                    })? as usize,
+                    "glyphcmds",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        op: op,
+                        src: src,
+                        dst: dst,
+                        mask_format: mask_format,
+                        glyphset: glyphset,
+                        src_x: src_x,
+                        src_y: src_y,
+                        glyphcmds: glyphcmds,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 28 + {
+                    let block_len: usize = self.glyphcmds.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+            #[inline]
+            fn size_hint(&self) -> usize {
+                let size: usize = 28 + {
+                    let block_len: usize = self.glyphcmds.len() * ::core::mem::size_of::<Byte>();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_request_asb_08359830! { CompositeGlyphs8Request }
 impl Request for CompositeGlyphs8Request {
-    const OPCODE: u8 = 23;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 23;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CompositeGlyphs8";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for CompositeGlyphs8Request {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompositeGlyphs8Request")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("op", &self.op)
+            .field("src", &self.src)
+            .field("dst", &self.dst)
+            .field("mask_format", &self.mask_format)
+            .field("glyphset", &self.glyphset)
+            .field("src_x", &self.src_x)
+            .field("src_y", &self.src_y)
+            .field("glyphcmds", &debug_bytes(&self.glyphcmds, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `CompositeGlyphs8Request`."]
+#[derive(Debug)]
+pub struct CompositeGlyphs8RequestBuilder {
+    pub inner: CompositeGlyphs8Request,
+}
+impl CompositeGlyphs8RequestBuilder {
+    #[inline]
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
+    }
+    #[inline]
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn mask_format(mut self, mask_format: Pictformat) -> Self {
+        self.inner.mask_format = mask_format;
+        self
+    }
+    #[inline]
+    pub fn glyphset(mut self, glyphset: Glyphset) -> Self {
+        self.inner.glyphset = glyphset;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn glyphcmds(mut self, glyphcmds: Vec<Byte>) -> Self {
+        self.inner.glyphcmds = glyphcmds;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CompositeGlyphs8Request {
+        self.inner
+    }
+}
+impl core::iter::Extend<Byte> for CompositeGlyphs8RequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Byte>>(&mut self, iter: I) {
+        self.inner.glyphcmds.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Byte> for CompositeGlyphs8RequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Byte>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for CompositeGlyphs8RequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CompositeGlyphs8Request {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                dst: Default::default(),
+                mask_format: Default::default(),
+                glyphset: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                glyphcmds: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CompositeGlyphs16Request {
     pub req_type: u8,
     pub length: u16,
@@ -3025,98 +6098,188 @@ pub struct CompositeGlyphs16Request {
     pub src_y: Int16,
     pub glyphcmds: Vec<Byte>,
 }
-impl CompositeGlyphs16Request {}
-impl AsByteSequence for CompositeGlyphs16Request {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += self.src.as_bytes(&mut bytes[index..]);
-        index += self.dst.as_bytes(&mut bytes[index..]);
-        index += self.mask_format.as_bytes(&mut bytes[index..]);
-        index += self.glyphset.as_bytes(&mut bytes[index..]);
-        index += self.src_x.as_bytes(&mut bytes[index..]);
-        index += self.src_y.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.glyphcmds, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+impl CompositeGlyphs16Request {
+    pub fn glyphcmds_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing CompositeGlyphs16Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = parse_item(bytes, index, "mask_format")?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (glyphcmds, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
-            CompositeGlyphs16Request {
-                req_type: req_type,
-                length: length,
-                op: op,
-                src: src,
-                dst: dst,
-                mask_format: mask_format,
-                glyphset: glyphset,
-                src_x: src_x,
-                src_y: src_y,
-                glyphcmds: glyphcmds,
-            },
+        let (glyphcmds, _): (Cow<'_, [Byte]>, usize) = parse_slice(
+            bytes,
             index,
-        ))
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "glyphcmds",
+                offset: index,
+            })? as usize,
+            "glyphcmds",
+        )?;
+        Ok(glyphcmds)
+    }
+    #[inline]
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            op: self.op.clone(),
+            src: self.src.clone(),
+            dst: self.dst.clone(),
+            mask_format: self.mask_format.clone(),
+            glyphset: self.glyphset.clone(),
+            src_x: self.src_x.clone(),
+            src_y: self.src_y.clone(),
+            glyphcmds: self.glyphcmds.clone(),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.dst.size()
-            + self.mask_format.size()
-            + self.glyphset.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + {
-                let block_len: usize = self.glyphcmds.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
-            }
-    }
-}
+    pub fn builder() -> CompositeGlyphs16RequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("dst", 12, 4),
+        ("mask_format", 16, 4),
+        ("glyphset", 20, 4),
+        ("src_x", 24, 2),
+        ("src_y", 26, 2),
+    ];
+}
+const _: () = assert!(<CompositeGlyphs16Request as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_08359830! { CompositeGlyphs16Request }
 impl Request for CompositeGlyphs16Request {
-    const OPCODE: u8 = 24;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 24;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CompositeGlyphs16";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for CompositeGlyphs16Request {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompositeGlyphs16Request")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("op", &self.op)
+            .field("src", &self.src)
+            .field("dst", &self.dst)
+            .field("mask_format", &self.mask_format)
+            .field("glyphset", &self.glyphset)
+            .field("src_x", &self.src_x)
+            .field("src_y", &self.src_y)
+            .field("glyphcmds", &debug_bytes(&self.glyphcmds, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `CompositeGlyphs16Request`."]
+#[derive(Debug)]
+pub struct CompositeGlyphs16RequestBuilder {
+    pub inner: CompositeGlyphs16Request,
+}
+impl CompositeGlyphs16RequestBuilder {
+    #[inline]
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
+    }
+    #[inline]
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn mask_format(mut self, mask_format: Pictformat) -> Self {
+        self.inner.mask_format = mask_format;
+        self
+    }
+    #[inline]
+    pub fn glyphset(mut self, glyphset: Glyphset) -> Self {
+        self.inner.glyphset = glyphset;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn glyphcmds(mut self, glyphcmds: Vec<Byte>) -> Self {
+        self.inner.glyphcmds = glyphcmds;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CompositeGlyphs16Request {
+        self.inner
+    }
+}
+impl core::iter::Extend<Byte> for CompositeGlyphs16RequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Byte>>(&mut self, iter: I) {
+        self.inner.glyphcmds.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Byte> for CompositeGlyphs16RequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Byte>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for CompositeGlyphs16RequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CompositeGlyphs16Request {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                dst: Default::default(),
+                mask_format: Default::default(),
+                glyphset: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                glyphcmds: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CompositeGlyphs32Request {
     pub req_type: u8,
     pub length: u16,
@@ -3129,98 +6292,188 @@ pub struct CompositeGlyphs32Request {
     pub src_y: Int16,
     pub glyphcmds: Vec<Byte>,
 }
-impl CompositeGlyphs32Request {}
-impl AsByteSequence for CompositeGlyphs32Request {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += self.src.as_bytes(&mut bytes[index..]);
-        index += self.dst.as_bytes(&mut bytes[index..]);
-        index += self.mask_format.as_bytes(&mut bytes[index..]);
-        index += self.glyphset.as_bytes(&mut bytes[index..]);
-        index += self.src_x.as_bytes(&mut bytes[index..]);
-        index += self.src_y.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.glyphcmds, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+impl CompositeGlyphs32Request {
+    pub fn glyphcmds_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing CompositeGlyphs32Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = parse_item(bytes, index, "mask_format")?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = parse_item(bytes, index, "glyphset")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (glyphcmds, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
-            CompositeGlyphs32Request {
-                req_type: req_type,
-                length: length,
-                op: op,
-                src: src,
-                dst: dst,
-                mask_format: mask_format,
-                glyphset: glyphset,
-                src_x: src_x,
-                src_y: src_y,
-                glyphcmds: glyphcmds,
-            },
+        let (glyphcmds, _): (Cow<'_, [Byte]>, usize) = parse_slice(
+            bytes,
             index,
-        ))
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "glyphcmds",
+                offset: index,
+            })? as usize,
+            "glyphcmds",
+        )?;
+        Ok(glyphcmds)
+    }
+    #[inline]
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            op: self.op.clone(),
+            src: self.src.clone(),
+            dst: self.dst.clone(),
+            mask_format: self.mask_format.clone(),
+            glyphset: self.glyphset.clone(),
+            src_x: self.src_x.clone(),
+            src_y: self.src_y.clone(),
+            glyphcmds: self.glyphcmds.clone(),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.src.size()
-            + self.dst.size()
-            + self.mask_format.size()
-            + self.glyphset.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + {
-                let block_len: usize = self.glyphcmds.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
-            }
-    }
-}
+    pub fn builder() -> CompositeGlyphs32RequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("src", 8, 4),
+        ("dst", 12, 4),
+        ("mask_format", 16, 4),
+        ("glyphset", 20, 4),
+        ("src_x", 24, 2),
+        ("src_y", 26, 2),
+    ];
+}
+const _: () = assert!(<CompositeGlyphs32Request as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_08359830! { CompositeGlyphs32Request }
 impl Request for CompositeGlyphs32Request {
-    const OPCODE: u8 = 25;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 25;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CompositeGlyphs32";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for CompositeGlyphs32Request {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompositeGlyphs32Request")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("op", &self.op)
+            .field("src", &self.src)
+            .field("dst", &self.dst)
+            .field("mask_format", &self.mask_format)
+            .field("glyphset", &self.glyphset)
+            .field("src_x", &self.src_x)
+            .field("src_y", &self.src_y)
+            .field("glyphcmds", &debug_bytes(&self.glyphcmds, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `CompositeGlyphs32Request`."]
+#[derive(Debug)]
+pub struct CompositeGlyphs32RequestBuilder {
+    pub inner: CompositeGlyphs32Request,
+}
+impl CompositeGlyphs32RequestBuilder {
+    #[inline]
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
+    }
+    #[inline]
+    pub fn src(mut self, src: Picture) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn mask_format(mut self, mask_format: Pictformat) -> Self {
+        self.inner.mask_format = mask_format;
+        self
+    }
+    #[inline]
+    pub fn glyphset(mut self, glyphset: Glyphset) -> Self {
+        self.inner.glyphset = glyphset;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn glyphcmds(mut self, glyphcmds: Vec<Byte>) -> Self {
+        self.inner.glyphcmds = glyphcmds;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CompositeGlyphs32Request {
+        self.inner
+    }
+}
+impl core::iter::Extend<Byte> for CompositeGlyphs32RequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Byte>>(&mut self, iter: I) {
+        self.inner.glyphcmds.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Byte> for CompositeGlyphs32RequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Byte>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for CompositeGlyphs32RequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CompositeGlyphs32Request {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                src: Default::default(),
+                dst: Default::default(),
+                mask_format: Default::default(),
+                glyphset: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                glyphcmds: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FillRectanglesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -3229,44 +6482,68 @@ pub struct FillRectanglesRequest {
     pub color: Color,
     pub rects: Vec<Rectangle>,
 }
-impl FillRectanglesRequest {}
-impl AsByteSequence for FillRectanglesRequest {
+impl FillRectanglesRequest {
     #[inline]
+    pub fn builder() -> FillRectanglesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("op", 4, 1),
+        ("dst", 8, 4),
+        ("color", 12, 8),
+    ];
+}
+const _: () = assert!(<FillRectanglesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FillRectanglesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.op.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.dst.as_bytes(&mut bytes[index..]);
         index += self.color.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.rects, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Rectangle>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FillRectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = parse_item(bytes, index, "op")?;
         index += sz;
         index += 3;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = parse_item(bytes, index, "dst")?;
         index += sz;
-        let (color, sz): (Color, usize) = <Color>::from_bytes(&bytes[index..])?;
+        let (color, sz): (Color, usize) = parse_item(bytes, index, "color")?;
         index += sz;
-        let (rects, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rects, block_len): (Vec<Rectangle>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "rects",
+                offset: index,
+            })? as usize,
+            "rects",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FillRectanglesRequest {
                 req_type: req_type,
                 length: length,
@@ -3280,27 +6557,94 @@ impl AsByteSequence for FillRectanglesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.op.size()
-            + 3
-            + self.dst.size()
-            + self.color.size()
-            + {
-                let block_len: usize = self.rects.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-                block_len + pad
-            }
+        let size: usize = 20 + {
+            let block_len: usize = self.rects.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FillRectanglesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FillRectanglesRequest {
-    const OPCODE: u8 = 26;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 26;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FillRectangles";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FillRectanglesRequest`."]
+#[derive(Clone, Debug)]
+pub struct FillRectanglesRequestBuilder {
+    pub inner: FillRectanglesRequest,
+}
+impl FillRectanglesRequestBuilder {
+    #[inline]
+    pub fn op(mut self, op: PictOp) -> Self {
+        self.inner.op = op;
+        self
+    }
+    #[inline]
+    pub fn dst(mut self, dst: Picture) -> Self {
+        self.inner.dst = dst;
+        self
+    }
+    #[inline]
+    pub fn color(mut self, color: Color) -> Self {
+        self.inner.color = color;
+        self
+    }
+    #[inline]
+    pub fn rects(mut self, rects: Vec<Rectangle>) -> Self {
+        self.inner.rects = rects;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FillRectanglesRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Rectangle> for FillRectanglesRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Rectangle>>(&mut self, iter: I) {
+        self.inner.rects.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Rectangle> for FillRectanglesRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Rectangle>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for FillRectanglesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FillRectanglesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                op: Default::default(),
+                dst: Default::default(),
+                color: Default::default(),
+                rects: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateCursorRequest {
     pub req_type: u8,
     pub length: u16,
@@ -3309,38 +6653,46 @@ pub struct CreateCursorRequest {
     pub x: Card16,
     pub y: Card16,
 }
-impl CreateCursorRequest {}
-impl AsByteSequence for CreateCursorRequest {
+impl CreateCursorRequest {
     #[inline]
+    pub fn builder() -> CreateCursorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateCursorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateCursorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.cid.as_bytes(&mut bytes[index..]);
         index += self.source.as_bytes(&mut bytes[index..]);
         index += self.x.as_bytes(&mut bytes[index..]);
         index += self.y.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cid, sz): (Cursor, usize) = parse_item(bytes, index, "cid")?;
         index += sz;
-        let (source, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Picture, usize) = parse_item(bytes, index, "source")?;
         index += sz;
-        let (x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateCursorRequest {
                 req_type: req_type,
                 length: length,
@@ -3354,22 +6706,75 @@ impl AsByteSequence for CreateCursorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.cid.size()
-            + self.source.size()
-            + self.x.size()
-            + self.y.size()
+        let size: usize = 12 + self.cid.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateCursorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateCursorRequest {
-    const OPCODE: u8 = 27;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 27;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateCursor";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateCursorRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateCursorRequestBuilder {
+    pub inner: CreateCursorRequest,
+}
+impl CreateCursorRequestBuilder {
+    #[inline]
+    pub fn cid(mut self, cid: Cursor) -> Self {
+        self.inner.cid = cid;
+        self
+    }
+    #[inline]
+    pub fn source(mut self, source: Picture) -> Self {
+        self.inner.source = source;
+        self
+    }
+    #[inline]
+    pub fn x(mut self, x: Card16) -> Self {
+        self.inner.x = x;
+        self
+    }
+    #[inline]
+    pub fn y(mut self, y: Card16) -> Self {
+        self.inner.y = y;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateCursorRequest {
+        self.inner
+    }
+}
+impl Default for CreateCursorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateCursorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                cid: Default::default(),
+                source: Default::default(),
+                x: Default::default(),
+                y: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Transform {
     pub matrix11: Fixed,
     pub matrix12: Fixed,
@@ -3381,9 +6786,21 @@ pub struct Transform {
     pub matrix32: Fixed,
     pub matrix33: Fixed,
 }
-impl Transform {}
+impl Transform {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("matrix11", 0, 4),
+        ("matrix12", 4, 4),
+        ("matrix13", 8, 4),
+        ("matrix21", 12, 4),
+        ("matrix22", 16, 4),
+        ("matrix23", 20, 4),
+        ("matrix31", 24, 4),
+        ("matrix32", 28, 4),
+        ("matrix33", 32, 4),
+    ];
+}
 impl AsByteSequence for Transform {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.matrix11.as_bytes(&mut bytes[index..]);
@@ -3397,29 +6814,28 @@ impl AsByteSequence for Transform {
         index += self.matrix33.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Transform from byte buffer");
-        let (matrix11, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix11, sz): (Fixed, usize) = parse_item(bytes, index, "matrix11")?;
         index += sz;
-        let (matrix12, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix12, sz): (Fixed, usize) = parse_item(bytes, index, "matrix12")?;
         index += sz;
-        let (matrix13, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix13, sz): (Fixed, usize) = parse_item(bytes, index, "matrix13")?;
         index += sz;
-        let (matrix21, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix21, sz): (Fixed, usize) = parse_item(bytes, index, "matrix21")?;
         index += sz;
-        let (matrix22, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix22, sz): (Fixed, usize) = parse_item(bytes, index, "matrix22")?;
         index += sz;
-        let (matrix23, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix23, sz): (Fixed, usize) = parse_item(bytes, index, "matrix23")?;
         index += sz;
-        let (matrix31, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix31, sz): (Fixed, usize) = parse_item(bytes, index, "matrix31")?;
         index += sz;
-        let (matrix32, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix32, sz): (Fixed, usize) = parse_item(bytes, index, "matrix32")?;
         index += sz;
-        let (matrix33, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix33, sz): (Fixed, usize) = parse_item(bytes, index, "matrix33")?;
         index += sz;
-        Some((
+        Ok((
             Transform {
                 matrix11: matrix11,
                 matrix12: matrix12,
@@ -3436,50 +6852,65 @@ impl AsByteSequence for Transform {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.matrix11.size()
-            + self.matrix12.size()
-            + self.matrix13.size()
-            + self.matrix21.size()
-            + self.matrix22.size()
-            + self.matrix23.size()
-            + self.matrix31.size()
-            + self.matrix32.size()
-            + self.matrix33.size()
-    }
-}
-#[derive(Clone, Debug, Default)]
+        36
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Transform {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPictureTransformRequest {
     pub req_type: u8,
     pub length: u16,
     pub picture: Picture,
     pub transform: Transform,
 }
-impl SetPictureTransformRequest {}
-impl AsByteSequence for SetPictureTransformRequest {
+impl SetPictureTransformRequest {
     #[inline]
+    pub fn builder() -> SetPictureTransformRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("transform", 8, 36),
+    ];
+}
+const _: () = assert!(<SetPictureTransformRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetPictureTransformRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.transform.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureTransformRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let (transform, sz): (Transform, usize) = parse_item(bytes, index, "transform")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPictureTransformRequest {
                 req_type: req_type,
                 length: length,
@@ -3491,44 +6922,108 @@ impl AsByteSequence for SetPictureTransformRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.picture.size() + self.transform.size()
+        let size: usize = 44;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetPictureTransformRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetPictureTransformRequest {
-    const OPCODE: u8 = 28;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 28;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureTransform";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPictureTransformRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPictureTransformRequestBuilder {
+    pub inner: SetPictureTransformRequest,
+}
+impl SetPictureTransformRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.inner.transform = transform;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPictureTransformRequest {
+        self.inner
+    }
+}
+impl Default for SetPictureTransformRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPictureTransformRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                transform: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryFiltersRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
 }
-impl QueryFiltersRequest {}
-impl AsByteSequence for QueryFiltersRequest {
+impl QueryFiltersRequest {
+    #[inline]
+    pub fn builder() -> QueryFiltersRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryFiltersReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryFiltersRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryFiltersRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFiltersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryFiltersRequest {
                 req_type: req_type,
                 length: length,
@@ -3539,16 +7034,57 @@ impl AsByteSequence for QueryFiltersRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size()
+        let size: usize = 4 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryFiltersRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryFiltersRequest {
-    const OPCODE: u8 = 29;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 29;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryFilters";
     type Reply = QueryFiltersReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryFiltersRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryFiltersRequestBuilder {
+    pub inner: QueryFiltersRequest,
+}
+impl QueryFiltersRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryFiltersRequest {
+        self.inner
+    }
+}
+impl Default for QueryFiltersRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryFiltersRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryFiltersReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -3556,51 +7092,111 @@ pub struct QueryFiltersReply {
     pub aliases: Vec<Card16>,
     pub filters: Vec<Str>,
 }
-impl QueryFiltersReply {}
-impl AsByteSequence for QueryFiltersReply {
+impl QueryFiltersReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn aliases_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        index += 16;
+        let (aliases, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "aliases")?;
+        Ok(aliases)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("aliases_len", 8, 4),
+        ("filters_len", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.aliases == other.aliases && self.filters == other.filters
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("aliases", &self.aliases, &other.aliases, &mut changes);
+        diff_list("filters", &self.filters, &other.filters, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryFiltersReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.aliases.len() as Card32).as_bytes(&mut bytes[index..]);
         index += (self.filters.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         let block_len: usize = vector_as_bytes(&self.aliases, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.filters, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Str>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Str>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFiltersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         index += 16;
         let (aliases, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "aliases")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (filters, block_len): (Vec<Str>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "filters")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Str>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryFiltersReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -3611,28 +7207,44 @@ impl AsByteSequence for QueryFiltersReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
-            + 16
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.aliases.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.filters.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Str>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            32 + {
+                let block_len: usize = self.aliases.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.filters.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Str>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryFiltersReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPictureFilterRequest {
     pub req_type: u8,
     pub length: u16,
@@ -3640,48 +7252,82 @@ pub struct SetPictureFilterRequest {
     pub filter: String,
     pub values: Vec<Fixed>,
 }
-impl SetPictureFilterRequest {}
-impl AsByteSequence for SetPictureFilterRequest {
+impl SetPictureFilterRequest {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(picture: Picture, filter: &str, values: Vec<Fixed>) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            picture: picture,
+            filter: String::from(filter),
+            values: values,
+        }
+    }
     #[inline]
+    pub fn builder() -> SetPictureFilterRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("filter_len", 8, 2),
+    ];
+}
+const _: () = assert!(<SetPictureFilterRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetPictureFilterRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += (self.filter.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = string_as_bytes(&self.filter, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         let block_len: usize = vector_as_bytes(&self.values, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fixed>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureFilterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
         let (filter, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "filter")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        let (values, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (values, block_len): (Vec<Fixed>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "values",
+                offset: index,
+            })? as usize,
+            "values",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPictureFilterRequest {
                 req_type: req_type,
                 length: length,
@@ -3694,36 +7340,86 @@ impl AsByteSequence for SetPictureFilterRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + ::core::mem::size_of::<Card16>()
-            + 2
-            + {
+        let size: usize =
+            12 + {
                 let block_len: usize = self.filter.len();
                 let pad: usize = buffer_pad(block_len, 4);
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.values.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetPictureFilterRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetPictureFilterRequest {
-    const OPCODE: u8 = 30;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 30;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureFilter";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPictureFilterRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPictureFilterRequestBuilder {
+    pub inner: SetPictureFilterRequest,
+}
+impl SetPictureFilterRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn filter(mut self, filter: String) -> Self {
+        self.inner.filter = filter;
+        self
+    }
+    #[inline]
+    pub fn values(mut self, values: Vec<Fixed>) -> Self {
+        self.inner.values = values;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPictureFilterRequest {
+        self.inner
+    }
+}
+impl Default for SetPictureFilterRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPictureFilterRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                filter: Default::default(),
+                values: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Animcursorelt {
     pub cursor: Cursor,
     pub delay: Card32,
 }
-impl Animcursorelt {}
+impl Animcursorelt {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for Animcursorelt {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -3733,14 +7429,14 @@ impl AsByteSequence for Animcursorelt {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Animcursorelt from byte buffer");
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = parse_item(bytes, index, "cursor")?;
         index += sz;
-        let (delay, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (delay, sz): (Card32, usize) = parse_item(bytes, index, "delay")?;
         index += sz;
-        Some((
+        Ok((
             Animcursorelt {
                 cursor: cursor,
                 delay: delay,
@@ -3750,46 +7446,74 @@ impl AsByteSequence for Animcursorelt {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.cursor.size() + self.delay.size()
+        4 + self.cursor.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Animcursorelt {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateAnimCursorRequest {
     pub req_type: u8,
     pub length: u16,
     pub cid: Cursor,
     pub cursors: Vec<Animcursorelt>,
 }
-impl CreateAnimCursorRequest {}
-impl AsByteSequence for CreateAnimCursorRequest {
+impl CreateAnimCursorRequest {
     #[inline]
+    pub fn builder() -> CreateAnimCursorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateAnimCursorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateAnimCursorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.cid.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.cursors, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Animcursorelt>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Animcursorelt>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateAnimCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cid, sz): (Cursor, usize) = parse_item(bytes, index, "cid")?;
         index += sz;
-        let (cursors, block_len): (Vec<Animcursorelt>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (cursors, block_len): (Vec<Animcursorelt>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "cursors",
+                offset: index,
+            })? as usize,
+            "cursors",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Animcursorelt>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateAnimCursorRequest {
                 req_type: req_type,
                 length: length,
@@ -3801,26 +7525,94 @@ impl AsByteSequence for CreateAnimCursorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.cid.size() + {
+        let size: usize = 4 + self.cid.size() + {
             let block_len: usize = self.cursors.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Animcursorelt>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateAnimCursorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateAnimCursorRequest {
-    const OPCODE: u8 = 31;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 31;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateAnimCursor";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateAnimCursorRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateAnimCursorRequestBuilder {
+    pub inner: CreateAnimCursorRequest,
+}
+impl CreateAnimCursorRequestBuilder {
+    #[inline]
+    pub fn cid(mut self, cid: Cursor) -> Self {
+        self.inner.cid = cid;
+        self
+    }
+    #[inline]
+    pub fn cursors(mut self, cursors: Vec<Animcursorelt>) -> Self {
+        self.inner.cursors = cursors;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateAnimCursorRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Animcursorelt> for CreateAnimCursorRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Animcursorelt>>(&mut self, iter: I) {
+        self.inner.cursors.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Animcursorelt> for CreateAnimCursorRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Animcursorelt>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for CreateAnimCursorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateAnimCursorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                cid: Default::default(),
+                cursors: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Spanfix {
     pub l: Fixed,
     pub r: Fixed,
     pub y: Fixed,
 }
-impl Spanfix {}
+impl Spanfix {
+    #[inline]
+    pub const fn new(l: Fixed, r: Fixed, y: Fixed) -> Self {
+        Self { l: l, r: r, y: y }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("l", 0, 4), ("r", 4, 4), ("y", 8, 4)];
+}
 impl AsByteSequence for Spanfix {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -3830,29 +7622,44 @@ impl AsByteSequence for Spanfix {
         index += self.y.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Spanfix from byte buffer");
-        let (l, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (l, sz): (Fixed, usize) = parse_item(bytes, index, "l")?;
         index += sz;
-        let (r, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (r, sz): (Fixed, usize) = parse_item(bytes, index, "r")?;
         index += sz;
-        let (y, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Fixed, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        Some((Spanfix { l: l, r: r, y: y }, index))
+        Ok((Spanfix { l: l, r: r, y: y }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.l.size() + self.r.size() + self.y.size()
+        12
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Spanfix {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Trap {
     pub top: Spanfix,
     pub bot: Spanfix,
 }
-impl Trap {}
+impl Trap {
+    #[inline]
+    pub const fn new(top: Spanfix, bot: Spanfix) -> Self {
+        Self { top: top, bot: bot }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("top", 0, 12), ("bot", 12, 12)];
+}
 impl AsByteSequence for Trap {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -3862,21 +7669,31 @@ impl AsByteSequence for Trap {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Trap from byte buffer");
-        let (top, sz): (Spanfix, usize) = <Spanfix>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Spanfix, usize) = parse_item(bytes, index, "top")?;
         index += sz;
-        let (bot, sz): (Spanfix, usize) = <Spanfix>::from_bytes(&bytes[index..])?;
+        let (bot, sz): (Spanfix, usize) = parse_item(bytes, index, "bot")?;
         index += sz;
-        Some((Trap { top: top, bot: bot }, index))
+        Ok((Trap { top: top, bot: bot }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.top.size() + self.bot.size()
+        24
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Trap {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AddTrapsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -3885,42 +7702,66 @@ pub struct AddTrapsRequest {
     pub y_off: Int16,
     pub traps: Vec<Trap>,
 }
-impl AddTrapsRequest {}
-impl AsByteSequence for AddTrapsRequest {
+impl AddTrapsRequest {
     #[inline]
+    pub fn builder() -> AddTrapsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("x_off", 8, 2),
+        ("y_off", 10, 2),
+    ];
+}
+const _: () = assert!(<AddTrapsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AddTrapsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.x_off.as_bytes(&mut bytes[index..]);
         index += self.y_off.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.traps, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Trap>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Trap>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddTrapsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_off, sz): (Int16, usize) = parse_item(bytes, index, "x_off")?;
         index += sz;
-        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_off, sz): (Int16, usize) = parse_item(bytes, index, "y_off")?;
         index += sz;
-        let (traps, block_len): (Vec<Trap>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (traps, block_len): (Vec<Trap>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "traps",
+                offset: index,
+            })? as usize,
+            "traps",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Trap>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AddTrapsRequest {
                 req_type: req_type,
                 length: length,
@@ -3934,58 +7775,139 @@ impl AsByteSequence for AddTrapsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + self.x_off.size()
-            + self.y_off.size()
-            + {
-                let block_len: usize = self.traps.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Trap>());
-                block_len + pad
-            }
+        let size: usize = 12 + {
+            let block_len: usize = self.traps.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Trap>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AddTrapsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AddTrapsRequest {
-    const OPCODE: u8 = 32;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 32;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddTraps";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AddTrapsRequest`."]
+#[derive(Clone, Debug)]
+pub struct AddTrapsRequestBuilder {
+    pub inner: AddTrapsRequest,
+}
+impl AddTrapsRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn x_off(mut self, x_off: Int16) -> Self {
+        self.inner.x_off = x_off;
+        self
+    }
+    #[inline]
+    pub fn y_off(mut self, y_off: Int16) -> Self {
+        self.inner.y_off = y_off;
+        self
+    }
+    #[inline]
+    pub fn traps(mut self, traps: Vec<Trap>) -> Self {
+        self.inner.traps = traps;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AddTrapsRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Trap> for AddTrapsRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Trap>>(&mut self, iter: I) {
+        self.inner.traps.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Trap> for AddTrapsRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Trap>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for AddTrapsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AddTrapsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                x_off: Default::default(),
+                y_off: Default::default(),
+                traps: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateSolidFillRequest {
     pub req_type: u8,
     pub length: u16,
     pub picture: Picture,
     pub color: Color,
 }
-impl CreateSolidFillRequest {}
-impl AsByteSequence for CreateSolidFillRequest {
+impl CreateSolidFillRequest {
     #[inline]
+    pub fn builder() -> CreateSolidFillRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("color", 8, 8),
+    ];
+}
+const _: () = assert!(<CreateSolidFillRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateSolidFillRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.color.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSolidFillRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (color, sz): (Color, usize) = <Color>::from_bytes(&bytes[index..])?;
+        let (color, sz): (Color, usize) = parse_item(bytes, index, "color")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateSolidFillRequest {
                 req_type: req_type,
                 length: length,
@@ -3997,16 +7919,63 @@ impl AsByteSequence for CreateSolidFillRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.picture.size() + self.color.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateSolidFillRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateSolidFillRequest {
-    const OPCODE: u8 = 33;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 33;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateSolidFill";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateSolidFillRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateSolidFillRequestBuilder {
+    pub inner: CreateSolidFillRequest,
+}
+impl CreateSolidFillRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn color(mut self, color: Color) -> Self {
+        self.inner.color = color;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateSolidFillRequest {
+        self.inner
+    }
+}
+impl Default for CreateSolidFillRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateSolidFillRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                color: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateLinearGradientRequest {
     pub req_type: u8,
     pub length: u16,
@@ -4017,13 +7986,53 @@ pub struct CreateLinearGradientRequest {
     pub stops: Vec<Fixed>,
     pub colors: Vec<Color>,
 }
-impl CreateLinearGradientRequest {}
+impl CreateLinearGradientRequest {
+    #[inline]
+    pub fn builder() -> CreateLinearGradientRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("p1", 8, 8),
+        ("p2", 16, 8),
+        ("num_stops", 24, 4),
+    ];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    #[inline]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize = (self.num_stops as usize) as usize;
+            if self.stops.len() != expected {
+                return Err(ValidationError {
+                    list: "stops",
+                    expected,
+                    found: self.stops.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (self.num_stops as usize) as usize;
+            if self.colors.len() != expected {
+                return Err(ValidationError {
+                    list: "colors",
+                    expected,
+                    found: self.colors.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<CreateLinearGradientRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for CreateLinearGradientRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.p1.as_bytes(&mut bytes[index..]);
@@ -4031,38 +8040,45 @@ impl AsByteSequence for CreateLinearGradientRequest {
         index += self.num_stops.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.stops, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fixed>()),
+        );
         let block_len: usize = vector_as_bytes(&self.colors, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Color>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateLinearGradientRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p1, sz): (Pointfix, usize) = parse_item(bytes, index, "p1")?;
         index += sz;
-        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p2, sz): (Pointfix, usize) = parse_item(bytes, index, "p2")?;
         index += sz;
-        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_stops, sz): (Card32, usize) = parse_item(bytes, index, "num_stops")?;
         index += sz;
         let (stops, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            parse_vector(bytes, index, (num_stops as usize) as usize, "stops")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (colors, block_len): (Vec<Color>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            parse_vector(bytes, index, (num_stops as usize) as usize, "colors")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateLinearGradientRequest {
                 req_type: req_type,
                 length: length,
@@ -4076,34 +8092,125 @@ impl AsByteSequence for CreateLinearGradientRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + self.p1.size()
-            + self.p2.size()
-            + self.num_stops.size()
-            + {
+        let size: usize =
+            28 + {
                 let block_len: usize = self.stops.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.colors.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Color>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateLinearGradientRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateLinearGradientRequest {
-    const OPCODE: u8 = 34;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 34;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateLinearGradient";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateLinearGradientRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let picture: Picture = u.arbitrary()?;
+        let p1: Pointfix = u.arbitrary()?;
+        let p2: Pointfix = u.arbitrary()?;
+        let num_stops: Card32 = u.arbitrary()?;
+        let stops = arbitrary_list(u, (num_stops as usize) as usize)?;
+        let colors = arbitrary_list(u, (num_stops as usize) as usize)?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            picture: picture,
+            p1: p1,
+            p2: p2,
+            num_stops: num_stops,
+            stops: stops,
+            colors: colors,
+        })
+    }
+}
+#[doc = " A builder for `CreateLinearGradientRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateLinearGradientRequestBuilder {
+    pub inner: CreateLinearGradientRequest,
+}
+impl CreateLinearGradientRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn p1(mut self, p1: Pointfix) -> Self {
+        self.inner.p1 = p1;
+        self
+    }
+    #[inline]
+    pub fn p2(mut self, p2: Pointfix) -> Self {
+        self.inner.p2 = p2;
+        self
+    }
+    #[inline]
+    pub fn num_stops(mut self, num_stops: Card32) -> Self {
+        self.inner.num_stops = num_stops;
+        self
+    }
+    #[inline]
+    pub fn stops(mut self, stops: Vec<Fixed>) -> Self {
+        self.inner.stops = stops;
+        self
+    }
+    #[inline]
+    pub fn colors(mut self, colors: Vec<Color>) -> Self {
+        self.inner.colors = colors;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateLinearGradientRequest {
+        self.inner
+    }
+}
+impl Default for CreateLinearGradientRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateLinearGradientRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                p1: Default::default(),
+                p2: Default::default(),
+                num_stops: Default::default(),
+                stops: Default::default(),
+                colors: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRadialGradientRequest {
     pub req_type: u8,
     pub length: u16,
@@ -4116,13 +8223,55 @@ pub struct CreateRadialGradientRequest {
     pub stops: Vec<Fixed>,
     pub colors: Vec<Color>,
 }
-impl CreateRadialGradientRequest {}
+impl CreateRadialGradientRequest {
+    #[inline]
+    pub fn builder() -> CreateRadialGradientRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("inner", 8, 8),
+        ("outer", 16, 8),
+        ("inner_radius", 24, 4),
+        ("outer_radius", 28, 4),
+        ("num_stops", 32, 4),
+    ];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    #[inline]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize = (self.num_stops as usize) as usize;
+            if self.stops.len() != expected {
+                return Err(ValidationError {
+                    list: "stops",
+                    expected,
+                    found: self.stops.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (self.num_stops as usize) as usize;
+            if self.colors.len() != expected {
+                return Err(ValidationError {
+                    list: "colors",
+                    expected,
+                    found: self.colors.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<CreateRadialGradientRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for CreateRadialGradientRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.inner.as_bytes(&mut bytes[index..]);
@@ -4132,42 +8281,49 @@ impl AsByteSequence for CreateRadialGradientRequest {
         index += self.num_stops.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.stops, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fixed>()),
+        );
         let block_len: usize = vector_as_bytes(&self.colors, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Color>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRadialGradientRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (inner, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (inner, sz): (Pointfix, usize) = parse_item(bytes, index, "inner")?;
         index += sz;
-        let (outer, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (outer, sz): (Pointfix, usize) = parse_item(bytes, index, "outer")?;
         index += sz;
-        let (inner_radius, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (inner_radius, sz): (Fixed, usize) = parse_item(bytes, index, "inner_radius")?;
         index += sz;
-        let (outer_radius, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (outer_radius, sz): (Fixed, usize) = parse_item(bytes, index, "outer_radius")?;
         index += sz;
-        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_stops, sz): (Card32, usize) = parse_item(bytes, index, "num_stops")?;
         index += sz;
         let (stops, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            parse_vector(bytes, index, (num_stops as usize) as usize, "stops")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (colors, block_len): (Vec<Color>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            parse_vector(bytes, index, (num_stops as usize) as usize, "colors")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateRadialGradientRequest {
                 req_type: req_type,
                 length: length,
@@ -4183,36 +8339,141 @@ impl AsByteSequence for CreateRadialGradientRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + self.inner.size()
-            + self.outer.size()
-            + self.inner_radius.size()
-            + self.outer_radius.size()
-            + self.num_stops.size()
-            + {
+        let size: usize =
+            36 + {
                 let block_len: usize = self.stops.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.colors.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Color>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateRadialGradientRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateRadialGradientRequest {
-    const OPCODE: u8 = 35;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 35;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRadialGradient";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateRadialGradientRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let picture: Picture = u.arbitrary()?;
+        let inner: Pointfix = u.arbitrary()?;
+        let outer: Pointfix = u.arbitrary()?;
+        let inner_radius: Fixed = u.arbitrary()?;
+        let outer_radius: Fixed = u.arbitrary()?;
+        let num_stops: Card32 = u.arbitrary()?;
+        let stops = arbitrary_list(u, (num_stops as usize) as usize)?;
+        let colors = arbitrary_list(u, (num_stops as usize) as usize)?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            picture: picture,
+            inner: inner,
+            outer: outer,
+            inner_radius: inner_radius,
+            outer_radius: outer_radius,
+            num_stops: num_stops,
+            stops: stops,
+            colors: colors,
+        })
+    }
+}
+#[doc = " A builder for `CreateRadialGradientRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRadialGradientRequestBuilder {
+    pub inner: CreateRadialGradientRequest,
+}
+impl CreateRadialGradientRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn inner(mut self, inner: Pointfix) -> Self {
+        self.inner.inner = inner;
+        self
+    }
+    #[inline]
+    pub fn outer(mut self, outer: Pointfix) -> Self {
+        self.inner.outer = outer;
+        self
+    }
+    #[inline]
+    pub fn inner_radius(mut self, inner_radius: Fixed) -> Self {
+        self.inner.inner_radius = inner_radius;
+        self
+    }
+    #[inline]
+    pub fn outer_radius(mut self, outer_radius: Fixed) -> Self {
+        self.inner.outer_radius = outer_radius;
+        self
+    }
+    #[inline]
+    pub fn num_stops(mut self, num_stops: Card32) -> Self {
+        self.inner.num_stops = num_stops;
+        self
+    }
+    #[inline]
+    pub fn stops(mut self, stops: Vec<Fixed>) -> Self {
+        self.inner.stops = stops;
+        self
+    }
+    #[inline]
+    pub fn colors(mut self, colors: Vec<Color>) -> Self {
+        self.inner.colors = colors;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRadialGradientRequest {
+        self.inner
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl Default for CreateRadialGradientRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRadialGradientRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                inner: Default::default(),
+                outer: Default::default(),
+                inner_radius: Default::default(),
+                outer_radius: Default::default(),
+                num_stops: Default::default(),
+                stops: Default::default(),
+                colors: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateConicalGradientRequest {
     pub req_type: u8,
     pub length: u16,
@@ -4223,13 +8484,53 @@ pub struct CreateConicalGradientRequest {
     pub stops: Vec<Fixed>,
     pub colors: Vec<Color>,
 }
-impl CreateConicalGradientRequest {}
+impl CreateConicalGradientRequest {
+    #[inline]
+    pub fn builder() -> CreateConicalGradientRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("picture", 4, 4),
+        ("center", 8, 8),
+        ("angle", 16, 4),
+        ("num_stops", 20, 4),
+    ];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    #[inline]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize = (self.num_stops as usize) as usize;
+            if self.stops.len() != expected {
+                return Err(ValidationError {
+                    list: "stops",
+                    expected,
+                    found: self.stops.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (self.num_stops as usize) as usize;
+            if self.colors.len() != expected {
+                return Err(ValidationError {
+                    list: "colors",
+                    expected,
+                    found: self.colors.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<CreateConicalGradientRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for CreateConicalGradientRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.center.as_bytes(&mut bytes[index..]);
@@ -4237,38 +8538,45 @@ impl AsByteSequence for CreateConicalGradientRequest {
         index += self.num_stops.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.stops, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fixed>()),
+        );
         let block_len: usize = vector_as_bytes(&self.colors, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Color>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateConicalGradientRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (center, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (center, sz): (Pointfix, usize) = parse_item(bytes, index, "center")?;
         index += sz;
-        let (angle, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (angle, sz): (Fixed, usize) = parse_item(bytes, index, "angle")?;
         index += sz;
-        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_stops, sz): (Card32, usize) = parse_item(bytes, index, "num_stops")?;
         index += sz;
         let (stops, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            parse_vector(bytes, index, (num_stops as usize) as usize, "stops")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (colors, block_len): (Vec<Color>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            parse_vector(bytes, index, (num_stops as usize) as usize, "colors")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateConicalGradientRequest {
                 req_type: req_type,
                 length: length,
@@ -4282,66 +8590,126 @@ impl AsByteSequence for CreateConicalGradientRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + self.center.size()
-            + self.angle.size()
-            + self.num_stops.size()
-            + {
+        let size: usize =
+            24 + {
                 let block_len: usize = self.stops.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.colors.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Color>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateConicalGradientRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateConicalGradientRequest {
-    const OPCODE: u8 = 36;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
+    const MINOR_OPCODE: u16 = 36;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateConicalGradient";
     type Reply = ();
-}
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum PolyMode {
-    Precise = 0,
-    Imprecise = 1,
-}
-impl AsByteSequence for PolyMode {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        (*self as i32).as_bytes(bytes)
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateConicalGradientRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let picture: Picture = u.arbitrary()?;
+        let center: Pointfix = u.arbitrary()?;
+        let angle: Fixed = u.arbitrary()?;
+        let num_stops: Card32 = u.arbitrary()?;
+        let stops = arbitrary_list(u, (num_stops as usize) as usize)?;
+        let colors = arbitrary_list(u, (num_stops as usize) as usize)?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            picture: picture,
+            center: center,
+            angle: angle,
+            num_stops: num_stops,
+            stops: stops,
+            colors: colors,
+        })
+    }
+}
+#[doc = " A builder for `CreateConicalGradientRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateConicalGradientRequestBuilder {
+    pub inner: CreateConicalGradientRequest,
+}
+impl CreateConicalGradientRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        match underlying {
-            0 => Some((Self::Precise, sz)),
-            1 => Some((Self::Imprecise, sz)),
-            _ => None,
-        }
+    pub fn center(mut self, center: Pointfix) -> Self {
+        self.inner.center = center;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        ::core::mem::size_of::<i32>()
+    pub fn angle(mut self, angle: Fixed) -> Self {
+        self.inner.angle = angle;
+        self
+    }
+    #[inline]
+    pub fn num_stops(mut self, num_stops: Card32) -> Self {
+        self.inner.num_stops = num_stops;
+        self
+    }
+    #[inline]
+    pub fn stops(mut self, stops: Vec<Fixed>) -> Self {
+        self.inner.stops = stops;
+        self
+    }
+    #[inline]
+    pub fn colors(mut self, colors: Vec<Color>) -> Self {
+        self.inner.colors = colors;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateConicalGradientRequest {
+        self.inner
     }
 }
-impl Default for PolyMode {
+impl Default for CreateConicalGradientRequestBuilder {
     #[inline]
-    fn default() -> PolyMode {
-        PolyMode::Precise
+    fn default() -> Self {
+        Self {
+            inner: CreateConicalGradientRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                center: Default::default(),
+                angle: Default::default(),
+                num_stops: Default::default(),
+                stops: Default::default(),
+                colors: Default::default(),
+            },
+        }
     }
 }
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum PolyEdge {
     Sharp = 0,
     Smooth = 1,
@@ -4352,12 +8720,12 @@ impl AsByteSequence for PolyEdge {
         (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Sharp, sz)),
-            1 => Some((Self::Smooth, sz)),
-            _ => None,
+            0 => Ok((Self::Sharp, sz)),
+            1 => Ok((Self::Smooth, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -4365,38 +8733,56 @@ impl AsByteSequence for PolyEdge {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for PolyEdge {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for PolyEdge {
     #[inline]
     fn default() -> PolyEdge {
         PolyEdge::Sharp
     }
 }
+impl From<PolyEdge> for i32 {
+    #[inline]
+    fn from(base: PolyEdge) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for PolyEdge {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::Sharp),
+            1 => Ok(Self::Smooth),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum SubPixel {
-    Unknown = 0,
-    HorizontalRgb = 1,
-    HorizontalBgr = 2,
-    VerticalRgb = 3,
-    VerticalBgr = 4,
-    None = 5,
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum PolyMode {
+    Precise = 0,
+    Imprecise = 1,
 }
-impl AsByteSequence for SubPixel {
+impl AsByteSequence for PolyMode {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Unknown, sz)),
-            1 => Some((Self::HorizontalRgb, sz)),
-            2 => Some((Self::HorizontalBgr, sz)),
-            3 => Some((Self::VerticalRgb, sz)),
-            4 => Some((Self::VerticalBgr, sz)),
-            5 => Some((Self::None, sz)),
-            _ => None,
+            0 => Ok((Self::Precise, sz)),
+            1 => Ok((Self::Imprecise, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -4404,14 +8790,40 @@ impl AsByteSequence for SubPixel {
         ::core::mem::size_of::<i32>()
     }
 }
-impl Default for SubPixel {
+impl core::convert::TryFrom<&[u8]> for PolyMode {
+    type Error = ParseError;
     #[inline]
-    fn default() -> SubPixel {
-        SubPixel::Unknown
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Default for PolyMode {
+    #[inline]
+    fn default() -> PolyMode {
+        PolyMode::Precise
+    }
+}
+impl From<PolyMode> for i32 {
+    #[inline]
+    fn from(base: PolyMode) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for PolyMode {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::Precise),
+            1 => Ok(Self::Imprecise),
+            _ => Err(value),
+        }
     }
 }
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Repeat {
     None = 0,
     Normal = 1,
@@ -4424,14 +8836,14 @@ impl AsByteSequence for Repeat {
         (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::None, sz)),
-            1 => Some((Self::Normal, sz)),
-            2 => Some((Self::Pad, sz)),
-            3 => Some((Self::Reflect, sz)),
-            _ => None,
+            0 => Ok((Self::None, sz)),
+            1 => Ok((Self::Normal, sz)),
+            2 => Ok((Self::Pad, sz)),
+            3 => Ok((Self::Reflect, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -4439,9 +8851,163 @@ impl AsByteSequence for Repeat {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Repeat {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Repeat {
     #[inline]
     fn default() -> Repeat {
         Repeat::None
     }
 }
+impl From<Repeat> for i32 {
+    #[inline]
+    fn from(base: Repeat) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for Repeat {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Normal),
+            2 => Ok(Self::Pad),
+            3 => Ok(Self::Reflect),
+            _ => Err(value),
+        }
+    }
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum SubPixel {
+    Unknown = 0,
+    HorizontalRgb = 1,
+    HorizontalBgr = 2,
+    VerticalRgb = 3,
+    VerticalBgr = 4,
+    None = 5,
+}
+impl AsByteSequence for SubPixel {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        (*self as i32).as_bytes(bytes)
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
+        match underlying {
+            0 => Ok((Self::Unknown, sz)),
+            1 => Ok((Self::HorizontalRgb, sz)),
+            2 => Ok((Self::HorizontalBgr, sz)),
+            3 => Ok((Self::VerticalRgb, sz)),
+            4 => Ok((Self::VerticalBgr, sz)),
+            5 => Ok((Self::None, sz)),
+            _ => Err(unreadable::<Self>()),
+        }
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        ::core::mem::size_of::<i32>()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SubPixel {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Default for SubPixel {
+    #[inline]
+    fn default() -> SubPixel {
+        SubPixel::Unknown
+    }
+}
+impl From<SubPixel> for i32 {
+    #[inline]
+    fn from(base: SubPixel) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for SubPixel {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::HorizontalRgb),
+            2 => Ok(Self::HorizontalBgr),
+            3 => Ok(Self::VerticalRgb),
+            4 => Ok(Self::VerticalBgr),
+            5 => Ok(Self::None),
+            _ => Err(value),
+        }
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "QueryPictFormats"),
+    (2, "QueryPictIndexValues"),
+    (4, "CreatePicture"),
+    (5, "ChangePicture"),
+    (6, "SetPictureClipRectangles"),
+    (7, "FreePicture"),
+    (8, "Composite"),
+    (10, "Trapezoids"),
+    (11, "Triangles"),
+    (12, "TriStrip"),
+    (13, "TriFan"),
+    (17, "CreateGlyphSet"),
+    (18, "ReferenceGlyphSet"),
+    (19, "FreeGlyphSet"),
+    (20, "AddGlyphs"),
+    (22, "FreeGlyphs"),
+    (23, "CompositeGlyphs8"),
+    (24, "CompositeGlyphs16"),
+    (25, "CompositeGlyphs32"),
+    (26, "FillRectangles"),
+    (27, "CreateCursor"),
+    (28, "SetPictureTransform"),
+    (29, "QueryFilters"),
+    (30, "SetPictureFilter"),
+    (31, "CreateAnimCursor"),
+    (32, "AddTraps"),
+    (33, "CreateSolidFill"),
+    (34, "CreateLinearGradient"),
+    (35, "CreateRadialGradient"),
+    (36, "CreateConicalGradient"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,17 +1,41 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "res")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 2;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Client {
     pub resource_base: Card32,
     pub resource_mask: Card32,
 }
-impl Client {}
+impl Client {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(resource_base: Card32, resource_mask: Card32) -> Self {
+        Self {
+            resource_base: resource_base,
+            resource_mask: resource_mask,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("resource_base", 0, 4), ("resource_mask", 4, 4)];
+}
 impl AsByteSequence for Client {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -21,14 +45,14 @@ impl AsByteSequence for Client {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Client from byte buffer");
-        let (resource_base, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource_base, sz): (Card32, usize) = parse_item(bytes, index, "resource_base")?;
         index += sz;
-        let (resource_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource_mask, sz): (Card32, usize) = parse_item(bytes, index, "resource_mask")?;
         index += sz;
-        Some((
+        Ok((
             Client {
                 resource_base: resource_base,
                 resource_mask: resource_mask,
@@ -38,15 +62,27 @@ impl AsByteSequence for Client {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.resource_base.size() + self.resource_mask.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Client {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Type {
     pub resource_type: Atom,
     pub count: Card32,
 }
-impl Type {}
+impl Type {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for Type {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -56,14 +92,14 @@ impl AsByteSequence for Type {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Type from byte buffer");
-        let (resource_type, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (resource_type, sz): (Atom, usize) = parse_item(bytes, index, "resource_type")?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = parse_item(bytes, index, "count")?;
         index += sz;
-        Some((
+        Ok((
             Type {
                 resource_type: resource_type,
                 count: count,
@@ -73,15 +109,34 @@ impl AsByteSequence for Type {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.resource_type.size() + self.count.size()
+        4 + self.resource_type.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Type {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ClientIdSpec {
     pub client: Card32,
     pub mask: ClientIdMask,
 }
-impl ClientIdSpec {}
+impl ClientIdSpec {
+    #[inline]
+    pub const fn new(client: Card32, mask: ClientIdMask) -> Self {
+        Self {
+            client: client,
+            mask: mask,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("client", 0, 4), ("mask", 4, 4)];
+}
 impl AsByteSequence for ClientIdSpec {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -91,14 +146,14 @@ impl AsByteSequence for ClientIdSpec {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientIdSpec from byte buffer");
-        let (client, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client, sz): (Card32, usize) = parse_item(bytes, index, "client")?;
         index += sz;
-        let (mask, sz): (ClientIdMask, usize) = <ClientIdMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ClientIdMask, usize) = parse_item(bytes, index, "mask")?;
         index += sz;
-        Some((
+        Ok((
             ClientIdSpec {
                 client: client,
                 mask: mask,
@@ -108,11 +163,20 @@ impl AsByteSequence for ClientIdSpec {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.client.size() + self.mask.size()
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ClientIdSpec {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ClientIdMask {
     pub inner: u32,
 }
@@ -162,19 +226,39 @@ impl ClientIdMask {
     pub const LOCAL_CLIENT_PID: Self = Self { inner: 2 };
     pub const COMPLETE: Self = Self { inner: 3 };
 }
+impl core::ops::Deref for ClientIdMask {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &u32 {
+        &self.inner
+    }
+}
+impl AsRef<u32> for ClientIdMask {
+    #[inline]
+    fn as_ref(&self) -> &u32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for ClientIdMask {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((ClientIdMask { inner: inner }, sz))
+        Ok((ClientIdMask { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ClientIdMask {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for ClientIdMask {
@@ -211,37 +295,53 @@ impl core::ops::BitXor for ClientIdMask {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ClientIdValue {
     pub spec: ClientIdSpec,
     pub length: Card32,
     pub value: Vec<Card32>,
 }
-impl ClientIdValue {}
-impl AsByteSequence for ClientIdValue {
+impl ClientIdValue {
     #[inline]
+    pub fn value_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (spec, sz): (ClientIdSpec, usize) = parse_item(bytes, index, "spec")?;
+        index += sz;
+        let (length, sz): (Card32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (value, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, ((length as usize) / (4)) as usize, "value")?;
+        Ok(value)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("spec", 0, 8), ("length", 8, 4)];
+}
+impl AsByteSequence for ClientIdValue {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.spec.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.value, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientIdValue from byte buffer");
-        let (spec, sz): (ClientIdSpec, usize) = <ClientIdSpec>::from_bytes(&bytes[index..])?;
+        let (spec, sz): (ClientIdSpec, usize) = parse_item(bytes, index, "spec")?;
         index += sz;
-        let (length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
         let (value, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) / (4)) as usize)?;
+            parse_vector(bytes, index, ((length as usize) / (4)) as usize, "value")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        Ok((
             ClientIdValue {
                 spec: spec,
                 length: length,
@@ -252,19 +352,68 @@ impl AsByteSequence for ClientIdValue {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.spec.size() + self.length.size() + {
+        12 + {
             let block_len: usize = self.value.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
             block_len + pad
         }
     }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        12 + {
+            let block_len: usize = self.value.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        }
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ClientIdValue {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for ClientIdValue {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let spec: ClientIdSpec = u.arbitrary()?;
+        let length: Card32 = u.arbitrary()?;
+        let value = arbitrary_list(u, ((length as usize) / (4)) as usize)?;
+        Ok(Self {
+            spec: spec,
+            length: length,
+            value: value,
+        })
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ResourceIdSpec {
     pub resource: Card32,
     pub ty: Card32,
 }
-impl ResourceIdSpec {}
+impl ResourceIdSpec {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(resource: Card32, ty: Card32) -> Self {
+        Self {
+            resource: resource,
+            ty: ty,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("resource", 0, 4), ("ty", 4, 4)];
+}
 impl AsByteSequence for ResourceIdSpec {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -274,14 +423,14 @@ impl AsByteSequence for ResourceIdSpec {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceIdSpec from byte buffer");
-        let (resource, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource, sz): (Card32, usize) = parse_item(bytes, index, "resource")?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        Some((
+        Ok((
             ResourceIdSpec {
                 resource: resource,
                 ty: ty,
@@ -291,17 +440,48 @@ impl AsByteSequence for ResourceIdSpec {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.resource.size() + self.ty.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ResourceIdSpec {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ResourceSizeSpec {
     pub spec: ResourceIdSpec,
     pub bytes_: Card32,
     pub ref_count: Card32,
     pub use_count: Card32,
 }
-impl ResourceSizeSpec {}
+impl ResourceSizeSpec {
+    #[inline]
+    pub const fn new(
+        spec: ResourceIdSpec,
+        bytes_: Card32,
+        ref_count: Card32,
+        use_count: Card32,
+    ) -> Self {
+        Self {
+            spec: spec,
+            bytes_: bytes_,
+            ref_count: ref_count,
+            use_count: use_count,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("spec", 0, 8),
+        ("bytes_", 8, 4),
+        ("ref_count", 12, 4),
+        ("use_count", 16, 4),
+    ];
+}
 impl AsByteSequence for ResourceSizeSpec {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -312,19 +492,18 @@ impl AsByteSequence for ResourceSizeSpec {
         index += self.use_count.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceSizeSpec from byte buffer");
-        let (spec, sz): (ResourceIdSpec, usize) = <ResourceIdSpec>::from_bytes(&bytes[index..])?;
+        let (spec, sz): (ResourceIdSpec, usize) = parse_item(bytes, index, "spec")?;
         index += sz;
-        let (bytes_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_, sz): (Card32, usize) = parse_item(bytes, index, "bytes_")?;
         index += sz;
-        let (ref_count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ref_count, sz): (Card32, usize) = parse_item(bytes, index, "ref_count")?;
         index += sz;
-        let (use_count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (use_count, sz): (Card32, usize) = parse_item(bytes, index, "use_count")?;
         index += sz;
-        Some((
+        Ok((
             ResourceSizeSpec {
                 spec: spec,
                 bytes_: bytes_,
@@ -336,40 +515,53 @@ impl AsByteSequence for ResourceSizeSpec {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.spec.size() + self.bytes_.size() + self.ref_count.size() + self.use_count.size()
+        20
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ResourceSizeSpec {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ResourceSizeValue {
     pub size: ResourceSizeSpec,
     pub cross_references: Vec<ResourceSizeSpec>,
 }
-impl ResourceSizeValue {}
+impl ResourceSizeValue {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("size", 0, 20), ("cross_references_len", 20, 4)];
+}
 impl AsByteSequence for ResourceSizeValue {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.size.as_bytes(&mut bytes[index..]);
         index += (self.cross_references.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.cross_references, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeSpec>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeSpec>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceSizeValue from byte buffer");
-        let (size, sz): (ResourceSizeSpec, usize) =
-            <ResourceSizeSpec>::from_bytes(&bytes[index..])?;
+        let (size, sz): (ResourceSizeSpec, usize) = parse_item(bytes, index, "size")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (cross_references, block_len): (Vec<ResourceSizeSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "cross_references")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeSpec>());
-        Some((
+        Ok((
             ResourceSizeValue {
                 size: size,
                 cross_references: cross_references,
@@ -379,46 +571,83 @@ impl AsByteSequence for ResourceSizeValue {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.size.size() + ::core::mem::size_of::<Card32>() + {
+        24 + {
             let block_len: usize = self.cross_references.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeSpec>());
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ResourceSizeValue {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major: Card8,
     pub client_minor: Card8,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major", 4, 1),
+        ("client_minor", 5, 1),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major.as_bytes(&mut bytes[index..]);
         index += self.client_minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_major, sz): (Card8, usize) = parse_item(bytes, index, "client_major")?;
         index += sz;
-        let (client_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_minor, sz): (Card8, usize) = parse_item(bytes, index, "client_minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -430,20 +659,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major.size()
-            + self.client_minor.size()
+        let size: usize = 6;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn client_major(mut self, client_major: Card8) -> Self {
+        self.inner.client_major = client_major;
+        self
+    }
+    #[inline]
+    pub fn client_minor(mut self, client_minor: Card8) -> Self {
+        self.inner.client_minor = client_minor;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major: Default::default(),
+                client_minor: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -451,35 +723,105 @@ pub struct QueryVersionReply {
     pub server_major: Card16,
     pub server_minor: Card16,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let server_major: Card16 = read_item(reader, &mut index, 2, "server_major")?;
+        let server_minor: Card16 = read_item(reader, &mut index, 2, "server_minor")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            server_major: server_major,
+            server_minor: server_minor,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("server_major", 8, 2),
+        ("server_minor", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.server_major == other.server_major && self.server_minor == other.server_minor
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "server_major",
+            &self.server_major,
+            &other.server_major,
+            &mut changes,
+        );
+        diff_value(
+            "server_minor",
+            &self.server_minor,
+            &other.server_minor,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.server_major.as_bytes(&mut bytes[index..]);
         index += self.server_minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (server_major, sz): (Card16, usize) = parse_item(bytes, index, "server_major")?;
         index += sz;
-        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor, sz): (Card16, usize) = parse_item(bytes, index, "server_minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -492,39 +834,69 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.server_major.size()
-            + self.server_minor.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryClientsRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryClientsRequest {}
+impl QueryClientsRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryClientsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryClientsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryClientsRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryClientsRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryClientsRequest {
                 req_type: req_type,
                 length: length,
@@ -534,57 +906,168 @@ impl AsByteSequence for QueryClientsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryClientsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryClientsRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClients";
     type Reply = QueryClientsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryClientsRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryClientsRequestBuilder {
+    pub inner: QueryClientsRequest,
+}
+impl QueryClientsRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryClientsRequest {
+        self.inner
+    }
+}
+impl Default for QueryClientsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryClientsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryClientsReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub clients: Vec<Client>,
 }
-impl QueryClientsReply {}
-impl AsByteSequence for QueryClientsReply {
+impl QueryClientsReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn clients_iter(bytes: &[u8]) -> Result<ListIter<'_, Client>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "clients"))
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (clients, block_len): (Vec<Client>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 8, "clients")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Client>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryClientsReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            clients: clients,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("clients_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.clients == other.clients
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("clients", &self.clients, &other.clients, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryClientsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.clients.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.clients, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Client>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Client>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (clients, block_len): (Vec<Client>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "clients")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Client>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryClientsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -596,109 +1079,238 @@ impl AsByteSequence for QueryClientsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.clients.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Client>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.clients.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Client>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryClientsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryClientResourcesRequest {
     pub req_type: u8,
     pub length: u16,
     pub xid: Card32,
 }
-impl QueryClientResourcesRequest {}
-impl AsByteSequence for QueryClientResourcesRequest {
+impl QueryClientResourcesRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.xid.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryClientResourcesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (xid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryClientResourcesRequest {
-                req_type: req_type,
-                length: length,
-                xid: xid,
-            },
-            index,
-        ))
+    pub fn builder() -> QueryClientResourcesRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.xid.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryClientResourcesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("xid", 4, 4)];
+}
+const _: () = assert!(<QueryClientResourcesRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_xid_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.xid.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (xid, sz): (Card32, usize) = parse_item(bytes, index, "xid")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        xid: xid,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_xid_only_request_asb! { QueryClientResourcesRequest }
 impl Request for QueryClientResourcesRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClientResources";
     type Reply = QueryClientResourcesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryClientResourcesRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryClientResourcesRequestBuilder {
+    pub inner: QueryClientResourcesRequest,
+}
+impl QueryClientResourcesRequestBuilder {
+    #[inline]
+    pub fn xid(mut self, xid: Card32) -> Self {
+        self.inner.xid = xid;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryClientResourcesRequest {
+        self.inner
+    }
+}
+impl Default for QueryClientResourcesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryClientResourcesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                xid: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryClientResourcesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub types: Vec<Type>,
 }
-impl QueryClientResourcesReply {}
-impl AsByteSequence for QueryClientResourcesReply {
+impl QueryClientResourcesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn types_iter(bytes: &[u8]) -> Result<ListIter<'_, Type>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "types"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("types_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.types == other.types
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("types", &self.types, &other.types, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryClientResourcesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.types.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.types, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Type>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Type>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientResourcesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (types, block_len): (Vec<Type>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "types")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Type>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryClientResourcesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -710,68 +1322,95 @@ impl AsByteSequence for QueryClientResourcesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.types.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Type>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.types.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Type>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryClientResourcesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryClientPixmapBytesRequest {
     pub req_type: u8,
     pub length: u16,
     pub xid: Card32,
 }
-impl QueryClientPixmapBytesRequest {}
-impl AsByteSequence for QueryClientPixmapBytesRequest {
+impl QueryClientPixmapBytesRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.xid.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryClientPixmapBytesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (xid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryClientPixmapBytesRequest {
-                req_type: req_type,
-                length: length,
-                xid: xid,
-            },
-            index,
-        ))
+    pub fn builder() -> QueryClientPixmapBytesRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.xid.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryClientPixmapBytesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("xid", 4, 4)];
 }
+const _: () = assert!(<QueryClientPixmapBytesRequest as Request>::MINOR_OPCODE < 0x100);
+impl_xid_only_request_asb! { QueryClientPixmapBytesRequest }
 impl Request for QueryClientPixmapBytesRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClientPixmapBytes";
     type Reply = QueryClientPixmapBytesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryClientPixmapBytesRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryClientPixmapBytesRequestBuilder {
+    pub inner: QueryClientPixmapBytesRequest,
+}
+impl QueryClientPixmapBytesRequestBuilder {
+    #[inline]
+    pub fn xid(mut self, xid: Card32) -> Self {
+        self.inner.xid = xid;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryClientPixmapBytesRequest {
+        self.inner
+    }
+}
+impl Default for QueryClientPixmapBytesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryClientPixmapBytesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                xid: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryClientPixmapBytesReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -779,35 +1418,100 @@ pub struct QueryClientPixmapBytesReply {
     pub bytes_: Card32,
     pub bytes_overflow: Card32,
 }
-impl QueryClientPixmapBytesReply {}
-impl AsByteSequence for QueryClientPixmapBytesReply {
+impl QueryClientPixmapBytesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let bytes_: Card32 = read_item(reader, &mut index, 4, "bytes_")?;
+        let bytes_overflow: Card32 = read_item(reader, &mut index, 4, "bytes_overflow")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryClientPixmapBytesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            bytes_: bytes_,
+            bytes_overflow: bytes_overflow,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("bytes_", 8, 4),
+        ("bytes_overflow", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.bytes_ == other.bytes_ && self.bytes_overflow == other.bytes_overflow
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("bytes_", &self.bytes_, &other.bytes_, &mut changes);
+        diff_value(
+            "bytes_overflow",
+            &self.bytes_overflow,
+            &other.bytes_overflow,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryClientPixmapBytesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.bytes_.as_bytes(&mut bytes[index..]);
         index += self.bytes_overflow.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientPixmapBytesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (bytes_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (bytes_, sz): (Card32, usize) = parse_item(bytes, index, "bytes_")?;
         index += sz;
-        let (bytes_overflow, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_overflow, sz): (Card32, usize) = parse_item(bytes, index, "bytes_overflow")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryClientPixmapBytesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -820,50 +1524,76 @@ impl AsByteSequence for QueryClientPixmapBytesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.bytes_.size()
-            + self.bytes_overflow.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryClientPixmapBytesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryClientIdsRequest {
     pub req_type: u8,
     pub length: u16,
     pub specs: Vec<ClientIdSpec>,
 }
-impl QueryClientIdsRequest {}
-impl AsByteSequence for QueryClientIdsRequest {
+impl QueryClientIdsRequest {
+    #[inline]
+    pub fn builder() -> QueryClientIdsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryClientIdsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("specs_len", 4, 4)];
+}
+const _: () = assert!(<QueryClientIdsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryClientIdsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.specs.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.specs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientIdSpec>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ClientIdSpec>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientIdsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (specs, block_len): (Vec<ClientIdSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "specs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientIdSpec>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryClientIdsRequest {
                 req_type: req_type,
                 length: length,
@@ -874,61 +1604,167 @@ impl AsByteSequence for QueryClientIdsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
+        let size: usize = 8 + {
             let block_len: usize = self.specs.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientIdSpec>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryClientIdsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryClientIdsRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClientIds";
     type Reply = QueryClientIdsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryClientIdsRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryClientIdsRequestBuilder {
+    pub inner: QueryClientIdsRequest,
+}
+impl QueryClientIdsRequestBuilder {
+    #[inline]
+    pub fn specs(mut self, specs: Vec<ClientIdSpec>) -> Self {
+        self.inner.specs = specs;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryClientIdsRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<ClientIdSpec> for QueryClientIdsRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = ClientIdSpec>>(&mut self, iter: I) {
+        self.inner.specs.extend(iter);
+    }
+}
+impl core::iter::FromIterator<ClientIdSpec> for QueryClientIdsRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = ClientIdSpec>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for QueryClientIdsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryClientIdsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                specs: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryClientIdsReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub ids: Vec<ClientIdValue>,
 }
-impl QueryClientIdsReply {}
-impl AsByteSequence for QueryClientIdsReply {
+impl QueryClientIdsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn ids_iter(bytes: &[u8]) -> Result<ListIter<'_, ClientIdValue>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "ids"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("ids_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.ids == other.ids
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("ids", &self.ids, &other.ids, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryClientIdsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.ids.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.ids, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ClientIdValue>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ClientIdValue>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientIdsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (ids, block_len): (Vec<ClientIdValue>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "ids")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientIdValue>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryClientIdsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -940,59 +1776,88 @@ impl AsByteSequence for QueryClientIdsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.ids.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientIdValue>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.ids.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ClientIdValue>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryClientIdsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryResourceBytesRequest {
     pub req_type: u8,
     pub length: u16,
     pub client: Card32,
     pub specs: Vec<ResourceIdSpec>,
 }
-impl QueryResourceBytesRequest {}
+impl QueryResourceBytesRequest {
+    #[inline]
+    pub fn builder() -> QueryResourceBytesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryResourceBytesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client", 4, 4),
+        ("specs_len", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryResourceBytesRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryResourceBytesRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client.as_bytes(&mut bytes[index..]);
         index += (self.specs.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.specs, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ResourceIdSpec>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ResourceIdSpec>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryResourceBytesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client, sz): (Card32, usize) = parse_item(bytes, index, "client")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (specs, block_len): (Vec<ResourceIdSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "specs")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ResourceIdSpec>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryResourceBytesRequest {
                 req_type: req_type,
                 length: length,
@@ -1004,66 +1869,173 @@ impl AsByteSequence for QueryResourceBytesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client.size()
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.specs.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ResourceIdSpec>());
-                block_len + pad
-            }
+        let size: usize = 12 + {
+            let block_len: usize = self.specs.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ResourceIdSpec>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryResourceBytesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryResourceBytesRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryResourceBytes";
     type Reply = QueryResourceBytesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryResourceBytesRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryResourceBytesRequestBuilder {
+    pub inner: QueryResourceBytesRequest,
+}
+impl QueryResourceBytesRequestBuilder {
+    #[inline]
+    pub fn client(mut self, client: Card32) -> Self {
+        self.inner.client = client;
+        self
+    }
+    #[inline]
+    pub fn specs(mut self, specs: Vec<ResourceIdSpec>) -> Self {
+        self.inner.specs = specs;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryResourceBytesRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<ResourceIdSpec> for QueryResourceBytesRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = ResourceIdSpec>>(&mut self, iter: I) {
+        self.inner.specs.extend(iter);
+    }
+}
+impl core::iter::FromIterator<ResourceIdSpec> for QueryResourceBytesRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = ResourceIdSpec>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for QueryResourceBytesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryResourceBytesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client: Default::default(),
+                specs: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryResourceBytesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub sizes: Vec<ResourceSizeValue>,
 }
-impl QueryResourceBytesReply {}
-impl AsByteSequence for QueryResourceBytesReply {
+impl QueryResourceBytesReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn sizes_iter(bytes: &[u8]) -> Result<ListIter<'_, ResourceSizeValue>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "sizes"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("sizes_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.sizes == other.sizes
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("sizes", &self.sizes, &other.sizes, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryResourceBytesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.sizes.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.sizes, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeValue>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeValue>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryResourceBytesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (sizes, block_len): (Vec<ResourceSizeValue>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "sizes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeValue>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryResourceBytesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1075,17 +2047,53 @@ impl AsByteSequence for QueryResourceBytesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.sizes.iter().map(|i| i.size()).sum();
-                let pad: usize =
-                    buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeValue>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.sizes.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeValue>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
+impl core::convert::TryFrom<&[u8]> for QueryResourceBytesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "QueryClients"),
+    (2, "QueryClientResources"),
+    (3, "QueryClientPixmapBytes"),
+    (4, "QueryClientIds"),
+    (5, "QueryResourceBytes"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
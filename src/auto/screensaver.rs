@@ -1,46 +1,81 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "screensaver")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major_version: Card8,
     pub client_minor_version: Card8,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major_version", 4, 1),
+        ("client_minor_version", 5, 1),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major_version.as_bytes(&mut bytes[index..]);
         index += self.client_minor_version.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card8, usize) =
+            parse_item(bytes, index, "client_major_version")?;
         index += sz;
-        let (client_minor_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card8, usize) =
+            parse_item(bytes, index, "client_minor_version")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -52,21 +87,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major_version.size()
-            + self.client_minor_version.size()
-            + 2
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn client_major_version(mut self, client_major_version: Card8) -> Self {
+        self.inner.client_major_version = client_major_version;
+        self
+    }
+    #[inline]
+    pub fn client_minor_version(mut self, client_minor_version: Card8) -> Self {
+        self.inner.client_minor_version = client_minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major_version: Default::default(),
+                client_minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -74,37 +151,113 @@ pub struct QueryVersionReply {
     pub server_major_version: Card16,
     pub server_minor_version: Card16,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let server_major_version: Card16 =
+            read_item(reader, &mut index, 2, "server_major_version")?;
+        let server_minor_version: Card16 =
+            read_item(reader, &mut index, 2, "server_minor_version")?;
+        skip_bytes(reader, &mut index, 20)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            server_major_version: server_major_version,
+            server_minor_version: server_minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("server_major_version", 8, 2),
+        ("server_minor_version", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.server_major_version == other.server_major_version
+            && self.server_minor_version == other.server_minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "server_major_version",
+            &self.server_major_version,
+            &other.server_major_version,
+            &mut changes,
+        );
+        diff_value(
+            "server_minor_version",
+            &self.server_minor_version,
+            &other.server_minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.server_major_version.as_bytes(&mut bytes[index..]);
         index += self.server_minor_version.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (server_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (server_major_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "server_major_version")?;
         index += sz;
-        let (server_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "server_minor_version")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -117,64 +270,135 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.server_major_version.size()
-            + self.server_minor_version.size()
-            + 20
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryInfoRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
 }
-impl QueryInfoRequest {}
-impl AsByteSequence for QueryInfoRequest {
+impl QueryInfoRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> QueryInfoRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryInfoRequest {
-                req_type: req_type,
-                length: length,
-                drawable: drawable,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<QueryInfoRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_drawable_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.drawable.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        drawable: drawable,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.drawable.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_drawable_only_request_asb! { QueryInfoRequest }
 impl Request for QueryInfoRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryInfo";
     type Reply = QueryInfoReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryInfoRequestBuilder {
+    pub inner: QueryInfoRequest,
+}
+impl QueryInfoRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryInfoRequest {
+        self.inner
+    }
+}
+impl Default for QueryInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryInfoReply {
     pub reply_type: u8,
     pub state: Card8,
@@ -186,9 +410,70 @@ pub struct QueryInfoReply {
     pub event_mask: Card32,
     pub kind: Kind,
 }
-impl QueryInfoReply {}
-impl AsByteSequence for QueryInfoReply {
+impl QueryInfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("state", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.state == other.state
+            && self.saver_window == other.saver_window
+            && self.ms_until_server == other.ms_until_server
+            && self.ms_since_user_input == other.ms_since_user_input
+            && self.event_mask == other.event_mask
+            && self.kind == other.kind
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("state", &self.state, &other.state, &mut changes);
+        diff_value(
+            "saver_window",
+            &self.saver_window,
+            &other.saver_window,
+            &mut changes,
+        );
+        diff_value(
+            "ms_until_server",
+            &self.ms_until_server,
+            &other.ms_until_server,
+            &mut changes,
+        );
+        diff_value(
+            "ms_since_user_input",
+            &self.ms_since_user_input,
+            &other.ms_since_user_input,
+            &mut changes,
+        );
+        diff_value(
+            "event_mask",
+            &self.event_mask,
+            &other.event_mask,
+            &mut changes,
+        );
+        diff_value("kind", &self.kind, &other.kind, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -200,33 +485,36 @@ impl AsByteSequence for QueryInfoReply {
         index += self.ms_since_user_input.as_bytes(&mut bytes[index..]);
         index += self.event_mask.as_bytes(&mut bytes[index..]);
         index += self.kind.as_bytes(&mut bytes[index..]);
-        index += 7;
+        index += zero_pad(&mut bytes[index..], 7);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (state, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card8, usize) = parse_item(bytes, index, "state")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (saver_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (saver_window, sz): (Window, usize) = parse_item(bytes, index, "saver_window")?;
         index += sz;
-        let (ms_until_server, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ms_until_server, sz): (Card32, usize) = parse_item(bytes, index, "ms_until_server")?;
         index += sz;
-        let (ms_since_user_input, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ms_since_user_input, sz): (Card32, usize) =
+            parse_item(bytes, index, "ms_since_user_input")?;
         index += sz;
-        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (Card32, usize) = parse_item(bytes, index, "event_mask")?;
         index += sz;
-        let (kind, sz): (Kind, usize) = <Kind>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Kind, usize) = parse_item(bytes, index, "kind")?;
         index += sz;
         index += 7;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryInfoReply {
                 reply_type: reply_type,
                 state: state,
@@ -243,20 +531,21 @@ impl AsByteSequence for QueryInfoReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.state.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.saver_window.size()
-            + self.ms_until_server.size()
-            + self.ms_since_user_input.size()
-            + self.event_mask.size()
-            + self.kind.size()
-            + 7
+        let size: usize = 28 + self.saver_window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Kind {
     Blanked = 0,
     Internal = 1,
@@ -268,13 +557,13 @@ impl AsByteSequence for Kind {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Blanked, sz)),
-            1 => Some((Self::Internal, sz)),
-            2 => Some((Self::External, sz)),
-            _ => None,
+            0 => Ok((Self::Blanked, sz)),
+            1 => Ok((Self::Internal, sz)),
+            2 => Ok((Self::External, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -282,45 +571,81 @@ impl AsByteSequence for Kind {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Kind {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Kind {
     #[inline]
     fn default() -> Kind {
         Kind::Blanked
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Kind> for u8 {
+    #[inline]
+    fn from(base: Kind) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for Kind {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Blanked),
+            1 => Ok(Self::Internal),
+            2 => Ok(Self::External),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectInputRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub event_mask: Event,
 }
-impl SelectInputRequest {}
-impl AsByteSequence for SelectInputRequest {
+impl SelectInputRequest {
     #[inline]
+    pub fn builder() -> SelectInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SelectInputRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectInputRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (event_mask, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (Event, usize) = parse_item(bytes, index, "event_mask")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectInputRequest {
                 req_type: req_type,
                 length: length,
@@ -332,21 +657,64 @@ impl AsByteSequence for SelectInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.event_mask.size()
+        let size: usize = 8 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectInputRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
+#[doc = " A builder for `SelectInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectInputRequestBuilder {
+    pub inner: SelectInputRequest,
+}
+impl SelectInputRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn event_mask(mut self, event_mask: Event) -> Self {
+        self.inner.event_mask = event_mask;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectInputRequest {
+        self.inner
+    }
+}
+impl Default for SelectInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                event_mask: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Event {
     pub inner: u32,
 }
@@ -396,19 +764,39 @@ impl Event {
     pub const CYCLE_MASK: Self = Self { inner: 2 };
     pub const COMPLETE: Self = Self { inner: 3 };
 }
+impl core::ops::Deref for Event {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &u32 {
+        &self.inner
+    }
+}
+impl AsRef<u32> for Event {
+    #[inline]
+    fn as_ref(&self) -> &u32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for Event {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((Event { inner: inner }, sz))
+        Ok((Event { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Event {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for Event {
@@ -445,7 +833,10 @@ impl core::ops::BitXor for Event {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetAttributesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -459,29 +850,246 @@ pub struct SetAttributesRequest {
     pub depth: Card8,
     pub visual: Visualid,
     pub value_mask: Cw,
-    pub background_pixmap: Pixmap,
-    pub background_pixel: Card32,
-    pub border_pixmap: Pixmap,
-    pub border_pixel: Card32,
-    pub bit_gravity: Gravity,
-    pub win_gravity: Gravity,
-    pub backing_store: BackingStore,
-    pub backing_planes: Card32,
-    pub backing_pixel: Card32,
-    pub override_redirect: Bool32,
-    pub save_under: Bool32,
-    pub event_mask: EventMask,
-    pub do_not_propogate_mask: EventMask,
-    pub colormap: Colormap,
-    pub cursor: Cursor,
-}
-impl SetAttributesRequest {}
-impl AsByteSequence for SetAttributesRequest {
+    pub background_pixmap: Option<Pixmap>,
+    pub background_pixel: Option<Card32>,
+    pub border_pixmap: Option<Pixmap>,
+    pub border_pixel: Option<Card32>,
+    pub bit_gravity: Option<Gravity>,
+    pub win_gravity: Option<Gravity>,
+    pub backing_store: Option<BackingStore>,
+    pub backing_planes: Option<Card32>,
+    pub backing_pixel: Option<Card32>,
+    pub override_redirect: Option<Bool32>,
+    pub save_under: Option<Bool32>,
+    pub event_mask: Option<EventMask>,
+    pub do_not_propogate_mask: Option<EventMask>,
+    pub colormap: Option<Colormap>,
+    pub cursor: Option<Cursor>,
+}
+impl SetAttributesRequest {
+    #[inline]
+    pub fn builder() -> SetAttributesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+    #[doc = " Set `background_pixmap`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_background_pixmap(&mut self, background_pixmap: Pixmap) -> &mut Self {
+        self.value_mask.set_back_pixmap(true);
+        self.background_pixmap = Some(background_pixmap);
+        self
+    }
+    #[doc = " Leave out `background_pixmap`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_background_pixmap(&mut self) -> &mut Self {
+        self.value_mask.set_back_pixmap(false);
+        self.background_pixmap = None;
+        self
+    }
+    #[doc = " Set `background_pixel`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_background_pixel(&mut self, background_pixel: Card32) -> &mut Self {
+        self.value_mask.set_back_pixel(true);
+        self.background_pixel = Some(background_pixel);
+        self
+    }
+    #[doc = " Leave out `background_pixel`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_background_pixel(&mut self) -> &mut Self {
+        self.value_mask.set_back_pixel(false);
+        self.background_pixel = None;
+        self
+    }
+    #[doc = " Set `border_pixmap`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_border_pixmap(&mut self, border_pixmap: Pixmap) -> &mut Self {
+        self.value_mask.set_border_pixmap(true);
+        self.border_pixmap = Some(border_pixmap);
+        self
+    }
+    #[doc = " Leave out `border_pixmap`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_border_pixmap(&mut self) -> &mut Self {
+        self.value_mask.set_border_pixmap(false);
+        self.border_pixmap = None;
+        self
+    }
+    #[doc = " Set `border_pixel`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_border_pixel(&mut self, border_pixel: Card32) -> &mut Self {
+        self.value_mask.set_border_pixel(true);
+        self.border_pixel = Some(border_pixel);
+        self
+    }
+    #[doc = " Leave out `border_pixel`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_border_pixel(&mut self) -> &mut Self {
+        self.value_mask.set_border_pixel(false);
+        self.border_pixel = None;
+        self
+    }
+    #[doc = " Set `bit_gravity`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_bit_gravity(&mut self, bit_gravity: Gravity) -> &mut Self {
+        self.value_mask.set_bit_gravity(true);
+        self.bit_gravity = Some(bit_gravity);
+        self
+    }
+    #[doc = " Leave out `bit_gravity`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_bit_gravity(&mut self) -> &mut Self {
+        self.value_mask.set_bit_gravity(false);
+        self.bit_gravity = None;
+        self
+    }
+    #[doc = " Set `win_gravity`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_win_gravity(&mut self, win_gravity: Gravity) -> &mut Self {
+        self.value_mask.set_win_gravity(true);
+        self.win_gravity = Some(win_gravity);
+        self
+    }
+    #[doc = " Leave out `win_gravity`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_win_gravity(&mut self) -> &mut Self {
+        self.value_mask.set_win_gravity(false);
+        self.win_gravity = None;
+        self
+    }
+    #[doc = " Set `backing_store`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_backing_store(&mut self, backing_store: BackingStore) -> &mut Self {
+        self.value_mask.set_backing_store(true);
+        self.backing_store = Some(backing_store);
+        self
+    }
+    #[doc = " Leave out `backing_store`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_backing_store(&mut self) -> &mut Self {
+        self.value_mask.set_backing_store(false);
+        self.backing_store = None;
+        self
+    }
+    #[doc = " Set `backing_planes`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_backing_planes(&mut self, backing_planes: Card32) -> &mut Self {
+        self.value_mask.set_backing_planes(true);
+        self.backing_planes = Some(backing_planes);
+        self
+    }
+    #[doc = " Leave out `backing_planes`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_backing_planes(&mut self) -> &mut Self {
+        self.value_mask.set_backing_planes(false);
+        self.backing_planes = None;
+        self
+    }
+    #[doc = " Set `backing_pixel`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_backing_pixel(&mut self, backing_pixel: Card32) -> &mut Self {
+        self.value_mask.set_backing_pixel(true);
+        self.backing_pixel = Some(backing_pixel);
+        self
+    }
+    #[doc = " Leave out `backing_pixel`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_backing_pixel(&mut self) -> &mut Self {
+        self.value_mask.set_backing_pixel(false);
+        self.backing_pixel = None;
+        self
+    }
+    #[doc = " Set `override_redirect`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_override_redirect(&mut self, override_redirect: Bool32) -> &mut Self {
+        self.value_mask.set_override_redirect(true);
+        self.override_redirect = Some(override_redirect);
+        self
+    }
+    #[doc = " Leave out `override_redirect`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_override_redirect(&mut self) -> &mut Self {
+        self.value_mask.set_override_redirect(false);
+        self.override_redirect = None;
+        self
+    }
+    #[doc = " Set `save_under`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_save_under(&mut self, save_under: Bool32) -> &mut Self {
+        self.value_mask.set_save_under(true);
+        self.save_under = Some(save_under);
+        self
+    }
+    #[doc = " Leave out `save_under`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_save_under(&mut self) -> &mut Self {
+        self.value_mask.set_save_under(false);
+        self.save_under = None;
+        self
+    }
+    #[doc = " Set `event_mask`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_event_mask(&mut self, event_mask: EventMask) -> &mut Self {
+        self.value_mask.set_event_mask(true);
+        self.event_mask = Some(event_mask);
+        self
+    }
+    #[doc = " Leave out `event_mask`, and clear the bit of `value_mask` that says it is present."]
     #[inline]
+    pub fn clear_event_mask(&mut self) -> &mut Self {
+        self.value_mask.set_event_mask(false);
+        self.event_mask = None;
+        self
+    }
+    #[doc = " Set `do_not_propogate_mask`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_do_not_propogate_mask(&mut self, do_not_propogate_mask: EventMask) -> &mut Self {
+        self.value_mask.set_dont_propagate(true);
+        self.do_not_propogate_mask = Some(do_not_propogate_mask);
+        self
+    }
+    #[doc = " Leave out `do_not_propogate_mask`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_do_not_propogate_mask(&mut self) -> &mut Self {
+        self.value_mask.set_dont_propagate(false);
+        self.do_not_propogate_mask = None;
+        self
+    }
+    #[doc = " Set `colormap`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_colormap(&mut self, colormap: Colormap) -> &mut Self {
+        self.value_mask.set_colormap(true);
+        self.colormap = Some(colormap);
+        self
+    }
+    #[doc = " Leave out `colormap`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_colormap(&mut self) -> &mut Self {
+        self.value_mask.set_colormap(false);
+        self.colormap = None;
+        self
+    }
+    #[doc = " Set `cursor`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_cursor(&mut self, cursor: Cursor) -> &mut Self {
+        self.value_mask.set_cursor(true);
+        self.cursor = Some(cursor);
+        self
+    }
+    #[doc = " Leave out `cursor`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_cursor(&mut self) -> &mut Self {
+        self.value_mask.set_cursor(false);
+        self.cursor = None;
+        self
+    }
+}
+const _: () = assert!(<SetAttributesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetAttributesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.x.as_bytes(&mut bytes[index..]);
@@ -492,8 +1100,23 @@ impl AsByteSequence for SetAttributesRequest {
         index += self.class.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += self.visual.as_bytes(&mut bytes[index..]);
-        index += self.value_mask.as_bytes(&mut bytes[index..]);
-        let cond0 = (self.value_mask);
+        let mut cond0: Cw = self.value_mask;
+        cond0.set_back_pixmap(self.background_pixmap.is_some());
+        cond0.set_back_pixel(self.background_pixel.is_some());
+        cond0.set_border_pixmap(self.border_pixmap.is_some());
+        cond0.set_border_pixel(self.border_pixel.is_some());
+        cond0.set_bit_gravity(self.bit_gravity.is_some());
+        cond0.set_win_gravity(self.win_gravity.is_some());
+        cond0.set_backing_store(self.backing_store.is_some());
+        cond0.set_backing_planes(self.backing_planes.is_some());
+        cond0.set_backing_pixel(self.backing_pixel.is_some());
+        cond0.set_override_redirect(self.override_redirect.is_some());
+        cond0.set_save_under(self.save_under.is_some());
+        cond0.set_event_mask(self.event_mask.is_some());
+        cond0.set_dont_propagate(self.do_not_propogate_mask.is_some());
+        cond0.set_colormap(self.colormap.is_some());
+        cond0.set_cursor(self.cursor.is_some());
+        index += cond0.as_bytes(&mut bytes[index..]);
         if cond0.back_pixmap() {
             index += self.background_pixmap.as_bytes(&mut bytes[index..]);
         }
@@ -539,146 +1162,150 @@ impl AsByteSequence for SetAttributesRequest {
         if cond0.cursor() {
             index += self.cursor.as_bytes(&mut bytes[index..]);
         }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (border_width, sz): (Card16, usize) = parse_item(bytes, index, "border_width")?;
         index += sz;
-        let (class, sz): (WindowClass, usize) = <WindowClass>::from_bytes(&bytes[index..])?;
+        let (class, sz): (WindowClass, usize) = parse_item(bytes, index, "class")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = parse_item(bytes, index, "visual")?;
         index += sz;
-        let (value_mask, sz): (Cw, usize) = <Cw>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cw, usize) = parse_item(bytes, index, "value_mask")?;
         index += sz;
         let cond0 = (value_mask);
-        let background_pixmap: Pixmap = if cond0.back_pixmap() {
-            let (background_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (background_pixmap,) = if cond0.back_pixmap() {
+            let (background_pixmap, sz): (Pixmap, usize) =
+                parse_item(bytes, index, "background_pixmap")?;
             index += sz;
-            background_pixmap
+            (Some(background_pixmap),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let background_pixel: Card32 = if cond0.back_pixel() {
-            let (background_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (background_pixel,) = if cond0.back_pixel() {
+            let (background_pixel, sz): (Card32, usize) =
+                parse_item(bytes, index, "background_pixel")?;
             index += sz;
-            background_pixel
+            (Some(background_pixel),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let border_pixmap: Pixmap = if cond0.border_pixmap() {
-            let (border_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (border_pixmap,) = if cond0.border_pixmap() {
+            let (border_pixmap, sz): (Pixmap, usize) = parse_item(bytes, index, "border_pixmap")?;
             index += sz;
-            border_pixmap
+            (Some(border_pixmap),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let border_pixel: Card32 = if cond0.border_pixel() {
-            let (border_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (border_pixel,) = if cond0.border_pixel() {
+            let (border_pixel, sz): (Card32, usize) = parse_item(bytes, index, "border_pixel")?;
             index += sz;
-            border_pixel
+            (Some(border_pixel),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let bit_gravity: Gravity = if cond0.bit_gravity() {
-            let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+        let (bit_gravity,) = if cond0.bit_gravity() {
+            let (bit_gravity, sz): (Gravity, usize) = parse_item(bytes, index, "bit_gravity")?;
             index += sz;
-            bit_gravity
+            (Some(bit_gravity),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let win_gravity: Gravity = if cond0.win_gravity() {
-            let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+        let (win_gravity,) = if cond0.win_gravity() {
+            let (win_gravity, sz): (Gravity, usize) = parse_item(bytes, index, "win_gravity")?;
             index += sz;
-            win_gravity
+            (Some(win_gravity),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let backing_store: BackingStore = if cond0.backing_store() {
+        let (backing_store,) = if cond0.backing_store() {
             let (backing_store, sz): (BackingStore, usize) =
-                <BackingStore>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "backing_store")?;
             index += sz;
-            backing_store
+            (Some(backing_store),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let backing_planes: Card32 = if cond0.backing_planes() {
-            let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (backing_planes,) = if cond0.backing_planes() {
+            let (backing_planes, sz): (Card32, usize) = parse_item(bytes, index, "backing_planes")?;
             index += sz;
-            backing_planes
+            (Some(backing_planes),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let backing_pixel: Card32 = if cond0.backing_pixel() {
-            let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (backing_pixel,) = if cond0.backing_pixel() {
+            let (backing_pixel, sz): (Card32, usize) = parse_item(bytes, index, "backing_pixel")?;
             index += sz;
-            backing_pixel
+            (Some(backing_pixel),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let override_redirect: Bool32 = if cond0.override_redirect() {
-            let (override_redirect, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+        let (override_redirect,) = if cond0.override_redirect() {
+            let (override_redirect, sz): (Bool32, usize) =
+                parse_item(bytes, index, "override_redirect")?;
             index += sz;
-            override_redirect
+            (Some(override_redirect),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let save_under: Bool32 = if cond0.save_under() {
-            let (save_under, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+        let (save_under,) = if cond0.save_under() {
+            let (save_under, sz): (Bool32, usize) = parse_item(bytes, index, "save_under")?;
             index += sz;
-            save_under
+            (Some(save_under),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let event_mask: EventMask = if cond0.event_mask() {
-            let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask,) = if cond0.event_mask() {
+            let (event_mask, sz): (EventMask, usize) = parse_item(bytes, index, "event_mask")?;
             index += sz;
-            event_mask
+            (Some(event_mask),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let do_not_propogate_mask: EventMask = if cond0.dont_propagate() {
+        let (do_not_propogate_mask,) = if cond0.dont_propagate() {
             let (do_not_propogate_mask, sz): (EventMask, usize) =
-                <EventMask>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "do_not_propogate_mask")?;
             index += sz;
-            do_not_propogate_mask
+            (Some(do_not_propogate_mask),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let colormap: Colormap = if cond0.colormap() {
-            let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (colormap,) = if cond0.colormap() {
+            let (colormap, sz): (Colormap, usize) = parse_item(bytes, index, "colormap")?;
             index += sz;
-            colormap
+            (Some(colormap),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let cursor: Cursor = if cond0.cursor() {
-            let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor,) = if cond0.cursor() {
+            let (cursor, sz): (Cursor, usize) = parse_item(bytes, index, "cursor")?;
             index += sz;
-            cursor
+            (Some(cursor),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetAttributesRequest {
                 req_type: req_type,
                 length: length,
@@ -711,19 +1338,10 @@ impl AsByteSequence for SetAttributesRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
+        let size: usize = 15
             + self.drawable.size()
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
-            + self.border_width.size()
             + self.class.size()
-            + self.depth.size()
             + self.visual.size()
             + self.value_mask.size()
             + self.background_pixmap.size()
@@ -740,92 +1358,316 @@ impl AsByteSequence for SetAttributesRequest {
             + self.event_mask.size()
             + self.do_not_propogate_mask.size()
             + self.colormap.size()
-            + self.cursor.size()
+            + self.cursor.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetAttributesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetAttributesRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetAttributes";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct UnsetAttributesRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub drawable: Drawable,
+#[doc = " A builder for `SetAttributesRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetAttributesRequestBuilder {
+    pub inner: SetAttributesRequest,
 }
-impl UnsetAttributesRequest {}
-impl AsByteSequence for UnsetAttributesRequest {
+impl SetAttributesRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing UnsetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            UnsetAttributesRequest {
-                req_type: req_type,
-                length: length,
-                drawable: drawable,
+    pub fn x(mut self, x: Int16) -> Self {
+        self.inner.x = x;
+        self
+    }
+    #[inline]
+    pub fn y(mut self, y: Int16) -> Self {
+        self.inner.y = y;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn border_width(mut self, border_width: Card16) -> Self {
+        self.inner.border_width = border_width;
+        self
+    }
+    #[inline]
+    pub fn class(mut self, class: WindowClass) -> Self {
+        self.inner.class = class;
+        self
+    }
+    #[inline]
+    pub fn depth(mut self, depth: Card8) -> Self {
+        self.inner.depth = depth;
+        self
+    }
+    #[inline]
+    pub fn visual(mut self, visual: Visualid) -> Self {
+        self.inner.visual = visual;
+        self
+    }
+    #[inline]
+    pub fn value_mask(mut self, value_mask: Cw) -> Self {
+        self.inner.value_mask = value_mask;
+        self
+    }
+    #[inline]
+    pub fn background_pixmap(mut self, background_pixmap: Pixmap) -> Self {
+        self.inner.value_mask.set_back_pixmap(true);
+        self.inner.background_pixmap = Some(background_pixmap);
+        self
+    }
+    #[inline]
+    pub fn background_pixel(mut self, background_pixel: Card32) -> Self {
+        self.inner.value_mask.set_back_pixel(true);
+        self.inner.background_pixel = Some(background_pixel);
+        self
+    }
+    #[inline]
+    pub fn border_pixmap(mut self, border_pixmap: Pixmap) -> Self {
+        self.inner.value_mask.set_border_pixmap(true);
+        self.inner.border_pixmap = Some(border_pixmap);
+        self
+    }
+    #[inline]
+    pub fn border_pixel(mut self, border_pixel: Card32) -> Self {
+        self.inner.value_mask.set_border_pixel(true);
+        self.inner.border_pixel = Some(border_pixel);
+        self
+    }
+    #[inline]
+    pub fn bit_gravity(mut self, bit_gravity: Gravity) -> Self {
+        self.inner.value_mask.set_bit_gravity(true);
+        self.inner.bit_gravity = Some(bit_gravity);
+        self
+    }
+    #[inline]
+    pub fn win_gravity(mut self, win_gravity: Gravity) -> Self {
+        self.inner.value_mask.set_win_gravity(true);
+        self.inner.win_gravity = Some(win_gravity);
+        self
+    }
+    #[inline]
+    pub fn backing_store(mut self, backing_store: BackingStore) -> Self {
+        self.inner.value_mask.set_backing_store(true);
+        self.inner.backing_store = Some(backing_store);
+        self
+    }
+    #[inline]
+    pub fn backing_planes(mut self, backing_planes: Card32) -> Self {
+        self.inner.value_mask.set_backing_planes(true);
+        self.inner.backing_planes = Some(backing_planes);
+        self
+    }
+    #[inline]
+    pub fn backing_pixel(mut self, backing_pixel: Card32) -> Self {
+        self.inner.value_mask.set_backing_pixel(true);
+        self.inner.backing_pixel = Some(backing_pixel);
+        self
+    }
+    #[inline]
+    pub fn override_redirect(mut self, override_redirect: Bool32) -> Self {
+        self.inner.value_mask.set_override_redirect(true);
+        self.inner.override_redirect = Some(override_redirect);
+        self
+    }
+    #[inline]
+    pub fn save_under(mut self, save_under: Bool32) -> Self {
+        self.inner.value_mask.set_save_under(true);
+        self.inner.save_under = Some(save_under);
+        self
+    }
+    #[inline]
+    pub fn event_mask(mut self, event_mask: EventMask) -> Self {
+        self.inner.value_mask.set_event_mask(true);
+        self.inner.event_mask = Some(event_mask);
+        self
+    }
+    #[inline]
+    pub fn do_not_propogate_mask(mut self, do_not_propogate_mask: EventMask) -> Self {
+        self.inner.value_mask.set_dont_propagate(true);
+        self.inner.do_not_propogate_mask = Some(do_not_propogate_mask);
+        self
+    }
+    #[inline]
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.inner.value_mask.set_colormap(true);
+        self.inner.colormap = Some(colormap);
+        self
+    }
+    #[inline]
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.inner.value_mask.set_cursor(true);
+        self.inner.cursor = Some(cursor);
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetAttributesRequest {
+        self.inner
+    }
+}
+impl Default for SetAttributesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetAttributesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                x: Default::default(),
+                y: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                border_width: Default::default(),
+                class: Default::default(),
+                depth: Default::default(),
+                visual: Default::default(),
+                value_mask: Default::default(),
+                background_pixmap: Default::default(),
+                background_pixel: Default::default(),
+                border_pixmap: Default::default(),
+                border_pixel: Default::default(),
+                bit_gravity: Default::default(),
+                win_gravity: Default::default(),
+                backing_store: Default::default(),
+                backing_planes: Default::default(),
+                backing_pixel: Default::default(),
+                override_redirect: Default::default(),
+                save_under: Default::default(),
+                event_mask: Default::default(),
+                do_not_propogate_mask: Default::default(),
+                colormap: Default::default(),
+                cursor: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct UnsetAttributesRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub drawable: Drawable,
+}
+impl UnsetAttributesRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size()
+    pub fn builder() -> UnsetAttributesRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<UnsetAttributesRequest as Request>::MINOR_OPCODE < 0x100);
+impl_drawable_only_request_asb! { UnsetAttributesRequest }
 impl Request for UnsetAttributesRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnsetAttributes";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `UnsetAttributesRequest`."]
+#[derive(Clone, Debug)]
+pub struct UnsetAttributesRequestBuilder {
+    pub inner: UnsetAttributesRequest,
+}
+impl UnsetAttributesRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> UnsetAttributesRequest {
+        self.inner
+    }
+}
+impl Default for UnsetAttributesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnsetAttributesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SuspendRequest {
     pub req_type: u8,
     pub length: u16,
     pub suspend: Card32,
 }
-impl SuspendRequest {}
-impl AsByteSequence for SuspendRequest {
+impl SuspendRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> SuspendRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("suspend", 4, 4)];
+}
+const _: () = assert!(<SuspendRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SuspendRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.suspend.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SuspendRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (suspend, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (suspend, sz): (Card32, usize) = parse_item(bytes, index, "suspend")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SuspendRequest {
                 req_type: req_type,
                 length: length,
@@ -836,17 +1678,58 @@ impl AsByteSequence for SuspendRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.suspend.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SuspendRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SuspendRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Suspend";
     type Reply = ();
 }
+#[doc = " A builder for `SuspendRequest`."]
+#[derive(Clone, Debug)]
+pub struct SuspendRequestBuilder {
+    pub inner: SuspendRequest,
+}
+impl SuspendRequestBuilder {
+    #[inline]
+    pub fn suspend(mut self, suspend: Card32) -> Self {
+        self.inner.suspend = suspend;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SuspendRequest {
+        self.inner
+    }
+}
+impl Default for SuspendRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SuspendRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                suspend: Default::default(),
+            },
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum State {
     Off = 0,
     On = 1,
@@ -859,14 +1742,14 @@ impl AsByteSequence for State {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Off, sz)),
-            1 => Some((Self::On, sz)),
-            2 => Some((Self::Cycle, sz)),
-            3 => Some((Self::Disabled, sz)),
-            _ => None,
+            0 => Ok((Self::Off, sz)),
+            1 => Ok((Self::On, sz)),
+            2 => Ok((Self::Cycle, sz)),
+            3 => Ok((Self::Disabled, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -874,13 +1757,42 @@ impl AsByteSequence for State {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for State {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for State {
     #[inline]
     fn default() -> State {
         State::Off
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<State> for u8 {
+    #[inline]
+    fn from(base: State) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for State {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::On),
+            2 => Ok(Self::Cycle),
+            3 => Ok(Self::Disabled),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct NotifyEvent {
     pub event_type: u8,
     pub state: State,
@@ -891,9 +1803,22 @@ pub struct NotifyEvent {
     pub kind: Kind,
     pub forced: bool,
 }
-impl NotifyEvent {}
-impl AsByteSequence for NotifyEvent {
+impl NotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("state", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for NotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -904,31 +1829,30 @@ impl AsByteSequence for NotifyEvent {
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.kind.as_bytes(&mut bytes[index..]);
         index += self.forced.as_bytes(&mut bytes[index..]);
-        index += 14;
+        index += zero_pad(&mut bytes[index..], 14);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (state, sz): (State, usize) = <State>::from_bytes(&bytes[index..])?;
+        let (state, sz): (State, usize) = parse_item(bytes, index, "state")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (kind, sz): (Kind, usize) = <Kind>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Kind, usize) = parse_item(bytes, index, "kind")?;
         index += sz;
-        let (forced, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (forced, sz): (bool, usize) = parse_item(bytes, index, "forced")?;
         index += sz;
         index += 14;
-        Some((
+        Ok((
             NotifyEvent {
                 event_type: event_type,
                 state: state,
@@ -944,17 +1868,82 @@ impl AsByteSequence for NotifyEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.state.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.root.size()
-            + self.window.size()
-            + self.kind.size()
-            + self.forced.size()
-            + 14
+        19 + self.time.size() + self.root.size() + self.window.size() + self.forced.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for NotifyEvent {
     const OPCODE: u8 = 0;
 }
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum AnyEvent {
+    Notify(NotifyEvent),
+}
+impl AnyEvent {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            0 => Ok(Self::Notify(NotifyEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::Notify(_) => NotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<NotifyEvent> for AnyEvent {
+    #[inline]
+    fn from(event: NotifyEvent) -> Self {
+        Self::Notify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "Notify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "QueryInfo"),
+    (2, "SelectInput"),
+    (3, "SetAttributes"),
+    (4, "UnsetAttributes"),
+    (5, "Suspend"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,15 +1,20 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "shape")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
 use super::xproto::*;
 pub type Op = Card8;
 pub type Kind = Card8;
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Sk {
     Bounding = 0,
     Clip = 1,
@@ -21,13 +26,13 @@ impl AsByteSequence for Sk {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Bounding, sz)),
-            1 => Some((Self::Clip, sz)),
-            2 => Some((Self::Input, sz)),
-            _ => None,
+            0 => Ok((Self::Bounding, sz)),
+            1 => Ok((Self::Clip, sz)),
+            2 => Ok((Self::Input, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -35,37 +40,89 @@ impl AsByteSequence for Sk {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Sk {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Sk {
     #[inline]
     fn default() -> Sk {
         Sk::Bounding
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Sk> for u8 {
+    #[inline]
+    fn from(base: Sk) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for Sk {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Bounding),
+            1 => Ok(Self::Clip),
+            2 => Ok(Self::Input),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryVersionRequest {}
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryVersionRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -75,16 +132,51 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -92,35 +184,105 @@ pub struct QueryVersionReply {
     pub major_version: Card16,
     pub minor_version: Card16,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card16 = read_item(reader, &mut index, 2, "major_version")?;
+        let minor_version: Card16 = read_item(reader, &mut index, 2, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 2),
+        ("minor_version", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card16, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -133,15 +295,21 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct RectanglesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -153,53 +321,77 @@ pub struct RectanglesRequest {
     pub y_offset: Int16,
     pub rectangles: Vec<Rectangle>,
 }
-impl RectanglesRequest {}
-impl AsByteSequence for RectanglesRequest {
+impl RectanglesRequest {
     #[inline]
+    pub fn builder() -> RectanglesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("operation", 4, 1),
+        ("destination_kind", 5, 1),
+    ];
+}
+const _: () = assert!(<RectanglesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for RectanglesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.operation.as_bytes(&mut bytes[index..]);
         index += self.destination_kind.as_bytes(&mut bytes[index..]);
         index += self.ordering.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.destination_window.as_bytes(&mut bytes[index..]);
         index += self.x_offset.as_bytes(&mut bytes[index..]);
         index += self.y_offset.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.rectangles, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Rectangle>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing RectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (operation, sz): (So, usize) = <So>::from_bytes(&bytes[index..])?;
+        let (operation, sz): (So, usize) = parse_item(bytes, index, "operation")?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = parse_item(bytes, index, "destination_kind")?;
         index += sz;
-        let (ordering, sz): (ClipOrdering, usize) = <ClipOrdering>::from_bytes(&bytes[index..])?;
+        let (ordering, sz): (ClipOrdering, usize) = parse_item(bytes, index, "ordering")?;
         index += sz;
         index += 1;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) =
+            parse_item(bytes, index, "destination_window")?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = parse_item(bytes, index, "x_offset")?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = parse_item(bytes, index, "y_offset")?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "rectangles",
+                offset: index,
+            })? as usize,
+            "rectangles",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             RectanglesRequest {
                 req_type: req_type,
                 length: length,
@@ -216,31 +408,112 @@ impl AsByteSequence for RectanglesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.operation.size()
-            + self.destination_kind.size()
-            + self.ordering.size()
-            + 1
-            + self.destination_window.size()
-            + self.x_offset.size()
-            + self.y_offset.size()
-            + {
-                let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-                block_len + pad
-            }
+        let size: usize = 11 + self.ordering.size() + self.destination_window.size() + {
+            let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for RectanglesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for RectanglesRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Rectangles";
     type Reply = ();
 }
+#[doc = " A builder for `RectanglesRequest`."]
+#[derive(Clone, Debug)]
+pub struct RectanglesRequestBuilder {
+    pub inner: RectanglesRequest,
+}
+impl RectanglesRequestBuilder {
+    #[inline]
+    pub fn operation(mut self, operation: So) -> Self {
+        self.inner.operation = operation;
+        self
+    }
+    #[inline]
+    pub fn destination_kind(mut self, destination_kind: Sk) -> Self {
+        self.inner.destination_kind = destination_kind;
+        self
+    }
+    #[inline]
+    pub fn ordering(mut self, ordering: ClipOrdering) -> Self {
+        self.inner.ordering = ordering;
+        self
+    }
+    #[inline]
+    pub fn destination_window(mut self, destination_window: Window) -> Self {
+        self.inner.destination_window = destination_window;
+        self
+    }
+    #[inline]
+    pub fn x_offset(mut self, x_offset: Int16) -> Self {
+        self.inner.x_offset = x_offset;
+        self
+    }
+    #[inline]
+    pub fn y_offset(mut self, y_offset: Int16) -> Self {
+        self.inner.y_offset = y_offset;
+        self
+    }
+    #[inline]
+    pub fn rectangles(mut self, rectangles: Vec<Rectangle>) -> Self {
+        self.inner.rectangles = rectangles;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> RectanglesRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Rectangle> for RectanglesRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Rectangle>>(&mut self, iter: I) {
+        self.inner.rectangles.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Rectangle> for RectanglesRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Rectangle>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for RectanglesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: RectanglesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                operation: Default::default(),
+                destination_kind: Default::default(),
+                ordering: Default::default(),
+                destination_window: Default::default(),
+                x_offset: Default::default(),
+                y_offset: Default::default(),
+                rectangles: Default::default(),
+            },
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum So {
     Set = 0,
     Union = 1,
@@ -253,16 +526,15 @@ impl AsByteSequence for So {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u8).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Set, sz)),
-            1 => Some((Self::Union, sz)),
-            2 => Some((Self::Intersect, sz)),
-            3 => Some((Self::Subtract, sz)),
-            4 => Some((Self::Invert, sz)),
-            _ => None,
+            0 => Ok((Self::Set, sz)),
+            1 => Ok((Self::Union, sz)),
+            2 => Ok((Self::Intersect, sz)),
+            3 => Ok((Self::Subtract, sz)),
+            4 => Ok((Self::Invert, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -270,13 +542,43 @@ impl AsByteSequence for So {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for So {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for So {
     #[inline]
     fn default() -> So {
         So::Set
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<So> for u8 {
+    #[inline]
+    fn from(base: So) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for So {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Set),
+            1 => Ok(Self::Union),
+            2 => Ok(Self::Intersect),
+            3 => Ok(Self::Subtract),
+            4 => Ok(Self::Invert),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct MaskRequest {
     pub req_type: u8,
     pub length: u16,
@@ -287,46 +589,60 @@ pub struct MaskRequest {
     pub y_offset: Int16,
     pub source_bitmap: Pixmap,
 }
-impl MaskRequest {}
-impl AsByteSequence for MaskRequest {
+impl MaskRequest {
     #[inline]
+    pub fn builder() -> MaskRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("operation", 4, 1),
+        ("destination_kind", 5, 1),
+    ];
+}
+const _: () = assert!(<MaskRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for MaskRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.operation.as_bytes(&mut bytes[index..]);
         index += self.destination_kind.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.destination_window.as_bytes(&mut bytes[index..]);
         index += self.x_offset.as_bytes(&mut bytes[index..]);
         index += self.y_offset.as_bytes(&mut bytes[index..]);
         index += self.source_bitmap.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing MaskRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (operation, sz): (So, usize) = <So>::from_bytes(&bytes[index..])?;
+        let (operation, sz): (So, usize) = parse_item(bytes, index, "operation")?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = parse_item(bytes, index, "destination_kind")?;
         index += sz;
         index += 2;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) =
+            parse_item(bytes, index, "destination_window")?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = parse_item(bytes, index, "x_offset")?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = parse_item(bytes, index, "y_offset")?;
         index += sz;
-        let (source_bitmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (source_bitmap, sz): (Pixmap, usize) = parse_item(bytes, index, "source_bitmap")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             MaskRequest {
                 req_type: req_type,
                 length: length,
@@ -342,25 +658,88 @@ impl AsByteSequence for MaskRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.operation.size()
-            + self.destination_kind.size()
-            + 2
-            + self.destination_window.size()
-            + self.x_offset.size()
-            + self.y_offset.size()
-            + self.source_bitmap.size()
+        let size: usize = 12 + self.destination_window.size() + self.source_bitmap.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for MaskRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for MaskRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Mask";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `MaskRequest`."]
+#[derive(Clone, Debug)]
+pub struct MaskRequestBuilder {
+    pub inner: MaskRequest,
+}
+impl MaskRequestBuilder {
+    #[inline]
+    pub fn operation(mut self, operation: So) -> Self {
+        self.inner.operation = operation;
+        self
+    }
+    #[inline]
+    pub fn destination_kind(mut self, destination_kind: Sk) -> Self {
+        self.inner.destination_kind = destination_kind;
+        self
+    }
+    #[inline]
+    pub fn destination_window(mut self, destination_window: Window) -> Self {
+        self.inner.destination_window = destination_window;
+        self
+    }
+    #[inline]
+    pub fn x_offset(mut self, x_offset: Int16) -> Self {
+        self.inner.x_offset = x_offset;
+        self
+    }
+    #[inline]
+    pub fn y_offset(mut self, y_offset: Int16) -> Self {
+        self.inner.y_offset = y_offset;
+        self
+    }
+    #[inline]
+    pub fn source_bitmap(mut self, source_bitmap: Pixmap) -> Self {
+        self.inner.source_bitmap = source_bitmap;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> MaskRequest {
+        self.inner
+    }
+}
+impl Default for MaskRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: MaskRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                operation: Default::default(),
+                destination_kind: Default::default(),
+                destination_window: Default::default(),
+                x_offset: Default::default(),
+                y_offset: Default::default(),
+                source_bitmap: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CombineRequest {
     pub req_type: u8,
     pub length: u16,
@@ -372,49 +751,64 @@ pub struct CombineRequest {
     pub y_offset: Int16,
     pub source_window: Window,
 }
-impl CombineRequest {}
-impl AsByteSequence for CombineRequest {
+impl CombineRequest {
     #[inline]
+    pub fn builder() -> CombineRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("operation", 4, 1),
+        ("destination_kind", 5, 1),
+        ("source_kind", 6, 1),
+    ];
+}
+const _: () = assert!(<CombineRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CombineRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.operation.as_bytes(&mut bytes[index..]);
         index += self.destination_kind.as_bytes(&mut bytes[index..]);
         index += self.source_kind.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.destination_window.as_bytes(&mut bytes[index..]);
         index += self.x_offset.as_bytes(&mut bytes[index..]);
         index += self.y_offset.as_bytes(&mut bytes[index..]);
         index += self.source_window.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CombineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (operation, sz): (So, usize) = <So>::from_bytes(&bytes[index..])?;
+        let (operation, sz): (So, usize) = parse_item(bytes, index, "operation")?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = parse_item(bytes, index, "destination_kind")?;
         index += sz;
-        let (source_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (source_kind, sz): (Sk, usize) = parse_item(bytes, index, "source_kind")?;
         index += sz;
         index += 1;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) =
+            parse_item(bytes, index, "destination_window")?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = parse_item(bytes, index, "x_offset")?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = parse_item(bytes, index, "y_offset")?;
         index += sz;
-        let (source_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (source_window, sz): (Window, usize) = parse_item(bytes, index, "source_window")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CombineRequest {
                 req_type: req_type,
                 length: length,
@@ -431,26 +825,94 @@ impl AsByteSequence for CombineRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.operation.size()
-            + self.destination_kind.size()
-            + self.source_kind.size()
-            + 1
-            + self.destination_window.size()
-            + self.x_offset.size()
-            + self.y_offset.size()
-            + self.source_window.size()
+        let size: usize = 12 + self.destination_window.size() + self.source_window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CombineRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CombineRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Combine";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CombineRequest`."]
+#[derive(Clone, Debug)]
+pub struct CombineRequestBuilder {
+    pub inner: CombineRequest,
+}
+impl CombineRequestBuilder {
+    #[inline]
+    pub fn operation(mut self, operation: So) -> Self {
+        self.inner.operation = operation;
+        self
+    }
+    #[inline]
+    pub fn destination_kind(mut self, destination_kind: Sk) -> Self {
+        self.inner.destination_kind = destination_kind;
+        self
+    }
+    #[inline]
+    pub fn source_kind(mut self, source_kind: Sk) -> Self {
+        self.inner.source_kind = source_kind;
+        self
+    }
+    #[inline]
+    pub fn destination_window(mut self, destination_window: Window) -> Self {
+        self.inner.destination_window = destination_window;
+        self
+    }
+    #[inline]
+    pub fn x_offset(mut self, x_offset: Int16) -> Self {
+        self.inner.x_offset = x_offset;
+        self
+    }
+    #[inline]
+    pub fn y_offset(mut self, y_offset: Int16) -> Self {
+        self.inner.y_offset = y_offset;
+        self
+    }
+    #[inline]
+    pub fn source_window(mut self, source_window: Window) -> Self {
+        self.inner.source_window = source_window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CombineRequest {
+        self.inner
+    }
+}
+impl Default for CombineRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CombineRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                operation: Default::default(),
+                destination_kind: Default::default(),
+                source_kind: Default::default(),
+                destination_window: Default::default(),
+                x_offset: Default::default(),
+                y_offset: Default::default(),
+                source_window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct OffsetRequest {
     pub req_type: u8,
     pub length: u16,
@@ -459,40 +921,53 @@ pub struct OffsetRequest {
     pub x_offset: Int16,
     pub y_offset: Int16,
 }
-impl OffsetRequest {}
-impl AsByteSequence for OffsetRequest {
+impl OffsetRequest {
     #[inline]
+    pub fn builder() -> OffsetRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("destination_kind", 4, 1),
+    ];
+}
+const _: () = assert!(<OffsetRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for OffsetRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.destination_kind.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.destination_window.as_bytes(&mut bytes[index..]);
         index += self.x_offset.as_bytes(&mut bytes[index..]);
         index += self.y_offset.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OffsetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = parse_item(bytes, index, "destination_kind")?;
         index += sz;
         index += 3;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) =
+            parse_item(bytes, index, "destination_window")?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = parse_item(bytes, index, "x_offset")?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = parse_item(bytes, index, "y_offset")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             OffsetRequest {
                 req_type: req_type,
                 length: length,
@@ -506,71 +981,191 @@ impl AsByteSequence for OffsetRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.destination_kind.size()
-            + 3
-            + self.destination_window.size()
-            + self.x_offset.size()
-            + self.y_offset.size()
+        let size: usize = 12 + self.destination_window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for OffsetRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for OffsetRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Offset";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `OffsetRequest`."]
+#[derive(Clone, Debug)]
+pub struct OffsetRequestBuilder {
+    pub inner: OffsetRequest,
+}
+impl OffsetRequestBuilder {
+    #[inline]
+    pub fn destination_kind(mut self, destination_kind: Sk) -> Self {
+        self.inner.destination_kind = destination_kind;
+        self
+    }
+    #[inline]
+    pub fn destination_window(mut self, destination_window: Window) -> Self {
+        self.inner.destination_window = destination_window;
+        self
+    }
+    #[inline]
+    pub fn x_offset(mut self, x_offset: Int16) -> Self {
+        self.inner.x_offset = x_offset;
+        self
+    }
+    #[inline]
+    pub fn y_offset(mut self, y_offset: Int16) -> Self {
+        self.inner.y_offset = y_offset;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> OffsetRequest {
+        self.inner
+    }
+}
+impl Default for OffsetRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: OffsetRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                destination_kind: Default::default(),
+                destination_window: Default::default(),
+                x_offset: Default::default(),
+                y_offset: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryExtentsRequest {
     pub req_type: u8,
     pub length: u16,
     pub destination_window: Window,
 }
-impl QueryExtentsRequest {}
-impl AsByteSequence for QueryExtentsRequest {
+impl QueryExtentsRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.destination_window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> QueryExtentsRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryExtentsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryExtentsRequest {
-                req_type: req_type,
-                length: length,
-                destination_window: destination_window,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.destination_window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryExtentsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryExtentsRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_destination_window_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.destination_window.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (destination_window, sz): (Window, usize) =
+                    parse_item(bytes, index, "destination_window")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        destination_window: destination_window,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.destination_window.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_destination_window_only_request_asb! { QueryExtentsRequest }
 impl Request for QueryExtentsRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryExtents";
     type Reply = QueryExtentsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryExtentsRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryExtentsRequestBuilder {
+    pub inner: QueryExtentsRequest,
+}
+impl QueryExtentsRequestBuilder {
+    #[inline]
+    pub fn destination_window(mut self, destination_window: Window) -> Self {
+        self.inner.destination_window = destination_window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryExtentsRequest {
+        self.inner
+    }
+}
+impl Default for QueryExtentsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryExtentsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                destination_window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryExtentsReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -586,18 +1181,126 @@ pub struct QueryExtentsReply {
     pub clip_shape_extents_width: Card16,
     pub clip_shape_extents_height: Card16,
 }
-impl QueryExtentsReply {}
-impl AsByteSequence for QueryExtentsReply {
+impl QueryExtentsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("bounding_shaped", 8, 1),
+        ("clip_shaped", 9, 1),
+        ("bounding_shape_extents_x", 12, 2),
+        ("bounding_shape_extents_y", 14, 2),
+        ("bounding_shape_extents_width", 16, 2),
+        ("bounding_shape_extents_height", 18, 2),
+        ("clip_shape_extents_x", 20, 2),
+        ("clip_shape_extents_y", 22, 2),
+        ("clip_shape_extents_width", 24, 2),
+        ("clip_shape_extents_height", 26, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.bounding_shaped == other.bounding_shaped
+            && self.clip_shaped == other.clip_shaped
+            && self.bounding_shape_extents_x == other.bounding_shape_extents_x
+            && self.bounding_shape_extents_y == other.bounding_shape_extents_y
+            && self.bounding_shape_extents_width == other.bounding_shape_extents_width
+            && self.bounding_shape_extents_height == other.bounding_shape_extents_height
+            && self.clip_shape_extents_x == other.clip_shape_extents_x
+            && self.clip_shape_extents_y == other.clip_shape_extents_y
+            && self.clip_shape_extents_width == other.clip_shape_extents_width
+            && self.clip_shape_extents_height == other.clip_shape_extents_height
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "bounding_shaped",
+            &self.bounding_shaped,
+            &other.bounding_shaped,
+            &mut changes,
+        );
+        diff_value(
+            "clip_shaped",
+            &self.clip_shaped,
+            &other.clip_shaped,
+            &mut changes,
+        );
+        diff_value(
+            "bounding_shape_extents_x",
+            &self.bounding_shape_extents_x,
+            &other.bounding_shape_extents_x,
+            &mut changes,
+        );
+        diff_value(
+            "bounding_shape_extents_y",
+            &self.bounding_shape_extents_y,
+            &other.bounding_shape_extents_y,
+            &mut changes,
+        );
+        diff_value(
+            "bounding_shape_extents_width",
+            &self.bounding_shape_extents_width,
+            &other.bounding_shape_extents_width,
+            &mut changes,
+        );
+        diff_value(
+            "bounding_shape_extents_height",
+            &self.bounding_shape_extents_height,
+            &other.bounding_shape_extents_height,
+            &mut changes,
+        );
+        diff_value(
+            "clip_shape_extents_x",
+            &self.clip_shape_extents_x,
+            &other.clip_shape_extents_x,
+            &mut changes,
+        );
+        diff_value(
+            "clip_shape_extents_y",
+            &self.clip_shape_extents_y,
+            &other.clip_shape_extents_y,
+            &mut changes,
+        );
+        diff_value(
+            "clip_shape_extents_width",
+            &self.clip_shape_extents_width,
+            &other.clip_shape_extents_width,
+            &mut changes,
+        );
+        diff_value(
+            "clip_shape_extents_height",
+            &self.clip_shape_extents_height,
+            &other.clip_shape_extents_height,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryExtentsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.bounding_shaped.as_bytes(&mut bytes[index..]);
         index += self.clip_shaped.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.bounding_shape_extents_x.as_bytes(&mut bytes[index..]);
         index += self.bounding_shape_extents_y.as_bytes(&mut bytes[index..]);
         index += self
@@ -610,45 +1313,51 @@ impl AsByteSequence for QueryExtentsReply {
         index += self.clip_shape_extents_y.as_bytes(&mut bytes[index..]);
         index += self.clip_shape_extents_width.as_bytes(&mut bytes[index..]);
         index += self.clip_shape_extents_height.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtentsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (bounding_shaped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (bounding_shaped, sz): (bool, usize) = parse_item(bytes, index, "bounding_shaped")?;
         index += sz;
-        let (clip_shaped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (clip_shaped, sz): (bool, usize) = parse_item(bytes, index, "clip_shaped")?;
         index += sz;
         index += 2;
-        let (bounding_shape_extents_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (bounding_shape_extents_x, sz): (Int16, usize) =
+            parse_item(bytes, index, "bounding_shape_extents_x")?;
         index += sz;
-        let (bounding_shape_extents_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (bounding_shape_extents_y, sz): (Int16, usize) =
+            parse_item(bytes, index, "bounding_shape_extents_y")?;
         index += sz;
         let (bounding_shape_extents_width, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "bounding_shape_extents_width")?;
         index += sz;
         let (bounding_shape_extents_height, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "bounding_shape_extents_height")?;
         index += sz;
-        let (clip_shape_extents_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_shape_extents_x, sz): (Int16, usize) =
+            parse_item(bytes, index, "clip_shape_extents_x")?;
         index += sz;
-        let (clip_shape_extents_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_shape_extents_y, sz): (Int16, usize) =
+            parse_item(bytes, index, "clip_shape_extents_y")?;
         index += sz;
         let (clip_shape_extents_width, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "clip_shape_extents_width")?;
         index += sz;
         let (clip_shape_extents_height, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "clip_shape_extents_height")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryExtentsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -669,58 +1378,64 @@ impl AsByteSequence for QueryExtentsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.bounding_shaped.size()
-            + self.clip_shaped.size()
-            + 2
-            + self.bounding_shape_extents_x.size()
-            + self.bounding_shape_extents_y.size()
-            + self.bounding_shape_extents_width.size()
-            + self.bounding_shape_extents_height.size()
-            + self.clip_shape_extents_x.size()
-            + self.clip_shape_extents_y.size()
-            + self.clip_shape_extents_width.size()
-            + self.clip_shape_extents_height.size()
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let size: usize = 26 + self.bounding_shaped.size() + self.clip_shaped.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryExtentsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectInputRequest {
     pub req_type: u8,
     pub length: u16,
     pub destination_window: Window,
     pub enable: bool,
 }
-impl SelectInputRequest {}
-impl AsByteSequence for SelectInputRequest {
+impl SelectInputRequest {
     #[inline]
+    pub fn builder() -> SelectInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SelectInputRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectInputRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.destination_window.as_bytes(&mut bytes[index..]);
         index += self.enable.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) =
+            parse_item(bytes, index, "destination_window")?;
         index += sz;
-        let (enable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enable, sz): (bool, usize) = parse_item(bytes, index, "enable")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectInputRequest {
                 req_type: req_type,
                 length: length,
@@ -732,76 +1447,166 @@ impl AsByteSequence for SelectInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.destination_window.size()
-            + self.enable.size()
-            + 3
+        let size: usize = 7 + self.destination_window.size() + self.enable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectInputRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SelectInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectInputRequestBuilder {
+    pub inner: SelectInputRequest,
+}
+impl SelectInputRequestBuilder {
+    #[inline]
+    pub fn destination_window(mut self, destination_window: Window) -> Self {
+        self.inner.destination_window = destination_window;
+        self
+    }
+    #[inline]
+    pub fn enable(mut self, enable: bool) -> Self {
+        self.inner.enable = enable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectInputRequest {
+        self.inner
+    }
+}
+impl Default for SelectInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                destination_window: Default::default(),
+                enable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct InputSelectedRequest {
     pub req_type: u8,
     pub length: u16,
     pub destination_window: Window,
 }
-impl InputSelectedRequest {}
-impl AsByteSequence for InputSelectedRequest {
+impl InputSelectedRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.destination_window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> InputSelectedRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing InputSelectedRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            InputSelectedRequest {
-                req_type: req_type,
-                length: length,
-                destination_window: destination_window,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.destination_window.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<InputSelectedReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<InputSelectedRequest as Request>::MINOR_OPCODE < 0x100);
+impl_destination_window_only_request_asb! { InputSelectedRequest }
 impl Request for InputSelectedRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "InputSelected";
     type Reply = InputSelectedReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `InputSelectedRequest`."]
+#[derive(Clone, Debug)]
+pub struct InputSelectedRequestBuilder {
+    pub inner: InputSelectedRequest,
+}
+impl InputSelectedRequestBuilder {
+    #[inline]
+    pub fn destination_window(mut self, destination_window: Window) -> Self {
+        self.inner.destination_window = destination_window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> InputSelectedRequest {
+        self.inner
+    }
+}
+impl Default for InputSelectedRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: InputSelectedRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                destination_window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct InputSelectedReply {
     pub reply_type: u8,
     pub enabled: bool,
     pub sequence: u16,
     pub length: u32,
 }
-impl InputSelectedReply {}
+impl InputSelectedReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("enabled", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.enabled == other.enabled
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("enabled", &self.enabled, &other.enabled, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for InputSelectedReply {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -810,21 +1615,23 @@ impl AsByteSequence for InputSelectedReply {
         index += self.enabled.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing InputSelectedReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (enabled, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enabled, sz): (bool, usize) = parse_item(bytes, index, "enabled")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        index += buffer_pad(index, 4);
+        Ok((
             InputSelectedReply {
                 reply_type: reply_type,
                 enabled: enabled,
@@ -836,44 +1643,71 @@ impl AsByteSequence for InputSelectedReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + self.enabled.size() + self.sequence.size() + self.length.size()
+        let size: usize = 7 + self.enabled.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for InputSelectedReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetRectanglesRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub source_kind: Sk,
 }
-impl GetRectanglesRequest {}
-impl AsByteSequence for GetRectanglesRequest {
+impl GetRectanglesRequest {
+    #[inline]
+    pub fn builder() -> GetRectanglesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetRectanglesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetRectanglesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetRectanglesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.source_kind.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetRectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (source_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (source_kind, sz): (Sk, usize) = parse_item(bytes, index, "source_kind")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetRectanglesRequest {
                 req_type: req_type,
                 length: length,
@@ -885,21 +1719,63 @@ impl AsByteSequence for GetRectanglesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.window.size()
-            + self.source_kind.size()
-            + 3
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetRectanglesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetRectanglesRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetRectangles";
     type Reply = GetRectanglesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetRectanglesRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetRectanglesRequestBuilder {
+    pub inner: GetRectanglesRequest,
+}
+impl GetRectanglesRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn source_kind(mut self, source_kind: Sk) -> Self {
+        self.inner.source_kind = source_kind;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetRectanglesRequest {
+        self.inner
+    }
+}
+impl Default for GetRectanglesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetRectanglesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                source_kind: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetRectanglesReply {
     pub reply_type: u8,
     pub ordering: ClipOrdering,
@@ -907,9 +1783,41 @@ pub struct GetRectanglesReply {
     pub length: u32,
     pub rectangles: Vec<Rectangle>,
 }
-impl GetRectanglesReply {}
-impl AsByteSequence for GetRectanglesReply {
+impl GetRectanglesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("reply_type", 0, 1)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.ordering == other.ordering && self.rectangles == other.rectangles
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("ordering", &self.ordering, &other.ordering, &mut changes);
+        diff_list(
+            "rectangles",
+            &self.rectangles,
+            &other.rectangles,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetRectanglesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -917,32 +1825,37 @@ impl AsByteSequence for GetRectanglesReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.rectangles.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.rectangles, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Rectangle>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetRectanglesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (ordering, sz): (ClipOrdering, usize) = <ClipOrdering>::from_bytes(&bytes[index..])?;
+        let (ordering, sz): (ClipOrdering, usize) = parse_item(bytes, index, "ordering")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "rectangles")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetRectanglesReply {
                 reply_type: reply_type,
                 ordering: ordering,
@@ -955,20 +1868,25 @@ impl AsByteSequence for GetRectanglesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.ordering.size()
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-                block_len + pad
-            }
+        let size: usize = 31 + self.ordering.size() + {
+            let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetRectanglesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct NotifyEvent {
     pub event_type: u8,
     pub shape_kind: Sk,
@@ -981,9 +1899,25 @@ pub struct NotifyEvent {
     pub server_time: Timestamp,
     pub shaped: bool,
 }
-impl NotifyEvent {}
-impl AsByteSequence for NotifyEvent {
+impl NotifyEvent {
     #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("shape_kind", 1, 1),
+        ("sequence", 2, 2),
+    ];
+}
+impl AsByteSequence for NotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -996,35 +1930,34 @@ impl AsByteSequence for NotifyEvent {
         index += self.extents_height.as_bytes(&mut bytes[index..]);
         index += self.server_time.as_bytes(&mut bytes[index..]);
         index += self.shaped.as_bytes(&mut bytes[index..]);
-        index += 11;
+        index += zero_pad(&mut bytes[index..], 11);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (shape_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (shape_kind, sz): (Sk, usize) = parse_item(bytes, index, "shape_kind")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (affected_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (affected_window, sz): (Window, usize) = parse_item(bytes, index, "affected_window")?;
         index += sz;
-        let (extents_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (extents_x, sz): (Int16, usize) = parse_item(bytes, index, "extents_x")?;
         index += sz;
-        let (extents_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (extents_y, sz): (Int16, usize) = parse_item(bytes, index, "extents_y")?;
         index += sz;
-        let (extents_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (extents_width, sz): (Card16, usize) = parse_item(bytes, index, "extents_width")?;
         index += sz;
-        let (extents_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (extents_height, sz): (Card16, usize) = parse_item(bytes, index, "extents_height")?;
         index += sz;
-        let (server_time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (server_time, sz): (Timestamp, usize) = parse_item(bytes, index, "server_time")?;
         index += sz;
-        let (shaped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (shaped, sz): (bool, usize) = parse_item(bytes, index, "shaped")?;
         index += sz;
         index += 11;
-        Some((
+        Ok((
             NotifyEvent {
                 event_type: event_type,
                 shape_kind: shape_kind,
@@ -1042,19 +1975,85 @@ impl AsByteSequence for NotifyEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.shape_kind.size()
-            + self.sequence.size()
-            + self.affected_window.size()
-            + self.extents_x.size()
-            + self.extents_y.size()
-            + self.extents_width.size()
-            + self.extents_height.size()
-            + self.server_time.size()
-            + self.shaped.size()
-            + 11
+        23 + self.affected_window.size() + self.server_time.size() + self.shaped.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for NotifyEvent {
     const OPCODE: u8 = 0;
 }
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    Notify(NotifyEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            0 => Ok(Self::Notify(NotifyEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::Notify(_) => NotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<NotifyEvent> for Event {
+    #[inline]
+    fn from(event: NotifyEvent) -> Self {
+        Self::Notify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "Notify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "Rectangles"),
+    (2, "Mask"),
+    (3, "Combine"),
+    (4, "Offset"),
+    (5, "QueryExtents"),
+    (6, "SelectInput"),
+    (7, "InputSelected"),
+    (8, "GetRectangles"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
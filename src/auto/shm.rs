@@ -1,13 +1,18 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "shm")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 2;
 use super::xproto::*;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Seg {
     pub xid: XID,
 }
@@ -16,6 +21,23 @@ impl Seg {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Seg {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Seg {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Seg {
     #[inline]
@@ -27,31 +49,78 @@ impl XidType for Seg {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Seg {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Seg({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Seg {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Seg> for XID {
+    #[inline]
+    fn from(base: Seg) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryVersionRequest {}
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryVersionRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -61,16 +130,51 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub shared_pixmaps: bool,
@@ -82,9 +186,75 @@ pub struct QueryVersionReply {
     pub gid: Card16,
     pub pixmap_format: Card8,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("shared_pixmaps", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 2),
+        ("minor_version", 10, 2),
+        ("uid", 12, 2),
+        ("gid", 14, 2),
+        ("pixmap_format", 16, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.shared_pixmaps == other.shared_pixmaps
+            && self.major_version == other.major_version
+            && self.minor_version == other.minor_version
+            && self.uid == other.uid
+            && self.gid == other.gid
+            && self.pixmap_format == other.pixmap_format
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "shared_pixmaps",
+            &self.shared_pixmaps,
+            &other.shared_pixmaps,
+            &mut changes,
+        );
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        diff_value("uid", &self.uid, &other.uid, &mut changes);
+        diff_value("gid", &self.gid, &other.gid, &mut changes);
+        diff_value(
+            "pixmap_format",
+            &self.pixmap_format,
+            &other.pixmap_format,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -96,33 +266,35 @@ impl AsByteSequence for QueryVersionReply {
         index += self.uid.as_bytes(&mut bytes[index..]);
         index += self.gid.as_bytes(&mut bytes[index..]);
         index += self.pixmap_format.as_bytes(&mut bytes[index..]);
-        index += 15;
+        index += zero_pad(&mut bytes[index..], 15);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (shared_pixmaps, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (shared_pixmaps, sz): (bool, usize) = parse_item(bytes, index, "shared_pixmaps")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card16, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        let (uid, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (uid, sz): (Card16, usize) = parse_item(bytes, index, "uid")?;
         index += sz;
-        let (gid, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (gid, sz): (Card16, usize) = parse_item(bytes, index, "gid")?;
         index += sz;
-        let (pixmap_format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pixmap_format, sz): (Card8, usize) = parse_item(bytes, index, "pixmap_format")?;
         index += sz;
         index += 15;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 shared_pixmaps: shared_pixmaps,
@@ -139,19 +311,21 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.shared_pixmaps.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
-            + self.uid.size()
-            + self.gid.size()
-            + self.pixmap_format.size()
-            + 15
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let size: usize = 31 + self.shared_pixmaps.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AttachRequest {
     pub req_type: u8,
     pub length: u16,
@@ -159,37 +333,51 @@ pub struct AttachRequest {
     pub shmid: Card32,
     pub read_only: bool,
 }
-impl AttachRequest {}
-impl AsByteSequence for AttachRequest {
+impl AttachRequest {
     #[inline]
+    pub fn builder() -> AttachRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("shmseg", 4, 4),
+        ("shmid", 8, 4),
+        ("read_only", 12, 1),
+    ];
+}
+const _: () = assert!(<AttachRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AttachRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.shmseg.as_bytes(&mut bytes[index..]);
         index += self.shmid.as_bytes(&mut bytes[index..]);
         index += self.read_only.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttachRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (shmid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (shmid, sz): (Card32, usize) = parse_item(bytes, index, "shmid")?;
         index += sz;
-        let (read_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (read_only, sz): (bool, usize) = parse_item(bytes, index, "read_only")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AttachRequest {
                 req_type: req_type,
                 length: length,
@@ -202,50 +390,107 @@ impl AsByteSequence for AttachRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.shmseg.size()
-            + self.shmid.size()
-            + self.read_only.size()
-            + 3
+        let size: usize = 15 + self.read_only.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AttachRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AttachRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Attach";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AttachRequest`."]
+#[derive(Clone, Debug)]
+pub struct AttachRequestBuilder {
+    pub inner: AttachRequest,
+}
+impl AttachRequestBuilder {
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    pub fn shmid(mut self, shmid: Card32) -> Self {
+        self.inner.shmid = shmid;
+        self
+    }
+    #[inline]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.inner.read_only = read_only;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AttachRequest {
+        self.inner
+    }
+}
+impl Default for AttachRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AttachRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                shmseg: Default::default(),
+                shmid: Default::default(),
+                read_only: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DetachRequest {
     pub req_type: u8,
     pub length: u16,
     pub shmseg: Seg,
 }
-impl DetachRequest {}
-impl AsByteSequence for DetachRequest {
+impl DetachRequest {
     #[inline]
+    pub fn builder() -> DetachRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("shmseg", 4, 4)];
+}
+const _: () = assert!(<DetachRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DetachRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.shmseg.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DetachRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DetachRequest {
                 req_type: req_type,
                 length: length,
@@ -256,16 +501,58 @@ impl AsByteSequence for DetachRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.shmseg.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DetachRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DetachRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Detach";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DetachRequest`."]
+#[derive(Clone, Debug)]
+pub struct DetachRequestBuilder {
+    pub inner: DetachRequest,
+}
+impl DetachRequestBuilder {
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DetachRequest {
+        self.inner
+    }
+}
+impl Default for DetachRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DetachRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                shmseg: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PutImageRequest {
     pub req_type: u8,
     pub length: u16,
@@ -285,13 +572,20 @@ pub struct PutImageRequest {
     pub shmseg: Seg,
     pub offset: Card32,
 }
-impl PutImageRequest {}
-impl AsByteSequence for PutImageRequest {
+impl PutImageRequest {
     #[inline]
+    pub fn builder() -> PutImageRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<PutImageRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PutImageRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.gc.as_bytes(&mut bytes[index..]);
@@ -306,52 +600,53 @@ impl AsByteSequence for PutImageRequest {
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
         index += self.send_event.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.shmseg.as_bytes(&mut bytes[index..]);
         index += self.offset.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PutImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = parse_item(bytes, index, "gc")?;
         index += sz;
-        let (total_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_width, sz): (Card16, usize) = parse_item(bytes, index, "total_width")?;
         index += sz;
-        let (total_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_height, sz): (Card16, usize) = parse_item(bytes, index, "total_height")?;
         index += sz;
-        let (src_x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Card16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Card16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (src_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_width, sz): (Card16, usize) = parse_item(bytes, index, "src_width")?;
         index += sz;
-        let (src_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_height, sz): (Card16, usize) = parse_item(bytes, index, "src_height")?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = parse_item(bytes, index, "dst_x")?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = parse_item(bytes, index, "dst_y")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        let (send_event, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (send_event, sz): (bool, usize) = parse_item(bytes, index, "send_event")?;
         index += sz;
         index += 1;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = parse_item(bytes, index, "offset")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PutImageRequest {
                 req_type: req_type,
                 length: length,
@@ -376,34 +671,151 @@ impl AsByteSequence for PutImageRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.gc.size()
-            + self.total_width.size()
-            + self.total_height.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + self.src_width.size()
-            + self.src_height.size()
-            + self.dst_x.size()
-            + self.dst_y.size()
-            + self.depth.size()
-            + self.format.size()
-            + self.send_event.size()
-            + 1
-            + self.shmseg.size()
-            + self.offset.size()
+        let size: usize = 31 + self.drawable.size() + self.gc.size() + self.send_event.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PutImageRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PutImageRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutImage";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PutImageRequest`."]
+#[derive(Clone, Debug)]
+pub struct PutImageRequestBuilder {
+    pub inner: PutImageRequest,
+}
+impl PutImageRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn total_width(mut self, total_width: Card16) -> Self {
+        self.inner.total_width = total_width;
+        self
+    }
+    #[inline]
+    pub fn total_height(mut self, total_height: Card16) -> Self {
+        self.inner.total_height = total_height;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Card16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Card16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn src_width(mut self, src_width: Card16) -> Self {
+        self.inner.src_width = src_width;
+        self
+    }
+    #[inline]
+    pub fn src_height(mut self, src_height: Card16) -> Self {
+        self.inner.src_height = src_height;
+        self
+    }
+    #[inline]
+    pub fn dst_x(mut self, dst_x: Int16) -> Self {
+        self.inner.dst_x = dst_x;
+        self
+    }
+    #[inline]
+    pub fn dst_y(mut self, dst_y: Int16) -> Self {
+        self.inner.dst_y = dst_y;
+        self
+    }
+    #[inline]
+    pub fn format(mut self, format: Card8) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn send_event(mut self, send_event: bool) -> Self {
+        self.inner.send_event = send_event;
+        self
+    }
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    pub fn offset(mut self, offset: Card32) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PutImageRequest {
+        self.inner
+    }
+    #[doc = " Set `depth`, which must be between 1 and 32. Other values are refused, rather than sent to the server."]
+    #[inline]
+    pub fn depth(mut self, depth: Card8) -> Result<Self, BuildError> {
+        match depth {
+            1..=32 => {
+                self.inner.depth = depth;
+                Ok(self)
+            }
+            _ => Err(BuildError {
+                field: "depth",
+                value: depth.into(),
+            }),
+        }
+    }
+}
+impl Default for PutImageRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PutImageRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                gc: Default::default(),
+                total_width: Default::default(),
+                total_height: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                src_width: Default::default(),
+                src_height: Default::default(),
+                dst_x: Default::default(),
+                dst_y: Default::default(),
+                depth: Default::default(),
+                format: Default::default(),
+                send_event: Default::default(),
+                shmseg: Default::default(),
+                offset: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetImageRequest {
     pub req_type: u8,
     pub length: u16,
@@ -417,13 +829,26 @@ pub struct GetImageRequest {
     pub shmseg: Seg,
     pub offset: Card32,
 }
-impl GetImageRequest {}
-impl AsByteSequence for GetImageRequest {
+impl GetImageRequest {
+    #[inline]
+    pub fn builder() -> GetImageRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GetImageReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetImageRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetImageRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.x.as_bytes(&mut bytes[index..]);
@@ -432,40 +857,41 @@ impl AsByteSequence for GetImageRequest {
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.plane_mask.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.shmseg.as_bytes(&mut bytes[index..]);
         index += self.offset.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (plane_mask, sz): (Card32, usize) = parse_item(bytes, index, "plane_mask")?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = parse_item(bytes, index, "format")?;
         index += sz;
         index += 3;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = parse_item(bytes, index, "offset")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetImageRequest {
                 req_type: req_type,
                 length: length,
@@ -484,28 +910,105 @@ impl AsByteSequence for GetImageRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
-            + self.plane_mask.size()
-            + self.format.size()
-            + 3
-            + self.shmseg.size()
-            + self.offset.size()
+        let size: usize = 28 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetImageRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetImageRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetImage";
     type Reply = GetImageReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetImageRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetImageRequestBuilder {
+    pub inner: GetImageRequest,
+}
+impl GetImageRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn x(mut self, x: Int16) -> Self {
+        self.inner.x = x;
+        self
+    }
+    #[inline]
+    pub fn y(mut self, y: Int16) -> Self {
+        self.inner.y = y;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn plane_mask(mut self, plane_mask: Card32) -> Self {
+        self.inner.plane_mask = plane_mask;
+        self
+    }
+    #[inline]
+    pub fn format(mut self, format: Card8) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    pub fn offset(mut self, offset: Card32) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetImageRequest {
+        self.inner
+    }
+}
+impl Default for GetImageRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetImageRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                x: Default::default(),
+                y: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                plane_mask: Default::default(),
+                format: Default::default(),
+                shmseg: Default::default(),
+                offset: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetImageReply {
     pub reply_type: u8,
     pub depth: Card8,
@@ -514,9 +1017,42 @@ pub struct GetImageReply {
     pub visual: Visualid,
     pub size: Card32,
 }
-impl GetImageReply {}
-impl AsByteSequence for GetImageReply {
+impl GetImageReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("depth", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.depth == other.depth && self.visual == other.visual && self.size == other.size
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("depth", &self.depth, &other.depth, &mut changes);
+        diff_value("visual", &self.visual, &other.visual, &mut changes);
+        diff_value("size", &self.size, &other.size, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetImageReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -525,25 +1061,27 @@ impl AsByteSequence for GetImageReply {
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.visual.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetImageReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (visual, sz): (Visualid, usize) = parse_item(bytes, index, "visual")?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card32, usize) = parse_item(bytes, index, "size")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetImageReply {
                 reply_type: reply_type,
                 depth: depth,
@@ -557,15 +1095,21 @@ impl AsByteSequence for GetImageReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.depth.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.visual.size()
-            + self.size.size()
+        let size: usize = 12 + self.visual.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetImageReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreatePixmapRequest {
     pub req_type: u8,
     pub length: u16,
@@ -577,49 +1121,57 @@ pub struct CreatePixmapRequest {
     pub shmseg: Seg,
     pub offset: Card32,
 }
-impl CreatePixmapRequest {}
-impl AsByteSequence for CreatePixmapRequest {
+impl CreatePixmapRequest {
     #[inline]
+    pub fn builder() -> CreatePixmapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreatePixmapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreatePixmapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.pid.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.shmseg.as_bytes(&mut bytes[index..]);
         index += self.offset.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (pid, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pid, sz): (Pixmap, usize) = parse_item(bytes, index, "pid")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
         index += 3;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = parse_item(bytes, index, "offset")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreatePixmapRequest {
                 req_type: req_type,
                 length: length,
@@ -636,92 +1188,236 @@ impl AsByteSequence for CreatePixmapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.pid.size()
-            + self.drawable.size()
-            + self.width.size()
-            + self.height.size()
-            + self.depth.size()
-            + 3
-            + self.shmseg.size()
-            + self.offset.size()
+        let size: usize = 20 + self.pid.size() + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreatePixmapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreatePixmapRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePixmap";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreatePixmapRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreatePixmapRequestBuilder {
+    pub inner: CreatePixmapRequest,
+}
+impl CreatePixmapRequestBuilder {
+    #[inline]
+    pub fn pid(mut self, pid: Pixmap) -> Self {
+        self.inner.pid = pid;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    pub fn offset(mut self, offset: Card32) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreatePixmapRequest {
+        self.inner
+    }
+    #[doc = " Set `depth`, which must be between 1 and 32. Other values are refused, rather than sent to the server."]
+    #[inline]
+    pub fn depth(mut self, depth: Card8) -> Result<Self, BuildError> {
+        match depth {
+            1..=32 => {
+                self.inner.depth = depth;
+                Ok(self)
+            }
+            _ => Err(BuildError {
+                field: "depth",
+                value: depth.into(),
+            }),
+        }
+    }
+}
+impl Default for CreatePixmapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreatePixmapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                pid: Default::default(),
+                drawable: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                depth: Default::default(),
+                shmseg: Default::default(),
+                offset: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AttachFdRequest {
     pub req_type: u8,
     pub length: u16,
     pub shmseg: Seg,
+    pub shm_fd: Fd,
     pub read_only: bool,
-    pub shm_fd: Vec<Fd>,
 }
-impl AttachFdRequest {}
-impl AsByteSequence for AttachFdRequest {
+impl AttachFdRequest {
     #[inline]
+    pub fn builder() -> AttachFdRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("shmseg", 4, 4),
+        ("read_only", 8, 1),
+    ];
+}
+const _: () = assert!(<AttachFdRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AttachFdRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.shmseg.as_bytes(&mut bytes[index..]);
         index += self.read_only.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttachFdRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (read_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (read_only, sz): (bool, usize) = parse_item(bytes, index, "read_only")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AttachFdRequest {
                 req_type: req_type,
                 length: length,
                 shmseg: shmseg,
                 read_only: read_only,
-                shm_fd: vec![],
+                shm_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.shmseg.size()
-            + self.read_only.size()
-            + 3
+        let size: usize = 11 + self.read_only.size();
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.shm_fd);
     }
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.shm_fd)
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.shm_fd = *fds.get(0)?;
+        Some(1)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AttachFdRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AttachFdRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AttachFd";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AttachFdRequest`."]
+#[derive(Clone, Debug)]
+pub struct AttachFdRequestBuilder {
+    pub inner: AttachFdRequest,
+}
+impl AttachFdRequestBuilder {
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    pub fn shm_fd(mut self, shm_fd: Fd) -> Self {
+        self.inner.shm_fd = shm_fd;
+        self
+    }
+    #[inline]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.inner.read_only = read_only;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AttachFdRequest {
+        self.inner
+    }
+}
+impl Default for AttachFdRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AttachFdRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                shmseg: Default::default(),
+                shm_fd: Default::default(),
+                read_only: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateSegmentRequest {
     pub req_type: u8,
     pub length: u16,
@@ -729,37 +1425,59 @@ pub struct CreateSegmentRequest {
     pub size: Card32,
     pub read_only: bool,
 }
-impl CreateSegmentRequest {}
-impl AsByteSequence for CreateSegmentRequest {
+impl CreateSegmentRequest {
+    #[inline]
+    pub fn builder() -> CreateSegmentRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateSegmentReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("shmseg", 4, 4),
+        ("size", 8, 4),
+        ("read_only", 12, 1),
+    ];
+}
+const _: () = assert!(<CreateSegmentRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateSegmentRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.shmseg.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
         index += self.read_only.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSegmentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card32, usize) = parse_item(bytes, index, "size")?;
         index += sz;
-        let (read_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (read_only, sz): (bool, usize) = parse_item(bytes, index, "read_only")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateSegmentRequest {
                 req_type: req_type,
                 length: length,
@@ -772,75 +1490,177 @@ impl AsByteSequence for CreateSegmentRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.shmseg.size()
-            + self.size.size()
-            + self.read_only.size()
-            + 3
+        let size: usize = 15 + self.read_only.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateSegmentRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateSegmentRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "CreateSegment";
     type Reply = CreateSegmentReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateSegmentRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateSegmentRequestBuilder {
+    pub inner: CreateSegmentRequest,
+}
+impl CreateSegmentRequestBuilder {
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    pub fn size(mut self, size: Card32) -> Self {
+        self.inner.size = size;
+        self
+    }
+    #[inline]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.inner.read_only = read_only;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateSegmentRequest {
+        self.inner
+    }
+}
+impl Default for CreateSegmentRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateSegmentRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                shmseg: Default::default(),
+                size: Default::default(),
+                read_only: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateSegmentReply {
     pub reply_type: u8,
     pub nfd: Card8,
     pub sequence: u16,
     pub length: u32,
-    pub shm_fd: Vec<Fd>,
+    pub shm_fd: Fd,
 }
-impl CreateSegmentReply {}
-impl AsByteSequence for CreateSegmentReply {
+impl CreateSegmentReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("nfd", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.nfd == other.nfd && self.shm_fd == other.shm_fd
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("nfd", &self.nfd, &other.nfd, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for CreateSegmentReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
         index += self.nfd.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
-        index += 24;
+        index += zero_pad(&mut bytes[index..], 24);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSegmentReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = parse_item(bytes, index, "nfd")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         index += 24;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateSegmentReply {
                 reply_type: reply_type,
                 nfd: nfd,
                 sequence: sequence,
                 length: length,
-                shm_fd: vec![],
+                shm_fd: Default::default(),
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + self.nfd.size() + self.sequence.size() + self.length.size() + 24
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn as_fds(&self, fds: &mut Vec<Fd>) {
+        fds.push(self.shm_fd);
+    }
+    #[inline]
+    fn take_fds(&mut self, fds: &[Fd]) -> Option<usize> {
+        self.shm_fd = *fds.get(0)?;
+        Some(1)
     }
+}
+impl core::convert::TryFrom<&[u8]> for CreateSegmentReply {
+    type Error = ParseError;
     #[inline]
-    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
-        Some(&mut self.shm_fd)
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct CompletionEvent {
     pub event_type: u8,
     pub sequence: u16,
@@ -850,13 +1670,25 @@ pub struct CompletionEvent {
     pub shmseg: Seg,
     pub offset: Card32,
 }
-impl CompletionEvent {}
-impl AsByteSequence for CompletionEvent {
+impl CompletionEvent {
     #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("event_type", 0, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for CompletionEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.minor_event.as_bytes(&mut bytes[index..]);
@@ -865,26 +1697,25 @@ impl AsByteSequence for CompletionEvent {
         index += self.offset.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompletionEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (minor_event, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_event, sz): (Card16, usize) = parse_item(bytes, index, "minor_event")?;
         index += sz;
-        let (major_event, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (major_event, sz): (Byte, usize) = parse_item(bytes, index, "major_event")?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = parse_item(bytes, index, "offset")?;
         index += sz;
-        Some((
+        Ok((
             CompletionEvent {
                 event_type: event_type,
                 sequence: sequence,
@@ -899,16 +1730,84 @@ impl AsByteSequence for CompletionEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + 1
-            + self.sequence.size()
-            + self.drawable.size()
-            + self.minor_event.size()
-            + self.major_event.size()
-            + self.shmseg.size()
-            + self.offset.size()
+        15 + self.drawable.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CompletionEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for CompletionEvent {
     const OPCODE: u8 = 0;
 }
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    Completion(CompletionEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            0 => Ok(Self::Completion(CompletionEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::Completion(_) => CompletionEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<CompletionEvent> for Event {
+    #[inline]
+    fn from(event: CompletionEvent) -> Self {
+        Self::Completion(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "Completion")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "Attach"),
+    (2, "Detach"),
+    (3, "PutImage"),
+    (4, "GetImage"),
+    (5, "CreatePixmap"),
+    (6, "AttachFd"),
+    (7, "CreateSegment"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,13 +1,18 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "sync")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 3;
+pub const MINOR_VERSION: u32 = 1;
 use super::xproto::*;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Alarm {
     pub xid: XID,
 }
@@ -16,6 +21,23 @@ impl Alarm {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Alarm {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Alarm {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Alarm {
     #[inline]
@@ -27,8 +49,30 @@ impl XidType for Alarm {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Alarm {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Alarm({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Alarm {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Alarm> for XID {
+    #[inline]
+    fn from(base: Alarm) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Counter {
     pub xid: XID,
 }
@@ -37,6 +81,23 @@ impl Counter {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Counter {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Counter {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Counter {
     #[inline]
@@ -48,8 +109,30 @@ impl XidType for Counter {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Counter {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Counter({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Counter {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Counter> for XID {
+    #[inline]
+    fn from(base: Counter) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Fence {
     pub xid: XID,
 }
@@ -58,6 +141,23 @@ impl Fence {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Fence {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Fence {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Fence {
     #[inline]
@@ -69,15 +169,40 @@ impl XidType for Fence {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Fence {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Fence({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Fence {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Fence> for XID {
+    #[inline]
+    fn from(base: Fence) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Systemcounter {
     pub counter: Counter,
     pub resolution: Int64,
     pub name: String,
 }
-impl Systemcounter {}
+impl Systemcounter {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("counter", 0, 4), ("resolution", 4, 8), ("name_len", 12, 2)];
+}
 impl AsByteSequence for Systemcounter {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.counter.as_bytes(&mut bytes[index..]);
@@ -85,23 +210,22 @@ impl AsByteSequence for Systemcounter {
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Systemcounter from byte buffer");
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = parse_item(bytes, index, "counter")?;
         index += sz;
-        let (resolution, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (resolution, sz): (Int64, usize) = parse_item(bytes, index, "resolution")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        Some((
+        Ok((
             Systemcounter {
                 counter: counter,
                 resolution: resolution,
@@ -112,21 +236,52 @@ impl AsByteSequence for Systemcounter {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.counter.size() + self.resolution.size() + ::core::mem::size_of::<Card16>() + {
+        14 + {
             let block_len: usize = self.name.len();
             let pad: usize = buffer_pad(block_len, 4);
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Systemcounter {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Trigger {
     pub counter: Counter,
     pub wait_type: Valuetype,
     pub wait_value: Int64,
     pub test_type: Testtype,
 }
-impl Trigger {}
+impl Trigger {
+    #[inline]
+    pub const fn new(
+        counter: Counter,
+        wait_type: Valuetype,
+        wait_value: Int64,
+        test_type: Testtype,
+    ) -> Self {
+        Self {
+            counter: counter,
+            wait_type: wait_type,
+            wait_value: wait_value,
+            test_type: test_type,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("counter", 0, 4),
+        ("wait_type", 4, 4),
+        ("wait_value", 8, 8),
+        ("test_type", 16, 4),
+    ];
+}
 impl AsByteSequence for Trigger {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -137,19 +292,18 @@ impl AsByteSequence for Trigger {
         index += self.test_type.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Trigger from byte buffer");
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = parse_item(bytes, index, "counter")?;
         index += sz;
-        let (wait_type, sz): (Valuetype, usize) = <Valuetype>::from_bytes(&bytes[index..])?;
+        let (wait_type, sz): (Valuetype, usize) = parse_item(bytes, index, "wait_type")?;
         index += sz;
-        let (wait_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (wait_value, sz): (Int64, usize) = parse_item(bytes, index, "wait_value")?;
         index += sz;
-        let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(&bytes[index..])?;
+        let (test_type, sz): (Testtype, usize) = parse_item(bytes, index, "test_type")?;
         index += sz;
-        Some((
+        Ok((
             Trigger {
                 counter: counter,
                 wait_type: wait_type,
@@ -161,11 +315,20 @@ impl AsByteSequence for Trigger {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.counter.size() + self.wait_type.size() + self.wait_value.size() + self.test_type.size()
+        20
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Trigger {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Valuetype {
     Absolute = 0,
     Relative = 1,
@@ -176,12 +339,12 @@ impl AsByteSequence for Valuetype {
         (*self as u32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Absolute, sz)),
-            1 => Some((Self::Relative, sz)),
-            _ => None,
+            0 => Ok((Self::Absolute, sz)),
+            1 => Ok((Self::Relative, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -189,14 +352,40 @@ impl AsByteSequence for Valuetype {
         ::core::mem::size_of::<u32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Valuetype {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Valuetype {
     #[inline]
     fn default() -> Valuetype {
         Valuetype::Absolute
     }
 }
+impl From<Valuetype> for u32 {
+    #[inline]
+    fn from(base: Valuetype) -> Self {
+        base as u32
+    }
+}
+impl core::convert::TryFrom<u32> for Valuetype {
+    type Error = u32;
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::Absolute),
+            1 => Ok(Self::Relative),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Testtype {
     PositiveTransition = 0,
     NegativeTransition = 1,
@@ -209,14 +398,14 @@ impl AsByteSequence for Testtype {
         (*self as u32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::PositiveTransition, sz)),
-            1 => Some((Self::NegativeTransition, sz)),
-            2 => Some((Self::PositiveComparison, sz)),
-            3 => Some((Self::NegativeComparison, sz)),
-            _ => None,
+            0 => Ok((Self::PositiveTransition, sz)),
+            1 => Ok((Self::NegativeTransition, sz)),
+            2 => Ok((Self::PositiveComparison, sz)),
+            3 => Ok((Self::NegativeComparison, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -224,18 +413,57 @@ impl AsByteSequence for Testtype {
         ::core::mem::size_of::<u32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Testtype {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Testtype {
     #[inline]
     fn default() -> Testtype {
         Testtype::PositiveTransition
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Testtype> for u32 {
+    #[inline]
+    fn from(base: Testtype) -> Self {
+        base as u32
+    }
+}
+impl core::convert::TryFrom<u32> for Testtype {
+    type Error = u32;
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, u32> {
+        match value {
+            0 => Ok(Self::PositiveTransition),
+            1 => Ok(Self::NegativeTransition),
+            2 => Ok(Self::PositiveComparison),
+            3 => Ok(Self::NegativeComparison),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Waitcondition {
     pub trigger: Trigger,
     pub event_threshold: Int64,
 }
-impl Waitcondition {}
+impl Waitcondition {
+    #[inline]
+    pub const fn new(trigger: Trigger, event_threshold: Int64) -> Self {
+        Self {
+            trigger: trigger,
+            event_threshold: event_threshold,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("trigger", 0, 20), ("event_threshold", 20, 8)];
+}
 impl AsByteSequence for Waitcondition {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -245,14 +473,14 @@ impl AsByteSequence for Waitcondition {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Waitcondition from byte buffer");
-        let (trigger, sz): (Trigger, usize) = <Trigger>::from_bytes(&bytes[index..])?;
+        let (trigger, sz): (Trigger, usize) = parse_item(bytes, index, "trigger")?;
         index += sz;
-        let (event_threshold, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (event_threshold, sz): (Int64, usize) = parse_item(bytes, index, "event_threshold")?;
         index += sz;
-        Some((
+        Ok((
             Waitcondition {
                 trigger: trigger,
                 event_threshold: event_threshold,
@@ -262,42 +490,81 @@ impl AsByteSequence for Waitcondition {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.trigger.size() + self.event_threshold.size()
+        28
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Waitcondition {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct InitializeRequest {
     pub req_type: u8,
     pub length: u16,
     pub desired_major_version: Card8,
     pub desired_minor_version: Card8,
 }
-impl InitializeRequest {}
-impl AsByteSequence for InitializeRequest {
+impl InitializeRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> InitializeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<InitializeReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("desired_major_version", 4, 1),
+        ("desired_minor_version", 5, 1),
+    ];
+}
+const _: () = assert!(<InitializeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for InitializeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.desired_major_version.as_bytes(&mut bytes[index..]);
         index += self.desired_minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing InitializeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (desired_major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (desired_major_version, sz): (Card8, usize) =
+            parse_item(bytes, index, "desired_major_version")?;
         index += sz;
-        let (desired_minor_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (desired_minor_version, sz): (Card8, usize) =
+            parse_item(bytes, index, "desired_minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             InitializeRequest {
                 req_type: req_type,
                 length: length,
@@ -309,20 +576,63 @@ impl AsByteSequence for InitializeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.desired_major_version.size()
-            + self.desired_minor_version.size()
+        let size: usize = 6;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for InitializeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for InitializeRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Initialize";
     type Reply = InitializeReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `InitializeRequest`."]
+#[derive(Clone, Debug)]
+pub struct InitializeRequestBuilder {
+    pub inner: InitializeRequest,
+}
+impl InitializeRequestBuilder {
+    #[inline]
+    pub fn desired_major_version(mut self, desired_major_version: Card8) -> Self {
+        self.inner.desired_major_version = desired_major_version;
+        self
+    }
+    #[inline]
+    pub fn desired_minor_version(mut self, desired_minor_version: Card8) -> Self {
+        self.inner.desired_minor_version = desired_minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> InitializeRequest {
+        self.inner
+    }
+}
+impl Default for InitializeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: InitializeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                desired_major_version: Default::default(),
+                desired_minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct InitializeReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -330,37 +640,108 @@ pub struct InitializeReply {
     pub major_version: Card8,
     pub minor_version: Card8,
 }
-impl InitializeReply {}
-impl AsByteSequence for InitializeReply {
+impl InitializeReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card8 = read_item(reader, &mut index, 1, "major_version")?;
+        let minor_version: Card8 = read_item(reader, &mut index, 1, "minor_version")?;
+        skip_bytes(reader, &mut index, 22)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(InitializeReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 1),
+        ("minor_version", 9, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for InitializeReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
-        index += 22;
+        index += zero_pad(&mut bytes[index..], 22);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing InitializeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card8, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card8, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
         index += 22;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             InitializeReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -373,40 +754,69 @@ impl AsByteSequence for InitializeReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
-            + 22
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for InitializeReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListSystemCountersRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl ListSystemCountersRequest {}
+impl ListSystemCountersRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> ListSystemCountersRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListSystemCountersReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<ListSystemCountersRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for ListSystemCountersRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSystemCountersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListSystemCountersRequest {
                 req_type: req_type,
                 length: length,
@@ -416,57 +826,143 @@ impl AsByteSequence for ListSystemCountersRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListSystemCountersRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ListSystemCountersRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListSystemCounters";
     type Reply = ListSystemCountersReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ListSystemCountersRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListSystemCountersRequestBuilder {
+    pub inner: ListSystemCountersRequest,
+}
+impl ListSystemCountersRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListSystemCountersRequest {
+        self.inner
+    }
+}
+impl Default for ListSystemCountersRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListSystemCountersRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ListSystemCountersReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub counters: Vec<Systemcounter>,
 }
-impl ListSystemCountersReply {}
-impl AsByteSequence for ListSystemCountersReply {
+impl ListSystemCountersReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn counters_iter(bytes: &[u8]) -> Result<ListIter<'_, Systemcounter>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::padded(bytes, index, len0 as usize, 4, "counters"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("counters_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.counters == other.counters
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("counters", &self.counters, &other.counters, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for ListSystemCountersReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.counters.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = vector_as_bytes(&self.counters, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 20);
+        let block_len: usize = padded_vector_as_bytes(&self.counters, &mut bytes[index..], 4);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Systemcounter>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Systemcounter>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSystemCountersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (counters, block_len): (Vec<Systemcounter>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 4, "counters")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Systemcounter>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListSystemCountersReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -478,52 +974,77 @@ impl AsByteSequence for ListSystemCountersReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.counters.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Systemcounter>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self
+                .counters
+                .iter()
+                .map(|i| {
+                    let size = i.size();
+                    size + buffer_pad(size, 4)
+                })
+                .sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Systemcounter>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListSystemCountersReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateCounterRequest {
     pub req_type: u8,
     pub length: u16,
     pub id: Counter,
     pub initial_value: Int64,
 }
-impl CreateCounterRequest {}
+impl CreateCounterRequest {
+    #[inline]
+    pub fn builder() -> CreateCounterRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("id", 4, 4),
+        ("initial_value", 8, 8),
+    ];
+}
+const _: () = assert!(<CreateCounterRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for CreateCounterRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.id.as_bytes(&mut bytes[index..]);
         index += self.initial_value.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (id, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Counter, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (initial_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (initial_value, sz): (Int64, usize) = parse_item(bytes, index, "initial_value")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateCounterRequest {
                 req_type: req_type,
                 length: length,
@@ -535,144 +1056,329 @@ impl AsByteSequence for CreateCounterRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.id.size() + self.initial_value.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateCounterRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateCounterRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateCounter";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct DestroyCounterRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub counter: Counter,
+#[doc = " A builder for `CreateCounterRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateCounterRequestBuilder {
+    pub inner: CreateCounterRequest,
 }
-impl DestroyCounterRequest {}
-impl AsByteSequence for DestroyCounterRequest {
+impl CreateCounterRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.counter.as_bytes(&mut bytes[index..]);
-        index
+    pub fn id(mut self, id: Counter) -> Self {
+        self.inner.id = id;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DestroyCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DestroyCounterRequest {
-                req_type: req_type,
-                length: length,
-                counter: counter,
+    pub fn initial_value(mut self, initial_value: Int64) -> Self {
+        self.inner.initial_value = initial_value;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateCounterRequest {
+        self.inner
+    }
+}
+impl Default for CreateCounterRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateCounterRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                id: Default::default(),
+                initial_value: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct DestroyCounterRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub counter: Counter,
+}
+impl DestroyCounterRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.counter.size()
+    pub fn builder() -> DestroyCounterRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("counter", 4, 4)];
 }
+const _: () = assert!(<DestroyCounterRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_counter_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.counter.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (counter, sz): (Counter, usize) = parse_item(bytes, index, "counter")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        counter: counter,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_counter_only_request_asb! { DestroyCounterRequest }
 impl Request for DestroyCounterRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyCounter";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyCounterRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyCounterRequestBuilder {
+    pub inner: DestroyCounterRequest,
+}
+impl DestroyCounterRequestBuilder {
+    #[inline]
+    pub fn counter(mut self, counter: Counter) -> Self {
+        self.inner.counter = counter;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyCounterRequest {
+        self.inner
+    }
+}
+impl Default for DestroyCounterRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyCounterRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                counter: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryCounterRequest {
     pub req_type: u8,
     pub length: u16,
     pub counter: Counter,
 }
-impl QueryCounterRequest {}
-impl AsByteSequence for QueryCounterRequest {
+impl QueryCounterRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.counter.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryCounterRequest {
-                req_type: req_type,
-                length: length,
-                counter: counter,
-            },
-            index,
-        ))
+    pub fn builder() -> QueryCounterRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.counter.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryCounterReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("counter", 4, 4)];
 }
+const _: () = assert!(<QueryCounterRequest as Request>::MINOR_OPCODE < 0x100);
+impl_counter_only_request_asb! { QueryCounterRequest }
 impl Request for QueryCounterRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryCounter";
     type Reply = QueryCounterReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryCounterRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryCounterRequestBuilder {
+    pub inner: QueryCounterRequest,
+}
+impl QueryCounterRequestBuilder {
+    #[inline]
+    pub fn counter(mut self, counter: Counter) -> Self {
+        self.inner.counter = counter;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryCounterRequest {
+        self.inner
+    }
+}
+impl Default for QueryCounterRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryCounterRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                counter: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryCounterReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub counter_value: Int64,
 }
-impl QueryCounterReply {}
-impl AsByteSequence for QueryCounterReply {
+impl QueryCounterReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let counter_value: Int64 = read_item(reader, &mut index, 8, "counter_value")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryCounterReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            counter_value: counter_value,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("counter_value", 8, 8),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.counter_value == other.counter_value
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "counter_value",
+            &self.counter_value,
+            &other.counter_value,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryCounterReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.counter_value.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryCounterReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (counter_value, sz): (Int64, usize) = parse_item(bytes, index, "counter_value")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryCounterReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -684,46 +1390,71 @@ impl AsByteSequence for QueryCounterReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.counter_value.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryCounterReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AwaitRequest {
     pub req_type: u8,
     pub length: u16,
     pub wait_list: Vec<Waitcondition>,
 }
-impl AwaitRequest {}
-impl AsByteSequence for AwaitRequest {
+impl AwaitRequest {
     #[inline]
+    pub fn builder() -> AwaitRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<AwaitRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AwaitRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.wait_list, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Waitcondition>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Waitcondition>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AwaitRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (wait_list, block_len): (Vec<Waitcondition>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (wait_list, block_len): (Vec<Waitcondition>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "wait_list",
+                offset: index,
+            })? as usize,
+            "wait_list",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Waitcondition>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AwaitRequest {
                 req_type: req_type,
                 length: length,
@@ -734,52 +1465,121 @@ impl AsByteSequence for AwaitRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + {
+        let size: usize = 4 + {
             let block_len: usize = self.wait_list.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Waitcondition>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AwaitRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AwaitRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Await";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AwaitRequest`."]
+#[derive(Clone, Debug)]
+pub struct AwaitRequestBuilder {
+    pub inner: AwaitRequest,
+}
+impl AwaitRequestBuilder {
+    #[inline]
+    pub fn wait_list(mut self, wait_list: Vec<Waitcondition>) -> Self {
+        self.inner.wait_list = wait_list;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AwaitRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Waitcondition> for AwaitRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Waitcondition>>(&mut self, iter: I) {
+        self.inner.wait_list.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Waitcondition> for AwaitRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Waitcondition>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for AwaitRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AwaitRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                wait_list: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangeCounterRequest {
     pub req_type: u8,
     pub length: u16,
     pub counter: Counter,
     pub amount: Int64,
 }
-impl ChangeCounterRequest {}
+impl ChangeCounterRequest {
+    #[inline]
+    pub fn builder() -> ChangeCounterRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("counter", 4, 4),
+        ("amount", 8, 8),
+    ];
+}
+const _: () = assert!(<ChangeCounterRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for ChangeCounterRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.counter.as_bytes(&mut bytes[index..]);
         index += self.amount.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = parse_item(bytes, index, "counter")?;
         index += sz;
-        let (amount, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (amount, sz): (Int64, usize) = parse_item(bytes, index, "amount")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ChangeCounterRequest {
                 req_type: req_type,
                 length: length,
@@ -791,48 +1591,109 @@ impl AsByteSequence for ChangeCounterRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.counter.size() + self.amount.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ChangeCounterRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ChangeCounterRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeCounter";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ChangeCounterRequest`."]
+#[derive(Clone, Debug)]
+pub struct ChangeCounterRequestBuilder {
+    pub inner: ChangeCounterRequest,
+}
+impl ChangeCounterRequestBuilder {
+    #[inline]
+    pub fn counter(mut self, counter: Counter) -> Self {
+        self.inner.counter = counter;
+        self
+    }
+    #[inline]
+    pub fn amount(mut self, amount: Int64) -> Self {
+        self.inner.amount = amount;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangeCounterRequest {
+        self.inner
+    }
+}
+impl Default for ChangeCounterRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangeCounterRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                counter: Default::default(),
+                amount: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetCounterRequest {
     pub req_type: u8,
     pub length: u16,
     pub counter: Counter,
     pub value: Int64,
 }
-impl SetCounterRequest {}
+impl SetCounterRequest {
+    #[inline]
+    pub fn builder() -> SetCounterRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("counter", 4, 4),
+        ("value", 8, 8),
+    ];
+}
+const _: () = assert!(<SetCounterRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for SetCounterRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.counter.as_bytes(&mut bytes[index..]);
         index += self.value.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = parse_item(bytes, index, "counter")?;
         index += sz;
-        let (value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int64, usize) = parse_item(bytes, index, "value")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetCounterRequest {
                 req_type: req_type,
                 length: length,
@@ -844,154 +1705,400 @@ impl AsByteSequence for SetCounterRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.counter.size() + self.value.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetCounterRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetCounterRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCounter";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetCounterRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetCounterRequestBuilder {
+    pub inner: SetCounterRequest,
+}
+impl SetCounterRequestBuilder {
+    #[inline]
+    pub fn counter(mut self, counter: Counter) -> Self {
+        self.inner.counter = counter;
+        self
+    }
+    #[inline]
+    pub fn value(mut self, value: Int64) -> Self {
+        self.inner.value = value;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetCounterRequest {
+        self.inner
+    }
+}
+impl Default for SetCounterRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetCounterRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                counter: Default::default(),
+                value: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateAlarmRequest {
     pub req_type: u8,
     pub length: u16,
     pub id: Alarm,
     pub value_mask: Ca,
-    pub counter: Counter,
-    pub value_type: Valuetype,
-    pub value: Int64,
-    pub test_type: Testtype,
-    pub delta: Int64,
-    pub events: Card32,
+    pub counter: Option<Counter>,
+    pub value_type: Option<Valuetype>,
+    pub value: Option<Int64>,
+    pub test_type: Option<Testtype>,
+    pub delta: Option<Int64>,
+    pub events: Option<Card32>,
 }
-impl CreateAlarmRequest {}
-impl AsByteSequence for CreateAlarmRequest {
+impl CreateAlarmRequest {
+    #[inline]
+    pub fn builder() -> CreateAlarmRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("id", 4, 4),
+        ("value_mask", 8, 4),
+    ];
+    #[doc = " Set `counter`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_counter(&mut self, counter: Counter) -> &mut Self {
+        self.value_mask.set_counter(true);
+        self.counter = Some(counter);
+        self
+    }
+    #[doc = " Leave out `counter`, and clear the bit of `value_mask` that says it is present."]
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.id.as_bytes(&mut bytes[index..]);
-        index += self.value_mask.as_bytes(&mut bytes[index..]);
-        let cond0 = (self.value_mask);
-        if cond0.counter() {
-            index += self.counter.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.value_type() {
-            index += self.value_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.value() {
-            index += self.value.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.test_type() {
-            index += self.test_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.delta() {
-            index += self.delta.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.events() {
-            index += self.events.as_bytes(&mut bytes[index..]);
-        }
-        index
+    pub fn clear_counter(&mut self) -> &mut Self {
+        self.value_mask.set_counter(false);
+        self.counter = None;
+        self
     }
+    #[doc = " Set `value_type`, and set the bit of `value_mask` that says it is present."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CreateAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (id, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (value_mask, sz): (Ca, usize) = <Ca>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let cond0 = (value_mask);
-        let counter: Counter = if cond0.counter() {
-            let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
-            index += sz;
-            counter
-        } else {
-            Default::default()
-        };
-        let value_type: Valuetype = if cond0.value_type() {
-            let (value_type, sz): (Valuetype, usize) = <Valuetype>::from_bytes(&bytes[index..])?;
-            index += sz;
-            value_type
-        } else {
-            Default::default()
-        };
-        let value: Int64 = if cond0.value() {
-            let (value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
-            index += sz;
-            value
-        } else {
-            Default::default()
-        };
-        let test_type: Testtype = if cond0.test_type() {
-            let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(&bytes[index..])?;
-            index += sz;
-            test_type
-        } else {
-            Default::default()
-        };
-        let delta: Int64 = if cond0.delta() {
-            let (delta, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
-            index += sz;
-            delta
-        } else {
-            Default::default()
-        };
-        let events: Card32 = if cond0.events() {
-            let (events, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-            index += sz;
-            events
-        } else {
-            Default::default()
-        };
-        Some((
-            CreateAlarmRequest {
-                req_type: req_type,
-                length: length,
-                id: id,
-                value_mask: value_mask,
-                counter: counter,
-                value_type: value_type,
-                value: value,
-                test_type: test_type,
-                delta: delta,
-                events: events,
-            },
-            index,
-        ))
+    pub fn set_value_type(&mut self, value_type: Valuetype) -> &mut Self {
+        self.value_mask.set_value_type(true);
+        self.value_type = Some(value_type);
+        self
     }
+    #[doc = " Leave out `value_type`, and clear the bit of `value_mask` that says it is present."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.id.size()
-            + self.value_mask.size()
-            + self.counter.size()
-            + self.value_type.size()
-            + self.value.size()
-            + self.test_type.size()
-            + self.delta.size()
-            + self.events.size()
+    pub fn clear_value_type(&mut self) -> &mut Self {
+        self.value_mask.set_value_type(false);
+        self.value_type = None;
+        self
+    }
+    #[doc = " Set `value`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_value(&mut self, value: Int64) -> &mut Self {
+        self.value_mask.set_value(true);
+        self.value = Some(value);
+        self
+    }
+    #[doc = " Leave out `value`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_value(&mut self) -> &mut Self {
+        self.value_mask.set_value(false);
+        self.value = None;
+        self
+    }
+    #[doc = " Set `test_type`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_test_type(&mut self, test_type: Testtype) -> &mut Self {
+        self.value_mask.set_test_type(true);
+        self.test_type = Some(test_type);
+        self
+    }
+    #[doc = " Leave out `test_type`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_test_type(&mut self) -> &mut Self {
+        self.value_mask.set_test_type(false);
+        self.test_type = None;
+        self
+    }
+    #[doc = " Set `delta`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_delta(&mut self, delta: Int64) -> &mut Self {
+        self.value_mask.set_delta(true);
+        self.delta = Some(delta);
+        self
+    }
+    #[doc = " Leave out `delta`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_delta(&mut self) -> &mut Self {
+        self.value_mask.set_delta(false);
+        self.delta = None;
+        self
+    }
+    #[doc = " Set `events`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_events(&mut self, events: Card32) -> &mut Self {
+        self.value_mask.set_events(true);
+        self.events = Some(events);
+        self
+    }
+    #[doc = " Leave out `events`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_events(&mut self) -> &mut Self {
+        self.value_mask.set_events(false);
+        self.events = None;
+        self
     }
 }
+const _: () = assert!(<CreateAlarmRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_id_value_mask_value_list_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.id.as_bytes(&mut bytes[index..]);
+                let mut cond0: Ca = self.value_mask;
+                cond0.set_counter(self.counter.is_some());
+                cond0.set_value_type(self.value_type.is_some());
+                cond0.set_value(self.value.is_some());
+                cond0.set_test_type(self.test_type.is_some());
+                cond0.set_delta(self.delta.is_some());
+                cond0.set_events(self.events.is_some());
+                index += cond0.as_bytes(&mut bytes[index..]);
+                if cond0.counter() {
+                    index += self.counter.as_bytes(&mut bytes[index..]);
+                }
+                if cond0.value_type() {
+                    index += self.value_type.as_bytes(&mut bytes[index..]);
+                }
+                if cond0.value() {
+                    index += self.value.as_bytes(&mut bytes[index..]);
+                }
+                if cond0.test_type() {
+                    index += self.test_type.as_bytes(&mut bytes[index..]);
+                }
+                if cond0.delta() {
+                    index += self.delta.as_bytes(&mut bytes[index..]);
+                }
+                if cond0.events() {
+                    index += self.events.as_bytes(&mut bytes[index..]);
+                }
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (id, sz): (Alarm, usize) = parse_item(bytes, index, "id")?;
+                index += sz;
+                let (value_mask, sz): (Ca, usize) = parse_item(bytes, index, "value_mask")?;
+                index += sz;
+                let cond0 = (value_mask);
+                let (counter,) = if cond0.counter() {
+                    let (counter, sz): (Counter, usize) = parse_item(bytes, index, "counter")?;
+                    index += sz;
+                    (Some(counter),)
+                } else {
+                    (Default::default(),)
+                };
+                let (value_type,) = if cond0.value_type() {
+                    let (value_type, sz): (Valuetype, usize) =
+                        parse_item(bytes, index, "value_type")?;
+                    index += sz;
+                    (Some(value_type),)
+                } else {
+                    (Default::default(),)
+                };
+                let (value,) = if cond0.value() {
+                    let (value, sz): (Int64, usize) = parse_item(bytes, index, "value")?;
+                    index += sz;
+                    (Some(value),)
+                } else {
+                    (Default::default(),)
+                };
+                let (test_type,) = if cond0.test_type() {
+                    let (test_type, sz): (Testtype, usize) = parse_item(bytes, index, "test_type")?;
+                    index += sz;
+                    (Some(test_type),)
+                } else {
+                    (Default::default(),)
+                };
+                let (delta,) = if cond0.delta() {
+                    let (delta, sz): (Int64, usize) = parse_item(bytes, index, "delta")?;
+                    index += sz;
+                    (Some(delta),)
+                } else {
+                    (Default::default(),)
+                };
+                let (events,) = if cond0.events() {
+                    let (events, sz): (Card32, usize) = parse_item(bytes, index, "events")?;
+                    index += sz;
+                    (Some(events),)
+                } else {
+                    (Default::default(),)
+                };
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        id: id,
+                        value_mask: value_mask,
+                        counter: counter,
+                        value_type: value_type,
+                        value: value,
+                        test_type: test_type,
+                        delta: delta,
+                        events: events,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 12
+                    + self.counter.size()
+                    + self.value_type.size()
+                    + self.value.size()
+                    + self.test_type.size()
+                    + self.delta.size()
+                    + self.events.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_id_value_mask_value_list_request_asb! { CreateAlarmRequest }
 impl Request for CreateAlarmRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateAlarm";
     type Reply = ();
 }
+#[doc = " A builder for `CreateAlarmRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateAlarmRequestBuilder {
+    pub inner: CreateAlarmRequest,
+}
+impl CreateAlarmRequestBuilder {
+    #[inline]
+    pub fn id(mut self, id: Alarm) -> Self {
+        self.inner.id = id;
+        self
+    }
+    #[inline]
+    pub fn value_mask(mut self, value_mask: Ca) -> Self {
+        self.inner.value_mask = value_mask;
+        self
+    }
+    #[inline]
+    pub fn counter(mut self, counter: Counter) -> Self {
+        self.inner.value_mask.set_counter(true);
+        self.inner.counter = Some(counter);
+        self
+    }
+    #[inline]
+    pub fn value_type(mut self, value_type: Valuetype) -> Self {
+        self.inner.value_mask.set_value_type(true);
+        self.inner.value_type = Some(value_type);
+        self
+    }
+    #[inline]
+    pub fn value(mut self, value: Int64) -> Self {
+        self.inner.value_mask.set_value(true);
+        self.inner.value = Some(value);
+        self
+    }
+    #[inline]
+    pub fn test_type(mut self, test_type: Testtype) -> Self {
+        self.inner.value_mask.set_test_type(true);
+        self.inner.test_type = Some(test_type);
+        self
+    }
+    #[inline]
+    pub fn delta(mut self, delta: Int64) -> Self {
+        self.inner.value_mask.set_delta(true);
+        self.inner.delta = Some(delta);
+        self
+    }
+    #[inline]
+    pub fn events(mut self, events: Card32) -> Self {
+        self.inner.value_mask.set_events(true);
+        self.inner.events = Some(events);
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateAlarmRequest {
+        self.inner
+    }
+}
+impl Default for CreateAlarmRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateAlarmRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                id: Default::default(),
+                value_mask: Default::default(),
+                counter: Default::default(),
+                value_type: Default::default(),
+                value: Default::default(),
+                test_type: Default::default(),
+                delta: Default::default(),
+                events: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Ca {
     pub inner: u32,
 }
@@ -1116,19 +2223,39 @@ impl Ca {
     pub const EVENTS: Self = Self { inner: 32 };
     pub const COMPLETE: Self = Self { inner: 63 };
 }
+impl core::ops::Deref for Ca {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &u32 {
+        &self.inner
+    }
+}
+impl AsRef<u32> for Ca {
+    #[inline]
+    fn as_ref(&self) -> &u32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for Ca {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((Ca { inner: inner }, sz))
+        Ok((Ca { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Ca {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for Ca {
@@ -1165,240 +2292,382 @@ impl core::ops::BitXor for Ca {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangeAlarmRequest {
     pub req_type: u8,
     pub length: u16,
     pub id: Alarm,
     pub value_mask: Ca,
-    pub counter: Counter,
-    pub value_type: Valuetype,
-    pub value: Int64,
-    pub test_type: Testtype,
-    pub delta: Int64,
-    pub events: Card32,
+    pub counter: Option<Counter>,
+    pub value_type: Option<Valuetype>,
+    pub value: Option<Int64>,
+    pub test_type: Option<Testtype>,
+    pub delta: Option<Int64>,
+    pub events: Option<Card32>,
 }
-impl ChangeAlarmRequest {}
-impl AsByteSequence for ChangeAlarmRequest {
+impl ChangeAlarmRequest {
+    #[inline]
+    pub fn builder() -> ChangeAlarmRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("id", 4, 4),
+        ("value_mask", 8, 4),
+    ];
+    #[doc = " Set `counter`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_counter(&mut self, counter: Counter) -> &mut Self {
+        self.value_mask.set_counter(true);
+        self.counter = Some(counter);
+        self
+    }
+    #[doc = " Leave out `counter`, and clear the bit of `value_mask` that says it is present."]
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.id.as_bytes(&mut bytes[index..]);
-        index += self.value_mask.as_bytes(&mut bytes[index..]);
-        let cond0 = (self.value_mask);
-        if cond0.counter() {
-            index += self.counter.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.value_type() {
-            index += self.value_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.value() {
-            index += self.value.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.test_type() {
-            index += self.test_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.delta() {
-            index += self.delta.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.events() {
-            index += self.events.as_bytes(&mut bytes[index..]);
-        }
-        index
+    pub fn clear_counter(&mut self) -> &mut Self {
+        self.value_mask.set_counter(false);
+        self.counter = None;
+        self
     }
+    #[doc = " Set `value_type`, and set the bit of `value_mask` that says it is present."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ChangeAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (id, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (value_mask, sz): (Ca, usize) = <Ca>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let cond0 = (value_mask);
-        let counter: Counter = if cond0.counter() {
-            let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
-            index += sz;
-            counter
-        } else {
-            Default::default()
-        };
-        let value_type: Valuetype = if cond0.value_type() {
-            let (value_type, sz): (Valuetype, usize) = <Valuetype>::from_bytes(&bytes[index..])?;
-            index += sz;
-            value_type
-        } else {
-            Default::default()
-        };
-        let value: Int64 = if cond0.value() {
-            let (value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
-            index += sz;
-            value
-        } else {
-            Default::default()
-        };
-        let test_type: Testtype = if cond0.test_type() {
-            let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(&bytes[index..])?;
-            index += sz;
-            test_type
-        } else {
-            Default::default()
-        };
-        let delta: Int64 = if cond0.delta() {
-            let (delta, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
-            index += sz;
-            delta
-        } else {
-            Default::default()
-        };
-        let events: Card32 = if cond0.events() {
-            let (events, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-            index += sz;
-            events
-        } else {
-            Default::default()
-        };
-        Some((
-            ChangeAlarmRequest {
-                req_type: req_type,
-                length: length,
-                id: id,
-                value_mask: value_mask,
-                counter: counter,
-                value_type: value_type,
-                value: value,
-                test_type: test_type,
-                delta: delta,
-                events: events,
-            },
-            index,
-        ))
+    pub fn set_value_type(&mut self, value_type: Valuetype) -> &mut Self {
+        self.value_mask.set_value_type(true);
+        self.value_type = Some(value_type);
+        self
     }
+    #[doc = " Leave out `value_type`, and clear the bit of `value_mask` that says it is present."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.id.size()
-            + self.value_mask.size()
-            + self.counter.size()
-            + self.value_type.size()
-            + self.value.size()
-            + self.test_type.size()
-            + self.delta.size()
-            + self.events.size()
+    pub fn clear_value_type(&mut self) -> &mut Self {
+        self.value_mask.set_value_type(false);
+        self.value_type = None;
+        self
+    }
+    #[doc = " Set `value`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_value(&mut self, value: Int64) -> &mut Self {
+        self.value_mask.set_value(true);
+        self.value = Some(value);
+        self
+    }
+    #[doc = " Leave out `value`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_value(&mut self) -> &mut Self {
+        self.value_mask.set_value(false);
+        self.value = None;
+        self
+    }
+    #[doc = " Set `test_type`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_test_type(&mut self, test_type: Testtype) -> &mut Self {
+        self.value_mask.set_test_type(true);
+        self.test_type = Some(test_type);
+        self
+    }
+    #[doc = " Leave out `test_type`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_test_type(&mut self) -> &mut Self {
+        self.value_mask.set_test_type(false);
+        self.test_type = None;
+        self
+    }
+    #[doc = " Set `delta`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_delta(&mut self, delta: Int64) -> &mut Self {
+        self.value_mask.set_delta(true);
+        self.delta = Some(delta);
+        self
+    }
+    #[doc = " Leave out `delta`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_delta(&mut self) -> &mut Self {
+        self.value_mask.set_delta(false);
+        self.delta = None;
+        self
+    }
+    #[doc = " Set `events`, and set the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn set_events(&mut self, events: Card32) -> &mut Self {
+        self.value_mask.set_events(true);
+        self.events = Some(events);
+        self
+    }
+    #[doc = " Leave out `events`, and clear the bit of `value_mask` that says it is present."]
+    #[inline]
+    pub fn clear_events(&mut self) -> &mut Self {
+        self.value_mask.set_events(false);
+        self.events = None;
+        self
     }
 }
+const _: () = assert!(<ChangeAlarmRequest as Request>::MINOR_OPCODE < 0x100);
+impl_id_value_mask_value_list_request_asb! { ChangeAlarmRequest }
 impl Request for ChangeAlarmRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeAlarm";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct DestroyAlarmRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub alarm: Alarm,
+#[doc = " A builder for `ChangeAlarmRequest`."]
+#[derive(Clone, Debug)]
+pub struct ChangeAlarmRequestBuilder {
+    pub inner: ChangeAlarmRequest,
 }
-impl DestroyAlarmRequest {}
-impl AsByteSequence for DestroyAlarmRequest {
+impl ChangeAlarmRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.alarm.as_bytes(&mut bytes[index..]);
-        index
+    pub fn id(mut self, id: Alarm) -> Self {
+        self.inner.id = id;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DestroyAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DestroyAlarmRequest {
-                req_type: req_type,
-                length: length,
-                alarm: alarm,
+    pub fn value_mask(mut self, value_mask: Ca) -> Self {
+        self.inner.value_mask = value_mask;
+        self
+    }
+    #[inline]
+    pub fn counter(mut self, counter: Counter) -> Self {
+        self.inner.value_mask.set_counter(true);
+        self.inner.counter = Some(counter);
+        self
+    }
+    #[inline]
+    pub fn value_type(mut self, value_type: Valuetype) -> Self {
+        self.inner.value_mask.set_value_type(true);
+        self.inner.value_type = Some(value_type);
+        self
+    }
+    #[inline]
+    pub fn value(mut self, value: Int64) -> Self {
+        self.inner.value_mask.set_value(true);
+        self.inner.value = Some(value);
+        self
+    }
+    #[inline]
+    pub fn test_type(mut self, test_type: Testtype) -> Self {
+        self.inner.value_mask.set_test_type(true);
+        self.inner.test_type = Some(test_type);
+        self
+    }
+    #[inline]
+    pub fn delta(mut self, delta: Int64) -> Self {
+        self.inner.value_mask.set_delta(true);
+        self.inner.delta = Some(delta);
+        self
+    }
+    #[inline]
+    pub fn events(mut self, events: Card32) -> Self {
+        self.inner.value_mask.set_events(true);
+        self.inner.events = Some(events);
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangeAlarmRequest {
+        self.inner
+    }
+}
+impl Default for ChangeAlarmRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangeAlarmRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                id: Default::default(),
+                value_mask: Default::default(),
+                counter: Default::default(),
+                value_type: Default::default(),
+                value: Default::default(),
+                test_type: Default::default(),
+                delta: Default::default(),
+                events: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct DestroyAlarmRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub alarm: Alarm,
+}
+impl DestroyAlarmRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.alarm.size()
+    pub fn builder() -> DestroyAlarmRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("alarm", 4, 4)];
 }
+const _: () = assert!(<DestroyAlarmRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_alarm_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.alarm.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (alarm, sz): (Alarm, usize) = parse_item(bytes, index, "alarm")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        alarm: alarm,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_alarm_only_request_asb! { DestroyAlarmRequest }
 impl Request for DestroyAlarmRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyAlarm";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyAlarmRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyAlarmRequestBuilder {
+    pub inner: DestroyAlarmRequest,
+}
+impl DestroyAlarmRequestBuilder {
+    #[inline]
+    pub fn alarm(mut self, alarm: Alarm) -> Self {
+        self.inner.alarm = alarm;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyAlarmRequest {
+        self.inner
+    }
+}
+impl Default for DestroyAlarmRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyAlarmRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                alarm: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryAlarmRequest {
     pub req_type: u8,
     pub length: u16,
     pub alarm: Alarm,
 }
-impl QueryAlarmRequest {}
-impl AsByteSequence for QueryAlarmRequest {
+impl QueryAlarmRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.alarm.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> QueryAlarmRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryAlarmRequest {
-                req_type: req_type,
-                length: length,
-                alarm: alarm,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.alarm.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryAlarmReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("alarm", 4, 4)];
 }
+const _: () = assert!(<QueryAlarmRequest as Request>::MINOR_OPCODE < 0x100);
+impl_alarm_only_request_asb! { QueryAlarmRequest }
 impl Request for QueryAlarmRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryAlarm";
     type Reply = QueryAlarmReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryAlarmRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryAlarmRequestBuilder {
+    pub inner: QueryAlarmRequest,
+}
+impl QueryAlarmRequestBuilder {
+    #[inline]
+    pub fn alarm(mut self, alarm: Alarm) -> Self {
+        self.inner.alarm = alarm;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryAlarmRequest {
+        self.inner
+    }
+}
+impl Default for QueryAlarmRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryAlarmRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                alarm: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryAlarmReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1408,43 +2677,85 @@ pub struct QueryAlarmReply {
     pub events: bool,
     pub state: Alarmstate,
 }
-impl QueryAlarmReply {}
+impl QueryAlarmReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("trigger", 8, 20),
+        ("delta", 28, 8),
+        ("events", 36, 1),
+        ("state", 37, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.trigger == other.trigger
+            && self.delta == other.delta
+            && self.events == other.events
+            && self.state == other.state
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("trigger", &self.trigger, &other.trigger, &mut changes);
+        diff_value("delta", &self.delta, &other.delta, &mut changes);
+        diff_value("events", &self.events, &other.events, &mut changes);
+        diff_value("state", &self.state, &other.state, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for QueryAlarmReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.trigger.as_bytes(&mut bytes[index..]);
         index += self.delta.as_bytes(&mut bytes[index..]);
         index += self.events.as_bytes(&mut bytes[index..]);
         index += self.state.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryAlarmReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (trigger, sz): (Trigger, usize) = <Trigger>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (trigger, sz): (Trigger, usize) = parse_item(bytes, index, "trigger")?;
         index += sz;
-        let (delta, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (delta, sz): (Int64, usize) = parse_item(bytes, index, "delta")?;
         index += sz;
-        let (events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (events, sz): (bool, usize) = parse_item(bytes, index, "events")?;
         index += sz;
-        let (state, sz): (Alarmstate, usize) = <Alarmstate>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Alarmstate, usize) = parse_item(bytes, index, "state")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryAlarmReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1459,19 +2770,21 @@ impl AsByteSequence for QueryAlarmReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.trigger.size()
-            + self.delta.size()
-            + self.events.size()
-            + self.state.size()
-            + 2
+        let size: usize = 39 + self.events.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryAlarmReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Alarmstate {
     Active = 0,
     Inactive = 1,
@@ -1483,13 +2796,13 @@ impl AsByteSequence for Alarmstate {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Active, sz)),
-            1 => Some((Self::Inactive, sz)),
-            2 => Some((Self::Destroyed, sz)),
-            _ => None,
+            0 => Ok((Self::Active, sz)),
+            1 => Ok((Self::Inactive, sz)),
+            2 => Ok((Self::Destroyed, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -1497,45 +2810,92 @@ impl AsByteSequence for Alarmstate {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Alarmstate {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Alarmstate {
     #[inline]
     fn default() -> Alarmstate {
         Alarmstate::Active
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Alarmstate> for u8 {
+    #[inline]
+    fn from(base: Alarmstate) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for Alarmstate {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Active),
+            1 => Ok(Self::Inactive),
+            2 => Ok(Self::Destroyed),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPriorityRequest {
     pub req_type: u8,
     pub length: u16,
     pub id: Card32,
     pub priority: Int32,
 }
-impl SetPriorityRequest {}
-impl AsByteSequence for SetPriorityRequest {
+impl SetPriorityRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> SetPriorityRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("id", 4, 4),
+        ("priority", 8, 4),
+    ];
+}
+const _: () = assert!(<SetPriorityRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetPriorityRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.id.as_bytes(&mut bytes[index..]);
         index += self.priority.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPriorityRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (priority, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (priority, sz): (Int32, usize) = parse_item(bytes, index, "priority")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPriorityRequest {
                 req_type: req_type,
                 length: length,
@@ -1547,44 +2907,115 @@ impl AsByteSequence for SetPriorityRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.id.size() + self.priority.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetPriorityRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetPriorityRequest {
-    const OPCODE: u8 = 12;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 12;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPriority";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPriorityRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPriorityRequestBuilder {
+    pub inner: SetPriorityRequest,
+}
+impl SetPriorityRequestBuilder {
+    #[inline]
+    pub fn id(mut self, id: Card32) -> Self {
+        self.inner.id = id;
+        self
+    }
+    #[inline]
+    pub fn priority(mut self, priority: Int32) -> Self {
+        self.inner.priority = priority;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPriorityRequest {
+        self.inner
+    }
+}
+impl Default for SetPriorityRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPriorityRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                id: Default::default(),
+                priority: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPriorityRequest {
     pub req_type: u8,
     pub length: u16,
     pub id: Card32,
 }
-impl GetPriorityRequest {}
-impl AsByteSequence for GetPriorityRequest {
+impl GetPriorityRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> GetPriorityRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPriorityReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("id", 4, 4)];
+}
+const _: () = assert!(<GetPriorityRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetPriorityRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPriorityRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetPriorityRequest {
                 req_type: req_type,
                 length: length,
@@ -1595,48 +3026,145 @@ impl AsByteSequence for GetPriorityRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.id.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetPriorityRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetPriorityRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPriority";
     type Reply = GetPriorityReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetPriorityRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPriorityRequestBuilder {
+    pub inner: GetPriorityRequest,
+}
+impl GetPriorityRequestBuilder {
+    #[inline]
+    pub fn id(mut self, id: Card32) -> Self {
+        self.inner.id = id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPriorityRequest {
+        self.inner
+    }
+}
+impl Default for GetPriorityRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPriorityRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPriorityReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub priority: Int32,
 }
-impl GetPriorityReply {}
-impl AsByteSequence for GetPriorityReply {
+impl GetPriorityReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let priority: Int32 = read_item(reader, &mut index, 4, "priority")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetPriorityReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            priority: priority,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("priority", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("priority", &self.priority, &other.priority, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetPriorityReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.priority.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPriorityReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (priority, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (priority, sz): (Int32, usize) = parse_item(bytes, index, "priority")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetPriorityReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1648,14 +3176,21 @@ impl AsByteSequence for GetPriorityReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.priority.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetPriorityReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateFenceRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1663,35 +3198,44 @@ pub struct CreateFenceRequest {
     pub fence: Fence,
     pub initially_triggered: bool,
 }
-impl CreateFenceRequest {}
-impl AsByteSequence for CreateFenceRequest {
+impl CreateFenceRequest {
     #[inline]
+    pub fn builder() -> CreateFenceRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CreateFenceRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateFenceRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.fence.as_bytes(&mut bytes[index..]);
         index += self.initially_triggered.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Fence, usize) = parse_item(bytes, index, "fence")?;
         index += sz;
-        let (initially_triggered, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (initially_triggered, sz): (bool, usize) =
+            parse_item(bytes, index, "initially_triggered")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateFenceRequest {
                 req_type: req_type,
                 length: length,
@@ -1704,247 +3248,423 @@ impl AsByteSequence for CreateFenceRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.drawable.size()
-            + self.fence.size()
-            + self.initially_triggered.size()
+        let size: usize = 8 + self.drawable.size() + self.initially_triggered.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateFenceRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateFenceRequest {
-    const OPCODE: u8 = 14;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 14;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateFence";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct TriggerFenceRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub fence: Fence,
+#[doc = " A builder for `CreateFenceRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateFenceRequestBuilder {
+    pub inner: CreateFenceRequest,
 }
-impl TriggerFenceRequest {}
-impl AsByteSequence for TriggerFenceRequest {
+impl CreateFenceRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.fence.as_bytes(&mut bytes[index..]);
-        index
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing TriggerFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            TriggerFenceRequest {
-                req_type: req_type,
-                length: length,
-                fence: fence,
+    pub fn fence(mut self, fence: Fence) -> Self {
+        self.inner.fence = fence;
+        self
+    }
+    #[inline]
+    pub fn initially_triggered(mut self, initially_triggered: bool) -> Self {
+        self.inner.initially_triggered = initially_triggered;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateFenceRequest {
+        self.inner
+    }
+}
+impl Default for CreateFenceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateFenceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                fence: Default::default(),
+                initially_triggered: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct TriggerFenceRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub fence: Fence,
+}
+impl TriggerFenceRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.fence.size()
+    pub fn builder() -> TriggerFenceRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("fence", 4, 4)];
 }
+const _: () = assert!(<TriggerFenceRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_fence_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.fence.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (fence, sz): (Fence, usize) = parse_item(bytes, index, "fence")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        fence: fence,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_fence_only_request_asb! { TriggerFenceRequest }
 impl Request for TriggerFenceRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TriggerFence";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct ResetFenceRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub fence: Fence,
+#[doc = " A builder for `TriggerFenceRequest`."]
+#[derive(Clone, Debug)]
+pub struct TriggerFenceRequestBuilder {
+    pub inner: TriggerFenceRequest,
 }
-impl ResetFenceRequest {}
-impl AsByteSequence for ResetFenceRequest {
+impl TriggerFenceRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.fence.as_bytes(&mut bytes[index..]);
-        index
+    pub fn fence(mut self, fence: Fence) -> Self {
+        self.inner.fence = fence;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ResetFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            ResetFenceRequest {
-                req_type: req_type,
-                length: length,
-                fence: fence,
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> TriggerFenceRequest {
+        self.inner
+    }
+}
+impl Default for TriggerFenceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: TriggerFenceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                fence: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct ResetFenceRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub fence: Fence,
+}
+impl ResetFenceRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.fence.size()
+    pub fn builder() -> ResetFenceRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("fence", 4, 4)];
 }
+const _: () = assert!(<ResetFenceRequest as Request>::MINOR_OPCODE < 0x100);
+impl_fence_only_request_asb! { ResetFenceRequest }
 impl Request for ResetFenceRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ResetFence";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct DestroyFenceRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub fence: Fence,
+#[doc = " A builder for `ResetFenceRequest`."]
+#[derive(Clone, Debug)]
+pub struct ResetFenceRequestBuilder {
+    pub inner: ResetFenceRequest,
 }
-impl DestroyFenceRequest {}
-impl AsByteSequence for DestroyFenceRequest {
+impl ResetFenceRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.fence.as_bytes(&mut bytes[index..]);
-        index
+    pub fn fence(mut self, fence: Fence) -> Self {
+        self.inner.fence = fence;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DestroyFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DestroyFenceRequest {
-                req_type: req_type,
-                length: length,
-                fence: fence,
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ResetFenceRequest {
+        self.inner
+    }
+}
+impl Default for ResetFenceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ResetFenceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                fence: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct DestroyFenceRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub fence: Fence,
+}
+impl DestroyFenceRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.fence.size()
+    pub fn builder() -> DestroyFenceRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("fence", 4, 4)];
 }
+const _: () = assert!(<DestroyFenceRequest as Request>::MINOR_OPCODE < 0x100);
+impl_fence_only_request_asb! { DestroyFenceRequest }
 impl Request for DestroyFenceRequest {
-    const OPCODE: u8 = 17;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 17;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyFence";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyFenceRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyFenceRequestBuilder {
+    pub inner: DestroyFenceRequest,
+}
+impl DestroyFenceRequestBuilder {
+    #[inline]
+    pub fn fence(mut self, fence: Fence) -> Self {
+        self.inner.fence = fence;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyFenceRequest {
+        self.inner
+    }
+}
+impl Default for DestroyFenceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyFenceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                fence: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryFenceRequest {
     pub req_type: u8,
     pub length: u16,
     pub fence: Fence,
 }
-impl QueryFenceRequest {}
-impl AsByteSequence for QueryFenceRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.fence.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl QueryFenceRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryFenceRequest {
-                req_type: req_type,
-                length: length,
-                fence: fence,
-            },
-            index,
-        ))
+    pub fn builder() -> QueryFenceRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.fence.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryFenceReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("fence", 4, 4)];
 }
+const _: () = assert!(<QueryFenceRequest as Request>::MINOR_OPCODE < 0x100);
+impl_fence_only_request_asb! { QueryFenceRequest }
 impl Request for QueryFenceRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryFence";
     type Reply = QueryFenceReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryFenceRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryFenceRequestBuilder {
+    pub inner: QueryFenceRequest,
+}
+impl QueryFenceRequestBuilder {
+    #[inline]
+    pub fn fence(mut self, fence: Fence) -> Self {
+        self.inner.fence = fence;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryFenceRequest {
+        self.inner
+    }
+}
+impl Default for QueryFenceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryFenceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                fence: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryFenceReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub triggered: bool,
 }
-impl QueryFenceReply {}
-impl AsByteSequence for QueryFenceReply {
+impl QueryFenceReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("triggered", 8, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.triggered == other.triggered
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("triggered", &self.triggered, &other.triggered, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryFenceReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.triggered.as_bytes(&mut bytes[index..]);
-        index += 23;
+        index += zero_pad(&mut bytes[index..], 23);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFenceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (triggered, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (triggered, sz): (bool, usize) = parse_item(bytes, index, "triggered")?;
         index += sz;
         index += 23;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryFenceReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1956,47 +3676,71 @@ impl AsByteSequence for QueryFenceReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.triggered.size()
-            + 23
+        let size: usize = 31 + self.triggered.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryFenceReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AwaitFenceRequest {
     pub req_type: u8,
     pub length: u16,
     pub fence_list: Vec<Fence>,
 }
-impl AwaitFenceRequest {}
-impl AsByteSequence for AwaitFenceRequest {
+impl AwaitFenceRequest {
     #[inline]
+    pub fn builder() -> AwaitFenceRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<AwaitFenceRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AwaitFenceRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.fence_list, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fence>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Fence>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AwaitFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (fence_list, block_len): (Vec<Fence>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (fence_list, block_len): (Vec<Fence>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "fence_list",
+                offset: index,
+            })? as usize,
+            "fence_list",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fence>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AwaitFenceRequest {
                 req_type: req_type,
                 length: length,
@@ -2007,71 +3751,136 @@ impl AsByteSequence for AwaitFenceRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + {
+        let size: usize = 4 + {
             let block_len: usize = self.fence_list.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fence>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AwaitFenceRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AwaitFenceRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AwaitFence";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AwaitFenceRequest`."]
+#[derive(Clone, Debug)]
+pub struct AwaitFenceRequestBuilder {
+    pub inner: AwaitFenceRequest,
+}
+impl AwaitFenceRequestBuilder {
+    #[inline]
+    pub fn fence_list(mut self, fence_list: Vec<Fence>) -> Self {
+        self.inner.fence_list = fence_list;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AwaitFenceRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Fence> for AwaitFenceRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Fence>>(&mut self, iter: I) {
+        self.inner.fence_list.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Fence> for AwaitFenceRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Fence>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for AwaitFenceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AwaitFenceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                fence_list: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct AlarmError {
     pub _error_type: u8,
     pub error_code: u8,
-    pub major_code: u8,
-    pub minor_code: u8,
     pub sequence: u16,
     pub bad_alarm: Card32,
     pub minor_opcode: Card16,
     pub major_opcode: Card8,
 }
-impl AlarmError {}
+impl AlarmError {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 11;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("_error_type", 0, 1),
+        ("error_code", 1, 1),
+        ("sequence", 2, 2),
+        ("bad_alarm", 4, 4),
+        ("minor_opcode", 8, 2),
+        ("major_opcode", 10, 1),
+    ];
+}
+const _: () = assert!(AlarmError::FIXED_SIZE <= 32);
 impl AsByteSequence for AlarmError {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self._error_type.as_bytes(&mut bytes[index..]);
         index += self.error_code.as_bytes(&mut bytes[index..]);
-        index += self.major_code.as_bytes(&mut bytes[index..]);
-        index += self.minor_code.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.bad_alarm.as_bytes(&mut bytes[index..]);
         index += self.minor_opcode.as_bytes(&mut bytes[index..]);
         index += self.major_opcode.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AlarmError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = parse_item(bytes, index, "_error_type")?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = parse_item(bytes, index, "error_code")?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (bad_alarm, sz): (Card32, usize) = parse_item(bytes, index, "bad_alarm")?;
         index += sz;
-        let (bad_alarm, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = parse_item(bytes, index, "minor_opcode")?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = parse_item(bytes, index, "major_opcode")?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
+        Ok((
             AlarmError {
                 _error_type: _error_type,
                 error_code: error_code,
-                major_code: major_code,
-                minor_code: minor_code,
                 sequence: sequence,
                 bad_alarm: bad_alarm,
                 minor_opcode: minor_opcode,
@@ -2082,71 +3891,95 @@ impl AsByteSequence for AlarmError {
     }
     #[inline]
     fn size(&self) -> usize {
-        self._error_type.size()
-            + self.error_code.size()
-            + self.major_code.size()
-            + self.minor_code.size()
-            + self.sequence.size()
-            + self.bad_alarm.size()
-            + self.minor_opcode.size()
-            + self.major_opcode.size()
+        11
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AlarmError {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Error for AlarmError {
     const OPCODE: u8 = 1;
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Display for AlarmError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "X error: BadAlarm (code 1) on request {}:{}",
+            self.major_opcode, self.minor_opcode
+        )
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for AlarmError {}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct CounterError {
     pub _error_type: u8,
     pub error_code: u8,
-    pub major_code: u8,
-    pub minor_code: u8,
     pub sequence: u16,
     pub bad_counter: Card32,
     pub minor_opcode: Card16,
     pub major_opcode: Card8,
 }
-impl CounterError {}
+impl CounterError {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 11;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("_error_type", 0, 1),
+        ("error_code", 1, 1),
+        ("sequence", 2, 2),
+        ("bad_counter", 4, 4),
+        ("minor_opcode", 8, 2),
+        ("major_opcode", 10, 1),
+    ];
+}
+const _: () = assert!(CounterError::FIXED_SIZE <= 32);
 impl AsByteSequence for CounterError {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self._error_type.as_bytes(&mut bytes[index..]);
         index += self.error_code.as_bytes(&mut bytes[index..]);
-        index += self.major_code.as_bytes(&mut bytes[index..]);
-        index += self.minor_code.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.bad_counter.as_bytes(&mut bytes[index..]);
         index += self.minor_opcode.as_bytes(&mut bytes[index..]);
         index += self.major_opcode.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CounterError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = parse_item(bytes, index, "_error_type")?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = parse_item(bytes, index, "error_code")?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (bad_counter, sz): (Card32, usize) = parse_item(bytes, index, "bad_counter")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = parse_item(bytes, index, "minor_opcode")?;
         index += sz;
-        let (bad_counter, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = parse_item(bytes, index, "major_opcode")?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
+        Ok((
             CounterError {
                 _error_type: _error_type,
                 error_code: error_code,
-                major_code: major_code,
-                minor_code: minor_code,
                 sequence: sequence,
                 bad_counter: bad_counter,
                 minor_opcode: minor_opcode,
@@ -2157,178 +3990,332 @@ impl AsByteSequence for CounterError {
     }
     #[inline]
     fn size(&self) -> usize {
-        self._error_type.size()
-            + self.error_code.size()
-            + self.major_code.size()
-            + self.minor_code.size()
-            + self.sequence.size()
-            + self.bad_counter.size()
-            + self.minor_opcode.size()
-            + self.major_opcode.size()
+        11
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CounterError {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Error for CounterError {
     const OPCODE: u8 = 0;
 }
-#[derive(Clone, Debug, Default)]
-pub struct CounterNotifyEvent {
+impl core::fmt::Display for CounterError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "X error: BadCounter (code 0) on request {}:{}",
+            self.major_opcode, self.minor_opcode
+        )
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for CounterError {}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct AlarmNotifyEvent {
     pub event_type: u8,
     pub kind: Card8,
     pub sequence: u16,
-    pub counter: Counter,
-    pub wait_value: Int64,
+    pub alarm: Alarm,
     pub counter_value: Int64,
+    pub alarm_value: Int64,
     pub timestamp: Timestamp,
-    pub count: Card16,
-    pub destroyed: bool,
+    pub state: Alarmstate,
 }
-impl CounterNotifyEvent {}
-impl AsByteSequence for CounterNotifyEvent {
-    #[inline]
+impl AlarmNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 1;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("kind", 1, 1),
+        ("sequence", 2, 2),
+        ("alarm", 4, 4),
+        ("counter_value", 8, 8),
+        ("alarm_value", 16, 8),
+    ];
+}
+impl AsByteSequence for AlarmNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
         index += self.kind.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.counter.as_bytes(&mut bytes[index..]);
-        index += self.wait_value.as_bytes(&mut bytes[index..]);
+        index += self.alarm.as_bytes(&mut bytes[index..]);
         index += self.counter_value.as_bytes(&mut bytes[index..]);
+        index += self.alarm_value.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
-        index += self.count.as_bytes(&mut bytes[index..]);
-        index += self.destroyed.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += self.state.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 3);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing CounterNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (kind, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing AlarmNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Card8, usize) = parse_item(bytes, index, "kind")?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (wait_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (alarm, sz): (Alarm, usize) = parse_item(bytes, index, "alarm")?;
         index += sz;
-        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (counter_value, sz): (Int64, usize) = parse_item(bytes, index, "counter_value")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (alarm_value, sz): (Int64, usize) = parse_item(bytes, index, "alarm_value")?;
         index += sz;
-        let (count, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (destroyed, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Alarmstate, usize) = parse_item(bytes, index, "state")?;
         index += sz;
-        index += 1;
-        Some((
-            CounterNotifyEvent {
+        index += 3;
+        Ok((
+            AlarmNotifyEvent {
                 event_type: event_type,
                 kind: kind,
                 sequence: sequence,
-                counter: counter,
-                wait_value: wait_value,
+                alarm: alarm,
                 counter_value: counter_value,
+                alarm_value: alarm_value,
                 timestamp: timestamp,
-                count: count,
-                destroyed: destroyed,
+                state: state,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.kind.size()
-            + self.sequence.size()
-            + self.counter.size()
-            + self.wait_value.size()
-            + self.counter_value.size()
-            + self.timestamp.size()
-            + self.count.size()
-            + self.destroyed.size()
-            + 1
+        28 + self.timestamp.size()
     }
 }
-impl crate::auto::Event for CounterNotifyEvent {
-    const OPCODE: u8 = 0;
+impl core::convert::TryFrom<&[u8]> for AlarmNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct AlarmNotifyEvent {
+impl crate::auto::Event for AlarmNotifyEvent {
+    const OPCODE: u8 = 1;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct CounterNotifyEvent {
     pub event_type: u8,
     pub kind: Card8,
     pub sequence: u16,
-    pub alarm: Alarm,
+    pub counter: Counter,
+    pub wait_value: Int64,
     pub counter_value: Int64,
-    pub alarm_value: Int64,
     pub timestamp: Timestamp,
-    pub state: Alarmstate,
+    pub count: Card16,
+    pub destroyed: bool,
 }
-impl AlarmNotifyEvent {}
-impl AsByteSequence for AlarmNotifyEvent {
-    #[inline]
+impl CounterNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("kind", 1, 1),
+        ("sequence", 2, 2),
+        ("counter", 4, 4),
+        ("wait_value", 8, 8),
+        ("counter_value", 16, 8),
+    ];
+}
+impl AsByteSequence for CounterNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
         index += self.kind.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.alarm.as_bytes(&mut bytes[index..]);
+        index += self.counter.as_bytes(&mut bytes[index..]);
+        index += self.wait_value.as_bytes(&mut bytes[index..]);
         index += self.counter_value.as_bytes(&mut bytes[index..]);
-        index += self.alarm_value.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
-        index += self.state.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += self.count.as_bytes(&mut bytes[index..]);
+        index += self.destroyed.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 1);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing AlarmNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing CounterNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (kind, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Card8, usize) = parse_item(bytes, index, "kind")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = parse_item(bytes, index, "counter")?;
         index += sz;
-        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (wait_value, sz): (Int64, usize) = parse_item(bytes, index, "wait_value")?;
         index += sz;
-        let (alarm_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (counter_value, sz): (Int64, usize) = parse_item(bytes, index, "counter_value")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (state, sz): (Alarmstate, usize) = <Alarmstate>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card16, usize) = parse_item(bytes, index, "count")?;
         index += sz;
-        index += 3;
-        Some((
-            AlarmNotifyEvent {
+        let (destroyed, sz): (bool, usize) = parse_item(bytes, index, "destroyed")?;
+        index += sz;
+        index += 1;
+        Ok((
+            CounterNotifyEvent {
                 event_type: event_type,
                 kind: kind,
                 sequence: sequence,
-                alarm: alarm,
+                counter: counter,
+                wait_value: wait_value,
                 counter_value: counter_value,
-                alarm_value: alarm_value,
                 timestamp: timestamp,
-                state: state,
+                count: count,
+                destroyed: destroyed,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.kind.size()
-            + self.sequence.size()
-            + self.alarm.size()
-            + self.counter_value.size()
-            + self.alarm_value.size()
-            + self.timestamp.size()
-            + self.state.size()
-            + 3
+        27 + self.timestamp.size() + self.destroyed.size()
     }
 }
-impl crate::auto::Event for AlarmNotifyEvent {
-    const OPCODE: u8 = 1;
+impl core::convert::TryFrom<&[u8]> for CounterNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl crate::auto::Event for CounterNotifyEvent {
+    const OPCODE: u8 = 0;
+}
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    AlarmNotify(AlarmNotifyEvent),
+    CounterNotify(CounterNotifyEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            1 => Ok(Self::AlarmNotify(AlarmNotifyEvent::from_bytes(bytes)?.0)),
+            0 => Ok(Self::CounterNotify(
+                CounterNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::AlarmNotify(_) => AlarmNotifyEvent::RESPONSE_TYPE,
+            Self::CounterNotify(_) => CounterNotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<AlarmNotifyEvent> for Event {
+    #[inline]
+    fn from(event: AlarmNotifyEvent) -> Self {
+        Self::AlarmNotify(event)
+    }
+}
+impl From<CounterNotifyEvent> for Event {
+    #[inline]
+    fn from(event: CounterNotifyEvent) -> Self {
+        Self::CounterNotify(event)
+    }
+}
+#[doc = " Parse an error of this extension out of its code and its bytes. The codes of the extension's errors count up from `first_error`, the first error code that the server gave it. Errors with other codes are kept as bytes."]
+#[inline]
+pub fn error_from_code(code: u8, first_error: u8, bytes: &[u8]) -> Result<XError, ParseError> {
+    Ok(match code.wrapping_sub(first_error) {
+        0 => XError::SyncCounter(CounterError::from_bytes(bytes)?.0),
+        1 => XError::SyncAlarm(AlarmError::from_bytes(bytes)?.0),
+        _ => XError::Unknown {
+            code,
+            bytes: bytes.iter().copied().collect(),
+        },
+    })
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "CounterNotify"), (1, "AlarmNotify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[(0, "Counter"), (1, "Alarm")];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "Initialize"),
+    (1, "ListSystemCounters"),
+    (2, "CreateCounter"),
+    (3, "SetCounter"),
+    (4, "ChangeCounter"),
+    (5, "QueryCounter"),
+    (6, "DestroyCounter"),
+    (7, "Await"),
+    (8, "CreateAlarm"),
+    (9, "ChangeAlarm"),
+    (10, "QueryAlarm"),
+    (11, "DestroyAlarm"),
+    (12, "SetPriority"),
+    (13, "GetPriority"),
+    (14, "CreateFence"),
+    (15, "TriggerFence"),
+    (16, "ResetFence"),
+    (17, "DestroyFence"),
+    (18, "QueryFence"),
+    (19, "AwaitFence"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
 }
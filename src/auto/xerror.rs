@@ -0,0 +1,98 @@
+// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+use tinyvec::TinyVec;
+
+#[doc = r" An error sent by the X server. The errors of an extension are only parsed into"]
+#[doc = r" their structures if the extension's feature is enabled."]
+#[derive(Debug, Clone)]
+pub enum XError {
+    #[doc = " A `Request` error of the core protocol."]
+    Request(super::xproto::RequestError),
+    #[doc = " A `Value` error of the core protocol."]
+    Value(super::xproto::ValueError),
+    #[doc = " A `Window` error of the core protocol."]
+    Window(super::xproto::WindowError),
+    #[doc = " A `Pixmap` error of the core protocol."]
+    Pixmap(super::xproto::PixmapError),
+    #[doc = " An `Atom` error of the core protocol."]
+    Atom(super::xproto::AtomError),
+    #[doc = " A `Cursor` error of the core protocol."]
+    Cursor(super::xproto::CursorError),
+    #[doc = " A `Font` error of the core protocol."]
+    Font(super::xproto::FontError),
+    #[doc = " A `Match` error of the core protocol."]
+    Match(super::xproto::MatchError),
+    #[doc = " A `Drawable` error of the core protocol."]
+    Drawable(super::xproto::DrawableError),
+    #[doc = " An `Access` error of the core protocol."]
+    Access(super::xproto::AccessError),
+    #[doc = " An `Alloc` error of the core protocol."]
+    Alloc(super::xproto::AllocError),
+    #[doc = " A `Colormap` error of the core protocol."]
+    Colormap(super::xproto::ColormapError),
+    #[doc = " A `GContext` error of the core protocol."]
+    GContext(super::xproto::GContextError),
+    #[doc = " An `IdChoice` error of the core protocol."]
+    IdChoice(super::xproto::IdChoiceError),
+    #[doc = " A `Name` error of the core protocol."]
+    Name(super::xproto::NameError),
+    #[doc = " A `Length` error of the core protocol."]
+    Length(super::xproto::LengthError),
+    #[doc = " An `Implementation` error of the core protocol."]
+    Implementation(super::xproto::ImplementationError),
+    #[doc = " A `BadContext` error of the `RECORD` extension."]
+    #[cfg(feature = "record")]
+    RecordBadContext(super::record::BadContextError),
+    #[doc = " A `Counter` error of the `SYNC` extension."]
+    #[cfg(feature = "sync")]
+    SyncCounter(super::sync::CounterError),
+    #[doc = " An `Alarm` error of the `SYNC` extension."]
+    #[cfg(feature = "sync")]
+    SyncAlarm(super::sync::AlarmError),
+    #[doc = " A `Keyboard` error of the `XKEYBOARD` extension."]
+    #[cfg(feature = "xkb")]
+    XkbKeyboard(super::xkb::KeyboardError),
+    #[doc = r" An error that isn't one of the above, kept as its code and its bytes."]
+    Unknown {
+        #[doc = r" The code of the error."]
+        code: u8,
+        #[doc = r" The bytes of the error."]
+        bytes: TinyVec<[u8; 32]>,
+    },
+}
+impl core::fmt::Display for XError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Request(e) => core::fmt::Display::fmt(e, f),
+            Self::Value(e) => core::fmt::Display::fmt(e, f),
+            Self::Window(e) => core::fmt::Display::fmt(e, f),
+            Self::Pixmap(e) => core::fmt::Display::fmt(e, f),
+            Self::Atom(e) => core::fmt::Display::fmt(e, f),
+            Self::Cursor(e) => core::fmt::Display::fmt(e, f),
+            Self::Font(e) => core::fmt::Display::fmt(e, f),
+            Self::Match(e) => core::fmt::Display::fmt(e, f),
+            Self::Drawable(e) => core::fmt::Display::fmt(e, f),
+            Self::Access(e) => core::fmt::Display::fmt(e, f),
+            Self::Alloc(e) => core::fmt::Display::fmt(e, f),
+            Self::Colormap(e) => core::fmt::Display::fmt(e, f),
+            Self::GContext(e) => core::fmt::Display::fmt(e, f),
+            Self::IdChoice(e) => core::fmt::Display::fmt(e, f),
+            Self::Name(e) => core::fmt::Display::fmt(e, f),
+            Self::Length(e) => core::fmt::Display::fmt(e, f),
+            Self::Implementation(e) => core::fmt::Display::fmt(e, f),
+            #[cfg(feature = "record")]
+            Self::RecordBadContext(e) => core::fmt::Display::fmt(e, f),
+            #[cfg(feature = "sync")]
+            Self::SyncCounter(e) => core::fmt::Display::fmt(e, f),
+            #[cfg(feature = "sync")]
+            Self::SyncAlarm(e) => core::fmt::Display::fmt(e, f),
+            #[cfg(feature = "xkb")]
+            Self::XkbKeyboard(e) => core::fmt::Display::fmt(e, f),
+            Self::Unknown { code, .. } => {
+                write!(f, "X error: {} (code {})", crate::ErrorCode(*code), code)
+            }
+        }
+    }
+}
@@ -1,18 +1,44 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "xf86dri")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
-#[derive(Clone, Debug, Default)]
+pub const MAJOR_VERSION: u32 = 4;
+pub const MINOR_VERSION: u32 = 1;
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct DrmClipRect {
     pub x1: Int16,
     pub y1: Int16,
     pub x2: Int16,
     pub x3: Int16,
 }
-impl DrmClipRect {}
+impl DrmClipRect {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(x1: Int16, y1: Int16, x2: Int16, x3: Int16) -> Self {
+        Self {
+            x1: x1,
+            y1: y1,
+            x2: x2,
+            x3: x3,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("x1", 0, 2), ("y1", 2, 2), ("x2", 4, 2), ("x3", 6, 2)];
+}
 impl AsByteSequence for DrmClipRect {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -23,19 +49,18 @@ impl AsByteSequence for DrmClipRect {
         index += self.x3.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DrmClipRect from byte buffer");
-        let (x1, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x1, sz): (Int16, usize) = parse_item(bytes, index, "x1")?;
         index += sz;
-        let (y1, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y1, sz): (Int16, usize) = parse_item(bytes, index, "y1")?;
         index += sz;
-        let (x2, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x2, sz): (Int16, usize) = parse_item(bytes, index, "x2")?;
         index += sz;
-        let (x3, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x3, sz): (Int16, usize) = parse_item(bytes, index, "x3")?;
         index += sz;
-        Some((
+        Ok((
             DrmClipRect {
                 x1: x1,
                 y1: y1,
@@ -47,34 +72,68 @@ impl AsByteSequence for DrmClipRect {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.x1.size() + self.y1.size() + self.x2.size() + self.x3.size()
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DrmClipRect {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryVersionRequest {}
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryVersionRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -84,16 +143,51 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -102,38 +196,121 @@ pub struct QueryVersionReply {
     pub dri_minor_version: Card16,
     pub dri_minor_patch: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let dri_major_version: Card16 = read_item(reader, &mut index, 2, "dri_major_version")?;
+        let dri_minor_version: Card16 = read_item(reader, &mut index, 2, "dri_minor_version")?;
+        let dri_minor_patch: Card32 = read_item(reader, &mut index, 4, "dri_minor_patch")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            dri_major_version: dri_major_version,
+            dri_minor_version: dri_minor_version,
+            dri_minor_patch: dri_minor_patch,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("dri_major_version", 8, 2),
+        ("dri_minor_version", 10, 2),
+        ("dri_minor_patch", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.dri_major_version == other.dri_major_version
+            && self.dri_minor_version == other.dri_minor_version
+            && self.dri_minor_patch == other.dri_minor_patch
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "dri_major_version",
+            &self.dri_major_version,
+            &other.dri_major_version,
+            &mut changes,
+        );
+        diff_value(
+            "dri_minor_version",
+            &self.dri_minor_version,
+            &other.dri_minor_version,
+            &mut changes,
+        );
+        diff_value(
+            "dri_minor_patch",
+            &self.dri_minor_patch,
+            &other.dri_minor_patch,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.dri_major_version.as_bytes(&mut bytes[index..]);
         index += self.dri_minor_version.as_bytes(&mut bytes[index..]);
         index += self.dri_minor_patch.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (dri_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (dri_major_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "dri_major_version")?;
         index += sz;
-        let (dri_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dri_minor_version, sz): (Card16, usize) =
+            parse_item(bytes, index, "dri_minor_version")?;
         index += sz;
-        let (dri_minor_patch, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dri_minor_patch, sz): (Card32, usize) = parse_item(bytes, index, "dri_minor_patch")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -147,96 +324,212 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.dri_major_version.size()
-            + self.dri_minor_version.size()
-            + self.dri_minor_patch.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryDirectRenderingCapableRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
 }
-impl QueryDirectRenderingCapableRequest {}
-impl AsByteSequence for QueryDirectRenderingCapableRequest {
+impl QueryDirectRenderingCapableRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryDirectRenderingCapableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryDirectRenderingCapableRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> QueryDirectRenderingCapableRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryDirectRenderingCapableReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 4)];
+}
+const _: () = assert!(<QueryDirectRenderingCapableRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_screen_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.screen.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        screen: screen,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_screen_only_request_asb! { QueryDirectRenderingCapableRequest }
 impl Request for QueryDirectRenderingCapableRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryDirectRenderingCapable";
     type Reply = QueryDirectRenderingCapableReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryDirectRenderingCapableRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryDirectRenderingCapableRequestBuilder {
+    pub inner: QueryDirectRenderingCapableRequest,
+}
+impl QueryDirectRenderingCapableRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryDirectRenderingCapableRequest {
+        self.inner
+    }
+}
+impl Default for QueryDirectRenderingCapableRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryDirectRenderingCapableRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryDirectRenderingCapableReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub is_capable: bool,
 }
-impl QueryDirectRenderingCapableReply {}
+impl QueryDirectRenderingCapableReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("is_capable", 8, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.is_capable == other.is_capable
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "is_capable",
+            &self.is_capable,
+            &other.is_capable,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for QueryDirectRenderingCapableReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.is_capable.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryDirectRenderingCapableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (is_capable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (is_capable, sz): (bool, usize) = parse_item(bytes, index, "is_capable")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryDirectRenderingCapableReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -248,62 +541,91 @@ impl AsByteSequence for QueryDirectRenderingCapableReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.is_capable.size()
+        let size: usize = 8 + self.is_capable.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryDirectRenderingCapableReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct OpenConnectionRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
 }
-impl OpenConnectionRequest {}
-impl AsByteSequence for OpenConnectionRequest {
+impl OpenConnectionRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing OpenConnectionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            OpenConnectionRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> OpenConnectionRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<OpenConnectionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 4)];
 }
+const _: () = assert!(<OpenConnectionRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { OpenConnectionRequest }
 impl Request for OpenConnectionRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "OpenConnection";
     type Reply = OpenConnectionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `OpenConnectionRequest`."]
+#[derive(Clone, Debug)]
+pub struct OpenConnectionRequestBuilder {
+    pub inner: OpenConnectionRequest,
+}
+impl OpenConnectionRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> OpenConnectionRequest {
+        self.inner
+    }
+}
+impl Default for OpenConnectionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: OpenConnectionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct OpenConnectionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -312,47 +634,134 @@ pub struct OpenConnectionReply {
     pub sarea_handle_high: Card32,
     pub bus_id: String,
 }
-impl OpenConnectionReply {}
-impl AsByteSequence for OpenConnectionReply {
+impl OpenConnectionReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let sarea_handle_low: Card32 = read_item(reader, &mut index, 4, "sarea_handle_low")?;
+        let sarea_handle_high: Card32 = read_item(reader, &mut index, 4, "sarea_handle_high")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let (bus_id, block_len): (String, usize) =
+            read_string(reader, &mut index, len0 as usize, "bus_id")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(OpenConnectionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            sarea_handle_low: sarea_handle_low,
+            sarea_handle_high: sarea_handle_high,
+            bus_id: bus_id,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("sarea_handle_low", 8, 4),
+        ("sarea_handle_high", 12, 4),
+        ("bus_id_len", 16, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.sarea_handle_low == other.sarea_handle_low
+            && self.sarea_handle_high == other.sarea_handle_high
+            && self.bus_id == other.bus_id
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "sarea_handle_low",
+            &self.sarea_handle_low,
+            &other.sarea_handle_low,
+            &mut changes,
+        );
+        diff_value(
+            "sarea_handle_high",
+            &self.sarea_handle_high,
+            &other.sarea_handle_high,
+            &mut changes,
+        );
+        diff_list(
+            "bus_id",
+            str::as_bytes(&self.bus_id),
+            str::as_bytes(&other.bus_id),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for OpenConnectionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.sarea_handle_low.as_bytes(&mut bytes[index..]);
         index += self.sarea_handle_high.as_bytes(&mut bytes[index..]);
         index += (self.bus_id.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         let block_len: usize = string_as_bytes(&self.bus_id, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenConnectionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (sarea_handle_low, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (sarea_handle_low, sz): (Card32, usize) = parse_item(bytes, index, "sarea_handle_low")?;
         index += sz;
-        let (sarea_handle_high, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sarea_handle_high, sz): (Card32, usize) =
+            parse_item(bytes, index, "sarea_handle_high")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 12;
         let (bus_id, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "bus_id")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             OpenConnectionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -366,118 +775,158 @@ impl AsByteSequence for OpenConnectionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.sarea_handle_low.size()
-            + self.sarea_handle_high.size()
-            + ::core::mem::size_of::<Card32>()
-            + 12
-            + {
-                let block_len: usize = self.bus_id.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.bus_id.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for OpenConnectionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CloseConnectionRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
 }
-impl CloseConnectionRequest {}
-impl AsByteSequence for CloseConnectionRequest {
+impl CloseConnectionRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CloseConnectionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            CloseConnectionRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size()
+    pub fn builder() -> CloseConnectionRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 4)];
 }
+const _: () = assert!(<CloseConnectionRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { CloseConnectionRequest }
 impl Request for CloseConnectionRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CloseConnection";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CloseConnectionRequest`."]
+#[derive(Clone, Debug)]
+pub struct CloseConnectionRequestBuilder {
+    pub inner: CloseConnectionRequest,
+}
+impl CloseConnectionRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CloseConnectionRequest {
+        self.inner
+    }
+}
+impl Default for CloseConnectionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CloseConnectionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetClientDriverNameRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
 }
-impl GetClientDriverNameRequest {}
-impl AsByteSequence for GetClientDriverNameRequest {
+impl GetClientDriverNameRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetClientDriverNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetClientDriverNameRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetClientDriverNameRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetClientDriverNameReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 4)];
 }
+const _: () = assert!(<GetClientDriverNameRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetClientDriverNameRequest }
 impl Request for GetClientDriverNameRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetClientDriverName";
     type Reply = GetClientDriverNameReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetClientDriverNameRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetClientDriverNameRequestBuilder {
+    pub inner: GetClientDriverNameRequest,
+}
+impl GetClientDriverNameRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetClientDriverNameRequest {
+        self.inner
+    }
+}
+impl Default for GetClientDriverNameRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetClientDriverNameRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetClientDriverNameReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -487,13 +936,107 @@ pub struct GetClientDriverNameReply {
     pub client_driver_patch_version: Card32,
     pub client_driver_name: String,
 }
-impl GetClientDriverNameReply {}
-impl AsByteSequence for GetClientDriverNameReply {
+impl GetClientDriverNameReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let client_driver_major_version: Card32 =
+            read_item(reader, &mut index, 4, "client_driver_major_version")?;
+        let client_driver_minor_version: Card32 =
+            read_item(reader, &mut index, 4, "client_driver_minor_version")?;
+        let client_driver_patch_version: Card32 =
+            read_item(reader, &mut index, 4, "client_driver_patch_version")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 8)?;
+        let (client_driver_name, block_len): (String, usize) =
+            read_string(reader, &mut index, len0 as usize, "client_driver_name")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetClientDriverNameReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            client_driver_major_version: client_driver_major_version,
+            client_driver_minor_version: client_driver_minor_version,
+            client_driver_patch_version: client_driver_patch_version,
+            client_driver_name: client_driver_name,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("client_driver_major_version", 8, 4),
+        ("client_driver_minor_version", 12, 4),
+        ("client_driver_patch_version", 16, 4),
+        ("client_driver_name_len", 20, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.client_driver_major_version == other.client_driver_major_version
+            && self.client_driver_minor_version == other.client_driver_minor_version
+            && self.client_driver_patch_version == other.client_driver_patch_version
+            && self.client_driver_name == other.client_driver_name
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "client_driver_major_version",
+            &self.client_driver_major_version,
+            &other.client_driver_major_version,
+            &mut changes,
+        );
+        diff_value(
+            "client_driver_minor_version",
+            &self.client_driver_minor_version,
+            &other.client_driver_minor_version,
+            &mut changes,
+        );
+        diff_value(
+            "client_driver_patch_version",
+            &self.client_driver_patch_version,
+            &other.client_driver_patch_version,
+            &mut changes,
+        );
+        diff_list(
+            "client_driver_name",
+            str::as_bytes(&self.client_driver_name),
+            str::as_bytes(&other.client_driver_name),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetClientDriverNameReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self
@@ -506,40 +1049,45 @@ impl AsByteSequence for GetClientDriverNameReply {
             .client_driver_patch_version
             .as_bytes(&mut bytes[index..]);
         index += (self.client_driver_name.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += zero_pad(&mut bytes[index..], 8);
         let block_len: usize = string_as_bytes(&self.client_driver_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetClientDriverNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         let (client_driver_major_version, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "client_driver_major_version")?;
         index += sz;
         let (client_driver_minor_version, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "client_driver_minor_version")?;
         index += sz;
         let (client_driver_patch_version, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "client_driver_patch_version")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 8;
         let (client_driver_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "client_driver_name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetClientDriverNameReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -554,23 +1102,25 @@ impl AsByteSequence for GetClientDriverNameReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.client_driver_major_version.size()
-            + self.client_driver_minor_version.size()
-            + self.client_driver_patch_version.size()
-            + ::core::mem::size_of::<Card32>()
-            + 8
-            + {
-                let block_len: usize = self.client_driver_name.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.client_driver_name.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetClientDriverNameReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateContextRequest {
     pub req_type: u8,
     pub length: u16,
@@ -578,35 +1128,63 @@ pub struct CreateContextRequest {
     pub visual: Card32,
     pub context: Card32,
 }
-impl CreateContextRequest {}
-impl AsByteSequence for CreateContextRequest {
+impl CreateContextRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> CreateContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("visual", 8, 4),
+        ("context", 12, 4),
+    ];
+}
+const _: () = assert!(<CreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateContextRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.visual.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (visual, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Card32, usize) = parse_item(bytes, index, "visual")?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Card32, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateContextRequest {
                 req_type: req_type,
                 length: length,
@@ -619,53 +1197,162 @@ impl AsByteSequence for CreateContextRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + self.visual.size()
-            + self.context.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateContextRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = CreateContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateContextRequestBuilder {
+    pub inner: CreateContextRequest,
+}
+impl CreateContextRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn visual(mut self, visual: Card32) -> Self {
+        self.inner.visual = visual;
+        self
+    }
+    #[inline]
+    pub fn context(mut self, context: Card32) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateContextRequest {
+        self.inner
+    }
+}
+impl Default for CreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                visual: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub hw_context: Card32,
 }
-impl CreateContextReply {}
-impl AsByteSequence for CreateContextReply {
+impl CreateContextReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let hw_context: Card32 = read_item(reader, &mut index, 4, "hw_context")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(CreateContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            hw_context: hw_context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("hw_context", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.hw_context == other.hw_context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "hw_context",
+            &self.hw_context,
+            &other.hw_context,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for CreateContextReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.hw_context.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (hw_context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (hw_context, sz): (Card32, usize) = parse_item(bytes, index, "hw_context")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateContextReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -677,46 +1364,72 @@ impl AsByteSequence for CreateContextReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.hw_context.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateContextReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DestroyContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
     pub context: Card32,
 }
-impl DestroyContextRequest {}
-impl AsByteSequence for DestroyContextRequest {
+impl DestroyContextRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> DestroyContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("context", 8, 4),
+    ];
+}
+const _: () = assert!(<DestroyContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DestroyContextRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Card32, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DestroyContextRequest {
                 req_type: req_type,
                 length: length,
@@ -728,101 +1441,294 @@ impl AsByteSequence for DestroyContextRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.context.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DestroyContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DestroyContextRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyContextRequestBuilder {
+    pub inner: DestroyContextRequest,
+}
+impl DestroyContextRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn context(mut self, context: Card32) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyContextRequest {
+        self.inner
+    }
+}
+impl Default for DestroyContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateDrawableRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
     pub drawable: Card32,
 }
-impl CreateDrawableRequest {}
-impl AsByteSequence for CreateDrawableRequest {
+impl CreateDrawableRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CreateDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            CreateDrawableRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-                drawable: drawable,
-            },
-            index,
-        ))
+    pub fn builder() -> CreateDrawableRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.drawable.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateDrawableReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("drawable", 8, 4),
+    ];
+}
+const _: () = assert!(<CreateDrawableRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_screen_drawable_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.screen.as_bytes(&mut bytes[index..]);
+                index += self.drawable.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
+                index += sz;
+                let (drawable, sz): (Card32, usize) = parse_item(bytes, index, "drawable")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        screen: screen,
+                        drawable: drawable,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 12;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_screen_drawable_request_asb! { CreateDrawableRequest }
 impl Request for CreateDrawableRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateDrawable";
     type Reply = CreateDrawableReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateDrawableRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateDrawableRequestBuilder {
+    pub inner: CreateDrawableRequest,
+}
+impl CreateDrawableRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Card32) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateDrawableRequest {
+        self.inner
+    }
+}
+impl Default for CreateDrawableRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateDrawableRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateDrawableReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub hw_drawable_handle: Card32,
 }
-impl CreateDrawableReply {}
-impl AsByteSequence for CreateDrawableReply {
+impl CreateDrawableReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let hw_drawable_handle: Card32 = read_item(reader, &mut index, 4, "hw_drawable_handle")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(CreateDrawableReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            hw_drawable_handle: hw_drawable_handle,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("hw_drawable_handle", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.hw_drawable_handle == other.hw_drawable_handle
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "hw_drawable_handle",
+            &self.hw_drawable_handle,
+            &other.hw_drawable_handle,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for CreateDrawableReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.hw_drawable_handle.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateDrawableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (hw_drawable_handle, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (hw_drawable_handle, sz): (Card32, usize) =
+            parse_item(bytes, index, "hw_drawable_handle")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateDrawableReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -834,120 +1740,176 @@ impl AsByteSequence for CreateDrawableReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.hw_drawable_handle.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for CreateDrawableReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DestroyDrawableRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
     pub drawable: Card32,
 }
-impl DestroyDrawableRequest {}
-impl AsByteSequence for DestroyDrawableRequest {
+impl DestroyDrawableRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DestroyDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DestroyDrawableRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-                drawable: drawable,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.drawable.size()
+    pub fn builder() -> DestroyDrawableRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("drawable", 8, 4),
+    ];
 }
+const _: () = assert!(<DestroyDrawableRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_drawable_request_asb! { DestroyDrawableRequest }
 impl Request for DestroyDrawableRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyDrawable";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyDrawableRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyDrawableRequestBuilder {
+    pub inner: DestroyDrawableRequest,
+}
+impl DestroyDrawableRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Card32) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyDrawableRequest {
+        self.inner
+    }
+}
+impl Default for DestroyDrawableRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyDrawableRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetDrawableInfoRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
     pub drawable: Card32,
 }
-impl GetDrawableInfoRequest {}
-impl AsByteSequence for GetDrawableInfoRequest {
+impl GetDrawableInfoRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetDrawableInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetDrawableInfoRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-                drawable: drawable,
-            },
-            index,
-        ))
+    pub fn builder() -> GetDrawableInfoRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.drawable.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetDrawableInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("drawable", 8, 4),
+    ];
 }
+const _: () = assert!(<GetDrawableInfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_drawable_request_asb! { GetDrawableInfoRequest }
 impl Request for GetDrawableInfoRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDrawableInfo";
     type Reply = GetDrawableInfoReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetDrawableInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetDrawableInfoRequestBuilder {
+    pub inner: GetDrawableInfoRequest,
+}
+impl GetDrawableInfoRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Card32) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetDrawableInfoRequest {
+        self.inner
+    }
+}
+impl Default for GetDrawableInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetDrawableInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetDrawableInfoReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -963,13 +1925,243 @@ pub struct GetDrawableInfoReply {
     pub clip_rects: Vec<DrmClipRect>,
     pub back_clip_rects: Vec<DrmClipRect>,
 }
-impl GetDrawableInfoReply {}
-impl AsByteSequence for GetDrawableInfoReply {
+impl GetDrawableInfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn clip_rects_iter(bytes: &[u8]) -> Result<ListIter<'_, DrmClipRect>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (drawable_table_index, sz): (Card32, usize) =
+            parse_item(bytes, index, "drawable_table_index")?;
+        index += sz;
+        let (drawable_table_stamp, sz): (Card32, usize) =
+            parse_item(bytes, index, "drawable_table_stamp")?;
+        index += sz;
+        let (drawable_origin_x, sz): (Int16, usize) =
+            parse_item(bytes, index, "drawable_origin_x")?;
+        index += sz;
+        let (drawable_origin_y, sz): (Int16, usize) =
+            parse_item(bytes, index, "drawable_origin_y")?;
+        index += sz;
+        let (drawable_size_w, sz): (Int16, usize) = parse_item(bytes, index, "drawable_size_w")?;
+        index += sz;
+        let (drawable_size_h, sz): (Int16, usize) = parse_item(bytes, index, "drawable_size_h")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (back_x, sz): (Int16, usize) = parse_item(bytes, index, "back_x")?;
+        index += sz;
+        let (back_y, sz): (Int16, usize) = parse_item(bytes, index, "back_y")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        Ok(ListIter::new(bytes, index, len0 as usize, "clip_rects"))
+    }
+    pub fn back_clip_rects_iter(bytes: &[u8]) -> Result<ListIter<'_, DrmClipRect>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (drawable_table_index, sz): (Card32, usize) =
+            parse_item(bytes, index, "drawable_table_index")?;
+        index += sz;
+        let (drawable_table_stamp, sz): (Card32, usize) =
+            parse_item(bytes, index, "drawable_table_stamp")?;
+        index += sz;
+        let (drawable_origin_x, sz): (Int16, usize) =
+            parse_item(bytes, index, "drawable_origin_x")?;
+        index += sz;
+        let (drawable_origin_y, sz): (Int16, usize) =
+            parse_item(bytes, index, "drawable_origin_y")?;
+        index += sz;
+        let (drawable_size_w, sz): (Int16, usize) = parse_item(bytes, index, "drawable_size_w")?;
+        index += sz;
+        let (drawable_size_h, sz): (Int16, usize) = parse_item(bytes, index, "drawable_size_h")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (back_x, sz): (Int16, usize) = parse_item(bytes, index, "back_x")?;
+        index += sz;
+        let (back_y, sz): (Int16, usize) = parse_item(bytes, index, "back_y")?;
+        index += sz;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (clip_rects, block_len): (Vec<DrmClipRect>, usize) =
+            parse_vector(bytes, index, len0 as usize, "clip_rects")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
+        Ok(ListIter::new(
+            bytes,
+            index,
+            len1 as usize,
+            "back_clip_rects",
+        ))
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let drawable_table_index: Card32 =
+            read_item(reader, &mut index, 4, "drawable_table_index")?;
+        let drawable_table_stamp: Card32 =
+            read_item(reader, &mut index, 4, "drawable_table_stamp")?;
+        let drawable_origin_x: Int16 = read_item(reader, &mut index, 2, "drawable_origin_x")?;
+        let drawable_origin_y: Int16 = read_item(reader, &mut index, 2, "drawable_origin_y")?;
+        let drawable_size_w: Int16 = read_item(reader, &mut index, 2, "drawable_size_w")?;
+        let drawable_size_h: Int16 = read_item(reader, &mut index, 2, "drawable_size_h")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        let back_x: Int16 = read_item(reader, &mut index, 2, "back_x")?;
+        let back_y: Int16 = read_item(reader, &mut index, 2, "back_y")?;
+        let len1: Card32 = read_item(reader, &mut index, 4, "len1")?;
+        let (clip_rects, block_len): (Vec<DrmClipRect>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 8, "clip_rects")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>()),
+        )?;
+        let (back_clip_rects, block_len): (Vec<DrmClipRect>, usize) =
+            read_vector(reader, &mut index, len1 as usize, 8, "back_clip_rects")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetDrawableInfoReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            drawable_table_index: drawable_table_index,
+            drawable_table_stamp: drawable_table_stamp,
+            drawable_origin_x: drawable_origin_x,
+            drawable_origin_y: drawable_origin_y,
+            drawable_size_w: drawable_size_w,
+            drawable_size_h: drawable_size_h,
+            back_x: back_x,
+            back_y: back_y,
+            clip_rects: clip_rects,
+            back_clip_rects: back_clip_rects,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("drawable_table_index", 8, 4),
+        ("drawable_table_stamp", 12, 4),
+        ("drawable_origin_x", 16, 2),
+        ("drawable_origin_y", 18, 2),
+        ("drawable_size_w", 20, 2),
+        ("drawable_size_h", 22, 2),
+        ("clip_rects_len", 24, 4),
+        ("back_x", 28, 2),
+        ("back_y", 30, 2),
+        ("back_clip_rects_len", 32, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.drawable_table_index == other.drawable_table_index
+            && self.drawable_table_stamp == other.drawable_table_stamp
+            && self.drawable_origin_x == other.drawable_origin_x
+            && self.drawable_origin_y == other.drawable_origin_y
+            && self.drawable_size_w == other.drawable_size_w
+            && self.drawable_size_h == other.drawable_size_h
+            && self.back_x == other.back_x
+            && self.back_y == other.back_y
+            && self.clip_rects == other.clip_rects
+            && self.back_clip_rects == other.back_clip_rects
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "drawable_table_index",
+            &self.drawable_table_index,
+            &other.drawable_table_index,
+            &mut changes,
+        );
+        diff_value(
+            "drawable_table_stamp",
+            &self.drawable_table_stamp,
+            &other.drawable_table_stamp,
+            &mut changes,
+        );
+        diff_value(
+            "drawable_origin_x",
+            &self.drawable_origin_x,
+            &other.drawable_origin_x,
+            &mut changes,
+        );
+        diff_value(
+            "drawable_origin_y",
+            &self.drawable_origin_y,
+            &other.drawable_origin_y,
+            &mut changes,
+        );
+        diff_value(
+            "drawable_size_w",
+            &self.drawable_size_w,
+            &other.drawable_size_w,
+            &mut changes,
+        );
+        diff_value(
+            "drawable_size_h",
+            &self.drawable_size_h,
+            &other.drawable_size_h,
+            &mut changes,
+        );
+        diff_value("back_x", &self.back_x, &other.back_x, &mut changes);
+        diff_value("back_y", &self.back_y, &other.back_y, &mut changes);
+        diff_list(
+            "clip_rects",
+            &self.clip_rects,
+            &other.clip_rects,
+            &mut changes,
+        );
+        diff_list(
+            "back_clip_rects",
+            &self.back_clip_rects,
+            &other.back_clip_rects,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetDrawableInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable_table_index.as_bytes(&mut bytes[index..]);
@@ -984,52 +2176,64 @@ impl AsByteSequence for GetDrawableInfoReply {
         index += (self.back_clip_rects.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.clip_rects, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>()),
+        );
         let block_len: usize = vector_as_bytes(&self.back_clip_rects, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDrawableInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable_table_index, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (drawable_table_index, sz): (Card32, usize) =
+            parse_item(bytes, index, "drawable_table_index")?;
         index += sz;
-        let (drawable_table_stamp, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (drawable_table_stamp, sz): (Card32, usize) =
+            parse_item(bytes, index, "drawable_table_stamp")?;
         index += sz;
-        let (drawable_origin_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_origin_x, sz): (Int16, usize) =
+            parse_item(bytes, index, "drawable_origin_x")?;
         index += sz;
-        let (drawable_origin_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_origin_y, sz): (Int16, usize) =
+            parse_item(bytes, index, "drawable_origin_y")?;
         index += sz;
-        let (drawable_size_w, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_size_w, sz): (Int16, usize) = parse_item(bytes, index, "drawable_size_w")?;
         index += sz;
-        let (drawable_size_h, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_size_h, sz): (Int16, usize) = parse_item(bytes, index, "drawable_size_h")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (back_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (back_x, sz): (Int16, usize) = parse_item(bytes, index, "back_x")?;
         index += sz;
-        let (back_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (back_y, sz): (Int16, usize) = parse_item(bytes, index, "back_y")?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (clip_rects, block_len): (Vec<DrmClipRect>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "clip_rects")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
         let (back_clip_rects, block_len): (Vec<DrmClipRect>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "back_clip_rects")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetDrawableInfoReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1048,83 +2252,101 @@ impl AsByteSequence for GetDrawableInfoReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.drawable_table_index.size()
-            + self.drawable_table_stamp.size()
-            + self.drawable_origin_x.size()
-            + self.drawable_origin_y.size()
-            + self.drawable_size_w.size()
-            + self.drawable_size_h.size()
-            + ::core::mem::size_of::<Card32>()
-            + self.back_x.size()
-            + self.back_y.size()
-            + ::core::mem::size_of::<Card32>()
-            + {
+        let size: usize =
+            36 + {
                 let block_len: usize = self.clip_rects.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.back_clip_rects.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetDrawableInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetDeviceInfoRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
 }
-impl GetDeviceInfoRequest {}
-impl AsByteSequence for GetDeviceInfoRequest {
+impl GetDeviceInfoRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetDeviceInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetDeviceInfoRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetDeviceInfoRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetDeviceInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 4)];
 }
+const _: () = assert!(<GetDeviceInfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetDeviceInfoRequest }
 impl Request for GetDeviceInfoRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceInfo";
     type Reply = GetDeviceInfoReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetDeviceInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetDeviceInfoRequestBuilder {
+    pub inner: GetDeviceInfoRequest,
+}
+impl GetDeviceInfoRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetDeviceInfoRequest {
+        self.inner
+    }
+}
+impl Default for GetDeviceInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetDeviceInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetDeviceInfoReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1136,13 +2358,156 @@ pub struct GetDeviceInfoReply {
     pub framebuffer_stride: Card32,
     pub device_private: Vec<Card32>,
 }
-impl GetDeviceInfoReply {}
-impl AsByteSequence for GetDeviceInfoReply {
+impl GetDeviceInfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn device_private_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (framebuffer_handle_low, sz): (Card32, usize) =
+            parse_item(bytes, index, "framebuffer_handle_low")?;
+        index += sz;
+        let (framebuffer_handle_high, sz): (Card32, usize) =
+            parse_item(bytes, index, "framebuffer_handle_high")?;
+        index += sz;
+        let (framebuffer_origin_offset, sz): (Card32, usize) =
+            parse_item(bytes, index, "framebuffer_origin_offset")?;
+        index += sz;
+        let (framebuffer_size, sz): (Card32, usize) = parse_item(bytes, index, "framebuffer_size")?;
+        index += sz;
+        let (framebuffer_stride, sz): (Card32, usize) =
+            parse_item(bytes, index, "framebuffer_stride")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (device_private, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "device_private")?;
+        Ok(device_private)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let framebuffer_handle_low: Card32 =
+            read_item(reader, &mut index, 4, "framebuffer_handle_low")?;
+        let framebuffer_handle_high: Card32 =
+            read_item(reader, &mut index, 4, "framebuffer_handle_high")?;
+        let framebuffer_origin_offset: Card32 =
+            read_item(reader, &mut index, 4, "framebuffer_origin_offset")?;
+        let framebuffer_size: Card32 = read_item(reader, &mut index, 4, "framebuffer_size")?;
+        let framebuffer_stride: Card32 = read_item(reader, &mut index, 4, "framebuffer_stride")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        let (device_private, block_len): (Vec<Card32>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 4, "device_private")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetDeviceInfoReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            framebuffer_handle_low: framebuffer_handle_low,
+            framebuffer_handle_high: framebuffer_handle_high,
+            framebuffer_origin_offset: framebuffer_origin_offset,
+            framebuffer_size: framebuffer_size,
+            framebuffer_stride: framebuffer_stride,
+            device_private: device_private,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("framebuffer_handle_low", 8, 4),
+        ("framebuffer_handle_high", 12, 4),
+        ("framebuffer_origin_offset", 16, 4),
+        ("framebuffer_size", 20, 4),
+        ("framebuffer_stride", 24, 4),
+        ("device_private_len", 28, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.framebuffer_handle_low == other.framebuffer_handle_low
+            && self.framebuffer_handle_high == other.framebuffer_handle_high
+            && self.framebuffer_origin_offset == other.framebuffer_origin_offset
+            && self.framebuffer_size == other.framebuffer_size
+            && self.framebuffer_stride == other.framebuffer_stride
+            && self.device_private == other.device_private
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "framebuffer_handle_low",
+            &self.framebuffer_handle_low,
+            &other.framebuffer_handle_low,
+            &mut changes,
+        );
+        diff_value(
+            "framebuffer_handle_high",
+            &self.framebuffer_handle_high,
+            &other.framebuffer_handle_high,
+            &mut changes,
+        );
+        diff_value(
+            "framebuffer_origin_offset",
+            &self.framebuffer_origin_offset,
+            &other.framebuffer_origin_offset,
+            &mut changes,
+        );
+        diff_value(
+            "framebuffer_size",
+            &self.framebuffer_size,
+            &other.framebuffer_size,
+            &mut changes,
+        );
+        diff_value(
+            "framebuffer_stride",
+            &self.framebuffer_stride,
+            &other.framebuffer_stride,
+            &mut changes,
+        );
+        diff_list(
+            "device_private",
+            &self.device_private,
+            &other.device_private,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetDeviceInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.framebuffer_handle_low.as_bytes(&mut bytes[index..]);
@@ -1153,38 +2518,46 @@ impl AsByteSequence for GetDeviceInfoReply {
         index += (self.device_private.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.device_private, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (framebuffer_handle_low, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (framebuffer_handle_low, sz): (Card32, usize) =
+            parse_item(bytes, index, "framebuffer_handle_low")?;
         index += sz;
-        let (framebuffer_handle_high, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (framebuffer_handle_high, sz): (Card32, usize) =
+            parse_item(bytes, index, "framebuffer_handle_high")?;
         index += sz;
         let (framebuffer_origin_offset, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "framebuffer_origin_offset")?;
         index += sz;
-        let (framebuffer_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (framebuffer_size, sz): (Card32, usize) = parse_item(bytes, index, "framebuffer_size")?;
         index += sz;
-        let (framebuffer_stride, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (framebuffer_stride, sz): (Card32, usize) =
+            parse_item(bytes, index, "framebuffer_stride")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (device_private, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "device_private")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetDeviceInfoReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1201,56 +2574,93 @@ impl AsByteSequence for GetDeviceInfoReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.framebuffer_handle_low.size()
-            + self.framebuffer_handle_high.size()
-            + self.framebuffer_origin_offset.size()
-            + self.framebuffer_size.size()
-            + self.framebuffer_stride.size()
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.device_private.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.device_private.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 32 + {
+            let block_len: usize = self.device_private.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetDeviceInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AuthConnectionRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card32,
     pub magic: Card32,
 }
-impl AuthConnectionRequest {}
-impl AsByteSequence for AuthConnectionRequest {
+impl AuthConnectionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> AuthConnectionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<AuthConnectionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("magic", 8, 4),
+    ];
+}
+const _: () = assert!(<AuthConnectionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AuthConnectionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.magic.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthConnectionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (magic, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (magic, sz): (Card32, usize) = parse_item(bytes, index, "magic")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AuthConnectionRequest {
                 req_type: req_type,
                 length: length,
@@ -1262,48 +2672,156 @@ impl AsByteSequence for AuthConnectionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.magic.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AuthConnectionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AuthConnectionRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AuthConnection";
     type Reply = AuthConnectionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `AuthConnectionRequest`."]
+#[derive(Clone, Debug)]
+pub struct AuthConnectionRequestBuilder {
+    pub inner: AuthConnectionRequest,
+}
+impl AuthConnectionRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn magic(mut self, magic: Card32) -> Self {
+        self.inner.magic = magic;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AuthConnectionRequest {
+        self.inner
+    }
+}
+impl Default for AuthConnectionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AuthConnectionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                magic: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AuthConnectionReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub authenticated: Card32,
 }
-impl AuthConnectionReply {}
-impl AsByteSequence for AuthConnectionReply {
+impl AuthConnectionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let authenticated: Card32 = read_item(reader, &mut index, 4, "authenticated")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(AuthConnectionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            authenticated: authenticated,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("authenticated", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.authenticated == other.authenticated
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "authenticated",
+            &self.authenticated,
+            &other.authenticated,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for AuthConnectionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.authenticated.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthConnectionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (authenticated, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (authenticated, sz): (Card32, usize) = parse_item(bytes, index, "authenticated")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AuthConnectionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1315,10 +2833,55 @@ impl AsByteSequence for AuthConnectionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.authenticated.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AuthConnectionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "QueryDirectRenderingCapable"),
+    (2, "OpenConnection"),
+    (3, "CloseConnection"),
+    (4, "GetClientDriverName"),
+    (5, "CreateContext"),
+    (6, "DestroyContext"),
+    (7, "CreateDrawable"),
+    (8, "DestroyDrawable"),
+    (9, "GetDrawableInfo"),
+    (10, "GetDeviceInfo"),
+    (11, "AuthConnection"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,14 +1,19 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "xf86vidmode")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 2;
+pub const MINOR_VERSION: u32 = 2;
 pub type Syncrange = Card32;
 pub type Dotclock = Card32;
 pub type ClockFlag = Card32;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ModeInfo {
     pub dotclock: Dotclock,
     pub hdisplay: Card16,
@@ -23,9 +28,24 @@ pub struct ModeInfo {
     pub flags: ModeFlag,
     pub privsize: Card32,
 }
-impl ModeInfo {}
+impl ModeInfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("dotclock", 0, 4),
+        ("hdisplay", 4, 2),
+        ("hsyncstart", 6, 2),
+        ("hsyncend", 8, 2),
+        ("htotal", 10, 2),
+        ("hskew", 12, 4),
+        ("vdisplay", 16, 2),
+        ("vsyncstart", 18, 2),
+        ("vsyncend", 20, 2),
+        ("vtotal", 22, 2),
+        ("flags", 28, 4),
+        ("privsize", 44, 4),
+    ];
+}
 impl AsByteSequence for ModeInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.dotclock.as_bytes(&mut bytes[index..]);
@@ -38,43 +58,42 @@ impl AsByteSequence for ModeInfo {
         index += self.vsyncstart.as_bytes(&mut bytes[index..]);
         index += self.vsyncend.as_bytes(&mut bytes[index..]);
         index += self.vtotal.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         index += self.privsize.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModeInfo from byte buffer");
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card32, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
         index += 4;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 12;
-        let (privsize, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (privsize, sz): (Card32, usize) = parse_item(bytes, index, "privsize")?;
         index += sz;
-        Some((
+        Ok((
             ModeInfo {
                 dotclock: dotclock,
                 hdisplay: hdisplay,
@@ -94,24 +113,20 @@ impl AsByteSequence for ModeInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.dotclock.size()
-            + self.hdisplay.size()
-            + self.hsyncstart.size()
-            + self.hsyncend.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vdisplay.size()
-            + self.vsyncstart.size()
-            + self.vsyncend.size()
-            + self.vtotal.size()
-            + 4
-            + self.flags.size()
-            + 12
-            + self.privsize.size()
+        48
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ModeInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ModeFlag {
     pub inner: u32,
 }
@@ -285,7 +300,6 @@ impl ModeFlag {
         }
         self
     }
-    #[inline]
     pub fn new(
         positive_h_sync: bool,
         negative_h_sync: bool,
@@ -362,21 +376,46 @@ impl ModeFlag {
     pub const HALF_CLOCK: Self = Self { inner: 4096 };
     pub const COMPLETE: Self = Self { inner: 8191 };
 }
-impl AsByteSequence for ModeFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for ModeFlag {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((ModeFlag { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for ModeFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+macro_rules! impl_u32_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                4
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_u32_flags_asb! { ModeFlag }
 impl core::ops::Not for ModeFlag {
     type Output = ModeFlag;
     #[inline]
@@ -411,31 +450,58 @@ impl core::ops::BitXor for ModeFlag {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryVersionRequest {}
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryVersionRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -445,16 +511,51 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -462,35 +563,105 @@ pub struct QueryVersionReply {
     pub major_version: Card16,
     pub minor_version: Card16,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card16 = read_item(reader, &mut index, 2, "major_version")?;
+        let minor_version: Card16 = read_item(reader, &mut index, 2, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 2),
+        ("minor_version", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card16, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -503,65 +674,144 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetModeLineRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetModeLineRequest {}
-impl AsByteSequence for GetModeLineRequest {
+impl GetModeLineRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetModeLineRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetModeLineRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetModeLineReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
+}
+const _: () = assert!(<GetModeLineRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_screen_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.screen.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 2);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
+                index += sz;
+                index += 2;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        screen: screen,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_screen_only_request_asb! { GetModeLineRequest }
 impl Request for GetModeLineRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetModeLine";
     type Reply = GetModeLineReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetModeLineRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetModeLineRequestBuilder {
+    pub inner: GetModeLineRequest,
+}
+impl GetModeLineRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetModeLineRequest {
+        self.inner
+    }
+}
+impl Default for GetModeLineRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetModeLineRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetModeLineReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -579,13 +829,175 @@ pub struct GetModeLineReply {
     pub flags: ModeFlag,
     pub private: Vec<Card8>,
 }
-impl GetModeLineReply {}
-impl AsByteSequence for GetModeLineReply {
+impl GetModeLineReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn private_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
+        index += sz;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
+        index += sz;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
+        index += sz;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
+        index += sz;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
+        index += sz;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
+        index += sz;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
+        index += sz;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
+        index += sz;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
+        index += sz;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
+        index += sz;
+        index += 2;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
+        index += sz;
+        index += 12;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (private, _): (Cow<'_, [Card8]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "private")?;
+        Ok(private)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let dotclock: Dotclock = read_item(reader, &mut index, 4, "dotclock")?;
+        let hdisplay: Card16 = read_item(reader, &mut index, 2, "hdisplay")?;
+        let hsyncstart: Card16 = read_item(reader, &mut index, 2, "hsyncstart")?;
+        let hsyncend: Card16 = read_item(reader, &mut index, 2, "hsyncend")?;
+        let htotal: Card16 = read_item(reader, &mut index, 2, "htotal")?;
+        let hskew: Card16 = read_item(reader, &mut index, 2, "hskew")?;
+        let vdisplay: Card16 = read_item(reader, &mut index, 2, "vdisplay")?;
+        let vsyncstart: Card16 = read_item(reader, &mut index, 2, "vsyncstart")?;
+        let vsyncend: Card16 = read_item(reader, &mut index, 2, "vsyncend")?;
+        let vtotal: Card16 = read_item(reader, &mut index, 2, "vtotal")?;
+        skip_bytes(reader, &mut index, 2)?;
+        let flags: ModeFlag = read_item(reader, &mut index, 4, "flags")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        let (private, block_len): (Vec<Card8>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 1, "private")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetModeLineReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            dotclock: dotclock,
+            hdisplay: hdisplay,
+            hsyncstart: hsyncstart,
+            hsyncend: hsyncend,
+            htotal: htotal,
+            hskew: hskew,
+            vdisplay: vdisplay,
+            vsyncstart: vsyncstart,
+            vsyncend: vsyncend,
+            vtotal: vtotal,
+            flags: flags,
+            private: private,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("dotclock", 8, 4),
+        ("hdisplay", 12, 2),
+        ("hsyncstart", 14, 2),
+        ("hsyncend", 16, 2),
+        ("htotal", 18, 2),
+        ("hskew", 20, 2),
+        ("vdisplay", 22, 2),
+        ("vsyncstart", 24, 2),
+        ("vsyncend", 26, 2),
+        ("vtotal", 28, 2),
+        ("flags", 32, 4),
+        ("private_len", 48, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.dotclock == other.dotclock
+            && self.hdisplay == other.hdisplay
+            && self.hsyncstart == other.hsyncstart
+            && self.hsyncend == other.hsyncend
+            && self.htotal == other.htotal
+            && self.hskew == other.hskew
+            && self.vdisplay == other.vdisplay
+            && self.vsyncstart == other.vsyncstart
+            && self.vsyncend == other.vsyncend
+            && self.vtotal == other.vtotal
+            && self.flags == other.flags
+            && self.private == other.private
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("dotclock", &self.dotclock, &other.dotclock, &mut changes);
+        diff_value("hdisplay", &self.hdisplay, &other.hdisplay, &mut changes);
+        diff_value(
+            "hsyncstart",
+            &self.hsyncstart,
+            &other.hsyncstart,
+            &mut changes,
+        );
+        diff_value("hsyncend", &self.hsyncend, &other.hsyncend, &mut changes);
+        diff_value("htotal", &self.htotal, &other.htotal, &mut changes);
+        diff_value("hskew", &self.hskew, &other.hskew, &mut changes);
+        diff_value("vdisplay", &self.vdisplay, &other.vdisplay, &mut changes);
+        diff_value(
+            "vsyncstart",
+            &self.vsyncstart,
+            &other.vsyncstart,
+            &mut changes,
+        );
+        diff_value("vsyncend", &self.vsyncend, &other.vsyncend, &mut changes);
+        diff_value("vtotal", &self.vtotal, &other.vtotal, &mut changes);
+        diff_value("flags", &self.flags, &other.flags, &mut changes);
+        diff_list("private", &self.private, &other.private, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetModeLineReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.dotclock.as_bytes(&mut bytes[index..]);
@@ -598,57 +1010,62 @@ impl AsByteSequence for GetModeLineReply {
         index += self.vsyncstart.as_bytes(&mut bytes[index..]);
         index += self.vsyncend.as_bytes(&mut bytes[index..]);
         index += self.vtotal.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         index += (self.private.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetModeLineReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "private")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetModeLineReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -671,32 +1088,56 @@ impl AsByteSequence for GetModeLineReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.dotclock.size()
-            + self.hdisplay.size()
-            + self.hsyncstart.size()
-            + self.hsyncend.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vdisplay.size()
-            + self.vsyncstart.size()
-            + self.vsyncend.size()
-            + self.vtotal.size()
-            + 2
-            + self.flags.size()
-            + 12
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.private.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
+        let size: usize = 52 + {
+            let block_len: usize = self.private.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 52 + {
+            let block_len: usize = self.private.len() * ::core::mem::size_of::<Card8>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetModeLineReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for GetModeLineReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetModeLineReply")
+            .field("reply_type", &self.reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("dotclock", &self.dotclock)
+            .field("hdisplay", &self.hdisplay)
+            .field("hsyncstart", &self.hsyncstart)
+            .field("hsyncend", &self.hsyncend)
+            .field("htotal", &self.htotal)
+            .field("hskew", &self.hskew)
+            .field("vdisplay", &self.vdisplay)
+            .field("vsyncstart", &self.vsyncstart)
+            .field("vsyncend", &self.vsyncend)
+            .field("vtotal", &self.vtotal)
+            .field("flags", &self.flags)
+            .field("private", &debug_bytes(&self.private, 64))
+            .finish()
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ModModeLineRequest {
     pub req_type: u8,
     pub length: u16,
@@ -713,13 +1154,90 @@ pub struct ModModeLineRequest {
     pub flags: ModeFlag,
     pub private: Vec<Card8>,
 }
-impl ModModeLineRequest {}
-impl AsByteSequence for ModModeLineRequest {
+impl ModModeLineRequest {
+    pub fn private_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
+        index += sz;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
+        index += sz;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
+        index += sz;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
+        index += sz;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
+        index += sz;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
+        index += sz;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
+        index += sz;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
+        index += sz;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
+        index += sz;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
+        index += sz;
+        index += 2;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
+        index += sz;
+        index += 12;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (private, _): (Cow<'_, [Card8]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "private")?;
+        Ok(private)
+    }
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            screen: self.screen.clone(),
+            hdisplay: self.hdisplay.clone(),
+            hsyncstart: self.hsyncstart.clone(),
+            hsyncend: self.hsyncend.clone(),
+            htotal: self.htotal.clone(),
+            hskew: self.hskew.clone(),
+            vdisplay: self.vdisplay.clone(),
+            vsyncstart: self.vsyncstart.clone(),
+            vsyncend: self.vsyncend.clone(),
+            vtotal: self.vtotal.clone(),
+            flags: self.flags.clone(),
+            private: self.private.clone(),
+        }
+    }
     #[inline]
+    pub fn builder() -> ModModeLineRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("hdisplay", 8, 2),
+        ("hsyncstart", 10, 2),
+        ("hsyncend", 12, 2),
+        ("htotal", 14, 2),
+        ("hskew", 16, 2),
+        ("vdisplay", 18, 2),
+        ("vsyncstart", 20, 2),
+        ("vsyncend", 22, 2),
+        ("vtotal", 24, 2),
+        ("flags", 28, 4),
+        ("private_len", 44, 4),
+    ];
+}
+const _: () = assert!(<ModModeLineRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ModModeLineRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.hdisplay.as_bytes(&mut bytes[index..]);
@@ -731,55 +1249,59 @@ impl AsByteSequence for ModModeLineRequest {
         index += self.vsyncstart.as_bytes(&mut bytes[index..]);
         index += self.vsyncend.as_bytes(&mut bytes[index..]);
         index += self.vtotal.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         index += (self.private.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "private")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ModModeLineRequest {
                 req_type: req_type,
                 length: length,
@@ -801,69 +1323,223 @@ impl AsByteSequence for ModModeLineRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + self.hdisplay.size()
-            + self.hsyncstart.size()
-            + self.hsyncend.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vdisplay.size()
-            + self.vsyncstart.size()
-            + self.vsyncend.size()
-            + self.vtotal.size()
-            + 2
-            + self.flags.size()
-            + 12
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.private.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
+        let size: usize = 48 + {
+            let block_len: usize = self.private.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 48 + {
+            let block_len: usize = self.private.len() * ::core::mem::size_of::<Card8>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ModModeLineRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ModModeLineRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ModModeLine";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for ModModeLineRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ModModeLineRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("screen", &self.screen)
+            .field("hdisplay", &self.hdisplay)
+            .field("hsyncstart", &self.hsyncstart)
+            .field("hsyncend", &self.hsyncend)
+            .field("htotal", &self.htotal)
+            .field("hskew", &self.hskew)
+            .field("vdisplay", &self.vdisplay)
+            .field("vsyncstart", &self.vsyncstart)
+            .field("vsyncend", &self.vsyncend)
+            .field("vtotal", &self.vtotal)
+            .field("flags", &self.flags)
+            .field("private", &debug_bytes(&self.private, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `ModModeLineRequest`."]
+#[derive(Debug)]
+pub struct ModModeLineRequestBuilder {
+    pub inner: ModModeLineRequest,
+}
+impl ModModeLineRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn hdisplay(mut self, hdisplay: Card16) -> Self {
+        self.inner.hdisplay = hdisplay;
+        self
+    }
+    #[inline]
+    pub fn hsyncstart(mut self, hsyncstart: Card16) -> Self {
+        self.inner.hsyncstart = hsyncstart;
+        self
+    }
+    #[inline]
+    pub fn hsyncend(mut self, hsyncend: Card16) -> Self {
+        self.inner.hsyncend = hsyncend;
+        self
+    }
+    #[inline]
+    pub fn htotal(mut self, htotal: Card16) -> Self {
+        self.inner.htotal = htotal;
+        self
+    }
+    #[inline]
+    pub fn hskew(mut self, hskew: Card16) -> Self {
+        self.inner.hskew = hskew;
+        self
+    }
+    #[inline]
+    pub fn vdisplay(mut self, vdisplay: Card16) -> Self {
+        self.inner.vdisplay = vdisplay;
+        self
+    }
+    #[inline]
+    pub fn vsyncstart(mut self, vsyncstart: Card16) -> Self {
+        self.inner.vsyncstart = vsyncstart;
+        self
+    }
+    #[inline]
+    pub fn vsyncend(mut self, vsyncend: Card16) -> Self {
+        self.inner.vsyncend = vsyncend;
+        self
+    }
+    #[inline]
+    pub fn vtotal(mut self, vtotal: Card16) -> Self {
+        self.inner.vtotal = vtotal;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: ModeFlag) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn private(mut self, private: Vec<Card8>) -> Self {
+        self.inner.private = private;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ModModeLineRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card8> for ModModeLineRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card8>>(&mut self, iter: I) {
+        self.inner.private.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card8> for ModModeLineRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for ModModeLineRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ModModeLineRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                hdisplay: Default::default(),
+                hsyncstart: Default::default(),
+                hsyncend: Default::default(),
+                htotal: Default::default(),
+                hskew: Default::default(),
+                vdisplay: Default::default(),
+                vsyncstart: Default::default(),
+                vsyncend: Default::default(),
+                vtotal: Default::default(),
+                flags: Default::default(),
+                private: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SwitchModeRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
     pub zoom: Card16,
 }
-impl SwitchModeRequest {}
-impl AsByteSequence for SwitchModeRequest {
+impl SwitchModeRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> SwitchModeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 2),
+        ("zoom", 6, 2),
+    ];
+}
+const _: () = assert!(<SwitchModeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SwitchModeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.zoom.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwitchModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (zoom, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (zoom, sz): (Card16, usize) = parse_item(bytes, index, "zoom")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SwitchModeRequest {
                 req_type: req_type,
                 length: length,
@@ -875,66 +1551,133 @@ impl AsByteSequence for SwitchModeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.zoom.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SwitchModeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SwitchModeRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwitchMode";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SwitchModeRequest`."]
+#[derive(Clone, Debug)]
+pub struct SwitchModeRequestBuilder {
+    pub inner: SwitchModeRequest,
+}
+impl SwitchModeRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn zoom(mut self, zoom: Card16) -> Self {
+        self.inner.zoom = zoom;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SwitchModeRequest {
+        self.inner
+    }
+}
+impl Default for SwitchModeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SwitchModeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                zoom: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetMonitorRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetMonitorRequest {}
-impl AsByteSequence for GetMonitorRequest {
+impl GetMonitorRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetMonitorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetMonitorRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetMonitorRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetMonitorReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
 }
+const _: () = assert!(<GetMonitorRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetMonitorRequest }
 impl Request for GetMonitorRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMonitor";
     type Reply = GetMonitorReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetMonitorRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetMonitorRequestBuilder {
+    pub inner: GetMonitorRequest,
+}
+impl GetMonitorRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetMonitorRequest {
+        self.inner
+    }
+}
+impl Default for GetMonitorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetMonitorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetMonitorReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -946,80 +1689,163 @@ pub struct GetMonitorReply {
     pub alignment_pad: Vec<Void>,
     pub model: String,
 }
-impl GetMonitorReply {}
+impl GetMonitorReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("vendor_length", 8, 1),
+        ("model_len", 9, 1),
+        ("hsync_len", 10, 1),
+        ("vsync_len", 11, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.vendor_length == other.vendor_length
+            && self.hsync == other.hsync
+            && self.vsync == other.vsync
+            && self.vendor == other.vendor
+            && self.alignment_pad == other.alignment_pad
+            && self.model == other.model
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "vendor_length",
+            &self.vendor_length,
+            &other.vendor_length,
+            &mut changes,
+        );
+        diff_list("hsync", &self.hsync, &other.hsync, &mut changes);
+        diff_list("vsync", &self.vsync, &other.vsync, &mut changes);
+        diff_list(
+            "vendor",
+            str::as_bytes(&self.vendor),
+            str::as_bytes(&other.vendor),
+            &mut changes,
+        );
+        diff_list(
+            "alignment_pad",
+            &self.alignment_pad,
+            &other.alignment_pad,
+            &mut changes,
+        );
+        diff_list(
+            "model",
+            str::as_bytes(&self.model),
+            str::as_bytes(&other.model),
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for GetMonitorReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.vendor_length.as_bytes(&mut bytes[index..]);
         index += (self.model.len() as Card8).as_bytes(&mut bytes[index..]);
         index += (self.hsync.len() as Card8).as_bytes(&mut bytes[index..]);
         index += (self.vsync.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.hsync, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Syncrange>()),
+        );
         let block_len: usize = vector_as_bytes(&self.vsync, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Syncrange>()),
+        );
         let block_len: usize = string_as_bytes(&self.vendor, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
         let block_len: usize = vector_as_bytes(&self.alignment_pad, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Void>()),
+        );
         let block_len: usize = string_as_bytes(&self.model, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMonitorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (vendor_length, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (vendor_length, sz): (Card8, usize) = parse_item(bytes, index, "vendor_length")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
         index += 20;
         let (hsync, block_len): (Vec<Syncrange>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "hsync")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
         let (vsync, block_len): (Vec<Syncrange>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            parse_vector(bytes, index, len2 as usize, "vsync")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
         let (vendor, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], (vendor_length as usize) as usize)?;
+            parse_string(bytes, index, (vendor_length as usize) as usize, "vendor")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        let (alignment_pad, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (alignment_pad, block_len): (Vec<Void>, usize) = parse_vector(
+            bytes,
+            index,
             ((((vendor_length as usize) + (3)) & (!(3))) - (vendor_length as usize)) as usize,
+            "alignment_pad",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
         let (model, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "model")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetMonitorReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1034,77 +1860,139 @@ impl AsByteSequence for GetMonitorReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.vendor_length.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + 20
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.hsync.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.vsync.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.vendor.len();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.alignment_pad.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.model.len();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetMonitorReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for GetMonitorReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetMonitorReply")
+            .field("reply_type", &self.reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("vendor_length", &self.vendor_length)
+            .field("hsync", &self.hsync)
+            .field("vsync", &self.vsync)
+            .field("vendor", &self.vendor)
+            .field("alignment_pad", &debug_bytes(&self.alignment_pad, 64))
+            .field("model", &self.model)
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMonitorReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let vendor_length: Card8 = u.arbitrary()?;
+        let hsync = u.arbitrary()?;
+        let vsync = u.arbitrary()?;
+        let model = u.arbitrary()?;
+        let vendor = arbitrary_string(u, (vendor_length as usize) as usize)?;
+        let alignment_pad = arbitrary_list(
+            u,
+            ((((vendor_length as usize) + (3)) & (!(3))) - (vendor_length as usize)) as usize,
+        )?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            vendor_length: vendor_length,
+            hsync: hsync,
+            vsync: vsync,
+            vendor: vendor,
+            alignment_pad: alignment_pad,
+            model: model,
+        })
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct LockModeSwitchRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
     pub lock: Card16,
 }
-impl LockModeSwitchRequest {}
-impl AsByteSequence for LockModeSwitchRequest {
+impl LockModeSwitchRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> LockModeSwitchRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 2),
+        ("lock", 6, 2),
+    ];
+}
+const _: () = assert!(<LockModeSwitchRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for LockModeSwitchRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.lock.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing LockModeSwitchRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (lock, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (lock, sz): (Card16, usize) = parse_item(bytes, index, "lock")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             LockModeSwitchRequest {
                 req_type: req_type,
                 length: length,
@@ -1116,107 +2004,251 @@ impl AsByteSequence for LockModeSwitchRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.lock.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for LockModeSwitchRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for LockModeSwitchRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "LockModeSwitch";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `LockModeSwitchRequest`."]
+#[derive(Clone, Debug)]
+pub struct LockModeSwitchRequestBuilder {
+    pub inner: LockModeSwitchRequest,
+}
+impl LockModeSwitchRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn lock(mut self, lock: Card16) -> Self {
+        self.inner.lock = lock;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> LockModeSwitchRequest {
+        self.inner
+    }
+}
+impl Default for LockModeSwitchRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: LockModeSwitchRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                lock: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetAllModeLinesRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetAllModeLinesRequest {}
-impl AsByteSequence for GetAllModeLinesRequest {
+impl GetAllModeLinesRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetAllModeLinesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetAllModeLinesRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetAllModeLinesRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetAllModeLinesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
 }
+const _: () = assert!(<GetAllModeLinesRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetAllModeLinesRequest }
 impl Request for GetAllModeLinesRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetAllModeLines";
     type Reply = GetAllModeLinesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetAllModeLinesRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetAllModeLinesRequestBuilder {
+    pub inner: GetAllModeLinesRequest,
+}
+impl GetAllModeLinesRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetAllModeLinesRequest {
+        self.inner
+    }
+}
+impl Default for GetAllModeLinesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetAllModeLinesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetAllModeLinesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub modeinfo: Vec<ModeInfo>,
 }
-impl GetAllModeLinesReply {}
-impl AsByteSequence for GetAllModeLinesReply {
+impl GetAllModeLinesReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn modeinfo_iter(bytes: &[u8]) -> Result<ListIter<'_, ModeInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "modeinfo"))
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (modeinfo, block_len): (Vec<ModeInfo>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 48, "modeinfo")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetAllModeLinesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            modeinfo: modeinfo,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("modeinfo_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.modeinfo == other.modeinfo
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("modeinfo", &self.modeinfo, &other.modeinfo, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetAllModeLinesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.modeinfo.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.modeinfo, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetAllModeLinesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (modeinfo, block_len): (Vec<ModeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "modeinfo")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetAllModeLinesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1228,20 +2260,25 @@ impl AsByteSequence for GetAllModeLinesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.modeinfo.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.modeinfo.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetAllModeLinesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct AddModeLineRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1270,13 +2307,138 @@ pub struct AddModeLineRequest {
     pub after_flags: ModeFlag,
     pub private: Vec<Card8>,
 }
-impl AddModeLineRequest {}
-impl AsByteSequence for AddModeLineRequest {
+impl AddModeLineRequest {
+    pub fn private_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
+        index += sz;
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
+        index += sz;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
+        index += sz;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
+        index += sz;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
+        index += sz;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
+        index += sz;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
+        index += sz;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
+        index += sz;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
+        index += sz;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
+        index += sz;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
+        index += sz;
+        index += 2;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
+        index += sz;
+        index += 12;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (after_dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "after_dotclock")?;
+        index += sz;
+        let (after_hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "after_hdisplay")?;
+        index += sz;
+        let (after_hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "after_hsyncstart")?;
+        index += sz;
+        let (after_hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "after_hsyncend")?;
+        index += sz;
+        let (after_htotal, sz): (Card16, usize) = parse_item(bytes, index, "after_htotal")?;
+        index += sz;
+        let (after_hskew, sz): (Card16, usize) = parse_item(bytes, index, "after_hskew")?;
+        index += sz;
+        let (after_vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "after_vdisplay")?;
+        index += sz;
+        let (after_vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "after_vsyncstart")?;
+        index += sz;
+        let (after_vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "after_vsyncend")?;
+        index += sz;
+        let (after_vtotal, sz): (Card16, usize) = parse_item(bytes, index, "after_vtotal")?;
+        index += sz;
+        let (after_flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "after_flags")?;
+        index += sz;
+        let (private, _): (Cow<'_, [Card8]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "private")?;
+        Ok(private)
+    }
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            screen: self.screen.clone(),
+            dotclock: self.dotclock.clone(),
+            hdisplay: self.hdisplay.clone(),
+            hsyncstart: self.hsyncstart.clone(),
+            hsyncend: self.hsyncend.clone(),
+            htotal: self.htotal.clone(),
+            hskew: self.hskew.clone(),
+            vdisplay: self.vdisplay.clone(),
+            vsyncstart: self.vsyncstart.clone(),
+            vsyncend: self.vsyncend.clone(),
+            vtotal: self.vtotal.clone(),
+            flags: self.flags.clone(),
+            after_dotclock: self.after_dotclock.clone(),
+            after_hdisplay: self.after_hdisplay.clone(),
+            after_hsyncstart: self.after_hsyncstart.clone(),
+            after_hsyncend: self.after_hsyncend.clone(),
+            after_htotal: self.after_htotal.clone(),
+            after_hskew: self.after_hskew.clone(),
+            after_vdisplay: self.after_vdisplay.clone(),
+            after_vsyncstart: self.after_vsyncstart.clone(),
+            after_vsyncend: self.after_vsyncend.clone(),
+            after_vtotal: self.after_vtotal.clone(),
+            after_flags: self.after_flags.clone(),
+            private: self.private.clone(),
+        }
+    }
     #[inline]
+    pub fn builder() -> AddModeLineRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("dotclock", 8, 4),
+        ("hdisplay", 12, 2),
+        ("hsyncstart", 14, 2),
+        ("hsyncend", 16, 2),
+        ("htotal", 18, 2),
+        ("hskew", 20, 2),
+        ("vdisplay", 22, 2),
+        ("vsyncstart", 24, 2),
+        ("vsyncend", 26, 2),
+        ("vtotal", 28, 2),
+        ("flags", 32, 4),
+        ("private_len", 48, 4),
+        ("after_dotclock", 52, 4),
+        ("after_hdisplay", 56, 2),
+        ("after_hsyncstart", 58, 2),
+        ("after_hsyncend", 60, 2),
+        ("after_htotal", 62, 2),
+        ("after_hskew", 64, 2),
+        ("after_vdisplay", 66, 2),
+        ("after_vsyncstart", 68, 2),
+        ("after_vsyncend", 70, 2),
+        ("after_vtotal", 72, 2),
+        ("after_flags", 74, 4),
+    ];
+}
+const _: () = assert!(<AddModeLineRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for AddModeLineRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.dotclock.as_bytes(&mut bytes[index..]);
@@ -1289,9 +2451,9 @@ impl AsByteSequence for AddModeLineRequest {
         index += self.vsyncstart.as_bytes(&mut bytes[index..]);
         index += self.vsyncend.as_bytes(&mut bytes[index..]);
         index += self.vtotal.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         index += (self.private.len() as Card32).as_bytes(&mut bytes[index..]);
         index += self.after_dotclock.as_bytes(&mut bytes[index..]);
         index += self.after_hdisplay.as_bytes(&mut bytes[index..]);
@@ -1306,73 +2468,77 @@ impl AsByteSequence for AddModeLineRequest {
         index += self.after_flags.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (after_dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (after_dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "after_dotclock")?;
         index += sz;
-        let (after_hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "after_hdisplay")?;
         index += sz;
-        let (after_hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "after_hsyncstart")?;
         index += sz;
-        let (after_hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "after_hsyncend")?;
         index += sz;
-        let (after_htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_htotal, sz): (Card16, usize) = parse_item(bytes, index, "after_htotal")?;
         index += sz;
-        let (after_hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hskew, sz): (Card16, usize) = parse_item(bytes, index, "after_hskew")?;
         index += sz;
-        let (after_vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "after_vdisplay")?;
         index += sz;
-        let (after_vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "after_vsyncstart")?;
         index += sz;
-        let (after_vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "after_vsyncend")?;
         index += sz;
-        let (after_vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vtotal, sz): (Card16, usize) = parse_item(bytes, index, "after_vtotal")?;
         index += sz;
-        let (after_flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (after_flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "after_flags")?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "private")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             AddModeLineRequest {
                 req_type: req_type,
                 length: length,
@@ -1406,49 +2572,256 @@ impl AsByteSequence for AddModeLineRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + self.dotclock.size()
-            + self.hdisplay.size()
-            + self.hsyncstart.size()
-            + self.hsyncend.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vdisplay.size()
-            + self.vsyncstart.size()
-            + self.vsyncend.size()
-            + self.vtotal.size()
-            + 2
-            + self.flags.size()
-            + 12
-            + ::core::mem::size_of::<Card32>()
-            + self.after_dotclock.size()
-            + self.after_hdisplay.size()
-            + self.after_hsyncstart.size()
-            + self.after_hsyncend.size()
-            + self.after_htotal.size()
-            + self.after_hskew.size()
-            + self.after_vdisplay.size()
-            + self.after_vsyncstart.size()
-            + self.after_vsyncend.size()
-            + self.after_vtotal.size()
-            + self.after_flags.size()
-            + {
-                let block_len: usize = self.private.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
+        let size: usize = 78 + {
+            let block_len: usize = self.private.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 78 + {
+            let block_len: usize = self.private.len() * ::core::mem::size_of::<Card8>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AddModeLineRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for AddModeLineRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddModeLine";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for AddModeLineRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AddModeLineRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("screen", &self.screen)
+            .field("dotclock", &self.dotclock)
+            .field("hdisplay", &self.hdisplay)
+            .field("hsyncstart", &self.hsyncstart)
+            .field("hsyncend", &self.hsyncend)
+            .field("htotal", &self.htotal)
+            .field("hskew", &self.hskew)
+            .field("vdisplay", &self.vdisplay)
+            .field("vsyncstart", &self.vsyncstart)
+            .field("vsyncend", &self.vsyncend)
+            .field("vtotal", &self.vtotal)
+            .field("flags", &self.flags)
+            .field("after_dotclock", &self.after_dotclock)
+            .field("after_hdisplay", &self.after_hdisplay)
+            .field("after_hsyncstart", &self.after_hsyncstart)
+            .field("after_hsyncend", &self.after_hsyncend)
+            .field("after_htotal", &self.after_htotal)
+            .field("after_hskew", &self.after_hskew)
+            .field("after_vdisplay", &self.after_vdisplay)
+            .field("after_vsyncstart", &self.after_vsyncstart)
+            .field("after_vsyncend", &self.after_vsyncend)
+            .field("after_vtotal", &self.after_vtotal)
+            .field("after_flags", &self.after_flags)
+            .field("private", &debug_bytes(&self.private, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `AddModeLineRequest`."]
+#[derive(Debug)]
+pub struct AddModeLineRequestBuilder {
+    pub inner: AddModeLineRequest,
+}
+impl AddModeLineRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn dotclock(mut self, dotclock: Dotclock) -> Self {
+        self.inner.dotclock = dotclock;
+        self
+    }
+    #[inline]
+    pub fn hdisplay(mut self, hdisplay: Card16) -> Self {
+        self.inner.hdisplay = hdisplay;
+        self
+    }
+    #[inline]
+    pub fn hsyncstart(mut self, hsyncstart: Card16) -> Self {
+        self.inner.hsyncstart = hsyncstart;
+        self
+    }
+    #[inline]
+    pub fn hsyncend(mut self, hsyncend: Card16) -> Self {
+        self.inner.hsyncend = hsyncend;
+        self
+    }
+    #[inline]
+    pub fn htotal(mut self, htotal: Card16) -> Self {
+        self.inner.htotal = htotal;
+        self
+    }
+    #[inline]
+    pub fn hskew(mut self, hskew: Card16) -> Self {
+        self.inner.hskew = hskew;
+        self
+    }
+    #[inline]
+    pub fn vdisplay(mut self, vdisplay: Card16) -> Self {
+        self.inner.vdisplay = vdisplay;
+        self
+    }
+    #[inline]
+    pub fn vsyncstart(mut self, vsyncstart: Card16) -> Self {
+        self.inner.vsyncstart = vsyncstart;
+        self
+    }
+    #[inline]
+    pub fn vsyncend(mut self, vsyncend: Card16) -> Self {
+        self.inner.vsyncend = vsyncend;
+        self
+    }
+    #[inline]
+    pub fn vtotal(mut self, vtotal: Card16) -> Self {
+        self.inner.vtotal = vtotal;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: ModeFlag) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn after_dotclock(mut self, after_dotclock: Dotclock) -> Self {
+        self.inner.after_dotclock = after_dotclock;
+        self
+    }
+    #[inline]
+    pub fn after_hdisplay(mut self, after_hdisplay: Card16) -> Self {
+        self.inner.after_hdisplay = after_hdisplay;
+        self
+    }
+    #[inline]
+    pub fn after_hsyncstart(mut self, after_hsyncstart: Card16) -> Self {
+        self.inner.after_hsyncstart = after_hsyncstart;
+        self
+    }
+    #[inline]
+    pub fn after_hsyncend(mut self, after_hsyncend: Card16) -> Self {
+        self.inner.after_hsyncend = after_hsyncend;
+        self
+    }
+    #[inline]
+    pub fn after_htotal(mut self, after_htotal: Card16) -> Self {
+        self.inner.after_htotal = after_htotal;
+        self
+    }
+    #[inline]
+    pub fn after_hskew(mut self, after_hskew: Card16) -> Self {
+        self.inner.after_hskew = after_hskew;
+        self
+    }
+    #[inline]
+    pub fn after_vdisplay(mut self, after_vdisplay: Card16) -> Self {
+        self.inner.after_vdisplay = after_vdisplay;
+        self
+    }
+    #[inline]
+    pub fn after_vsyncstart(mut self, after_vsyncstart: Card16) -> Self {
+        self.inner.after_vsyncstart = after_vsyncstart;
+        self
+    }
+    #[inline]
+    pub fn after_vsyncend(mut self, after_vsyncend: Card16) -> Self {
+        self.inner.after_vsyncend = after_vsyncend;
+        self
+    }
+    #[inline]
+    pub fn after_vtotal(mut self, after_vtotal: Card16) -> Self {
+        self.inner.after_vtotal = after_vtotal;
+        self
+    }
+    #[inline]
+    pub fn after_flags(mut self, after_flags: ModeFlag) -> Self {
+        self.inner.after_flags = after_flags;
+        self
+    }
+    #[inline]
+    pub fn private(mut self, private: Vec<Card8>) -> Self {
+        self.inner.private = private;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> AddModeLineRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card8> for AddModeLineRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card8>>(&mut self, iter: I) {
+        self.inner.private.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card8> for AddModeLineRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for AddModeLineRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: AddModeLineRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                dotclock: Default::default(),
+                hdisplay: Default::default(),
+                hsyncstart: Default::default(),
+                hsyncend: Default::default(),
+                htotal: Default::default(),
+                hskew: Default::default(),
+                vdisplay: Default::default(),
+                vsyncstart: Default::default(),
+                vsyncend: Default::default(),
+                vtotal: Default::default(),
+                flags: Default::default(),
+                after_dotclock: Default::default(),
+                after_hdisplay: Default::default(),
+                after_hsyncstart: Default::default(),
+                after_hsyncend: Default::default(),
+                after_htotal: Default::default(),
+                after_hskew: Default::default(),
+                after_vdisplay: Default::default(),
+                after_vsyncstart: Default::default(),
+                after_vsyncend: Default::default(),
+                after_vtotal: Default::default(),
+                after_flags: Default::default(),
+                private: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DeleteModeLineRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1466,130 +2839,365 @@ pub struct DeleteModeLineRequest {
     pub flags: ModeFlag,
     pub private: Vec<Card8>,
 }
-impl DeleteModeLineRequest {}
-impl AsByteSequence for DeleteModeLineRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+impl DeleteModeLineRequest {
+    pub fn private_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
         let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += self.dotclock.as_bytes(&mut bytes[index..]);
-        index += self.hdisplay.as_bytes(&mut bytes[index..]);
-        index += self.hsyncstart.as_bytes(&mut bytes[index..]);
-        index += self.hsyncend.as_bytes(&mut bytes[index..]);
-        index += self.htotal.as_bytes(&mut bytes[index..]);
-        index += self.hskew.as_bytes(&mut bytes[index..]);
-        index += self.vdisplay.as_bytes(&mut bytes[index..]);
-        index += self.vsyncstart.as_bytes(&mut bytes[index..]);
-        index += self.vsyncend.as_bytes(&mut bytes[index..]);
-        index += self.vtotal.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 12;
-        index += (self.private.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DeleteModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
-            DeleteModeLineRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-                dotclock: dotclock,
-                hdisplay: hdisplay,
-                hsyncstart: hsyncstart,
-                hsyncend: hsyncend,
-                htotal: htotal,
-                hskew: hskew,
-                vdisplay: vdisplay,
-                vsyncstart: vsyncstart,
-                vsyncend: vsyncend,
-                vtotal: vtotal,
-                flags: flags,
-                private: private,
-            },
-            index,
-        ))
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (private, _): (Cow<'_, [Card8]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "private")?;
+        Ok(private)
+    }
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            screen: self.screen.clone(),
+            dotclock: self.dotclock.clone(),
+            hdisplay: self.hdisplay.clone(),
+            hsyncstart: self.hsyncstart.clone(),
+            hsyncend: self.hsyncend.clone(),
+            htotal: self.htotal.clone(),
+            hskew: self.hskew.clone(),
+            vdisplay: self.vdisplay.clone(),
+            vsyncstart: self.vsyncstart.clone(),
+            vsyncend: self.vsyncend.clone(),
+            vtotal: self.vtotal.clone(),
+            flags: self.flags.clone(),
+            private: self.private.clone(),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + self.dotclock.size()
-            + self.hdisplay.size()
-            + self.hsyncstart.size()
-            + self.hsyncend.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vdisplay.size()
-            + self.vsyncstart.size()
-            + self.vsyncend.size()
-            + self.vtotal.size()
-            + 2
-            + self.flags.size()
-            + 12
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.private.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
+    pub fn builder() -> DeleteModeLineRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("dotclock", 8, 4),
+        ("hdisplay", 12, 2),
+        ("hsyncstart", 14, 2),
+        ("hsyncend", 16, 2),
+        ("htotal", 18, 2),
+        ("hskew", 20, 2),
+        ("vdisplay", 22, 2),
+        ("vsyncstart", 24, 2),
+        ("vsyncend", 26, 2),
+        ("vtotal", 28, 2),
+        ("flags", 32, 4),
+        ("private_len", 48, 4),
+    ];
+}
+const _: () = assert!(<DeleteModeLineRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_request_asb_a8180ab3 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.screen.as_bytes(&mut bytes[index..]);
+                index += self.dotclock.as_bytes(&mut bytes[index..]);
+                index += self.hdisplay.as_bytes(&mut bytes[index..]);
+                index += self.hsyncstart.as_bytes(&mut bytes[index..]);
+                index += self.hsyncend.as_bytes(&mut bytes[index..]);
+                index += self.htotal.as_bytes(&mut bytes[index..]);
+                index += self.hskew.as_bytes(&mut bytes[index..]);
+                index += self.vdisplay.as_bytes(&mut bytes[index..]);
+                index += self.vsyncstart.as_bytes(&mut bytes[index..]);
+                index += self.vsyncend.as_bytes(&mut bytes[index..]);
+                index += self.vtotal.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 2);
+                index += self.flags.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 12);
+                index += (self.private.len() as Card32).as_bytes(&mut bytes[index..]);
+                let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
             }
-    }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
+                index += sz;
+                let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
+                index += sz;
+                let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
+                index += sz;
+                let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
+                index += sz;
+                let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
+                index += sz;
+                let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
+                index += sz;
+                let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
+                index += sz;
+                let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
+                index += sz;
+                let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
+                index += sz;
+                let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
+                index += sz;
+                let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
+                index += sz;
+                index += 2;
+                let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
+                index += sz;
+                index += 12;
+                let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+                index += sz;
+                let (private, block_len): (Vec<Card8>, usize) =
+                    parse_vector(bytes, index, len0 as usize, "private")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        screen: screen,
+                        dotclock: dotclock,
+                        hdisplay: hdisplay,
+                        hsyncstart: hsyncstart,
+                        hsyncend: hsyncend,
+                        htotal: htotal,
+                        hskew: hskew,
+                        vdisplay: vdisplay,
+                        vsyncstart: vsyncstart,
+                        vsyncend: vsyncend,
+                        vtotal: vtotal,
+                        flags: flags,
+                        private: private,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 52 + {
+                    let block_len: usize = self.private.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+            #[inline]
+            fn size_hint(&self) -> usize {
+                let size: usize = 52 + {
+                    let block_len: usize = self.private.len() * ::core::mem::size_of::<Card8>();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_request_asb_a8180ab3! { DeleteModeLineRequest }
 impl Request for DeleteModeLineRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteModeLine";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for DeleteModeLineRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DeleteModeLineRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("screen", &self.screen)
+            .field("dotclock", &self.dotclock)
+            .field("hdisplay", &self.hdisplay)
+            .field("hsyncstart", &self.hsyncstart)
+            .field("hsyncend", &self.hsyncend)
+            .field("htotal", &self.htotal)
+            .field("hskew", &self.hskew)
+            .field("vdisplay", &self.vdisplay)
+            .field("vsyncstart", &self.vsyncstart)
+            .field("vsyncend", &self.vsyncend)
+            .field("vtotal", &self.vtotal)
+            .field("flags", &self.flags)
+            .field("private", &debug_bytes(&self.private, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `DeleteModeLineRequest`."]
+#[derive(Debug)]
+pub struct DeleteModeLineRequestBuilder {
+    pub inner: DeleteModeLineRequest,
+}
+impl DeleteModeLineRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn dotclock(mut self, dotclock: Dotclock) -> Self {
+        self.inner.dotclock = dotclock;
+        self
+    }
+    #[inline]
+    pub fn hdisplay(mut self, hdisplay: Card16) -> Self {
+        self.inner.hdisplay = hdisplay;
+        self
+    }
+    #[inline]
+    pub fn hsyncstart(mut self, hsyncstart: Card16) -> Self {
+        self.inner.hsyncstart = hsyncstart;
+        self
+    }
+    #[inline]
+    pub fn hsyncend(mut self, hsyncend: Card16) -> Self {
+        self.inner.hsyncend = hsyncend;
+        self
+    }
+    #[inline]
+    pub fn htotal(mut self, htotal: Card16) -> Self {
+        self.inner.htotal = htotal;
+        self
+    }
+    #[inline]
+    pub fn hskew(mut self, hskew: Card16) -> Self {
+        self.inner.hskew = hskew;
+        self
+    }
+    #[inline]
+    pub fn vdisplay(mut self, vdisplay: Card16) -> Self {
+        self.inner.vdisplay = vdisplay;
+        self
+    }
+    #[inline]
+    pub fn vsyncstart(mut self, vsyncstart: Card16) -> Self {
+        self.inner.vsyncstart = vsyncstart;
+        self
+    }
+    #[inline]
+    pub fn vsyncend(mut self, vsyncend: Card16) -> Self {
+        self.inner.vsyncend = vsyncend;
+        self
+    }
+    #[inline]
+    pub fn vtotal(mut self, vtotal: Card16) -> Self {
+        self.inner.vtotal = vtotal;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: ModeFlag) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn private(mut self, private: Vec<Card8>) -> Self {
+        self.inner.private = private;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DeleteModeLineRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card8> for DeleteModeLineRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card8>>(&mut self, iter: I) {
+        self.inner.private.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card8> for DeleteModeLineRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for DeleteModeLineRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DeleteModeLineRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                dotclock: Default::default(),
+                hdisplay: Default::default(),
+                hsyncstart: Default::default(),
+                hsyncend: Default::default(),
+                htotal: Default::default(),
+                hskew: Default::default(),
+                vdisplay: Default::default(),
+                vsyncstart: Default::default(),
+                vsyncend: Default::default(),
+                vtotal: Default::default(),
+                flags: Default::default(),
+                private: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ValidateModeLineRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1607,164 +3215,337 @@ pub struct ValidateModeLineRequest {
     pub flags: ModeFlag,
     pub private: Vec<Card8>,
 }
-impl ValidateModeLineRequest {}
-impl AsByteSequence for ValidateModeLineRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += self.dotclock.as_bytes(&mut bytes[index..]);
-        index += self.hdisplay.as_bytes(&mut bytes[index..]);
-        index += self.hsyncstart.as_bytes(&mut bytes[index..]);
-        index += self.hsyncend.as_bytes(&mut bytes[index..]);
-        index += self.htotal.as_bytes(&mut bytes[index..]);
-        index += self.hskew.as_bytes(&mut bytes[index..]);
-        index += self.vdisplay.as_bytes(&mut bytes[index..]);
-        index += self.vsyncstart.as_bytes(&mut bytes[index..]);
-        index += self.vsyncend.as_bytes(&mut bytes[index..]);
-        index += self.vtotal.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 12;
-        index += (self.private.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+impl ValidateModeLineRequest {
+    pub fn private_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing ValidateModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
-            ValidateModeLineRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-                dotclock: dotclock,
-                hdisplay: hdisplay,
-                hsyncstart: hsyncstart,
-                hsyncend: hsyncend,
-                htotal: htotal,
-                hskew: hskew,
-                vdisplay: vdisplay,
-                vsyncstart: vsyncstart,
-                vsyncend: vsyncend,
-                vtotal: vtotal,
-                flags: flags,
-                private: private,
-            },
-            index,
-        ))
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (private, _): (Cow<'_, [Card8]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "private")?;
+        Ok(private)
+    }
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            screen: self.screen.clone(),
+            dotclock: self.dotclock.clone(),
+            hdisplay: self.hdisplay.clone(),
+            hsyncstart: self.hsyncstart.clone(),
+            hsyncend: self.hsyncend.clone(),
+            htotal: self.htotal.clone(),
+            hskew: self.hskew.clone(),
+            vdisplay: self.vdisplay.clone(),
+            vsyncstart: self.vsyncstart.clone(),
+            vsyncend: self.vsyncend.clone(),
+            vtotal: self.vtotal.clone(),
+            flags: self.flags.clone(),
+            private: self.private.clone(),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + self.dotclock.size()
-            + self.hdisplay.size()
-            + self.hsyncstart.size()
-            + self.hsyncend.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vdisplay.size()
-            + self.vsyncstart.size()
-            + self.vsyncend.size()
-            + self.vtotal.size()
-            + 2
-            + self.flags.size()
-            + 12
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.private.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-    }
+    pub fn builder() -> ValidateModeLineRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ValidateModeLineReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("dotclock", 8, 4),
+        ("hdisplay", 12, 2),
+        ("hsyncstart", 14, 2),
+        ("hsyncend", 16, 2),
+        ("htotal", 18, 2),
+        ("hskew", 20, 2),
+        ("vdisplay", 22, 2),
+        ("vsyncstart", 24, 2),
+        ("vsyncend", 26, 2),
+        ("vtotal", 28, 2),
+        ("flags", 32, 4),
+        ("private_len", 48, 4),
+    ];
 }
+const _: () = assert!(<ValidateModeLineRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_a8180ab3! { ValidateModeLineRequest }
 impl Request for ValidateModeLineRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ValidateModeLine";
     type Reply = ValidateModeLineReply;
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for ValidateModeLineRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ValidateModeLineRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("screen", &self.screen)
+            .field("dotclock", &self.dotclock)
+            .field("hdisplay", &self.hdisplay)
+            .field("hsyncstart", &self.hsyncstart)
+            .field("hsyncend", &self.hsyncend)
+            .field("htotal", &self.htotal)
+            .field("hskew", &self.hskew)
+            .field("vdisplay", &self.vdisplay)
+            .field("vsyncstart", &self.vsyncstart)
+            .field("vsyncend", &self.vsyncend)
+            .field("vtotal", &self.vtotal)
+            .field("flags", &self.flags)
+            .field("private", &debug_bytes(&self.private, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `ValidateModeLineRequest`."]
+#[derive(Debug)]
+pub struct ValidateModeLineRequestBuilder {
+    pub inner: ValidateModeLineRequest,
+}
+impl ValidateModeLineRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn dotclock(mut self, dotclock: Dotclock) -> Self {
+        self.inner.dotclock = dotclock;
+        self
+    }
+    #[inline]
+    pub fn hdisplay(mut self, hdisplay: Card16) -> Self {
+        self.inner.hdisplay = hdisplay;
+        self
+    }
+    #[inline]
+    pub fn hsyncstart(mut self, hsyncstart: Card16) -> Self {
+        self.inner.hsyncstart = hsyncstart;
+        self
+    }
+    #[inline]
+    pub fn hsyncend(mut self, hsyncend: Card16) -> Self {
+        self.inner.hsyncend = hsyncend;
+        self
+    }
+    #[inline]
+    pub fn htotal(mut self, htotal: Card16) -> Self {
+        self.inner.htotal = htotal;
+        self
+    }
+    #[inline]
+    pub fn hskew(mut self, hskew: Card16) -> Self {
+        self.inner.hskew = hskew;
+        self
+    }
+    #[inline]
+    pub fn vdisplay(mut self, vdisplay: Card16) -> Self {
+        self.inner.vdisplay = vdisplay;
+        self
+    }
+    #[inline]
+    pub fn vsyncstart(mut self, vsyncstart: Card16) -> Self {
+        self.inner.vsyncstart = vsyncstart;
+        self
+    }
+    #[inline]
+    pub fn vsyncend(mut self, vsyncend: Card16) -> Self {
+        self.inner.vsyncend = vsyncend;
+        self
+    }
+    #[inline]
+    pub fn vtotal(mut self, vtotal: Card16) -> Self {
+        self.inner.vtotal = vtotal;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: ModeFlag) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn private(mut self, private: Vec<Card8>) -> Self {
+        self.inner.private = private;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ValidateModeLineRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card8> for ValidateModeLineRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card8>>(&mut self, iter: I) {
+        self.inner.private.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card8> for ValidateModeLineRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for ValidateModeLineRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ValidateModeLineRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                dotclock: Default::default(),
+                hdisplay: Default::default(),
+                hsyncstart: Default::default(),
+                hsyncend: Default::default(),
+                htotal: Default::default(),
+                hskew: Default::default(),
+                vdisplay: Default::default(),
+                vsyncstart: Default::default(),
+                vsyncend: Default::default(),
+                vtotal: Default::default(),
+                flags: Default::default(),
+                private: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ValidateModeLineReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub status: Card32,
 }
-impl ValidateModeLineReply {}
-impl AsByteSequence for ValidateModeLineReply {
+impl ValidateModeLineReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let status: Card32 = read_item(reader, &mut index, 4, "status")?;
+        skip_bytes(reader, &mut index, 20)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(ValidateModeLineReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            status: status,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("status", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for ValidateModeLineReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.status.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ValidateModeLineReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (status, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (status, sz): (Card32, usize) = parse_item(bytes, index, "status")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ValidateModeLineReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1776,15 +3557,21 @@ impl AsByteSequence for ValidateModeLineReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.status.size()
-            + 20
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ValidateModeLineReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SwitchToModeRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1802,180 +3589,309 @@ pub struct SwitchToModeRequest {
     pub flags: ModeFlag,
     pub private: Vec<Card8>,
 }
-impl SwitchToModeRequest {}
-impl AsByteSequence for SwitchToModeRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += self.dotclock.as_bytes(&mut bytes[index..]);
-        index += self.hdisplay.as_bytes(&mut bytes[index..]);
-        index += self.hsyncstart.as_bytes(&mut bytes[index..]);
-        index += self.hsyncend.as_bytes(&mut bytes[index..]);
-        index += self.htotal.as_bytes(&mut bytes[index..]);
-        index += self.hskew.as_bytes(&mut bytes[index..]);
-        index += self.vdisplay.as_bytes(&mut bytes[index..]);
-        index += self.vsyncstart.as_bytes(&mut bytes[index..]);
-        index += self.vsyncend.as_bytes(&mut bytes[index..]);
-        index += self.vtotal.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 12;
-        index += (self.private.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+impl SwitchToModeRequest {
+    pub fn private_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing SwitchToModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = parse_item(bytes, index, "dotclock")?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = parse_item(bytes, index, "hdisplay")?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "hsyncstart")?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = parse_item(bytes, index, "hsyncend")?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = parse_item(bytes, index, "htotal")?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = parse_item(bytes, index, "hskew")?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = parse_item(bytes, index, "vdisplay")?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = parse_item(bytes, index, "vsyncstart")?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = parse_item(bytes, index, "vsyncend")?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = parse_item(bytes, index, "vtotal")?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
-            SwitchToModeRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-                dotclock: dotclock,
-                hdisplay: hdisplay,
-                hsyncstart: hsyncstart,
-                hsyncend: hsyncend,
-                htotal: htotal,
-                hskew: hskew,
-                vdisplay: vdisplay,
-                vsyncstart: vsyncstart,
-                vsyncend: vsyncend,
-                vtotal: vtotal,
-                flags: flags,
-                private: private,
-            },
-            index,
-        ))
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (private, _): (Cow<'_, [Card8]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "private")?;
+        Ok(private)
+    }
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            screen: self.screen.clone(),
+            dotclock: self.dotclock.clone(),
+            hdisplay: self.hdisplay.clone(),
+            hsyncstart: self.hsyncstart.clone(),
+            hsyncend: self.hsyncend.clone(),
+            htotal: self.htotal.clone(),
+            hskew: self.hskew.clone(),
+            vdisplay: self.vdisplay.clone(),
+            vsyncstart: self.vsyncstart.clone(),
+            vsyncend: self.vsyncend.clone(),
+            vtotal: self.vtotal.clone(),
+            flags: self.flags.clone(),
+            private: self.private.clone(),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + self.dotclock.size()
-            + self.hdisplay.size()
-            + self.hsyncstart.size()
-            + self.hsyncend.size()
-            + self.htotal.size()
-            + self.hskew.size()
-            + self.vdisplay.size()
-            + self.vsyncstart.size()
-            + self.vsyncend.size()
-            + self.vtotal.size()
-            + 2
-            + self.flags.size()
-            + 12
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.private.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-    }
+    pub fn builder() -> SwitchToModeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 4),
+        ("dotclock", 8, 4),
+        ("hdisplay", 12, 2),
+        ("hsyncstart", 14, 2),
+        ("hsyncend", 16, 2),
+        ("htotal", 18, 2),
+        ("hskew", 20, 2),
+        ("vdisplay", 22, 2),
+        ("vsyncstart", 24, 2),
+        ("vsyncend", 26, 2),
+        ("vtotal", 28, 2),
+        ("flags", 32, 4),
+        ("private_len", 48, 4),
+    ];
 }
+const _: () = assert!(<SwitchToModeRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_a8180ab3! { SwitchToModeRequest }
 impl Request for SwitchToModeRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwitchToMode";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for SwitchToModeRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SwitchToModeRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("screen", &self.screen)
+            .field("dotclock", &self.dotclock)
+            .field("hdisplay", &self.hdisplay)
+            .field("hsyncstart", &self.hsyncstart)
+            .field("hsyncend", &self.hsyncend)
+            .field("htotal", &self.htotal)
+            .field("hskew", &self.hskew)
+            .field("vdisplay", &self.vdisplay)
+            .field("vsyncstart", &self.vsyncstart)
+            .field("vsyncend", &self.vsyncend)
+            .field("vtotal", &self.vtotal)
+            .field("flags", &self.flags)
+            .field("private", &debug_bytes(&self.private, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `SwitchToModeRequest`."]
+#[derive(Debug)]
+pub struct SwitchToModeRequestBuilder {
+    pub inner: SwitchToModeRequest,
+}
+impl SwitchToModeRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card32) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn dotclock(mut self, dotclock: Dotclock) -> Self {
+        self.inner.dotclock = dotclock;
+        self
+    }
+    #[inline]
+    pub fn hdisplay(mut self, hdisplay: Card16) -> Self {
+        self.inner.hdisplay = hdisplay;
+        self
+    }
+    #[inline]
+    pub fn hsyncstart(mut self, hsyncstart: Card16) -> Self {
+        self.inner.hsyncstart = hsyncstart;
+        self
+    }
+    #[inline]
+    pub fn hsyncend(mut self, hsyncend: Card16) -> Self {
+        self.inner.hsyncend = hsyncend;
+        self
+    }
+    #[inline]
+    pub fn htotal(mut self, htotal: Card16) -> Self {
+        self.inner.htotal = htotal;
+        self
+    }
+    #[inline]
+    pub fn hskew(mut self, hskew: Card16) -> Self {
+        self.inner.hskew = hskew;
+        self
+    }
+    #[inline]
+    pub fn vdisplay(mut self, vdisplay: Card16) -> Self {
+        self.inner.vdisplay = vdisplay;
+        self
+    }
+    #[inline]
+    pub fn vsyncstart(mut self, vsyncstart: Card16) -> Self {
+        self.inner.vsyncstart = vsyncstart;
+        self
+    }
+    #[inline]
+    pub fn vsyncend(mut self, vsyncend: Card16) -> Self {
+        self.inner.vsyncend = vsyncend;
+        self
+    }
+    #[inline]
+    pub fn vtotal(mut self, vtotal: Card16) -> Self {
+        self.inner.vtotal = vtotal;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: ModeFlag) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn private(mut self, private: Vec<Card8>) -> Self {
+        self.inner.private = private;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SwitchToModeRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card8> for SwitchToModeRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card8>>(&mut self, iter: I) {
+        self.inner.private.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card8> for SwitchToModeRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for SwitchToModeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SwitchToModeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                dotclock: Default::default(),
+                hdisplay: Default::default(),
+                hsyncstart: Default::default(),
+                hsyncend: Default::default(),
+                htotal: Default::default(),
+                hskew: Default::default(),
+                vdisplay: Default::default(),
+                vsyncstart: Default::default(),
+                vsyncend: Default::default(),
+                vtotal: Default::default(),
+                flags: Default::default(),
+                private: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetViewPortRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetViewPortRequest {}
-impl AsByteSequence for GetViewPortRequest {
+impl GetViewPortRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetViewPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetViewPortRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetViewPortRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetViewPortReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
 }
+const _: () = assert!(<GetViewPortRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetViewPortRequest }
 impl Request for GetViewPortRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetViewPort";
     type Reply = GetViewPortReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetViewPortRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetViewPortRequestBuilder {
+    pub inner: GetViewPortRequest,
+}
+impl GetViewPortRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetViewPortRequest {
+        self.inner
+    }
+}
+impl Default for GetViewPortRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetViewPortRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetViewPortReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1983,37 +3899,98 @@ pub struct GetViewPortReply {
     pub x: Card32,
     pub y: Card32,
 }
-impl GetViewPortReply {}
-impl AsByteSequence for GetViewPortReply {
+impl GetViewPortReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let x: Card32 = read_item(reader, &mut index, 4, "x")?;
+        let y: Card32 = read_item(reader, &mut index, 4, "y")?;
+        skip_bytes(reader, &mut index, 16)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetViewPortReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            x: x,
+            y: y,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("x", 8, 4),
+        ("y", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("x", &self.x, &other.x, &mut changes);
+        diff_value("y", &self.y, &other.y, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetViewPortReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.x.as_bytes(&mut bytes[index..]);
         index += self.y.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetViewPortReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (x, sz): (Card32, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card32, usize) = parse_item(bytes, index, "y")?;
         index += sz;
         index += 16;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetViewPortReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2026,16 +4003,21 @@ impl AsByteSequence for GetViewPortReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.x.size()
-            + self.y.size()
-            + 16
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetViewPortReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetViewPortRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2043,37 +4025,57 @@ pub struct SetViewPortRequest {
     pub x: Card32,
     pub y: Card32,
 }
-impl SetViewPortRequest {}
-impl AsByteSequence for SetViewPortRequest {
+impl SetViewPortRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> SetViewPortRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 2),
+        ("x", 8, 4),
+        ("y", 12, 4),
+    ];
+}
+const _: () = assert!(<SetViewPortRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetViewPortRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.x.as_bytes(&mut bytes[index..]);
         index += self.y.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetViewPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
         index += 2;
-        let (x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card32, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card32, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetViewPortRequest {
                 req_type: req_type,
                 length: length,
@@ -2086,72 +4088,140 @@ impl AsByteSequence for SetViewPortRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + 2
-            + self.x.size()
-            + self.y.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetViewPortRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Request for SetViewPortRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 12;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetViewPort";
+    type Reply = ();
+}
+#[doc = " A builder for `SetViewPortRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetViewPortRequestBuilder {
+    pub inner: SetViewPortRequest,
+}
+impl SetViewPortRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn x(mut self, x: Card32) -> Self {
+        self.inner.x = x;
+        self
+    }
+    #[inline]
+    pub fn y(mut self, y: Card32) -> Self {
+        self.inner.y = y;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetViewPortRequest {
+        self.inner
+    }
+}
+impl Default for SetViewPortRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetViewPortRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                x: Default::default(),
+                y: Default::default(),
+            },
+        }
     }
 }
-impl Request for SetViewPortRequest {
-    const OPCODE: u8 = 12;
-    const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
-}
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetDotClocksRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetDotClocksRequest {}
-impl AsByteSequence for GetDotClocksRequest {
+impl GetDotClocksRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetDotClocksRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetDotClocksRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetDotClocksRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetDotClocksReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
 }
+const _: () = assert!(<GetDotClocksRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetDotClocksRequest }
 impl Request for GetDotClocksRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDotClocks";
     type Reply = GetDotClocksReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetDotClocksRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetDotClocksRequestBuilder {
+    pub inner: GetDotClocksRequest,
+}
+impl GetDotClocksRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetDotClocksRequest {
+        self.inner
+    }
+}
+impl Default for GetDotClocksRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetDotClocksRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetDotClocksReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2161,49 +4231,155 @@ pub struct GetDotClocksReply {
     pub maxclocks: Card32,
     pub clock: Vec<Card32>,
 }
-impl GetDotClocksReply {}
-impl AsByteSequence for GetDotClocksReply {
+impl GetDotClocksReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn clock_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (flags, sz): (ClockFlag, usize) = parse_item(bytes, index, "flags")?;
+        index += sz;
+        let (clocks, sz): (Card32, usize) = parse_item(bytes, index, "clocks")?;
+        index += sz;
+        let (maxclocks, sz): (Card32, usize) = parse_item(bytes, index, "maxclocks")?;
+        index += sz;
+        index += 12;
+        let (clock, _): (Cow<'_, [Card32]>, usize) = parse_slice(
+            bytes,
+            index,
+            (((1) - ((flags as usize) & (1))) * (clocks as usize)) as usize,
+            "clock",
+        )?;
+        Ok(clock)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let flags: ClockFlag = read_item(reader, &mut index, 4, "flags")?;
+        let clocks: Card32 = read_item(reader, &mut index, 4, "clocks")?;
+        let maxclocks: Card32 = read_item(reader, &mut index, 4, "maxclocks")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let (clock, block_len): (Vec<Card32>, usize) = read_vector(
+            reader,
+            &mut index,
+            (((1) - ((flags as usize) & (1))) * (clocks as usize)) as usize,
+            4,
+            "clock",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetDotClocksReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            flags: flags,
+            clocks: clocks,
+            maxclocks: maxclocks,
+            clock: clock,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("flags", 8, 4),
+        ("clocks", 12, 4),
+        ("maxclocks", 16, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.flags == other.flags
+            && self.clocks == other.clocks
+            && self.maxclocks == other.maxclocks
+            && self.clock == other.clock
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("flags", &self.flags, &other.flags, &mut changes);
+        diff_value("clocks", &self.clocks, &other.clocks, &mut changes);
+        diff_value("maxclocks", &self.maxclocks, &other.maxclocks, &mut changes);
+        diff_list("clock", &self.clock, &other.clock, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetDotClocksReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.flags.as_bytes(&mut bytes[index..]);
         index += self.clocks.as_bytes(&mut bytes[index..]);
         index += self.maxclocks.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         let block_len: usize = vector_as_bytes(&self.clock, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDotClocksReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (flags, sz): (ClockFlag, usize) = <ClockFlag>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (flags, sz): (ClockFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (clocks, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (clocks, sz): (Card32, usize) = parse_item(bytes, index, "clocks")?;
         index += sz;
-        let (maxclocks, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (maxclocks, sz): (Card32, usize) = parse_item(bytes, index, "maxclocks")?;
         index += sz;
         index += 12;
-        let (clock, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (clock, block_len): (Vec<Card32>, usize) = parse_vector(
+            bytes,
+            index,
             (((1) - ((flags as usize) & (1))) * (clocks as usize)) as usize,
+            "clock",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetDotClocksReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2218,55 +4394,86 @@ impl AsByteSequence for GetDotClocksReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.flags.size()
-            + self.clocks.size()
-            + self.maxclocks.size()
-            + 12
-            + {
-                let block_len: usize = self.clock.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.clock.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 32 + {
+            let block_len: usize = self.clock.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetDotClocksReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 pub const CLOCK_FLAG_PROGRAMABLE: ClockFlag = 1;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetClientVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub major: Card16,
     pub minor: Card16,
 }
-impl SetClientVersionRequest {}
-impl AsByteSequence for SetClientVersionRequest {
+impl SetClientVersionRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> SetClientVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("major", 4, 2),
+        ("minor", 6, 2),
+    ];
+}
+const _: () = assert!(<SetClientVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetClientVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major.as_bytes(&mut bytes[index..]);
         index += self.minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetClientVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major, sz): (Card16, usize) = parse_item(bytes, index, "major")?;
         index += sz;
-        let (minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Card16, usize) = parse_item(bytes, index, "minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetClientVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -2278,16 +4485,64 @@ impl AsByteSequence for SetClientVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.major.size() + self.minor.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetClientVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetClientVersionRequest {
-    const OPCODE: u8 = 14;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 14;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetClientVersion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetClientVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetClientVersionRequestBuilder {
+    pub inner: SetClientVersionRequest,
+}
+impl SetClientVersionRequestBuilder {
+    #[inline]
+    pub fn major(mut self, major: Card16) -> Self {
+        self.inner.major = major;
+        self
+    }
+    #[inline]
+    pub fn minor(mut self, minor: Card16) -> Self {
+        self.inner.minor = minor;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetClientVersionRequest {
+        self.inner
+    }
+}
+impl Default for SetClientVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetClientVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                major: Default::default(),
+                minor: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetGammaRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2296,42 +4551,63 @@ pub struct SetGammaRequest {
     pub green: Card32,
     pub blue: Card32,
 }
-impl SetGammaRequest {}
+impl SetGammaRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> SetGammaRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 2),
+        ("red", 8, 4),
+        ("green", 12, 4),
+        ("blue", 16, 4),
+    ];
+}
+const _: () = assert!(<SetGammaRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for SetGammaRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.red.as_bytes(&mut bytes[index..]);
         index += self.green.as_bytes(&mut bytes[index..]);
         index += self.blue.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
         index += 2;
-        let (red, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card32, usize) = parse_item(bytes, index, "red")?;
         index += sz;
-        let (green, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card32, usize) = parse_item(bytes, index, "green")?;
         index += sz;
-        let (blue, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card32, usize) = parse_item(bytes, index, "blue")?;
         index += sz;
         index += 12;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetGammaRequest {
                 req_type: req_type,
                 length: length,
@@ -2345,54 +4621,127 @@ impl AsByteSequence for SetGammaRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + 2
-            + self.red.size()
-            + self.green.size()
-            + self.blue.size()
-            + 12
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetGammaRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetGammaRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetGamma";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetGammaRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetGammaRequestBuilder {
+    pub inner: SetGammaRequest,
+}
+impl SetGammaRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn red(mut self, red: Card32) -> Self {
+        self.inner.red = red;
+        self
+    }
+    #[inline]
+    pub fn green(mut self, green: Card32) -> Self {
+        self.inner.green = green;
+        self
+    }
+    #[inline]
+    pub fn blue(mut self, blue: Card32) -> Self {
+        self.inner.blue = blue;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetGammaRequest {
+        self.inner
+    }
+}
+impl Default for SetGammaRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetGammaRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                red: Default::default(),
+                green: Default::default(),
+                blue: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetGammaRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetGammaRequest {}
-impl AsByteSequence for GetGammaRequest {
+impl GetGammaRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> GetGammaRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GetGammaReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
+}
+const _: () = assert!(<GetGammaRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetGammaRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 26;
+        index += zero_pad(&mut bytes[index..], 26);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
         index += 26;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetGammaRequest {
                 req_type: req_type,
                 length: length,
@@ -2403,16 +4752,57 @@ impl AsByteSequence for GetGammaRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 26
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetGammaRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetGammaRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetGamma";
     type Reply = GetGammaReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetGammaRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetGammaRequestBuilder {
+    pub inner: GetGammaRequest,
+}
+impl GetGammaRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetGammaRequest {
+        self.inner
+    }
+}
+impl Default for GetGammaRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetGammaRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetGammaReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2421,40 +4811,105 @@ pub struct GetGammaReply {
     pub green: Card32,
     pub blue: Card32,
 }
-impl GetGammaReply {}
-impl AsByteSequence for GetGammaReply {
+impl GetGammaReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let red: Card32 = read_item(reader, &mut index, 4, "red")?;
+        let green: Card32 = read_item(reader, &mut index, 4, "green")?;
+        let blue: Card32 = read_item(reader, &mut index, 4, "blue")?;
+        skip_bytes(reader, &mut index, 12)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetGammaReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            red: red,
+            green: green,
+            blue: blue,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("red", 8, 4),
+        ("green", 12, 4),
+        ("blue", 16, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.red == other.red && self.green == other.green && self.blue == other.blue
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("red", &self.red, &other.red, &mut changes);
+        diff_value("green", &self.green, &other.green, &mut changes);
+        diff_value("blue", &self.blue, &other.blue, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetGammaReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.red.as_bytes(&mut bytes[index..]);
         index += self.green.as_bytes(&mut bytes[index..]);
         index += self.blue.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (red, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (red, sz): (Card32, usize) = parse_item(bytes, index, "red")?;
         index += sz;
-        let (green, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card32, usize) = parse_item(bytes, index, "green")?;
         index += sz;
-        let (blue, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card32, usize) = parse_item(bytes, index, "blue")?;
         index += sz;
         index += 12;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetGammaReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2468,49 +4923,80 @@ impl AsByteSequence for GetGammaReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.red.size()
-            + self.green.size()
-            + self.blue.size()
-            + 12
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetGammaReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetGammaRampRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
     pub size: Card16,
 }
-impl GetGammaRampRequest {}
-impl AsByteSequence for GetGammaRampRequest {
+impl GetGammaRampRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> GetGammaRampRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetGammaRampReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 2),
+        ("size", 6, 2),
+    ];
+}
+const _: () = assert!(<GetGammaRampRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetGammaRampRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRampRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetGammaRampRequest {
                 req_type: req_type,
                 length: length,
@@ -2518,78 +5004,328 @@ impl AsByteSequence for GetGammaRampRequest {
                 size: size,
             },
             index,
-        ))
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetGammaRampRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Request for GetGammaRampRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 17;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetGammaRamp";
+    type Reply = GetGammaRampReply;
+}
+#[doc = " A builder for `GetGammaRampRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetGammaRampRequestBuilder {
+    pub inner: GetGammaRampRequest,
+}
+impl GetGammaRampRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn size(mut self, size: Card16) -> Self {
+        self.inner.size = size;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetGammaRampRequest {
+        self.inner
+    }
+}
+impl Default for GetGammaRampRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetGammaRampRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                size: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct GetGammaRampReply {
+    pub reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub size: Card16,
+    pub red: Vec<Card16>,
+    pub green: Vec<Card16>,
+    pub blue: Vec<Card16>,
+}
+impl GetGammaRampReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn red_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 22;
+        let (red, _): (Cow<'_, [Card16]>, usize) = parse_slice(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
+        Ok(red)
+    }
+    pub fn green_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 22;
+        let (red, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, _): (Cow<'_, [Card16]>, usize) = parse_slice(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "green",
+        )?;
+        Ok(green)
+    }
+    pub fn blue_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        index += 22;
+        let (red, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "green",
+        )?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (blue, _): (Cow<'_, [Card16]>, usize) = parse_slice(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "blue",
+        )?;
+        Ok(blue)
     }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + self.size.size()
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let size: Card16 = read_item(reader, &mut index, 2, "size")?;
+        skip_bytes(reader, &mut index, 22)?;
+        let (red, block_len): (Vec<Card16>, usize) = read_vector(
+            reader,
+            &mut index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            2,
+            "red",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        )?;
+        let (green, block_len): (Vec<Card16>, usize) = read_vector(
+            reader,
+            &mut index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            2,
+            "green",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        )?;
+        let (blue, block_len): (Vec<Card16>, usize) = read_vector(
+            reader,
+            &mut index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            2,
+            "blue",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetGammaRampReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            size: size,
+            red: red,
+            green: green,
+            blue: blue,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("size", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.red == other.red
+            && self.green == other.green
+            && self.blue == other.blue
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("size", &self.size, &other.size, &mut changes);
+        diff_list("red", &self.red, &other.red, &mut changes);
+        diff_list("green", &self.green, &other.green, &mut changes);
+        diff_list("blue", &self.blue, &other.blue, &mut changes);
+        changes
     }
 }
-impl Request for GetGammaRampRequest {
-    const OPCODE: u8 = 17;
-    const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = GetGammaRampReply;
-}
-#[derive(Clone, Debug, Default)]
-pub struct GetGammaRampReply {
-    pub reply_type: u8,
-    pub sequence: u16,
-    pub length: u32,
-    pub size: Card16,
-    pub red: Vec<Card16>,
-    pub green: Vec<Card16>,
-    pub blue: Vec<Card16>,
-}
-impl GetGammaRampReply {}
 impl AsByteSequence for GetGammaRampReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
-        index += 22;
+        index += zero_pad(&mut bytes[index..], 22);
         let block_len: usize = vector_as_bytes(&self.red, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.green, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.blue, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRampReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
         index += sz;
         index += 22;
-        let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (red, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (green, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "green",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (blue, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "blue",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetGammaRampReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2602,32 +5338,73 @@ impl AsByteSequence for GetGammaRampReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.size.size()
-            + 22
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.red.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.green.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.blue.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            32 + {
+                let block_len: usize = self.red.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.green.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.blue.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetGammaRampReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetGammaRampReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let size: Card16 = u.arbitrary()?;
+        let red = arbitrary_list(u, (((size as usize) + (1)) & (!(1))) as usize)?;
+        let green = arbitrary_list(u, (((size as usize) + (1)) & (!(1))) as usize)?;
+        let blue = arbitrary_list(u, (((size as usize) + (1)) & (!(1))) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            size: size,
+            red: red,
+            green: green,
+            blue: blue,
+        })
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetGammaRampRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2637,53 +5414,203 @@ pub struct SetGammaRampRequest {
     pub green: Vec<Card16>,
     pub blue: Vec<Card16>,
 }
-impl SetGammaRampRequest {}
+impl SetGammaRampRequest {
+    pub fn red_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
+        index += sz;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        let (red, _): (Cow<'_, [Card16]>, usize) = parse_slice(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
+        Ok(red)
+    }
+    pub fn green_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
+        index += sz;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        let (red, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, _): (Cow<'_, [Card16]>, usize) = parse_slice(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "green",
+        )?;
+        Ok(green)
+    }
+    pub fn blue_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
+        index += sz;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
+        index += sz;
+        let (red, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (green, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "green",
+        )?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        let (blue, _): (Cow<'_, [Card16]>, usize) = parse_slice(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "blue",
+        )?;
+        Ok(blue)
+    }
+    #[inline]
+    pub fn builder() -> SetGammaRampRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("screen", 4, 2),
+        ("size", 6, 2),
+    ];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize = (((self.size as usize) + (1)) & (!(1))) as usize;
+            if self.red.len() != expected {
+                return Err(ValidationError {
+                    list: "red",
+                    expected,
+                    found: self.red.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (((self.size as usize) + (1)) & (!(1))) as usize;
+            if self.green.len() != expected {
+                return Err(ValidationError {
+                    list: "green",
+                    expected,
+                    found: self.green.len(),
+                });
+            }
+        }
+        {
+            let expected: usize = (((self.size as usize) + (1)) & (!(1))) as usize;
+            if self.blue.len() != expected {
+                return Err(ValidationError {
+                    list: "blue",
+                    expected,
+                    found: self.blue.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<SetGammaRampRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for SetGammaRampRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.screen.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.red, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.green, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
         let block_len: usize = vector_as_bytes(&self.blue, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetGammaRampRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = parse_item(bytes, index, "screen")?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
         index += sz;
-        let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (red, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "red",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (green, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "green",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (blue, block_len): (Vec<Card16>, usize) = parse_vector(
+            bytes,
+            index,
+            (((size as usize) + (1)) & (!(1))) as usize,
+            "blue",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetGammaRampRequest {
                 req_type: req_type,
                 length: length,
@@ -2696,121 +5623,300 @@ impl AsByteSequence for SetGammaRampRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.screen.size()
-            + self.size.size()
-            + {
+        let size: usize =
+            8 + {
                 let block_len: usize = self.red.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.green.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.blue.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            8 + {
+                let block_len: usize = self.red.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.green.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.blue.len() * ::core::mem::size_of::<Card16>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetGammaRampRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetGammaRampRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetGammaRamp";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for SetGammaRampRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let screen: Card16 = u.arbitrary()?;
+        let size: Card16 = u.arbitrary()?;
+        let red = arbitrary_list(u, (((size as usize) + (1)) & (!(1))) as usize)?;
+        let green = arbitrary_list(u, (((size as usize) + (1)) & (!(1))) as usize)?;
+        let blue = arbitrary_list(u, (((size as usize) + (1)) & (!(1))) as usize)?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            screen: screen,
+            size: size,
+            red: red,
+            green: green,
+            blue: blue,
+        })
+    }
+}
+#[doc = " A builder for `SetGammaRampRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetGammaRampRequestBuilder {
+    pub inner: SetGammaRampRequest,
+}
+impl SetGammaRampRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    pub fn size(mut self, size: Card16) -> Self {
+        self.inner.size = size;
+        self
+    }
+    #[inline]
+    pub fn red(mut self, red: Vec<Card16>) -> Self {
+        self.inner.red = red;
+        self
+    }
+    #[inline]
+    pub fn green(mut self, green: Vec<Card16>) -> Self {
+        self.inner.green = green;
+        self
+    }
+    #[inline]
+    pub fn blue(mut self, blue: Vec<Card16>) -> Self {
+        self.inner.blue = blue;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetGammaRampRequest {
+        self.inner
+    }
+}
+impl Default for SetGammaRampRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetGammaRampRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+                size: Default::default(),
+                red: Default::default(),
+                green: Default::default(),
+                blue: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetGammaRampSizeRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetGammaRampSizeRequest {}
-impl AsByteSequence for GetGammaRampSizeRequest {
+impl GetGammaRampSizeRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetGammaRampSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetGammaRampSizeRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetGammaRampSizeRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetGammaRampSizeReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
 }
+const _: () = assert!(<GetGammaRampSizeRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetGammaRampSizeRequest }
 impl Request for GetGammaRampSizeRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetGammaRampSize";
     type Reply = GetGammaRampSizeReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetGammaRampSizeRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetGammaRampSizeRequestBuilder {
+    pub inner: GetGammaRampSizeRequest,
+}
+impl GetGammaRampSizeRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetGammaRampSizeRequest {
+        self.inner
+    }
+}
+impl Default for GetGammaRampSizeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetGammaRampSizeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetGammaRampSizeReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub size: Card16,
 }
-impl GetGammaRampSizeReply {}
-impl AsByteSequence for GetGammaRampSizeReply {
+impl GetGammaRampSizeReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let size: Card16 = read_item(reader, &mut index, 2, "size")?;
+        skip_bytes(reader, &mut index, 22)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetGammaRampSizeReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            size: size,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("size", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.size == other.size
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("size", &self.size, &other.size, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetGammaRampSizeReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.size.as_bytes(&mut bytes[index..]);
-        index += 22;
+        index += zero_pad(&mut bytes[index..], 22);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRampSizeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (size, sz): (Card16, usize) = parse_item(bytes, index, "size")?;
         index += sz;
         index += 22;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetGammaRampSizeReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2822,99 +5928,181 @@ impl AsByteSequence for GetGammaRampSizeReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.size.size()
-            + 22
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetGammaRampSizeReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPermissionsRequest {
     pub req_type: u8,
     pub length: u16,
     pub screen: Card16,
 }
-impl GetPermissionsRequest {}
-impl AsByteSequence for GetPermissionsRequest {
+impl GetPermissionsRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.screen.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetPermissionsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetPermissionsRequest {
-                req_type: req_type,
-                length: length,
-                screen: screen,
-            },
-            index,
-        ))
+    pub fn builder() -> GetPermissionsRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.screen.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPermissionsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("screen", 4, 2)];
 }
+const _: () = assert!(<GetPermissionsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_screen_only_request_asb! { GetPermissionsRequest }
 impl Request for GetPermissionsRequest {
-    const OPCODE: u8 = 20;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
+    const MINOR_OPCODE: u16 = 20;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPermissions";
     type Reply = GetPermissionsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetPermissionsRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPermissionsRequestBuilder {
+    pub inner: GetPermissionsRequest,
+}
+impl GetPermissionsRequestBuilder {
+    #[inline]
+    pub fn screen(mut self, screen: Card16) -> Self {
+        self.inner.screen = screen;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPermissionsRequest {
+        self.inner
+    }
+}
+impl Default for GetPermissionsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPermissionsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                screen: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPermissionsReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub permissions: Permission,
 }
-impl GetPermissionsReply {}
-impl AsByteSequence for GetPermissionsReply {
+impl GetPermissionsReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let permissions: Permission = read_item(reader, &mut index, 4, "permissions")?;
+        skip_bytes(reader, &mut index, 20)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetPermissionsReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            permissions: permissions,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("permissions", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.permissions == other.permissions
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "permissions",
+            &self.permissions,
+            &other.permissions,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetPermissionsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.permissions.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPermissionsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (permissions, sz): (Permission, usize) = <Permission>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (permissions, sz): (Permission, usize) = parse_item(bytes, index, "permissions")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetPermissionsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2926,16 +6114,21 @@ impl AsByteSequence for GetPermissionsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.permissions.size()
-            + 20
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetPermissionsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Permission {
     pub inner: u32,
 }
@@ -2985,21 +6178,20 @@ impl Permission {
     pub const WRITE: Self = Self { inner: 2 };
     pub const COMPLETE: Self = Self { inner: 3 };
 }
-impl AsByteSequence for Permission {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for Permission {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((Permission { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for Permission {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+impl_u32_flags_asb! { Permission }
 impl core::ops::Not for Permission {
     type Output = Permission;
     #[inline]
@@ -3034,3 +6226,53 @@ impl core::ops::BitXor for Permission {
         }
     }
 }
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "GetModeLine"),
+    (2, "ModModeLine"),
+    (3, "SwitchMode"),
+    (4, "GetMonitor"),
+    (5, "LockModeSwitch"),
+    (6, "GetAllModeLines"),
+    (7, "AddModeLine"),
+    (8, "DeleteModeLine"),
+    (9, "ValidateModeLine"),
+    (10, "SwitchToMode"),
+    (11, "GetViewPort"),
+    (12, "SetViewPort"),
+    (13, "GetDotClocks"),
+    (14, "SetClientVersion"),
+    (15, "SetGamma"),
+    (16, "GetGamma"),
+    (17, "GetGammaRamp"),
+    (18, "SetGammaRamp"),
+    (19, "GetGammaRampSize"),
+    (20, "GetPermissions"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,46 +1,89 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "fixes")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+#[cfg(not(feature = "render"))]
+compile_error!(
+    "The `fixes` feature requires the `render` feature, since it uses the types that it defines"
+);
+#[cfg(not(feature = "shape"))]
+compile_error!(
+    "The `fixes` feature requires the `shape` feature, since it uses the types that it defines"
+);
+pub const MAJOR_VERSION: u32 = 5;
+pub const MINOR_VERSION: u32 = 0;
 use super::render::*;
 use super::shape::*;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major_version: Card32,
     pub client_minor_version: Card32,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major_version", 4, 4),
+        ("client_minor_version", 8, 4),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major_version.as_bytes(&mut bytes[index..]);
         index += self.client_minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_major_version")?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            parse_item(bytes, index, "client_minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -52,20 +95,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major_version.size()
-            + self.client_minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn client_major_version(mut self, client_major_version: Card32) -> Self {
+        self.inner.client_major_version = client_major_version;
+        self
+    }
+    #[inline]
+    pub fn client_minor_version(mut self, client_minor_version: Card32) -> Self {
+        self.inner.client_minor_version = client_minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major_version: Default::default(),
+                client_minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -73,37 +159,108 @@ pub struct QueryVersionReply {
     pub major_version: Card32,
     pub minor_version: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card32 = read_item(reader, &mut index, 4, "major_version")?;
+        let minor_version: Card32 = read_item(reader, &mut index, 4, "minor_version")?;
+        skip_bytes(reader, &mut index, 16)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 4),
+        ("minor_version", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card32, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
         index += 16;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -116,16 +273,21 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
-            + 16
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangeSaveSetRequest {
     pub req_type: u8,
     pub length: u16,
@@ -134,40 +296,54 @@ pub struct ChangeSaveSetRequest {
     pub map: SaveSetMapping,
     pub window: Window,
 }
-impl ChangeSaveSetRequest {}
-impl AsByteSequence for ChangeSaveSetRequest {
+impl ChangeSaveSetRequest {
     #[inline]
+    pub fn builder() -> ChangeSaveSetRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("mode", 4, 1),
+        ("target", 5, 1),
+        ("map", 6, 1),
+    ];
+}
+const _: () = assert!(<ChangeSaveSetRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ChangeSaveSetRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.mode.as_bytes(&mut bytes[index..]);
         index += self.target.as_bytes(&mut bytes[index..]);
         index += self.map.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.window.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeSaveSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (mode, sz): (SaveSetMode, usize) = <SaveSetMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (SaveSetMode, usize) = parse_item(bytes, index, "mode")?;
         index += sz;
-        let (target, sz): (SaveSetTarget, usize) = <SaveSetTarget>::from_bytes(&bytes[index..])?;
+        let (target, sz): (SaveSetTarget, usize) = parse_item(bytes, index, "target")?;
         index += sz;
-        let (map, sz): (SaveSetMapping, usize) = <SaveSetMapping>::from_bytes(&bytes[index..])?;
+        let (map, sz): (SaveSetMapping, usize) = parse_item(bytes, index, "map")?;
         index += sz;
         index += 1;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ChangeSaveSetRequest {
                 req_type: req_type,
                 length: length,
@@ -181,24 +357,76 @@ impl AsByteSequence for ChangeSaveSetRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.mode.size()
-            + self.target.size()
-            + self.map.size()
-            + 1
-            + self.window.size()
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ChangeSaveSetRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ChangeSaveSetRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeSaveSet";
     type Reply = ();
 }
+#[doc = " A builder for `ChangeSaveSetRequest`."]
+#[derive(Clone, Debug)]
+pub struct ChangeSaveSetRequestBuilder {
+    pub inner: ChangeSaveSetRequest,
+}
+impl ChangeSaveSetRequestBuilder {
+    #[inline]
+    pub fn mode(mut self, mode: SaveSetMode) -> Self {
+        self.inner.mode = mode;
+        self
+    }
+    #[inline]
+    pub fn target(mut self, target: SaveSetTarget) -> Self {
+        self.inner.target = target;
+        self
+    }
+    #[inline]
+    pub fn map(mut self, map: SaveSetMapping) -> Self {
+        self.inner.map = map;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangeSaveSetRequest {
+        self.inner
+    }
+}
+impl Default for ChangeSaveSetRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangeSaveSetRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                mode: Default::default(),
+                target: Default::default(),
+                map: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SaveSetMode {
     Insert = 0,
     Delete = 1,
@@ -209,12 +437,12 @@ impl AsByteSequence for SaveSetMode {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Insert, sz)),
-            1 => Some((Self::Delete, sz)),
-            _ => None,
+            0 => Ok((Self::Insert, sz)),
+            1 => Ok((Self::Delete, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -222,14 +450,40 @@ impl AsByteSequence for SaveSetMode {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SaveSetMode {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SaveSetMode {
     #[inline]
     fn default() -> SaveSetMode {
         SaveSetMode::Insert
     }
 }
+impl From<SaveSetMode> for u8 {
+    #[inline]
+    fn from(base: SaveSetMode) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SaveSetMode {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Insert),
+            1 => Ok(Self::Delete),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SaveSetTarget {
     Nearest = 0,
     Root = 1,
@@ -240,12 +494,12 @@ impl AsByteSequence for SaveSetTarget {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Nearest, sz)),
-            1 => Some((Self::Root, sz)),
-            _ => None,
+            0 => Ok((Self::Nearest, sz)),
+            1 => Ok((Self::Root, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -253,14 +507,40 @@ impl AsByteSequence for SaveSetTarget {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SaveSetTarget {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SaveSetTarget {
     #[inline]
     fn default() -> SaveSetTarget {
         SaveSetTarget::Nearest
     }
 }
+impl From<SaveSetTarget> for u8 {
+    #[inline]
+    fn from(base: SaveSetTarget) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SaveSetTarget {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Nearest),
+            1 => Ok(Self::Root),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SaveSetMapping {
     Map = 0,
     Unmap = 1,
@@ -271,12 +551,12 @@ impl AsByteSequence for SaveSetMapping {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Map, sz)),
-            1 => Some((Self::Unmap, sz)),
-            _ => None,
+            0 => Ok((Self::Map, sz)),
+            1 => Ok((Self::Unmap, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -284,14 +564,40 @@ impl AsByteSequence for SaveSetMapping {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SaveSetMapping {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SaveSetMapping {
     #[inline]
     fn default() -> SaveSetMapping {
         SaveSetMapping::Map
     }
 }
+impl From<SaveSetMapping> for u8 {
+    #[inline]
+    fn from(base: SaveSetMapping) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SaveSetMapping {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Map),
+            1 => Ok(Self::Unmap),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SelectionEvent {
     SetSelectionOwner = 0,
     SelectionWindowDestroy = 1,
@@ -303,13 +609,13 @@ impl AsByteSequence for SelectionEvent {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::SetSelectionOwner, sz)),
-            1 => Some((Self::SelectionWindowDestroy, sz)),
-            2 => Some((Self::SelectionClientClose, sz)),
-            _ => None,
+            0 => Ok((Self::SetSelectionOwner, sz)),
+            1 => Ok((Self::SelectionWindowDestroy, sz)),
+            2 => Ok((Self::SelectionClientClose, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -317,13 +623,41 @@ impl AsByteSequence for SelectionEvent {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SelectionEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SelectionEvent {
     #[inline]
     fn default() -> SelectionEvent {
         SelectionEvent::SetSelectionOwner
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<SelectionEvent> for u8 {
+    #[inline]
+    fn from(base: SelectionEvent) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SelectionEvent {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::SetSelectionOwner),
+            1 => Ok(Self::SelectionWindowDestroy),
+            2 => Ok(Self::SelectionClientClose),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectSelectionInputRequest {
     pub req_type: u8,
     pub length: u16,
@@ -331,36 +665,43 @@ pub struct SelectSelectionInputRequest {
     pub selection: Atom,
     pub event_mask: SelectionEventMask,
 }
-impl SelectSelectionInputRequest {}
-impl AsByteSequence for SelectSelectionInputRequest {
+impl SelectSelectionInputRequest {
     #[inline]
+    pub fn builder() -> SelectSelectionInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SelectSelectionInputRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectSelectionInputRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.selection.as_bytes(&mut bytes[index..]);
         index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectSelectionInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = parse_item(bytes, index, "selection")?;
         index += sz;
-        let (event_mask, sz): (SelectionEventMask, usize) =
-            <SelectionEventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (SelectionEventMask, usize) = parse_item(bytes, index, "event_mask")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectSelectionInputRequest {
                 req_type: req_type,
                 length: length,
@@ -373,22 +714,70 @@ impl AsByteSequence for SelectSelectionInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.window.size()
-            + self.selection.size()
-            + self.event_mask.size()
+        let size: usize = 8 + self.window.size() + self.selection.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectSelectionInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectSelectionInputRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectSelectionInput";
     type Reply = ();
 }
+#[doc = " A builder for `SelectSelectionInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectSelectionInputRequestBuilder {
+    pub inner: SelectSelectionInputRequest,
+}
+impl SelectSelectionInputRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn selection(mut self, selection: Atom) -> Self {
+        self.inner.selection = selection;
+        self
+    }
+    #[inline]
+    pub fn event_mask(mut self, event_mask: SelectionEventMask) -> Self {
+        self.inner.event_mask = event_mask;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectSelectionInputRequest {
+        self.inner
+    }
+}
+impl Default for SelectSelectionInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectSelectionInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                selection: Default::default(),
+                event_mask: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SelectionEventMask {
     pub inner: u32,
 }
@@ -459,21 +848,46 @@ impl SelectionEventMask {
     pub const SELECTION_CLIENT_CLOSE: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 7 };
 }
-impl AsByteSequence for SelectionEventMask {
+impl core::ops::Deref for SelectionEventMask {
+    type Target = u32;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((SelectionEventMask { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for SelectionEventMask {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+macro_rules! impl_u32_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                4
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_u32_flags_asb! { SelectionEventMask }
 impl core::ops::Not for SelectionEventMask {
     type Output = SelectionEventMask;
     #[inline]
@@ -508,40 +922,50 @@ impl core::ops::BitXor for SelectionEventMask {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectCursorInputRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub event_mask: CursorNotifyMask,
 }
-impl SelectCursorInputRequest {}
-impl AsByteSequence for SelectCursorInputRequest {
+impl SelectCursorInputRequest {
     #[inline]
+    pub fn builder() -> SelectCursorInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SelectCursorInputRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectCursorInputRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectCursorInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (event_mask, sz): (CursorNotifyMask, usize) =
-            <CursorNotifyMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (CursorNotifyMask, usize) = parse_item(bytes, index, "event_mask")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectCursorInputRequest {
                 req_type: req_type,
                 length: length,
@@ -553,17 +977,64 @@ impl AsByteSequence for SelectCursorInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.event_mask.size()
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectCursorInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectCursorInputRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectCursorInput";
     type Reply = ();
 }
+#[doc = " A builder for `SelectCursorInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectCursorInputRequestBuilder {
+    pub inner: SelectCursorInputRequest,
+}
+impl SelectCursorInputRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn event_mask(mut self, event_mask: CursorNotifyMask) -> Self {
+        self.inner.event_mask = event_mask;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectCursorInputRequest {
+        self.inner
+    }
+}
+impl Default for SelectCursorInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectCursorInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                event_mask: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CursorNotifyMask {
     pub inner: u32,
 }
@@ -596,21 +1067,20 @@ impl CursorNotifyMask {
     pub const DISPLAY_CURSOR: Self = Self { inner: 1 };
     pub const COMPLETE: Self = Self { inner: 1 };
 }
-impl AsByteSequence for CursorNotifyMask {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for CursorNotifyMask {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((CursorNotifyMask { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for CursorNotifyMask {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+impl_u32_flags_asb! { CursorNotifyMask }
 impl core::ops::Not for CursorNotifyMask {
     type Output = CursorNotifyMask;
     #[inline]
@@ -645,50 +1115,120 @@ impl core::ops::BitXor for CursorNotifyMask {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCursorImageRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetCursorImageRequest {}
-impl AsByteSequence for GetCursorImageRequest {
+impl GetCursorImageRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetCursorImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetCursorImageRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetCursorImageRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCursorImageReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetCursorImageRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_empty_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_empty_request_asb! { GetCursorImageRequest }
 impl Request for GetCursorImageRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCursorImage";
     type Reply = GetCursorImageReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetCursorImageRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCursorImageRequestBuilder {
+    pub inner: GetCursorImageRequest,
+}
+impl GetCursorImageRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCursorImageRequest {
+        self.inner
+    }
+}
+impl Default for GetCursorImageRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetCursorImageRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCursorImageReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -702,13 +1242,150 @@ pub struct GetCursorImageReply {
     pub cursor_serial: Card32,
     pub cursor_image: Vec<Card32>,
 }
-impl GetCursorImageReply {}
-impl AsByteSequence for GetCursorImageReply {
+impl GetCursorImageReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn cursor_image_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
+        index += sz;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        let (xhot, sz): (Card16, usize) = parse_item(bytes, index, "xhot")?;
+        index += sz;
+        let (yhot, sz): (Card16, usize) = parse_item(bytes, index, "yhot")?;
+        index += sz;
+        let (cursor_serial, sz): (Card32, usize) = parse_item(bytes, index, "cursor_serial")?;
+        index += sz;
+        index += 8;
+        let (cursor_image, _): (Cow<'_, [Card32]>, usize) = parse_slice(
+            bytes,
+            index,
+            ((width as usize) * (height as usize)) as usize,
+            "cursor_image",
+        )?;
+        Ok(cursor_image)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let x: Int16 = read_item(reader, &mut index, 2, "x")?;
+        let y: Int16 = read_item(reader, &mut index, 2, "y")?;
+        let width: Card16 = read_item(reader, &mut index, 2, "width")?;
+        let height: Card16 = read_item(reader, &mut index, 2, "height")?;
+        let xhot: Card16 = read_item(reader, &mut index, 2, "xhot")?;
+        let yhot: Card16 = read_item(reader, &mut index, 2, "yhot")?;
+        let cursor_serial: Card32 = read_item(reader, &mut index, 4, "cursor_serial")?;
+        skip_bytes(reader, &mut index, 8)?;
+        let (cursor_image, block_len): (Vec<Card32>, usize) = read_vector(
+            reader,
+            &mut index,
+            ((width as usize) * (height as usize)) as usize,
+            4,
+            "cursor_image",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetCursorImageReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            x: x,
+            y: y,
+            width: width,
+            height: height,
+            xhot: xhot,
+            yhot: yhot,
+            cursor_serial: cursor_serial,
+            cursor_image: cursor_image,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("x", 8, 2),
+        ("y", 10, 2),
+        ("width", 12, 2),
+        ("height", 14, 2),
+        ("xhot", 16, 2),
+        ("yhot", 18, 2),
+        ("cursor_serial", 20, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.x == other.x
+            && self.y == other.y
+            && self.width == other.width
+            && self.height == other.height
+            && self.xhot == other.xhot
+            && self.yhot == other.yhot
+            && self.cursor_serial == other.cursor_serial
+            && self.cursor_image == other.cursor_image
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("x", &self.x, &other.x, &mut changes);
+        diff_value("y", &self.y, &other.y, &mut changes);
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_value("xhot", &self.xhot, &other.xhot, &mut changes);
+        diff_value("yhot", &self.yhot, &other.yhot, &mut changes);
+        diff_value(
+            "cursor_serial",
+            &self.cursor_serial,
+            &other.cursor_serial,
+            &mut changes,
+        );
+        diff_list(
+            "cursor_image",
+            &self.cursor_image,
+            &other.cursor_image,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetCursorImageReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.x.as_bytes(&mut bytes[index..]);
@@ -718,45 +1395,52 @@ impl AsByteSequence for GetCursorImageReply {
         index += self.xhot.as_bytes(&mut bytes[index..]);
         index += self.yhot.as_bytes(&mut bytes[index..]);
         index += self.cursor_serial.as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += zero_pad(&mut bytes[index..], 8);
         let block_len: usize = vector_as_bytes(&self.cursor_image, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorImageReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (xhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (xhot, sz): (Card16, usize) = parse_item(bytes, index, "xhot")?;
         index += sz;
-        let (yhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (yhot, sz): (Card16, usize) = parse_item(bytes, index, "yhot")?;
         index += sz;
-        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (cursor_serial, sz): (Card32, usize) = parse_item(bytes, index, "cursor_serial")?;
         index += sz;
         index += 8;
-        let (cursor_image, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (cursor_image, block_len): (Vec<Card32>, usize) = parse_vector(
+            bytes,
+            index,
             ((width as usize) * (height as usize)) as usize,
+            "cursor_image",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCursorImageReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -775,27 +1459,64 @@ impl AsByteSequence for GetCursorImageReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
-            + self.xhot.size()
-            + self.yhot.size()
-            + self.cursor_serial.size()
-            + 8
-            + {
-                let block_len: usize = self.cursor_image.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.cursor_image.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 32 + {
+            let block_len: usize = self.cursor_image.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetCursorImageReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCursorImageReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let x: Int16 = u.arbitrary()?;
+        let y: Int16 = u.arbitrary()?;
+        let width: Card16 = u.arbitrary()?;
+        let height: Card16 = u.arbitrary()?;
+        let xhot: Card16 = u.arbitrary()?;
+        let yhot: Card16 = u.arbitrary()?;
+        let cursor_serial: Card32 = u.arbitrary()?;
+        let cursor_image = arbitrary_list(u, ((width as usize) * (height as usize)) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            x: x,
+            y: y,
+            width: width,
+            height: height,
+            xhot: xhot,
+            yhot: yhot,
+            cursor_serial: cursor_serial,
+            cursor_image: cursor_image,
+        })
     }
 }
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Region {
     pub xid: XID,
 }
@@ -804,6 +1525,23 @@ impl Region {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Region {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Region {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Region {
     #[inline]
@@ -815,100 +1553,222 @@ impl XidType for Region {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Region {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Region({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Region {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Region> for XID {
+    #[inline]
+    fn from(base: Region) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRegionRequest {
     pub req_type: u8,
     pub length: u16,
     pub region: Region,
     pub rectangles: Vec<Rectangle>,
 }
-impl CreateRegionRequest {}
-impl AsByteSequence for CreateRegionRequest {
+impl CreateRegionRequest {
+    #[inline]
+    pub fn builder() -> CreateRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
+}
+const _: () = assert!(<CreateRegionRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_region_rectangles_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.region.as_bytes(&mut bytes[index..]);
+                let block_len: usize = vector_as_bytes(&self.rectangles, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Rectangle>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
+                index += sz;
+                let (rectangles, block_len): (Vec<Rectangle>, usize) = parse_remainder(
+                    bytes,
+                    index,
+                    (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                        field: "rectangles",
+                        offset: index,
+                    })? as usize,
+                    "rectangles",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        region: region,
+                        rectangles: rectangles,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8 + {
+                    let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_region_rectangles_request_asb! { CreateRegionRequest }
+impl Request for CreateRegionRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 5;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegion";
+    type Reply = ();
+}
+#[doc = " A builder for `CreateRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRegionRequestBuilder {
+    pub inner: CreateRegionRequest,
+}
+impl CreateRegionRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.region.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.rectangles, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        index
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CreateRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        Some((
-            CreateRegionRequest {
-                req_type: req_type,
-                length: length,
-                region: region,
-                rectangles: rectangles,
-            },
-            index,
-        ))
+    pub fn rectangles(mut self, rectangles: Vec<Rectangle>) -> Self {
+        self.inner.rectangles = rectangles;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size() + {
-            let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-            block_len + pad
-        }
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRegionRequest {
+        self.inner
     }
 }
-impl Request for CreateRegionRequest {
-    const OPCODE: u8 = 5;
-    const EXTENSION: Option<&'static str> = Some("XFIXES");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
+impl core::iter::Extend<Rectangle> for CreateRegionRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Rectangle>>(&mut self, iter: I) {
+        self.inner.rectangles.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Rectangle> for CreateRegionRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Rectangle>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for CreateRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                rectangles: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRegionFromBitmapRequest {
     pub req_type: u8,
     pub length: u16,
     pub region: Region,
     pub bitmap: Pixmap,
 }
-impl CreateRegionFromBitmapRequest {}
-impl AsByteSequence for CreateRegionFromBitmapRequest {
+impl CreateRegionFromBitmapRequest {
     #[inline]
+    pub fn builder() -> CreateRegionFromBitmapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
+}
+const _: () = assert!(<CreateRegionFromBitmapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateRegionFromBitmapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.bitmap.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromBitmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (bitmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (bitmap, sz): (Pixmap, usize) = parse_item(bytes, index, "bitmap")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateRegionFromBitmapRequest {
                 req_type: req_type,
                 length: length,
@@ -920,16 +1780,64 @@ impl AsByteSequence for CreateRegionFromBitmapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size() + self.bitmap.size()
+        let size: usize = 8 + self.bitmap.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateRegionFromBitmapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateRegionFromBitmapRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromBitmap";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateRegionFromBitmapRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRegionFromBitmapRequestBuilder {
+    pub inner: CreateRegionFromBitmapRequest,
+}
+impl CreateRegionFromBitmapRequestBuilder {
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn bitmap(mut self, bitmap: Pixmap) -> Self {
+        self.inner.bitmap = bitmap;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRegionFromBitmapRequest {
+        self.inner
+    }
+}
+impl Default for CreateRegionFromBitmapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRegionFromBitmapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                bitmap: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRegionFromWindowRequest {
     pub req_type: u8,
     pub length: u16,
@@ -937,37 +1845,46 @@ pub struct CreateRegionFromWindowRequest {
     pub window: Window,
     pub kind: Sk,
 }
-impl CreateRegionFromWindowRequest {}
-impl AsByteSequence for CreateRegionFromWindowRequest {
+impl CreateRegionFromWindowRequest {
     #[inline]
+    pub fn builder() -> CreateRegionFromWindowRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
+}
+const _: () = assert!(<CreateRegionFromWindowRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateRegionFromWindowRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.kind.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Sk, usize) = parse_item(bytes, index, "kind")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateRegionFromWindowRequest {
                 req_type: req_type,
                 length: length,
@@ -980,54 +1897,111 @@ impl AsByteSequence for CreateRegionFromWindowRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.region.size()
-            + self.window.size()
-            + self.kind.size()
-            + 3
+        let size: usize = 11 + self.window.size() + self.kind.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateRegionFromWindowRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateRegionFromWindowRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromWindow";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateRegionFromWindowRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRegionFromWindowRequestBuilder {
+    pub inner: CreateRegionFromWindowRequest,
+}
+impl CreateRegionFromWindowRequestBuilder {
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn kind(mut self, kind: Sk) -> Self {
+        self.inner.kind = kind;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRegionFromWindowRequest {
+        self.inner
+    }
+}
+impl Default for CreateRegionFromWindowRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRegionFromWindowRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                window: Default::default(),
+                kind: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRegionFromGcRequest {
     pub req_type: u8,
     pub length: u16,
     pub region: Region,
     pub gc: Gcontext,
 }
-impl CreateRegionFromGcRequest {}
-impl AsByteSequence for CreateRegionFromGcRequest {
+impl CreateRegionFromGcRequest {
     #[inline]
+    pub fn builder() -> CreateRegionFromGcRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
+}
+const _: () = assert!(<CreateRegionFromGcRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateRegionFromGcRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.gc.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromGcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = parse_item(bytes, index, "gc")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateRegionFromGcRequest {
                 req_type: req_type,
                 length: length,
@@ -1039,48 +2013,105 @@ impl AsByteSequence for CreateRegionFromGcRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size() + self.gc.size()
+        let size: usize = 8 + self.gc.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateRegionFromGcRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateRegionFromGcRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromGc";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateRegionFromGcRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRegionFromGcRequestBuilder {
+    pub inner: CreateRegionFromGcRequest,
+}
+impl CreateRegionFromGcRequestBuilder {
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRegionFromGcRequest {
+        self.inner
+    }
+}
+impl Default for CreateRegionFromGcRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRegionFromGcRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                gc: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateRegionFromPictureRequest {
     pub req_type: u8,
     pub length: u16,
     pub region: Region,
     pub picture: Picture,
 }
-impl CreateRegionFromPictureRequest {}
-impl AsByteSequence for CreateRegionFromPictureRequest {
+impl CreateRegionFromPictureRequest {
     #[inline]
+    pub fn builder() -> CreateRegionFromPictureRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
+}
+const _: () = assert!(<CreateRegionFromPictureRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateRegionFromPictureRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromPictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateRegionFromPictureRequest {
                 req_type: req_type,
                 length: length,
@@ -1092,178 +2123,373 @@ impl AsByteSequence for CreateRegionFromPictureRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size() + self.picture.size()
+        let size: usize = 8 + self.picture.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateRegionFromPictureRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateRegionFromPictureRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromPicture";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct DestroyRegionRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub region: Region,
+#[doc = " A builder for `CreateRegionFromPictureRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateRegionFromPictureRequestBuilder {
+    pub inner: CreateRegionFromPictureRequest,
 }
-impl DestroyRegionRequest {}
-impl AsByteSequence for DestroyRegionRequest {
+impl CreateRegionFromPictureRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.region.as_bytes(&mut bytes[index..]);
-        index
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing DestroyRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            DestroyRegionRequest {
-                req_type: req_type,
-                length: length,
-                region: region,
-            },
-            index,
-        ))
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size()
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateRegionFromPictureRequest {
+        self.inner
+    }
+}
+impl Default for CreateRegionFromPictureRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateRegionFromPictureRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                picture: Default::default(),
+            },
+        }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct DestroyRegionRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub region: Region,
+}
+impl DestroyRegionRequest {
+    #[inline]
+    pub fn builder() -> DestroyRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
+}
+const _: () = assert!(<DestroyRegionRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_region_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.region.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        region: region,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_region_only_request_asb! { DestroyRegionRequest }
 impl Request for DestroyRegionRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct SetRegionRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub region: Region,
-    pub rectangles: Vec<Rectangle>,
+#[doc = " A builder for `DestroyRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyRegionRequestBuilder {
+    pub inner: DestroyRegionRequest,
 }
-impl SetRegionRequest {}
-impl AsByteSequence for SetRegionRequest {
+impl DestroyRegionRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.region.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.rectangles, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        index
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SetRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        Some((
-            SetRegionRequest {
-                req_type: req_type,
-                length: length,
-                region: region,
-                rectangles: rectangles,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyRegionRequest {
+        self.inner
     }
+}
+impl Default for DestroyRegionRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size() + {
-            let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-            block_len + pad
+    fn default() -> Self {
+        Self {
+            inner: DestroyRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+            },
         }
     }
 }
-impl Request for SetRegionRequest {
-    const OPCODE: u8 = 11;
-    const EXTENSION: Option<&'static str> = Some("XFIXES");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
-}
-#[derive(Clone, Debug, Default)]
-pub struct CopyRegionRequest {
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct SetRegionRequest {
     pub req_type: u8,
     pub length: u16,
-    pub source: Region,
-    pub destination: Region,
+    pub region: Region,
+    pub rectangles: Vec<Rectangle>,
 }
-impl CopyRegionRequest {}
-impl AsByteSequence for CopyRegionRequest {
+impl SetRegionRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.source.as_bytes(&mut bytes[index..]);
-        index += self.destination.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> SetRegionRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
+}
+const _: () = assert!(<SetRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl_region_rectangles_request_asb! { SetRegionRequest }
+impl Request for SetRegionRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 11;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetRegion";
+    type Reply = ();
+}
+#[doc = " A builder for `SetRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetRegionRequestBuilder {
+    pub inner: SetRegionRequest,
+}
+impl SetRegionRequestBuilder {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing CopyRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            CopyRegionRequest {
-                req_type: req_type,
-                length: length,
-                source: source,
-                destination: destination,
-            },
-            index,
-        ))
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.source.size() + self.destination.size()
+    pub fn rectangles(mut self, rectangles: Vec<Rectangle>) -> Self {
+        self.inner.rectangles = rectangles;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetRegionRequest {
+        self.inner
     }
 }
+impl core::iter::Extend<Rectangle> for SetRegionRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Rectangle>>(&mut self, iter: I) {
+        self.inner.rectangles.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Rectangle> for SetRegionRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Rectangle>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for SetRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                rectangles: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct CopyRegionRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub source: Region,
+    pub destination: Region,
+}
+impl CopyRegionRequest {
+    #[inline]
+    pub fn builder() -> CopyRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("source", 4, 4),
+        ("destination", 8, 4),
+    ];
+}
+const _: () = assert!(<CopyRegionRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_source_destination_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.source.as_bytes(&mut bytes[index..]);
+                index += self.destination.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (source, sz): (Region, usize) = parse_item(bytes, index, "source")?;
+                index += sz;
+                let (destination, sz): (Region, usize) = parse_item(bytes, index, "destination")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        source: source,
+                        destination: destination,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 12;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_source_destination_request_asb! { CopyRegionRequest }
 impl Request for CopyRegionRequest {
-    const OPCODE: u8 = 12;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 12;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CopyRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct CopyRegionRequestBuilder {
+    pub inner: CopyRegionRequest,
+}
+impl CopyRegionRequestBuilder {
+    #[inline]
+    pub fn source(mut self, source: Region) -> Self {
+        self.inner.source = source;
+        self
+    }
+    #[inline]
+    pub fn destination(mut self, destination: Region) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CopyRegionRequest {
+        self.inner
+    }
+}
+impl Default for CopyRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CopyRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source: Default::default(),
+                destination: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct UnionRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1271,62 +2497,134 @@ pub struct UnionRegionRequest {
     pub source2: Region,
     pub destination: Region,
 }
-impl UnionRegionRequest {}
-impl AsByteSequence for UnionRegionRequest {
+impl UnionRegionRequest {
+    #[inline]
+    pub fn builder() -> UnionRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("source1", 4, 4),
+        ("source2", 8, 4),
+        ("destination", 12, 4),
+    ];
+}
+const _: () = assert!(<UnionRegionRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_source1_source2_destination_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.source1.as_bytes(&mut bytes[index..]);
+                index += self.source2.as_bytes(&mut bytes[index..]);
+                index += self.destination.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (source1, sz): (Region, usize) = parse_item(bytes, index, "source1")?;
+                index += sz;
+                let (source2, sz): (Region, usize) = parse_item(bytes, index, "source2")?;
+                index += sz;
+                let (destination, sz): (Region, usize) = parse_item(bytes, index, "destination")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        source1: source1,
+                        source2: source2,
+                        destination: destination,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 16;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_source1_source2_destination_request_asb! { UnionRegionRequest }
+impl Request for UnionRegionRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 13;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnionRegion";
+    type Reply = ();
+}
+#[doc = " A builder for `UnionRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct UnionRegionRequestBuilder {
+    pub inner: UnionRegionRequest,
+}
+impl UnionRegionRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.source1.as_bytes(&mut bytes[index..]);
-        index += self.source2.as_bytes(&mut bytes[index..]);
-        index += self.destination.as_bytes(&mut bytes[index..]);
-        index
+    pub fn source1(mut self, source1: Region) -> Self {
+        self.inner.source1 = source1;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing UnionRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source1, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source2, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            UnionRegionRequest {
-                req_type: req_type,
-                length: length,
-                source1: source1,
-                source2: source2,
-                destination: destination,
-            },
-            index,
-        ))
+    pub fn source2(mut self, source2: Region) -> Self {
+        self.inner.source2 = source2;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.source1.size()
-            + self.source2.size()
-            + self.destination.size()
+    pub fn destination(mut self, destination: Region) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> UnionRegionRequest {
+        self.inner
     }
 }
-impl Request for UnionRegionRequest {
-    const OPCODE: u8 = 13;
-    const EXTENSION: Option<&'static str> = Some("XFIXES");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
+impl Default for UnionRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnionRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source1: Default::default(),
+                source2: Default::default(),
+                destination: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct IntersectRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1334,62 +2632,75 @@ pub struct IntersectRegionRequest {
     pub source2: Region,
     pub destination: Region,
 }
-impl IntersectRegionRequest {}
-impl AsByteSequence for IntersectRegionRequest {
+impl IntersectRegionRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.source1.as_bytes(&mut bytes[index..]);
-        index += self.source2.as_bytes(&mut bytes[index..]);
-        index += self.destination.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing IntersectRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source1, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source2, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            IntersectRegionRequest {
-                req_type: req_type,
-                length: length,
-                source1: source1,
-                source2: source2,
-                destination: destination,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.source1.size()
-            + self.source2.size()
-            + self.destination.size()
+    pub fn builder() -> IntersectRegionRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("source1", 4, 4),
+        ("source2", 8, 4),
+        ("destination", 12, 4),
+    ];
 }
+const _: () = assert!(<IntersectRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl_source1_source2_destination_request_asb! { IntersectRegionRequest }
 impl Request for IntersectRegionRequest {
-    const OPCODE: u8 = 14;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 14;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "IntersectRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `IntersectRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct IntersectRegionRequestBuilder {
+    pub inner: IntersectRegionRequest,
+}
+impl IntersectRegionRequestBuilder {
+    #[inline]
+    pub fn source1(mut self, source1: Region) -> Self {
+        self.inner.source1 = source1;
+        self
+    }
+    #[inline]
+    pub fn source2(mut self, source2: Region) -> Self {
+        self.inner.source2 = source2;
+        self
+    }
+    #[inline]
+    pub fn destination(mut self, destination: Region) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> IntersectRegionRequest {
+        self.inner
+    }
+}
+impl Default for IntersectRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: IntersectRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source1: Default::default(),
+                source2: Default::default(),
+                destination: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SubtractRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1397,62 +2708,75 @@ pub struct SubtractRegionRequest {
     pub source2: Region,
     pub destination: Region,
 }
-impl SubtractRegionRequest {}
-impl AsByteSequence for SubtractRegionRequest {
+impl SubtractRegionRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.source1.as_bytes(&mut bytes[index..]);
-        index += self.source2.as_bytes(&mut bytes[index..]);
-        index += self.destination.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SubtractRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source1, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source2, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            SubtractRegionRequest {
-                req_type: req_type,
-                length: length,
-                source1: source1,
-                source2: source2,
-                destination: destination,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.source1.size()
-            + self.source2.size()
-            + self.destination.size()
+    pub fn builder() -> SubtractRegionRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("source1", 4, 4),
+        ("source2", 8, 4),
+        ("destination", 12, 4),
+    ];
 }
+const _: () = assert!(<SubtractRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl_source1_source2_destination_request_asb! { SubtractRegionRequest }
 impl Request for SubtractRegionRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SubtractRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SubtractRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct SubtractRegionRequestBuilder {
+    pub inner: SubtractRegionRequest,
+}
+impl SubtractRegionRequestBuilder {
+    #[inline]
+    pub fn source1(mut self, source1: Region) -> Self {
+        self.inner.source1 = source1;
+        self
+    }
+    #[inline]
+    pub fn source2(mut self, source2: Region) -> Self {
+        self.inner.source2 = source2;
+        self
+    }
+    #[inline]
+    pub fn destination(mut self, destination: Region) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SubtractRegionRequest {
+        self.inner
+    }
+}
+impl Default for SubtractRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SubtractRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source1: Default::default(),
+                source2: Default::default(),
+                destination: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct InvertRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1460,35 +2784,44 @@ pub struct InvertRegionRequest {
     pub bounds: Rectangle,
     pub destination: Region,
 }
-impl InvertRegionRequest {}
-impl AsByteSequence for InvertRegionRequest {
+impl InvertRegionRequest {
     #[inline]
+    pub fn builder() -> InvertRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("source", 4, 4)];
+}
+const _: () = assert!(<InvertRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for InvertRegionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.source.as_bytes(&mut bytes[index..]);
         index += self.bounds.as_bytes(&mut bytes[index..]);
         index += self.destination.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing InvertRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Region, usize) = parse_item(bytes, index, "source")?;
         index += sz;
-        let (bounds, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let (bounds, sz): (Rectangle, usize) = parse_item(bytes, index, "bounds")?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = parse_item(bytes, index, "destination")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             InvertRegionRequest {
                 req_type: req_type,
                 length: length,
@@ -1501,21 +2834,70 @@ impl AsByteSequence for InvertRegionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.source.size()
-            + self.bounds.size()
-            + self.destination.size()
+        let size: usize = 12 + self.bounds.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for InvertRegionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for InvertRegionRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "InvertRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `InvertRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct InvertRegionRequestBuilder {
+    pub inner: InvertRegionRequest,
+}
+impl InvertRegionRequestBuilder {
+    #[inline]
+    pub fn source(mut self, source: Region) -> Self {
+        self.inner.source = source;
+        self
+    }
+    #[inline]
+    pub fn bounds(mut self, bounds: Rectangle) -> Self {
+        self.inner.bounds = bounds;
+        self
+    }
+    #[inline]
+    pub fn destination(mut self, destination: Region) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> InvertRegionRequest {
+        self.inner
+    }
+}
+impl Default for InvertRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: InvertRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source: Default::default(),
+                bounds: Default::default(),
+                destination: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct TranslateRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1523,35 +2905,49 @@ pub struct TranslateRegionRequest {
     pub dx: Int16,
     pub dy: Int16,
 }
-impl TranslateRegionRequest {}
-impl AsByteSequence for TranslateRegionRequest {
+impl TranslateRegionRequest {
     #[inline]
+    pub fn builder() -> TranslateRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("region", 4, 4),
+        ("dx", 8, 2),
+        ("dy", 10, 2),
+    ];
+}
+const _: () = assert!(<TranslateRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for TranslateRegionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.dx.as_bytes(&mut bytes[index..]);
         index += self.dy.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing TranslateRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (dx, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dx, sz): (Int16, usize) = parse_item(bytes, index, "dx")?;
         index += sz;
-        let (dy, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dy, sz): (Int16, usize) = parse_item(bytes, index, "dy")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             TranslateRegionRequest {
                 req_type: req_type,
                 length: length,
@@ -1563,123 +2959,202 @@ impl AsByteSequence for TranslateRegionRequest {
         ))
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.region.size()
-            + self.dx.size()
-            + self.dy.size()
+    fn size(&self) -> usize {
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for TranslateRegionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Request for TranslateRegionRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 17;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TranslateRegion";
+    type Reply = ();
+}
+#[doc = " A builder for `TranslateRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct TranslateRegionRequestBuilder {
+    pub inner: TranslateRegionRequest,
+}
+impl TranslateRegionRequestBuilder {
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn dx(mut self, dx: Int16) -> Self {
+        self.inner.dx = dx;
+        self
+    }
+    #[inline]
+    pub fn dy(mut self, dy: Int16) -> Self {
+        self.inner.dy = dy;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> TranslateRegionRequest {
+        self.inner
     }
 }
-impl Request for TranslateRegionRequest {
-    const OPCODE: u8 = 17;
-    const EXTENSION: Option<&'static str> = Some("XFIXES");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
+impl Default for TranslateRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: TranslateRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+                dx: Default::default(),
+                dy: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct RegionExtentsRequest {
     pub req_type: u8,
     pub length: u16,
     pub source: Region,
     pub destination: Region,
 }
-impl RegionExtentsRequest {}
-impl AsByteSequence for RegionExtentsRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.source.as_bytes(&mut bytes[index..]);
-        index += self.destination.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing RegionExtentsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            RegionExtentsRequest {
-                req_type: req_type,
-                length: length,
-                source: source,
-                destination: destination,
-            },
-            index,
-        ))
-    }
+impl RegionExtentsRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.source.size() + self.destination.size()
+    pub fn builder() -> RegionExtentsRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("source", 4, 4),
+        ("destination", 8, 4),
+    ];
 }
+const _: () = assert!(<RegionExtentsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_source_destination_request_asb! { RegionExtentsRequest }
 impl Request for RegionExtentsRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RegionExtents";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `RegionExtentsRequest`."]
+#[derive(Clone, Debug)]
+pub struct RegionExtentsRequestBuilder {
+    pub inner: RegionExtentsRequest,
+}
+impl RegionExtentsRequestBuilder {
+    #[inline]
+    pub fn source(mut self, source: Region) -> Self {
+        self.inner.source = source;
+        self
+    }
+    #[inline]
+    pub fn destination(mut self, destination: Region) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> RegionExtentsRequest {
+        self.inner
+    }
+}
+impl Default for RegionExtentsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: RegionExtentsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source: Default::default(),
+                destination: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FetchRegionRequest {
     pub req_type: u8,
     pub length: u16,
     pub region: Region,
 }
-impl FetchRegionRequest {}
-impl AsByteSequence for FetchRegionRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.region.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl FetchRegionRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing FetchRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            FetchRegionRequest {
-                req_type: req_type,
-                length: length,
-                region: region,
-            },
-            index,
-        ))
+    pub fn builder() -> FetchRegionRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.region.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<FetchRegionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("region", 4, 4)];
 }
+const _: () = assert!(<FetchRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl_region_only_request_asb! { FetchRegionRequest }
 impl Request for FetchRegionRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FetchRegion";
     type Reply = FetchRegionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FetchRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct FetchRegionRequestBuilder {
+    pub inner: FetchRegionRequest,
+}
+impl FetchRegionRequestBuilder {
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FetchRegionRequest {
+        self.inner
+    }
+}
+impl Default for FetchRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FetchRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                region: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct FetchRegionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1687,41 +3162,83 @@ pub struct FetchRegionReply {
     pub extents: Rectangle,
     pub rectangles: Vec<Rectangle>,
 }
-impl FetchRegionReply {}
-impl AsByteSequence for FetchRegionReply {
+impl FetchRegionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.extents == other.extents && self.rectangles == other.rectangles
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("extents", &self.extents, &other.extents, &mut changes);
+        diff_list(
+            "rectangles",
+            &self.rectangles,
+            &other.rectangles,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for FetchRegionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.extents.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         let block_len: usize = vector_as_bytes(&self.rectangles, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Rectangle>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FetchRegionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (extents, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (extents, sz): (Rectangle, usize) = parse_item(bytes, index, "extents")?;
         index += sz;
         index += 16;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) / (2)) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = parse_vector(
+            bytes,
+            index,
+            ((length as usize) / (2)) as usize,
+            "rectangles",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FetchRegionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1734,20 +3251,43 @@ impl AsByteSequence for FetchRegionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.extents.size()
-            + 16
-            + {
-                let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
-                block_len + pad
-            }
+        let size: usize = 24 + self.extents.size() + {
+            let block_len: usize = self.rectangles.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FetchRegionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for FetchRegionReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let extents: Rectangle = u.arbitrary()?;
+        let rectangles = arbitrary_list(u, ((length as usize) / (2)) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            extents: extents,
+            rectangles: rectangles,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetGcClipRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1756,38 +3296,46 @@ pub struct SetGcClipRegionRequest {
     pub x_origin: Int16,
     pub y_origin: Int16,
 }
-impl SetGcClipRegionRequest {}
-impl AsByteSequence for SetGcClipRegionRequest {
+impl SetGcClipRegionRequest {
     #[inline]
+    pub fn builder() -> SetGcClipRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetGcClipRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetGcClipRegionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.gc.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.x_origin.as_bytes(&mut bytes[index..]);
         index += self.y_origin.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetGcClipRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = parse_item(bytes, index, "gc")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (x_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_origin, sz): (Int16, usize) = parse_item(bytes, index, "x_origin")?;
         index += sz;
-        let (y_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_origin, sz): (Int16, usize) = parse_item(bytes, index, "y_origin")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetGcClipRegionRequest {
                 req_type: req_type,
                 length: length,
@@ -1801,23 +3349,77 @@ impl AsByteSequence for SetGcClipRegionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.gc.size()
-            + self.region.size()
-            + self.x_origin.size()
-            + self.y_origin.size()
+        let size: usize = 12 + self.gc.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetGcClipRegionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetGcClipRegionRequest {
-    const OPCODE: u8 = 20;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 20;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetGcClipRegion";
     type Reply = ();
 }
+#[doc = " A builder for `SetGcClipRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetGcClipRegionRequestBuilder {
+    pub inner: SetGcClipRegionRequest,
+}
+impl SetGcClipRegionRequestBuilder {
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn x_origin(mut self, x_origin: Int16) -> Self {
+        self.inner.x_origin = x_origin;
+        self
+    }
+    #[inline]
+    pub fn y_origin(mut self, y_origin: Int16) -> Self {
+        self.inner.y_origin = y_origin;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetGcClipRegionRequest {
+        self.inner
+    }
+}
+impl Default for SetGcClipRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetGcClipRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                gc: Default::default(),
+                region: Default::default(),
+                x_origin: Default::default(),
+                y_origin: Default::default(),
+            },
+        }
+    }
+}
 pub const REGION_NONE: Region = <Region>::const_from_xid(0);
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetWindowShapeRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1827,43 +3429,51 @@ pub struct SetWindowShapeRegionRequest {
     pub y_offset: Int16,
     pub region: Region,
 }
-impl SetWindowShapeRegionRequest {}
-impl AsByteSequence for SetWindowShapeRegionRequest {
+impl SetWindowShapeRegionRequest {
     #[inline]
+    pub fn builder() -> SetWindowShapeRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetWindowShapeRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetWindowShapeRegionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.dest.as_bytes(&mut bytes[index..]);
         index += self.dest_kind.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.x_offset.as_bytes(&mut bytes[index..]);
         index += self.y_offset.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetWindowShapeRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (dest, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (dest, sz): (Window, usize) = parse_item(bytes, index, "dest")?;
         index += sz;
-        let (dest_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (dest_kind, sz): (Sk, usize) = parse_item(bytes, index, "dest_kind")?;
         index += sz;
         index += 3;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = parse_item(bytes, index, "x_offset")?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = parse_item(bytes, index, "y_offset")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetWindowShapeRegionRequest {
                 req_type: req_type,
                 length: length,
@@ -1878,24 +3488,82 @@ impl AsByteSequence for SetWindowShapeRegionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.dest.size()
-            + self.dest_kind.size()
-            + 3
-            + self.x_offset.size()
-            + self.y_offset.size()
-            + self.region.size()
+        let size: usize = 15 + self.dest.size() + self.dest_kind.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetWindowShapeRegionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetWindowShapeRegionRequest {
-    const OPCODE: u8 = 21;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 21;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetWindowShapeRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetWindowShapeRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetWindowShapeRegionRequestBuilder {
+    pub inner: SetWindowShapeRegionRequest,
+}
+impl SetWindowShapeRegionRequestBuilder {
+    #[inline]
+    pub fn dest(mut self, dest: Window) -> Self {
+        self.inner.dest = dest;
+        self
+    }
+    #[inline]
+    pub fn dest_kind(mut self, dest_kind: Sk) -> Self {
+        self.inner.dest_kind = dest_kind;
+        self
+    }
+    #[inline]
+    pub fn x_offset(mut self, x_offset: Int16) -> Self {
+        self.inner.x_offset = x_offset;
+        self
+    }
+    #[inline]
+    pub fn y_offset(mut self, y_offset: Int16) -> Self {
+        self.inner.y_offset = y_offset;
+        self
+    }
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetWindowShapeRegionRequest {
+        self.inner
+    }
+}
+impl Default for SetWindowShapeRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetWindowShapeRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                dest: Default::default(),
+                dest_kind: Default::default(),
+                x_offset: Default::default(),
+                y_offset: Default::default(),
+                region: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPictureClipRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1904,38 +3572,46 @@ pub struct SetPictureClipRegionRequest {
     pub x_origin: Int16,
     pub y_origin: Int16,
 }
-impl SetPictureClipRegionRequest {}
-impl AsByteSequence for SetPictureClipRegionRequest {
+impl SetPictureClipRegionRequest {
     #[inline]
+    pub fn builder() -> SetPictureClipRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetPictureClipRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetPictureClipRegionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.picture.as_bytes(&mut bytes[index..]);
         index += self.region.as_bytes(&mut bytes[index..]);
         index += self.x_origin.as_bytes(&mut bytes[index..]);
         index += self.y_origin.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureClipRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = parse_item(bytes, index, "picture")?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = parse_item(bytes, index, "region")?;
         index += sz;
-        let (x_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_origin, sz): (Int16, usize) = parse_item(bytes, index, "x_origin")?;
         index += sz;
-        let (y_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_origin, sz): (Int16, usize) = parse_item(bytes, index, "y_origin")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPictureClipRegionRequest {
                 req_type: req_type,
                 length: length,
@@ -1949,62 +3625,137 @@ impl AsByteSequence for SetPictureClipRegionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.picture.size()
-            + self.region.size()
-            + self.x_origin.size()
-            + self.y_origin.size()
+        let size: usize = 12 + self.picture.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetPictureClipRegionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetPictureClipRegionRequest {
-    const OPCODE: u8 = 22;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 22;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureClipRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPictureClipRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPictureClipRegionRequestBuilder {
+    pub inner: SetPictureClipRegionRequest,
+}
+impl SetPictureClipRegionRequestBuilder {
+    #[inline]
+    pub fn picture(mut self, picture: Picture) -> Self {
+        self.inner.picture = picture;
+        self
+    }
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.region = region;
+        self
+    }
+    #[inline]
+    pub fn x_origin(mut self, x_origin: Int16) -> Self {
+        self.inner.x_origin = x_origin;
+        self
+    }
+    #[inline]
+    pub fn y_origin(mut self, y_origin: Int16) -> Self {
+        self.inner.y_origin = y_origin;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPictureClipRegionRequest {
+        self.inner
+    }
+}
+impl Default for SetPictureClipRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPictureClipRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                picture: Default::default(),
+                region: Default::default(),
+                x_origin: Default::default(),
+                y_origin: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetCursorNameRequest {
     pub req_type: u8,
     pub length: u16,
     pub cursor: Cursor,
     pub name: String,
 }
-impl SetCursorNameRequest {}
-impl AsByteSequence for SetCursorNameRequest {
+impl SetCursorNameRequest {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(cursor: Cursor, name: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            cursor: cursor,
+            name: String::from(name),
+        }
+    }
     #[inline]
+    pub fn builder() -> SetCursorNameRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SetCursorNameRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetCursorNameRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.cursor.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCursorNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = parse_item(bytes, index, "cursor")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetCursorNameRequest {
                 req_type: req_type,
                 length: length,
@@ -2016,54 +3767,112 @@ impl AsByteSequence for SetCursorNameRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.cursor.size()
-            + ::core::mem::size_of::<Card16>()
-            + 2
-            + {
-                let block_len: usize = self.name.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+        let size: usize = 8 + self.cursor.size() + {
+            let block_len: usize = self.name.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetCursorNameRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Request for SetCursorNameRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 23;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCursorName";
+    type Reply = ();
+}
+#[doc = " A builder for `SetCursorNameRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetCursorNameRequestBuilder {
+    pub inner: SetCursorNameRequest,
+}
+impl SetCursorNameRequestBuilder {
+    #[inline]
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.inner.cursor = cursor;
+        self
+    }
+    #[inline]
+    pub fn name(mut self, name: String) -> Self {
+        self.inner.name = name;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetCursorNameRequest {
+        self.inner
+    }
+}
+impl Default for SetCursorNameRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetCursorNameRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                cursor: Default::default(),
+                name: Default::default(),
+            },
+        }
     }
 }
-impl Request for SetCursorNameRequest {
-    const OPCODE: u8 = 23;
-    const EXTENSION: Option<&'static str> = Some("XFIXES");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
-}
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCursorNameRequest {
     pub req_type: u8,
     pub length: u16,
     pub cursor: Cursor,
 }
-impl GetCursorNameRequest {}
-impl AsByteSequence for GetCursorNameRequest {
+impl GetCursorNameRequest {
+    #[inline]
+    pub fn builder() -> GetCursorNameRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCursorNameReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetCursorNameRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetCursorNameRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.cursor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = parse_item(bytes, index, "cursor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCursorNameRequest {
                 req_type: req_type,
                 length: length,
@@ -2074,16 +3883,57 @@ impl AsByteSequence for GetCursorNameRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.cursor.size()
+        let size: usize = 4 + self.cursor.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetCursorNameRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetCursorNameRequest {
-    const OPCODE: u8 = 24;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 24;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCursorName";
     type Reply = GetCursorNameReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetCursorNameRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCursorNameRequestBuilder {
+    pub inner: GetCursorNameRequest,
+}
+impl GetCursorNameRequestBuilder {
+    #[inline]
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.inner.cursor = cursor;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCursorNameRequest {
+        self.inner
+    }
+}
+impl Default for GetCursorNameRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetCursorNameRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                cursor: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCursorNameReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2091,43 +3941,81 @@ pub struct GetCursorNameReply {
     pub atom: Atom,
     pub name: String,
 }
-impl GetCursorNameReply {}
-impl AsByteSequence for GetCursorNameReply {
+impl GetCursorNameReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.atom == other.atom && self.name == other.name
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("atom", &self.atom, &other.atom, &mut changes);
+        diff_list(
+            "name",
+            str::as_bytes(&self.name),
+            str::as_bytes(&other.name),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetCursorNameReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.atom.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 18;
+        index += zero_pad(&mut bytes[index..], 18);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (atom, sz): (Atom, usize) = parse_item(bytes, index, "atom")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 18;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCursorNameReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2140,64 +4028,86 @@ impl AsByteSequence for GetCursorNameReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.atom.size()
-            + ::core::mem::size_of::<Card16>()
-            + 18
-            + {
-                let block_len: usize = self.name.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+        let size: usize = 28 + self.atom.size() + {
+            let block_len: usize = self.name.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetCursorNameReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCursorImageAndNameRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetCursorImageAndNameRequest {}
-impl AsByteSequence for GetCursorImageAndNameRequest {
+impl GetCursorImageAndNameRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetCursorImageAndNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetCursorImageAndNameRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetCursorImageAndNameRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCursorImageAndNameReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetCursorImageAndNameRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { GetCursorImageAndNameRequest }
 impl Request for GetCursorImageAndNameRequest {
-    const OPCODE: u8 = 25;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 25;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCursorImageAndName";
     type Reply = GetCursorImageAndNameReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetCursorImageAndNameRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCursorImageAndNameRequestBuilder {
+    pub inner: GetCursorImageAndNameRequest,
+}
+impl GetCursorImageAndNameRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCursorImageAndNameRequest {
+        self.inner
+    }
+}
+impl Default for GetCursorImageAndNameRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetCursorImageAndNameRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCursorImageAndNameReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2213,13 +4123,125 @@ pub struct GetCursorImageAndNameReply {
     pub cursor_image: Vec<Card32>,
     pub name: String,
 }
-impl GetCursorImageAndNameReply {}
-impl AsByteSequence for GetCursorImageAndNameReply {
+impl GetCursorImageAndNameReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn cursor_image_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
+        index += sz;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        let (xhot, sz): (Card16, usize) = parse_item(bytes, index, "xhot")?;
+        index += sz;
+        let (yhot, sz): (Card16, usize) = parse_item(bytes, index, "yhot")?;
+        index += sz;
+        let (cursor_serial, sz): (Card32, usize) = parse_item(bytes, index, "cursor_serial")?;
+        index += sz;
+        let (cursor_atom, sz): (Atom, usize) = parse_item(bytes, index, "cursor_atom")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 2;
+        let (cursor_image, _): (Cow<'_, [Card32]>, usize) = parse_slice(
+            bytes,
+            index,
+            ((width as usize) * (height as usize)) as usize,
+            "cursor_image",
+        )?;
+        Ok(cursor_image)
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("x", 8, 2),
+        ("y", 10, 2),
+        ("width", 12, 2),
+        ("height", 14, 2),
+        ("xhot", 16, 2),
+        ("yhot", 18, 2),
+        ("cursor_serial", 20, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.x == other.x
+            && self.y == other.y
+            && self.width == other.width
+            && self.height == other.height
+            && self.xhot == other.xhot
+            && self.yhot == other.yhot
+            && self.cursor_serial == other.cursor_serial
+            && self.cursor_atom == other.cursor_atom
+            && self.cursor_image == other.cursor_image
+            && self.name == other.name
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("x", &self.x, &other.x, &mut changes);
+        diff_value("y", &self.y, &other.y, &mut changes);
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_value("xhot", &self.xhot, &other.xhot, &mut changes);
+        diff_value("yhot", &self.yhot, &other.yhot, &mut changes);
+        diff_value(
+            "cursor_serial",
+            &self.cursor_serial,
+            &other.cursor_serial,
+            &mut changes,
+        );
+        diff_value(
+            "cursor_atom",
+            &self.cursor_atom,
+            &other.cursor_atom,
+            &mut changes,
+        );
+        diff_list(
+            "cursor_image",
+            &self.cursor_image,
+            &other.cursor_image,
+            &mut changes,
+        );
+        diff_list(
+            "name",
+            str::as_bytes(&self.name),
+            str::as_bytes(&other.name),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetCursorImageAndNameReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.x.as_bytes(&mut bytes[index..]);
@@ -2231,55 +4253,65 @@ impl AsByteSequence for GetCursorImageAndNameReply {
         index += self.cursor_serial.as_bytes(&mut bytes[index..]);
         index += self.cursor_atom.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.cursor_image, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorImageAndNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (x, sz): (Int16, usize) = parse_item(bytes, index, "x")?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = parse_item(bytes, index, "y")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (xhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (xhot, sz): (Card16, usize) = parse_item(bytes, index, "xhot")?;
         index += sz;
-        let (yhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (yhot, sz): (Card16, usize) = parse_item(bytes, index, "yhot")?;
         index += sz;
-        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (cursor_serial, sz): (Card32, usize) = parse_item(bytes, index, "cursor_serial")?;
         index += sz;
-        let (cursor_atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (cursor_atom, sz): (Atom, usize) = parse_item(bytes, index, "cursor_atom")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
-        let (cursor_image, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (cursor_image, block_len): (Vec<Card32>, usize) = parse_vector(
+            bytes,
+            index,
             ((width as usize) * (height as usize)) as usize,
+            "cursor_image",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCursorImageAndNameReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2298,22 +4330,9 @@ impl AsByteSequence for GetCursorImageAndNameReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.x.size()
-            + self.y.size()
-            + self.width.size()
-            + self.height.size()
-            + self.xhot.size()
-            + self.yhot.size()
-            + self.cursor_serial.size()
+        let size: usize = 28
             + self.cursor_atom.size()
-            + ::core::mem::size_of::<Card16>()
-            + 2
             + {
                 let block_len: usize = self.cursor_image.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
@@ -2323,42 +4342,110 @@ impl AsByteSequence for GetCursorImageAndNameReply {
                 let block_len: usize = self.name.len();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
-}
-#[derive(Clone, Debug, Default)]
+    fn size_hint(&self) -> usize {
+        let size: usize = 28
+            + self.cursor_atom.size()
+            + {
+                let block_len: usize = self.cursor_image.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
+            }
+            + {
+                let block_len: usize = self.name.len();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetCursorImageAndNameReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCursorImageAndNameReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let x: Int16 = u.arbitrary()?;
+        let y: Int16 = u.arbitrary()?;
+        let width: Card16 = u.arbitrary()?;
+        let height: Card16 = u.arbitrary()?;
+        let xhot: Card16 = u.arbitrary()?;
+        let yhot: Card16 = u.arbitrary()?;
+        let cursor_serial: Card32 = u.arbitrary()?;
+        let cursor_atom: Atom = u.arbitrary()?;
+        let name = u.arbitrary()?;
+        let cursor_image = arbitrary_list(u, ((width as usize) * (height as usize)) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            x: x,
+            y: y,
+            width: width,
+            height: height,
+            xhot: xhot,
+            yhot: yhot,
+            cursor_serial: cursor_serial,
+            cursor_atom: cursor_atom,
+            cursor_image: cursor_image,
+            name: name,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangeCursorRequest {
     pub req_type: u8,
     pub length: u16,
     pub source: Cursor,
     pub destination: Cursor,
 }
-impl ChangeCursorRequest {}
-impl AsByteSequence for ChangeCursorRequest {
+impl ChangeCursorRequest {
     #[inline]
+    pub fn builder() -> ChangeCursorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<ChangeCursorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ChangeCursorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.source.as_bytes(&mut bytes[index..]);
         index += self.destination.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (source, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Cursor, usize) = parse_item(bytes, index, "source")?;
         index += sz;
-        let (destination, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Cursor, usize) = parse_item(bytes, index, "destination")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ChangeCursorRequest {
                 req_type: req_type,
                 length: length,
@@ -2370,56 +4457,125 @@ impl AsByteSequence for ChangeCursorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.source.size() + self.destination.size()
+        let size: usize = 4 + self.source.size() + self.destination.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ChangeCursorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ChangeCursorRequest {
-    const OPCODE: u8 = 26;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 26;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeCursor";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ChangeCursorRequest`."]
+#[derive(Clone, Debug)]
+pub struct ChangeCursorRequestBuilder {
+    pub inner: ChangeCursorRequest,
+}
+impl ChangeCursorRequestBuilder {
+    #[inline]
+    pub fn source(mut self, source: Cursor) -> Self {
+        self.inner.source = source;
+        self
+    }
+    #[inline]
+    pub fn destination(mut self, destination: Cursor) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangeCursorRequest {
+        self.inner
+    }
+}
+impl Default for ChangeCursorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangeCursorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source: Default::default(),
+                destination: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ChangeCursorByNameRequest {
     pub req_type: u8,
     pub length: u16,
     pub src: Cursor,
     pub name: String,
 }
-impl ChangeCursorByNameRequest {}
-impl AsByteSequence for ChangeCursorByNameRequest {
+impl ChangeCursorByNameRequest {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(src: Cursor, name: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            src: src,
+            name: String::from(name),
+        }
+    }
     #[inline]
+    pub fn builder() -> ChangeCursorByNameRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<ChangeCursorByNameRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ChangeCursorByNameRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.src.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeCursorByNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (src, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Cursor, usize) = parse_item(bytes, index, "src")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ChangeCursorByNameRequest {
                 req_type: req_type,
                 length: length,
@@ -2431,26 +4587,68 @@ impl AsByteSequence for ChangeCursorByNameRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.src.size()
-            + ::core::mem::size_of::<Card16>()
-            + 2
-            + {
-                let block_len: usize = self.name.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+        let size: usize = 8 + self.src.size() + {
+            let block_len: usize = self.name.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ChangeCursorByNameRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ChangeCursorByNameRequest {
-    const OPCODE: u8 = 27;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 27;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeCursorByName";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ChangeCursorByNameRequest`."]
+#[derive(Clone, Debug)]
+pub struct ChangeCursorByNameRequestBuilder {
+    pub inner: ChangeCursorByNameRequest,
+}
+impl ChangeCursorByNameRequestBuilder {
+    #[inline]
+    pub fn src(mut self, src: Cursor) -> Self {
+        self.inner.src = src;
+        self
+    }
+    #[inline]
+    pub fn name(mut self, name: String) -> Self {
+        self.inner.name = name;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ChangeCursorByNameRequest {
+        self.inner
+    }
+}
+impl Default for ChangeCursorByNameRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ChangeCursorByNameRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                src: Default::default(),
+                name: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ExpandRegionRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2461,13 +4659,29 @@ pub struct ExpandRegionRequest {
     pub top: Card16,
     pub bottom: Card16,
 }
-impl ExpandRegionRequest {}
-impl AsByteSequence for ExpandRegionRequest {
+impl ExpandRegionRequest {
     #[inline]
+    pub fn builder() -> ExpandRegionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("source", 4, 4),
+        ("destination", 8, 4),
+        ("left", 12, 2),
+        ("right", 14, 2),
+        ("top", 16, 2),
+        ("bottom", 18, 2),
+    ];
+}
+const _: () = assert!(<ExpandRegionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ExpandRegionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.source.as_bytes(&mut bytes[index..]);
         index += self.destination.as_bytes(&mut bytes[index..]);
@@ -2475,30 +4689,31 @@ impl AsByteSequence for ExpandRegionRequest {
         index += self.right.as_bytes(&mut bytes[index..]);
         index += self.top.as_bytes(&mut bytes[index..]);
         index += self.bottom.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ExpandRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Region, usize) = parse_item(bytes, index, "source")?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = parse_item(bytes, index, "destination")?;
         index += sz;
-        let (left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Card16, usize) = parse_item(bytes, index, "left")?;
         index += sz;
-        let (right, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (right, sz): (Card16, usize) = parse_item(bytes, index, "right")?;
         index += sz;
-        let (top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Card16, usize) = parse_item(bytes, index, "top")?;
         index += sz;
-        let (bottom, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (bottom, sz): (Card16, usize) = parse_item(bytes, index, "bottom")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ExpandRegionRequest {
                 req_type: req_type,
                 length: length,
@@ -2514,121 +4729,251 @@ impl AsByteSequence for ExpandRegionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.source.size()
-            + self.destination.size()
-            + self.left.size()
-            + self.right.size()
-            + self.top.size()
-            + self.bottom.size()
+        let size: usize = 20;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ExpandRegionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ExpandRegionRequest {
-    const OPCODE: u8 = 28;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 28;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ExpandRegion";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ExpandRegionRequest`."]
+#[derive(Clone, Debug)]
+pub struct ExpandRegionRequestBuilder {
+    pub inner: ExpandRegionRequest,
+}
+impl ExpandRegionRequestBuilder {
+    #[inline]
+    pub fn source(mut self, source: Region) -> Self {
+        self.inner.source = source;
+        self
+    }
+    #[inline]
+    pub fn destination(mut self, destination: Region) -> Self {
+        self.inner.destination = destination;
+        self
+    }
+    #[inline]
+    pub fn left(mut self, left: Card16) -> Self {
+        self.inner.left = left;
+        self
+    }
+    #[inline]
+    pub fn right(mut self, right: Card16) -> Self {
+        self.inner.right = right;
+        self
+    }
+    #[inline]
+    pub fn top(mut self, top: Card16) -> Self {
+        self.inner.top = top;
+        self
+    }
+    #[inline]
+    pub fn bottom(mut self, bottom: Card16) -> Self {
+        self.inner.bottom = bottom;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ExpandRegionRequest {
+        self.inner
+    }
+}
+impl Default for ExpandRegionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ExpandRegionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                source: Default::default(),
+                destination: Default::default(),
+                left: Default::default(),
+                right: Default::default(),
+                top: Default::default(),
+                bottom: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct HideCursorRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl HideCursorRequest {}
-impl AsByteSequence for HideCursorRequest {
+impl HideCursorRequest {
+    #[inline]
+    pub fn builder() -> HideCursorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<HideCursorRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_window_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.window.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        window: window,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.window.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_window_only_request_asb! { HideCursorRequest }
+impl Request for HideCursorRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 29;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "HideCursor";
+    type Reply = ();
+}
+#[doc = " A builder for `HideCursorRequest`."]
+#[derive(Clone, Debug)]
+pub struct HideCursorRequestBuilder {
+    pub inner: HideCursorRequest,
+}
+impl HideCursorRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing HideCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            HideCursorRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> HideCursorRequest {
+        self.inner
+    }
+}
+impl Default for HideCursorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: HideCursorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct ShowCursorRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub window: Window,
+}
+impl ShowCursorRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    pub fn builder() -> ShowCursorRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
-impl Request for HideCursorRequest {
-    const OPCODE: u8 = 29;
+const _: () = assert!(<ShowCursorRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { ShowCursorRequest }
+impl Request for ShowCursorRequest {
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 30;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ShowCursor";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct ShowCursorRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub window: Window,
+#[doc = " A builder for `ShowCursorRequest`."]
+#[derive(Clone, Debug)]
+pub struct ShowCursorRequestBuilder {
+    pub inner: ShowCursorRequest,
 }
-impl ShowCursorRequest {}
-impl AsByteSequence for ShowCursorRequest {
+impl ShowCursorRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ShowCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            ShowCursorRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ShowCursorRequest {
+        self.inner
     }
+}
+impl Default for ShowCursorRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    fn default() -> Self {
+        Self {
+            inner: ShowCursorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
     }
 }
-impl Request for ShowCursorRequest {
-    const OPCODE: u8 = 30;
-    const EXTENSION: Option<&'static str> = Some("XFIXES");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
-}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Barrier {
     pub xid: XID,
 }
@@ -2637,6 +4982,23 @@ impl Barrier {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Barrier {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Barrier {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Barrier {
     #[inline]
@@ -2648,7 +5010,30 @@ impl XidType for Barrier {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Barrier {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Barrier({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Barrier {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Barrier> for XID {
+    #[inline]
+    fn from(base: Barrier) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreatePointerBarrierRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2661,13 +5046,49 @@ pub struct CreatePointerBarrierRequest {
     pub directions: BarrierDirections,
     pub devices: Vec<Card16>,
 }
-impl CreatePointerBarrierRequest {}
-impl AsByteSequence for CreatePointerBarrierRequest {
+impl CreatePointerBarrierRequest {
+    pub fn devices_slice(bytes: &[u8]) -> Result<Cow<'_, [Card16]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (barrier, sz): (Barrier, usize) = parse_item(bytes, index, "barrier")?;
+        index += sz;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+        index += sz;
+        let (x1, sz): (Card16, usize) = parse_item(bytes, index, "x1")?;
+        index += sz;
+        let (y1, sz): (Card16, usize) = parse_item(bytes, index, "y1")?;
+        index += sz;
+        let (x2, sz): (Card16, usize) = parse_item(bytes, index, "x2")?;
+        index += sz;
+        let (y2, sz): (Card16, usize) = parse_item(bytes, index, "y2")?;
+        index += sz;
+        let (directions, sz): (BarrierDirections, usize) = parse_item(bytes, index, "directions")?;
+        index += sz;
+        index += 2;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (devices, _): (Cow<'_, [Card16]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "devices")?;
+        Ok(devices)
+    }
     #[inline]
+    pub fn builder() -> CreatePointerBarrierRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("barrier", 4, 4)];
+}
+const _: () = assert!(<CreatePointerBarrierRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreatePointerBarrierRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.barrier.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
@@ -2676,45 +5097,48 @@ impl AsByteSequence for CreatePointerBarrierRequest {
         index += self.x2.as_bytes(&mut bytes[index..]);
         index += self.y2.as_bytes(&mut bytes[index..]);
         index += self.directions.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += (self.devices.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.devices, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card16>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePointerBarrierRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(&bytes[index..])?;
+        let (barrier, sz): (Barrier, usize) = parse_item(bytes, index, "barrier")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (x1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x1, sz): (Card16, usize) = parse_item(bytes, index, "x1")?;
         index += sz;
-        let (y1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y1, sz): (Card16, usize) = parse_item(bytes, index, "y1")?;
         index += sz;
-        let (x2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x2, sz): (Card16, usize) = parse_item(bytes, index, "x2")?;
         index += sz;
-        let (y2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y2, sz): (Card16, usize) = parse_item(bytes, index, "y2")?;
         index += sz;
-        let (directions, sz): (BarrierDirections, usize) =
-            <BarrierDirections>::from_bytes(&bytes[index..])?;
+        let (directions, sz): (BarrierDirections, usize) = parse_item(bytes, index, "directions")?;
         index += sz;
         index += 2;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (devices, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "devices")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreatePointerBarrierRequest {
                 req_type: req_type,
                 length: length,
@@ -2732,33 +5156,127 @@ impl AsByteSequence for CreatePointerBarrierRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.barrier.size()
-            + self.window.size()
-            + self.x1.size()
-            + self.y1.size()
-            + self.x2.size()
-            + self.y2.size()
-            + self.directions.size()
-            + 2
-            + ::core::mem::size_of::<Card16>()
-            + {
-                let block_len: usize = self.devices.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-                block_len + pad
-            }
+        let size: usize = 24 + self.window.size() + {
+            let block_len: usize = self.devices.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 24 + self.window.size() + {
+            let block_len: usize = self.devices.len() * ::core::mem::size_of::<Card16>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreatePointerBarrierRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreatePointerBarrierRequest {
-    const OPCODE: u8 = 31;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 31;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePointerBarrier";
     type Reply = ();
 }
+#[doc = " A builder for `CreatePointerBarrierRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreatePointerBarrierRequestBuilder {
+    pub inner: CreatePointerBarrierRequest,
+}
+impl CreatePointerBarrierRequestBuilder {
+    #[inline]
+    pub fn barrier(mut self, barrier: Barrier) -> Self {
+        self.inner.barrier = barrier;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn x1(mut self, x1: Card16) -> Self {
+        self.inner.x1 = x1;
+        self
+    }
+    #[inline]
+    pub fn y1(mut self, y1: Card16) -> Self {
+        self.inner.y1 = y1;
+        self
+    }
+    #[inline]
+    pub fn x2(mut self, x2: Card16) -> Self {
+        self.inner.x2 = x2;
+        self
+    }
+    #[inline]
+    pub fn y2(mut self, y2: Card16) -> Self {
+        self.inner.y2 = y2;
+        self
+    }
+    #[inline]
+    pub fn directions(mut self, directions: BarrierDirections) -> Self {
+        self.inner.directions = directions;
+        self
+    }
+    #[inline]
+    pub fn devices(mut self, devices: Vec<Card16>) -> Self {
+        self.inner.devices = devices;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreatePointerBarrierRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card16> for CreatePointerBarrierRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card16>>(&mut self, iter: I) {
+        self.inner.devices.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card16> for CreatePointerBarrierRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card16>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for CreatePointerBarrierRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreatePointerBarrierRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                barrier: Default::default(),
+                window: Default::default(),
+                x1: Default::default(),
+                y1: Default::default(),
+                x2: Default::default(),
+                y2: Default::default(),
+                directions: Default::default(),
+                devices: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct BarrierDirections {
     pub inner: u32,
 }
@@ -2842,21 +5360,20 @@ impl BarrierDirections {
     pub const NEGATIVE_Y: Self = Self { inner: 8 };
     pub const COMPLETE: Self = Self { inner: 15 };
 }
-impl AsByteSequence for BarrierDirections {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for BarrierDirections {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((BarrierDirections { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for BarrierDirections {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+impl_u32_flags_asb! { BarrierDirections }
 impl core::ops::Not for BarrierDirections {
     type Output = BarrierDirections;
     #[inline]
@@ -2891,35 +5408,47 @@ impl core::ops::BitXor for BarrierDirections {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DeletePointerBarrierRequest {
     pub req_type: u8,
     pub length: u16,
     pub barrier: Barrier,
 }
-impl DeletePointerBarrierRequest {}
-impl AsByteSequence for DeletePointerBarrierRequest {
+impl DeletePointerBarrierRequest {
     #[inline]
+    pub fn builder() -> DeletePointerBarrierRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("barrier", 4, 4)];
+}
+const _: () = assert!(<DeletePointerBarrierRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DeletePointerBarrierRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.barrier.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeletePointerBarrierRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(&bytes[index..])?;
+        let (barrier, sz): (Barrier, usize) = parse_item(bytes, index, "barrier")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DeletePointerBarrierRequest {
                 req_type: req_type,
                 length: length,
@@ -2930,163 +5459,339 @@ impl AsByteSequence for DeletePointerBarrierRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.barrier.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DeletePointerBarrierRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DeletePointerBarrierRequest {
-    const OPCODE: u8 = 32;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
+    const MINOR_OPCODE: u16 = 32;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeletePointerBarrier";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct SelectionNotifyEvent {
+#[doc = " A builder for `DeletePointerBarrierRequest`."]
+#[derive(Clone, Debug)]
+pub struct DeletePointerBarrierRequestBuilder {
+    pub inner: DeletePointerBarrierRequest,
+}
+impl DeletePointerBarrierRequestBuilder {
+    #[inline]
+    pub fn barrier(mut self, barrier: Barrier) -> Self {
+        self.inner.barrier = barrier;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DeletePointerBarrierRequest {
+        self.inner
+    }
+}
+impl Default for DeletePointerBarrierRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DeletePointerBarrierRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                barrier: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct CursorNotifyEvent {
     pub event_type: u8,
-    pub subtype: SelectionEvent,
+    pub subtype: Card8,
     pub sequence: u16,
     pub window: Window,
-    pub owner: Window,
-    pub selection: Atom,
+    pub cursor_serial: Card32,
     pub timestamp: Timestamp,
-    pub selection_timestamp: Timestamp,
+    pub name: Atom,
 }
-impl SelectionNotifyEvent {}
-impl AsByteSequence for SelectionNotifyEvent {
+impl CursorNotifyEvent {
     #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 1;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("subtype", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for CursorNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
         index += self.subtype.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.owner.as_bytes(&mut bytes[index..]);
-        index += self.selection.as_bytes(&mut bytes[index..]);
+        index += self.cursor_serial.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
-        index += self.selection_timestamp.as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += self.name.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 12);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing SelectionNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (subtype, sz): (SelectionEvent, usize) = <SelectionEvent>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing CursorNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (subtype, sz): (Card8, usize) = parse_item(bytes, index, "subtype")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (owner, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (cursor_serial, sz): (Card32, usize) = parse_item(bytes, index, "cursor_serial")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        let (selection_timestamp, sz): (Timestamp, usize) =
-            <Timestamp>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        index += 8;
-        Some((
-            SelectionNotifyEvent {
+        index += 12;
+        Ok((
+            CursorNotifyEvent {
                 event_type: event_type,
                 subtype: subtype,
                 sequence: sequence,
                 window: window,
-                owner: owner,
-                selection: selection,
+                cursor_serial: cursor_serial,
                 timestamp: timestamp,
-                selection_timestamp: selection_timestamp,
+                name: name,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.subtype.size()
-            + self.sequence.size()
-            + self.window.size()
-            + self.owner.size()
-            + self.selection.size()
-            + self.timestamp.size()
-            + self.selection_timestamp.size()
-            + 8
+        20 + self.window.size() + self.timestamp.size() + self.name.size()
     }
 }
-impl crate::auto::Event for SelectionNotifyEvent {
-    const OPCODE: u8 = 0;
+impl core::convert::TryFrom<&[u8]> for CursorNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct CursorNotifyEvent {
+impl crate::auto::Event for CursorNotifyEvent {
+    const OPCODE: u8 = 1;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct SelectionNotifyEvent {
     pub event_type: u8,
-    pub subtype: Card8,
+    pub subtype: SelectionEvent,
     pub sequence: u16,
     pub window: Window,
-    pub cursor_serial: Card32,
+    pub owner: Window,
+    pub selection: Atom,
     pub timestamp: Timestamp,
-    pub name: Atom,
+    pub selection_timestamp: Timestamp,
 }
-impl CursorNotifyEvent {}
-impl AsByteSequence for CursorNotifyEvent {
+impl SelectionNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("subtype", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for SelectionNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
         index += self.subtype.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.cursor_serial.as_bytes(&mut bytes[index..]);
+        index += self.owner.as_bytes(&mut bytes[index..]);
+        index += self.selection.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
-        index += self.name.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += self.selection_timestamp.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 8);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing CursorNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing SelectionNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (subtype, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (subtype, sz): (SelectionEvent, usize) = parse_item(bytes, index, "subtype")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (owner, sz): (Window, usize) = parse_item(bytes, index, "owner")?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = parse_item(bytes, index, "selection")?;
         index += sz;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = parse_item(bytes, index, "timestamp")?;
         index += sz;
-        index += 12;
-        Some((
-            CursorNotifyEvent {
+        let (selection_timestamp, sz): (Timestamp, usize) =
+            parse_item(bytes, index, "selection_timestamp")?;
+        index += sz;
+        index += 8;
+        Ok((
+            SelectionNotifyEvent {
                 event_type: event_type,
                 subtype: subtype,
                 sequence: sequence,
                 window: window,
-                cursor_serial: cursor_serial,
+                owner: owner,
+                selection: selection,
                 timestamp: timestamp,
-                name: name,
+                selection_timestamp: selection_timestamp,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.subtype.size()
-            + self.sequence.size()
-            + self.window.size()
-            + self.cursor_serial.size()
+        12 + self.window.size()
+            + self.owner.size()
+            + self.selection.size()
             + self.timestamp.size()
-            + self.name.size()
-            + 12
+            + self.selection_timestamp.size()
     }
 }
-impl crate::auto::Event for CursorNotifyEvent {
-    const OPCODE: u8 = 1;
+impl core::convert::TryFrom<&[u8]> for SelectionNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl crate::auto::Event for SelectionNotifyEvent {
+    const OPCODE: u8 = 0;
+}
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    CursorNotify(CursorNotifyEvent),
+    SelectionNotify(SelectionNotifyEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            1 => Ok(Self::CursorNotify(CursorNotifyEvent::from_bytes(bytes)?.0)),
+            0 => Ok(Self::SelectionNotify(
+                SelectionNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::CursorNotify(_) => CursorNotifyEvent::RESPONSE_TYPE,
+            Self::SelectionNotify(_) => SelectionNotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<CursorNotifyEvent> for Event {
+    #[inline]
+    fn from(event: CursorNotifyEvent) -> Self {
+        Self::CursorNotify(event)
+    }
+}
+impl From<SelectionNotifyEvent> for Event {
+    #[inline]
+    fn from(event: SelectionNotifyEvent) -> Self {
+        Self::SelectionNotify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "SelectionNotify"), (1, "CursorNotify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "ChangeSaveSet"),
+    (2, "SelectSelectionInput"),
+    (3, "SelectCursorInput"),
+    (4, "GetCursorImage"),
+    (5, "CreateRegion"),
+    (6, "CreateRegionFromBitmap"),
+    (7, "CreateRegionFromWindow"),
+    (8, "CreateRegionFromGc"),
+    (9, "CreateRegionFromPicture"),
+    (10, "DestroyRegion"),
+    (11, "SetRegion"),
+    (12, "CopyRegion"),
+    (13, "UnionRegion"),
+    (14, "IntersectRegion"),
+    (15, "SubtractRegion"),
+    (16, "InvertRegion"),
+    (17, "TranslateRegion"),
+    (18, "RegionExtents"),
+    (19, "FetchRegion"),
+    (20, "SetGcClipRegion"),
+    (21, "SetWindowShapeRegion"),
+    (22, "SetPictureClipRegion"),
+    (23, "SetCursorName"),
+    (24, "GetCursorName"),
+    (25, "GetCursorImageAndName"),
+    (26, "ChangeCursor"),
+    (27, "ChangeCursorByName"),
+    (28, "ExpandRegion"),
+    (29, "HideCursor"),
+    (30, "ShowCursor"),
+    (31, "CreatePointerBarrier"),
+    (32, "DeletePointerBarrier"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
 }
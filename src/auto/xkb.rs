@@ -1,16 +1,21 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "xkb")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 0;
 use super::xproto::*;
 pub type DeviceSpec = Card16;
 pub type LedClassSpec = Card16;
 pub type BellClassSpec = Card16;
 pub type IdSpec = Card16;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct IndicatorMap {
     pub flags: ImFlag,
     pub which_groups: ImGroupsWhich,
@@ -21,9 +26,16 @@ pub struct IndicatorMap {
     pub vmods: VMod,
     pub ctrls: BoolCtrl,
 }
-impl IndicatorMap {}
+impl IndicatorMap {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("flags", 0, 1),
+        ("which_groups", 1, 1),
+        ("groups", 2, 1),
+        ("which_mods", 3, 1),
+    ];
+}
 impl AsByteSequence for IndicatorMap {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.flags.as_bytes(&mut bytes[index..]);
@@ -36,28 +48,26 @@ impl AsByteSequence for IndicatorMap {
         index += self.ctrls.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing IndicatorMap from byte buffer");
-        let (flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ImFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (which_groups, sz): (ImGroupsWhich, usize) =
-            <ImGroupsWhich>::from_bytes(&bytes[index..])?;
+        let (which_groups, sz): (ImGroupsWhich, usize) = parse_item(bytes, index, "which_groups")?;
         index += sz;
-        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (groups, sz): (SetOfGroup, usize) = parse_item(bytes, index, "groups")?;
         index += sz;
-        let (which_mods, sz): (ImModsWhich, usize) = <ImModsWhich>::from_bytes(&bytes[index..])?;
+        let (which_mods, sz): (ImModsWhich, usize) = parse_item(bytes, index, "which_mods")?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods")?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "real_mods")?;
         index += sz;
-        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (vmods, sz): (VMod, usize) = parse_item(bytes, index, "vmods")?;
         index += sz;
-        let (ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (ctrls, sz): (BoolCtrl, usize) = parse_item(bytes, index, "ctrls")?;
         index += sz;
-        Some((
+        Ok((
             IndicatorMap {
                 flags: flags,
                 which_groups: which_groups,
@@ -73,18 +83,20 @@ impl AsByteSequence for IndicatorMap {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.flags.size()
-            + self.which_groups.size()
-            + self.groups.size()
-            + self.which_mods.size()
-            + self.mods.size()
-            + self.real_mods.size()
-            + self.vmods.size()
-            + self.ctrls.size()
+        10 + self.mods.size() + self.real_mods.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for IndicatorMap {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ImFlag {
     pub inner: u8,
 }
@@ -151,21 +163,46 @@ impl ImFlag {
     pub const NO_EXPLICIT: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 224 };
 }
-impl AsByteSequence for ImFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for ImFlag {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((ImFlag { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for ImFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+macro_rules! impl_u8_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                1
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_u8_flags_asb! { ImFlag }
 impl core::ops::Not for ImFlag {
     type Output = ImFlag;
     #[inline]
@@ -201,7 +238,9 @@ impl core::ops::BitXor for ImFlag {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ImGroupsWhich {
     pub inner: u8,
 }
@@ -308,21 +347,20 @@ impl ImGroupsWhich {
     pub const USE_COMPAT: Self = Self { inner: 16 };
     pub const COMPLETE: Self = Self { inner: 31 };
 }
-impl AsByteSequence for ImGroupsWhich {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for ImGroupsWhich {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((ImGroupsWhich { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for ImGroupsWhich {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { ImGroupsWhich }
 impl core::ops::Not for ImGroupsWhich {
     type Output = ImGroupsWhich;
     #[inline]
@@ -358,7 +396,9 @@ impl core::ops::BitXor for ImGroupsWhich {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetOfGroup {
     pub inner: u8,
 }
@@ -442,21 +482,20 @@ impl SetOfGroup {
     pub const GROUP4: Self = Self { inner: 8 };
     pub const COMPLETE: Self = Self { inner: 15 };
 }
-impl AsByteSequence for SetOfGroup {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for SetOfGroup {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((SetOfGroup { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for SetOfGroup {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { SetOfGroup }
 impl core::ops::Not for SetOfGroup {
     type Output = SetOfGroup;
     #[inline]
@@ -492,7 +531,9 @@ impl core::ops::BitXor for SetOfGroup {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ImModsWhich {
     pub inner: u8,
 }
@@ -599,21 +640,20 @@ impl ImModsWhich {
     pub const USE_COMPAT: Self = Self { inner: 16 };
     pub const COMPLETE: Self = Self { inner: 31 };
 }
-impl AsByteSequence for ImModsWhich {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for ImModsWhich {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((ImModsWhich { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for ImModsWhich {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { ImModsWhich }
 impl core::ops::Not for ImModsWhich {
     type Output = ImModsWhich;
     #[inline]
@@ -649,7 +689,9 @@ impl core::ops::BitXor for ImModsWhich {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct VMod {
     pub inner: u16,
 }
@@ -862,7 +904,6 @@ impl VMod {
         }
         self
     }
-    #[inline]
     pub fn new(
         Zero: bool,
         One: bool,
@@ -954,21 +995,46 @@ impl VMod {
     pub const FIFTEEN: Self = Self { inner: 32768 };
     pub const COMPLETE: Self = Self { inner: 65535 };
 }
-impl AsByteSequence for VMod {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for VMod {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((VMod { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for VMod {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+macro_rules! impl_u16_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                2
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_u16_flags_asb! { VMod }
 impl core::ops::Not for VMod {
     type Output = VMod;
     #[inline]
@@ -1004,7 +1070,9 @@ impl core::ops::BitXor for VMod {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct BoolCtrl {
     pub inner: u32,
 }
@@ -1178,7 +1246,6 @@ impl BoolCtrl {
         }
         self
     }
-    #[inline]
     pub fn new(
         repeat_keys: bool,
         slow_keys: bool,
@@ -1255,21 +1322,46 @@ impl BoolCtrl {
     pub const IGNORE_GROUP_LOCK_MASK: Self = Self { inner: 4096 };
     pub const COMPLETE: Self = Self { inner: 8191 };
 }
-impl AsByteSequence for BoolCtrl {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for BoolCtrl {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((BoolCtrl { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for BoolCtrl {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+macro_rules! impl_u32_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                4
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_u32_flags_asb! { BoolCtrl }
 impl core::ops::Not for BoolCtrl {
     type Output = BoolCtrl;
     #[inline]
@@ -1304,13 +1396,18 @@ impl core::ops::BitXor for BoolCtrl {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ModDef {
     pub mask: ModMask,
     pub real_mods: ModMask,
     pub vmods: VMod,
 }
-impl ModDef {}
+impl ModDef {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for ModDef {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1320,17 +1417,16 @@ impl AsByteSequence for ModDef {
         index += self.vmods.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModDef from byte buffer");
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = parse_item(bytes, index, "mask")?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "real_mods")?;
         index += sz;
-        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (vmods, sz): (VMod, usize) = parse_item(bytes, index, "vmods")?;
         index += sz;
-        Some((
+        Ok((
             ModDef {
                 mask: mask,
                 real_mods: real_mods,
@@ -1341,14 +1437,48 @@ impl AsByteSequence for ModDef {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.mask.size() + self.real_mods.size() + self.vmods.size()
+        2 + self.mask.size() + self.real_mods.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ModDef {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KeyName {
     pub name: [c_char; 4],
 }
-impl KeyName {}
+impl KeyName {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(name: [c_char; 4]) -> Self {
+        Self { name: name }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+}
+impl core::ops::Deref for KeyName {
+    type Target = [c_char; 4];
+    #[inline]
+    fn deref(&self) -> &[c_char; 4] {
+        &self.name
+    }
+}
+impl AsRef<[c_char; 4]> for KeyName {
+    #[inline]
+    fn as_ref(&self) -> &[c_char; 4] {
+        &self.name
+    }
+}
 impl AsByteSequence for KeyName {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1357,24 +1487,49 @@ impl AsByteSequence for KeyName {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyName from byte buffer");
-        let (name, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(&bytes[index..])?;
+        let (name, sz): ([c_char; 4], usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        Some((KeyName { name: name }, index))
+        Ok((KeyName { name: name }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.name.size()
+        4
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KeyName {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KeyAlias {
     pub real: [c_char; 4],
     pub alias: [c_char; 4],
 }
-impl KeyAlias {}
+impl KeyAlias {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(real: [c_char; 4], alias: [c_char; 4]) -> Self {
+        Self {
+            real: real,
+            alias: alias,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("real", 0, 4), ("alias", 4, 4)];
+}
 impl AsByteSequence for KeyAlias {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1384,14 +1539,14 @@ impl AsByteSequence for KeyAlias {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyAlias from byte buffer");
-        let (real, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(&bytes[index..])?;
+        let (real, sz): ([c_char; 4], usize) = parse_item(bytes, index, "real")?;
         index += sz;
-        let (alias, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(&bytes[index..])?;
+        let (alias, sz): ([c_char; 4], usize) = parse_item(bytes, index, "alias")?;
         index += sz;
-        Some((
+        Ok((
             KeyAlias {
                 real: real,
                 alias: alias,
@@ -1401,46 +1556,63 @@ impl AsByteSequence for KeyAlias {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.real.size() + self.alias.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KeyAlias {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CountedString16 {
     pub length: Card16,
     pub string: String,
     pub alignment_pad: Vec<Void>,
 }
-impl CountedString16 {}
+impl CountedString16 {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("length", 0, 2)];
+}
 impl AsByteSequence for CountedString16 {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.length.as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.string, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
         let block_len: usize = vector_as_bytes(&self.alignment_pad, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Void>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CountedString16 from byte buffer");
-        let (length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
         let (string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            parse_string(bytes, index, (length as usize) as usize, "string")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        let (alignment_pad, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (alignment_pad, block_len): (Vec<Void>, usize) = parse_vector(
+            bytes,
+            index,
             ((((length as usize) + (5)) & (!(3))) - ((length as usize) + (2))) as usize,
+            "alignment_pad",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
-        Some((
+        Ok((
             CountedString16 {
                 length: length,
                 string: string,
@@ -1451,20 +1623,54 @@ impl AsByteSequence for CountedString16 {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.length.size()
-            + {
-                let block_len: usize = self.string.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.alignment_pad.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
-                block_len + pad
-            }
+        2 + {
+            let block_len: usize = self.string.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.alignment_pad.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Void>());
+            block_len + pad
+        }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for CountedString16 {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for CountedString16 {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CountedString16")
+            .field("length", &self.length)
+            .field("string", &self.string)
+            .field("alignment_pad", &debug_bytes(&self.alignment_pad, 64))
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for CountedString16 {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let length: Card16 = u.arbitrary()?;
+        let string = arbitrary_string(u, (length as usize) as usize)?;
+        let alignment_pad = arbitrary_list(
+            u,
+            ((((length as usize) + (5)) & (!(3))) - ((length as usize) + (2))) as usize,
+        )?;
+        Ok(Self {
+            length: length,
+            string: string,
+            alignment_pad: alignment_pad,
+        })
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KtMapEntry {
     pub active: bool,
     pub mods_mask: ModMask,
@@ -1472,9 +1678,11 @@ pub struct KtMapEntry {
     pub mods_mods: ModMask,
     pub mods_vmods: VMod,
 }
-impl KtMapEntry {}
+impl KtMapEntry {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("active", 0, 1)];
+}
 impl AsByteSequence for KtMapEntry {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.active.as_bytes(&mut bytes[index..]);
@@ -1482,25 +1690,24 @@ impl AsByteSequence for KtMapEntry {
         index += self.level.as_bytes(&mut bytes[index..]);
         index += self.mods_mods.as_bytes(&mut bytes[index..]);
         index += self.mods_vmods.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KtMapEntry from byte buffer");
-        let (active, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (active, sz): (bool, usize) = parse_item(bytes, index, "active")?;
         index += sz;
-        let (mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mask, sz): (ModMask, usize) = parse_item(bytes, index, "mods_mask")?;
         index += sz;
-        let (level, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Card8, usize) = parse_item(bytes, index, "level")?;
         index += sz;
-        let (mods_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods_mods")?;
         index += sz;
-        let (mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (mods_vmods, sz): (VMod, usize) = parse_item(bytes, index, "mods_vmods")?;
         index += sz;
         index += 2;
-        Some((
+        Ok((
             KtMapEntry {
                 active: active,
                 mods_mask: mods_mask,
@@ -1513,15 +1720,18 @@ impl AsByteSequence for KtMapEntry {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.active.size()
-            + self.mods_mask.size()
-            + self.level.size()
-            + self.mods_mods.size()
-            + self.mods_vmods.size()
-            + 2
+        5 + self.active.size() + self.mods_mask.size() + self.mods_mods.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KtMapEntry {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KeyType {
     pub mods_mask: ModMask,
     pub mods_mods: ModMask,
@@ -1532,9 +1742,11 @@ pub struct KeyType {
     pub map: Vec<KtMapEntry>,
     pub preserve: Vec<ModDef>,
 }
-impl KeyType {}
+impl KeyType {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for KeyType {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.mods_mask.as_bytes(&mut bytes[index..]);
@@ -1543,43 +1755,50 @@ impl AsByteSequence for KeyType {
         index += self.num_levels.as_bytes(&mut bytes[index..]);
         index += self.n_map_entries.as_bytes(&mut bytes[index..]);
         index += self.has_preserve.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         let block_len: usize = vector_as_bytes(&self.map, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KtMapEntry>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<KtMapEntry>()),
+        );
         let block_len: usize = vector_as_bytes(&self.preserve, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ModDef>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyType from byte buffer");
-        let (mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mask, sz): (ModMask, usize) = parse_item(bytes, index, "mods_mask")?;
         index += sz;
-        let (mods_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods_mods")?;
         index += sz;
-        let (mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (mods_vmods, sz): (VMod, usize) = parse_item(bytes, index, "mods_vmods")?;
         index += sz;
-        let (num_levels, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_levels, sz): (Card8, usize) = parse_item(bytes, index, "num_levels")?;
         index += sz;
-        let (n_map_entries, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_map_entries, sz): (Card8, usize) = parse_item(bytes, index, "n_map_entries")?;
         index += sz;
-        let (has_preserve, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (has_preserve, sz): (bool, usize) = parse_item(bytes, index, "has_preserve")?;
         index += sz;
         index += 1;
         let (map, block_len): (Vec<KtMapEntry>, usize) =
-            vector_from_bytes(&bytes[index..], (n_map_entries as usize) as usize)?;
+            parse_vector(bytes, index, (n_map_entries as usize) as usize, "map")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KtMapEntry>());
-        let (preserve, block_len): (Vec<ModDef>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (preserve, block_len): (Vec<ModDef>, usize) = parse_vector(
+            bytes,
+            index,
             ((has_preserve as usize) * (n_map_entries as usize)) as usize,
+            "preserve",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
-        Some((
+        Ok((
             KeyType {
                 mods_mask: mods_mask,
                 mods_mods: mods_mods,
@@ -1593,15 +1812,10 @@ impl AsByteSequence for KeyType {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.mods_mask.size()
+        5 + self.mods_mask.size()
             + self.mods_mods.size()
-            + self.mods_vmods.size()
-            + self.num_levels.size()
-            + self.n_map_entries.size()
             + self.has_preserve.size()
-            + 1
             + {
                 let block_len: usize = self.map.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KtMapEntry>());
@@ -1614,16 +1828,56 @@ impl AsByteSequence for KeyType {
             }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KeyType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyType {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mods_mask: ModMask = u.arbitrary()?;
+        let mods_mods: ModMask = u.arbitrary()?;
+        let mods_vmods: VMod = u.arbitrary()?;
+        let num_levels: Card8 = u.arbitrary()?;
+        let n_map_entries: Card8 = u.arbitrary()?;
+        let has_preserve: bool = u.arbitrary()?;
+        let preserve = u.arbitrary()?;
+        let map = arbitrary_list(u, (n_map_entries as usize) as usize)?;
+        Ok(Self {
+            mods_mask: mods_mask,
+            mods_mods: mods_mods,
+            mods_vmods: mods_vmods,
+            num_levels: num_levels,
+            n_map_entries: n_map_entries,
+            has_preserve: has_preserve,
+            map: map,
+            preserve: preserve,
+        })
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KeySymMap {
     pub kt_index: [Card8; 4],
     pub group_info: Card8,
     pub width: Card8,
     pub syms: Vec<Keysym>,
 }
-impl KeySymMap {}
+impl KeySymMap {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("kt_index", 0, 4),
+        ("group_info", 4, 1),
+        ("width", 5, 1),
+        ("syms_len", 6, 2),
+    ];
+}
 impl AsByteSequence for KeySymMap {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.kt_index.as_bytes(&mut bytes[index..]);
@@ -1632,26 +1886,28 @@ impl AsByteSequence for KeySymMap {
         index += (self.syms.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.syms, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Keysym>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeySymMap from byte buffer");
-        let (kt_index, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(&bytes[index..])?;
+        let (kt_index, sz): ([Card8; 4], usize) = parse_item(bytes, index, "kt_index")?;
         index += sz;
-        let (group_info, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (group_info, sz): (Card8, usize) = parse_item(bytes, index, "group_info")?;
         index += sz;
-        let (width, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card8, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (syms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "syms")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
-        Some((
+        Ok((
             KeySymMap {
                 kt_index: kt_index,
                 group_info: group_info,
@@ -1663,23 +1919,43 @@ impl AsByteSequence for KeySymMap {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.kt_index.size()
-            + self.group_info.size()
-            + self.width.size()
-            + ::core::mem::size_of::<Card16>()
-            + {
-                let block_len: usize = self.syms.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
-                block_len + pad
-            }
+        8 + {
+            let block_len: usize = self.syms.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
+            block_len + pad
+        }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KeySymMap {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CommonBehavior {
     pub ty: Card8,
     pub data: Card8,
 }
-impl CommonBehavior {}
+impl CommonBehavior {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(ty: Card8, data: Card8) -> Self {
+        Self { ty: ty, data: data }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 2;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1), ("data", 1, 1)];
+}
 impl AsByteSequence for CommonBehavior {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1689,54 +1965,103 @@ impl AsByteSequence for CommonBehavior {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CommonBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (data, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (data, sz): (Card8, usize) = parse_item(bytes, index, "data")?;
         index += sz;
-        Some((CommonBehavior { ty: ty, data: data }, index))
+        Ok((CommonBehavior { ty: ty, data: data }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.data.size()
+        2
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for CommonBehavior {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct DefaultBehavior {
     pub ty: Card8,
 }
-impl DefaultBehavior {}
+impl DefaultBehavior {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(ty: Card8) -> Self {
+        Self { ty: ty }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 2;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1)];
+}
 impl AsByteSequence for DefaultBehavior {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DefaultBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
         index += 1;
-        Some((DefaultBehavior { ty: ty }, index))
+        Ok((DefaultBehavior { ty: ty }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + 1
+        2
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DefaultBehavior {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 pub type LockBehavior = DefaultBehavior;
-#[derive(Clone, Debug, Default)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct RadioGroupBehavior {
     pub ty: Card8,
     pub group: Card8,
 }
-impl RadioGroupBehavior {}
+impl RadioGroupBehavior {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(ty: Card8, group: Card8) -> Self {
+        Self {
+            ty: ty,
+            group: group,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 2;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1), ("group", 1, 1)];
+}
 impl AsByteSequence for RadioGroupBehavior {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1746,14 +2071,14 @@ impl AsByteSequence for RadioGroupBehavior {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing RadioGroupBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (group, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Card8, usize) = parse_item(bytes, index, "group")?;
         index += sz;
-        Some((
+        Ok((
             RadioGroupBehavior {
                 ty: ty,
                 group: group,
@@ -1763,15 +2088,27 @@ impl AsByteSequence for RadioGroupBehavior {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.group.size()
+        2
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for RadioGroupBehavior {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct OverlayBehavior {
     pub ty: Card8,
     pub key: Keycode,
 }
-impl OverlayBehavior {}
+impl OverlayBehavior {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1)];
+}
 impl AsByteSequence for OverlayBehavior {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1781,48 +2118,60 @@ impl AsByteSequence for OverlayBehavior {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OverlayBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (key, sz): (Keycode, usize) = parse_item(bytes, index, "key")?;
         index += sz;
-        Some((OverlayBehavior { ty: ty, key: key }, index))
+        Ok((OverlayBehavior { ty: ty, key: key }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.key.size()
+        1 + self.key.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for OverlayBehavior {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 pub type PermamentLockBehavior = LockBehavior;
 pub type PermamentRadioGroupBehavior = RadioGroupBehavior;
 pub type PermamentOverlayBehavior = OverlayBehavior;
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetBehavior {
     pub keycode: Keycode,
     pub behavior: Behavior,
 }
-impl SetBehavior {}
+impl SetBehavior {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for SetBehavior {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.keycode.as_bytes(&mut bytes[index..]);
         index += self.behavior.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetBehavior from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
         index += sz;
-        let (behavior, sz): (Behavior, usize) = <Behavior>::from_bytes(&bytes[index..])?;
+        let (behavior, sz): (Behavior, usize) = parse_item(bytes, index, "behavior")?;
         index += sz;
         index += 1;
-        Some((
+        Ok((
             SetBehavior {
                 keycode: keycode,
                 behavior: behavior,
@@ -1832,15 +2181,27 @@ impl AsByteSequence for SetBehavior {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.keycode.size() + self.behavior.size() + 1
+        1 + self.keycode.size() + self.behavior.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SetBehavior {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetExplicit {
     pub keycode: Keycode,
     pub explicit: Explicit,
 }
-impl SetExplicit {}
+impl SetExplicit {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for SetExplicit {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1850,14 +2211,14 @@ impl AsByteSequence for SetExplicit {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetExplicit from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
         index += sz;
-        let (explicit, sz): (Explicit, usize) = <Explicit>::from_bytes(&bytes[index..])?;
+        let (explicit, sz): (Explicit, usize) = parse_item(bytes, index, "explicit")?;
         index += sz;
-        Some((
+        Ok((
             SetExplicit {
                 keycode: keycode,
                 explicit: explicit,
@@ -1867,11 +2228,20 @@ impl AsByteSequence for SetExplicit {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.keycode.size() + self.explicit.size()
+        1 + self.keycode.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetExplicit {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Explicit {
     pub inner: u8,
 }
@@ -1980,7 +2350,6 @@ impl Explicit {
         }
         self
     }
-    #[inline]
     pub fn new(
         key_type1: bool,
         key_type2: bool,
@@ -2032,21 +2401,20 @@ impl Explicit {
     pub const V_MOD_MAP: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 255 };
 }
-impl AsByteSequence for Explicit {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for Explicit {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((Explicit { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for Explicit {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { Explicit }
 impl core::ops::Not for Explicit {
     type Output = Explicit;
     #[inline]
@@ -2081,12 +2449,17 @@ impl core::ops::BitXor for Explicit {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KeyModMap {
     pub keycode: Keycode,
     pub mods: ModMask,
 }
-impl KeyModMap {}
+impl KeyModMap {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for KeyModMap {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -2096,14 +2469,14 @@ impl AsByteSequence for KeyModMap {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyModMap from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods")?;
         index += sz;
-        Some((
+        Ok((
             KeyModMap {
                 keycode: keycode,
                 mods: mods,
@@ -2116,31 +2489,43 @@ impl AsByteSequence for KeyModMap {
         self.keycode.size() + self.mods.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KeyModMap {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KeyVModMap {
     pub keycode: Keycode,
     pub vmods: VMod,
 }
-impl KeyVModMap {}
+impl KeyVModMap {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for KeyVModMap {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.keycode.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.vmods.as_bytes(&mut bytes[index..]);
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyVModMap from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
         index += sz;
         index += 1;
-        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (vmods, sz): (VMod, usize) = parse_item(bytes, index, "vmods")?;
         index += sz;
-        Some((
+        Ok((
             KeyVModMap {
                 keycode: keycode,
                 vmods: vmods,
@@ -2150,16 +2535,28 @@ impl AsByteSequence for KeyVModMap {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.keycode.size() + 1 + self.vmods.size()
+        3 + self.keycode.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KeyVModMap {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct KtSetMapEntry {
     pub level: Card8,
     pub real_mods: ModMask,
     pub virtual_mods: VMod,
 }
-impl KtSetMapEntry {}
+impl KtSetMapEntry {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("level", 0, 1)];
+}
 impl AsByteSequence for KtSetMapEntry {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -2169,17 +2566,16 @@ impl AsByteSequence for KtSetMapEntry {
         index += self.virtual_mods.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KtSetMapEntry from byte buffer");
-        let (level, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Card8, usize) = parse_item(bytes, index, "level")?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "real_mods")?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
         index += sz;
-        Some((
+        Ok((
             KtSetMapEntry {
                 level: level,
                 real_mods: real_mods,
@@ -2190,10 +2586,18 @@ impl AsByteSequence for KtSetMapEntry {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.level.size() + self.real_mods.size() + self.virtual_mods.size()
+        3 + self.real_mods.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for KtSetMapEntry {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetKeyType {
     pub mask: ModMask,
     pub real_mods: ModMask,
@@ -2204,9 +2608,11 @@ pub struct SetKeyType {
     pub entries: Vec<KtSetMapEntry>,
     pub preserve_entries: Vec<KtSetMapEntry>,
 }
-impl SetKeyType {}
+impl SetKeyType {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for SetKeyType {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.mask.as_bytes(&mut bytes[index..]);
@@ -2215,43 +2621,50 @@ impl AsByteSequence for SetKeyType {
         index += self.num_levels.as_bytes(&mut bytes[index..]);
         index += self.n_map_entries.as_bytes(&mut bytes[index..]);
         index += self.preserve.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         let block_len: usize = vector_as_bytes(&self.entries, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>()),
+        );
         let block_len: usize = vector_as_bytes(&self.preserve_entries, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetKeyType from byte buffer");
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = parse_item(bytes, index, "mask")?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "real_mods")?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
         index += sz;
-        let (num_levels, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_levels, sz): (Card8, usize) = parse_item(bytes, index, "num_levels")?;
         index += sz;
-        let (n_map_entries, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_map_entries, sz): (Card8, usize) = parse_item(bytes, index, "n_map_entries")?;
         index += sz;
-        let (preserve, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (preserve, sz): (bool, usize) = parse_item(bytes, index, "preserve")?;
         index += sz;
         index += 1;
         let (entries, block_len): (Vec<KtSetMapEntry>, usize) =
-            vector_from_bytes(&bytes[index..], (n_map_entries as usize) as usize)?;
+            parse_vector(bytes, index, (n_map_entries as usize) as usize, "entries")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>());
-        let (preserve_entries, block_len): (Vec<KtSetMapEntry>, usize) = vector_from_bytes(
-            &bytes[index..],
+        let (preserve_entries, block_len): (Vec<KtSetMapEntry>, usize) = parse_vector(
+            bytes,
+            index,
             ((preserve as usize) * (n_map_entries as usize)) as usize,
+            "preserve_entries",
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>());
-        Some((
+        Ok((
             SetKeyType {
                 mask: mask,
                 real_mods: real_mods,
@@ -2265,15 +2678,10 @@ impl AsByteSequence for SetKeyType {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.mask.size()
+        5 + self.mask.size()
             + self.real_mods.size()
-            + self.virtual_mods.size()
-            + self.num_levels.size()
-            + self.n_map_entries.size()
             + self.preserve.size()
-            + 1
             + {
                 let block_len: usize = self.entries.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>());
@@ -2286,39 +2694,77 @@ impl AsByteSequence for SetKeyType {
             }
     }
 }
+impl core::convert::TryFrom<&[u8]> for SetKeyType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for SetKeyType {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mask: ModMask = u.arbitrary()?;
+        let real_mods: ModMask = u.arbitrary()?;
+        let virtual_mods: VMod = u.arbitrary()?;
+        let num_levels: Card8 = u.arbitrary()?;
+        let n_map_entries: Card8 = u.arbitrary()?;
+        let preserve: bool = u.arbitrary()?;
+        let preserve_entries = u.arbitrary()?;
+        let entries = arbitrary_list(u, (n_map_entries as usize) as usize)?;
+        Ok(Self {
+            mask: mask,
+            real_mods: real_mods,
+            virtual_mods: virtual_mods,
+            num_levels: num_levels,
+            n_map_entries: n_map_entries,
+            preserve: preserve,
+            entries: entries,
+            preserve_entries: preserve_entries,
+        })
+    }
+}
 pub type String8 = Char;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Outline {
     pub corner_radius: Card8,
     pub points: Vec<Point>,
 }
-impl Outline {}
+impl Outline {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("points_len", 0, 1), ("corner_radius", 1, 1)];
+}
 impl AsByteSequence for Outline {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += (self.points.len() as Card8).as_bytes(&mut bytes[index..]);
         index += self.corner_radius.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.points, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Point>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Point>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Outline from byte buffer");
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (corner_radius, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (corner_radius, sz): (Card8, usize) = parse_item(bytes, index, "corner_radius")?;
         index += sz;
         index += 2;
         let (points, block_len): (Vec<Point>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "points")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Point>());
-        Some((
+        Ok((
             Outline {
                 corner_radius: corner_radius,
                 points: points,
@@ -2328,53 +2774,66 @@ impl AsByteSequence for Outline {
     }
     #[inline]
     fn size(&self) -> usize {
-        ::core::mem::size_of::<Card8>() + self.corner_radius.size() + 2 + {
+        4 + {
             let block_len: usize = self.points.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Point>());
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Outline {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Shape {
     pub name: Atom,
     pub primary_ndx: Card8,
     pub approx_ndx: Card8,
     pub outlines: Vec<Outline>,
 }
-impl Shape {}
+impl Shape {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for Shape {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.name.as_bytes(&mut bytes[index..]);
         index += (self.outlines.len() as Card8).as_bytes(&mut bytes[index..]);
         index += self.primary_ndx.as_bytes(&mut bytes[index..]);
         index += self.approx_ndx.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         let block_len: usize = vector_as_bytes(&self.outlines, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Outline>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Outline>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Shape from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (primary_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (primary_ndx, sz): (Card8, usize) = parse_item(bytes, index, "primary_ndx")?;
         index += sz;
-        let (approx_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (approx_ndx, sz): (Card8, usize) = parse_item(bytes, index, "approx_ndx")?;
         index += sz;
         index += 1;
         let (outlines, block_len): (Vec<Outline>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "outlines")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Outline>());
-        Some((
+        Ok((
             Shape {
                 name: name,
                 primary_ndx: primary_ndx,
@@ -2386,26 +2845,47 @@ impl AsByteSequence for Shape {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.name.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.primary_ndx.size()
-            + self.approx_ndx.size()
-            + 1
-            + {
-                let block_len: usize = self.outlines.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Outline>());
-                block_len + pad
-            }
+        4 + self.name.size() + {
+            let block_len: usize = self.outlines.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Outline>());
+            block_len + pad
+        }
     }
 }
-#[derive(Clone, Debug, Default)]
-pub struct Key {
+impl core::convert::TryFrom<&[u8]> for Shape {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct Key {
     pub name: [String8; 4],
     pub gap: Int16,
     pub shape_ndx: Card8,
     pub color_ndx: Card8,
 }
-impl Key {}
+impl Key {
+    #[inline]
+    pub const fn new(name: [String8; 4], gap: Int16, shape_ndx: Card8, color_ndx: Card8) -> Self {
+        Self {
+            name: name,
+            gap: gap,
+            shape_ndx: shape_ndx,
+            color_ndx: color_ndx,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("name", 0, 4),
+        ("gap", 4, 2),
+        ("shape_ndx", 6, 1),
+        ("color_ndx", 7, 1),
+    ];
+}
 impl AsByteSequence for Key {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -2416,19 +2896,18 @@ impl AsByteSequence for Key {
         index += self.color_ndx.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Key from byte buffer");
-        let (name, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(&bytes[index..])?;
+        let (name, sz): ([String8; 4], usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (gap, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (gap, sz): (Int16, usize) = parse_item(bytes, index, "gap")?;
         index += sz;
-        let (shape_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (shape_ndx, sz): (Card8, usize) = parse_item(bytes, index, "shape_ndx")?;
         index += sz;
-        let (color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (color_ndx, sz): (Card8, usize) = parse_item(bytes, index, "color_ndx")?;
         index += sz;
-        Some((
+        Ok((
             Key {
                 name: name,
                 gap: gap,
@@ -2440,15 +2919,34 @@ impl AsByteSequence for Key {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.name.size() + self.gap.size() + self.shape_ndx.size() + self.color_ndx.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Key {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct OverlayKey {
     pub over: [String8; 4],
     pub under: [String8; 4],
 }
-impl OverlayKey {}
+impl OverlayKey {
+    #[inline]
+    pub const fn new(over: [String8; 4], under: [String8; 4]) -> Self {
+        Self {
+            over: over,
+            under: under,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("over", 0, 4), ("under", 4, 4)];
+}
 impl AsByteSequence for OverlayKey {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -2458,14 +2956,14 @@ impl AsByteSequence for OverlayKey {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OverlayKey from byte buffer");
-        let (over, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(&bytes[index..])?;
+        let (over, sz): ([String8; 4], usize) = parse_item(bytes, index, "over")?;
         index += sz;
-        let (under, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(&bytes[index..])?;
+        let (under, sz): ([String8; 4], usize) = parse_item(bytes, index, "under")?;
         index += sz;
-        Some((
+        Ok((
             OverlayKey {
                 over: over,
                 under: under,
@@ -2475,41 +2973,54 @@ impl AsByteSequence for OverlayKey {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.over.size() + self.under.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for OverlayKey {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct OverlayRow {
     pub row_under: Card8,
     pub keys: Vec<OverlayKey>,
 }
-impl OverlayRow {}
+impl OverlayRow {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("row_under", 0, 1), ("keys_len", 1, 1)];
+}
 impl AsByteSequence for OverlayRow {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.row_under.as_bytes(&mut bytes[index..]);
         index += (self.keys.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.keys, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<OverlayKey>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<OverlayKey>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing OverlayRow from byte buffer");
-        let (row_under, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (row_under, sz): (Card8, usize) = parse_item(bytes, index, "row_under")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
         let (keys, block_len): (Vec<OverlayKey>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "keys")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<OverlayKey>());
-        Some((
+        Ok((
             OverlayRow {
                 row_under: row_under,
                 keys: keys,
@@ -2519,45 +3030,58 @@ impl AsByteSequence for OverlayRow {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.row_under.size() + ::core::mem::size_of::<Card8>() + 2 + {
+        4 + {
             let block_len: usize = self.keys.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<OverlayKey>());
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for OverlayRow {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Overlay {
     pub name: Atom,
     pub rows: Vec<OverlayRow>,
 }
-impl Overlay {}
+impl Overlay {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for Overlay {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.name.as_bytes(&mut bytes[index..]);
         index += (self.rows.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         let block_len: usize = vector_as_bytes(&self.rows, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<OverlayRow>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<OverlayRow>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Overlay from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 3;
         let (rows, block_len): (Vec<OverlayRow>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "rows")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<OverlayRow>());
-        Some((
+        Ok((
             Overlay {
                 name: name,
                 rows: rows,
@@ -2567,53 +3091,71 @@ impl AsByteSequence for Overlay {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.name.size() + ::core::mem::size_of::<Card8>() + 3 + {
+        4 + self.name.size() + {
             let block_len: usize = self.rows.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<OverlayRow>());
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Overlay {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Row {
     pub top: Int16,
     pub left: Int16,
     pub vertical: bool,
     pub keys: Vec<Key>,
 }
-impl Row {}
+impl Row {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("top", 0, 2),
+        ("left", 2, 2),
+        ("keys_len", 4, 1),
+        ("vertical", 5, 1),
+    ];
+}
 impl AsByteSequence for Row {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.top.as_bytes(&mut bytes[index..]);
         index += self.left.as_bytes(&mut bytes[index..]);
         index += (self.keys.len() as Card8).as_bytes(&mut bytes[index..]);
         index += self.vertical.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.keys, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Key>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Key>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Row from byte buffer");
-        let (top, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Int16, usize) = parse_item(bytes, index, "top")?;
         index += sz;
-        let (left, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Int16, usize) = parse_item(bytes, index, "left")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (vertical, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (vertical, sz): (bool, usize) = parse_item(bytes, index, "vertical")?;
         index += sz;
         index += 2;
         let (keys, block_len): (Vec<Key>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "keys")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Key>());
-        Some((
+        Ok((
             Row {
                 top: top,
                 left: left,
@@ -2625,25 +3167,31 @@ impl AsByteSequence for Row {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.top.size()
-            + self.left.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.vertical.size()
-            + 2
-            + {
-                let block_len: usize = self.keys.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Key>());
-                block_len + pad
-            }
+        7 + self.vertical.size() + {
+            let block_len: usize = self.keys.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Key>());
+            block_len + pad
+        }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Row {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Listing {
     pub flags: Card16,
     pub length: Card16,
     pub string: Vec<String8>,
 }
-impl Listing {}
+impl Listing {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("flags", 0, 2), ("length", 2, 2)];
+}
 impl AsByteSequence for Listing {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -2652,22 +3200,21 @@ impl AsByteSequence for Listing {
         index += self.length.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.string, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 2));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Listing from byte buffer");
-        let (flags, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card16, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
         let (string, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            parse_vector(bytes, index, (length as usize) as usize, "string")?;
         index += block_len;
         index += buffer_pad(block_len, 2);
-        Some((
+        Ok((
             Listing {
                 flags: flags,
                 length: length,
@@ -2678,14 +3225,36 @@ impl AsByteSequence for Listing {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.flags.size() + self.length.size() + {
+        4 + {
             let block_len: usize = self.string.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, 2);
             block_len + pad
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Listing {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for Listing {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let flags: Card16 = u.arbitrary()?;
+        let length: Card16 = u.arbitrary()?;
+        let string = arbitrary_list(u, (length as usize) as usize)?;
+        Ok(Self {
+            flags: flags,
+            length: length,
+            string: string,
+        })
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct DeviceLedInfo {
     pub led_class: LedClass,
     pub led_id: IdSpec,
@@ -2696,9 +3265,33 @@ pub struct DeviceLedInfo {
     pub names: Vec<Atom>,
     pub maps: Vec<IndicatorMap>,
 }
-impl DeviceLedInfo {}
-impl AsByteSequence for DeviceLedInfo {
+impl DeviceLedInfo {
+    #[doc = " Interpret `led_id` as a `Id`. Returns `None` if it holds a value that isn't one of the enumeration's."]
+    #[inline]
+    pub fn led_id_enum(&self) -> Option<Id> {
+        core::convert::TryFrom::try_from(self.led_id).ok()
+    }
+    #[doc = " Pair each item of `names` with the bit of `names_present` that it stands for, from the lowest bit up."]
+    #[inline]
+    pub fn names_by_mask(&self) -> impl Iterator<Item = (u32, &Atom)> + '_ {
+        mask_bits(u32::from(self.names_present)).zip(self.names.iter())
+    }
+    #[doc = " Pair each item of `maps` with the bit of `maps_present` that it stands for, from the lowest bit up."]
     #[inline]
+    pub fn maps_by_mask(&self) -> impl Iterator<Item = (u32, &IndicatorMap)> + '_ {
+        mask_bits(u32::from(self.maps_present)).zip(self.maps.iter())
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("led_class", 0, 2),
+        ("led_id", 2, 2),
+        ("names_present", 4, 4),
+        ("maps_present", 8, 4),
+        ("phys_indicators", 12, 4),
+        ("state", 16, 4),
+    ];
+}
+impl AsByteSequence for DeviceLedInfo {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.led_class.as_bytes(&mut bytes[index..]);
@@ -2709,37 +3302,46 @@ impl AsByteSequence for DeviceLedInfo {
         index += self.state.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.names, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+        );
         let block_len: usize = vector_as_bytes(&self.maps, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceLedInfo from byte buffer");
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = parse_item(bytes, index, "led_class")?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = parse_item(bytes, index, "led_id")?;
         index += sz;
-        let (names_present, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (names_present, sz): (Card32, usize) = parse_item(bytes, index, "names_present")?;
         index += sz;
-        let (maps_present, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (maps_present, sz): (Card32, usize) = parse_item(bytes, index, "maps_present")?;
         index += sz;
-        let (phys_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (phys_indicators, sz): (Card32, usize) = parse_item(bytes, index, "phys_indicators")?;
         index += sz;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card32, usize) = parse_item(bytes, index, "state")?;
         index += sz;
-        let (names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((names_present).count_ones()) as usize)?;
+        let (names, block_len): (Vec<Atom>, usize) = parse_vector(
+            bytes,
+            index,
+            ((names_present).count_ones()) as usize,
+            "names",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], ((maps_present).count_ones()) as usize)?;
+            parse_vector(bytes, index, ((maps_present).count_ones()) as usize, "maps")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-        Some((
+        Ok((
             DeviceLedInfo {
                 led_class: led_class,
                 led_id: led_id,
@@ -2753,28 +3355,53 @@ impl AsByteSequence for DeviceLedInfo {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.led_class.size()
-            + self.led_id.size()
-            + self.names_present.size()
-            + self.maps_present.size()
-            + self.phys_indicators.size()
-            + self.state.size()
-            + {
-                let block_len: usize = self.names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-                block_len + pad
-            }
+        20 + {
+            let block_len: usize = self.names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+            block_len + pad
+        }
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DeviceLedInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceLedInfo {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let led_class: LedClass = u.arbitrary()?;
+        let led_id: IdSpec = u.arbitrary()?;
+        let names_present: Card32 = u.arbitrary()?;
+        let maps_present: Card32 = u.arbitrary()?;
+        let phys_indicators: Card32 = u.arbitrary()?;
+        let state: Card32 = u.arbitrary()?;
+        let names = arbitrary_list(u, ((names_present).count_ones()) as usize)?;
+        let maps = arbitrary_list(u, ((maps_present).count_ones()) as usize)?;
+        Ok(Self {
+            led_class: led_class,
+            led_id: led_id,
+            names_present: names_present,
+            maps_present: maps_present,
+            phys_indicators: phys_indicators,
+            state: state,
+            names: names,
+            maps: maps,
+        })
     }
 }
 #[repr(u16)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum LedClass {
     KbdFeedbackClass = 0,
     LedFeedbackClass = 4,
@@ -2787,14 +3414,14 @@ impl AsByteSequence for LedClass {
         (*self as u16).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::KbdFeedbackClass, sz)),
-            4 => Some((Self::LedFeedbackClass, sz)),
-            768 => Some((Self::DfltXiClass, sz)),
-            1280 => Some((Self::AllXiClasses, sz)),
-            _ => None,
+            0 => Ok((Self::KbdFeedbackClass, sz)),
+            4 => Ok((Self::LedFeedbackClass, sz)),
+            768 => Ok((Self::DfltXiClass, sz)),
+            1280 => Ok((Self::AllXiClasses, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -2802,14 +3429,42 @@ impl AsByteSequence for LedClass {
         ::core::mem::size_of::<u16>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for LedClass {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for LedClass {
     #[inline]
     fn default() -> LedClass {
         LedClass::KbdFeedbackClass
     }
 }
+impl From<LedClass> for u16 {
+    #[inline]
+    fn from(base: LedClass) -> Self {
+        base as u16
+    }
+}
+impl core::convert::TryFrom<u16> for LedClass {
+    type Error = u16;
+    #[inline]
+    fn try_from(value: u16) -> Result<Self, u16> {
+        match value {
+            0 => Ok(Self::KbdFeedbackClass),
+            4 => Ok(Self::LedFeedbackClass),
+            768 => Ok(Self::DfltXiClass),
+            1280 => Ok(Self::AllXiClasses),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u16)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Id {
     UseCoreKbd = 256,
     UseCorePtr = 512,
@@ -2824,18 +3479,17 @@ impl AsByteSequence for Id {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u16).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
         match underlying {
-            256 => Some((Self::UseCoreKbd, sz)),
-            512 => Some((Self::UseCorePtr, sz)),
-            768 => Some((Self::DfltXiClass, sz)),
-            1024 => Some((Self::DfltXiId, sz)),
-            1280 => Some((Self::AllXiClass, sz)),
-            1536 => Some((Self::AllXiId, sz)),
-            65280 => Some((Self::XiNone, sz)),
-            _ => None,
+            256 => Ok((Self::UseCoreKbd, sz)),
+            512 => Ok((Self::UseCorePtr, sz)),
+            768 => Ok((Self::DfltXiClass, sz)),
+            1024 => Ok((Self::DfltXiId, sz)),
+            1280 => Ok((Self::AllXiClass, sz)),
+            1536 => Ok((Self::AllXiId, sz)),
+            65280 => Ok((Self::XiNone, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -2843,41 +3497,97 @@ impl AsByteSequence for Id {
         ::core::mem::size_of::<u16>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Id {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Id {
     #[inline]
     fn default() -> Id {
         Id::UseCoreKbd
     }
 }
-#[derive(Clone, Debug, Default)]
-pub struct SaNoAction {
-    pub ty: SaType,
-}
-impl SaNoAction {}
-impl AsByteSequence for SaNoAction {
+impl From<Id> for u16 {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.ty.as_bytes(&mut bytes[index..]);
-        index += 7;
-        index
+    fn from(base: Id) -> Self {
+        base as u16
     }
+}
+impl core::convert::TryFrom<u16> for Id {
+    type Error = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SaNoAction from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 7;
-        Some((SaNoAction { ty: ty }, index))
+    fn try_from(value: u16) -> Result<Self, u16> {
+        match value {
+            256 => Ok(Self::UseCoreKbd),
+            512 => Ok(Self::UseCorePtr),
+            768 => Ok(Self::DfltXiClass),
+            1024 => Ok(Self::DfltXiId),
+            1280 => Ok(Self::AllXiClass),
+            1536 => Ok(Self::AllXiId),
+            65280 => Ok(Self::XiNone),
+            _ => Err(value),
+        }
     }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct SaNoAction {
+    pub ty: SaType,
+}
+impl SaNoAction {
     #[inline]
-    fn size(&self) -> usize {
-        self.ty.size() + 7
+    pub const fn new(ty: SaType) -> Self {
+        Self { ty: ty }
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1)];
+}
+macro_rules! impl_ty_only_struct_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.ty.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 7);
+                index
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
+                index += sz;
+                index += 7;
+                Ok(($name { ty: ty }, index))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                8
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_ty_only_struct_asb! { SaNoAction }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SaType {
     NoAction = 0,
     SetMods = 1,
@@ -2906,32 +3616,31 @@ impl AsByteSequence for SaType {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u8).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::NoAction, sz)),
-            1 => Some((Self::SetMods, sz)),
-            2 => Some((Self::LatchMods, sz)),
-            3 => Some((Self::LockMods, sz)),
-            4 => Some((Self::SetGroup, sz)),
-            5 => Some((Self::LatchGroup, sz)),
-            6 => Some((Self::LockGroup, sz)),
-            7 => Some((Self::MovePtr, sz)),
-            8 => Some((Self::PtrBtn, sz)),
-            9 => Some((Self::LockPtrBtn, sz)),
-            10 => Some((Self::SetPtrDflt, sz)),
-            11 => Some((Self::IsoLock, sz)),
-            12 => Some((Self::Terminate, sz)),
-            13 => Some((Self::SwitchScreen, sz)),
-            14 => Some((Self::SetControls, sz)),
-            15 => Some((Self::LockControls, sz)),
-            16 => Some((Self::ActionMessage, sz)),
-            17 => Some((Self::RedirectKey, sz)),
-            18 => Some((Self::DeviceBtn, sz)),
-            19 => Some((Self::LockDeviceBtn, sz)),
-            20 => Some((Self::DeviceValuator, sz)),
-            _ => None,
+            0 => Ok((Self::NoAction, sz)),
+            1 => Ok((Self::SetMods, sz)),
+            2 => Ok((Self::LatchMods, sz)),
+            3 => Ok((Self::LockMods, sz)),
+            4 => Ok((Self::SetGroup, sz)),
+            5 => Ok((Self::LatchGroup, sz)),
+            6 => Ok((Self::LockGroup, sz)),
+            7 => Ok((Self::MovePtr, sz)),
+            8 => Ok((Self::PtrBtn, sz)),
+            9 => Ok((Self::LockPtrBtn, sz)),
+            10 => Ok((Self::SetPtrDflt, sz)),
+            11 => Ok((Self::IsoLock, sz)),
+            12 => Ok((Self::Terminate, sz)),
+            13 => Ok((Self::SwitchScreen, sz)),
+            14 => Ok((Self::SetControls, sz)),
+            15 => Ok((Self::LockControls, sz)),
+            16 => Ok((Self::ActionMessage, sz)),
+            17 => Ok((Self::RedirectKey, sz)),
+            18 => Ok((Self::DeviceBtn, sz)),
+            19 => Ok((Self::LockDeviceBtn, sz)),
+            20 => Ok((Self::DeviceValuator, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -2939,13 +3648,57 @@ impl AsByteSequence for SaType {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SaType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SaType {
     #[inline]
     fn default() -> SaType {
         SaType::NoAction
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<SaType> for u8 {
+    #[inline]
+    fn from(base: SaType) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SaType {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::NoAction),
+            1 => Ok(Self::SetMods),
+            2 => Ok(Self::LatchMods),
+            3 => Ok(Self::LockMods),
+            4 => Ok(Self::SetGroup),
+            5 => Ok(Self::LatchGroup),
+            6 => Ok(Self::LockGroup),
+            7 => Ok(Self::MovePtr),
+            8 => Ok(Self::PtrBtn),
+            9 => Ok(Self::LockPtrBtn),
+            10 => Ok(Self::SetPtrDflt),
+            11 => Ok(Self::IsoLock),
+            12 => Ok(Self::Terminate),
+            13 => Ok(Self::SwitchScreen),
+            14 => Ok(Self::SetControls),
+            15 => Ok(Self::LockControls),
+            16 => Ok(Self::ActionMessage),
+            17 => Ok(Self::RedirectKey),
+            18 => Ok(Self::DeviceBtn),
+            19 => Ok(Self::LockDeviceBtn),
+            20 => Ok(Self::DeviceValuator),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaSetMods {
     pub ty: SaType,
     pub flags: Sa,
@@ -2954,9 +3707,11 @@ pub struct SaSetMods {
     pub vmods_high: VModsHigh,
     pub vmods_low: VModsLow,
 }
-impl SaSetMods {}
+impl SaSetMods {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1), ("flags", 1, 1)];
+}
 impl AsByteSequence for SaSetMods {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
@@ -2965,27 +3720,26 @@ impl AsByteSequence for SaSetMods {
         index += self.real_mods.as_bytes(&mut bytes[index..]);
         index += self.vmods_high.as_bytes(&mut bytes[index..]);
         index += self.vmods_low.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetMods from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (Sa, usize) = <Sa>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Sa, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = parse_item(bytes, index, "mask")?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "real_mods")?;
         index += sz;
-        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_high, sz): (VModsHigh, usize) = parse_item(bytes, index, "vmods_high")?;
         index += sz;
-        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_low, sz): (VModsLow, usize) = parse_item(bytes, index, "vmods_low")?;
         index += sz;
         index += 2;
-        Some((
+        Ok((
             SaSetMods {
                 ty: ty,
                 flags: flags,
@@ -2999,17 +3753,20 @@ impl AsByteSequence for SaSetMods {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size()
-            + self.flags.size()
-            + self.mask.size()
-            + self.real_mods.size()
-            + self.vmods_high.size()
-            + self.vmods_low.size()
-            + 2
+        6 + self.mask.size() + self.real_mods.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaSetMods {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Sa {
     pub inner: u8,
 }
@@ -3076,21 +3833,20 @@ impl Sa {
     pub const USE_MOD_MAP_MODS: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 7 };
 }
-impl AsByteSequence for Sa {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for Sa {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((Sa { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for Sa {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { Sa }
 impl core::ops::Not for Sa {
     type Output = Sa;
     #[inline]
@@ -3126,7 +3882,9 @@ impl core::ops::BitXor for Sa {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct VModsHigh {
     pub inner: u8,
 }
@@ -3235,7 +3993,6 @@ impl VModsHigh {
         }
         self
     }
-    #[inline]
     pub fn new(
         Eight: bool,
         Nine: bool,
@@ -3287,21 +4044,20 @@ impl VModsHigh {
     pub const FIFTEEN: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 255 };
 }
-impl AsByteSequence for VModsHigh {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for VModsHigh {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((VModsHigh { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for VModsHigh {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { VModsHigh }
 impl core::ops::Not for VModsHigh {
     type Output = VModsHigh;
     #[inline]
@@ -3337,7 +4093,9 @@ impl core::ops::BitXor for VModsHigh {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct VModsLow {
     pub inner: u8,
 }
@@ -3446,7 +4204,6 @@ impl VModsLow {
         }
         self
     }
-    #[inline]
     pub fn new(
         Zero: bool,
         One: bool,
@@ -3498,21 +4255,20 @@ impl VModsLow {
     pub const SEVEN: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 255 };
 }
-impl AsByteSequence for VModsLow {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for VModsLow {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((VModsLow { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for VModsLow {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { VModsLow }
 impl core::ops::Not for VModsLow {
     type Output = VModsLow;
     #[inline]
@@ -3549,13 +4305,27 @@ impl core::ops::BitXor for VModsLow {
 }
 pub type SaLatchMods = SaSetMods;
 pub type SaLockMods = SaSetMods;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaSetGroup {
     pub ty: SaType,
     pub flags: Sa,
     pub group: Int8,
 }
-impl SaSetGroup {}
+impl SaSetGroup {
+    #[inline]
+    pub const fn new(ty: SaType, flags: Sa, group: Int8) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            group: group,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("ty", 0, 1), ("flags", 1, 1), ("group", 2, 1)];
+}
 impl AsByteSequence for SaSetGroup {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -3563,21 +4333,20 @@ impl AsByteSequence for SaSetGroup {
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.flags.as_bytes(&mut bytes[index..]);
         index += self.group.as_bytes(&mut bytes[index..]);
-        index += 5;
+        index += zero_pad(&mut bytes[index..], 5);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetGroup from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (Sa, usize) = <Sa>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Sa, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (group, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Int8, usize) = parse_item(bytes, index, "group")?;
         index += sz;
         index += 5;
-        Some((
+        Ok((
             SaSetGroup {
                 ty: ty,
                 flags: flags,
@@ -3588,12 +4357,21 @@ impl AsByteSequence for SaSetGroup {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.flags.size() + self.group.size() + 5
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaSetGroup {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 pub type SaLatchGroup = SaSetGroup;
 pub type SaLockGroup = SaSetGroup;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaMovePtr {
     pub ty: SaType,
     pub flags: SaMovePtrFlag,
@@ -3602,9 +4380,36 @@ pub struct SaMovePtr {
     pub y_high: Int8,
     pub y_low: Card8,
 }
-impl SaMovePtr {}
-impl AsByteSequence for SaMovePtr {
+impl SaMovePtr {
     #[inline]
+    pub const fn new(
+        ty: SaType,
+        flags: SaMovePtrFlag,
+        x_high: Int8,
+        x_low: Card8,
+        y_high: Int8,
+        y_low: Card8,
+    ) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            x_high: x_high,
+            x_low: x_low,
+            y_high: y_high,
+            y_low: y_low,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("ty", 0, 1),
+        ("flags", 1, 1),
+        ("x_high", 2, 1),
+        ("x_low", 3, 1),
+        ("y_high", 4, 1),
+        ("y_low", 5, 1),
+    ];
+}
+impl AsByteSequence for SaMovePtr {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
@@ -3613,27 +4418,26 @@ impl AsByteSequence for SaMovePtr {
         index += self.x_low.as_bytes(&mut bytes[index..]);
         index += self.y_high.as_bytes(&mut bytes[index..]);
         index += self.y_low.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaMovePtr from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (SaMovePtrFlag, usize) = <SaMovePtrFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (SaMovePtrFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (x_high, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (x_high, sz): (Int8, usize) = parse_item(bytes, index, "x_high")?;
         index += sz;
-        let (x_low, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (x_low, sz): (Card8, usize) = parse_item(bytes, index, "x_low")?;
         index += sz;
-        let (y_high, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (y_high, sz): (Int8, usize) = parse_item(bytes, index, "y_high")?;
         index += sz;
-        let (y_low, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (y_low, sz): (Card8, usize) = parse_item(bytes, index, "y_low")?;
         index += sz;
         index += 2;
-        Some((
+        Ok((
             SaMovePtr {
                 ty: ty,
                 flags: flags,
@@ -3647,17 +4451,20 @@ impl AsByteSequence for SaMovePtr {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size()
-            + self.flags.size()
-            + self.x_high.size()
-            + self.x_low.size()
-            + self.y_high.size()
-            + self.y_low.size()
-            + 2
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaMovePtr {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaMovePtrFlag {
     pub inner: u8,
 }
@@ -3724,21 +4531,20 @@ impl SaMovePtrFlag {
     pub const MOVE_ABSOLUTE_Y: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 7 };
 }
-impl AsByteSequence for SaMovePtrFlag {
+impl core::ops::Deref for SaMovePtrFlag {
+    type Target = u8;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((SaMovePtrFlag { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for SaMovePtrFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { SaMovePtrFlag }
 impl core::ops::Not for SaMovePtrFlag {
     type Output = SaMovePtrFlag;
     #[inline]
@@ -3773,14 +4579,33 @@ impl core::ops::BitXor for SaMovePtrFlag {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaPtrBtn {
     pub ty: SaType,
     pub flags: Card8,
     pub count: Card8,
     pub button: Card8,
 }
-impl SaPtrBtn {}
+impl SaPtrBtn {
+    #[inline]
+    pub const fn new(ty: SaType, flags: Card8, count: Card8, button: Card8) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            count: count,
+            button: button,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("ty", 0, 1),
+        ("flags", 1, 1),
+        ("count", 2, 1),
+        ("button", 3, 1),
+    ];
+}
 impl AsByteSequence for SaPtrBtn {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -3789,23 +4614,22 @@ impl AsByteSequence for SaPtrBtn {
         index += self.flags.as_bytes(&mut bytes[index..]);
         index += self.count.as_bytes(&mut bytes[index..]);
         index += self.button.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaPtrBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = parse_item(bytes, index, "count")?;
         index += sz;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = parse_item(bytes, index, "button")?;
         index += sz;
         index += 4;
-        Some((
+        Ok((
             SaPtrBtn {
                 ty: ty,
                 flags: flags,
@@ -3817,40 +4641,60 @@ impl AsByteSequence for SaPtrBtn {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.flags.size() + self.count.size() + self.button.size() + 4
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SaPtrBtn {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaLockPtrBtn {
     pub ty: SaType,
     pub flags: Card8,
     pub button: Card8,
 }
-impl SaLockPtrBtn {}
+impl SaLockPtrBtn {
+    #[inline]
+    pub const fn new(ty: SaType, flags: Card8, button: Card8) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            button: button,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("ty", 0, 1), ("flags", 1, 1), ("button", 3, 1)];
+}
 impl AsByteSequence for SaLockPtrBtn {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.button.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaLockPtrBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 1;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = parse_item(bytes, index, "button")?;
         index += sz;
         index += 4;
-        Some((
+        Ok((
             SaLockPtrBtn {
                 ty: ty,
                 flags: flags,
@@ -3861,17 +4705,48 @@ impl AsByteSequence for SaLockPtrBtn {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.flags.size() + 1 + self.button.size() + 4
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SaLockPtrBtn {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaSetPtrDflt {
     pub ty: SaType,
     pub flags: SaSetPtrDfltFlag,
     pub affect: SaSetPtrDfltFlag,
     pub value: Int8,
 }
-impl SaSetPtrDflt {}
+impl SaSetPtrDflt {
+    #[inline]
+    pub const fn new(
+        ty: SaType,
+        flags: SaSetPtrDfltFlag,
+        affect: SaSetPtrDfltFlag,
+        value: Int8,
+    ) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            affect: affect,
+            value: value,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("ty", 0, 1),
+        ("flags", 1, 1),
+        ("affect", 2, 1),
+        ("value", 3, 1),
+    ];
+}
 impl AsByteSequence for SaSetPtrDflt {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -3880,25 +4755,22 @@ impl AsByteSequence for SaSetPtrDflt {
         index += self.flags.as_bytes(&mut bytes[index..]);
         index += self.affect.as_bytes(&mut bytes[index..]);
         index += self.value.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetPtrDflt from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (SaSetPtrDfltFlag, usize) =
-            <SaSetPtrDfltFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (SaSetPtrDfltFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (affect, sz): (SaSetPtrDfltFlag, usize) =
-            <SaSetPtrDfltFlag>::from_bytes(&bytes[index..])?;
+        let (affect, sz): (SaSetPtrDfltFlag, usize) = parse_item(bytes, index, "affect")?;
         index += sz;
-        let (value, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int8, usize) = parse_item(bytes, index, "value")?;
         index += sz;
         index += 4;
-        Some((
+        Ok((
             SaSetPtrDflt {
                 ty: ty,
                 flags: flags,
@@ -3910,11 +4782,20 @@ impl AsByteSequence for SaSetPtrDflt {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.flags.size() + self.affect.size() + self.value.size() + 4
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaSetPtrDflt {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaSetPtrDfltFlag {
     pub inner: u8,
 }
@@ -3964,21 +4845,20 @@ impl SaSetPtrDfltFlag {
     pub const DFLT_BTN_ABSOLUTE: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 5 };
 }
-impl AsByteSequence for SaSetPtrDfltFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for SaSetPtrDfltFlag {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((SaSetPtrDfltFlag { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for SaSetPtrDfltFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { SaSetPtrDfltFlag }
 impl core::ops::Not for SaSetPtrDfltFlag {
     type Output = SaSetPtrDfltFlag;
     #[inline]
@@ -4013,7 +4893,9 @@ impl core::ops::BitXor for SaSetPtrDfltFlag {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaIsoLock {
     pub ty: SaType,
     pub flags: SaIsoLockFlag,
@@ -4024,9 +4906,11 @@ pub struct SaIsoLock {
     pub vmods_high: VModsHigh,
     pub vmods_low: VModsLow,
 }
-impl SaIsoLock {}
+impl SaIsoLock {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1), ("flags", 1, 1)];
+}
 impl AsByteSequence for SaIsoLock {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
@@ -4039,28 +4923,26 @@ impl AsByteSequence for SaIsoLock {
         index += self.vmods_low.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaIsoLock from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (SaIsoLockFlag, usize) = <SaIsoLockFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (SaIsoLockFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = parse_item(bytes, index, "mask")?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "real_mods")?;
         index += sz;
-        let (group, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Int8, usize) = parse_item(bytes, index, "group")?;
         index += sz;
-        let (affect, sz): (SaIsoLockNoAffect, usize) =
-            <SaIsoLockNoAffect>::from_bytes(&bytes[index..])?;
+        let (affect, sz): (SaIsoLockNoAffect, usize) = parse_item(bytes, index, "affect")?;
         index += sz;
-        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_high, sz): (VModsHigh, usize) = parse_item(bytes, index, "vmods_high")?;
         index += sz;
-        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_low, sz): (VModsLow, usize) = parse_item(bytes, index, "vmods_low")?;
         index += sz;
-        Some((
+        Ok((
             SaIsoLock {
                 ty: ty,
                 flags: flags,
@@ -4076,18 +4958,20 @@ impl AsByteSequence for SaIsoLock {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size()
-            + self.flags.size()
-            + self.mask.size()
-            + self.real_mods.size()
-            + self.group.size()
-            + self.affect.size()
-            + self.vmods_high.size()
-            + self.vmods_low.size()
+        6 + self.mask.size() + self.real_mods.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaIsoLock {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaIsoLockFlag {
     pub inner: u8,
 }
@@ -4176,21 +5060,20 @@ impl SaIsoLockFlag {
     pub const ISO_DFLT_IS_GROUP: Self = Self { inner: 8 };
     pub const COMPLETE: Self = Self { inner: 15 };
 }
-impl AsByteSequence for SaIsoLockFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for SaIsoLockFlag {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((SaIsoLockFlag { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for SaIsoLockFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { SaIsoLockFlag }
 impl core::ops::Not for SaIsoLockFlag {
     type Output = SaIsoLockFlag;
     #[inline]
@@ -4226,7 +5109,9 @@ impl core::ops::BitXor for SaIsoLockFlag {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaIsoLockNoAffect {
     pub inner: u8,
 }
@@ -4310,21 +5195,20 @@ impl SaIsoLockNoAffect {
     pub const MODS: Self = Self { inner: 64 };
     pub const COMPLETE: Self = Self { inner: 120 };
 }
-impl AsByteSequence for SaIsoLockNoAffect {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for SaIsoLockNoAffect {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((SaIsoLockNoAffect { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for SaIsoLockNoAffect {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { SaIsoLockNoAffect }
 impl core::ops::Not for SaIsoLockNoAffect {
     type Output = SaIsoLockNoAffect;
     #[inline]
@@ -4359,40 +5243,42 @@ impl core::ops::BitXor for SaIsoLockNoAffect {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaTerminate {
     pub ty: SaType,
 }
-impl SaTerminate {}
-impl AsByteSequence for SaTerminate {
+impl SaTerminate {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.ty.as_bytes(&mut bytes[index..]);
-        index += 7;
-        index
+    pub const fn new(ty: SaType) -> Self {
+        Self { ty: ty }
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1)];
+}
+impl_ty_only_struct_asb! { SaTerminate }
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct SaSwitchScreen {
+    pub ty: SaType,
+    pub flags: Card8,
+    pub new_screen: Int8,
+}
+impl SaSwitchScreen {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SaTerminate from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 7;
-        Some((SaTerminate { ty: ty }, index))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.ty.size() + 7
+    pub const fn new(ty: SaType, flags: Card8, new_screen: Int8) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            new_screen: new_screen,
+        }
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("ty", 0, 1), ("flags", 1, 1), ("new_screen", 2, 1)];
 }
-#[derive(Clone, Debug, Default)]
-pub struct SaSwitchScreen {
-    pub ty: SaType,
-    pub flags: Card8,
-    pub new_screen: Int8,
-}
-impl SaSwitchScreen {}
 impl AsByteSequence for SaSwitchScreen {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -4400,21 +5286,20 @@ impl AsByteSequence for SaSwitchScreen {
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.flags.as_bytes(&mut bytes[index..]);
         index += self.new_screen.as_bytes(&mut bytes[index..]);
-        index += 5;
+        index += zero_pad(&mut bytes[index..], 5);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSwitchScreen from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (new_screen, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (new_screen, sz): (Int8, usize) = parse_item(bytes, index, "new_screen")?;
         index += sz;
         index += 5;
-        Some((
+        Ok((
             SaSwitchScreen {
                 ty: ty,
                 flags: flags,
@@ -4425,42 +5310,69 @@ impl AsByteSequence for SaSwitchScreen {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.flags.size() + self.new_screen.size() + 5
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SaSwitchScreen {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaSetControls {
     pub ty: SaType,
     pub bool_ctrls_high: BoolCtrlsHigh,
     pub bool_ctrls_low: BoolCtrlsLow,
 }
-impl SaSetControls {}
+impl SaSetControls {
+    #[inline]
+    pub const fn new(
+        ty: SaType,
+        bool_ctrls_high: BoolCtrlsHigh,
+        bool_ctrls_low: BoolCtrlsLow,
+    ) -> Self {
+        Self {
+            ty: ty,
+            bool_ctrls_high: bool_ctrls_high,
+            bool_ctrls_low: bool_ctrls_low,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("ty", 0, 1),
+        ("bool_ctrls_high", 4, 1),
+        ("bool_ctrls_low", 5, 1),
+    ];
+}
 impl AsByteSequence for SaSetControls {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.bool_ctrls_high.as_bytes(&mut bytes[index..]);
         index += self.bool_ctrls_low.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetControls from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
         index += 3;
         let (bool_ctrls_high, sz): (BoolCtrlsHigh, usize) =
-            <BoolCtrlsHigh>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "bool_ctrls_high")?;
         index += sz;
         let (bool_ctrls_low, sz): (BoolCtrlsLow, usize) =
-            <BoolCtrlsLow>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "bool_ctrls_low")?;
         index += sz;
         index += 2;
-        Some((
+        Ok((
             SaSetControls {
                 ty: ty,
                 bool_ctrls_high: bool_ctrls_high,
@@ -4471,11 +5383,20 @@ impl AsByteSequence for SaSetControls {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + 3 + self.bool_ctrls_high.size() + self.bool_ctrls_low.size() + 2
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaSetControls {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct BoolCtrlsHigh {
     pub inner: u8,
 }
@@ -4582,21 +5503,20 @@ impl BoolCtrlsHigh {
     pub const IGNORE_GROUP_LOCK: Self = Self { inner: 16 };
     pub const COMPLETE: Self = Self { inner: 31 };
 }
-impl AsByteSequence for BoolCtrlsHigh {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for BoolCtrlsHigh {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((BoolCtrlsHigh { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for BoolCtrlsHigh {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { BoolCtrlsHigh }
 impl core::ops::Not for BoolCtrlsHigh {
     type Output = BoolCtrlsHigh;
     #[inline]
@@ -4632,7 +5552,9 @@ impl core::ops::BitXor for BoolCtrlsHigh {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct BoolCtrlsLow {
     pub inner: u8,
 }
@@ -4741,7 +5663,6 @@ impl BoolCtrlsLow {
         }
         self
     }
-    #[inline]
     pub fn new(
         repeat_keys: bool,
         slow_keys: bool,
@@ -4793,21 +5714,20 @@ impl BoolCtrlsLow {
     pub const ACCESS_X_TIMEOUT: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 255 };
 }
-impl AsByteSequence for BoolCtrlsLow {
+impl core::ops::Deref for BoolCtrlsLow {
+    type Target = u8;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((BoolCtrlsLow { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for BoolCtrlsLow {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { BoolCtrlsLow }
 impl core::ops::Not for BoolCtrlsLow {
     type Output = BoolCtrlsLow;
     #[inline]
@@ -4843,13 +5763,27 @@ impl core::ops::BitXor for BoolCtrlsLow {
     }
 }
 pub type SaLockControls = SaSetControls;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaActionMessage {
     pub ty: SaType,
     pub flags: ActionMessageFlag,
     pub message: [Card8; 6],
 }
-impl SaActionMessage {}
+impl SaActionMessage {
+    #[inline]
+    pub const fn new(ty: SaType, flags: ActionMessageFlag, message: [Card8; 6]) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            message: message,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("ty", 0, 1), ("flags", 1, 1), ("message", 2, 6)];
+}
 impl AsByteSequence for SaActionMessage {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -4859,18 +5793,16 @@ impl AsByteSequence for SaActionMessage {
         index += self.message.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaActionMessage from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (ActionMessageFlag, usize) =
-            <ActionMessageFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ActionMessageFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (message, sz): ([Card8; 6], usize) = <[Card8; 6]>::from_bytes(&bytes[index..])?;
+        let (message, sz): ([Card8; 6], usize) = parse_item(bytes, index, "message")?;
         index += sz;
-        Some((
+        Ok((
             SaActionMessage {
                 ty: ty,
                 flags: flags,
@@ -4881,11 +5813,20 @@ impl AsByteSequence for SaActionMessage {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.flags.size() + self.message.size()
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaActionMessage {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ActionMessageFlag {
     pub inner: u8,
 }
@@ -4952,21 +5893,20 @@ impl ActionMessageFlag {
     pub const GEN_KEY_EVENT: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 7 };
 }
-impl AsByteSequence for ActionMessageFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for ActionMessageFlag {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((ActionMessageFlag { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for ActionMessageFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { ActionMessageFlag }
 impl core::ops::Not for ActionMessageFlag {
     type Output = ActionMessageFlag;
     #[inline]
@@ -5001,7 +5941,9 @@ impl core::ops::BitXor for ActionMessageFlag {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaRedirectKey {
     pub ty: SaType,
     pub newkey: Keycode,
@@ -5012,9 +5954,11 @@ pub struct SaRedirectKey {
     pub vmods_high: VModsHigh,
     pub vmods_low: VModsLow,
 }
-impl SaRedirectKey {}
+impl SaRedirectKey {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1)];
+}
 impl AsByteSequence for SaRedirectKey {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
@@ -5027,27 +5971,27 @@ impl AsByteSequence for SaRedirectKey {
         index += self.vmods_low.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaRedirectKey from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (newkey, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (newkey, sz): (Keycode, usize) = parse_item(bytes, index, "newkey")?;
         index += sz;
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = parse_item(bytes, index, "mask")?;
         index += sz;
-        let (real_modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_modifiers, sz): (ModMask, usize) = parse_item(bytes, index, "real_modifiers")?;
         index += sz;
-        let (vmods_mask_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_mask_high, sz): (VModsHigh, usize) =
+            parse_item(bytes, index, "vmods_mask_high")?;
         index += sz;
-        let (vmods_mask_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_mask_low, sz): (VModsLow, usize) = parse_item(bytes, index, "vmods_mask_low")?;
         index += sz;
-        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_high, sz): (VModsHigh, usize) = parse_item(bytes, index, "vmods_high")?;
         index += sz;
-        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_low, sz): (VModsLow, usize) = parse_item(bytes, index, "vmods_low")?;
         index += sz;
-        Some((
+        Ok((
             SaRedirectKey {
                 ty: ty,
                 newkey: newkey,
@@ -5063,17 +6007,19 @@ impl AsByteSequence for SaRedirectKey {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size()
-            + self.newkey.size()
-            + self.mask.size()
-            + self.real_modifiers.size()
-            + self.vmods_mask_high.size()
-            + self.vmods_mask_low.size()
-            + self.vmods_high.size()
-            + self.vmods_low.size()
+        5 + self.newkey.size() + self.mask.size() + self.real_modifiers.size()
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SaRedirectKey {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaDeviceBtn {
     pub ty: SaType,
     pub flags: Card8,
@@ -5081,9 +6027,27 @@ pub struct SaDeviceBtn {
     pub button: Card8,
     pub device: Card8,
 }
-impl SaDeviceBtn {}
-impl AsByteSequence for SaDeviceBtn {
+impl SaDeviceBtn {
     #[inline]
+    pub const fn new(ty: SaType, flags: Card8, count: Card8, button: Card8, device: Card8) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            count: count,
+            button: button,
+            device: device,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("ty", 0, 1),
+        ("flags", 1, 1),
+        ("count", 2, 1),
+        ("button", 3, 1),
+        ("device", 4, 1),
+    ];
+}
+impl AsByteSequence for SaDeviceBtn {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
@@ -5091,25 +6055,24 @@ impl AsByteSequence for SaDeviceBtn {
         index += self.count.as_bytes(&mut bytes[index..]);
         index += self.button.as_bytes(&mut bytes[index..]);
         index += self.device.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaDeviceBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = parse_item(bytes, index, "count")?;
         index += sz;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = parse_item(bytes, index, "button")?;
         index += sz;
-        let (device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card8, usize) = parse_item(bytes, index, "device")?;
         index += sz;
         index += 3;
-        Some((
+        Ok((
             SaDeviceBtn {
                 ty: ty,
                 flags: flags,
@@ -5122,49 +6085,68 @@ impl AsByteSequence for SaDeviceBtn {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size()
-            + self.flags.size()
-            + self.count.size()
-            + self.button.size()
-            + self.device.size()
-            + 3
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SaDeviceBtn {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaLockDeviceBtn {
     pub ty: SaType,
     pub flags: LockDeviceFlags,
     pub button: Card8,
     pub device: Card8,
 }
-impl SaLockDeviceBtn {}
-impl AsByteSequence for SaLockDeviceBtn {
+impl SaLockDeviceBtn {
     #[inline]
+    pub const fn new(ty: SaType, flags: LockDeviceFlags, button: Card8, device: Card8) -> Self {
+        Self {
+            ty: ty,
+            flags: flags,
+            button: button,
+            device: device,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("ty", 0, 1),
+        ("flags", 1, 1),
+        ("button", 3, 1),
+        ("device", 4, 1),
+    ];
+}
+impl AsByteSequence for SaLockDeviceBtn {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.flags.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.button.as_bytes(&mut bytes[index..]);
         index += self.device.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaLockDeviceBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (flags, sz): (LockDeviceFlags, usize) = <LockDeviceFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (LockDeviceFlags, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
         index += 1;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = parse_item(bytes, index, "button")?;
         index += sz;
-        let (device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card8, usize) = parse_item(bytes, index, "device")?;
         index += sz;
         index += 3;
-        Some((
+        Ok((
             SaLockDeviceBtn {
                 ty: ty,
                 flags: flags,
@@ -5176,11 +6158,20 @@ impl AsByteSequence for SaLockDeviceBtn {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.flags.size() + 1 + self.button.size() + self.device.size() + 3
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaLockDeviceBtn {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct LockDeviceFlags {
     pub inner: u8,
 }
@@ -5230,21 +6221,20 @@ impl LockDeviceFlags {
     pub const NO_UNLOCK: Self = Self { inner: 2 };
     pub const COMPLETE: Self = Self { inner: 3 };
 }
-impl AsByteSequence for LockDeviceFlags {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for LockDeviceFlags {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((LockDeviceFlags { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for LockDeviceFlags {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { LockDeviceFlags }
 impl core::ops::Not for LockDeviceFlags {
     type Output = LockDeviceFlags;
     #[inline]
@@ -5279,7 +6269,9 @@ impl core::ops::BitXor for LockDeviceFlags {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SaDeviceValuator {
     pub ty: SaType,
     pub device: Card8,
@@ -5290,9 +6282,42 @@ pub struct SaDeviceValuator {
     pub val2index: Card8,
     pub val2value: Card8,
 }
-impl SaDeviceValuator {}
+impl SaDeviceValuator {
+    #[inline]
+    pub const fn new(
+        ty: SaType,
+        device: Card8,
+        val1what: SaValWhat,
+        val1index: Card8,
+        val1value: Card8,
+        val2what: SaValWhat,
+        val2index: Card8,
+        val2value: Card8,
+    ) -> Self {
+        Self {
+            ty: ty,
+            device: device,
+            val1what: val1what,
+            val1index: val1index,
+            val1value: val1value,
+            val2what: val2what,
+            val2index: val2index,
+            val2value: val2value,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("ty", 0, 1),
+        ("device", 1, 1),
+        ("val1what", 2, 1),
+        ("val1index", 3, 1),
+        ("val1value", 4, 1),
+        ("val2what", 5, 1),
+        ("val2index", 6, 1),
+        ("val2value", 7, 1),
+    ];
+}
 impl AsByteSequence for SaDeviceValuator {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.ty.as_bytes(&mut bytes[index..]);
@@ -5305,27 +6330,26 @@ impl AsByteSequence for SaDeviceValuator {
         index += self.val2value.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaDeviceValuator from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card8, usize) = parse_item(bytes, index, "device")?;
         index += sz;
-        let (val1what, sz): (SaValWhat, usize) = <SaValWhat>::from_bytes(&bytes[index..])?;
+        let (val1what, sz): (SaValWhat, usize) = parse_item(bytes, index, "val1what")?;
         index += sz;
-        let (val1index, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val1index, sz): (Card8, usize) = parse_item(bytes, index, "val1index")?;
         index += sz;
-        let (val1value, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val1value, sz): (Card8, usize) = parse_item(bytes, index, "val1value")?;
         index += sz;
-        let (val2what, sz): (SaValWhat, usize) = <SaValWhat>::from_bytes(&bytes[index..])?;
+        let (val2what, sz): (SaValWhat, usize) = parse_item(bytes, index, "val2what")?;
         index += sz;
-        let (val2index, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val2index, sz): (Card8, usize) = parse_item(bytes, index, "val2index")?;
         index += sz;
-        let (val2value, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val2value, sz): (Card8, usize) = parse_item(bytes, index, "val2value")?;
         index += sz;
-        Some((
+        Ok((
             SaDeviceValuator {
                 ty: ty,
                 device: device,
@@ -5341,18 +6365,20 @@ impl AsByteSequence for SaDeviceValuator {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size()
-            + self.device.size()
-            + self.val1what.size()
-            + self.val1index.size()
-            + self.val1value.size()
-            + self.val2what.size()
-            + self.val2index.size()
-            + self.val2value.size()
+        8
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SaDeviceValuator {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SaValWhat {
     IgnoreVal = 0,
     SetValMin = 1,
@@ -5366,17 +6392,16 @@ impl AsByteSequence for SaValWhat {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u8).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::IgnoreVal, sz)),
-            1 => Some((Self::SetValMin, sz)),
-            2 => Some((Self::SetValCenter, sz)),
-            3 => Some((Self::SetValMax, sz)),
-            4 => Some((Self::SetValRelative, sz)),
-            5 => Some((Self::SetValAbsolute, sz)),
-            _ => None,
+            0 => Ok((Self::IgnoreVal, sz)),
+            1 => Ok((Self::SetValMin, sz)),
+            2 => Ok((Self::SetValCenter, sz)),
+            3 => Ok((Self::SetValMax, sz)),
+            4 => Ok((Self::SetValRelative, sz)),
+            5 => Ok((Self::SetValAbsolute, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -5384,18 +6409,55 @@ impl AsByteSequence for SaValWhat {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SaValWhat {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SaValWhat {
     #[inline]
     fn default() -> SaValWhat {
         SaValWhat::IgnoreVal
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<SaValWhat> for u8 {
+    #[inline]
+    fn from(base: SaValWhat) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SaValWhat {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::IgnoreVal),
+            1 => Ok(Self::SetValMin),
+            2 => Ok(Self::SetValCenter),
+            3 => Ok(Self::SetValMax),
+            4 => Ok(Self::SetValRelative),
+            5 => Ok(Self::SetValAbsolute),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SiAction {
     pub ty: SaType,
     pub data: [Card8; 7],
 }
-impl SiAction {}
+impl SiAction {
+    #[inline]
+    pub const fn new(ty: SaType, data: [Card8; 7]) -> Self {
+        Self { ty: ty, data: data }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("ty", 0, 1), ("data", 1, 7)];
+}
 impl AsByteSequence for SiAction {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -5405,21 +6467,30 @@ impl AsByteSequence for SiAction {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SiAction from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (data, sz): ([Card8; 7], usize) = <[Card8; 7]>::from_bytes(&bytes[index..])?;
+        let (data, sz): ([Card8; 7], usize) = parse_item(bytes, index, "data")?;
         index += sz;
-        Some((SiAction { ty: ty, data: data }, index))
+        Ok((SiAction { ty: ty, data: data }, index))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size() + self.data.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for SiAction {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SymInterpret {
     pub sym: Keysym,
     pub mods: ModMask,
@@ -5428,9 +6499,16 @@ pub struct SymInterpret {
     pub flags: Card8,
     pub action: SiAction,
 }
-impl SymInterpret {}
-impl AsByteSequence for SymInterpret {
+impl SymInterpret {
+    #[doc = " Interpret `match_` as a `SymInterpretMatch`. Returns `None` if it holds a value that isn't one of the enumeration's."]
     #[inline]
+    pub fn match_enum(&self) -> Option<SymInterpretMatch> {
+        core::convert::TryFrom::try_from(self.match_).ok()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
+impl AsByteSequence for SymInterpret {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.sym.as_bytes(&mut bytes[index..]);
@@ -5441,23 +6519,22 @@ impl AsByteSequence for SymInterpret {
         index += self.action.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SymInterpret from byte buffer");
-        let (sym, sz): (Keysym, usize) = <Keysym>::from_bytes(&bytes[index..])?;
+        let (sym, sz): (Keysym, usize) = parse_item(bytes, index, "sym")?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods")?;
         index += sz;
-        let (match_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (match_, sz): (Card8, usize) = parse_item(bytes, index, "match_")?;
         index += sz;
-        let (virtual_mod, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (virtual_mod, sz): (VModsLow, usize) = parse_item(bytes, index, "virtual_mod")?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (action, sz): (SiAction, usize) = <SiAction>::from_bytes(&bytes[index..])?;
+        let (action, sz): (SiAction, usize) = parse_item(bytes, index, "action")?;
         index += sz;
-        Some((
+        Ok((
             SymInterpret {
                 sym: sym,
                 mods: mods,
@@ -5471,16 +6548,20 @@ impl AsByteSequence for SymInterpret {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.sym.size()
-            + self.mods.size()
-            + self.match_.size()
-            + self.virtual_mod.size()
-            + self.flags.size()
-            + self.action.size()
+        11 + self.sym.size() + self.mods.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SymInterpret {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum SymInterpretMatch {
     NoneOf = 0,
     AnyOfOrNone = 1,
@@ -5493,16 +6574,15 @@ impl AsByteSequence for SymInterpretMatch {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u8).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::NoneOf, sz)),
-            1 => Some((Self::AnyOfOrNone, sz)),
-            2 => Some((Self::AnyOf, sz)),
-            3 => Some((Self::AllOf, sz)),
-            4 => Some((Self::Exactly, sz)),
-            _ => None,
+            0 => Ok((Self::NoneOf, sz)),
+            1 => Ok((Self::AnyOfOrNone, sz)),
+            2 => Ok((Self::AnyOf, sz)),
+            3 => Ok((Self::AllOf, sz)),
+            4 => Ok((Self::Exactly, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -5510,45 +6590,102 @@ impl AsByteSequence for SymInterpretMatch {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for SymInterpretMatch {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for SymInterpretMatch {
     #[inline]
     fn default() -> SymInterpretMatch {
         SymInterpretMatch::NoneOf
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<SymInterpretMatch> for u8 {
+    #[inline]
+    fn from(base: SymInterpretMatch) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for SymInterpretMatch {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::NoneOf),
+            1 => Ok(Self::AnyOfOrNone),
+            2 => Ok(Self::AnyOf),
+            3 => Ok(Self::AllOf),
+            4 => Ok(Self::Exactly),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct UseExtensionRequest {
     pub req_type: u8,
     pub length: u16,
     pub wanted_major: Card16,
     pub wanted_minor: Card16,
 }
-impl UseExtensionRequest {}
-impl AsByteSequence for UseExtensionRequest {
+impl UseExtensionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> UseExtensionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<UseExtensionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("wanted_major", 4, 2),
+        ("wanted_minor", 6, 2),
+    ];
+}
+const _: () = assert!(<UseExtensionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for UseExtensionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.wanted_major.as_bytes(&mut bytes[index..]);
         index += self.wanted_minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing UseExtensionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (wanted_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (wanted_major, sz): (Card16, usize) = parse_item(bytes, index, "wanted_major")?;
         index += sz;
-        let (wanted_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (wanted_minor, sz): (Card16, usize) = parse_item(bytes, index, "wanted_minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             UseExtensionRequest {
                 req_type: req_type,
                 length: length,
@@ -5560,20 +6697,63 @@ impl AsByteSequence for UseExtensionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.wanted_major.size()
-            + self.wanted_minor.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for UseExtensionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for UseExtensionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UseExtension";
     type Reply = UseExtensionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `UseExtensionRequest`."]
+#[derive(Clone, Debug)]
+pub struct UseExtensionRequestBuilder {
+    pub inner: UseExtensionRequest,
+}
+impl UseExtensionRequestBuilder {
+    #[inline]
+    pub fn wanted_major(mut self, wanted_major: Card16) -> Self {
+        self.inner.wanted_major = wanted_major;
+        self
+    }
+    #[inline]
+    pub fn wanted_minor(mut self, wanted_minor: Card16) -> Self {
+        self.inner.wanted_minor = wanted_minor;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> UseExtensionRequest {
+        self.inner
+    }
+}
+impl Default for UseExtensionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UseExtensionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                wanted_major: Default::default(),
+                wanted_minor: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct UseExtensionReply {
     pub reply_type: u8,
     pub supported: bool,
@@ -5582,9 +6762,56 @@ pub struct UseExtensionReply {
     pub server_major: Card16,
     pub server_minor: Card16,
 }
-impl UseExtensionReply {}
+impl UseExtensionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("supported", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("server_major", 8, 2),
+        ("server_minor", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.supported == other.supported
+            && self.server_major == other.server_major
+            && self.server_minor == other.server_minor
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("supported", &self.supported, &other.supported, &mut changes);
+        diff_value(
+            "server_major",
+            &self.server_major,
+            &other.server_major,
+            &mut changes,
+        );
+        diff_value(
+            "server_minor",
+            &self.server_minor,
+            &other.server_minor,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for UseExtensionReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -5593,27 +6820,29 @@ impl AsByteSequence for UseExtensionReply {
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.server_major.as_bytes(&mut bytes[index..]);
         index += self.server_minor.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing UseExtensionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (supported, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (bool, usize) = parse_item(bytes, index, "supported")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (server_major, sz): (Card16, usize) = parse_item(bytes, index, "server_major")?;
         index += sz;
-        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor, sz): (Card16, usize) = parse_item(bytes, index, "server_minor")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             UseExtensionReply {
                 reply_type: reply_type,
                 supported: supported,
@@ -5627,16 +6856,21 @@ impl AsByteSequence for UseExtensionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.supported.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.server_major.size()
-            + self.server_minor.size()
-            + 20
+        let size: usize = 31 + self.supported.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for UseExtensionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectEventsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -5646,36 +6880,52 @@ pub struct SelectEventsRequest {
     pub select_all: EventType,
     pub affect_map: MapPart,
     pub map: MapPart,
-    pub affect_new_keyboard: NknDetail,
-    pub new_keyboard_details: NknDetail,
-    pub affect_state: StatePart,
-    pub state_details: StatePart,
-    pub affect_ctrls: Control,
-    pub ctrl_details: Control,
-    pub affect_indicator_state: Card32,
-    pub indicator_state_details: Card32,
-    pub affect_indicator_map: Card32,
-    pub indicator_map_details: Card32,
-    pub affect_names: NameDetail,
-    pub names_details: NameDetail,
-    pub affect_compat: CmDetail,
-    pub compat_details: CmDetail,
-    pub affect_bell: Card8,
-    pub bell_details: Card8,
-    pub affect_msg_details: Card8,
-    pub msg_details: Card8,
-    pub affect_access_x: AxnDetail,
-    pub access_x_details: AxnDetail,
-    pub affect_ext_dev: XiFeature,
-    pub extdev_details: XiFeature,
-}
-impl SelectEventsRequest {}
+    pub affect_new_keyboard: Option<NknDetail>,
+    pub new_keyboard_details: Option<NknDetail>,
+    pub affect_state: Option<StatePart>,
+    pub state_details: Option<StatePart>,
+    pub affect_ctrls: Option<Control>,
+    pub ctrl_details: Option<Control>,
+    pub affect_indicator_state: Option<Card32>,
+    pub indicator_state_details: Option<Card32>,
+    pub affect_indicator_map: Option<Card32>,
+    pub indicator_map_details: Option<Card32>,
+    pub affect_names: Option<NameDetail>,
+    pub names_details: Option<NameDetail>,
+    pub affect_compat: Option<CmDetail>,
+    pub compat_details: Option<CmDetail>,
+    pub affect_bell: Option<Card8>,
+    pub bell_details: Option<Card8>,
+    pub affect_msg_details: Option<Card8>,
+    pub msg_details: Option<Card8>,
+    pub affect_access_x: Option<AxnDetail>,
+    pub access_x_details: Option<AxnDetail>,
+    pub affect_ext_dev: Option<XiFeature>,
+    pub extdev_details: Option<XiFeature>,
+}
+impl SelectEventsRequest {
+    #[inline]
+    pub fn builder() -> SelectEventsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("affect_which", 6, 2),
+        ("clear", 8, 2),
+        ("select_all", 10, 2),
+        ("affect_map", 12, 2),
+        ("map", 14, 2),
+    ];
+}
+const _: () = assert!(<SelectEventsRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for SelectEventsRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.affect_which.as_bytes(&mut bytes[index..]);
@@ -5686,261 +6936,187 @@ impl AsByteSequence for SelectEventsRequest {
         let cond0 = ((self.affect_which) & ((!(self.clear)) & (!(self.select_all))));
         if cond0.new_keyboard_notify() {
             index += self.affect_new_keyboard.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.new_keyboard_notify() {
             index += self.new_keyboard_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.state_notify() {
             index += self.affect_state.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.state_notify() {
             index += self.state_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.controls_notify() {
             index += self.affect_ctrls.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.controls_notify() {
             index += self.ctrl_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.indicator_state_notify() {
             index += self.affect_indicator_state.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.indicator_state_notify() {
             index += self.indicator_state_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.indicator_map_notify() {
             index += self.affect_indicator_map.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.indicator_map_notify() {
             index += self.indicator_map_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.names_notify() {
             index += self.affect_names.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.names_notify() {
             index += self.names_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.compat_map_notify() {
             index += self.affect_compat.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.compat_map_notify() {
             index += self.compat_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.bell_notify() {
             index += self.affect_bell.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.bell_notify() {
             index += self.bell_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.action_message() {
             index += self.affect_msg_details.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.action_message() {
             index += self.msg_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.access_x_notify() {
             index += self.affect_access_x.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.access_x_notify() {
             index += self.access_x_details.as_bytes(&mut bytes[index..]);
         }
         if cond0.extension_device_notify() {
             index += self.affect_ext_dev.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.extension_device_notify() {
             index += self.extdev_details.as_bytes(&mut bytes[index..]);
         }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (affect_which, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (affect_which, sz): (EventType, usize) = parse_item(bytes, index, "affect_which")?;
         index += sz;
-        let (clear, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (clear, sz): (EventType, usize) = parse_item(bytes, index, "clear")?;
         index += sz;
-        let (select_all, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (select_all, sz): (EventType, usize) = parse_item(bytes, index, "select_all")?;
         index += sz;
-        let (affect_map, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (affect_map, sz): (MapPart, usize) = parse_item(bytes, index, "affect_map")?;
         index += sz;
-        let (map, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (map, sz): (MapPart, usize) = parse_item(bytes, index, "map")?;
         index += sz;
         let cond0 = ((affect_which) & ((!(clear)) & (!(select_all))));
-        let affect_new_keyboard: NknDetail = if cond0.new_keyboard_notify() {
+        let (affect_new_keyboard, new_keyboard_details) = if cond0.new_keyboard_notify() {
             let (affect_new_keyboard, sz): (NknDetail, usize) =
-                <NknDetail>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "affect_new_keyboard")?;
             index += sz;
-            affect_new_keyboard
-        } else {
-            Default::default()
-        };
-        let new_keyboard_details: NknDetail = if cond0.new_keyboard_notify() {
             let (new_keyboard_details, sz): (NknDetail, usize) =
-                <NknDetail>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "new_keyboard_details")?;
             index += sz;
-            new_keyboard_details
+            (Some(affect_new_keyboard), Some(new_keyboard_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_state: StatePart = if cond0.state_notify() {
-            let (affect_state, sz): (StatePart, usize) = <StatePart>::from_bytes(&bytes[index..])?;
+        let (affect_state, state_details) = if cond0.state_notify() {
+            let (affect_state, sz): (StatePart, usize) = parse_item(bytes, index, "affect_state")?;
             index += sz;
-            affect_state
-        } else {
-            Default::default()
-        };
-        let state_details: StatePart = if cond0.state_notify() {
-            let (state_details, sz): (StatePart, usize) = <StatePart>::from_bytes(&bytes[index..])?;
+            let (state_details, sz): (StatePart, usize) =
+                parse_item(bytes, index, "state_details")?;
             index += sz;
-            state_details
+            (Some(affect_state), Some(state_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_ctrls: Control = if cond0.controls_notify() {
-            let (affect_ctrls, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+        let (affect_ctrls, ctrl_details) = if cond0.controls_notify() {
+            let (affect_ctrls, sz): (Control, usize) = parse_item(bytes, index, "affect_ctrls")?;
             index += sz;
-            affect_ctrls
-        } else {
-            Default::default()
-        };
-        let ctrl_details: Control = if cond0.controls_notify() {
-            let (ctrl_details, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+            let (ctrl_details, sz): (Control, usize) = parse_item(bytes, index, "ctrl_details")?;
             index += sz;
-            ctrl_details
+            (Some(affect_ctrls), Some(ctrl_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_indicator_state: Card32 = if cond0.indicator_state_notify() {
+        let (affect_indicator_state, indicator_state_details) = if cond0.indicator_state_notify() {
             let (affect_indicator_state, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "affect_indicator_state")?;
             index += sz;
-            affect_indicator_state
-        } else {
-            Default::default()
-        };
-        let indicator_state_details: Card32 = if cond0.indicator_state_notify() {
             let (indicator_state_details, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "indicator_state_details")?;
             index += sz;
-            indicator_state_details
+            (Some(affect_indicator_state), Some(indicator_state_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_indicator_map: Card32 = if cond0.indicator_map_notify() {
+        let (affect_indicator_map, indicator_map_details) = if cond0.indicator_map_notify() {
             let (affect_indicator_map, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "affect_indicator_map")?;
             index += sz;
-            affect_indicator_map
-        } else {
-            Default::default()
-        };
-        let indicator_map_details: Card32 = if cond0.indicator_map_notify() {
             let (indicator_map_details, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "indicator_map_details")?;
             index += sz;
-            indicator_map_details
+            (Some(affect_indicator_map), Some(indicator_map_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_names: NameDetail = if cond0.names_notify() {
-            let (affect_names, sz): (NameDetail, usize) =
-                <NameDetail>::from_bytes(&bytes[index..])?;
+        let (affect_names, names_details) = if cond0.names_notify() {
+            let (affect_names, sz): (NameDetail, usize) = parse_item(bytes, index, "affect_names")?;
             index += sz;
-            affect_names
-        } else {
-            Default::default()
-        };
-        let names_details: NameDetail = if cond0.names_notify() {
             let (names_details, sz): (NameDetail, usize) =
-                <NameDetail>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "names_details")?;
             index += sz;
-            names_details
+            (Some(affect_names), Some(names_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_compat: CmDetail = if cond0.compat_map_notify() {
-            let (affect_compat, sz): (CmDetail, usize) = <CmDetail>::from_bytes(&bytes[index..])?;
+        let (affect_compat, compat_details) = if cond0.compat_map_notify() {
+            let (affect_compat, sz): (CmDetail, usize) = parse_item(bytes, index, "affect_compat")?;
             index += sz;
-            affect_compat
-        } else {
-            Default::default()
-        };
-        let compat_details: CmDetail = if cond0.compat_map_notify() {
-            let (compat_details, sz): (CmDetail, usize) = <CmDetail>::from_bytes(&bytes[index..])?;
+            let (compat_details, sz): (CmDetail, usize) =
+                parse_item(bytes, index, "compat_details")?;
             index += sz;
-            compat_details
+            (Some(affect_compat), Some(compat_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_bell: Card8 = if cond0.bell_notify() {
-            let (affect_bell, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (affect_bell, bell_details) = if cond0.bell_notify() {
+            let (affect_bell, sz): (Card8, usize) = parse_item(bytes, index, "affect_bell")?;
             index += sz;
-            affect_bell
-        } else {
-            Default::default()
-        };
-        let bell_details: Card8 = if cond0.bell_notify() {
-            let (bell_details, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (bell_details, sz): (Card8, usize) = parse_item(bytes, index, "bell_details")?;
             index += sz;
-            bell_details
+            (Some(affect_bell), Some(bell_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_msg_details: Card8 = if cond0.action_message() {
-            let (affect_msg_details, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (affect_msg_details, msg_details) = if cond0.action_message() {
+            let (affect_msg_details, sz): (Card8, usize) =
+                parse_item(bytes, index, "affect_msg_details")?;
             index += sz;
-            affect_msg_details
-        } else {
-            Default::default()
-        };
-        let msg_details: Card8 = if cond0.action_message() {
-            let (msg_details, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (msg_details, sz): (Card8, usize) = parse_item(bytes, index, "msg_details")?;
             index += sz;
-            msg_details
+            (Some(affect_msg_details), Some(msg_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_access_x: AxnDetail = if cond0.access_x_notify() {
+        let (affect_access_x, access_x_details) = if cond0.access_x_notify() {
             let (affect_access_x, sz): (AxnDetail, usize) =
-                <AxnDetail>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "affect_access_x")?;
             index += sz;
-            affect_access_x
-        } else {
-            Default::default()
-        };
-        let access_x_details: AxnDetail = if cond0.access_x_notify() {
             let (access_x_details, sz): (AxnDetail, usize) =
-                <AxnDetail>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "access_x_details")?;
             index += sz;
-            access_x_details
+            (Some(affect_access_x), Some(access_x_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        let affect_ext_dev: XiFeature = if cond0.extension_device_notify() {
+        let (affect_ext_dev, extdev_details) = if cond0.extension_device_notify() {
             let (affect_ext_dev, sz): (XiFeature, usize) =
-                <XiFeature>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "affect_ext_dev")?;
             index += sz;
-            affect_ext_dev
-        } else {
-            Default::default()
-        };
-        let extdev_details: XiFeature = if cond0.extension_device_notify() {
             let (extdev_details, sz): (XiFeature, usize) =
-                <XiFeature>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "extdev_details")?;
             index += sz;
-            extdev_details
+            (Some(affect_ext_dev), Some(extdev_details))
         } else {
-            Default::default()
+            (Default::default(), Default::default())
         };
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectEventsRequest {
                 req_type: req_type,
                 length: length,
@@ -5976,17 +7152,8 @@ impl AsByteSequence for SelectEventsRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.affect_which.size()
-            + self.clear.size()
-            + self.select_all.size()
-            + self.affect_map.size()
-            + self.map.size()
+        let size: usize = 16
             + self.affect_new_keyboard.size()
             + self.new_keyboard_details.size()
             + self.affect_state.size()
@@ -6008,54 +7175,257 @@ impl AsByteSequence for SelectEventsRequest {
             + self.affect_access_x.size()
             + self.access_x_details.size()
             + self.affect_ext_dev.size()
-            + self.extdev_details.size()
+            + self.extdev_details.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectEventsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectEventsRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectEvents";
     type Reply = ();
 }
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct EventType {
-    pub inner: u16,
+#[doc = " A builder for `SelectEventsRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectEventsRequestBuilder {
+    pub inner: SelectEventsRequest,
 }
-impl EventType {
+impl SelectEventsRequestBuilder {
     #[inline]
-    pub fn new_keyboard_notify(&self) -> bool {
-        self.inner & (1 << 0) != 0
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
     }
     #[inline]
-    pub fn set_new_keyboard_notify(&mut self, val: bool) -> &mut Self {
-        if val {
-            self.inner |= 1 << 0;
-        } else {
-            self.inner &= !(1 << 0);
-        }
+    pub fn affect_which(mut self, affect_which: EventType) -> Self {
+        self.inner.affect_which = affect_which;
         self
     }
     #[inline]
-    pub fn map_notify(&self) -> bool {
-        self.inner & (1 << 1) != 0
+    pub fn clear(mut self, clear: EventType) -> Self {
+        self.inner.clear = clear;
+        self
     }
     #[inline]
-    pub fn set_map_notify(&mut self, val: bool) -> &mut Self {
-        if val {
-            self.inner |= 1 << 1;
-        } else {
-            self.inner &= !(1 << 1);
-        }
+    pub fn select_all(mut self, select_all: EventType) -> Self {
+        self.inner.select_all = select_all;
         self
     }
     #[inline]
-    pub fn state_notify(&self) -> bool {
-        self.inner & (1 << 2) != 0
+    pub fn affect_map(mut self, affect_map: MapPart) -> Self {
+        self.inner.affect_map = affect_map;
+        self
     }
     #[inline]
-    pub fn set_state_notify(&mut self, val: bool) -> &mut Self {
-        if val {
+    pub fn map(mut self, map: MapPart) -> Self {
+        self.inner.map = map;
+        self
+    }
+    #[inline]
+    pub fn affect_new_keyboard(mut self, affect_new_keyboard: NknDetail) -> Self {
+        self.inner.affect_new_keyboard = Some(affect_new_keyboard);
+        self
+    }
+    #[inline]
+    pub fn new_keyboard_details(mut self, new_keyboard_details: NknDetail) -> Self {
+        self.inner.new_keyboard_details = Some(new_keyboard_details);
+        self
+    }
+    #[inline]
+    pub fn affect_state(mut self, affect_state: StatePart) -> Self {
+        self.inner.affect_state = Some(affect_state);
+        self
+    }
+    #[inline]
+    pub fn state_details(mut self, state_details: StatePart) -> Self {
+        self.inner.state_details = Some(state_details);
+        self
+    }
+    #[inline]
+    pub fn affect_ctrls(mut self, affect_ctrls: Control) -> Self {
+        self.inner.affect_ctrls = Some(affect_ctrls);
+        self
+    }
+    #[inline]
+    pub fn ctrl_details(mut self, ctrl_details: Control) -> Self {
+        self.inner.ctrl_details = Some(ctrl_details);
+        self
+    }
+    #[inline]
+    pub fn affect_indicator_state(mut self, affect_indicator_state: Card32) -> Self {
+        self.inner.affect_indicator_state = Some(affect_indicator_state);
+        self
+    }
+    #[inline]
+    pub fn indicator_state_details(mut self, indicator_state_details: Card32) -> Self {
+        self.inner.indicator_state_details = Some(indicator_state_details);
+        self
+    }
+    #[inline]
+    pub fn affect_indicator_map(mut self, affect_indicator_map: Card32) -> Self {
+        self.inner.affect_indicator_map = Some(affect_indicator_map);
+        self
+    }
+    #[inline]
+    pub fn indicator_map_details(mut self, indicator_map_details: Card32) -> Self {
+        self.inner.indicator_map_details = Some(indicator_map_details);
+        self
+    }
+    #[inline]
+    pub fn affect_names(mut self, affect_names: NameDetail) -> Self {
+        self.inner.affect_names = Some(affect_names);
+        self
+    }
+    #[inline]
+    pub fn names_details(mut self, names_details: NameDetail) -> Self {
+        self.inner.names_details = Some(names_details);
+        self
+    }
+    #[inline]
+    pub fn affect_compat(mut self, affect_compat: CmDetail) -> Self {
+        self.inner.affect_compat = Some(affect_compat);
+        self
+    }
+    #[inline]
+    pub fn compat_details(mut self, compat_details: CmDetail) -> Self {
+        self.inner.compat_details = Some(compat_details);
+        self
+    }
+    #[inline]
+    pub fn affect_bell(mut self, affect_bell: Card8) -> Self {
+        self.inner.affect_bell = Some(affect_bell);
+        self
+    }
+    #[inline]
+    pub fn bell_details(mut self, bell_details: Card8) -> Self {
+        self.inner.bell_details = Some(bell_details);
+        self
+    }
+    #[inline]
+    pub fn affect_msg_details(mut self, affect_msg_details: Card8) -> Self {
+        self.inner.affect_msg_details = Some(affect_msg_details);
+        self
+    }
+    #[inline]
+    pub fn msg_details(mut self, msg_details: Card8) -> Self {
+        self.inner.msg_details = Some(msg_details);
+        self
+    }
+    #[inline]
+    pub fn affect_access_x(mut self, affect_access_x: AxnDetail) -> Self {
+        self.inner.affect_access_x = Some(affect_access_x);
+        self
+    }
+    #[inline]
+    pub fn access_x_details(mut self, access_x_details: AxnDetail) -> Self {
+        self.inner.access_x_details = Some(access_x_details);
+        self
+    }
+    #[inline]
+    pub fn affect_ext_dev(mut self, affect_ext_dev: XiFeature) -> Self {
+        self.inner.affect_ext_dev = Some(affect_ext_dev);
+        self
+    }
+    #[inline]
+    pub fn extdev_details(mut self, extdev_details: XiFeature) -> Self {
+        self.inner.extdev_details = Some(extdev_details);
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectEventsRequest {
+        self.inner
+    }
+}
+impl Default for SelectEventsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectEventsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                affect_which: Default::default(),
+                clear: Default::default(),
+                select_all: Default::default(),
+                affect_map: Default::default(),
+                map: Default::default(),
+                affect_new_keyboard: Default::default(),
+                new_keyboard_details: Default::default(),
+                affect_state: Default::default(),
+                state_details: Default::default(),
+                affect_ctrls: Default::default(),
+                ctrl_details: Default::default(),
+                affect_indicator_state: Default::default(),
+                indicator_state_details: Default::default(),
+                affect_indicator_map: Default::default(),
+                indicator_map_details: Default::default(),
+                affect_names: Default::default(),
+                names_details: Default::default(),
+                affect_compat: Default::default(),
+                compat_details: Default::default(),
+                affect_bell: Default::default(),
+                bell_details: Default::default(),
+                affect_msg_details: Default::default(),
+                msg_details: Default::default(),
+                affect_access_x: Default::default(),
+                access_x_details: Default::default(),
+                affect_ext_dev: Default::default(),
+                extdev_details: Default::default(),
+            },
+        }
+    }
+}
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct EventType {
+    pub inner: u16,
+}
+impl EventType {
+    #[inline]
+    pub fn new_keyboard_notify(&self) -> bool {
+        self.inner & (1 << 0) != 0
+    }
+    #[inline]
+    pub fn set_new_keyboard_notify(&mut self, val: bool) -> &mut Self {
+        if val {
+            self.inner |= 1 << 0;
+        } else {
+            self.inner &= !(1 << 0);
+        }
+        self
+    }
+    #[inline]
+    pub fn map_notify(&self) -> bool {
+        self.inner & (1 << 1) != 0
+    }
+    #[inline]
+    pub fn set_map_notify(&mut self, val: bool) -> &mut Self {
+        if val {
+            self.inner |= 1 << 1;
+        } else {
+            self.inner &= !(1 << 1);
+        }
+        self
+    }
+    #[inline]
+    pub fn state_notify(&self) -> bool {
+        self.inner & (1 << 2) != 0
+    }
+    #[inline]
+    pub fn set_state_notify(&mut self, val: bool) -> &mut Self {
+        if val {
             self.inner |= 1 << 2;
         } else {
             self.inner &= !(1 << 2);
@@ -6179,7 +7549,6 @@ impl EventType {
         }
         self
     }
-    #[inline]
     pub fn new(
         new_keyboard_notify: bool,
         map_notify: bool,
@@ -6251,21 +7620,20 @@ impl EventType {
     pub const EXTENSION_DEVICE_NOTIFY: Self = Self { inner: 2048 };
     pub const COMPLETE: Self = Self { inner: 4095 };
 }
-impl AsByteSequence for EventType {
+impl core::ops::Deref for EventType {
+    type Target = u16;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((EventType { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for EventType {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { EventType }
 impl core::ops::Not for EventType {
     type Output = EventType;
     #[inline]
@@ -6301,7 +7669,9 @@ impl core::ops::BitXor for EventType {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct MapPart {
     pub inner: u16,
 }
@@ -6410,7 +7780,6 @@ impl MapPart {
         }
         self
     }
-    #[inline]
     pub fn new(
         key_types: bool,
         key_syms: bool,
@@ -6462,21 +7831,20 @@ impl MapPart {
     pub const VIRTUAL_MOD_MAP: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 255 };
 }
-impl AsByteSequence for MapPart {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for MapPart {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((MapPart { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for MapPart {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { MapPart }
 impl core::ops::Not for MapPart {
     type Output = MapPart;
     #[inline]
@@ -6511,7 +7879,10 @@ impl core::ops::BitXor for MapPart {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct BellRequest {
     pub req_type: u8,
     pub length: u16,
@@ -6526,13 +7897,31 @@ pub struct BellRequest {
     pub name: Atom,
     pub window: Window,
 }
-impl BellRequest {}
+impl BellRequest {
+    #[inline]
+    pub fn builder() -> BellRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("bell_class", 6, 2),
+        ("bell_id", 8, 2),
+        ("percent", 10, 1),
+        ("force_sound", 11, 1),
+        ("event_only", 12, 1),
+        ("pitch", 14, 2),
+        ("duration", 16, 2),
+    ];
+}
+const _: () = assert!(<BellRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for BellRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.bell_class.as_bytes(&mut bytes[index..]);
@@ -6540,47 +7929,47 @@ impl AsByteSequence for BellRequest {
         index += self.percent.as_bytes(&mut bytes[index..]);
         index += self.force_sound.as_bytes(&mut bytes[index..]);
         index += self.event_only.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.pitch.as_bytes(&mut bytes[index..]);
         index += self.duration.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.name.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing BellRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (bell_class, sz): (BellClassSpec, usize) =
-            <BellClassSpec>::from_bytes(&bytes[index..])?;
+        let (bell_class, sz): (BellClassSpec, usize) = parse_item(bytes, index, "bell_class")?;
         index += sz;
-        let (bell_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (bell_id, sz): (IdSpec, usize) = parse_item(bytes, index, "bell_id")?;
         index += sz;
-        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Int8, usize) = parse_item(bytes, index, "percent")?;
         index += sz;
-        let (force_sound, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (force_sound, sz): (bool, usize) = parse_item(bytes, index, "force_sound")?;
         index += sz;
-        let (event_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (event_only, sz): (bool, usize) = parse_item(bytes, index, "event_only")?;
         index += sz;
         index += 1;
-        let (pitch, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Int16, usize) = parse_item(bytes, index, "pitch")?;
         index += sz;
-        let (duration, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (duration, sz): (Int16, usize) = parse_item(bytes, index, "duration")?;
         index += sz;
         index += 2;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             BellRequest {
                 req_type: req_type,
                 length: length,
@@ -6600,80 +7989,232 @@ impl AsByteSequence for BellRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.bell_class.size()
-            + self.bell_id.size()
-            + self.percent.size()
+        let size: usize = 18
             + self.force_sound.size()
             + self.event_only.size()
-            + 1
-            + self.pitch.size()
-            + self.duration.size()
-            + 2
             + self.name.size()
-            + self.window.size()
+            + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for BellRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for BellRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Bell";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetStateRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub device_spec: DeviceSpec,
+#[doc = " A builder for `BellRequest`."]
+#[derive(Clone, Debug)]
+pub struct BellRequestBuilder {
+    pub inner: BellRequest,
 }
-impl GetStateRequest {}
-impl AsByteSequence for GetStateRequest {
+impl BellRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetStateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetStateRequest {
-                req_type: req_type,
-                length: length,
-                device_spec: device_spec,
-            },
-            index,
-        ))
+    pub fn bell_class(mut self, bell_class: BellClassSpec) -> Self {
+        self.inner.bell_class = bell_class;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.device_spec.size() + 2
+    pub fn bell_id(mut self, bell_id: IdSpec) -> Self {
+        self.inner.bell_id = bell_id;
+        self
+    }
+    #[inline]
+    pub fn percent(mut self, percent: Int8) -> Self {
+        self.inner.percent = percent;
+        self
+    }
+    #[inline]
+    pub fn force_sound(mut self, force_sound: bool) -> Self {
+        self.inner.force_sound = force_sound;
+        self
+    }
+    #[inline]
+    pub fn event_only(mut self, event_only: bool) -> Self {
+        self.inner.event_only = event_only;
+        self
+    }
+    #[inline]
+    pub fn pitch(mut self, pitch: Int16) -> Self {
+        self.inner.pitch = pitch;
+        self
+    }
+    #[inline]
+    pub fn duration(mut self, duration: Int16) -> Self {
+        self.inner.duration = duration;
+        self
+    }
+    #[inline]
+    pub fn name(mut self, name: Atom) -> Self {
+        self.inner.name = name;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> BellRequest {
+        self.inner
+    }
+}
+impl Default for BellRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: BellRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                bell_class: Default::default(),
+                bell_id: Default::default(),
+                percent: Default::default(),
+                force_sound: Default::default(),
+                event_only: Default::default(),
+                pitch: Default::default(),
+                duration: Default::default(),
+                name: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct GetStateRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub device_spec: DeviceSpec,
+}
+impl GetStateRequest {
+    #[inline]
+    pub fn builder() -> GetStateRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GetStateReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("device_spec", 4, 2)];
+}
+const _: () = assert!(<GetStateRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_device_spec_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.device_spec.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 2);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (device_spec, sz): (DeviceSpec, usize) =
+                    parse_item(bytes, index, "device_spec")?;
+                index += sz;
+                index += 2;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        device_spec: device_spec,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_device_spec_only_request_asb! { GetStateRequest }
 impl Request for GetStateRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetState";
     type Reply = GetStateReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetStateRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetStateRequestBuilder {
+    pub inner: GetStateRequest,
+}
+impl GetStateRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetStateRequest {
+        self.inner
+    }
+}
+impl Default for GetStateRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetStateRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetStateReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -6694,9 +8235,118 @@ pub struct GetStateReply {
     pub compat_lookup_mods: ModMask,
     pub ptr_btn_state: KeyButMask,
 }
-impl GetStateReply {}
+impl GetStateReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.mods == other.mods
+            && self.base_mods == other.base_mods
+            && self.latched_mods == other.latched_mods
+            && self.locked_mods == other.locked_mods
+            && self.group == other.group
+            && self.locked_group == other.locked_group
+            && self.base_group == other.base_group
+            && self.latched_group == other.latched_group
+            && self.compat_state == other.compat_state
+            && self.grab_mods == other.grab_mods
+            && self.compat_grab_mods == other.compat_grab_mods
+            && self.lookup_mods == other.lookup_mods
+            && self.compat_lookup_mods == other.compat_lookup_mods
+            && self.ptr_btn_state == other.ptr_btn_state
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value("mods", &self.mods, &other.mods, &mut changes);
+        diff_value("base_mods", &self.base_mods, &other.base_mods, &mut changes);
+        diff_value(
+            "latched_mods",
+            &self.latched_mods,
+            &other.latched_mods,
+            &mut changes,
+        );
+        diff_value(
+            "locked_mods",
+            &self.locked_mods,
+            &other.locked_mods,
+            &mut changes,
+        );
+        diff_value("group", &self.group, &other.group, &mut changes);
+        diff_value(
+            "locked_group",
+            &self.locked_group,
+            &other.locked_group,
+            &mut changes,
+        );
+        diff_value(
+            "base_group",
+            &self.base_group,
+            &other.base_group,
+            &mut changes,
+        );
+        diff_value(
+            "latched_group",
+            &self.latched_group,
+            &other.latched_group,
+            &mut changes,
+        );
+        diff_value(
+            "compat_state",
+            &self.compat_state,
+            &other.compat_state,
+            &mut changes,
+        );
+        diff_value("grab_mods", &self.grab_mods, &other.grab_mods, &mut changes);
+        diff_value(
+            "compat_grab_mods",
+            &self.compat_grab_mods,
+            &other.compat_grab_mods,
+            &mut changes,
+        );
+        diff_value(
+            "lookup_mods",
+            &self.lookup_mods,
+            &other.lookup_mods,
+            &mut changes,
+        );
+        diff_value(
+            "compat_lookup_mods",
+            &self.compat_lookup_mods,
+            &other.compat_lookup_mods,
+            &mut changes,
+        );
+        diff_value(
+            "ptr_btn_state",
+            &self.ptr_btn_state,
+            &other.ptr_btn_state,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for GetStateReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -6716,54 +8366,58 @@ impl AsByteSequence for GetStateReply {
         index += self.compat_grab_mods.as_bytes(&mut bytes[index..]);
         index += self.lookup_mods.as_bytes(&mut bytes[index..]);
         index += self.compat_lookup_mods.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.ptr_btn_state.as_bytes(&mut bytes[index..]);
-        index += 6;
+        index += zero_pad(&mut bytes[index..], 6);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetStateReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods")?;
         index += sz;
-        let (base_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (base_mods, sz): (ModMask, usize) = parse_item(bytes, index, "base_mods")?;
         index += sz;
-        let (latched_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (latched_mods, sz): (ModMask, usize) = parse_item(bytes, index, "latched_mods")?;
         index += sz;
-        let (locked_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (locked_mods, sz): (ModMask, usize) = parse_item(bytes, index, "locked_mods")?;
         index += sz;
-        let (group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Group, usize) = parse_item(bytes, index, "group")?;
         index += sz;
-        let (locked_group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (locked_group, sz): (Group, usize) = parse_item(bytes, index, "locked_group")?;
         index += sz;
-        let (base_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (base_group, sz): (Int16, usize) = parse_item(bytes, index, "base_group")?;
         index += sz;
-        let (latched_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (latched_group, sz): (Int16, usize) = parse_item(bytes, index, "latched_group")?;
         index += sz;
-        let (compat_state, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_state, sz): (ModMask, usize) = parse_item(bytes, index, "compat_state")?;
         index += sz;
-        let (grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (grab_mods, sz): (ModMask, usize) = parse_item(bytes, index, "grab_mods")?;
         index += sz;
-        let (compat_grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_grab_mods, sz): (ModMask, usize) =
+            parse_item(bytes, index, "compat_grab_mods")?;
         index += sz;
-        let (lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (lookup_mods, sz): (ModMask, usize) = parse_item(bytes, index, "lookup_mods")?;
         index += sz;
-        let (compat_lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_lookup_mods, sz): (ModMask, usize) =
+            parse_item(bytes, index, "compat_lookup_mods")?;
         index += sz;
         index += 1;
-        let (ptr_btn_state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (ptr_btn_state, sz): (KeyButMask, usize) = parse_item(bytes, index, "ptr_btn_state")?;
         index += sz;
         index += 6;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetStateReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -6789,30 +8443,31 @@ impl AsByteSequence for GetStateReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
+        let size: usize = 21
             + self.mods.size()
             + self.base_mods.size()
             + self.latched_mods.size()
             + self.locked_mods.size()
-            + self.group.size()
-            + self.locked_group.size()
-            + self.base_group.size()
-            + self.latched_group.size()
             + self.compat_state.size()
             + self.grab_mods.size()
             + self.compat_grab_mods.size()
             + self.lookup_mods.size()
             + self.compat_lookup_mods.size()
-            + 1
-            + self.ptr_btn_state.size()
-            + 6
+            + self.ptr_btn_state.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetStateReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Group {
     One = 0,
     Two = 1,
@@ -6825,14 +8480,14 @@ impl AsByteSequence for Group {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::One, sz)),
-            1 => Some((Self::Two, sz)),
-            2 => Some((Self::Three, sz)),
-            3 => Some((Self::Four, sz)),
-            _ => None,
+            0 => Ok((Self::One, sz)),
+            1 => Ok((Self::Two, sz)),
+            2 => Ok((Self::Three, sz)),
+            3 => Ok((Self::Four, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -6840,13 +8495,42 @@ impl AsByteSequence for Group {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Group {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Group {
     #[inline]
     fn default() -> Group {
         Group::One
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<Group> for u8 {
+    #[inline]
+    fn from(base: Group) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for Group {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::One),
+            1 => Ok(Self::Two),
+            2 => Ok(Self::Three),
+            3 => Ok(Self::Four),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct LatchLockStateRequest {
     pub req_type: u8,
     pub length: u16,
@@ -6859,13 +8543,21 @@ pub struct LatchLockStateRequest {
     pub latch_group: bool,
     pub group_latch: Card16,
 }
-impl LatchLockStateRequest {}
-impl AsByteSequence for LatchLockStateRequest {
+impl LatchLockStateRequest {
     #[inline]
+    pub fn builder() -> LatchLockStateRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("device_spec", 4, 2)];
+}
+const _: () = assert!(<LatchLockStateRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for LatchLockStateRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.affect_mod_locks.as_bytes(&mut bytes[index..]);
@@ -6873,38 +8565,41 @@ impl AsByteSequence for LatchLockStateRequest {
         index += self.lock_group.as_bytes(&mut bytes[index..]);
         index += self.group_lock.as_bytes(&mut bytes[index..]);
         index += self.affect_mod_latches.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.latch_group.as_bytes(&mut bytes[index..]);
         index += self.group_latch.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing LatchLockStateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (affect_mod_locks, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (affect_mod_locks, sz): (ModMask, usize) =
+            parse_item(bytes, index, "affect_mod_locks")?;
         index += sz;
-        let (mod_locks, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mod_locks, sz): (ModMask, usize) = parse_item(bytes, index, "mod_locks")?;
         index += sz;
-        let (lock_group, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (lock_group, sz): (bool, usize) = parse_item(bytes, index, "lock_group")?;
         index += sz;
-        let (group_lock, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (group_lock, sz): (Group, usize) = parse_item(bytes, index, "group_lock")?;
         index += sz;
-        let (affect_mod_latches, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (affect_mod_latches, sz): (ModMask, usize) =
+            parse_item(bytes, index, "affect_mod_latches")?;
         index += sz;
         index += 1;
-        let (latch_group, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (latch_group, sz): (bool, usize) = parse_item(bytes, index, "latch_group")?;
         index += sz;
-        let (group_latch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (group_latch, sz): (Card16, usize) = parse_item(bytes, index, "group_latch")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             LatchLockStateRequest {
                 req_type: req_type,
                 length: length,
@@ -6922,77 +8617,169 @@ impl AsByteSequence for LatchLockStateRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
+        let size: usize = 10
             + self.affect_mod_locks.size()
             + self.mod_locks.size()
             + self.lock_group.size()
-            + self.group_lock.size()
             + self.affect_mod_latches.size()
-            + 1
-            + self.latch_group.size()
-            + self.group_latch.size()
+            + self.latch_group.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for LatchLockStateRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for LatchLockStateRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "LatchLockState";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetControlsRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub device_spec: DeviceSpec,
+#[doc = " A builder for `LatchLockStateRequest`."]
+#[derive(Clone, Debug)]
+pub struct LatchLockStateRequestBuilder {
+    pub inner: LatchLockStateRequest,
 }
-impl GetControlsRequest {}
-impl AsByteSequence for GetControlsRequest {
+impl LatchLockStateRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetControlsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetControlsRequest {
-                req_type: req_type,
-                length: length,
-                device_spec: device_spec,
+    pub fn affect_mod_locks(mut self, affect_mod_locks: ModMask) -> Self {
+        self.inner.affect_mod_locks = affect_mod_locks;
+        self
+    }
+    #[inline]
+    pub fn mod_locks(mut self, mod_locks: ModMask) -> Self {
+        self.inner.mod_locks = mod_locks;
+        self
+    }
+    #[inline]
+    pub fn lock_group(mut self, lock_group: bool) -> Self {
+        self.inner.lock_group = lock_group;
+        self
+    }
+    #[inline]
+    pub fn group_lock(mut self, group_lock: Group) -> Self {
+        self.inner.group_lock = group_lock;
+        self
+    }
+    #[inline]
+    pub fn affect_mod_latches(mut self, affect_mod_latches: ModMask) -> Self {
+        self.inner.affect_mod_latches = affect_mod_latches;
+        self
+    }
+    #[inline]
+    pub fn latch_group(mut self, latch_group: bool) -> Self {
+        self.inner.latch_group = latch_group;
+        self
+    }
+    #[inline]
+    pub fn group_latch(mut self, group_latch: Card16) -> Self {
+        self.inner.group_latch = group_latch;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> LatchLockStateRequest {
+        self.inner
+    }
+}
+impl Default for LatchLockStateRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: LatchLockStateRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                affect_mod_locks: Default::default(),
+                mod_locks: Default::default(),
+                lock_group: Default::default(),
+                group_lock: Default::default(),
+                affect_mod_latches: Default::default(),
+                latch_group: Default::default(),
+                group_latch: Default::default(),
             },
-            index,
-        ))
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct GetControlsRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub device_spec: DeviceSpec,
+}
+impl GetControlsRequest {
+    #[inline]
+    pub fn builder() -> GetControlsRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.device_spec.size() + 2
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetControlsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("device_spec", 4, 2)];
 }
+const _: () = assert!(<GetControlsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_device_spec_only_request_asb! { GetControlsRequest }
 impl Request for GetControlsRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetControls";
     type Reply = GetControlsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetControlsRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetControlsRequestBuilder {
+    pub inner: GetControlsRequest,
+}
+impl GetControlsRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetControlsRequest {
+        self.inner
+    }
+}
+impl Default for GetControlsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetControlsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetControlsReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -7025,9 +8812,225 @@ pub struct GetControlsReply {
     pub enabled_controls: BoolCtrl,
     pub per_key_repeat: [Card8; 32],
 }
-impl GetControlsReply {}
+impl GetControlsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("mouse_keys_dflt_btn", 8, 1),
+        ("num_groups", 9, 1),
+        ("groups_wrap", 10, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.mouse_keys_dflt_btn == other.mouse_keys_dflt_btn
+            && self.num_groups == other.num_groups
+            && self.groups_wrap == other.groups_wrap
+            && self.internal_mods_mask == other.internal_mods_mask
+            && self.ignore_lock_mods_mask == other.ignore_lock_mods_mask
+            && self.internal_mods_real_mods == other.internal_mods_real_mods
+            && self.ignore_lock_mods_real_mods == other.ignore_lock_mods_real_mods
+            && self.internal_mods_vmods == other.internal_mods_vmods
+            && self.ignore_lock_mods_vmods == other.ignore_lock_mods_vmods
+            && self.repeat_delay == other.repeat_delay
+            && self.repeat_interval == other.repeat_interval
+            && self.slow_keys_delay == other.slow_keys_delay
+            && self.debounce_delay == other.debounce_delay
+            && self.mouse_keys_delay == other.mouse_keys_delay
+            && self.mouse_keys_interval == other.mouse_keys_interval
+            && self.mouse_keys_time_to_max == other.mouse_keys_time_to_max
+            && self.mouse_keys_max_speed == other.mouse_keys_max_speed
+            && self.mouse_keys_curve == other.mouse_keys_curve
+            && self.access_x_option == other.access_x_option
+            && self.access_x_timeout == other.access_x_timeout
+            && self.access_x_timeout_options_mask == other.access_x_timeout_options_mask
+            && self.access_x_timeout_options_values == other.access_x_timeout_options_values
+            && self.access_x_timeout_mask == other.access_x_timeout_mask
+            && self.access_x_timeout_values == other.access_x_timeout_values
+            && self.enabled_controls == other.enabled_controls
+            && self.per_key_repeat == other.per_key_repeat
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value(
+            "mouse_keys_dflt_btn",
+            &self.mouse_keys_dflt_btn,
+            &other.mouse_keys_dflt_btn,
+            &mut changes,
+        );
+        diff_value(
+            "num_groups",
+            &self.num_groups,
+            &other.num_groups,
+            &mut changes,
+        );
+        diff_value(
+            "groups_wrap",
+            &self.groups_wrap,
+            &other.groups_wrap,
+            &mut changes,
+        );
+        diff_value(
+            "internal_mods_mask",
+            &self.internal_mods_mask,
+            &other.internal_mods_mask,
+            &mut changes,
+        );
+        diff_value(
+            "ignore_lock_mods_mask",
+            &self.ignore_lock_mods_mask,
+            &other.ignore_lock_mods_mask,
+            &mut changes,
+        );
+        diff_value(
+            "internal_mods_real_mods",
+            &self.internal_mods_real_mods,
+            &other.internal_mods_real_mods,
+            &mut changes,
+        );
+        diff_value(
+            "ignore_lock_mods_real_mods",
+            &self.ignore_lock_mods_real_mods,
+            &other.ignore_lock_mods_real_mods,
+            &mut changes,
+        );
+        diff_value(
+            "internal_mods_vmods",
+            &self.internal_mods_vmods,
+            &other.internal_mods_vmods,
+            &mut changes,
+        );
+        diff_value(
+            "ignore_lock_mods_vmods",
+            &self.ignore_lock_mods_vmods,
+            &other.ignore_lock_mods_vmods,
+            &mut changes,
+        );
+        diff_value(
+            "repeat_delay",
+            &self.repeat_delay,
+            &other.repeat_delay,
+            &mut changes,
+        );
+        diff_value(
+            "repeat_interval",
+            &self.repeat_interval,
+            &other.repeat_interval,
+            &mut changes,
+        );
+        diff_value(
+            "slow_keys_delay",
+            &self.slow_keys_delay,
+            &other.slow_keys_delay,
+            &mut changes,
+        );
+        diff_value(
+            "debounce_delay",
+            &self.debounce_delay,
+            &other.debounce_delay,
+            &mut changes,
+        );
+        diff_value(
+            "mouse_keys_delay",
+            &self.mouse_keys_delay,
+            &other.mouse_keys_delay,
+            &mut changes,
+        );
+        diff_value(
+            "mouse_keys_interval",
+            &self.mouse_keys_interval,
+            &other.mouse_keys_interval,
+            &mut changes,
+        );
+        diff_value(
+            "mouse_keys_time_to_max",
+            &self.mouse_keys_time_to_max,
+            &other.mouse_keys_time_to_max,
+            &mut changes,
+        );
+        diff_value(
+            "mouse_keys_max_speed",
+            &self.mouse_keys_max_speed,
+            &other.mouse_keys_max_speed,
+            &mut changes,
+        );
+        diff_value(
+            "mouse_keys_curve",
+            &self.mouse_keys_curve,
+            &other.mouse_keys_curve,
+            &mut changes,
+        );
+        diff_value(
+            "access_x_option",
+            &self.access_x_option,
+            &other.access_x_option,
+            &mut changes,
+        );
+        diff_value(
+            "access_x_timeout",
+            &self.access_x_timeout,
+            &other.access_x_timeout,
+            &mut changes,
+        );
+        diff_value(
+            "access_x_timeout_options_mask",
+            &self.access_x_timeout_options_mask,
+            &other.access_x_timeout_options_mask,
+            &mut changes,
+        );
+        diff_value(
+            "access_x_timeout_options_values",
+            &self.access_x_timeout_options_values,
+            &other.access_x_timeout_options_values,
+            &mut changes,
+        );
+        diff_value(
+            "access_x_timeout_mask",
+            &self.access_x_timeout_mask,
+            &other.access_x_timeout_mask,
+            &mut changes,
+        );
+        diff_value(
+            "access_x_timeout_values",
+            &self.access_x_timeout_values,
+            &other.access_x_timeout_values,
+            &mut changes,
+        );
+        diff_value(
+            "enabled_controls",
+            &self.enabled_controls,
+            &other.enabled_controls,
+            &mut changes,
+        );
+        diff_value(
+            "per_key_repeat",
+            &self.per_key_repeat,
+            &other.per_key_repeat,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for GetControlsReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -7043,7 +9046,7 @@ impl AsByteSequence for GetControlsReply {
         index += self
             .ignore_lock_mods_real_mods
             .as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.internal_mods_vmods.as_bytes(&mut bytes[index..]);
         index += self.ignore_lock_mods_vmods.as_bytes(&mut bytes[index..]);
         index += self.repeat_delay.as_bytes(&mut bytes[index..]);
@@ -7063,87 +9066,98 @@ impl AsByteSequence for GetControlsReply {
         index += self
             .access_x_timeout_options_values
             .as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.access_x_timeout_mask.as_bytes(&mut bytes[index..]);
         index += self.access_x_timeout_values.as_bytes(&mut bytes[index..]);
         index += self.enabled_controls.as_bytes(&mut bytes[index..]);
         index += self.per_key_repeat.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetControlsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (mouse_keys_dflt_btn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (mouse_keys_dflt_btn, sz): (Card8, usize) =
+            parse_item(bytes, index, "mouse_keys_dflt_btn")?;
         index += sz;
-        let (num_groups, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_groups, sz): (Card8, usize) = parse_item(bytes, index, "num_groups")?;
         index += sz;
-        let (groups_wrap, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (groups_wrap, sz): (Card8, usize) = parse_item(bytes, index, "groups_wrap")?;
         index += sz;
-        let (internal_mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (internal_mods_mask, sz): (ModMask, usize) =
+            parse_item(bytes, index, "internal_mods_mask")?;
         index += sz;
-        let (ignore_lock_mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_mods_mask, sz): (ModMask, usize) =
+            parse_item(bytes, index, "ignore_lock_mods_mask")?;
         index += sz;
         let (internal_mods_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "internal_mods_real_mods")?;
         index += sz;
         let (ignore_lock_mods_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "ignore_lock_mods_real_mods")?;
         index += sz;
         index += 1;
-        let (internal_mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (internal_mods_vmods, sz): (VMod, usize) =
+            parse_item(bytes, index, "internal_mods_vmods")?;
         index += sz;
-        let (ignore_lock_mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_mods_vmods, sz): (VMod, usize) =
+            parse_item(bytes, index, "ignore_lock_mods_vmods")?;
         index += sz;
-        let (repeat_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_delay, sz): (Card16, usize) = parse_item(bytes, index, "repeat_delay")?;
         index += sz;
-        let (repeat_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_interval, sz): (Card16, usize) = parse_item(bytes, index, "repeat_interval")?;
         index += sz;
-        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (slow_keys_delay, sz): (Card16, usize) = parse_item(bytes, index, "slow_keys_delay")?;
         index += sz;
-        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (debounce_delay, sz): (Card16, usize) = parse_item(bytes, index, "debounce_delay")?;
         index += sz;
-        let (mouse_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_delay, sz): (Card16, usize) = parse_item(bytes, index, "mouse_keys_delay")?;
         index += sz;
-        let (mouse_keys_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_interval, sz): (Card16, usize) =
+            parse_item(bytes, index, "mouse_keys_interval")?;
         index += sz;
-        let (mouse_keys_time_to_max, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_time_to_max, sz): (Card16, usize) =
+            parse_item(bytes, index, "mouse_keys_time_to_max")?;
         index += sz;
-        let (mouse_keys_max_speed, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_max_speed, sz): (Card16, usize) =
+            parse_item(bytes, index, "mouse_keys_max_speed")?;
         index += sz;
-        let (mouse_keys_curve, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_curve, sz): (Int16, usize) = parse_item(bytes, index, "mouse_keys_curve")?;
         index += sz;
-        let (access_x_option, sz): (AxOption, usize) = <AxOption>::from_bytes(&bytes[index..])?;
+        let (access_x_option, sz): (AxOption, usize) = parse_item(bytes, index, "access_x_option")?;
         index += sz;
-        let (access_x_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (access_x_timeout, sz): (Card16, usize) = parse_item(bytes, index, "access_x_timeout")?;
         index += sz;
         let (access_x_timeout_options_mask, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_options_mask")?;
         index += sz;
         let (access_x_timeout_options_values, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_options_values")?;
         index += sz;
         index += 2;
         let (access_x_timeout_mask, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_mask")?;
         index += sz;
         let (access_x_timeout_values, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_values")?;
         index += sz;
-        let (enabled_controls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (enabled_controls, sz): (BoolCtrl, usize) =
+            parse_item(bytes, index, "enabled_controls")?;
         index += sz;
         let (per_key_repeat, sz): ([Card8; 32], usize) =
-            <[Card8; 32]>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "per_key_repeat")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetControlsReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -7181,42 +9195,25 @@ impl AsByteSequence for GetControlsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.mouse_keys_dflt_btn.size()
-            + self.num_groups.size()
-            + self.groups_wrap.size()
+        let size: usize = 88
             + self.internal_mods_mask.size()
             + self.ignore_lock_mods_mask.size()
             + self.internal_mods_real_mods.size()
-            + self.ignore_lock_mods_real_mods.size()
-            + 1
-            + self.internal_mods_vmods.size()
-            + self.ignore_lock_mods_vmods.size()
-            + self.repeat_delay.size()
-            + self.repeat_interval.size()
-            + self.slow_keys_delay.size()
-            + self.debounce_delay.size()
-            + self.mouse_keys_delay.size()
-            + self.mouse_keys_interval.size()
-            + self.mouse_keys_time_to_max.size()
-            + self.mouse_keys_max_speed.size()
-            + self.mouse_keys_curve.size()
-            + self.access_x_option.size()
-            + self.access_x_timeout.size()
-            + self.access_x_timeout_options_mask.size()
-            + self.access_x_timeout_options_values.size()
-            + 2
-            + self.access_x_timeout_mask.size()
-            + self.access_x_timeout_values.size()
-            + self.enabled_controls.size()
-            + self.per_key_repeat.size()
+            + self.ignore_lock_mods_real_mods.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetControlsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AxOption {
     pub inner: u16,
 }
@@ -7377,7 +9374,6 @@ impl AxOption {
         }
         self
     }
-    #[inline]
     pub fn new(
         sk_press_fb: bool,
         sk_accept_fb: bool,
@@ -7449,21 +9445,20 @@ impl AxOption {
     pub const DUMB_BELL: Self = Self { inner: 2048 };
     pub const COMPLETE: Self = Self { inner: 4095 };
 }
-impl AsByteSequence for AxOption {
+impl core::ops::Deref for AxOption {
+    type Target = u16;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((AxOption { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for AxOption {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { AxOption }
 impl core::ops::Not for AxOption {
     type Output = AxOption;
     #[inline]
@@ -7498,7 +9493,10 @@ impl core::ops::BitXor for AxOption {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetControlsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -7533,13 +9531,21 @@ pub struct SetControlsRequest {
     pub access_x_timeout_options_values: AxOption,
     pub per_key_repeat: [Card8; 32],
 }
-impl SetControlsRequest {}
-impl AsByteSequence for SetControlsRequest {
+impl SetControlsRequest {
     #[inline]
+    pub fn builder() -> SetControlsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("device_spec", 4, 2)];
+}
+const _: () = assert!(<SetControlsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetControlsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.affect_internal_real_mods.as_bytes(&mut bytes[index..]);
@@ -7559,7 +9565,7 @@ impl AsByteSequence for SetControlsRequest {
         index += self.mouse_keys_dflt_btn.as_bytes(&mut bytes[index..]);
         index += self.groups_wrap.as_bytes(&mut bytes[index..]);
         index += self.access_x_options.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.affect_enabled_controls.as_bytes(&mut bytes[index..]);
         index += self.enabled_controls.as_bytes(&mut bytes[index..]);
         index += self.change_controls.as_bytes(&mut bytes[index..]);
@@ -7582,89 +9588,100 @@ impl AsByteSequence for SetControlsRequest {
             .access_x_timeout_options_values
             .as_bytes(&mut bytes[index..]);
         index += self.per_key_repeat.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetControlsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
         let (affect_internal_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "affect_internal_real_mods")?;
         index += sz;
-        let (internal_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (internal_real_mods, sz): (ModMask, usize) =
+            parse_item(bytes, index, "internal_real_mods")?;
         index += sz;
         let (affect_ignore_lock_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "affect_ignore_lock_real_mods")?;
         index += sz;
-        let (ignore_lock_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_real_mods, sz): (ModMask, usize) =
+            parse_item(bytes, index, "ignore_lock_real_mods")?;
         index += sz;
         let (affect_internal_virtual_mods, sz): (VMod, usize) =
-            <VMod>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "affect_internal_virtual_mods")?;
         index += sz;
-        let (internal_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (internal_virtual_mods, sz): (VMod, usize) =
+            parse_item(bytes, index, "internal_virtual_mods")?;
         index += sz;
         let (affect_ignore_lock_virtual_mods, sz): (VMod, usize) =
-            <VMod>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "affect_ignore_lock_virtual_mods")?;
         index += sz;
-        let (ignore_lock_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_virtual_mods, sz): (VMod, usize) =
+            parse_item(bytes, index, "ignore_lock_virtual_mods")?;
         index += sz;
-        let (mouse_keys_dflt_btn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_dflt_btn, sz): (Card8, usize) =
+            parse_item(bytes, index, "mouse_keys_dflt_btn")?;
         index += sz;
-        let (groups_wrap, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (groups_wrap, sz): (Card8, usize) = parse_item(bytes, index, "groups_wrap")?;
         index += sz;
-        let (access_x_options, sz): (AxOption, usize) = <AxOption>::from_bytes(&bytes[index..])?;
+        let (access_x_options, sz): (AxOption, usize) =
+            parse_item(bytes, index, "access_x_options")?;
         index += sz;
         index += 2;
         let (affect_enabled_controls, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "affect_enabled_controls")?;
         index += sz;
-        let (enabled_controls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (enabled_controls, sz): (BoolCtrl, usize) =
+            parse_item(bytes, index, "enabled_controls")?;
         index += sz;
-        let (change_controls, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+        let (change_controls, sz): (Control, usize) = parse_item(bytes, index, "change_controls")?;
         index += sz;
-        let (repeat_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_delay, sz): (Card16, usize) = parse_item(bytes, index, "repeat_delay")?;
         index += sz;
-        let (repeat_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_interval, sz): (Card16, usize) = parse_item(bytes, index, "repeat_interval")?;
         index += sz;
-        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (slow_keys_delay, sz): (Card16, usize) = parse_item(bytes, index, "slow_keys_delay")?;
         index += sz;
-        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (debounce_delay, sz): (Card16, usize) = parse_item(bytes, index, "debounce_delay")?;
         index += sz;
-        let (mouse_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_delay, sz): (Card16, usize) = parse_item(bytes, index, "mouse_keys_delay")?;
         index += sz;
-        let (mouse_keys_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_interval, sz): (Card16, usize) =
+            parse_item(bytes, index, "mouse_keys_interval")?;
         index += sz;
-        let (mouse_keys_time_to_max, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_time_to_max, sz): (Card16, usize) =
+            parse_item(bytes, index, "mouse_keys_time_to_max")?;
         index += sz;
-        let (mouse_keys_max_speed, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_max_speed, sz): (Card16, usize) =
+            parse_item(bytes, index, "mouse_keys_max_speed")?;
         index += sz;
-        let (mouse_keys_curve, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_curve, sz): (Int16, usize) = parse_item(bytes, index, "mouse_keys_curve")?;
         index += sz;
-        let (access_x_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (access_x_timeout, sz): (Card16, usize) = parse_item(bytes, index, "access_x_timeout")?;
         index += sz;
         let (access_x_timeout_mask, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_mask")?;
         index += sz;
         let (access_x_timeout_values, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_values")?;
         index += sz;
         let (access_x_timeout_options_mask, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_options_mask")?;
         index += sz;
         let (access_x_timeout_options_values, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "access_x_timeout_options_values")?;
         index += sz;
         let (per_key_repeat, sz): ([Card8; 32], usize) =
-            <[Card8; 32]>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "per_key_repeat")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetControlsRequest {
                 req_type: req_type,
                 length: length,
@@ -7704,50 +9721,245 @@ impl AsByteSequence for SetControlsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
+        let size: usize = 96
             + self.affect_internal_real_mods.size()
             + self.internal_real_mods.size()
             + self.affect_ignore_lock_real_mods.size()
-            + self.ignore_lock_real_mods.size()
-            + self.affect_internal_virtual_mods.size()
-            + self.internal_virtual_mods.size()
-            + self.affect_ignore_lock_virtual_mods.size()
-            + self.ignore_lock_virtual_mods.size()
-            + self.mouse_keys_dflt_btn.size()
-            + self.groups_wrap.size()
-            + self.access_x_options.size()
-            + 2
-            + self.affect_enabled_controls.size()
-            + self.enabled_controls.size()
-            + self.change_controls.size()
-            + self.repeat_delay.size()
-            + self.repeat_interval.size()
-            + self.slow_keys_delay.size()
-            + self.debounce_delay.size()
-            + self.mouse_keys_delay.size()
-            + self.mouse_keys_interval.size()
-            + self.mouse_keys_time_to_max.size()
-            + self.mouse_keys_max_speed.size()
-            + self.mouse_keys_curve.size()
-            + self.access_x_timeout.size()
-            + self.access_x_timeout_mask.size()
-            + self.access_x_timeout_values.size()
-            + self.access_x_timeout_options_mask.size()
-            + self.access_x_timeout_options_values.size()
-            + self.per_key_repeat.size()
+            + self.ignore_lock_real_mods.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetControlsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetControlsRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetControls";
     type Reply = ();
 }
+#[doc = " A builder for `SetControlsRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetControlsRequestBuilder {
+    pub inner: SetControlsRequest,
+}
+impl SetControlsRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn affect_internal_real_mods(mut self, affect_internal_real_mods: ModMask) -> Self {
+        self.inner.affect_internal_real_mods = affect_internal_real_mods;
+        self
+    }
+    #[inline]
+    pub fn internal_real_mods(mut self, internal_real_mods: ModMask) -> Self {
+        self.inner.internal_real_mods = internal_real_mods;
+        self
+    }
+    #[inline]
+    pub fn affect_ignore_lock_real_mods(mut self, affect_ignore_lock_real_mods: ModMask) -> Self {
+        self.inner.affect_ignore_lock_real_mods = affect_ignore_lock_real_mods;
+        self
+    }
+    #[inline]
+    pub fn ignore_lock_real_mods(mut self, ignore_lock_real_mods: ModMask) -> Self {
+        self.inner.ignore_lock_real_mods = ignore_lock_real_mods;
+        self
+    }
+    #[inline]
+    pub fn affect_internal_virtual_mods(mut self, affect_internal_virtual_mods: VMod) -> Self {
+        self.inner.affect_internal_virtual_mods = affect_internal_virtual_mods;
+        self
+    }
+    #[inline]
+    pub fn internal_virtual_mods(mut self, internal_virtual_mods: VMod) -> Self {
+        self.inner.internal_virtual_mods = internal_virtual_mods;
+        self
+    }
+    #[inline]
+    pub fn affect_ignore_lock_virtual_mods(
+        mut self,
+        affect_ignore_lock_virtual_mods: VMod,
+    ) -> Self {
+        self.inner.affect_ignore_lock_virtual_mods = affect_ignore_lock_virtual_mods;
+        self
+    }
+    #[inline]
+    pub fn ignore_lock_virtual_mods(mut self, ignore_lock_virtual_mods: VMod) -> Self {
+        self.inner.ignore_lock_virtual_mods = ignore_lock_virtual_mods;
+        self
+    }
+    #[inline]
+    pub fn mouse_keys_dflt_btn(mut self, mouse_keys_dflt_btn: Card8) -> Self {
+        self.inner.mouse_keys_dflt_btn = mouse_keys_dflt_btn;
+        self
+    }
+    #[inline]
+    pub fn groups_wrap(mut self, groups_wrap: Card8) -> Self {
+        self.inner.groups_wrap = groups_wrap;
+        self
+    }
+    #[inline]
+    pub fn access_x_options(mut self, access_x_options: AxOption) -> Self {
+        self.inner.access_x_options = access_x_options;
+        self
+    }
+    #[inline]
+    pub fn affect_enabled_controls(mut self, affect_enabled_controls: BoolCtrl) -> Self {
+        self.inner.affect_enabled_controls = affect_enabled_controls;
+        self
+    }
+    #[inline]
+    pub fn enabled_controls(mut self, enabled_controls: BoolCtrl) -> Self {
+        self.inner.enabled_controls = enabled_controls;
+        self
+    }
+    #[inline]
+    pub fn change_controls(mut self, change_controls: Control) -> Self {
+        self.inner.change_controls = change_controls;
+        self
+    }
+    #[inline]
+    pub fn repeat_delay(mut self, repeat_delay: Card16) -> Self {
+        self.inner.repeat_delay = repeat_delay;
+        self
+    }
+    #[inline]
+    pub fn repeat_interval(mut self, repeat_interval: Card16) -> Self {
+        self.inner.repeat_interval = repeat_interval;
+        self
+    }
+    #[inline]
+    pub fn slow_keys_delay(mut self, slow_keys_delay: Card16) -> Self {
+        self.inner.slow_keys_delay = slow_keys_delay;
+        self
+    }
+    #[inline]
+    pub fn debounce_delay(mut self, debounce_delay: Card16) -> Self {
+        self.inner.debounce_delay = debounce_delay;
+        self
+    }
+    #[inline]
+    pub fn mouse_keys_delay(mut self, mouse_keys_delay: Card16) -> Self {
+        self.inner.mouse_keys_delay = mouse_keys_delay;
+        self
+    }
+    #[inline]
+    pub fn mouse_keys_interval(mut self, mouse_keys_interval: Card16) -> Self {
+        self.inner.mouse_keys_interval = mouse_keys_interval;
+        self
+    }
+    #[inline]
+    pub fn mouse_keys_time_to_max(mut self, mouse_keys_time_to_max: Card16) -> Self {
+        self.inner.mouse_keys_time_to_max = mouse_keys_time_to_max;
+        self
+    }
+    #[inline]
+    pub fn mouse_keys_max_speed(mut self, mouse_keys_max_speed: Card16) -> Self {
+        self.inner.mouse_keys_max_speed = mouse_keys_max_speed;
+        self
+    }
+    #[inline]
+    pub fn mouse_keys_curve(mut self, mouse_keys_curve: Int16) -> Self {
+        self.inner.mouse_keys_curve = mouse_keys_curve;
+        self
+    }
+    #[inline]
+    pub fn access_x_timeout(mut self, access_x_timeout: Card16) -> Self {
+        self.inner.access_x_timeout = access_x_timeout;
+        self
+    }
+    #[inline]
+    pub fn access_x_timeout_mask(mut self, access_x_timeout_mask: BoolCtrl) -> Self {
+        self.inner.access_x_timeout_mask = access_x_timeout_mask;
+        self
+    }
+    #[inline]
+    pub fn access_x_timeout_values(mut self, access_x_timeout_values: BoolCtrl) -> Self {
+        self.inner.access_x_timeout_values = access_x_timeout_values;
+        self
+    }
+    #[inline]
+    pub fn access_x_timeout_options_mask(
+        mut self,
+        access_x_timeout_options_mask: AxOption,
+    ) -> Self {
+        self.inner.access_x_timeout_options_mask = access_x_timeout_options_mask;
+        self
+    }
+    #[inline]
+    pub fn access_x_timeout_options_values(
+        mut self,
+        access_x_timeout_options_values: AxOption,
+    ) -> Self {
+        self.inner.access_x_timeout_options_values = access_x_timeout_options_values;
+        self
+    }
+    #[inline]
+    pub fn per_key_repeat(mut self, per_key_repeat: [Card8; 32]) -> Self {
+        self.inner.per_key_repeat = per_key_repeat;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetControlsRequest {
+        self.inner
+    }
+}
+impl Default for SetControlsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetControlsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                affect_internal_real_mods: Default::default(),
+                internal_real_mods: Default::default(),
+                affect_ignore_lock_real_mods: Default::default(),
+                ignore_lock_real_mods: Default::default(),
+                affect_internal_virtual_mods: Default::default(),
+                internal_virtual_mods: Default::default(),
+                affect_ignore_lock_virtual_mods: Default::default(),
+                ignore_lock_virtual_mods: Default::default(),
+                mouse_keys_dflt_btn: Default::default(),
+                groups_wrap: Default::default(),
+                access_x_options: Default::default(),
+                affect_enabled_controls: Default::default(),
+                enabled_controls: Default::default(),
+                change_controls: Default::default(),
+                repeat_delay: Default::default(),
+                repeat_interval: Default::default(),
+                slow_keys_delay: Default::default(),
+                debounce_delay: Default::default(),
+                mouse_keys_delay: Default::default(),
+                mouse_keys_interval: Default::default(),
+                mouse_keys_time_to_max: Default::default(),
+                mouse_keys_max_speed: Default::default(),
+                mouse_keys_curve: Default::default(),
+                access_x_timeout: Default::default(),
+                access_x_timeout_mask: Default::default(),
+                access_x_timeout_values: Default::default(),
+                access_x_timeout_options_mask: Default::default(),
+                access_x_timeout_options_values: Default::default(),
+                per_key_repeat: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Control {
     pub inner: u32,
 }
@@ -7854,21 +10066,20 @@ impl Control {
     pub const CONTROLS_ENABLED: Self = Self { inner: 2147483648 };
     pub const COMPLETE: Self = Self { inner: 4160749568 };
 }
-impl AsByteSequence for Control {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for Control {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((Control { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for Control {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+impl_u32_flags_asb! { Control }
 impl core::ops::Not for Control {
     type Output = Control;
     #[inline]
@@ -7903,7 +10114,10 @@ impl core::ops::BitXor for Control {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetMapRequest {
     pub req_type: u8,
     pub length: u16,
@@ -7926,13 +10140,34 @@ pub struct GetMapRequest {
     pub first_v_mod_map_key: Keycode,
     pub n_v_mod_map_keys: Card8,
 }
-impl GetMapRequest {}
-impl AsByteSequence for GetMapRequest {
+impl GetMapRequest {
     #[inline]
+    pub fn builder() -> GetMapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GetMapReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("full", 6, 2),
+        ("partial", 8, 2),
+        ("first_type", 10, 1),
+        ("n_types", 11, 1),
+    ];
+}
+const _: () = assert!(<GetMapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetMapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.full.as_bytes(&mut bytes[index..]);
@@ -7952,56 +10187,62 @@ impl AsByteSequence for GetMapRequest {
         index += self.n_mod_map_keys.as_bytes(&mut bytes[index..]);
         index += self.first_v_mod_map_key.as_bytes(&mut bytes[index..]);
         index += self.n_v_mod_map_keys.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (full, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (full, sz): (MapPart, usize) = parse_item(bytes, index, "full")?;
         index += sz;
-        let (partial, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (partial, sz): (MapPart, usize) = parse_item(bytes, index, "partial")?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = parse_item(bytes, index, "first_type")?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = parse_item(bytes, index, "n_types")?;
         index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_sym, sz): (Keycode, usize) = parse_item(bytes, index, "first_key_sym")?;
         index += sz;
-        let (n_key_syms, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_syms, sz): (Card8, usize) = parse_item(bytes, index, "n_key_syms")?;
         index += sz;
-        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_action, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_action")?;
         index += sz;
-        let (n_key_actions, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_actions, sz): (Card8, usize) = parse_item(bytes, index, "n_key_actions")?;
         index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_behavior")?;
         index += sz;
-        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_behaviors, sz): (Card8, usize) = parse_item(bytes, index, "n_key_behaviors")?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_explicit")?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_explicit, sz): (Card8, usize) = parse_item(bytes, index, "n_key_explicit")?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_mod_map_key")?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_mod_map_keys")?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_v_mod_map_key")?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_v_mod_map_keys")?;
         index += sz;
         index += 2;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetMapRequest {
                 req_type: req_type,
                 length: length,
@@ -8029,81 +10270,226 @@ impl AsByteSequence for GetMapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.full.size()
-            + self.partial.size()
-            + self.first_type.size()
-            + self.n_types.size()
+        let size: usize = 22
             + self.first_key_sym.size()
-            + self.n_key_syms.size()
             + self.first_key_action.size()
-            + self.n_key_actions.size()
             + self.first_key_behavior.size()
-            + self.n_key_behaviors.size()
-            + self.virtual_mods.size()
             + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
             + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + 2
+            + self.first_v_mod_map_key.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetMapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetMapRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMap";
     type Reply = GetMapReply;
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetMapReply {
-    pub reply_type: u8,
-    pub device_id: Card8,
-    pub sequence: u16,
-    pub length: u32,
-    pub min_key_code: Keycode,
-    pub max_key_code: Keycode,
-    pub present: MapPart,
-    pub first_type: Card8,
-    pub total_types: Card8,
-    pub first_key_sym: Keycode,
-    pub total_syms: Card16,
-    pub first_key_action: Keycode,
-    pub first_key_behavior: Keycode,
-    pub n_key_behaviors: Card8,
-    pub first_key_explicit: Keycode,
-    pub n_key_explicit: Card8,
-    pub first_mod_map_key: Keycode,
-    pub n_mod_map_keys: Card8,
-    pub first_v_mod_map_key: Keycode,
-    pub n_v_mod_map_keys: Card8,
-    pub virtual_mods: VMod,
-    pub types_rtrn: Vec<KeyType>,
-    pub syms_rtrn: Vec<KeySymMap>,
-    pub acts_rtrn_count: Vec<Card8>,
-    pub acts_rtrn_acts: Vec<Action>,
-    pub behaviors_rtrn: Vec<SetBehavior>,
-    pub vmods_rtrn: Vec<Card8>,
-    pub explicit_rtrn: Vec<SetExplicit>,
-    pub modmap_rtrn: Vec<KeyModMap>,
-    pub vmodmap_rtrn: Vec<KeyVModMap>,
+#[doc = " A builder for `GetMapRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetMapRequestBuilder {
+    pub inner: GetMapRequest,
 }
-impl GetMapReply {}
-impl AsByteSequence for GetMapReply {
+impl GetMapRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index += self.min_key_code.as_bytes(&mut bytes[index..]);
-        index += self.max_key_code.as_bytes(&mut bytes[index..]);
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn full(mut self, full: MapPart) -> Self {
+        self.inner.full = full;
+        self
+    }
+    #[inline]
+    pub fn partial(mut self, partial: MapPart) -> Self {
+        self.inner.partial = partial;
+        self
+    }
+    #[inline]
+    pub fn first_type(mut self, first_type: Card8) -> Self {
+        self.inner.first_type = first_type;
+        self
+    }
+    #[inline]
+    pub fn n_types(mut self, n_types: Card8) -> Self {
+        self.inner.n_types = n_types;
+        self
+    }
+    #[inline]
+    pub fn first_key_sym(mut self, first_key_sym: Keycode) -> Self {
+        self.inner.first_key_sym = first_key_sym;
+        self
+    }
+    #[inline]
+    pub fn n_key_syms(mut self, n_key_syms: Card8) -> Self {
+        self.inner.n_key_syms = n_key_syms;
+        self
+    }
+    #[inline]
+    pub fn first_key_action(mut self, first_key_action: Keycode) -> Self {
+        self.inner.first_key_action = first_key_action;
+        self
+    }
+    #[inline]
+    pub fn n_key_actions(mut self, n_key_actions: Card8) -> Self {
+        self.inner.n_key_actions = n_key_actions;
+        self
+    }
+    #[inline]
+    pub fn first_key_behavior(mut self, first_key_behavior: Keycode) -> Self {
+        self.inner.first_key_behavior = first_key_behavior;
+        self
+    }
+    #[inline]
+    pub fn n_key_behaviors(mut self, n_key_behaviors: Card8) -> Self {
+        self.inner.n_key_behaviors = n_key_behaviors;
+        self
+    }
+    #[inline]
+    pub fn virtual_mods(mut self, virtual_mods: VMod) -> Self {
+        self.inner.virtual_mods = virtual_mods;
+        self
+    }
+    #[inline]
+    pub fn first_key_explicit(mut self, first_key_explicit: Keycode) -> Self {
+        self.inner.first_key_explicit = first_key_explicit;
+        self
+    }
+    #[inline]
+    pub fn n_key_explicit(mut self, n_key_explicit: Card8) -> Self {
+        self.inner.n_key_explicit = n_key_explicit;
+        self
+    }
+    #[inline]
+    pub fn first_mod_map_key(mut self, first_mod_map_key: Keycode) -> Self {
+        self.inner.first_mod_map_key = first_mod_map_key;
+        self
+    }
+    #[inline]
+    pub fn n_mod_map_keys(mut self, n_mod_map_keys: Card8) -> Self {
+        self.inner.n_mod_map_keys = n_mod_map_keys;
+        self
+    }
+    #[inline]
+    pub fn first_v_mod_map_key(mut self, first_v_mod_map_key: Keycode) -> Self {
+        self.inner.first_v_mod_map_key = first_v_mod_map_key;
+        self
+    }
+    #[inline]
+    pub fn n_v_mod_map_keys(mut self, n_v_mod_map_keys: Card8) -> Self {
+        self.inner.n_v_mod_map_keys = n_v_mod_map_keys;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetMapRequest {
+        self.inner
+    }
+}
+impl Default for GetMapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetMapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                full: Default::default(),
+                partial: Default::default(),
+                first_type: Default::default(),
+                n_types: Default::default(),
+                first_key_sym: Default::default(),
+                n_key_syms: Default::default(),
+                first_key_action: Default::default(),
+                n_key_actions: Default::default(),
+                first_key_behavior: Default::default(),
+                n_key_behaviors: Default::default(),
+                virtual_mods: Default::default(),
+                first_key_explicit: Default::default(),
+                n_key_explicit: Default::default(),
+                first_mod_map_key: Default::default(),
+                n_mod_map_keys: Default::default(),
+                first_v_mod_map_key: Default::default(),
+                n_v_mod_map_keys: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct GetMapReply {
+    pub reply_type: u8,
+    pub device_id: Card8,
+    pub sequence: u16,
+    pub length: u32,
+    pub min_key_code: Keycode,
+    pub max_key_code: Keycode,
+    pub present: MapPart,
+    pub first_type: Card8,
+    pub total_types: Card8,
+    pub first_key_sym: Keycode,
+    pub total_syms: Card16,
+    pub first_key_action: Keycode,
+    pub first_key_behavior: Keycode,
+    pub n_key_behaviors: Card8,
+    pub first_key_explicit: Keycode,
+    pub n_key_explicit: Card8,
+    pub first_mod_map_key: Keycode,
+    pub n_mod_map_keys: Card8,
+    pub first_v_mod_map_key: Keycode,
+    pub n_v_mod_map_keys: Card8,
+    pub virtual_mods: VMod,
+    pub types_rtrn: Vec<KeyType>,
+    pub syms_rtrn: Vec<KeySymMap>,
+    pub acts_rtrn_count: Vec<Card8>,
+    pub acts_rtrn_acts: Vec<Action>,
+    pub behaviors_rtrn: Vec<SetBehavior>,
+    pub vmods_rtrn: Vec<Card8>,
+    pub explicit_rtrn: Vec<SetExplicit>,
+    pub modmap_rtrn: Vec<KeyModMap>,
+    pub vmodmap_rtrn: Vec<KeyVModMap>,
+}
+impl GetMapReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+}
+impl AsByteSequence for GetMapReply {
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.reply_type.as_bytes(&mut bytes[index..]);
+        index += self.device_id.as_bytes(&mut bytes[index..]);
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 2);
+        index += self.min_key_code.as_bytes(&mut bytes[index..]);
+        index += self.max_key_code.as_bytes(&mut bytes[index..]);
         index += self.present.as_bytes(&mut bytes[index..]);
         index += self.first_type.as_bytes(&mut bytes[index..]);
         index += (self.types_rtrn.len() as Card8).as_bytes(&mut bytes[index..]);
@@ -8126,140 +10512,242 @@ impl AsByteSequence for GetMapReply {
         index += self.first_v_mod_map_key.as_bytes(&mut bytes[index..]);
         index += self.n_v_mod_map_keys.as_bytes(&mut bytes[index..]);
         index += (self.vmodmap_rtrn.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.virtual_mods.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.types_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-        let block_len: usize = vector_as_bytes(&self.syms_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-        let block_len: usize = vector_as_bytes(&self.acts_rtrn_count, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let block_len: usize = vector_as_bytes(&self.acts_rtrn_acts, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
-        let block_len: usize = vector_as_bytes(&self.behaviors_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-        let block_len: usize = vector_as_bytes(&self.vmods_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let block_len: usize = vector_as_bytes(&self.explicit_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-        let block_len: usize = vector_as_bytes(&self.modmap_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-        let block_len: usize = vector_as_bytes(&self.vmodmap_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+        let cond0 = (self.present);
+        if cond0.key_types() {
+            let block_len: usize = vector_as_bytes(&self.types_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyType>()),
+            );
+        }
+        if cond0.key_syms() {
+            let block_len: usize = vector_as_bytes(&self.syms_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>()),
+            );
+        }
+        if cond0.key_actions() {
+            let block_len: usize = vector_as_bytes(&self.acts_rtrn_count, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+            let block_len: usize = vector_as_bytes(&self.acts_rtrn_acts, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Action>()),
+            );
+        }
+        if cond0.key_behaviors() {
+            let block_len: usize = vector_as_bytes(&self.behaviors_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>()),
+            );
+        }
+        if cond0.virtual_mods() {
+            let block_len: usize = vector_as_bytes(&self.vmods_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+        }
+        if cond0.explicit_components() {
+            let block_len: usize = vector_as_bytes(&self.explicit_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+        }
+        if cond0.modifier_map() {
+            let block_len: usize = vector_as_bytes(&self.modmap_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+        }
+        if cond0.virtual_mod_map() {
+            let block_len: usize = vector_as_bytes(&self.vmodmap_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>()),
+            );
+        }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         index += 2;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "min_key_code")?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "max_key_code")?;
         index += sz;
-        let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (present, sz): (MapPart, usize) = parse_item(bytes, index, "present")?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = parse_item(bytes, index, "first_type")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (total_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (total_types, sz): (Card8, usize) = parse_item(bytes, index, "total_types")?;
         index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_sym, sz): (Keycode, usize) = parse_item(bytes, index, "first_key_sym")?;
         index += sz;
-        let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_syms, sz): (Card16, usize) = parse_item(bytes, index, "total_syms")?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_action, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_action")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (len3, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card8, usize) = parse_item(bytes, index, "len3")?;
         index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_behavior")?;
         index += sz;
-        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_behaviors, sz): (Card8, usize) = parse_item(bytes, index, "n_key_behaviors")?;
         index += sz;
-        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len4, sz): (Card8, usize) = parse_item(bytes, index, "len4")?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_explicit")?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_explicit, sz): (Card8, usize) = parse_item(bytes, index, "n_key_explicit")?;
         index += sz;
-        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len5, sz): (Card8, usize) = parse_item(bytes, index, "len5")?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_mod_map_key")?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_mod_map_keys")?;
         index += sz;
-        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len6, sz): (Card8, usize) = parse_item(bytes, index, "len6")?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_v_mod_map_key")?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_v_mod_map_keys")?;
         index += sz;
-        let (len7, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len7, sz): (Card8, usize) = parse_item(bytes, index, "len7")?;
         index += sz;
         index += 1;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
         index += sz;
-        let (types_rtrn, block_len): (Vec<KeyType>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-        let (syms_rtrn, block_len): (Vec<KeySymMap>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-        let (acts_rtrn_count, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let (acts_rtrn_acts, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
-        let (behaviors_rtrn, block_len): (Vec<SetBehavior>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-        let (vmods_rtrn, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let (explicit_rtrn, block_len): (Vec<SetExplicit>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-        let (modmap_rtrn, block_len): (Vec<KeyModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len6 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-        let (vmodmap_rtrn, block_len): (Vec<KeyVModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len7 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
-        Some((
+        let cond0 = (present);
+        let (types_rtrn,) = if cond0.key_types() {
+            let (types_rtrn, block_len): (Vec<KeyType>, usize) =
+                parse_vector(bytes, index, len0 as usize, "types_rtrn")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+            (types_rtrn,)
+        } else {
+            (Default::default(),)
+        };
+        let (syms_rtrn,) = if cond0.key_syms() {
+            let (syms_rtrn, block_len): (Vec<KeySymMap>, usize) =
+                parse_vector(bytes, index, len1 as usize, "syms_rtrn")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+            (syms_rtrn,)
+        } else {
+            (Default::default(),)
+        };
+        let (acts_rtrn_count, acts_rtrn_acts) = if cond0.key_actions() {
+            let (acts_rtrn_count, block_len): (Vec<Card8>, usize) =
+                parse_vector(bytes, index, len3 as usize, "acts_rtrn_count")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            index += buffer_pad(index, 4);
+            let (acts_rtrn_acts, block_len): (Vec<Action>, usize) =
+                parse_vector(bytes, index, len2 as usize, "acts_rtrn_acts")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
+            (acts_rtrn_count, acts_rtrn_acts)
+        } else {
+            (Default::default(), Default::default())
+        };
+        let (behaviors_rtrn,) = if cond0.key_behaviors() {
+            let (behaviors_rtrn, block_len): (Vec<SetBehavior>, usize) =
+                parse_vector(bytes, index, len4 as usize, "behaviors_rtrn")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+            (behaviors_rtrn,)
+        } else {
+            (Default::default(),)
+        };
+        let (vmods_rtrn,) = if cond0.virtual_mods() {
+            let (vmods_rtrn, block_len): (Vec<Card8>, usize) = parse_vector(
+                bytes,
+                index,
+                ((virtual_mods).count_ones()) as usize,
+                "vmods_rtrn",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            index += buffer_pad(index, 4);
+            (vmods_rtrn,)
+        } else {
+            (Default::default(),)
+        };
+        let (explicit_rtrn,) = if cond0.explicit_components() {
+            let (explicit_rtrn, block_len): (Vec<SetExplicit>, usize) =
+                parse_vector(bytes, index, len5 as usize, "explicit_rtrn")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+            index += buffer_pad(index, 4);
+            (explicit_rtrn,)
+        } else {
+            (Default::default(),)
+        };
+        let (modmap_rtrn,) = if cond0.modifier_map() {
+            let (modmap_rtrn, block_len): (Vec<KeyModMap>, usize) =
+                parse_vector(bytes, index, len6 as usize, "modmap_rtrn")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+            index += buffer_pad(index, 4);
+            (modmap_rtrn,)
+        } else {
+            (Default::default(),)
+        };
+        let (vmodmap_rtrn,) = if cond0.virtual_mod_map() {
+            let (vmodmap_rtrn, block_len): (Vec<KeyVModMap>, usize) =
+                parse_vector(bytes, index, len7 as usize, "vmodmap_rtrn")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            (vmodmap_rtrn,)
+        } else {
+            (Default::default(),)
+        };
+        index += buffer_pad(index, 4);
+        Ok((
             GetMapReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -8295,88 +10783,196 @@ impl AsByteSequence for GetMapReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + 2
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.present.size()
-            + self.first_type.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.total_types.size()
-            + self.first_key_sym.size()
-            + self.total_syms.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_action.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_behavior.size()
-            + self.n_key_behaviors.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + 1
-            + self.virtual_mods.size()
-            + {
-                let block_len: usize = self.types_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.syms_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.acts_rtrn_count.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
-                let block_len: usize = self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.behaviors_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-                block_len + pad
-            }
-            + {
+        let size: usize = ({
+            let size: usize = ({
+                let size: usize = ({
+                    let size: usize = ({
+                        let size: usize = 32
+                            + self.min_key_code.size()
+                            + self.max_key_code.size()
+                            + self.first_key_sym.size()
+                            + self.first_key_action.size()
+                            + self.first_key_behavior.size()
+                            + self.first_key_explicit.size()
+                            + self.first_mod_map_key.size()
+                            + self.first_v_mod_map_key.size()
+                            + {
+                                let block_len: usize =
+                                    self.types_rtrn.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+                                block_len + pad
+                            }
+                            + {
+                                let block_len: usize =
+                                    self.syms_rtrn.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                                block_len + pad
+                            }
+                            + {
+                                let block_len: usize =
+                                    self.acts_rtrn_count.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                                block_len + pad
+                            };
+                        size + buffer_pad(size, 4)
+                    }) + {
+                        let block_len: usize = self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
+                        block_len + pad
+                    } + {
+                        let block_len: usize = self.behaviors_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+                        block_len + pad
+                    } + {
+                        let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                    size + buffer_pad(size, 4)
+                }) + {
+                    let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = ({
+            let size: usize = ({
+                let size: usize = ({
+                    let size: usize = ({
+                        let size: usize = 32
+                            + self.min_key_code.size()
+                            + self.max_key_code.size()
+                            + self.first_key_sym.size()
+                            + self.first_key_action.size()
+                            + self.first_key_behavior.size()
+                            + self.first_key_explicit.size()
+                            + self.first_mod_map_key.size()
+                            + self.first_v_mod_map_key.size()
+                            + {
+                                let block_len: usize =
+                                    self.types_rtrn.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+                                block_len + pad
+                            }
+                            + {
+                                let block_len: usize =
+                                    self.syms_rtrn.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                                block_len + pad
+                            }
+                            + {
+                                let block_len: usize =
+                                    self.acts_rtrn_count.len() * ::core::mem::size_of::<Card8>();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                                block_len + pad
+                            };
+                        size + buffer_pad(size, 4)
+                    }) + {
+                        let block_len: usize = self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
+                        block_len + pad
+                    } + {
+                        let block_len: usize = self.behaviors_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+                        block_len + pad
+                    } + {
+                        let block_len: usize =
+                            self.vmods_rtrn.len() * ::core::mem::size_of::<Card8>();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                    size + buffer_pad(size, 4)
+                }) + {
+                    let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }) + {
+                let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
                 block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetMapReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for GetMapReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetMapReply")
+            .field("reply_type", &self.reply_type)
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("min_key_code", &self.min_key_code)
+            .field("max_key_code", &self.max_key_code)
+            .field("present", &self.present)
+            .field("first_type", &self.first_type)
+            .field("total_types", &self.total_types)
+            .field("first_key_sym", &self.first_key_sym)
+            .field("total_syms", &self.total_syms)
+            .field("first_key_action", &self.first_key_action)
+            .field("first_key_behavior", &self.first_key_behavior)
+            .field("n_key_behaviors", &self.n_key_behaviors)
+            .field("first_key_explicit", &self.first_key_explicit)
+            .field("n_key_explicit", &self.n_key_explicit)
+            .field("first_mod_map_key", &self.first_mod_map_key)
+            .field("n_mod_map_keys", &self.n_mod_map_keys)
+            .field("first_v_mod_map_key", &self.first_v_mod_map_key)
+            .field("n_v_mod_map_keys", &self.n_v_mod_map_keys)
+            .field("virtual_mods", &self.virtual_mods)
+            .field("types_rtrn", &self.types_rtrn)
+            .field("syms_rtrn", &self.syms_rtrn)
+            .field("acts_rtrn_count", &debug_bytes(&self.acts_rtrn_count, 64))
+            .field("acts_rtrn_acts", &self.acts_rtrn_acts)
+            .field("behaviors_rtrn", &self.behaviors_rtrn)
+            .field("vmods_rtrn", &debug_bytes(&self.vmods_rtrn, 64))
+            .field("explicit_rtrn", &self.explicit_rtrn)
+            .field("modmap_rtrn", &self.modmap_rtrn)
+            .field("vmodmap_rtrn", &self.vmodmap_rtrn)
+            .finish()
+    }
+}
+#[derive(Clone)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetMapRequest {
     pub req_type: u8,
     pub length: u16,
@@ -8408,13 +11004,26 @@ pub struct SetMapRequest {
     pub modmap: Vec<KeyModMap>,
     pub vmodmap: Vec<KeyVModMap>,
 }
-impl SetMapRequest {}
-impl AsByteSequence for SetMapRequest {
+impl SetMapRequest {
     #[inline]
+    pub fn builder() -> SetMapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("present", 6, 2),
+        ("flags", 8, 2),
+    ];
+}
+const _: () = assert!(<SetMapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetMapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.present.as_bytes(&mut bytes[index..]);
@@ -8442,135 +11051,232 @@ impl AsByteSequence for SetMapRequest {
         index += self.n_v_mod_map_keys.as_bytes(&mut bytes[index..]);
         index += (self.vmodmap.len() as Card8).as_bytes(&mut bytes[index..]);
         index += self.virtual_mods.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.types, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
-        let block_len: usize = vector_as_bytes(&self.syms, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-        let block_len: usize = vector_as_bytes(&self.actions_count, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let block_len: usize = vector_as_bytes(&self.actions, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
-        let block_len: usize = vector_as_bytes(&self.behaviors, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-        let block_len: usize = vector_as_bytes(&self.vmods, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let block_len: usize = vector_as_bytes(&self.explicit, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-        let block_len: usize = vector_as_bytes(&self.modmap, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-        let block_len: usize = vector_as_bytes(&self.vmodmap, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+        let cond0 = (self.present);
+        if cond0.key_types() {
+            let block_len: usize = vector_as_bytes(&self.types, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>()),
+            );
+        }
+        if cond0.key_syms() {
+            let block_len: usize = vector_as_bytes(&self.syms, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>()),
+            );
+        }
+        if cond0.key_actions() {
+            let block_len: usize = vector_as_bytes(&self.actions_count, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+            let block_len: usize = vector_as_bytes(&self.actions, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Action>()),
+            );
+        }
+        if cond0.key_behaviors() {
+            let block_len: usize = vector_as_bytes(&self.behaviors, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>()),
+            );
+        }
+        if cond0.virtual_mods() {
+            let block_len: usize = vector_as_bytes(&self.vmods, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+        }
+        if cond0.explicit_components() {
+            let block_len: usize = vector_as_bytes(&self.explicit, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>()),
+            );
+        }
+        if cond0.modifier_map() {
+            let block_len: usize = vector_as_bytes(&self.modmap, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>()),
+            );
+        }
+        if cond0.virtual_mod_map() {
+            let block_len: usize = vector_as_bytes(&self.vmodmap, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>()),
+            );
+        }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (present, sz): (MapPart, usize) = parse_item(bytes, index, "present")?;
         index += sz;
-        let (flags, sz): (SetMapFlags, usize) = <SetMapFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (SetMapFlags, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "min_key_code")?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "max_key_code")?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = parse_item(bytes, index, "first_type")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_sym, sz): (Keycode, usize) = parse_item(bytes, index, "first_key_sym")?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_syms, sz): (Card16, usize) = parse_item(bytes, index, "total_syms")?;
         index += sz;
-        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_action, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_action")?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
         index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_behavior")?;
         index += sz;
-        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_behaviors, sz): (Card8, usize) = parse_item(bytes, index, "n_key_behaviors")?;
         index += sz;
-        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len4, sz): (Card8, usize) = parse_item(bytes, index, "len4")?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_explicit")?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_explicit, sz): (Card8, usize) = parse_item(bytes, index, "n_key_explicit")?;
         index += sz;
-        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len5, sz): (Card8, usize) = parse_item(bytes, index, "len5")?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_mod_map_key")?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_mod_map_keys")?;
         index += sz;
-        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len6, sz): (Card8, usize) = parse_item(bytes, index, "len6")?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_v_mod_map_key")?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_v_mod_map_keys")?;
         index += sz;
-        let (len7, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len7, sz): (Card8, usize) = parse_item(bytes, index, "len7")?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
         index += sz;
-        let (types, block_len): (Vec<SetKeyType>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
-        let (syms, block_len): (Vec<KeySymMap>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-        let (actions_count, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let (actions, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
-        let (behaviors, block_len): (Vec<SetBehavior>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-        let (vmods, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let (explicit, block_len): (Vec<SetExplicit>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-        let (modmap, block_len): (Vec<KeyModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len6 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-        let (vmodmap, block_len): (Vec<KeyVModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len7 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
-        Some((
+        let cond0 = (present);
+        let (types,) = if cond0.key_types() {
+            let (types, block_len): (Vec<SetKeyType>, usize) =
+                parse_vector(bytes, index, len0 as usize, "types")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
+            (types,)
+        } else {
+            (Default::default(),)
+        };
+        let (syms,) = if cond0.key_syms() {
+            let (syms, block_len): (Vec<KeySymMap>, usize) =
+                parse_vector(bytes, index, len1 as usize, "syms")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+            (syms,)
+        } else {
+            (Default::default(),)
+        };
+        let (actions_count, actions) = if cond0.key_actions() {
+            let (actions_count, block_len): (Vec<Card8>, usize) =
+                parse_vector(bytes, index, len2 as usize, "actions_count")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            index += buffer_pad(index, 4);
+            let (actions, block_len): (Vec<Action>, usize) =
+                parse_vector(bytes, index, len3 as usize, "actions")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
+            (actions_count, actions)
+        } else {
+            (Default::default(), Default::default())
+        };
+        let (behaviors,) = if cond0.key_behaviors() {
+            let (behaviors, block_len): (Vec<SetBehavior>, usize) =
+                parse_vector(bytes, index, len4 as usize, "behaviors")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+            (behaviors,)
+        } else {
+            (Default::default(),)
+        };
+        let (vmods,) = if cond0.virtual_mods() {
+            let (vmods, block_len): (Vec<Card8>, usize) = parse_vector(
+                bytes,
+                index,
+                ((virtual_mods).count_ones()) as usize,
+                "vmods",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            index += buffer_pad(index, 4);
+            (vmods,)
+        } else {
+            (Default::default(),)
+        };
+        let (explicit,) = if cond0.explicit_components() {
+            let (explicit, block_len): (Vec<SetExplicit>, usize) =
+                parse_vector(bytes, index, len5 as usize, "explicit")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+            (explicit,)
+        } else {
+            (Default::default(),)
+        };
+        let (modmap,) = if cond0.modifier_map() {
+            let (modmap, block_len): (Vec<KeyModMap>, usize) =
+                parse_vector(bytes, index, len6 as usize, "modmap")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+            (modmap,)
+        } else {
+            (Default::default(),)
+        };
+        let (vmodmap,) = if cond0.virtual_mod_map() {
+            let (vmodmap, block_len): (Vec<KeyVModMap>, usize) =
+                parse_vector(bytes, index, len7 as usize, "vmodmap")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            (vmodmap,)
+        } else {
+            (Default::default(),)
+        };
+        index += buffer_pad(index, 4);
+        Ok((
             SetMapRequest {
                 req_type: req_type,
                 length: length,
@@ -8605,93 +11311,375 @@ impl AsByteSequence for SetMapRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.present.size()
-            + self.flags.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.first_type.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_sym.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.total_syms.size()
-            + self.first_key_action.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card16>()
-            + self.first_key_behavior.size()
-            + self.n_key_behaviors.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.virtual_mods.size()
-            + {
-                let block_len: usize = self.types.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
+        let size: usize = ({
+            let size: usize = ({
+                let size: usize = 28
+                    + self.min_key_code.size()
+                    + self.max_key_code.size()
+                    + self.first_key_sym.size()
+                    + self.first_key_action.size()
+                    + self.first_key_behavior.size()
+                    + self.first_key_explicit.size()
+                    + self.first_mod_map_key.size()
+                    + self.first_v_mod_map_key.size()
+                    + {
+                        let block_len: usize = self.types.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.syms.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.actions_count.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }) + {
+                let block_len: usize = self.actions.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.syms.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+            } + {
+                let block_len: usize = self.behaviors.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.actions_count.iter().map(|i| i.size()).sum();
+            } + {
+                let block_len: usize = self.vmods.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
                 block_len + pad
-            }
-            + 4
-            + {
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.explicit.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.modmap.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.vmodmap.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = ({
+            let size: usize = ({
+                let size: usize = 28
+                    + self.min_key_code.size()
+                    + self.max_key_code.size()
+                    + self.first_key_sym.size()
+                    + self.first_key_action.size()
+                    + self.first_key_behavior.size()
+                    + self.first_key_explicit.size()
+                    + self.first_mod_map_key.size()
+                    + self.first_v_mod_map_key.size()
+                    + {
+                        let block_len: usize = self.types.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.syms.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize =
+                            self.actions_count.len() * ::core::mem::size_of::<Card8>();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.actions.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.behaviors.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmods.iter().map(|i| i.size()).sum();
+            } + {
+                let block_len: usize = self.vmods.len() * ::core::mem::size_of::<Card8>();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.explicit.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.modmap.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmodmap.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
-                block_len + pad
-            }
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.explicit.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.modmap.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.vmodmap.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetMapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetMapRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetMap";
     type Reply = ();
 }
+impl core::fmt::Debug for SetMapRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SetMapRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("device_spec", &self.device_spec)
+            .field("present", &self.present)
+            .field("flags", &self.flags)
+            .field("min_key_code", &self.min_key_code)
+            .field("max_key_code", &self.max_key_code)
+            .field("first_type", &self.first_type)
+            .field("first_key_sym", &self.first_key_sym)
+            .field("total_syms", &self.total_syms)
+            .field("first_key_action", &self.first_key_action)
+            .field("first_key_behavior", &self.first_key_behavior)
+            .field("n_key_behaviors", &self.n_key_behaviors)
+            .field("first_key_explicit", &self.first_key_explicit)
+            .field("n_key_explicit", &self.n_key_explicit)
+            .field("first_mod_map_key", &self.first_mod_map_key)
+            .field("n_mod_map_keys", &self.n_mod_map_keys)
+            .field("first_v_mod_map_key", &self.first_v_mod_map_key)
+            .field("n_v_mod_map_keys", &self.n_v_mod_map_keys)
+            .field("virtual_mods", &self.virtual_mods)
+            .field("types", &self.types)
+            .field("syms", &self.syms)
+            .field("actions_count", &debug_bytes(&self.actions_count, 64))
+            .field("actions", &self.actions)
+            .field("behaviors", &self.behaviors)
+            .field("vmods", &debug_bytes(&self.vmods, 64))
+            .field("explicit", &self.explicit)
+            .field("modmap", &self.modmap)
+            .field("vmodmap", &self.vmodmap)
+            .finish()
+    }
+}
+#[doc = " A builder for `SetMapRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetMapRequestBuilder {
+    pub inner: SetMapRequest,
+}
+impl SetMapRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn present(mut self, present: MapPart) -> Self {
+        self.inner.present = present;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: SetMapFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn min_key_code(mut self, min_key_code: Keycode) -> Self {
+        self.inner.min_key_code = min_key_code;
+        self
+    }
+    #[inline]
+    pub fn max_key_code(mut self, max_key_code: Keycode) -> Self {
+        self.inner.max_key_code = max_key_code;
+        self
+    }
+    #[inline]
+    pub fn first_type(mut self, first_type: Card8) -> Self {
+        self.inner.first_type = first_type;
+        self
+    }
+    #[inline]
+    pub fn first_key_sym(mut self, first_key_sym: Keycode) -> Self {
+        self.inner.first_key_sym = first_key_sym;
+        self
+    }
+    #[inline]
+    pub fn total_syms(mut self, total_syms: Card16) -> Self {
+        self.inner.total_syms = total_syms;
+        self
+    }
+    #[inline]
+    pub fn first_key_action(mut self, first_key_action: Keycode) -> Self {
+        self.inner.first_key_action = first_key_action;
+        self
+    }
+    #[inline]
+    pub fn first_key_behavior(mut self, first_key_behavior: Keycode) -> Self {
+        self.inner.first_key_behavior = first_key_behavior;
+        self
+    }
+    #[inline]
+    pub fn n_key_behaviors(mut self, n_key_behaviors: Card8) -> Self {
+        self.inner.n_key_behaviors = n_key_behaviors;
+        self
+    }
+    #[inline]
+    pub fn first_key_explicit(mut self, first_key_explicit: Keycode) -> Self {
+        self.inner.first_key_explicit = first_key_explicit;
+        self
+    }
+    #[inline]
+    pub fn n_key_explicit(mut self, n_key_explicit: Card8) -> Self {
+        self.inner.n_key_explicit = n_key_explicit;
+        self
+    }
+    #[inline]
+    pub fn first_mod_map_key(mut self, first_mod_map_key: Keycode) -> Self {
+        self.inner.first_mod_map_key = first_mod_map_key;
+        self
+    }
+    #[inline]
+    pub fn n_mod_map_keys(mut self, n_mod_map_keys: Card8) -> Self {
+        self.inner.n_mod_map_keys = n_mod_map_keys;
+        self
+    }
+    #[inline]
+    pub fn first_v_mod_map_key(mut self, first_v_mod_map_key: Keycode) -> Self {
+        self.inner.first_v_mod_map_key = first_v_mod_map_key;
+        self
+    }
+    #[inline]
+    pub fn n_v_mod_map_keys(mut self, n_v_mod_map_keys: Card8) -> Self {
+        self.inner.n_v_mod_map_keys = n_v_mod_map_keys;
+        self
+    }
+    #[inline]
+    pub fn virtual_mods(mut self, virtual_mods: VMod) -> Self {
+        self.inner.virtual_mods = virtual_mods;
+        self
+    }
+    #[inline]
+    pub fn types(mut self, types: Vec<SetKeyType>) -> Self {
+        self.inner.present.set_key_types(true);
+        self.inner.types = types;
+        self
+    }
+    #[inline]
+    pub fn syms(mut self, syms: Vec<KeySymMap>) -> Self {
+        self.inner.present.set_key_syms(true);
+        self.inner.syms = syms;
+        self
+    }
+    #[inline]
+    pub fn actions_count(mut self, actions_count: Vec<Card8>) -> Self {
+        self.inner.present.set_key_actions(true);
+        self.inner.actions_count = actions_count;
+        self
+    }
+    #[inline]
+    pub fn actions(mut self, actions: Vec<Action>) -> Self {
+        self.inner.present.set_key_actions(true);
+        self.inner.actions = actions;
+        self
+    }
+    #[inline]
+    pub fn behaviors(mut self, behaviors: Vec<SetBehavior>) -> Self {
+        self.inner.present.set_key_behaviors(true);
+        self.inner.behaviors = behaviors;
+        self
+    }
+    #[inline]
+    pub fn vmods(mut self, vmods: Vec<Card8>) -> Self {
+        self.inner.present.set_virtual_mods(true);
+        self.inner.vmods = vmods;
+        self
+    }
+    #[inline]
+    pub fn explicit(mut self, explicit: Vec<SetExplicit>) -> Self {
+        self.inner.present.set_explicit_components(true);
+        self.inner.explicit = explicit;
+        self
+    }
+    #[inline]
+    pub fn modmap(mut self, modmap: Vec<KeyModMap>) -> Self {
+        self.inner.present.set_modifier_map(true);
+        self.inner.modmap = modmap;
+        self
+    }
+    #[inline]
+    pub fn vmodmap(mut self, vmodmap: Vec<KeyVModMap>) -> Self {
+        self.inner.present.set_virtual_mod_map(true);
+        self.inner.vmodmap = vmodmap;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetMapRequest {
+        self.inner
+    }
+}
+impl Default for SetMapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetMapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                present: Default::default(),
+                flags: Default::default(),
+                min_key_code: Default::default(),
+                max_key_code: Default::default(),
+                first_type: Default::default(),
+                first_key_sym: Default::default(),
+                total_syms: Default::default(),
+                first_key_action: Default::default(),
+                first_key_behavior: Default::default(),
+                n_key_behaviors: Default::default(),
+                first_key_explicit: Default::default(),
+                n_key_explicit: Default::default(),
+                first_mod_map_key: Default::default(),
+                n_mod_map_keys: Default::default(),
+                first_v_mod_map_key: Default::default(),
+                n_v_mod_map_keys: Default::default(),
+                virtual_mods: Default::default(),
+                types: Default::default(),
+                syms: Default::default(),
+                actions_count: Default::default(),
+                actions: Default::default(),
+                behaviors: Default::default(),
+                vmods: Default::default(),
+                explicit: Default::default(),
+                modmap: Default::default(),
+                vmodmap: Default::default(),
+            },
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetMapFlags {
     pub inner: u16,
 }
@@ -8741,21 +11729,20 @@ impl SetMapFlags {
     pub const RECOMPUTE_ACTIONS: Self = Self { inner: 2 };
     pub const COMPLETE: Self = Self { inner: 3 };
 }
-impl AsByteSequence for SetMapFlags {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for SetMapFlags {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((SetMapFlags { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for SetMapFlags {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { SetMapFlags }
 impl core::ops::Not for SetMapFlags {
     type Output = SetMapFlags;
     #[inline]
@@ -8790,7 +11777,10 @@ impl core::ops::BitXor for SetMapFlags {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetCompatMapRequest {
     pub req_type: u8,
     pub length: u16,
@@ -8800,41 +11790,65 @@ pub struct GetCompatMapRequest {
     pub first_si: Card16,
     pub n_si: Card16,
 }
-impl GetCompatMapRequest {}
-impl AsByteSequence for GetCompatMapRequest {
+impl GetCompatMapRequest {
     #[inline]
+    pub fn builder() -> GetCompatMapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetCompatMapReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("groups", 6, 1),
+        ("get_all_si", 7, 1),
+        ("first_si", 8, 2),
+        ("n_si", 10, 2),
+    ];
+}
+const _: () = assert!(<GetCompatMapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetCompatMapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.groups.as_bytes(&mut bytes[index..]);
         index += self.get_all_si.as_bytes(&mut bytes[index..]);
         index += self.first_si.as_bytes(&mut bytes[index..]);
         index += self.n_si.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCompatMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (groups, sz): (SetOfGroup, usize) = parse_item(bytes, index, "groups")?;
         index += sz;
-        let (get_all_si, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (get_all_si, sz): (bool, usize) = parse_item(bytes, index, "get_all_si")?;
         index += sz;
-        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first_si, sz): (Card16, usize) = parse_item(bytes, index, "first_si")?;
         index += sz;
-        let (n_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_si, sz): (Card16, usize) = parse_item(bytes, index, "n_si")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCompatMapRequest {
                 req_type: req_type,
                 length: length,
@@ -8849,23 +11863,81 @@ impl AsByteSequence for GetCompatMapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.groups.size()
-            + self.get_all_si.size()
-            + self.first_si.size()
-            + self.n_si.size()
+        let size: usize = 11 + self.get_all_si.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetCompatMapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetCompatMapRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCompatMap";
     type Reply = GetCompatMapReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetCompatMapRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetCompatMapRequestBuilder {
+    pub inner: GetCompatMapRequest,
+}
+impl GetCompatMapRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn groups(mut self, groups: SetOfGroup) -> Self {
+        self.inner.groups = groups;
+        self
+    }
+    #[inline]
+    pub fn get_all_si(mut self, get_all_si: bool) -> Self {
+        self.inner.get_all_si = get_all_si;
+        self
+    }
+    #[inline]
+    pub fn first_si(mut self, first_si: Card16) -> Self {
+        self.inner.first_si = first_si;
+        self
+    }
+    #[inline]
+    pub fn n_si(mut self, n_si: Card16) -> Self {
+        self.inner.n_si = n_si;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetCompatMapRequest {
+        self.inner
+    }
+}
+impl Default for GetCompatMapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetCompatMapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                groups: Default::default(),
+                get_all_si: Default::default(),
+                first_si: Default::default(),
+                n_si: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetCompatMapReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -8877,9 +11949,129 @@ pub struct GetCompatMapReply {
     pub si_rtrn: Vec<SymInterpret>,
     pub group_rtrn: Vec<ModDef>,
 }
-impl GetCompatMapReply {}
-impl AsByteSequence for GetCompatMapReply {
+impl GetCompatMapReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn si_rtrn_iter(bytes: &[u8]) -> Result<ListIter<'_, SymInterpret>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (groups_rtrn, sz): (SetOfGroup, usize) = parse_item(bytes, index, "groups_rtrn")?;
+        index += sz;
+        index += 1;
+        let (first_si_rtrn, sz): (Card16, usize) = parse_item(bytes, index, "first_si_rtrn")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (n_total_si, sz): (Card16, usize) = parse_item(bytes, index, "n_total_si")?;
+        index += sz;
+        index += 16;
+        Ok(ListIter::new(bytes, index, len0 as usize, "si_rtrn"))
+    }
+    pub fn group_rtrn_iter(bytes: &[u8]) -> Result<ListIter<'_, ModDef>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (groups_rtrn, sz): (SetOfGroup, usize) = parse_item(bytes, index, "groups_rtrn")?;
+        index += sz;
+        index += 1;
+        let (first_si_rtrn, sz): (Card16, usize) = parse_item(bytes, index, "first_si_rtrn")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (n_total_si, sz): (Card16, usize) = parse_item(bytes, index, "n_total_si")?;
+        index += sz;
+        index += 16;
+        let (si_rtrn, block_len): (Vec<SymInterpret>, usize) =
+            parse_vector(bytes, index, len0 as usize, "si_rtrn")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+        Ok(ListIter::new(
+            bytes,
+            index,
+            ((groups_rtrn).count_ones()) as usize,
+            "group_rtrn",
+        ))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("groups_rtrn", 8, 1),
+        ("first_si_rtrn", 10, 2),
+        ("si_rtrn_len", 12, 2),
+        ("n_total_si", 14, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.groups_rtrn == other.groups_rtrn
+            && self.first_si_rtrn == other.first_si_rtrn
+            && self.n_total_si == other.n_total_si
+            && self.si_rtrn == other.si_rtrn
+            && self.group_rtrn == other.group_rtrn
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value(
+            "groups_rtrn",
+            &self.groups_rtrn,
+            &other.groups_rtrn,
+            &mut changes,
+        );
+        diff_value(
+            "first_si_rtrn",
+            &self.first_si_rtrn,
+            &other.first_si_rtrn,
+            &mut changes,
+        );
+        diff_value(
+            "n_total_si",
+            &self.n_total_si,
+            &other.n_total_si,
+            &mut changes,
+        );
+        diff_list("si_rtrn", &self.si_rtrn, &other.si_rtrn, &mut changes);
+        diff_list(
+            "group_rtrn",
+            &self.group_rtrn,
+            &other.group_rtrn,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetCompatMapReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -8887,50 +12079,62 @@ impl AsByteSequence for GetCompatMapReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.groups_rtrn.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.first_si_rtrn.as_bytes(&mut bytes[index..]);
         index += (self.si_rtrn.len() as Card16).as_bytes(&mut bytes[index..]);
         index += self.n_total_si.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         let block_len: usize = vector_as_bytes(&self.si_rtrn, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>()),
+        );
         let block_len: usize = vector_as_bytes(&self.group_rtrn, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ModDef>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCompatMapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (groups_rtrn, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (groups_rtrn, sz): (SetOfGroup, usize) = parse_item(bytes, index, "groups_rtrn")?;
         index += sz;
         index += 1;
-        let (first_si_rtrn, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first_si_rtrn, sz): (Card16, usize) = parse_item(bytes, index, "first_si_rtrn")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_total_si, sz): (Card16, usize) = parse_item(bytes, index, "n_total_si")?;
         index += sz;
         index += 16;
         let (si_rtrn, block_len): (Vec<SymInterpret>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "si_rtrn")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
-        let (group_rtrn, block_len): (Vec<ModDef>, usize) =
-            vector_from_bytes(&bytes[index..], ((groups_rtrn).count_ones()) as usize)?;
+        let (group_rtrn, block_len): (Vec<ModDef>, usize) = parse_vector(
+            bytes,
+            index,
+            ((groups_rtrn).count_ones()) as usize,
+            "group_rtrn",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetCompatMapReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -8945,31 +12149,31 @@ impl AsByteSequence for GetCompatMapReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.groups_rtrn.size()
-            + 1
-            + self.first_si_rtrn.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.n_total_si.size()
-            + 16
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.si_rtrn.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.group_rtrn.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetCompatMapReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetCompatMapRequest {
     pub req_type: u8,
     pub length: u16,
@@ -8981,62 +12185,85 @@ pub struct SetCompatMapRequest {
     pub si: Vec<SymInterpret>,
     pub group_maps: Vec<ModDef>,
 }
-impl SetCompatMapRequest {}
-impl AsByteSequence for SetCompatMapRequest {
+impl SetCompatMapRequest {
     #[inline]
+    pub fn builder() -> SetCompatMapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("recompute_actions", 7, 1),
+        ("truncate_si", 8, 1),
+        ("groups", 9, 1),
+        ("first_si", 10, 2),
+        ("si_len", 12, 2),
+    ];
+}
+const _: () = assert!(<SetCompatMapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetCompatMapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.recompute_actions.as_bytes(&mut bytes[index..]);
         index += self.truncate_si.as_bytes(&mut bytes[index..]);
         index += self.groups.as_bytes(&mut bytes[index..]);
         index += self.first_si.as_bytes(&mut bytes[index..]);
         index += (self.si.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.si, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>()),
+        );
         let block_len: usize = vector_as_bytes(&self.group_maps, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ModDef>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCompatMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
         index += 1;
-        let (recompute_actions, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (recompute_actions, sz): (bool, usize) = parse_item(bytes, index, "recompute_actions")?;
         index += sz;
-        let (truncate_si, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (truncate_si, sz): (bool, usize) = parse_item(bytes, index, "truncate_si")?;
         index += sz;
-        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (groups, sz): (SetOfGroup, usize) = parse_item(bytes, index, "groups")?;
         index += sz;
-        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first_si, sz): (Card16, usize) = parse_item(bytes, index, "first_si")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
         let (si, block_len): (Vec<SymInterpret>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "si")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
         let (group_maps, block_len): (Vec<ModDef>, usize) =
-            vector_from_bytes(&bytes[index..], ((groups).count_ones()) as usize)?;
+            parse_vector(bytes, index, ((groups).count_ones()) as usize, "group_maps")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetCompatMapRequest {
                 req_type: req_type,
                 length: length,
@@ -9051,19 +12278,10 @@ impl AsByteSequence for SetCompatMapRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + 1
+        let size: usize = 14
             + self.recompute_actions.size()
             + self.truncate_si.size()
-            + self.groups.size()
-            + self.first_si.size()
-            + ::core::mem::size_of::<Card16>()
-            + 2
             + {
                 let block_len: usize = self.si.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
@@ -9073,76 +12291,226 @@ impl AsByteSequence for SetCompatMapRequest {
                 let block_len: usize = self.group_maps.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetCompatMapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetCompatMapRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCompatMap";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetIndicatorStateRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub device_spec: DeviceSpec,
+#[doc = " A builder for `SetCompatMapRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetCompatMapRequestBuilder {
+    pub inner: SetCompatMapRequest,
 }
-impl GetIndicatorStateRequest {}
-impl AsByteSequence for GetIndicatorStateRequest {
+impl SetCompatMapRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetIndicatorStateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 2;
-        Some((
-            GetIndicatorStateRequest {
-                req_type: req_type,
-                length: length,
-                device_spec: device_spec,
-            },
-            index,
-        ))
+    pub fn recompute_actions(mut self, recompute_actions: bool) -> Self {
+        self.inner.recompute_actions = recompute_actions;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.device_spec.size() + 2
+    pub fn truncate_si(mut self, truncate_si: bool) -> Self {
+        self.inner.truncate_si = truncate_si;
+        self
     }
-}
-impl Request for GetIndicatorStateRequest {
-    const OPCODE: u8 = 12;
-    const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = GetIndicatorStateReply;
-}
-#[derive(Clone, Debug, Default)]
-pub struct GetIndicatorStateReply {
-    pub reply_type: u8,
-    pub device_id: Card8,
-    pub sequence: u16,
-    pub length: u32,
-    pub state: Card32,
-}
-impl GetIndicatorStateReply {}
-impl AsByteSequence for GetIndicatorStateReply {
     #[inline]
+    pub fn groups(mut self, groups: SetOfGroup) -> Self {
+        self.inner.groups = groups;
+        self
+    }
+    #[inline]
+    pub fn first_si(mut self, first_si: Card16) -> Self {
+        self.inner.first_si = first_si;
+        self
+    }
+    #[inline]
+    pub fn si(mut self, si: Vec<SymInterpret>) -> Self {
+        self.inner.si = si;
+        self
+    }
+    #[inline]
+    pub fn group_maps(mut self, group_maps: Vec<ModDef>) -> Self {
+        self.inner.group_maps = group_maps;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetCompatMapRequest {
+        self.inner
+    }
+}
+impl Default for SetCompatMapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetCompatMapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                recompute_actions: Default::default(),
+                truncate_si: Default::default(),
+                groups: Default::default(),
+                first_si: Default::default(),
+                si: Default::default(),
+                group_maps: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct GetIndicatorStateRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub device_spec: DeviceSpec,
+}
+impl GetIndicatorStateRequest {
+    #[inline]
+    pub fn builder() -> GetIndicatorStateRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetIndicatorStateReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("device_spec", 4, 2)];
+}
+const _: () = assert!(<GetIndicatorStateRequest as Request>::MINOR_OPCODE < 0x100);
+impl_device_spec_only_request_asb! { GetIndicatorStateRequest }
+impl Request for GetIndicatorStateRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 12;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetIndicatorState";
+    type Reply = GetIndicatorStateReply;
+}
+#[doc = " A builder for `GetIndicatorStateRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetIndicatorStateRequestBuilder {
+    pub inner: GetIndicatorStateRequest,
+}
+impl GetIndicatorStateRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetIndicatorStateRequest {
+        self.inner
+    }
+}
+impl Default for GetIndicatorStateRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetIndicatorStateRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct GetIndicatorStateReply {
+    pub reply_type: u8,
+    pub device_id: Card8,
+    pub sequence: u16,
+    pub length: u32,
+    pub state: Card32,
+}
+impl GetIndicatorStateReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        let device_id: Card8 = read_item(reader, &mut index, 1, "device_id")?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let state: Card32 = read_item(reader, &mut index, 4, "state")?;
+        skip_bytes(reader, &mut index, 20)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetIndicatorStateReply {
+            reply_type: reply_type,
+            device_id: device_id,
+            sequence: sequence,
+            length: length,
+            state: state,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("state", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id && self.state == other.state
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value("state", &self.state, &other.state, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetIndicatorStateReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -9150,25 +12518,27 @@ impl AsByteSequence for GetIndicatorStateReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.state.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIndicatorStateReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (state, sz): (Card32, usize) = parse_item(bytes, index, "state")?;
         index += sz;
         index += 20;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetIndicatorStateReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -9181,49 +12551,76 @@ impl AsByteSequence for GetIndicatorStateReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.state.size()
-            + 20
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for GetIndicatorStateReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetIndicatorMapRequest {
     pub req_type: u8,
     pub length: u16,
     pub device_spec: DeviceSpec,
     pub which: Card32,
 }
-impl GetIndicatorMapRequest {}
-impl AsByteSequence for GetIndicatorMapRequest {
+impl GetIndicatorMapRequest {
+    #[inline]
+    pub fn builder() -> GetIndicatorMapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetIndicatorMapReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("which", 8, 4),
+    ];
+}
+const _: () = assert!(<GetIndicatorMapRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetIndicatorMapRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.which.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIndicatorMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
         index += 2;
-        let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (which, sz): (Card32, usize) = parse_item(bytes, index, "which")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetIndicatorMapRequest {
                 req_type: req_type,
                 length: length,
@@ -9235,21 +12632,62 @@ impl AsByteSequence for GetIndicatorMapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + 2
-            + self.which.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetIndicatorMapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetIndicatorMapRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetIndicatorMap";
     type Reply = GetIndicatorMapReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetIndicatorMapRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetIndicatorMapRequestBuilder {
+    pub inner: GetIndicatorMapRequest,
+}
+impl GetIndicatorMapRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn which(mut self, which: Card32) -> Self {
+        self.inner.which = which;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetIndicatorMapRequest {
+        self.inner
+    }
+}
+impl Default for GetIndicatorMapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetIndicatorMapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                which: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetIndicatorMapReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -9260,9 +12698,91 @@ pub struct GetIndicatorMapReply {
     pub n_indicators: Card8,
     pub maps: Vec<IndicatorMap>,
 }
-impl GetIndicatorMapReply {}
-impl AsByteSequence for GetIndicatorMapReply {
+impl GetIndicatorMapReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn maps_iter(bytes: &[u8]) -> Result<ListIter<'_, IndicatorMap>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (which, sz): (Card32, usize) = parse_item(bytes, index, "which")?;
+        index += sz;
+        let (real_indicators, sz): (Card32, usize) = parse_item(bytes, index, "real_indicators")?;
+        index += sz;
+        let (n_indicators, sz): (Card8, usize) = parse_item(bytes, index, "n_indicators")?;
+        index += sz;
+        index += 15;
+        Ok(ListIter::new(
+            bytes,
+            index,
+            ((which).count_ones()) as usize,
+            "maps",
+        ))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " Pair each item of `maps` with the bit of `which` that it stands for, from the lowest bit up."]
+    #[inline]
+    pub fn maps_by_mask(&self) -> impl Iterator<Item = (u32, &IndicatorMap)> + '_ {
+        mask_bits(u32::from(self.which)).zip(self.maps.iter())
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("which", 8, 4),
+        ("real_indicators", 12, 4),
+        ("n_indicators", 16, 1),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.which == other.which
+            && self.real_indicators == other.real_indicators
+            && self.n_indicators == other.n_indicators
+            && self.maps == other.maps
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value("which", &self.which, &other.which, &mut changes);
+        diff_value(
+            "real_indicators",
+            &self.real_indicators,
+            &other.real_indicators,
+            &mut changes,
+        );
+        diff_value(
+            "n_indicators",
+            &self.n_indicators,
+            &other.n_indicators,
+            &mut changes,
+        );
+        diff_list("maps", &self.maps, &other.maps, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetIndicatorMapReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -9272,36 +12792,41 @@ impl AsByteSequence for GetIndicatorMapReply {
         index += self.which.as_bytes(&mut bytes[index..]);
         index += self.real_indicators.as_bytes(&mut bytes[index..]);
         index += self.n_indicators.as_bytes(&mut bytes[index..]);
-        index += 15;
+        index += zero_pad(&mut bytes[index..], 15);
         let block_len: usize = vector_as_bytes(&self.maps, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIndicatorMapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (which, sz): (Card32, usize) = parse_item(bytes, index, "which")?;
         index += sz;
-        let (real_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (real_indicators, sz): (Card32, usize) = parse_item(bytes, index, "real_indicators")?;
         index += sz;
-        let (n_indicators, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_indicators, sz): (Card8, usize) = parse_item(bytes, index, "n_indicators")?;
         index += sz;
         index += 15;
         let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], ((which).count_ones()) as usize)?;
+            parse_vector(bytes, index, ((which).count_ones()) as usize, "maps")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetIndicatorMapReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -9317,22 +12842,48 @@ impl AsByteSequence for GetIndicatorMapReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.which.size()
-            + self.real_indicators.size()
-            + self.n_indicators.size()
-            + 15
-            + {
-                let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let size: usize = 32 + {
+            let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetIndicatorMapReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for GetIndicatorMapReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let device_id: Card8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let which: Card32 = u.arbitrary()?;
+        let real_indicators: Card32 = u.arbitrary()?;
+        let n_indicators: Card8 = u.arbitrary()?;
+        let maps = arbitrary_list(u, ((which).count_ones()) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            device_id: device_id,
+            sequence: sequence,
+            length: length,
+            which: which,
+            real_indicators: real_indicators,
+            n_indicators: n_indicators,
+            maps: maps,
+        })
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetIndicatorMapRequest {
     pub req_type: u8,
     pub length: u16,
@@ -9340,41 +12891,78 @@ pub struct SetIndicatorMapRequest {
     pub which: Card32,
     pub maps: Vec<IndicatorMap>,
 }
-impl SetIndicatorMapRequest {}
+impl SetIndicatorMapRequest {
+    #[inline]
+    pub fn builder() -> SetIndicatorMapRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Pair each item of `maps` with the bit of `which` that it stands for, from the lowest bit up."]
+    #[inline]
+    pub fn maps_by_mask(&self) -> impl Iterator<Item = (u32, &IndicatorMap)> + '_ {
+        mask_bits(u32::from(self.which)).zip(self.maps.iter())
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("which", 8, 4),
+    ];
+    #[doc = " Check that the lists of this request have the lengths that its other fields describe."]
+    #[doc = " This is done before the request is sent."]
+    #[inline]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        {
+            let expected: usize = ((self.which).count_ones()) as usize;
+            if self.maps.len() != expected {
+                return Err(ValidationError {
+                    list: "maps",
+                    expected,
+                    found: self.maps.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+const _: () = assert!(<SetIndicatorMapRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for SetIndicatorMapRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.which.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.maps, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetIndicatorMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
         index += 2;
-        let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (which, sz): (Card32, usize) = parse_item(bytes, index, "which")?;
         index += sz;
         let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], ((which).count_ones()) as usize)?;
+            parse_vector(bytes, index, ((which).count_ones()) as usize, "maps")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetIndicatorMapRequest {
                 req_type: req_type,
                 length: length,
@@ -9387,26 +12975,96 @@ impl AsByteSequence for SetIndicatorMapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + 2
-            + self.which.size()
-            + {
-                let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-                block_len + pad
-            }
+        let size: usize = 12 + {
+            let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetIndicatorMapRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetIndicatorMapRequest {
-    const OPCODE: u8 = 14;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 14;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetIndicatorMap";
     type Reply = ();
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationError> {
+        Self::validate(self)
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for SetIndicatorMapRequest {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let req_type: u8 = u.arbitrary()?;
+        let length: u16 = u.arbitrary()?;
+        let device_spec: DeviceSpec = u.arbitrary()?;
+        let which: Card32 = u.arbitrary()?;
+        let maps = arbitrary_list(u, ((which).count_ones()) as usize)?;
+        Ok(Self {
+            req_type: req_type,
+            length: length,
+            device_spec: device_spec,
+            which: which,
+            maps: maps,
+        })
+    }
+}
+#[doc = " A builder for `SetIndicatorMapRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetIndicatorMapRequestBuilder {
+    pub inner: SetIndicatorMapRequest,
+}
+impl SetIndicatorMapRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn which(mut self, which: Card32) -> Self {
+        self.inner.which = which;
+        self
+    }
+    #[inline]
+    pub fn maps(mut self, maps: Vec<IndicatorMap>) -> Self {
+        self.inner.maps = maps;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetIndicatorMapRequest {
+        self.inner
+    }
+}
+impl Default for SetIndicatorMapRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetIndicatorMapRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                which: Default::default(),
+                maps: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetNamedIndicatorRequest {
     pub req_type: u8,
     pub length: u16,
@@ -9415,40 +13073,67 @@ pub struct GetNamedIndicatorRequest {
     pub led_id: IdSpec,
     pub indicator: Atom,
 }
-impl GetNamedIndicatorRequest {}
-impl AsByteSequence for GetNamedIndicatorRequest {
+impl GetNamedIndicatorRequest {
     #[inline]
+    pub fn builder() -> GetNamedIndicatorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetNamedIndicatorReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " Interpret `led_id` as a `Id`. Returns `None` if it holds a value that isn't one of the enumeration's."]
+    #[inline]
+    pub fn led_id_enum(&self) -> Option<Id> {
+        core::convert::TryFrom::try_from(self.led_id).ok()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("led_class", 6, 2),
+        ("led_id", 8, 2),
+    ];
+}
+const _: () = assert!(<GetNamedIndicatorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetNamedIndicatorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.led_class.as_bytes(&mut bytes[index..]);
         index += self.led_id.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.indicator.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetNamedIndicatorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = parse_item(bytes, index, "led_class")?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = parse_item(bytes, index, "led_id")?;
         index += sz;
         index += 2;
-        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (indicator, sz): (Atom, usize) = parse_item(bytes, index, "indicator")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetNamedIndicatorRequest {
                 req_type: req_type,
                 length: length,
@@ -9462,23 +13147,75 @@ impl AsByteSequence for GetNamedIndicatorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.led_class.size()
-            + self.led_id.size()
-            + 2
-            + self.indicator.size()
+        let size: usize = 12 + self.indicator.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetNamedIndicatorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetNamedIndicatorRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetNamedIndicator";
     type Reply = GetNamedIndicatorReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetNamedIndicatorRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetNamedIndicatorRequestBuilder {
+    pub inner: GetNamedIndicatorRequest,
+}
+impl GetNamedIndicatorRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn led_class(mut self, led_class: LedClass) -> Self {
+        self.inner.led_class = led_class;
+        self
+    }
+    #[inline]
+    pub fn led_id(mut self, led_id: IdSpec) -> Self {
+        self.inner.led_id = led_id;
+        self
+    }
+    #[inline]
+    pub fn indicator(mut self, indicator: Atom) -> Self {
+        self.inner.indicator = indicator;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetNamedIndicatorRequest {
+        self.inner
+    }
+}
+impl Default for GetNamedIndicatorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetNamedIndicatorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                led_class: Default::default(),
+                led_id: Default::default(),
+                indicator: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetNamedIndicatorReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -9499,9 +13236,93 @@ pub struct GetNamedIndicatorReply {
     pub map_ctrls: BoolCtrl,
     pub supported: bool,
 }
-impl GetNamedIndicatorReply {}
+impl GetNamedIndicatorReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.indicator == other.indicator
+            && self.found == other.found
+            && self.on == other.on
+            && self.real_indicator == other.real_indicator
+            && self.ndx == other.ndx
+            && self.map_flags == other.map_flags
+            && self.map_which_groups == other.map_which_groups
+            && self.map_groups == other.map_groups
+            && self.map_which_mods == other.map_which_mods
+            && self.map_mods == other.map_mods
+            && self.map_real_mods == other.map_real_mods
+            && self.map_vmod == other.map_vmod
+            && self.map_ctrls == other.map_ctrls
+            && self.supported == other.supported
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value("indicator", &self.indicator, &other.indicator, &mut changes);
+        diff_value("found", &self.found, &other.found, &mut changes);
+        diff_value("on", &self.on, &other.on, &mut changes);
+        diff_value(
+            "real_indicator",
+            &self.real_indicator,
+            &other.real_indicator,
+            &mut changes,
+        );
+        diff_value("ndx", &self.ndx, &other.ndx, &mut changes);
+        diff_value("map_flags", &self.map_flags, &other.map_flags, &mut changes);
+        diff_value(
+            "map_which_groups",
+            &self.map_which_groups,
+            &other.map_which_groups,
+            &mut changes,
+        );
+        diff_value(
+            "map_groups",
+            &self.map_groups,
+            &other.map_groups,
+            &mut changes,
+        );
+        diff_value(
+            "map_which_mods",
+            &self.map_which_mods,
+            &other.map_which_mods,
+            &mut changes,
+        );
+        diff_value("map_mods", &self.map_mods, &other.map_mods, &mut changes);
+        diff_value(
+            "map_real_mods",
+            &self.map_real_mods,
+            &other.map_real_mods,
+            &mut changes,
+        );
+        diff_value("map_vmod", &self.map_vmod, &other.map_vmod, &mut changes);
+        diff_value("map_ctrls", &self.map_ctrls, &other.map_ctrls, &mut changes);
+        diff_value("supported", &self.supported, &other.supported, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for GetNamedIndicatorReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -9522,53 +13343,55 @@ impl AsByteSequence for GetNamedIndicatorReply {
         index += self.map_vmod.as_bytes(&mut bytes[index..]);
         index += self.map_ctrls.as_bytes(&mut bytes[index..]);
         index += self.supported.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetNamedIndicatorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (indicator, sz): (Atom, usize) = parse_item(bytes, index, "indicator")?;
         index += sz;
-        let (found, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (found, sz): (bool, usize) = parse_item(bytes, index, "found")?;
         index += sz;
-        let (on, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (on, sz): (bool, usize) = parse_item(bytes, index, "on")?;
         index += sz;
-        let (real_indicator, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (real_indicator, sz): (bool, usize) = parse_item(bytes, index, "real_indicator")?;
         index += sz;
-        let (ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ndx, sz): (Card8, usize) = parse_item(bytes, index, "ndx")?;
         index += sz;
-        let (map_flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(&bytes[index..])?;
+        let (map_flags, sz): (ImFlag, usize) = parse_item(bytes, index, "map_flags")?;
         index += sz;
         let (map_which_groups, sz): (ImGroupsWhich, usize) =
-            <ImGroupsWhich>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "map_which_groups")?;
         index += sz;
-        let (map_groups, sz): (SetOfGroups, usize) = <SetOfGroups>::from_bytes(&bytes[index..])?;
+        let (map_groups, sz): (SetOfGroups, usize) = parse_item(bytes, index, "map_groups")?;
         index += sz;
         let (map_which_mods, sz): (ImModsWhich, usize) =
-            <ImModsWhich>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "map_which_mods")?;
         index += sz;
-        let (map_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (map_mods, sz): (ModMask, usize) = parse_item(bytes, index, "map_mods")?;
         index += sz;
-        let (map_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (map_real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "map_real_mods")?;
         index += sz;
-        let (map_vmod, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (map_vmod, sz): (VMod, usize) = parse_item(bytes, index, "map_vmod")?;
         index += sz;
-        let (map_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (map_ctrls, sz): (BoolCtrl, usize) = parse_item(bytes, index, "map_ctrls")?;
         index += sz;
-        let (supported, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (bool, usize) = parse_item(bytes, index, "supported")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetNamedIndicatorReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -9594,29 +13417,28 @@ impl AsByteSequence for GetNamedIndicatorReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
+        let size: usize = 22
             + self.indicator.size()
             + self.found.size()
             + self.on.size()
             + self.real_indicator.size()
-            + self.ndx.size()
-            + self.map_flags.size()
-            + self.map_which_groups.size()
-            + self.map_groups.size()
-            + self.map_which_mods.size()
             + self.map_mods.size()
             + self.map_real_mods.size()
-            + self.map_vmod.size()
-            + self.map_ctrls.size()
-            + self.supported.size()
-            + 3
+            + self.supported.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetNamedIndicatorReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetOfGroups {
     pub inner: u8,
 }
@@ -9649,21 +13471,20 @@ impl SetOfGroups {
     pub const ANY: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 128 };
 }
-impl AsByteSequence for SetOfGroups {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for SetOfGroups {
+    type Target = u8;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((SetOfGroups { inner: inner }, sz))
+    fn deref(&self) -> &u8 {
+        &self.inner
     }
+}
+impl AsRef<u8> for SetOfGroups {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u8 {
+        &self.inner
     }
 }
+impl_u8_flags_asb! { SetOfGroups }
 impl core::ops::Not for SetOfGroups {
     type Output = SetOfGroups;
     #[inline]
@@ -9698,7 +13519,10 @@ impl core::ops::BitXor for SetOfGroups {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetNamedIndicatorRequest {
     pub req_type: u8,
     pub length: u16,
@@ -9718,24 +13542,42 @@ pub struct SetNamedIndicatorRequest {
     pub map_vmods: VMod,
     pub map_ctrls: BoolCtrl,
 }
-impl SetNamedIndicatorRequest {}
-impl AsByteSequence for SetNamedIndicatorRequest {
+impl SetNamedIndicatorRequest {
     #[inline]
+    pub fn builder() -> SetNamedIndicatorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Interpret `led_id` as a `Id`. Returns `None` if it holds a value that isn't one of the enumeration's."]
+    #[inline]
+    pub fn led_id_enum(&self) -> Option<Id> {
+        core::convert::TryFrom::try_from(self.led_id).ok()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("led_class", 6, 2),
+        ("led_id", 8, 2),
+    ];
+}
+const _: () = assert!(<SetNamedIndicatorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetNamedIndicatorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.led_class.as_bytes(&mut bytes[index..]);
         index += self.led_id.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.indicator.as_bytes(&mut bytes[index..]);
         index += self.set_state.as_bytes(&mut bytes[index..]);
         index += self.on.as_bytes(&mut bytes[index..]);
         index += self.set_map.as_bytes(&mut bytes[index..]);
         index += self.create_map.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.map_flags.as_bytes(&mut bytes[index..]);
         index += self.map_which_groups.as_bytes(&mut bytes[index..]);
         index += self.map_groups.as_bytes(&mut bytes[index..]);
@@ -9743,52 +13585,53 @@ impl AsByteSequence for SetNamedIndicatorRequest {
         index += self.map_real_mods.as_bytes(&mut bytes[index..]);
         index += self.map_vmods.as_bytes(&mut bytes[index..]);
         index += self.map_ctrls.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetNamedIndicatorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = parse_item(bytes, index, "led_class")?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = parse_item(bytes, index, "led_id")?;
         index += sz;
         index += 2;
-        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (indicator, sz): (Atom, usize) = parse_item(bytes, index, "indicator")?;
         index += sz;
-        let (set_state, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (set_state, sz): (bool, usize) = parse_item(bytes, index, "set_state")?;
         index += sz;
-        let (on, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (on, sz): (bool, usize) = parse_item(bytes, index, "on")?;
         index += sz;
-        let (set_map, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (set_map, sz): (bool, usize) = parse_item(bytes, index, "set_map")?;
         index += sz;
-        let (create_map, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (create_map, sz): (bool, usize) = parse_item(bytes, index, "create_map")?;
         index += sz;
         index += 1;
-        let (map_flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(&bytes[index..])?;
+        let (map_flags, sz): (ImFlag, usize) = parse_item(bytes, index, "map_flags")?;
         index += sz;
         let (map_which_groups, sz): (ImGroupsWhich, usize) =
-            <ImGroupsWhich>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "map_which_groups")?;
         index += sz;
-        let (map_groups, sz): (SetOfGroups, usize) = <SetOfGroups>::from_bytes(&bytes[index..])?;
+        let (map_groups, sz): (SetOfGroups, usize) = parse_item(bytes, index, "map_groups")?;
         index += sz;
         let (map_which_mods, sz): (ImModsWhich, usize) =
-            <ImModsWhich>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "map_which_mods")?;
         index += sz;
-        let (map_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (map_real_mods, sz): (ModMask, usize) = parse_item(bytes, index, "map_real_mods")?;
         index += sz;
-        let (map_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (map_vmods, sz): (VMod, usize) = parse_item(bytes, index, "map_vmods")?;
         index += sz;
-        let (map_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (map_ctrls, sz): (BoolCtrl, usize) = parse_item(bytes, index, "map_ctrls")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetNamedIndicatorRequest {
                 req_type: req_type,
                 length: length,
@@ -9813,69 +13656,201 @@ impl AsByteSequence for SetNamedIndicatorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.led_class.size()
-            + self.led_id.size()
-            + 2
+        let size: usize = 23
             + self.indicator.size()
             + self.set_state.size()
             + self.on.size()
             + self.set_map.size()
             + self.create_map.size()
-            + 1
-            + self.map_flags.size()
-            + self.map_which_groups.size()
-            + self.map_groups.size()
-            + self.map_which_mods.size()
-            + self.map_real_mods.size()
-            + self.map_vmods.size()
-            + self.map_ctrls.size()
+            + self.map_real_mods.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetNamedIndicatorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetNamedIndicatorRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetNamedIndicator";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetNamesRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub device_spec: DeviceSpec,
-    pub which: NameDetail,
+#[doc = " A builder for `SetNamedIndicatorRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetNamedIndicatorRequestBuilder {
+    pub inner: SetNamedIndicatorRequest,
 }
-impl GetNamesRequest {}
-impl AsByteSequence for GetNamesRequest {
+impl SetNamedIndicatorRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index += self.which.as_bytes(&mut bytes[index..]);
-        index
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetNamesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+    pub fn led_class(mut self, led_class: LedClass) -> Self {
+        self.inner.led_class = led_class;
+        self
+    }
+    #[inline]
+    pub fn led_id(mut self, led_id: IdSpec) -> Self {
+        self.inner.led_id = led_id;
+        self
+    }
+    #[inline]
+    pub fn indicator(mut self, indicator: Atom) -> Self {
+        self.inner.indicator = indicator;
+        self
+    }
+    #[inline]
+    pub fn set_state(mut self, set_state: bool) -> Self {
+        self.inner.set_state = set_state;
+        self
+    }
+    #[inline]
+    pub fn on(mut self, on: bool) -> Self {
+        self.inner.on = on;
+        self
+    }
+    #[inline]
+    pub fn set_map(mut self, set_map: bool) -> Self {
+        self.inner.set_map = set_map;
+        self
+    }
+    #[inline]
+    pub fn create_map(mut self, create_map: bool) -> Self {
+        self.inner.create_map = create_map;
+        self
+    }
+    #[inline]
+    pub fn map_flags(mut self, map_flags: ImFlag) -> Self {
+        self.inner.map_flags = map_flags;
+        self
+    }
+    #[inline]
+    pub fn map_which_groups(mut self, map_which_groups: ImGroupsWhich) -> Self {
+        self.inner.map_which_groups = map_which_groups;
+        self
+    }
+    #[inline]
+    pub fn map_groups(mut self, map_groups: SetOfGroups) -> Self {
+        self.inner.map_groups = map_groups;
+        self
+    }
+    #[inline]
+    pub fn map_which_mods(mut self, map_which_mods: ImModsWhich) -> Self {
+        self.inner.map_which_mods = map_which_mods;
+        self
+    }
+    #[inline]
+    pub fn map_real_mods(mut self, map_real_mods: ModMask) -> Self {
+        self.inner.map_real_mods = map_real_mods;
+        self
+    }
+    #[inline]
+    pub fn map_vmods(mut self, map_vmods: VMod) -> Self {
+        self.inner.map_vmods = map_vmods;
+        self
+    }
+    #[inline]
+    pub fn map_ctrls(mut self, map_ctrls: BoolCtrl) -> Self {
+        self.inner.map_ctrls = map_ctrls;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetNamedIndicatorRequest {
+        self.inner
+    }
+}
+impl Default for SetNamedIndicatorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetNamedIndicatorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                led_class: Default::default(),
+                led_id: Default::default(),
+                indicator: Default::default(),
+                set_state: Default::default(),
+                on: Default::default(),
+                set_map: Default::default(),
+                create_map: Default::default(),
+                map_flags: Default::default(),
+                map_which_groups: Default::default(),
+                map_groups: Default::default(),
+                map_which_mods: Default::default(),
+                map_real_mods: Default::default(),
+                map_vmods: Default::default(),
+                map_ctrls: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct GetNamesRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub device_spec: DeviceSpec,
+    pub which: NameDetail,
+}
+impl GetNamesRequest {
+    #[inline]
+    pub fn builder() -> GetNamesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GetNamesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("which", 8, 4),
+    ];
+}
+const _: () = assert!(<GetNamesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetNamesRequest {
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.device_spec.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 2);
+        index += self.which.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+        index
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing GetNamesRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
         index += 2;
-        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let (which, sz): (NameDetail, usize) = parse_item(bytes, index, "which")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetNamesRequest {
                 req_type: req_type,
                 length: length,
@@ -9887,21 +13862,63 @@ impl AsByteSequence for GetNamesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + 2
-            + self.which.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetNamesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetNamesRequest {
-    const OPCODE: u8 = 17;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 17;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetNames";
     type Reply = GetNamesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetNamesRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetNamesRequestBuilder {
+    pub inner: GetNamesRequest,
+}
+impl GetNamesRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn which(mut self, which: NameDetail) -> Self {
+        self.inner.which = which;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetNamesRequest {
+        self.inner
+    }
+}
+impl Default for GetNamesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetNamesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                which: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetNamesReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -9916,12 +13933,12 @@ pub struct GetNamesReply {
     pub first_key: Keycode,
     pub indicators: Card32,
     pub n_kt_levels: Card16,
-    pub keycodes_name: Atom,
-    pub geometry_name: Atom,
-    pub symbols_name: Atom,
-    pub phys_symbols_name: Atom,
-    pub types_name: Atom,
-    pub compat_name: Atom,
+    pub keycodes_name: Option<Atom>,
+    pub geometry_name: Option<Atom>,
+    pub symbols_name: Option<Atom>,
+    pub phys_symbols_name: Option<Atom>,
+    pub types_name: Option<Atom>,
+    pub compat_name: Option<Atom>,
     pub type_names: Vec<Atom>,
     pub n_levels_per_type: Vec<Card8>,
     pub kt_level_names: Vec<Atom>,
@@ -9932,16 +13949,198 @@ pub struct GetNamesReply {
     pub key_aliases: Vec<KeyAlias>,
     pub radio_group_names: Vec<Atom>,
 }
-impl GetNamesReply {}
+impl GetNamesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("which", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.which == other.which
+            && self.min_key_code == other.min_key_code
+            && self.max_key_code == other.max_key_code
+            && self.n_types == other.n_types
+            && self.group_names == other.group_names
+            && self.virtual_mods == other.virtual_mods
+            && self.first_key == other.first_key
+            && self.indicators == other.indicators
+            && self.n_kt_levels == other.n_kt_levels
+            && self.keycodes_name == other.keycodes_name
+            && self.geometry_name == other.geometry_name
+            && self.symbols_name == other.symbols_name
+            && self.phys_symbols_name == other.phys_symbols_name
+            && self.types_name == other.types_name
+            && self.compat_name == other.compat_name
+            && self.type_names == other.type_names
+            && self.n_levels_per_type == other.n_levels_per_type
+            && self.kt_level_names == other.kt_level_names
+            && self.indicator_names == other.indicator_names
+            && self.virtual_mod_names == other.virtual_mod_names
+            && self.groups == other.groups
+            && self.key_names == other.key_names
+            && self.key_aliases == other.key_aliases
+            && self.radio_group_names == other.radio_group_names
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value("which", &self.which, &other.which, &mut changes);
+        diff_value(
+            "min_key_code",
+            &self.min_key_code,
+            &other.min_key_code,
+            &mut changes,
+        );
+        diff_value(
+            "max_key_code",
+            &self.max_key_code,
+            &other.max_key_code,
+            &mut changes,
+        );
+        diff_value("n_types", &self.n_types, &other.n_types, &mut changes);
+        diff_value(
+            "group_names",
+            &self.group_names,
+            &other.group_names,
+            &mut changes,
+        );
+        diff_value(
+            "virtual_mods",
+            &self.virtual_mods,
+            &other.virtual_mods,
+            &mut changes,
+        );
+        diff_value("first_key", &self.first_key, &other.first_key, &mut changes);
+        diff_value(
+            "indicators",
+            &self.indicators,
+            &other.indicators,
+            &mut changes,
+        );
+        diff_value(
+            "n_kt_levels",
+            &self.n_kt_levels,
+            &other.n_kt_levels,
+            &mut changes,
+        );
+        diff_value(
+            "keycodes_name",
+            &self.keycodes_name,
+            &other.keycodes_name,
+            &mut changes,
+        );
+        diff_value(
+            "geometry_name",
+            &self.geometry_name,
+            &other.geometry_name,
+            &mut changes,
+        );
+        diff_value(
+            "symbols_name",
+            &self.symbols_name,
+            &other.symbols_name,
+            &mut changes,
+        );
+        diff_value(
+            "phys_symbols_name",
+            &self.phys_symbols_name,
+            &other.phys_symbols_name,
+            &mut changes,
+        );
+        diff_value(
+            "types_name",
+            &self.types_name,
+            &other.types_name,
+            &mut changes,
+        );
+        diff_value(
+            "compat_name",
+            &self.compat_name,
+            &other.compat_name,
+            &mut changes,
+        );
+        diff_list(
+            "type_names",
+            &self.type_names,
+            &other.type_names,
+            &mut changes,
+        );
+        diff_list(
+            "n_levels_per_type",
+            &self.n_levels_per_type,
+            &other.n_levels_per_type,
+            &mut changes,
+        );
+        diff_list(
+            "kt_level_names",
+            &self.kt_level_names,
+            &other.kt_level_names,
+            &mut changes,
+        );
+        diff_list(
+            "indicator_names",
+            &self.indicator_names,
+            &other.indicator_names,
+            &mut changes,
+        );
+        diff_list(
+            "virtual_mod_names",
+            &self.virtual_mod_names,
+            &other.virtual_mod_names,
+            &mut changes,
+        );
+        diff_list("groups", &self.groups, &other.groups, &mut changes);
+        diff_list("key_names", &self.key_names, &other.key_names, &mut changes);
+        diff_list(
+            "key_aliases",
+            &self.key_aliases,
+            &other.key_aliases,
+            &mut changes,
+        );
+        diff_list(
+            "radio_group_names",
+            &self.radio_group_names,
+            &other.radio_group_names,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for GetNamesReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.which.as_bytes(&mut bytes[index..]);
+        let mut cond0: NameDetail = self.which;
+        cond0.set_keycodes(self.keycodes_name.is_some());
+        cond0.set_geometry(self.geometry_name.is_some());
+        cond0.set_symbols(self.symbols_name.is_some());
+        cond0.set_phys_symbols(self.phys_symbols_name.is_some());
+        cond0.set_types(self.types_name.is_some());
+        cond0.set_compat(self.compat_name.is_some());
+        index += cond0.as_bytes(&mut bytes[index..]);
         index += self.min_key_code.as_bytes(&mut bytes[index..]);
         index += self.max_key_code.as_bytes(&mut bytes[index..]);
         index += self.n_types.as_bytes(&mut bytes[index..]);
@@ -9953,8 +14152,7 @@ impl AsByteSequence for GetNamesReply {
         index += (self.radio_group_names.len() as Card8).as_bytes(&mut bytes[index..]);
         index += (self.key_aliases.len() as Card8).as_bytes(&mut bytes[index..]);
         index += self.n_kt_levels.as_bytes(&mut bytes[index..]);
-        index += 4;
-        let cond0 = (self.which);
+        index += zero_pad(&mut bytes[index..], 4);
         if cond0.keycodes() {
             index += self.keycodes_name.as_bytes(&mut bytes[index..]);
         }
@@ -9973,159 +14171,266 @@ impl AsByteSequence for GetNamesReply {
         if cond0.compat() {
             index += self.compat_name.as_bytes(&mut bytes[index..]);
         }
-        let block_len: usize = vector_as_bytes(&self.type_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.indicator_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.virtual_mod_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.groups, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.key_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-        let block_len: usize = vector_as_bytes(&self.key_aliases, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-        let block_len: usize = vector_as_bytes(&self.radio_group_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+        if cond0.key_type_names() {
+            let block_len: usize = vector_as_bytes(&self.type_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.kt_level_names() {
+            let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+            let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.indicator_names() {
+            let block_len: usize = vector_as_bytes(&self.indicator_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.virtual_mod_names() {
+            let block_len: usize = vector_as_bytes(&self.virtual_mod_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.group_names() {
+            let block_len: usize = vector_as_bytes(&self.groups, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.key_names() {
+            let block_len: usize = vector_as_bytes(&self.key_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyName>()),
+            );
+        }
+        if cond0.key_aliases() {
+            let block_len: usize = vector_as_bytes(&self.key_aliases, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>()),
+            );
+        }
+        if cond0.rg_names() {
+            let block_len: usize = vector_as_bytes(&self.radio_group_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetNamesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (which, sz): (NameDetail, usize) = parse_item(bytes, index, "which")?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "min_key_code")?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "max_key_code")?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = parse_item(bytes, index, "n_types")?;
         index += sz;
-        let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (group_names, sz): (SetOfGroup, usize) = parse_item(bytes, index, "group_names")?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
         index += sz;
-        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key, sz): (Keycode, usize) = parse_item(bytes, index, "first_key")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (indicators, sz): (Card32, usize) = parse_item(bytes, index, "indicators")?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (n_kt_levels, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_kt_levels, sz): (Card16, usize) = parse_item(bytes, index, "n_kt_levels")?;
         index += sz;
         index += 4;
         let cond0 = (which);
-        let keycodes_name: Atom = if cond0.keycodes() {
-            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (keycodes_name,) = if cond0.keycodes() {
+            let (keycodes_name, sz): (Atom, usize) = parse_item(bytes, index, "keycodes_name")?;
             index += sz;
-            keycodes_name
+            (Some(keycodes_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let geometry_name: Atom = if cond0.geometry() {
-            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (geometry_name,) = if cond0.geometry() {
+            let (geometry_name, sz): (Atom, usize) = parse_item(bytes, index, "geometry_name")?;
             index += sz;
-            geometry_name
+            (Some(geometry_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let symbols_name: Atom = if cond0.symbols() {
-            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (symbols_name,) = if cond0.symbols() {
+            let (symbols_name, sz): (Atom, usize) = parse_item(bytes, index, "symbols_name")?;
             index += sz;
-            symbols_name
+            (Some(symbols_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let phys_symbols_name: Atom = if cond0.phys_symbols() {
-            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (phys_symbols_name,) = if cond0.phys_symbols() {
+            let (phys_symbols_name, sz): (Atom, usize) =
+                parse_item(bytes, index, "phys_symbols_name")?;
             index += sz;
-            phys_symbols_name
+            (Some(phys_symbols_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let types_name: Atom = if cond0.types() {
-            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (types_name,) = if cond0.types() {
+            let (types_name, sz): (Atom, usize) = parse_item(bytes, index, "types_name")?;
             index += sz;
-            types_name
+            (Some(types_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let compat_name: Atom = if cond0.compat() {
-            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (compat_name,) = if cond0.compat() {
+            let (compat_name, sz): (Atom, usize) = parse_item(bytes, index, "compat_name")?;
             index += sz;
-            compat_name
+            (Some(compat_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let (type_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (n_levels_per_type, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let (kt_level_names, block_len): (Vec<Atom>, usize) = vector_from_bytes(
-            &bytes[index..],
-            (n_levels_per_type
-                .iter()
-                .map(|a| {
-                    (TryInto::<usize>::try_into(*a).expect("Unable to cast type to usize")) as usize
-                })
-                .sum::<usize>()) as usize,
-        )?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (indicator_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((indicators).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (virtual_mod_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (groups, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((group_names).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (key_names, block_len): (Vec<KeyName>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-        let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-        let (radio_group_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        Some((
+        let (type_names,) = if cond0.key_type_names() {
+            let (type_names, block_len): (Vec<Atom>, usize) =
+                parse_vector(bytes, index, (n_types as usize) as usize, "type_names")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (type_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (n_levels_per_type, kt_level_names) = if cond0.kt_level_names() {
+            let (n_levels_per_type, block_len): (Vec<Card8>, usize) = parse_vector(
+                bytes,
+                index,
+                (n_types as usize) as usize,
+                "n_levels_per_type",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            index += buffer_pad(index, 4);
+            let (kt_level_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                (n_levels_per_type
+                    .iter()
+                    .map(|a| {
+                        (TryInto::<usize>::try_into(*a).expect("Unable to cast type to usize"))
+                            as usize
+                    })
+                    .sum::<usize>()) as usize,
+                "kt_level_names",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (n_levels_per_type, kt_level_names)
+        } else {
+            (Default::default(), Default::default())
+        };
+        let (indicator_names,) = if cond0.indicator_names() {
+            let (indicator_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                ((indicators).count_ones()) as usize,
+                "indicator_names",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (indicator_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (virtual_mod_names,) = if cond0.virtual_mod_names() {
+            let (virtual_mod_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                ((virtual_mods).count_ones()) as usize,
+                "virtual_mod_names",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (virtual_mod_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (groups,) = if cond0.group_names() {
+            let (groups, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                ((group_names).count_ones()) as usize,
+                "groups",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (groups,)
+        } else {
+            (Default::default(),)
+        };
+        let (key_names,) = if cond0.key_names() {
+            let (key_names, block_len): (Vec<KeyName>, usize) =
+                parse_vector(bytes, index, len0 as usize, "key_names")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            (key_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (key_aliases,) = if cond0.key_aliases() {
+            let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
+                parse_vector(bytes, index, len2 as usize, "key_aliases")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            (key_aliases,)
+        } else {
+            (Default::default(),)
+        };
+        let (radio_group_names,) = if cond0.rg_names() {
+            let (radio_group_names, block_len): (Vec<Atom>, usize) =
+                parse_vector(bytes, index, len1 as usize, "radio_group_names")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (radio_group_names,)
+        } else {
+            (Default::default(),)
+        };
+        index += buffer_pad(index, 4);
+        Ok((
             GetNamesReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -10159,80 +14464,165 @@ impl AsByteSequence for GetNamesReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.which.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.n_types.size()
-            + self.group_names.size()
-            + self.virtual_mods.size()
-            + self.first_key.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.indicators.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + self.n_kt_levels.size()
-            + 4
-            + self.keycodes_name.size()
-            + self.geometry_name.size()
-            + self.symbols_name.size()
-            + self.phys_symbols_name.size()
-            + self.types_name.size()
-            + self.compat_name.size()
-            + {
-                let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
+        let size: usize = ({
+            let size: usize = 29
+                + self.min_key_code.size()
+                + self.max_key_code.size()
+                + self.first_key.size()
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = ({
+            let size: usize = 29
+                + self.min_key_code.size()
+                + self.max_key_code.size()
+                + self.first_key.size()
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize =
+                        self.n_levels_per_type.len() * ::core::mem::size_of::<Card8>();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetNamesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for GetNamesReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetNamesReply")
+            .field("reply_type", &self.reply_type)
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("which", &self.which)
+            .field("min_key_code", &self.min_key_code)
+            .field("max_key_code", &self.max_key_code)
+            .field("n_types", &self.n_types)
+            .field("group_names", &self.group_names)
+            .field("virtual_mods", &self.virtual_mods)
+            .field("first_key", &self.first_key)
+            .field("indicators", &self.indicators)
+            .field("n_kt_levels", &self.n_kt_levels)
+            .field("keycodes_name", &self.keycodes_name)
+            .field("geometry_name", &self.geometry_name)
+            .field("symbols_name", &self.symbols_name)
+            .field("phys_symbols_name", &self.phys_symbols_name)
+            .field("types_name", &self.types_name)
+            .field("compat_name", &self.compat_name)
+            .field("type_names", &self.type_names)
+            .field(
+                "n_levels_per_type",
+                &debug_bytes(&self.n_levels_per_type, 64),
+            )
+            .field("kt_level_names", &self.kt_level_names)
+            .field("indicator_names", &self.indicator_names)
+            .field("virtual_mod_names", &self.virtual_mod_names)
+            .field("groups", &self.groups)
+            .field("key_names", &self.key_names)
+            .field("key_aliases", &self.key_aliases)
+            .field("radio_group_names", &self.radio_group_names)
+            .finish()
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct NameDetail {
     pub inner: u32,
 }
@@ -10419,7 +14809,6 @@ impl NameDetail {
         }
         self
     }
-    #[inline]
     pub fn new(
         keycodes: bool,
         geometry: bool,
@@ -10501,21 +14890,20 @@ impl NameDetail {
     pub const RG_NAMES: Self = Self { inner: 8192 };
     pub const COMPLETE: Self = Self { inner: 16383 };
 }
-impl AsByteSequence for NameDetail {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for NameDetail {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((NameDetail { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for NameDetail {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+impl_u32_flags_asb! { NameDetail }
 impl core::ops::Not for NameDetail {
     type Output = NameDetail;
     #[inline]
@@ -10550,7 +14938,10 @@ impl core::ops::BitXor for NameDetail {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetNamesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -10565,12 +14956,12 @@ pub struct SetNamesRequest {
     pub group_names: SetOfGroup,
     pub first_key: Keycode,
     pub total_kt_level_names: Card16,
-    pub keycodes_name: Atom,
-    pub geometry_name: Atom,
-    pub symbols_name: Atom,
-    pub phys_symbols_name: Atom,
-    pub types_name: Atom,
-    pub compat_name: Atom,
+    pub keycodes_name: Option<Atom>,
+    pub geometry_name: Option<Atom>,
+    pub symbols_name: Option<Atom>,
+    pub phys_symbols_name: Option<Atom>,
+    pub types_name: Option<Atom>,
+    pub compat_name: Option<Atom>,
     pub type_names: Vec<Atom>,
     pub n_levels_per_type: Vec<Card8>,
     pub kt_level_names: Vec<Atom>,
@@ -10581,17 +14972,128 @@ pub struct SetNamesRequest {
     pub key_aliases: Vec<KeyAlias>,
     pub radio_group_names: Vec<Atom>,
 }
-impl SetNamesRequest {}
-impl AsByteSequence for SetNamesRequest {
+impl SetNamesRequest {
+    #[inline]
+    pub fn builder() -> SetNamesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("virtual_mods", 6, 2),
+        ("which", 8, 4),
+        ("first_type", 12, 1),
+        ("n_types", 13, 1),
+        ("first_kt_levelt", 14, 1),
+        ("n_kt_levels", 15, 1),
+        ("indicators", 16, 4),
+        ("group_names", 20, 1),
+        ("radio_group_names_len", 21, 1),
+    ];
+    #[doc = " Set `keycodes_name`, and set the bit of `which` that says it is present."]
+    #[inline]
+    pub fn set_keycodes_name(&mut self, keycodes_name: Atom) -> &mut Self {
+        self.which.set_keycodes(true);
+        self.keycodes_name = Some(keycodes_name);
+        self
+    }
+    #[doc = " Leave out `keycodes_name`, and clear the bit of `which` that says it is present."]
+    #[inline]
+    pub fn clear_keycodes_name(&mut self) -> &mut Self {
+        self.which.set_keycodes(false);
+        self.keycodes_name = None;
+        self
+    }
+    #[doc = " Set `geometry_name`, and set the bit of `which` that says it is present."]
+    #[inline]
+    pub fn set_geometry_name(&mut self, geometry_name: Atom) -> &mut Self {
+        self.which.set_geometry(true);
+        self.geometry_name = Some(geometry_name);
+        self
+    }
+    #[doc = " Leave out `geometry_name`, and clear the bit of `which` that says it is present."]
+    #[inline]
+    pub fn clear_geometry_name(&mut self) -> &mut Self {
+        self.which.set_geometry(false);
+        self.geometry_name = None;
+        self
+    }
+    #[doc = " Set `symbols_name`, and set the bit of `which` that says it is present."]
+    #[inline]
+    pub fn set_symbols_name(&mut self, symbols_name: Atom) -> &mut Self {
+        self.which.set_symbols(true);
+        self.symbols_name = Some(symbols_name);
+        self
+    }
+    #[doc = " Leave out `symbols_name`, and clear the bit of `which` that says it is present."]
+    #[inline]
+    pub fn clear_symbols_name(&mut self) -> &mut Self {
+        self.which.set_symbols(false);
+        self.symbols_name = None;
+        self
+    }
+    #[doc = " Set `phys_symbols_name`, and set the bit of `which` that says it is present."]
+    #[inline]
+    pub fn set_phys_symbols_name(&mut self, phys_symbols_name: Atom) -> &mut Self {
+        self.which.set_phys_symbols(true);
+        self.phys_symbols_name = Some(phys_symbols_name);
+        self
+    }
+    #[doc = " Leave out `phys_symbols_name`, and clear the bit of `which` that says it is present."]
+    #[inline]
+    pub fn clear_phys_symbols_name(&mut self) -> &mut Self {
+        self.which.set_phys_symbols(false);
+        self.phys_symbols_name = None;
+        self
+    }
+    #[doc = " Set `types_name`, and set the bit of `which` that says it is present."]
+    #[inline]
+    pub fn set_types_name(&mut self, types_name: Atom) -> &mut Self {
+        self.which.set_types(true);
+        self.types_name = Some(types_name);
+        self
+    }
+    #[doc = " Leave out `types_name`, and clear the bit of `which` that says it is present."]
     #[inline]
+    pub fn clear_types_name(&mut self) -> &mut Self {
+        self.which.set_types(false);
+        self.types_name = None;
+        self
+    }
+    #[doc = " Set `compat_name`, and set the bit of `which` that says it is present."]
+    #[inline]
+    pub fn set_compat_name(&mut self, compat_name: Atom) -> &mut Self {
+        self.which.set_compat(true);
+        self.compat_name = Some(compat_name);
+        self
+    }
+    #[doc = " Leave out `compat_name`, and clear the bit of `which` that says it is present."]
+    #[inline]
+    pub fn clear_compat_name(&mut self) -> &mut Self {
+        self.which.set_compat(false);
+        self.compat_name = None;
+        self
+    }
+}
+const _: () = assert!(<SetNamesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetNamesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.virtual_mods.as_bytes(&mut bytes[index..]);
-        index += self.which.as_bytes(&mut bytes[index..]);
+        let mut cond0: NameDetail = self.which;
+        cond0.set_keycodes(self.keycodes_name.is_some());
+        cond0.set_geometry(self.geometry_name.is_some());
+        cond0.set_symbols(self.symbols_name.is_some());
+        cond0.set_phys_symbols(self.phys_symbols_name.is_some());
+        cond0.set_types(self.types_name.is_some());
+        cond0.set_compat(self.compat_name.is_some());
+        index += cond0.as_bytes(&mut bytes[index..]);
         index += self.first_type.as_bytes(&mut bytes[index..]);
         index += self.n_types.as_bytes(&mut bytes[index..]);
         index += self.first_kt_levelt.as_bytes(&mut bytes[index..]);
@@ -10602,9 +15104,8 @@ impl AsByteSequence for SetNamesRequest {
         index += self.first_key.as_bytes(&mut bytes[index..]);
         index += (self.key_names.len() as Card8).as_bytes(&mut bytes[index..]);
         index += (self.key_aliases.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.total_kt_level_names.as_bytes(&mut bytes[index..]);
-        let cond0 = (self.which);
         if cond0.keycodes() {
             index += self.keycodes_name.as_bytes(&mut bytes[index..]);
         }
@@ -10623,162 +15124,267 @@ impl AsByteSequence for SetNamesRequest {
         if cond0.compat() {
             index += self.compat_name.as_bytes(&mut bytes[index..]);
         }
-        let block_len: usize = vector_as_bytes(&self.type_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.indicator_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.virtual_mod_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.groups, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.key_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-        let block_len: usize = vector_as_bytes(&self.key_aliases, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-        let block_len: usize = vector_as_bytes(&self.radio_group_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+        if cond0.key_type_names() {
+            let block_len: usize = vector_as_bytes(&self.type_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.kt_level_names() {
+            let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+            );
+            index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+            let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.indicator_names() {
+            let block_len: usize = vector_as_bytes(&self.indicator_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.virtual_mod_names() {
+            let block_len: usize = vector_as_bytes(&self.virtual_mod_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.group_names() {
+            let block_len: usize = vector_as_bytes(&self.groups, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        if cond0.key_names() {
+            let block_len: usize = vector_as_bytes(&self.key_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyName>()),
+            );
+        }
+        if cond0.key_aliases() {
+            let block_len: usize = vector_as_bytes(&self.key_aliases, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>()),
+            );
+        }
+        if cond0.rg_names() {
+            let block_len: usize = vector_as_bytes(&self.radio_group_names, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+            );
+        }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetNamesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
         index += sz;
-        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let (which, sz): (NameDetail, usize) = parse_item(bytes, index, "which")?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = parse_item(bytes, index, "first_type")?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = parse_item(bytes, index, "n_types")?;
         index += sz;
-        let (first_kt_levelt, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_kt_levelt, sz): (Card8, usize) = parse_item(bytes, index, "first_kt_levelt")?;
         index += sz;
-        let (n_kt_levels, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_kt_levels, sz): (Card8, usize) = parse_item(bytes, index, "n_kt_levels")?;
         index += sz;
-        let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (indicators, sz): (Card32, usize) = parse_item(bytes, index, "indicators")?;
         index += sz;
-        let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (group_names, sz): (SetOfGroup, usize) = parse_item(bytes, index, "group_names")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key, sz): (Keycode, usize) = parse_item(bytes, index, "first_key")?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
         index += 1;
-        let (total_kt_level_names, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_kt_level_names, sz): (Card16, usize) =
+            parse_item(bytes, index, "total_kt_level_names")?;
         index += sz;
         let cond0 = (which);
-        let keycodes_name: Atom = if cond0.keycodes() {
-            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (keycodes_name,) = if cond0.keycodes() {
+            let (keycodes_name, sz): (Atom, usize) = parse_item(bytes, index, "keycodes_name")?;
             index += sz;
-            keycodes_name
+            (Some(keycodes_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let geometry_name: Atom = if cond0.geometry() {
-            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (geometry_name,) = if cond0.geometry() {
+            let (geometry_name, sz): (Atom, usize) = parse_item(bytes, index, "geometry_name")?;
             index += sz;
-            geometry_name
+            (Some(geometry_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let symbols_name: Atom = if cond0.symbols() {
-            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (symbols_name,) = if cond0.symbols() {
+            let (symbols_name, sz): (Atom, usize) = parse_item(bytes, index, "symbols_name")?;
             index += sz;
-            symbols_name
+            (Some(symbols_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let phys_symbols_name: Atom = if cond0.phys_symbols() {
-            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (phys_symbols_name,) = if cond0.phys_symbols() {
+            let (phys_symbols_name, sz): (Atom, usize) =
+                parse_item(bytes, index, "phys_symbols_name")?;
             index += sz;
-            phys_symbols_name
+            (Some(phys_symbols_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let types_name: Atom = if cond0.types() {
-            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (types_name,) = if cond0.types() {
+            let (types_name, sz): (Atom, usize) = parse_item(bytes, index, "types_name")?;
             index += sz;
-            types_name
+            (Some(types_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let compat_name: Atom = if cond0.compat() {
-            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (compat_name,) = if cond0.compat() {
+            let (compat_name, sz): (Atom, usize) = parse_item(bytes, index, "compat_name")?;
             index += sz;
-            compat_name
+            (Some(compat_name),)
         } else {
-            Default::default()
+            (Default::default(),)
         };
-        let (type_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (n_levels_per_type, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let (kt_level_names, block_len): (Vec<Atom>, usize) = vector_from_bytes(
-            &bytes[index..],
-            (n_levels_per_type
-                .iter()
-                .map(|a| {
-                    (TryInto::<usize>::try_into(*a).expect("Unable to cast type to usize")) as usize
-                })
-                .sum::<usize>()) as usize,
-        )?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (indicator_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((indicators).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (virtual_mod_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (groups, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((group_names).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (key_names, block_len): (Vec<KeyName>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-        let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-        let (radio_group_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        Some((
+        let (type_names,) = if cond0.key_type_names() {
+            let (type_names, block_len): (Vec<Atom>, usize) =
+                parse_vector(bytes, index, (n_types as usize) as usize, "type_names")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (type_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (n_levels_per_type, kt_level_names) = if cond0.kt_level_names() {
+            let (n_levels_per_type, block_len): (Vec<Card8>, usize) = parse_vector(
+                bytes,
+                index,
+                (n_types as usize) as usize,
+                "n_levels_per_type",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            index += buffer_pad(index, 4);
+            let (kt_level_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                (n_levels_per_type
+                    .iter()
+                    .map(|a| {
+                        (TryInto::<usize>::try_into(*a).expect("Unable to cast type to usize"))
+                            as usize
+                    })
+                    .sum::<usize>()) as usize,
+                "kt_level_names",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (n_levels_per_type, kt_level_names)
+        } else {
+            (Default::default(), Default::default())
+        };
+        let (indicator_names,) = if cond0.indicator_names() {
+            let (indicator_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                ((indicators).count_ones()) as usize,
+                "indicator_names",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (indicator_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (virtual_mod_names,) = if cond0.virtual_mod_names() {
+            let (virtual_mod_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                ((virtual_mods).count_ones()) as usize,
+                "virtual_mod_names",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (virtual_mod_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (groups,) = if cond0.group_names() {
+            let (groups, block_len): (Vec<Atom>, usize) = parse_vector(
+                bytes,
+                index,
+                ((group_names).count_ones()) as usize,
+                "groups",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (groups,)
+        } else {
+            (Default::default(),)
+        };
+        let (key_names,) = if cond0.key_names() {
+            let (key_names, block_len): (Vec<KeyName>, usize) =
+                parse_vector(bytes, index, len1 as usize, "key_names")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            (key_names,)
+        } else {
+            (Default::default(),)
+        };
+        let (key_aliases,) = if cond0.key_aliases() {
+            let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
+                parse_vector(bytes, index, len2 as usize, "key_aliases")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            (key_aliases,)
+        } else {
+            (Default::default(),)
+        };
+        let (radio_group_names,) = if cond0.rg_names() {
+            let (radio_group_names, block_len): (Vec<Atom>, usize) =
+                parse_vector(bytes, index, len0 as usize, "radio_group_names")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            (radio_group_names,)
+        } else {
+            (Default::default(),)
+        };
+        index += buffer_pad(index, 4);
+        Ok((
             SetNamesRequest {
                 req_type: req_type,
                 length: length,
@@ -10812,87 +15418,363 @@ impl AsByteSequence for SetNamesRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.virtual_mods.size()
-            + self.which.size()
-            + self.first_type.size()
-            + self.n_types.size()
-            + self.first_kt_levelt.size()
-            + self.n_kt_levels.size()
-            + self.indicators.size()
-            + self.group_names.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + 1
-            + self.total_kt_level_names.size()
-            + self.keycodes_name.size()
-            + self.geometry_name.size()
-            + self.symbols_name.size()
-            + self.phys_symbols_name.size()
-            + self.types_name.size()
-            + self.compat_name.size()
-            + {
-                let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
-                let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
+        let size: usize = ({
+            let size: usize = 27
+                + self.first_key.size()
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = ({
+            let size: usize = 27
+                + self.first_key.size()
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize =
+                        self.n_levels_per_type.len() * ::core::mem::size_of::<Card8>();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetNamesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetNamesRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetNames";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for SetNamesRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SetNamesRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("device_spec", &self.device_spec)
+            .field("virtual_mods", &self.virtual_mods)
+            .field("which", &self.which)
+            .field("first_type", &self.first_type)
+            .field("n_types", &self.n_types)
+            .field("first_kt_levelt", &self.first_kt_levelt)
+            .field("n_kt_levels", &self.n_kt_levels)
+            .field("indicators", &self.indicators)
+            .field("group_names", &self.group_names)
+            .field("first_key", &self.first_key)
+            .field("total_kt_level_names", &self.total_kt_level_names)
+            .field("keycodes_name", &self.keycodes_name)
+            .field("geometry_name", &self.geometry_name)
+            .field("symbols_name", &self.symbols_name)
+            .field("phys_symbols_name", &self.phys_symbols_name)
+            .field("types_name", &self.types_name)
+            .field("compat_name", &self.compat_name)
+            .field("type_names", &self.type_names)
+            .field(
+                "n_levels_per_type",
+                &debug_bytes(&self.n_levels_per_type, 64),
+            )
+            .field("kt_level_names", &self.kt_level_names)
+            .field("indicator_names", &self.indicator_names)
+            .field("virtual_mod_names", &self.virtual_mod_names)
+            .field("groups", &self.groups)
+            .field("key_names", &self.key_names)
+            .field("key_aliases", &self.key_aliases)
+            .field("radio_group_names", &self.radio_group_names)
+            .finish()
+    }
+}
+#[doc = " A builder for `SetNamesRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetNamesRequestBuilder {
+    pub inner: SetNamesRequest,
+}
+impl SetNamesRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn virtual_mods(mut self, virtual_mods: VMod) -> Self {
+        self.inner.virtual_mods = virtual_mods;
+        self
+    }
+    #[inline]
+    pub fn which(mut self, which: NameDetail) -> Self {
+        self.inner.which = which;
+        self
+    }
+    #[inline]
+    pub fn first_type(mut self, first_type: Card8) -> Self {
+        self.inner.first_type = first_type;
+        self
+    }
+    #[inline]
+    pub fn n_types(mut self, n_types: Card8) -> Self {
+        self.inner.n_types = n_types;
+        self
+    }
+    #[inline]
+    pub fn first_kt_levelt(mut self, first_kt_levelt: Card8) -> Self {
+        self.inner.first_kt_levelt = first_kt_levelt;
+        self
+    }
+    #[inline]
+    pub fn n_kt_levels(mut self, n_kt_levels: Card8) -> Self {
+        self.inner.n_kt_levels = n_kt_levels;
+        self
+    }
+    #[inline]
+    pub fn indicators(mut self, indicators: Card32) -> Self {
+        self.inner.indicators = indicators;
+        self
+    }
+    #[inline]
+    pub fn group_names(mut self, group_names: SetOfGroup) -> Self {
+        self.inner.group_names = group_names;
+        self
+    }
+    #[inline]
+    pub fn first_key(mut self, first_key: Keycode) -> Self {
+        self.inner.first_key = first_key;
+        self
+    }
+    #[inline]
+    pub fn total_kt_level_names(mut self, total_kt_level_names: Card16) -> Self {
+        self.inner.total_kt_level_names = total_kt_level_names;
+        self
+    }
+    #[inline]
+    pub fn keycodes_name(mut self, keycodes_name: Atom) -> Self {
+        self.inner.which.set_keycodes(true);
+        self.inner.keycodes_name = Some(keycodes_name);
+        self
+    }
+    #[inline]
+    pub fn geometry_name(mut self, geometry_name: Atom) -> Self {
+        self.inner.which.set_geometry(true);
+        self.inner.geometry_name = Some(geometry_name);
+        self
+    }
+    #[inline]
+    pub fn symbols_name(mut self, symbols_name: Atom) -> Self {
+        self.inner.which.set_symbols(true);
+        self.inner.symbols_name = Some(symbols_name);
+        self
+    }
+    #[inline]
+    pub fn phys_symbols_name(mut self, phys_symbols_name: Atom) -> Self {
+        self.inner.which.set_phys_symbols(true);
+        self.inner.phys_symbols_name = Some(phys_symbols_name);
+        self
+    }
+    #[inline]
+    pub fn types_name(mut self, types_name: Atom) -> Self {
+        self.inner.which.set_types(true);
+        self.inner.types_name = Some(types_name);
+        self
+    }
+    #[inline]
+    pub fn compat_name(mut self, compat_name: Atom) -> Self {
+        self.inner.which.set_compat(true);
+        self.inner.compat_name = Some(compat_name);
+        self
+    }
+    #[inline]
+    pub fn type_names(mut self, type_names: Vec<Atom>) -> Self {
+        self.inner.which.set_key_type_names(true);
+        self.inner.type_names = type_names;
+        self
+    }
+    #[inline]
+    pub fn n_levels_per_type(mut self, n_levels_per_type: Vec<Card8>) -> Self {
+        self.inner.which.set_kt_level_names(true);
+        self.inner.n_levels_per_type = n_levels_per_type;
+        self
+    }
+    #[inline]
+    pub fn kt_level_names(mut self, kt_level_names: Vec<Atom>) -> Self {
+        self.inner.which.set_kt_level_names(true);
+        self.inner.kt_level_names = kt_level_names;
+        self
+    }
+    #[inline]
+    pub fn indicator_names(mut self, indicator_names: Vec<Atom>) -> Self {
+        self.inner.which.set_indicator_names(true);
+        self.inner.indicator_names = indicator_names;
+        self
+    }
+    #[inline]
+    pub fn virtual_mod_names(mut self, virtual_mod_names: Vec<Atom>) -> Self {
+        self.inner.which.set_virtual_mod_names(true);
+        self.inner.virtual_mod_names = virtual_mod_names;
+        self
+    }
+    #[inline]
+    pub fn groups(mut self, groups: Vec<Atom>) -> Self {
+        self.inner.which.set_group_names(true);
+        self.inner.groups = groups;
+        self
+    }
+    #[inline]
+    pub fn key_names(mut self, key_names: Vec<KeyName>) -> Self {
+        self.inner.which.set_key_names(true);
+        self.inner.key_names = key_names;
+        self
+    }
+    #[inline]
+    pub fn key_aliases(mut self, key_aliases: Vec<KeyAlias>) -> Self {
+        self.inner.which.set_key_aliases(true);
+        self.inner.key_aliases = key_aliases;
+        self
+    }
+    #[inline]
+    pub fn radio_group_names(mut self, radio_group_names: Vec<Atom>) -> Self {
+        self.inner.which.set_rg_names(true);
+        self.inner.radio_group_names = radio_group_names;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetNamesRequest {
+        self.inner
+    }
+}
+impl Default for SetNamesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetNamesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                virtual_mods: Default::default(),
+                which: Default::default(),
+                first_type: Default::default(),
+                n_types: Default::default(),
+                first_kt_levelt: Default::default(),
+                n_kt_levels: Default::default(),
+                indicators: Default::default(),
+                group_names: Default::default(),
+                first_key: Default::default(),
+                total_kt_level_names: Default::default(),
+                keycodes_name: Default::default(),
+                geometry_name: Default::default(),
+                symbols_name: Default::default(),
+                phys_symbols_name: Default::default(),
+                types_name: Default::default(),
+                compat_name: Default::default(),
+                type_names: Default::default(),
+                n_levels_per_type: Default::default(),
+                kt_level_names: Default::default(),
+                indicator_names: Default::default(),
+                virtual_mod_names: Default::default(),
+                groups: Default::default(),
+                key_names: Default::default(),
+                key_aliases: Default::default(),
+                radio_group_names: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PerClientFlagsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -10903,46 +15785,72 @@ pub struct PerClientFlagsRequest {
     pub auto_ctrls: BoolCtrl,
     pub auto_ctrls_values: BoolCtrl,
 }
-impl PerClientFlagsRequest {}
-impl AsByteSequence for PerClientFlagsRequest {
+impl PerClientFlagsRequest {
+    #[inline]
+    pub fn builder() -> PerClientFlagsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PerClientFlagsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("change", 8, 4),
+        ("value", 12, 4),
+        ("ctrls_to_change", 16, 4),
+        ("auto_ctrls", 20, 4),
+        ("auto_ctrls_values", 24, 4),
+    ];
+}
+const _: () = assert!(<PerClientFlagsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PerClientFlagsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.change.as_bytes(&mut bytes[index..]);
         index += self.value.as_bytes(&mut bytes[index..]);
         index += self.ctrls_to_change.as_bytes(&mut bytes[index..]);
         index += self.auto_ctrls.as_bytes(&mut bytes[index..]);
         index += self.auto_ctrls_values.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PerClientFlagsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
         index += 2;
-        let (change, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let (change, sz): (PerClientFlag, usize) = parse_item(bytes, index, "change")?;
         index += sz;
-        let (value, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let (value, sz): (PerClientFlag, usize) = parse_item(bytes, index, "value")?;
         index += sz;
-        let (ctrls_to_change, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (ctrls_to_change, sz): (BoolCtrl, usize) = parse_item(bytes, index, "ctrls_to_change")?;
         index += sz;
-        let (auto_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls, sz): (BoolCtrl, usize) = parse_item(bytes, index, "auto_ctrls")?;
         index += sz;
-        let (auto_ctrls_values, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls_values, sz): (BoolCtrl, usize) =
+            parse_item(bytes, index, "auto_ctrls_values")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PerClientFlagsRequest {
                 req_type: req_type,
                 length: length,
@@ -10958,25 +15866,87 @@ impl AsByteSequence for PerClientFlagsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + 2
-            + self.change.size()
-            + self.value.size()
-            + self.ctrls_to_change.size()
-            + self.auto_ctrls.size()
-            + self.auto_ctrls_values.size()
+        let size: usize = 28;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PerClientFlagsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PerClientFlagsRequest {
-    const OPCODE: u8 = 21;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 21;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PerClientFlags";
     type Reply = PerClientFlagsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PerClientFlagsRequest`."]
+#[derive(Clone, Debug)]
+pub struct PerClientFlagsRequestBuilder {
+    pub inner: PerClientFlagsRequest,
+}
+impl PerClientFlagsRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn change(mut self, change: PerClientFlag) -> Self {
+        self.inner.change = change;
+        self
+    }
+    #[inline]
+    pub fn value(mut self, value: PerClientFlag) -> Self {
+        self.inner.value = value;
+        self
+    }
+    #[inline]
+    pub fn ctrls_to_change(mut self, ctrls_to_change: BoolCtrl) -> Self {
+        self.inner.ctrls_to_change = ctrls_to_change;
+        self
+    }
+    #[inline]
+    pub fn auto_ctrls(mut self, auto_ctrls: BoolCtrl) -> Self {
+        self.inner.auto_ctrls = auto_ctrls;
+        self
+    }
+    #[inline]
+    pub fn auto_ctrls_values(mut self, auto_ctrls_values: BoolCtrl) -> Self {
+        self.inner.auto_ctrls_values = auto_ctrls_values;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PerClientFlagsRequest {
+        self.inner
+    }
+}
+impl Default for PerClientFlagsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PerClientFlagsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                change: Default::default(),
+                value: Default::default(),
+                ctrls_to_change: Default::default(),
+                auto_ctrls: Default::default(),
+                auto_ctrls_values: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PerClientFlagsReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -10987,9 +15957,87 @@ pub struct PerClientFlagsReply {
     pub auto_ctrls: BoolCtrl,
     pub auto_ctrls_values: BoolCtrl,
 }
-impl PerClientFlagsReply {}
-impl AsByteSequence for PerClientFlagsReply {
+impl PerClientFlagsReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        let device_id: Card8 = read_item(reader, &mut index, 1, "device_id")?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let supported: PerClientFlag = read_item(reader, &mut index, 4, "supported")?;
+        let value: PerClientFlag = read_item(reader, &mut index, 4, "value")?;
+        let auto_ctrls: BoolCtrl = read_item(reader, &mut index, 4, "auto_ctrls")?;
+        let auto_ctrls_values: BoolCtrl = read_item(reader, &mut index, 4, "auto_ctrls_values")?;
+        skip_bytes(reader, &mut index, 8)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PerClientFlagsReply {
+            reply_type: reply_type,
+            device_id: device_id,
+            sequence: sequence,
+            length: length,
+            supported: supported,
+            value: value,
+            auto_ctrls: auto_ctrls,
+            auto_ctrls_values: auto_ctrls_values,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("supported", 8, 4),
+        ("value", 12, 4),
+        ("auto_ctrls", 16, 4),
+        ("auto_ctrls_values", 20, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.supported == other.supported
+            && self.value == other.value
+            && self.auto_ctrls == other.auto_ctrls
+            && self.auto_ctrls_values == other.auto_ctrls_values
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value("supported", &self.supported, &other.supported, &mut changes);
+        diff_value("value", &self.value, &other.value, &mut changes);
+        diff_value(
+            "auto_ctrls",
+            &self.auto_ctrls,
+            &other.auto_ctrls,
+            &mut changes,
+        );
+        diff_value(
+            "auto_ctrls_values",
+            &self.auto_ctrls_values,
+            &other.auto_ctrls_values,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for PerClientFlagsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -11000,31 +16048,34 @@ impl AsByteSequence for PerClientFlagsReply {
         index += self.value.as_bytes(&mut bytes[index..]);
         index += self.auto_ctrls.as_bytes(&mut bytes[index..]);
         index += self.auto_ctrls_values.as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += zero_pad(&mut bytes[index..], 8);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PerClientFlagsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (supported, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (supported, sz): (PerClientFlag, usize) = parse_item(bytes, index, "supported")?;
         index += sz;
-        let (value, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let (value, sz): (PerClientFlag, usize) = parse_item(bytes, index, "value")?;
         index += sz;
-        let (auto_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls, sz): (BoolCtrl, usize) = parse_item(bytes, index, "auto_ctrls")?;
         index += sz;
-        let (auto_ctrls_values, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls_values, sz): (BoolCtrl, usize) =
+            parse_item(bytes, index, "auto_ctrls_values")?;
         index += sz;
         index += 8;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PerClientFlagsReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -11040,19 +16091,21 @@ impl AsByteSequence for PerClientFlagsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.supported.size()
-            + self.value.size()
-            + self.auto_ctrls.size()
-            + self.auto_ctrls_values.size()
-            + 8
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PerClientFlagsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PerClientFlag {
     pub inner: u32,
 }
@@ -11159,21 +16212,20 @@ impl PerClientFlag {
     pub const SEND_EVENT_USES_XKB_STATE: Self = Self { inner: 16 };
     pub const COMPLETE: Self = Self { inner: 31 };
 }
-impl AsByteSequence for PerClientFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for PerClientFlag {
+    type Target = u32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((PerClientFlag { inner: inner }, sz))
+    fn deref(&self) -> &u32 {
+        &self.inner
     }
+}
+impl AsRef<u32> for PerClientFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u32 {
+        &self.inner
     }
 }
+impl_u32_flags_asb! { PerClientFlag }
 impl core::ops::Not for PerClientFlag {
     type Output = PerClientFlag;
     #[inline]
@@ -11208,39 +16260,63 @@ impl core::ops::BitXor for PerClientFlag {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListComponentsRequest {
     pub req_type: u8,
     pub length: u16,
     pub device_spec: DeviceSpec,
     pub max_names: Card16,
 }
-impl ListComponentsRequest {}
-impl AsByteSequence for ListComponentsRequest {
+impl ListComponentsRequest {
+    #[inline]
+    pub fn builder() -> ListComponentsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListComponentsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("max_names", 6, 2),
+    ];
+}
+const _: () = assert!(<ListComponentsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ListComponentsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.max_names.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListComponentsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (max_names, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_names, sz): (Card16, usize) = parse_item(bytes, index, "max_names")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListComponentsRequest {
                 req_type: req_type,
                 length: length,
@@ -11252,36 +16328,381 @@ impl AsByteSequence for ListComponentsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.max_names.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListComponentsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ListComponentsRequest {
-    const OPCODE: u8 = 22;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 22;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListComponents";
     type Reply = ListComponentsReply;
 }
-#[derive(Clone, Debug, Default)]
-pub struct ListComponentsReply {
-    pub reply_type: u8,
-    pub device_id: Card8,
-    pub sequence: u16,
-    pub length: u32,
-    pub extra: Card16,
-    pub keymaps: Vec<Listing>,
+#[doc = " A builder for `ListComponentsRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListComponentsRequestBuilder {
+    pub inner: ListComponentsRequest,
+}
+impl ListComponentsRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn max_names(mut self, max_names: Card16) -> Self {
+        self.inner.max_names = max_names;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListComponentsRequest {
+        self.inner
+    }
+}
+impl Default for ListComponentsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListComponentsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                max_names: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct ListComponentsReply {
+    pub reply_type: u8,
+    pub device_id: Card8,
+    pub sequence: u16,
+    pub length: u32,
+    pub extra: Card16,
+    pub keymaps: Vec<Listing>,
     pub keycodes: Vec<Listing>,
     pub types: Vec<Listing>,
     pub compat_maps: Vec<Listing>,
     pub symbols: Vec<Listing>,
     pub geometries: Vec<Listing>,
 }
-impl ListComponentsReply {}
-impl AsByteSequence for ListComponentsReply {
+impl ListComponentsReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn keymaps_iter(bytes: &[u8]) -> Result<ListIter<'_, Listing>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
+        let (len4, sz): (Card16, usize) = parse_item(bytes, index, "len4")?;
+        index += sz;
+        let (len5, sz): (Card16, usize) = parse_item(bytes, index, "len5")?;
+        index += sz;
+        let (extra, sz): (Card16, usize) = parse_item(bytes, index, "extra")?;
+        index += sz;
+        index += 10;
+        Ok(ListIter::padded(bytes, index, len0 as usize, 2, "keymaps"))
+    }
+    pub fn keycodes_iter(bytes: &[u8]) -> Result<ListIter<'_, Listing>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
+        let (len4, sz): (Card16, usize) = parse_item(bytes, index, "len4")?;
+        index += sz;
+        let (len5, sz): (Card16, usize) = parse_item(bytes, index, "len5")?;
+        index += sz;
+        let (extra, sz): (Card16, usize) = parse_item(bytes, index, "extra")?;
+        index += sz;
+        index += 10;
+        let (keymaps, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len0 as usize, 2, "keymaps")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        Ok(ListIter::padded(bytes, index, len1 as usize, 2, "keycodes"))
+    }
+    pub fn types_iter(bytes: &[u8]) -> Result<ListIter<'_, Listing>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
+        let (len4, sz): (Card16, usize) = parse_item(bytes, index, "len4")?;
+        index += sz;
+        let (len5, sz): (Card16, usize) = parse_item(bytes, index, "len5")?;
+        index += sz;
+        let (extra, sz): (Card16, usize) = parse_item(bytes, index, "extra")?;
+        index += sz;
+        index += 10;
+        let (keymaps, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len0 as usize, 2, "keymaps")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (keycodes, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len1 as usize, 2, "keycodes")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        Ok(ListIter::padded(bytes, index, len2 as usize, 2, "types"))
+    }
+    pub fn compat_maps_iter(bytes: &[u8]) -> Result<ListIter<'_, Listing>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
+        let (len4, sz): (Card16, usize) = parse_item(bytes, index, "len4")?;
+        index += sz;
+        let (len5, sz): (Card16, usize) = parse_item(bytes, index, "len5")?;
+        index += sz;
+        let (extra, sz): (Card16, usize) = parse_item(bytes, index, "extra")?;
+        index += sz;
+        index += 10;
+        let (keymaps, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len0 as usize, 2, "keymaps")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (keycodes, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len1 as usize, 2, "keycodes")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (types, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len2 as usize, 2, "types")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        Ok(ListIter::padded(
+            bytes,
+            index,
+            len3 as usize,
+            2,
+            "compat_maps",
+        ))
+    }
+    pub fn symbols_iter(bytes: &[u8]) -> Result<ListIter<'_, Listing>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
+        let (len4, sz): (Card16, usize) = parse_item(bytes, index, "len4")?;
+        index += sz;
+        let (len5, sz): (Card16, usize) = parse_item(bytes, index, "len5")?;
+        index += sz;
+        let (extra, sz): (Card16, usize) = parse_item(bytes, index, "extra")?;
+        index += sz;
+        index += 10;
+        let (keymaps, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len0 as usize, 2, "keymaps")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (keycodes, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len1 as usize, 2, "keycodes")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (types, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len2 as usize, 2, "types")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (compat_maps, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len3 as usize, 2, "compat_maps")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        Ok(ListIter::padded(bytes, index, len4 as usize, 2, "symbols"))
+    }
+    pub fn geometries_iter(bytes: &[u8]) -> Result<ListIter<'_, Listing>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
+        index += sz;
+        let (len4, sz): (Card16, usize) = parse_item(bytes, index, "len4")?;
+        index += sz;
+        let (len5, sz): (Card16, usize) = parse_item(bytes, index, "len5")?;
+        index += sz;
+        let (extra, sz): (Card16, usize) = parse_item(bytes, index, "extra")?;
+        index += sz;
+        index += 10;
+        let (keymaps, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len0 as usize, 2, "keymaps")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (keycodes, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len1 as usize, 2, "keycodes")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (types, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len2 as usize, 2, "types")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (compat_maps, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len3 as usize, 2, "compat_maps")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        let (symbols, block_len): (Vec<Listing>, usize) =
+            parse_padded_vector(bytes, index, len4 as usize, 2, "symbols")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        Ok(ListIter::padded(
+            bytes,
+            index,
+            len5 as usize,
+            2,
+            "geometries",
+        ))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("keymaps_len", 8, 2),
+        ("keycodes_len", 10, 2),
+        ("types_len", 12, 2),
+        ("compat_maps_len", 14, 2),
+        ("symbols_len", 16, 2),
+        ("geometries_len", 18, 2),
+        ("extra", 20, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.device_id == other.device_id
+            && self.extra == other.extra
+            && self.keymaps == other.keymaps
+            && self.keycodes == other.keycodes
+            && self.types == other.types
+            && self.compat_maps == other.compat_maps
+            && self.symbols == other.symbols
+            && self.geometries == other.geometries
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("device_id", &self.device_id, &other.device_id, &mut changes);
+        diff_value("extra", &self.extra, &other.extra, &mut changes);
+        diff_list("keymaps", &self.keymaps, &other.keymaps, &mut changes);
+        diff_list("keycodes", &self.keycodes, &other.keycodes, &mut changes);
+        diff_list("types", &self.types, &other.types, &mut changes);
+        diff_list(
+            "compat_maps",
+            &self.compat_maps,
+            &other.compat_maps,
+            &mut changes,
+        );
+        diff_list("symbols", &self.symbols, &other.symbols, &mut changes);
+        diff_list(
+            "geometries",
+            &self.geometries,
+            &other.geometries,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for ListComponentsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -11295,79 +16716,99 @@ impl AsByteSequence for ListComponentsReply {
         index += (self.symbols.len() as Card16).as_bytes(&mut bytes[index..]);
         index += (self.geometries.len() as Card16).as_bytes(&mut bytes[index..]);
         index += self.extra.as_bytes(&mut bytes[index..]);
-        index += 10;
-        let block_len: usize = vector_as_bytes(&self.keymaps, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 10);
+        let block_len: usize = padded_vector_as_bytes(&self.keymaps, &mut bytes[index..], 2);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
-        let block_len: usize = vector_as_bytes(&self.keycodes, &mut bytes[index..]);
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Listing>()),
+        );
+        let block_len: usize = padded_vector_as_bytes(&self.keycodes, &mut bytes[index..], 2);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
-        let block_len: usize = vector_as_bytes(&self.types, &mut bytes[index..]);
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Listing>()),
+        );
+        let block_len: usize = padded_vector_as_bytes(&self.types, &mut bytes[index..], 2);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
-        let block_len: usize = vector_as_bytes(&self.compat_maps, &mut bytes[index..]);
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Listing>()),
+        );
+        let block_len: usize = padded_vector_as_bytes(&self.compat_maps, &mut bytes[index..], 2);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
-        let block_len: usize = vector_as_bytes(&self.symbols, &mut bytes[index..]);
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Listing>()),
+        );
+        let block_len: usize = padded_vector_as_bytes(&self.symbols, &mut bytes[index..], 2);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
-        let block_len: usize = vector_as_bytes(&self.geometries, &mut bytes[index..]);
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Listing>()),
+        );
+        let block_len: usize = padded_vector_as_bytes(&self.geometries, &mut bytes[index..], 2);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Listing>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListComponentsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = parse_item(bytes, index, "len3")?;
         index += sz;
-        let (len4, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len4, sz): (Card16, usize) = parse_item(bytes, index, "len4")?;
         index += sz;
-        let (len5, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len5, sz): (Card16, usize) = parse_item(bytes, index, "len5")?;
         index += sz;
-        let (extra, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (extra, sz): (Card16, usize) = parse_item(bytes, index, "extra")?;
         index += sz;
         index += 10;
         let (keymaps, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 2, "keymaps")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (keycodes, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_padded_vector(bytes, index, len1 as usize, 2, "keycodes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (types, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            parse_padded_vector(bytes, index, len2 as usize, 2, "types")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (compat_maps, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            parse_padded_vector(bytes, index, len3 as usize, 2, "compat_maps")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (symbols, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
+            parse_padded_vector(bytes, index, len4 as usize, 2, "symbols")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (geometries, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
+            parse_padded_vector(bytes, index, len5 as usize, 2, "geometries")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListComponentsReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -11384,53 +16825,89 @@ impl AsByteSequence for ListComponentsReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
-            + self.extra.size()
-            + 10
-            + {
-                let block_len: usize = self.keymaps.iter().map(|i| i.size()).sum();
+        let size: usize =
+            32 + {
+                let block_len: usize = self
+                    .keymaps
+                    .iter()
+                    .map(|i| {
+                        let size = i.size();
+                        size + buffer_pad(size, 2)
+                    })
+                    .sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Listing>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.keycodes.iter().map(|i| i.size()).sum();
+            } + {
+                let block_len: usize = self
+                    .keycodes
+                    .iter()
+                    .map(|i| {
+                        let size = i.size();
+                        size + buffer_pad(size, 2)
+                    })
+                    .sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Listing>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.types.iter().map(|i| i.size()).sum();
+            } + {
+                let block_len: usize = self
+                    .types
+                    .iter()
+                    .map(|i| {
+                        let size = i.size();
+                        size + buffer_pad(size, 2)
+                    })
+                    .sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Listing>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.compat_maps.iter().map(|i| i.size()).sum();
+            } + {
+                let block_len: usize = self
+                    .compat_maps
+                    .iter()
+                    .map(|i| {
+                        let size = i.size();
+                        size + buffer_pad(size, 2)
+                    })
+                    .sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Listing>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.symbols.iter().map(|i| i.size()).sum();
+            } + {
+                let block_len: usize = self
+                    .symbols
+                    .iter()
+                    .map(|i| {
+                        let size = i.size();
+                        size + buffer_pad(size, 2)
+                    })
+                    .sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Listing>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.geometries.iter().map(|i| i.size()).sum();
+            } + {
+                let block_len: usize = self
+                    .geometries
+                    .iter()
+                    .map(|i| {
+                        let size = i.size();
+                        size + buffer_pad(size, 2)
+                    })
+                    .sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Listing>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ListComponentsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetKbdByNameRequest {
     pub req_type: u8,
     pub length: u16,
@@ -11439,40 +16916,63 @@ pub struct GetKbdByNameRequest {
     pub want: GbnDetail,
     pub load: bool,
 }
-impl GetKbdByNameRequest {}
-impl AsByteSequence for GetKbdByNameRequest {
+impl GetKbdByNameRequest {
+    #[inline]
+    pub fn builder() -> GetKbdByNameRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetKbdByNameReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("need", 6, 2),
+        ("want", 8, 2),
+        ("load", 10, 1),
+    ];
+}
+const _: () = assert!(<GetKbdByNameRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetKbdByNameRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.need.as_bytes(&mut bytes[index..]);
         index += self.want.as_bytes(&mut bytes[index..]);
         index += self.load.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKbdByNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (need, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (need, sz): (GbnDetail, usize) = parse_item(bytes, index, "need")?;
         index += sz;
-        let (want, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (want, sz): (GbnDetail, usize) = parse_item(bytes, index, "want")?;
         index += sz;
-        let (load, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (load, sz): (bool, usize) = parse_item(bytes, index, "load")?;
         index += sz;
         index += 1;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetKbdByNameRequest {
                 req_type: req_type,
                 length: length,
@@ -11486,23 +16986,75 @@ impl AsByteSequence for GetKbdByNameRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.need.size()
-            + self.want.size()
-            + self.load.size()
-            + 1
+        let size: usize = 11 + self.load.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetKbdByNameRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetKbdByNameRequest {
-    const OPCODE: u8 = 23;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 23;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetKbdByName";
     type Reply = GetKbdByNameReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetKbdByNameRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetKbdByNameRequestBuilder {
+    pub inner: GetKbdByNameRequest,
+}
+impl GetKbdByNameRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn need(mut self, need: GbnDetail) -> Self {
+        self.inner.need = need;
+        self
+    }
+    #[inline]
+    pub fn want(mut self, want: GbnDetail) -> Self {
+        self.inner.want = want;
+        self
+    }
+    #[inline]
+    pub fn load(mut self, load: bool) -> Self {
+        self.inner.load = load;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetKbdByNameRequest {
+        self.inner
+    }
+}
+impl Default for GetKbdByNameRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetKbdByNameRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                need: Default::default(),
+                want: Default::default(),
+                load: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetKbdByNameReply {
     pub reply_type: u8,
     pub device_id: Card8,
@@ -11514,28 +17066,35 @@ pub struct GetKbdByNameReply {
     pub new_keyboard: bool,
     pub found: GbnDetail,
     pub reported: GbnDetail,
-    pub getmap_type: Card8,
-    pub type_device_id: Card8,
-    pub getmap_sequence: Card16,
-    pub getmap_length: Card32,
-    pub type_min_key_code: Keycode,
-    pub type_max_key_code: Keycode,
-    pub present: MapPart,
-    pub first_type: Card8,
-    pub n_types: Card8,
-    pub total_types: Card8,
-    pub first_key_sym: Keycode,
-    pub total_syms: Card16,
-    pub first_key_action: Keycode,
-    pub first_key_behavior: Keycode,
-    pub n_key_behaviors: Card8,
-    pub first_key_explicit: Keycode,
-    pub n_key_explicit: Card8,
-    pub first_mod_map_key: Keycode,
-    pub n_mod_map_keys: Card8,
-    pub first_v_mod_map_key: Keycode,
-    pub n_v_mod_map_keys: Card8,
-    pub virtual_mods: VMod,
+    pub getmap_type: Option<Card8>,
+    pub type_device_id: Option<Card8>,
+    pub getmap_sequence: Option<Card16>,
+    pub getmap_length: Option<Card32>,
+    pub type_min_key_code: Option<Keycode>,
+    pub type_max_key_code: Option<Keycode>,
+    pub present: Option<MapPart>,
+    pub first_type: Option<Card8>,
+    pub n_types: Option<Card8>,
+    pub total_types: Option<Card8>,
+    pub first_key_sym: Option<Keycode>,
+    pub total_syms: Option<Card16>,
+    pub n_key_syms: Option<Card8>,
+    pub first_key_action: Option<Keycode>,
+    pub total_actions: Option<Card16>,
+    pub n_key_actions: Option<Card8>,
+    pub first_key_behavior: Option<Keycode>,
+    pub n_key_behaviors: Option<Card8>,
+    pub total_key_behaviors: Option<Card8>,
+    pub first_key_explicit: Option<Keycode>,
+    pub n_key_explicit: Option<Card8>,
+    pub total_key_explicit: Option<Card8>,
+    pub first_mod_map_key: Option<Keycode>,
+    pub n_mod_map_keys: Option<Card8>,
+    pub total_mod_map_keys: Option<Card8>,
+    pub first_v_mod_map_key: Option<Keycode>,
+    pub n_v_mod_map_keys: Option<Card8>,
+    pub total_v_mod_map_keys: Option<Card8>,
+    pub virtual_mods: Option<VMod>,
     pub types_rtrn: Vec<KeyType>,
     pub syms_rtrn: Vec<KeySymMap>,
     pub acts_rtrn_count: Vec<Card8>,
@@ -11545,41 +17104,44 @@ pub struct GetKbdByNameReply {
     pub explicit_rtrn: Vec<SetExplicit>,
     pub modmap_rtrn: Vec<KeyModMap>,
     pub vmodmap_rtrn: Vec<KeyVModMap>,
-    pub compatmap_type: Card8,
-    pub compat_device_id: Card8,
-    pub compatmap_sequence: Card16,
-    pub compatmap_length: Card32,
-    pub groups_rtrn: SetOfGroup,
-    pub first_si_rtrn: Card16,
-    pub n_total_si: Card16,
+    pub compatmap_type: Option<Card8>,
+    pub compat_device_id: Option<Card8>,
+    pub compatmap_sequence: Option<Card16>,
+    pub compatmap_length: Option<Card32>,
+    pub groups_rtrn: Option<SetOfGroup>,
+    pub first_si_rtrn: Option<Card16>,
+    pub n_total_si: Option<Card16>,
     pub si_rtrn: Vec<SymInterpret>,
     pub group_rtrn: Vec<ModDef>,
-    pub indicatormap_type: Card8,
-    pub indicator_device_id: Card8,
-    pub indicatormap_sequence: Card16,
-    pub indicatormap_length: Card32,
-    pub which: Card32,
-    pub real_indicators: Card32,
+    pub indicatormap_type: Option<Card8>,
+    pub indicator_device_id: Option<Card8>,
+    pub indicatormap_sequence: Option<Card16>,
+    pub indicatormap_length: Option<Card32>,
+    pub which: Option<Card32>,
+    pub real_indicators: Option<Card32>,
     pub maps: Vec<IndicatorMap>,
-    pub keyname_type: Card8,
-    pub key_device_id: Card8,
-    pub keyname_sequence: Card16,
-    pub keyname_length: Card32,
-    pub which_: NameDetail,
-    pub key_min_key_code: Keycode,
-    pub key_max_key_code: Keycode,
-    pub n_types_: Card8,
-    pub group_names: SetOfGroup,
-    pub virtual_mods_: VMod,
-    pub first_key: Keycode,
-    pub indicators: Card32,
-    pub n_kt_levels: Card16,
-    pub keycodes_name: Atom,
-    pub geometry_name: Atom,
-    pub symbols_name: Atom,
-    pub phys_symbols_name: Atom,
-    pub types_name: Atom,
-    pub compat_name: Atom,
+    pub keyname_type: Option<Card8>,
+    pub key_device_id: Option<Card8>,
+    pub keyname_sequence: Option<Card16>,
+    pub keyname_length: Option<Card32>,
+    pub which_: Option<NameDetail>,
+    pub key_min_key_code: Option<Keycode>,
+    pub key_max_key_code: Option<Keycode>,
+    pub n_types_: Option<Card8>,
+    pub group_names: Option<SetOfGroup>,
+    pub virtual_mods_: Option<VMod>,
+    pub first_key: Option<Keycode>,
+    pub n_keys: Option<Card8>,
+    pub indicators: Option<Card32>,
+    pub n_radio_groups: Option<Card8>,
+    pub n_key_aliases: Option<Card8>,
+    pub n_kt_levels: Option<Card16>,
+    pub keycodes_name: Option<Atom>,
+    pub geometry_name: Option<Atom>,
+    pub symbols_name: Option<Atom>,
+    pub phys_symbols_name: Option<Atom>,
+    pub types_name: Option<Atom>,
+    pub compat_name: Option<Atom>,
     pub type_names: Vec<Atom>,
     pub n_levels_per_type: Vec<Card8>,
     pub kt_level_names: Vec<Atom>,
@@ -11589,27 +17151,44 @@ pub struct GetKbdByNameReply {
     pub key_names: Vec<KeyName>,
     pub key_aliases: Vec<KeyAlias>,
     pub radio_group_names: Vec<Atom>,
-    pub geometry_type: Card8,
-    pub geometry_device_id: Card8,
-    pub geometry_sequence: Card16,
-    pub geometry_length: Card32,
-    pub name: Atom,
-    pub geometry_found: bool,
-    pub width_mm: Card16,
-    pub height_mm: Card16,
-    pub n_properties: Card16,
-    pub n_colors: Card16,
-    pub n_shapes: Card16,
-    pub n_sections: Card16,
-    pub n_doodads: Card16,
-    pub n_key_aliases: Card16,
-    pub base_color_ndx: Card8,
-    pub label_color_ndx: Card8,
-    pub label_font: CountedString16,
-}
-impl GetKbdByNameReply {}
+    pub geometry_type: Option<Card8>,
+    pub geometry_device_id: Option<Card8>,
+    pub geometry_sequence: Option<Card16>,
+    pub geometry_length: Option<Card32>,
+    pub name: Option<Atom>,
+    pub geometry_found: Option<bool>,
+    pub width_mm: Option<Card16>,
+    pub height_mm: Option<Card16>,
+    pub n_properties: Option<Card16>,
+    pub n_colors: Option<Card16>,
+    pub n_shapes: Option<Card16>,
+    pub n_sections: Option<Card16>,
+    pub n_doodads: Option<Card16>,
+    pub n_key_aliases_: Option<Card16>,
+    pub base_color_ndx: Option<Card8>,
+    pub label_color_ndx: Option<Card8>,
+    pub label_font: Option<CountedString16>,
+}
+impl GetKbdByNameReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+}
 impl AsByteSequence for GetKbdByNameReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -11622,950 +17201,1136 @@ impl AsByteSequence for GetKbdByNameReply {
         index += self.new_keyboard.as_bytes(&mut bytes[index..]);
         index += self.found.as_bytes(&mut bytes[index..]);
         index += self.reported.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 16);
         let cond0 = (self.reported);
         if cond0.server_symbols() {
             index += self.getmap_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.type_device_id.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.getmap_sequence.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.getmap_length.as_bytes(&mut bytes[index..]);
-        }
-        index += 2;
-        if cond0.server_symbols() {
+            index += zero_pad(&mut bytes[index..], 2);
             index += self.type_min_key_code.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.type_max_key_code.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.present.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.first_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.n_types.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.total_types.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.first_key_sym.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.total_syms.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.syms_rtrn.len() as Card8).as_bytes(&mut bytes[index..]);
-        if cond0.server_symbols() {
+            index += self.n_key_syms.as_bytes(&mut bytes[index..]);
             index += self.first_key_action.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.acts_rtrn_acts.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += (self.acts_rtrn_count.len() as Card8).as_bytes(&mut bytes[index..]);
-        if cond0.server_symbols() {
+            index += self.total_actions.as_bytes(&mut bytes[index..]);
+            index += self.n_key_actions.as_bytes(&mut bytes[index..]);
             index += self.first_key_behavior.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.n_key_behaviors.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.behaviors_rtrn.len() as Card8).as_bytes(&mut bytes[index..]);
-        if cond0.server_symbols() {
+            index += self.total_key_behaviors.as_bytes(&mut bytes[index..]);
             index += self.first_key_explicit.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.n_key_explicit.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.explicit_rtrn.len() as Card8).as_bytes(&mut bytes[index..]);
-        if cond0.server_symbols() {
+            index += self.total_key_explicit.as_bytes(&mut bytes[index..]);
             index += self.first_mod_map_key.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.n_mod_map_keys.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.modmap_rtrn.len() as Card8).as_bytes(&mut bytes[index..]);
-        if cond0.server_symbols() {
+            index += self.total_mod_map_keys.as_bytes(&mut bytes[index..]);
             index += self.first_v_mod_map_key.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.server_symbols() {
             index += self.n_v_mod_map_keys.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.vmodmap_rtrn.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 1;
-        if cond0.server_symbols() {
+            index += self.total_v_mod_map_keys.as_bytes(&mut bytes[index..]);
+            index += zero_pad(&mut bytes[index..], 1);
             index += self.virtual_mods.as_bytes(&mut bytes[index..]);
+            let cond1 = (self.present.unwrap_or_default());
+            if cond1.key_types() {
+                let block_len: usize = vector_as_bytes(&self.types_rtrn, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<KeyType>()),
+                );
+            }
+            if cond1.key_syms() {
+                let block_len: usize = vector_as_bytes(&self.syms_rtrn, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>()),
+                );
+            }
+            if cond1.key_actions() {
+                let block_len: usize = vector_as_bytes(&self.acts_rtrn_count, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                let block_len: usize = vector_as_bytes(&self.acts_rtrn_acts, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Action>()),
+                );
+            }
+            if cond1.key_behaviors() {
+                let block_len: usize = vector_as_bytes(&self.behaviors_rtrn, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>()),
+                );
+            }
+            if cond1.virtual_mods() {
+                let block_len: usize = vector_as_bytes(&self.vmods_rtrn, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+            }
+            if cond1.explicit_components() {
+                let block_len: usize = vector_as_bytes(&self.explicit_rtrn, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+            }
+            if cond1.modifier_map() {
+                let block_len: usize = vector_as_bytes(&self.modmap_rtrn, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+            }
+            if cond1.virtual_mod_map() {
+                let block_len: usize = vector_as_bytes(&self.vmodmap_rtrn, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>()),
+                );
+            }
         }
-        let block_len: usize = vector_as_bytes(&self.types_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-        let block_len: usize = vector_as_bytes(&self.syms_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-        let block_len: usize = vector_as_bytes(&self.acts_rtrn_count, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let block_len: usize = vector_as_bytes(&self.acts_rtrn_acts, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
-        let block_len: usize = vector_as_bytes(&self.behaviors_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-        let block_len: usize = vector_as_bytes(&self.vmods_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let block_len: usize = vector_as_bytes(&self.explicit_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-        let block_len: usize = vector_as_bytes(&self.modmap_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-        let block_len: usize = vector_as_bytes(&self.vmodmap_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
         if cond0.compat_map() {
             index += self.compatmap_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.compat_map() {
             index += self.compat_device_id.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.compat_map() {
             index += self.compatmap_sequence.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.compat_map() {
             index += self.compatmap_length.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.compat_map() {
             index += self.groups_rtrn.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.compat_map() {
+            index += zero_pad(&mut bytes[index..], 1);
             index += self.first_si_rtrn.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.si_rtrn.len() as Card16).as_bytes(&mut bytes[index..]);
-        if cond0.compat_map() {
+            index += (self.si_rtrn.len() as Card16).as_bytes(&mut bytes[index..]);
             index += self.n_total_si.as_bytes(&mut bytes[index..]);
+            index += zero_pad(&mut bytes[index..], 16);
+            let block_len: usize = vector_as_bytes(&self.si_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>()),
+            );
+            let block_len: usize = vector_as_bytes(&self.group_rtrn, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<ModDef>()),
+            );
         }
-        let block_len: usize = vector_as_bytes(&self.si_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
-        let block_len: usize = vector_as_bytes(&self.group_rtrn, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
         if cond0.indicator_maps() {
             index += self.indicatormap_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.indicator_maps() {
             index += self.indicator_device_id.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.indicator_maps() {
             index += self.indicatormap_sequence.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.indicator_maps() {
             index += self.indicatormap_length.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.indicator_maps() {
             index += self.which.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.indicator_maps() {
             index += self.real_indicators.as_bytes(&mut bytes[index..]);
+            index += (self.maps.len() as Card8).as_bytes(&mut bytes[index..]);
+            index += zero_pad(&mut bytes[index..], 15);
+            let block_len: usize = vector_as_bytes(&self.maps, &mut bytes[index..]);
+            index += block_len;
+            index += zero_pad(
+                &mut bytes[index..],
+                buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>()),
+            );
         }
-        index += (self.maps.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += 15;
-        let block_len: usize = vector_as_bytes(&self.maps, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
         if cond0.other_names() {
             index += self.keyname_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.key_device_id.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.keyname_sequence.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.keyname_length.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.which_.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.key_min_key_code.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.key_max_key_code.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.n_types_.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.group_names.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.virtual_mods_.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
             index += self.first_key.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.key_names.len() as Card8).as_bytes(&mut bytes[index..]);
-        if cond0.other_names() {
+            index += self.n_keys.as_bytes(&mut bytes[index..]);
             index += self.indicators.as_bytes(&mut bytes[index..]);
-        }
-        index += (self.radio_group_names.len() as Card8).as_bytes(&mut bytes[index..]);
-        index += (self.key_aliases.len() as Card8).as_bytes(&mut bytes[index..]);
-        if cond0.other_names() {
+            index += self.n_radio_groups.as_bytes(&mut bytes[index..]);
+            index += self.n_key_aliases.as_bytes(&mut bytes[index..]);
             index += self.n_kt_levels.as_bytes(&mut bytes[index..]);
+            index += zero_pad(&mut bytes[index..], 4);
+            let cond2 = (self.which_.unwrap_or_default());
+            if cond2.keycodes() {
+                index += self.keycodes_name.as_bytes(&mut bytes[index..]);
+            }
+            if cond2.geometry() {
+                index += self.geometry_name.as_bytes(&mut bytes[index..]);
+            }
+            if cond2.symbols() {
+                index += self.symbols_name.as_bytes(&mut bytes[index..]);
+            }
+            if cond2.phys_symbols() {
+                index += self.phys_symbols_name.as_bytes(&mut bytes[index..]);
+            }
+            if cond2.types() {
+                index += self.types_name.as_bytes(&mut bytes[index..]);
+            }
+            if cond2.compat() {
+                index += self.compat_name.as_bytes(&mut bytes[index..]);
+            }
+            if cond2.key_type_names() {
+                let block_len: usize = vector_as_bytes(&self.type_names, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+                );
+            }
+            if cond2.kt_level_names() {
+                let block_len: usize =
+                    vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+                );
+            }
+            if cond2.indicator_names() {
+                let block_len: usize = vector_as_bytes(&self.indicator_names, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+                );
+            }
+            if cond2.virtual_mod_names() {
+                let block_len: usize =
+                    vector_as_bytes(&self.virtual_mod_names, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+                );
+            }
+            if cond2.group_names() {
+                let block_len: usize = vector_as_bytes(&self.groups, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+                );
+            }
+            if cond2.key_names() {
+                let block_len: usize = vector_as_bytes(&self.key_names, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<KeyName>()),
+                );
+            }
+            if cond2.key_aliases() {
+                let block_len: usize = vector_as_bytes(&self.key_aliases, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>()),
+                );
+            }
+            if cond2.rg_names() {
+                let block_len: usize =
+                    vector_as_bytes(&self.radio_group_names, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<Atom>()),
+                );
+            }
         }
-        if cond0.other_names() {
-            index += self.keycodes_name.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
-            index += self.geometry_name.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
-            index += self.symbols_name.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
-            index += self.phys_symbols_name.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
-            index += self.types_name.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.other_names() {
-            index += self.compat_name.as_bytes(&mut bytes[index..]);
-        }
-        let block_len: usize = vector_as_bytes(&self.type_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.indicator_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.virtual_mod_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.groups, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let block_len: usize = vector_as_bytes(&self.key_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-        let block_len: usize = vector_as_bytes(&self.key_aliases, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-        let block_len: usize = vector_as_bytes(&self.radio_group_names, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         if cond0.geometry() {
             index += self.geometry_type.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.geometry_device_id.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.geometry_sequence.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.geometry_length.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.name.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.geometry_found.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
+            index += zero_pad(&mut bytes[index..], 1);
             index += self.width_mm.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.height_mm.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.n_properties.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.n_colors.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.n_shapes.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.n_sections.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.n_doodads.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
-            index += self.n_key_aliases.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
+            index += self.n_key_aliases_.as_bytes(&mut bytes[index..]);
             index += self.base_color_ndx.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.label_color_ndx.as_bytes(&mut bytes[index..]);
-        }
-        if cond0.geometry() {
             index += self.label_font.as_bytes(&mut bytes[index..]);
         }
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKbdByNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (min_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "min_key_code")?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "max_key_code")?;
         index += sz;
-        let (loaded, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (loaded, sz): (bool, usize) = parse_item(bytes, index, "loaded")?;
         index += sz;
-        let (new_keyboard, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (new_keyboard, sz): (bool, usize) = parse_item(bytes, index, "new_keyboard")?;
         index += sz;
-        let (found, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (found, sz): (GbnDetail, usize) = parse_item(bytes, index, "found")?;
         index += sz;
-        let (reported, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (reported, sz): (GbnDetail, usize) = parse_item(bytes, index, "reported")?;
         index += sz;
         index += 16;
         let cond0 = (reported);
-        let getmap_type: Card8 = if cond0.server_symbols() {
-            let (getmap_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (
+            getmap_type,
+            type_device_id,
+            getmap_sequence,
+            getmap_length,
+            type_min_key_code,
+            type_max_key_code,
+            present,
+            first_type,
+            n_types,
+            total_types,
+            first_key_sym,
+            total_syms,
+            n_key_syms,
+            first_key_action,
+            total_actions,
+            n_key_actions,
+            first_key_behavior,
+            n_key_behaviors,
+            total_key_behaviors,
+            first_key_explicit,
+            n_key_explicit,
+            total_key_explicit,
+            first_mod_map_key,
+            n_mod_map_keys,
+            total_mod_map_keys,
+            first_v_mod_map_key,
+            n_v_mod_map_keys,
+            total_v_mod_map_keys,
+            virtual_mods,
+            types_rtrn,
+            syms_rtrn,
+            acts_rtrn_count,
+            acts_rtrn_acts,
+            behaviors_rtrn,
+            vmods_rtrn,
+            explicit_rtrn,
+            modmap_rtrn,
+            vmodmap_rtrn,
+        ) = if cond0.server_symbols() {
+            let (getmap_type, sz): (Card8, usize) = parse_item(bytes, index, "getmap_type")?;
             index += sz;
-            getmap_type
-        } else {
-            Default::default()
-        };
-        let type_device_id: Card8 = if cond0.server_symbols() {
-            let (type_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (type_device_id, sz): (Card8, usize) = parse_item(bytes, index, "type_device_id")?;
             index += sz;
-            type_device_id
-        } else {
-            Default::default()
-        };
-        let getmap_sequence: Card16 = if cond0.server_symbols() {
-            let (getmap_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (getmap_sequence, sz): (Card16, usize) =
+                parse_item(bytes, index, "getmap_sequence")?;
             index += sz;
-            getmap_sequence
-        } else {
-            Default::default()
-        };
-        let getmap_length: Card32 = if cond0.server_symbols() {
-            let (getmap_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (getmap_length, sz): (Card32, usize) = parse_item(bytes, index, "getmap_length")?;
             index += sz;
-            getmap_length
-        } else {
-            Default::default()
-        };
-        index += 2;
-        let type_min_key_code: Keycode = if cond0.server_symbols() {
-            let (type_min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            index += 2;
+            let (type_min_key_code, sz): (Keycode, usize) =
+                parse_item(bytes, index, "type_min_key_code")?;
             index += sz;
-            type_min_key_code
-        } else {
-            Default::default()
-        };
-        let type_max_key_code: Keycode = if cond0.server_symbols() {
-            let (type_max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (type_max_key_code, sz): (Keycode, usize) =
+                parse_item(bytes, index, "type_max_key_code")?;
             index += sz;
-            type_max_key_code
-        } else {
-            Default::default()
-        };
-        let present: MapPart = if cond0.server_symbols() {
-            let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+            let (present, sz): (MapPart, usize) = parse_item(bytes, index, "present")?;
             index += sz;
-            present
-        } else {
-            Default::default()
-        };
-        let first_type: Card8 = if cond0.server_symbols() {
-            let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (first_type, sz): (Card8, usize) = parse_item(bytes, index, "first_type")?;
             index += sz;
-            first_type
-        } else {
-            Default::default()
-        };
-        let n_types: Card8 = if cond0.server_symbols() {
-            let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_types, sz): (Card8, usize) = parse_item(bytes, index, "n_types")?;
             index += sz;
-            n_types
-        } else {
-            Default::default()
-        };
-        let total_types: Card8 = if cond0.server_symbols() {
-            let (total_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (total_types, sz): (Card8, usize) = parse_item(bytes, index, "total_types")?;
             index += sz;
-            total_types
-        } else {
-            Default::default()
-        };
-        let first_key_sym: Keycode = if cond0.server_symbols() {
-            let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (first_key_sym, sz): (Keycode, usize) = parse_item(bytes, index, "first_key_sym")?;
             index += sz;
-            first_key_sym
-        } else {
-            Default::default()
-        };
-        let total_syms: Card16 = if cond0.server_symbols() {
-            let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (total_syms, sz): (Card16, usize) = parse_item(bytes, index, "total_syms")?;
             index += sz;
-            total_syms
-        } else {
-            Default::default()
-        };
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let first_key_action: Keycode = if cond0.server_symbols() {
-            let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (n_key_syms, sz): (Card8, usize) = parse_item(bytes, index, "n_key_syms")?;
+            index += sz;
+            let (first_key_action, sz): (Keycode, usize) =
+                parse_item(bytes, index, "first_key_action")?;
+            index += sz;
+            let (total_actions, sz): (Card16, usize) = parse_item(bytes, index, "total_actions")?;
+            index += sz;
+            let (n_key_actions, sz): (Card8, usize) = parse_item(bytes, index, "n_key_actions")?;
             index += sz;
-            first_key_action
-        } else {
-            Default::default()
-        };
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let first_key_behavior: Keycode = if cond0.server_symbols() {
             let (first_key_behavior, sz): (Keycode, usize) =
-                <Keycode>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "first_key_behavior")?;
             index += sz;
-            first_key_behavior
-        } else {
-            Default::default()
-        };
-        let n_key_behaviors: Card8 = if cond0.server_symbols() {
-            let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_key_behaviors, sz): (Card8, usize) =
+                parse_item(bytes, index, "n_key_behaviors")?;
+            index += sz;
+            let (total_key_behaviors, sz): (Card8, usize) =
+                parse_item(bytes, index, "total_key_behaviors")?;
             index += sz;
-            n_key_behaviors
-        } else {
-            Default::default()
-        };
-        let (len3, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let first_key_explicit: Keycode = if cond0.server_symbols() {
             let (first_key_explicit, sz): (Keycode, usize) =
-                <Keycode>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "first_key_explicit")?;
             index += sz;
-            first_key_explicit
-        } else {
-            Default::default()
-        };
-        let n_key_explicit: Card8 = if cond0.server_symbols() {
-            let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_key_explicit, sz): (Card8, usize) = parse_item(bytes, index, "n_key_explicit")?;
             index += sz;
-            n_key_explicit
-        } else {
-            Default::default()
-        };
-        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let first_mod_map_key: Keycode = if cond0.server_symbols() {
-            let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (total_key_explicit, sz): (Card8, usize) =
+                parse_item(bytes, index, "total_key_explicit")?;
             index += sz;
-            first_mod_map_key
-        } else {
-            Default::default()
-        };
-        let n_mod_map_keys: Card8 = if cond0.server_symbols() {
-            let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (first_mod_map_key, sz): (Keycode, usize) =
+                parse_item(bytes, index, "first_mod_map_key")?;
+            index += sz;
+            let (n_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_mod_map_keys")?;
+            index += sz;
+            let (total_mod_map_keys, sz): (Card8, usize) =
+                parse_item(bytes, index, "total_mod_map_keys")?;
             index += sz;
-            n_mod_map_keys
-        } else {
-            Default::default()
-        };
-        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let first_v_mod_map_key: Keycode = if cond0.server_symbols() {
             let (first_v_mod_map_key, sz): (Keycode, usize) =
-                <Keycode>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "first_v_mod_map_key")?;
             index += sz;
-            first_v_mod_map_key
-        } else {
-            Default::default()
-        };
-        let n_v_mod_map_keys: Card8 = if cond0.server_symbols() {
-            let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_v_mod_map_keys, sz): (Card8, usize) =
+                parse_item(bytes, index, "n_v_mod_map_keys")?;
             index += sz;
-            n_v_mod_map_keys
-        } else {
-            Default::default()
-        };
-        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let virtual_mods: VMod = if cond0.server_symbols() {
-            let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+            let (total_v_mod_map_keys, sz): (Card8, usize) =
+                parse_item(bytes, index, "total_v_mod_map_keys")?;
             index += sz;
-            virtual_mods
-        } else {
-            Default::default()
-        };
-        let (types_rtrn, block_len): (Vec<KeyType>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-        let (syms_rtrn, block_len): (Vec<KeySymMap>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-        let (acts_rtrn_count, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
-        let (acts_rtrn_acts, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
-        let (behaviors_rtrn, block_len): (Vec<SetBehavior>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-        let (vmods_rtrn, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let (explicit_rtrn, block_len): (Vec<SetExplicit>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-        let (modmap_rtrn, block_len): (Vec<KeyModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-        let (vmodmap_rtrn, block_len): (Vec<KeyVModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len6 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
-        let compatmap_type: Card8 = if cond0.compat_map() {
-            let (compatmap_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            index += 1;
+            let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
             index += sz;
-            compatmap_type
-        } else {
-            Default::default()
+            let cond1 = (present);
+            let (types_rtrn,) = if cond1.key_types() {
+                let (types_rtrn, block_len): (Vec<KeyType>, usize) =
+                    parse_vector(bytes, index, (n_types as usize) as usize, "types_rtrn")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+                (types_rtrn,)
+            } else {
+                (Default::default(),)
+            };
+            let (syms_rtrn,) = if cond1.key_syms() {
+                let (syms_rtrn, block_len): (Vec<KeySymMap>, usize) =
+                    parse_vector(bytes, index, (n_key_syms as usize) as usize, "syms_rtrn")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                (syms_rtrn,)
+            } else {
+                (Default::default(),)
+            };
+            let (acts_rtrn_count, acts_rtrn_acts) = if cond1.key_actions() {
+                let (acts_rtrn_count, block_len): (Vec<Card8>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (n_key_actions as usize) as usize,
+                    "acts_rtrn_count",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                index += buffer_pad(index, 4);
+                let (acts_rtrn_acts, block_len): (Vec<Action>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (total_actions as usize) as usize,
+                    "acts_rtrn_acts",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
+                (acts_rtrn_count, acts_rtrn_acts)
+            } else {
+                (Default::default(), Default::default())
+            };
+            let (behaviors_rtrn,) = if cond1.key_behaviors() {
+                let (behaviors_rtrn, block_len): (Vec<SetBehavior>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (total_key_behaviors as usize) as usize,
+                    "behaviors_rtrn",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+                (behaviors_rtrn,)
+            } else {
+                (Default::default(),)
+            };
+            let (vmods_rtrn,) = if cond1.virtual_mods() {
+                let (vmods_rtrn, block_len): (Vec<Card8>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    ((virtual_mods).count_ones()) as usize,
+                    "vmods_rtrn",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                index += buffer_pad(index, 4);
+                (vmods_rtrn,)
+            } else {
+                (Default::default(),)
+            };
+            let (explicit_rtrn,) = if cond1.explicit_components() {
+                let (explicit_rtrn, block_len): (Vec<SetExplicit>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (total_key_explicit as usize) as usize,
+                    "explicit_rtrn",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                index += buffer_pad(index, 4);
+                (explicit_rtrn,)
+            } else {
+                (Default::default(),)
+            };
+            let (modmap_rtrn,) = if cond1.modifier_map() {
+                let (modmap_rtrn, block_len): (Vec<KeyModMap>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (total_mod_map_keys as usize) as usize,
+                    "modmap_rtrn",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+                index += buffer_pad(index, 4);
+                (modmap_rtrn,)
+            } else {
+                (Default::default(),)
+            };
+            let (vmodmap_rtrn,) = if cond1.virtual_mod_map() {
+                let (vmodmap_rtrn, block_len): (Vec<KeyVModMap>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (total_v_mod_map_keys as usize) as usize,
+                    "vmodmap_rtrn",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+                (vmodmap_rtrn,)
+            } else {
+                (Default::default(),)
+            };
+            (
+                Some(getmap_type),
+                Some(type_device_id),
+                Some(getmap_sequence),
+                Some(getmap_length),
+                Some(type_min_key_code),
+                Some(type_max_key_code),
+                Some(present),
+                Some(first_type),
+                Some(n_types),
+                Some(total_types),
+                Some(first_key_sym),
+                Some(total_syms),
+                Some(n_key_syms),
+                Some(first_key_action),
+                Some(total_actions),
+                Some(n_key_actions),
+                Some(first_key_behavior),
+                Some(n_key_behaviors),
+                Some(total_key_behaviors),
+                Some(first_key_explicit),
+                Some(n_key_explicit),
+                Some(total_key_explicit),
+                Some(first_mod_map_key),
+                Some(n_mod_map_keys),
+                Some(total_mod_map_keys),
+                Some(first_v_mod_map_key),
+                Some(n_v_mod_map_keys),
+                Some(total_v_mod_map_keys),
+                Some(virtual_mods),
+                types_rtrn,
+                syms_rtrn,
+                acts_rtrn_count,
+                acts_rtrn_acts,
+                behaviors_rtrn,
+                vmods_rtrn,
+                explicit_rtrn,
+                modmap_rtrn,
+                vmodmap_rtrn,
+            )
+        } else {
+            (
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )
         };
-        let compat_device_id: Card8 = if cond0.compat_map() {
-            let (compat_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (
+            compatmap_type,
+            compat_device_id,
+            compatmap_sequence,
+            compatmap_length,
+            groups_rtrn,
+            first_si_rtrn,
+            n_total_si,
+            si_rtrn,
+            group_rtrn,
+        ) = if cond0.compat_map() {
+            let (compatmap_type, sz): (Card8, usize) = parse_item(bytes, index, "compatmap_type")?;
             index += sz;
-            compat_device_id
-        } else {
-            Default::default()
-        };
-        let compatmap_sequence: Card16 = if cond0.compat_map() {
-            let (compatmap_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (compat_device_id, sz): (Card8, usize) =
+                parse_item(bytes, index, "compat_device_id")?;
             index += sz;
-            compatmap_sequence
-        } else {
-            Default::default()
-        };
-        let compatmap_length: Card32 = if cond0.compat_map() {
-            let (compatmap_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (compatmap_sequence, sz): (Card16, usize) =
+                parse_item(bytes, index, "compatmap_sequence")?;
             index += sz;
-            compatmap_length
-        } else {
-            Default::default()
-        };
-        let groups_rtrn: SetOfGroup = if cond0.compat_map() {
-            let (groups_rtrn, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+            let (compatmap_length, sz): (Card32, usize) =
+                parse_item(bytes, index, "compatmap_length")?;
             index += sz;
-            groups_rtrn
-        } else {
-            Default::default()
-        };
-        let first_si_rtrn: Card16 = if cond0.compat_map() {
-            let (first_si_rtrn, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (groups_rtrn, sz): (SetOfGroup, usize) = parse_item(bytes, index, "groups_rtrn")?;
             index += sz;
-            first_si_rtrn
-        } else {
-            Default::default()
-        };
-        let (len7, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let n_total_si: Card16 = if cond0.compat_map() {
-            let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            index += 1;
+            let (first_si_rtrn, sz): (Card16, usize) = parse_item(bytes, index, "first_si_rtrn")?;
             index += sz;
-            n_total_si
-        } else {
-            Default::default()
-        };
-        let (si_rtrn, block_len): (Vec<SymInterpret>, usize) =
-            vector_from_bytes(&bytes[index..], len7 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
-        let (group_rtrn, block_len): (Vec<ModDef>, usize) =
-            vector_from_bytes(&bytes[index..], ((groups_rtrn).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
-        let indicatormap_type: Card8 = if cond0.indicator_maps() {
-            let (indicatormap_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
             index += sz;
-            indicatormap_type
-        } else {
-            Default::default()
-        };
-        let indicator_device_id: Card8 = if cond0.indicator_maps() {
-            let (indicator_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_total_si, sz): (Card16, usize) = parse_item(bytes, index, "n_total_si")?;
             index += sz;
-            indicator_device_id
-        } else {
-            Default::default()
+            index += 16;
+            let (si_rtrn, block_len): (Vec<SymInterpret>, usize) =
+                parse_vector(bytes, index, len0 as usize, "si_rtrn")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+            let (group_rtrn, block_len): (Vec<ModDef>, usize) = parse_vector(
+                bytes,
+                index,
+                ((groups_rtrn).count_ones()) as usize,
+                "group_rtrn",
+            )?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+            (
+                Some(compatmap_type),
+                Some(compat_device_id),
+                Some(compatmap_sequence),
+                Some(compatmap_length),
+                Some(groups_rtrn),
+                Some(first_si_rtrn),
+                Some(n_total_si),
+                si_rtrn,
+                group_rtrn,
+            )
+        } else {
+            (
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )
         };
-        let indicatormap_sequence: Card16 = if cond0.indicator_maps() {
-            let (indicatormap_sequence, sz): (Card16, usize) =
-                <Card16>::from_bytes(&bytes[index..])?;
+        let (
+            indicatormap_type,
+            indicator_device_id,
+            indicatormap_sequence,
+            indicatormap_length,
+            which,
+            real_indicators,
+            maps,
+        ) = if cond0.indicator_maps() {
+            let (indicatormap_type, sz): (Card8, usize) =
+                parse_item(bytes, index, "indicatormap_type")?;
             index += sz;
-            indicatormap_sequence
-        } else {
-            Default::default()
-        };
-        let indicatormap_length: Card32 = if cond0.indicator_maps() {
-            let (indicatormap_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (indicator_device_id, sz): (Card8, usize) =
+                parse_item(bytes, index, "indicator_device_id")?;
             index += sz;
-            indicatormap_length
-        } else {
-            Default::default()
-        };
-        let which: Card32 = if cond0.indicator_maps() {
-            let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (indicatormap_sequence, sz): (Card16, usize) =
+                parse_item(bytes, index, "indicatormap_sequence")?;
             index += sz;
-            which
-        } else {
-            Default::default()
-        };
-        let real_indicators: Card32 = if cond0.indicator_maps() {
-            let (real_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (indicatormap_length, sz): (Card32, usize) =
+                parse_item(bytes, index, "indicatormap_length")?;
             index += sz;
-            real_indicators
-        } else {
-            Default::default()
-        };
-        let (len8, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 15;
-        let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], len8 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-        let keyname_type: Card8 = if cond0.other_names() {
-            let (keyname_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (which, sz): (Card32, usize) = parse_item(bytes, index, "which")?;
             index += sz;
-            keyname_type
-        } else {
-            Default::default()
-        };
-        let key_device_id: Card8 = if cond0.other_names() {
-            let (key_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (real_indicators, sz): (Card32, usize) =
+                parse_item(bytes, index, "real_indicators")?;
             index += sz;
-            key_device_id
-        } else {
-            Default::default()
-        };
-        let keyname_sequence: Card16 = if cond0.other_names() {
-            let (keyname_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
             index += sz;
-            keyname_sequence
-        } else {
-            Default::default()
+            index += 15;
+            let (maps, block_len): (Vec<IndicatorMap>, usize) =
+                parse_vector(bytes, index, len1 as usize, "maps")?;
+            index += block_len;
+            index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+            (
+                Some(indicatormap_type),
+                Some(indicator_device_id),
+                Some(indicatormap_sequence),
+                Some(indicatormap_length),
+                Some(which),
+                Some(real_indicators),
+                maps,
+            )
+        } else {
+            (
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )
         };
-        let keyname_length: Card32 = if cond0.other_names() {
-            let (keyname_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (
+            keyname_type,
+            key_device_id,
+            keyname_sequence,
+            keyname_length,
+            which_,
+            key_min_key_code,
+            key_max_key_code,
+            n_types_,
+            group_names,
+            virtual_mods_,
+            first_key,
+            n_keys,
+            indicators,
+            n_radio_groups,
+            n_key_aliases,
+            n_kt_levels,
+            keycodes_name,
+            geometry_name,
+            symbols_name,
+            phys_symbols_name,
+            types_name,
+            compat_name,
+            type_names,
+            n_levels_per_type,
+            kt_level_names,
+            indicator_names,
+            virtual_mod_names,
+            groups,
+            key_names,
+            key_aliases,
+            radio_group_names,
+        ) = if cond0.other_names() {
+            let (keyname_type, sz): (Card8, usize) = parse_item(bytes, index, "keyname_type")?;
             index += sz;
-            keyname_length
-        } else {
-            Default::default()
-        };
-        let which_: NameDetail = if cond0.other_names() {
-            let (which_, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+            let (key_device_id, sz): (Card8, usize) = parse_item(bytes, index, "key_device_id")?;
             index += sz;
-            which_
-        } else {
-            Default::default()
-        };
-        let key_min_key_code: Keycode = if cond0.other_names() {
-            let (key_min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (keyname_sequence, sz): (Card16, usize) =
+                parse_item(bytes, index, "keyname_sequence")?;
             index += sz;
-            key_min_key_code
-        } else {
-            Default::default()
-        };
-        let key_max_key_code: Keycode = if cond0.other_names() {
-            let (key_max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (keyname_length, sz): (Card32, usize) = parse_item(bytes, index, "keyname_length")?;
             index += sz;
-            key_max_key_code
-        } else {
-            Default::default()
-        };
-        let n_types_: Card8 = if cond0.other_names() {
-            let (n_types_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (which_, sz): (NameDetail, usize) = parse_item(bytes, index, "which_")?;
             index += sz;
-            n_types_
-        } else {
-            Default::default()
-        };
-        let group_names: SetOfGroup = if cond0.other_names() {
-            let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+            let (key_min_key_code, sz): (Keycode, usize) =
+                parse_item(bytes, index, "key_min_key_code")?;
             index += sz;
-            group_names
-        } else {
-            Default::default()
-        };
-        let virtual_mods_: VMod = if cond0.other_names() {
-            let (virtual_mods_, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+            let (key_max_key_code, sz): (Keycode, usize) =
+                parse_item(bytes, index, "key_max_key_code")?;
             index += sz;
-            virtual_mods_
-        } else {
-            Default::default()
-        };
-        let first_key: Keycode = if cond0.other_names() {
-            let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (n_types_, sz): (Card8, usize) = parse_item(bytes, index, "n_types_")?;
             index += sz;
-            first_key
-        } else {
-            Default::default()
-        };
-        let (len9, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let indicators: Card32 = if cond0.other_names() {
-            let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (group_names, sz): (SetOfGroup, usize) = parse_item(bytes, index, "group_names")?;
             index += sz;
-            indicators
-        } else {
-            Default::default()
-        };
-        let (len10, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len11, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let n_kt_levels: Card16 = if cond0.other_names() {
-            let (n_kt_levels, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (virtual_mods_, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods_")?;
             index += sz;
-            n_kt_levels
-        } else {
-            Default::default()
-        };
-        let keycodes_name: Atom = if cond0.other_names() {
-            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (first_key, sz): (Keycode, usize) = parse_item(bytes, index, "first_key")?;
             index += sz;
-            keycodes_name
-        } else {
-            Default::default()
-        };
-        let geometry_name: Atom = if cond0.other_names() {
-            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (n_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_keys")?;
             index += sz;
-            geometry_name
-        } else {
-            Default::default()
-        };
-        let symbols_name: Atom = if cond0.other_names() {
-            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (indicators, sz): (Card32, usize) = parse_item(bytes, index, "indicators")?;
             index += sz;
-            symbols_name
-        } else {
-            Default::default()
-        };
-        let phys_symbols_name: Atom = if cond0.other_names() {
-            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (n_radio_groups, sz): (Card8, usize) = parse_item(bytes, index, "n_radio_groups")?;
             index += sz;
-            phys_symbols_name
-        } else {
-            Default::default()
-        };
-        let types_name: Atom = if cond0.other_names() {
-            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (n_key_aliases, sz): (Card8, usize) = parse_item(bytes, index, "n_key_aliases")?;
             index += sz;
-            types_name
-        } else {
-            Default::default()
-        };
-        let compat_name: Atom = if cond0.other_names() {
-            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (n_kt_levels, sz): (Card16, usize) = parse_item(bytes, index, "n_kt_levels")?;
             index += sz;
-            compat_name
-        } else {
-            Default::default()
+            index += 4;
+            let cond2 = (which_);
+            let (keycodes_name,) = if cond2.keycodes() {
+                let (keycodes_name, sz): (Atom, usize) = parse_item(bytes, index, "keycodes_name")?;
+                index += sz;
+                (Some(keycodes_name),)
+            } else {
+                (Default::default(),)
+            };
+            let (geometry_name,) = if cond2.geometry() {
+                let (geometry_name, sz): (Atom, usize) = parse_item(bytes, index, "geometry_name")?;
+                index += sz;
+                (Some(geometry_name),)
+            } else {
+                (Default::default(),)
+            };
+            let (symbols_name,) = if cond2.symbols() {
+                let (symbols_name, sz): (Atom, usize) = parse_item(bytes, index, "symbols_name")?;
+                index += sz;
+                (Some(symbols_name),)
+            } else {
+                (Default::default(),)
+            };
+            let (phys_symbols_name,) = if cond2.phys_symbols() {
+                let (phys_symbols_name, sz): (Atom, usize) =
+                    parse_item(bytes, index, "phys_symbols_name")?;
+                index += sz;
+                (Some(phys_symbols_name),)
+            } else {
+                (Default::default(),)
+            };
+            let (types_name,) = if cond2.types() {
+                let (types_name, sz): (Atom, usize) = parse_item(bytes, index, "types_name")?;
+                index += sz;
+                (Some(types_name),)
+            } else {
+                (Default::default(),)
+            };
+            let (compat_name,) = if cond2.compat() {
+                let (compat_name, sz): (Atom, usize) = parse_item(bytes, index, "compat_name")?;
+                index += sz;
+                (Some(compat_name),)
+            } else {
+                (Default::default(),)
+            };
+            let (type_names,) = if cond2.key_type_names() {
+                let (type_names, block_len): (Vec<Atom>, usize) =
+                    parse_vector(bytes, index, (n_types_ as usize) as usize, "type_names")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                (type_names,)
+            } else {
+                (Default::default(),)
+            };
+            let (n_levels_per_type, kt_level_names) = if cond2.kt_level_names() {
+                let (n_levels_per_type, block_len): (Vec<Card8>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (n_types_ as usize) as usize,
+                    "n_levels_per_type",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                index += buffer_pad(index, 4);
+                let (kt_level_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (n_levels_per_type
+                        .iter()
+                        .map(|a| {
+                            (TryInto::<usize>::try_into(*a).expect("Unable to cast type to usize"))
+                                as usize
+                        })
+                        .sum::<usize>()) as usize,
+                    "kt_level_names",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                (n_levels_per_type, kt_level_names)
+            } else {
+                (Default::default(), Default::default())
+            };
+            let (indicator_names,) = if cond2.indicator_names() {
+                let (indicator_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    ((indicators).count_ones()) as usize,
+                    "indicator_names",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                (indicator_names,)
+            } else {
+                (Default::default(),)
+            };
+            let (virtual_mod_names,) = if cond2.virtual_mod_names() {
+                let (virtual_mod_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    ((virtual_mods_).count_ones()) as usize,
+                    "virtual_mod_names",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                (virtual_mod_names,)
+            } else {
+                (Default::default(),)
+            };
+            let (groups,) = if cond2.group_names() {
+                let (groups, block_len): (Vec<Atom>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    ((group_names).count_ones()) as usize,
+                    "groups",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                (groups,)
+            } else {
+                (Default::default(),)
+            };
+            let (key_names,) = if cond2.key_names() {
+                let (key_names, block_len): (Vec<KeyName>, usize) =
+                    parse_vector(bytes, index, (n_keys as usize) as usize, "key_names")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+                (key_names,)
+            } else {
+                (Default::default(),)
+            };
+            let (key_aliases,) = if cond2.key_aliases() {
+                let (key_aliases, block_len): (Vec<KeyAlias>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (n_key_aliases as usize) as usize,
+                    "key_aliases",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+                (key_aliases,)
+            } else {
+                (Default::default(),)
+            };
+            let (radio_group_names,) = if cond2.rg_names() {
+                let (radio_group_names, block_len): (Vec<Atom>, usize) = parse_vector(
+                    bytes,
+                    index,
+                    (n_radio_groups as usize) as usize,
+                    "radio_group_names",
+                )?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                (radio_group_names,)
+            } else {
+                (Default::default(),)
+            };
+            (
+                Some(keyname_type),
+                Some(key_device_id),
+                Some(keyname_sequence),
+                Some(keyname_length),
+                Some(which_),
+                Some(key_min_key_code),
+                Some(key_max_key_code),
+                Some(n_types_),
+                Some(group_names),
+                Some(virtual_mods_),
+                Some(first_key),
+                Some(n_keys),
+                Some(indicators),
+                Some(n_radio_groups),
+                Some(n_key_aliases),
+                Some(n_kt_levels),
+                keycodes_name,
+                geometry_name,
+                symbols_name,
+                phys_symbols_name,
+                types_name,
+                compat_name,
+                type_names,
+                n_levels_per_type,
+                kt_level_names,
+                indicator_names,
+                virtual_mod_names,
+                groups,
+                key_names,
+                key_aliases,
+                radio_group_names,
+            )
+        } else {
+            (
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )
         };
-        let (type_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (n_levels_per_type, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        let (kt_level_names, block_len): (Vec<Atom>, usize) = vector_from_bytes(
-            &bytes[index..],
-            (n_levels_per_type
-                .iter()
-                .map(|a| {
-                    (TryInto::<usize>::try_into(*a).expect("Unable to cast type to usize")) as usize
-                })
-                .sum::<usize>()) as usize,
-        )?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (indicator_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((indicators).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (virtual_mod_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (groups, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((group_names).count_ones()) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let (key_names, block_len): (Vec<KeyName>, usize) =
-            vector_from_bytes(&bytes[index..], len9 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-        let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
-            vector_from_bytes(&bytes[index..], len11 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-        let (radio_group_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len10 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-        let geometry_type: Card8 = if cond0.geometry() {
-            let (geometry_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (
+            geometry_type,
+            geometry_device_id,
+            geometry_sequence,
+            geometry_length,
+            name,
+            geometry_found,
+            width_mm,
+            height_mm,
+            n_properties,
+            n_colors,
+            n_shapes,
+            n_sections,
+            n_doodads,
+            n_key_aliases_,
+            base_color_ndx,
+            label_color_ndx,
+            label_font,
+        ) = if cond0.geometry() {
+            let (geometry_type, sz): (Card8, usize) = parse_item(bytes, index, "geometry_type")?;
             index += sz;
-            geometry_type
-        } else {
-            Default::default()
-        };
-        let geometry_device_id: Card8 = if cond0.geometry() {
-            let (geometry_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (geometry_device_id, sz): (Card8, usize) =
+                parse_item(bytes, index, "geometry_device_id")?;
             index += sz;
-            geometry_device_id
-        } else {
-            Default::default()
-        };
-        let geometry_sequence: Card16 = if cond0.geometry() {
-            let (geometry_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (geometry_sequence, sz): (Card16, usize) =
+                parse_item(bytes, index, "geometry_sequence")?;
             index += sz;
-            geometry_sequence
-        } else {
-            Default::default()
-        };
-        let geometry_length: Card32 = if cond0.geometry() {
-            let (geometry_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (geometry_length, sz): (Card32, usize) =
+                parse_item(bytes, index, "geometry_length")?;
             index += sz;
-            geometry_length
-        } else {
-            Default::default()
-        };
-        let name: Atom = if cond0.geometry() {
-            let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
             index += sz;
-            name
-        } else {
-            Default::default()
-        };
-        let geometry_found: bool = if cond0.geometry() {
-            let (geometry_found, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+            let (geometry_found, sz): (bool, usize) = parse_item(bytes, index, "geometry_found")?;
             index += sz;
-            geometry_found
-        } else {
-            Default::default()
-        };
-        let width_mm: Card16 = if cond0.geometry() {
-            let (width_mm, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            index += 1;
+            let (width_mm, sz): (Card16, usize) = parse_item(bytes, index, "width_mm")?;
             index += sz;
-            width_mm
-        } else {
-            Default::default()
-        };
-        let height_mm: Card16 = if cond0.geometry() {
-            let (height_mm, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (height_mm, sz): (Card16, usize) = parse_item(bytes, index, "height_mm")?;
             index += sz;
-            height_mm
-        } else {
-            Default::default()
-        };
-        let n_properties: Card16 = if cond0.geometry() {
-            let (n_properties, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_properties, sz): (Card16, usize) = parse_item(bytes, index, "n_properties")?;
             index += sz;
-            n_properties
-        } else {
-            Default::default()
-        };
-        let n_colors: Card16 = if cond0.geometry() {
-            let (n_colors, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_colors, sz): (Card16, usize) = parse_item(bytes, index, "n_colors")?;
             index += sz;
-            n_colors
-        } else {
-            Default::default()
-        };
-        let n_shapes: Card16 = if cond0.geometry() {
-            let (n_shapes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_shapes, sz): (Card16, usize) = parse_item(bytes, index, "n_shapes")?;
             index += sz;
-            n_shapes
-        } else {
-            Default::default()
-        };
-        let n_sections: Card16 = if cond0.geometry() {
-            let (n_sections, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_sections, sz): (Card16, usize) = parse_item(bytes, index, "n_sections")?;
             index += sz;
-            n_sections
-        } else {
-            Default::default()
-        };
-        let n_doodads: Card16 = if cond0.geometry() {
-            let (n_doodads, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_doodads, sz): (Card16, usize) = parse_item(bytes, index, "n_doodads")?;
             index += sz;
-            n_doodads
-        } else {
-            Default::default()
-        };
-        let n_key_aliases: Card16 = if cond0.geometry() {
-            let (n_key_aliases, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_key_aliases_, sz): (Card16, usize) = parse_item(bytes, index, "n_key_aliases_")?;
             index += sz;
-            n_key_aliases
-        } else {
-            Default::default()
-        };
-        let base_color_ndx: Card8 = if cond0.geometry() {
-            let (base_color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (base_color_ndx, sz): (Card8, usize) = parse_item(bytes, index, "base_color_ndx")?;
             index += sz;
-            base_color_ndx
-        } else {
-            Default::default()
-        };
-        let label_color_ndx: Card8 = if cond0.geometry() {
-            let (label_color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (label_color_ndx, sz): (Card8, usize) =
+                parse_item(bytes, index, "label_color_ndx")?;
             index += sz;
-            label_color_ndx
-        } else {
-            Default::default()
-        };
-        let label_font: CountedString16 = if cond0.geometry() {
             let (label_font, sz): (CountedString16, usize) =
-                <CountedString16>::from_bytes(&bytes[index..])?;
+                parse_item(bytes, index, "label_font")?;
             index += sz;
-            label_font
-        } else {
-            Default::default()
+            (
+                Some(geometry_type),
+                Some(geometry_device_id),
+                Some(geometry_sequence),
+                Some(geometry_length),
+                Some(name),
+                Some(geometry_found),
+                Some(width_mm),
+                Some(height_mm),
+                Some(n_properties),
+                Some(n_colors),
+                Some(n_shapes),
+                Some(n_sections),
+                Some(n_doodads),
+                Some(n_key_aliases_),
+                Some(base_color_ndx),
+                Some(label_color_ndx),
+                Some(label_font),
+            )
+        } else {
+            (
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )
         };
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetKbdByNameReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -12589,15 +18354,22 @@ impl AsByteSequence for GetKbdByNameReply {
                 total_types: total_types,
                 first_key_sym: first_key_sym,
                 total_syms: total_syms,
+                n_key_syms: n_key_syms,
                 first_key_action: first_key_action,
+                total_actions: total_actions,
+                n_key_actions: n_key_actions,
                 first_key_behavior: first_key_behavior,
                 n_key_behaviors: n_key_behaviors,
+                total_key_behaviors: total_key_behaviors,
                 first_key_explicit: first_key_explicit,
                 n_key_explicit: n_key_explicit,
+                total_key_explicit: total_key_explicit,
                 first_mod_map_key: first_mod_map_key,
                 n_mod_map_keys: n_mod_map_keys,
+                total_mod_map_keys: total_mod_map_keys,
                 first_v_mod_map_key: first_v_mod_map_key,
                 n_v_mod_map_keys: n_v_mod_map_keys,
+                total_v_mod_map_keys: total_v_mod_map_keys,
                 virtual_mods: virtual_mods,
                 types_rtrn: types_rtrn,
                 syms_rtrn: syms_rtrn,
@@ -12635,7 +18407,10 @@ impl AsByteSequence for GetKbdByNameReply {
                 group_names: group_names,
                 virtual_mods_: virtual_mods_,
                 first_key: first_key,
+                n_keys: n_keys,
                 indicators: indicators,
+                n_radio_groups: n_radio_groups,
+                n_key_aliases: n_key_aliases,
                 n_kt_levels: n_kt_levels,
                 keycodes_name: keycodes_name,
                 geometry_name: geometry_name,
@@ -12665,7 +18440,7 @@ impl AsByteSequence for GetKbdByNameReply {
                 n_shapes: n_shapes,
                 n_sections: n_sections,
                 n_doodads: n_doodads,
-                n_key_aliases: n_key_aliases,
+                n_key_aliases_: n_key_aliases_,
                 base_color_ndx: base_color_ndx,
                 label_color_ndx: label_color_ndx,
                 label_font: label_font,
@@ -12673,159 +18448,386 @@ impl AsByteSequence for GetKbdByNameReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.loaded.size()
-            + self.new_keyboard.size()
-            + self.found.size()
-            + self.reported.size()
-            + 16
-            + self.getmap_type.size()
-            + self.type_device_id.size()
-            + self.getmap_sequence.size()
-            + self.getmap_length.size()
-            + 2
-            + self.type_min_key_code.size()
-            + self.type_max_key_code.size()
-            + self.present.size()
-            + self.first_type.size()
-            + self.n_types.size()
-            + self.total_types.size()
-            + self.first_key_sym.size()
-            + self.total_syms.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_action.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_behavior.size()
-            + self.n_key_behaviors.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + 1
-            + self.virtual_mods.size()
+        let size: usize = ({
+            let size: usize = ({
+                let size: usize = ({
+                    let size: usize = ({
+                        let size: usize = ({
+                            let size: usize = 31
+                                + self.min_key_code.size()
+                                + self.max_key_code.size()
+                                + self.loaded.size()
+                                + self.new_keyboard.size()
+                                + self.getmap_type.size()
+                                + self.type_device_id.size()
+                                + self.getmap_sequence.size()
+                                + self.getmap_length.size()
+                                + self.type_min_key_code.size()
+                                + self.type_max_key_code.size()
+                                + self.present.size()
+                                + self.first_type.size()
+                                + self.n_types.size()
+                                + self.total_types.size()
+                                + self.first_key_sym.size()
+                                + self.total_syms.size()
+                                + self.n_key_syms.size()
+                                + self.first_key_action.size()
+                                + self.total_actions.size()
+                                + self.n_key_actions.size()
+                                + self.first_key_behavior.size()
+                                + self.n_key_behaviors.size()
+                                + self.total_key_behaviors.size()
+                                + self.first_key_explicit.size()
+                                + self.n_key_explicit.size()
+                                + self.total_key_explicit.size()
+                                + self.first_mod_map_key.size()
+                                + self.n_mod_map_keys.size()
+                                + self.total_mod_map_keys.size()
+                                + self.first_v_mod_map_key.size()
+                                + self.n_v_mod_map_keys.size()
+                                + self.total_v_mod_map_keys.size()
+                                + self.virtual_mods.size()
+                                + {
+                                    let block_len: usize =
+                                        self.types_rtrn.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+                                    block_len + pad
+                                }
+                                + {
+                                    let block_len: usize =
+                                        self.syms_rtrn.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                                    block_len + pad
+                                }
+                                + {
+                                    let block_len: usize =
+                                        self.acts_rtrn_count.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                                    block_len + pad
+                                };
+                            size + buffer_pad(size, 4)
+                        }) + {
+                            let block_len: usize =
+                                self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<Action>());
+                            block_len + pad
+                        } + {
+                            let block_len: usize =
+                                self.behaviors_rtrn.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+                            block_len + pad
+                        } + {
+                            let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                            block_len + pad
+                        };
+                        size + buffer_pad(size, 4)
+                    }) + {
+                        let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                        block_len + pad
+                    };
+                    size + buffer_pad(size, 4)
+                }) + {
+                    let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }) + 39
+                + {
+                    let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+                    block_len + pad
+                }
+                + self.compatmap_type.size()
+                + self.compat_device_id.size()
+                + self.compatmap_sequence.size()
+                + self.compatmap_length.size()
+                + self.groups_rtrn.size()
+                + self.first_si_rtrn.size()
+                + self.n_total_si.size()
+                + {
+                    let block_len: usize = self.si_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.group_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+                    block_len + pad
+                }
+                + self.indicatormap_type.size()
+                + self.indicator_device_id.size()
+                + self.indicatormap_sequence.size()
+                + self.indicatormap_length.size()
+                + self.which.size()
+                + self.real_indicators.size()
+                + {
+                    let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+                    block_len + pad
+                }
+                + self.keyname_type.size()
+                + self.key_device_id.size()
+                + self.keyname_sequence.size()
+                + self.keyname_length.size()
+                + self.which_.size()
+                + self.key_min_key_code.size()
+                + self.key_max_key_code.size()
+                + self.n_types_.size()
+                + self.group_names.size()
+                + self.virtual_mods_.size()
+                + self.first_key.size()
+                + self.n_keys.size()
+                + self.indicators.size()
+                + self.n_radio_groups.size()
+                + self.n_key_aliases.size()
+                + self.n_kt_levels.size()
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + 1
             + {
-                let block_len: usize = self.types_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.syms_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.acts_rtrn_count.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
-                let block_len: usize = self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.behaviors_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
                 block_len + pad
             }
             + {
-                let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+                let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
                 block_len + pad
             }
             + {
-                let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+                let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
                 block_len + pad
             }
-            + self.compatmap_type.size()
-            + self.compat_device_id.size()
-            + self.compatmap_sequence.size()
-            + self.compatmap_length.size()
-            + self.groups_rtrn.size()
-            + self.first_si_rtrn.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.n_total_si.size()
             + {
-                let block_len: usize = self.si_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+                let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
                 block_len + pad
             }
             + {
-                let block_len: usize = self.group_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+                let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
                 block_len + pad
             }
-            + self.indicatormap_type.size()
-            + self.indicator_device_id.size()
-            + self.indicatormap_sequence.size()
-            + self.indicatormap_length.size()
-            + self.which.size()
-            + self.real_indicators.size()
-            + ::core::mem::size_of::<Card8>()
-            + 15
             + {
-                let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+                let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
                 block_len + pad
             }
-            + self.keyname_type.size()
-            + self.key_device_id.size()
-            + self.keyname_sequence.size()
-            + self.keyname_length.size()
-            + self.which_.size()
-            + self.key_min_key_code.size()
-            + self.key_max_key_code.size()
-            + self.n_types_.size()
-            + self.group_names.size()
-            + self.virtual_mods_.size()
-            + self.first_key.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.indicators.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + self.n_kt_levels.size()
-            + self.keycodes_name.size()
-            + self.geometry_name.size()
-            + self.symbols_name.size()
-            + self.phys_symbols_name.size()
-            + self.types_name.size()
-            + self.compat_name.size()
             + {
-                let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
                 block_len + pad
             }
-            + {
-                let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
+            + self.geometry_type.size()
+            + self.geometry_device_id.size()
+            + self.geometry_sequence.size()
+            + self.geometry_length.size()
+            + self.name.size()
+            + self.geometry_found.size()
+            + self.width_mm.size()
+            + self.height_mm.size()
+            + self.n_properties.size()
+            + self.n_colors.size()
+            + self.n_shapes.size()
+            + self.n_sections.size()
+            + self.n_doodads.size()
+            + self.n_key_aliases_.size()
+            + self.base_color_ndx.size()
+            + self.label_color_ndx.size()
+            + self.label_font.size();
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = ({
+            let size: usize = ({
+                let size: usize = ({
+                    let size: usize = ({
+                        let size: usize = ({
+                            let size: usize = 31
+                                + self.min_key_code.size()
+                                + self.max_key_code.size()
+                                + self.loaded.size()
+                                + self.new_keyboard.size()
+                                + self.getmap_type.size()
+                                + self.type_device_id.size()
+                                + self.getmap_sequence.size()
+                                + self.getmap_length.size()
+                                + self.type_min_key_code.size()
+                                + self.type_max_key_code.size()
+                                + self.present.size()
+                                + self.first_type.size()
+                                + self.n_types.size()
+                                + self.total_types.size()
+                                + self.first_key_sym.size()
+                                + self.total_syms.size()
+                                + self.n_key_syms.size()
+                                + self.first_key_action.size()
+                                + self.total_actions.size()
+                                + self.n_key_actions.size()
+                                + self.first_key_behavior.size()
+                                + self.n_key_behaviors.size()
+                                + self.total_key_behaviors.size()
+                                + self.first_key_explicit.size()
+                                + self.n_key_explicit.size()
+                                + self.total_key_explicit.size()
+                                + self.first_mod_map_key.size()
+                                + self.n_mod_map_keys.size()
+                                + self.total_mod_map_keys.size()
+                                + self.first_v_mod_map_key.size()
+                                + self.n_v_mod_map_keys.size()
+                                + self.total_v_mod_map_keys.size()
+                                + self.virtual_mods.size()
+                                + {
+                                    let block_len: usize =
+                                        self.types_rtrn.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+                                    block_len + pad
+                                }
+                                + {
+                                    let block_len: usize =
+                                        self.syms_rtrn.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                                    block_len + pad
+                                }
+                                + {
+                                    let block_len: usize = self.acts_rtrn_count.len()
+                                        * ::core::mem::size_of::<Card8>();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                                    block_len + pad
+                                };
+                            size + buffer_pad(size, 4)
+                        }) + {
+                            let block_len: usize =
+                                self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<Action>());
+                            block_len + pad
+                        } + {
+                            let block_len: usize =
+                                self.behaviors_rtrn.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+                            block_len + pad
+                        } + {
+                            let block_len: usize =
+                                self.vmods_rtrn.len() * ::core::mem::size_of::<Card8>();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                            block_len + pad
+                        };
+                        size + buffer_pad(size, 4)
+                    }) + {
+                        let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                        block_len + pad
+                    };
+                    size + buffer_pad(size, 4)
+                }) + {
+                    let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }) + 39
+                + {
+                    let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+                    block_len + pad
+                }
+                + self.compatmap_type.size()
+                + self.compat_device_id.size()
+                + self.compatmap_sequence.size()
+                + self.compatmap_length.size()
+                + self.groups_rtrn.size()
+                + self.first_si_rtrn.size()
+                + self.n_total_si.size()
+                + {
+                    let block_len: usize = self.si_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.group_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+                    block_len + pad
+                }
+                + self.indicatormap_type.size()
+                + self.indicator_device_id.size()
+                + self.indicatormap_sequence.size()
+                + self.indicatormap_length.size()
+                + self.which.size()
+                + self.real_indicators.size()
+                + {
+                    let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+                    block_len + pad
+                }
+                + self.keyname_type.size()
+                + self.key_device_id.size()
+                + self.keyname_sequence.size()
+                + self.keyname_length.size()
+                + self.which_.size()
+                + self.key_min_key_code.size()
+                + self.key_max_key_code.size()
+                + self.n_types_.size()
+                + self.group_names.size()
+                + self.virtual_mods_.size()
+                + self.first_key.size()
+                + self.n_keys.size()
+                + self.indicators.size()
+                + self.n_radio_groups.size()
+                + self.n_key_aliases.size()
+                + self.n_kt_levels.size()
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize =
+                        self.n_levels_per_type.len() * ::core::mem::size_of::<Card8>();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + 1
             + {
                 let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
@@ -12874,14 +18876,146 @@ impl AsByteSequence for GetKbdByNameReply {
             + self.n_shapes.size()
             + self.n_sections.size()
             + self.n_doodads.size()
-            + self.n_key_aliases.size()
+            + self.n_key_aliases_.size()
             + self.base_color_ndx.size()
             + self.label_color_ndx.size()
-            + self.label_font.size()
+            + self.label_font.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetKbdByNameReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for GetKbdByNameReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetKbdByNameReply")
+            .field("reply_type", &self.reply_type)
+            .field("device_id", &self.device_id)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("min_key_code", &self.min_key_code)
+            .field("max_key_code", &self.max_key_code)
+            .field("loaded", &self.loaded)
+            .field("new_keyboard", &self.new_keyboard)
+            .field("found", &self.found)
+            .field("reported", &self.reported)
+            .field("getmap_type", &self.getmap_type)
+            .field("type_device_id", &self.type_device_id)
+            .field("getmap_sequence", &self.getmap_sequence)
+            .field("getmap_length", &self.getmap_length)
+            .field("type_min_key_code", &self.type_min_key_code)
+            .field("type_max_key_code", &self.type_max_key_code)
+            .field("present", &self.present)
+            .field("first_type", &self.first_type)
+            .field("n_types", &self.n_types)
+            .field("total_types", &self.total_types)
+            .field("first_key_sym", &self.first_key_sym)
+            .field("total_syms", &self.total_syms)
+            .field("n_key_syms", &self.n_key_syms)
+            .field("first_key_action", &self.first_key_action)
+            .field("total_actions", &self.total_actions)
+            .field("n_key_actions", &self.n_key_actions)
+            .field("first_key_behavior", &self.first_key_behavior)
+            .field("n_key_behaviors", &self.n_key_behaviors)
+            .field("total_key_behaviors", &self.total_key_behaviors)
+            .field("first_key_explicit", &self.first_key_explicit)
+            .field("n_key_explicit", &self.n_key_explicit)
+            .field("total_key_explicit", &self.total_key_explicit)
+            .field("first_mod_map_key", &self.first_mod_map_key)
+            .field("n_mod_map_keys", &self.n_mod_map_keys)
+            .field("total_mod_map_keys", &self.total_mod_map_keys)
+            .field("first_v_mod_map_key", &self.first_v_mod_map_key)
+            .field("n_v_mod_map_keys", &self.n_v_mod_map_keys)
+            .field("total_v_mod_map_keys", &self.total_v_mod_map_keys)
+            .field("virtual_mods", &self.virtual_mods)
+            .field("types_rtrn", &self.types_rtrn)
+            .field("syms_rtrn", &self.syms_rtrn)
+            .field("acts_rtrn_count", &debug_bytes(&self.acts_rtrn_count, 64))
+            .field("acts_rtrn_acts", &self.acts_rtrn_acts)
+            .field("behaviors_rtrn", &self.behaviors_rtrn)
+            .field("vmods_rtrn", &debug_bytes(&self.vmods_rtrn, 64))
+            .field("explicit_rtrn", &self.explicit_rtrn)
+            .field("modmap_rtrn", &self.modmap_rtrn)
+            .field("vmodmap_rtrn", &self.vmodmap_rtrn)
+            .field("compatmap_type", &self.compatmap_type)
+            .field("compat_device_id", &self.compat_device_id)
+            .field("compatmap_sequence", &self.compatmap_sequence)
+            .field("compatmap_length", &self.compatmap_length)
+            .field("groups_rtrn", &self.groups_rtrn)
+            .field("first_si_rtrn", &self.first_si_rtrn)
+            .field("n_total_si", &self.n_total_si)
+            .field("si_rtrn", &self.si_rtrn)
+            .field("group_rtrn", &self.group_rtrn)
+            .field("indicatormap_type", &self.indicatormap_type)
+            .field("indicator_device_id", &self.indicator_device_id)
+            .field("indicatormap_sequence", &self.indicatormap_sequence)
+            .field("indicatormap_length", &self.indicatormap_length)
+            .field("which", &self.which)
+            .field("real_indicators", &self.real_indicators)
+            .field("maps", &self.maps)
+            .field("keyname_type", &self.keyname_type)
+            .field("key_device_id", &self.key_device_id)
+            .field("keyname_sequence", &self.keyname_sequence)
+            .field("keyname_length", &self.keyname_length)
+            .field("which_", &self.which_)
+            .field("key_min_key_code", &self.key_min_key_code)
+            .field("key_max_key_code", &self.key_max_key_code)
+            .field("n_types_", &self.n_types_)
+            .field("group_names", &self.group_names)
+            .field("virtual_mods_", &self.virtual_mods_)
+            .field("first_key", &self.first_key)
+            .field("n_keys", &self.n_keys)
+            .field("indicators", &self.indicators)
+            .field("n_radio_groups", &self.n_radio_groups)
+            .field("n_key_aliases", &self.n_key_aliases)
+            .field("n_kt_levels", &self.n_kt_levels)
+            .field("keycodes_name", &self.keycodes_name)
+            .field("geometry_name", &self.geometry_name)
+            .field("symbols_name", &self.symbols_name)
+            .field("phys_symbols_name", &self.phys_symbols_name)
+            .field("types_name", &self.types_name)
+            .field("compat_name", &self.compat_name)
+            .field("type_names", &self.type_names)
+            .field(
+                "n_levels_per_type",
+                &debug_bytes(&self.n_levels_per_type, 64),
+            )
+            .field("kt_level_names", &self.kt_level_names)
+            .field("indicator_names", &self.indicator_names)
+            .field("virtual_mod_names", &self.virtual_mod_names)
+            .field("groups", &self.groups)
+            .field("key_names", &self.key_names)
+            .field("key_aliases", &self.key_aliases)
+            .field("radio_group_names", &self.radio_group_names)
+            .field("geometry_type", &self.geometry_type)
+            .field("geometry_device_id", &self.geometry_device_id)
+            .field("geometry_sequence", &self.geometry_sequence)
+            .field("geometry_length", &self.geometry_length)
+            .field("name", &self.name)
+            .field("geometry_found", &self.geometry_found)
+            .field("width_mm", &self.width_mm)
+            .field("height_mm", &self.height_mm)
+            .field("n_properties", &self.n_properties)
+            .field("n_colors", &self.n_colors)
+            .field("n_shapes", &self.n_shapes)
+            .field("n_sections", &self.n_sections)
+            .field("n_doodads", &self.n_doodads)
+            .field("n_key_aliases_", &self.n_key_aliases_)
+            .field("base_color_ndx", &self.base_color_ndx)
+            .field("label_color_ndx", &self.label_color_ndx)
+            .field("label_font", &self.label_font)
+            .finish()
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GbnDetail {
     pub inner: u16,
 }
@@ -12990,7 +19124,6 @@ impl GbnDetail {
         }
         self
     }
-    #[inline]
     pub fn new(
         types: bool,
         compat_map: bool,
@@ -13042,21 +19175,20 @@ impl GbnDetail {
     pub const OTHER_NAMES: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 255 };
 }
-impl AsByteSequence for GbnDetail {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for GbnDetail {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((GbnDetail { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for GbnDetail {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { GbnDetail }
 impl core::ops::Not for GbnDetail {
     type Output = GbnDetail;
     #[inline]
@@ -13091,7 +19223,10 @@ impl core::ops::BitXor for GbnDetail {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetDeviceInfoRequest {
     pub req_type: u8,
     pub length: u16,
@@ -13103,49 +19238,80 @@ pub struct GetDeviceInfoRequest {
     pub led_class: LedClass,
     pub led_id: IdSpec,
 }
-impl GetDeviceInfoRequest {}
-impl AsByteSequence for GetDeviceInfoRequest {
+impl GetDeviceInfoRequest {
+    #[inline]
+    pub fn builder() -> GetDeviceInfoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetDeviceInfoReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " Interpret `led_id` as a `Id`. Returns `None` if it holds a value that isn't one of the enumeration's."]
     #[inline]
+    pub fn led_id_enum(&self) -> Option<Id> {
+        core::convert::TryFrom::try_from(self.led_id).ok()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("wanted", 6, 2),
+        ("all_buttons", 8, 1),
+        ("first_button", 9, 1),
+        ("n_buttons", 10, 1),
+        ("led_class", 12, 2),
+        ("led_id", 14, 2),
+    ];
+}
+const _: () = assert!(<GetDeviceInfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetDeviceInfoRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.wanted.as_bytes(&mut bytes[index..]);
         index += self.all_buttons.as_bytes(&mut bytes[index..]);
         index += self.first_button.as_bytes(&mut bytes[index..]);
         index += self.n_buttons.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.led_class.as_bytes(&mut bytes[index..]);
         index += self.led_id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (wanted, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (wanted, sz): (XiFeature, usize) = parse_item(bytes, index, "wanted")?;
         index += sz;
-        let (all_buttons, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (all_buttons, sz): (bool, usize) = parse_item(bytes, index, "all_buttons")?;
         index += sz;
-        let (first_button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_button, sz): (Card8, usize) = parse_item(bytes, index, "first_button")?;
         index += sz;
-        let (n_buttons, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_buttons, sz): (Card8, usize) = parse_item(bytes, index, "n_buttons")?;
         index += sz;
         index += 1;
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = parse_item(bytes, index, "led_class")?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = parse_item(bytes, index, "led_id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetDeviceInfoRequest {
                 req_type: req_type,
                 length: length,
@@ -13162,39 +19328,106 @@ impl AsByteSequence for GetDeviceInfoRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.wanted.size()
-            + self.all_buttons.size()
-            + self.first_button.size()
-            + self.n_buttons.size()
-            + 1
-            + self.led_class.size()
-            + self.led_id.size()
+        let size: usize = 15 + self.all_buttons.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetDeviceInfoRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetDeviceInfoRequest {
-    const OPCODE: u8 = 24;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 24;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceInfo";
     type Reply = GetDeviceInfoReply;
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetDeviceInfoReply {
-    pub reply_type: u8,
-    pub device_id: Card8,
-    pub sequence: u16,
-    pub length: u32,
-    pub present: XiFeature,
-    pub supported: XiFeature,
-    pub unsupported: XiFeature,
-    pub first_btn_wanted: Card8,
-    pub n_btns_wanted: Card8,
-    pub first_btn_rtrn: Card8,
-    pub total_btns: Card8,
-    pub has_own_state: bool,
+#[doc = " A builder for `GetDeviceInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetDeviceInfoRequestBuilder {
+    pub inner: GetDeviceInfoRequest,
+}
+impl GetDeviceInfoRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn wanted(mut self, wanted: XiFeature) -> Self {
+        self.inner.wanted = wanted;
+        self
+    }
+    #[inline]
+    pub fn all_buttons(mut self, all_buttons: bool) -> Self {
+        self.inner.all_buttons = all_buttons;
+        self
+    }
+    #[inline]
+    pub fn first_button(mut self, first_button: Card8) -> Self {
+        self.inner.first_button = first_button;
+        self
+    }
+    #[inline]
+    pub fn n_buttons(mut self, n_buttons: Card8) -> Self {
+        self.inner.n_buttons = n_buttons;
+        self
+    }
+    #[inline]
+    pub fn led_class(mut self, led_class: LedClass) -> Self {
+        self.inner.led_class = led_class;
+        self
+    }
+    #[inline]
+    pub fn led_id(mut self, led_id: IdSpec) -> Self {
+        self.inner.led_id = led_id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetDeviceInfoRequest {
+        self.inner
+    }
+}
+impl Default for GetDeviceInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetDeviceInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                wanted: Default::default(),
+                all_buttons: Default::default(),
+                first_button: Default::default(),
+                n_buttons: Default::default(),
+                led_class: Default::default(),
+                led_id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct GetDeviceInfoReply {
+    pub reply_type: u8,
+    pub device_id: Card8,
+    pub sequence: u16,
+    pub length: u32,
+    pub present: XiFeature,
+    pub supported: XiFeature,
+    pub unsupported: XiFeature,
+    pub first_btn_wanted: Card8,
+    pub n_btns_wanted: Card8,
+    pub first_btn_rtrn: Card8,
+    pub total_btns: Card8,
+    pub has_own_state: bool,
     pub dflt_kbd_fb: Card16,
     pub dflt_led_fb: Card16,
     pub dev_type: Atom,
@@ -13202,9 +19435,98 @@ pub struct GetDeviceInfoReply {
     pub btn_actions: Vec<Action>,
     pub leds: Vec<DeviceLedInfo>,
 }
-impl GetDeviceInfoReply {}
-impl AsByteSequence for GetDeviceInfoReply {
+impl GetDeviceInfoReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn leds_iter(bytes: &[u8]) -> Result<ListIter<'_, DeviceLedInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (present, sz): (XiFeature, usize) = parse_item(bytes, index, "present")?;
+        index += sz;
+        let (supported, sz): (XiFeature, usize) = parse_item(bytes, index, "supported")?;
+        index += sz;
+        let (unsupported, sz): (XiFeature, usize) = parse_item(bytes, index, "unsupported")?;
+        index += sz;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (first_btn_wanted, sz): (Card8, usize) = parse_item(bytes, index, "first_btn_wanted")?;
+        index += sz;
+        let (n_btns_wanted, sz): (Card8, usize) = parse_item(bytes, index, "n_btns_wanted")?;
+        index += sz;
+        let (first_btn_rtrn, sz): (Card8, usize) = parse_item(bytes, index, "first_btn_rtrn")?;
+        index += sz;
+        let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (total_btns, sz): (Card8, usize) = parse_item(bytes, index, "total_btns")?;
+        index += sz;
+        let (has_own_state, sz): (bool, usize) = parse_item(bytes, index, "has_own_state")?;
+        index += sz;
+        let (dflt_kbd_fb, sz): (Card16, usize) = parse_item(bytes, index, "dflt_kbd_fb")?;
+        index += sz;
+        let (dflt_led_fb, sz): (Card16, usize) = parse_item(bytes, index, "dflt_led_fb")?;
+        index += sz;
+        index += 2;
+        let (dev_type, sz): (Atom, usize) = parse_item(bytes, index, "dev_type")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (name, block_len): (Vec<String8>, usize) =
+            parse_vector(bytes, index, len2 as usize, "name")?;
+        index += block_len;
+        index += buffer_pad(block_len, 4);
+        let (btn_actions, block_len): (Vec<Action>, usize) =
+            parse_vector(bytes, index, len1 as usize, "btn_actions")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
+        Ok(ListIter::new(bytes, index, len0 as usize, "leds"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " Interpret `dflt_kbd_fb` as a `Id`. Returns `None` if it holds a value that isn't one of the enumeration's."]
+    #[inline]
+    pub fn dflt_kbd_fb_enum(&self) -> Option<Id> {
+        core::convert::TryFrom::try_from(self.dflt_kbd_fb).ok()
+    }
+    #[doc = " Interpret `dflt_led_fb` as a `Id`. Returns `None` if it holds a value that isn't one of the enumeration's."]
+    #[inline]
+    pub fn dflt_led_fb_enum(&self) -> Option<Id> {
+        core::convert::TryFrom::try_from(self.dflt_led_fb).ok()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("device_id", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("present", 8, 2),
+        ("supported", 10, 2),
+        ("unsupported", 12, 2),
+        ("leds_len", 14, 2),
+        ("first_btn_wanted", 16, 1),
+        ("n_btns_wanted", 17, 1),
+        ("first_btn_rtrn", 18, 1),
+        ("btn_actions_len", 19, 1),
+        ("total_btns", 20, 1),
+        ("has_own_state", 21, 1),
+        ("dflt_kbd_fb", 22, 2),
+        ("dflt_led_fb", 24, 2),
+    ];
+}
+impl AsByteSequence for GetDeviceInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -13223,74 +19545,82 @@ impl AsByteSequence for GetDeviceInfoReply {
         index += self.has_own_state.as_bytes(&mut bytes[index..]);
         index += self.dflt_kbd_fb.as_bytes(&mut bytes[index..]);
         index += self.dflt_led_fb.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.dev_type.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         let block_len: usize = vector_as_bytes(&self.btn_actions, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Action>()),
+        );
         let block_len: usize = vector_as_bytes(&self.leds, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (present, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (present, sz): (XiFeature, usize) = parse_item(bytes, index, "present")?;
         index += sz;
-        let (supported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (XiFeature, usize) = parse_item(bytes, index, "supported")?;
         index += sz;
-        let (unsupported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (unsupported, sz): (XiFeature, usize) = parse_item(bytes, index, "unsupported")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (first_btn_wanted, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_btn_wanted, sz): (Card8, usize) = parse_item(bytes, index, "first_btn_wanted")?;
         index += sz;
-        let (n_btns_wanted, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_btns_wanted, sz): (Card8, usize) = parse_item(bytes, index, "n_btns_wanted")?;
         index += sz;
-        let (first_btn_rtrn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_btn_rtrn, sz): (Card8, usize) = parse_item(bytes, index, "first_btn_rtrn")?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (total_btns, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (total_btns, sz): (Card8, usize) = parse_item(bytes, index, "total_btns")?;
         index += sz;
-        let (has_own_state, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (has_own_state, sz): (bool, usize) = parse_item(bytes, index, "has_own_state")?;
         index += sz;
-        let (dflt_kbd_fb, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dflt_kbd_fb, sz): (Card16, usize) = parse_item(bytes, index, "dflt_kbd_fb")?;
         index += sz;
-        let (dflt_led_fb, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dflt_led_fb, sz): (Card16, usize) = parse_item(bytes, index, "dflt_led_fb")?;
         index += sz;
         index += 2;
-        let (dev_type, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (dev_type, sz): (Atom, usize) = parse_item(bytes, index, "dev_type")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
         let (name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            parse_vector(bytes, index, len2 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (btn_actions, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "btn_actions")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
         let (leds, block_len): (Vec<DeviceLedInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "leds")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetDeviceInfoReply {
                 reply_type: reply_type,
                 device_id: device_id,
@@ -13314,27 +19644,10 @@ impl AsByteSequence for GetDeviceInfoReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.present.size()
-            + self.supported.size()
-            + self.unsupported.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.first_btn_wanted.size()
-            + self.n_btns_wanted.size()
-            + self.first_btn_rtrn.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.total_btns.size()
+        let size: usize = 29
             + self.has_own_state.size()
-            + self.dflt_kbd_fb.size()
-            + self.dflt_led_fb.size()
-            + 2
             + self.dev_type.size()
-            + ::core::mem::size_of::<Card16>()
             + {
                 let block_len: usize = self.name.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, 4);
@@ -13349,11 +19662,21 @@ impl AsByteSequence for GetDeviceInfoReply {
                 let block_len: usize = self.leds.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetDeviceInfoReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct XiFeature {
     pub inner: u16,
 }
@@ -13460,21 +19783,20 @@ impl XiFeature {
     pub const INDICATOR_STATE: Self = Self { inner: 16 };
     pub const COMPLETE: Self = Self { inner: 31 };
 }
-impl AsByteSequence for XiFeature {
+impl core::ops::Deref for XiFeature {
+    type Target = u16;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((XiFeature { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for XiFeature {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { XiFeature }
 impl core::ops::Not for XiFeature {
     type Output = XiFeature;
     #[inline]
@@ -13509,7 +19831,10 @@ impl core::ops::BitXor for XiFeature {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetDeviceInfoRequest {
     pub req_type: u8,
     pub length: u16,
@@ -13519,13 +19844,28 @@ pub struct SetDeviceInfoRequest {
     pub btn_actions: Vec<Action>,
     pub leds: Vec<DeviceLedInfo>,
 }
-impl SetDeviceInfoRequest {}
-impl AsByteSequence for SetDeviceInfoRequest {
+impl SetDeviceInfoRequest {
     #[inline]
+    pub fn builder() -> SetDeviceInfoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device_spec", 4, 2),
+        ("first_btn", 6, 1),
+        ("btn_actions_len", 7, 1),
+        ("change", 8, 2),
+        ("leds_len", 10, 2),
+    ];
+}
+const _: () = assert!(<SetDeviceInfoRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetDeviceInfoRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device_spec.as_bytes(&mut bytes[index..]);
         index += self.first_btn.as_bytes(&mut bytes[index..]);
@@ -13534,40 +19874,47 @@ impl AsByteSequence for SetDeviceInfoRequest {
         index += (self.leds.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.btn_actions, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Action>()),
+        );
         let block_len: usize = vector_as_bytes(&self.leds, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = parse_item(bytes, index, "device_spec")?;
         index += sz;
-        let (first_btn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_btn, sz): (Card8, usize) = parse_item(bytes, index, "first_btn")?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (change, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (change, sz): (XiFeature, usize) = parse_item(bytes, index, "change")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (btn_actions, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "btn_actions")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
         let (leds, block_len): (Vec<DeviceLedInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "leds")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetDeviceInfoRequest {
                 req_type: req_type,
                 length: length,
@@ -13580,35 +19927,92 @@ impl AsByteSequence for SetDeviceInfoRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.first_btn.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.change.size()
-            + ::core::mem::size_of::<Card16>()
-            + {
+        let size: usize =
+            12 + {
                 let block_len: usize = self.btn_actions.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.leds.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetDeviceInfoRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetDeviceInfoRequest {
-    const OPCODE: u8 = 25;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 25;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceInfo";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetDeviceInfoRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetDeviceInfoRequestBuilder {
+    pub inner: SetDeviceInfoRequest,
+}
+impl SetDeviceInfoRequestBuilder {
+    #[inline]
+    pub fn device_spec(mut self, device_spec: DeviceSpec) -> Self {
+        self.inner.device_spec = device_spec;
+        self
+    }
+    #[inline]
+    pub fn first_btn(mut self, first_btn: Card8) -> Self {
+        self.inner.first_btn = first_btn;
+        self
+    }
+    #[inline]
+    pub fn change(mut self, change: XiFeature) -> Self {
+        self.inner.change = change;
+        self
+    }
+    #[inline]
+    pub fn btn_actions(mut self, btn_actions: Vec<Action>) -> Self {
+        self.inner.btn_actions = btn_actions;
+        self
+    }
+    #[inline]
+    pub fn leds(mut self, leds: Vec<DeviceLedInfo>) -> Self {
+        self.inner.leds = leds;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetDeviceInfoRequest {
+        self.inner
+    }
+}
+impl Default for SetDeviceInfoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetDeviceInfoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device_spec: Default::default(),
+                first_btn: Default::default(),
+                change: Default::default(),
+                btn_actions: Default::default(),
+                leds: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetDebuggingFlagsRequest {
     pub req_type: u8,
     pub length: u16,
@@ -13618,50 +20022,77 @@ pub struct SetDebuggingFlagsRequest {
     pub ctrls: Card32,
     pub message: Vec<String8>,
 }
-impl SetDebuggingFlagsRequest {}
-impl AsByteSequence for SetDebuggingFlagsRequest {
+impl SetDebuggingFlagsRequest {
+    #[inline]
+    pub fn builder() -> SetDebuggingFlagsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<SetDebuggingFlagsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("message_len", 4, 2),
+        ("affect_flags", 8, 4),
+        ("flags", 12, 4),
+        ("affect_ctrls", 16, 4),
+        ("ctrls", 20, 4),
+    ];
+}
+const _: () = assert!(<SetDebuggingFlagsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetDebuggingFlagsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.message.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.affect_flags.as_bytes(&mut bytes[index..]);
         index += self.flags.as_bytes(&mut bytes[index..]);
         index += self.affect_ctrls.as_bytes(&mut bytes[index..]);
         index += self.ctrls.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.message, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDebuggingFlagsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 2;
-        let (affect_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (affect_flags, sz): (Card32, usize) = parse_item(bytes, index, "affect_flags")?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (affect_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (affect_ctrls, sz): (Card32, usize) = parse_item(bytes, index, "affect_ctrls")?;
         index += sz;
-        let (ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ctrls, sz): (Card32, usize) = parse_item(bytes, index, "ctrls")?;
         index += sz;
         let (message, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "message")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetDebuggingFlagsRequest {
                 req_type: req_type,
                 length: length,
@@ -13676,29 +20107,99 @@ impl AsByteSequence for SetDebuggingFlagsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + ::core::mem::size_of::<Card16>()
-            + 2
-            + self.affect_flags.size()
-            + self.flags.size()
-            + self.affect_ctrls.size()
-            + self.ctrls.size()
-            + {
-                let block_len: usize = self.message.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
-                block_len + pad
-            }
+        let size: usize = 24 + {
+            let block_len: usize = self.message.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetDebuggingFlagsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetDebuggingFlagsRequest {
-    const OPCODE: u8 = 101;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
+    const MINOR_OPCODE: u16 = 101;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDebuggingFlags";
     type Reply = SetDebuggingFlagsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetDebuggingFlagsRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetDebuggingFlagsRequestBuilder {
+    pub inner: SetDebuggingFlagsRequest,
+}
+impl SetDebuggingFlagsRequestBuilder {
+    #[inline]
+    pub fn affect_flags(mut self, affect_flags: Card32) -> Self {
+        self.inner.affect_flags = affect_flags;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: Card32) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn affect_ctrls(mut self, affect_ctrls: Card32) -> Self {
+        self.inner.affect_ctrls = affect_ctrls;
+        self
+    }
+    #[inline]
+    pub fn ctrls(mut self, ctrls: Card32) -> Self {
+        self.inner.ctrls = ctrls;
+        self
+    }
+    #[inline]
+    pub fn message(mut self, message: Vec<String8>) -> Self {
+        self.inner.message = message;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetDebuggingFlagsRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<String8> for SetDebuggingFlagsRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = String8>>(&mut self, iter: I) {
+        self.inner.message.extend(iter);
+    }
+}
+impl core::iter::FromIterator<String8> for SetDebuggingFlagsRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = String8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for SetDebuggingFlagsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetDebuggingFlagsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                affect_flags: Default::default(),
+                flags: Default::default(),
+                affect_ctrls: Default::default(),
+                ctrls: Default::default(),
+                message: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SetDebuggingFlagsReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -13708,43 +20209,135 @@ pub struct SetDebuggingFlagsReply {
     pub supported_flags: Card32,
     pub supported_ctrls: Card32,
 }
-impl SetDebuggingFlagsReply {}
-impl AsByteSequence for SetDebuggingFlagsReply {
+impl SetDebuggingFlagsReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let current_flags: Card32 = read_item(reader, &mut index, 4, "current_flags")?;
+        let current_ctrls: Card32 = read_item(reader, &mut index, 4, "current_ctrls")?;
+        let supported_flags: Card32 = read_item(reader, &mut index, 4, "supported_flags")?;
+        let supported_ctrls: Card32 = read_item(reader, &mut index, 4, "supported_ctrls")?;
+        skip_bytes(reader, &mut index, 8)?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(SetDebuggingFlagsReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            current_flags: current_flags,
+            current_ctrls: current_ctrls,
+            supported_flags: supported_flags,
+            supported_ctrls: supported_ctrls,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("current_flags", 8, 4),
+        ("current_ctrls", 12, 4),
+        ("supported_flags", 16, 4),
+        ("supported_ctrls", 20, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.current_flags == other.current_flags
+            && self.current_ctrls == other.current_ctrls
+            && self.supported_flags == other.supported_flags
+            && self.supported_ctrls == other.supported_ctrls
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "current_flags",
+            &self.current_flags,
+            &other.current_flags,
+            &mut changes,
+        );
+        diff_value(
+            "current_ctrls",
+            &self.current_ctrls,
+            &other.current_ctrls,
+            &mut changes,
+        );
+        diff_value(
+            "supported_flags",
+            &self.supported_flags,
+            &other.supported_flags,
+            &mut changes,
+        );
+        diff_value(
+            "supported_ctrls",
+            &self.supported_ctrls,
+            &other.supported_ctrls,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for SetDebuggingFlagsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.current_flags.as_bytes(&mut bytes[index..]);
         index += self.current_ctrls.as_bytes(&mut bytes[index..]);
         index += self.supported_flags.as_bytes(&mut bytes[index..]);
         index += self.supported_ctrls.as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += zero_pad(&mut bytes[index..], 8);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDebuggingFlagsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (current_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (current_flags, sz): (Card32, usize) = parse_item(bytes, index, "current_flags")?;
         index += sz;
-        let (current_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (current_ctrls, sz): (Card32, usize) = parse_item(bytes, index, "current_ctrls")?;
         index += sz;
-        let (supported_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (supported_flags, sz): (Card32, usize) = parse_item(bytes, index, "supported_flags")?;
         index += sz;
-        let (supported_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (supported_ctrls, sz): (Card32, usize) = parse_item(bytes, index, "supported_ctrls")?;
         index += sz;
         index += 8;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetDebuggingFlagsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -13759,19 +20352,21 @@ impl AsByteSequence for SetDebuggingFlagsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.current_flags.size()
-            + self.current_ctrls.size()
-            + self.supported_flags.size()
-            + self.supported_ctrls.size()
-            + 8
+        let size: usize = 32;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetDebuggingFlagsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct NknDetail {
     pub inner: u16,
 }
@@ -13838,21 +20433,20 @@ impl NknDetail {
     pub const DEVICE_ID: Self = Self { inner: 4 };
     pub const COMPLETE: Self = Self { inner: 7 };
 }
-impl AsByteSequence for NknDetail {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for NknDetail {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((NknDetail { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for NknDetail {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { NknDetail }
 impl core::ops::Not for NknDetail {
     type Output = NknDetail;
     #[inline]
@@ -13888,7 +20482,9 @@ impl core::ops::BitXor for NknDetail {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct StatePart {
     pub inner: u16,
 }
@@ -14075,7 +20671,6 @@ impl StatePart {
         }
         self
     }
-    #[inline]
     pub fn new(
         modifier_state: bool,
         modifier_base: bool,
@@ -14157,21 +20752,20 @@ impl StatePart {
     pub const POINTER_BUTTONS: Self = Self { inner: 8192 };
     pub const COMPLETE: Self = Self { inner: 16383 };
 }
-impl AsByteSequence for StatePart {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for StatePart {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((StatePart { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for StatePart {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { StatePart }
 impl core::ops::Not for StatePart {
     type Output = StatePart;
     #[inline]
@@ -14207,7 +20801,9 @@ impl core::ops::BitXor for StatePart {
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum BellClassResult {
     KbdFeedbackClass = 0,
     BellFeedbackClass = 5,
@@ -14218,12 +20814,12 @@ impl AsByteSequence for BellClassResult {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::KbdFeedbackClass, sz)),
-            5 => Some((Self::BellFeedbackClass, sz)),
-            _ => None,
+            0 => Ok((Self::KbdFeedbackClass, sz)),
+            5 => Ok((Self::BellFeedbackClass, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -14231,14 +20827,40 @@ impl AsByteSequence for BellClassResult {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for BellClassResult {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for BellClassResult {
     #[inline]
     fn default() -> BellClassResult {
         BellClassResult::KbdFeedbackClass
     }
 }
+impl From<BellClassResult> for u8 {
+    #[inline]
+    fn from(base: BellClassResult) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for BellClassResult {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::KbdFeedbackClass),
+            5 => Ok(Self::BellFeedbackClass),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AxnDetail {
     pub inner: u16,
 }
@@ -14381,21 +21003,20 @@ impl AxnDetail {
     pub const AXK_WARNING: Self = Self { inner: 64 };
     pub const COMPLETE: Self = Self { inner: 127 };
 }
-impl AsByteSequence for AxnDetail {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for AxnDetail {
+    type Target = u16;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
-        Some((AxnDetail { inner: inner }, sz))
+    fn deref(&self) -> &u16 {
+        &self.inner
     }
+}
+impl AsRef<u16> for AxnDetail {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &u16 {
+        &self.inner
     }
 }
+impl_u16_flags_asb! { AxnDetail }
 impl core::ops::Not for AxnDetail {
     type Output = AxnDetail;
     #[inline]
@@ -14431,7 +21052,9 @@ impl core::ops::BitXor for AxnDetail {
     }
 }
 #[repr(u16)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum LedClassResult {
     KbdFeedbackClass = 0,
     LedFeedbackClass = 4,
@@ -14442,12 +21065,12 @@ impl AsByteSequence for LedClassResult {
         (*self as u16).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u16, usize) = <u16>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::KbdFeedbackClass, sz)),
-            4 => Some((Self::LedFeedbackClass, sz)),
-            _ => None,
+            0 => Ok((Self::KbdFeedbackClass, sz)),
+            4 => Ok((Self::LedFeedbackClass, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -14455,14 +21078,40 @@ impl AsByteSequence for LedClassResult {
         ::core::mem::size_of::<u16>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for LedClassResult {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for LedClassResult {
     #[inline]
     fn default() -> LedClassResult {
         LedClassResult::KbdFeedbackClass
     }
 }
+impl From<LedClassResult> for u16 {
+    #[inline]
+    fn from(base: LedClassResult) -> Self {
+        base as u16
+    }
+}
+impl core::convert::TryFrom<u16> for LedClassResult {
+    type Error = u16;
+    #[inline]
+    fn try_from(value: u16) -> Result<Self, u16> {
+        match value {
+            0 => Ok(Self::KbdFeedbackClass),
+            4 => Ok(Self::LedFeedbackClass),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum BehaviorType {
     Default = 0,
     Lock = 1,
@@ -14479,20 +21128,19 @@ impl AsByteSequence for BehaviorType {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as i32).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Default, sz)),
-            1 => Some((Self::Lock, sz)),
-            2 => Some((Self::RadioGroup, sz)),
-            3 => Some((Self::Overlay1, sz)),
-            4 => Some((Self::Overlay2, sz)),
-            129 => Some((Self::PermamentLock, sz)),
-            130 => Some((Self::PermamentRadioGroup, sz)),
-            131 => Some((Self::PermamentOverlay1, sz)),
-            132 => Some((Self::PermamentOverlay2, sz)),
-            _ => None,
+            0 => Ok((Self::Default, sz)),
+            1 => Ok((Self::Lock, sz)),
+            2 => Ok((Self::RadioGroup, sz)),
+            3 => Ok((Self::Overlay1, sz)),
+            4 => Ok((Self::Overlay2, sz)),
+            129 => Ok((Self::PermamentLock, sz)),
+            130 => Ok((Self::PermamentRadioGroup, sz)),
+            131 => Ok((Self::PermamentOverlay1, sz)),
+            132 => Ok((Self::PermamentOverlay2, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -14500,140 +21148,117 @@ impl AsByteSequence for BehaviorType {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for BehaviorType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for BehaviorType {
     #[inline]
     fn default() -> BehaviorType {
         BehaviorType::Default
     }
 }
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct SymInterpMatch {
-    pub inner: i32,
-}
-impl SymInterpMatch {
+impl From<BehaviorType> for i32 {
     #[inline]
-    pub fn level_one_only(&self) -> bool {
-        self.inner & (1 << 7) != 0
+    fn from(base: BehaviorType) -> Self {
+        base as i32
     }
+}
+impl core::convert::TryFrom<i32> for BehaviorType {
+    type Error = i32;
     #[inline]
-    pub fn set_level_one_only(&mut self, val: bool) -> &mut Self {
-        if val {
-            self.inner |= 1 << 7;
-        } else {
-            self.inner &= !(1 << 7);
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::Default),
+            1 => Ok(Self::Lock),
+            2 => Ok(Self::RadioGroup),
+            3 => Ok(Self::Overlay1),
+            4 => Ok(Self::Overlay2),
+            129 => Ok(Self::PermamentLock),
+            130 => Ok(Self::PermamentRadioGroup),
+            131 => Ok(Self::PermamentOverlay1),
+            132 => Ok(Self::PermamentOverlay2),
+            _ => Err(value),
         }
-        self
     }
-    #[inline]
-    pub fn new(level_one_only: bool) -> Self {
-        let mut inner: i32 = 0;
-        if level_one_only {
-            inner |= 1 << 7;
-        }
-        SymInterpMatch { inner: inner }
-    }
-    #[inline]
-    pub fn count_ones(&self) -> usize {
-        self.inner.count_ones() as usize
-    }
-    pub const LEVEL_ONE_ONLY: Self = Self { inner: 128 };
-    pub const COMPLETE: Self = Self { inner: 128 };
 }
-impl AsByteSequence for SymInterpMatch {
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum BellClass {
+    KbdFeedbackClass = 0,
+    BellFeedbackClass = 5,
+    DfltXiClass = 768,
+}
+impl AsByteSequence for BellClass {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
+        (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((SymInterpMatch { inner: inner }, sz))
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
+        match underlying {
+            0 => Ok((Self::KbdFeedbackClass, sz)),
+            5 => Ok((Self::BellFeedbackClass, sz)),
+            768 => Ok((Self::DfltXiClass, sz)),
+            _ => Err(unreadable::<Self>()),
+        }
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
-    }
-}
-impl core::ops::Not for SymInterpMatch {
-    type Output = SymInterpMatch;
-    #[inline]
-    fn not(self) -> SymInterpMatch {
-        SymInterpMatch { inner: !self.inner }
+        ::core::mem::size_of::<i32>()
     }
 }
-impl core::ops::BitAnd for SymInterpMatch {
-    type Output = SymInterpMatch;
+impl core::convert::TryFrom<&[u8]> for BellClass {
+    type Error = ParseError;
     #[inline]
-    fn bitand(self, rhs: SymInterpMatch) -> SymInterpMatch {
-        SymInterpMatch {
-            inner: self.inner & rhs.inner,
-        }
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-impl core::ops::BitOr for SymInterpMatch {
-    type Output = SymInterpMatch;
+impl Default for BellClass {
     #[inline]
-    fn bitor(self, rhs: SymInterpMatch) -> SymInterpMatch {
-        SymInterpMatch {
-            inner: self.inner | rhs.inner,
-        }
+    fn default() -> BellClass {
+        BellClass::KbdFeedbackClass
     }
 }
-impl core::ops::BitXor for SymInterpMatch {
-    type Output = SymInterpMatch;
+impl From<BellClass> for i32 {
     #[inline]
-    fn bitxor(self, rhs: SymInterpMatch) -> SymInterpMatch {
-        SymInterpMatch {
-            inner: self.inner ^ rhs.inner,
-        }
+    fn from(base: BellClass) -> Self {
+        base as i32
     }
 }
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Const {
-    KeyNameLength = 4,
-    PerKeyBitArraySize = 32,
-    MaxLegalKeyCode = 255,
-}
-impl AsByteSequence for Const {
+impl core::convert::TryFrom<i32> for BellClass {
+    type Error = i32;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        (*self as i32).as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        match underlying {
-            4 => Some((Self::KeyNameLength, sz)),
-            32 => Some((Self::PerKeyBitArraySize, sz)),
-            255 => Some((Self::MaxLegalKeyCode, sz)),
-            _ => None,
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::KbdFeedbackClass),
+            5 => Ok(Self::BellFeedbackClass),
+            768 => Ok(Self::DfltXiClass),
+            _ => Err(value),
         }
     }
-    #[inline]
-    fn size(&self) -> usize {
-        ::core::mem::size_of::<i32>()
-    }
-}
-impl Default for Const {
-    #[inline]
-    fn default() -> Const {
-        Const::KeyNameLength
-    }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct SwitchScreenFlag {
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct CmDetail {
     pub inner: i32,
 }
-impl SwitchScreenFlag {
+impl CmDetail {
     #[inline]
-    pub fn application(&self) -> bool {
+    pub fn sym_interp(&self) -> bool {
         self.inner & (1 << 0) != 0
     }
     #[inline]
-    pub fn set_application(&mut self, val: bool) -> &mut Self {
+    pub fn set_sym_interp(&mut self, val: bool) -> &mut Self {
         if val {
             self.inner |= 1 << 0;
         } else {
@@ -14642,88 +21267,175 @@ impl SwitchScreenFlag {
         self
     }
     #[inline]
-    pub fn absolute(&self) -> bool {
-        self.inner & (1 << 2) != 0
+    pub fn group_compat(&self) -> bool {
+        self.inner & (1 << 1) != 0
     }
     #[inline]
-    pub fn set_absolute(&mut self, val: bool) -> &mut Self {
+    pub fn set_group_compat(&mut self, val: bool) -> &mut Self {
         if val {
-            self.inner |= 1 << 2;
+            self.inner |= 1 << 1;
         } else {
-            self.inner &= !(1 << 2);
+            self.inner &= !(1 << 1);
         }
         self
     }
     #[inline]
-    pub fn new(application: bool, absolute: bool) -> Self {
+    pub fn new(sym_interp: bool, group_compat: bool) -> Self {
         let mut inner: i32 = 0;
-        if application {
+        if sym_interp {
             inner |= 1 << 0;
         }
-        if absolute {
-            inner |= 1 << 2;
+        if group_compat {
+            inner |= 1 << 1;
         }
-        SwitchScreenFlag { inner: inner }
+        CmDetail { inner: inner }
     }
     #[inline]
     pub fn count_ones(&self) -> usize {
         self.inner.count_ones() as usize
     }
-    pub const APPLICATION: Self = Self { inner: 1 };
-    pub const ABSOLUTE: Self = Self { inner: 4 };
-    pub const COMPLETE: Self = Self { inner: 5 };
+    pub const SYM_INTERP: Self = Self { inner: 1 };
+    pub const GROUP_COMPAT: Self = Self { inner: 2 };
+    pub const COMPLETE: Self = Self { inner: 3 };
 }
-impl AsByteSequence for SwitchScreenFlag {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for CmDetail {
+    type Target = i32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((SwitchScreenFlag { inner: inner }, sz))
+    fn deref(&self) -> &i32 {
+        &self.inner
     }
+}
+impl AsRef<i32> for CmDetail {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &i32 {
+        &self.inner
     }
 }
-impl core::ops::Not for SwitchScreenFlag {
-    type Output = SwitchScreenFlag;
+macro_rules! impl_i32_flags_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                self.inner.as_bytes(bytes)
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
+                Ok(($name { inner: inner }, sz))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                4
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_i32_flags_asb! { CmDetail }
+impl core::ops::Not for CmDetail {
+    type Output = CmDetail;
     #[inline]
-    fn not(self) -> SwitchScreenFlag {
-        SwitchScreenFlag { inner: !self.inner }
+    fn not(self) -> CmDetail {
+        CmDetail { inner: !self.inner }
     }
 }
-impl core::ops::BitAnd for SwitchScreenFlag {
-    type Output = SwitchScreenFlag;
+impl core::ops::BitAnd for CmDetail {
+    type Output = CmDetail;
     #[inline]
-    fn bitand(self, rhs: SwitchScreenFlag) -> SwitchScreenFlag {
-        SwitchScreenFlag {
+    fn bitand(self, rhs: CmDetail) -> CmDetail {
+        CmDetail {
             inner: self.inner & rhs.inner,
         }
     }
 }
-impl core::ops::BitOr for SwitchScreenFlag {
-    type Output = SwitchScreenFlag;
+impl core::ops::BitOr for CmDetail {
+    type Output = CmDetail;
     #[inline]
-    fn bitor(self, rhs: SwitchScreenFlag) -> SwitchScreenFlag {
-        SwitchScreenFlag {
+    fn bitor(self, rhs: CmDetail) -> CmDetail {
+        CmDetail {
             inner: self.inner | rhs.inner,
         }
     }
 }
-impl core::ops::BitXor for SwitchScreenFlag {
-    type Output = SwitchScreenFlag;
+impl core::ops::BitXor for CmDetail {
+    type Output = CmDetail;
     #[inline]
-    fn bitxor(self, rhs: SwitchScreenFlag) -> SwitchScreenFlag {
-        SwitchScreenFlag {
+    fn bitxor(self, rhs: CmDetail) -> CmDetail {
+        CmDetail {
             inner: self.inner ^ rhs.inner,
         }
     }
 }
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Const {
+    KeyNameLength = 4,
+    PerKeyBitArraySize = 32,
+    MaxLegalKeyCode = 255,
+}
+impl AsByteSequence for Const {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        (*self as i32).as_bytes(bytes)
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
+        match underlying {
+            4 => Ok((Self::KeyNameLength, sz)),
+            32 => Ok((Self::PerKeyBitArraySize, sz)),
+            255 => Ok((Self::MaxLegalKeyCode, sz)),
+            _ => Err(unreadable::<Self>()),
+        }
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        ::core::mem::size_of::<i32>()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Const {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Default for Const {
+    #[inline]
+    fn default() -> Const {
+        Const::KeyNameLength
+    }
+}
+impl From<Const> for i32 {
+    #[inline]
+    fn from(base: Const) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for Const {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            4 => Ok(Self::KeyNameLength),
+            32 => Ok(Self::PerKeyBitArraySize),
+            255 => Ok(Self::MaxLegalKeyCode),
+            _ => Err(value),
+        }
+    }
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum DoodadType {
     Outline = 1,
     Solid = 2,
@@ -14736,16 +21448,15 @@ impl AsByteSequence for DoodadType {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as i32).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            1 => Some((Self::Outline, sz)),
-            2 => Some((Self::Solid, sz)),
-            3 => Some((Self::Text, sz)),
-            4 => Some((Self::Indicator, sz)),
-            5 => Some((Self::Logo, sz)),
-            _ => None,
+            1 => Ok((Self::Outline, sz)),
+            2 => Ok((Self::Solid, sz)),
+            3 => Ok((Self::Text, sz)),
+            4 => Ok((Self::Indicator, sz)),
+            5 => Ok((Self::Logo, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -14753,32 +21464,61 @@ impl AsByteSequence for DoodadType {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for DoodadType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for DoodadType {
     #[inline]
     fn default() -> DoodadType {
         DoodadType::Outline
     }
 }
+impl From<DoodadType> for i32 {
+    #[inline]
+    fn from(base: DoodadType) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for DoodadType {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            1 => Ok(Self::Outline),
+            2 => Ok(Self::Solid),
+            3 => Ok(Self::Text),
+            4 => Ok(Self::Indicator),
+            5 => Ok(Self::Logo),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum BellClass {
-    KbdFeedbackClass = 0,
-    BellFeedbackClass = 5,
-    DfltXiClass = 768,
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Error {
+    BadId = 253,
+    BadClass = 254,
+    BadDevice = 255,
 }
-impl AsByteSequence for BellClass {
+impl AsByteSequence for Error {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::KbdFeedbackClass, sz)),
-            5 => Some((Self::BellFeedbackClass, sz)),
-            768 => Some((Self::DfltXiClass, sz)),
-            _ => None,
+            253 => Ok((Self::BadId, sz)),
+            254 => Ok((Self::BadClass, sz)),
+            255 => Ok((Self::BadDevice, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -14786,53 +21526,137 @@ impl AsByteSequence for BellClass {
         ::core::mem::size_of::<i32>()
     }
 }
-impl Default for BellClass {
+impl core::convert::TryFrom<&[u8]> for Error {
+    type Error = ParseError;
     #[inline]
-    fn default() -> BellClass {
-        BellClass::KbdFeedbackClass
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct GroupsWrap {
-    pub inner: i32,
+impl Default for Error {
+    #[inline]
+    fn default() -> Error {
+        Error::BadId
+    }
 }
-impl GroupsWrap {
+impl From<Error> for i32 {
     #[inline]
-    pub fn clamp_into_range(&self) -> bool {
-        self.inner & (1 << 6) != 0
+    fn from(base: Error) -> Self {
+        base as i32
     }
+}
+impl core::convert::TryFrom<i32> for Error {
+    type Error = i32;
     #[inline]
-    pub fn set_clamp_into_range(&mut self, val: bool) -> &mut Self {
-        if val {
-            self.inner |= 1 << 6;
-        } else {
-            self.inner &= !(1 << 6);
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            253 => Ok(Self::BadId),
+            254 => Ok(Self::BadClass),
+            255 => Ok(Self::BadDevice),
+            _ => Err(value),
         }
-        self
     }
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Groups {
+    Any = 254,
+    All = 255,
+}
+impl AsByteSequence for Groups {
     #[inline]
-    pub fn redirect_into_range(&self) -> bool {
-        self.inner & (1 << 7) != 0
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    pub fn set_redirect_into_range(&mut self, val: bool) -> &mut Self {
-        if val {
-            self.inner |= 1 << 7;
-        } else {
-            self.inner &= !(1 << 7);
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
+        match underlying {
+            254 => Ok((Self::Any, sz)),
+            255 => Ok((Self::All, sz)),
+            _ => Err(unreadable::<Self>()),
         }
-        self
     }
     #[inline]
-    pub fn new(clamp_into_range: bool, redirect_into_range: bool) -> Self {
-        let mut inner: i32 = 0;
-        if clamp_into_range {
-            inner |= 1 << 6;
-        }
-        if redirect_into_range {
-            inner |= 1 << 7;
-        }
+    fn size(&self) -> usize {
+        ::core::mem::size_of::<i32>()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Groups {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Default for Groups {
+    #[inline]
+    fn default() -> Groups {
+        Groups::Any
+    }
+}
+impl From<Groups> for i32 {
+    #[inline]
+    fn from(base: Groups) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for Groups {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            254 => Ok(Self::Any),
+            255 => Ok(Self::All),
+            _ => Err(value),
+        }
+    }
+}
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct GroupsWrap {
+    pub inner: i32,
+}
+impl GroupsWrap {
+    #[inline]
+    pub fn clamp_into_range(&self) -> bool {
+        self.inner & (1 << 6) != 0
+    }
+    #[inline]
+    pub fn set_clamp_into_range(&mut self, val: bool) -> &mut Self {
+        if val {
+            self.inner |= 1 << 6;
+        } else {
+            self.inner &= !(1 << 6);
+        }
+        self
+    }
+    #[inline]
+    pub fn redirect_into_range(&self) -> bool {
+        self.inner & (1 << 7) != 0
+    }
+    #[inline]
+    pub fn set_redirect_into_range(&mut self, val: bool) -> &mut Self {
+        if val {
+            self.inner |= 1 << 7;
+        } else {
+            self.inner &= !(1 << 7);
+        }
+        self
+    }
+    #[inline]
+    pub fn new(clamp_into_range: bool, redirect_into_range: bool) -> Self {
+        let mut inner: i32 = 0;
+        if clamp_into_range {
+            inner |= 1 << 6;
+        }
+        if redirect_into_range {
+            inner |= 1 << 7;
+        }
         GroupsWrap { inner: inner }
     }
     #[inline]
@@ -14843,21 +21667,20 @@ impl GroupsWrap {
     pub const REDIRECT_INTO_RANGE: Self = Self { inner: 128 };
     pub const COMPLETE: Self = Self { inner: 192 };
 }
-impl AsByteSequence for GroupsWrap {
+impl core::ops::Deref for GroupsWrap {
+    type Target = i32;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((GroupsWrap { inner: inner }, sz))
+    fn deref(&self) -> &i32 {
+        &self.inner
     }
+}
+impl AsRef<i32> for GroupsWrap {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &i32 {
+        &self.inner
     }
 }
+impl_i32_flags_asb! { GroupsWrap }
 impl core::ops::Not for GroupsWrap {
     type Output = GroupsWrap;
     #[inline]
@@ -14893,17 +21716,19 @@ impl core::ops::BitXor for GroupsWrap {
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct CmDetail {
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct SwitchScreenFlag {
     pub inner: i32,
 }
-impl CmDetail {
+impl SwitchScreenFlag {
     #[inline]
-    pub fn sym_interp(&self) -> bool {
+    pub fn application(&self) -> bool {
         self.inner & (1 << 0) != 0
     }
     #[inline]
-    pub fn set_sym_interp(&mut self, val: bool) -> &mut Self {
+    pub fn set_application(&mut self, val: bool) -> &mut Self {
         if val {
             self.inner |= 1 << 0;
         } else {
@@ -14912,204 +21737,235 @@ impl CmDetail {
         self
     }
     #[inline]
-    pub fn group_compat(&self) -> bool {
-        self.inner & (1 << 1) != 0
+    pub fn absolute(&self) -> bool {
+        self.inner & (1 << 2) != 0
     }
     #[inline]
-    pub fn set_group_compat(&mut self, val: bool) -> &mut Self {
+    pub fn set_absolute(&mut self, val: bool) -> &mut Self {
         if val {
-            self.inner |= 1 << 1;
+            self.inner |= 1 << 2;
         } else {
-            self.inner &= !(1 << 1);
+            self.inner &= !(1 << 2);
         }
         self
     }
     #[inline]
-    pub fn new(sym_interp: bool, group_compat: bool) -> Self {
+    pub fn new(application: bool, absolute: bool) -> Self {
         let mut inner: i32 = 0;
-        if sym_interp {
+        if application {
             inner |= 1 << 0;
         }
-        if group_compat {
-            inner |= 1 << 1;
+        if absolute {
+            inner |= 1 << 2;
         }
-        CmDetail { inner: inner }
+        SwitchScreenFlag { inner: inner }
     }
     #[inline]
     pub fn count_ones(&self) -> usize {
         self.inner.count_ones() as usize
     }
-    pub const SYM_INTERP: Self = Self { inner: 1 };
-    pub const GROUP_COMPAT: Self = Self { inner: 2 };
-    pub const COMPLETE: Self = Self { inner: 3 };
+    pub const APPLICATION: Self = Self { inner: 1 };
+    pub const ABSOLUTE: Self = Self { inner: 4 };
+    pub const COMPLETE: Self = Self { inner: 5 };
 }
-impl AsByteSequence for CmDetail {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        self.inner.as_bytes(bytes)
-    }
+impl core::ops::Deref for SwitchScreenFlag {
+    type Target = i32;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((CmDetail { inner: inner }, sz))
+    fn deref(&self) -> &i32 {
+        &self.inner
     }
+}
+impl AsRef<i32> for SwitchScreenFlag {
     #[inline]
-    fn size(&self) -> usize {
-        self.inner.size()
+    fn as_ref(&self) -> &i32 {
+        &self.inner
     }
 }
-impl core::ops::Not for CmDetail {
-    type Output = CmDetail;
+impl_i32_flags_asb! { SwitchScreenFlag }
+impl core::ops::Not for SwitchScreenFlag {
+    type Output = SwitchScreenFlag;
     #[inline]
-    fn not(self) -> CmDetail {
-        CmDetail { inner: !self.inner }
+    fn not(self) -> SwitchScreenFlag {
+        SwitchScreenFlag { inner: !self.inner }
     }
 }
-impl core::ops::BitAnd for CmDetail {
-    type Output = CmDetail;
+impl core::ops::BitAnd for SwitchScreenFlag {
+    type Output = SwitchScreenFlag;
     #[inline]
-    fn bitand(self, rhs: CmDetail) -> CmDetail {
-        CmDetail {
+    fn bitand(self, rhs: SwitchScreenFlag) -> SwitchScreenFlag {
+        SwitchScreenFlag {
             inner: self.inner & rhs.inner,
         }
     }
 }
-impl core::ops::BitOr for CmDetail {
-    type Output = CmDetail;
+impl core::ops::BitOr for SwitchScreenFlag {
+    type Output = SwitchScreenFlag;
     #[inline]
-    fn bitor(self, rhs: CmDetail) -> CmDetail {
-        CmDetail {
+    fn bitor(self, rhs: SwitchScreenFlag) -> SwitchScreenFlag {
+        SwitchScreenFlag {
             inner: self.inner | rhs.inner,
         }
     }
 }
-impl core::ops::BitXor for CmDetail {
-    type Output = CmDetail;
+impl core::ops::BitXor for SwitchScreenFlag {
+    type Output = SwitchScreenFlag;
     #[inline]
-    fn bitxor(self, rhs: CmDetail) -> CmDetail {
-        CmDetail {
+    fn bitxor(self, rhs: SwitchScreenFlag) -> SwitchScreenFlag {
+        SwitchScreenFlag {
             inner: self.inner ^ rhs.inner,
         }
     }
 }
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Groups {
-    Any = 254,
-    All = 255,
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct SymInterpMatch {
+    pub inner: i32,
 }
-impl AsByteSequence for Groups {
+impl SymInterpMatch {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        (*self as i32).as_bytes(bytes)
+    pub fn level_one_only(&self) -> bool {
+        self.inner & (1 << 7) != 0
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        match underlying {
-            254 => Some((Self::Any, sz)),
-            255 => Some((Self::All, sz)),
-            _ => None,
+    pub fn set_level_one_only(&mut self, val: bool) -> &mut Self {
+        if val {
+            self.inner |= 1 << 7;
+        } else {
+            self.inner &= !(1 << 7);
         }
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        ::core::mem::size_of::<i32>()
+    pub fn new(level_one_only: bool) -> Self {
+        let mut inner: i32 = 0;
+        if level_one_only {
+            inner |= 1 << 7;
+        }
+        SymInterpMatch { inner: inner }
+    }
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.inner.count_ones() as usize
     }
+    pub const LEVEL_ONE_ONLY: Self = Self { inner: 128 };
+    pub const COMPLETE: Self = Self { inner: 128 };
 }
-impl Default for Groups {
+impl core::ops::Deref for SymInterpMatch {
+    type Target = i32;
     #[inline]
-    fn default() -> Groups {
-        Groups::Any
+    fn deref(&self) -> &i32 {
+        &self.inner
     }
 }
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Error {
-    BadId = 253,
-    BadClass = 254,
-    BadDevice = 255,
+impl AsRef<i32> for SymInterpMatch {
+    #[inline]
+    fn as_ref(&self) -> &i32 {
+        &self.inner
+    }
 }
-impl AsByteSequence for Error {
+impl_i32_flags_asb! { SymInterpMatch }
+impl core::ops::Not for SymInterpMatch {
+    type Output = SymInterpMatch;
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        (*self as i32).as_bytes(bytes)
+    fn not(self) -> SymInterpMatch {
+        SymInterpMatch { inner: !self.inner }
     }
+}
+impl core::ops::BitAnd for SymInterpMatch {
+    type Output = SymInterpMatch;
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        match underlying {
-            253 => Some((Self::BadId, sz)),
-            254 => Some((Self::BadClass, sz)),
-            255 => Some((Self::BadDevice, sz)),
-            _ => None,
+    fn bitand(self, rhs: SymInterpMatch) -> SymInterpMatch {
+        SymInterpMatch {
+            inner: self.inner & rhs.inner,
         }
     }
+}
+impl core::ops::BitOr for SymInterpMatch {
+    type Output = SymInterpMatch;
     #[inline]
-    fn size(&self) -> usize {
-        ::core::mem::size_of::<i32>()
+    fn bitor(self, rhs: SymInterpMatch) -> SymInterpMatch {
+        SymInterpMatch {
+            inner: self.inner | rhs.inner,
+        }
     }
 }
-impl Default for Error {
+impl core::ops::BitXor for SymInterpMatch {
+    type Output = SymInterpMatch;
     #[inline]
-    fn default() -> Error {
-        Error::BadId
+    fn bitxor(self, rhs: SymInterpMatch) -> SymInterpMatch {
+        SymInterpMatch {
+            inner: self.inner ^ rhs.inner,
+        }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct KeyboardError {
     pub _error_type: u8,
     pub error_code: u8,
-    pub major_code: u8,
-    pub minor_code: u8,
     pub sequence: u16,
     pub value: Card32,
     pub minor_opcode: Card16,
     pub major_opcode: Card8,
 }
-impl KeyboardError {}
-impl AsByteSequence for KeyboardError {
+impl KeyboardError {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 32;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("_error_type", 0, 1),
+        ("error_code", 1, 1),
+        ("sequence", 2, 2),
+        ("value", 4, 4),
+        ("minor_opcode", 8, 2),
+        ("major_opcode", 10, 1),
+    ];
+}
+const _: () = assert!(KeyboardError::FIXED_SIZE <= 32);
+impl AsByteSequence for KeyboardError {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self._error_type.as_bytes(&mut bytes[index..]);
         index += self.error_code.as_bytes(&mut bytes[index..]);
-        index += self.major_code.as_bytes(&mut bytes[index..]);
-        index += self.minor_code.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.value.as_bytes(&mut bytes[index..]);
         index += self.minor_opcode.as_bytes(&mut bytes[index..]);
         index += self.major_opcode.as_bytes(&mut bytes[index..]);
-        index += 21;
+        index += zero_pad(&mut bytes[index..], 21);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyboardError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = parse_item(bytes, index, "_error_type")?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = parse_item(bytes, index, "error_code")?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Card32, usize) = parse_item(bytes, index, "value")?;
         index += sz;
-        let (value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = parse_item(bytes, index, "minor_opcode")?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = parse_item(bytes, index, "major_opcode")?;
         index += sz;
         index += 21;
-        Some((
+        Ok((
             KeyboardError {
                 _error_type: _error_type,
                 error_code: error_code,
-                major_code: major_code,
-                minor_code: minor_code,
                 sequence: sequence,
                 value: value,
                 minor_opcode: minor_opcode,
@@ -15120,50 +21976,62 @@ impl AsByteSequence for KeyboardError {
     }
     #[inline]
     fn size(&self) -> usize {
-        self._error_type.size()
-            + self.error_code.size()
-            + self.major_code.size()
-            + self.minor_code.size()
-            + self.sequence.size()
-            + self.value.size()
-            + self.minor_opcode.size()
-            + self.major_opcode.size()
-            + 21
+        32
+    }
+}
+impl core::convert::TryFrom<&[u8]> for KeyboardError {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Error for KeyboardError {
     const OPCODE: u8 = 0;
 }
-#[derive(Clone, Debug, Default)]
-pub struct MapNotifyEvent {
+impl core::fmt::Display for KeyboardError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "X error: BadKeyboard (code 0) on request {}:{}",
+            self.major_opcode, self.minor_opcode
+        )
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for KeyboardError {}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct AccessXNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub ptr_btn_actions: Card8,
-    pub changed: MapPart,
-    pub min_key_code: Keycode,
-    pub max_key_code: Keycode,
-    pub first_type: Card8,
-    pub n_types: Card8,
-    pub first_key_sym: Keycode,
-    pub n_key_syms: Card8,
-    pub first_key_act: Keycode,
-    pub n_key_acts: Card8,
-    pub first_key_behavior: Keycode,
-    pub n_key_behavior: Card8,
-    pub first_key_explicit: Keycode,
-    pub n_key_explicit: Card8,
-    pub first_mod_map_key: Keycode,
-    pub n_mod_map_keys: Card8,
-    pub first_v_mod_map_key: Keycode,
-    pub n_v_mod_map_keys: Card8,
-    pub virtual_mods: VMod,
-}
-impl MapNotifyEvent {}
-impl AsByteSequence for MapNotifyEvent {
-    #[inline]
+    pub keycode: Keycode,
+    pub detailt: AxnDetail,
+    pub slow_keys_delay: Card16,
+    pub debounce_delay: Card16,
+}
+impl AccessXNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 10;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for AccessXNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15171,156 +22039,98 @@ impl AsByteSequence for MapNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.ptr_btn_actions.as_bytes(&mut bytes[index..]);
-        index += self.changed.as_bytes(&mut bytes[index..]);
-        index += self.min_key_code.as_bytes(&mut bytes[index..]);
-        index += self.max_key_code.as_bytes(&mut bytes[index..]);
-        index += self.first_type.as_bytes(&mut bytes[index..]);
-        index += self.n_types.as_bytes(&mut bytes[index..]);
-        index += self.first_key_sym.as_bytes(&mut bytes[index..]);
-        index += self.n_key_syms.as_bytes(&mut bytes[index..]);
-        index += self.first_key_act.as_bytes(&mut bytes[index..]);
-        index += self.n_key_acts.as_bytes(&mut bytes[index..]);
-        index += self.first_key_behavior.as_bytes(&mut bytes[index..]);
-        index += self.n_key_behavior.as_bytes(&mut bytes[index..]);
-        index += self.first_key_explicit.as_bytes(&mut bytes[index..]);
-        index += self.n_key_explicit.as_bytes(&mut bytes[index..]);
-        index += self.first_mod_map_key.as_bytes(&mut bytes[index..]);
-        index += self.n_mod_map_keys.as_bytes(&mut bytes[index..]);
-        index += self.first_v_mod_map_key.as_bytes(&mut bytes[index..]);
-        index += self.n_v_mod_map_keys.as_bytes(&mut bytes[index..]);
-        index += self.virtual_mods.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += self.keycode.as_bytes(&mut bytes[index..]);
+        index += self.detailt.as_bytes(&mut bytes[index..]);
+        index += self.slow_keys_delay.as_bytes(&mut bytes[index..]);
+        index += self.debounce_delay.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 16);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing MapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (ptr_btn_actions, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (changed, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (n_key_syms, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (first_key_act, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (n_key_acts, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing AccessXNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (n_key_behavior, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (detailt, sz): (AxnDetail, usize) = parse_item(bytes, index, "detailt")?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (slow_keys_delay, sz): (Card16, usize) = parse_item(bytes, index, "slow_keys_delay")?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (debounce_delay, sz): (Card16, usize) = parse_item(bytes, index, "debounce_delay")?;
         index += sz;
-        index += 2;
-        Some((
-            MapNotifyEvent {
+        index += 16;
+        Ok((
+            AccessXNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                ptr_btn_actions: ptr_btn_actions,
-                changed: changed,
-                min_key_code: min_key_code,
-                max_key_code: max_key_code,
-                first_type: first_type,
-                n_types: n_types,
-                first_key_sym: first_key_sym,
-                n_key_syms: n_key_syms,
-                first_key_act: first_key_act,
-                n_key_acts: n_key_acts,
-                first_key_behavior: first_key_behavior,
-                n_key_behavior: n_key_behavior,
-                first_key_explicit: first_key_explicit,
-                n_key_explicit: n_key_explicit,
-                first_mod_map_key: first_mod_map_key,
-                n_mod_map_keys: n_mod_map_keys,
-                first_v_mod_map_key: first_v_mod_map_key,
-                n_v_mod_map_keys: n_v_mod_map_keys,
-                virtual_mods: virtual_mods,
+                keycode: keycode,
+                detailt: detailt,
+                slow_keys_delay: slow_keys_delay,
+                debounce_delay: debounce_delay,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.ptr_btn_actions.size()
-            + self.changed.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.first_type.size()
-            + self.n_types.size()
-            + self.first_key_sym.size()
-            + self.n_key_syms.size()
-            + self.first_key_act.size()
-            + self.n_key_acts.size()
-            + self.first_key_behavior.size()
-            + self.n_key_behavior.size()
-            + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
-            + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + self.virtual_mods.size()
-            + 2
+        27 + self.time.size() + self.keycode.size()
     }
 }
-impl crate::auto::Event for MapNotifyEvent {
-    const OPCODE: u8 = 1;
+impl core::convert::TryFrom<&[u8]> for AccessXNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct IndicatorStateNotifyEvent {
+impl crate::auto::Event for AccessXNotifyEvent {
+    const OPCODE: u8 = 10;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct ActionMessageEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub state: Card32,
-    pub state_changed: Card32,
+    pub keycode: Keycode,
+    pub press: bool,
+    pub key_event_follows: bool,
+    pub mods: ModMask,
+    pub group: Group,
+    pub message: [String8; 8],
 }
-impl IndicatorStateNotifyEvent {}
-impl AsByteSequence for IndicatorStateNotifyEvent {
+impl ActionMessageEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 9;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for ActionMessageEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15328,80 +22138,112 @@ impl AsByteSequence for IndicatorStateNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += self.state.as_bytes(&mut bytes[index..]);
-        index += self.state_changed.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += self.keycode.as_bytes(&mut bytes[index..]);
+        index += self.press.as_bytes(&mut bytes[index..]);
+        index += self.key_event_follows.as_bytes(&mut bytes[index..]);
+        index += self.mods.as_bytes(&mut bytes[index..]);
+        index += self.group.as_bytes(&mut bytes[index..]);
+        index += self.message.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 10);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing IndicatorStateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing ActionMessageEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        index += 3;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
         index += sz;
-        let (state_changed, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (press, sz): (bool, usize) = parse_item(bytes, index, "press")?;
         index += sz;
-        index += 12;
-        Some((
-            IndicatorStateNotifyEvent {
+        let (key_event_follows, sz): (bool, usize) = parse_item(bytes, index, "key_event_follows")?;
+        index += sz;
+        let (mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods")?;
+        index += sz;
+        let (group, sz): (Group, usize) = parse_item(bytes, index, "group")?;
+        index += sz;
+        let (message, sz): ([String8; 8], usize) = parse_item(bytes, index, "message")?;
+        index += sz;
+        index += 10;
+        Ok((
+            ActionMessageEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                state: state,
-                state_changed: state_changed,
+                keycode: keycode,
+                press: press,
+                key_event_follows: key_event_follows,
+                mods: mods,
+                group: group,
+                message: message,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + 3
-            + self.state.size()
-            + self.state_changed.size()
-            + 12
+        24 + self.time.size()
+            + self.keycode.size()
+            + self.press.size()
+            + self.key_event_follows.size()
+            + self.mods.size()
     }
 }
-impl crate::auto::Event for IndicatorStateNotifyEvent {
-    const OPCODE: u8 = 4;
+impl core::convert::TryFrom<&[u8]> for ActionMessageEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct NewKeyboardNotifyEvent {
+impl crate::auto::Event for ActionMessageEvent {
+    const OPCODE: u8 = 9;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct BellNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub old_device_id: Card8,
-    pub min_key_code: Keycode,
-    pub max_key_code: Keycode,
-    pub old_min_key_code: Keycode,
-    pub old_max_key_code: Keycode,
-    pub request_major: Card8,
-    pub request_minor: Card8,
-    pub changed: NknDetail,
+    pub bell_class: BellClassResult,
+    pub bell_id: Card8,
+    pub percent: Card8,
+    pub pitch: Card16,
+    pub duration: Card16,
+    pub name: Atom,
+    pub window: Window,
+    pub event_only: bool,
 }
-impl NewKeyboardNotifyEvent {}
-impl AsByteSequence for NewKeyboardNotifyEvent {
+impl BellNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for BellNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15409,101 +22251,112 @@ impl AsByteSequence for NewKeyboardNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.old_device_id.as_bytes(&mut bytes[index..]);
-        index += self.min_key_code.as_bytes(&mut bytes[index..]);
-        index += self.max_key_code.as_bytes(&mut bytes[index..]);
-        index += self.old_min_key_code.as_bytes(&mut bytes[index..]);
-        index += self.old_max_key_code.as_bytes(&mut bytes[index..]);
-        index += self.request_major.as_bytes(&mut bytes[index..]);
-        index += self.request_minor.as_bytes(&mut bytes[index..]);
-        index += self.changed.as_bytes(&mut bytes[index..]);
-        index += 14;
+        index += self.bell_class.as_bytes(&mut bytes[index..]);
+        index += self.bell_id.as_bytes(&mut bytes[index..]);
+        index += self.percent.as_bytes(&mut bytes[index..]);
+        index += self.pitch.as_bytes(&mut bytes[index..]);
+        index += self.duration.as_bytes(&mut bytes[index..]);
+        index += self.name.as_bytes(&mut bytes[index..]);
+        index += self.window.as_bytes(&mut bytes[index..]);
+        index += self.event_only.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 7);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing NewKeyboardNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing BellNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (old_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bell_class, sz): (BellClassResult, usize) = parse_item(bytes, index, "bell_class")?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (bell_id, sz): (Card8, usize) = parse_item(bytes, index, "bell_id")?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Card8, usize) = parse_item(bytes, index, "percent")?;
         index += sz;
-        let (old_min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Card16, usize) = parse_item(bytes, index, "pitch")?;
         index += sz;
-        let (old_max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (duration, sz): (Card16, usize) = parse_item(bytes, index, "duration")?;
         index += sz;
-        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (changed, sz): (NknDetail, usize) = <NknDetail>::from_bytes(&bytes[index..])?;
+        let (event_only, sz): (bool, usize) = parse_item(bytes, index, "event_only")?;
         index += sz;
-        index += 14;
-        Some((
-            NewKeyboardNotifyEvent {
+        index += 7;
+        Ok((
+            BellNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                old_device_id: old_device_id,
-                min_key_code: min_key_code,
-                max_key_code: max_key_code,
-                old_min_key_code: old_min_key_code,
-                old_max_key_code: old_max_key_code,
-                request_major: request_major,
-                request_minor: request_minor,
-                changed: changed,
+                bell_class: bell_class,
+                bell_id: bell_id,
+                percent: percent,
+                pitch: pitch,
+                duration: duration,
+                name: name,
+                window: window,
+                event_only: event_only,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.old_device_id.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.old_min_key_code.size()
-            + self.old_max_key_code.size()
-            + self.request_major.size()
-            + self.request_minor.size()
-            + self.changed.size()
-            + 14
+        19 + self.time.size() + self.name.size() + self.window.size() + self.event_only.size()
     }
 }
-impl crate::auto::Event for NewKeyboardNotifyEvent {
-    const OPCODE: u8 = 0;
+impl core::convert::TryFrom<&[u8]> for BellNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct IndicatorMapNotifyEvent {
+impl crate::auto::Event for BellNotifyEvent {
+    const OPCODE: u8 = 8;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct CompatMapNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub state: Card32,
-    pub map_changed: Card32,
+    pub changed_groups: SetOfGroup,
+    pub first_si: Card16,
+    pub n_si: Card16,
+    pub n_total_si: Card16,
 }
-impl IndicatorMapNotifyEvent {}
-impl AsByteSequence for IndicatorMapNotifyEvent {
+impl CompatMapNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 7;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for CompatMapNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15511,62 +22364,191 @@ impl AsByteSequence for IndicatorMapNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += 3;
-        index += self.state.as_bytes(&mut bytes[index..]);
-        index += self.map_changed.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += self.changed_groups.as_bytes(&mut bytes[index..]);
+        index += self.first_si.as_bytes(&mut bytes[index..]);
+        index += self.n_si.as_bytes(&mut bytes[index..]);
+        index += self.n_total_si.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 16);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing IndicatorMapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing CompatMapNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        index += 3;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (changed_groups, sz): (SetOfGroup, usize) = parse_item(bytes, index, "changed_groups")?;
         index += sz;
-        let (map_changed, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (first_si, sz): (Card16, usize) = parse_item(bytes, index, "first_si")?;
         index += sz;
-        index += 12;
-        Some((
-            IndicatorMapNotifyEvent {
+        let (n_si, sz): (Card16, usize) = parse_item(bytes, index, "n_si")?;
+        index += sz;
+        let (n_total_si, sz): (Card16, usize) = parse_item(bytes, index, "n_total_si")?;
+        index += sz;
+        index += 16;
+        Ok((
+            CompatMapNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                state: state,
-                map_changed: map_changed,
+                changed_groups: changed_groups,
+                first_si: first_si,
+                n_si: n_si,
+                n_total_si: n_total_si,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + 3
-            + self.state.size()
-            + self.map_changed.size()
-            + 12
+        28 + self.time.size()
     }
 }
-impl crate::auto::Event for IndicatorMapNotifyEvent {
-    const OPCODE: u8 = 5;
+impl core::convert::TryFrom<&[u8]> for CompatMapNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl crate::auto::Event for CompatMapNotifyEvent {
+    const OPCODE: u8 = 7;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct ControlsNotifyEvent {
+    pub event_type: u8,
+    pub xkb_type: Card8,
+    pub sequence: u16,
+    pub time: Timestamp,
+    pub device_id: Card8,
+    pub num_groups: Card8,
+    pub changed_controls: Control,
+    pub enabled_controls: BoolCtrl,
+    pub enabled_control_changes: BoolCtrl,
+    pub keycode: Keycode,
+    pub event_type_: Card8,
+    pub request_major: Card8,
+    pub request_minor: Card8,
+}
+impl ControlsNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 3;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for ControlsNotifyEvent {
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.event_type.as_bytes(&mut bytes[index..]);
+        index += self.xkb_type.as_bytes(&mut bytes[index..]);
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.time.as_bytes(&mut bytes[index..]);
+        index += self.device_id.as_bytes(&mut bytes[index..]);
+        index += self.num_groups.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 2);
+        index += self.changed_controls.as_bytes(&mut bytes[index..]);
+        index += self.enabled_controls.as_bytes(&mut bytes[index..]);
+        index += self.enabled_control_changes.as_bytes(&mut bytes[index..]);
+        index += self.keycode.as_bytes(&mut bytes[index..]);
+        index += self.event_type_.as_bytes(&mut bytes[index..]);
+        index += self.request_major.as_bytes(&mut bytes[index..]);
+        index += self.request_minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 4);
+        index
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing ControlsNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
+        index += sz;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (num_groups, sz): (Card8, usize) = parse_item(bytes, index, "num_groups")?;
+        index += sz;
+        index += 2;
+        let (changed_controls, sz): (Control, usize) =
+            parse_item(bytes, index, "changed_controls")?;
+        index += sz;
+        let (enabled_controls, sz): (BoolCtrl, usize) =
+            parse_item(bytes, index, "enabled_controls")?;
+        index += sz;
+        let (enabled_control_changes, sz): (BoolCtrl, usize) =
+            parse_item(bytes, index, "enabled_control_changes")?;
+        index += sz;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
+        index += sz;
+        let (event_type_, sz): (Card8, usize) = parse_item(bytes, index, "event_type_")?;
+        index += sz;
+        let (request_major, sz): (Card8, usize) = parse_item(bytes, index, "request_major")?;
+        index += sz;
+        let (request_minor, sz): (Card8, usize) = parse_item(bytes, index, "request_minor")?;
+        index += sz;
+        index += 4;
+        Ok((
+            ControlsNotifyEvent {
+                event_type: event_type,
+                xkb_type: xkb_type,
+                sequence: sequence,
+                time: time,
+                device_id: device_id,
+                num_groups: num_groups,
+                changed_controls: changed_controls,
+                enabled_controls: enabled_controls,
+                enabled_control_changes: enabled_control_changes,
+                keycode: keycode,
+                event_type_: event_type_,
+                request_major: request_major,
+                request_minor: request_minor,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        27 + self.time.size() + self.keycode.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ControlsNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl crate::auto::Event for ControlsNotifyEvent {
+    const OPCODE: u8 = 3;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct ExtensionDeviceNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
@@ -15583,9 +22565,22 @@ pub struct ExtensionDeviceNotifyEvent {
     pub supported: XiFeature,
     pub unsupported: XiFeature,
 }
-impl ExtensionDeviceNotifyEvent {}
-impl AsByteSequence for ExtensionDeviceNotifyEvent {
+impl ExtensionDeviceNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 11;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for ExtensionDeviceNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15593,7 +22588,7 @@ impl AsByteSequence for ExtensionDeviceNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.reason.as_bytes(&mut bytes[index..]);
         index += self.led_class.as_bytes(&mut bytes[index..]);
         index += self.led_id.as_bytes(&mut bytes[index..]);
@@ -15603,45 +22598,43 @@ impl AsByteSequence for ExtensionDeviceNotifyEvent {
         index += self.n_buttons.as_bytes(&mut bytes[index..]);
         index += self.supported.as_bytes(&mut bytes[index..]);
         index += self.unsupported.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ExtensionDeviceNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
         index += 1;
-        let (reason, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (reason, sz): (XiFeature, usize) = parse_item(bytes, index, "reason")?;
         index += sz;
-        let (led_class, sz): (LedClassResult, usize) =
-            <LedClassResult>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClassResult, usize) = parse_item(bytes, index, "led_class")?;
         index += sz;
-        let (led_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (Card16, usize) = parse_item(bytes, index, "led_id")?;
         index += sz;
-        let (leds_defined, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (leds_defined, sz): (Card32, usize) = parse_item(bytes, index, "leds_defined")?;
         index += sz;
-        let (led_state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_state, sz): (Card32, usize) = parse_item(bytes, index, "led_state")?;
         index += sz;
-        let (first_button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_button, sz): (Card8, usize) = parse_item(bytes, index, "first_button")?;
         index += sz;
-        let (n_buttons, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_buttons, sz): (Card8, usize) = parse_item(bytes, index, "n_buttons")?;
         index += sz;
-        let (supported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (XiFeature, usize) = parse_item(bytes, index, "supported")?;
         index += sz;
-        let (unsupported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (unsupported, sz): (XiFeature, usize) = parse_item(bytes, index, "unsupported")?;
         index += sz;
         index += 2;
-        Some((
+        Ok((
             ExtensionDeviceNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
@@ -15663,42 +22656,48 @@ impl AsByteSequence for ExtensionDeviceNotifyEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + 1
-            + self.reason.size()
-            + self.led_class.size()
-            + self.led_id.size()
-            + self.leds_defined.size()
-            + self.led_state.size()
-            + self.first_button.size()
-            + self.n_buttons.size()
-            + self.supported.size()
-            + self.unsupported.size()
-            + 2
+        28 + self.time.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ExtensionDeviceNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for ExtensionDeviceNotifyEvent {
     const OPCODE: u8 = 11;
 }
-#[derive(Clone, Debug, Default)]
-pub struct AccessXNotifyEvent {
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct IndicatorMapNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub keycode: Keycode,
-    pub detailt: AxnDetail,
-    pub slow_keys_delay: Card16,
-    pub debounce_delay: Card16,
+    pub state: Card32,
+    pub map_changed: Card32,
 }
-impl AccessXNotifyEvent {}
-impl AsByteSequence for AccessXNotifyEvent {
+impl IndicatorMapNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 5;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for IndicatorMapNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15706,98 +22705,88 @@ impl AsByteSequence for AccessXNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.keycode.as_bytes(&mut bytes[index..]);
-        index += self.detailt.as_bytes(&mut bytes[index..]);
-        index += self.slow_keys_delay.as_bytes(&mut bytes[index..]);
-        index += self.debounce_delay.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += self.state.as_bytes(&mut bytes[index..]);
+        index += self.map_changed.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 12);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing AccessXNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing IndicatorMapNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (detailt, sz): (AxnDetail, usize) = <AxnDetail>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        index += 3;
+        let (state, sz): (Card32, usize) = parse_item(bytes, index, "state")?;
         index += sz;
-        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (map_changed, sz): (Card32, usize) = parse_item(bytes, index, "map_changed")?;
         index += sz;
-        index += 16;
-        Some((
-            AccessXNotifyEvent {
+        index += 12;
+        Ok((
+            IndicatorMapNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                keycode: keycode,
-                detailt: detailt,
-                slow_keys_delay: slow_keys_delay,
-                debounce_delay: debounce_delay,
+                state: state,
+                map_changed: map_changed,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.keycode.size()
-            + self.detailt.size()
-            + self.slow_keys_delay.size()
-            + self.debounce_delay.size()
-            + 16
+        28 + self.time.size()
     }
 }
-impl crate::auto::Event for AccessXNotifyEvent {
-    const OPCODE: u8 = 10;
+impl core::convert::TryFrom<&[u8]> for IndicatorMapNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct StateNotifyEvent {
+impl crate::auto::Event for IndicatorMapNotifyEvent {
+    const OPCODE: u8 = 5;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct IndicatorStateNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub mods: ModMask,
-    pub base_mods: ModMask,
-    pub latched_mods: ModMask,
-    pub locked_mods: ModMask,
-    pub group: Group,
-    pub base_group: Int16,
-    pub latched_group: Int16,
-    pub locked_group: Group,
-    pub compat_state: ModMask,
-    pub grab_mods: ModMask,
-    pub compat_grab_mods: ModMask,
-    pub lookup_mods: ModMask,
-    pub compat_loockup_mods: ModMask,
-    pub ptr_btn_state: KeyButMask,
-    pub changed: StatePart,
-    pub keycode: Keycode,
-    pub event_type_: Card8,
-    pub request_major: Card8,
-    pub request_minor: Card8,
+    pub state: Card32,
+    pub state_changed: Card32,
 }
-impl StateNotifyEvent {}
-impl AsByteSequence for StateNotifyEvent {
+impl IndicatorStateNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 4;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for IndicatorStateNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15805,141 +22794,247 @@ impl AsByteSequence for StateNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.mods.as_bytes(&mut bytes[index..]);
-        index += self.base_mods.as_bytes(&mut bytes[index..]);
-        index += self.latched_mods.as_bytes(&mut bytes[index..]);
-        index += self.locked_mods.as_bytes(&mut bytes[index..]);
-        index += self.group.as_bytes(&mut bytes[index..]);
-        index += self.base_group.as_bytes(&mut bytes[index..]);
-        index += self.latched_group.as_bytes(&mut bytes[index..]);
-        index += self.locked_group.as_bytes(&mut bytes[index..]);
-        index += self.compat_state.as_bytes(&mut bytes[index..]);
-        index += self.grab_mods.as_bytes(&mut bytes[index..]);
-        index += self.compat_grab_mods.as_bytes(&mut bytes[index..]);
-        index += self.lookup_mods.as_bytes(&mut bytes[index..]);
-        index += self.compat_loockup_mods.as_bytes(&mut bytes[index..]);
-        index += self.ptr_btn_state.as_bytes(&mut bytes[index..]);
-        index += self.changed.as_bytes(&mut bytes[index..]);
-        index += self.keycode.as_bytes(&mut bytes[index..]);
-        index += self.event_type_.as_bytes(&mut bytes[index..]);
-        index += self.request_major.as_bytes(&mut bytes[index..]);
-        index += self.request_minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 3);
+        index += self.state.as_bytes(&mut bytes[index..]);
+        index += self.state_changed.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 12);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing StateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (base_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (latched_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (locked_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (base_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (latched_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (locked_group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (compat_state, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (compat_grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (compat_loockup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing IndicatorStateNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (ptr_btn_state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (changed, sz): (StatePart, usize) = <StatePart>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (event_type_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        index += 3;
+        let (state, sz): (Card32, usize) = parse_item(bytes, index, "state")?;
         index += sz;
-        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (state_changed, sz): (Card32, usize) = parse_item(bytes, index, "state_changed")?;
         index += sz;
-        Some((
-            StateNotifyEvent {
+        index += 12;
+        Ok((
+            IndicatorStateNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                mods: mods,
-                base_mods: base_mods,
-                latched_mods: latched_mods,
-                locked_mods: locked_mods,
-                group: group,
-                base_group: base_group,
-                latched_group: latched_group,
-                locked_group: locked_group,
-                compat_state: compat_state,
-                grab_mods: grab_mods,
-                compat_grab_mods: compat_grab_mods,
-                lookup_mods: lookup_mods,
-                compat_loockup_mods: compat_loockup_mods,
-                ptr_btn_state: ptr_btn_state,
-                changed: changed,
-                keycode: keycode,
-                event_type_: event_type_,
-                request_major: request_major,
-                request_minor: request_minor,
+                state: state,
+                state_changed: state_changed,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.mods.size()
-            + self.base_mods.size()
-            + self.latched_mods.size()
-            + self.locked_mods.size()
-            + self.group.size()
-            + self.base_group.size()
-            + self.latched_group.size()
-            + self.locked_group.size()
-            + self.compat_state.size()
-            + self.grab_mods.size()
-            + self.compat_grab_mods.size()
-            + self.lookup_mods.size()
-            + self.compat_loockup_mods.size()
-            + self.ptr_btn_state.size()
-            + self.changed.size()
-            + self.keycode.size()
-            + self.event_type_.size()
-            + self.request_major.size()
-            + self.request_minor.size()
+        28 + self.time.size()
     }
 }
-impl crate::auto::Event for StateNotifyEvent {
-    const OPCODE: u8 = 2;
+impl core::convert::TryFrom<&[u8]> for IndicatorStateNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
+impl crate::auto::Event for IndicatorStateNotifyEvent {
+    const OPCODE: u8 = 4;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct MapNotifyEvent {
+    pub event_type: u8,
+    pub xkb_type: Card8,
+    pub sequence: u16,
+    pub time: Timestamp,
+    pub device_id: Card8,
+    pub ptr_btn_actions: Card8,
+    pub changed: MapPart,
+    pub min_key_code: Keycode,
+    pub max_key_code: Keycode,
+    pub first_type: Card8,
+    pub n_types: Card8,
+    pub first_key_sym: Keycode,
+    pub n_key_syms: Card8,
+    pub first_key_act: Keycode,
+    pub n_key_acts: Card8,
+    pub first_key_behavior: Keycode,
+    pub n_key_behavior: Card8,
+    pub first_key_explicit: Keycode,
+    pub n_key_explicit: Card8,
+    pub first_mod_map_key: Keycode,
+    pub n_mod_map_keys: Card8,
+    pub first_v_mod_map_key: Keycode,
+    pub n_v_mod_map_keys: Card8,
+    pub virtual_mods: VMod,
+}
+impl MapNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 1;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for MapNotifyEvent {
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.event_type.as_bytes(&mut bytes[index..]);
+        index += self.xkb_type.as_bytes(&mut bytes[index..]);
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.time.as_bytes(&mut bytes[index..]);
+        index += self.device_id.as_bytes(&mut bytes[index..]);
+        index += self.ptr_btn_actions.as_bytes(&mut bytes[index..]);
+        index += self.changed.as_bytes(&mut bytes[index..]);
+        index += self.min_key_code.as_bytes(&mut bytes[index..]);
+        index += self.max_key_code.as_bytes(&mut bytes[index..]);
+        index += self.first_type.as_bytes(&mut bytes[index..]);
+        index += self.n_types.as_bytes(&mut bytes[index..]);
+        index += self.first_key_sym.as_bytes(&mut bytes[index..]);
+        index += self.n_key_syms.as_bytes(&mut bytes[index..]);
+        index += self.first_key_act.as_bytes(&mut bytes[index..]);
+        index += self.n_key_acts.as_bytes(&mut bytes[index..]);
+        index += self.first_key_behavior.as_bytes(&mut bytes[index..]);
+        index += self.n_key_behavior.as_bytes(&mut bytes[index..]);
+        index += self.first_key_explicit.as_bytes(&mut bytes[index..]);
+        index += self.n_key_explicit.as_bytes(&mut bytes[index..]);
+        index += self.first_mod_map_key.as_bytes(&mut bytes[index..]);
+        index += self.n_mod_map_keys.as_bytes(&mut bytes[index..]);
+        index += self.first_v_mod_map_key.as_bytes(&mut bytes[index..]);
+        index += self.n_v_mod_map_keys.as_bytes(&mut bytes[index..]);
+        index += self.virtual_mods.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 2);
+        index
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing MapNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
+        index += sz;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
+        index += sz;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
+        index += sz;
+        let (ptr_btn_actions, sz): (Card8, usize) = parse_item(bytes, index, "ptr_btn_actions")?;
+        index += sz;
+        let (changed, sz): (MapPart, usize) = parse_item(bytes, index, "changed")?;
+        index += sz;
+        let (min_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "min_key_code")?;
+        index += sz;
+        let (max_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "max_key_code")?;
+        index += sz;
+        let (first_type, sz): (Card8, usize) = parse_item(bytes, index, "first_type")?;
+        index += sz;
+        let (n_types, sz): (Card8, usize) = parse_item(bytes, index, "n_types")?;
+        index += sz;
+        let (first_key_sym, sz): (Keycode, usize) = parse_item(bytes, index, "first_key_sym")?;
+        index += sz;
+        let (n_key_syms, sz): (Card8, usize) = parse_item(bytes, index, "n_key_syms")?;
+        index += sz;
+        let (first_key_act, sz): (Keycode, usize) = parse_item(bytes, index, "first_key_act")?;
+        index += sz;
+        let (n_key_acts, sz): (Card8, usize) = parse_item(bytes, index, "n_key_acts")?;
+        index += sz;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_behavior")?;
+        index += sz;
+        let (n_key_behavior, sz): (Card8, usize) = parse_item(bytes, index, "n_key_behavior")?;
+        index += sz;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_key_explicit")?;
+        index += sz;
+        let (n_key_explicit, sz): (Card8, usize) = parse_item(bytes, index, "n_key_explicit")?;
+        index += sz;
+        let (first_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_mod_map_key")?;
+        index += sz;
+        let (n_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_mod_map_keys")?;
+        index += sz;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            parse_item(bytes, index, "first_v_mod_map_key")?;
+        index += sz;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_v_mod_map_keys")?;
+        index += sz;
+        let (virtual_mods, sz): (VMod, usize) = parse_item(bytes, index, "virtual_mods")?;
+        index += sz;
+        index += 2;
+        Ok((
+            MapNotifyEvent {
+                event_type: event_type,
+                xkb_type: xkb_type,
+                sequence: sequence,
+                time: time,
+                device_id: device_id,
+                ptr_btn_actions: ptr_btn_actions,
+                changed: changed,
+                min_key_code: min_key_code,
+                max_key_code: max_key_code,
+                first_type: first_type,
+                n_types: n_types,
+                first_key_sym: first_key_sym,
+                n_key_syms: n_key_syms,
+                first_key_act: first_key_act,
+                n_key_acts: n_key_acts,
+                first_key_behavior: first_key_behavior,
+                n_key_behavior: n_key_behavior,
+                first_key_explicit: first_key_explicit,
+                n_key_explicit: n_key_explicit,
+                first_mod_map_key: first_mod_map_key,
+                n_mod_map_keys: n_mod_map_keys,
+                first_v_mod_map_key: first_v_mod_map_key,
+                n_v_mod_map_keys: n_v_mod_map_keys,
+                virtual_mods: virtual_mods,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        20 + self.time.size()
+            + self.min_key_code.size()
+            + self.max_key_code.size()
+            + self.first_key_sym.size()
+            + self.first_key_act.size()
+            + self.first_key_behavior.size()
+            + self.first_key_explicit.size()
+            + self.first_mod_map_key.size()
+            + self.first_v_mod_map_key.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for MapNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl crate::auto::Event for MapNotifyEvent {
+    const OPCODE: u8 = 1;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
 pub struct NamesNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
@@ -15959,9 +23054,22 @@ pub struct NamesNotifyEvent {
     pub n_keys: Card8,
     pub changed_indicators: Card32,
 }
-impl NamesNotifyEvent {}
-impl AsByteSequence for NamesNotifyEvent {
+impl NamesNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 6;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for NamesNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -15969,7 +23077,7 @@ impl AsByteSequence for NamesNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.changed.as_bytes(&mut bytes[index..]);
         index += self.first_type.as_bytes(&mut bytes[index..]);
         index += self.n_types.as_bytes(&mut bytes[index..]);
@@ -15982,51 +23090,52 @@ impl AsByteSequence for NamesNotifyEvent {
         index += self.first_key.as_bytes(&mut bytes[index..]);
         index += self.n_keys.as_bytes(&mut bytes[index..]);
         index += self.changed_indicators.as_bytes(&mut bytes[index..]);
-        index += 4;
+        index += zero_pad(&mut bytes[index..], 4);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing NamesNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
         index += 1;
-        let (changed, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let (changed, sz): (NameDetail, usize) = parse_item(bytes, index, "changed")?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = parse_item(bytes, index, "first_type")?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = parse_item(bytes, index, "n_types")?;
         index += sz;
-        let (first_level_name, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_level_name, sz): (Card8, usize) = parse_item(bytes, index, "first_level_name")?;
         index += sz;
-        let (n_level_names, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_level_names, sz): (Card8, usize) = parse_item(bytes, index, "n_level_names")?;
         index += sz;
-        let (n_radio_groups, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_radio_groups, sz): (Card8, usize) = parse_item(bytes, index, "n_radio_groups")?;
         index += sz;
-        let (n_key_aliases, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_aliases, sz): (Card8, usize) = parse_item(bytes, index, "n_key_aliases")?;
         index += sz;
         let (changed_group_names, sz): (SetOfGroup, usize) =
-            <SetOfGroup>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "changed_group_names")?;
         index += sz;
-        let (changed_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (changed_virtual_mods, sz): (VMod, usize) =
+            parse_item(bytes, index, "changed_virtual_mods")?;
         index += sz;
-        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key, sz): (Keycode, usize) = parse_item(bytes, index, "first_key")?;
         index += sz;
-        let (n_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_keys, sz): (Card8, usize) = parse_item(bytes, index, "n_keys")?;
         index += sz;
-        let (changed_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (changed_indicators, sz): (Card32, usize) =
+            parse_item(bytes, index, "changed_indicators")?;
         index += sz;
         index += 4;
-        Some((
+        Ok((
             NamesNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
@@ -16051,45 +23160,54 @@ impl AsByteSequence for NamesNotifyEvent {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + 1
-            + self.changed.size()
-            + self.first_type.size()
-            + self.n_types.size()
-            + self.first_level_name.size()
-            + self.n_level_names.size()
-            + self.n_radio_groups.size()
-            + self.n_key_aliases.size()
-            + self.changed_group_names.size()
-            + self.changed_virtual_mods.size()
-            + self.first_key.size()
-            + self.n_keys.size()
-            + self.changed_indicators.size()
-            + 4
+        28 + self.time.size() + self.first_key.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for NamesNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl crate::auto::Event for NamesNotifyEvent {
     const OPCODE: u8 = 6;
 }
-#[derive(Clone, Debug, Default)]
-pub struct CompatMapNotifyEvent {
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct NewKeyboardNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub changed_groups: SetOfGroup,
-    pub first_si: Card16,
-    pub n_si: Card16,
-    pub n_total_si: Card16,
+    pub old_device_id: Card8,
+    pub min_key_code: Keycode,
+    pub max_key_code: Keycode,
+    pub old_min_key_code: Keycode,
+    pub old_max_key_code: Keycode,
+    pub request_major: Card8,
+    pub request_minor: Card8,
+    pub changed: NknDetail,
 }
-impl CompatMapNotifyEvent {}
-impl AsByteSequence for CompatMapNotifyEvent {
+impl NewKeyboardNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for NewKeyboardNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -16097,87 +23215,133 @@ impl AsByteSequence for CompatMapNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.changed_groups.as_bytes(&mut bytes[index..]);
-        index += self.first_si.as_bytes(&mut bytes[index..]);
-        index += self.n_si.as_bytes(&mut bytes[index..]);
-        index += self.n_total_si.as_bytes(&mut bytes[index..]);
-        index += 16;
+        index += self.old_device_id.as_bytes(&mut bytes[index..]);
+        index += self.min_key_code.as_bytes(&mut bytes[index..]);
+        index += self.max_key_code.as_bytes(&mut bytes[index..]);
+        index += self.old_min_key_code.as_bytes(&mut bytes[index..]);
+        index += self.old_max_key_code.as_bytes(&mut bytes[index..]);
+        index += self.request_major.as_bytes(&mut bytes[index..]);
+        index += self.request_minor.as_bytes(&mut bytes[index..]);
+        index += self.changed.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 14);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing CompatMapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing NewKeyboardNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (changed_groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (old_device_id, sz): (Card8, usize) = parse_item(bytes, index, "old_device_id")?;
         index += sz;
-        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "min_key_code")?;
         index += sz;
-        let (n_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = parse_item(bytes, index, "max_key_code")?;
         index += sz;
-        let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (old_min_key_code, sz): (Keycode, usize) =
+            parse_item(bytes, index, "old_min_key_code")?;
         index += sz;
-        index += 16;
-        Some((
-            CompatMapNotifyEvent {
+        let (old_max_key_code, sz): (Keycode, usize) =
+            parse_item(bytes, index, "old_max_key_code")?;
+        index += sz;
+        let (request_major, sz): (Card8, usize) = parse_item(bytes, index, "request_major")?;
+        index += sz;
+        let (request_minor, sz): (Card8, usize) = parse_item(bytes, index, "request_minor")?;
+        index += sz;
+        let (changed, sz): (NknDetail, usize) = parse_item(bytes, index, "changed")?;
+        index += sz;
+        index += 14;
+        Ok((
+            NewKeyboardNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                changed_groups: changed_groups,
-                first_si: first_si,
-                n_si: n_si,
-                n_total_si: n_total_si,
+                old_device_id: old_device_id,
+                min_key_code: min_key_code,
+                max_key_code: max_key_code,
+                old_min_key_code: old_min_key_code,
+                old_max_key_code: old_max_key_code,
+                request_major: request_major,
+                request_minor: request_minor,
+                changed: changed,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.changed_groups.size()
-            + self.first_si.size()
-            + self.n_si.size()
-            + self.n_total_si.size()
-            + 16
+        24 + self.time.size()
+            + self.min_key_code.size()
+            + self.max_key_code.size()
+            + self.old_min_key_code.size()
+            + self.old_max_key_code.size()
     }
 }
-impl crate::auto::Event for CompatMapNotifyEvent {
-    const OPCODE: u8 = 7;
+impl core::convert::TryFrom<&[u8]> for NewKeyboardNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct ControlsNotifyEvent {
+impl crate::auto::Event for NewKeyboardNotifyEvent {
+    const OPCODE: u8 = 0;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct StateNotifyEvent {
     pub event_type: u8,
     pub xkb_type: Card8,
     pub sequence: u16,
     pub time: Timestamp,
     pub device_id: Card8,
-    pub num_groups: Card8,
-    pub changed_controls: Control,
-    pub enabled_controls: BoolCtrl,
-    pub enabled_control_changes: BoolCtrl,
+    pub mods: ModMask,
+    pub base_mods: ModMask,
+    pub latched_mods: ModMask,
+    pub locked_mods: ModMask,
+    pub group: Group,
+    pub base_group: Int16,
+    pub latched_group: Int16,
+    pub locked_group: Group,
+    pub compat_state: ModMask,
+    pub grab_mods: ModMask,
+    pub compat_grab_mods: ModMask,
+    pub lookup_mods: ModMask,
+    pub compat_loockup_mods: ModMask,
+    pub ptr_btn_state: KeyButMask,
+    pub changed: StatePart,
     pub keycode: Keycode,
     pub event_type_: Card8,
     pub request_major: Card8,
     pub request_minor: Card8,
 }
-impl ControlsNotifyEvent {}
-impl AsByteSequence for ControlsNotifyEvent {
+impl StateNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 2;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("xkb_type", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for StateNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
@@ -16185,294 +23349,354 @@ impl AsByteSequence for ControlsNotifyEvent {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.num_groups.as_bytes(&mut bytes[index..]);
-        index += 2;
-        index += self.changed_controls.as_bytes(&mut bytes[index..]);
-        index += self.enabled_controls.as_bytes(&mut bytes[index..]);
-        index += self.enabled_control_changes.as_bytes(&mut bytes[index..]);
+        index += self.mods.as_bytes(&mut bytes[index..]);
+        index += self.base_mods.as_bytes(&mut bytes[index..]);
+        index += self.latched_mods.as_bytes(&mut bytes[index..]);
+        index += self.locked_mods.as_bytes(&mut bytes[index..]);
+        index += self.group.as_bytes(&mut bytes[index..]);
+        index += self.base_group.as_bytes(&mut bytes[index..]);
+        index += self.latched_group.as_bytes(&mut bytes[index..]);
+        index += self.locked_group.as_bytes(&mut bytes[index..]);
+        index += self.compat_state.as_bytes(&mut bytes[index..]);
+        index += self.grab_mods.as_bytes(&mut bytes[index..]);
+        index += self.compat_grab_mods.as_bytes(&mut bytes[index..]);
+        index += self.lookup_mods.as_bytes(&mut bytes[index..]);
+        index += self.compat_loockup_mods.as_bytes(&mut bytes[index..]);
+        index += self.ptr_btn_state.as_bytes(&mut bytes[index..]);
+        index += self.changed.as_bytes(&mut bytes[index..]);
         index += self.keycode.as_bytes(&mut bytes[index..]);
         index += self.event_type_.as_bytes(&mut bytes[index..]);
         index += self.request_major.as_bytes(&mut bytes[index..]);
         index += self.request_minor.as_bytes(&mut bytes[index..]);
-        index += 4;
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing ControlsNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing StateNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = parse_item(bytes, index, "xkb_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = parse_item(bytes, index, "device_id")?;
         index += sz;
-        let (num_groups, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = parse_item(bytes, index, "mods")?;
         index += sz;
-        index += 2;
-        let (changed_controls, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+        let (base_mods, sz): (ModMask, usize) = parse_item(bytes, index, "base_mods")?;
         index += sz;
-        let (enabled_controls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (latched_mods, sz): (ModMask, usize) = parse_item(bytes, index, "latched_mods")?;
         index += sz;
-        let (enabled_control_changes, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (locked_mods, sz): (ModMask, usize) = parse_item(bytes, index, "locked_mods")?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Group, usize) = parse_item(bytes, index, "group")?;
         index += sz;
-        let (event_type_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (base_group, sz): (Int16, usize) = parse_item(bytes, index, "base_group")?;
         index += sz;
-        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (latched_group, sz): (Int16, usize) = parse_item(bytes, index, "latched_group")?;
         index += sz;
-        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (locked_group, sz): (Group, usize) = parse_item(bytes, index, "locked_group")?;
         index += sz;
-        index += 4;
-        Some((
-            ControlsNotifyEvent {
-                event_type: event_type,
-                xkb_type: xkb_type,
-                sequence: sequence,
-                time: time,
-                device_id: device_id,
-                num_groups: num_groups,
-                changed_controls: changed_controls,
-                enabled_controls: enabled_controls,
-                enabled_control_changes: enabled_control_changes,
-                keycode: keycode,
-                event_type_: event_type_,
-                request_major: request_major,
-                request_minor: request_minor,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.num_groups.size()
-            + 2
-            + self.changed_controls.size()
-            + self.enabled_controls.size()
-            + self.enabled_control_changes.size()
-            + self.keycode.size()
-            + self.event_type_.size()
-            + self.request_major.size()
-            + self.request_minor.size()
-            + 4
-    }
-}
-impl crate::auto::Event for ControlsNotifyEvent {
-    const OPCODE: u8 = 3;
-}
-#[derive(Clone, Debug, Default)]
-pub struct ActionMessageEvent {
-    pub event_type: u8,
-    pub xkb_type: Card8,
-    pub sequence: u16,
-    pub time: Timestamp,
-    pub device_id: Card8,
-    pub keycode: Keycode,
-    pub press: bool,
-    pub key_event_follows: bool,
-    pub mods: ModMask,
-    pub group: Group,
-    pub message: [String8; 8],
-}
-impl ActionMessageEvent {}
-impl AsByteSequence for ActionMessageEvent {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += self.xkb_type.as_bytes(&mut bytes[index..]);
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.time.as_bytes(&mut bytes[index..]);
-        index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.keycode.as_bytes(&mut bytes[index..]);
-        index += self.press.as_bytes(&mut bytes[index..]);
-        index += self.key_event_follows.as_bytes(&mut bytes[index..]);
-        index += self.mods.as_bytes(&mut bytes[index..]);
-        index += self.group.as_bytes(&mut bytes[index..]);
-        index += self.message.as_bytes(&mut bytes[index..]);
-        index += 10;
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ActionMessageEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (compat_state, sz): (ModMask, usize) = parse_item(bytes, index, "compat_state")?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (grab_mods, sz): (ModMask, usize) = parse_item(bytes, index, "grab_mods")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (compat_grab_mods, sz): (ModMask, usize) =
+            parse_item(bytes, index, "compat_grab_mods")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (lookup_mods, sz): (ModMask, usize) = parse_item(bytes, index, "lookup_mods")?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (compat_loockup_mods, sz): (ModMask, usize) =
+            parse_item(bytes, index, "compat_loockup_mods")?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (ptr_btn_state, sz): (KeyButMask, usize) = parse_item(bytes, index, "ptr_btn_state")?;
         index += sz;
-        let (press, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (changed, sz): (StatePart, usize) = parse_item(bytes, index, "changed")?;
         index += sz;
-        let (key_event_follows, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = parse_item(bytes, index, "keycode")?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (event_type_, sz): (Card8, usize) = parse_item(bytes, index, "event_type_")?;
         index += sz;
-        let (group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (request_major, sz): (Card8, usize) = parse_item(bytes, index, "request_major")?;
         index += sz;
-        let (message, sz): ([String8; 8], usize) = <[String8; 8]>::from_bytes(&bytes[index..])?;
+        let (request_minor, sz): (Card8, usize) = parse_item(bytes, index, "request_minor")?;
         index += sz;
-        index += 10;
-        Some((
-            ActionMessageEvent {
+        Ok((
+            StateNotifyEvent {
                 event_type: event_type,
                 xkb_type: xkb_type,
                 sequence: sequence,
                 time: time,
                 device_id: device_id,
-                keycode: keycode,
-                press: press,
-                key_event_follows: key_event_follows,
                 mods: mods,
+                base_mods: base_mods,
+                latched_mods: latched_mods,
+                locked_mods: locked_mods,
                 group: group,
-                message: message,
+                base_group: base_group,
+                latched_group: latched_group,
+                locked_group: locked_group,
+                compat_state: compat_state,
+                grab_mods: grab_mods,
+                compat_grab_mods: compat_grab_mods,
+                lookup_mods: lookup_mods,
+                compat_loockup_mods: compat_loockup_mods,
+                ptr_btn_state: ptr_btn_state,
+                changed: changed,
+                keycode: keycode,
+                event_type_: event_type_,
+                request_major: request_major,
+                request_minor: request_minor,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.keycode.size()
-            + self.press.size()
-            + self.key_event_follows.size()
+        16 + self.time.size()
             + self.mods.size()
-            + self.group.size()
-            + self.message.size()
-            + 10
+            + self.base_mods.size()
+            + self.latched_mods.size()
+            + self.locked_mods.size()
+            + self.compat_state.size()
+            + self.grab_mods.size()
+            + self.compat_grab_mods.size()
+            + self.lookup_mods.size()
+            + self.compat_loockup_mods.size()
+            + self.ptr_btn_state.size()
+            + self.keycode.size()
     }
 }
-impl crate::auto::Event for ActionMessageEvent {
-    const OPCODE: u8 = 9;
+impl core::convert::TryFrom<&[u8]> for StateNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct BellNotifyEvent {
-    pub event_type: u8,
-    pub xkb_type: Card8,
-    pub sequence: u16,
-    pub time: Timestamp,
-    pub device_id: Card8,
-    pub bell_class: BellClassResult,
-    pub bell_id: Card8,
-    pub percent: Card8,
-    pub pitch: Card16,
-    pub duration: Card16,
-    pub name: Atom,
-    pub window: Window,
-    pub event_only: bool,
+impl crate::auto::Event for StateNotifyEvent {
+    const OPCODE: u8 = 2;
 }
-impl BellNotifyEvent {}
-impl AsByteSequence for BellNotifyEvent {
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    AccessXNotify(AccessXNotifyEvent),
+    ActionMessage(ActionMessageEvent),
+    BellNotify(BellNotifyEvent),
+    CompatMapNotify(CompatMapNotifyEvent),
+    ControlsNotify(ControlsNotifyEvent),
+    ExtensionDeviceNotify(ExtensionDeviceNotifyEvent),
+    IndicatorMapNotify(IndicatorMapNotifyEvent),
+    IndicatorStateNotify(IndicatorStateNotifyEvent),
+    MapNotify(MapNotifyEvent),
+    NamesNotify(NamesNotifyEvent),
+    NewKeyboardNotify(NewKeyboardNotifyEvent),
+    StateNotify(StateNotifyEvent),
+}
+impl Event {
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            10 => Ok(Self::AccessXNotify(
+                AccessXNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            9 => Ok(Self::ActionMessage(
+                ActionMessageEvent::from_bytes(bytes)?.0,
+            )),
+            8 => Ok(Self::BellNotify(BellNotifyEvent::from_bytes(bytes)?.0)),
+            7 => Ok(Self::CompatMapNotify(
+                CompatMapNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            3 => Ok(Self::ControlsNotify(
+                ControlsNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            11 => Ok(Self::ExtensionDeviceNotify(
+                ExtensionDeviceNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            5 => Ok(Self::IndicatorMapNotify(
+                IndicatorMapNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            4 => Ok(Self::IndicatorStateNotify(
+                IndicatorStateNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            1 => Ok(Self::MapNotify(MapNotifyEvent::from_bytes(bytes)?.0)),
+            6 => Ok(Self::NamesNotify(NamesNotifyEvent::from_bytes(bytes)?.0)),
+            0 => Ok(Self::NewKeyboardNotify(
+                NewKeyboardNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            2 => Ok(Self::StateNotify(StateNotifyEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::AccessXNotify(_) => AccessXNotifyEvent::RESPONSE_TYPE,
+            Self::ActionMessage(_) => ActionMessageEvent::RESPONSE_TYPE,
+            Self::BellNotify(_) => BellNotifyEvent::RESPONSE_TYPE,
+            Self::CompatMapNotify(_) => CompatMapNotifyEvent::RESPONSE_TYPE,
+            Self::ControlsNotify(_) => ControlsNotifyEvent::RESPONSE_TYPE,
+            Self::ExtensionDeviceNotify(_) => ExtensionDeviceNotifyEvent::RESPONSE_TYPE,
+            Self::IndicatorMapNotify(_) => IndicatorMapNotifyEvent::RESPONSE_TYPE,
+            Self::IndicatorStateNotify(_) => IndicatorStateNotifyEvent::RESPONSE_TYPE,
+            Self::MapNotify(_) => MapNotifyEvent::RESPONSE_TYPE,
+            Self::NamesNotify(_) => NamesNotifyEvent::RESPONSE_TYPE,
+            Self::NewKeyboardNotify(_) => NewKeyboardNotifyEvent::RESPONSE_TYPE,
+            Self::StateNotify(_) => StateNotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<AccessXNotifyEvent> for Event {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += self.xkb_type.as_bytes(&mut bytes[index..]);
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.time.as_bytes(&mut bytes[index..]);
-        index += self.device_id.as_bytes(&mut bytes[index..]);
-        index += self.bell_class.as_bytes(&mut bytes[index..]);
-        index += self.bell_id.as_bytes(&mut bytes[index..]);
-        index += self.percent.as_bytes(&mut bytes[index..]);
-        index += self.pitch.as_bytes(&mut bytes[index..]);
-        index += self.duration.as_bytes(&mut bytes[index..]);
-        index += self.name.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.event_only.as_bytes(&mut bytes[index..]);
-        index += 7;
-        index
+    fn from(event: AccessXNotifyEvent) -> Self {
+        Self::AccessXNotify(event)
     }
+}
+impl From<ActionMessageEvent> for Event {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing BellNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (bell_class, sz): (BellClassResult, usize) =
-            <BellClassResult>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (bell_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (percent, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (duration, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (event_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 7;
-        Some((
-            BellNotifyEvent {
-                event_type: event_type,
-                xkb_type: xkb_type,
-                sequence: sequence,
-                time: time,
-                device_id: device_id,
-                bell_class: bell_class,
-                bell_id: bell_id,
-                percent: percent,
-                pitch: pitch,
-                duration: duration,
-                name: name,
-                window: window,
-                event_only: event_only,
-            },
-            index,
-        ))
+    fn from(event: ActionMessageEvent) -> Self {
+        Self::ActionMessage(event)
     }
+}
+impl From<BellNotifyEvent> for Event {
     #[inline]
-    fn size(&self) -> usize {
-        self.event_type.size()
-            + self.xkb_type.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.device_id.size()
-            + self.bell_class.size()
-            + self.bell_id.size()
-            + self.percent.size()
-            + self.pitch.size()
-            + self.duration.size()
-            + self.name.size()
-            + self.window.size()
-            + self.event_only.size()
-            + 7
+    fn from(event: BellNotifyEvent) -> Self {
+        Self::BellNotify(event)
     }
 }
-impl crate::auto::Event for BellNotifyEvent {
-    const OPCODE: u8 = 8;
+impl From<CompatMapNotifyEvent> for Event {
+    #[inline]
+    fn from(event: CompatMapNotifyEvent) -> Self {
+        Self::CompatMapNotify(event)
+    }
+}
+impl From<ControlsNotifyEvent> for Event {
+    #[inline]
+    fn from(event: ControlsNotifyEvent) -> Self {
+        Self::ControlsNotify(event)
+    }
+}
+impl From<ExtensionDeviceNotifyEvent> for Event {
+    #[inline]
+    fn from(event: ExtensionDeviceNotifyEvent) -> Self {
+        Self::ExtensionDeviceNotify(event)
+    }
+}
+impl From<IndicatorMapNotifyEvent> for Event {
+    #[inline]
+    fn from(event: IndicatorMapNotifyEvent) -> Self {
+        Self::IndicatorMapNotify(event)
+    }
+}
+impl From<IndicatorStateNotifyEvent> for Event {
+    #[inline]
+    fn from(event: IndicatorStateNotifyEvent) -> Self {
+        Self::IndicatorStateNotify(event)
+    }
+}
+impl From<MapNotifyEvent> for Event {
+    #[inline]
+    fn from(event: MapNotifyEvent) -> Self {
+        Self::MapNotify(event)
+    }
+}
+impl From<NamesNotifyEvent> for Event {
+    #[inline]
+    fn from(event: NamesNotifyEvent) -> Self {
+        Self::NamesNotify(event)
+    }
+}
+impl From<NewKeyboardNotifyEvent> for Event {
+    #[inline]
+    fn from(event: NewKeyboardNotifyEvent) -> Self {
+        Self::NewKeyboardNotify(event)
+    }
+}
+impl From<StateNotifyEvent> for Event {
+    #[inline]
+    fn from(event: StateNotifyEvent) -> Self {
+        Self::StateNotify(event)
+    }
+}
+#[doc = " Parse an error of this extension out of its code and its bytes. The codes of the extension's errors count up from `first_error`, the first error code that the server gave it. Errors with other codes are kept as bytes."]
+#[inline]
+pub fn error_from_code(code: u8, first_error: u8, bytes: &[u8]) -> Result<XError, ParseError> {
+    Ok(match code.wrapping_sub(first_error) {
+        0 => XError::XkbKeyboard(KeyboardError::from_bytes(bytes)?.0),
+        _ => XError::Unknown {
+            code,
+            bytes: bytes.iter().copied().collect(),
+        },
+    })
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[
+    (0, "NewKeyboardNotify"),
+    (1, "MapNotify"),
+    (2, "StateNotify"),
+    (3, "ControlsNotify"),
+    (4, "IndicatorStateNotify"),
+    (5, "IndicatorMapNotify"),
+    (6, "NamesNotify"),
+    (7, "CompatMapNotify"),
+    (8, "BellNotify"),
+    (9, "ActionMessage"),
+    (10, "AccessXNotify"),
+    (11, "ExtensionDeviceNotify"),
+];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[(0, "Keyboard")];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "UseExtension"),
+    (1, "SelectEvents"),
+    (3, "Bell"),
+    (4, "GetState"),
+    (5, "LatchLockState"),
+    (6, "GetControls"),
+    (7, "SetControls"),
+    (8, "GetMap"),
+    (9, "SetMap"),
+    (10, "GetCompatMap"),
+    (11, "SetCompatMap"),
+    (12, "GetIndicatorState"),
+    (13, "GetIndicatorMap"),
+    (14, "SetIndicatorMap"),
+    (15, "GetNamedIndicator"),
+    (16, "SetNamedIndicator"),
+    (17, "GetNames"),
+    (18, "SetNames"),
+    (21, "PerClientFlags"),
+    (22, "ListComponents"),
+    (23, "GetKbdByName"),
+    (24, "GetDeviceInfo"),
+    (25, "SetDeviceInfo"),
+    (101, "SetDebuggingFlags"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
 }
@@ -1,49 +1,55 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "print")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 0;
 use super::xproto::*;
 pub type String8 = Char;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Printer {
     pub name: Vec<String8>,
     pub description: Vec<String8>,
 }
-impl Printer {}
+impl Printer {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("name_len", 0, 4)];
+}
 impl AsByteSequence for Printer {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += (self.name.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         index += (self.description.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.description, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Printer from byte buffer");
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (description, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "description")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        Some((
+        Ok((
             Printer {
                 name: name,
                 description: description,
@@ -53,22 +59,28 @@ impl AsByteSequence for Printer {
     }
     #[inline]
     fn size(&self) -> usize {
-        ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.name.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, 4);
-                block_len + pad
-            }
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.description.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, 4);
-                block_len + pad
-            }
+        8 + {
+            let block_len: usize = self.name.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, 4);
+            block_len + pad
+        } + {
+            let block_len: usize = self.description.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, 4);
+            block_len + pad
+        }
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Printer {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Pcontext {
     pub xid: XID,
 }
@@ -77,6 +89,23 @@ impl Pcontext {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Pcontext {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Pcontext {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Pcontext {
     #[inline]
@@ -88,50 +117,141 @@ impl XidType for Pcontext {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Pcontext {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Pcontext({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Pcontext {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Pcontext> for XID {
+    #[inline]
+    fn from(base: Pcontext) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintQueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl PrintQueryVersionRequest {}
-impl AsByteSequence for PrintQueryVersionRequest {
+impl PrintQueryVersionRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintQueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintQueryVersionRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> PrintQueryVersionRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintQueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<PrintQueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_empty_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_empty_request_asb! { PrintQueryVersionRequest }
 impl Request for PrintQueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintQueryVersion";
     type Reply = PrintQueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintQueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintQueryVersionRequestBuilder {
+    pub inner: PrintQueryVersionRequest,
+}
+impl PrintQueryVersionRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintQueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for PrintQueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintQueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintQueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -139,35 +259,105 @@ pub struct PrintQueryVersionReply {
     pub major_version: Card16,
     pub minor_version: Card16,
 }
-impl PrintQueryVersionReply {}
-impl AsByteSequence for PrintQueryVersionReply {
+impl PrintQueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major_version: Card16 = read_item(reader, &mut index, 2, "major_version")?;
+        let minor_version: Card16 = read_item(reader, &mut index, 2, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintQueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major_version: major_version,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major_version", 8, 2),
+        ("minor_version", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for PrintQueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintQueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major_version, sz): (Card16, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintQueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -180,61 +370,94 @@ impl AsByteSequence for PrintQueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major_version.size()
-            + self.minor_version.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for PrintQueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetPrinterListRequest {
     pub req_type: u8,
     pub length: u16,
     pub printer_name: Vec<String8>,
     pub locale: Vec<String8>,
 }
-impl PrintGetPrinterListRequest {}
+impl PrintGetPrinterListRequest {
+    #[inline]
+    pub fn builder() -> PrintGetPrinterListRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetPrinterListReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("printer_name_len", 4, 4),
+        ("locale_len", 8, 4),
+    ];
+}
+const _: () = assert!(<PrintGetPrinterListRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for PrintGetPrinterListRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.printer_name.len() as Card32).as_bytes(&mut bytes[index..]);
         index += (self.locale.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.printer_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
         let block_len: usize = vector_as_bytes(&self.locale, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetPrinterListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (printer_name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "printer_name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         let (locale, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "locale")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetPrinterListRequest {
                 req_type: req_type,
                 length: length,
@@ -244,73 +467,165 @@ impl AsByteSequence for PrintGetPrinterListRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
-            + {
+        let size: usize =
+            12 + {
                 let block_len: usize = self.printer_name.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.locale.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetPrinterListRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintGetPrinterListRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetPrinterList";
     type Reply = PrintGetPrinterListReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetPrinterListRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetPrinterListRequestBuilder {
+    pub inner: PrintGetPrinterListRequest,
+}
+impl PrintGetPrinterListRequestBuilder {
+    #[inline]
+    pub fn printer_name(mut self, printer_name: Vec<String8>) -> Self {
+        self.inner.printer_name = printer_name;
+        self
+    }
+    #[inline]
+    pub fn locale(mut self, locale: Vec<String8>) -> Self {
+        self.inner.locale = locale;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetPrinterListRequest {
+        self.inner
+    }
+}
+impl Default for PrintGetPrinterListRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetPrinterListRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                printer_name: Default::default(),
+                locale: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetPrinterListReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub printers: Vec<Printer>,
 }
-impl PrintGetPrinterListReply {}
-impl AsByteSequence for PrintGetPrinterListReply {
+impl PrintGetPrinterListReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn printers_iter(bytes: &[u8]) -> Result<ListIter<'_, Printer>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::padded(bytes, index, len0 as usize, 4, "printers"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("printers_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.printers == other.printers
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("printers", &self.printers, &other.printers, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for PrintGetPrinterListReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.printers.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = vector_as_bytes(&self.printers, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 20);
+        let block_len: usize = padded_vector_as_bytes(&self.printers, &mut bytes[index..], 4);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Printer>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Printer>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetPrinterListReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (printers, block_len): (Vec<Printer>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 4, "printers")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Printer>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetPrinterListReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -322,63 +637,87 @@ impl AsByteSequence for PrintGetPrinterListReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.printers.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Printer>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self
+                .printers
+                .iter()
+                .map(|i| {
+                    let size = i.size();
+                    size + buffer_pad(size, 4)
+                })
+                .sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Printer>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for PrintGetPrinterListReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintRehashPrinterListRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl PrintRehashPrinterListRequest {}
-impl AsByteSequence for PrintRehashPrinterListRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl PrintRehashPrinterListRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintRehashPrinterListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintRehashPrinterListRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn builder() -> PrintRehashPrinterListRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<PrintRehashPrinterListRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { PrintRehashPrinterListRequest }
 impl Request for PrintRehashPrinterListRequest {
-    const OPCODE: u8 = 20;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 20;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintRehashPrinterList";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintRehashPrinterListRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintRehashPrinterListRequestBuilder {
+    pub inner: PrintRehashPrinterListRequest,
+}
+impl PrintRehashPrinterListRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintRehashPrinterListRequest {
+        self.inner
+    }
+}
+impl Default for PrintRehashPrinterListRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintRehashPrinterListRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateContextRequest {
     pub req_type: u8,
     pub length: u16,
@@ -386,49 +725,69 @@ pub struct CreateContextRequest {
     pub printer_name: Vec<String8>,
     pub locale: Vec<String8>,
 }
-impl CreateContextRequest {}
+impl CreateContextRequest {
+    #[inline]
+    pub fn builder() -> CreateContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("context_id", 4, 4),
+        ("printer_name_len", 8, 4),
+        ("locale_len", 12, 4),
+    ];
+}
+const _: () = assert!(<CreateContextRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for CreateContextRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context_id.as_bytes(&mut bytes[index..]);
         index += (self.printer_name.len() as Card32).as_bytes(&mut bytes[index..]);
         index += (self.locale.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.printer_name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
         let block_len: usize = vector_as_bytes(&self.locale, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context_id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Card32, usize) = parse_item(bytes, index, "context_id")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (printer_name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "printer_name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         let (locale, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "locale")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateContextRequest {
                 req_type: req_type,
                 length: length,
@@ -439,156 +798,344 @@ impl AsByteSequence for CreateContextRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context_id.size()
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
-            + {
+        let size: usize =
+            16 + {
                 let block_len: usize = self.printer_name.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.locale.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateContextRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateContextRequestBuilder {
+    pub inner: CreateContextRequest,
+}
+impl CreateContextRequestBuilder {
+    #[inline]
+    pub fn context_id(mut self, context_id: Card32) -> Self {
+        self.inner.context_id = context_id;
+        self
+    }
+    #[inline]
+    pub fn printer_name(mut self, printer_name: Vec<String8>) -> Self {
+        self.inner.printer_name = printer_name;
+        self
+    }
+    #[inline]
+    pub fn locale(mut self, locale: Vec<String8>) -> Self {
+        self.inner.locale = locale;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateContextRequest {
+        self.inner
+    }
+}
+impl Default for CreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context_id: Default::default(),
+                printer_name: Default::default(),
+                locale: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintSetContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Card32,
 }
-impl PrintSetContextRequest {}
-impl AsByteSequence for PrintSetContextRequest {
+impl PrintSetContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintSetContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintSetContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn builder() -> PrintSetContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context", 4, 4)];
+}
+const _: () = assert!(<PrintSetContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_context_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.context.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (context, sz): (Card32, usize) = parse_item(bytes, index, "context")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        context: context,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_context_only_request_asb! { PrintSetContextRequest }
 impl Request for PrintSetContextRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSetContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintSetContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintSetContextRequestBuilder {
+    pub inner: PrintSetContextRequest,
+}
+impl PrintSetContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Card32) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintSetContextRequest {
+        self.inner
+    }
+}
+impl Default for PrintSetContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintSetContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl PrintGetContextRequest {}
-impl AsByteSequence for PrintGetContextRequest {
+impl PrintGetContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintGetContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintGetContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> PrintGetContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<PrintGetContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { PrintGetContextRequest }
 impl Request for PrintGetContextRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetContext";
     type Reply = PrintGetContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetContextRequestBuilder {
+    pub inner: PrintGetContextRequest,
+}
+impl PrintGetContextRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetContextRequest {
+        self.inner
+    }
+}
+impl Default for PrintGetContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: Card32,
 }
-impl PrintGetContextReply {}
-impl AsByteSequence for PrintGetContextReply {
+impl PrintGetContextReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let context: Card32 = read_item(reader, &mut index, 4, "context")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintGetContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("context", &self.context, &other.context, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for PrintGetContextReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (context, sz): (Card32, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetContextReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -600,133 +1147,207 @@ impl AsByteSequence for PrintGetContextReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + 1 + self.sequence.size() + self.length.size() + self.context.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for PrintGetContextReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintDestroyContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Card32,
 }
-impl PrintDestroyContextRequest {}
-impl AsByteSequence for PrintDestroyContextRequest {
+impl PrintDestroyContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintDestroyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintDestroyContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn builder() -> PrintDestroyContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context", 4, 4)];
 }
+const _: () = assert!(<PrintDestroyContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { PrintDestroyContextRequest }
 impl Request for PrintDestroyContextRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintDestroyContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintDestroyContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintDestroyContextRequestBuilder {
+    pub inner: PrintDestroyContextRequest,
+}
+impl PrintDestroyContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Card32) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintDestroyContextRequest {
+        self.inner
+    }
+}
+impl Default for PrintDestroyContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintDestroyContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetScreenOfContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl PrintGetScreenOfContextRequest {}
-impl AsByteSequence for PrintGetScreenOfContextRequest {
+impl PrintGetScreenOfContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintGetScreenOfContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintGetScreenOfContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> PrintGetScreenOfContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetScreenOfContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<PrintGetScreenOfContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { PrintGetScreenOfContextRequest }
 impl Request for PrintGetScreenOfContextRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetScreenOfContext";
     type Reply = PrintGetScreenOfContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetScreenOfContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetScreenOfContextRequestBuilder {
+    pub inner: PrintGetScreenOfContextRequest,
+}
+impl PrintGetScreenOfContextRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetScreenOfContextRequest {
+        self.inner
+    }
+}
+impl Default for PrintGetScreenOfContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetScreenOfContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetScreenOfContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub root: Window,
 }
-impl PrintGetScreenOfContextReply {}
-impl AsByteSequence for PrintGetScreenOfContextReply {
+impl PrintGetScreenOfContextReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.root == other.root
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
     #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("root", &self.root, &other.root, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for PrintGetScreenOfContextReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.root.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetScreenOfContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetScreenOfContextReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -738,38 +1359,64 @@ impl AsByteSequence for PrintGetScreenOfContextReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + 1 + self.sequence.size() + self.length.size() + self.root.size()
+        let size: usize = 8 + self.root.size();
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for PrintGetScreenOfContextReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintStartJobRequest {
     pub req_type: u8,
     pub length: u16,
     pub output_mode: Card8,
 }
-impl PrintStartJobRequest {}
-impl AsByteSequence for PrintStartJobRequest {
+impl PrintStartJobRequest {
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> PrintStartJobRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("output_mode", 4, 1)];
+}
+const _: () = assert!(<PrintStartJobRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PrintStartJobRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.output_mode.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintStartJobRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (output_mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (output_mode, sz): (Card8, usize) = parse_item(bytes, index, "output_mode")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintStartJobRequest {
                 req_type: req_type,
                 length: length,
@@ -780,92 +1427,209 @@ impl AsByteSequence for PrintStartJobRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.output_mode.size()
+        let size: usize = 5;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintStartJobRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintStartJobRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintStartJob";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct PrintEndJobRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub cancel: bool,
+#[doc = " A builder for `PrintStartJobRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintStartJobRequestBuilder {
+    pub inner: PrintStartJobRequest,
 }
-impl PrintEndJobRequest {}
-impl AsByteSequence for PrintEndJobRequest {
+impl PrintStartJobRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.cancel.as_bytes(&mut bytes[index..]);
-        index
+    pub fn output_mode(mut self, output_mode: Card8) -> Self {
+        self.inner.output_mode = output_mode;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintEndJobRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintEndJobRequest {
-                req_type: req_type,
-                length: length,
-                cancel: cancel,
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintStartJobRequest {
+        self.inner
+    }
+}
+impl Default for PrintStartJobRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintStartJobRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                output_mode: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct PrintEndJobRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub cancel: bool,
+}
+impl PrintEndJobRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.cancel.size()
+    pub fn builder() -> PrintEndJobRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("cancel", 4, 1)];
 }
+const _: () = assert!(<PrintEndJobRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_cancel_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.cancel.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (cancel, sz): (bool, usize) = parse_item(bytes, index, "cancel")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        cancel: cancel,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.cancel.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_cancel_only_request_asb! { PrintEndJobRequest }
 impl Request for PrintEndJobRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintEndJob";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintEndJobRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintEndJobRequestBuilder {
+    pub inner: PrintEndJobRequest,
+}
+impl PrintEndJobRequestBuilder {
+    #[inline]
+    pub fn cancel(mut self, cancel: bool) -> Self {
+        self.inner.cancel = cancel;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintEndJobRequest {
+        self.inner
+    }
+}
+impl Default for PrintEndJobRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintEndJobRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                cancel: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintStartDocRequest {
     pub req_type: u8,
     pub length: u16,
     pub driver_mode: Card8,
 }
-impl PrintStartDocRequest {}
-impl AsByteSequence for PrintStartDocRequest {
+impl PrintStartDocRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
     #[inline]
+    pub fn builder() -> PrintStartDocRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("driver_mode", 4, 1)];
+}
+const _: () = assert!(<PrintStartDocRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PrintStartDocRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.driver_mode.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintStartDocRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (driver_mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (driver_mode, sz): (Card8, usize) = parse_item(bytes, index, "driver_mode")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintStartDocRequest {
                 req_type: req_type,
                 length: length,
@@ -876,64 +1640,115 @@ impl AsByteSequence for PrintStartDocRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.driver_mode.size()
+        let size: usize = 5;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintStartDocRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintStartDocRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintStartDoc";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct PrintEndDocRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub cancel: bool,
+#[doc = " A builder for `PrintStartDocRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintStartDocRequestBuilder {
+    pub inner: PrintStartDocRequest,
 }
-impl PrintEndDocRequest {}
-impl AsByteSequence for PrintEndDocRequest {
+impl PrintStartDocRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.cancel.as_bytes(&mut bytes[index..]);
-        index
+    pub fn driver_mode(mut self, driver_mode: Card8) -> Self {
+        self.inner.driver_mode = driver_mode;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintEndDocRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintEndDocRequest {
-                req_type: req_type,
-                length: length,
-                cancel: cancel,
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintStartDocRequest {
+        self.inner
+    }
+}
+impl Default for PrintStartDocRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintStartDocRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                driver_mode: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct PrintEndDocRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub cancel: bool,
+}
+impl PrintEndDocRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.cancel.size()
+    pub fn builder() -> PrintEndDocRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("cancel", 4, 1)];
 }
+const _: () = assert!(<PrintEndDocRequest as Request>::MINOR_OPCODE < 0x100);
+impl_cancel_only_request_asb! { PrintEndDocRequest }
 impl Request for PrintEndDocRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintEndDoc";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintEndDocRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintEndDocRequestBuilder {
+    pub inner: PrintEndDocRequest,
+}
+impl PrintEndDocRequestBuilder {
+    #[inline]
+    pub fn cancel(mut self, cancel: bool) -> Self {
+        self.inner.cancel = cancel;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintEndDocRequest {
+        self.inner
+    }
+}
+impl Default for PrintEndDocRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintEndDocRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                cancel: Default::default(),
+            },
+        }
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintPutDocumentDataRequest {
     pub req_type: u8,
     pub length: u16,
@@ -942,13 +1757,49 @@ pub struct PrintPutDocumentDataRequest {
     pub doc_format: Vec<String8>,
     pub options: Vec<String8>,
 }
-impl PrintPutDocumentDataRequest {}
-impl AsByteSequence for PrintPutDocumentDataRequest {
+impl PrintPutDocumentDataRequest {
+    pub fn data_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
+        index += sz;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
+        index += sz;
+        let (data, _): (Cow<'_, [Byte]>, usize) = parse_slice(bytes, index, len0 as usize, "data")?;
+        Ok(data)
+    }
     #[inline]
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            drawable: self.drawable.clone(),
+            data: self.data.clone(),
+            doc_format: self.doc_format.clone(),
+            options: self.options.clone(),
+        }
+    }
+    #[inline]
+    pub fn builder() -> PrintPutDocumentDataRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<PrintPutDocumentDataRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PrintPutDocumentDataRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += (self.data.len() as Card32).as_bytes(&mut bytes[index..]);
@@ -956,45 +1807,55 @@ impl AsByteSequence for PrintPutDocumentDataRequest {
         index += (self.options.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+        );
         let block_len: usize = vector_as_bytes(&self.doc_format, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
         let block_len: usize = vector_as_bytes(&self.options, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintPutDocumentDataRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = parse_item(bytes, index, "len2")?;
         index += sz;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "data")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         let (doc_format, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "doc_format")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         let (options, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            parse_vector(bytes, index, len2 as usize, "options")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintPutDocumentDataRequest {
                 req_type: req_type,
                 length: length,
@@ -1006,15 +1867,9 @@ impl AsByteSequence for PrintPutDocumentDataRequest {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
+        let size: usize = 12
             + self.drawable.size()
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card16>()
             + {
                 let block_len: usize = self.data.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
@@ -1029,48 +1884,162 @@ impl AsByteSequence for PrintPutDocumentDataRequest {
                 let block_len: usize = self.options.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
                 block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+    fn size_hint(&self) -> usize {
+        let size: usize = 12
+            + self.drawable.size()
+            + {
+                let block_len: usize = self.data.len() * ::core::mem::size_of::<Byte>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+                block_len + pad
+            }
+            + {
+                let block_len: usize = self.doc_format.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
+                block_len + pad
             }
+            + {
+                let block_len: usize = self.options.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintPutDocumentDataRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintPutDocumentDataRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintPutDocumentData";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for PrintPutDocumentDataRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PrintPutDocumentDataRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("drawable", &self.drawable)
+            .field("data", &debug_bytes(&self.data, 64))
+            .field("doc_format", &self.doc_format)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+#[doc = " A builder for `PrintPutDocumentDataRequest`."]
+#[derive(Debug)]
+pub struct PrintPutDocumentDataRequestBuilder {
+    pub inner: PrintPutDocumentDataRequest,
+}
+impl PrintPutDocumentDataRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn data(mut self, data: Vec<Byte>) -> Self {
+        self.inner.data = data;
+        self
+    }
+    #[inline]
+    pub fn doc_format(mut self, doc_format: Vec<String8>) -> Self {
+        self.inner.doc_format = doc_format;
+        self
+    }
+    #[inline]
+    pub fn options(mut self, options: Vec<String8>) -> Self {
+        self.inner.options = options;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintPutDocumentDataRequest {
+        self.inner
+    }
+}
+impl Default for PrintPutDocumentDataRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintPutDocumentDataRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                data: Default::default(),
+                doc_format: Default::default(),
+                options: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetDocumentDataRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Pcontext,
     pub max_bytes: Card32,
 }
-impl PrintGetDocumentDataRequest {}
+impl PrintGetDocumentDataRequest {
+    #[inline]
+    pub fn builder() -> PrintGetDocumentDataRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetDocumentDataReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("context", 4, 4),
+        ("max_bytes", 8, 4),
+    ];
+}
+const _: () = assert!(<PrintGetDocumentDataRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for PrintGetDocumentDataRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += self.max_bytes.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetDocumentDataRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (max_bytes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (max_bytes, sz): (Card32, usize) = parse_item(bytes, index, "max_bytes")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetDocumentDataRequest {
                 req_type: req_type,
                 length: length,
@@ -1082,16 +2051,63 @@ impl AsByteSequence for PrintGetDocumentDataRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size() + self.max_bytes.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetDocumentDataRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintGetDocumentDataRequest {
-    const OPCODE: u8 = 12;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 12;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetDocumentData";
     type Reply = PrintGetDocumentDataReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetDocumentDataRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetDocumentDataRequestBuilder {
+    pub inner: PrintGetDocumentDataRequest,
+}
+impl PrintGetDocumentDataRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn max_bytes(mut self, max_bytes: Card32) -> Self {
+        self.inner.max_bytes = max_bytes;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetDocumentDataRequest {
+        self.inner
+    }
+}
+impl Default for PrintGetDocumentDataRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetDocumentDataRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                max_bytes: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetDocumentDataReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1100,47 +2116,148 @@ pub struct PrintGetDocumentDataReply {
     pub finished_flag: Card32,
     pub data: Vec<Byte>,
 }
-impl PrintGetDocumentDataReply {}
-impl AsByteSequence for PrintGetDocumentDataReply {
+impl PrintGetDocumentDataReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn data_slice(bytes: &[u8]) -> Result<Cow<'_, [Byte]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (status_code, sz): (Card32, usize) = parse_item(bytes, index, "status_code")?;
+        index += sz;
+        let (finished_flag, sz): (Card32, usize) = parse_item(bytes, index, "finished_flag")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 12;
+        let (data, _): (Cow<'_, [Byte]>, usize) = parse_slice(bytes, index, len0 as usize, "data")?;
+        Ok(data)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let status_code: Card32 = read_item(reader, &mut index, 4, "status_code")?;
+        let finished_flag: Card32 = read_item(reader, &mut index, 4, "finished_flag")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let (data, block_len): (Vec<Byte>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 1, "data")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintGetDocumentDataReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            status_code: status_code,
+            finished_flag: finished_flag,
+            data: data,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("status_code", 8, 4),
+        ("finished_flag", 12, 4),
+        ("data_len", 16, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status_code == other.status_code
+            && self.finished_flag == other.finished_flag
+            && self.data == other.data
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "status_code",
+            &self.status_code,
+            &other.status_code,
+            &mut changes,
+        );
+        diff_value(
+            "finished_flag",
+            &self.finished_flag,
+            &other.finished_flag,
+            &mut changes,
+        );
+        diff_list("data", &self.data, &other.data, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for PrintGetDocumentDataReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.status_code.as_bytes(&mut bytes[index..]);
         index += self.finished_flag.as_bytes(&mut bytes[index..]);
         index += (self.data.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Byte>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetDocumentDataReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (status_code, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (status_code, sz): (Card32, usize) = parse_item(bytes, index, "status_code")?;
         index += sz;
-        let (finished_flag, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (finished_flag, sz): (Card32, usize) = parse_item(bytes, index, "finished_flag")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "data")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetDocumentDataReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1154,50 +2271,83 @@ impl AsByteSequence for PrintGetDocumentDataReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.status_code.size()
-            + self.finished_flag.size()
-            + ::core::mem::size_of::<Card32>()
-            + 12
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 32 + {
+            let block_len: usize = self.data.len() * ::core::mem::size_of::<Byte>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for PrintGetDocumentDataReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for PrintGetDocumentDataReply {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PrintGetDocumentDataReply")
+            .field("reply_type", &self.reply_type)
+            .field("sequence", &self.sequence)
+            .field("length", &self.length)
+            .field("status_code", &self.status_code)
+            .field("finished_flag", &self.finished_flag)
+            .field("data", &debug_bytes(&self.data, 64))
+            .finish()
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintStartPageRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl PrintStartPageRequest {}
-impl AsByteSequence for PrintStartPageRequest {
+impl PrintStartPageRequest {
     #[inline]
+    pub fn builder() -> PrintStartPageRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<PrintStartPageRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PrintStartPageRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintStartPageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintStartPageRequest {
                 req_type: req_type,
                 length: length,
@@ -1208,46 +2358,97 @@ impl AsByteSequence for PrintStartPageRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+        let size: usize = 4 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintStartPageRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintStartPageRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintStartPage";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintStartPageRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintStartPageRequestBuilder {
+    pub inner: PrintStartPageRequest,
+}
+impl PrintStartPageRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintStartPageRequest {
+        self.inner
+    }
+}
+impl Default for PrintStartPageRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintStartPageRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintEndPageRequest {
     pub req_type: u8,
     pub length: u16,
     pub cancel: bool,
 }
-impl PrintEndPageRequest {}
-impl AsByteSequence for PrintEndPageRequest {
+impl PrintEndPageRequest {
     #[inline]
+    pub fn builder() -> PrintEndPageRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("cancel", 4, 1)];
+}
+const _: () = assert!(<PrintEndPageRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PrintEndPageRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.cancel.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintEndPageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (cancel, sz): (bool, usize) = parse_item(bytes, index, "cancel")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintEndPageRequest {
                 req_type: req_type,
                 length: length,
@@ -1258,48 +2459,103 @@ impl AsByteSequence for PrintEndPageRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.cancel.size() + 3
+        let size: usize = 7 + self.cancel.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintEndPageRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintEndPageRequest {
-    const OPCODE: u8 = 14;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 14;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintEndPage";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintEndPageRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintEndPageRequestBuilder {
+    pub inner: PrintEndPageRequest,
+}
+impl PrintEndPageRequestBuilder {
+    #[inline]
+    pub fn cancel(mut self, cancel: bool) -> Self {
+        self.inner.cancel = cancel;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintEndPageRequest {
+        self.inner
+    }
+}
+impl Default for PrintEndPageRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintEndPageRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                cancel: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintSelectInputRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Pcontext,
     pub event_mask: Card32,
 }
-impl PrintSelectInputRequest {}
+impl PrintSelectInputRequest {
+    #[inline]
+    pub fn builder() -> PrintSelectInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("context", 4, 4),
+        ("event_mask", 8, 4),
+    ];
+}
+const _: () = assert!(<PrintSelectInputRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for PrintSelectInputRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (Card32, usize) = parse_item(bytes, index, "event_mask")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintSelectInputRequest {
                 req_type: req_type,
                 length: length,
@@ -1311,64 +2567,179 @@ impl AsByteSequence for PrintSelectInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size() + self.event_mask.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintSelectInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintSelectInputRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSelectInput";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct PrintInputSelectedRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub context: Pcontext,
+#[doc = " A builder for `PrintSelectInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintSelectInputRequestBuilder {
+    pub inner: PrintSelectInputRequest,
 }
-impl PrintInputSelectedRequest {}
-impl AsByteSequence for PrintInputSelectedRequest {
+impl PrintSelectInputRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintInputSelectedRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintInputSelectedRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
+    pub fn event_mask(mut self, event_mask: Card32) -> Self {
+        self.inner.event_mask = event_mask;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintSelectInputRequest {
+        self.inner
+    }
+}
+impl Default for PrintSelectInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintSelectInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                event_mask: Default::default(),
             },
-            index,
-        ))
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct PrintInputSelectedRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub context: Pcontext,
+}
+impl PrintInputSelectedRequest {
+    #[inline]
+    pub fn builder() -> PrintInputSelectedRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintInputSelectedReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context", 4, 4)];
+}
+const _: () = assert!(<PrintInputSelectedRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_request_asb_d4d061a0 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.context.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        context: context,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_request_asb_d4d061a0! { PrintInputSelectedRequest }
 impl Request for PrintInputSelectedRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintInputSelected";
     type Reply = PrintInputSelectedReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintInputSelectedRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintInputSelectedRequestBuilder {
+    pub inner: PrintInputSelectedRequest,
+}
+impl PrintInputSelectedRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintInputSelectedRequest {
+        self.inner
+    }
+}
+impl Default for PrintInputSelectedRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintInputSelectedRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintInputSelectedReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1376,35 +2747,105 @@ pub struct PrintInputSelectedReply {
     pub event_mask: Card32,
     pub all_events_mask: Card32,
 }
-impl PrintInputSelectedReply {}
-impl AsByteSequence for PrintInputSelectedReply {
+impl PrintInputSelectedReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let event_mask: Card32 = read_item(reader, &mut index, 4, "event_mask")?;
+        let all_events_mask: Card32 = read_item(reader, &mut index, 4, "all_events_mask")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintInputSelectedReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            event_mask: event_mask,
+            all_events_mask: all_events_mask,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("event_mask", 8, 4),
+        ("all_events_mask", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.event_mask == other.event_mask && self.all_events_mask == other.all_events_mask
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "event_mask",
+            &self.event_mask,
+            &other.event_mask,
+            &mut changes,
+        );
+        diff_value(
+            "all_events_mask",
+            &self.all_events_mask,
+            &other.all_events_mask,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for PrintInputSelectedReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.event_mask.as_bytes(&mut bytes[index..]);
         index += self.all_events_mask.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintInputSelectedReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (event_mask, sz): (Card32, usize) = parse_item(bytes, index, "event_mask")?;
         index += sz;
-        let (all_events_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (all_events_mask, sz): (Card32, usize) = parse_item(bytes, index, "all_events_mask")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintInputSelectedReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1417,49 +2858,76 @@ impl AsByteSequence for PrintInputSelectedReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.event_mask.size()
-            + self.all_events_mask.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintInputSelectedReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetAttributesRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Pcontext,
     pub pool: Card8,
 }
-impl PrintGetAttributesRequest {}
+impl PrintGetAttributesRequest {
+    #[inline]
+    pub fn builder() -> PrintGetAttributesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetAttributesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("context", 4, 4),
+        ("pool", 8, 1),
+    ];
+}
+const _: () = assert!(<PrintGetAttributesRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for PrintGetAttributesRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += self.pool.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pool, sz): (Card8, usize) = parse_item(bytes, index, "pool")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetAttributesRequest {
                 req_type: req_type,
                 length: length,
@@ -1471,57 +2939,170 @@ impl AsByteSequence for PrintGetAttributesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size() + self.pool.size() + 3
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetAttributesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintGetAttributesRequest {
-    const OPCODE: u8 = 17;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 17;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetAttributes";
     type Reply = PrintGetAttributesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetAttributesRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetAttributesRequestBuilder {
+    pub inner: PrintGetAttributesRequest,
+}
+impl PrintGetAttributesRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn pool(mut self, pool: Card8) -> Self {
+        self.inner.pool = pool;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetAttributesRequest {
+        self.inner
+    }
+}
+impl Default for PrintGetAttributesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetAttributesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                pool: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetAttributesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub attributes: Vec<String8>,
 }
-impl PrintGetAttributesReply {}
-impl AsByteSequence for PrintGetAttributesReply {
+impl PrintGetAttributesReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (attributes, block_len): (Vec<String8>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 1, "attributes")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintGetAttributesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            attributes: attributes,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("attributes_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.attributes == other.attributes
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "attributes",
+            &self.attributes,
+            &other.attributes,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for PrintGetAttributesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.attributes.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.attributes, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (attributes, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "attributes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetAttributesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1533,20 +3114,25 @@ impl AsByteSequence for PrintGetAttributesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.attributes.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.attributes.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetAttributesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetOneAttributesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1554,44 +3140,69 @@ pub struct PrintGetOneAttributesRequest {
     pub pool: Card8,
     pub name: Vec<String8>,
 }
-impl PrintGetOneAttributesRequest {}
+impl PrintGetOneAttributesRequest {
+    #[inline]
+    pub fn builder() -> PrintGetOneAttributesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetOneAttributesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("context", 4, 4),
+        ("name_len", 8, 4),
+        ("pool", 12, 1),
+    ];
+}
+const _: () = assert!(<PrintGetOneAttributesRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for PrintGetOneAttributesRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card32).as_bytes(&mut bytes[index..]);
         index += self.pool.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         let block_len: usize = vector_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetOneAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pool, sz): (Card8, usize) = parse_item(bytes, index, "pool")?;
         index += sz;
         index += 3;
         let (name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetOneAttributesRequest {
                 req_type: req_type,
                 length: length,
@@ -1604,68 +3215,189 @@ impl AsByteSequence for PrintGetOneAttributesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context.size()
-            + ::core::mem::size_of::<Card32>()
-            + self.pool.size()
-            + 3
-            + {
-                let block_len: usize = self.name.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
-                block_len + pad
-            }
+        let size: usize = 16 + {
+            let block_len: usize = self.name.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetOneAttributesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintGetOneAttributesRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetOneAttributes";
     type Reply = PrintGetOneAttributesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetOneAttributesRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetOneAttributesRequestBuilder {
+    pub inner: PrintGetOneAttributesRequest,
+}
+impl PrintGetOneAttributesRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn pool(mut self, pool: Card8) -> Self {
+        self.inner.pool = pool;
+        self
+    }
+    #[inline]
+    pub fn name(mut self, name: Vec<String8>) -> Self {
+        self.inner.name = name;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetOneAttributesRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<String8> for PrintGetOneAttributesRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = String8>>(&mut self, iter: I) {
+        self.inner.name.extend(iter);
+    }
+}
+impl core::iter::FromIterator<String8> for PrintGetOneAttributesRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = String8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for PrintGetOneAttributesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetOneAttributesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                pool: Default::default(),
+                name: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetOneAttributesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub value: Vec<String8>,
 }
-impl PrintGetOneAttributesReply {}
-impl AsByteSequence for PrintGetOneAttributesReply {
+impl PrintGetOneAttributesReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (value, block_len): (Vec<String8>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 1, "value")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintGetOneAttributesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            value: value,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("value_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("value", &self.value, &other.value, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for PrintGetOneAttributesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.value.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.value, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetOneAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (value, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "value")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetOneAttributesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1677,20 +3409,25 @@ impl AsByteSequence for PrintGetOneAttributesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.value.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.value.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetOneAttributesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintSetAttributesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1700,47 +3437,72 @@ pub struct PrintSetAttributesRequest {
     pub rule: Card8,
     pub attributes: Vec<String8>,
 }
-impl PrintSetAttributesRequest {}
+impl PrintSetAttributesRequest {
+    #[inline]
+    pub fn builder() -> PrintSetAttributesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("context", 4, 4),
+        ("string_len", 8, 4),
+        ("pool", 12, 1),
+        ("rule", 13, 1),
+    ];
+}
+const _: () = assert!(<PrintSetAttributesRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for PrintSetAttributesRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += self.string_len.as_bytes(&mut bytes[index..]);
         index += self.pool.as_bytes(&mut bytes[index..]);
         index += self.rule.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         let block_len: usize = vector_as_bytes(&self.attributes, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<String8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (string_len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (string_len, sz): (Card32, usize) = parse_item(bytes, index, "string_len")?;
         index += sz;
-        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pool, sz): (Card8, usize) = parse_item(bytes, index, "pool")?;
         index += sz;
-        let (rule, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (rule, sz): (Card8, usize) = parse_item(bytes, index, "rule")?;
         index += sz;
         index += 2;
-        let (attributes, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (attributes, block_len): (Vec<String8>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "attributes",
+                offset: index,
+            })? as usize,
+            "attributes",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintSetAttributesRequest {
                 req_type: req_type,
                 length: length,
@@ -1755,76 +3517,164 @@ impl AsByteSequence for PrintSetAttributesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context.size()
-            + self.string_len.size()
-            + self.pool.size()
-            + self.rule.size()
-            + 2
-            + {
-                let block_len: usize = self.attributes.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
-                block_len + pad
-            }
+        let size: usize = 16 + {
+            let block_len: usize = self.attributes.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<String8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintSetAttributesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintSetAttributesRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSetAttributes";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintSetAttributesRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintSetAttributesRequestBuilder {
+    pub inner: PrintSetAttributesRequest,
+}
+impl PrintSetAttributesRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn string_len(mut self, string_len: Card32) -> Self {
+        self.inner.string_len = string_len;
+        self
+    }
+    #[inline]
+    pub fn pool(mut self, pool: Card8) -> Self {
+        self.inner.pool = pool;
+        self
+    }
+    #[inline]
+    pub fn rule(mut self, rule: Card8) -> Self {
+        self.inner.rule = rule;
+        self
+    }
+    #[inline]
+    pub fn attributes(mut self, attributes: Vec<String8>) -> Self {
+        self.inner.attributes = attributes;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintSetAttributesRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<String8> for PrintSetAttributesRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = String8>>(&mut self, iter: I) {
+        self.inner.attributes.extend(iter);
+    }
+}
+impl core::iter::FromIterator<String8> for PrintSetAttributesRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = String8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for PrintSetAttributesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintSetAttributesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                string_len: Default::default(),
+                pool: Default::default(),
+                rule: Default::default(),
+                attributes: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetPageDimensionsRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Pcontext,
 }
-impl PrintGetPageDimensionsRequest {}
-impl AsByteSequence for PrintGetPageDimensionsRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl PrintGetPageDimensionsRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintGetPageDimensionsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintGetPageDimensionsRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    pub fn builder() -> PrintGetPageDimensionsRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetPageDimensionsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context", 4, 4)];
 }
+const _: () = assert!(<PrintGetPageDimensionsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_d4d061a0! { PrintGetPageDimensionsRequest }
 impl Request for PrintGetPageDimensionsRequest {
-    const OPCODE: u8 = 21;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 21;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetPageDimensions";
     type Reply = PrintGetPageDimensionsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetPageDimensionsRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetPageDimensionsRequestBuilder {
+    pub inner: PrintGetPageDimensionsRequest,
+}
+impl PrintGetPageDimensionsRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetPageDimensionsRequest {
+        self.inner
+    }
+}
+impl Default for PrintGetPageDimensionsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetPageDimensionsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetPageDimensionsReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1836,13 +3686,102 @@ pub struct PrintGetPageDimensionsReply {
     pub reproducible_width: Card16,
     pub reproducible_height: Card16,
 }
-impl PrintGetPageDimensionsReply {}
-impl AsByteSequence for PrintGetPageDimensionsReply {
+impl PrintGetPageDimensionsReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 20;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let width: Card16 = read_item(reader, &mut index, 2, "width")?;
+        let height: Card16 = read_item(reader, &mut index, 2, "height")?;
+        let offset_x: Card16 = read_item(reader, &mut index, 2, "offset_x")?;
+        let offset_y: Card16 = read_item(reader, &mut index, 2, "offset_y")?;
+        let reproducible_width: Card16 = read_item(reader, &mut index, 2, "reproducible_width")?;
+        let reproducible_height: Card16 = read_item(reader, &mut index, 2, "reproducible_height")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintGetPageDimensionsReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            width: width,
+            height: height,
+            offset_x: offset_x,
+            offset_y: offset_y,
+            reproducible_width: reproducible_width,
+            reproducible_height: reproducible_height,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("width", 8, 2),
+        ("height", 10, 2),
+        ("offset_x", 12, 2),
+        ("offset_y", 14, 2),
+        ("reproducible_width", 16, 2),
+        ("reproducible_height", 18, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.offset_x == other.offset_x
+            && self.offset_y == other.offset_y
+            && self.reproducible_width == other.reproducible_width
+            && self.reproducible_height == other.reproducible_height
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_value("offset_x", &self.offset_x, &other.offset_x, &mut changes);
+        diff_value("offset_y", &self.offset_y, &other.offset_y, &mut changes);
+        diff_value(
+            "reproducible_width",
+            &self.reproducible_width,
+            &other.reproducible_width,
+            &mut changes,
+        );
+        diff_value(
+            "reproducible_height",
+            &self.reproducible_height,
+            &other.reproducible_height,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for PrintGetPageDimensionsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
@@ -1851,32 +3790,36 @@ impl AsByteSequence for PrintGetPageDimensionsReply {
         index += self.offset_y.as_bytes(&mut bytes[index..]);
         index += self.reproducible_width.as_bytes(&mut bytes[index..]);
         index += self.reproducible_height.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetPageDimensionsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (offset_x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (offset_x, sz): (Card16, usize) = parse_item(bytes, index, "offset_x")?;
         index += sz;
-        let (offset_y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (offset_y, sz): (Card16, usize) = parse_item(bytes, index, "offset_y")?;
         index += sz;
-        let (reproducible_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (reproducible_width, sz): (Card16, usize) =
+            parse_item(bytes, index, "reproducible_width")?;
         index += sz;
-        let (reproducible_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (reproducible_height, sz): (Card16, usize) =
+            parse_item(bytes, index, "reproducible_height")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetPageDimensionsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1893,103 +3836,160 @@ impl AsByteSequence for PrintGetPageDimensionsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.width.size()
-            + self.height.size()
-            + self.offset_x.size()
-            + self.offset_y.size()
-            + self.reproducible_width.size()
-            + self.reproducible_height.size()
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let size: usize = 20;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetPageDimensionsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintQueryScreensRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl PrintQueryScreensRequest {}
-impl AsByteSequence for PrintQueryScreensRequest {
+impl PrintQueryScreensRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintQueryScreensRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintQueryScreensRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> PrintQueryScreensRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintQueryScreensReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<PrintQueryScreensRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { PrintQueryScreensRequest }
 impl Request for PrintQueryScreensRequest {
-    const OPCODE: u8 = 22;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 22;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintQueryScreens";
     type Reply = PrintQueryScreensReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintQueryScreensRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintQueryScreensRequestBuilder {
+    pub inner: PrintQueryScreensRequest,
+}
+impl PrintQueryScreensRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintQueryScreensRequest {
+        self.inner
+    }
+}
+impl Default for PrintQueryScreensRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintQueryScreensRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintQueryScreensReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub roots: Vec<Window>,
 }
-impl PrintQueryScreensReply {}
+impl PrintQueryScreensReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("roots_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.roots == other.roots
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("roots", &self.roots, &other.roots, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for PrintQueryScreensReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.roots.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.roots, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Window>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Window>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintQueryScreensReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (roots, block_len): (Vec<Window>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "roots")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Window>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintQueryScreensReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2001,52 +4001,78 @@ impl AsByteSequence for PrintQueryScreensReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.roots.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Window>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.roots.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Window>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintQueryScreensReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintSetImageResolutionRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Pcontext,
     pub image_resolution: Card16,
 }
-impl PrintSetImageResolutionRequest {}
+impl PrintSetImageResolutionRequest {
+    #[inline]
+    pub fn builder() -> PrintSetImageResolutionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintSetImageResolutionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("context", 4, 4),
+        ("image_resolution", 8, 2),
+    ];
+}
+const _: () = assert!(<PrintSetImageResolutionRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for PrintSetImageResolutionRequest {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += self.image_resolution.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSetImageResolutionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (image_resolution, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (image_resolution, sz): (Card16, usize) = parse_item(bytes, index, "image_resolution")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintSetImageResolutionRequest {
                 req_type: req_type,
                 length: length,
@@ -2058,20 +4084,63 @@ impl AsByteSequence for PrintSetImageResolutionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context.size()
-            + self.image_resolution.size()
+        let size: usize = 10;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintSetImageResolutionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PrintSetImageResolutionRequest {
-    const OPCODE: u8 = 23;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 23;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSetImageResolution";
     type Reply = PrintSetImageResolutionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintSetImageResolutionRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintSetImageResolutionRequestBuilder {
+    pub inner: PrintSetImageResolutionRequest,
+}
+impl PrintSetImageResolutionRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn image_resolution(mut self, image_resolution: Card16) -> Self {
+        self.inner.image_resolution = image_resolution;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintSetImageResolutionRequest {
+        self.inner
+    }
+}
+impl Default for PrintSetImageResolutionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintSetImageResolutionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+                image_resolution: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintSetImageResolutionReply {
     pub reply_type: u8,
     pub status: bool,
@@ -2079,9 +4148,47 @@ pub struct PrintSetImageResolutionReply {
     pub length: u32,
     pub previous_resolutions: Card16,
 }
-impl PrintSetImageResolutionReply {}
+impl PrintSetImageResolutionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("status", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("previous_resolutions", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.status == other.status && self.previous_resolutions == other.previous_resolutions
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("status", &self.status, &other.status, &mut changes);
+        diff_value(
+            "previous_resolutions",
+            &self.previous_resolutions,
+            &other.previous_resolutions,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for PrintSetImageResolutionReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -2089,23 +4196,26 @@ impl AsByteSequence for PrintSetImageResolutionReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.previous_resolutions.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSetImageResolutionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (status, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (status, sz): (bool, usize) = parse_item(bytes, index, "status")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (previous_resolutions, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (previous_resolutions, sz): (Card16, usize) =
+            parse_item(bytes, index, "previous_resolutions")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintSetImageResolutionReply {
                 reply_type: reply_type,
                 status: status,
@@ -2118,94 +4228,178 @@ impl AsByteSequence for PrintSetImageResolutionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.status.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.previous_resolutions.size()
+        let size: usize = 9 + self.status.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintSetImageResolutionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PrintGetImageResolutionRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: Pcontext,
 }
-impl PrintGetImageResolutionRequest {}
-impl AsByteSequence for PrintGetImageResolutionRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.context.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl PrintGetImageResolutionRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PrintGetImageResolutionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PrintGetImageResolutionRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    pub fn builder() -> PrintGetImageResolutionRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<PrintGetImageResolutionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context", 4, 4)];
 }
+const _: () = assert!(<PrintGetImageResolutionRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_d4d061a0! { PrintGetImageResolutionRequest }
 impl Request for PrintGetImageResolutionRequest {
-    const OPCODE: u8 = 24;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
+    const MINOR_OPCODE: u16 = 24;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetImageResolution";
     type Reply = PrintGetImageResolutionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PrintGetImageResolutionRequest`."]
+#[derive(Clone, Debug)]
+pub struct PrintGetImageResolutionRequestBuilder {
+    pub inner: PrintGetImageResolutionRequest,
+}
+impl PrintGetImageResolutionRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: Pcontext) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PrintGetImageResolutionRequest {
+        self.inner
+    }
+}
+impl Default for PrintGetImageResolutionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PrintGetImageResolutionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct PrintGetImageResolutionReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub image_resolution: Card16,
 }
-impl PrintGetImageResolutionReply {}
-impl AsByteSequence for PrintGetImageResolutionReply {
+impl PrintGetImageResolutionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let image_resolution: Card16 = read_item(reader, &mut index, 2, "image_resolution")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(PrintGetImageResolutionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            image_resolution: image_resolution,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("image_resolution", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.image_resolution == other.image_resolution
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "image_resolution",
+            &self.image_resolution,
+            &other.image_resolution,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for PrintGetImageResolutionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.image_resolution.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetImageResolutionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (image_resolution, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (image_resolution, sz): (Card16, usize) = parse_item(bytes, index, "image_resolution")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PrintGetImageResolutionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2217,15 +4411,92 @@ impl AsByteSequence for PrintGetImageResolutionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.image_resolution.size()
+        let size: usize = 10;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PrintGetImageResolutionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Attr {
+    JobAttr = 1,
+    DocAttr = 2,
+    PageAttr = 3,
+    PrinterAttr = 4,
+    ServerAttr = 5,
+    MediumAttr = 6,
+    SpoolerAttr = 7,
+}
+impl AsByteSequence for Attr {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        (*self as i32).as_bytes(bytes)
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
+        match underlying {
+            1 => Ok((Self::JobAttr, sz)),
+            2 => Ok((Self::DocAttr, sz)),
+            3 => Ok((Self::PageAttr, sz)),
+            4 => Ok((Self::PrinterAttr, sz)),
+            5 => Ok((Self::ServerAttr, sz)),
+            6 => Ok((Self::MediumAttr, sz)),
+            7 => Ok((Self::SpoolerAttr, sz)),
+            _ => Err(unreadable::<Self>()),
+        }
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        ::core::mem::size_of::<i32>()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Attr {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Default for Attr {
+    #[inline]
+    fn default() -> Attr {
+        Attr::JobAttr
+    }
+}
+impl From<Attr> for i32 {
+    #[inline]
+    fn from(base: Attr) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for Attr {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            1 => Ok(Self::JobAttr),
+            2 => Ok(Self::DocAttr),
+            3 => Ok(Self::PageAttr),
+            4 => Ok(Self::PrinterAttr),
+            5 => Ok(Self::ServerAttr),
+            6 => Ok(Self::MediumAttr),
+            7 => Ok(Self::SpoolerAttr),
+            _ => Err(value),
+        }
     }
 }
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Detail {
     StartJobNotify = 1,
     EndJobNotify = 2,
@@ -2239,17 +4510,16 @@ impl AsByteSequence for Detail {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as i32).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            1 => Some((Self::StartJobNotify, sz)),
-            2 => Some((Self::EndJobNotify, sz)),
-            3 => Some((Self::StartDocNotify, sz)),
-            4 => Some((Self::EndDocNotify, sz)),
-            5 => Some((Self::StartPageNotify, sz)),
-            6 => Some((Self::EndPageNotify, sz)),
-            _ => None,
+            1 => Ok((Self::StartJobNotify, sz)),
+            2 => Ok((Self::EndJobNotify, sz)),
+            3 => Ok((Self::StartDocNotify, sz)),
+            4 => Ok((Self::EndDocNotify, sz)),
+            5 => Ok((Self::StartPageNotify, sz)),
+            6 => Ok((Self::EndPageNotify, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -2257,14 +4527,44 @@ impl AsByteSequence for Detail {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Detail {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Detail {
     #[inline]
     fn default() -> Detail {
         Detail::StartJobNotify
     }
 }
+impl From<Detail> for i32 {
+    #[inline]
+    fn from(base: Detail) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for Detail {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            1 => Ok(Self::StartJobNotify),
+            2 => Ok(Self::EndJobNotify),
+            3 => Ok(Self::StartDocNotify),
+            4 => Ok(Self::EndDocNotify),
+            5 => Ok(Self::StartPageNotify),
+            6 => Ok(Self::EndPageNotify),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct EvMask {
     pub inner: i32,
 }
@@ -2314,19 +4614,39 @@ impl EvMask {
     pub const ATTRIBUTE_MASK: Self = Self { inner: 2 };
     pub const COMPLETE: Self = Self { inner: 3 };
 }
+impl core::ops::Deref for EvMask {
+    type Target = i32;
+    #[inline]
+    fn deref(&self) -> &i32 {
+        &self.inner
+    }
+}
+impl AsRef<i32> for EvMask {
+    #[inline]
+    fn as_ref(&self) -> &i32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for EvMask {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        Some((EvMask { inner: inner }, sz))
+        Ok((EvMask { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for EvMask {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for EvMask {
@@ -2364,48 +4684,9 @@ impl core::ops::BitXor for EvMask {
     }
 }
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Attr {
-    JobAttr = 1,
-    DocAttr = 2,
-    PageAttr = 3,
-    PrinterAttr = 4,
-    ServerAttr = 5,
-    MediumAttr = 6,
-    SpoolerAttr = 7,
-}
-impl AsByteSequence for Attr {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        (*self as i32).as_bytes(bytes)
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
-        match underlying {
-            1 => Some((Self::JobAttr, sz)),
-            2 => Some((Self::DocAttr, sz)),
-            3 => Some((Self::PageAttr, sz)),
-            4 => Some((Self::PrinterAttr, sz)),
-            5 => Some((Self::ServerAttr, sz)),
-            6 => Some((Self::MediumAttr, sz)),
-            7 => Some((Self::SpoolerAttr, sz)),
-            _ => None,
-        }
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        ::core::mem::size_of::<i32>()
-    }
-}
-impl Default for Attr {
-    #[inline]
-    fn default() -> Attr {
-        Attr::JobAttr
-    }
-}
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum GetDoc {
     Finished = 0,
     SecondConsumer = 1,
@@ -2416,12 +4697,12 @@ impl AsByteSequence for GetDoc {
         (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Finished, sz)),
-            1 => Some((Self::SecondConsumer, sz)),
-            _ => None,
+            0 => Ok((Self::Finished, sz)),
+            1 => Ok((Self::SecondConsumer, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -2429,22 +4710,66 @@ impl AsByteSequence for GetDoc {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for GetDoc {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for GetDoc {
     #[inline]
     fn default() -> GetDoc {
         GetDoc::Finished
     }
 }
-#[derive(Clone, Debug, Default)]
-pub struct NotifyEvent {
+impl From<GetDoc> for i32 {
+    #[inline]
+    fn from(base: GetDoc) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for GetDoc {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::Finished),
+            1 => Ok(Self::SecondConsumer),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct AttributNotifyEvent {
     pub event_type: u8,
     pub detail: Card8,
     pub sequence: u16,
     pub context: Pcontext,
-    pub cancel: bool,
 }
-impl NotifyEvent {}
-impl AsByteSequence for NotifyEvent {
+impl AttributNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 1;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("detail", 1, 1),
+        ("sequence", 2, 2),
+        ("context", 4, 4),
+    ];
+}
+impl AsByteSequence for AttributNotifyEvent {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
@@ -2452,55 +4777,76 @@ impl AsByteSequence for NotifyEvent {
         index += self.detail.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
-        index += self.cancel.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (detail, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing AttributNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card8, usize) = parse_item(bytes, index, "detail")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        Some((
-            NotifyEvent {
+        Ok((
+            AttributNotifyEvent {
                 event_type: event_type,
                 detail: detail,
                 sequence: sequence,
                 context: context,
-                cancel: cancel,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.detail.size()
-            + self.sequence.size()
-            + self.context.size()
-            + self.cancel.size()
+        8
     }
 }
-impl crate::auto::Event for NotifyEvent {
-    const OPCODE: u8 = 0;
+impl core::convert::TryFrom<&[u8]> for AttributNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct AttributNotifyEvent {
+impl crate::auto::Event for AttributNotifyEvent {
+    const OPCODE: u8 = 1;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct NotifyEvent {
     pub event_type: u8,
     pub detail: Card8,
     pub sequence: u16,
     pub context: Pcontext,
+    pub cancel: bool,
 }
-impl AttributNotifyEvent {}
-impl AsByteSequence for AttributNotifyEvent {
+impl NotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("event_type", 0, 1),
+        ("detail", 1, 1),
+        ("sequence", 2, 2),
+        ("context", 4, 4),
+        ("cancel", 8, 1),
+    ];
+}
+impl AsByteSequence for NotifyEvent {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
@@ -2508,35 +4854,141 @@ impl AsByteSequence for AttributNotifyEvent {
         index += self.detail.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
+        index += self.cancel.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing AttributNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing NotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (detail, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card8, usize) = parse_item(bytes, index, "detail")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        Some((
-            AttributNotifyEvent {
+        let (cancel, sz): (bool, usize) = parse_item(bytes, index, "cancel")?;
+        index += sz;
+        Ok((
+            NotifyEvent {
                 event_type: event_type,
                 detail: detail,
                 sequence: sequence,
                 context: context,
+                cancel: cancel,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size() + self.detail.size() + self.sequence.size() + self.context.size()
+        8 + self.cancel.size()
     }
 }
-impl crate::auto::Event for AttributNotifyEvent {
-    const OPCODE: u8 = 1;
+impl core::convert::TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl crate::auto::Event for NotifyEvent {
+    const OPCODE: u8 = 0;
+}
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    AttributNotify(AttributNotifyEvent),
+    Notify(NotifyEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            1 => Ok(Self::AttributNotify(
+                AttributNotifyEvent::from_bytes(bytes)?.0,
+            )),
+            0 => Ok(Self::Notify(NotifyEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::AttributNotify(_) => AttributNotifyEvent::RESPONSE_TYPE,
+            Self::Notify(_) => NotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<AttributNotifyEvent> for Event {
+    #[inline]
+    fn from(event: AttributNotifyEvent) -> Self {
+        Self::AttributNotify(event)
+    }
+}
+impl From<NotifyEvent> for Event {
+    #[inline]
+    fn from(event: NotifyEvent) -> Self {
+        Self::Notify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "Notify"), (1, "AttributNotify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "PrintQueryVersion"),
+    (1, "PrintGetPrinterList"),
+    (2, "CreateContext"),
+    (3, "PrintSetContext"),
+    (4, "PrintGetContext"),
+    (5, "PrintDestroyContext"),
+    (6, "PrintGetScreenOfContext"),
+    (7, "PrintStartJob"),
+    (8, "PrintEndJob"),
+    (9, "PrintStartDoc"),
+    (10, "PrintEndDoc"),
+    (11, "PrintPutDocumentData"),
+    (12, "PrintGetDocumentData"),
+    (13, "PrintStartPage"),
+    (14, "PrintEndPage"),
+    (15, "PrintSelectInput"),
+    (16, "PrintInputSelected"),
+    (17, "PrintGetAttributes"),
+    (18, "PrintSetAttributes"),
+    (19, "PrintGetOneAttributes"),
+    (20, "PrintRehashPrinterList"),
+    (21, "PrintGetPageDimensions"),
+    (22, "PrintQueryScreens"),
+    (23, "PrintSetImageResolution"),
+    (24, "PrintGetImageResolution"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
 }
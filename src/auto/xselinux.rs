@@ -1,44 +1,77 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "selinux")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 0;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub client_major: Card8,
     pub client_minor: Card8,
 }
-impl QueryVersionRequest {}
-impl AsByteSequence for QueryVersionRequest {
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("client_major", 4, 1),
+        ("client_minor", 5, 1),
+    ];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.client_major.as_bytes(&mut bytes[index..]);
         index += self.client_minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (client_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_major, sz): (Card8, usize) = parse_item(bytes, index, "client_major")?;
         index += sz;
-        let (client_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_minor, sz): (Card8, usize) = parse_item(bytes, index, "client_minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -50,20 +83,63 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.client_major.size()
-            + self.client_minor.size()
+        let size: usize = 6;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    pub fn client_major(mut self, client_major: Card8) -> Self {
+        self.inner.client_major = client_major;
+        self
+    }
+    #[inline]
+    pub fn client_minor(mut self, client_minor: Card8) -> Self {
+        self.inner.client_minor = client_minor;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                client_major: Default::default(),
+                client_minor: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -71,35 +147,105 @@ pub struct QueryVersionReply {
     pub server_major: Card16,
     pub server_minor: Card16,
 }
-impl QueryVersionReply {}
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let server_major: Card16 = read_item(reader, &mut index, 2, "server_major")?;
+        let server_minor: Card16 = read_item(reader, &mut index, 2, "server_minor")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            server_major: server_major,
+            server_minor: server_minor,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("server_major", 8, 2),
+        ("server_minor", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.server_major == other.server_major && self.server_minor == other.server_minor
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "server_major",
+            &self.server_major,
+            &other.server_major,
+            &mut changes,
+        );
+        diff_value(
+            "server_minor",
+            &self.server_minor,
+            &other.server_minor,
+            &mut changes,
+        );
+        changes
+    }
+}
 impl AsByteSequence for QueryVersionReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.server_major.as_bytes(&mut bytes[index..]);
         index += self.server_minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (server_major, sz): (Card16, usize) = parse_item(bytes, index, "server_major")?;
         index += sz;
-        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor, sz): (Card16, usize) = parse_item(bytes, index, "server_minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -112,222 +258,474 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.server_major.size()
-            + self.server_minor.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetDeviceCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: String,
 }
-impl SetDeviceCreateContextRequest {}
-impl AsByteSequence for SetDeviceCreateContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
+impl SetDeviceCreateContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SetDeviceCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            SetDeviceCreateContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(context: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            context: String::from(context),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
-            let block_len: usize = self.context.len();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-            block_len + pad
+    pub fn builder() -> SetDeviceCreateContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_len", 4, 4)];
+}
+const _: () = assert!(<SetDeviceCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_context_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
+                let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+                index += sz;
+                let (context, block_len): (String, usize) =
+                    parse_string(bytes, index, len0 as usize, "context")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        context: context,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8 + {
+                    let block_len: usize = self.context.len();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
         }
-    }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_context_only_request_asb! { SetDeviceCreateContextRequest }
 impl Request for SetDeviceCreateContextRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceCreateContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetDeviceCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetDeviceCreateContextRequestBuilder {
+    pub inner: SetDeviceCreateContextRequest,
+}
+impl SetDeviceCreateContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: String) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetDeviceCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for SetDeviceCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetDeviceCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetDeviceCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetDeviceCreateContextRequest {}
-impl AsByteSequence for GetDeviceCreateContextRequest {
+impl GetDeviceCreateContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetDeviceCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetDeviceCreateContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetDeviceCreateContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetDeviceCreateContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetDeviceCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_empty_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            #[inline]
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_empty_request_asb! { GetDeviceCreateContextRequest }
 impl Request for GetDeviceCreateContextRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceCreateContext";
     type Reply = GetDeviceCreateContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetDeviceCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetDeviceCreateContextRequestBuilder {
+    pub inner: GetDeviceCreateContextRequest,
+}
+impl GetDeviceCreateContextRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetDeviceCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for GetDeviceCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetDeviceCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetDeviceCreateContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetDeviceCreateContextReply {}
-impl AsByteSequence for GetDeviceCreateContextReply {
+impl GetDeviceCreateContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetDeviceCreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetDeviceCreateContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetDeviceCreateContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+macro_rules! impl_context_only_reply_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.reply_type.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 1);
+                index += self.sequence.as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], 20);
+                let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
+                index += block_len;
+                index += zero_pad(
+                    &mut bytes[index..],
+                    buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+                );
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
             }
-    }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+                index += sz;
+                index += 1;
+                let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+                index += sz;
+                let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let bytes: &[u8] = reply_bytes(bytes, length);
+                let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+                index += sz;
+                index += 20;
+                let (context, block_len): (String, usize) =
+                    parse_string(bytes, index, len0 as usize, "context")?;
+                index += block_len;
+                index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        reply_type: reply_type,
+                        sequence: sequence,
+                        length: length,
+                        context: context,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 32 + {
+                    let block_len: usize = self.context.len();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
-#[derive(Clone, Debug, Default)]
+impl_context_only_reply_asb! { GetDeviceCreateContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetDeviceContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub device: Card32,
     pub context: String,
 }
-impl SetDeviceContextRequest {}
-impl AsByteSequence for SetDeviceContextRequest {
+impl SetDeviceContextRequest {
     #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(device: Card32, context: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            device: device,
+            context: String::from(context),
+        }
+    }
+    #[inline]
+    pub fn builder() -> SetDeviceContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("device", 4, 4),
+        ("context_len", 8, 4),
+    ];
+}
+const _: () = assert!(<SetDeviceContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetDeviceContextRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device.as_bytes(&mut bytes[index..]);
         index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card32, usize) = parse_item(bytes, index, "device")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "context")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetDeviceContextRequest {
                 req_type: req_type,
                 length: length,
@@ -339,53 +737,119 @@ impl AsByteSequence for SetDeviceContextRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device.size()
-            + ::core::mem::size_of::<Card32>()
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+        let size: usize = 12 + {
+            let block_len: usize = self.context.len();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetDeviceContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetDeviceContextRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetDeviceContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetDeviceContextRequestBuilder {
+    pub inner: SetDeviceContextRequest,
+}
+impl SetDeviceContextRequestBuilder {
+    #[inline]
+    pub fn device(mut self, device: Card32) -> Self {
+        self.inner.device = device;
+        self
+    }
+    #[inline]
+    pub fn context(mut self, context: String) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetDeviceContextRequest {
+        self.inner
+    }
+}
+impl Default for SetDeviceContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetDeviceContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetDeviceContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub device: Card32,
 }
-impl GetDeviceContextRequest {}
-impl AsByteSequence for GetDeviceContextRequest {
+impl GetDeviceContextRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> GetDeviceContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetDeviceContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("device", 4, 4)];
+}
+const _: () = assert!(<GetDeviceContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetDeviceContextRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.device.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (device, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card32, usize) = parse_item(bytes, index, "device")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetDeviceContextRequest {
                 req_type: req_type,
                 length: length,
@@ -396,372 +860,530 @@ impl AsByteSequence for GetDeviceContextRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.device.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetDeviceContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetDeviceContextRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceContext";
     type Reply = GetDeviceContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetDeviceContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetDeviceContextRequestBuilder {
+    pub inner: GetDeviceContextRequest,
+}
+impl GetDeviceContextRequestBuilder {
+    #[inline]
+    pub fn device(mut self, device: Card32) -> Self {
+        self.inner.device = device;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetDeviceContextRequest {
+        self.inner
+    }
+}
+impl Default for GetDeviceContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetDeviceContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                device: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetDeviceContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetDeviceContextReply {}
-impl AsByteSequence for GetDeviceContextReply {
+impl GetDeviceContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetDeviceContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetDeviceContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetDeviceContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetDeviceContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetWindowCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: String,
 }
-impl SetWindowCreateContextRequest {}
-impl AsByteSequence for SetWindowCreateContextRequest {
+impl SetWindowCreateContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SetWindowCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            SetWindowCreateContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(context: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            context: String::from(context),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
-            let block_len: usize = self.context.len();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-            block_len + pad
-        }
+    pub fn builder() -> SetWindowCreateContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_len", 4, 4)];
 }
+const _: () = assert!(<SetWindowCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { SetWindowCreateContextRequest }
 impl Request for SetWindowCreateContextRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetWindowCreateContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetWindowCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetWindowCreateContextRequestBuilder {
+    pub inner: SetWindowCreateContextRequest,
+}
+impl SetWindowCreateContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: String) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetWindowCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for SetWindowCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetWindowCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetWindowCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetWindowCreateContextRequest {}
-impl AsByteSequence for GetWindowCreateContextRequest {
+impl GetWindowCreateContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetWindowCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetWindowCreateContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetWindowCreateContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetWindowCreateContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetWindowCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { GetWindowCreateContextRequest }
 impl Request for GetWindowCreateContextRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetWindowCreateContext";
     type Reply = GetWindowCreateContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetWindowCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetWindowCreateContextRequestBuilder {
+    pub inner: GetWindowCreateContextRequest,
+}
+impl GetWindowCreateContextRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetWindowCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for GetWindowCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetWindowCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetWindowCreateContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetWindowCreateContextReply {}
-impl AsByteSequence for GetWindowCreateContextReply {
+impl GetWindowCreateContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetWindowCreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetWindowCreateContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetWindowCreateContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetWindowCreateContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetWindowContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl GetWindowContextRequest {}
-impl AsByteSequence for GetWindowContextRequest {
+impl GetWindowContextRequest {
+    #[inline]
+    pub fn builder() -> GetWindowContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetWindowContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetWindowContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_window_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.window.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        window: window,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.window.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_window_only_request_asb! { GetWindowContextRequest }
+impl Request for GetWindowContextRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 7;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetWindowContext";
+    type Reply = GetWindowContextReply;
+}
+#[doc = " A builder for `GetWindowContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetWindowContextRequestBuilder {
+    pub inner: GetWindowContextRequest,
+}
+impl GetWindowContextRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetWindowContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetWindowContextRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetWindowContextRequest {
+        self.inner
     }
+}
+impl Default for GetWindowContextRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    fn default() -> Self {
+        Self {
+            inner: GetWindowContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
     }
 }
-impl Request for GetWindowContextRequest {
-    const OPCODE: u8 = 7;
-    const EXTENSION: Option<&'static str> = Some("SELinux");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = GetWindowContextReply;
-}
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetWindowContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetWindowContextReply {}
-impl AsByteSequence for GetWindowContextReply {
+impl GetWindowContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetWindowContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetWindowContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetWindowContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetWindowContextReply }
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ListItem {
     pub name: Atom,
     pub object_context: String,
     pub data_context: String,
 }
-impl ListItem {}
+impl ListItem {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for ListItem {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.name.as_bytes(&mut bytes[index..]);
@@ -769,31 +1391,30 @@ impl AsByteSequence for ListItem {
         index += (self.data_context.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.object_context, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         let block_len: usize = string_as_bytes(&self.data_context, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListItem from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = parse_item(bytes, index, "name")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
         let (object_context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_string(bytes, index, len0 as usize, "object_context")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (data_context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_string(bytes, index, len1 as usize, "data_context")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        Some((
+        Ok((
             ListItem {
                 name: name,
                 object_context: object_context,
@@ -804,9 +1425,7 @@ impl AsByteSequence for ListItem {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.name.size()
-            + ::core::mem::size_of::<Card32>()
-            + ::core::mem::size_of::<Card32>()
+        8 + self.name.size()
             + {
                 let block_len: usize = self.object_context.len();
                 let pad: usize = buffer_pad(block_len, 4);
@@ -819,670 +1438,930 @@ impl AsByteSequence for ListItem {
             }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ListItem {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPropertyCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: String,
 }
-impl SetPropertyCreateContextRequest {}
-impl AsByteSequence for SetPropertyCreateContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
+impl SetPropertyCreateContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SetPropertyCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            SetPropertyCreateContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(context: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            context: String::from(context),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
-            let block_len: usize = self.context.len();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-            block_len + pad
-        }
+    pub fn builder() -> SetPropertyCreateContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_len", 4, 4)];
 }
+const _: () = assert!(<SetPropertyCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { SetPropertyCreateContextRequest }
 impl Request for SetPropertyCreateContextRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPropertyCreateContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPropertyCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPropertyCreateContextRequestBuilder {
+    pub inner: SetPropertyCreateContextRequest,
+}
+impl SetPropertyCreateContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: String) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPropertyCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for SetPropertyCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPropertyCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPropertyCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetPropertyCreateContextRequest {}
-impl AsByteSequence for GetPropertyCreateContextRequest {
+impl GetPropertyCreateContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetPropertyCreateContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetPropertyCreateContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPropertyCreateContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetPropertyCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { GetPropertyCreateContextRequest }
 impl Request for GetPropertyCreateContextRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPropertyCreateContext";
     type Reply = GetPropertyCreateContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetPropertyCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPropertyCreateContextRequestBuilder {
+    pub inner: GetPropertyCreateContextRequest,
+}
+impl GetPropertyCreateContextRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPropertyCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for GetPropertyCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPropertyCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPropertyCreateContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetPropertyCreateContextReply {}
-impl AsByteSequence for GetPropertyCreateContextReply {
+impl GetPropertyCreateContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyCreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetPropertyCreateContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetPropertyCreateContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetPropertyCreateContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPropertyUseContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: String,
 }
-impl SetPropertyUseContextRequest {}
-impl AsByteSequence for SetPropertyUseContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
+impl SetPropertyUseContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SetPropertyUseContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            SetPropertyUseContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(context: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            context: String::from(context),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
-            let block_len: usize = self.context.len();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-            block_len + pad
-        }
+    pub fn builder() -> SetPropertyUseContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_len", 4, 4)];
 }
+const _: () = assert!(<SetPropertyUseContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { SetPropertyUseContextRequest }
 impl Request for SetPropertyUseContextRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPropertyUseContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPropertyUseContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPropertyUseContextRequestBuilder {
+    pub inner: SetPropertyUseContextRequest,
+}
+impl SetPropertyUseContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: String) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPropertyUseContextRequest {
+        self.inner
+    }
+}
+impl Default for SetPropertyUseContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPropertyUseContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPropertyUseContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetPropertyUseContextRequest {}
-impl AsByteSequence for GetPropertyUseContextRequest {
+impl GetPropertyUseContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyUseContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetPropertyUseContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetPropertyUseContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPropertyUseContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetPropertyUseContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { GetPropertyUseContextRequest }
 impl Request for GetPropertyUseContextRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPropertyUseContext";
     type Reply = GetPropertyUseContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetPropertyUseContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPropertyUseContextRequestBuilder {
+    pub inner: GetPropertyUseContextRequest,
+}
+impl GetPropertyUseContextRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPropertyUseContextRequest {
+        self.inner
+    }
+}
+impl Default for GetPropertyUseContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPropertyUseContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPropertyUseContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetPropertyUseContextReply {}
-impl AsByteSequence for GetPropertyUseContextReply {
+impl GetPropertyUseContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyUseContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetPropertyUseContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetPropertyUseContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetPropertyUseContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPropertyContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub property: Atom,
 }
-impl GetPropertyContextRequest {}
-impl AsByteSequence for GetPropertyContextRequest {
+impl GetPropertyContextRequest {
+    #[inline]
+    pub fn builder() -> GetPropertyContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPropertyContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetPropertyContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_window_property_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.window.as_bytes(&mut bytes[index..]);
+                index += self.property.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
+                index += sz;
+                let (property, sz): (Atom, usize) = parse_item(bytes, index, "property")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        window: window,
+                        property: property,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.window.size() + self.property.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_window_property_request_asb! { GetPropertyContextRequest }
+impl Request for GetPropertyContextRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 12;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPropertyContext";
+    type Reply = GetPropertyContextReply;
+}
+#[doc = " A builder for `GetPropertyContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPropertyContextRequestBuilder {
+    pub inner: GetPropertyContextRequest,
+}
+impl GetPropertyContextRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.property.as_bytes(&mut bytes[index..]);
-        index
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetPropertyContextRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-                property: property,
-            },
-            index,
-        ))
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.property.size()
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPropertyContextRequest {
+        self.inner
     }
 }
-impl Request for GetPropertyContextRequest {
-    const OPCODE: u8 = 12;
-    const EXTENSION: Option<&'static str> = Some("SELinux");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = GetPropertyContextReply;
+impl Default for GetPropertyContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPropertyContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                property: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPropertyContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetPropertyContextReply {}
-impl AsByteSequence for GetPropertyContextReply {
+impl GetPropertyContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetPropertyContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetPropertyContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetPropertyContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPropertyDataContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub property: Atom,
 }
-impl GetPropertyDataContextRequest {}
-impl AsByteSequence for GetPropertyDataContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index += self.property.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetPropertyDataContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyDataContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetPropertyDataContextRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-                property: property,
-            },
-            index,
-        ))
+    pub fn builder() -> GetPropertyDataContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.property.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPropertyDataContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetPropertyDataContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_property_request_asb! { GetPropertyDataContextRequest }
 impl Request for GetPropertyDataContextRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPropertyDataContext";
     type Reply = GetPropertyDataContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetPropertyDataContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPropertyDataContextRequestBuilder {
+    pub inner: GetPropertyDataContextRequest,
+}
+impl GetPropertyDataContextRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn property(mut self, property: Atom) -> Self {
+        self.inner.property = property;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPropertyDataContextRequest {
+        self.inner
+    }
+}
+impl Default for GetPropertyDataContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPropertyDataContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                property: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPropertyDataContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetPropertyDataContextReply {}
-impl AsByteSequence for GetPropertyDataContextReply {
+impl GetPropertyDataContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetPropertyDataContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetPropertyDataContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetPropertyDataContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetPropertyDataContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListPropertiesRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl ListPropertiesRequest {}
-impl AsByteSequence for ListPropertiesRequest {
+impl ListPropertiesRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.window.as_bytes(&mut bytes[index..]);
-        index
+    pub fn builder() -> ListPropertiesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListPropertiesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<ListPropertiesRequest as Request>::MINOR_OPCODE < 0x100);
+impl_window_only_request_asb! { ListPropertiesRequest }
+impl Request for ListPropertiesRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 14;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListProperties";
+    type Reply = ListPropertiesReply;
+}
+#[doc = " A builder for `ListPropertiesRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListPropertiesRequestBuilder {
+    pub inner: ListPropertiesRequest,
+}
+impl ListPropertiesRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListPropertiesRequest {
+        self.inner
+    }
+}
+impl Default for ListPropertiesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListPropertiesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
     }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct ListPropertiesReply {
+    pub reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub properties: Vec<ListItem>,
+}
+impl ListPropertiesReply {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn properties_iter(bytes: &[u8]) -> Result<ListIter<'_, ListItem>, ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing ListPropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
-            ListPropertiesRequest {
-                req_type: req_type,
-                length: length,
-                window: window,
-            },
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::padded(
+            bytes,
             index,
+            len0 as usize,
+            4,
+            "properties",
         ))
     }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("properties_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.properties == other.properties
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "properties",
+            &self.properties,
+            &other.properties,
+            &mut changes,
+        );
+        changes
     }
 }
-impl Request for ListPropertiesRequest {
-    const OPCODE: u8 = 14;
-    const EXTENSION: Option<&'static str> = Some("SELinux");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ListPropertiesReply;
-}
-#[derive(Clone, Debug, Default)]
-pub struct ListPropertiesReply {
-    pub reply_type: u8,
-    pub sequence: u16,
-    pub length: u32,
-    pub properties: Vec<ListItem>,
-}
-impl ListPropertiesReply {}
 impl AsByteSequence for ListPropertiesReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.properties.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = vector_as_bytes(&self.properties, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 20);
+        let block_len: usize = padded_vector_as_bytes(&self.properties, &mut bytes[index..], 4);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ListItem>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListPropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (properties, block_len): (Vec<ListItem>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 4, "properties")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListPropertiesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1494,668 +2373,926 @@ impl AsByteSequence for ListPropertiesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.properties.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self
+                .properties
+                .iter()
+                .map(|i| {
+                    let size = i.size();
+                    size + buffer_pad(size, 4)
+                })
+                .sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ListPropertiesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetSelectionCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: String,
 }
-impl SetSelectionCreateContextRequest {}
-impl AsByteSequence for SetSelectionCreateContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
+impl SetSelectionCreateContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SetSelectionCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            SetSelectionCreateContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(context: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            context: String::from(context),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
-            let block_len: usize = self.context.len();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-            block_len + pad
-        }
+    pub fn builder() -> SetSelectionCreateContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_len", 4, 4)];
 }
+const _: () = assert!(<SetSelectionCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { SetSelectionCreateContextRequest }
 impl Request for SetSelectionCreateContextRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetSelectionCreateContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetSelectionCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetSelectionCreateContextRequestBuilder {
+    pub inner: SetSelectionCreateContextRequest,
+}
+impl SetSelectionCreateContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: String) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetSelectionCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for SetSelectionCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetSelectionCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetSelectionCreateContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetSelectionCreateContextRequest {}
-impl AsByteSequence for GetSelectionCreateContextRequest {
+impl GetSelectionCreateContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionCreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetSelectionCreateContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetSelectionCreateContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetSelectionCreateContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetSelectionCreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { GetSelectionCreateContextRequest }
 impl Request for GetSelectionCreateContextRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSelectionCreateContext";
     type Reply = GetSelectionCreateContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetSelectionCreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetSelectionCreateContextRequestBuilder {
+    pub inner: GetSelectionCreateContextRequest,
+}
+impl GetSelectionCreateContextRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetSelectionCreateContextRequest {
+        self.inner
+    }
+}
+impl Default for GetSelectionCreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetSelectionCreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetSelectionCreateContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetSelectionCreateContextReply {}
-impl AsByteSequence for GetSelectionCreateContextReply {
+impl GetSelectionCreateContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionCreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetSelectionCreateContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetSelectionCreateContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetSelectionCreateContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetSelectionUseContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context: String,
 }
-impl SetSelectionUseContextRequest {}
-impl AsByteSequence for SetSelectionUseContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
+impl SetSelectionUseContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing SetSelectionUseContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            SetSelectionUseContextRequest {
-                req_type: req_type,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn new(context: &str) -> Self {
+        Self {
+            req_type: Default::default(),
+            length: Default::default(),
+            context: String::from(context),
+        }
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
-            let block_len: usize = self.context.len();
-            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-            block_len + pad
-        }
+    pub fn builder() -> SetSelectionUseContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_len", 4, 4)];
 }
+const _: () = assert!(<SetSelectionUseContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_context_only_request_asb! { SetSelectionUseContextRequest }
 impl Request for SetSelectionUseContextRequest {
-    const OPCODE: u8 = 17;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 17;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetSelectionUseContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetSelectionUseContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetSelectionUseContextRequestBuilder {
+    pub inner: SetSelectionUseContextRequest,
+}
+impl SetSelectionUseContextRequestBuilder {
+    #[inline]
+    pub fn context(mut self, context: String) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetSelectionUseContextRequest {
+        self.inner
+    }
+}
+impl Default for SetSelectionUseContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetSelectionUseContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetSelectionUseContextRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl GetSelectionUseContextRequest {}
-impl AsByteSequence for GetSelectionUseContextRequest {
+impl GetSelectionUseContextRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionUseContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetSelectionUseContextRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> GetSelectionUseContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetSelectionUseContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetSelectionUseContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { GetSelectionUseContextRequest }
 impl Request for GetSelectionUseContextRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSelectionUseContext";
     type Reply = GetSelectionUseContextReply;
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetSelectionUseContextReply {
-    pub reply_type: u8,
-    pub sequence: u16,
-    pub length: u32,
-    pub context: String,
+#[doc = " A builder for `GetSelectionUseContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetSelectionUseContextRequestBuilder {
+    pub inner: GetSelectionUseContextRequest,
 }
-impl GetSelectionUseContextReply {}
-impl AsByteSequence for GetSelectionUseContextReply {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionUseContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
-        let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetSelectionUseContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
+impl GetSelectionUseContextRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetSelectionUseContextRequest {
+        self.inner
     }
 }
-#[derive(Clone, Debug, Default)]
-pub struct GetSelectionContextRequest {
-    pub req_type: u8,
-    pub length: u16,
-    pub selection: Atom,
-}
-impl GetSelectionContextRequest {}
-impl AsByteSequence for GetSelectionContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.selection.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl Default for GetSelectionUseContextRequestBuilder {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetSelectionContextRequest {
-                req_type: req_type,
-                length: length,
-                selection: selection,
+    fn default() -> Self {
+        Self {
+            inner: GetSelectionUseContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
             },
-            index,
-        ))
+        }
     }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub struct GetSelectionUseContextReply {
+    pub reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context: String,
+}
+impl GetSelectionUseContextReply {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.selection.size()
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (context, block_len): (String, usize) =
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetSelectionUseContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetSelectionUseContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
+pub struct GetSelectionContextRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub selection: Atom,
+}
+impl GetSelectionContextRequest {
+    #[inline]
+    pub fn builder() -> GetSelectionContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetSelectionContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<GetSelectionContextRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_selection_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.selection.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (selection, sz): (Atom, usize) = parse_item(bytes, index, "selection")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        selection: selection,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 4 + self.selection.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
 }
+impl_selection_only_request_asb! { GetSelectionContextRequest }
 impl Request for GetSelectionContextRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSelectionContext";
     type Reply = GetSelectionContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetSelectionContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetSelectionContextRequestBuilder {
+    pub inner: GetSelectionContextRequest,
+}
+impl GetSelectionContextRequestBuilder {
+    #[inline]
+    pub fn selection(mut self, selection: Atom) -> Self {
+        self.inner.selection = selection;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetSelectionContextRequest {
+        self.inner
+    }
+}
+impl Default for GetSelectionContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetSelectionContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                selection: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetSelectionContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetSelectionContextReply {}
-impl AsByteSequence for GetSelectionContextReply {
+impl GetSelectionContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetSelectionContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetSelectionContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetSelectionContextReply }
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetSelectionDataContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub selection: Atom,
 }
-impl GetSelectionDataContextRequest {}
-impl AsByteSequence for GetSelectionDataContextRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.selection.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl GetSelectionDataContextRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionDataContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetSelectionDataContextRequest {
-                req_type: req_type,
-                length: length,
-                selection: selection,
-            },
-            index,
-        ))
+    pub fn builder() -> GetSelectionDataContextRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.selection.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetSelectionDataContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<GetSelectionDataContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl_selection_only_request_asb! { GetSelectionDataContextRequest }
 impl Request for GetSelectionDataContextRequest {
-    const OPCODE: u8 = 20;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 20;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSelectionDataContext";
     type Reply = GetSelectionDataContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetSelectionDataContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetSelectionDataContextRequestBuilder {
+    pub inner: GetSelectionDataContextRequest,
+}
+impl GetSelectionDataContextRequestBuilder {
+    #[inline]
+    pub fn selection(mut self, selection: Atom) -> Self {
+        self.inner.selection = selection;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetSelectionDataContextRequest {
+        self.inner
+    }
+}
+impl Default for GetSelectionDataContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetSelectionDataContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                selection: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetSelectionDataContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetSelectionDataContextReply {}
-impl AsByteSequence for GetSelectionDataContextReply {
+impl GetSelectionDataContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetSelectionDataContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetSelectionDataContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetSelectionDataContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetSelectionDataContextReply }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListSelectionsRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl ListSelectionsRequest {}
-impl AsByteSequence for ListSelectionsRequest {
+impl ListSelectionsRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ListSelectionsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            ListSelectionsRequest {
-                req_type: req_type,
-                length: length,
-            },
-            index,
-        ))
+    pub fn builder() -> ListSelectionsRequestBuilder {
+        Default::default()
     }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListSelectionsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
 }
+const _: () = assert!(<ListSelectionsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_empty_request_asb! { ListSelectionsRequest }
 impl Request for ListSelectionsRequest {
-    const OPCODE: u8 = 21;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 21;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListSelections";
     type Reply = ListSelectionsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ListSelectionsRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListSelectionsRequestBuilder {
+    pub inner: ListSelectionsRequest,
+}
+impl ListSelectionsRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListSelectionsRequest {
+        self.inner
+    }
+}
+impl Default for ListSelectionsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListSelectionsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ListSelectionsReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub selections: Vec<ListItem>,
 }
-impl ListSelectionsReply {}
-impl AsByteSequence for ListSelectionsReply {
+impl ListSelectionsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn selections_iter(bytes: &[u8]) -> Result<ListIter<'_, ListItem>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::padded(
+            bytes,
+            index,
+            len0 as usize,
+            4,
+            "selections",
+        ))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("selections_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.selections == other.selections
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "selections",
+            &self.selections,
+            &other.selections,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for ListSelectionsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.selections.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = vector_as_bytes(&self.selections, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 20);
+        let block_len: usize = padded_vector_as_bytes(&self.selections, &mut bytes[index..], 4);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ListItem>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSelectionsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (selections, block_len): (Vec<ListItem>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 4, "selections")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListSelectionsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2167,48 +3304,83 @@ impl AsByteSequence for ListSelectionsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.selections.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self
+                .selections
+                .iter()
+                .map(|i| {
+                    let size = i.size();
+                    size + buffer_pad(size, 4)
+                })
+                .sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ListItem>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListSelectionsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetClientContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub resource: Card32,
 }
-impl GetClientContextRequest {}
-impl AsByteSequence for GetClientContextRequest {
+impl GetClientContextRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> GetClientContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetClientContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("resource", 4, 4)];
+}
+const _: () = assert!(<GetClientContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetClientContextRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.resource.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetClientContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (resource, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource, sz): (Card32, usize) = parse_item(bytes, index, "resource")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetClientContextRequest {
                 req_type: req_type,
                 length: length,
@@ -2219,78 +3391,176 @@ impl AsByteSequence for GetClientContextRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.resource.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetClientContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetClientContextRequest {
-    const OPCODE: u8 = 22;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SELinux");
+    const MINOR_OPCODE: u16 = 22;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetClientContext";
     type Reply = GetClientContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetClientContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetClientContextRequestBuilder {
+    pub inner: GetClientContextRequest,
+}
+impl GetClientContextRequestBuilder {
+    #[inline]
+    pub fn resource(mut self, resource: Card32) -> Self {
+        self.inner.resource = resource;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetClientContextRequest {
+        self.inner
+    }
+}
+impl Default for GetClientContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetClientContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                resource: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetClientContextReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub context: String,
 }
-impl GetClientContextReply {}
-impl AsByteSequence for GetClientContextReply {
+impl GetClientContextReply {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.sequence.as_bytes(&mut bytes[index..]);
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += (self.context.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
-        let block_len: usize = string_as_bytes(&self.context, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
         let mut index: usize = 0;
-        log::trace!("Deserializing GetClientContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 20;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
         let (context, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        Some((
-            GetClientContextReply {
-                reply_type: reply_type,
-                sequence: sequence,
-                length: length,
-                context: context,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.context.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-    }
+            read_string(reader, &mut index, len0 as usize, "context")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<c_char>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetClientContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            context: context,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("context_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list(
+            "context",
+            str::as_bytes(&self.context),
+            str::as_bytes(&other.context),
+            &mut changes,
+        );
+        changes
+    }
+}
+impl_context_only_reply_asb! { GetClientContextReply }
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "SetDeviceCreateContext"),
+    (2, "GetDeviceCreateContext"),
+    (3, "SetDeviceContext"),
+    (4, "GetDeviceContext"),
+    (5, "SetWindowCreateContext"),
+    (6, "GetWindowCreateContext"),
+    (7, "GetWindowContext"),
+    (8, "SetPropertyCreateContext"),
+    (9, "GetPropertyCreateContext"),
+    (10, "SetPropertyUseContext"),
+    (11, "GetPropertyUseContext"),
+    (12, "GetPropertyContext"),
+    (13, "GetPropertyDataContext"),
+    (14, "ListProperties"),
+    (15, "SetSelectionCreateContext"),
+    (16, "GetSelectionCreateContext"),
+    (17, "SetSelectionUseContext"),
+    (18, "GetSelectionUseContext"),
+    (19, "GetSelectionContext"),
+    (20, "GetSelectionDataContext"),
+    (21, "ListSelections"),
+    (22, "GetClientContext"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
 }
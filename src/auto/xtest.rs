@@ -1,46 +1,79 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "xtest")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+pub const MAJOR_VERSION: u32 = 2;
+pub const MINOR_VERSION: u32 = 2;
 use super::xproto::*;
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetVersionRequest {
     pub req_type: u8,
     pub length: u16,
     pub major_version: Card8,
     pub minor_version: Card16,
 }
-impl GetVersionRequest {}
-impl AsByteSequence for GetVersionRequest {
+impl GetVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> GetVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("major_version", 4, 1),
+        ("minor_version", 6, 2),
+    ];
+}
+const _: () = assert!(<GetVersionRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetVersionRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major_version.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card8, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
         index += 1;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -52,21 +85,63 @@ impl AsByteSequence for GetVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.major_version.size()
-            + 1
-            + self.minor_version.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XTEST");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVersion";
     type Reply = GetVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetVersionRequestBuilder {
+    pub inner: GetVersionRequest,
+}
+impl GetVersionRequestBuilder {
+    #[inline]
+    pub fn major_version(mut self, major_version: Card8) -> Self {
+        self.inner.major_version = major_version;
+        self
+    }
+    #[inline]
+    pub fn minor_version(mut self, minor_version: Card16) -> Self {
+        self.inner.minor_version = minor_version;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetVersionRequest {
+        self.inner
+    }
+}
+impl Default for GetVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                major_version: Default::default(),
+                minor_version: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetVersionReply {
     pub reply_type: u8,
     pub major_version: Card8,
@@ -74,9 +149,76 @@ pub struct GetVersionReply {
     pub length: u32,
     pub minor_version: Card16,
 }
-impl GetVersionReply {}
-impl AsByteSequence for GetVersionReply {
+impl GetVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        let major_version: Card8 = read_item(reader, &mut index, 1, "major_version")?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let minor_version: Card16 = read_item(reader, &mut index, 2, "minor_version")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetVersionReply {
+            reply_type: reply_type,
+            major_version: major_version,
+            sequence: sequence,
+            length: length,
+            minor_version: minor_version,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("major_version", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("minor_version", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major_version == other.major_version && self.minor_version == other.minor_version
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "major_version",
+            &self.major_version,
+            &other.major_version,
+            &mut changes,
+        );
+        diff_value(
+            "minor_version",
+            &self.minor_version,
+            &other.minor_version,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for GetVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
@@ -84,23 +226,25 @@ impl AsByteSequence for GetVersionReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card8, usize) = parse_item(bytes, index, "major_version")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (minor_version, sz): (Card16, usize) = parse_item(bytes, index, "minor_version")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetVersionReply {
                 reply_type: reply_type,
                 major_version: major_version,
@@ -113,46 +257,69 @@ impl AsByteSequence for GetVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.major_version.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.minor_version.size()
+        let size: usize = 10;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CompareCursorRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
     pub cursor: Cursor,
 }
-impl CompareCursorRequest {}
-impl AsByteSequence for CompareCursorRequest {
+impl CompareCursorRequest {
     #[inline]
+    pub fn builder() -> CompareCursorRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CompareCursorReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<CompareCursorRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CompareCursorRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
         index += self.cursor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompareCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = parse_item(bytes, index, "cursor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CompareCursorRequest {
                 req_type: req_type,
                 length: length,
@@ -164,23 +331,102 @@ impl AsByteSequence for CompareCursorRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size() + self.cursor.size()
+        let size: usize = 8 + self.window.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CompareCursorRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CompareCursorRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XTEST");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CompareCursor";
     type Reply = CompareCursorReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CompareCursorRequest`."]
+#[derive(Clone, Debug)]
+pub struct CompareCursorRequestBuilder {
+    pub inner: CompareCursorRequest,
+}
+impl CompareCursorRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.inner.cursor = cursor;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CompareCursorRequest {
+        self.inner
+    }
+}
+impl Default for CompareCursorRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CompareCursorRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+                cursor: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CompareCursorReply {
     pub reply_type: u8,
     pub same: bool,
     pub sequence: u16,
     pub length: u32,
 }
-impl CompareCursorReply {}
+impl CompareCursorReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("same", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.same == other.same
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("same", &self.same, &other.same, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for CompareCursorReply {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -189,21 +435,23 @@ impl AsByteSequence for CompareCursorReply {
         index += self.same.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompareCursorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (same, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same, sz): (bool, usize) = parse_item(bytes, index, "same")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        index += buffer_pad(index, 4);
+        Ok((
             CompareCursorReply {
                 reply_type: reply_type,
                 same: same,
@@ -215,10 +463,21 @@ impl AsByteSequence for CompareCursorReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + self.same.size() + self.sequence.size() + self.length.size()
+        let size: usize = 7 + self.same.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CompareCursorReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct FakeInputRequest {
     pub req_type: u8,
     pub length: u16,
@@ -230,53 +489,67 @@ pub struct FakeInputRequest {
     pub root_y: Int16,
     pub deviceid: Card8,
 }
-impl FakeInputRequest {}
-impl AsByteSequence for FakeInputRequest {
+impl FakeInputRequest {
     #[inline]
+    pub fn builder() -> FakeInputRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("ty", 4, 1),
+        ("detail", 5, 1),
+        ("time", 8, 4),
+    ];
+}
+const _: () = assert!(<FakeInputRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for FakeInputRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.detail.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.root.as_bytes(&mut bytes[index..]);
-        index += 8;
+        index += zero_pad(&mut bytes[index..], 8);
         index += self.root_x.as_bytes(&mut bytes[index..]);
         index += self.root_y.as_bytes(&mut bytes[index..]);
-        index += 7;
+        index += zero_pad(&mut bytes[index..], 7);
         index += self.deviceid.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing FakeInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (ty, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Byte, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = parse_item(bytes, index, "detail")?;
         index += sz;
         index += 2;
-        let (time, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Card32, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = parse_item(bytes, index, "root")?;
         index += sz;
         index += 8;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = parse_item(bytes, index, "root_x")?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = parse_item(bytes, index, "root_y")?;
         index += sz;
         index += 7;
-        let (deviceid, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (Card8, usize) = parse_item(bytes, index, "deviceid")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             FakeInputRequest {
                 req_type: req_type,
                 length: length,
@@ -293,58 +566,133 @@ impl AsByteSequence for FakeInputRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.ty.size()
-            + self.detail.size()
-            + 2
-            + self.time.size()
-            + self.root.size()
-            + 8
-            + self.root_x.size()
-            + self.root_y.size()
-            + 7
-            + self.deviceid.size()
+        let size: usize = 32 + self.root.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for FakeInputRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for FakeInputRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XTEST");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FakeInput";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `FakeInputRequest`."]
+#[derive(Clone, Debug)]
+pub struct FakeInputRequestBuilder {
+    pub inner: FakeInputRequest,
+}
+impl FakeInputRequestBuilder {
+    #[inline]
+    pub fn ty(mut self, ty: Byte) -> Self {
+        self.inner.ty = ty;
+        self
+    }
+    #[inline]
+    pub fn detail(mut self, detail: Byte) -> Self {
+        self.inner.detail = detail;
+        self
+    }
+    #[inline]
+    pub fn time(mut self, time: Card32) -> Self {
+        self.inner.time = time;
+        self
+    }
+    #[inline]
+    pub fn root(mut self, root: Window) -> Self {
+        self.inner.root = root;
+        self
+    }
+    #[inline]
+    pub fn root_x(mut self, root_x: Int16) -> Self {
+        self.inner.root_x = root_x;
+        self
+    }
+    #[inline]
+    pub fn root_y(mut self, root_y: Int16) -> Self {
+        self.inner.root_y = root_y;
+        self
+    }
+    #[inline]
+    pub fn deviceid(mut self, deviceid: Card8) -> Self {
+        self.inner.deviceid = deviceid;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> FakeInputRequest {
+        self.inner
+    }
+}
+impl Default for FakeInputRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: FakeInputRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                ty: Default::default(),
+                detail: Default::default(),
+                time: Default::default(),
+                root: Default::default(),
+                root_x: Default::default(),
+                root_y: Default::default(),
+                deviceid: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GrabControlRequest {
     pub req_type: u8,
     pub length: u16,
     pub impervious: bool,
 }
-impl GrabControlRequest {}
-impl AsByteSequence for GrabControlRequest {
+impl GrabControlRequest {
     #[inline]
+    pub fn builder() -> GrabControlRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("impervious", 4, 1)];
+}
+const _: () = assert!(<GrabControlRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GrabControlRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.impervious.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (impervious, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (impervious, sz): (bool, usize) = parse_item(bytes, index, "impervious")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GrabControlRequest {
                 req_type: req_type,
                 length: length,
@@ -355,17 +703,58 @@ impl AsByteSequence for GrabControlRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.impervious.size() + 3
+        let size: usize = 7 + self.impervious.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GrabControlRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GrabControlRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XTEST");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabControl";
     type Reply = ();
 }
+#[doc = " A builder for `GrabControlRequest`."]
+#[derive(Clone, Debug)]
+pub struct GrabControlRequestBuilder {
+    pub inner: GrabControlRequest,
+}
+impl GrabControlRequestBuilder {
+    #[inline]
+    pub fn impervious(mut self, impervious: bool) -> Self {
+        self.inner.impervious = impervious;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GrabControlRequest {
+        self.inner
+    }
+}
+impl Default for GrabControlRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GrabControlRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                impervious: Default::default(),
+            },
+        }
+    }
+}
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum Cursor {
     None = 0,
     Current = 1,
@@ -376,12 +765,12 @@ impl AsByteSequence for Cursor {
         (*self as i32).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (i32, usize) = <i32>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::None, sz)),
-            1 => Some((Self::Current, sz)),
-            _ => None,
+            0 => Ok((Self::None, sz)),
+            1 => Ok((Self::Current, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -389,9 +778,66 @@ impl AsByteSequence for Cursor {
         ::core::mem::size_of::<i32>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for Cursor {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for Cursor {
     #[inline]
     fn default() -> Cursor {
         Cursor::None
     }
 }
+impl From<Cursor> for i32 {
+    #[inline]
+    fn from(base: Cursor) -> Self {
+        base as i32
+    }
+}
+impl core::convert::TryFrom<i32> for Cursor {
+    type Error = i32;
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, i32> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Current),
+            _ => Err(value),
+        }
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "GetVersion"),
+    (1, "CompareCursor"),
+    (2, "FakeInput"),
+    (3, "GrabControl"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
@@ -1,14 +1,23 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "xv")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+#[cfg(not(feature = "shm"))]
+compile_error!(
+    "The `xv` feature requires the `shm` feature, since it uses the types that it defines"
+);
+pub const MAJOR_VERSION: u32 = 2;
+pub const MINOR_VERSION: u32 = 2;
 use super::shm::*;
 use super::xproto::*;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Port {
     pub xid: XID,
 }
@@ -17,6 +26,23 @@ impl Port {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Port {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Port {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Port {
     #[inline]
@@ -28,8 +54,30 @@ impl XidType for Port {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Port {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Port({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Port {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Port> for XID {
+    #[inline]
+    fn from(base: Port) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Encoding {
     pub xid: XID,
 }
@@ -38,6 +86,23 @@ impl Encoding {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Encoding {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Encoding {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Encoding {
     #[inline]
@@ -49,12 +114,52 @@ impl XidType for Encoding {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Encoding {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Encoding({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Encoding {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Encoding> for XID {
+    #[inline]
+    fn from(base: Encoding) -> Self {
+        base.xid
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "pod", derive(bytemuck::Pod, bytemuck::Zeroable,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Rational {
     pub numerator: Int32,
     pub denominator: Int32,
 }
-impl Rational {}
+impl Rational {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub const fn new(numerator: Int32, denominator: Int32) -> Self {
+        Self {
+            numerator: numerator,
+            denominator: denominator,
+        }
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 8;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("numerator", 0, 4), ("denominator", 4, 4)];
+}
 impl AsByteSequence for Rational {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -64,14 +169,14 @@ impl AsByteSequence for Rational {
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Rational from byte buffer");
-        let (numerator, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (numerator, sz): (Int32, usize) = parse_item(bytes, index, "numerator")?;
         index += sz;
-        let (denominator, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (denominator, sz): (Int32, usize) = parse_item(bytes, index, "denominator")?;
         index += sz;
-        Some((
+        Ok((
             Rational {
                 numerator: numerator,
                 denominator: denominator,
@@ -81,34 +186,46 @@ impl AsByteSequence for Rational {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.numerator.size() + self.denominator.size()
+        8
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Rational {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Format {
     pub visual: Visualid,
     pub depth: Card8,
 }
-impl Format {}
+impl Format {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[];
+}
 impl AsByteSequence for Format {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.visual.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Format from byte buffer");
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = parse_item(bytes, index, "visual")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
         index += 3;
-        Some((
+        Ok((
             Format {
                 visual: visual,
                 depth: depth,
@@ -118,10 +235,19 @@ impl AsByteSequence for Format {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.visual.size() + self.depth.size() + 3
+        4 + self.visual.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Format {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AdaptorInfo {
     pub base_id: Port,
     pub num_ports: Card16,
@@ -129,9 +255,17 @@ pub struct AdaptorInfo {
     pub name: String,
     pub formats: Vec<Format>,
 }
-impl AdaptorInfo {}
+impl AdaptorInfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("base_id", 0, 4),
+        ("name_len", 4, 2),
+        ("num_ports", 6, 2),
+        ("formats_len", 8, 2),
+        ("ty", 10, 1),
+    ];
+}
 impl AsByteSequence for AdaptorInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.base_id.as_bytes(&mut bytes[index..]);
@@ -139,38 +273,40 @@ impl AsByteSequence for AdaptorInfo {
         index += self.num_ports.as_bytes(&mut bytes[index..]);
         index += (self.formats.len() as Card16).as_bytes(&mut bytes[index..]);
         index += self.ty.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         let block_len: usize = vector_as_bytes(&self.formats, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Format>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Format>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AdaptorInfo from byte buffer");
-        let (base_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (base_id, sz): (Port, usize) = parse_item(bytes, index, "base_id")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (num_ports, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_ports, sz): (Card16, usize) = parse_item(bytes, index, "num_ports")?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = parse_item(bytes, index, "len1")?;
         index += sz;
-        let (ty, sz): (Type, usize) = <Type>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Type, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
         index += 1;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (formats, block_len): (Vec<Format>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            parse_vector(bytes, index, len1 as usize, "formats")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Format>());
-        Some((
+        Ok((
             AdaptorInfo {
                 base_id: base_id,
                 num_ports: num_ports,
@@ -183,26 +319,28 @@ impl AsByteSequence for AdaptorInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.base_id.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.num_ports.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.ty.size()
-            + 1
-            + {
-                let block_len: usize = self.name.len();
-                let pad: usize = buffer_pad(block_len, 4);
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.formats.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Format>());
-                block_len + pad
-            }
+        12 + {
+            let block_len: usize = self.name.len();
+            let pad: usize = buffer_pad(block_len, 4);
+            block_len + pad
+        } + {
+            let block_len: usize = self.formats.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Format>());
+            block_len + pad
+        }
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AdaptorInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Type {
     pub inner: u8,
 }
@@ -309,19 +447,39 @@ impl Type {
     pub const IMAGE_MASK: Self = Self { inner: 16 };
     pub const COMPLETE: Self = Self { inner: 31 };
 }
+impl core::ops::Deref for Type {
+    type Target = u8;
+    #[inline]
+    fn deref(&self) -> &u8 {
+        &self.inner
+    }
+}
+impl AsRef<u8> for Type {
+    #[inline]
+    fn as_ref(&self) -> &u8 {
+        &self.inner
+    }
+}
 impl AsByteSequence for Type {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
-        Some((Type { inner: inner }, sz))
+        Ok((Type { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        1
+    }
+}
+impl core::convert::TryFrom<&[u8]> for Type {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for Type {
@@ -358,7 +516,9 @@ impl core::ops::BitXor for Type {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct EncodingInfo {
     pub encoding: Encoding,
     pub width: Card16,
@@ -366,41 +526,48 @@ pub struct EncodingInfo {
     pub rate: Rational,
     pub name: String,
 }
-impl EncodingInfo {}
+impl EncodingInfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("encoding", 0, 4),
+        ("name_len", 4, 2),
+        ("width", 6, 2),
+        ("height", 8, 2),
+        ("rate", 12, 8),
+    ];
+}
 impl AsByteSequence for EncodingInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.encoding.as_bytes(&mut bytes[index..]);
         index += (self.name.len() as Card16).as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.rate.as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing EncodingInfo from byte buffer");
-        let (encoding, sz): (Encoding, usize) = <Encoding>::from_bytes(&bytes[index..])?;
+        let (encoding, sz): (Encoding, usize) = parse_item(bytes, index, "encoding")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
         index += 2;
-        let (rate, sz): (Rational, usize) = <Rational>::from_bytes(&bytes[index..])?;
+        let (rate, sz): (Rational, usize) = parse_item(bytes, index, "rate")?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        Some((
+        Ok((
             EncodingInfo {
                 encoding: encoding,
                 width: width,
@@ -413,20 +580,22 @@ impl AsByteSequence for EncodingInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.encoding.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.width.size()
-            + self.height.size()
-            + 2
-            + self.rate.size()
-            + {
-                let block_len: usize = self.name.len();
-                let pad: usize = buffer_pad(block_len, 4);
-                block_len + pad
-            }
+        20 + {
+            let block_len: usize = self.name.len();
+            let pad: usize = buffer_pad(block_len, 4);
+            block_len + pad
+        }
+    }
+}
+impl core::convert::TryFrom<&[u8]> for EncodingInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Image {
     pub id: Card32,
     pub width: Card16,
@@ -436,9 +605,77 @@ pub struct Image {
     pub offsets: Vec<Card32>,
     pub data: Vec<Card8>,
 }
-impl Image {}
+impl Image {
+    pub fn pitches_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (num_planes, sz): (Card32, usize) = parse_item(bytes, index, "num_planes")?;
+        index += sz;
+        let (pitches, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (num_planes as usize) as usize, "pitches")?;
+        Ok(pitches)
+    }
+    pub fn offsets_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (num_planes, sz): (Card32, usize) = parse_item(bytes, index, "num_planes")?;
+        index += sz;
+        let (pitches, block_len): (Vec<Card32>, usize) =
+            parse_vector(bytes, index, (num_planes as usize) as usize, "pitches")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        let (offsets, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (num_planes as usize) as usize, "offsets")?;
+        Ok(offsets)
+    }
+    pub fn data_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
+        let mut index: usize = 0;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (num_planes, sz): (Card32, usize) = parse_item(bytes, index, "num_planes")?;
+        index += sz;
+        let (pitches, block_len): (Vec<Card32>, usize) =
+            parse_vector(bytes, index, (num_planes as usize) as usize, "pitches")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        let (offsets, block_len): (Vec<Card32>, usize) =
+            parse_vector(bytes, index, (num_planes as usize) as usize, "offsets")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        let (data, _): (Cow<'_, [Card8]>, usize) =
+            parse_slice(bytes, index, len0 as usize, "data")?;
+        Ok(data)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("id", 0, 4),
+        ("width", 4, 2),
+        ("height", 6, 2),
+        ("data_len", 8, 4),
+        ("num_planes", 12, 4),
+    ];
+}
 impl AsByteSequence for Image {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.id.as_bytes(&mut bytes[index..]);
@@ -448,42 +685,50 @@ impl AsByteSequence for Image {
         index += self.num_planes.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.pitches, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = vector_as_bytes(&self.offsets, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+        );
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing Image from byte buffer");
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (num_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_planes, sz): (Card32, usize) = parse_item(bytes, index, "num_planes")?;
         index += sz;
         let (pitches, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            parse_vector(bytes, index, (num_planes as usize) as usize, "pitches")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (offsets, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            parse_vector(bytes, index, (num_planes as usize) as usize, "offsets")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (data, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "data")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
+        Ok((
             Image {
                 id: id,
                 width: width,
@@ -496,40 +741,99 @@ impl AsByteSequence for Image {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.id.size()
-            + self.width.size()
-            + self.height.size()
-            + ::core::mem::size_of::<Card32>()
-            + self.num_planes.size()
-            + {
-                let block_len: usize = self.pitches.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.offsets.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
+        16 + {
+            let block_len: usize = self.pitches.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.offsets.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        }
+    }
+    fn size_hint(&self) -> usize {
+        16 + {
+            let block_len: usize = self.pitches.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.offsets.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.data.len() * ::core::mem::size_of::<Card8>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for Image {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl core::fmt::Debug for Image {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Image")
+            .field("id", &self.id)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("num_planes", &self.num_planes)
+            .field("pitches", &self.pitches)
+            .field("offsets", &self.offsets)
+            .field("data", &debug_bytes(&self.data, 64))
+            .finish()
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for Image {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let id: Card32 = u.arbitrary()?;
+        let width: Card16 = u.arbitrary()?;
+        let height: Card16 = u.arbitrary()?;
+        let num_planes: Card32 = u.arbitrary()?;
+        let data = u.arbitrary()?;
+        let pitches = arbitrary_list(u, (num_planes as usize) as usize)?;
+        let offsets = arbitrary_list(u, (num_planes as usize) as usize)?;
+        Ok(Self {
+            id: id,
+            width: width,
+            height: height,
+            num_planes: num_planes,
+            pitches: pitches,
+            offsets: offsets,
+            data: data,
+        })
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AttributeInfo {
     pub flags: AttributeFlag,
     pub min: Int32,
     pub max: Int32,
     pub name: String,
 }
-impl AttributeInfo {}
+impl AttributeInfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("flags", 0, 4),
+        ("min", 4, 4),
+        ("max", 8, 4),
+        ("name_len", 12, 4),
+    ];
+}
 impl AsByteSequence for AttributeInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.flags.as_bytes(&mut bytes[index..]);
@@ -538,25 +842,24 @@ impl AsByteSequence for AttributeInfo {
         index += (self.name.len() as Card32).as_bytes(&mut bytes[index..]);
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, 4);
+        index += zero_pad(&mut bytes[index..], buffer_pad(block_len, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttributeInfo from byte buffer");
-        let (flags, sz): (AttributeFlag, usize) = <AttributeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (AttributeFlag, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        let (min, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (min, sz): (Int32, usize) = parse_item(bytes, index, "min")?;
         index += sz;
-        let (max, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (max, sz): (Int32, usize) = parse_item(bytes, index, "max")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) = parse_string(bytes, index, len0 as usize, "name")?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        Some((
+        Ok((
             AttributeInfo {
                 flags: flags,
                 min: min,
@@ -568,15 +871,24 @@ impl AsByteSequence for AttributeInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.flags.size() + self.min.size() + self.max.size() + ::core::mem::size_of::<Card32>() + {
+        16 + {
             let block_len: usize = self.name.len();
             let pad: usize = buffer_pad(block_len, 4);
             block_len + pad
         }
     }
 }
+impl core::convert::TryFrom<&[u8]> for AttributeInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct AttributeFlag {
     pub inner: u32,
 }
@@ -626,19 +938,39 @@ impl AttributeFlag {
     pub const SETTABLE: Self = Self { inner: 2 };
     pub const COMPLETE: Self = Self { inner: 3 };
 }
+impl core::ops::Deref for AttributeFlag {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &u32 {
+        &self.inner
+    }
+}
+impl AsRef<u32> for AttributeFlag {
+    #[inline]
+    fn as_ref(&self) -> &u32 {
+        &self.inner
+    }
+}
 impl AsByteSequence for AttributeFlag {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         self.inner.as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (inner, sz): (u32, usize) = <u32>::from_bytes(bytes)?;
-        Some((AttributeFlag { inner: inner }, sz))
+        Ok((AttributeFlag { inner: inner }, sz))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.inner.size()
+        4
+    }
+}
+impl core::convert::TryFrom<&[u8]> for AttributeFlag {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl core::ops::Not for AttributeFlag {
@@ -675,7 +1007,9 @@ impl core::ops::BitXor for AttributeFlag {
         }
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ImageFormatInfo {
     pub id: Card32,
     pub ty: ImageFormatInfoType,
@@ -700,20 +1034,22 @@ pub struct ImageFormatInfo {
     pub vcomp_order: [Card8; 32],
     pub vscanline_order: ScanlineOrder,
 }
-impl ImageFormatInfo {}
+impl ImageFormatInfo {
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("id", 0, 4), ("ty", 4, 1)];
+}
 impl AsByteSequence for ImageFormatInfo {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.id.as_bytes(&mut bytes[index..]);
         index += self.ty.as_bytes(&mut bytes[index..]);
         index += self.byte_order.as_bytes(&mut bytes[index..]);
-        index += 2;
+        index += zero_pad(&mut bytes[index..], 2);
         index += self.guid.as_bytes(&mut bytes[index..]);
         index += self.bpp.as_bytes(&mut bytes[index..]);
         index += self.num_planes.as_bytes(&mut bytes[index..]);
         index += self.depth.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
         index += self.red_mask.as_bytes(&mut bytes[index..]);
         index += self.green_mask.as_bytes(&mut bytes[index..]);
         index += self.blue_mask.as_bytes(&mut bytes[index..]);
@@ -729,64 +1065,61 @@ impl AsByteSequence for ImageFormatInfo {
         index += self.vvert_v_period.as_bytes(&mut bytes[index..]);
         index += self.vcomp_order.as_bytes(&mut bytes[index..]);
         index += self.vscanline_order.as_bytes(&mut bytes[index..]);
-        index += 11;
+        index += zero_pad(&mut bytes[index..], 11);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ImageFormatInfo from byte buffer");
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (ty, sz): (ImageFormatInfoType, usize) =
-            <ImageFormatInfoType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (ImageFormatInfoType, usize) = parse_item(bytes, index, "ty")?;
         index += sz;
-        let (byte_order, sz): (ImageOrder, usize) = <ImageOrder>::from_bytes(&bytes[index..])?;
+        let (byte_order, sz): (ImageOrder, usize) = parse_item(bytes, index, "byte_order")?;
         index += sz;
         index += 2;
-        let (guid, sz): ([Card8; 16], usize) = <[Card8; 16]>::from_bytes(&bytes[index..])?;
+        let (guid, sz): ([Card8; 16], usize) = parse_item(bytes, index, "guid")?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = parse_item(bytes, index, "bpp")?;
         index += sz;
-        let (num_planes, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_planes, sz): (Card8, usize) = parse_item(bytes, index, "num_planes")?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = parse_item(bytes, index, "depth")?;
         index += sz;
         index += 3;
-        let (red_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (red_mask, sz): (Card32, usize) = parse_item(bytes, index, "red_mask")?;
         index += sz;
-        let (green_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green_mask, sz): (Card32, usize) = parse_item(bytes, index, "green_mask")?;
         index += sz;
-        let (blue_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue_mask, sz): (Card32, usize) = parse_item(bytes, index, "blue_mask")?;
         index += sz;
-        let (format, sz): (ImageFormatInfoFormat, usize) =
-            <ImageFormatInfoFormat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (ImageFormatInfoFormat, usize) = parse_item(bytes, index, "format")?;
         index += sz;
-        let (y_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (y_sample_bits, sz): (Card32, usize) = parse_item(bytes, index, "y_sample_bits")?;
         index += sz;
-        let (u_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (u_sample_bits, sz): (Card32, usize) = parse_item(bytes, index, "u_sample_bits")?;
         index += sz;
-        let (v_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (v_sample_bits, sz): (Card32, usize) = parse_item(bytes, index, "v_sample_bits")?;
         index += sz;
-        let (vhorz_y_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vhorz_y_period, sz): (Card32, usize) = parse_item(bytes, index, "vhorz_y_period")?;
         index += sz;
-        let (vhorz_u_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vhorz_u_period, sz): (Card32, usize) = parse_item(bytes, index, "vhorz_u_period")?;
         index += sz;
-        let (vhorz_v_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vhorz_v_period, sz): (Card32, usize) = parse_item(bytes, index, "vhorz_v_period")?;
         index += sz;
-        let (vvert_y_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vvert_y_period, sz): (Card32, usize) = parse_item(bytes, index, "vvert_y_period")?;
         index += sz;
-        let (vvert_u_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vvert_u_period, sz): (Card32, usize) = parse_item(bytes, index, "vvert_u_period")?;
         index += sz;
-        let (vvert_v_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vvert_v_period, sz): (Card32, usize) = parse_item(bytes, index, "vvert_v_period")?;
         index += sz;
-        let (vcomp_order, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (vcomp_order, sz): ([Card8; 32], usize) = parse_item(bytes, index, "vcomp_order")?;
         index += sz;
         let (vscanline_order, sz): (ScanlineOrder, usize) =
-            <ScanlineOrder>::from_bytes(&bytes[index..])?;
+            parse_item(bytes, index, "vscanline_order")?;
         index += sz;
         index += 11;
-        Some((
+        Ok((
             ImageFormatInfo {
                 id: id,
                 ty: ty,
@@ -816,35 +1149,20 @@ impl AsByteSequence for ImageFormatInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.id.size()
-            + self.ty.size()
-            + self.byte_order.size()
-            + 2
-            + self.guid.size()
-            + self.bpp.size()
-            + self.num_planes.size()
-            + self.depth.size()
-            + 3
-            + self.red_mask.size()
-            + self.green_mask.size()
-            + self.blue_mask.size()
-            + self.format.size()
-            + self.y_sample_bits.size()
-            + self.u_sample_bits.size()
-            + self.v_sample_bits.size()
-            + self.vhorz_y_period.size()
-            + self.vhorz_u_period.size()
-            + self.vhorz_v_period.size()
-            + self.vvert_y_period.size()
-            + self.vvert_u_period.size()
-            + self.vvert_v_period.size()
-            + self.vcomp_order.size()
-            + self.vscanline_order.size()
-            + 11
+        122 + self.byte_order.size()
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ImageFormatInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum ImageFormatInfoType {
     Rgb = 0,
     Yuv = 1,
@@ -855,12 +1173,12 @@ impl AsByteSequence for ImageFormatInfoType {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Rgb, sz)),
-            1 => Some((Self::Yuv, sz)),
-            _ => None,
+            0 => Ok((Self::Rgb, sz)),
+            1 => Ok((Self::Yuv, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -868,14 +1186,40 @@ impl AsByteSequence for ImageFormatInfoType {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for ImageFormatInfoType {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for ImageFormatInfoType {
     #[inline]
     fn default() -> ImageFormatInfoType {
         ImageFormatInfoType::Rgb
     }
 }
+impl From<ImageFormatInfoType> for u8 {
+    #[inline]
+    fn from(base: ImageFormatInfoType) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for ImageFormatInfoType {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Rgb),
+            1 => Ok(Self::Yuv),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum ImageFormatInfoFormat {
     Packed = 0,
     Planar = 1,
@@ -886,12 +1230,12 @@ impl AsByteSequence for ImageFormatInfoFormat {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Packed, sz)),
-            1 => Some((Self::Planar, sz)),
-            _ => None,
+            0 => Ok((Self::Packed, sz)),
+            1 => Ok((Self::Planar, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -899,14 +1243,40 @@ impl AsByteSequence for ImageFormatInfoFormat {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for ImageFormatInfoFormat {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for ImageFormatInfoFormat {
     #[inline]
     fn default() -> ImageFormatInfoFormat {
         ImageFormatInfoFormat::Packed
     }
 }
+impl From<ImageFormatInfoFormat> for u8 {
+    #[inline]
+    fn from(base: ImageFormatInfoFormat) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for ImageFormatInfoFormat {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Packed),
+            1 => Ok(Self::Planar),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum ScanlineOrder {
     TopToBottom = 0,
     BottomToTop = 1,
@@ -917,12 +1287,12 @@ impl AsByteSequence for ScanlineOrder {
         (*self as u8).as_bytes(bytes)
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::TopToBottom, sz)),
-            1 => Some((Self::BottomToTop, sz)),
-            _ => None,
+            0 => Ok((Self::TopToBottom, sz)),
+            1 => Ok((Self::BottomToTop, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -930,14 +1300,40 @@ impl AsByteSequence for ScanlineOrder {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for ScanlineOrder {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for ScanlineOrder {
     #[inline]
     fn default() -> ScanlineOrder {
         ScanlineOrder::TopToBottom
     }
 }
+impl From<ScanlineOrder> for u8 {
+    #[inline]
+    fn from(base: ScanlineOrder) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for ScanlineOrder {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::TopToBottom),
+            1 => Ok(Self::BottomToTop),
+            _ => Err(value),
+        }
+    }
+}
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum VideoNotifyReason {
     Started = 0,
     Stopped = 1,
@@ -950,16 +1346,15 @@ impl AsByteSequence for VideoNotifyReason {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u8).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Started, sz)),
-            1 => Some((Self::Stopped, sz)),
-            2 => Some((Self::Busy, sz)),
-            3 => Some((Self::Preempted, sz)),
-            4 => Some((Self::HardError, sz)),
-            _ => None,
+            0 => Ok((Self::Started, sz)),
+            1 => Ok((Self::Stopped, sz)),
+            2 => Ok((Self::Busy, sz)),
+            3 => Ok((Self::Preempted, sz)),
+            4 => Ok((Self::HardError, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -967,37 +1362,91 @@ impl AsByteSequence for VideoNotifyReason {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for VideoNotifyReason {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for VideoNotifyReason {
     #[inline]
     fn default() -> VideoNotifyReason {
         VideoNotifyReason::Started
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<VideoNotifyReason> for u8 {
+    #[inline]
+    fn from(base: VideoNotifyReason) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for VideoNotifyReason {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Started),
+            1 => Ok(Self::Stopped),
+            2 => Ok(Self::Busy),
+            3 => Ok(Self::Preempted),
+            4 => Ok(Self::HardError),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryExtensionRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryExtensionRequest {}
+impl QueryExtensionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryExtensionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryExtensionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryExtensionRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryExtensionRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryExtensionRequest {
                 req_type: req_type,
                 length: length,
@@ -1007,16 +1456,51 @@ impl AsByteSequence for QueryExtensionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryExtensionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryExtensionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryExtension";
     type Reply = QueryExtensionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryExtensionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryExtensionRequestBuilder {
+    pub inner: QueryExtensionRequest,
+}
+impl QueryExtensionRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryExtensionRequest {
+        self.inner
+    }
+}
+impl Default for QueryExtensionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryExtensionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryExtensionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -1024,35 +1508,95 @@ pub struct QueryExtensionReply {
     pub major: Card16,
     pub minor: Card16,
 }
-impl QueryExtensionReply {}
-impl AsByteSequence for QueryExtensionReply {
+impl QueryExtensionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major: Card16 = read_item(reader, &mut index, 2, "major")?;
+        let minor: Card16 = read_item(reader, &mut index, 2, "minor")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryExtensionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major: major,
+            minor: minor,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major", 8, 2),
+        ("minor", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("major", &self.major, &other.major, &mut changes);
+        diff_value("minor", &self.minor, &other.minor, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryExtensionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major.as_bytes(&mut bytes[index..]);
         index += self.minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major, sz): (Card16, usize) = parse_item(bytes, index, "major")?;
         index += sz;
-        let (minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Card16, usize) = parse_item(bytes, index, "minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryExtensionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1065,43 +1609,65 @@ impl AsByteSequence for QueryExtensionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major.size()
-            + self.minor.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryExtensionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryAdaptorsRequest {
     pub req_type: u8,
     pub length: u16,
     pub window: Window,
 }
-impl QueryAdaptorsRequest {}
-impl AsByteSequence for QueryAdaptorsRequest {
+impl QueryAdaptorsRequest {
     #[inline]
+    pub fn builder() -> QueryAdaptorsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryAdaptorsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryAdaptorsRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryAdaptorsRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryAdaptorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = parse_item(bytes, index, "window")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryAdaptorsRequest {
                 req_type: req_type,
                 length: length,
@@ -1112,57 +1678,149 @@ impl AsByteSequence for QueryAdaptorsRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.window.size()
+        let size: usize = 4 + self.window.size();
+        size + buffer_pad(size, 4)
     }
 }
-impl Request for QueryAdaptorsRequest {
-    const OPCODE: u8 = 1;
+impl core::convert::TryFrom<&[u8]> for QueryAdaptorsRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl Request for QueryAdaptorsRequest {
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryAdaptors";
     type Reply = QueryAdaptorsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryAdaptorsRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryAdaptorsRequestBuilder {
+    pub inner: QueryAdaptorsRequest,
+}
+impl QueryAdaptorsRequestBuilder {
+    #[inline]
+    pub fn window(mut self, window: Window) -> Self {
+        self.inner.window = window;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryAdaptorsRequest {
+        self.inner
+    }
+}
+impl Default for QueryAdaptorsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryAdaptorsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                window: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryAdaptorsReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub info: Vec<AdaptorInfo>,
 }
-impl QueryAdaptorsReply {}
-impl AsByteSequence for QueryAdaptorsReply {
+impl QueryAdaptorsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn info_iter(bytes: &[u8]) -> Result<ListIter<'_, AdaptorInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 22;
+        Ok(ListIter::padded(bytes, index, len0 as usize, 4, "info"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
     #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("info_len", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.info == other.info
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("info", &self.info, &other.info, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryAdaptorsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.info.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 22;
-        let block_len: usize = vector_as_bytes(&self.info, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 22);
+        let block_len: usize = padded_vector_as_bytes(&self.info, &mut bytes[index..], 4);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<AdaptorInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<AdaptorInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryAdaptorsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 22;
         let (info, block_len): (Vec<AdaptorInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 4, "info")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AdaptorInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryAdaptorsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1174,109 +1832,239 @@ impl AsByteSequence for QueryAdaptorsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card16>()
-            + 22
-            + {
-                let block_len: usize = self.info.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<AdaptorInfo>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self
+                .info
+                .iter()
+                .map(|i| {
+                    let size = i.size();
+                    size + buffer_pad(size, 4)
+                })
+                .sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<AdaptorInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryAdaptorsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryEncodingsRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
 }
-impl QueryEncodingsRequest {}
-impl AsByteSequence for QueryEncodingsRequest {
+impl QueryEncodingsRequest {
+    #[inline]
+    pub fn builder() -> QueryEncodingsRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryEncodingsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<QueryEncodingsRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_port_only_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.port.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        port: port,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8;
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_port_only_request_asb! { QueryEncodingsRequest }
+impl Request for QueryEncodingsRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 2;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryEncodings";
+    type Reply = QueryEncodingsReply;
+}
+#[doc = " A builder for `QueryEncodingsRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryEncodingsRequestBuilder {
+    pub inner: QueryEncodingsRequest,
+}
+impl QueryEncodingsRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryEncodingsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryEncodingsRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-            },
-            index,
-        ))
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryEncodingsRequest {
+        self.inner
     }
+}
+impl Default for QueryEncodingsRequestBuilder {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size()
+    fn default() -> Self {
+        Self {
+            inner: QueryEncodingsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+            },
+        }
     }
 }
-impl Request for QueryEncodingsRequest {
-    const OPCODE: u8 = 2;
-    const EXTENSION: Option<&'static str> = Some("XVideo");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = QueryEncodingsReply;
-}
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryEncodingsReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub info: Vec<EncodingInfo>,
 }
-impl QueryEncodingsReply {}
-impl AsByteSequence for QueryEncodingsReply {
+impl QueryEncodingsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn info_iter(bytes: &[u8]) -> Result<ListIter<'_, EncodingInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 22;
+        Ok(ListIter::padded(bytes, index, len0 as usize, 4, "info"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("info_len", 8, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.info == other.info
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("info", &self.info, &other.info, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryEncodingsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.info.len() as Card16).as_bytes(&mut bytes[index..]);
-        index += 22;
-        let block_len: usize = vector_as_bytes(&self.info, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 22);
+        let block_len: usize = padded_vector_as_bytes(&self.info, &mut bytes[index..], 4);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<EncodingInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<EncodingInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryEncodingsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card16, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 22;
         let (info, block_len): (Vec<EncodingInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 4, "info")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EncodingInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryEncodingsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1288,80 +2076,211 @@ impl AsByteSequence for QueryEncodingsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card16>()
-            + 22
-            + {
-                let block_len: usize = self.info.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<EncodingInfo>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self
+                .info
+                .iter()
+                .map(|i| {
+                    let size = i.size();
+                    size + buffer_pad(size, 4)
+                })
+                .sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<EncodingInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for QueryEncodingsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GrabPortRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
     pub time: Timestamp,
 }
-impl GrabPortRequest {}
-impl AsByteSequence for GrabPortRequest {
+impl GrabPortRequest {
+    #[inline]
+    pub fn builder() -> GrabPortRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(bytes: &[u8]) -> Result<Result<GrabPortReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<GrabPortRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_port_time_request_asb {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.port.as_bytes(&mut bytes[index..]);
+                index += self.time.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
+                index += sz;
+                let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        port: port,
+                        time: time,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 8 + self.time.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_port_time_request_asb! { GrabPortRequest }
+impl Request for GrabPortRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 3;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabPort";
+    type Reply = GrabPortReply;
+}
+#[doc = " A builder for `GrabPortRequest`."]
+#[derive(Clone, Debug)]
+pub struct GrabPortRequestBuilder {
+    pub inner: GrabPortRequest,
+}
+impl GrabPortRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index += self.time.as_bytes(&mut bytes[index..]);
-        index
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GrabPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GrabPortRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-                time: time,
-            },
-            index,
-        ))
+    pub fn time(mut self, time: Timestamp) -> Self {
+        self.inner.time = time;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size() + self.time.size()
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GrabPortRequest {
+        self.inner
     }
 }
-impl Request for GrabPortRequest {
-    const OPCODE: u8 = 3;
-    const EXTENSION: Option<&'static str> = Some("XVideo");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = GrabPortReply;
+impl Default for GrabPortRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GrabPortRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                time: Default::default(),
+            },
+        }
+    }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GrabPortReply {
     pub reply_type: u8,
     pub result: GrabPortStatus,
     pub sequence: u16,
     pub length: u32,
 }
-impl GrabPortReply {}
+impl GrabPortReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        let result: GrabPortStatus = read_item(reader, &mut index, 1, "result")?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GrabPortReply {
+            reply_type: reply_type,
+            result: result,
+            sequence: sequence,
+            length: length,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("result", 1, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.result == other.result
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("result", &self.result, &other.result, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for GrabPortReply {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
@@ -1370,21 +2289,23 @@ impl AsByteSequence for GrabPortReply {
         index += self.result.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabPortReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
-        let (result, sz): (GrabPortStatus, usize) = <GrabPortStatus>::from_bytes(&bytes[index..])?;
+        let (result, sz): (GrabPortStatus, usize) = parse_item(bytes, index, "result")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        index += buffer_pad(index, 4);
+        Ok((
             GrabPortReply {
                 reply_type: reply_type,
                 result: result,
@@ -1396,11 +2317,21 @@ impl AsByteSequence for GrabPortReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + self.result.size() + self.sequence.size() + self.length.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GrabPortReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub enum GrabPortStatus {
     Success = 0,
     BadExtension = 1,
@@ -1414,17 +2345,16 @@ impl AsByteSequence for GrabPortStatus {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         (*self as u8).as_bytes(bytes)
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
         match underlying {
-            0 => Some((Self::Success, sz)),
-            1 => Some((Self::BadExtension, sz)),
-            2 => Some((Self::AlreadyGrabbed, sz)),
-            3 => Some((Self::InvalidTime, sz)),
-            4 => Some((Self::BadReply, sz)),
-            5 => Some((Self::BadAlloc, sz)),
-            _ => None,
+            0 => Ok((Self::Success, sz)),
+            1 => Ok((Self::BadExtension, sz)),
+            2 => Ok((Self::AlreadyGrabbed, sz)),
+            3 => Ok((Self::InvalidTime, sz)),
+            4 => Ok((Self::BadReply, sz)),
+            5 => Ok((Self::BadAlloc, sz)),
+            _ => Err(unreadable::<Self>()),
         }
     }
     #[inline]
@@ -1432,66 +2362,108 @@ impl AsByteSequence for GrabPortStatus {
         ::core::mem::size_of::<u8>()
     }
 }
+impl core::convert::TryFrom<&[u8]> for GrabPortStatus {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
 impl Default for GrabPortStatus {
     #[inline]
     fn default() -> GrabPortStatus {
         GrabPortStatus::Success
     }
 }
-#[derive(Clone, Debug, Default)]
+impl From<GrabPortStatus> for u8 {
+    #[inline]
+    fn from(base: GrabPortStatus) -> Self {
+        base as u8
+    }
+}
+impl core::convert::TryFrom<u8> for GrabPortStatus {
+    type Error = u8;
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Self::Success),
+            1 => Ok(Self::BadExtension),
+            2 => Ok(Self::AlreadyGrabbed),
+            3 => Ok(Self::InvalidTime),
+            4 => Ok(Self::BadReply),
+            5 => Ok(Self::BadAlloc),
+            _ => Err(value),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct UngrabPortRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
     pub time: Timestamp,
 }
-impl UngrabPortRequest {}
-impl AsByteSequence for UngrabPortRequest {
+impl UngrabPortRequest {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index += self.time.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing UngrabPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            UngrabPortRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-                time: time,
-            },
-            index,
-        ))
-    }
-    #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size() + self.time.size()
+    pub fn builder() -> UngrabPortRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
 }
+const _: () = assert!(<UngrabPortRequest as Request>::MINOR_OPCODE < 0x100);
+impl_port_time_request_asb! { UngrabPortRequest }
 impl Request for UngrabPortRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabPort";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `UngrabPortRequest`."]
+#[derive(Clone, Debug)]
+pub struct UngrabPortRequestBuilder {
+    pub inner: UngrabPortRequest,
+}
+impl UngrabPortRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn time(mut self, time: Timestamp) -> Self {
+        self.inner.time = time;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> UngrabPortRequest {
+        self.inner
+    }
+}
+impl Default for UngrabPortRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UngrabPortRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                time: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PutVideoRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1507,102 +2479,209 @@ pub struct PutVideoRequest {
     pub drw_w: Card16,
     pub drw_h: Card16,
 }
-impl PutVideoRequest {}
-impl AsByteSequence for PutVideoRequest {
+impl PutVideoRequest {
+    #[inline]
+    pub fn builder() -> PutVideoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<PutVideoRequest as Request>::MINOR_OPCODE < 0x100);
+macro_rules! impl_request_asb_ad6a1476 {
+    ($ name : ident) => {
+        impl AsByteSequence for $name {
+            fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+                let mut index: usize = 0;
+                index += self.req_type.as_bytes(&mut bytes[index..]);
+                index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
+                index += self.length.as_bytes(&mut bytes[index..]);
+                index += self.port.as_bytes(&mut bytes[index..]);
+                index += self.drawable.as_bytes(&mut bytes[index..]);
+                index += self.gc.as_bytes(&mut bytes[index..]);
+                index += self.vid_x.as_bytes(&mut bytes[index..]);
+                index += self.vid_y.as_bytes(&mut bytes[index..]);
+                index += self.vid_w.as_bytes(&mut bytes[index..]);
+                index += self.vid_h.as_bytes(&mut bytes[index..]);
+                index += self.drw_x.as_bytes(&mut bytes[index..]);
+                index += self.drw_y.as_bytes(&mut bytes[index..]);
+                index += self.drw_w.as_bytes(&mut bytes[index..]);
+                index += self.drw_h.as_bytes(&mut bytes[index..]);
+                index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
+                index
+            }
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+                let mut index: usize = 0;
+                log::trace!(concat!(
+                    "Deserializing ",
+                    stringify!($name),
+                    " from byte buffer"
+                ));
+                let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+                index += sz;
+                index += 1;
+                let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+                index += sz;
+                let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
+                index += sz;
+                let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
+                index += sz;
+                let (gc, sz): (Gcontext, usize) = parse_item(bytes, index, "gc")?;
+                index += sz;
+                let (vid_x, sz): (Int16, usize) = parse_item(bytes, index, "vid_x")?;
+                index += sz;
+                let (vid_y, sz): (Int16, usize) = parse_item(bytes, index, "vid_y")?;
+                index += sz;
+                let (vid_w, sz): (Card16, usize) = parse_item(bytes, index, "vid_w")?;
+                index += sz;
+                let (vid_h, sz): (Card16, usize) = parse_item(bytes, index, "vid_h")?;
+                index += sz;
+                let (drw_x, sz): (Int16, usize) = parse_item(bytes, index, "drw_x")?;
+                index += sz;
+                let (drw_y, sz): (Int16, usize) = parse_item(bytes, index, "drw_y")?;
+                index += sz;
+                let (drw_w, sz): (Card16, usize) = parse_item(bytes, index, "drw_w")?;
+                index += sz;
+                let (drw_h, sz): (Card16, usize) = parse_item(bytes, index, "drw_h")?;
+                index += sz;
+                index += buffer_pad(index, 4);
+                Ok((
+                    $name {
+                        req_type: req_type,
+                        length: length,
+                        port: port,
+                        drawable: drawable,
+                        gc: gc,
+                        vid_x: vid_x,
+                        vid_y: vid_y,
+                        vid_w: vid_w,
+                        vid_h: vid_h,
+                        drw_x: drw_x,
+                        drw_y: drw_y,
+                        drw_w: drw_w,
+                        drw_h: drw_h,
+                    },
+                    index,
+                ))
+            }
+            #[inline]
+            fn size(&self) -> usize {
+                let size: usize = 24 + self.drawable.size() + self.gc.size();
+                size + buffer_pad(size, 4)
+            }
+        }
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                Self::from_bytes(bytes).map(|(value, _)| value)
+            }
+        }
+    };
+}
+impl_request_asb_ad6a1476! { PutVideoRequest }
+impl Request for PutVideoRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 5;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutVideo";
+    type Reply = ();
+}
+#[doc = " A builder for `PutVideoRequest`."]
+#[derive(Clone, Debug)]
+pub struct PutVideoRequestBuilder {
+    pub inner: PutVideoRequest,
+}
+impl PutVideoRequestBuilder {
     #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index += self.gc.as_bytes(&mut bytes[index..]);
-        index += self.vid_x.as_bytes(&mut bytes[index..]);
-        index += self.vid_y.as_bytes(&mut bytes[index..]);
-        index += self.vid_w.as_bytes(&mut bytes[index..]);
-        index += self.vid_h.as_bytes(&mut bytes[index..]);
-        index += self.drw_x.as_bytes(&mut bytes[index..]);
-        index += self.drw_y.as_bytes(&mut bytes[index..]);
-        index += self.drw_w.as_bytes(&mut bytes[index..]);
-        index += self.drw_h.as_bytes(&mut bytes[index..]);
-        index
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PutVideoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PutVideoRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-                drawable: drawable,
-                gc: gc,
-                vid_x: vid_x,
-                vid_y: vid_y,
-                vid_w: vid_w,
-                vid_h: vid_h,
-                drw_x: drw_x,
-                drw_y: drw_y,
-                drw_w: drw_w,
-                drw_h: drw_h,
-            },
-            index,
-        ))
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
     }
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.drawable.size()
-            + self.gc.size()
-            + self.vid_x.size()
-            + self.vid_y.size()
-            + self.vid_w.size()
-            + self.vid_h.size()
-            + self.drw_x.size()
-            + self.drw_y.size()
-            + self.drw_w.size()
-            + self.drw_h.size()
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn vid_x(mut self, vid_x: Int16) -> Self {
+        self.inner.vid_x = vid_x;
+        self
+    }
+    #[inline]
+    pub fn vid_y(mut self, vid_y: Int16) -> Self {
+        self.inner.vid_y = vid_y;
+        self
+    }
+    #[inline]
+    pub fn vid_w(mut self, vid_w: Card16) -> Self {
+        self.inner.vid_w = vid_w;
+        self
+    }
+    #[inline]
+    pub fn vid_h(mut self, vid_h: Card16) -> Self {
+        self.inner.vid_h = vid_h;
+        self
+    }
+    #[inline]
+    pub fn drw_x(mut self, drw_x: Int16) -> Self {
+        self.inner.drw_x = drw_x;
+        self
+    }
+    #[inline]
+    pub fn drw_y(mut self, drw_y: Int16) -> Self {
+        self.inner.drw_y = drw_y;
+        self
+    }
+    #[inline]
+    pub fn drw_w(mut self, drw_w: Card16) -> Self {
+        self.inner.drw_w = drw_w;
+        self
+    }
+    #[inline]
+    pub fn drw_h(mut self, drw_h: Card16) -> Self {
+        self.inner.drw_h = drw_h;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PutVideoRequest {
+        self.inner
+    }
+}
+impl Default for PutVideoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PutVideoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                drawable: Default::default(),
+                gc: Default::default(),
+                vid_x: Default::default(),
+                vid_y: Default::default(),
+                vid_w: Default::default(),
+                vid_h: Default::default(),
+                drw_x: Default::default(),
+                drw_y: Default::default(),
+                drw_w: Default::default(),
+                drw_h: Default::default(),
+            },
+        }
     }
 }
-impl Request for PutVideoRequest {
-    const OPCODE: u8 = 5;
-    const EXTENSION: Option<&'static str> = Some("XVideo");
-    const REPLY_EXPECTS_FDS: bool = false;
-    type Reply = ();
-}
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PutStillRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1618,102 +2697,118 @@ pub struct PutStillRequest {
     pub drw_w: Card16,
     pub drw_h: Card16,
 }
-impl PutStillRequest {}
-impl AsByteSequence for PutStillRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index += self.gc.as_bytes(&mut bytes[index..]);
-        index += self.vid_x.as_bytes(&mut bytes[index..]);
-        index += self.vid_y.as_bytes(&mut bytes[index..]);
-        index += self.vid_w.as_bytes(&mut bytes[index..]);
-        index += self.vid_h.as_bytes(&mut bytes[index..]);
-        index += self.drw_x.as_bytes(&mut bytes[index..]);
-        index += self.drw_y.as_bytes(&mut bytes[index..]);
-        index += self.drw_w.as_bytes(&mut bytes[index..]);
-        index += self.drw_h.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing PutStillRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            PutStillRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-                drawable: drawable,
-                gc: gc,
-                vid_x: vid_x,
-                vid_y: vid_y,
-                vid_w: vid_w,
-                vid_h: vid_h,
-                drw_x: drw_x,
-                drw_y: drw_y,
-                drw_w: drw_w,
-                drw_h: drw_h,
-            },
-            index,
-        ))
-    }
+impl PutStillRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.drawable.size()
-            + self.gc.size()
-            + self.vid_x.size()
-            + self.vid_y.size()
-            + self.vid_w.size()
-            + self.vid_h.size()
-            + self.drw_x.size()
-            + self.drw_y.size()
-            + self.drw_w.size()
-            + self.drw_h.size()
+    pub fn builder() -> PutStillRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
 }
+const _: () = assert!(<PutStillRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_ad6a1476! { PutStillRequest }
 impl Request for PutStillRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutStill";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `PutStillRequest`."]
+#[derive(Clone, Debug)]
+pub struct PutStillRequestBuilder {
+    pub inner: PutStillRequest,
+}
+impl PutStillRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn vid_x(mut self, vid_x: Int16) -> Self {
+        self.inner.vid_x = vid_x;
+        self
+    }
+    #[inline]
+    pub fn vid_y(mut self, vid_y: Int16) -> Self {
+        self.inner.vid_y = vid_y;
+        self
+    }
+    #[inline]
+    pub fn vid_w(mut self, vid_w: Card16) -> Self {
+        self.inner.vid_w = vid_w;
+        self
+    }
+    #[inline]
+    pub fn vid_h(mut self, vid_h: Card16) -> Self {
+        self.inner.vid_h = vid_h;
+        self
+    }
+    #[inline]
+    pub fn drw_x(mut self, drw_x: Int16) -> Self {
+        self.inner.drw_x = drw_x;
+        self
+    }
+    #[inline]
+    pub fn drw_y(mut self, drw_y: Int16) -> Self {
+        self.inner.drw_y = drw_y;
+        self
+    }
+    #[inline]
+    pub fn drw_w(mut self, drw_w: Card16) -> Self {
+        self.inner.drw_w = drw_w;
+        self
+    }
+    #[inline]
+    pub fn drw_h(mut self, drw_h: Card16) -> Self {
+        self.inner.drw_h = drw_h;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PutStillRequest {
+        self.inner
+    }
+}
+impl Default for PutStillRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PutStillRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                drawable: Default::default(),
+                gc: Default::default(),
+                vid_x: Default::default(),
+                vid_y: Default::default(),
+                vid_w: Default::default(),
+                vid_h: Default::default(),
+                drw_x: Default::default(),
+                drw_y: Default::default(),
+                drw_w: Default::default(),
+                drw_h: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetVideoRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1728,103 +2823,119 @@ pub struct GetVideoRequest {
     pub drw_y: Int16,
     pub drw_w: Card16,
     pub drw_h: Card16,
-}
-impl GetVideoRequest {}
-impl AsByteSequence for GetVideoRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index += self.gc.as_bytes(&mut bytes[index..]);
-        index += self.vid_x.as_bytes(&mut bytes[index..]);
-        index += self.vid_y.as_bytes(&mut bytes[index..]);
-        index += self.vid_w.as_bytes(&mut bytes[index..]);
-        index += self.vid_h.as_bytes(&mut bytes[index..]);
-        index += self.drw_x.as_bytes(&mut bytes[index..]);
-        index += self.drw_y.as_bytes(&mut bytes[index..]);
-        index += self.drw_w.as_bytes(&mut bytes[index..]);
-        index += self.drw_h.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetVideoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetVideoRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-                drawable: drawable,
-                gc: gc,
-                vid_x: vid_x,
-                vid_y: vid_y,
-                vid_w: vid_w,
-                vid_h: vid_h,
-                drw_x: drw_x,
-                drw_y: drw_y,
-                drw_w: drw_w,
-                drw_h: drw_h,
-            },
-            index,
-        ))
-    }
+}
+impl GetVideoRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.drawable.size()
-            + self.gc.size()
-            + self.vid_x.size()
-            + self.vid_y.size()
-            + self.vid_w.size()
-            + self.vid_h.size()
-            + self.drw_x.size()
-            + self.drw_y.size()
-            + self.drw_w.size()
-            + self.drw_h.size()
+    pub fn builder() -> GetVideoRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
 }
+const _: () = assert!(<GetVideoRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_ad6a1476! { GetVideoRequest }
 impl Request for GetVideoRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVideo";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetVideoRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetVideoRequestBuilder {
+    pub inner: GetVideoRequest,
+}
+impl GetVideoRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn vid_x(mut self, vid_x: Int16) -> Self {
+        self.inner.vid_x = vid_x;
+        self
+    }
+    #[inline]
+    pub fn vid_y(mut self, vid_y: Int16) -> Self {
+        self.inner.vid_y = vid_y;
+        self
+    }
+    #[inline]
+    pub fn vid_w(mut self, vid_w: Card16) -> Self {
+        self.inner.vid_w = vid_w;
+        self
+    }
+    #[inline]
+    pub fn vid_h(mut self, vid_h: Card16) -> Self {
+        self.inner.vid_h = vid_h;
+        self
+    }
+    #[inline]
+    pub fn drw_x(mut self, drw_x: Int16) -> Self {
+        self.inner.drw_x = drw_x;
+        self
+    }
+    #[inline]
+    pub fn drw_y(mut self, drw_y: Int16) -> Self {
+        self.inner.drw_y = drw_y;
+        self
+    }
+    #[inline]
+    pub fn drw_w(mut self, drw_w: Card16) -> Self {
+        self.inner.drw_w = drw_w;
+        self
+    }
+    #[inline]
+    pub fn drw_h(mut self, drw_h: Card16) -> Self {
+        self.inner.drw_h = drw_h;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetVideoRequest {
+        self.inner
+    }
+}
+impl Default for GetVideoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetVideoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                drawable: Default::default(),
+                gc: Default::default(),
+                vid_x: Default::default(),
+                vid_y: Default::default(),
+                vid_w: Default::default(),
+                vid_h: Default::default(),
+                drw_x: Default::default(),
+                drw_y: Default::default(),
+                drw_w: Default::default(),
+                drw_h: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetStillRequest {
     pub req_type: u8,
     pub length: u16,
@@ -1840,134 +2951,159 @@ pub struct GetStillRequest {
     pub drw_w: Card16,
     pub drw_h: Card16,
 }
-impl GetStillRequest {}
-impl AsByteSequence for GetStillRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
-        index += self.gc.as_bytes(&mut bytes[index..]);
-        index += self.vid_x.as_bytes(&mut bytes[index..]);
-        index += self.vid_y.as_bytes(&mut bytes[index..]);
-        index += self.vid_w.as_bytes(&mut bytes[index..]);
-        index += self.vid_h.as_bytes(&mut bytes[index..]);
-        index += self.drw_x.as_bytes(&mut bytes[index..]);
-        index += self.drw_y.as_bytes(&mut bytes[index..]);
-        index += self.drw_w.as_bytes(&mut bytes[index..]);
-        index += self.drw_h.as_bytes(&mut bytes[index..]);
-        index
-    }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing GetStillRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            GetStillRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-                drawable: drawable,
-                gc: gc,
-                vid_x: vid_x,
-                vid_y: vid_y,
-                vid_w: vid_w,
-                vid_h: vid_h,
-                drw_x: drw_x,
-                drw_y: drw_y,
-                drw_w: drw_w,
-                drw_h: drw_h,
-            },
-            index,
-        ))
-    }
+impl GetStillRequest {
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.drawable.size()
-            + self.gc.size()
-            + self.vid_x.size()
-            + self.vid_y.size()
-            + self.vid_w.size()
-            + self.vid_h.size()
-            + self.drw_x.size()
-            + self.drw_y.size()
-            + self.drw_w.size()
-            + self.drw_h.size()
+    pub fn builder() -> GetStillRequestBuilder {
+        Default::default()
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
 }
+const _: () = assert!(<GetStillRequest as Request>::MINOR_OPCODE < 0x100);
+impl_request_asb_ad6a1476! { GetStillRequest }
 impl Request for GetStillRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetStill";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetStillRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetStillRequestBuilder {
+    pub inner: GetStillRequest,
+}
+impl GetStillRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn vid_x(mut self, vid_x: Int16) -> Self {
+        self.inner.vid_x = vid_x;
+        self
+    }
+    #[inline]
+    pub fn vid_y(mut self, vid_y: Int16) -> Self {
+        self.inner.vid_y = vid_y;
+        self
+    }
+    #[inline]
+    pub fn vid_w(mut self, vid_w: Card16) -> Self {
+        self.inner.vid_w = vid_w;
+        self
+    }
+    #[inline]
+    pub fn vid_h(mut self, vid_h: Card16) -> Self {
+        self.inner.vid_h = vid_h;
+        self
+    }
+    #[inline]
+    pub fn drw_x(mut self, drw_x: Int16) -> Self {
+        self.inner.drw_x = drw_x;
+        self
+    }
+    #[inline]
+    pub fn drw_y(mut self, drw_y: Int16) -> Self {
+        self.inner.drw_y = drw_y;
+        self
+    }
+    #[inline]
+    pub fn drw_w(mut self, drw_w: Card16) -> Self {
+        self.inner.drw_w = drw_w;
+        self
+    }
+    #[inline]
+    pub fn drw_h(mut self, drw_h: Card16) -> Self {
+        self.inner.drw_h = drw_h;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetStillRequest {
+        self.inner
+    }
+}
+impl Default for GetStillRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetStillRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                drawable: Default::default(),
+                gc: Default::default(),
+                vid_x: Default::default(),
+                vid_y: Default::default(),
+                vid_w: Default::default(),
+                vid_h: Default::default(),
+                drw_x: Default::default(),
+                drw_y: Default::default(),
+                drw_w: Default::default(),
+                drw_h: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct StopVideoRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
     pub drawable: Drawable,
 }
-impl StopVideoRequest {}
-impl AsByteSequence for StopVideoRequest {
+impl StopVideoRequest {
     #[inline]
+    pub fn builder() -> StopVideoRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<StopVideoRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for StopVideoRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing StopVideoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             StopVideoRequest {
                 req_type: req_type,
                 length: length,
@@ -1979,50 +3115,106 @@ impl AsByteSequence for StopVideoRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size() + self.drawable.size()
+        let size: usize = 8 + self.drawable.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for StopVideoRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for StopVideoRequest {
-    const OPCODE: u8 = 9;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 9;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "StopVideo";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `StopVideoRequest`."]
+#[derive(Clone, Debug)]
+pub struct StopVideoRequestBuilder {
+    pub inner: StopVideoRequest,
+}
+impl StopVideoRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> StopVideoRequest {
+        self.inner
+    }
+}
+impl Default for StopVideoRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: StopVideoRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                drawable: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectVideoNotifyRequest {
     pub req_type: u8,
     pub length: u16,
     pub drawable: Drawable,
     pub onoff: bool,
 }
-impl SelectVideoNotifyRequest {}
-impl AsByteSequence for SelectVideoNotifyRequest {
+impl SelectVideoNotifyRequest {
     #[inline]
+    pub fn builder() -> SelectVideoNotifyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<SelectVideoNotifyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectVideoNotifyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.onoff.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectVideoNotifyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (onoff, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (onoff, sz): (bool, usize) = parse_item(bytes, index, "onoff")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectVideoNotifyRequest {
                 req_type: req_type,
                 length: length,
@@ -2034,50 +3226,111 @@ impl AsByteSequence for SelectVideoNotifyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.drawable.size() + self.onoff.size() + 3
+        let size: usize = 7 + self.drawable.size() + self.onoff.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectVideoNotifyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectVideoNotifyRequest {
-    const OPCODE: u8 = 10;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 10;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectVideoNotify";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SelectVideoNotifyRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectVideoNotifyRequestBuilder {
+    pub inner: SelectVideoNotifyRequest,
+}
+impl SelectVideoNotifyRequestBuilder {
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn onoff(mut self, onoff: bool) -> Self {
+        self.inner.onoff = onoff;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectVideoNotifyRequest {
+        self.inner
+    }
+}
+impl Default for SelectVideoNotifyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectVideoNotifyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                drawable: Default::default(),
+                onoff: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SelectPortNotifyRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
     pub onoff: bool,
 }
-impl SelectPortNotifyRequest {}
-impl AsByteSequence for SelectPortNotifyRequest {
+impl SelectPortNotifyRequest {
     #[inline]
+    pub fn builder() -> SelectPortNotifyRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("port", 4, 4),
+        ("onoff", 8, 1),
+    ];
+}
+const _: () = assert!(<SelectPortNotifyRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SelectPortNotifyRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.onoff.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectPortNotifyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (onoff, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (onoff, sz): (bool, usize) = parse_item(bytes, index, "onoff")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SelectPortNotifyRequest {
                 req_type: req_type,
                 length: length,
@@ -2089,16 +3342,64 @@ impl AsByteSequence for SelectPortNotifyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size() + self.onoff.size() + 3
+        let size: usize = 11 + self.onoff.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SelectPortNotifyRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SelectPortNotifyRequest {
-    const OPCODE: u8 = 11;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 11;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectPortNotify";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SelectPortNotifyRequest`."]
+#[derive(Clone, Debug)]
+pub struct SelectPortNotifyRequestBuilder {
+    pub inner: SelectPortNotifyRequest,
+}
+impl SelectPortNotifyRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn onoff(mut self, onoff: bool) -> Self {
+        self.inner.onoff = onoff;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SelectPortNotifyRequest {
+        self.inner
+    }
+}
+impl Default for SelectPortNotifyRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SelectPortNotifyRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                onoff: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryBestSizeRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2109,13 +3410,37 @@ pub struct QueryBestSizeRequest {
     pub drw_h: Card16,
     pub motion: bool,
 }
-impl QueryBestSizeRequest {}
-impl AsByteSequence for QueryBestSizeRequest {
+impl QueryBestSizeRequest {
+    #[inline]
+    pub fn builder() -> QueryBestSizeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryBestSizeReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("port", 4, 4),
+        ("vid_w", 8, 2),
+        ("vid_h", 10, 2),
+        ("drw_w", 12, 2),
+        ("drw_h", 14, 2),
+        ("motion", 16, 1),
+    ];
+}
+const _: () = assert!(<QueryBestSizeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryBestSizeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.vid_w.as_bytes(&mut bytes[index..]);
@@ -2123,32 +3448,33 @@ impl AsByteSequence for QueryBestSizeRequest {
         index += self.drw_w.as_bytes(&mut bytes[index..]);
         index += self.drw_h.as_bytes(&mut bytes[index..]);
         index += self.motion.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryBestSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_w, sz): (Card16, usize) = parse_item(bytes, index, "vid_w")?;
         index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_h, sz): (Card16, usize) = parse_item(bytes, index, "vid_h")?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = parse_item(bytes, index, "drw_w")?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = parse_item(bytes, index, "drw_h")?;
         index += sz;
-        let (motion, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (motion, sz): (bool, usize) = parse_item(bytes, index, "motion")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryBestSizeRequest {
                 req_type: req_type,
                 length: length,
@@ -2164,25 +3490,87 @@ impl AsByteSequence for QueryBestSizeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.vid_w.size()
-            + self.vid_h.size()
-            + self.drw_w.size()
-            + self.drw_h.size()
-            + self.motion.size()
-            + 3
+        let size: usize = 19 + self.motion.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryBestSizeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryBestSizeRequest {
-    const OPCODE: u8 = 12;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 12;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryBestSize";
     type Reply = QueryBestSizeReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryBestSizeRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryBestSizeRequestBuilder {
+    pub inner: QueryBestSizeRequest,
+}
+impl QueryBestSizeRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn vid_w(mut self, vid_w: Card16) -> Self {
+        self.inner.vid_w = vid_w;
+        self
+    }
+    #[inline]
+    pub fn vid_h(mut self, vid_h: Card16) -> Self {
+        self.inner.vid_h = vid_h;
+        self
+    }
+    #[inline]
+    pub fn drw_w(mut self, drw_w: Card16) -> Self {
+        self.inner.drw_w = drw_w;
+        self
+    }
+    #[inline]
+    pub fn drw_h(mut self, drw_h: Card16) -> Self {
+        self.inner.drw_h = drw_h;
+        self
+    }
+    #[inline]
+    pub fn motion(mut self, motion: bool) -> Self {
+        self.inner.motion = motion;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryBestSizeRequest {
+        self.inner
+    }
+}
+impl Default for QueryBestSizeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryBestSizeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                vid_w: Default::default(),
+                vid_h: Default::default(),
+                drw_w: Default::default(),
+                drw_h: Default::default(),
+                motion: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryBestSizeReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2190,35 +3578,105 @@ pub struct QueryBestSizeReply {
     pub actual_width: Card16,
     pub actual_height: Card16,
 }
-impl QueryBestSizeReply {}
-impl AsByteSequence for QueryBestSizeReply {
+impl QueryBestSizeReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let actual_width: Card16 = read_item(reader, &mut index, 2, "actual_width")?;
+        let actual_height: Card16 = read_item(reader, &mut index, 2, "actual_height")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryBestSizeReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            actual_width: actual_width,
+            actual_height: actual_height,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("actual_width", 8, 2),
+        ("actual_height", 10, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.actual_width == other.actual_width && self.actual_height == other.actual_height
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "actual_width",
+            &self.actual_width,
+            &other.actual_width,
+            &mut changes,
+        );
+        diff_value(
+            "actual_height",
+            &self.actual_height,
+            &other.actual_height,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryBestSizeReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.actual_width.as_bytes(&mut bytes[index..]);
         index += self.actual_height.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryBestSizeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (actual_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (actual_width, sz): (Card16, usize) = parse_item(bytes, index, "actual_width")?;
         index += sz;
-        let (actual_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (actual_height, sz): (Card16, usize) = parse_item(bytes, index, "actual_height")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryBestSizeReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2231,15 +3689,21 @@ impl AsByteSequence for QueryBestSizeReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.actual_width.size()
-            + self.actual_height.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryBestSizeReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct SetPortAttributeRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2247,35 +3711,44 @@ pub struct SetPortAttributeRequest {
     pub attribute: Atom,
     pub value: Int32,
 }
-impl SetPortAttributeRequest {}
-impl AsByteSequence for SetPortAttributeRequest {
+impl SetPortAttributeRequest {
     #[inline]
+    pub fn builder() -> SetPortAttributeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<SetPortAttributeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for SetPortAttributeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.attribute.as_bytes(&mut bytes[index..]);
         index += self.value.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPortAttributeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (attribute, sz): (Atom, usize) = parse_item(bytes, index, "attribute")?;
         index += sz;
-        let (value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int32, usize) = parse_item(bytes, index, "value")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             SetPortAttributeRequest {
                 req_type: req_type,
                 length: length,
@@ -2288,53 +3761,119 @@ impl AsByteSequence for SetPortAttributeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.attribute.size()
-            + self.value.size()
+        let size: usize = 12 + self.attribute.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SetPortAttributeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for SetPortAttributeRequest {
-    const OPCODE: u8 = 13;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 13;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPortAttribute";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `SetPortAttributeRequest`."]
+#[derive(Clone, Debug)]
+pub struct SetPortAttributeRequestBuilder {
+    pub inner: SetPortAttributeRequest,
+}
+impl SetPortAttributeRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn attribute(mut self, attribute: Atom) -> Self {
+        self.inner.attribute = attribute;
+        self
+    }
+    #[inline]
+    pub fn value(mut self, value: Int32) -> Self {
+        self.inner.value = value;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> SetPortAttributeRequest {
+        self.inner
+    }
+}
+impl Default for SetPortAttributeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: SetPortAttributeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                attribute: Default::default(),
+                value: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct GetPortAttributeRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
     pub attribute: Atom,
 }
-impl GetPortAttributeRequest {}
-impl AsByteSequence for GetPortAttributeRequest {
+impl GetPortAttributeRequest {
+    #[inline]
+    pub fn builder() -> GetPortAttributeRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<GetPortAttributeReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<GetPortAttributeRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for GetPortAttributeRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.attribute.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPortAttributeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (attribute, sz): (Atom, usize) = parse_item(bytes, index, "attribute")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetPortAttributeRequest {
                 req_type: req_type,
                 length: length,
@@ -2346,48 +3885,151 @@ impl AsByteSequence for GetPortAttributeRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size() + self.attribute.size()
+        let size: usize = 8 + self.attribute.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetPortAttributeRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for GetPortAttributeRequest {
-    const OPCODE: u8 = 14;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 14;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPortAttribute";
     type Reply = GetPortAttributeReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `GetPortAttributeRequest`."]
+#[derive(Clone, Debug)]
+pub struct GetPortAttributeRequestBuilder {
+    pub inner: GetPortAttributeRequest,
+}
+impl GetPortAttributeRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn attribute(mut self, attribute: Atom) -> Self {
+        self.inner.attribute = attribute;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> GetPortAttributeRequest {
+        self.inner
+    }
+}
+impl Default for GetPortAttributeRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: GetPortAttributeRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                attribute: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct GetPortAttributeReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub value: Int32,
 }
-impl GetPortAttributeReply {}
-impl AsByteSequence for GetPortAttributeReply {
+impl GetPortAttributeReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
     #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 12;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let value: Int32 = read_item(reader, &mut index, 4, "value")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(GetPortAttributeReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            value: value,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("value", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("value", &self.value, &other.value, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for GetPortAttributeReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.value.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPortAttributeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (value, sz): (Int32, usize) = parse_item(bytes, index, "value")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             GetPortAttributeReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2399,58 +4041,85 @@ impl AsByteSequence for GetPortAttributeReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + 1 + self.sequence.size() + self.length.size() + self.value.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for GetPortAttributeReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryPortAttributesRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
 }
-impl QueryPortAttributesRequest {}
-impl AsByteSequence for QueryPortAttributesRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl QueryPortAttributesRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing QueryPortAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            QueryPortAttributesRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-            },
-            index,
-        ))
+    pub fn builder() -> QueryPortAttributesRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryPortAttributesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
 }
+const _: () = assert!(<QueryPortAttributesRequest as Request>::MINOR_OPCODE < 0x100);
+impl_port_only_request_asb! { QueryPortAttributesRequest }
 impl Request for QueryPortAttributesRequest {
-    const OPCODE: u8 = 15;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 15;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryPortAttributes";
     type Reply = QueryPortAttributesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryPortAttributesRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryPortAttributesRequestBuilder {
+    pub inner: QueryPortAttributesRequest,
+}
+impl QueryPortAttributesRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryPortAttributesRequest {
+        self.inner
+    }
+}
+impl Default for QueryPortAttributesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryPortAttributesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryPortAttributesReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2458,44 +4127,110 @@ pub struct QueryPortAttributesReply {
     pub text_size: Card32,
     pub attributes: Vec<AttributeInfo>,
 }
-impl QueryPortAttributesReply {}
-impl AsByteSequence for QueryPortAttributesReply {
+impl QueryPortAttributesReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn attributes_iter(bytes: &[u8]) -> Result<ListIter<'_, AttributeInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        let (text_size, sz): (Card32, usize) = parse_item(bytes, index, "text_size")?;
+        index += sz;
+        index += 16;
+        Ok(ListIter::padded(
+            bytes,
+            index,
+            len0 as usize,
+            4,
+            "attributes",
+        ))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("attributes_len", 8, 4),
+        ("text_size", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.text_size == other.text_size && self.attributes == other.attributes
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("text_size", &self.text_size, &other.text_size, &mut changes);
+        diff_list(
+            "attributes",
+            &self.attributes,
+            &other.attributes,
+            &mut changes,
+        );
+        changes
+    }
+}
+impl AsByteSequence for QueryPortAttributesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.attributes.len() as Card32).as_bytes(&mut bytes[index..]);
         index += self.text_size.as_bytes(&mut bytes[index..]);
-        index += 16;
-        let block_len: usize = vector_as_bytes(&self.attributes, &mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 16);
+        let block_len: usize = padded_vector_as_bytes(&self.attributes, &mut bytes[index..], 4);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<AttributeInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<AttributeInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPortAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
-        let (text_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (text_size, sz): (Card32, usize) = parse_item(bytes, index, "text_size")?;
         index += sz;
         index += 16;
         let (attributes, block_len): (Vec<AttributeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_padded_vector(bytes, index, len0 as usize, 4, "attributes")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AttributeInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryPortAttributesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2508,110 +4243,188 @@ impl AsByteSequence for QueryPortAttributesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + self.text_size.size()
-            + 16
-            + {
-                let block_len: usize = self.attributes.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<AttributeInfo>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self
+                .attributes
+                .iter()
+                .map(|i| {
+                    let size = i.size();
+                    size + buffer_pad(size, 4)
+                })
+                .sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<AttributeInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryPortAttributesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListImageFormatsRequest {
     pub req_type: u8,
     pub length: u16,
     pub port: Port,
 }
-impl ListImageFormatsRequest {}
-impl AsByteSequence for ListImageFormatsRequest {
-    #[inline]
-    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
-        let mut index: usize = 0;
-        index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
-        index += self.length.as_bytes(&mut bytes[index..]);
-        index += self.port.as_bytes(&mut bytes[index..]);
-        index
-    }
+impl ListImageFormatsRequest {
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        let mut index: usize = 0;
-        log::trace!("Deserializing ListImageFormatsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
-        index += sz;
-        index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
-        index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
-        index += sz;
-        Some((
-            ListImageFormatsRequest {
-                req_type: req_type,
-                length: length,
-                port: port,
-            },
-            index,
-        ))
+    pub fn builder() -> ListImageFormatsRequestBuilder {
+        Default::default()
     }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
-    fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port.size()
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListImageFormatsReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
     }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
 }
+const _: () = assert!(<ListImageFormatsRequest as Request>::MINOR_OPCODE < 0x100);
+impl_port_only_request_asb! { ListImageFormatsRequest }
 impl Request for ListImageFormatsRequest {
-    const OPCODE: u8 = 16;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 16;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListImageFormats";
     type Reply = ListImageFormatsReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ListImageFormatsRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListImageFormatsRequestBuilder {
+    pub inner: ListImageFormatsRequest,
+}
+impl ListImageFormatsRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListImageFormatsRequest {
+        self.inner
+    }
+}
+impl Default for ListImageFormatsRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListImageFormatsRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ListImageFormatsReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub format: Vec<ImageFormatInfo>,
 }
-impl ListImageFormatsReply {}
-impl AsByteSequence for ListImageFormatsReply {
+impl ListImageFormatsReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn format_iter(bytes: &[u8]) -> Result<ListIter<'_, ImageFormatInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "format"))
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("format_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.format == other.format
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("format", &self.format, &other.format, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for ListImageFormatsReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.format.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.format, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListImageFormatsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (format, block_len): (Vec<ImageFormatInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "format")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListImageFormatsReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2623,20 +4436,25 @@ impl AsByteSequence for ListImageFormatsReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.format.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.format.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::convert::TryFrom<&[u8]> for ListImageFormatsReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryImageAttributesRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2645,38 +4463,61 @@ pub struct QueryImageAttributesRequest {
     pub width: Card16,
     pub height: Card16,
 }
-impl QueryImageAttributesRequest {}
-impl AsByteSequence for QueryImageAttributesRequest {
+impl QueryImageAttributesRequest {
     #[inline]
+    pub fn builder() -> QueryImageAttributesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryImageAttributesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("port", 4, 4),
+        ("id", 8, 4),
+        ("width", 12, 2),
+        ("height", 14, 2),
+    ];
+}
+const _: () = assert!(<QueryImageAttributesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for QueryImageAttributesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.id.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryImageAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryImageAttributesRequest {
                 req_type: req_type,
                 length: length,
@@ -2690,22 +4531,74 @@ impl AsByteSequence for QueryImageAttributesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.id.size()
-            + self.width.size()
-            + self.height.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryImageAttributesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryImageAttributesRequest {
-    const OPCODE: u8 = 17;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 17;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryImageAttributes";
     type Reply = QueryImageAttributesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryImageAttributesRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryImageAttributesRequestBuilder {
+    pub inner: QueryImageAttributesRequest,
+}
+impl QueryImageAttributesRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn id(mut self, id: Card32) -> Self {
+        self.inner.id = id;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryImageAttributesRequest {
+        self.inner
+    }
+}
+impl Default for QueryImageAttributesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryImageAttributesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                id: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryImageAttributesReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -2717,57 +4610,214 @@ pub struct QueryImageAttributesReply {
     pub pitches: Vec<Card32>,
     pub offsets: Vec<Card32>,
 }
-impl QueryImageAttributesReply {}
+impl QueryImageAttributesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn pitches_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (num_planes, sz): (Card32, usize) = parse_item(bytes, index, "num_planes")?;
+        index += sz;
+        let (data_size, sz): (Card32, usize) = parse_item(bytes, index, "data_size")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        index += 12;
+        let (pitches, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (num_planes as usize) as usize, "pitches")?;
+        Ok(pitches)
+    }
+    pub fn offsets_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (num_planes, sz): (Card32, usize) = parse_item(bytes, index, "num_planes")?;
+        index += sz;
+        let (data_size, sz): (Card32, usize) = parse_item(bytes, index, "data_size")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        index += 12;
+        let (pitches, block_len): (Vec<Card32>, usize) =
+            parse_vector(bytes, index, (num_planes as usize) as usize, "pitches")?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        let (offsets, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (num_planes as usize) as usize, "offsets")?;
+        Ok(offsets)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let num_planes: Card32 = read_item(reader, &mut index, 4, "num_planes")?;
+        let data_size: Card32 = read_item(reader, &mut index, 4, "data_size")?;
+        let width: Card16 = read_item(reader, &mut index, 2, "width")?;
+        let height: Card16 = read_item(reader, &mut index, 2, "height")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let (pitches, block_len): (Vec<Card32>, usize) = read_vector(
+            reader,
+            &mut index,
+            (num_planes as usize) as usize,
+            4,
+            "pitches",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        let (offsets, block_len): (Vec<Card32>, usize) = read_vector(
+            reader,
+            &mut index,
+            (num_planes as usize) as usize,
+            4,
+            "offsets",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryImageAttributesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            num_planes: num_planes,
+            data_size: data_size,
+            width: width,
+            height: height,
+            pitches: pitches,
+            offsets: offsets,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("num_planes", 8, 4),
+        ("data_size", 12, 4),
+        ("width", 16, 2),
+        ("height", 18, 2),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.num_planes == other.num_planes
+            && self.data_size == other.data_size
+            && self.width == other.width
+            && self.height == other.height
+            && self.pitches == other.pitches
+            && self.offsets == other.offsets
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "num_planes",
+            &self.num_planes,
+            &other.num_planes,
+            &mut changes,
+        );
+        diff_value("data_size", &self.data_size, &other.data_size, &mut changes);
+        diff_value("width", &self.width, &other.width, &mut changes);
+        diff_value("height", &self.height, &other.height, &mut changes);
+        diff_list("pitches", &self.pitches, &other.pitches, &mut changes);
+        diff_list("offsets", &self.offsets, &other.offsets, &mut changes);
+        changes
+    }
+}
 impl AsByteSequence for QueryImageAttributesReply {
-    #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.num_planes.as_bytes(&mut bytes[index..]);
         index += self.data_size.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         let block_len: usize = vector_as_bytes(&self.pitches, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
         let block_len: usize = vector_as_bytes(&self.offsets, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryImageAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (num_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (num_planes, sz): (Card32, usize) = parse_item(bytes, index, "num_planes")?;
         index += sz;
-        let (data_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (data_size, sz): (Card32, usize) = parse_item(bytes, index, "data_size")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
         index += 12;
         let (pitches, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            parse_vector(bytes, index, (num_planes as usize) as usize, "pitches")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (offsets, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            parse_vector(bytes, index, (num_planes as usize) as usize, "offsets")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryImageAttributesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -2782,30 +4832,70 @@ impl AsByteSequence for QueryImageAttributesReply {
             index,
         ))
     }
-    #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.num_planes.size()
-            + self.data_size.size()
-            + self.width.size()
-            + self.height.size()
-            + 12
-            + {
+        let size: usize =
+            32 + {
                 let block_len: usize = self.pitches.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.offsets.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
                 block_len + pad
-            }
+            };
+        size + buffer_pad(size, 4)
     }
-}
-#[derive(Clone, Debug, Default)]
+    fn size_hint(&self) -> usize {
+        let size: usize =
+            32 + {
+                let block_len: usize = self.pitches.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
+            } + {
+                let block_len: usize = self.offsets.len() * ::core::mem::size_of::<Card32>();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+                block_len + pad
+            };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryImageAttributesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryImageAttributesReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let num_planes: Card32 = u.arbitrary()?;
+        let data_size: Card32 = u.arbitrary()?;
+        let width: Card16 = u.arbitrary()?;
+        let height: Card16 = u.arbitrary()?;
+        let pitches = arbitrary_list(u, (num_planes as usize) as usize)?;
+        let offsets = arbitrary_list(u, (num_planes as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            num_planes: num_planes,
+            data_size: data_size,
+            width: width,
+            height: height,
+            pitches: pitches,
+            offsets: offsets,
+        })
+    }
+}
+#[derive(PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct PutImageRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2825,13 +4915,88 @@ pub struct PutImageRequest {
     pub height: Card16,
     pub data: Vec<Card8>,
 }
-impl PutImageRequest {}
-impl AsByteSequence for PutImageRequest {
+impl PutImageRequest {
+    pub fn data_slice(bytes: &[u8]) -> Result<Cow<'_, [Card8]>, ParseError> {
+        let mut index: usize = 0;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
+        index += sz;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
+        index += sz;
+        let (gc, sz): (Gcontext, usize) = parse_item(bytes, index, "gc")?;
+        index += sz;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
+        index += sz;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
+        index += sz;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
+        index += sz;
+        let (src_w, sz): (Card16, usize) = parse_item(bytes, index, "src_w")?;
+        index += sz;
+        let (src_h, sz): (Card16, usize) = parse_item(bytes, index, "src_h")?;
+        index += sz;
+        let (drw_x, sz): (Int16, usize) = parse_item(bytes, index, "drw_x")?;
+        index += sz;
+        let (drw_y, sz): (Int16, usize) = parse_item(bytes, index, "drw_y")?;
+        index += sz;
+        let (drw_w, sz): (Card16, usize) = parse_item(bytes, index, "drw_w")?;
+        index += sz;
+        let (drw_h, sz): (Card16, usize) = parse_item(bytes, index, "drw_h")?;
+        index += sz;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
+        index += sz;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
+        index += sz;
+        let (data, _): (Cow<'_, [Card8]>, usize) = parse_slice(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "data",
+                offset: index,
+            })? as usize,
+            "data",
+        )?;
+        Ok(data)
+    }
+    pub fn deep_clone(&self) -> Self {
+        Self {
+            req_type: self.req_type.clone(),
+            length: self.length.clone(),
+            port: self.port.clone(),
+            drawable: self.drawable.clone(),
+            gc: self.gc.clone(),
+            id: self.id.clone(),
+            src_x: self.src_x.clone(),
+            src_y: self.src_y.clone(),
+            src_w: self.src_w.clone(),
+            src_h: self.src_h.clone(),
+            drw_x: self.drw_x.clone(),
+            drw_y: self.drw_y.clone(),
+            drw_w: self.drw_w.clone(),
+            drw_h: self.drw_h.clone(),
+            width: self.width.clone(),
+            height: self.height.clone(),
+            data: self.data.clone(),
+        }
+    }
     #[inline]
+    pub fn builder() -> PutImageRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<PutImageRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for PutImageRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
@@ -2849,51 +5014,62 @@ impl AsByteSequence for PutImageRequest {
         index += self.height.as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card8>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing PutImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = parse_item(bytes, index, "gc")?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (src_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_w, sz): (Card16, usize) = parse_item(bytes, index, "src_w")?;
         index += sz;
-        let (src_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_h, sz): (Card16, usize) = parse_item(bytes, index, "src_h")?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = parse_item(bytes, index, "drw_x")?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = parse_item(bytes, index, "drw_y")?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = parse_item(bytes, index, "drw_w")?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = parse_item(bytes, index, "drw_h")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (data, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Card8>, usize) = parse_remainder(
+            bytes,
+            index,
+            (length as usize * 4).checked_sub(index).ok_or(ParseError {
+                field: "data",
+                offset: index,
+            })? as usize,
+            "data",
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             PutImageRequest {
                 req_type: req_type,
                 length: length,
@@ -2918,37 +5094,193 @@ impl AsByteSequence for PutImageRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.drawable.size()
-            + self.gc.size()
-            + self.id.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + self.src_w.size()
-            + self.src_h.size()
-            + self.drw_x.size()
-            + self.drw_y.size()
-            + self.drw_w.size()
-            + self.drw_h.size()
-            + self.width.size()
-            + self.height.size()
-            + {
-                let block_len: usize = self.data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
+        let size: usize = 32 + self.drawable.size() + self.gc.size() + {
+            let block_len: usize = self.data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 32 + self.drawable.size() + self.gc.size() + {
+            let block_len: usize = self.data.len() * ::core::mem::size_of::<Card8>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for PutImageRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for PutImageRequest {
-    const OPCODE: u8 = 18;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 18;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutImage";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for PutImageRequest {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PutImageRequest")
+            .field("req_type", &self.req_type)
+            .field("length", &self.length)
+            .field("port", &self.port)
+            .field("drawable", &self.drawable)
+            .field("gc", &self.gc)
+            .field("id", &self.id)
+            .field("src_x", &self.src_x)
+            .field("src_y", &self.src_y)
+            .field("src_w", &self.src_w)
+            .field("src_h", &self.src_h)
+            .field("drw_x", &self.drw_x)
+            .field("drw_y", &self.drw_y)
+            .field("drw_w", &self.drw_w)
+            .field("drw_h", &self.drw_h)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("data", &debug_bytes(&self.data, 64))
+            .finish()
+    }
+}
+#[doc = " A builder for `PutImageRequest`."]
+#[derive(Debug)]
+pub struct PutImageRequestBuilder {
+    pub inner: PutImageRequest,
+}
+impl PutImageRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn id(mut self, id: Card32) -> Self {
+        self.inner.id = id;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn src_w(mut self, src_w: Card16) -> Self {
+        self.inner.src_w = src_w;
+        self
+    }
+    #[inline]
+    pub fn src_h(mut self, src_h: Card16) -> Self {
+        self.inner.src_h = src_h;
+        self
+    }
+    #[inline]
+    pub fn drw_x(mut self, drw_x: Int16) -> Self {
+        self.inner.drw_x = drw_x;
+        self
+    }
+    #[inline]
+    pub fn drw_y(mut self, drw_y: Int16) -> Self {
+        self.inner.drw_y = drw_y;
+        self
+    }
+    #[inline]
+    pub fn drw_w(mut self, drw_w: Card16) -> Self {
+        self.inner.drw_w = drw_w;
+        self
+    }
+    #[inline]
+    pub fn drw_h(mut self, drw_h: Card16) -> Self {
+        self.inner.drw_h = drw_h;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn data(mut self, data: Vec<Card8>) -> Self {
+        self.inner.data = data;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> PutImageRequest {
+        self.inner
+    }
+}
+impl core::iter::Extend<Card8> for PutImageRequestBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Card8>>(&mut self, iter: I) {
+        self.inner.data.extend(iter);
+    }
+}
+impl core::iter::FromIterator<Card8> for PutImageRequestBuilder {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Card8>>(iter: I) -> Self {
+        let mut builder = Self::default();
+        builder.extend(iter);
+        builder
+    }
+}
+impl Default for PutImageRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: PutImageRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                drawable: Default::default(),
+                gc: Default::default(),
+                id: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                src_w: Default::default(),
+                src_h: Default::default(),
+                drw_x: Default::default(),
+                drw_y: Default::default(),
+                drw_w: Default::default(),
+                drw_h: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                data: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ShmPutImageRequest {
     pub req_type: u8,
     pub length: u16,
@@ -2970,13 +5302,21 @@ pub struct ShmPutImageRequest {
     pub height: Card16,
     pub send_event: Card8,
 }
-impl ShmPutImageRequest {}
-impl AsByteSequence for ShmPutImageRequest {
+impl ShmPutImageRequest {
     #[inline]
+    pub fn builder() -> ShmPutImageRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("port", 4, 4)];
+}
+const _: () = assert!(<ShmPutImageRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ShmPutImageRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
         index += self.drawable.as_bytes(&mut bytes[index..]);
@@ -2995,54 +5335,55 @@ impl AsByteSequence for ShmPutImageRequest {
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.send_event.as_bytes(&mut bytes[index..]);
-        index += 3;
+        index += zero_pad(&mut bytes[index..], 3);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ShmPutImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = parse_item(bytes, index, "gc")?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = parse_item(bytes, index, "shmseg")?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = parse_item(bytes, index, "offset")?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = parse_item(bytes, index, "src_x")?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = parse_item(bytes, index, "src_y")?;
         index += sz;
-        let (src_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_w, sz): (Card16, usize) = parse_item(bytes, index, "src_w")?;
         index += sz;
-        let (src_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_h, sz): (Card16, usize) = parse_item(bytes, index, "src_h")?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = parse_item(bytes, index, "drw_x")?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = parse_item(bytes, index, "drw_y")?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = parse_item(bytes, index, "drw_w")?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = parse_item(bytes, index, "drw_h")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (send_event, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (send_event, sz): (Card8, usize) = parse_item(bytes, index, "send_event")?;
         index += sz;
         index += 3;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ShmPutImageRequest {
                 req_type: req_type,
                 length: length,
@@ -3069,162 +5410,397 @@ impl AsByteSequence for ShmPutImageRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.port.size()
-            + self.drawable.size()
-            + self.gc.size()
-            + self.shmseg.size()
-            + self.id.size()
-            + self.offset.size()
-            + self.src_x.size()
-            + self.src_y.size()
-            + self.src_w.size()
-            + self.src_h.size()
-            + self.drw_x.size()
-            + self.drw_y.size()
-            + self.drw_w.size()
-            + self.drw_h.size()
-            + self.width.size()
-            + self.height.size()
-            + self.send_event.size()
-            + 3
+        let size: usize = 40 + self.drawable.size() + self.gc.size() + self.shmseg.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ShmPutImageRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ShmPutImageRequest {
-    const OPCODE: u8 = 19;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
+    const MINOR_OPCODE: u16 = 19;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ShmPutImage";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
-pub struct VideoNotifyEvent {
+#[doc = " A builder for `ShmPutImageRequest`."]
+#[derive(Clone, Debug)]
+pub struct ShmPutImageRequestBuilder {
+    pub inner: ShmPutImageRequest,
+}
+impl ShmPutImageRequestBuilder {
+    #[inline]
+    pub fn port(mut self, port: Port) -> Self {
+        self.inner.port = port;
+        self
+    }
+    #[inline]
+    pub fn drawable(mut self, drawable: Drawable) -> Self {
+        self.inner.drawable = drawable;
+        self
+    }
+    #[inline]
+    pub fn gc(mut self, gc: Gcontext) -> Self {
+        self.inner.gc = gc;
+        self
+    }
+    #[inline]
+    pub fn shmseg(mut self, shmseg: Seg) -> Self {
+        self.inner.shmseg = shmseg;
+        self
+    }
+    #[inline]
+    pub fn id(mut self, id: Card32) -> Self {
+        self.inner.id = id;
+        self
+    }
+    #[inline]
+    pub fn offset(mut self, offset: Card32) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+    #[inline]
+    pub fn src_x(mut self, src_x: Int16) -> Self {
+        self.inner.src_x = src_x;
+        self
+    }
+    #[inline]
+    pub fn src_y(mut self, src_y: Int16) -> Self {
+        self.inner.src_y = src_y;
+        self
+    }
+    #[inline]
+    pub fn src_w(mut self, src_w: Card16) -> Self {
+        self.inner.src_w = src_w;
+        self
+    }
+    #[inline]
+    pub fn src_h(mut self, src_h: Card16) -> Self {
+        self.inner.src_h = src_h;
+        self
+    }
+    #[inline]
+    pub fn drw_x(mut self, drw_x: Int16) -> Self {
+        self.inner.drw_x = drw_x;
+        self
+    }
+    #[inline]
+    pub fn drw_y(mut self, drw_y: Int16) -> Self {
+        self.inner.drw_y = drw_y;
+        self
+    }
+    #[inline]
+    pub fn drw_w(mut self, drw_w: Card16) -> Self {
+        self.inner.drw_w = drw_w;
+        self
+    }
+    #[inline]
+    pub fn drw_h(mut self, drw_h: Card16) -> Self {
+        self.inner.drw_h = drw_h;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn send_event(mut self, send_event: Card8) -> Self {
+        self.inner.send_event = send_event;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ShmPutImageRequest {
+        self.inner
+    }
+}
+impl Default for ShmPutImageRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ShmPutImageRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port: Default::default(),
+                drawable: Default::default(),
+                gc: Default::default(),
+                shmseg: Default::default(),
+                id: Default::default(),
+                offset: Default::default(),
+                src_x: Default::default(),
+                src_y: Default::default(),
+                src_w: Default::default(),
+                src_h: Default::default(),
+                drw_x: Default::default(),
+                drw_y: Default::default(),
+                drw_w: Default::default(),
+                drw_h: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                send_event: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct PortNotifyEvent {
     pub event_type: u8,
-    pub reason: VideoNotifyReason,
     pub sequence: u16,
     pub time: Timestamp,
-    pub drawable: Drawable,
     pub port: Port,
+    pub attribute: Atom,
+    pub value: Int32,
 }
-impl VideoNotifyEvent {}
-impl AsByteSequence for VideoNotifyEvent {
+impl PortNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 1;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("event_type", 0, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for PortNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += self.reason.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
-        index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
+        index += self.attribute.as_bytes(&mut bytes[index..]);
+        index += self.value.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing VideoNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing PortNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        let (reason, sz): (VideoNotifyReason, usize) =
-            <VideoNotifyReason>::from_bytes(&bytes[index..])?;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (attribute, sz): (Atom, usize) = parse_item(bytes, index, "attribute")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int32, usize) = parse_item(bytes, index, "value")?;
         index += sz;
-        Some((
-            VideoNotifyEvent {
+        Ok((
+            PortNotifyEvent {
                 event_type: event_type,
-                reason: reason,
                 sequence: sequence,
                 time: time,
-                drawable: drawable,
                 port: port,
+                attribute: attribute,
+                value: value,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + self.reason.size()
-            + self.sequence.size()
-            + self.time.size()
-            + self.drawable.size()
-            + self.port.size()
+        12 + self.time.size() + self.attribute.size()
     }
 }
-impl crate::auto::Event for VideoNotifyEvent {
-    const OPCODE: u8 = 0;
+impl core::convert::TryFrom<&[u8]> for PortNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
 }
-#[derive(Clone, Debug, Default)]
-pub struct PortNotifyEvent {
+impl crate::auto::Event for PortNotifyEvent {
+    const OPCODE: u8 = 1;
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[non_exhaustive]
+pub struct VideoNotifyEvent {
     pub event_type: u8,
+    pub reason: VideoNotifyReason,
     pub sequence: u16,
     pub time: Timestamp,
+    pub drawable: Drawable,
     pub port: Port,
-    pub attribute: Atom,
-    pub value: Int32,
 }
-impl PortNotifyEvent {}
-impl AsByteSequence for PortNotifyEvent {
+impl VideoNotifyEvent {
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        Self::RESPONSE_TYPE
+    }
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " The response type of this event. This is relative to the first event of the extension, which is assigned by the server when the extension is queried."]
+    pub const RESPONSE_TYPE: u8 = 0;
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("event_type", 0, 1), ("reason", 1, 1), ("sequence", 2, 2)];
+}
+impl AsByteSequence for VideoNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += self.reason.as_bytes(&mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
+        index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.port.as_bytes(&mut bytes[index..]);
-        index += self.attribute.as_bytes(&mut bytes[index..]);
-        index += self.value.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
-        log::trace!("Deserializing PortNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        log::trace!("Deserializing VideoNotifyEvent from byte buffer");
+        let (event_type, sz): (u8, usize) = parse_item(bytes, index, "event_type")?;
         index += sz;
-        index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (reason, sz): (VideoNotifyReason, usize) = parse_item(bytes, index, "reason")?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = parse_item(bytes, index, "time")?;
         index += sz;
-        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = parse_item(bytes, index, "drawable")?;
         index += sz;
-        let (value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = parse_item(bytes, index, "port")?;
         index += sz;
-        Some((
-            PortNotifyEvent {
+        Ok((
+            VideoNotifyEvent {
                 event_type: event_type,
+                reason: reason,
                 sequence: sequence,
                 time: time,
+                drawable: drawable,
                 port: port,
-                attribute: attribute,
-                value: value,
             },
             index,
         ))
     }
     #[inline]
     fn size(&self) -> usize {
-        self.event_type.size()
-            + 1
-            + self.sequence.size()
-            + self.time.size()
-            + self.port.size()
-            + self.attribute.size()
-            + self.value.size()
+        8 + self.time.size() + self.drawable.size()
     }
 }
-impl crate::auto::Event for PortNotifyEvent {
-    const OPCODE: u8 = 1;
+impl core::convert::TryFrom<&[u8]> for VideoNotifyEvent {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+impl crate::auto::Event for VideoNotifyEvent {
+    const OPCODE: u8 = 0;
+}
+#[doc = " One of the events in this module."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+pub enum Event {
+    PortNotify(PortNotifyEvent),
+    VideoNotify(VideoNotifyEvent),
+}
+impl Event {
+    #[inline]
+    pub fn parse(opcode: u8, bytes: &[u8]) -> Result<Self, ParseError> {
+        match opcode {
+            1 => Ok(Self::PortNotify(PortNotifyEvent::from_bytes(bytes)?.0)),
+            0 => Ok(Self::VideoNotify(VideoNotifyEvent::from_bytes(bytes)?.0)),
+            _ => Err(ParseError {
+                field: "opcode",
+                offset: 0,
+            }),
+        }
+    }
+    #[inline]
+    pub const fn response_type(&self) -> u8 {
+        match self {
+            Self::PortNotify(_) => PortNotifyEvent::RESPONSE_TYPE,
+            Self::VideoNotify(_) => VideoNotifyEvent::RESPONSE_TYPE,
+        }
+    }
+}
+impl From<PortNotifyEvent> for Event {
+    #[inline]
+    fn from(event: PortNotifyEvent) -> Self {
+        Self::PortNotify(event)
+    }
+}
+impl From<VideoNotifyEvent> for Event {
+    #[inline]
+    fn from(event: VideoNotifyEvent) -> Self {
+        Self::VideoNotify(event)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[(0, "VideoNotify"), (1, "PortNotify")];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryExtension"),
+    (1, "QueryAdaptors"),
+    (2, "QueryEncodings"),
+    (3, "GrabPort"),
+    (4, "UngrabPort"),
+    (5, "PutVideo"),
+    (6, "PutStill"),
+    (7, "GetVideo"),
+    (8, "GetStill"),
+    (9, "StopVideo"),
+    (10, "SelectVideoNotify"),
+    (11, "SelectPortNotify"),
+    (12, "QueryBestSize"),
+    (13, "SetPortAttribute"),
+    (14, "GetPortAttribute"),
+    (15, "QueryPortAttributes"),
+    (16, "ListImageFormats"),
+    (17, "QueryImageAttributes"),
+    (18, "PutImage"),
+    (19, "ShmPutImage"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
 }
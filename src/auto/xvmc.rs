@@ -1,13 +1,22 @@
 // This file was automatically generated.
 // It is considered to be licensed under the MIT and Apache 2.0 licenses.
 
+#![cfg(feature = "xvmc")]
 #![allow(warnings)]
 
 use super::prelude::*;
 
+#[cfg(not(feature = "xv"))]
+compile_error!(
+    "The `xvmc` feature requires the `xv` feature, since it uses the types that it defines"
+);
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
 use super::xv::*;
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Context {
     pub xid: XID,
 }
@@ -16,6 +25,23 @@ impl Context {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Context {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Context {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Context {
     #[inline]
@@ -27,8 +53,30 @@ impl XidType for Context {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Context {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Context({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Context {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Context> for XID {
+    #[inline]
+    fn from(base: Context) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Surface {
     pub xid: XID,
 }
@@ -37,6 +85,23 @@ impl Surface {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Surface {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Surface {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Surface {
     #[inline]
@@ -48,8 +113,30 @@ impl XidType for Surface {
         Self { xid: xid }
     }
 }
+impl core::fmt::Debug for Surface {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Surface({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Surface {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Surface> for XID {
+    #[inline]
+    fn from(base: Surface) -> Self {
+        base.xid
+    }
+}
 #[repr(transparent)]
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct Subpicture {
     pub xid: XID,
 }
@@ -58,6 +145,23 @@ impl Subpicture {
     pub const fn const_from_xid(xid: XID) -> Self {
         Self { xid: xid }
     }
+    #[inline]
+    pub const fn new(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl core::ops::Deref for Subpicture {
+    type Target = XID;
+    #[inline]
+    fn deref(&self) -> &XID {
+        &self.xid
+    }
+}
+impl AsRef<XID> for Subpicture {
+    #[inline]
+    fn as_ref(&self) -> &XID {
+        &self.xid
+    }
 }
 impl XidType for Subpicture {
     #[inline]
@@ -69,7 +173,29 @@ impl XidType for Subpicture {
         Self { xid: xid }
     }
 }
-#[derive(Clone, Debug, Default)]
+impl core::fmt::Debug for Subpicture {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Subpicture({:#x})", self.xid)
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<XID> for Subpicture {
+    #[inline]
+    fn from(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[cfg(feature = "xid-conversions")]
+impl From<Subpicture> for XID {
+    #[inline]
+    fn from(base: Subpicture) -> Self {
+        base.xid
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct SurfaceInfo {
     pub id: Surface,
     pub chroma_format: Card16,
@@ -81,9 +207,45 @@ pub struct SurfaceInfo {
     pub mc_type: Card32,
     pub flags: Card32,
 }
-impl SurfaceInfo {}
-impl AsByteSequence for SurfaceInfo {
+impl SurfaceInfo {
     #[inline]
+    pub const fn new(
+        id: Surface,
+        chroma_format: Card16,
+        pad0: Card16,
+        max_width: Card16,
+        max_height: Card16,
+        subpicture_max_width: Card16,
+        subpicture_max_height: Card16,
+        mc_type: Card32,
+        flags: Card32,
+    ) -> Self {
+        Self {
+            id: id,
+            chroma_format: chroma_format,
+            pad0: pad0,
+            max_width: max_width,
+            max_height: max_height,
+            subpicture_max_width: subpicture_max_width,
+            subpicture_max_height: subpicture_max_height,
+            mc_type: mc_type,
+            flags: flags,
+        }
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("id", 0, 4),
+        ("chroma_format", 4, 2),
+        ("pad0", 6, 2),
+        ("max_width", 8, 2),
+        ("max_height", 10, 2),
+        ("subpicture_max_width", 12, 2),
+        ("subpicture_max_height", 14, 2),
+        ("mc_type", 16, 4),
+        ("flags", 20, 4),
+    ];
+}
+impl AsByteSequence for SurfaceInfo {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.id.as_bytes(&mut bytes[index..]);
@@ -97,29 +259,30 @@ impl AsByteSequence for SurfaceInfo {
         index += self.flags.as_bytes(&mut bytes[index..]);
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing SurfaceInfo from byte buffer");
-        let (id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Surface, usize) = parse_item(bytes, index, "id")?;
         index += sz;
-        let (chroma_format, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (chroma_format, sz): (Card16, usize) = parse_item(bytes, index, "chroma_format")?;
         index += sz;
-        let (pad0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pad0, sz): (Card16, usize) = parse_item(bytes, index, "pad0")?;
         index += sz;
-        let (max_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_width, sz): (Card16, usize) = parse_item(bytes, index, "max_width")?;
         index += sz;
-        let (max_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_height, sz): (Card16, usize) = parse_item(bytes, index, "max_height")?;
         index += sz;
-        let (subpicture_max_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (subpicture_max_width, sz): (Card16, usize) =
+            parse_item(bytes, index, "subpicture_max_width")?;
         index += sz;
-        let (subpicture_max_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (subpicture_max_height, sz): (Card16, usize) =
+            parse_item(bytes, index, "subpicture_max_height")?;
         index += sz;
-        let (mc_type, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mc_type, sz): (Card32, usize) = parse_item(bytes, index, "mc_type")?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        Some((
+        Ok((
             SurfaceInfo {
                 id: id,
                 chroma_format: chroma_format,
@@ -136,42 +299,68 @@ impl AsByteSequence for SurfaceInfo {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.id.size()
-            + self.chroma_format.size()
-            + self.pad0.size()
-            + self.max_width.size()
-            + self.max_height.size()
-            + self.subpicture_max_width.size()
-            + self.subpicture_max_height.size()
-            + self.mc_type.size()
-            + self.flags.size()
-    }
-}
-#[derive(Clone, Debug, Default)]
+        24
+    }
+}
+impl core::convert::TryFrom<&[u8]> for SurfaceInfo {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct QueryVersionRequest {
     pub req_type: u8,
     pub length: u16,
 }
-impl QueryVersionRequest {}
+impl QueryVersionRequest {
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[inline]
+    pub fn builder() -> QueryVersionRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 4;
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<QueryVersionReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<QueryVersionRequest as Request>::MINOR_OPCODE < 0x100);
 impl AsByteSequence for QueryVersionRequest {
     #[inline]
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionRequest {
                 req_type: req_type,
                 length: length,
@@ -181,16 +370,51 @@ impl AsByteSequence for QueryVersionRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size()
+        let size: usize = 4;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 0;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `QueryVersionRequest`."]
+#[derive(Clone, Debug)]
+pub struct QueryVersionRequestBuilder {
+    pub inner: QueryVersionRequest,
+}
+impl QueryVersionRequestBuilder {
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> QueryVersionRequest {
+        self.inner
+    }
+}
+impl Default for QueryVersionRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: QueryVersionRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct QueryVersionReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -198,35 +422,95 @@ pub struct QueryVersionReply {
     pub major: Card32,
     pub minor: Card32,
 }
-impl QueryVersionReply {}
-impl AsByteSequence for QueryVersionReply {
+impl QueryVersionReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[inline]
+    pub const fn size(&self) -> usize {
+        Self::FIXED_SIZE
+    }
+    #[doc = " The size of this structure in bytes, which never changes."]
+    pub const FIXED_SIZE: usize = 16;
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let major: Card32 = read_item(reader, &mut index, 4, "major")?;
+        let minor: Card32 = read_item(reader, &mut index, 4, "minor")?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(QueryVersionReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            major: major,
+            minor: minor,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("major", 8, 4),
+        ("minor", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value("major", &self.major, &other.major, &mut changes);
+        diff_value("minor", &self.minor, &other.minor, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for QueryVersionReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.major.as_bytes(&mut bytes[index..]);
         index += self.minor.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (major, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (major, sz): (Card32, usize) = parse_item(bytes, index, "major")?;
         index += sz;
-        let (minor, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Card32, usize) = parse_item(bytes, index, "minor")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             QueryVersionReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -239,43 +523,65 @@ impl AsByteSequence for QueryVersionReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.major.size()
-            + self.minor.size()
+        let size: usize = 16;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListSurfaceTypesRequest {
     pub req_type: u8,
     pub length: u16,
     pub port_id: Port,
 }
-impl ListSurfaceTypesRequest {}
-impl AsByteSequence for ListSurfaceTypesRequest {
+impl ListSurfaceTypesRequest {
+    #[inline]
+    pub fn builder() -> ListSurfaceTypesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListSurfaceTypesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<ListSurfaceTypesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ListSurfaceTypesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port_id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSurfaceTypesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port_id, sz): (Port, usize) = parse_item(bytes, index, "port_id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListSurfaceTypesRequest {
                 req_type: req_type,
                 length: length,
@@ -286,57 +592,174 @@ impl AsByteSequence for ListSurfaceTypesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port_id.size()
+        let size: usize = 4 + self.port_id.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListSurfaceTypesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ListSurfaceTypesRequest {
-    const OPCODE: u8 = 1;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 1;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListSurfaceTypes";
     type Reply = ListSurfaceTypesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ListSurfaceTypesRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListSurfaceTypesRequestBuilder {
+    pub inner: ListSurfaceTypesRequest,
+}
+impl ListSurfaceTypesRequestBuilder {
+    #[inline]
+    pub fn port_id(mut self, port_id: Port) -> Self {
+        self.inner.port_id = port_id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListSurfaceTypesRequest {
+        self.inner
+    }
+}
+impl Default for ListSurfaceTypesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListSurfaceTypesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port_id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ListSurfaceTypesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub surfaces: Vec<SurfaceInfo>,
 }
-impl ListSurfaceTypesReply {}
-impl AsByteSequence for ListSurfaceTypesReply {
+impl ListSurfaceTypesReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn surfaces_iter(bytes: &[u8]) -> Result<ListIter<'_, SurfaceInfo>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
+        index += sz;
+        index += 20;
+        Ok(ListIter::new(bytes, index, len0 as usize, "surfaces"))
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let len0: Card32 = read_item(reader, &mut index, 4, "len0")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (surfaces, block_len): (Vec<SurfaceInfo>, usize) =
+            read_vector(reader, &mut index, len0 as usize, 24, "surfaces")?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<SurfaceInfo>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(ListSurfaceTypesReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            surfaces: surfaces,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("surfaces_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.surfaces == other.surfaces
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("surfaces", &self.surfaces, &other.surfaces, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for ListSurfaceTypesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.surfaces.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.surfaces, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<SurfaceInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<SurfaceInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSurfaceTypesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (surfaces, block_len): (Vec<SurfaceInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "surfaces")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SurfaceInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListSurfaceTypesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -348,20 +771,25 @@ impl AsByteSequence for ListSurfaceTypesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.surfaces.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SurfaceInfo>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let size: usize = 32 + {
+            let block_len: usize = self.surfaces.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SurfaceInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListSurfaceTypesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateContextRequest {
     pub req_type: u8,
     pub length: u16,
@@ -372,13 +800,29 @@ pub struct CreateContextRequest {
     pub height: Card16,
     pub flags: Card32,
 }
-impl CreateContextRequest {}
-impl AsByteSequence for CreateContextRequest {
+impl CreateContextRequest {
     #[inline]
+    pub fn builder() -> CreateContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateContextReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_id", 4, 4)];
+}
+const _: () = assert!(<CreateContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateContextRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context_id.as_bytes(&mut bytes[index..]);
         index += self.port_id.as_bytes(&mut bytes[index..]);
@@ -386,30 +830,31 @@ impl AsByteSequence for CreateContextRequest {
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.flags.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context_id, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Context, usize) = parse_item(bytes, index, "context_id")?;
         index += sz;
-        let (port_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port_id, sz): (Port, usize) = parse_item(bytes, index, "port_id")?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = parse_item(bytes, index, "surface_id")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = parse_item(bytes, index, "flags")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateContextRequest {
                 req_type: req_type,
                 length: length,
@@ -425,24 +870,86 @@ impl AsByteSequence for CreateContextRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.context_id.size()
-            + self.port_id.size()
-            + self.surface_id.size()
-            + self.width.size()
-            + self.height.size()
-            + self.flags.size()
+        let size: usize = 20 + self.port_id.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateContextRequest {
-    const OPCODE: u8 = 2;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 2;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = CreateContextReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateContextRequestBuilder {
+    pub inner: CreateContextRequest,
+}
+impl CreateContextRequestBuilder {
+    #[inline]
+    pub fn context_id(mut self, context_id: Context) -> Self {
+        self.inner.context_id = context_id;
+        self
+    }
+    #[inline]
+    pub fn port_id(mut self, port_id: Port) -> Self {
+        self.inner.port_id = port_id;
+        self
+    }
+    #[inline]
+    pub fn surface_id(mut self, surface_id: Surface) -> Self {
+        self.inner.surface_id = surface_id;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: Card32) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateContextRequest {
+        self.inner
+    }
+}
+impl Default for CreateContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context_id: Default::default(),
+                port_id: Default::default(),
+                surface_id: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                flags: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateContextReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -452,47 +959,162 @@ pub struct CreateContextReply {
     pub flags_return: Card32,
     pub priv_data: Vec<Card32>,
 }
-impl CreateContextReply {}
-impl AsByteSequence for CreateContextReply {
+impl CreateContextReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn priv_data_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width_actual, sz): (Card16, usize) = parse_item(bytes, index, "width_actual")?;
+        index += sz;
+        let (height_actual, sz): (Card16, usize) = parse_item(bytes, index, "height_actual")?;
+        index += sz;
+        let (flags_return, sz): (Card32, usize) = parse_item(bytes, index, "flags_return")?;
+        index += sz;
+        index += 20;
+        let (priv_data, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (length as usize) as usize, "priv_data")?;
+        Ok(priv_data)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let width_actual: Card16 = read_item(reader, &mut index, 2, "width_actual")?;
+        let height_actual: Card16 = read_item(reader, &mut index, 2, "height_actual")?;
+        let flags_return: Card32 = read_item(reader, &mut index, 4, "flags_return")?;
+        skip_bytes(reader, &mut index, 20)?;
+        let (priv_data, block_len): (Vec<Card32>, usize) = read_vector(
+            reader,
+            &mut index,
+            (length as usize) as usize,
+            4,
+            "priv_data",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(CreateContextReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            width_actual: width_actual,
+            height_actual: height_actual,
+            flags_return: flags_return,
+            priv_data: priv_data,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("width_actual", 8, 2),
+        ("height_actual", 10, 2),
+        ("flags_return", 12, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.width_actual == other.width_actual
+            && self.height_actual == other.height_actual
+            && self.flags_return == other.flags_return
+            && self.priv_data == other.priv_data
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "width_actual",
+            &self.width_actual,
+            &other.width_actual,
+            &mut changes,
+        );
+        diff_value(
+            "height_actual",
+            &self.height_actual,
+            &other.height_actual,
+            &mut changes,
+        );
+        diff_value(
+            "flags_return",
+            &self.flags_return,
+            &other.flags_return,
+            &mut changes,
+        );
+        diff_list("priv_data", &self.priv_data, &other.priv_data, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for CreateContextReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.width_actual.as_bytes(&mut bytes[index..]);
         index += self.height_actual.as_bytes(&mut bytes[index..]);
         index += self.flags_return.as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.priv_data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (width_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width_actual, sz): (Card16, usize) = parse_item(bytes, index, "width_actual")?;
         index += sz;
-        let (height_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height_actual, sz): (Card16, usize) = parse_item(bytes, index, "height_actual")?;
         index += sz;
-        let (flags_return, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags_return, sz): (Card32, usize) = parse_item(bytes, index, "flags_return")?;
         index += sz;
         index += 20;
         let (priv_data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            parse_vector(bytes, index, (length as usize) as usize, "priv_data")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateContextReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -507,50 +1129,93 @@ impl AsByteSequence for CreateContextReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.width_actual.size()
-            + self.height_actual.size()
-            + self.flags_return.size()
-            + 20
-            + {
-                let block_len: usize = self.priv_data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let size: usize = 36 + {
+            let block_len: usize = self.priv_data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 36 + {
+            let block_len: usize = self.priv_data.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateContextReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateContextReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let width_actual: Card16 = u.arbitrary()?;
+        let height_actual: Card16 = u.arbitrary()?;
+        let flags_return: Card32 = u.arbitrary()?;
+        let priv_data = arbitrary_list(u, (length as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            width_actual: width_actual,
+            height_actual: height_actual,
+            flags_return: flags_return,
+            priv_data: priv_data,
+        })
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DestroyContextRequest {
     pub req_type: u8,
     pub length: u16,
     pub context_id: Context,
 }
-impl DestroyContextRequest {}
-impl AsByteSequence for DestroyContextRequest {
+impl DestroyContextRequest {
     #[inline]
+    pub fn builder() -> DestroyContextRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("context_id", 4, 4)];
+}
+const _: () = assert!(<DestroyContextRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DestroyContextRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.context_id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (context_id, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Context, usize) = parse_item(bytes, index, "context_id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DestroyContextRequest {
                 req_type: req_type,
                 length: length,
@@ -561,48 +1226,111 @@ impl AsByteSequence for DestroyContextRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.context_id.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DestroyContextRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DestroyContextRequest {
-    const OPCODE: u8 = 3;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 3;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyContext";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroyContextRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroyContextRequestBuilder {
+    pub inner: DestroyContextRequest,
+}
+impl DestroyContextRequestBuilder {
+    #[inline]
+    pub fn context_id(mut self, context_id: Context) -> Self {
+        self.inner.context_id = context_id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroyContextRequest {
+        self.inner
+    }
+}
+impl Default for DestroyContextRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroyContextRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                context_id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateSurfaceRequest {
     pub req_type: u8,
     pub length: u16,
     pub surface_id: Surface,
     pub context_id: Context,
 }
-impl CreateSurfaceRequest {}
-impl AsByteSequence for CreateSurfaceRequest {
+impl CreateSurfaceRequest {
+    #[inline]
+    pub fn builder() -> CreateSurfaceRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateSurfaceReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("surface_id", 4, 4),
+        ("context_id", 8, 4),
+    ];
+}
+const _: () = assert!(<CreateSurfaceRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateSurfaceRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.surface_id.as_bytes(&mut bytes[index..]);
         index += self.context_id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSurfaceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = parse_item(bytes, index, "surface_id")?;
         index += sz;
-        let (context_id, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Context, usize) = parse_item(bytes, index, "context_id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateSurfaceRequest {
                 req_type: req_type,
                 length: length,
@@ -614,58 +1342,176 @@ impl AsByteSequence for CreateSurfaceRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.surface_id.size()
-            + self.context_id.size()
+        let size: usize = 12;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateSurfaceRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateSurfaceRequest {
-    const OPCODE: u8 = 4;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 4;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateSurface";
     type Reply = CreateSurfaceReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateSurfaceRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateSurfaceRequestBuilder {
+    pub inner: CreateSurfaceRequest,
+}
+impl CreateSurfaceRequestBuilder {
+    #[inline]
+    pub fn surface_id(mut self, surface_id: Surface) -> Self {
+        self.inner.surface_id = surface_id;
+        self
+    }
+    #[inline]
+    pub fn context_id(mut self, context_id: Context) -> Self {
+        self.inner.context_id = context_id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateSurfaceRequest {
+        self.inner
+    }
+}
+impl Default for CreateSurfaceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateSurfaceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                surface_id: Default::default(),
+                context_id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateSurfaceReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub priv_data: Vec<Card32>,
 }
-impl CreateSurfaceReply {}
-impl AsByteSequence for CreateSurfaceReply {
+impl CreateSurfaceReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn priv_data_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        index += 24;
+        let (priv_data, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (length as usize) as usize, "priv_data")?;
+        Ok(priv_data)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        skip_bytes(reader, &mut index, 24)?;
+        let (priv_data, block_len): (Vec<Card32>, usize) = read_vector(
+            reader,
+            &mut index,
+            (length as usize) as usize,
+            4,
+            "priv_data",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(CreateSurfaceReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            priv_data: priv_data,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("reply_type", 0, 1), ("sequence", 2, 2), ("length", 4, 4)];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.priv_data == other.priv_data
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("priv_data", &self.priv_data, &other.priv_data, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for CreateSurfaceReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
-        index += 24;
+        index += zero_pad(&mut bytes[index..], 24);
         let block_len: usize = vector_as_bytes(&self.priv_data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSurfaceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
         index += 24;
         let (priv_data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            parse_vector(bytes, index, (length as usize) as usize, "priv_data")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateSurfaceReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -677,42 +1523,87 @@ impl AsByteSequence for CreateSurfaceReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size() + 1 + self.sequence.size() + self.length.size() + 24 + {
+        let size: usize = 32 + {
             let block_len: usize = self.priv_data.iter().map(|i| i.size()).sum();
             let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
             block_len + pad
-        }
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 32 + {
+            let block_len: usize = self.priv_data.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateSurfaceReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateSurfaceReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let priv_data = arbitrary_list(u, (length as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            priv_data: priv_data,
+        })
     }
 }
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DestroySurfaceRequest {
     pub req_type: u8,
     pub length: u16,
     pub surface_id: Surface,
 }
-impl DestroySurfaceRequest {}
-impl AsByteSequence for DestroySurfaceRequest {
+impl DestroySurfaceRequest {
     #[inline]
+    pub fn builder() -> DestroySurfaceRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] =
+        &[("req_type", 0, 1), ("length", 2, 2), ("surface_id", 4, 4)];
+}
+const _: () = assert!(<DestroySurfaceRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DestroySurfaceRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.surface_id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroySurfaceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = parse_item(bytes, index, "surface_id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DestroySurfaceRequest {
                 req_type: req_type,
                 length: length,
@@ -723,16 +1614,58 @@ impl AsByteSequence for DestroySurfaceRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.surface_id.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DestroySurfaceRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DestroySurfaceRequest {
-    const OPCODE: u8 = 5;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 5;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroySurface";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroySurfaceRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroySurfaceRequestBuilder {
+    pub inner: DestroySurfaceRequest,
+}
+impl DestroySurfaceRequestBuilder {
+    #[inline]
+    pub fn surface_id(mut self, surface_id: Surface) -> Self {
+        self.inner.surface_id = surface_id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroySurfaceRequest {
+        self.inner
+    }
+}
+impl Default for DestroySurfaceRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroySurfaceRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                surface_id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct CreateSubpictureRequest {
     pub req_type: u8,
     pub length: u16,
@@ -742,41 +1675,65 @@ pub struct CreateSubpictureRequest {
     pub width: Card16,
     pub height: Card16,
 }
-impl CreateSubpictureRequest {}
-impl AsByteSequence for CreateSubpictureRequest {
+impl CreateSubpictureRequest {
     #[inline]
+    pub fn builder() -> CreateSubpictureRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
+    #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<CreateSubpictureReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("subpicture_id", 4, 4),
+        ("context", 8, 4),
+        ("xvimage_id", 12, 4),
+        ("width", 16, 2),
+        ("height", 18, 2),
+    ];
+}
+const _: () = assert!(<CreateSubpictureRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for CreateSubpictureRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.subpicture_id.as_bytes(&mut bytes[index..]);
         index += self.context.as_bytes(&mut bytes[index..]);
         index += self.xvimage_id.as_bytes(&mut bytes[index..]);
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSubpictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (subpicture_id, sz): (Subpicture, usize) = <Subpicture>::from_bytes(&bytes[index..])?;
+        let (subpicture_id, sz): (Subpicture, usize) = parse_item(bytes, index, "subpicture_id")?;
         index += sz;
-        let (context, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Context, usize) = parse_item(bytes, index, "context")?;
         index += sz;
-        let (xvimage_id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (xvimage_id, sz): (Card32, usize) = parse_item(bytes, index, "xvimage_id")?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = parse_item(bytes, index, "width")?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = parse_item(bytes, index, "height")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateSubpictureRequest {
                 req_type: req_type,
                 length: length,
@@ -791,23 +1748,80 @@ impl AsByteSequence for CreateSubpictureRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.subpicture_id.size()
-            + self.context.size()
-            + self.xvimage_id.size()
-            + self.width.size()
-            + self.height.size()
+        let size: usize = 20;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateSubpictureRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for CreateSubpictureRequest {
-    const OPCODE: u8 = 6;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 6;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateSubpicture";
     type Reply = CreateSubpictureReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `CreateSubpictureRequest`."]
+#[derive(Clone, Debug)]
+pub struct CreateSubpictureRequestBuilder {
+    pub inner: CreateSubpictureRequest,
+}
+impl CreateSubpictureRequestBuilder {
+    #[inline]
+    pub fn subpicture_id(mut self, subpicture_id: Subpicture) -> Self {
+        self.inner.subpicture_id = subpicture_id;
+        self
+    }
+    #[inline]
+    pub fn context(mut self, context: Context) -> Self {
+        self.inner.context = context;
+        self
+    }
+    #[inline]
+    pub fn xvimage_id(mut self, xvimage_id: Card32) -> Self {
+        self.inner.xvimage_id = xvimage_id;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: Card16) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: Card16) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> CreateSubpictureRequest {
+        self.inner
+    }
+}
+impl Default for CreateSubpictureRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: CreateSubpictureRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                subpicture_id: Default::default(),
+                context: Default::default(),
+                xvimage_id: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct CreateSubpictureReply {
     pub reply_type: u8,
     pub sequence: u16,
@@ -819,13 +1833,149 @@ pub struct CreateSubpictureReply {
     pub component_order: [Card8; 4],
     pub priv_data: Vec<Card32>,
 }
-impl CreateSubpictureReply {}
-impl AsByteSequence for CreateSubpictureReply {
+impl CreateSubpictureReply {
+    #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    pub fn priv_data_slice(bytes: &[u8]) -> Result<Cow<'_, [Card32]>, ParseError> {
+        let mut index: usize = 0;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
+        index += sz;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
+        index += sz;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width_actual, sz): (Card16, usize) = parse_item(bytes, index, "width_actual")?;
+        index += sz;
+        let (height_actual, sz): (Card16, usize) = parse_item(bytes, index, "height_actual")?;
+        index += sz;
+        let (num_palette_entries, sz): (Card16, usize) =
+            parse_item(bytes, index, "num_palette_entries")?;
+        index += sz;
+        let (entry_bytes, sz): (Card16, usize) = parse_item(bytes, index, "entry_bytes")?;
+        index += sz;
+        let (component_order, sz): ([Card8; 4], usize) =
+            parse_item(bytes, index, "component_order")?;
+        index += sz;
+        index += 12;
+        let (priv_data, _): (Cow<'_, [Card32]>, usize) =
+            parse_slice(bytes, index, (length as usize) as usize, "priv_data")?;
+        Ok(priv_data)
+    }
+    #[doc = " Read this reply out of a reader, without waiting for all of it to arrive. Use `from_bytes` instead if the reply is already in memory."]
+    #[cfg(feature = "std")]
+    pub fn read_from(reader: &mut dyn std::io::Read) -> Result<Self, std::io::Error> {
+        let mut index: usize = 0;
+        let reply_type: u8 = read_item(reader, &mut index, 1, "reply_type")?;
+        skip_bytes(reader, &mut index, 1)?;
+        let sequence: u16 = read_item(reader, &mut index, 2, "sequence")?;
+        let length: u32 = read_item(reader, &mut index, 4, "length")?;
+        let width_actual: Card16 = read_item(reader, &mut index, 2, "width_actual")?;
+        let height_actual: Card16 = read_item(reader, &mut index, 2, "height_actual")?;
+        let num_palette_entries: Card16 = read_item(reader, &mut index, 2, "num_palette_entries")?;
+        let entry_bytes: Card16 = read_item(reader, &mut index, 2, "entry_bytes")?;
+        let component_order: [Card8; 4] = read_item(reader, &mut index, 4, "component_order")?;
+        skip_bytes(reader, &mut index, 12)?;
+        let (priv_data, block_len): (Vec<Card32>, usize) = read_vector(
+            reader,
+            &mut index,
+            (length as usize) as usize,
+            4,
+            "priv_data",
+        )?;
+        skip_bytes(
+            reader,
+            &mut index,
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        )?;
+        skip_to_reply_end(reader, &mut index, length)?;
+        Ok(CreateSubpictureReply {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            width_actual: width_actual,
+            height_actual: height_actual,
+            num_palette_entries: num_palette_entries,
+            entry_bytes: entry_bytes,
+            component_order: component_order,
+            priv_data: priv_data,
+        })
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("width_actual", 8, 2),
+        ("height_actual", 10, 2),
+        ("num_palette_entries", 12, 2),
+        ("entry_bytes", 14, 2),
+        ("component_order", 16, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
     #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.width_actual == other.width_actual
+            && self.height_actual == other.height_actual
+            && self.num_palette_entries == other.num_palette_entries
+            && self.entry_bytes == other.entry_bytes
+            && self.component_order == other.component_order
+            && self.priv_data == other.priv_data
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_value(
+            "width_actual",
+            &self.width_actual,
+            &other.width_actual,
+            &mut changes,
+        );
+        diff_value(
+            "height_actual",
+            &self.height_actual,
+            &other.height_actual,
+            &mut changes,
+        );
+        diff_value(
+            "num_palette_entries",
+            &self.num_palette_entries,
+            &other.num_palette_entries,
+            &mut changes,
+        );
+        diff_value(
+            "entry_bytes",
+            &self.entry_bytes,
+            &other.entry_bytes,
+            &mut changes,
+        );
+        diff_value(
+            "component_order",
+            &self.component_order,
+            &other.component_order,
+            &mut changes,
+        );
+        diff_list("priv_data", &self.priv_data, &other.priv_data, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for CreateSubpictureReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.width_actual.as_bytes(&mut bytes[index..]);
@@ -833,39 +1983,46 @@ impl AsByteSequence for CreateSubpictureReply {
         index += self.num_palette_entries.as_bytes(&mut bytes[index..]);
         index += self.entry_bytes.as_bytes(&mut bytes[index..]);
         index += self.component_order.as_bytes(&mut bytes[index..]);
-        index += 12;
+        index += zero_pad(&mut bytes[index..], 12);
         let block_len: usize = vector_as_bytes(&self.priv_data, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<Card32>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSubpictureReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (width_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (width_actual, sz): (Card16, usize) = parse_item(bytes, index, "width_actual")?;
         index += sz;
-        let (height_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height_actual, sz): (Card16, usize) = parse_item(bytes, index, "height_actual")?;
         index += sz;
-        let (num_palette_entries, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_palette_entries, sz): (Card16, usize) =
+            parse_item(bytes, index, "num_palette_entries")?;
         index += sz;
-        let (entry_bytes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (entry_bytes, sz): (Card16, usize) = parse_item(bytes, index, "entry_bytes")?;
         index += sz;
-        let (component_order, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(&bytes[index..])?;
+        let (component_order, sz): ([Card8; 4], usize) =
+            parse_item(bytes, index, "component_order")?;
         index += sz;
         index += 12;
         let (priv_data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            parse_vector(bytes, index, (length as usize) as usize, "priv_data")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             CreateSubpictureReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -882,52 +2039,100 @@ impl AsByteSequence for CreateSubpictureReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.width_actual.size()
-            + self.height_actual.size()
-            + self.num_palette_entries.size()
-            + self.entry_bytes.size()
-            + self.component_order.size()
-            + 12
-            + {
-                let block_len: usize = self.priv_data.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
-    }
-}
-#[derive(Clone, Debug, Default)]
+        let size: usize = 32 + {
+            let block_len: usize = self.priv_data.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+    #[inline]
+    fn size_hint(&self) -> usize {
+        let size: usize = 32 + {
+            let block_len: usize = self.priv_data.len() * ::core::mem::size_of::<Card32>();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for CreateSubpictureReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[cfg(feature = "fuzzing")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateSubpictureReply {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let reply_type: u8 = u.arbitrary()?;
+        let sequence: u16 = u.arbitrary()?;
+        let length: u32 = u.arbitrary()?;
+        let width_actual: Card16 = u.arbitrary()?;
+        let height_actual: Card16 = u.arbitrary()?;
+        let num_palette_entries: Card16 = u.arbitrary()?;
+        let entry_bytes: Card16 = u.arbitrary()?;
+        let component_order: [Card8; 4] = u.arbitrary()?;
+        let priv_data = arbitrary_list(u, (length as usize) as usize)?;
+        Ok(Self {
+            reply_type: reply_type,
+            sequence: sequence,
+            length: length,
+            width_actual: width_actual,
+            height_actual: height_actual,
+            num_palette_entries: num_palette_entries,
+            entry_bytes: entry_bytes,
+            component_order: component_order,
+            priv_data: priv_data,
+        })
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct DestroySubpictureRequest {
     pub req_type: u8,
     pub length: u16,
     pub subpicture_id: Subpicture,
 }
-impl DestroySubpictureRequest {}
-impl AsByteSequence for DestroySubpictureRequest {
+impl DestroySubpictureRequest {
     #[inline]
+    pub fn builder() -> DestroySubpictureRequestBuilder {
+        Default::default()
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("req_type", 0, 1),
+        ("length", 2, 2),
+        ("subpicture_id", 4, 4),
+    ];
+}
+const _: () = assert!(<DestroySubpictureRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for DestroySubpictureRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.subpicture_id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroySubpictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (subpicture_id, sz): (Subpicture, usize) = <Subpicture>::from_bytes(&bytes[index..])?;
+        let (subpicture_id, sz): (Subpicture, usize) = parse_item(bytes, index, "subpicture_id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             DestroySubpictureRequest {
                 req_type: req_type,
                 length: length,
@@ -938,48 +2143,106 @@ impl AsByteSequence for DestroySubpictureRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.subpicture_id.size()
+        let size: usize = 8;
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for DestroySubpictureRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for DestroySubpictureRequest {
-    const OPCODE: u8 = 7;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 7;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroySubpicture";
     type Reply = ();
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `DestroySubpictureRequest`."]
+#[derive(Clone, Debug)]
+pub struct DestroySubpictureRequestBuilder {
+    pub inner: DestroySubpictureRequest,
+}
+impl DestroySubpictureRequestBuilder {
+    #[inline]
+    pub fn subpicture_id(mut self, subpicture_id: Subpicture) -> Self {
+        self.inner.subpicture_id = subpicture_id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> DestroySubpictureRequest {
+        self.inner
+    }
+}
+impl Default for DestroySubpictureRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: DestroySubpictureRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                subpicture_id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
+#[must_use = "requests do nothing unless sent to the server"]
 pub struct ListSubpictureTypesRequest {
     pub req_type: u8,
     pub length: u16,
     pub port_id: Port,
     pub surface_id: Surface,
 }
-impl ListSubpictureTypesRequest {}
-impl AsByteSequence for ListSubpictureTypesRequest {
+impl ListSubpictureTypesRequest {
+    #[inline]
+    pub fn builder() -> ListSubpictureTypesRequestBuilder {
+        Default::default()
+    }
+    #[doc = " Read what the server sent in response to this request, which is either its reply or an error."]
+    #[doc = " The response type in the first byte tells the two apart."]
     #[inline]
+    pub fn parse_reply_or_error(
+        bytes: &[u8],
+    ) -> Result<Result<ListSubpictureTypesReply, XError>, ParseError> {
+        parse_reply_or_error(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[("req_type", 0, 1), ("length", 2, 2)];
+}
+const _: () = assert!(<ListSubpictureTypesRequest as Request>::MINOR_OPCODE < 0x100);
+impl AsByteSequence for ListSubpictureTypesRequest {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.req_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += (<Self as Request>::MINOR_OPCODE as u8).as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.port_id.as_bytes(&mut bytes[index..]);
         index += self.surface_id.as_bytes(&mut bytes[index..]);
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSubpictureTypesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = parse_item(bytes, index, "req_type")?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (port_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port_id, sz): (Port, usize) = parse_item(bytes, index, "port_id")?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = parse_item(bytes, index, "surface_id")?;
         index += sz;
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListSubpictureTypesRequest {
                 req_type: req_type,
                 length: length,
@@ -991,57 +2254,140 @@ impl AsByteSequence for ListSubpictureTypesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size() + 1 + self.length.size() + self.port_id.size() + self.surface_id.size()
+        let size: usize = 8 + self.port_id.size();
+        size + buffer_pad(size, 4)
+    }
+}
+impl core::convert::TryFrom<&[u8]> for ListSubpictureTypesRequest {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
     }
 }
 impl Request for ListSubpictureTypesRequest {
-    const OPCODE: u8 = 8;
+    const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
+    const MINOR_OPCODE: u16 = 8;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListSubpictureTypes";
     type Reply = ListSubpictureTypesReply;
 }
-#[derive(Clone, Debug, Default)]
+#[doc = " A builder for `ListSubpictureTypesRequest`."]
+#[derive(Clone, Debug)]
+pub struct ListSubpictureTypesRequestBuilder {
+    pub inner: ListSubpictureTypesRequest,
+}
+impl ListSubpictureTypesRequestBuilder {
+    #[inline]
+    pub fn port_id(mut self, port_id: Port) -> Self {
+        self.inner.port_id = port_id;
+        self
+    }
+    #[inline]
+    pub fn surface_id(mut self, surface_id: Surface) -> Self {
+        self.inner.surface_id = surface_id;
+        self
+    }
+    #[inline]
+    #[must_use = "requests do nothing unless sent to the server"]
+    pub fn build(self) -> ListSubpictureTypesRequest {
+        self.inner
+    }
+}
+impl Default for ListSubpictureTypesRequestBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ListSubpictureTypesRequest {
+                req_type: Default::default(),
+                length: Default::default(),
+                port_id: Default::default(),
+                surface_id: Default::default(),
+            },
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary,))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,))]
 pub struct ListSubpictureTypesReply {
     pub reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub types: Vec<ImageFormatInfo>,
 }
-impl ListSubpictureTypesReply {}
-impl AsByteSequence for ListSubpictureTypesReply {
+impl ListSubpictureTypesReply {
     #[inline]
+    pub const fn sequence(&self) -> u16 {
+        self.sequence
+    }
+    #[doc = " Convert a sequence of bytes into this reply, like `from_bytes`, but fail if the reply is longer or shorter than its fields."]
+    #[doc = " Servers may add fields to replies in newer versions, so this is meant for checking the description of the reply."]
+    #[inline]
+    pub fn try_from_bytes_strict(bytes: &[u8]) -> Result<(Self, usize), StrictParseError> {
+        parse_reply_strict(bytes)
+    }
+    #[doc = " The name, offset and size in bytes of each field, as they are laid out in the bytes of this structure. Past the first item whose size isn't known ahead of time, the offsets depend on the value, so those fields are left out."]
+    pub const WIRE_FIELDS: &[(&str, usize, usize)] = &[
+        ("reply_type", 0, 1),
+        ("sequence", 2, 2),
+        ("length", 4, 4),
+        ("types_len", 8, 4),
+    ];
+    #[doc = " Tell whether this reply holds the same values as `other`. Unlike `==`, this leaves out the type, sequence number and length in the header, which depend on how the reply was sent."]
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.types == other.types
+    }
+    #[doc = " List the fields whose values differ between this reply and `other`, in the order they are sent in. The type, sequence number and length in the header are left out."]
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_list("types", &self.types, &other.types, &mut changes);
+        changes
+    }
+}
+impl AsByteSequence for ListSubpictureTypesReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += 1;
+        index += zero_pad(&mut bytes[index..], 1);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += (self.types.len() as Card32).as_bytes(&mut bytes[index..]);
-        index += 20;
+        index += zero_pad(&mut bytes[index..], 20);
         let block_len: usize = vector_as_bytes(&self.types, &mut bytes[index..]);
         index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
+        index += zero_pad(
+            &mut bytes[index..],
+            buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>()),
+        );
+        index += zero_pad(&mut bytes[index..], buffer_pad(index, 4));
         index
     }
-    #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSubpictureTypesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = parse_item(bytes, index, "reply_type")?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = parse_item(bytes, index, "sequence")?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = parse_item(bytes, index, "length")?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let bytes: &[u8] = reply_bytes(bytes, length);
+        let (len0, sz): (Card32, usize) = parse_item(bytes, index, "len0")?;
         index += sz;
         index += 20;
         let (types, block_len): (Vec<ImageFormatInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            parse_vector(bytes, index, len0 as usize, "types")?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
-        Some((
+        index += buffer_pad(index, 4);
+        Ok((
             ListSubpictureTypesReply {
                 reply_type: reply_type,
                 sequence: sequence,
@@ -1053,16 +2399,56 @@ impl AsByteSequence for ListSubpictureTypesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + ::core::mem::size_of::<Card32>()
-            + 20
-            + {
-                let block_len: usize = self.types.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
-                block_len + pad
-            }
+        let size: usize = 32 + {
+            let block_len: usize = self.types.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
+            block_len + pad
+        };
+        size + buffer_pad(size, 4)
     }
 }
+impl core::convert::TryFrom<&[u8]> for ListSubpictureTypesReply {
+    type Error = ParseError;
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes).map(|(value, _)| value)
+    }
+}
+#[doc = " The names of the events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const EVENT_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn event_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(EVENT_NAMES, opcode)
+}
+#[doc = " The names of the generic events in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const GENERIC_EVENT_NAMES: &[(u16, &str)] = &[];
+#[doc = " The name of the generic event with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn generic_event_name(opcode: u16) -> Option<&'static str> {
+    name_of_opcode(GENERIC_EVENT_NAMES, opcode)
+}
+#[doc = " The names of the errors in this namespace, sorted by their opcodes. The opcodes of an extension count up from the first one the server gives it."]
+pub const ERROR_NAMES: &[(u8, &str)] = &[];
+#[doc = " The name of the error with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn error_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(ERROR_NAMES, opcode)
+}
+#[doc = " The names of the requests in this namespace, sorted by their opcodes. The requests of an extension are numbered by their minor opcodes."]
+pub const REQUEST_NAMES: &[(u8, &str)] = &[
+    (0, "QueryVersion"),
+    (1, "ListSurfaceTypes"),
+    (2, "CreateContext"),
+    (3, "DestroyContext"),
+    (4, "CreateSurface"),
+    (5, "DestroySurface"),
+    (6, "CreateSubpicture"),
+    (7, "DestroySubpicture"),
+    (8, "ListSubpictureTypes"),
+];
+#[doc = " The name of the request with this opcode, if there is one in this namespace. This is meant for logging and error messages."]
+#[inline]
+pub fn request_name(opcode: u8) -> Option<&'static str> {
+    name_of_opcode(REQUEST_NAMES, opcode)
+}
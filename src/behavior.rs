@@ -7,10 +7,12 @@ use super::auto::{
         BehaviorType, CommonBehavior, DefaultBehavior, LockBehavior, OverlayBehavior,
         RadioGroupBehavior,
     },
-    AsByteSequence,
+    AsByteSequence, ParseError,
 };
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Behavior {
     Common(CommonBehavior),
     Default(DefaultBehavior),
@@ -56,12 +58,12 @@ impl AsByteSequence for Behavior {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let ty = BehaviorType::from_bytes(bytes);
 
         let res = match ty {
-            None => Self::Common(CommonBehavior::from_bytes(bytes)?.0),
-            Some((ty, _)) => match ty {
+            Err(_) => Self::Common(CommonBehavior::from_bytes(bytes)?.0),
+            Ok((ty, _)) => match ty {
                 BehaviorType::Default => Self::Default(DefaultBehavior::from_bytes(bytes)?.0),
                 BehaviorType::Lock => Self::Lock(LockBehavior::from_bytes(bytes)?.0),
                 BehaviorType::RadioGroup => {
@@ -84,6 +86,6 @@ impl AsByteSequence for Behavior {
             },
         };
 
-        Some((res, BEHAVIOR_SIZE))
+        Ok((res, BEHAVIOR_SIZE))
     }
 }
@@ -3,14 +3,16 @@
 //! This module provides the `ClientMessageData` structure, which is the data type returned by the
 //! `ClientMessageEvent` object.
 
-use crate::auto::AsByteSequence;
+use crate::auto::{unreadable, AsByteSequence, ParseError};
 use core::mem;
 
 /// The data returned from a client message. This is dictated by protocol to be five 32-bit integers in length;
 /// however, it could also represent 10 16-bit integers or 20 8-bit integers. It is usually represented as a
 /// union; however, the `bytes`, `shorts`, and `longs` methods can be used to access each of these
 /// representations.
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct ClientMessageData {
     // convention states that it should be at least 5 u32's
@@ -82,13 +84,13 @@ impl AsByteSequence for ClientMessageData {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         if bytes.len() < BYTE_LEN {
-            None
+            Err(unreadable::<Self>())
         } else {
             let mut res: [u32; LONG_LEN] = [0; LONG_LEN];
             res.copy_from_slice(bytemuck::cast_slice(bytes));
-            Some((ClientMessageData { data: res }, BYTE_LEN))
+            Ok((ClientMessageData { data: res }, BYTE_LEN))
         }
     }
 }
@@ -0,0 +1,154 @@
+// MIT/Apache2 License
+
+use super::{AsyncConnection, Display};
+use crate::{error::BreadError, event::Event};
+use alloc::boxed::Box;
+use core::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_lite::Stream;
+
+type WaitFuture<'a, Conn> =
+    Pin<Box<dyn Future<Output = (&'a mut Display<Conn>, crate::Result<Event>)> + Send + 'a>>;
+
+enum State<'a, Conn> {
+    /// Not waiting for anything; the next poll checks the event queue first.
+    Idle(&'a mut Display<Conn>),
+    /// Reading from the connection until the next event is found.
+    Waiting(WaitFuture<'a, Conn>),
+    /// The connection was closed, so no more events will come.
+    Closed,
+}
+
+/// A stream of the events that the X server sends to a `Display`, created by `Display::event_stream`.
+///
+/// Each event is read in full, including the extra bytes of generic events, before it is parsed.
+/// The read that is in progress is kept by the stream, rather than by the future that polls it, so
+/// the stream can be polled in a `select` loop without losing the bytes of a partially read event
+/// when another branch finishes first. Replies and errors read along the way are stored in the
+/// display, as they would be by `wait_for_event_async`.
+///
+/// The stream yields `crate::Result<Event>` rather than `Event`, since reading an event can fail.
+/// Errors that come from the connection itself, such as an I/O error or the server closing the
+/// connection, are yielded once, and then the stream ends, since nothing more can be read from it.
+/// Other errors, such as an X error that no request is waiting for, leave the stream open.
+pub struct EventStream<'a, Conn> {
+    state: State<'a, Conn>,
+}
+
+impl<'a, Conn> EventStream<'a, Conn> {
+    #[inline]
+    pub(crate) fn new(display: &'a mut Display<Conn>) -> Self {
+        Self {
+            state: State::Idle(display),
+        }
+    }
+}
+
+impl<Conn: AsyncConnection + Send> Stream for EventStream<'_, Conn> {
+    type Item = crate::Result<Event>;
+
+    #[inline]
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match mem::replace(&mut self.state, State::Closed) {
+                State::Idle(display) => match display.event_queue.pop_front() {
+                    Some(event) => {
+                        self.state = State::Idle(display);
+                        return Poll::Ready(Some(Ok(event)));
+                    }
+                    None => {
+                        self.state = State::Waiting(Box::pin(async move {
+                            let event = display.wait_for_event_async().await;
+                            (display, event)
+                        }));
+                    }
+                },
+                State::Waiting(mut wait) => match wait.as_mut().poll(cx) {
+                    Poll::Ready((display, event)) => {
+                        if !matches!(
+                            event,
+                            Err(BreadError::ClosedConnection
+                                | BreadError::Io(_)
+                                | BreadError::Tainted)
+                        ) {
+                            self.state = State::Idle(display);
+                        }
+                        return Poll::Ready(Some(event));
+                    }
+                    Poll::Pending => {
+                        self.state = State::Waiting(wait);
+                        return Poll::Pending;
+                    }
+                },
+                State::Closed => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[test]
+fn yield_events_until_connection_fails() {
+    use super::connection::GenericConnFuture;
+    use crate::Fd;
+    use alloc::{collections::VecDeque, vec::Vec};
+    use futures_lite::{future::block_on, StreamExt};
+
+    /// A connection that reads out the bytes queued in it, and fails once they run out.
+    struct QueuedBytes(VecDeque<u8>);
+
+    impl AsyncConnection for QueuedBytes {
+        fn send_packet<'future, 'a, 'b, 'c>(
+            &'a mut self,
+            _bytes: &'b [u8],
+            _fds: &'c mut Vec<Fd>,
+        ) -> GenericConnFuture<'future>
+        where
+            'a: 'future,
+            'b: 'future,
+            'c: 'future,
+        {
+            Box::pin(async { Ok(()) })
+        }
+
+        fn read_packet<'future, 'a, 'b, 'c>(
+            &'a mut self,
+            bytes: &'b mut [u8],
+            _fds: &'c mut Vec<Fd>,
+        ) -> GenericConnFuture<'future>
+        where
+            'a: 'future,
+            'b: 'future,
+            'c: 'future,
+        {
+            Box::pin(async move {
+                let len = bytes.len();
+                if self.0.len() < len {
+                    return Err(BreadError::Io("Connection reset".into()));
+                }
+                bytes
+                    .iter_mut()
+                    .zip(self.0.drain(..len))
+                    .for_each(|(b, q)| *b = q);
+                Ok(())
+            })
+        }
+    }
+
+    // an expose event, then a map notify event
+    let mut queued = VecDeque::new();
+    queued.extend(core::iter::once(12).chain(core::iter::repeat(0).take(31)));
+    queued.extend(core::iter::once(19).chain(core::iter::repeat(0).take(31)));
+    let mut display = Display::from_connection_internal(QueuedBytes(queued));
+    let mut events = display.event_stream();
+
+    block_on(async {
+        assert!(matches!(events.next().await, Some(Ok(Event::Expose(_)))));
+        assert!(matches!(events.next().await, Some(Ok(Event::MapNotify(_)))));
+        assert!(matches!(events.next().await, Some(Err(BreadError::Io(_)))));
+        assert!(events.next().await.is_none());
+    });
+}
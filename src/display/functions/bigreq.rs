@@ -0,0 +1,32 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::bigreq::EnableRequest,
+    display::{Connection, Display},
+    sr_request,
+};
+
+#[cfg(feature = "async")]
+use crate::display::AsyncConnection;
+
+impl<Conn: Connection> Display<Conn> {
+    /// Enable the BIG-REQUESTS extension, which allows requests longer than 256 kilobytes to be
+    /// sent to the server. Returns the new maximum length of a request, in four-byte units.
+    #[inline]
+    pub fn enable_big_requests(&mut self) -> crate::Result<u32> {
+        let reply = sr_request!(self, EnableRequest::default())?;
+        self.bigreq_max_length = Some(reply.maximum_request_length);
+        Ok(reply.maximum_request_length)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Conn: AsyncConnection + Send> Display<Conn> {
+    /// Enable the BIG-REQUESTS extension, async redox.
+    #[inline]
+    pub async fn enable_big_requests_async(&mut self) -> crate::Result<u32> {
+        let reply = sr_request!(self, EnableRequest::default(), async).await?;
+        self.bigreq_max_length = Some(reply.maximum_request_length);
+        Ok(reply.maximum_request_length)
+    }
+}
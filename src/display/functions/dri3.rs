@@ -12,13 +12,12 @@ use crate::{
         },
         sync::Fence,
         xproto::Drawable,
-        AsByteSequence,
     },
     display::{Connection, Display, RequestCookie},
     extension::ExtensionVersion,
     send_request, sr_request, Fd, Pixmap, Window,
 };
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 use cty::c_int;
 
 #[cfg(feature = "async")]
@@ -33,11 +32,10 @@ pub struct Modifiers {
 impl<Conn> Display<Conn> {
     #[inline]
     fn open_dri3_request(drawable: Drawable, provider: u32) -> OpenRequest {
-        OpenRequest {
-            drawable,
-            provider,
-            ..Default::default()
-        }
+        OpenRequest::builder()
+            .drawable(drawable)
+            .provider(provider)
+            .build()
     }
 }
 
@@ -59,8 +57,8 @@ impl<Conn: Connection> Display<Conn> {
         provider: u32,
     ) -> crate::Result<c_int> {
         let tok = self.open_dri3(drawable, provider)?;
-        let mut repl = self.resolve_request(tok)?;
-        Ok(repl.file_descriptors().unwrap()[0])
+        let repl = self.resolve_request(tok)?;
+        Ok(repl.device_fd)
     }
 
     #[inline]
@@ -71,11 +69,10 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryVersionRequest>> {
         send_request!(
             self,
-            QueryVersionRequest {
-                major_version: major,
-                minor_version: minor,
-                ..Default::default()
-            }
+            QueryVersionRequest::builder()
+                .major_version(major)
+                .minor_version(minor)
+                .build()
         )
     }
 
@@ -102,12 +99,11 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetSupportedModifiersRequest>> {
         send_request!(
             self,
-            GetSupportedModifiersRequest {
-                window,
-                depth,
-                bpp,
-                ..Default::default()
-            }
+            GetSupportedModifiersRequest::builder()
+                .window(window)
+                .depth(depth)
+                .bpp(bpp)
+                .build()
         )
     }
 
@@ -147,25 +143,24 @@ impl<Conn: Connection> Display<Conn> {
         fds.retain(|fd| *fd != -1);
         sr_request!(
             self,
-            PixmapFromBuffersRequest {
-                pixmap: xid,
-                window,
-                width,
-                height,
-                depth,
-                bpp,
-                modifier,
-                stride0: strides[0],
-                stride1: strides[1],
-                stride2: strides[2],
-                stride3: strides[3],
-                offset0: offsets[0],
-                offset1: offsets[1],
-                offset2: offsets[2],
-                offset3: offsets[3],
-                buffers: fds,
-                ..Default::default()
-            }
+            PixmapFromBuffersRequest::builder()
+                .pixmap(xid)
+                .window(window)
+                .width(width)
+                .height(height)
+                .depth(depth)
+                .bpp(bpp)
+                .modifier(modifier)
+                .stride0(strides[0])
+                .stride1(strides[1])
+                .stride2(strides[2])
+                .stride3(strides[3])
+                .offset0(offsets[0])
+                .offset1(offsets[1])
+                .offset2(offsets[2])
+                .offset3(offsets[3])
+                .buffers(fds)
+                .build()
         )?;
         Ok(xid)
     }
@@ -185,18 +180,17 @@ impl<Conn: Connection> Display<Conn> {
         let xid = Pixmap::const_from_xid(self.generate_xid()?);
         sr_request!(
             self,
-            PixmapFromBufferRequest {
-                pixmap: xid,
-                drawable,
-                size,
-                width,
-                height,
-                stride,
-                depth,
-                bpp,
-                pixmap_fd: vec![fd],
-                ..Default::default()
-            }
+            PixmapFromBufferRequest::builder()
+                .pixmap(xid)
+                .drawable(drawable)
+                .size(size)
+                .width(width)
+                .height(height)
+                .stride(stride)
+                .depth(depth)
+                .bpp(bpp)
+                .pixmap_fd(fd)
+                .build()
         )?;
         Ok(xid)
     }
@@ -211,13 +205,12 @@ impl<Conn: Connection> Display<Conn> {
         let xid = Fence::const_from_xid(self.generate_xid()?);
         sr_request!(
             self,
-            FenceFromFdRequest {
-                drawable,
-                initially_triggered,
-                fence: xid.xid,
-                fence_fd: vec![fence_fd],
-                ..Default::default()
-            }
+            FenceFromFdRequest::builder()
+                .drawable(drawable)
+                .initially_triggered(initially_triggered)
+                .fence(xid.xid)
+                .fence_fd(fence_fd)
+                .build()
         )?;
         Ok(xid)
     }
@@ -229,10 +222,7 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<BufferFromPixmapRequest>> {
         send_request!(
             self,
-            BufferFromPixmapRequest {
-                pixmap,
-                ..Default::default()
-            }
+            BufferFromPixmapRequest::builder().pixmap(pixmap).build()
         )
     }
 
@@ -252,10 +242,7 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<BuffersFromPixmapRequest>> {
         send_request!(
             self,
-            BuffersFromPixmapRequest {
-                pixmap,
-                ..Default::default()
-            }
+            BuffersFromPixmapRequest::builder().pixmap(pixmap).build()
         )
     }
 
@@ -292,8 +279,8 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         provider: u32,
     ) -> crate::Result<c_int> {
         let tok = self.open_dri3_async(drawable, provider).await?;
-        let mut repl = self.resolve_request_async(tok).await?;
-        Ok(repl.file_descriptors().unwrap()[0])
+        let repl = self.resolve_request_async(tok).await?;
+        Ok(repl.device_fd)
     }
 
     #[inline]
@@ -304,11 +291,10 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryVersionRequest>> {
         send_request!(
             self,
-            QueryVersionRequest {
-                major_version: major,
-                minor_version: minor,
-                ..Default::default()
-            },
+            QueryVersionRequest::builder()
+                .major_version(major)
+                .minor_version(minor)
+                .build(),
             async
         )
         .await
@@ -337,12 +323,11 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetSupportedModifiersRequest>> {
         send_request!(
             self,
-            GetSupportedModifiersRequest {
-                window,
-                depth,
-                bpp,
-                ..Default::default()
-            },
+            GetSupportedModifiersRequest::builder()
+                .window(window)
+                .depth(depth)
+                .bpp(bpp)
+                .build(),
             async
         )
         .await
@@ -386,25 +371,24 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         fds.retain(|fd| *fd != -1);
         sr_request!(
             self,
-            PixmapFromBuffersRequest {
-                pixmap: xid,
-                window,
-                width,
-                height,
-                depth,
-                bpp,
-                modifier,
-                stride0: strides[0],
-                stride1: strides[1],
-                stride2: strides[2],
-                stride3: strides[3],
-                offset0: offsets[0],
-                offset1: offsets[1],
-                offset2: offsets[2],
-                offset3: offsets[3],
-                buffers: fds,
-                ..Default::default()
-            },
+            PixmapFromBuffersRequest::builder()
+                .pixmap(xid)
+                .window(window)
+                .width(width)
+                .height(height)
+                .depth(depth)
+                .bpp(bpp)
+                .modifier(modifier)
+                .stride0(strides[0])
+                .stride1(strides[1])
+                .stride2(strides[2])
+                .stride3(strides[3])
+                .offset0(offsets[0])
+                .offset1(offsets[1])
+                .offset2(offsets[2])
+                .offset3(offsets[3])
+                .buffers(fds)
+                .build(),
             async
         )
         .await?;
@@ -426,18 +410,17 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         let xid = Pixmap::const_from_xid(self.generate_xid()?);
         sr_request!(
             self,
-            PixmapFromBufferRequest {
-                pixmap: xid,
-                drawable,
-                size,
-                width,
-                height,
-                stride,
-                depth,
-                bpp,
-                pixmap_fd: vec![fd],
-                ..Default::default()
-            },
+            PixmapFromBufferRequest::builder()
+                .pixmap(xid)
+                .drawable(drawable)
+                .size(size)
+                .width(width)
+                .height(height)
+                .stride(stride)
+                .depth(depth)
+                .bpp(bpp)
+                .pixmap_fd(fd)
+                .build(),
             async
         )
         .await?;
@@ -454,13 +437,12 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         let xid = Fence::const_from_xid(self.generate_xid()?);
         sr_request!(
             self,
-            FenceFromFdRequest {
-                drawable,
-                initially_triggered,
-                fence: xid.xid,
-                fence_fd: vec![fence_fd],
-                ..Default::default()
-            },
+            FenceFromFdRequest::builder()
+                .drawable(drawable)
+                .initially_triggered(initially_triggered)
+                .fence(xid.xid)
+                .fence_fd(fence_fd)
+                .build(),
             async
         )
         .await?;
@@ -474,10 +456,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<BufferFromPixmapRequest>> {
         send_request!(
             self,
-            BufferFromPixmapRequest {
-                pixmap,
-                ..Default::default()
-            },
+            BufferFromPixmapRequest::builder().pixmap(pixmap).build(),
             async
         )
         .await
@@ -499,10 +478,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<BuffersFromPixmapRequest>> {
         send_request!(
             self,
-            BuffersFromPixmapRequest {
-                pixmap,
-                ..Default::default()
-            },
+            BuffersFromPixmapRequest::builder().pixmap(pixmap).build(),
             async
         )
         .await
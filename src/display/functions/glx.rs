@@ -68,16 +68,15 @@ impl<Conn> Display<Conn> {
         attribs: Vec<u32>,
     ) -> CreateContextAttribsArbRequest {
         let attribs_len: u32 = attribs.len().try_into().expect("usize dont fit 2");
-        CreateContextAttribsArbRequest {
-            context,
-            fbconfig,
-            screen: screen.try_into().expect("usize dont fit"),
-            share_list,
-            is_direct,
-            num_attribs: attribs_len / 2u32,
-            attribs,
-            ..Default::default()
-        }
+        CreateContextAttribsArbRequest::builder()
+            .context(context)
+            .fbconfig(fbconfig)
+            .screen(screen.try_into().expect("usize dont fit"))
+            .share_list(share_list)
+            .is_direct(is_direct)
+            .num_attribs(attribs_len / 2u32)
+            .attribs(attribs)
+            .build()
     }
 }
 
@@ -91,11 +90,10 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryVersionRequest>> {
         send_request!(
             self,
-            QueryVersionRequest {
-                major_version: required_major,
-                minor_version: required_minor,
-                ..Default::default()
-            }
+            QueryVersionRequest::builder()
+                .major_version(required_major)
+                .minor_version(required_minor)
+                .build()
         )
     }
 
@@ -119,10 +117,9 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetVisualConfigsRequest>> {
         send_request!(
             self,
-            GetVisualConfigsRequest {
-                screen: screen as _,
-                ..Default::default()
-            }
+            GetVisualConfigsRequest::builder()
+                .screen(screen as _)
+                .build()
         )
     }
 
@@ -141,10 +138,7 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetFbConfigsRequest>> {
         send_request!(
             self,
-            GetFbConfigsRequest {
-                screen: screen as _,
-                ..Default::default()
-            }
+            GetFbConfigsRequest::builder().screen(screen as _).build()
         )
     }
 
@@ -163,10 +157,9 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetDrawableAttributesRequest>> {
         send_request!(
             self,
-            GetDrawableAttributesRequest {
-                drawable,
-                ..Default::default()
-            }
+            GetDrawableAttributesRequest::builder()
+                .drawable(drawable)
+                .build()
         )
     }
 
@@ -208,11 +201,10 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result {
         sr_request!(
             self,
-            SwapBuffersRequest {
-                context_tag,
-                drawable: drawable.into(),
-                ..Default::default()
-            }
+            SwapBuffersRequest::builder()
+                .context_tag(context_tag)
+                .drawable(drawable.into())
+                .build()
         )
     }
 }
@@ -228,11 +220,10 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryVersionRequest>> {
         send_request!(
             self,
-            QueryVersionRequest {
-                major_version: required_major,
-                minor_version: required_minor,
-                ..Default::default()
-            },
+            QueryVersionRequest::builder()
+                .major_version(required_major)
+                .minor_version(required_minor)
+                .build(),
             async
         )
         .await
@@ -246,10 +237,9 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetVisualConfigsRequest>> {
         send_request!(
             self,
-            GetVisualConfigsRequest {
-                screen: screen as _,
-                ..Default::default()
-            },
+            GetVisualConfigsRequest::builder()
+                .screen(screen as _)
+                .build(),
             async
         )
         .await
@@ -273,10 +263,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetFbConfigsRequest>> {
         send_request!(
             self,
-            GetFbConfigsRequest {
-                screen: screen as _,
-                ..Default::default()
-            },
+            GetFbConfigsRequest::builder().screen(screen as _).build(),
             async
         )
         .await
@@ -300,10 +287,9 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<GetDrawableAttributesRequest>> {
         send_request!(
             self,
-            GetDrawableAttributesRequest {
-                drawable,
-                ..Default::default()
-            },
+            GetDrawableAttributesRequest::builder()
+                .drawable(drawable)
+                .build(),
             async
         )
         .await
@@ -363,11 +349,10 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result {
         sr_request!(
             self,
-            SwapBuffersRequest {
-                context_tag,
-                drawable: drawable.into(),
-                ..Default::default()
-            },
+            SwapBuffersRequest::builder()
+                .context_tag(context_tag)
+                .drawable(drawable.into())
+                .build(),
             async
         )
         .await
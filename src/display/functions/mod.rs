@@ -4,6 +4,8 @@
 
 mod xproto;
 
+#[cfg(feature = "bigreq")]
+mod bigreq;
 #[cfg(feature = "dri3")]
 mod dri3;
 #[cfg(feature = "glx")]
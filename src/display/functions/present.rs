@@ -31,11 +31,10 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryVersionRequest>> {
         send_request!(
             self,
-            QueryVersionRequest {
-                major_version: major,
-                minor_version: minor,
-                ..Default::default()
-            }
+            QueryVersionRequest::builder()
+                .major_version(major)
+                .minor_version(minor)
+                .build()
         )
     }
 
@@ -60,10 +59,9 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryCapabilitiesRequest>> {
         send_request!(
             self,
-            QueryCapabilitiesRequest {
-                target: drawable.into().xid,
-                ..Default::default()
-            }
+            QueryCapabilitiesRequest::builder()
+                .target(drawable.into().xid)
+                .build()
         )
     }
 
@@ -86,12 +84,11 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result<()> {
         sr_request!(
             self,
-            SelectInputRequest {
-                eid: Event::const_from_xid(eid),
-                window,
-                event_mask: em,
-                ..Default::default()
-            }
+            SelectInputRequest::builder()
+                .eid(Event::const_from_xid(eid))
+                .window(window)
+                .event_mask(em)
+                .build()
         )
     }
 
@@ -116,24 +113,23 @@ impl<Conn: Connection> Display<Conn> {
     ) -> crate::Result {
         sr_request!(
             self,
-            PixmapRequest {
-                window,
-                pixmap,
-                serial,
-                valid,
-                update,
-                x_off: xoff,
-                y_off: yoff,
-                target_crtc,
-                wait_fence,
-                idle_fence,
-                options,
-                target_msc,
-                divisor,
-                remainder,
-                notifies,
-                ..Default::default()
-            }
+            PixmapRequest::builder()
+                .window(window)
+                .pixmap(pixmap)
+                .serial(serial)
+                .valid(valid)
+                .update(update)
+                .x_off(xoff)
+                .y_off(yoff)
+                .target_crtc(target_crtc)
+                .wait_fence(wait_fence)
+                .idle_fence(idle_fence)
+                .options(options)
+                .target_msc(target_msc)
+                .divisor(divisor)
+                .remainder(remainder)
+                .notifies(notifies)
+                .build()
         )
     }
 }
@@ -148,11 +144,10 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryVersionRequest>> {
         send_request!(
             self,
-            QueryVersionRequest {
-                major_version: major,
-                minor_version: minor,
-                ..Default::default()
-            },
+            QueryVersionRequest::builder()
+                .major_version(major)
+                .minor_version(minor)
+                .build(),
             async
         )
         .await
@@ -179,10 +174,9 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryCapabilitiesRequest>> {
         send_request!(
             self,
-            QueryCapabilitiesRequest {
-                target: drawable.into().xid,
-                ..Default::default()
-            },
+            QueryCapabilitiesRequest::builder()
+                .target(drawable.into().xid)
+                .build(),
             async
         )
         .await
@@ -207,12 +201,11 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<()> {
         sr_request!(
             self,
-            SelectInputRequest {
-                eid: Event::const_from_xid(eid),
-                window,
-                event_mask: em,
-                ..Default::default()
-            },
+            SelectInputRequest::builder()
+                .eid(Event::const_from_xid(eid))
+                .window(window)
+                .event_mask(em)
+                .build(),
             async
         )
         .await
@@ -239,24 +232,23 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result {
         sr_request!(
             self,
-            PixmapRequest {
-                window,
-                pixmap,
-                serial,
-                valid,
-                update,
-                x_off: xoff,
-                y_off: yoff,
-                target_crtc,
-                wait_fence,
-                idle_fence,
-                options,
-                target_msc,
-                divisor,
-                remainder,
-                notifies,
-                ..Default::default()
-            },
+            PixmapRequest::builder()
+                .window(window)
+                .pixmap(pixmap)
+                .serial(serial)
+                .valid(valid)
+                .update(update)
+                .x_off(xoff)
+                .y_off(yoff)
+                .target_crtc(target_crtc)
+                .wait_fence(wait_fence)
+                .idle_fence(idle_fence)
+                .options(options)
+                .target_msc(target_msc)
+                .divisor(divisor)
+                .remainder(remainder)
+                .notifies(notifies)
+                .build(),
             async
         )
         .await
@@ -12,24 +12,12 @@ use crate::display::AsyncConnection;
 impl<Conn: Connection> Display<Conn> {
     #[inline]
     pub fn trigger_fence(&mut self, fence: Fence) -> crate::Result {
-        sr_request!(
-            self,
-            TriggerFenceRequest {
-                fence,
-                ..Default::default()
-            }
-        )
+        sr_request!(self, TriggerFenceRequest::builder().fence(fence).build())
     }
 
     #[inline]
     pub fn free_sync_fence(&mut self, fence: Fence) -> crate::Result {
-        sr_request!(
-            self,
-            DestroyFenceRequest {
-                fence,
-                ..Default::default()
-            }
-        )
+        sr_request!(self, DestroyFenceRequest::builder().fence(fence).build())
     }
 }
 
@@ -39,10 +27,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     pub async fn trigger_fence_async(&mut self, fence: Fence) -> crate::Result {
         sr_request!(
             self,
-            TriggerFenceRequest {
-                fence,
-                ..Default::default()
-            },
+            TriggerFenceRequest::builder().fence(fence).build(),
             async
         )
         .await
@@ -52,10 +37,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     pub async fn free_sync_fence_async(&mut self, fence: Fence) -> crate::Result {
         sr_request!(
             self,
-            DestroyFenceRequest {
-                fence,
-                ..Default::default()
-            },
+            DestroyFenceRequest::builder().fence(fence).build(),
             async
         )
         .await
@@ -19,11 +19,10 @@ impl<Conn: Connection> Display<Conn> {
         let xid = Region::const_from_xid(self.generate_xid()?);
         sr_request!(
             self,
-            CreateRegionRequest {
-                region: xid,
-                rectangles,
-                ..Default::default()
-            }
+            CreateRegionRequest::builder()
+                .region(xid)
+                .rectangles(rectangles)
+                .build()
         )?;
         Ok(xid)
     }
@@ -39,11 +38,10 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         let xid = Region::const_from_xid(self.generate_xid()?);
         sr_request!(
             self,
-            CreateRegionRequest {
-                region: xid,
-                rectangles,
-                ..Default::default()
-            },
+            CreateRegionRequest::builder()
+                .region(xid)
+                .rectangles(rectangles)
+                .build(),
             async
         )
         .await?;
@@ -54,13 +52,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
 impl Region {
     #[inline]
     pub fn destroy<Conn: Connection>(self, dpy: &mut Display<Conn>) -> crate::Result {
-        sr_request!(
-            dpy,
-            DestroyRegionRequest {
-                region: self,
-                ..Default::default()
-            }
-        )
+        sr_request!(dpy, DestroyRegionRequest::builder().region(self).build())
     }
 
     #[cfg(feature = "async")]
@@ -71,10 +63,7 @@ impl Region {
     ) -> crate::Result {
         sr_request!(
             dpy,
-            DestroyRegionRequest {
-                region: self,
-                ..Default::default()
-            },
+            DestroyRegionRequest::builder().region(self).build(),
             async
         )
         .await
@@ -72,13 +72,12 @@ impl Colormap {
     /// Alloc color request.
     #[inline]
     fn alloc_color_request(self, r: u16, g: u16, b: u16) -> AllocColorRequest {
-        AllocColorRequest {
-            cmap: self,
-            red: r,
-            green: g,
-            blue: b,
-            ..Default::default()
-        }
+        AllocColorRequest::builder()
+            .cmap(self)
+            .red(r)
+            .green(g)
+            .blue(b)
+            .build()
     }
 
     /// Allocate a new color in the colormap.
@@ -12,13 +12,7 @@ use crate::display::AsyncConnection;
 impl Cursor {
     #[inline]
     pub fn free<Conn: Connection>(self, dpy: &mut Display<Conn>) -> crate::Result {
-        sr_request!(
-            dpy,
-            FreeCursorRequest {
-                cursor: self,
-                ..Default::default()
-            }
-        )
+        sr_request!(dpy, FreeCursorRequest::builder().cursor(self).build())
     }
 
     #[cfg(feature = "async")]
@@ -29,10 +23,7 @@ impl Cursor {
     ) -> crate::Result {
         sr_request!(
             dpy,
-            FreeCursorRequest {
-                cursor: self,
-                ..Default::default()
-            },
+            FreeCursorRequest::builder().cursor(self).build(),
             async
         )
         .await
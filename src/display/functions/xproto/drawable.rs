@@ -47,10 +47,7 @@ impl From<GetGeometryReply> for Geometry {
 /// Get geometry request.
 #[inline]
 fn get_geometry_request(target: Drawable) -> GetGeometryRequest {
-    GetGeometryRequest {
-        drawable: target,
-        ..Default::default()
-    }
+    GetGeometryRequest::builder().drawable(target).build()
 }
 
 /// Copy area request.
@@ -66,18 +63,17 @@ fn copy_area_request(
     dst_x: i16,
     dst_y: i16,
 ) -> CopyAreaRequest {
-    CopyAreaRequest {
-        src_drawable,
-        dst_drawable,
-        gc,
-        src_x,
-        src_y,
-        dst_x,
-        dst_y,
-        width,
-        height,
-        ..Default::default()
-    }
+    CopyAreaRequest::builder()
+        .src_drawable(src_drawable)
+        .dst_drawable(dst_drawable)
+        .gc(gc)
+        .src_x(src_x)
+        .src_y(src_y)
+        .dst_x(dst_x)
+        .dst_y(dst_y)
+        .width(width)
+        .height(height)
+        .build()
 }
 
 /// Copy plane request.
@@ -94,19 +90,18 @@ fn copy_plane_request(
     dst_y: i16,
     bit_plane: u32,
 ) -> CopyPlaneRequest {
-    CopyPlaneRequest {
-        src_drawable,
-        dst_drawable,
-        gc,
-        src_x,
-        src_y,
-        dst_x,
-        dst_y,
-        width,
-        height,
-        bit_plane,
-        ..Default::default()
-    }
+    CopyPlaneRequest::builder()
+        .src_drawable(src_drawable)
+        .dst_drawable(dst_drawable)
+        .gc(gc)
+        .src_x(src_x)
+        .src_y(src_y)
+        .dst_x(dst_x)
+        .dst_y(dst_y)
+        .width(width)
+        .height(height)
+        .bit_plane(bit_plane)
+        .build()
 }
 
 /// Create pixmap request.
@@ -118,14 +113,15 @@ fn create_pixmap_request(
     height: u16,
     depth: u8,
 ) -> CreatePixmapRequest {
-    CreatePixmapRequest {
-        drawable,
-        pid,
-        width,
-        height,
-        depth,
-        ..Default::default()
-    }
+    let mut request = CreatePixmapRequest::builder()
+        .drawable(drawable)
+        .pid(pid)
+        .width(width)
+        .height(height)
+        .build();
+    // the server checks the depth against the ones that the screen supports
+    request.depth = depth;
+    request
 }
 
 impl<Conn: Connection> Display<Conn> {
@@ -15,10 +15,7 @@ use crate::display::AsyncConnection;
 impl Gcontext {
     #[inline]
     fn change_request(self, params: GcParameters) -> ChangeGcRequest {
-        let mut cgcr = ChangeGcRequest {
-            gc: self,
-            ..Default::default()
-        };
+        let mut cgcr = ChangeGcRequest::builder().gc(self).build();
 
         let g = params.mask_change_gc_request(&mut cgcr);
         cgcr.value_mask = g;
@@ -49,12 +46,11 @@ impl Gcontext {
     /// Request to draw a line.
     #[inline]
     fn poly_segment_request(self, drawable: Drawable, line: &[Segment]) -> PolySegmentRequest {
-        PolySegmentRequest {
-            drawable,
-            gc: self,
-            segments: line.to_vec(),
-            ..Default::default()
-        }
+        PolySegmentRequest::builder()
+            .drawable(drawable)
+            .gc(self)
+            .segments(line.to_vec())
+            .build()
     }
 
     /// Draw a set of lines.
@@ -118,12 +114,11 @@ impl Gcontext {
         target: Drawable,
         rectangles: &[Rectangle],
     ) -> PolyRectangleRequest {
-        PolyRectangleRequest {
-            drawable: target,
-            gc: self,
-            rectangles: rectangles.to_vec(),
-            ..Default::default()
-        }
+        PolyRectangleRequest::builder()
+            .drawable(target)
+            .gc(self)
+            .rectangles(rectangles.to_vec())
+            .build()
     }
 
     /// Draw one or more rectangles to the screen.
@@ -191,12 +186,11 @@ impl Gcontext {
     /// Arc drawing request.
     #[inline]
     fn poly_arc_request(self, target: Drawable, arcs: &[Arc]) -> PolyArcRequest {
-        PolyArcRequest {
-            drawable: target,
-            gc: self,
-            arcs: arcs.to_vec(),
-            ..Default::default()
-        }
+        PolyArcRequest::builder()
+            .drawable(target)
+            .gc(self)
+            .arcs(arcs.to_vec())
+            .build()
     }
 
     /// Draw one or more arcs to the screen.
@@ -262,14 +256,13 @@ impl Gcontext {
         mode: CoordMode,
         points: &[Point],
     ) -> FillPolyRequest {
-        FillPolyRequest {
-            drawable,
-            gc: self,
-            shape,
-            coordinate_mode: mode,
-            points: points.to_vec(),
-            ..Default::default()
-        }
+        FillPolyRequest::builder()
+            .drawable(drawable)
+            .gc(self)
+            .shape(shape)
+            .coordinate_mode(mode)
+            .points(points.to_vec())
+            .build()
     }
 
     /// Fill a polygon specified by the given points.
@@ -322,12 +315,11 @@ impl Gcontext {
         drawable: Drawable,
         rectangles: &[Rectangle],
     ) -> PolyFillRectangleRequest {
-        PolyFillRectangleRequest {
-            drawable,
-            gc: self,
-            rectangles: rectangles.to_vec(),
-            ..Default::default()
-        }
+        PolyFillRectangleRequest::builder()
+            .drawable(drawable)
+            .gc(self)
+            .rectangles(rectangles.to_vec())
+            .build()
     }
 
     /// Fill a set of one or more rectangles.
@@ -395,12 +387,11 @@ impl Gcontext {
     /// Request to fill a series of arcs.
     #[inline]
     fn poly_fill_arc_request(self, drawable: Drawable, arcs: &[Arc]) -> PolyFillArcRequest {
-        PolyFillArcRequest {
-            drawable,
-            gc: self,
-            arcs: arcs.to_vec(),
-            ..Default::default()
-        }
+        PolyFillArcRequest::builder()
+            .drawable(drawable)
+            .gc(self)
+            .arcs(arcs.to_vec())
+            .build()
     }
 
     /// Fill a set of one or more arcs.
@@ -461,13 +452,7 @@ impl Gcontext {
     /// to fail.
     #[inline]
     pub fn free<Conn: Connection>(self, dpy: &mut Display<Conn>) -> crate::Result {
-        sr_request!(
-            dpy,
-            FreeGcRequest {
-                gc: self,
-                ..Default::default()
-            }
-        )
+        sr_request!(dpy, FreeGcRequest::builder().gc(self).build())
     }
 
     /// Free the memory this GC allocates, async redox.
@@ -477,14 +462,6 @@ impl Gcontext {
         self,
         dpy: &mut Display<Conn>,
     ) -> crate::Result {
-        sr_request!(
-            dpy,
-            FreeGcRequest {
-                gc: self,
-                ..Default::default()
-            },
-            async
-        )
-        .await
+        sr_request!(dpy, FreeGcRequest::builder().gc(self).build(), async).await
     }
 }
@@ -18,7 +18,7 @@ use crate::{
     display::{Connection, Display, RequestCookie},
     send_request, sr_request, Event, Extension,
 };
-use alloc::{boxed::Box, string::String};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
 use cty::c_char;
 
 #[cfg(feature = "async")]
@@ -61,7 +61,7 @@ crate::create_paramaterizer! {
 impl WindowParameters {
     #[inline]
     pub(crate) fn mask_to_change_attrs_request(&self, o: &mut ChangeWindowAttributesRequest) -> Cw {
-        let mut c: CreateWindowRequest = Default::default();
+        let mut c = CreateWindowRequest::builder().build();
         let mask = self.convert_to_flags(&mut c);
 
         o.background_pixmap = c.background_pixmap;
@@ -128,7 +128,7 @@ crate::create_paramaterizer! {
 impl GcParameters {
     #[inline]
     pub(crate) fn mask_change_gc_request(&self, req: &mut ChangeGcRequest) -> Gc {
-        let mut create_req: CreateGcRequest = Default::default();
+        let mut create_req = CreateGcRequest::builder().build();
         let gc = self.convert_to_flags(&mut create_req);
 
         // hopefully this gets optimized out
@@ -209,19 +209,18 @@ impl<Conn> Display<Conn> {
         const INHERITED_DEPTH: u8 = 0;
         const INHERITED_VISUAL: Visualid = 0;
 
-        let mut cwr = CreateWindowRequest {
-            wid,
-            parent,
-            class,
-            visual: visual.unwrap_or(INHERITED_VISUAL),
-            depth: depth.unwrap_or(INHERITED_DEPTH),
-            x,
-            y,
-            width,
-            height,
-            border_width,
-            ..Default::default()
-        };
+        let mut cwr = CreateWindowRequest::builder()
+            .wid(wid)
+            .parent(parent)
+            .class(class)
+            .visual(visual.unwrap_or(INHERITED_VISUAL))
+            .depth(depth.unwrap_or(INHERITED_DEPTH))
+            .x(x)
+            .y(y)
+            .width(width)
+            .height(height)
+            .border_width(border_width)
+            .build();
 
         let cw = props.convert_to_flags(&mut cwr);
         cwr.value_mask = cw;
@@ -241,19 +240,18 @@ impl<Conn> Display<Conn> {
         border: u32,
         background: u32,
     ) -> CreateWindowRequest {
-        let mut cwr = CreateWindowRequest {
-            parent,
-            x,
-            y,
-            width,
-            height,
-            border_width,
-            depth: 0,
-            class: WindowClass::CopyFromParent,
-            visual: 0,
-            wid,
-            ..Default::default()
-        };
+        let mut cwr = CreateWindowRequest::builder()
+            .parent(parent)
+            .x(x)
+            .y(y)
+            .width(width)
+            .height(height)
+            .border_width(border_width)
+            .depth(0)
+            .class(WindowClass::CopyFromParent)
+            .visual(0)
+            .wid(wid)
+            .build();
         let wp = WindowParameters {
             background_pixel: Some(background),
             border_pixel: Some(border),
@@ -272,11 +270,10 @@ impl<Conn> Display<Conn> {
         drawable: Drawable,
         props: GcParameters,
     ) -> CreateGcRequest {
-        let mut gcr = CreateGcRequest {
-            cid,
-            drawable,
-            ..Default::default()
-        };
+        let mut gcr = CreateGcRequest::builder()
+            .cid(cid)
+            .drawable(drawable)
+            .build();
 
         let gcmask = props.convert_to_flags(&mut gcr);
         gcr.value_mask = gcmask;
@@ -286,17 +283,16 @@ impl<Conn> Display<Conn> {
     /// Create an `InternAtomRequest` for our use.
     #[inline]
     fn intern_atom_request(name: String, exists: bool) -> InternAtomRequest {
-        InternAtomRequest {
-            only_if_exists: exists,
-            name,
-            ..Default::default()
-        }
+        InternAtomRequest::builder()
+            .only_if_exists(exists)
+            .name(name)
+            .build()
     }
 
     /// Change Keyboard Control Request
     #[inline]
     fn change_keyboard_control_request(props: KbParameters) -> ChangeKeyboardControlRequest {
-        let mut ckcr: ChangeKeyboardControlRequest = Default::default();
+        let mut ckcr = ChangeKeyboardControlRequest::builder().build();
         let c = props.convert_to_flags(&mut ckcr);
         ckcr.value_mask = c;
         ckcr
@@ -308,12 +304,11 @@ impl<Conn> Display<Conn> {
         cursor: Cursor,
         time: Option<Timestamp>,
     ) -> ChangeActivePointerGrabRequest {
-        ChangeActivePointerGrabRequest {
-            cursor,
-            event_mask,
-            time: time.unwrap_or(0),
-            ..Default::default()
-        }
+        ChangeActivePointerGrabRequest::builder()
+            .cursor(cursor)
+            .event_mask(event_mask)
+            .time(time.unwrap_or(0))
+            .build()
     }
 
     #[inline]
@@ -324,14 +319,13 @@ impl<Conn> Display<Conn> {
         do_accel: bool,
         do_threshold: bool,
     ) -> ChangePointerControlRequest {
-        ChangePointerControlRequest {
-            acceleration_numerator: accel_numer,
-            acceleration_denominator: accel_denom,
-            threshold,
-            do_acceleration: do_accel,
-            do_threshold,
-            ..Default::default()
-        }
+        ChangePointerControlRequest::builder()
+            .acceleration_numerator(accel_numer)
+            .acceleration_denominator(accel_denom)
+            .threshold(threshold)
+            .do_acceleration(do_accel)
+            .do_threshold(do_threshold)
+            .build()
     }
 
     /// Create a new cursor request.
@@ -349,20 +343,19 @@ impl<Conn> Display<Conn> {
         x: u16,
         y: u16,
     ) -> CreateCursorRequest {
-        CreateCursorRequest {
-            cid,
-            source,
-            mask,
-            fore_red: fg_red,
-            fore_blue: fg_blue,
-            fore_green: fg_green,
-            back_red: bg_red,
-            back_blue: bg_blue,
-            back_green: bg_green,
-            x,
-            y,
-            ..Default::default()
-        }
+        CreateCursorRequest::builder()
+            .cid(cid)
+            .source(source)
+            .mask(mask)
+            .fore_red(fg_red)
+            .fore_blue(fg_blue)
+            .fore_green(fg_green)
+            .back_red(bg_red)
+            .back_blue(bg_blue)
+            .back_green(bg_green)
+            .x(x)
+            .y(y)
+            .build()
     }
 
     #[inline]
@@ -372,12 +365,11 @@ impl<Conn> Display<Conn> {
 
         bytes[0] = event.opcode();
 
-        SendEventRequest {
-            destination: target,
-            event_mask: em,
-            event: bytemuck::cast::<_, [c_char; 32]>(bytes),
-            ..Default::default()
-        }
+        SendEventRequest::builder()
+            .destination(target)
+            .event_mask(em)
+            .event(bytemuck::cast::<_, [c_char; 32]>(bytes))
+            .build()
     }
 
     /// Create a new colormap request.
@@ -388,13 +380,12 @@ impl<Conn> Display<Conn> {
         win: Window,
         visual: Visualid,
     ) -> CreateColormapRequest {
-        CreateColormapRequest {
-            alloc,
-            mid: id,
-            window: win,
-            visual,
-            ..Default::default()
-        }
+        CreateColormapRequest::builder()
+            .alloc(alloc)
+            .mid(id)
+            .window(win)
+            .visual(visual)
+            .build()
     }
 }
 
@@ -405,13 +396,7 @@ impl<Conn: Connection> Display<Conn> {
         &mut self,
         name: String,
     ) -> crate::Result<RequestCookie<QueryExtensionRequest>> {
-        send_request!(
-            self,
-            QueryExtensionRequest {
-                name,
-                ..Default::default()
-            }
-        )
+        send_request!(self, QueryExtensionRequest::builder().name(name).build())
     }
 
     /// Query for extension information, but resolve immediately. The `Error::ExtensionNotPresent` error is
@@ -420,10 +405,7 @@ impl<Conn: Connection> Display<Conn> {
     pub fn query_extension_immediate(&mut self, name: String) -> crate::Result<Extension> {
         let qer = sr_request!(
             self,
-            QueryExtensionRequest {
-                name: name.clone(),
-                ..Default::default()
-            }
+            QueryExtensionRequest::builder().name(name.clone()).build()
         )?;
         Extension::from_reply(qer, name.into())
     }
@@ -525,6 +507,22 @@ impl<Conn: Connection> Display<Conn> {
         Ok(self.resolve_request(r)?.atom)
     }
 
+    /// Intern several atoms at once. Every request is sent before any of the replies are waited on, so
+    /// this takes one round trip instead of one per name. The atoms are returned in the order of the names.
+    /// The predefined atoms with fixed IDs, like `PRIMARY`, don't need to be interned; they are available
+    /// as the `ATOM_*` consts in `auto::xproto`.
+    #[inline]
+    pub fn intern_all(&mut self, names: &[&str], only_if_exists: bool) -> crate::Result<Vec<Atom>> {
+        let cookies = names
+            .iter()
+            .map(|name| self.intern_atom((*name).to_owned(), only_if_exists))
+            .collect::<crate::Result<Vec<_>>>()?;
+        cookies
+            .into_iter()
+            .map(|r| Ok(self.resolve_request(r)?.atom))
+            .collect()
+    }
+
     /// Change the keyboard's control properties.
     #[inline]
     pub fn change_keyboard_control(&mut self, props: KbParameters) -> crate::Result<()> {
@@ -534,24 +532,12 @@ impl<Conn: Connection> Display<Conn> {
 
     #[inline]
     pub fn bell(&mut self, percent: i8) -> crate::Result {
-        sr_request!(
-            self,
-            BellRequest {
-                percent,
-                ..Default::default()
-            }
-        )
+        sr_request!(self, BellRequest::builder().percent(percent).build())
     }
 
     #[inline]
     pub fn set_access_control(&mut self, mode: AccessControl) -> crate::Result {
-        sr_request!(
-            self,
-            SetAccessControlRequest {
-                mode,
-                ..Default::default()
-            }
-        )
+        sr_request!(self, SetAccessControlRequest::builder().mode(mode).build())
     }
 
     #[inline]
@@ -569,13 +555,7 @@ impl<Conn: Connection> Display<Conn> {
 
     #[inline]
     pub fn set_close_down_mode(&mut self, mode: CloseDown) -> crate::Result {
-        sr_request!(
-            self,
-            SetCloseDownModeRequest {
-                mode,
-                ..Default::default()
-            }
-        )
+        sr_request!(self, SetCloseDownModeRequest::builder().mode(mode).build())
     }
 
     #[inline]
@@ -626,13 +606,7 @@ impl<Conn: Connection> Display<Conn> {
 
     #[inline]
     pub fn force_screensaver(&mut self, mode: ScreenSaver) -> crate::Result {
-        sr_request!(
-            self,
-            ForceScreenSaverRequest {
-                mode,
-                ..Default::default()
-            }
-        )
+        sr_request!(self, ForceScreenSaverRequest::builder().mode(mode).build())
     }
 
     /// Send an event to the X server.
@@ -667,11 +641,10 @@ impl<Conn: Connection> Display<Conn> {
 
         send_request!(
             self,
-            GetKeyboardMappingRequest {
-                first_keycode: min_keycode,
-                count: max_keycode - min_keycode,
-                ..Default::default()
-            }
+            GetKeyboardMappingRequest::builder()
+                .first_keycode(min_keycode)
+                .count(max_keycode - min_keycode)
+                .build()
         )
     }
 
@@ -710,10 +683,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<RequestCookie<QueryExtensionRequest>> {
         send_request!(
             self,
-            QueryExtensionRequest {
-                name,
-                ..Default::default()
-            },
+            QueryExtensionRequest::builder().name(name).build(),
             async
         )
         .await
@@ -728,10 +698,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     ) -> crate::Result<Extension> {
         let qer = sr_request!(
             self,
-            QueryExtensionRequest {
-                name: name.clone(),
-                ..Default::default()
-            },
+            QueryExtensionRequest::builder().name(name.clone()).build(),
             async
         )
         .await?;
@@ -836,6 +803,28 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         Ok(self.resolve_request_async(r).await?.atom)
     }
 
+    /// Intern several atoms at once, async redox.
+    #[inline]
+    pub async fn intern_all_async(
+        &mut self,
+        names: &[&str],
+        only_if_exists: bool,
+    ) -> crate::Result<Vec<Atom>> {
+        let mut cookies = Vec::with_capacity(names.len());
+        for name in names {
+            cookies.push(
+                self.intern_atom_async((*name).to_owned(), only_if_exists)
+                    .await?,
+            );
+        }
+
+        let mut atoms = Vec::with_capacity(cookies.len());
+        for r in cookies {
+            atoms.push(self.resolve_request_async(r).await?.atom);
+        }
+        Ok(atoms)
+    }
+
     /// Change the keyboard's control properties, async redox.
     #[inline]
     pub async fn change_keyboard_control_async(
@@ -848,25 +837,14 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
 
     #[inline]
     pub async fn bell_async(&mut self, percent: i8) -> crate::Result {
-        sr_request!(
-            self,
-            BellRequest {
-                percent,
-                ..Default::default()
-            },
-            async
-        )
-        .await
+        sr_request!(self, BellRequest::builder().percent(percent).build(), async).await
     }
 
     #[inline]
     pub async fn set_access_control_async(&mut self, mode: AccessControl) -> crate::Result {
         sr_request!(
             self,
-            SetAccessControlRequest {
-                mode,
-                ..Default::default()
-            },
+            SetAccessControlRequest::builder().mode(mode).build(),
             async
         )
         .await
@@ -891,10 +869,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     pub async fn set_close_down_mode_async(&mut self, mode: CloseDown) -> crate::Result {
         sr_request!(
             self,
-            SetCloseDownModeRequest {
-                mode,
-                ..Default::default()
-            },
+            SetCloseDownModeRequest::builder().mode(mode).build(),
             async
         )
         .await
@@ -954,10 +929,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
     pub async fn force_screensaver_async(&mut self, mode: ScreenSaver) -> crate::Result {
         sr_request!(
             self,
-            ForceScreenSaverRequest {
-                mode,
-                ..Default::default()
-            },
+            ForceScreenSaverRequest::builder().mode(mode).build(),
             async
         )
         .await
@@ -1003,11 +975,10 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
 
         send_request!(
             self,
-            GetKeyboardMappingRequest {
-                first_keycode: min_keycode,
-                count: max_keycode - min_keycode,
-                ..Default::default()
-            },
+            GetKeyboardMappingRequest::builder()
+                .first_keycode(min_keycode)
+                .count(max_keycode - min_keycode)
+                .build(),
             async
         )
         .await
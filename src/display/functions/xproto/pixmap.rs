@@ -13,13 +13,7 @@ impl Pixmap {
     /// Free the memory used by a pixmap.
     #[inline]
     pub fn free<Conn: Connection>(self, dpy: &mut Display<Conn>) -> crate::Result {
-        sr_request!(
-            dpy,
-            FreePixmapRequest {
-                pixmap: self,
-                ..Default::default()
-            }
-        )
+        sr_request!(dpy, FreePixmapRequest::builder().pixmap(self).build())
     }
 
     /// Free the memory used by a pixmap, async redox.
@@ -31,10 +25,7 @@ impl Pixmap {
     ) -> crate::Result {
         sr_request!(
             dpy,
-            FreePixmapRequest {
-                pixmap: self,
-                ..Default::default()
-            },
+            FreePixmapRequest::builder().pixmap(self).build(),
             async
         )
         .await
@@ -3,6 +3,7 @@
 #![allow(clippy::similar_names)]
 
 use super::Geometry as DrawableGeometry;
+use crate::{auto::xproto::GetPropertyType, property_data::PropertyData};
 pub use crate::{
     auto::{
         xproto::{
@@ -10,9 +11,10 @@ pub use crate::{
             ChangeWindowAttributesRequest, Circulate, CirculateWindowRequest, ClearAreaRequest,
             Colormap, ConfigWindow, ConfigureWindowRequest, ConvertSelectionRequest, Cursor,
             DeletePropertyRequest, DestroySubwindowsRequest, DestroyWindowRequest, EventMask,
-            Gcontext, GetGeometryRequest, GetWindowAttributesReply, GetWindowAttributesRequest,
-            Gravity, MapState, MapWindowRequest, PropMode, SetMode, StackMode, Timestamp, Visualid,
-            Window, WindowClass, ATOM_WM_NAME,
+            Gcontext, GetGeometryRequest, GetPropertyReply, GetPropertyRequest,
+            GetWindowAttributesReply, GetWindowAttributesRequest, Gravity, MapState,
+            MapWindowRequest, PropMode, SetMode, StackMode, Timestamp, Visualid, Window,
+            WindowClass, ATOM_WM_NAME,
         },
         AsByteSequence,
     },
@@ -121,13 +123,7 @@ impl Window {
     /// Map this window to the screen.
     #[inline]
     pub fn map<Conn: Connection>(self, dpy: &mut Display<Conn>) -> crate::Result {
-        sr_request!(
-            dpy,
-            MapWindowRequest {
-                window: self,
-                ..Default::default()
-            }
-        )
+        sr_request!(dpy, MapWindowRequest::builder().window(self).build())
     }
 
     /// Map this window to the screen, async redox.
@@ -137,15 +133,7 @@ impl Window {
         self,
         dpy: &mut Display<Conn>,
     ) -> crate::Result {
-        sr_request!(
-            dpy,
-            MapWindowRequest {
-                window: self,
-                ..Default::default()
-            },
-            async
-        )
-        .await
+        sr_request!(dpy, MapWindowRequest::builder().window(self).build(), async).await
     }
 
     /// Request struct to change the property of a window.
@@ -157,6 +145,25 @@ impl Window {
         format: PropertyFormat,
         mode: PropMode,
         data: &[T],
+    ) -> ChangePropertyRequest {
+        self.property_request(
+            property,
+            Atom::const_from_xid(property_type as u32),
+            format,
+            mode,
+            data,
+        )
+    }
+
+    /// Request struct to change the property of a window, given the atom for the property's type.
+    #[inline]
+    fn property_request<T: AsByteSequence>(
+        self,
+        property: Atom,
+        property_type: Atom,
+        format: PropertyFormat,
+        mode: PropMode,
+        data: &[T],
     ) -> ChangePropertyRequest {
         // convert to a u8 collection
         let mut data_bytes: Vec<u8> = iter::repeat(0)
@@ -167,18 +174,17 @@ impl Window {
         });
         data_bytes.truncate(len);
 
-        let format = format as u8;
-
-        ChangePropertyRequest {
-            mode,
-            window: self,
-            property,
-            ty: Atom::const_from_xid(property_type as u32),
-            format,
-            data_len: data.len() as u32,
-            data: data_bytes,
-            ..Default::default()
-        }
+        let mut request = ChangePropertyRequest::builder()
+            .mode(mode)
+            .window(self)
+            .property(property)
+            .ty(property_type)
+            .data_len(data.len() as u32)
+            .data(data_bytes)
+            .build();
+        // every `PropertyFormat` is a format that properties can have
+        request.format = format as u8;
+        request
     }
 
     /// Change a property of the window, given an atom that identifies that property.
@@ -227,11 +233,10 @@ impl Window {
     ) -> crate::Result {
         sr_request!(
             dpy,
-            DeletePropertyRequest {
-                window: self,
-                property,
-                ..Default::default()
-            }
+            DeletePropertyRequest::builder()
+                .window(self)
+                .property(property)
+                .build()
         )
     }
 
@@ -245,16 +250,297 @@ impl Window {
     ) -> crate::Result {
         sr_request!(
             dpy,
-            DeletePropertyRequest {
-                window: self,
+            DeletePropertyRequest::builder()
+                .window(self)
+                .property(property)
+                .build(),
+            async
+        )
+        .await
+    }
+
+    /// Request struct to get the entire value of a property of a window.
+    #[inline]
+    fn get_property_request(
+        self,
+        property: Atom,
+        property_type: Atom,
+        delete: bool,
+    ) -> GetPropertyRequest {
+        GetPropertyRequest::builder()
+            .delete(delete)
+            .window(self)
+            .property(property)
+            .ty(property_type)
+            .long_offset(0)
+            .long_length(u32::MAX)
+            .build()
+    }
+
+    /// Change a property of the window that consists of 8-bit values. The format of the
+    /// property is set to match.
+    #[inline]
+    pub fn change_property_u8<Conn: Connection>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        mode: PropMode,
+        data: &[u8],
+    ) -> crate::Result<()> {
+        sr_request!(
+            dpy,
+            self.property_request(property, property_type, PropertyFormat::Eight, mode, data)
+        )
+    }
+
+    /// Change a property of the window that consists of 8-bit values, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn change_property_u8_async<Conn: AsyncConnection + Send>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        mode: PropMode,
+        data: &[u8],
+    ) -> crate::Result<()> {
+        sr_request!(
+            dpy,
+            self.property_request(property, property_type, PropertyFormat::Eight, mode, data),
+            async
+        )
+        .await
+    }
+
+    /// Get a property of the window that consists of 8-bit values. This fails if the property
+    /// has a type other than `property_type`, unless that is `AnyPropertyType`, if it is stored in
+    /// another format, or if it is too long to be sent in full. A property that doesn't exist has
+    /// no values.
+    #[inline]
+    pub fn get_property_u8<Conn: Connection>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        delete: bool,
+    ) -> crate::Result<Vec<u8>> {
+        let reply = sr_request!(
+            dpy,
+            self.get_property_request(property, property_type, delete)
+        )?;
+        property_values(
+            reply,
+            property_type,
+            PropertyFormat::Eight,
+            PropertyData::into_bytes,
+        )
+    }
+
+    /// Get a property of the window that consists of 8-bit values, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn get_property_u8_async<Conn: AsyncConnection + Send>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        delete: bool,
+    ) -> crate::Result<Vec<u8>> {
+        let reply = sr_request!(
+            dpy,
+            self.get_property_request(property, property_type, delete),
+            async
+        )
+        .await?;
+        property_values(
+            reply,
+            property_type,
+            PropertyFormat::Eight,
+            PropertyData::into_bytes,
+        )
+    }
+
+    /// Change a property of the window that consists of 16-bit values. The format of the
+    /// property is set to match.
+    #[inline]
+    pub fn change_property_u16<Conn: Connection>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        mode: PropMode,
+        data: &[u16],
+    ) -> crate::Result<()> {
+        sr_request!(
+            dpy,
+            self.property_request(property, property_type, PropertyFormat::Sixteen, mode, data)
+        )
+    }
+
+    /// Change a property of the window that consists of 16-bit values, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn change_property_u16_async<Conn: AsyncConnection + Send>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        mode: PropMode,
+        data: &[u16],
+    ) -> crate::Result<()> {
+        sr_request!(
+            dpy,
+            self.property_request(property, property_type, PropertyFormat::Sixteen, mode, data),
+            async
+        )
+        .await
+    }
+
+    /// Get a property of the window that consists of 16-bit values. This fails if the property
+    /// has a type other than `property_type`, unless that is `AnyPropertyType`, if it is stored in
+    /// another format, or if it is too long to be sent in full. A property that doesn't exist has
+    /// no values.
+    #[inline]
+    pub fn get_property_u16<Conn: Connection>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        delete: bool,
+    ) -> crate::Result<Vec<u16>> {
+        let reply = sr_request!(
+            dpy,
+            self.get_property_request(property, property_type, delete)
+        )?;
+        property_values(
+            reply,
+            property_type,
+            PropertyFormat::Sixteen,
+            PropertyData::into_shorts,
+        )
+    }
+
+    /// Get a property of the window that consists of 16-bit values, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn get_property_u16_async<Conn: AsyncConnection + Send>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        delete: bool,
+    ) -> crate::Result<Vec<u16>> {
+        let reply = sr_request!(
+            dpy,
+            self.get_property_request(property, property_type, delete),
+            async
+        )
+        .await?;
+        property_values(
+            reply,
+            property_type,
+            PropertyFormat::Sixteen,
+            PropertyData::into_shorts,
+        )
+    }
+
+    /// Change a property of the window that consists of 32-bit values. The format of the
+    /// property is set to match.
+    #[inline]
+    pub fn change_property_u32<Conn: Connection>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        mode: PropMode,
+        data: &[u32],
+    ) -> crate::Result<()> {
+        sr_request!(
+            dpy,
+            self.property_request(
                 property,
-                ..Default::default()
-            },
+                property_type,
+                PropertyFormat::ThirtyTwo,
+                mode,
+                data
+            )
+        )
+    }
+
+    /// Change a property of the window that consists of 32-bit values, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn change_property_u32_async<Conn: AsyncConnection + Send>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        mode: PropMode,
+        data: &[u32],
+    ) -> crate::Result<()> {
+        sr_request!(
+            dpy,
+            self.property_request(
+                property,
+                property_type,
+                PropertyFormat::ThirtyTwo,
+                mode,
+                data
+            ),
             async
         )
         .await
     }
 
+    /// Get a property of the window that consists of 32-bit values. This fails if the property
+    /// has a type other than `property_type`, unless that is `AnyPropertyType`, if it is stored in
+    /// another format, or if it is too long to be sent in full. A property that doesn't exist has
+    /// no values.
+    #[inline]
+    pub fn get_property_u32<Conn: Connection>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        delete: bool,
+    ) -> crate::Result<Vec<u32>> {
+        let reply = sr_request!(
+            dpy,
+            self.get_property_request(property, property_type, delete)
+        )?;
+        property_values(
+            reply,
+            property_type,
+            PropertyFormat::ThirtyTwo,
+            PropertyData::into_longs,
+        )
+    }
+
+    /// Get a property of the window that consists of 32-bit values, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn get_property_u32_async<Conn: AsyncConnection + Send>(
+        self,
+        dpy: &mut Display<Conn>,
+        property: Atom,
+        property_type: Atom,
+        delete: bool,
+    ) -> crate::Result<Vec<u32>> {
+        let reply = sr_request!(
+            dpy,
+            self.get_property_request(property, property_type, delete),
+            async
+        )
+        .await?;
+        property_values(
+            reply,
+            property_type,
+            PropertyFormat::ThirtyTwo,
+            PropertyData::into_longs,
+        )
+    }
+
     /// Set the protocols for the WM in regards to this window.
     #[inline]
     pub fn set_wm_protocols<Conn: Connection>(
@@ -334,10 +620,7 @@ impl Window {
     /// `GetWindowAttributesRequest`
     #[inline]
     fn get_window_attributes_request(self) -> GetWindowAttributesRequest {
-        GetWindowAttributesRequest {
-            window: self,
-            ..Default::default()
-        }
+        GetWindowAttributesRequest::builder().window(self).build()
     }
 
     /// Get the current set of window attributes for this window.
@@ -425,10 +708,9 @@ impl Window {
     /// Request to change this window's parameters.
     #[inline]
     fn change_window_attrs_request(self, props: WindowParameters) -> ChangeWindowAttributesRequest {
-        let mut cwar = ChangeWindowAttributesRequest {
-            window: self,
-            ..Default::default()
-        };
+        let mut cwar = ChangeWindowAttributesRequest::builder()
+            .window(self)
+            .build();
 
         let c = props.mask_to_change_attrs_request(&mut cwar);
         cwar.value_mask = c;
@@ -486,10 +768,7 @@ impl Window {
     /// Request to configure window.
     #[inline]
     fn configure_window_request(self, props: ConfigureWindowParameters) -> ConfigureWindowRequest {
-        let mut cwr = ConfigureWindowRequest {
-            window: self,
-            ..Default::default()
-        };
+        let mut cwr = ConfigureWindowRequest::builder().window(self).build();
         let c = props.convert_to_flags(&mut cwr);
         cwr.value_mask = c;
         cwr
@@ -574,11 +853,10 @@ impl Window {
 
     #[inline]
     fn change_save_set_request(self, mode: SetMode) -> ChangeSaveSetRequest {
-        ChangeSaveSetRequest {
-            mode,
-            window: self,
-            ..Default::default()
-        }
+        ChangeSaveSetRequest::builder()
+            .mode(mode)
+            .window(self)
+            .build()
     }
 
     #[inline]
@@ -674,11 +952,10 @@ impl Window {
 
     #[inline]
     fn circulate_window_request(self, direction: Circulate) -> CirculateWindowRequest {
-        CirculateWindowRequest {
-            window: self,
-            direction,
-            ..Default::default()
-        }
+        CirculateWindowRequest::builder()
+            .window(self)
+            .direction(direction)
+            .build()
     }
 
     #[inline]
@@ -710,15 +987,14 @@ impl Window {
         height: u16,
         exposures: bool,
     ) -> ClearAreaRequest {
-        ClearAreaRequest {
-            exposures,
-            x,
-            y,
-            width,
-            height,
-            window: self,
-            ..Default::default()
-        }
+        ClearAreaRequest::builder()
+            .exposures(exposures)
+            .x(x)
+            .y(y)
+            .width(width)
+            .height(height)
+            .window(self)
+            .build()
     }
 
     /// Clear an area of the window.
@@ -779,14 +1055,13 @@ impl Window {
         property: Atom,
         time: Timestamp,
     ) -> ConvertSelectionRequest {
-        ConvertSelectionRequest {
-            requestor: self,
-            selection,
-            target,
-            property,
-            time,
-            ..Default::default()
-        }
+        ConvertSelectionRequest::builder()
+            .requestor(self)
+            .selection(selection)
+            .target(target)
+            .property(property)
+            .time(time)
+            .build()
     }
 
     #[inline]
@@ -856,10 +1131,7 @@ impl Window {
     pub fn destroy_subwindows<Conn: Connection>(self, dpy: &mut Display<Conn>) -> crate::Result {
         sr_request!(
             dpy,
-            DestroySubwindowsRequest {
-                window: self,
-                ..Default::default()
-            }
+            DestroySubwindowsRequest::builder().window(self).build()
         )
     }
 
@@ -872,10 +1144,7 @@ impl Window {
     ) -> crate::Result {
         sr_request!(
             dpy,
-            DestroySubwindowsRequest {
-                window: self,
-                ..Default::default()
-            },
+            DestroySubwindowsRequest::builder().window(self).build(),
             async
         )
         .await
@@ -884,13 +1153,7 @@ impl Window {
     /// Free this window.
     #[inline]
     pub fn free<Conn: Connection>(self, dpy: &mut Display<Conn>) -> crate::Result {
-        sr_request!(
-            dpy,
-            DestroyWindowRequest {
-                window: self,
-                ..Default::default()
-            }
-        )
+        sr_request!(dpy, DestroyWindowRequest::builder().window(self).build())
     }
 
     /// Free this window, async redox.
@@ -902,10 +1165,7 @@ impl Window {
     ) -> crate::Result {
         sr_request!(
             dpy,
-            DestroyWindowRequest {
-                window: self,
-                ..Default::default()
-            },
+            DestroyWindowRequest::builder().window(self).build(),
             async
         )
         .await
@@ -968,6 +1228,36 @@ fn convert_get_window_attributes_reply(reply: GetWindowAttributesReply) -> Windo
     }
 }
 
+/// Take the items out of the value of a property, checking that the property has the requested
+/// type and is stored in the given format. The server sends no data for a property of another type
+/// or format, so these are errors, in order that they aren't mistaken for an empty property. A
+/// property that is too long to be sent in full is an error as well.
+#[inline]
+fn property_values<T>(
+    reply: GetPropertyReply,
+    property_type: Atom,
+    format: PropertyFormat,
+    items: fn(PropertyData) -> Option<Vec<T>>,
+) -> crate::Result<Vec<T>> {
+    let expected = format as u8;
+    let mismatch = |found| crate::BreadError::PropertyFormatMismatch { expected, found };
+    match reply.format {
+        // the property doesn't exist
+        0 => Ok(Vec::new()),
+        _ if property_type.xid != u32::from(GetPropertyType::Any) && reply.ty != property_type => {
+            Err(crate::BreadError::PropertyTypeMismatch {
+                expected: property_type,
+                found: reply.ty,
+            })
+        }
+        found if found != expected => Err(mismatch(found)),
+        _ if reply.bytes_after != 0 => Err(crate::BreadError::PropertyTruncated {
+            bytes_after: reply.bytes_after,
+        }),
+        found => items(reply.value).ok_or_else(|| mismatch(found)),
+    }
+}
+
 /// The type of the property being changed.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -984,3 +1274,78 @@ pub enum PropertyFormat {
     Sixteen = 16,
     ThirtyTwo = 32,
 }
+
+#[test]
+fn property_values_in_another_format() {
+    let any = Atom::const_from_xid(0);
+    let cardinal = Atom::const_from_xid(6);
+    let reply = GetPropertyReply {
+        format: 32,
+        ty: cardinal,
+        value: PropertyData::ThirtyTwo(Vec::new()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        property_values(
+            reply.clone(),
+            any,
+            PropertyFormat::Eight,
+            PropertyData::into_bytes
+        ),
+        Err(crate::BreadError::PropertyFormatMismatch {
+            expected: 8,
+            found: 32,
+        })
+    ));
+    assert_eq!(
+        property_values(
+            reply.clone(),
+            cardinal,
+            PropertyFormat::ThirtyTwo,
+            PropertyData::into_longs
+        )
+        .unwrap(),
+        Vec::<u32>::new()
+    );
+
+    // the server sends the actual type and format of a property of another type, but no data
+    let string = Atom::const_from_xid(31);
+    let mismatched = GetPropertyReply {
+        bytes_after: 4,
+        ..reply.clone()
+    };
+    assert!(matches!(
+        property_values(
+            mismatched.clone(),
+            string,
+            PropertyFormat::ThirtyTwo,
+            PropertyData::into_longs
+        ),
+        Err(crate::BreadError::PropertyTypeMismatch { expected, found })
+            if expected == string && found == cardinal
+    ));
+
+    // the rest of a property that is too long to be sent isn't silently left out
+    assert!(matches!(
+        property_values(
+            mismatched,
+            any,
+            PropertyFormat::ThirtyTwo,
+            PropertyData::into_longs
+        ),
+        Err(crate::BreadError::PropertyTruncated { bytes_after: 4 })
+    ));
+
+    // properties that don't exist have no format, and no items
+    let missing = GetPropertyReply::default();
+    assert_eq!(
+        property_values(
+            missing,
+            string,
+            PropertyFormat::Sixteen,
+            PropertyData::into_shorts
+        )
+        .unwrap(),
+        Vec::<u16>::new()
+    );
+}
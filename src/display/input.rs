@@ -134,7 +134,7 @@ impl<Conn> super::Display<Conn> {
         };
 
         // the first byte will always indicate an XGE event
-        if evbytes[0] & 0x7F != GENERIC_EVENT as _ {
+        if evbytes[0] & 0x7F != GENERIC_EVENT {
             return Err(event);
         }
 
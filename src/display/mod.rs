@@ -36,6 +36,11 @@ pub mod name;
 mod like;
 pub use like::*;
 
+#[cfg(feature = "async")]
+mod event_stream;
+#[cfg(feature = "async")]
+pub use event_stream::*;
+
 mod functions;
 mod input;
 mod output;
@@ -105,6 +110,9 @@ pub struct Display<Conn> {
     // we use byte arrays instead of static string pointers
     // here because cache locality leads to an overall speedup (todo: verify)
     extensions: HashMap<[u8; EXT_KEY_SIZE], u8>,
+
+    // the maximum length of a request in four-byte units, once BIG-REQUESTS is enabled
+    pub(crate) bigreq_max_length: Option<u32>,
 }
 
 /// Unique identifier for a context.
@@ -135,6 +143,29 @@ impl<R: Request> RequestCookie<R> {
     pub fn sequence(self) -> u16 {
         self.sequence
     }
+
+    /// Wait for the reply to this request. This is the same as `Display::resolve_request`; the type of the
+    /// reply is the request's `Request::Reply`, so it never has to be named.
+    #[inline]
+    pub fn reply<Conn: Connection>(self, display: &mut Display<Conn>) -> crate::Result<R::Reply>
+    where
+        R::Reply: Default,
+    {
+        display.resolve_request(self)
+    }
+
+    /// Wait for the reply to this request, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn reply_async<Conn: AsyncConnection + Send>(
+        self,
+        display: &mut Display<Conn>,
+    ) -> crate::Result<R::Reply>
+    where
+        R::Reply: Default,
+    {
+        display.resolve_request_async(self).await
+    }
 }
 
 impl<Conn: fmt::Debug> fmt::Debug for Display<Conn> {
@@ -210,11 +241,9 @@ impl<Conn> Display<Conn> {
     #[inline]
     fn decode_reply<R: Request>(reply: Box<[u8]>, fds: Box<[Fd]>) -> crate::Result<R::Reply> {
         let mut r = R::Reply::from_bytes(&reply)
-            .ok_or(crate::BreadError::BadObjectRead(None))?
+            .map_err(|_| crate::BreadError::BadObjectRead(None))?
             .0;
-        if let Some(fdslot) = r.file_descriptors() {
-            *fdslot = fds.into_vec();
-        }
+        r.take_fds(&fds).ok_or(BreadError::BadObjectRead(None))?;
 
         Ok(r)
     }
@@ -295,6 +324,7 @@ impl<Conn> Display<Conn> {
             checked: cfg!(debug_assertions),
             //            context: HashMap::new(),
             extensions: HashMap::with_capacity(8),
+            bigreq_max_length: None,
         }
     }
 
@@ -420,6 +450,18 @@ impl<Conn> Display<Conn> {
     pub fn checked(&self) -> bool {
         self.checked
     }
+
+    /// The length, in bytes, of the longest request that can be sent to the server. This is the
+    /// maximum the server advertised in the setup, or the one it gave when BIG-REQUESTS was
+    /// enabled. Sending a longer request fails with `RequestTooLarge`.
+    #[inline]
+    pub fn maximum_request_length(&self) -> usize {
+        match self.bigreq_max_length {
+            // the extra four bytes of length are part of the request
+            Some(big_max_len) => (big_max_len as usize).saturating_sub(1) * 4,
+            None => self.setup.maximum_request_length as usize * 4,
+        }
+    }
 }
 
 impl<Conn: Connection> Display<Conn> {
@@ -491,6 +533,27 @@ impl<Conn: Connection> Display<Conn> {
         }
     }
 
+    /// Send a request object to the X11 server and wait for its reply. This is `send_request` followed by
+    /// `resolve_request`; the type of the reply is taken from the request's `Request::Reply`.
+    ///
+    /// ```rust,no_run
+    /// use breadx::{auto::xproto::{GetGeometryReply, GetGeometryRequest}, DisplayConnection};
+    ///
+    /// let mut conn = DisplayConnection::create(None, None).unwrap();
+    /// let root = conn.default_root();
+    /// let reply: GetGeometryReply = conn
+    ///     .exchange_request(GetGeometryRequest::builder().drawable(root.into()).build())
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn exchange_request<R: Request>(&mut self, req: R) -> crate::Result<R::Reply>
+    where
+        R::Reply: Default,
+    {
+        let tok = self.send_request(req)?;
+        self.resolve_request(tok)
+    }
+
     /// Wait for a special event.
     #[inline]
     pub fn wait_for_special_event(&mut self, eid: XID) -> crate::Result<Event> {
@@ -558,8 +621,8 @@ impl<Conn: Connection> Display<Conn> {
         log::trace!("Reading remainder of setup.");
         self.connection()?.read_packet(&mut bytes[8..], &mut _fds)?;
 
-        let (setup, _) =
-            Setup::from_bytes(&bytes).ok_or(crate::BreadError::BadObjectRead(Some("Setup")))?;
+        let (setup, _) = Setup::from_bytes(&bytes)
+            .map_err(|_| crate::BreadError::BadObjectRead(Some("Setup")))?;
         self.setup = setup;
         self.xid = XidGenerator::new(self.setup.resource_id_base, self.setup.resource_id_mask);
 
@@ -653,6 +716,33 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         }
     }
 
+    /// Send a request object to the X11 server and wait for its reply, async redox. See the
+    /// `exchange_request` function for more information.
+    ///
+    /// ```rust,no_run
+    /// use breadx::{auto::xproto::{GetGeometryReply, GetGeometryRequest}, AsyncDisplayConnection};
+    ///
+    /// # async fn geometry() -> breadx::Result {
+    /// let mut conn = AsyncDisplayConnection::create_async(None, None).await?;
+    /// let root = conn.default_root();
+    /// let reply: GetGeometryReply = conn
+    ///     .exchange_request_async(GetGeometryRequest::builder().drawable(root.into()).build())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub async fn exchange_request_async<R: Request + Send>(
+        &mut self,
+        req: R,
+    ) -> crate::Result<R::Reply>
+    where
+        R::Reply: Default,
+    {
+        let tok = self.send_request_async(req).await?;
+        self.resolve_request_async(tok).await
+    }
+
     /// Wait for a special event, async redox.
     #[inline]
     pub async fn wait_for_special_event_async(&mut self, eid: XID) -> crate::Result<Event> {
@@ -698,6 +788,29 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
         }
     }
 
+    /// Turn this display into a stream of the events that the X server sends, for use in `select`
+    /// loops. See `EventStream` for more information.
+    ///
+    /// ```rust,no_run
+    /// use breadx::{AsyncDisplayConnection, Event};
+    /// use smol::stream::StreamExt;
+    ///
+    /// # async fn events() -> breadx::Result {
+    /// let mut conn = AsyncDisplayConnection::create_async(None, None).await?;
+    /// let mut events = conn.event_stream();
+    /// while let Some(event) = events.next().await {
+    ///     if let Event::Expose(expose) = event? {
+    ///         println!("Window {:?} was exposed", expose.window);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn event_stream(&mut self) -> EventStream<'_, Conn> {
+        EventStream::new(self)
+    }
+
     /// Initialize the setup, async redox.
     ///
     /// TODO; lots of copy-pasted code, redo this at some point
@@ -735,7 +848,7 @@ impl<Conn: AsyncConnection + Send> Display<Conn> {
             .await?;
 
         let (setup, _) = Setup::from_bytes(&bytes)
-            .ok_or_else(|| crate::BreadError::BadObjectRead(Some("Setup")))?;
+            .map_err(|_| crate::BreadError::BadObjectRead(Some("Setup")))?;
         self.setup = setup;
         self.xid = XidGenerator::new(self.setup.resource_id_base, self.setup.resource_id_mask);
 
@@ -27,15 +27,14 @@ impl<Conn> super::Display<Conn> {
         req: &R,
         ext_opcode: Option<u8>,
         discard_reply: bool,
-    ) -> (u64, TinyVec<[u8; 32]>) {
-        let sequence = self.request_number;
-        self.request_number += 1;
+    ) -> crate::Result<(u64, TinyVec<[u8; 32]>)> {
+        // the server would reject a request whose lists disagree with its other fields
+        req.validate()?;
 
         // write to bytes
-        let mut bytes: TinyVec<[u8; 32]> = cycled_zeroes(req.size());
+        let mut bytes: TinyVec<[u8; 32]> = cycled_zeroes(req.size_hint());
 
         let mut len = req.as_bytes(&mut bytes);
-        log::debug!("Request is given sequence number {}", sequence);
 
         // pad to a multiple of four bytes if we can
         let remainder = len % 4;
@@ -56,21 +55,66 @@ impl<Conn> super::Display<Conn> {
             }
             Some(extension) => {
                 // First byte is extension opcode
-                // Second byte is regular opcode
+                // Second byte is minor opcode, which always fits into it
+                log::debug!("Request has minor opcode {}", R::MINOR_OPCODE);
                 bytes[0] = extension;
-                bytes[1] = R::OPCODE;
+                bytes[1] = R::MINOR_OPCODE as u8;
             }
         }
 
         // Third and fourth are length
         let x_len = len / 4;
         log::trace!("xlen is {}", x_len);
-        let len_bytes = x_len.to_ne_bytes();
-        bytes[2] = len_bytes[0];
-        bytes[3] = len_bytes[1];
-
         bytes.truncate(len);
 
+        // the GLX workaround below looks at a field of the request, so read it before BIG-REQUESTS
+        // moves the body
+        let glx_vendor_code = bytes.get(32..36).map(|a| {
+            let mut arr: [u8; 4] = [0; 4];
+            arr.copy_from_slice(a);
+            u32::from_ne_bytes(arr)
+        });
+
+        // the server drops the connection of a client that sends a request longer than the one
+        // it advertised in the setup, so refuse to send those here instead
+        let max_len = self.setup.maximum_request_length as usize;
+        if x_len > max_len {
+            // with BIG-REQUESTS, the length is set to zero and followed by a 32-bit length that
+            // includes itself
+            let x_len = x_len + 1;
+            let big_max_len = match self.bigreq_max_length {
+                Some(big_max_len) => big_max_len as usize,
+                None => {
+                    return Err(crate::BreadError::RequestTooLarge {
+                        length: len,
+                        maximum: max_len * 4,
+                    })
+                }
+            };
+            if x_len > big_max_len {
+                return Err(crate::BreadError::RequestTooLarge {
+                    length: x_len * 4,
+                    maximum: big_max_len * 4,
+                });
+            }
+
+            bytes[2] = 0;
+            bytes[3] = 0;
+            let mut big_bytes: TinyVec<[u8; 32]> = TinyVec::with_capacity(len + 4);
+            big_bytes.extend_from_slice(&bytes[..4]);
+            big_bytes.extend_from_slice(&(x_len as u32).to_ne_bytes());
+            big_bytes.extend_from_slice(&bytes[4..]);
+            bytes = big_bytes;
+        } else {
+            let len_bytes = (x_len as u16).to_ne_bytes();
+            bytes[2] = len_bytes[0];
+            bytes[3] = len_bytes[1];
+        }
+
+        let sequence = self.request_number;
+        self.request_number += 1;
+        log::debug!("Request is given sequence number {}", sequence);
+
         log::trace!("Request has bytes {:?}", &bytes);
 
         let mut flags = PendingRequestFlags {
@@ -87,15 +131,7 @@ impl<Conn> super::Display<Conn> {
         // to summarize, the X server makes an arithmatic error when calculating the length of the reply of
         // requests GetFBConfigs and VendorPrivate. in these replies, they forget to multiply the length value
         // by two. therefore, on the input end, we have to multiply it by two ourselves.
-        match (
-            R::EXTENSION,
-            R::OPCODE,
-            bytes.get(32..36).map(|a| {
-                let mut arr: [u8; 4] = [0; 4];
-                arr.copy_from_slice(a);
-                u32::from_ne_bytes(arr)
-            }),
-        ) {
+        match (R::EXTENSION, R::MINOR_OPCODE, glx_vendor_code) {
             (Some("GLX"), 17, Some(0x10004)) | (Some("GLX"), 21, _) => {
                 log::debug!("Applying GLX FbConfig workaround to request");
                 flags.workaround = RequestWorkaround::GlxFbconfigBug;
@@ -107,7 +143,7 @@ impl<Conn> super::Display<Conn> {
             self.expect_reply(sequence, flags);
         }
 
-        (sequence, bytes)
+        Ok((sequence, bytes))
     }
 }
 
@@ -115,7 +151,7 @@ impl<Conn: Connection> super::Display<Conn> {
     #[inline]
     pub fn send_request_internal<R: Request>(
         &mut self,
-        mut req: R,
+        req: R,
         discard_reply: bool,
     ) -> crate::Result<RequestCookie<R>> {
         let ext_opcode = match R::EXTENSION {
@@ -123,15 +159,12 @@ impl<Conn: Connection> super::Display<Conn> {
             Some(ext) => Some(self.get_ext_opcode(ext)?),
         };
         let (sequence, bytes): (u64, TinyVec<[u8; 32]>) =
-            self.encode_request(&req, ext_opcode, discard_reply);
+            self.encode_request(&req, ext_opcode, discard_reply)?;
 
-        let mut _dummy: Vec<Fd> = vec![];
-        let fds = match req.file_descriptors() {
-            Some(fds) => fds,
-            None => &mut _dummy,
-        };
+        let mut fds: Vec<Fd> = vec![];
+        req.as_fds(&mut fds);
 
-        self.connection()?.send_packet(&bytes, fds)?;
+        self.connection()?.send_packet(&bytes, &mut fds)?;
         Ok(RequestCookie::from_sequence(sequence))
     }
 
@@ -158,20 +191,17 @@ impl<Conn: AsyncConnection + Send> super::Display<Conn> {
     #[inline]
     pub async fn send_request_internal_async<R: Request>(
         &mut self,
-        mut req: R,
+        req: R,
         discard_reply: bool,
     ) -> crate::Result<RequestCookie<R>> {
         let ext_opcode = match R::EXTENSION {
             None => None,
             Some(ext) => Some(self.get_ext_opcode_async(ext).await?),
         };
-        let (sequence, bytes) = self.encode_request(&req, ext_opcode, discard_reply);
+        let (sequence, bytes) = self.encode_request(&req, ext_opcode, discard_reply)?;
 
-        let mut _dummy: Vec<Fd> = vec![];
-        let fds = match req.file_descriptors() {
-            Some(fds) => fds,
-            None => &mut _dummy,
-        };
+        let mut fds: Vec<Fd> = vec![];
+        req.as_fds(&mut fds);
 
         /*
 
@@ -198,7 +228,7 @@ impl<Conn: AsyncConnection + Send> super::Display<Conn> {
         */
 
         let mut connection = self.connection.take().ok_or(crate::BreadError::Tainted)?;
-        let res = connection.send_packet(&bytes, fds).await;
+        let res = connection.send_packet(&bytes, &mut fds).await;
         self.connection = Some(connection);
         res?;
 
@@ -2,11 +2,17 @@
 
 //! This module provides structures used in error handling of `breadx` functions.
 
+use crate::auto::{
+    xproto::{error_from_code, Atom},
+    ParseError, ValidationError,
+};
 use alloc::{borrow::Cow, string::String};
 use core::{fmt, ops::Deref};
 #[cfg(feature = "std")]
 use std::{boxed::Box, error::Error as StdError, format, io::Error as IoError};
 
+pub use crate::auto::xerror::XError;
+
 /// The common error type returned by `breadx` functions.
 #[derive(Debug, Clone)]
 pub enum BreadError {
@@ -26,6 +32,13 @@ pub enum BreadError {
     FailedToAuthorize,
     /// BadReadError
     BadObjectRead(Option<&'static str>),
+    /// A field of an object could not be read from the bytes it was sent in.
+    BadFieldRead {
+        /// The name of the field.
+        field: &'static str,
+        /// The offset of the field into the object's bytes.
+        offset: usize,
+    },
     /// Required extension was not present.
     ExtensionNotPresent(Cow<'static, str>),
     /// Required request was not present.
@@ -45,6 +58,41 @@ pub enum BreadError {
     LoadLibraryFailed(&'static str),
     /// Operation would block an async operation.
     WouldBlock,
+    /// The request is longer than the X server accepts. Enabling BIG-REQUESTS raises the limit.
+    RequestTooLarge {
+        /// The length of the request, in bytes.
+        length: usize,
+        /// The longest request the server accepts, in bytes.
+        maximum: usize,
+    },
+    /// A property was stored in a different format than the one it was read as.
+    PropertyFormatMismatch {
+        /// The format the property was read as, in bits per item.
+        expected: u8,
+        /// The format the property is stored in, in bits per item.
+        found: u8,
+    },
+    /// A property has a different type than the one it was read as.
+    PropertyTypeMismatch {
+        /// The type the property was read as.
+        expected: Atom,
+        /// The type of the property.
+        found: Atom,
+    },
+    /// Only part of a property was read, since the rest of it was longer than the server would send.
+    PropertyTruncated {
+        /// The number of bytes of the property that were left unread.
+        bytes_after: u32,
+    },
+    /// A list in a request does not have the length that the request's other fields describe.
+    InvalidListLength {
+        /// The name of the list.
+        list: &'static str,
+        /// The number of elements the other fields describe.
+        expected: usize,
+        /// The number of elements in the list.
+        found: usize,
+    },
 }
 
 impl BreadError {
@@ -74,6 +122,27 @@ impl From<IoError> for BreadError {
     }
 }
 
+impl From<ParseError> for BreadError {
+    #[inline]
+    fn from(pe: ParseError) -> Self {
+        Self::BadFieldRead {
+            field: pe.field,
+            offset: pe.offset,
+        }
+    }
+}
+
+impl From<ValidationError> for BreadError {
+    #[inline]
+    fn from(ve: ValidationError) -> Self {
+        Self::InvalidListLength {
+            list: ve.list,
+            expected: ve.expected,
+            found: ve.found,
+        }
+    }
+}
+
 impl fmt::Display for BreadError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -90,6 +159,10 @@ impl fmt::Display for BreadError {
                 "Unable to read object of type from bytes: {}",
                 name.unwrap_or("Unknown")
             ),
+            Self::BadFieldRead { field, offset } => write!(
+                f,
+                "Unable to read field \"{field}\" at byte offset {offset}"
+            ),
             Self::Tainted => f.write_str("Connection is tainted by an incomplete send"),
             Self::NoMatchingRequest(seq) => write!(f, "Received reply with non-matching sequence {}", seq),
             Self::ExtensionNotPresent(ext) => write!(f, "Extension was not found on X server: {}", ext),
@@ -106,6 +179,30 @@ impl fmt::Display for BreadError {
             Self::ClosedConnection => f.write_str("The X connection closed without our end of the connection closing. Did you forget to listen for WM_DELTE_WINDOW?"),
             Self::LoadLibraryFailed(l) => write!(f, "Failed to load library: {}", l),
             Self::WouldBlock => f.write_str("Operation would block an async function"),
+            Self::RequestTooLarge { length, maximum } => write!(
+                f,
+                "Request of {length} bytes is longer than the maximum of {maximum} bytes"
+            ),
+            Self::PropertyFormatMismatch { expected, found } => write!(
+                f,
+                "Expected a property of format {expected}, but it has format {found}"
+            ),
+            Self::PropertyTypeMismatch { expected, found } => write!(
+                f,
+                "Expected a property of type {expected:?}, but it has type {found:?}"
+            ),
+            Self::PropertyTruncated { bytes_after } => write!(
+                f,
+                "Property was cut short with {bytes_after} bytes left unread"
+            ),
+            Self::InvalidListLength {
+                list,
+                expected,
+                found,
+            } => write!(
+                f,
+                "List \"{list}\" of a request should have {expected} elements, but it has {found}"
+            ),
             #[cfg(feature = "std")]
             Self::Io(i) => write!(f, "{}", i),
         }
@@ -152,4 +249,16 @@ impl fmt::Debug for ErrorCode {
 #[cfg(feature = "std")]
 impl StdError for BreadError {}
 
+impl XError {
+    /// Parse an error from the bytes sent by the X server.
+    #[inline]
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        error_from_code(*bytes.get(1)?, bytes).ok()
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for XError {}
+
 pub type Result<Success = ()> = core::result::Result<Success, BreadError>;
@@ -2,11 +2,13 @@
 
 #![cfg(feature = "input")]
 
-use crate::auto::AsByteSequence;
+use crate::auto::{unreadable, AsByteSequence, ParseError};
 use alloc::boxed::Box;
 
 /// TODO: placeholder for input events
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputEvent {
     NoneOfTheAbove(Box<[u8]>),
 }
@@ -37,7 +39,7 @@ impl AsByteSequence for InputEvent {
     }
 
     #[inline]
-    fn from_bytes(_bytes: &[u8]) -> Option<(Self, usize)> {
-        panic!("Unable to deserialize InputEvent from bytes")
+    fn from_bytes(_bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        Err(unreadable::<Self>())
     }
 }
@@ -129,139 +129,128 @@ impl Event {
 
             let opcode = *opcode;
             if opcode == ConfigureNotifyEvent::OPCODE {
-                let cne = ConfigureNotifyEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("ConfigureNotifyEvent")),
-                )?;
+                let cne = ConfigureNotifyEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ConfigureNotifyEvent")))?;
                 *self = Self::ConfigureNotify(cne.0);
             } else if opcode == ClientMessageEvent::OPCODE {
                 let cme = ClientMessageEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("ClientMessageEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ClientMessageEvent")))?;
                 *self = Self::ClientMessage(cme.0);
             } else if opcode == ExposeEvent::OPCODE {
                 let ee = ExposeEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("ExposeEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ExposeEvent")))?;
                 *self = Self::Expose(ee.0);
             } else if opcode == ButtonPressEvent::OPCODE {
                 let e = ButtonPressEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("ButtonPressEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ButtonPressEvent")))?;
                 *self = Self::ButtonPress(e.0);
             } else if opcode == ButtonReleaseEvent::OPCODE {
                 let e = ButtonReleaseEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("ButtonReleaseEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ButtonReleaseEvent")))?;
                 *self = Self::ButtonRelease(e.0);
             } else if opcode == CirculateNotifyEvent::OPCODE {
-                let e = CirculateNotifyEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("CirculateNotifyEvent")),
-                )?;
+                let e = CirculateNotifyEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("CirculateNotifyEvent")))?;
                 *self = Self::CirculateNotify(e.0);
             } else if opcode == CirculateRequestEvent::OPCODE {
-                let e = CirculateRequestEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("CirculateRequestEvent")),
-                )?;
+                let e = CirculateRequestEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("CirculateRequestEvent")))?;
                 *self = Self::CirculateRequest(e.0);
             } else if opcode == ConfigureRequestEvent::OPCODE {
-                let e = ConfigureRequestEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("ConfigureRequestEvent")),
-                )?;
+                let e = ConfigureRequestEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ConfigureRequestEvent")))?;
                 *self = Self::ConfigureRequest(e.0);
             } else if opcode == CreateNotifyEvent::OPCODE {
                 let e = CreateNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("CreateNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("CreateNotifyEvent")))?;
                 *self = Self::CreateNotify(e.0);
             } else if opcode == DestroyNotifyEvent::OPCODE {
                 let e = DestroyNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("DestroyNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("DestroyNotifyEvent")))?;
                 *self = Self::DestroyNotify(e.0);
             } else if opcode == EnterNotifyEvent::OPCODE {
                 let e = EnterNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("EnterNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("EnterNotifyEvent")))?;
                 *self = Self::EnterNotify(e.0);
             } else if opcode == FocusInEvent::OPCODE {
                 let e = FocusInEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("FocusInEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("FocusInEvent")))?;
                 *self = Self::FocusIn(e.0);
             } else if opcode == FocusOutEvent::OPCODE {
                 let e = FocusOutEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("FocusOutEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("FocusOutEvent")))?;
                 *self = Self::FocusOut(e.0);
             } else if opcode == GraphicsExposureEvent::OPCODE {
-                let e = GraphicsExposureEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("GraphicsExposureEvent")),
-                )?;
+                let e = GraphicsExposureEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("GraphicsExposureEvent")))?;
                 *self = Self::GraphicsExposure(e.0);
             } else if opcode == GravityNotifyEvent::OPCODE {
                 let e = GravityNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("GravityNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("GravityNotifyEvent")))?;
                 *self = Self::GravityNotify(e.0);
             } else if opcode == KeyPressEvent::OPCODE {
                 let e = KeyPressEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("KeyPressEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("KeyPressEvent")))?;
                 *self = Self::KeyPress(e.0);
             } else if opcode == KeyReleaseEvent::OPCODE {
                 let e = KeyReleaseEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("KeyReleaseEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("KeyReleaseEvent")))?;
                 *self = Self::KeyRelease(e.0);
             } else if opcode == KeymapNotifyEvent::OPCODE {
                 let e = KeymapNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("KeymapNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("KeymapNotifyEvent")))?;
                 *self = Self::KeymapNotify(e.0);
             } else if opcode == LeaveNotifyEvent::OPCODE {
                 let e = LeaveNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("LeaveNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("LeaveNotifyEvent")))?;
                 *self = Self::LeaveNotify(e.0);
             } else if opcode == MapNotifyEvent::OPCODE {
                 let e = MapNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("MapNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("MapNotifyEvent")))?;
                 *self = Self::MapNotify(e.0);
             } else if opcode == MapRequestEvent::OPCODE {
                 let e = MapRequestEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("MapRequestEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("MapRequestEvent")))?;
                 *self = Self::MapRequest(e.0);
             } else if opcode == MappingNotifyEvent::OPCODE {
                 let e = MappingNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("MappingNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("MappingNotifyEvent")))?;
                 *self = Self::MappingNotify(e.0);
             } else if opcode == NoExposureEvent::OPCODE {
                 let e = NoExposureEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("NoExposureEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("NoExposureEvent")))?;
                 *self = Self::NoExposure(e.0);
             } else if opcode == PropertyNotifyEvent::OPCODE {
-                let e = PropertyNotifyEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("PropertyNotifyEvent")),
-                )?;
+                let e = PropertyNotifyEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("PropertyNotifyEvent")))?;
                 *self = Self::PropertyNotify(e.0);
             } else if opcode == ReparentNotifyEvent::OPCODE {
-                let e = ReparentNotifyEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("ReparentNotifyEvent")),
-                )?;
+                let e = ReparentNotifyEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ReparentNotifyEvent")))?;
                 *self = Self::ReparentNotify(e.0);
             } else if opcode == ResizeRequestEvent::OPCODE {
                 let e = ResizeRequestEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("ResizeRequestEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("ResizeRequestEvent")))?;
                 *self = Self::ResizeRequest(e.0);
             } else if opcode == SelectionClearEvent::OPCODE {
-                let e = SelectionClearEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("SelectionClearEvent")),
-                )?;
+                let e = SelectionClearEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("SelectionClearEvent")))?;
                 *self = Self::SelectionClear(e.0);
             } else if opcode == SelectionNotifyEvent::OPCODE {
-                let e = SelectionNotifyEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("SelectionNotifyEvent")),
-                )?;
+                let e = SelectionNotifyEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("SelectionNotifyEvent")))?;
                 *self = Self::SelectionNotify(e.0);
             } else if opcode == SelectionRequestEvent::OPCODE {
-                let e = SelectionRequestEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("SelectionRequestEvent")),
-                )?;
+                let e = SelectionRequestEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("SelectionRequestEvent")))?;
                 *self = Self::SelectionRequest(e.0);
             } else if opcode == UnmapNotifyEvent::OPCODE {
                 let e = UnmapNotifyEvent::from_bytes(bytes)
-                    .ok_or(crate::BreadError::BadObjectRead(Some("UnmapNotifyEvent")))?;
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("UnmapNotifyEvent")))?;
                 *self = Self::UnmapNotify(e.0);
             } else if opcode == VisibilityNotifyEvent::OPCODE {
-                let e = VisibilityNotifyEvent::from_bytes(bytes).ok_or(
-                    crate::BreadError::BadObjectRead(Some("VisibilityNotifyEvent")),
-                )?;
+                let e = VisibilityNotifyEvent::from_bytes(bytes)
+                    .map_err(|_| crate::BreadError::BadObjectRead(Some("VisibilityNotifyEvent")))?;
                 *self = Self::VisibilityNotify(e.0);
             }
         }
@@ -240,7 +240,7 @@ fn put_sub_image_req<Conn, Data: Deref<Target = [u8]>>(
     }
 
     // How many bits are available to use.
-    let mut req: PutImageRequest = Default::default();
+    let mut req = PutImageRequest::builder().build();
 
     #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
     let available: usize = if dpy.setup().maximum_request_length > 65535 {
@@ -425,7 +425,7 @@ pub(crate) fn put_image_req<Conn, Data: Deref<Target = [u8]>>(
                 }
             });
 
-            if dest_bits_per_pixel != image.bits_per_pixel() as _ {
+            if dest_bits_per_pixel != image.bits_per_pixel() as usize {
                 let mut new_image = Image {
                     width,
                     height,
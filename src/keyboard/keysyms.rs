@@ -0,0 +1,5936 @@
+// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+#![allow(non_upper_case_globals, clippy::unreadable_literal)]
+
+use crate::auto::xproto::Keysym;
+
+#[doc = " Void symbol"]
+pub const XK_VoidSymbol: Keysym = 0xffffff;
+#[doc = " Back space, back char"]
+pub const XK_BackSpace: Keysym = 0xff08;
+pub const XK_Tab: Keysym = 0xff09;
+#[doc = " Linefeed, LF"]
+pub const XK_Linefeed: Keysym = 0xff0a;
+pub const XK_Clear: Keysym = 0xff0b;
+#[doc = " Return, enter"]
+pub const XK_Return: Keysym = 0xff0d;
+#[doc = " Pause, hold"]
+pub const XK_Pause: Keysym = 0xff13;
+pub const XK_Scroll_Lock: Keysym = 0xff14;
+pub const XK_Sys_Req: Keysym = 0xff15;
+pub const XK_Escape: Keysym = 0xff1b;
+#[doc = " Delete, rubout"]
+pub const XK_Delete: Keysym = 0xffff;
+#[doc = " Multi-key character compose"]
+pub const XK_Multi_key: Keysym = 0xff20;
+pub const XK_Codeinput: Keysym = 0xff37;
+pub const XK_SingleCandidate: Keysym = 0xff3c;
+pub const XK_MultipleCandidate: Keysym = 0xff3d;
+pub const XK_PreviousCandidate: Keysym = 0xff3e;
+#[doc = " Kanji, Kanji convert"]
+pub const XK_Kanji: Keysym = 0xff21;
+#[doc = " Cancel Conversion"]
+pub const XK_Muhenkan: Keysym = 0xff22;
+#[doc = " Start/Stop Conversion"]
+pub const XK_Henkan_Mode: Keysym = 0xff23;
+#[doc = " Alias for `XK_Henkan_Mode`"]
+pub const XK_Henkan: Keysym = 0xff23;
+#[doc = " to Romaji"]
+pub const XK_Romaji: Keysym = 0xff24;
+#[doc = " to Hiragana"]
+pub const XK_Hiragana: Keysym = 0xff25;
+#[doc = " to Katakana"]
+pub const XK_Katakana: Keysym = 0xff26;
+#[doc = " Hiragana/Katakana toggle"]
+pub const XK_Hiragana_Katakana: Keysym = 0xff27;
+#[doc = " to Zenkaku"]
+pub const XK_Zenkaku: Keysym = 0xff28;
+#[doc = " to Hankaku"]
+pub const XK_Hankaku: Keysym = 0xff29;
+#[doc = " Zenkaku/Hankaku toggle"]
+pub const XK_Zenkaku_Hankaku: Keysym = 0xff2a;
+#[doc = " Add to Dictionary"]
+pub const XK_Touroku: Keysym = 0xff2b;
+#[doc = " Delete from Dictionary"]
+pub const XK_Massyo: Keysym = 0xff2c;
+#[doc = " Kana Lock"]
+pub const XK_Kana_Lock: Keysym = 0xff2d;
+#[doc = " Kana Shift"]
+pub const XK_Kana_Shift: Keysym = 0xff2e;
+#[doc = " Alphanumeric Shift"]
+pub const XK_Eisu_Shift: Keysym = 0xff2f;
+#[doc = " Alphanumeric toggle"]
+pub const XK_Eisu_toggle: Keysym = 0xff30;
+#[doc = " Codeinput"]
+pub const XK_Kanji_Bangou: Keysym = 0xff37;
+#[doc = " Multiple/All Candidate(s)"]
+pub const XK_Zen_Koho: Keysym = 0xff3d;
+#[doc = " Previous Candidate"]
+pub const XK_Mae_Koho: Keysym = 0xff3e;
+pub const XK_Home: Keysym = 0xff50;
+#[doc = " Move left, left arrow"]
+pub const XK_Left: Keysym = 0xff51;
+#[doc = " Move up, up arrow"]
+pub const XK_Up: Keysym = 0xff52;
+#[doc = " Move right, right arrow"]
+pub const XK_Right: Keysym = 0xff53;
+#[doc = " Move down, down arrow"]
+pub const XK_Down: Keysym = 0xff54;
+#[doc = " Prior, previous"]
+pub const XK_Prior: Keysym = 0xff55;
+pub const XK_Page_Up: Keysym = 0xff55;
+#[doc = " Next"]
+pub const XK_Next: Keysym = 0xff56;
+pub const XK_Page_Down: Keysym = 0xff56;
+#[doc = " EOL"]
+pub const XK_End: Keysym = 0xff57;
+#[doc = " BOL"]
+pub const XK_Begin: Keysym = 0xff58;
+#[doc = " Select, mark"]
+pub const XK_Select: Keysym = 0xff60;
+pub const XK_Print: Keysym = 0xff61;
+#[doc = " Execute, run, do"]
+pub const XK_Execute: Keysym = 0xff62;
+#[doc = " Insert, insert here"]
+pub const XK_Insert: Keysym = 0xff63;
+pub const XK_Undo: Keysym = 0xff65;
+#[doc = " Redo, again"]
+pub const XK_Redo: Keysym = 0xff66;
+pub const XK_Menu: Keysym = 0xff67;
+#[doc = " Find, search"]
+pub const XK_Find: Keysym = 0xff68;
+#[doc = " Cancel, stop, abort, exit"]
+pub const XK_Cancel: Keysym = 0xff69;
+#[doc = " Help"]
+pub const XK_Help: Keysym = 0xff6a;
+pub const XK_Break: Keysym = 0xff6b;
+#[doc = " Character set switch"]
+pub const XK_Mode_switch: Keysym = 0xff7e;
+#[doc = " Alias for `XK_Mode_switch`"]
+pub const XK_script_switch: Keysym = 0xff7e;
+pub const XK_Num_Lock: Keysym = 0xff7f;
+#[doc = " Space"]
+pub const XK_KP_Space: Keysym = 0xff80;
+pub const XK_KP_Tab: Keysym = 0xff89;
+#[doc = " Enter"]
+pub const XK_KP_Enter: Keysym = 0xff8d;
+#[doc = " PF1, `KP_A`, ..."]
+pub const XK_KP_F1: Keysym = 0xff91;
+pub const XK_KP_F2: Keysym = 0xff92;
+pub const XK_KP_F3: Keysym = 0xff93;
+pub const XK_KP_F4: Keysym = 0xff94;
+pub const XK_KP_Home: Keysym = 0xff95;
+pub const XK_KP_Left: Keysym = 0xff96;
+pub const XK_KP_Up: Keysym = 0xff97;
+pub const XK_KP_Right: Keysym = 0xff98;
+pub const XK_KP_Down: Keysym = 0xff99;
+pub const XK_KP_Prior: Keysym = 0xff9a;
+pub const XK_KP_Page_Up: Keysym = 0xff9a;
+pub const XK_KP_Next: Keysym = 0xff9b;
+pub const XK_KP_Page_Down: Keysym = 0xff9b;
+pub const XK_KP_End: Keysym = 0xff9c;
+pub const XK_KP_Begin: Keysym = 0xff9d;
+pub const XK_KP_Insert: Keysym = 0xff9e;
+pub const XK_KP_Delete: Keysym = 0xff9f;
+#[doc = " Equals"]
+pub const XK_KP_Equal: Keysym = 0xffbd;
+pub const XK_KP_Multiply: Keysym = 0xffaa;
+pub const XK_KP_Add: Keysym = 0xffab;
+#[doc = " Separator, often comma"]
+pub const XK_KP_Separator: Keysym = 0xffac;
+pub const XK_KP_Subtract: Keysym = 0xffad;
+pub const XK_KP_Decimal: Keysym = 0xffae;
+pub const XK_KP_Divide: Keysym = 0xffaf;
+pub const XK_KP_0: Keysym = 0xffb0;
+pub const XK_KP_1: Keysym = 0xffb1;
+pub const XK_KP_2: Keysym = 0xffb2;
+pub const XK_KP_3: Keysym = 0xffb3;
+pub const XK_KP_4: Keysym = 0xffb4;
+pub const XK_KP_5: Keysym = 0xffb5;
+pub const XK_KP_6: Keysym = 0xffb6;
+pub const XK_KP_7: Keysym = 0xffb7;
+pub const XK_KP_8: Keysym = 0xffb8;
+pub const XK_KP_9: Keysym = 0xffb9;
+pub const XK_F1: Keysym = 0xffbe;
+pub const XK_F2: Keysym = 0xffbf;
+pub const XK_F3: Keysym = 0xffc0;
+pub const XK_F4: Keysym = 0xffc1;
+pub const XK_F5: Keysym = 0xffc2;
+pub const XK_F6: Keysym = 0xffc3;
+pub const XK_F7: Keysym = 0xffc4;
+pub const XK_F8: Keysym = 0xffc5;
+pub const XK_F9: Keysym = 0xffc6;
+pub const XK_F10: Keysym = 0xffc7;
+pub const XK_F11: Keysym = 0xffc8;
+pub const XK_L1: Keysym = 0xffc8;
+pub const XK_F12: Keysym = 0xffc9;
+pub const XK_L2: Keysym = 0xffc9;
+pub const XK_F13: Keysym = 0xffca;
+pub const XK_L3: Keysym = 0xffca;
+pub const XK_F14: Keysym = 0xffcb;
+pub const XK_L4: Keysym = 0xffcb;
+pub const XK_F15: Keysym = 0xffcc;
+pub const XK_L5: Keysym = 0xffcc;
+pub const XK_F16: Keysym = 0xffcd;
+pub const XK_L6: Keysym = 0xffcd;
+pub const XK_F17: Keysym = 0xffce;
+pub const XK_L7: Keysym = 0xffce;
+pub const XK_F18: Keysym = 0xffcf;
+pub const XK_L8: Keysym = 0xffcf;
+pub const XK_F19: Keysym = 0xffd0;
+pub const XK_L9: Keysym = 0xffd0;
+pub const XK_F20: Keysym = 0xffd1;
+pub const XK_L10: Keysym = 0xffd1;
+pub const XK_F21: Keysym = 0xffd2;
+pub const XK_R1: Keysym = 0xffd2;
+pub const XK_F22: Keysym = 0xffd3;
+pub const XK_R2: Keysym = 0xffd3;
+pub const XK_F23: Keysym = 0xffd4;
+pub const XK_R3: Keysym = 0xffd4;
+pub const XK_F24: Keysym = 0xffd5;
+pub const XK_R4: Keysym = 0xffd5;
+pub const XK_F25: Keysym = 0xffd6;
+pub const XK_R5: Keysym = 0xffd6;
+pub const XK_F26: Keysym = 0xffd7;
+pub const XK_R6: Keysym = 0xffd7;
+pub const XK_F27: Keysym = 0xffd8;
+pub const XK_R7: Keysym = 0xffd8;
+pub const XK_F28: Keysym = 0xffd9;
+pub const XK_R8: Keysym = 0xffd9;
+pub const XK_F29: Keysym = 0xffda;
+pub const XK_R9: Keysym = 0xffda;
+pub const XK_F30: Keysym = 0xffdb;
+pub const XK_R10: Keysym = 0xffdb;
+pub const XK_F31: Keysym = 0xffdc;
+pub const XK_R11: Keysym = 0xffdc;
+pub const XK_F32: Keysym = 0xffdd;
+pub const XK_R12: Keysym = 0xffdd;
+pub const XK_F33: Keysym = 0xffde;
+pub const XK_R13: Keysym = 0xffde;
+pub const XK_F34: Keysym = 0xffdf;
+pub const XK_R14: Keysym = 0xffdf;
+pub const XK_F35: Keysym = 0xffe0;
+pub const XK_R15: Keysym = 0xffe0;
+#[doc = " Left shift"]
+pub const XK_Shift_L: Keysym = 0xffe1;
+#[doc = " Right shift"]
+pub const XK_Shift_R: Keysym = 0xffe2;
+#[doc = " Left control"]
+pub const XK_Control_L: Keysym = 0xffe3;
+#[doc = " Right control"]
+pub const XK_Control_R: Keysym = 0xffe4;
+#[doc = " Caps lock"]
+pub const XK_Caps_Lock: Keysym = 0xffe5;
+#[doc = " Shift lock"]
+pub const XK_Shift_Lock: Keysym = 0xffe6;
+#[doc = " Left meta"]
+pub const XK_Meta_L: Keysym = 0xffe7;
+#[doc = " Right meta"]
+pub const XK_Meta_R: Keysym = 0xffe8;
+#[doc = " Left alt"]
+pub const XK_Alt_L: Keysym = 0xffe9;
+#[doc = " Right alt"]
+pub const XK_Alt_R: Keysym = 0xffea;
+#[doc = " Left super"]
+pub const XK_Super_L: Keysym = 0xffeb;
+#[doc = " Right super"]
+pub const XK_Super_R: Keysym = 0xffec;
+#[doc = " Left hyper"]
+pub const XK_Hyper_L: Keysym = 0xffed;
+#[doc = " Right hyper"]
+pub const XK_Hyper_R: Keysym = 0xffee;
+pub const XK_ISO_Lock: Keysym = 0xfe01;
+pub const XK_ISO_Level2_Latch: Keysym = 0xfe02;
+pub const XK_ISO_Level3_Shift: Keysym = 0xfe03;
+pub const XK_ISO_Level3_Latch: Keysym = 0xfe04;
+pub const XK_ISO_Level3_Lock: Keysym = 0xfe05;
+pub const XK_ISO_Level5_Shift: Keysym = 0xfe11;
+pub const XK_ISO_Level5_Latch: Keysym = 0xfe12;
+pub const XK_ISO_Level5_Lock: Keysym = 0xfe13;
+#[doc = " Alias for `XK_Mode_switch`"]
+pub const XK_ISO_Group_Shift: Keysym = 0xff7e;
+pub const XK_ISO_Group_Latch: Keysym = 0xfe06;
+pub const XK_ISO_Group_Lock: Keysym = 0xfe07;
+pub const XK_ISO_Next_Group: Keysym = 0xfe08;
+pub const XK_ISO_Next_Group_Lock: Keysym = 0xfe09;
+pub const XK_ISO_Prev_Group: Keysym = 0xfe0a;
+pub const XK_ISO_Prev_Group_Lock: Keysym = 0xfe0b;
+pub const XK_ISO_First_Group: Keysym = 0xfe0c;
+pub const XK_ISO_First_Group_Lock: Keysym = 0xfe0d;
+pub const XK_ISO_Last_Group: Keysym = 0xfe0e;
+pub const XK_ISO_Last_Group_Lock: Keysym = 0xfe0f;
+pub const XK_ISO_Left_Tab: Keysym = 0xfe20;
+pub const XK_ISO_Move_Line_Up: Keysym = 0xfe21;
+pub const XK_ISO_Move_Line_Down: Keysym = 0xfe22;
+pub const XK_ISO_Partial_Line_Up: Keysym = 0xfe23;
+pub const XK_ISO_Partial_Line_Down: Keysym = 0xfe24;
+pub const XK_ISO_Partial_Space_Left: Keysym = 0xfe25;
+pub const XK_ISO_Partial_Space_Right: Keysym = 0xfe26;
+pub const XK_ISO_Set_Margin_Left: Keysym = 0xfe27;
+pub const XK_ISO_Set_Margin_Right: Keysym = 0xfe28;
+pub const XK_ISO_Release_Margin_Left: Keysym = 0xfe29;
+pub const XK_ISO_Release_Margin_Right: Keysym = 0xfe2a;
+pub const XK_ISO_Release_Both_Margins: Keysym = 0xfe2b;
+pub const XK_ISO_Fast_Cursor_Left: Keysym = 0xfe2c;
+pub const XK_ISO_Fast_Cursor_Right: Keysym = 0xfe2d;
+pub const XK_ISO_Fast_Cursor_Up: Keysym = 0xfe2e;
+pub const XK_ISO_Fast_Cursor_Down: Keysym = 0xfe2f;
+pub const XK_ISO_Continuous_Underline: Keysym = 0xfe30;
+pub const XK_ISO_Discontinuous_Underline: Keysym = 0xfe31;
+pub const XK_ISO_Emphasize: Keysym = 0xfe32;
+pub const XK_ISO_Center_Object: Keysym = 0xfe33;
+pub const XK_ISO_Enter: Keysym = 0xfe34;
+pub const XK_dead_grave: Keysym = 0xfe50;
+pub const XK_dead_acute: Keysym = 0xfe51;
+pub const XK_dead_circumflex: Keysym = 0xfe52;
+pub const XK_dead_tilde: Keysym = 0xfe53;
+#[doc = " alias for `XK_dead_tilde`"]
+pub const XK_dead_perispomeni: Keysym = 0xfe53;
+pub const XK_dead_macron: Keysym = 0xfe54;
+pub const XK_dead_breve: Keysym = 0xfe55;
+pub const XK_dead_abovedot: Keysym = 0xfe56;
+pub const XK_dead_diaeresis: Keysym = 0xfe57;
+pub const XK_dead_abovering: Keysym = 0xfe58;
+pub const XK_dead_doubleacute: Keysym = 0xfe59;
+pub const XK_dead_caron: Keysym = 0xfe5a;
+pub const XK_dead_cedilla: Keysym = 0xfe5b;
+pub const XK_dead_ogonek: Keysym = 0xfe5c;
+pub const XK_dead_iota: Keysym = 0xfe5d;
+pub const XK_dead_voiced_sound: Keysym = 0xfe5e;
+pub const XK_dead_semivoiced_sound: Keysym = 0xfe5f;
+pub const XK_dead_belowdot: Keysym = 0xfe60;
+pub const XK_dead_hook: Keysym = 0xfe61;
+pub const XK_dead_horn: Keysym = 0xfe62;
+pub const XK_dead_stroke: Keysym = 0xfe63;
+pub const XK_dead_abovecomma: Keysym = 0xfe64;
+#[doc = " alias for `XK_dead_abovecomma`"]
+pub const XK_dead_psili: Keysym = 0xfe64;
+pub const XK_dead_abovereversedcomma: Keysym = 0xfe65;
+#[doc = " alias for `XK_dead_abovereversedcomma`"]
+pub const XK_dead_dasia: Keysym = 0xfe65;
+pub const XK_dead_doublegrave: Keysym = 0xfe66;
+pub const XK_dead_belowring: Keysym = 0xfe67;
+pub const XK_dead_belowmacron: Keysym = 0xfe68;
+pub const XK_dead_belowcircumflex: Keysym = 0xfe69;
+pub const XK_dead_belowtilde: Keysym = 0xfe6a;
+pub const XK_dead_belowbreve: Keysym = 0xfe6b;
+pub const XK_dead_belowdiaeresis: Keysym = 0xfe6c;
+pub const XK_dead_invertedbreve: Keysym = 0xfe6d;
+pub const XK_dead_belowcomma: Keysym = 0xfe6e;
+pub const XK_dead_currency: Keysym = 0xfe6f;
+pub const XK_dead_lowline: Keysym = 0xfe90;
+pub const XK_dead_aboveverticalline: Keysym = 0xfe91;
+pub const XK_dead_belowverticalline: Keysym = 0xfe92;
+pub const XK_dead_longsolidusoverlay: Keysym = 0xfe93;
+pub const XK_dead_a: Keysym = 0xfe80;
+pub const XK_dead_A: Keysym = 0xfe81;
+pub const XK_dead_e: Keysym = 0xfe82;
+pub const XK_dead_E: Keysym = 0xfe83;
+pub const XK_dead_i: Keysym = 0xfe84;
+pub const XK_dead_I: Keysym = 0xfe85;
+pub const XK_dead_o: Keysym = 0xfe86;
+pub const XK_dead_O: Keysym = 0xfe87;
+pub const XK_dead_u: Keysym = 0xfe88;
+pub const XK_dead_U: Keysym = 0xfe89;
+pub const XK_dead_small_schwa: Keysym = 0xfe8a;
+pub const XK_dead_capital_schwa: Keysym = 0xfe8b;
+pub const XK_dead_greek: Keysym = 0xfe8c;
+pub const XK_First_Virtual_Screen: Keysym = 0xfed0;
+pub const XK_Prev_Virtual_Screen: Keysym = 0xfed1;
+pub const XK_Next_Virtual_Screen: Keysym = 0xfed2;
+pub const XK_Last_Virtual_Screen: Keysym = 0xfed4;
+pub const XK_Terminate_Server: Keysym = 0xfed5;
+pub const XK_AccessX_Enable: Keysym = 0xfe70;
+pub const XK_AccessX_Feedback_Enable: Keysym = 0xfe71;
+pub const XK_RepeatKeys_Enable: Keysym = 0xfe72;
+pub const XK_SlowKeys_Enable: Keysym = 0xfe73;
+pub const XK_BounceKeys_Enable: Keysym = 0xfe74;
+pub const XK_StickyKeys_Enable: Keysym = 0xfe75;
+pub const XK_MouseKeys_Enable: Keysym = 0xfe76;
+pub const XK_MouseKeys_Accel_Enable: Keysym = 0xfe77;
+pub const XK_Overlay1_Enable: Keysym = 0xfe78;
+pub const XK_Overlay2_Enable: Keysym = 0xfe79;
+pub const XK_AudibleBell_Enable: Keysym = 0xfe7a;
+pub const XK_Pointer_Left: Keysym = 0xfee0;
+pub const XK_Pointer_Right: Keysym = 0xfee1;
+pub const XK_Pointer_Up: Keysym = 0xfee2;
+pub const XK_Pointer_Down: Keysym = 0xfee3;
+pub const XK_Pointer_UpLeft: Keysym = 0xfee4;
+pub const XK_Pointer_UpRight: Keysym = 0xfee5;
+pub const XK_Pointer_DownLeft: Keysym = 0xfee6;
+pub const XK_Pointer_DownRight: Keysym = 0xfee7;
+pub const XK_Pointer_Button_Dflt: Keysym = 0xfee8;
+pub const XK_Pointer_Button1: Keysym = 0xfee9;
+pub const XK_Pointer_Button2: Keysym = 0xfeea;
+pub const XK_Pointer_Button3: Keysym = 0xfeeb;
+pub const XK_Pointer_Button4: Keysym = 0xfeec;
+pub const XK_Pointer_Button5: Keysym = 0xfeed;
+pub const XK_Pointer_DblClick_Dflt: Keysym = 0xfeee;
+pub const XK_Pointer_DblClick1: Keysym = 0xfeef;
+pub const XK_Pointer_DblClick2: Keysym = 0xfef0;
+pub const XK_Pointer_DblClick3: Keysym = 0xfef1;
+pub const XK_Pointer_DblClick4: Keysym = 0xfef2;
+pub const XK_Pointer_DblClick5: Keysym = 0xfef3;
+pub const XK_Pointer_Drag_Dflt: Keysym = 0xfef4;
+pub const XK_Pointer_Drag1: Keysym = 0xfef5;
+pub const XK_Pointer_Drag2: Keysym = 0xfef6;
+pub const XK_Pointer_Drag3: Keysym = 0xfef7;
+pub const XK_Pointer_Drag4: Keysym = 0xfef8;
+pub const XK_Pointer_Drag5: Keysym = 0xfefd;
+pub const XK_Pointer_EnableKeys: Keysym = 0xfef9;
+pub const XK_Pointer_Accelerate: Keysym = 0xfefa;
+pub const XK_Pointer_DfltBtnNext: Keysym = 0xfefb;
+pub const XK_Pointer_DfltBtnPrev: Keysym = 0xfefc;
+pub const XK_ch: Keysym = 0xfea0;
+pub const XK_Ch: Keysym = 0xfea1;
+pub const XK_CH: Keysym = 0xfea2;
+pub const XK_c_h: Keysym = 0xfea3;
+pub const XK_C_h: Keysym = 0xfea4;
+pub const XK_C_H: Keysym = 0xfea5;
+pub const XK_3270_Duplicate: Keysym = 0xfd01;
+pub const XK_3270_FieldMark: Keysym = 0xfd02;
+pub const XK_3270_Right2: Keysym = 0xfd03;
+pub const XK_3270_Left2: Keysym = 0xfd04;
+pub const XK_3270_BackTab: Keysym = 0xfd05;
+pub const XK_3270_EraseEOF: Keysym = 0xfd06;
+pub const XK_3270_EraseInput: Keysym = 0xfd07;
+pub const XK_3270_Reset: Keysym = 0xfd08;
+pub const XK_3270_Quit: Keysym = 0xfd09;
+pub const XK_3270_PA1: Keysym = 0xfd0a;
+pub const XK_3270_PA2: Keysym = 0xfd0b;
+pub const XK_3270_PA3: Keysym = 0xfd0c;
+pub const XK_3270_Test: Keysym = 0xfd0d;
+pub const XK_3270_Attn: Keysym = 0xfd0e;
+pub const XK_3270_CursorBlink: Keysym = 0xfd0f;
+pub const XK_3270_AltCursor: Keysym = 0xfd10;
+pub const XK_3270_KeyClick: Keysym = 0xfd11;
+pub const XK_3270_Jump: Keysym = 0xfd12;
+pub const XK_3270_Ident: Keysym = 0xfd13;
+pub const XK_3270_Rule: Keysym = 0xfd14;
+pub const XK_3270_Copy: Keysym = 0xfd15;
+pub const XK_3270_Play: Keysym = 0xfd16;
+pub const XK_3270_Setup: Keysym = 0xfd17;
+pub const XK_3270_Record: Keysym = 0xfd18;
+pub const XK_3270_ChangeScreen: Keysym = 0xfd19;
+pub const XK_3270_DeleteWord: Keysym = 0xfd1a;
+pub const XK_3270_ExSelect: Keysym = 0xfd1b;
+pub const XK_3270_CursorSelect: Keysym = 0xfd1c;
+pub const XK_3270_PrintScreen: Keysym = 0xfd1d;
+pub const XK_3270_Enter: Keysym = 0xfd1e;
+#[doc = " U+0020 SPACE"]
+pub const XK_space: Keysym = 0x0020;
+#[doc = " U+0021 EXCLAMATION MARK"]
+pub const XK_exclam: Keysym = 0x0021;
+#[doc = " U+0022 QUOTATION MARK"]
+pub const XK_quotedbl: Keysym = 0x0022;
+#[doc = " U+0023 NUMBER SIGN"]
+pub const XK_numbersign: Keysym = 0x0023;
+#[doc = " U+0024 DOLLAR SIGN"]
+pub const XK_dollar: Keysym = 0x0024;
+#[doc = " U+0025 PERCENT SIGN"]
+pub const XK_percent: Keysym = 0x0025;
+#[doc = " U+0026 AMPERSAND"]
+pub const XK_ampersand: Keysym = 0x0026;
+#[doc = " U+0027 APOSTROPHE"]
+pub const XK_apostrophe: Keysym = 0x0027;
+#[doc = " deprecated"]
+pub const XK_quoteright: Keysym = 0x0027;
+#[doc = " U+0028 LEFT PARENTHESIS"]
+pub const XK_parenleft: Keysym = 0x0028;
+#[doc = " U+0029 RIGHT PARENTHESIS"]
+pub const XK_parenright: Keysym = 0x0029;
+#[doc = " U+002A ASTERISK"]
+pub const XK_asterisk: Keysym = 0x002a;
+#[doc = " U+002B PLUS SIGN"]
+pub const XK_plus: Keysym = 0x002b;
+#[doc = " U+002C COMMA"]
+pub const XK_comma: Keysym = 0x002c;
+#[doc = " U+002D HYPHEN-MINUS"]
+pub const XK_minus: Keysym = 0x002d;
+#[doc = " U+002E FULL STOP"]
+pub const XK_period: Keysym = 0x002e;
+#[doc = " U+002F SOLIDUS"]
+pub const XK_slash: Keysym = 0x002f;
+#[doc = " U+0030 DIGIT ZERO"]
+pub const XK_0: Keysym = 0x0030;
+#[doc = " U+0031 DIGIT ONE"]
+pub const XK_1: Keysym = 0x0031;
+#[doc = " U+0032 DIGIT TWO"]
+pub const XK_2: Keysym = 0x0032;
+#[doc = " U+0033 DIGIT THREE"]
+pub const XK_3: Keysym = 0x0033;
+#[doc = " U+0034 DIGIT FOUR"]
+pub const XK_4: Keysym = 0x0034;
+#[doc = " U+0035 DIGIT FIVE"]
+pub const XK_5: Keysym = 0x0035;
+#[doc = " U+0036 DIGIT SIX"]
+pub const XK_6: Keysym = 0x0036;
+#[doc = " U+0037 DIGIT SEVEN"]
+pub const XK_7: Keysym = 0x0037;
+#[doc = " U+0038 DIGIT EIGHT"]
+pub const XK_8: Keysym = 0x0038;
+#[doc = " U+0039 DIGIT NINE"]
+pub const XK_9: Keysym = 0x0039;
+#[doc = " U+003A COLON"]
+pub const XK_colon: Keysym = 0x003a;
+#[doc = " U+003B SEMICOLON"]
+pub const XK_semicolon: Keysym = 0x003b;
+#[doc = " U+003C LESS-THAN SIGN"]
+pub const XK_less: Keysym = 0x003c;
+#[doc = " U+003D EQUALS SIGN"]
+pub const XK_equal: Keysym = 0x003d;
+#[doc = " U+003E GREATER-THAN SIGN"]
+pub const XK_greater: Keysym = 0x003e;
+#[doc = " U+003F QUESTION MARK"]
+pub const XK_question: Keysym = 0x003f;
+#[doc = " U+0040 COMMERCIAL AT"]
+pub const XK_at: Keysym = 0x0040;
+#[doc = " U+0041 LATIN CAPITAL LETTER A"]
+pub const XK_A: Keysym = 0x0041;
+#[doc = " U+0042 LATIN CAPITAL LETTER B"]
+pub const XK_B: Keysym = 0x0042;
+#[doc = " U+0043 LATIN CAPITAL LETTER C"]
+pub const XK_C: Keysym = 0x0043;
+#[doc = " U+0044 LATIN CAPITAL LETTER D"]
+pub const XK_D: Keysym = 0x0044;
+#[doc = " U+0045 LATIN CAPITAL LETTER E"]
+pub const XK_E: Keysym = 0x0045;
+#[doc = " U+0046 LATIN CAPITAL LETTER F"]
+pub const XK_F: Keysym = 0x0046;
+#[doc = " U+0047 LATIN CAPITAL LETTER G"]
+pub const XK_G: Keysym = 0x0047;
+#[doc = " U+0048 LATIN CAPITAL LETTER H"]
+pub const XK_H: Keysym = 0x0048;
+#[doc = " U+0049 LATIN CAPITAL LETTER I"]
+pub const XK_I: Keysym = 0x0049;
+#[doc = " U+004A LATIN CAPITAL LETTER J"]
+pub const XK_J: Keysym = 0x004a;
+#[doc = " U+004B LATIN CAPITAL LETTER K"]
+pub const XK_K: Keysym = 0x004b;
+#[doc = " U+004C LATIN CAPITAL LETTER L"]
+pub const XK_L: Keysym = 0x004c;
+#[doc = " U+004D LATIN CAPITAL LETTER M"]
+pub const XK_M: Keysym = 0x004d;
+#[doc = " U+004E LATIN CAPITAL LETTER N"]
+pub const XK_N: Keysym = 0x004e;
+#[doc = " U+004F LATIN CAPITAL LETTER O"]
+pub const XK_O: Keysym = 0x004f;
+#[doc = " U+0050 LATIN CAPITAL LETTER P"]
+pub const XK_P: Keysym = 0x0050;
+#[doc = " U+0051 LATIN CAPITAL LETTER Q"]
+pub const XK_Q: Keysym = 0x0051;
+#[doc = " U+0052 LATIN CAPITAL LETTER R"]
+pub const XK_R: Keysym = 0x0052;
+#[doc = " U+0053 LATIN CAPITAL LETTER S"]
+pub const XK_S: Keysym = 0x0053;
+#[doc = " U+0054 LATIN CAPITAL LETTER T"]
+pub const XK_T: Keysym = 0x0054;
+#[doc = " U+0055 LATIN CAPITAL LETTER U"]
+pub const XK_U: Keysym = 0x0055;
+#[doc = " U+0056 LATIN CAPITAL LETTER V"]
+pub const XK_V: Keysym = 0x0056;
+#[doc = " U+0057 LATIN CAPITAL LETTER W"]
+pub const XK_W: Keysym = 0x0057;
+#[doc = " U+0058 LATIN CAPITAL LETTER X"]
+pub const XK_X: Keysym = 0x0058;
+#[doc = " U+0059 LATIN CAPITAL LETTER Y"]
+pub const XK_Y: Keysym = 0x0059;
+#[doc = " U+005A LATIN CAPITAL LETTER Z"]
+pub const XK_Z: Keysym = 0x005a;
+#[doc = " U+005B LEFT SQUARE BRACKET"]
+pub const XK_bracketleft: Keysym = 0x005b;
+#[doc = " U+005C REVERSE SOLIDUS"]
+pub const XK_backslash: Keysym = 0x005c;
+#[doc = " U+005D RIGHT SQUARE BRACKET"]
+pub const XK_bracketright: Keysym = 0x005d;
+#[doc = " U+005E CIRCUMFLEX ACCENT"]
+pub const XK_asciicircum: Keysym = 0x005e;
+#[doc = " U+005F LOW LINE"]
+pub const XK_underscore: Keysym = 0x005f;
+#[doc = " U+0060 GRAVE ACCENT"]
+pub const XK_grave: Keysym = 0x0060;
+#[doc = " deprecated"]
+pub const XK_quoteleft: Keysym = 0x0060;
+#[doc = " U+0061 LATIN SMALL LETTER A"]
+pub const XK_a: Keysym = 0x0061;
+#[doc = " U+0062 LATIN SMALL LETTER B"]
+pub const XK_b: Keysym = 0x0062;
+#[doc = " U+0063 LATIN SMALL LETTER C"]
+pub const XK_c: Keysym = 0x0063;
+#[doc = " U+0064 LATIN SMALL LETTER D"]
+pub const XK_d: Keysym = 0x0064;
+#[doc = " U+0065 LATIN SMALL LETTER E"]
+pub const XK_e: Keysym = 0x0065;
+#[doc = " U+0066 LATIN SMALL LETTER F"]
+pub const XK_f: Keysym = 0x0066;
+#[doc = " U+0067 LATIN SMALL LETTER G"]
+pub const XK_g: Keysym = 0x0067;
+#[doc = " U+0068 LATIN SMALL LETTER H"]
+pub const XK_h: Keysym = 0x0068;
+#[doc = " U+0069 LATIN SMALL LETTER I"]
+pub const XK_i: Keysym = 0x0069;
+#[doc = " U+006A LATIN SMALL LETTER J"]
+pub const XK_j: Keysym = 0x006a;
+#[doc = " U+006B LATIN SMALL LETTER K"]
+pub const XK_k: Keysym = 0x006b;
+#[doc = " U+006C LATIN SMALL LETTER L"]
+pub const XK_l: Keysym = 0x006c;
+#[doc = " U+006D LATIN SMALL LETTER M"]
+pub const XK_m: Keysym = 0x006d;
+#[doc = " U+006E LATIN SMALL LETTER N"]
+pub const XK_n: Keysym = 0x006e;
+#[doc = " U+006F LATIN SMALL LETTER O"]
+pub const XK_o: Keysym = 0x006f;
+#[doc = " U+0070 LATIN SMALL LETTER P"]
+pub const XK_p: Keysym = 0x0070;
+#[doc = " U+0071 LATIN SMALL LETTER Q"]
+pub const XK_q: Keysym = 0x0071;
+#[doc = " U+0072 LATIN SMALL LETTER R"]
+pub const XK_r: Keysym = 0x0072;
+#[doc = " U+0073 LATIN SMALL LETTER S"]
+pub const XK_s: Keysym = 0x0073;
+#[doc = " U+0074 LATIN SMALL LETTER T"]
+pub const XK_t: Keysym = 0x0074;
+#[doc = " U+0075 LATIN SMALL LETTER U"]
+pub const XK_u: Keysym = 0x0075;
+#[doc = " U+0076 LATIN SMALL LETTER V"]
+pub const XK_v: Keysym = 0x0076;
+#[doc = " U+0077 LATIN SMALL LETTER W"]
+pub const XK_w: Keysym = 0x0077;
+#[doc = " U+0078 LATIN SMALL LETTER X"]
+pub const XK_x: Keysym = 0x0078;
+#[doc = " U+0079 LATIN SMALL LETTER Y"]
+pub const XK_y: Keysym = 0x0079;
+#[doc = " U+007A LATIN SMALL LETTER Z"]
+pub const XK_z: Keysym = 0x007a;
+#[doc = " U+007B LEFT CURLY BRACKET"]
+pub const XK_braceleft: Keysym = 0x007b;
+#[doc = " U+007C VERTICAL LINE"]
+pub const XK_bar: Keysym = 0x007c;
+#[doc = " U+007D RIGHT CURLY BRACKET"]
+pub const XK_braceright: Keysym = 0x007d;
+#[doc = " U+007E TILDE"]
+pub const XK_asciitilde: Keysym = 0x007e;
+#[doc = " U+00A0 NO-BREAK SPACE"]
+pub const XK_nobreakspace: Keysym = 0x00a0;
+#[doc = " U+00A1 INVERTED EXCLAMATION MARK"]
+pub const XK_exclamdown: Keysym = 0x00a1;
+#[doc = " U+00A2 CENT SIGN"]
+pub const XK_cent: Keysym = 0x00a2;
+#[doc = " U+00A3 POUND SIGN"]
+pub const XK_sterling: Keysym = 0x00a3;
+#[doc = " U+00A4 CURRENCY SIGN"]
+pub const XK_currency: Keysym = 0x00a4;
+#[doc = " U+00A5 YEN SIGN"]
+pub const XK_yen: Keysym = 0x00a5;
+#[doc = " U+00A6 BROKEN BAR"]
+pub const XK_brokenbar: Keysym = 0x00a6;
+#[doc = " U+00A7 SECTION SIGN"]
+pub const XK_section: Keysym = 0x00a7;
+#[doc = " U+00A8 DIAERESIS"]
+pub const XK_diaeresis: Keysym = 0x00a8;
+#[doc = " U+00A9 COPYRIGHT SIGN"]
+pub const XK_copyright: Keysym = 0x00a9;
+#[doc = " U+00AA FEMININE ORDINAL INDICATOR"]
+pub const XK_ordfeminine: Keysym = 0x00aa;
+#[doc = " U+00AB LEFT-POINTING DOUBLE ANGLE QUOTATION MARK"]
+pub const XK_guillemotleft: Keysym = 0x00ab;
+#[doc = " U+00AC NOT SIGN"]
+pub const XK_notsign: Keysym = 0x00ac;
+#[doc = " U+00AD SOFT HYPHEN"]
+pub const XK_hyphen: Keysym = 0x00ad;
+#[doc = " U+00AE REGISTERED SIGN"]
+pub const XK_registered: Keysym = 0x00ae;
+#[doc = " U+00AF MACRON"]
+pub const XK_macron: Keysym = 0x00af;
+#[doc = " U+00B0 DEGREE SIGN"]
+pub const XK_degree: Keysym = 0x00b0;
+#[doc = " U+00B1 PLUS-MINUS SIGN"]
+pub const XK_plusminus: Keysym = 0x00b1;
+#[doc = " U+00B2 SUPERSCRIPT TWO"]
+pub const XK_twosuperior: Keysym = 0x00b2;
+#[doc = " U+00B3 SUPERSCRIPT THREE"]
+pub const XK_threesuperior: Keysym = 0x00b3;
+#[doc = " U+00B4 ACUTE ACCENT"]
+pub const XK_acute: Keysym = 0x00b4;
+#[doc = " U+00B5 MICRO SIGN"]
+pub const XK_mu: Keysym = 0x00b5;
+#[doc = " U+00B6 PILCROW SIGN"]
+pub const XK_paragraph: Keysym = 0x00b6;
+#[doc = " U+00B7 MIDDLE DOT"]
+pub const XK_periodcentered: Keysym = 0x00b7;
+#[doc = " U+00B8 CEDILLA"]
+pub const XK_cedilla: Keysym = 0x00b8;
+#[doc = " U+00B9 SUPERSCRIPT ONE"]
+pub const XK_onesuperior: Keysym = 0x00b9;
+#[doc = " U+00BA MASCULINE ORDINAL INDICATOR"]
+pub const XK_masculine: Keysym = 0x00ba;
+#[doc = " U+00BB RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK"]
+pub const XK_guillemotright: Keysym = 0x00bb;
+#[doc = " U+00BC VULGAR FRACTION ONE QUARTER"]
+pub const XK_onequarter: Keysym = 0x00bc;
+#[doc = " U+00BD VULGAR FRACTION ONE HALF"]
+pub const XK_onehalf: Keysym = 0x00bd;
+#[doc = " U+00BE VULGAR FRACTION THREE QUARTERS"]
+pub const XK_threequarters: Keysym = 0x00be;
+#[doc = " U+00BF INVERTED QUESTION MARK"]
+pub const XK_questiondown: Keysym = 0x00bf;
+#[doc = " U+00C0 LATIN CAPITAL LETTER A WITH GRAVE"]
+pub const XK_Agrave: Keysym = 0x00c0;
+#[doc = " U+00C1 LATIN CAPITAL LETTER A WITH ACUTE"]
+pub const XK_Aacute: Keysym = 0x00c1;
+#[doc = " U+00C2 LATIN CAPITAL LETTER A WITH CIRCUMFLEX"]
+pub const XK_Acircumflex: Keysym = 0x00c2;
+#[doc = " U+00C3 LATIN CAPITAL LETTER A WITH TILDE"]
+pub const XK_Atilde: Keysym = 0x00c3;
+#[doc = " U+00C4 LATIN CAPITAL LETTER A WITH DIAERESIS"]
+pub const XK_Adiaeresis: Keysym = 0x00c4;
+#[doc = " U+00C5 LATIN CAPITAL LETTER A WITH RING ABOVE"]
+pub const XK_Aring: Keysym = 0x00c5;
+#[doc = " U+00C6 LATIN CAPITAL LETTER AE"]
+pub const XK_AE: Keysym = 0x00c6;
+#[doc = " U+00C7 LATIN CAPITAL LETTER C WITH CEDILLA"]
+pub const XK_Ccedilla: Keysym = 0x00c7;
+#[doc = " U+00C8 LATIN CAPITAL LETTER E WITH GRAVE"]
+pub const XK_Egrave: Keysym = 0x00c8;
+#[doc = " U+00C9 LATIN CAPITAL LETTER E WITH ACUTE"]
+pub const XK_Eacute: Keysym = 0x00c9;
+#[doc = " U+00CA LATIN CAPITAL LETTER E WITH CIRCUMFLEX"]
+pub const XK_Ecircumflex: Keysym = 0x00ca;
+#[doc = " U+00CB LATIN CAPITAL LETTER E WITH DIAERESIS"]
+pub const XK_Ediaeresis: Keysym = 0x00cb;
+#[doc = " U+00CC LATIN CAPITAL LETTER I WITH GRAVE"]
+pub const XK_Igrave: Keysym = 0x00cc;
+#[doc = " U+00CD LATIN CAPITAL LETTER I WITH ACUTE"]
+pub const XK_Iacute: Keysym = 0x00cd;
+#[doc = " U+00CE LATIN CAPITAL LETTER I WITH CIRCUMFLEX"]
+pub const XK_Icircumflex: Keysym = 0x00ce;
+#[doc = " U+00CF LATIN CAPITAL LETTER I WITH DIAERESIS"]
+pub const XK_Idiaeresis: Keysym = 0x00cf;
+#[doc = " U+00D0 LATIN CAPITAL LETTER ETH"]
+pub const XK_ETH: Keysym = 0x00d0;
+#[doc = " deprecated"]
+pub const XK_Eth: Keysym = 0x00d0;
+#[doc = " U+00D1 LATIN CAPITAL LETTER N WITH TILDE"]
+pub const XK_Ntilde: Keysym = 0x00d1;
+#[doc = " U+00D2 LATIN CAPITAL LETTER O WITH GRAVE"]
+pub const XK_Ograve: Keysym = 0x00d2;
+#[doc = " U+00D3 LATIN CAPITAL LETTER O WITH ACUTE"]
+pub const XK_Oacute: Keysym = 0x00d3;
+#[doc = " U+00D4 LATIN CAPITAL LETTER O WITH CIRCUMFLEX"]
+pub const XK_Ocircumflex: Keysym = 0x00d4;
+#[doc = " U+00D5 LATIN CAPITAL LETTER O WITH TILDE"]
+pub const XK_Otilde: Keysym = 0x00d5;
+#[doc = " U+00D6 LATIN CAPITAL LETTER O WITH DIAERESIS"]
+pub const XK_Odiaeresis: Keysym = 0x00d6;
+#[doc = " U+00D7 MULTIPLICATION SIGN"]
+pub const XK_multiply: Keysym = 0x00d7;
+#[doc = " U+00D8 LATIN CAPITAL LETTER O WITH STROKE"]
+pub const XK_Oslash: Keysym = 0x00d8;
+#[doc = " U+00D8 LATIN CAPITAL LETTER O WITH STROKE"]
+pub const XK_Ooblique: Keysym = 0x00d8;
+#[doc = " U+00D9 LATIN CAPITAL LETTER U WITH GRAVE"]
+pub const XK_Ugrave: Keysym = 0x00d9;
+#[doc = " U+00DA LATIN CAPITAL LETTER U WITH ACUTE"]
+pub const XK_Uacute: Keysym = 0x00da;
+#[doc = " U+00DB LATIN CAPITAL LETTER U WITH CIRCUMFLEX"]
+pub const XK_Ucircumflex: Keysym = 0x00db;
+#[doc = " U+00DC LATIN CAPITAL LETTER U WITH DIAERESIS"]
+pub const XK_Udiaeresis: Keysym = 0x00dc;
+#[doc = " U+00DD LATIN CAPITAL LETTER Y WITH ACUTE"]
+pub const XK_Yacute: Keysym = 0x00dd;
+#[doc = " U+00DE LATIN CAPITAL LETTER THORN"]
+pub const XK_THORN: Keysym = 0x00de;
+#[doc = " deprecated"]
+pub const XK_Thorn: Keysym = 0x00de;
+#[doc = " U+00DF LATIN SMALL LETTER SHARP S"]
+pub const XK_ssharp: Keysym = 0x00df;
+#[doc = " U+00E0 LATIN SMALL LETTER A WITH GRAVE"]
+pub const XK_agrave: Keysym = 0x00e0;
+#[doc = " U+00E1 LATIN SMALL LETTER A WITH ACUTE"]
+pub const XK_aacute: Keysym = 0x00e1;
+#[doc = " U+00E2 LATIN SMALL LETTER A WITH CIRCUMFLEX"]
+pub const XK_acircumflex: Keysym = 0x00e2;
+#[doc = " U+00E3 LATIN SMALL LETTER A WITH TILDE"]
+pub const XK_atilde: Keysym = 0x00e3;
+#[doc = " U+00E4 LATIN SMALL LETTER A WITH DIAERESIS"]
+pub const XK_adiaeresis: Keysym = 0x00e4;
+#[doc = " U+00E5 LATIN SMALL LETTER A WITH RING ABOVE"]
+pub const XK_aring: Keysym = 0x00e5;
+#[doc = " U+00E6 LATIN SMALL LETTER AE"]
+pub const XK_ae: Keysym = 0x00e6;
+#[doc = " U+00E7 LATIN SMALL LETTER C WITH CEDILLA"]
+pub const XK_ccedilla: Keysym = 0x00e7;
+#[doc = " U+00E8 LATIN SMALL LETTER E WITH GRAVE"]
+pub const XK_egrave: Keysym = 0x00e8;
+#[doc = " U+00E9 LATIN SMALL LETTER E WITH ACUTE"]
+pub const XK_eacute: Keysym = 0x00e9;
+#[doc = " U+00EA LATIN SMALL LETTER E WITH CIRCUMFLEX"]
+pub const XK_ecircumflex: Keysym = 0x00ea;
+#[doc = " U+00EB LATIN SMALL LETTER E WITH DIAERESIS"]
+pub const XK_ediaeresis: Keysym = 0x00eb;
+#[doc = " U+00EC LATIN SMALL LETTER I WITH GRAVE"]
+pub const XK_igrave: Keysym = 0x00ec;
+#[doc = " U+00ED LATIN SMALL LETTER I WITH ACUTE"]
+pub const XK_iacute: Keysym = 0x00ed;
+#[doc = " U+00EE LATIN SMALL LETTER I WITH CIRCUMFLEX"]
+pub const XK_icircumflex: Keysym = 0x00ee;
+#[doc = " U+00EF LATIN SMALL LETTER I WITH DIAERESIS"]
+pub const XK_idiaeresis: Keysym = 0x00ef;
+#[doc = " U+00F0 LATIN SMALL LETTER ETH"]
+pub const XK_eth: Keysym = 0x00f0;
+#[doc = " U+00F1 LATIN SMALL LETTER N WITH TILDE"]
+pub const XK_ntilde: Keysym = 0x00f1;
+#[doc = " U+00F2 LATIN SMALL LETTER O WITH GRAVE"]
+pub const XK_ograve: Keysym = 0x00f2;
+#[doc = " U+00F3 LATIN SMALL LETTER O WITH ACUTE"]
+pub const XK_oacute: Keysym = 0x00f3;
+#[doc = " U+00F4 LATIN SMALL LETTER O WITH CIRCUMFLEX"]
+pub const XK_ocircumflex: Keysym = 0x00f4;
+#[doc = " U+00F5 LATIN SMALL LETTER O WITH TILDE"]
+pub const XK_otilde: Keysym = 0x00f5;
+#[doc = " U+00F6 LATIN SMALL LETTER O WITH DIAERESIS"]
+pub const XK_odiaeresis: Keysym = 0x00f6;
+#[doc = " U+00F7 DIVISION SIGN"]
+pub const XK_division: Keysym = 0x00f7;
+#[doc = " U+00F8 LATIN SMALL LETTER O WITH STROKE"]
+pub const XK_oslash: Keysym = 0x00f8;
+#[doc = " U+00F8 LATIN SMALL LETTER O WITH STROKE"]
+pub const XK_ooblique: Keysym = 0x00f8;
+#[doc = " U+00F9 LATIN SMALL LETTER U WITH GRAVE"]
+pub const XK_ugrave: Keysym = 0x00f9;
+#[doc = " U+00FA LATIN SMALL LETTER U WITH ACUTE"]
+pub const XK_uacute: Keysym = 0x00fa;
+#[doc = " U+00FB LATIN SMALL LETTER U WITH CIRCUMFLEX"]
+pub const XK_ucircumflex: Keysym = 0x00fb;
+#[doc = " U+00FC LATIN SMALL LETTER U WITH DIAERESIS"]
+pub const XK_udiaeresis: Keysym = 0x00fc;
+#[doc = " U+00FD LATIN SMALL LETTER Y WITH ACUTE"]
+pub const XK_yacute: Keysym = 0x00fd;
+#[doc = " U+00FE LATIN SMALL LETTER THORN"]
+pub const XK_thorn: Keysym = 0x00fe;
+#[doc = " U+00FF LATIN SMALL LETTER Y WITH DIAERESIS"]
+pub const XK_ydiaeresis: Keysym = 0x00ff;
+#[doc = " U+0104 LATIN CAPITAL LETTER A WITH OGONEK"]
+pub const XK_Aogonek: Keysym = 0x01a1;
+#[doc = " U+02D8 BREVE"]
+pub const XK_breve: Keysym = 0x01a2;
+#[doc = " U+0141 LATIN CAPITAL LETTER L WITH STROKE"]
+pub const XK_Lstroke: Keysym = 0x01a3;
+#[doc = " U+013D LATIN CAPITAL LETTER L WITH CARON"]
+pub const XK_Lcaron: Keysym = 0x01a5;
+#[doc = " U+015A LATIN CAPITAL LETTER S WITH ACUTE"]
+pub const XK_Sacute: Keysym = 0x01a6;
+#[doc = " U+0160 LATIN CAPITAL LETTER S WITH CARON"]
+pub const XK_Scaron: Keysym = 0x01a9;
+#[doc = " U+015E LATIN CAPITAL LETTER S WITH CEDILLA"]
+pub const XK_Scedilla: Keysym = 0x01aa;
+#[doc = " U+0164 LATIN CAPITAL LETTER T WITH CARON"]
+pub const XK_Tcaron: Keysym = 0x01ab;
+#[doc = " U+0179 LATIN CAPITAL LETTER Z WITH ACUTE"]
+pub const XK_Zacute: Keysym = 0x01ac;
+#[doc = " U+017D LATIN CAPITAL LETTER Z WITH CARON"]
+pub const XK_Zcaron: Keysym = 0x01ae;
+#[doc = " U+017B LATIN CAPITAL LETTER Z WITH DOT ABOVE"]
+pub const XK_Zabovedot: Keysym = 0x01af;
+#[doc = " U+0105 LATIN SMALL LETTER A WITH OGONEK"]
+pub const XK_aogonek: Keysym = 0x01b1;
+#[doc = " U+02DB OGONEK"]
+pub const XK_ogonek: Keysym = 0x01b2;
+#[doc = " U+0142 LATIN SMALL LETTER L WITH STROKE"]
+pub const XK_lstroke: Keysym = 0x01b3;
+#[doc = " U+013E LATIN SMALL LETTER L WITH CARON"]
+pub const XK_lcaron: Keysym = 0x01b5;
+#[doc = " U+015B LATIN SMALL LETTER S WITH ACUTE"]
+pub const XK_sacute: Keysym = 0x01b6;
+#[doc = " U+02C7 CARON"]
+pub const XK_caron: Keysym = 0x01b7;
+#[doc = " U+0161 LATIN SMALL LETTER S WITH CARON"]
+pub const XK_scaron: Keysym = 0x01b9;
+#[doc = " U+015F LATIN SMALL LETTER S WITH CEDILLA"]
+pub const XK_scedilla: Keysym = 0x01ba;
+#[doc = " U+0165 LATIN SMALL LETTER T WITH CARON"]
+pub const XK_tcaron: Keysym = 0x01bb;
+#[doc = " U+017A LATIN SMALL LETTER Z WITH ACUTE"]
+pub const XK_zacute: Keysym = 0x01bc;
+#[doc = " U+02DD DOUBLE ACUTE ACCENT"]
+pub const XK_doubleacute: Keysym = 0x01bd;
+#[doc = " U+017E LATIN SMALL LETTER Z WITH CARON"]
+pub const XK_zcaron: Keysym = 0x01be;
+#[doc = " U+017C LATIN SMALL LETTER Z WITH DOT ABOVE"]
+pub const XK_zabovedot: Keysym = 0x01bf;
+#[doc = " U+0154 LATIN CAPITAL LETTER R WITH ACUTE"]
+pub const XK_Racute: Keysym = 0x01c0;
+#[doc = " U+0102 LATIN CAPITAL LETTER A WITH BREVE"]
+pub const XK_Abreve: Keysym = 0x01c3;
+#[doc = " U+0139 LATIN CAPITAL LETTER L WITH ACUTE"]
+pub const XK_Lacute: Keysym = 0x01c5;
+#[doc = " U+0106 LATIN CAPITAL LETTER C WITH ACUTE"]
+pub const XK_Cacute: Keysym = 0x01c6;
+#[doc = " U+010C LATIN CAPITAL LETTER C WITH CARON"]
+pub const XK_Ccaron: Keysym = 0x01c8;
+#[doc = " U+0118 LATIN CAPITAL LETTER E WITH OGONEK"]
+pub const XK_Eogonek: Keysym = 0x01ca;
+#[doc = " U+011A LATIN CAPITAL LETTER E WITH CARON"]
+pub const XK_Ecaron: Keysym = 0x01cc;
+#[doc = " U+010E LATIN CAPITAL LETTER D WITH CARON"]
+pub const XK_Dcaron: Keysym = 0x01cf;
+#[doc = " U+0110 LATIN CAPITAL LETTER D WITH STROKE"]
+pub const XK_Dstroke: Keysym = 0x01d0;
+#[doc = " U+0143 LATIN CAPITAL LETTER N WITH ACUTE"]
+pub const XK_Nacute: Keysym = 0x01d1;
+#[doc = " U+0147 LATIN CAPITAL LETTER N WITH CARON"]
+pub const XK_Ncaron: Keysym = 0x01d2;
+#[doc = " U+0150 LATIN CAPITAL LETTER O WITH DOUBLE ACUTE"]
+pub const XK_Odoubleacute: Keysym = 0x01d5;
+#[doc = " U+0158 LATIN CAPITAL LETTER R WITH CARON"]
+pub const XK_Rcaron: Keysym = 0x01d8;
+#[doc = " U+016E LATIN CAPITAL LETTER U WITH RING ABOVE"]
+pub const XK_Uring: Keysym = 0x01d9;
+#[doc = " U+0170 LATIN CAPITAL LETTER U WITH DOUBLE ACUTE"]
+pub const XK_Udoubleacute: Keysym = 0x01db;
+#[doc = " U+0162 LATIN CAPITAL LETTER T WITH CEDILLA"]
+pub const XK_Tcedilla: Keysym = 0x01de;
+#[doc = " U+0155 LATIN SMALL LETTER R WITH ACUTE"]
+pub const XK_racute: Keysym = 0x01e0;
+#[doc = " U+0103 LATIN SMALL LETTER A WITH BREVE"]
+pub const XK_abreve: Keysym = 0x01e3;
+#[doc = " U+013A LATIN SMALL LETTER L WITH ACUTE"]
+pub const XK_lacute: Keysym = 0x01e5;
+#[doc = " U+0107 LATIN SMALL LETTER C WITH ACUTE"]
+pub const XK_cacute: Keysym = 0x01e6;
+#[doc = " U+010D LATIN SMALL LETTER C WITH CARON"]
+pub const XK_ccaron: Keysym = 0x01e8;
+#[doc = " U+0119 LATIN SMALL LETTER E WITH OGONEK"]
+pub const XK_eogonek: Keysym = 0x01ea;
+#[doc = " U+011B LATIN SMALL LETTER E WITH CARON"]
+pub const XK_ecaron: Keysym = 0x01ec;
+#[doc = " U+010F LATIN SMALL LETTER D WITH CARON"]
+pub const XK_dcaron: Keysym = 0x01ef;
+#[doc = " U+0111 LATIN SMALL LETTER D WITH STROKE"]
+pub const XK_dstroke: Keysym = 0x01f0;
+#[doc = " U+0144 LATIN SMALL LETTER N WITH ACUTE"]
+pub const XK_nacute: Keysym = 0x01f1;
+#[doc = " U+0148 LATIN SMALL LETTER N WITH CARON"]
+pub const XK_ncaron: Keysym = 0x01f2;
+#[doc = " U+0151 LATIN SMALL LETTER O WITH DOUBLE ACUTE"]
+pub const XK_odoubleacute: Keysym = 0x01f5;
+#[doc = " U+0159 LATIN SMALL LETTER R WITH CARON"]
+pub const XK_rcaron: Keysym = 0x01f8;
+#[doc = " U+016F LATIN SMALL LETTER U WITH RING ABOVE"]
+pub const XK_uring: Keysym = 0x01f9;
+#[doc = " U+0171 LATIN SMALL LETTER U WITH DOUBLE ACUTE"]
+pub const XK_udoubleacute: Keysym = 0x01fb;
+#[doc = " U+0163 LATIN SMALL LETTER T WITH CEDILLA"]
+pub const XK_tcedilla: Keysym = 0x01fe;
+#[doc = " U+02D9 DOT ABOVE"]
+pub const XK_abovedot: Keysym = 0x01ff;
+#[doc = " U+0126 LATIN CAPITAL LETTER H WITH STROKE"]
+pub const XK_Hstroke: Keysym = 0x02a1;
+#[doc = " U+0124 LATIN CAPITAL LETTER H WITH CIRCUMFLEX"]
+pub const XK_Hcircumflex: Keysym = 0x02a6;
+#[doc = " U+0130 LATIN CAPITAL LETTER I WITH DOT ABOVE"]
+pub const XK_Iabovedot: Keysym = 0x02a9;
+#[doc = " U+011E LATIN CAPITAL LETTER G WITH BREVE"]
+pub const XK_Gbreve: Keysym = 0x02ab;
+#[doc = " U+0134 LATIN CAPITAL LETTER J WITH CIRCUMFLEX"]
+pub const XK_Jcircumflex: Keysym = 0x02ac;
+#[doc = " U+0127 LATIN SMALL LETTER H WITH STROKE"]
+pub const XK_hstroke: Keysym = 0x02b1;
+#[doc = " U+0125 LATIN SMALL LETTER H WITH CIRCUMFLEX"]
+pub const XK_hcircumflex: Keysym = 0x02b6;
+#[doc = " U+0131 LATIN SMALL LETTER DOTLESS I"]
+pub const XK_idotless: Keysym = 0x02b9;
+#[doc = " U+011F LATIN SMALL LETTER G WITH BREVE"]
+pub const XK_gbreve: Keysym = 0x02bb;
+#[doc = " U+0135 LATIN SMALL LETTER J WITH CIRCUMFLEX"]
+pub const XK_jcircumflex: Keysym = 0x02bc;
+#[doc = " U+010A LATIN CAPITAL LETTER C WITH DOT ABOVE"]
+pub const XK_Cabovedot: Keysym = 0x02c5;
+#[doc = " U+0108 LATIN CAPITAL LETTER C WITH CIRCUMFLEX"]
+pub const XK_Ccircumflex: Keysym = 0x02c6;
+#[doc = " U+0120 LATIN CAPITAL LETTER G WITH DOT ABOVE"]
+pub const XK_Gabovedot: Keysym = 0x02d5;
+#[doc = " U+011C LATIN CAPITAL LETTER G WITH CIRCUMFLEX"]
+pub const XK_Gcircumflex: Keysym = 0x02d8;
+#[doc = " U+016C LATIN CAPITAL LETTER U WITH BREVE"]
+pub const XK_Ubreve: Keysym = 0x02dd;
+#[doc = " U+015C LATIN CAPITAL LETTER S WITH CIRCUMFLEX"]
+pub const XK_Scircumflex: Keysym = 0x02de;
+#[doc = " U+010B LATIN SMALL LETTER C WITH DOT ABOVE"]
+pub const XK_cabovedot: Keysym = 0x02e5;
+#[doc = " U+0109 LATIN SMALL LETTER C WITH CIRCUMFLEX"]
+pub const XK_ccircumflex: Keysym = 0x02e6;
+#[doc = " U+0121 LATIN SMALL LETTER G WITH DOT ABOVE"]
+pub const XK_gabovedot: Keysym = 0x02f5;
+#[doc = " U+011D LATIN SMALL LETTER G WITH CIRCUMFLEX"]
+pub const XK_gcircumflex: Keysym = 0x02f8;
+#[doc = " U+016D LATIN SMALL LETTER U WITH BREVE"]
+pub const XK_ubreve: Keysym = 0x02fd;
+#[doc = " U+015D LATIN SMALL LETTER S WITH CIRCUMFLEX"]
+pub const XK_scircumflex: Keysym = 0x02fe;
+#[doc = " U+0138 LATIN SMALL LETTER KRA"]
+pub const XK_kra: Keysym = 0x03a2;
+#[doc = " deprecated"]
+pub const XK_kappa: Keysym = 0x03a2;
+#[doc = " U+0156 LATIN CAPITAL LETTER R WITH CEDILLA"]
+pub const XK_Rcedilla: Keysym = 0x03a3;
+#[doc = " U+0128 LATIN CAPITAL LETTER I WITH TILDE"]
+pub const XK_Itilde: Keysym = 0x03a5;
+#[doc = " U+013B LATIN CAPITAL LETTER L WITH CEDILLA"]
+pub const XK_Lcedilla: Keysym = 0x03a6;
+#[doc = " U+0112 LATIN CAPITAL LETTER E WITH MACRON"]
+pub const XK_Emacron: Keysym = 0x03aa;
+#[doc = " U+0122 LATIN CAPITAL LETTER G WITH CEDILLA"]
+pub const XK_Gcedilla: Keysym = 0x03ab;
+#[doc = " U+0166 LATIN CAPITAL LETTER T WITH STROKE"]
+pub const XK_Tslash: Keysym = 0x03ac;
+#[doc = " U+0157 LATIN SMALL LETTER R WITH CEDILLA"]
+pub const XK_rcedilla: Keysym = 0x03b3;
+#[doc = " U+0129 LATIN SMALL LETTER I WITH TILDE"]
+pub const XK_itilde: Keysym = 0x03b5;
+#[doc = " U+013C LATIN SMALL LETTER L WITH CEDILLA"]
+pub const XK_lcedilla: Keysym = 0x03b6;
+#[doc = " U+0113 LATIN SMALL LETTER E WITH MACRON"]
+pub const XK_emacron: Keysym = 0x03ba;
+#[doc = " U+0123 LATIN SMALL LETTER G WITH CEDILLA"]
+pub const XK_gcedilla: Keysym = 0x03bb;
+#[doc = " U+0167 LATIN SMALL LETTER T WITH STROKE"]
+pub const XK_tslash: Keysym = 0x03bc;
+#[doc = " U+014A LATIN CAPITAL LETTER ENG"]
+pub const XK_ENG: Keysym = 0x03bd;
+#[doc = " U+014B LATIN SMALL LETTER ENG"]
+pub const XK_eng: Keysym = 0x03bf;
+#[doc = " U+0100 LATIN CAPITAL LETTER A WITH MACRON"]
+pub const XK_Amacron: Keysym = 0x03c0;
+#[doc = " U+012E LATIN CAPITAL LETTER I WITH OGONEK"]
+pub const XK_Iogonek: Keysym = 0x03c7;
+#[doc = " U+0116 LATIN CAPITAL LETTER E WITH DOT ABOVE"]
+pub const XK_Eabovedot: Keysym = 0x03cc;
+#[doc = " U+012A LATIN CAPITAL LETTER I WITH MACRON"]
+pub const XK_Imacron: Keysym = 0x03cf;
+#[doc = " U+0145 LATIN CAPITAL LETTER N WITH CEDILLA"]
+pub const XK_Ncedilla: Keysym = 0x03d1;
+#[doc = " U+014C LATIN CAPITAL LETTER O WITH MACRON"]
+pub const XK_Omacron: Keysym = 0x03d2;
+#[doc = " U+0136 LATIN CAPITAL LETTER K WITH CEDILLA"]
+pub const XK_Kcedilla: Keysym = 0x03d3;
+#[doc = " U+0172 LATIN CAPITAL LETTER U WITH OGONEK"]
+pub const XK_Uogonek: Keysym = 0x03d9;
+#[doc = " U+0168 LATIN CAPITAL LETTER U WITH TILDE"]
+pub const XK_Utilde: Keysym = 0x03dd;
+#[doc = " U+016A LATIN CAPITAL LETTER U WITH MACRON"]
+pub const XK_Umacron: Keysym = 0x03de;
+#[doc = " U+0101 LATIN SMALL LETTER A WITH MACRON"]
+pub const XK_amacron: Keysym = 0x03e0;
+#[doc = " U+012F LATIN SMALL LETTER I WITH OGONEK"]
+pub const XK_iogonek: Keysym = 0x03e7;
+#[doc = " U+0117 LATIN SMALL LETTER E WITH DOT ABOVE"]
+pub const XK_eabovedot: Keysym = 0x03ec;
+#[doc = " U+012B LATIN SMALL LETTER I WITH MACRON"]
+pub const XK_imacron: Keysym = 0x03ef;
+#[doc = " U+0146 LATIN SMALL LETTER N WITH CEDILLA"]
+pub const XK_ncedilla: Keysym = 0x03f1;
+#[doc = " U+014D LATIN SMALL LETTER O WITH MACRON"]
+pub const XK_omacron: Keysym = 0x03f2;
+#[doc = " U+0137 LATIN SMALL LETTER K WITH CEDILLA"]
+pub const XK_kcedilla: Keysym = 0x03f3;
+#[doc = " U+0173 LATIN SMALL LETTER U WITH OGONEK"]
+pub const XK_uogonek: Keysym = 0x03f9;
+#[doc = " U+0169 LATIN SMALL LETTER U WITH TILDE"]
+pub const XK_utilde: Keysym = 0x03fd;
+#[doc = " U+016B LATIN SMALL LETTER U WITH MACRON"]
+pub const XK_umacron: Keysym = 0x03fe;
+#[doc = " U+0174 LATIN CAPITAL LETTER W WITH CIRCUMFLEX"]
+pub const XK_Wcircumflex: Keysym = 0x1000174;
+#[doc = " U+0175 LATIN SMALL LETTER W WITH CIRCUMFLEX"]
+pub const XK_wcircumflex: Keysym = 0x1000175;
+#[doc = " U+0176 LATIN CAPITAL LETTER Y WITH CIRCUMFLEX"]
+pub const XK_Ycircumflex: Keysym = 0x1000176;
+#[doc = " U+0177 LATIN SMALL LETTER Y WITH CIRCUMFLEX"]
+pub const XK_ycircumflex: Keysym = 0x1000177;
+#[doc = " U+1E02 LATIN CAPITAL LETTER B WITH DOT ABOVE"]
+pub const XK_Babovedot: Keysym = 0x1001e02;
+#[doc = " U+1E03 LATIN SMALL LETTER B WITH DOT ABOVE"]
+pub const XK_babovedot: Keysym = 0x1001e03;
+#[doc = " U+1E0A LATIN CAPITAL LETTER D WITH DOT ABOVE"]
+pub const XK_Dabovedot: Keysym = 0x1001e0a;
+#[doc = " U+1E0B LATIN SMALL LETTER D WITH DOT ABOVE"]
+pub const XK_dabovedot: Keysym = 0x1001e0b;
+#[doc = " U+1E1E LATIN CAPITAL LETTER F WITH DOT ABOVE"]
+pub const XK_Fabovedot: Keysym = 0x1001e1e;
+#[doc = " U+1E1F LATIN SMALL LETTER F WITH DOT ABOVE"]
+pub const XK_fabovedot: Keysym = 0x1001e1f;
+#[doc = " U+1E40 LATIN CAPITAL LETTER M WITH DOT ABOVE"]
+pub const XK_Mabovedot: Keysym = 0x1001e40;
+#[doc = " U+1E41 LATIN SMALL LETTER M WITH DOT ABOVE"]
+pub const XK_mabovedot: Keysym = 0x1001e41;
+#[doc = " U+1E56 LATIN CAPITAL LETTER P WITH DOT ABOVE"]
+pub const XK_Pabovedot: Keysym = 0x1001e56;
+#[doc = " U+1E57 LATIN SMALL LETTER P WITH DOT ABOVE"]
+pub const XK_pabovedot: Keysym = 0x1001e57;
+#[doc = " U+1E60 LATIN CAPITAL LETTER S WITH DOT ABOVE"]
+pub const XK_Sabovedot: Keysym = 0x1001e60;
+#[doc = " U+1E61 LATIN SMALL LETTER S WITH DOT ABOVE"]
+pub const XK_sabovedot: Keysym = 0x1001e61;
+#[doc = " U+1E6A LATIN CAPITAL LETTER T WITH DOT ABOVE"]
+pub const XK_Tabovedot: Keysym = 0x1001e6a;
+#[doc = " U+1E6B LATIN SMALL LETTER T WITH DOT ABOVE"]
+pub const XK_tabovedot: Keysym = 0x1001e6b;
+#[doc = " U+1E80 LATIN CAPITAL LETTER W WITH GRAVE"]
+pub const XK_Wgrave: Keysym = 0x1001e80;
+#[doc = " U+1E81 LATIN SMALL LETTER W WITH GRAVE"]
+pub const XK_wgrave: Keysym = 0x1001e81;
+#[doc = " U+1E82 LATIN CAPITAL LETTER W WITH ACUTE"]
+pub const XK_Wacute: Keysym = 0x1001e82;
+#[doc = " U+1E83 LATIN SMALL LETTER W WITH ACUTE"]
+pub const XK_wacute: Keysym = 0x1001e83;
+#[doc = " U+1E84 LATIN CAPITAL LETTER W WITH DIAERESIS"]
+pub const XK_Wdiaeresis: Keysym = 0x1001e84;
+#[doc = " U+1E85 LATIN SMALL LETTER W WITH DIAERESIS"]
+pub const XK_wdiaeresis: Keysym = 0x1001e85;
+#[doc = " U+1EF2 LATIN CAPITAL LETTER Y WITH GRAVE"]
+pub const XK_Ygrave: Keysym = 0x1001ef2;
+#[doc = " U+1EF3 LATIN SMALL LETTER Y WITH GRAVE"]
+pub const XK_ygrave: Keysym = 0x1001ef3;
+#[doc = " U+0152 LATIN CAPITAL LIGATURE OE"]
+pub const XK_OE: Keysym = 0x13bc;
+#[doc = " U+0153 LATIN SMALL LIGATURE OE"]
+pub const XK_oe: Keysym = 0x13bd;
+#[doc = " U+0178 LATIN CAPITAL LETTER Y WITH DIAERESIS"]
+pub const XK_Ydiaeresis: Keysym = 0x13be;
+#[doc = " U+203E OVERLINE"]
+pub const XK_overline: Keysym = 0x047e;
+#[doc = " U+3002 IDEOGRAPHIC FULL STOP"]
+pub const XK_kana_fullstop: Keysym = 0x04a1;
+#[doc = " U+300C LEFT CORNER BRACKET"]
+pub const XK_kana_openingbracket: Keysym = 0x04a2;
+#[doc = " U+300D RIGHT CORNER BRACKET"]
+pub const XK_kana_closingbracket: Keysym = 0x04a3;
+#[doc = " U+3001 IDEOGRAPHIC COMMA"]
+pub const XK_kana_comma: Keysym = 0x04a4;
+#[doc = " U+30FB KATAKANA MIDDLE DOT"]
+pub const XK_kana_conjunctive: Keysym = 0x04a5;
+#[doc = " deprecated"]
+pub const XK_kana_middledot: Keysym = 0x04a5;
+#[doc = " U+30F2 KATAKANA LETTER WO"]
+pub const XK_kana_WO: Keysym = 0x04a6;
+#[doc = " U+30A1 KATAKANA LETTER SMALL A"]
+pub const XK_kana_a: Keysym = 0x04a7;
+#[doc = " U+30A3 KATAKANA LETTER SMALL I"]
+pub const XK_kana_i: Keysym = 0x04a8;
+#[doc = " U+30A5 KATAKANA LETTER SMALL U"]
+pub const XK_kana_u: Keysym = 0x04a9;
+#[doc = " U+30A7 KATAKANA LETTER SMALL E"]
+pub const XK_kana_e: Keysym = 0x04aa;
+#[doc = " U+30A9 KATAKANA LETTER SMALL O"]
+pub const XK_kana_o: Keysym = 0x04ab;
+#[doc = " U+30E3 KATAKANA LETTER SMALL YA"]
+pub const XK_kana_ya: Keysym = 0x04ac;
+#[doc = " U+30E5 KATAKANA LETTER SMALL YU"]
+pub const XK_kana_yu: Keysym = 0x04ad;
+#[doc = " U+30E7 KATAKANA LETTER SMALL YO"]
+pub const XK_kana_yo: Keysym = 0x04ae;
+#[doc = " U+30C3 KATAKANA LETTER SMALL TU"]
+pub const XK_kana_tsu: Keysym = 0x04af;
+#[doc = " deprecated"]
+pub const XK_kana_tu: Keysym = 0x04af;
+#[doc = " U+30FC KATAKANA-HIRAGANA PROLONGED SOUND MARK"]
+pub const XK_prolongedsound: Keysym = 0x04b0;
+#[doc = " U+30A2 KATAKANA LETTER A"]
+pub const XK_kana_A: Keysym = 0x04b1;
+#[doc = " U+30A4 KATAKANA LETTER I"]
+pub const XK_kana_I: Keysym = 0x04b2;
+#[doc = " U+30A6 KATAKANA LETTER U"]
+pub const XK_kana_U: Keysym = 0x04b3;
+#[doc = " U+30A8 KATAKANA LETTER E"]
+pub const XK_kana_E: Keysym = 0x04b4;
+#[doc = " U+30AA KATAKANA LETTER O"]
+pub const XK_kana_O: Keysym = 0x04b5;
+#[doc = " U+30AB KATAKANA LETTER KA"]
+pub const XK_kana_KA: Keysym = 0x04b6;
+#[doc = " U+30AD KATAKANA LETTER KI"]
+pub const XK_kana_KI: Keysym = 0x04b7;
+#[doc = " U+30AF KATAKANA LETTER KU"]
+pub const XK_kana_KU: Keysym = 0x04b8;
+#[doc = " U+30B1 KATAKANA LETTER KE"]
+pub const XK_kana_KE: Keysym = 0x04b9;
+#[doc = " U+30B3 KATAKANA LETTER KO"]
+pub const XK_kana_KO: Keysym = 0x04ba;
+#[doc = " U+30B5 KATAKANA LETTER SA"]
+pub const XK_kana_SA: Keysym = 0x04bb;
+#[doc = " U+30B7 KATAKANA LETTER SI"]
+pub const XK_kana_SHI: Keysym = 0x04bc;
+#[doc = " U+30B9 KATAKANA LETTER SU"]
+pub const XK_kana_SU: Keysym = 0x04bd;
+#[doc = " U+30BB KATAKANA LETTER SE"]
+pub const XK_kana_SE: Keysym = 0x04be;
+#[doc = " U+30BD KATAKANA LETTER SO"]
+pub const XK_kana_SO: Keysym = 0x04bf;
+#[doc = " U+30BF KATAKANA LETTER TA"]
+pub const XK_kana_TA: Keysym = 0x04c0;
+#[doc = " U+30C1 KATAKANA LETTER TI"]
+pub const XK_kana_CHI: Keysym = 0x04c1;
+#[doc = " deprecated"]
+pub const XK_kana_TI: Keysym = 0x04c1;
+#[doc = " U+30C4 KATAKANA LETTER TU"]
+pub const XK_kana_TSU: Keysym = 0x04c2;
+#[doc = " deprecated"]
+pub const XK_kana_TU: Keysym = 0x04c2;
+#[doc = " U+30C6 KATAKANA LETTER TE"]
+pub const XK_kana_TE: Keysym = 0x04c3;
+#[doc = " U+30C8 KATAKANA LETTER TO"]
+pub const XK_kana_TO: Keysym = 0x04c4;
+#[doc = " U+30CA KATAKANA LETTER NA"]
+pub const XK_kana_NA: Keysym = 0x04c5;
+#[doc = " U+30CB KATAKANA LETTER NI"]
+pub const XK_kana_NI: Keysym = 0x04c6;
+#[doc = " U+30CC KATAKANA LETTER NU"]
+pub const XK_kana_NU: Keysym = 0x04c7;
+#[doc = " U+30CD KATAKANA LETTER NE"]
+pub const XK_kana_NE: Keysym = 0x04c8;
+#[doc = " U+30CE KATAKANA LETTER NO"]
+pub const XK_kana_NO: Keysym = 0x04c9;
+#[doc = " U+30CF KATAKANA LETTER HA"]
+pub const XK_kana_HA: Keysym = 0x04ca;
+#[doc = " U+30D2 KATAKANA LETTER HI"]
+pub const XK_kana_HI: Keysym = 0x04cb;
+#[doc = " U+30D5 KATAKANA LETTER HU"]
+pub const XK_kana_FU: Keysym = 0x04cc;
+#[doc = " deprecated"]
+pub const XK_kana_HU: Keysym = 0x04cc;
+#[doc = " U+30D8 KATAKANA LETTER HE"]
+pub const XK_kana_HE: Keysym = 0x04cd;
+#[doc = " U+30DB KATAKANA LETTER HO"]
+pub const XK_kana_HO: Keysym = 0x04ce;
+#[doc = " U+30DE KATAKANA LETTER MA"]
+pub const XK_kana_MA: Keysym = 0x04cf;
+#[doc = " U+30DF KATAKANA LETTER MI"]
+pub const XK_kana_MI: Keysym = 0x04d0;
+#[doc = " U+30E0 KATAKANA LETTER MU"]
+pub const XK_kana_MU: Keysym = 0x04d1;
+#[doc = " U+30E1 KATAKANA LETTER ME"]
+pub const XK_kana_ME: Keysym = 0x04d2;
+#[doc = " U+30E2 KATAKANA LETTER MO"]
+pub const XK_kana_MO: Keysym = 0x04d3;
+#[doc = " U+30E4 KATAKANA LETTER YA"]
+pub const XK_kana_YA: Keysym = 0x04d4;
+#[doc = " U+30E6 KATAKANA LETTER YU"]
+pub const XK_kana_YU: Keysym = 0x04d5;
+#[doc = " U+30E8 KATAKANA LETTER YO"]
+pub const XK_kana_YO: Keysym = 0x04d6;
+#[doc = " U+30E9 KATAKANA LETTER RA"]
+pub const XK_kana_RA: Keysym = 0x04d7;
+#[doc = " U+30EA KATAKANA LETTER RI"]
+pub const XK_kana_RI: Keysym = 0x04d8;
+#[doc = " U+30EB KATAKANA LETTER RU"]
+pub const XK_kana_RU: Keysym = 0x04d9;
+#[doc = " U+30EC KATAKANA LETTER RE"]
+pub const XK_kana_RE: Keysym = 0x04da;
+#[doc = " U+30ED KATAKANA LETTER RO"]
+pub const XK_kana_RO: Keysym = 0x04db;
+#[doc = " U+30EF KATAKANA LETTER WA"]
+pub const XK_kana_WA: Keysym = 0x04dc;
+#[doc = " U+30F3 KATAKANA LETTER N"]
+pub const XK_kana_N: Keysym = 0x04dd;
+#[doc = " U+309B KATAKANA-HIRAGANA VOICED SOUND MARK"]
+pub const XK_voicedsound: Keysym = 0x04de;
+#[doc = " U+309C KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK"]
+pub const XK_semivoicedsound: Keysym = 0x04df;
+#[doc = " Alias for `XK_Mode_switch`"]
+pub const XK_kana_switch: Keysym = 0xff7e;
+#[doc = " U+06F0 EXTENDED ARABIC-INDIC DIGIT ZERO"]
+pub const XK_Farsi_0: Keysym = 0x10006f0;
+#[doc = " U+06F1 EXTENDED ARABIC-INDIC DIGIT ONE"]
+pub const XK_Farsi_1: Keysym = 0x10006f1;
+#[doc = " U+06F2 EXTENDED ARABIC-INDIC DIGIT TWO"]
+pub const XK_Farsi_2: Keysym = 0x10006f2;
+#[doc = " U+06F3 EXTENDED ARABIC-INDIC DIGIT THREE"]
+pub const XK_Farsi_3: Keysym = 0x10006f3;
+#[doc = " U+06F4 EXTENDED ARABIC-INDIC DIGIT FOUR"]
+pub const XK_Farsi_4: Keysym = 0x10006f4;
+#[doc = " U+06F5 EXTENDED ARABIC-INDIC DIGIT FIVE"]
+pub const XK_Farsi_5: Keysym = 0x10006f5;
+#[doc = " U+06F6 EXTENDED ARABIC-INDIC DIGIT SIX"]
+pub const XK_Farsi_6: Keysym = 0x10006f6;
+#[doc = " U+06F7 EXTENDED ARABIC-INDIC DIGIT SEVEN"]
+pub const XK_Farsi_7: Keysym = 0x10006f7;
+#[doc = " U+06F8 EXTENDED ARABIC-INDIC DIGIT EIGHT"]
+pub const XK_Farsi_8: Keysym = 0x10006f8;
+#[doc = " U+06F9 EXTENDED ARABIC-INDIC DIGIT NINE"]
+pub const XK_Farsi_9: Keysym = 0x10006f9;
+#[doc = " U+066A ARABIC PERCENT SIGN"]
+pub const XK_Arabic_percent: Keysym = 0x100066a;
+#[doc = " U+0670 ARABIC LETTER SUPERSCRIPT ALEF"]
+pub const XK_Arabic_superscript_alef: Keysym = 0x1000670;
+#[doc = " U+0679 ARABIC LETTER TTEH"]
+pub const XK_Arabic_tteh: Keysym = 0x1000679;
+#[doc = " U+067E ARABIC LETTER PEH"]
+pub const XK_Arabic_peh: Keysym = 0x100067e;
+#[doc = " U+0686 ARABIC LETTER TCHEH"]
+pub const XK_Arabic_tcheh: Keysym = 0x1000686;
+#[doc = " U+0688 ARABIC LETTER DDAL"]
+pub const XK_Arabic_ddal: Keysym = 0x1000688;
+#[doc = " U+0691 ARABIC LETTER RREH"]
+pub const XK_Arabic_rreh: Keysym = 0x1000691;
+#[doc = " U+060C ARABIC COMMA"]
+pub const XK_Arabic_comma: Keysym = 0x05ac;
+#[doc = " U+06D4 ARABIC FULL STOP"]
+pub const XK_Arabic_fullstop: Keysym = 0x10006d4;
+#[doc = " U+0660 ARABIC-INDIC DIGIT ZERO"]
+pub const XK_Arabic_0: Keysym = 0x1000660;
+#[doc = " U+0661 ARABIC-INDIC DIGIT ONE"]
+pub const XK_Arabic_1: Keysym = 0x1000661;
+#[doc = " U+0662 ARABIC-INDIC DIGIT TWO"]
+pub const XK_Arabic_2: Keysym = 0x1000662;
+#[doc = " U+0663 ARABIC-INDIC DIGIT THREE"]
+pub const XK_Arabic_3: Keysym = 0x1000663;
+#[doc = " U+0664 ARABIC-INDIC DIGIT FOUR"]
+pub const XK_Arabic_4: Keysym = 0x1000664;
+#[doc = " U+0665 ARABIC-INDIC DIGIT FIVE"]
+pub const XK_Arabic_5: Keysym = 0x1000665;
+#[doc = " U+0666 ARABIC-INDIC DIGIT SIX"]
+pub const XK_Arabic_6: Keysym = 0x1000666;
+#[doc = " U+0667 ARABIC-INDIC DIGIT SEVEN"]
+pub const XK_Arabic_7: Keysym = 0x1000667;
+#[doc = " U+0668 ARABIC-INDIC DIGIT EIGHT"]
+pub const XK_Arabic_8: Keysym = 0x1000668;
+#[doc = " U+0669 ARABIC-INDIC DIGIT NINE"]
+pub const XK_Arabic_9: Keysym = 0x1000669;
+#[doc = " U+061B ARABIC SEMICOLON"]
+pub const XK_Arabic_semicolon: Keysym = 0x05bb;
+#[doc = " U+061F ARABIC QUESTION MARK"]
+pub const XK_Arabic_question_mark: Keysym = 0x05bf;
+#[doc = " U+0621 ARABIC LETTER HAMZA"]
+pub const XK_Arabic_hamza: Keysym = 0x05c1;
+#[doc = " U+0622 ARABIC LETTER ALEF WITH MADDA ABOVE"]
+pub const XK_Arabic_maddaonalef: Keysym = 0x05c2;
+#[doc = " U+0623 ARABIC LETTER ALEF WITH HAMZA ABOVE"]
+pub const XK_Arabic_hamzaonalef: Keysym = 0x05c3;
+#[doc = " U+0624 ARABIC LETTER WAW WITH HAMZA ABOVE"]
+pub const XK_Arabic_hamzaonwaw: Keysym = 0x05c4;
+#[doc = " U+0625 ARABIC LETTER ALEF WITH HAMZA BELOW"]
+pub const XK_Arabic_hamzaunderalef: Keysym = 0x05c5;
+#[doc = " U+0626 ARABIC LETTER YEH WITH HAMZA ABOVE"]
+pub const XK_Arabic_hamzaonyeh: Keysym = 0x05c6;
+#[doc = " U+0627 ARABIC LETTER ALEF"]
+pub const XK_Arabic_alef: Keysym = 0x05c7;
+#[doc = " U+0628 ARABIC LETTER BEH"]
+pub const XK_Arabic_beh: Keysym = 0x05c8;
+#[doc = " U+0629 ARABIC LETTER TEH MARBUTA"]
+pub const XK_Arabic_tehmarbuta: Keysym = 0x05c9;
+#[doc = " U+062A ARABIC LETTER TEH"]
+pub const XK_Arabic_teh: Keysym = 0x05ca;
+#[doc = " U+062B ARABIC LETTER THEH"]
+pub const XK_Arabic_theh: Keysym = 0x05cb;
+#[doc = " U+062C ARABIC LETTER JEEM"]
+pub const XK_Arabic_jeem: Keysym = 0x05cc;
+#[doc = " U+062D ARABIC LETTER HAH"]
+pub const XK_Arabic_hah: Keysym = 0x05cd;
+#[doc = " U+062E ARABIC LETTER KHAH"]
+pub const XK_Arabic_khah: Keysym = 0x05ce;
+#[doc = " U+062F ARABIC LETTER DAL"]
+pub const XK_Arabic_dal: Keysym = 0x05cf;
+#[doc = " U+0630 ARABIC LETTER THAL"]
+pub const XK_Arabic_thal: Keysym = 0x05d0;
+#[doc = " U+0631 ARABIC LETTER REH"]
+pub const XK_Arabic_ra: Keysym = 0x05d1;
+#[doc = " U+0632 ARABIC LETTER ZAIN"]
+pub const XK_Arabic_zain: Keysym = 0x05d2;
+#[doc = " U+0633 ARABIC LETTER SEEN"]
+pub const XK_Arabic_seen: Keysym = 0x05d3;
+#[doc = " U+0634 ARABIC LETTER SHEEN"]
+pub const XK_Arabic_sheen: Keysym = 0x05d4;
+#[doc = " U+0635 ARABIC LETTER SAD"]
+pub const XK_Arabic_sad: Keysym = 0x05d5;
+#[doc = " U+0636 ARABIC LETTER DAD"]
+pub const XK_Arabic_dad: Keysym = 0x05d6;
+#[doc = " U+0637 ARABIC LETTER TAH"]
+pub const XK_Arabic_tah: Keysym = 0x05d7;
+#[doc = " U+0638 ARABIC LETTER ZAH"]
+pub const XK_Arabic_zah: Keysym = 0x05d8;
+#[doc = " U+0639 ARABIC LETTER AIN"]
+pub const XK_Arabic_ain: Keysym = 0x05d9;
+#[doc = " U+063A ARABIC LETTER GHAIN"]
+pub const XK_Arabic_ghain: Keysym = 0x05da;
+#[doc = " U+0640 ARABIC TATWEEL"]
+pub const XK_Arabic_tatweel: Keysym = 0x05e0;
+#[doc = " U+0641 ARABIC LETTER FEH"]
+pub const XK_Arabic_feh: Keysym = 0x05e1;
+#[doc = " U+0642 ARABIC LETTER QAF"]
+pub const XK_Arabic_qaf: Keysym = 0x05e2;
+#[doc = " U+0643 ARABIC LETTER KAF"]
+pub const XK_Arabic_kaf: Keysym = 0x05e3;
+#[doc = " U+0644 ARABIC LETTER LAM"]
+pub const XK_Arabic_lam: Keysym = 0x05e4;
+#[doc = " U+0645 ARABIC LETTER MEEM"]
+pub const XK_Arabic_meem: Keysym = 0x05e5;
+#[doc = " U+0646 ARABIC LETTER NOON"]
+pub const XK_Arabic_noon: Keysym = 0x05e6;
+#[doc = " U+0647 ARABIC LETTER HEH"]
+pub const XK_Arabic_ha: Keysym = 0x05e7;
+#[doc = " deprecated"]
+pub const XK_Arabic_heh: Keysym = 0x05e7;
+#[doc = " U+0648 ARABIC LETTER WAW"]
+pub const XK_Arabic_waw: Keysym = 0x05e8;
+#[doc = " U+0649 ARABIC LETTER ALEF MAKSURA"]
+pub const XK_Arabic_alefmaksura: Keysym = 0x05e9;
+#[doc = " U+064A ARABIC LETTER YEH"]
+pub const XK_Arabic_yeh: Keysym = 0x05ea;
+#[doc = " U+064B ARABIC FATHATAN"]
+pub const XK_Arabic_fathatan: Keysym = 0x05eb;
+#[doc = " U+064C ARABIC DAMMATAN"]
+pub const XK_Arabic_dammatan: Keysym = 0x05ec;
+#[doc = " U+064D ARABIC KASRATAN"]
+pub const XK_Arabic_kasratan: Keysym = 0x05ed;
+#[doc = " U+064E ARABIC FATHA"]
+pub const XK_Arabic_fatha: Keysym = 0x05ee;
+#[doc = " U+064F ARABIC DAMMA"]
+pub const XK_Arabic_damma: Keysym = 0x05ef;
+#[doc = " U+0650 ARABIC KASRA"]
+pub const XK_Arabic_kasra: Keysym = 0x05f0;
+#[doc = " U+0651 ARABIC SHADDA"]
+pub const XK_Arabic_shadda: Keysym = 0x05f1;
+#[doc = " U+0652 ARABIC SUKUN"]
+pub const XK_Arabic_sukun: Keysym = 0x05f2;
+#[doc = " U+0653 ARABIC MADDAH ABOVE"]
+pub const XK_Arabic_madda_above: Keysym = 0x1000653;
+#[doc = " U+0654 ARABIC HAMZA ABOVE"]
+pub const XK_Arabic_hamza_above: Keysym = 0x1000654;
+#[doc = " U+0655 ARABIC HAMZA BELOW"]
+pub const XK_Arabic_hamza_below: Keysym = 0x1000655;
+#[doc = " U+0698 ARABIC LETTER JEH"]
+pub const XK_Arabic_jeh: Keysym = 0x1000698;
+#[doc = " U+06A4 ARABIC LETTER VEH"]
+pub const XK_Arabic_veh: Keysym = 0x10006a4;
+#[doc = " U+06A9 ARABIC LETTER KEHEH"]
+pub const XK_Arabic_keheh: Keysym = 0x10006a9;
+#[doc = " U+06AF ARABIC LETTER GAF"]
+pub const XK_Arabic_gaf: Keysym = 0x10006af;
+#[doc = " U+06BA ARABIC LETTER NOON GHUNNA"]
+pub const XK_Arabic_noon_ghunna: Keysym = 0x10006ba;
+#[doc = " U+06BE ARABIC LETTER HEH DOACHASHMEE"]
+pub const XK_Arabic_heh_doachashmee: Keysym = 0x10006be;
+#[doc = " U+06CC ARABIC LETTER FARSI YEH"]
+pub const XK_Farsi_yeh: Keysym = 0x10006cc;
+#[doc = " U+06CC ARABIC LETTER FARSI YEH"]
+pub const XK_Arabic_farsi_yeh: Keysym = 0x10006cc;
+#[doc = " U+06D2 ARABIC LETTER YEH BARREE"]
+pub const XK_Arabic_yeh_baree: Keysym = 0x10006d2;
+#[doc = " U+06C1 ARABIC LETTER HEH GOAL"]
+pub const XK_Arabic_heh_goal: Keysym = 0x10006c1;
+#[doc = " Alias for `XK_Mode_switch`"]
+pub const XK_Arabic_switch: Keysym = 0xff7e;
+#[doc = " U+0492 CYRILLIC CAPITAL LETTER GHE WITH STROKE"]
+pub const XK_Cyrillic_GHE_bar: Keysym = 0x1000492;
+#[doc = " U+0493 CYRILLIC SMALL LETTER GHE WITH STROKE"]
+pub const XK_Cyrillic_ghe_bar: Keysym = 0x1000493;
+#[doc = " U+0496 CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER"]
+pub const XK_Cyrillic_ZHE_descender: Keysym = 0x1000496;
+#[doc = " U+0497 CYRILLIC SMALL LETTER ZHE WITH DESCENDER"]
+pub const XK_Cyrillic_zhe_descender: Keysym = 0x1000497;
+#[doc = " U+049A CYRILLIC CAPITAL LETTER KA WITH DESCENDER"]
+pub const XK_Cyrillic_KA_descender: Keysym = 0x100049a;
+#[doc = " U+049B CYRILLIC SMALL LETTER KA WITH DESCENDER"]
+pub const XK_Cyrillic_ka_descender: Keysym = 0x100049b;
+#[doc = " U+049C CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE"]
+pub const XK_Cyrillic_KA_vertstroke: Keysym = 0x100049c;
+#[doc = " U+049D CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE"]
+pub const XK_Cyrillic_ka_vertstroke: Keysym = 0x100049d;
+#[doc = " U+04A2 CYRILLIC CAPITAL LETTER EN WITH DESCENDER"]
+pub const XK_Cyrillic_EN_descender: Keysym = 0x10004a2;
+#[doc = " U+04A3 CYRILLIC SMALL LETTER EN WITH DESCENDER"]
+pub const XK_Cyrillic_en_descender: Keysym = 0x10004a3;
+#[doc = " U+04AE CYRILLIC CAPITAL LETTER STRAIGHT U"]
+pub const XK_Cyrillic_U_straight: Keysym = 0x10004ae;
+#[doc = " U+04AF CYRILLIC SMALL LETTER STRAIGHT U"]
+pub const XK_Cyrillic_u_straight: Keysym = 0x10004af;
+#[doc = " U+04B0 CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE"]
+pub const XK_Cyrillic_U_straight_bar: Keysym = 0x10004b0;
+#[doc = " U+04B1 CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE"]
+pub const XK_Cyrillic_u_straight_bar: Keysym = 0x10004b1;
+#[doc = " U+04B2 CYRILLIC CAPITAL LETTER HA WITH DESCENDER"]
+pub const XK_Cyrillic_HA_descender: Keysym = 0x10004b2;
+#[doc = " U+04B3 CYRILLIC SMALL LETTER HA WITH DESCENDER"]
+pub const XK_Cyrillic_ha_descender: Keysym = 0x10004b3;
+#[doc = " U+04B6 CYRILLIC CAPITAL LETTER CHE WITH DESCENDER"]
+pub const XK_Cyrillic_CHE_descender: Keysym = 0x10004b6;
+#[doc = " U+04B7 CYRILLIC SMALL LETTER CHE WITH DESCENDER"]
+pub const XK_Cyrillic_che_descender: Keysym = 0x10004b7;
+#[doc = " U+04B8 CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE"]
+pub const XK_Cyrillic_CHE_vertstroke: Keysym = 0x10004b8;
+#[doc = " U+04B9 CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE"]
+pub const XK_Cyrillic_che_vertstroke: Keysym = 0x10004b9;
+#[doc = " U+04BA CYRILLIC CAPITAL LETTER SHHA"]
+pub const XK_Cyrillic_SHHA: Keysym = 0x10004ba;
+#[doc = " U+04BB CYRILLIC SMALL LETTER SHHA"]
+pub const XK_Cyrillic_shha: Keysym = 0x10004bb;
+#[doc = " U+04D8 CYRILLIC CAPITAL LETTER SCHWA"]
+pub const XK_Cyrillic_SCHWA: Keysym = 0x10004d8;
+#[doc = " U+04D9 CYRILLIC SMALL LETTER SCHWA"]
+pub const XK_Cyrillic_schwa: Keysym = 0x10004d9;
+#[doc = " U+04E2 CYRILLIC CAPITAL LETTER I WITH MACRON"]
+pub const XK_Cyrillic_I_macron: Keysym = 0x10004e2;
+#[doc = " U+04E3 CYRILLIC SMALL LETTER I WITH MACRON"]
+pub const XK_Cyrillic_i_macron: Keysym = 0x10004e3;
+#[doc = " U+04E8 CYRILLIC CAPITAL LETTER BARRED O"]
+pub const XK_Cyrillic_O_bar: Keysym = 0x10004e8;
+#[doc = " U+04E9 CYRILLIC SMALL LETTER BARRED O"]
+pub const XK_Cyrillic_o_bar: Keysym = 0x10004e9;
+#[doc = " U+04EE CYRILLIC CAPITAL LETTER U WITH MACRON"]
+pub const XK_Cyrillic_U_macron: Keysym = 0x10004ee;
+#[doc = " U+04EF CYRILLIC SMALL LETTER U WITH MACRON"]
+pub const XK_Cyrillic_u_macron: Keysym = 0x10004ef;
+#[doc = " U+0452 CYRILLIC SMALL LETTER DJE"]
+pub const XK_Serbian_dje: Keysym = 0x06a1;
+#[doc = " U+0453 CYRILLIC SMALL LETTER GJE"]
+pub const XK_Macedonia_gje: Keysym = 0x06a2;
+#[doc = " U+0451 CYRILLIC SMALL LETTER IO"]
+pub const XK_Cyrillic_io: Keysym = 0x06a3;
+#[doc = " U+0454 CYRILLIC SMALL LETTER UKRAINIAN IE"]
+pub const XK_Ukrainian_ie: Keysym = 0x06a4;
+#[doc = " deprecated"]
+pub const XK_Ukranian_je: Keysym = 0x06a4;
+#[doc = " U+0455 CYRILLIC SMALL LETTER DZE"]
+pub const XK_Macedonia_dse: Keysym = 0x06a5;
+#[doc = " U+0456 CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I"]
+pub const XK_Ukrainian_i: Keysym = 0x06a6;
+#[doc = " deprecated"]
+pub const XK_Ukranian_i: Keysym = 0x06a6;
+#[doc = " U+0457 CYRILLIC SMALL LETTER YI"]
+pub const XK_Ukrainian_yi: Keysym = 0x06a7;
+#[doc = " deprecated"]
+pub const XK_Ukranian_yi: Keysym = 0x06a7;
+#[doc = " U+0458 CYRILLIC SMALL LETTER JE"]
+pub const XK_Cyrillic_je: Keysym = 0x06a8;
+#[doc = " deprecated"]
+pub const XK_Serbian_je: Keysym = 0x06a8;
+#[doc = " U+0459 CYRILLIC SMALL LETTER LJE"]
+pub const XK_Cyrillic_lje: Keysym = 0x06a9;
+#[doc = " deprecated"]
+pub const XK_Serbian_lje: Keysym = 0x06a9;
+#[doc = " U+045A CYRILLIC SMALL LETTER NJE"]
+pub const XK_Cyrillic_nje: Keysym = 0x06aa;
+#[doc = " deprecated"]
+pub const XK_Serbian_nje: Keysym = 0x06aa;
+#[doc = " U+045B CYRILLIC SMALL LETTER TSHE"]
+pub const XK_Serbian_tshe: Keysym = 0x06ab;
+#[doc = " U+045C CYRILLIC SMALL LETTER KJE"]
+pub const XK_Macedonia_kje: Keysym = 0x06ac;
+#[doc = " U+0491 CYRILLIC SMALL LETTER GHE WITH UPTURN"]
+pub const XK_Ukrainian_ghe_with_upturn: Keysym = 0x06ad;
+#[doc = " U+045E CYRILLIC SMALL LETTER SHORT U"]
+pub const XK_Byelorussian_shortu: Keysym = 0x06ae;
+#[doc = " U+045F CYRILLIC SMALL LETTER DZHE"]
+pub const XK_Cyrillic_dzhe: Keysym = 0x06af;
+#[doc = " deprecated"]
+pub const XK_Serbian_dze: Keysym = 0x06af;
+#[doc = " U+2116 NUMERO SIGN"]
+pub const XK_numerosign: Keysym = 0x06b0;
+#[doc = " U+0402 CYRILLIC CAPITAL LETTER DJE"]
+pub const XK_Serbian_DJE: Keysym = 0x06b1;
+#[doc = " U+0403 CYRILLIC CAPITAL LETTER GJE"]
+pub const XK_Macedonia_GJE: Keysym = 0x06b2;
+#[doc = " U+0401 CYRILLIC CAPITAL LETTER IO"]
+pub const XK_Cyrillic_IO: Keysym = 0x06b3;
+#[doc = " U+0404 CYRILLIC CAPITAL LETTER UKRAINIAN IE"]
+pub const XK_Ukrainian_IE: Keysym = 0x06b4;
+#[doc = " deprecated"]
+pub const XK_Ukranian_JE: Keysym = 0x06b4;
+#[doc = " U+0405 CYRILLIC CAPITAL LETTER DZE"]
+pub const XK_Macedonia_DSE: Keysym = 0x06b5;
+#[doc = " U+0406 CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I"]
+pub const XK_Ukrainian_I: Keysym = 0x06b6;
+#[doc = " deprecated"]
+pub const XK_Ukranian_I: Keysym = 0x06b6;
+#[doc = " U+0407 CYRILLIC CAPITAL LETTER YI"]
+pub const XK_Ukrainian_YI: Keysym = 0x06b7;
+#[doc = " deprecated"]
+pub const XK_Ukranian_YI: Keysym = 0x06b7;
+#[doc = " U+0408 CYRILLIC CAPITAL LETTER JE"]
+pub const XK_Cyrillic_JE: Keysym = 0x06b8;
+#[doc = " deprecated"]
+pub const XK_Serbian_JE: Keysym = 0x06b8;
+#[doc = " U+0409 CYRILLIC CAPITAL LETTER LJE"]
+pub const XK_Cyrillic_LJE: Keysym = 0x06b9;
+#[doc = " deprecated"]
+pub const XK_Serbian_LJE: Keysym = 0x06b9;
+#[doc = " U+040A CYRILLIC CAPITAL LETTER NJE"]
+pub const XK_Cyrillic_NJE: Keysym = 0x06ba;
+#[doc = " deprecated"]
+pub const XK_Serbian_NJE: Keysym = 0x06ba;
+#[doc = " U+040B CYRILLIC CAPITAL LETTER TSHE"]
+pub const XK_Serbian_TSHE: Keysym = 0x06bb;
+#[doc = " U+040C CYRILLIC CAPITAL LETTER KJE"]
+pub const XK_Macedonia_KJE: Keysym = 0x06bc;
+#[doc = " U+0490 CYRILLIC CAPITAL LETTER GHE WITH UPTURN"]
+pub const XK_Ukrainian_GHE_WITH_UPTURN: Keysym = 0x06bd;
+#[doc = " U+040E CYRILLIC CAPITAL LETTER SHORT U"]
+pub const XK_Byelorussian_SHORTU: Keysym = 0x06be;
+#[doc = " U+040F CYRILLIC CAPITAL LETTER DZHE"]
+pub const XK_Cyrillic_DZHE: Keysym = 0x06bf;
+#[doc = " deprecated"]
+pub const XK_Serbian_DZE: Keysym = 0x06bf;
+#[doc = " U+044E CYRILLIC SMALL LETTER YU"]
+pub const XK_Cyrillic_yu: Keysym = 0x06c0;
+#[doc = " U+0430 CYRILLIC SMALL LETTER A"]
+pub const XK_Cyrillic_a: Keysym = 0x06c1;
+#[doc = " U+0431 CYRILLIC SMALL LETTER BE"]
+pub const XK_Cyrillic_be: Keysym = 0x06c2;
+#[doc = " U+0446 CYRILLIC SMALL LETTER TSE"]
+pub const XK_Cyrillic_tse: Keysym = 0x06c3;
+#[doc = " U+0434 CYRILLIC SMALL LETTER DE"]
+pub const XK_Cyrillic_de: Keysym = 0x06c4;
+#[doc = " U+0435 CYRILLIC SMALL LETTER IE"]
+pub const XK_Cyrillic_ie: Keysym = 0x06c5;
+#[doc = " U+0444 CYRILLIC SMALL LETTER EF"]
+pub const XK_Cyrillic_ef: Keysym = 0x06c6;
+#[doc = " U+0433 CYRILLIC SMALL LETTER GHE"]
+pub const XK_Cyrillic_ghe: Keysym = 0x06c7;
+#[doc = " U+0445 CYRILLIC SMALL LETTER HA"]
+pub const XK_Cyrillic_ha: Keysym = 0x06c8;
+#[doc = " U+0438 CYRILLIC SMALL LETTER I"]
+pub const XK_Cyrillic_i: Keysym = 0x06c9;
+#[doc = " U+0439 CYRILLIC SMALL LETTER SHORT I"]
+pub const XK_Cyrillic_shorti: Keysym = 0x06ca;
+#[doc = " U+043A CYRILLIC SMALL LETTER KA"]
+pub const XK_Cyrillic_ka: Keysym = 0x06cb;
+#[doc = " U+043B CYRILLIC SMALL LETTER EL"]
+pub const XK_Cyrillic_el: Keysym = 0x06cc;
+#[doc = " U+043C CYRILLIC SMALL LETTER EM"]
+pub const XK_Cyrillic_em: Keysym = 0x06cd;
+#[doc = " U+043D CYRILLIC SMALL LETTER EN"]
+pub const XK_Cyrillic_en: Keysym = 0x06ce;
+#[doc = " U+043E CYRILLIC SMALL LETTER O"]
+pub const XK_Cyrillic_o: Keysym = 0x06cf;
+#[doc = " U+043F CYRILLIC SMALL LETTER PE"]
+pub const XK_Cyrillic_pe: Keysym = 0x06d0;
+#[doc = " U+044F CYRILLIC SMALL LETTER YA"]
+pub const XK_Cyrillic_ya: Keysym = 0x06d1;
+#[doc = " U+0440 CYRILLIC SMALL LETTER ER"]
+pub const XK_Cyrillic_er: Keysym = 0x06d2;
+#[doc = " U+0441 CYRILLIC SMALL LETTER ES"]
+pub const XK_Cyrillic_es: Keysym = 0x06d3;
+#[doc = " U+0442 CYRILLIC SMALL LETTER TE"]
+pub const XK_Cyrillic_te: Keysym = 0x06d4;
+#[doc = " U+0443 CYRILLIC SMALL LETTER U"]
+pub const XK_Cyrillic_u: Keysym = 0x06d5;
+#[doc = " U+0436 CYRILLIC SMALL LETTER ZHE"]
+pub const XK_Cyrillic_zhe: Keysym = 0x06d6;
+#[doc = " U+0432 CYRILLIC SMALL LETTER VE"]
+pub const XK_Cyrillic_ve: Keysym = 0x06d7;
+#[doc = " U+044C CYRILLIC SMALL LETTER SOFT SIGN"]
+pub const XK_Cyrillic_softsign: Keysym = 0x06d8;
+#[doc = " U+044B CYRILLIC SMALL LETTER YERU"]
+pub const XK_Cyrillic_yeru: Keysym = 0x06d9;
+#[doc = " U+0437 CYRILLIC SMALL LETTER ZE"]
+pub const XK_Cyrillic_ze: Keysym = 0x06da;
+#[doc = " U+0448 CYRILLIC SMALL LETTER SHA"]
+pub const XK_Cyrillic_sha: Keysym = 0x06db;
+#[doc = " U+044D CYRILLIC SMALL LETTER E"]
+pub const XK_Cyrillic_e: Keysym = 0x06dc;
+#[doc = " U+0449 CYRILLIC SMALL LETTER SHCHA"]
+pub const XK_Cyrillic_shcha: Keysym = 0x06dd;
+#[doc = " U+0447 CYRILLIC SMALL LETTER CHE"]
+pub const XK_Cyrillic_che: Keysym = 0x06de;
+#[doc = " U+044A CYRILLIC SMALL LETTER HARD SIGN"]
+pub const XK_Cyrillic_hardsign: Keysym = 0x06df;
+#[doc = " U+042E CYRILLIC CAPITAL LETTER YU"]
+pub const XK_Cyrillic_YU: Keysym = 0x06e0;
+#[doc = " U+0410 CYRILLIC CAPITAL LETTER A"]
+pub const XK_Cyrillic_A: Keysym = 0x06e1;
+#[doc = " U+0411 CYRILLIC CAPITAL LETTER BE"]
+pub const XK_Cyrillic_BE: Keysym = 0x06e2;
+#[doc = " U+0426 CYRILLIC CAPITAL LETTER TSE"]
+pub const XK_Cyrillic_TSE: Keysym = 0x06e3;
+#[doc = " U+0414 CYRILLIC CAPITAL LETTER DE"]
+pub const XK_Cyrillic_DE: Keysym = 0x06e4;
+#[doc = " U+0415 CYRILLIC CAPITAL LETTER IE"]
+pub const XK_Cyrillic_IE: Keysym = 0x06e5;
+#[doc = " U+0424 CYRILLIC CAPITAL LETTER EF"]
+pub const XK_Cyrillic_EF: Keysym = 0x06e6;
+#[doc = " U+0413 CYRILLIC CAPITAL LETTER GHE"]
+pub const XK_Cyrillic_GHE: Keysym = 0x06e7;
+#[doc = " U+0425 CYRILLIC CAPITAL LETTER HA"]
+pub const XK_Cyrillic_HA: Keysym = 0x06e8;
+#[doc = " U+0418 CYRILLIC CAPITAL LETTER I"]
+pub const XK_Cyrillic_I: Keysym = 0x06e9;
+#[doc = " U+0419 CYRILLIC CAPITAL LETTER SHORT I"]
+pub const XK_Cyrillic_SHORTI: Keysym = 0x06ea;
+#[doc = " U+041A CYRILLIC CAPITAL LETTER KA"]
+pub const XK_Cyrillic_KA: Keysym = 0x06eb;
+#[doc = " U+041B CYRILLIC CAPITAL LETTER EL"]
+pub const XK_Cyrillic_EL: Keysym = 0x06ec;
+#[doc = " U+041C CYRILLIC CAPITAL LETTER EM"]
+pub const XK_Cyrillic_EM: Keysym = 0x06ed;
+#[doc = " U+041D CYRILLIC CAPITAL LETTER EN"]
+pub const XK_Cyrillic_EN: Keysym = 0x06ee;
+#[doc = " U+041E CYRILLIC CAPITAL LETTER O"]
+pub const XK_Cyrillic_O: Keysym = 0x06ef;
+#[doc = " U+041F CYRILLIC CAPITAL LETTER PE"]
+pub const XK_Cyrillic_PE: Keysym = 0x06f0;
+#[doc = " U+042F CYRILLIC CAPITAL LETTER YA"]
+pub const XK_Cyrillic_YA: Keysym = 0x06f1;
+#[doc = " U+0420 CYRILLIC CAPITAL LETTER ER"]
+pub const XK_Cyrillic_ER: Keysym = 0x06f2;
+#[doc = " U+0421 CYRILLIC CAPITAL LETTER ES"]
+pub const XK_Cyrillic_ES: Keysym = 0x06f3;
+#[doc = " U+0422 CYRILLIC CAPITAL LETTER TE"]
+pub const XK_Cyrillic_TE: Keysym = 0x06f4;
+#[doc = " U+0423 CYRILLIC CAPITAL LETTER U"]
+pub const XK_Cyrillic_U: Keysym = 0x06f5;
+#[doc = " U+0416 CYRILLIC CAPITAL LETTER ZHE"]
+pub const XK_Cyrillic_ZHE: Keysym = 0x06f6;
+#[doc = " U+0412 CYRILLIC CAPITAL LETTER VE"]
+pub const XK_Cyrillic_VE: Keysym = 0x06f7;
+#[doc = " U+042C CYRILLIC CAPITAL LETTER SOFT SIGN"]
+pub const XK_Cyrillic_SOFTSIGN: Keysym = 0x06f8;
+#[doc = " U+042B CYRILLIC CAPITAL LETTER YERU"]
+pub const XK_Cyrillic_YERU: Keysym = 0x06f9;
+#[doc = " U+0417 CYRILLIC CAPITAL LETTER ZE"]
+pub const XK_Cyrillic_ZE: Keysym = 0x06fa;
+#[doc = " U+0428 CYRILLIC CAPITAL LETTER SHA"]
+pub const XK_Cyrillic_SHA: Keysym = 0x06fb;
+#[doc = " U+042D CYRILLIC CAPITAL LETTER E"]
+pub const XK_Cyrillic_E: Keysym = 0x06fc;
+#[doc = " U+0429 CYRILLIC CAPITAL LETTER SHCHA"]
+pub const XK_Cyrillic_SHCHA: Keysym = 0x06fd;
+#[doc = " U+0427 CYRILLIC CAPITAL LETTER CHE"]
+pub const XK_Cyrillic_CHE: Keysym = 0x06fe;
+#[doc = " U+042A CYRILLIC CAPITAL LETTER HARD SIGN"]
+pub const XK_Cyrillic_HARDSIGN: Keysym = 0x06ff;
+#[doc = " U+0386 GREEK CAPITAL LETTER ALPHA WITH TONOS"]
+pub const XK_Greek_ALPHAaccent: Keysym = 0x07a1;
+#[doc = " U+0388 GREEK CAPITAL LETTER EPSILON WITH TONOS"]
+pub const XK_Greek_EPSILONaccent: Keysym = 0x07a2;
+#[doc = " U+0389 GREEK CAPITAL LETTER ETA WITH TONOS"]
+pub const XK_Greek_ETAaccent: Keysym = 0x07a3;
+#[doc = " U+038A GREEK CAPITAL LETTER IOTA WITH TONOS"]
+pub const XK_Greek_IOTAaccent: Keysym = 0x07a4;
+#[doc = " U+03AA GREEK CAPITAL LETTER IOTA WITH DIALYTIKA"]
+pub const XK_Greek_IOTAdieresis: Keysym = 0x07a5;
+#[doc = " old typo"]
+pub const XK_Greek_IOTAdiaeresis: Keysym = 0x07a5;
+#[doc = " U+038C GREEK CAPITAL LETTER OMICRON WITH TONOS"]
+pub const XK_Greek_OMICRONaccent: Keysym = 0x07a7;
+#[doc = " U+038E GREEK CAPITAL LETTER UPSILON WITH TONOS"]
+pub const XK_Greek_UPSILONaccent: Keysym = 0x07a8;
+#[doc = " U+03AB GREEK CAPITAL LETTER UPSILON WITH DIALYTIKA"]
+pub const XK_Greek_UPSILONdieresis: Keysym = 0x07a9;
+#[doc = " U+038F GREEK CAPITAL LETTER OMEGA WITH TONOS"]
+pub const XK_Greek_OMEGAaccent: Keysym = 0x07ab;
+#[doc = " U+0385 GREEK DIALYTIKA TONOS"]
+pub const XK_Greek_accentdieresis: Keysym = 0x07ae;
+#[doc = " U+2015 HORIZONTAL BAR"]
+pub const XK_Greek_horizbar: Keysym = 0x07af;
+#[doc = " U+03AC GREEK SMALL LETTER ALPHA WITH TONOS"]
+pub const XK_Greek_alphaaccent: Keysym = 0x07b1;
+#[doc = " U+03AD GREEK SMALL LETTER EPSILON WITH TONOS"]
+pub const XK_Greek_epsilonaccent: Keysym = 0x07b2;
+#[doc = " U+03AE GREEK SMALL LETTER ETA WITH TONOS"]
+pub const XK_Greek_etaaccent: Keysym = 0x07b3;
+#[doc = " U+03AF GREEK SMALL LETTER IOTA WITH TONOS"]
+pub const XK_Greek_iotaaccent: Keysym = 0x07b4;
+#[doc = " U+03CA GREEK SMALL LETTER IOTA WITH DIALYTIKA"]
+pub const XK_Greek_iotadieresis: Keysym = 0x07b5;
+#[doc = " U+0390 GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS"]
+pub const XK_Greek_iotaaccentdieresis: Keysym = 0x07b6;
+#[doc = " U+03CC GREEK SMALL LETTER OMICRON WITH TONOS"]
+pub const XK_Greek_omicronaccent: Keysym = 0x07b7;
+#[doc = " U+03CD GREEK SMALL LETTER UPSILON WITH TONOS"]
+pub const XK_Greek_upsilonaccent: Keysym = 0x07b8;
+#[doc = " U+03CB GREEK SMALL LETTER UPSILON WITH DIALYTIKA"]
+pub const XK_Greek_upsilondieresis: Keysym = 0x07b9;
+#[doc = " U+03B0 GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS"]
+pub const XK_Greek_upsilonaccentdieresis: Keysym = 0x07ba;
+#[doc = " U+03CE GREEK SMALL LETTER OMEGA WITH TONOS"]
+pub const XK_Greek_omegaaccent: Keysym = 0x07bb;
+#[doc = " U+0391 GREEK CAPITAL LETTER ALPHA"]
+pub const XK_Greek_ALPHA: Keysym = 0x07c1;
+#[doc = " U+0392 GREEK CAPITAL LETTER BETA"]
+pub const XK_Greek_BETA: Keysym = 0x07c2;
+#[doc = " U+0393 GREEK CAPITAL LETTER GAMMA"]
+pub const XK_Greek_GAMMA: Keysym = 0x07c3;
+#[doc = " U+0394 GREEK CAPITAL LETTER DELTA"]
+pub const XK_Greek_DELTA: Keysym = 0x07c4;
+#[doc = " U+0395 GREEK CAPITAL LETTER EPSILON"]
+pub const XK_Greek_EPSILON: Keysym = 0x07c5;
+#[doc = " U+0396 GREEK CAPITAL LETTER ZETA"]
+pub const XK_Greek_ZETA: Keysym = 0x07c6;
+#[doc = " U+0397 GREEK CAPITAL LETTER ETA"]
+pub const XK_Greek_ETA: Keysym = 0x07c7;
+#[doc = " U+0398 GREEK CAPITAL LETTER THETA"]
+pub const XK_Greek_THETA: Keysym = 0x07c8;
+#[doc = " U+0399 GREEK CAPITAL LETTER IOTA"]
+pub const XK_Greek_IOTA: Keysym = 0x07c9;
+#[doc = " U+039A GREEK CAPITAL LETTER KAPPA"]
+pub const XK_Greek_KAPPA: Keysym = 0x07ca;
+#[doc = " U+039B GREEK CAPITAL LETTER LAMDA"]
+pub const XK_Greek_LAMDA: Keysym = 0x07cb;
+#[doc = " U+039B GREEK CAPITAL LETTER LAMDA"]
+pub const XK_Greek_LAMBDA: Keysym = 0x07cb;
+#[doc = " U+039C GREEK CAPITAL LETTER MU"]
+pub const XK_Greek_MU: Keysym = 0x07cc;
+#[doc = " U+039D GREEK CAPITAL LETTER NU"]
+pub const XK_Greek_NU: Keysym = 0x07cd;
+#[doc = " U+039E GREEK CAPITAL LETTER XI"]
+pub const XK_Greek_XI: Keysym = 0x07ce;
+#[doc = " U+039F GREEK CAPITAL LETTER OMICRON"]
+pub const XK_Greek_OMICRON: Keysym = 0x07cf;
+#[doc = " U+03A0 GREEK CAPITAL LETTER PI"]
+pub const XK_Greek_PI: Keysym = 0x07d0;
+#[doc = " U+03A1 GREEK CAPITAL LETTER RHO"]
+pub const XK_Greek_RHO: Keysym = 0x07d1;
+#[doc = " U+03A3 GREEK CAPITAL LETTER SIGMA"]
+pub const XK_Greek_SIGMA: Keysym = 0x07d2;
+#[doc = " U+03A4 GREEK CAPITAL LETTER TAU"]
+pub const XK_Greek_TAU: Keysym = 0x07d4;
+#[doc = " U+03A5 GREEK CAPITAL LETTER UPSILON"]
+pub const XK_Greek_UPSILON: Keysym = 0x07d5;
+#[doc = " U+03A6 GREEK CAPITAL LETTER PHI"]
+pub const XK_Greek_PHI: Keysym = 0x07d6;
+#[doc = " U+03A7 GREEK CAPITAL LETTER CHI"]
+pub const XK_Greek_CHI: Keysym = 0x07d7;
+#[doc = " U+03A8 GREEK CAPITAL LETTER PSI"]
+pub const XK_Greek_PSI: Keysym = 0x07d8;
+#[doc = " U+03A9 GREEK CAPITAL LETTER OMEGA"]
+pub const XK_Greek_OMEGA: Keysym = 0x07d9;
+#[doc = " U+03B1 GREEK SMALL LETTER ALPHA"]
+pub const XK_Greek_alpha: Keysym = 0x07e1;
+#[doc = " U+03B2 GREEK SMALL LETTER BETA"]
+pub const XK_Greek_beta: Keysym = 0x07e2;
+#[doc = " U+03B3 GREEK SMALL LETTER GAMMA"]
+pub const XK_Greek_gamma: Keysym = 0x07e3;
+#[doc = " U+03B4 GREEK SMALL LETTER DELTA"]
+pub const XK_Greek_delta: Keysym = 0x07e4;
+#[doc = " U+03B5 GREEK SMALL LETTER EPSILON"]
+pub const XK_Greek_epsilon: Keysym = 0x07e5;
+#[doc = " U+03B6 GREEK SMALL LETTER ZETA"]
+pub const XK_Greek_zeta: Keysym = 0x07e6;
+#[doc = " U+03B7 GREEK SMALL LETTER ETA"]
+pub const XK_Greek_eta: Keysym = 0x07e7;
+#[doc = " U+03B8 GREEK SMALL LETTER THETA"]
+pub const XK_Greek_theta: Keysym = 0x07e8;
+#[doc = " U+03B9 GREEK SMALL LETTER IOTA"]
+pub const XK_Greek_iota: Keysym = 0x07e9;
+#[doc = " U+03BA GREEK SMALL LETTER KAPPA"]
+pub const XK_Greek_kappa: Keysym = 0x07ea;
+#[doc = " U+03BB GREEK SMALL LETTER LAMDA"]
+pub const XK_Greek_lamda: Keysym = 0x07eb;
+#[doc = " U+03BB GREEK SMALL LETTER LAMDA"]
+pub const XK_Greek_lambda: Keysym = 0x07eb;
+#[doc = " U+03BC GREEK SMALL LETTER MU"]
+pub const XK_Greek_mu: Keysym = 0x07ec;
+#[doc = " U+03BD GREEK SMALL LETTER NU"]
+pub const XK_Greek_nu: Keysym = 0x07ed;
+#[doc = " U+03BE GREEK SMALL LETTER XI"]
+pub const XK_Greek_xi: Keysym = 0x07ee;
+#[doc = " U+03BF GREEK SMALL LETTER OMICRON"]
+pub const XK_Greek_omicron: Keysym = 0x07ef;
+#[doc = " U+03C0 GREEK SMALL LETTER PI"]
+pub const XK_Greek_pi: Keysym = 0x07f0;
+#[doc = " U+03C1 GREEK SMALL LETTER RHO"]
+pub const XK_Greek_rho: Keysym = 0x07f1;
+#[doc = " U+03C3 GREEK SMALL LETTER SIGMA"]
+pub const XK_Greek_sigma: Keysym = 0x07f2;
+#[doc = " U+03C2 GREEK SMALL LETTER FINAL SIGMA"]
+pub const XK_Greek_finalsmallsigma: Keysym = 0x07f3;
+#[doc = " U+03C4 GREEK SMALL LETTER TAU"]
+pub const XK_Greek_tau: Keysym = 0x07f4;
+#[doc = " U+03C5 GREEK SMALL LETTER UPSILON"]
+pub const XK_Greek_upsilon: Keysym = 0x07f5;
+#[doc = " U+03C6 GREEK SMALL LETTER PHI"]
+pub const XK_Greek_phi: Keysym = 0x07f6;
+#[doc = " U+03C7 GREEK SMALL LETTER CHI"]
+pub const XK_Greek_chi: Keysym = 0x07f7;
+#[doc = " U+03C8 GREEK SMALL LETTER PSI"]
+pub const XK_Greek_psi: Keysym = 0x07f8;
+#[doc = " U+03C9 GREEK SMALL LETTER OMEGA"]
+pub const XK_Greek_omega: Keysym = 0x07f9;
+#[doc = " Alias for `XK_Mode_switch`"]
+pub const XK_Greek_switch: Keysym = 0xff7e;
+#[doc = " U+23B7 RADICAL SYMBOL BOTTOM"]
+pub const XK_leftradical: Keysym = 0x08a1;
+#[doc = " (U+250C BOX DRAWINGS LIGHT DOWN AND RIGHT)"]
+pub const XK_topleftradical: Keysym = 0x08a2;
+#[doc = " (U+2500 BOX DRAWINGS LIGHT HORIZONTAL)"]
+pub const XK_horizconnector: Keysym = 0x08a3;
+#[doc = " U+2320 TOP HALF INTEGRAL"]
+pub const XK_topintegral: Keysym = 0x08a4;
+#[doc = " U+2321 BOTTOM HALF INTEGRAL"]
+pub const XK_botintegral: Keysym = 0x08a5;
+#[doc = " (U+2502 BOX DRAWINGS LIGHT VERTICAL)"]
+pub const XK_vertconnector: Keysym = 0x08a6;
+#[doc = " U+23A1 LEFT SQUARE BRACKET UPPER CORNER"]
+pub const XK_topleftsqbracket: Keysym = 0x08a7;
+#[doc = " U+23A3 LEFT SQUARE BRACKET LOWER CORNER"]
+pub const XK_botleftsqbracket: Keysym = 0x08a8;
+#[doc = " U+23A4 RIGHT SQUARE BRACKET UPPER CORNER"]
+pub const XK_toprightsqbracket: Keysym = 0x08a9;
+#[doc = " U+23A6 RIGHT SQUARE BRACKET LOWER CORNER"]
+pub const XK_botrightsqbracket: Keysym = 0x08aa;
+#[doc = " U+239B LEFT PARENTHESIS UPPER HOOK"]
+pub const XK_topleftparens: Keysym = 0x08ab;
+#[doc = " U+239D LEFT PARENTHESIS LOWER HOOK"]
+pub const XK_botleftparens: Keysym = 0x08ac;
+#[doc = " U+239E RIGHT PARENTHESIS UPPER HOOK"]
+pub const XK_toprightparens: Keysym = 0x08ad;
+#[doc = " U+23A0 RIGHT PARENTHESIS LOWER HOOK"]
+pub const XK_botrightparens: Keysym = 0x08ae;
+#[doc = " U+23A8 LEFT CURLY BRACKET MIDDLE PIECE"]
+pub const XK_leftmiddlecurlybrace: Keysym = 0x08af;
+#[doc = " U+23AC RIGHT CURLY BRACKET MIDDLE PIECE"]
+pub const XK_rightmiddlecurlybrace: Keysym = 0x08b0;
+pub const XK_topleftsummation: Keysym = 0x08b1;
+pub const XK_botleftsummation: Keysym = 0x08b2;
+pub const XK_topvertsummationconnector: Keysym = 0x08b3;
+pub const XK_botvertsummationconnector: Keysym = 0x08b4;
+pub const XK_toprightsummation: Keysym = 0x08b5;
+pub const XK_botrightsummation: Keysym = 0x08b6;
+pub const XK_rightmiddlesummation: Keysym = 0x08b7;
+#[doc = " U+2264 LESS-THAN OR EQUAL TO"]
+pub const XK_lessthanequal: Keysym = 0x08bc;
+#[doc = " U+2260 NOT EQUAL TO"]
+pub const XK_notequal: Keysym = 0x08bd;
+#[doc = " U+2265 GREATER-THAN OR EQUAL TO"]
+pub const XK_greaterthanequal: Keysym = 0x08be;
+#[doc = " U+222B INTEGRAL"]
+pub const XK_integral: Keysym = 0x08bf;
+#[doc = " U+2234 THEREFORE"]
+pub const XK_therefore: Keysym = 0x08c0;
+#[doc = " U+221D PROPORTIONAL TO"]
+pub const XK_variation: Keysym = 0x08c1;
+#[doc = " U+221E INFINITY"]
+pub const XK_infinity: Keysym = 0x08c2;
+#[doc = " U+2207 NABLA"]
+pub const XK_nabla: Keysym = 0x08c5;
+#[doc = " U+223C TILDE OPERATOR"]
+pub const XK_approximate: Keysym = 0x08c8;
+#[doc = " U+2243 ASYMPTOTICALLY EQUAL TO"]
+pub const XK_similarequal: Keysym = 0x08c9;
+#[doc = " U+21D4 LEFT RIGHT DOUBLE ARROW"]
+pub const XK_ifonlyif: Keysym = 0x08cd;
+#[doc = " U+21D2 RIGHTWARDS DOUBLE ARROW"]
+pub const XK_implies: Keysym = 0x08ce;
+#[doc = " U+2261 IDENTICAL TO"]
+pub const XK_identical: Keysym = 0x08cf;
+#[doc = " U+221A SQUARE ROOT"]
+pub const XK_radical: Keysym = 0x08d6;
+#[doc = " U+2282 SUBSET OF"]
+pub const XK_includedin: Keysym = 0x08da;
+#[doc = " U+2283 SUPERSET OF"]
+pub const XK_includes: Keysym = 0x08db;
+#[doc = " U+2229 INTERSECTION"]
+pub const XK_intersection: Keysym = 0x08dc;
+#[doc = " U+222A UNION"]
+pub const XK_union: Keysym = 0x08dd;
+#[doc = " U+2227 LOGICAL AND"]
+pub const XK_logicaland: Keysym = 0x08de;
+#[doc = " U+2228 LOGICAL OR"]
+pub const XK_logicalor: Keysym = 0x08df;
+#[doc = " U+2202 PARTIAL DIFFERENTIAL"]
+pub const XK_partialderivative: Keysym = 0x08ef;
+#[doc = " U+0192 LATIN SMALL LETTER F WITH HOOK"]
+pub const XK_function: Keysym = 0x08f6;
+#[doc = " U+2190 LEFTWARDS ARROW"]
+pub const XK_leftarrow: Keysym = 0x08fb;
+#[doc = " U+2191 UPWARDS ARROW"]
+pub const XK_uparrow: Keysym = 0x08fc;
+#[doc = " U+2192 RIGHTWARDS ARROW"]
+pub const XK_rightarrow: Keysym = 0x08fd;
+#[doc = " U+2193 DOWNWARDS ARROW"]
+pub const XK_downarrow: Keysym = 0x08fe;
+pub const XK_blank: Keysym = 0x09df;
+#[doc = " U+25C6 BLACK DIAMOND"]
+pub const XK_soliddiamond: Keysym = 0x09e0;
+#[doc = " U+2592 MEDIUM SHADE"]
+pub const XK_checkerboard: Keysym = 0x09e1;
+#[doc = " U+2409 SYMBOL FOR HORIZONTAL TABULATION"]
+pub const XK_ht: Keysym = 0x09e2;
+#[doc = " U+240C SYMBOL FOR FORM FEED"]
+pub const XK_ff: Keysym = 0x09e3;
+#[doc = " U+240D SYMBOL FOR CARRIAGE RETURN"]
+pub const XK_cr: Keysym = 0x09e4;
+#[doc = " U+240A SYMBOL FOR LINE FEED"]
+pub const XK_lf: Keysym = 0x09e5;
+#[doc = " U+2424 SYMBOL FOR NEWLINE"]
+pub const XK_nl: Keysym = 0x09e8;
+#[doc = " U+240B SYMBOL FOR VERTICAL TABULATION"]
+pub const XK_vt: Keysym = 0x09e9;
+#[doc = " U+2518 BOX DRAWINGS LIGHT UP AND LEFT"]
+pub const XK_lowrightcorner: Keysym = 0x09ea;
+#[doc = " U+2510 BOX DRAWINGS LIGHT DOWN AND LEFT"]
+pub const XK_uprightcorner: Keysym = 0x09eb;
+#[doc = " U+250C BOX DRAWINGS LIGHT DOWN AND RIGHT"]
+pub const XK_upleftcorner: Keysym = 0x09ec;
+#[doc = " U+2514 BOX DRAWINGS LIGHT UP AND RIGHT"]
+pub const XK_lowleftcorner: Keysym = 0x09ed;
+#[doc = " U+253C BOX DRAWINGS LIGHT VERTICAL AND HORIZONTAL"]
+pub const XK_crossinglines: Keysym = 0x09ee;
+#[doc = " U+23BA HORIZONTAL SCAN LINE-1"]
+pub const XK_horizlinescan1: Keysym = 0x09ef;
+#[doc = " U+23BB HORIZONTAL SCAN LINE-3"]
+pub const XK_horizlinescan3: Keysym = 0x09f0;
+#[doc = " U+2500 BOX DRAWINGS LIGHT HORIZONTAL"]
+pub const XK_horizlinescan5: Keysym = 0x09f1;
+#[doc = " U+23BC HORIZONTAL SCAN LINE-7"]
+pub const XK_horizlinescan7: Keysym = 0x09f2;
+#[doc = " U+23BD HORIZONTAL SCAN LINE-9"]
+pub const XK_horizlinescan9: Keysym = 0x09f3;
+#[doc = " U+251C BOX DRAWINGS LIGHT VERTICAL AND RIGHT"]
+pub const XK_leftt: Keysym = 0x09f4;
+#[doc = " U+2524 BOX DRAWINGS LIGHT VERTICAL AND LEFT"]
+pub const XK_rightt: Keysym = 0x09f5;
+#[doc = " U+2534 BOX DRAWINGS LIGHT UP AND HORIZONTAL"]
+pub const XK_bott: Keysym = 0x09f6;
+#[doc = " U+252C BOX DRAWINGS LIGHT DOWN AND HORIZONTAL"]
+pub const XK_topt: Keysym = 0x09f7;
+#[doc = " U+2502 BOX DRAWINGS LIGHT VERTICAL"]
+pub const XK_vertbar: Keysym = 0x09f8;
+#[doc = " U+2003 EM SPACE"]
+pub const XK_emspace: Keysym = 0x0aa1;
+#[doc = " U+2002 EN SPACE"]
+pub const XK_enspace: Keysym = 0x0aa2;
+#[doc = " U+2004 THREE-PER-EM SPACE"]
+pub const XK_em3space: Keysym = 0x0aa3;
+#[doc = " U+2005 FOUR-PER-EM SPACE"]
+pub const XK_em4space: Keysym = 0x0aa4;
+#[doc = " U+2007 FIGURE SPACE"]
+pub const XK_digitspace: Keysym = 0x0aa5;
+#[doc = " U+2008 PUNCTUATION SPACE"]
+pub const XK_punctspace: Keysym = 0x0aa6;
+#[doc = " U+2009 THIN SPACE"]
+pub const XK_thinspace: Keysym = 0x0aa7;
+#[doc = " U+200A HAIR SPACE"]
+pub const XK_hairspace: Keysym = 0x0aa8;
+#[doc = " U+2014 EM DASH"]
+pub const XK_emdash: Keysym = 0x0aa9;
+#[doc = " U+2013 EN DASH"]
+pub const XK_endash: Keysym = 0x0aaa;
+#[doc = " (U+2423 OPEN BOX)"]
+pub const XK_signifblank: Keysym = 0x0aac;
+#[doc = " U+2026 HORIZONTAL ELLIPSIS"]
+pub const XK_ellipsis: Keysym = 0x0aae;
+#[doc = " U+2025 TWO DOT LEADER"]
+pub const XK_doubbaselinedot: Keysym = 0x0aaf;
+#[doc = " U+2153 VULGAR FRACTION ONE THIRD"]
+pub const XK_onethird: Keysym = 0x0ab0;
+#[doc = " U+2154 VULGAR FRACTION TWO THIRDS"]
+pub const XK_twothirds: Keysym = 0x0ab1;
+#[doc = " U+2155 VULGAR FRACTION ONE FIFTH"]
+pub const XK_onefifth: Keysym = 0x0ab2;
+#[doc = " U+2156 VULGAR FRACTION TWO FIFTHS"]
+pub const XK_twofifths: Keysym = 0x0ab3;
+#[doc = " U+2157 VULGAR FRACTION THREE FIFTHS"]
+pub const XK_threefifths: Keysym = 0x0ab4;
+#[doc = " U+2158 VULGAR FRACTION FOUR FIFTHS"]
+pub const XK_fourfifths: Keysym = 0x0ab5;
+#[doc = " U+2159 VULGAR FRACTION ONE SIXTH"]
+pub const XK_onesixth: Keysym = 0x0ab6;
+#[doc = " U+215A VULGAR FRACTION FIVE SIXTHS"]
+pub const XK_fivesixths: Keysym = 0x0ab7;
+#[doc = " U+2105 CARE OF"]
+pub const XK_careof: Keysym = 0x0ab8;
+#[doc = " U+2012 FIGURE DASH"]
+pub const XK_figdash: Keysym = 0x0abb;
+#[doc = " (U+2329 LEFT-POINTING ANGLE BRACKET)"]
+pub const XK_leftanglebracket: Keysym = 0x0abc;
+#[doc = " (U+002E FULL STOP)"]
+pub const XK_decimalpoint: Keysym = 0x0abd;
+#[doc = " (U+232A RIGHT-POINTING ANGLE BRACKET)"]
+pub const XK_rightanglebracket: Keysym = 0x0abe;
+pub const XK_marker: Keysym = 0x0abf;
+#[doc = " U+215B VULGAR FRACTION ONE EIGHTH"]
+pub const XK_oneeighth: Keysym = 0x0ac3;
+#[doc = " U+215C VULGAR FRACTION THREE EIGHTHS"]
+pub const XK_threeeighths: Keysym = 0x0ac4;
+#[doc = " U+215D VULGAR FRACTION FIVE EIGHTHS"]
+pub const XK_fiveeighths: Keysym = 0x0ac5;
+#[doc = " U+215E VULGAR FRACTION SEVEN EIGHTHS"]
+pub const XK_seveneighths: Keysym = 0x0ac6;
+#[doc = " U+2122 TRADE MARK SIGN"]
+pub const XK_trademark: Keysym = 0x0ac9;
+#[doc = " (U+2613 SALTIRE)"]
+pub const XK_signaturemark: Keysym = 0x0aca;
+pub const XK_trademarkincircle: Keysym = 0x0acb;
+#[doc = " (U+25C1 WHITE LEFT-POINTING TRIANGLE)"]
+pub const XK_leftopentriangle: Keysym = 0x0acc;
+#[doc = " (U+25B7 WHITE RIGHT-POINTING TRIANGLE)"]
+pub const XK_rightopentriangle: Keysym = 0x0acd;
+#[doc = " (U+25CB WHITE CIRCLE)"]
+pub const XK_emopencircle: Keysym = 0x0ace;
+#[doc = " (U+25AF WHITE VERTICAL RECTANGLE)"]
+pub const XK_emopenrectangle: Keysym = 0x0acf;
+#[doc = " U+2018 LEFT SINGLE QUOTATION MARK"]
+pub const XK_leftsinglequotemark: Keysym = 0x0ad0;
+#[doc = " U+2019 RIGHT SINGLE QUOTATION MARK"]
+pub const XK_rightsinglequotemark: Keysym = 0x0ad1;
+#[doc = " U+201C LEFT DOUBLE QUOTATION MARK"]
+pub const XK_leftdoublequotemark: Keysym = 0x0ad2;
+#[doc = " U+201D RIGHT DOUBLE QUOTATION MARK"]
+pub const XK_rightdoublequotemark: Keysym = 0x0ad3;
+#[doc = " U+211E PRESCRIPTION TAKE"]
+pub const XK_prescription: Keysym = 0x0ad4;
+#[doc = " U+2030 PER MILLE SIGN"]
+pub const XK_permille: Keysym = 0x0ad5;
+#[doc = " U+2032 PRIME"]
+pub const XK_minutes: Keysym = 0x0ad6;
+#[doc = " U+2033 DOUBLE PRIME"]
+pub const XK_seconds: Keysym = 0x0ad7;
+#[doc = " U+271D LATIN CROSS"]
+pub const XK_latincross: Keysym = 0x0ad9;
+pub const XK_hexagram: Keysym = 0x0ada;
+#[doc = " (U+25AC BLACK RECTANGLE)"]
+pub const XK_filledrectbullet: Keysym = 0x0adb;
+#[doc = " (U+25C0 BLACK LEFT-POINTING TRIANGLE)"]
+pub const XK_filledlefttribullet: Keysym = 0x0adc;
+#[doc = " (U+25B6 BLACK RIGHT-POINTING TRIANGLE)"]
+pub const XK_filledrighttribullet: Keysym = 0x0add;
+#[doc = " (U+25CF BLACK CIRCLE)"]
+pub const XK_emfilledcircle: Keysym = 0x0ade;
+#[doc = " (U+25AE BLACK VERTICAL RECTANGLE)"]
+pub const XK_emfilledrect: Keysym = 0x0adf;
+#[doc = " (U+25E6 WHITE BULLET)"]
+pub const XK_enopencircbullet: Keysym = 0x0ae0;
+#[doc = " (U+25AB WHITE SMALL SQUARE)"]
+pub const XK_enopensquarebullet: Keysym = 0x0ae1;
+#[doc = " (U+25AD WHITE RECTANGLE)"]
+pub const XK_openrectbullet: Keysym = 0x0ae2;
+#[doc = " (U+25B3 WHITE UP-POINTING TRIANGLE)"]
+pub const XK_opentribulletup: Keysym = 0x0ae3;
+#[doc = " (U+25BD WHITE DOWN-POINTING TRIANGLE)"]
+pub const XK_opentribulletdown: Keysym = 0x0ae4;
+#[doc = " (U+2606 WHITE STAR)"]
+pub const XK_openstar: Keysym = 0x0ae5;
+#[doc = " (U+2022 BULLET)"]
+pub const XK_enfilledcircbullet: Keysym = 0x0ae6;
+#[doc = " (U+25AA BLACK SMALL SQUARE)"]
+pub const XK_enfilledsqbullet: Keysym = 0x0ae7;
+#[doc = " (U+25B2 BLACK UP-POINTING TRIANGLE)"]
+pub const XK_filledtribulletup: Keysym = 0x0ae8;
+#[doc = " (U+25BC BLACK DOWN-POINTING TRIANGLE)"]
+pub const XK_filledtribulletdown: Keysym = 0x0ae9;
+#[doc = " (U+261C WHITE LEFT POINTING INDEX)"]
+pub const XK_leftpointer: Keysym = 0x0aea;
+#[doc = " (U+261E WHITE RIGHT POINTING INDEX)"]
+pub const XK_rightpointer: Keysym = 0x0aeb;
+#[doc = " U+2663 BLACK CLUB SUIT"]
+pub const XK_club: Keysym = 0x0aec;
+#[doc = " U+2666 BLACK DIAMOND SUIT"]
+pub const XK_diamond: Keysym = 0x0aed;
+#[doc = " U+2665 BLACK HEART SUIT"]
+pub const XK_heart: Keysym = 0x0aee;
+#[doc = " U+2720 MALTESE CROSS"]
+pub const XK_maltesecross: Keysym = 0x0af0;
+#[doc = " U+2020 DAGGER"]
+pub const XK_dagger: Keysym = 0x0af1;
+#[doc = " U+2021 DOUBLE DAGGER"]
+pub const XK_doubledagger: Keysym = 0x0af2;
+#[doc = " U+2713 CHECK MARK"]
+pub const XK_checkmark: Keysym = 0x0af3;
+#[doc = " U+2717 BALLOT X"]
+pub const XK_ballotcross: Keysym = 0x0af4;
+#[doc = " U+266F MUSIC SHARP SIGN"]
+pub const XK_musicalsharp: Keysym = 0x0af5;
+#[doc = " U+266D MUSIC FLAT SIGN"]
+pub const XK_musicalflat: Keysym = 0x0af6;
+#[doc = " U+2642 MALE SIGN"]
+pub const XK_malesymbol: Keysym = 0x0af7;
+#[doc = " U+2640 FEMALE SIGN"]
+pub const XK_femalesymbol: Keysym = 0x0af8;
+#[doc = " U+260E BLACK TELEPHONE"]
+pub const XK_telephone: Keysym = 0x0af9;
+#[doc = " U+2315 TELEPHONE RECORDER"]
+pub const XK_telephonerecorder: Keysym = 0x0afa;
+#[doc = " U+2117 SOUND RECORDING COPYRIGHT"]
+pub const XK_phonographcopyright: Keysym = 0x0afb;
+#[doc = " U+2038 CARET"]
+pub const XK_caret: Keysym = 0x0afc;
+#[doc = " U+201A SINGLE LOW-9 QUOTATION MARK"]
+pub const XK_singlelowquotemark: Keysym = 0x0afd;
+#[doc = " U+201E DOUBLE LOW-9 QUOTATION MARK"]
+pub const XK_doublelowquotemark: Keysym = 0x0afe;
+pub const XK_cursor: Keysym = 0x0aff;
+#[doc = " (U+003C LESS-THAN SIGN)"]
+pub const XK_leftcaret: Keysym = 0x0ba3;
+#[doc = " (U+003E GREATER-THAN SIGN)"]
+pub const XK_rightcaret: Keysym = 0x0ba6;
+#[doc = " (U+2228 LOGICAL OR)"]
+pub const XK_downcaret: Keysym = 0x0ba8;
+#[doc = " (U+2227 LOGICAL AND)"]
+pub const XK_upcaret: Keysym = 0x0ba9;
+#[doc = " (U+00AF MACRON)"]
+pub const XK_overbar: Keysym = 0x0bc0;
+#[doc = " U+22A4 DOWN TACK"]
+pub const XK_downtack: Keysym = 0x0bc2;
+#[doc = " (U+2229 INTERSECTION)"]
+pub const XK_upshoe: Keysym = 0x0bc3;
+#[doc = " U+230A LEFT FLOOR"]
+pub const XK_downstile: Keysym = 0x0bc4;
+#[doc = " (U+005F LOW LINE)"]
+pub const XK_underbar: Keysym = 0x0bc6;
+#[doc = " U+2218 RING OPERATOR"]
+pub const XK_jot: Keysym = 0x0bca;
+#[doc = " U+2395 APL FUNCTIONAL SYMBOL QUAD"]
+pub const XK_quad: Keysym = 0x0bcc;
+#[doc = " U+22A5 UP TACK"]
+pub const XK_uptack: Keysym = 0x0bce;
+#[doc = " U+25CB WHITE CIRCLE"]
+pub const XK_circle: Keysym = 0x0bcf;
+#[doc = " U+2308 LEFT CEILING"]
+pub const XK_upstile: Keysym = 0x0bd3;
+#[doc = " (U+222A UNION)"]
+pub const XK_downshoe: Keysym = 0x0bd6;
+#[doc = " (U+2283 SUPERSET OF)"]
+pub const XK_rightshoe: Keysym = 0x0bd8;
+#[doc = " (U+2282 SUBSET OF)"]
+pub const XK_leftshoe: Keysym = 0x0bda;
+#[doc = " U+22A3 LEFT TACK"]
+pub const XK_lefttack: Keysym = 0x0bdc;
+#[doc = " U+22A2 RIGHT TACK"]
+pub const XK_righttack: Keysym = 0x0bfc;
+#[doc = " U+2017 DOUBLE LOW LINE"]
+pub const XK_hebrew_doublelowline: Keysym = 0x0cdf;
+#[doc = " U+05D0 HEBREW LETTER ALEF"]
+pub const XK_hebrew_aleph: Keysym = 0x0ce0;
+#[doc = " U+05D1 HEBREW LETTER BET"]
+pub const XK_hebrew_bet: Keysym = 0x0ce1;
+#[doc = " deprecated"]
+pub const XK_hebrew_beth: Keysym = 0x0ce1;
+#[doc = " U+05D2 HEBREW LETTER GIMEL"]
+pub const XK_hebrew_gimel: Keysym = 0x0ce2;
+#[doc = " deprecated"]
+pub const XK_hebrew_gimmel: Keysym = 0x0ce2;
+#[doc = " U+05D3 HEBREW LETTER DALET"]
+pub const XK_hebrew_dalet: Keysym = 0x0ce3;
+#[doc = " deprecated"]
+pub const XK_hebrew_daleth: Keysym = 0x0ce3;
+#[doc = " U+05D4 HEBREW LETTER HE"]
+pub const XK_hebrew_he: Keysym = 0x0ce4;
+#[doc = " U+05D5 HEBREW LETTER VAV"]
+pub const XK_hebrew_waw: Keysym = 0x0ce5;
+#[doc = " U+05D6 HEBREW LETTER ZAYIN"]
+pub const XK_hebrew_zain: Keysym = 0x0ce6;
+#[doc = " deprecated"]
+pub const XK_hebrew_zayin: Keysym = 0x0ce6;
+#[doc = " U+05D7 HEBREW LETTER HET"]
+pub const XK_hebrew_chet: Keysym = 0x0ce7;
+#[doc = " deprecated"]
+pub const XK_hebrew_het: Keysym = 0x0ce7;
+#[doc = " U+05D8 HEBREW LETTER TET"]
+pub const XK_hebrew_tet: Keysym = 0x0ce8;
+#[doc = " deprecated"]
+pub const XK_hebrew_teth: Keysym = 0x0ce8;
+#[doc = " U+05D9 HEBREW LETTER YOD"]
+pub const XK_hebrew_yod: Keysym = 0x0ce9;
+#[doc = " U+05DA HEBREW LETTER FINAL KAF"]
+pub const XK_hebrew_finalkaph: Keysym = 0x0cea;
+#[doc = " U+05DB HEBREW LETTER KAF"]
+pub const XK_hebrew_kaph: Keysym = 0x0ceb;
+#[doc = " U+05DC HEBREW LETTER LAMED"]
+pub const XK_hebrew_lamed: Keysym = 0x0cec;
+#[doc = " U+05DD HEBREW LETTER FINAL MEM"]
+pub const XK_hebrew_finalmem: Keysym = 0x0ced;
+#[doc = " U+05DE HEBREW LETTER MEM"]
+pub const XK_hebrew_mem: Keysym = 0x0cee;
+#[doc = " U+05DF HEBREW LETTER FINAL NUN"]
+pub const XK_hebrew_finalnun: Keysym = 0x0cef;
+#[doc = " U+05E0 HEBREW LETTER NUN"]
+pub const XK_hebrew_nun: Keysym = 0x0cf0;
+#[doc = " U+05E1 HEBREW LETTER SAMEKH"]
+pub const XK_hebrew_samech: Keysym = 0x0cf1;
+#[doc = " deprecated"]
+pub const XK_hebrew_samekh: Keysym = 0x0cf1;
+#[doc = " U+05E2 HEBREW LETTER AYIN"]
+pub const XK_hebrew_ayin: Keysym = 0x0cf2;
+#[doc = " U+05E3 HEBREW LETTER FINAL PE"]
+pub const XK_hebrew_finalpe: Keysym = 0x0cf3;
+#[doc = " U+05E4 HEBREW LETTER PE"]
+pub const XK_hebrew_pe: Keysym = 0x0cf4;
+#[doc = " U+05E5 HEBREW LETTER FINAL TSADI"]
+pub const XK_hebrew_finalzade: Keysym = 0x0cf5;
+#[doc = " deprecated"]
+pub const XK_hebrew_finalzadi: Keysym = 0x0cf5;
+#[doc = " U+05E6 HEBREW LETTER TSADI"]
+pub const XK_hebrew_zade: Keysym = 0x0cf6;
+#[doc = " deprecated"]
+pub const XK_hebrew_zadi: Keysym = 0x0cf6;
+#[doc = " U+05E7 HEBREW LETTER QOF"]
+pub const XK_hebrew_qoph: Keysym = 0x0cf7;
+#[doc = " deprecated"]
+pub const XK_hebrew_kuf: Keysym = 0x0cf7;
+#[doc = " U+05E8 HEBREW LETTER RESH"]
+pub const XK_hebrew_resh: Keysym = 0x0cf8;
+#[doc = " U+05E9 HEBREW LETTER SHIN"]
+pub const XK_hebrew_shin: Keysym = 0x0cf9;
+#[doc = " U+05EA HEBREW LETTER TAV"]
+pub const XK_hebrew_taw: Keysym = 0x0cfa;
+#[doc = " deprecated"]
+pub const XK_hebrew_taf: Keysym = 0x0cfa;
+#[doc = " Alias for `XK_Mode_switch`"]
+pub const XK_Hebrew_switch: Keysym = 0xff7e;
+#[doc = " U+0E01 THAI CHARACTER KO KAI"]
+pub const XK_Thai_kokai: Keysym = 0x0da1;
+#[doc = " U+0E02 THAI CHARACTER KHO KHAI"]
+pub const XK_Thai_khokhai: Keysym = 0x0da2;
+#[doc = " U+0E03 THAI CHARACTER KHO KHUAT"]
+pub const XK_Thai_khokhuat: Keysym = 0x0da3;
+#[doc = " U+0E04 THAI CHARACTER KHO KHWAI"]
+pub const XK_Thai_khokhwai: Keysym = 0x0da4;
+#[doc = " U+0E05 THAI CHARACTER KHO KHON"]
+pub const XK_Thai_khokhon: Keysym = 0x0da5;
+#[doc = " U+0E06 THAI CHARACTER KHO RAKHANG"]
+pub const XK_Thai_khorakhang: Keysym = 0x0da6;
+#[doc = " U+0E07 THAI CHARACTER NGO NGU"]
+pub const XK_Thai_ngongu: Keysym = 0x0da7;
+#[doc = " U+0E08 THAI CHARACTER CHO CHAN"]
+pub const XK_Thai_chochan: Keysym = 0x0da8;
+#[doc = " U+0E09 THAI CHARACTER CHO CHING"]
+pub const XK_Thai_choching: Keysym = 0x0da9;
+#[doc = " U+0E0A THAI CHARACTER CHO CHANG"]
+pub const XK_Thai_chochang: Keysym = 0x0daa;
+#[doc = " U+0E0B THAI CHARACTER SO SO"]
+pub const XK_Thai_soso: Keysym = 0x0dab;
+#[doc = " U+0E0C THAI CHARACTER CHO CHOE"]
+pub const XK_Thai_chochoe: Keysym = 0x0dac;
+#[doc = " U+0E0D THAI CHARACTER YO YING"]
+pub const XK_Thai_yoying: Keysym = 0x0dad;
+#[doc = " U+0E0E THAI CHARACTER DO CHADA"]
+pub const XK_Thai_dochada: Keysym = 0x0dae;
+#[doc = " U+0E0F THAI CHARACTER TO PATAK"]
+pub const XK_Thai_topatak: Keysym = 0x0daf;
+#[doc = " U+0E10 THAI CHARACTER THO THAN"]
+pub const XK_Thai_thothan: Keysym = 0x0db0;
+#[doc = " U+0E11 THAI CHARACTER THO NANGMONTHO"]
+pub const XK_Thai_thonangmontho: Keysym = 0x0db1;
+#[doc = " U+0E12 THAI CHARACTER THO PHUTHAO"]
+pub const XK_Thai_thophuthao: Keysym = 0x0db2;
+#[doc = " U+0E13 THAI CHARACTER NO NEN"]
+pub const XK_Thai_nonen: Keysym = 0x0db3;
+#[doc = " U+0E14 THAI CHARACTER DO DEK"]
+pub const XK_Thai_dodek: Keysym = 0x0db4;
+#[doc = " U+0E15 THAI CHARACTER TO TAO"]
+pub const XK_Thai_totao: Keysym = 0x0db5;
+#[doc = " U+0E16 THAI CHARACTER THO THUNG"]
+pub const XK_Thai_thothung: Keysym = 0x0db6;
+#[doc = " U+0E17 THAI CHARACTER THO THAHAN"]
+pub const XK_Thai_thothahan: Keysym = 0x0db7;
+#[doc = " U+0E18 THAI CHARACTER THO THONG"]
+pub const XK_Thai_thothong: Keysym = 0x0db8;
+#[doc = " U+0E19 THAI CHARACTER NO NU"]
+pub const XK_Thai_nonu: Keysym = 0x0db9;
+#[doc = " U+0E1A THAI CHARACTER BO BAIMAI"]
+pub const XK_Thai_bobaimai: Keysym = 0x0dba;
+#[doc = " U+0E1B THAI CHARACTER PO PLA"]
+pub const XK_Thai_popla: Keysym = 0x0dbb;
+#[doc = " U+0E1C THAI CHARACTER PHO PHUNG"]
+pub const XK_Thai_phophung: Keysym = 0x0dbc;
+#[doc = " U+0E1D THAI CHARACTER FO FA"]
+pub const XK_Thai_fofa: Keysym = 0x0dbd;
+#[doc = " U+0E1E THAI CHARACTER PHO PHAN"]
+pub const XK_Thai_phophan: Keysym = 0x0dbe;
+#[doc = " U+0E1F THAI CHARACTER FO FAN"]
+pub const XK_Thai_fofan: Keysym = 0x0dbf;
+#[doc = " U+0E20 THAI CHARACTER PHO SAMPHAO"]
+pub const XK_Thai_phosamphao: Keysym = 0x0dc0;
+#[doc = " U+0E21 THAI CHARACTER MO MA"]
+pub const XK_Thai_moma: Keysym = 0x0dc1;
+#[doc = " U+0E22 THAI CHARACTER YO YAK"]
+pub const XK_Thai_yoyak: Keysym = 0x0dc2;
+#[doc = " U+0E23 THAI CHARACTER RO RUA"]
+pub const XK_Thai_rorua: Keysym = 0x0dc3;
+#[doc = " U+0E24 THAI CHARACTER RU"]
+pub const XK_Thai_ru: Keysym = 0x0dc4;
+#[doc = " U+0E25 THAI CHARACTER LO LING"]
+pub const XK_Thai_loling: Keysym = 0x0dc5;
+#[doc = " U+0E26 THAI CHARACTER LU"]
+pub const XK_Thai_lu: Keysym = 0x0dc6;
+#[doc = " U+0E27 THAI CHARACTER WO WAEN"]
+pub const XK_Thai_wowaen: Keysym = 0x0dc7;
+#[doc = " U+0E28 THAI CHARACTER SO SALA"]
+pub const XK_Thai_sosala: Keysym = 0x0dc8;
+#[doc = " U+0E29 THAI CHARACTER SO RUSI"]
+pub const XK_Thai_sorusi: Keysym = 0x0dc9;
+#[doc = " U+0E2A THAI CHARACTER SO SUA"]
+pub const XK_Thai_sosua: Keysym = 0x0dca;
+#[doc = " U+0E2B THAI CHARACTER HO HIP"]
+pub const XK_Thai_hohip: Keysym = 0x0dcb;
+#[doc = " U+0E2C THAI CHARACTER LO CHULA"]
+pub const XK_Thai_lochula: Keysym = 0x0dcc;
+#[doc = " U+0E2D THAI CHARACTER O ANG"]
+pub const XK_Thai_oang: Keysym = 0x0dcd;
+#[doc = " U+0E2E THAI CHARACTER HO NOKHUK"]
+pub const XK_Thai_honokhuk: Keysym = 0x0dce;
+#[doc = " U+0E2F THAI CHARACTER PAIYANNOI"]
+pub const XK_Thai_paiyannoi: Keysym = 0x0dcf;
+#[doc = " U+0E30 THAI CHARACTER SARA A"]
+pub const XK_Thai_saraa: Keysym = 0x0dd0;
+#[doc = " U+0E31 THAI CHARACTER MAI HAN-AKAT"]
+pub const XK_Thai_maihanakat: Keysym = 0x0dd1;
+#[doc = " U+0E32 THAI CHARACTER SARA AA"]
+pub const XK_Thai_saraaa: Keysym = 0x0dd2;
+#[doc = " U+0E33 THAI CHARACTER SARA AM"]
+pub const XK_Thai_saraam: Keysym = 0x0dd3;
+#[doc = " U+0E34 THAI CHARACTER SARA I"]
+pub const XK_Thai_sarai: Keysym = 0x0dd4;
+#[doc = " U+0E35 THAI CHARACTER SARA II"]
+pub const XK_Thai_saraii: Keysym = 0x0dd5;
+#[doc = " U+0E36 THAI CHARACTER SARA UE"]
+pub const XK_Thai_saraue: Keysym = 0x0dd6;
+#[doc = " U+0E37 THAI CHARACTER SARA UEE"]
+pub const XK_Thai_sarauee: Keysym = 0x0dd7;
+#[doc = " U+0E38 THAI CHARACTER SARA U"]
+pub const XK_Thai_sarau: Keysym = 0x0dd8;
+#[doc = " U+0E39 THAI CHARACTER SARA UU"]
+pub const XK_Thai_sarauu: Keysym = 0x0dd9;
+#[doc = " U+0E3A THAI CHARACTER PHINTHU"]
+pub const XK_Thai_phinthu: Keysym = 0x0dda;
+pub const XK_Thai_maihanakat_maitho: Keysym = 0x0dde;
+#[doc = " U+0E3F THAI CURRENCY SYMBOL BAHT"]
+pub const XK_Thai_baht: Keysym = 0x0ddf;
+#[doc = " U+0E40 THAI CHARACTER SARA E"]
+pub const XK_Thai_sarae: Keysym = 0x0de0;
+#[doc = " U+0E41 THAI CHARACTER SARA AE"]
+pub const XK_Thai_saraae: Keysym = 0x0de1;
+#[doc = " U+0E42 THAI CHARACTER SARA O"]
+pub const XK_Thai_sarao: Keysym = 0x0de2;
+#[doc = " U+0E43 THAI CHARACTER SARA AI MAIMUAN"]
+pub const XK_Thai_saraaimaimuan: Keysym = 0x0de3;
+#[doc = " U+0E44 THAI CHARACTER SARA AI MAIMALAI"]
+pub const XK_Thai_saraaimaimalai: Keysym = 0x0de4;
+#[doc = " U+0E45 THAI CHARACTER LAKKHANGYAO"]
+pub const XK_Thai_lakkhangyao: Keysym = 0x0de5;
+#[doc = " U+0E46 THAI CHARACTER MAIYAMOK"]
+pub const XK_Thai_maiyamok: Keysym = 0x0de6;
+#[doc = " U+0E47 THAI CHARACTER MAITAIKHU"]
+pub const XK_Thai_maitaikhu: Keysym = 0x0de7;
+#[doc = " U+0E48 THAI CHARACTER MAI EK"]
+pub const XK_Thai_maiek: Keysym = 0x0de8;
+#[doc = " U+0E49 THAI CHARACTER MAI THO"]
+pub const XK_Thai_maitho: Keysym = 0x0de9;
+#[doc = " U+0E4A THAI CHARACTER MAI TRI"]
+pub const XK_Thai_maitri: Keysym = 0x0dea;
+#[doc = " U+0E4B THAI CHARACTER MAI CHATTAWA"]
+pub const XK_Thai_maichattawa: Keysym = 0x0deb;
+#[doc = " U+0E4C THAI CHARACTER THANTHAKHAT"]
+pub const XK_Thai_thanthakhat: Keysym = 0x0dec;
+#[doc = " U+0E4D THAI CHARACTER NIKHAHIT"]
+pub const XK_Thai_nikhahit: Keysym = 0x0ded;
+#[doc = " U+0E50 THAI DIGIT ZERO"]
+pub const XK_Thai_leksun: Keysym = 0x0df0;
+#[doc = " U+0E51 THAI DIGIT ONE"]
+pub const XK_Thai_leknung: Keysym = 0x0df1;
+#[doc = " U+0E52 THAI DIGIT TWO"]
+pub const XK_Thai_leksong: Keysym = 0x0df2;
+#[doc = " U+0E53 THAI DIGIT THREE"]
+pub const XK_Thai_leksam: Keysym = 0x0df3;
+#[doc = " U+0E54 THAI DIGIT FOUR"]
+pub const XK_Thai_leksi: Keysym = 0x0df4;
+#[doc = " U+0E55 THAI DIGIT FIVE"]
+pub const XK_Thai_lekha: Keysym = 0x0df5;
+#[doc = " U+0E56 THAI DIGIT SIX"]
+pub const XK_Thai_lekhok: Keysym = 0x0df6;
+#[doc = " U+0E57 THAI DIGIT SEVEN"]
+pub const XK_Thai_lekchet: Keysym = 0x0df7;
+#[doc = " U+0E58 THAI DIGIT EIGHT"]
+pub const XK_Thai_lekpaet: Keysym = 0x0df8;
+#[doc = " U+0E59 THAI DIGIT NINE"]
+pub const XK_Thai_lekkao: Keysym = 0x0df9;
+#[doc = " Hangul start/stop(toggle)"]
+pub const XK_Hangul: Keysym = 0xff31;
+#[doc = " Hangul start"]
+pub const XK_Hangul_Start: Keysym = 0xff32;
+#[doc = " Hangul end, English start"]
+pub const XK_Hangul_End: Keysym = 0xff33;
+#[doc = " Start Hangul->Hanja Conversion"]
+pub const XK_Hangul_Hanja: Keysym = 0xff34;
+#[doc = " Hangul Jamo mode"]
+pub const XK_Hangul_Jamo: Keysym = 0xff35;
+#[doc = " Hangul Romaja mode"]
+pub const XK_Hangul_Romaja: Keysym = 0xff36;
+#[doc = " Hangul code input mode"]
+pub const XK_Hangul_Codeinput: Keysym = 0xff37;
+#[doc = " Jeonja mode"]
+pub const XK_Hangul_Jeonja: Keysym = 0xff38;
+#[doc = " Banja mode"]
+pub const XK_Hangul_Banja: Keysym = 0xff39;
+#[doc = " Pre Hanja conversion"]
+pub const XK_Hangul_PreHanja: Keysym = 0xff3a;
+#[doc = " Post Hanja conversion"]
+pub const XK_Hangul_PostHanja: Keysym = 0xff3b;
+#[doc = " Single candidate"]
+pub const XK_Hangul_SingleCandidate: Keysym = 0xff3c;
+#[doc = " Multiple candidate"]
+pub const XK_Hangul_MultipleCandidate: Keysym = 0xff3d;
+#[doc = " Previous candidate"]
+pub const XK_Hangul_PreviousCandidate: Keysym = 0xff3e;
+#[doc = " Special symbols"]
+pub const XK_Hangul_Special: Keysym = 0xff3f;
+#[doc = " Alias for `XK_Mode_switch`"]
+pub const XK_Hangul_switch: Keysym = 0xff7e;
+#[doc = " U+3131 HANGUL LETTER KIYEOK"]
+pub const XK_Hangul_Kiyeog: Keysym = 0x0ea1;
+#[doc = " U+3132 HANGUL LETTER SSANGKIYEOK"]
+pub const XK_Hangul_SsangKiyeog: Keysym = 0x0ea2;
+#[doc = " U+3133 HANGUL LETTER KIYEOK-SIOS"]
+pub const XK_Hangul_KiyeogSios: Keysym = 0x0ea3;
+#[doc = " U+3134 HANGUL LETTER NIEUN"]
+pub const XK_Hangul_Nieun: Keysym = 0x0ea4;
+#[doc = " U+3135 HANGUL LETTER NIEUN-CIEUC"]
+pub const XK_Hangul_NieunJieuj: Keysym = 0x0ea5;
+#[doc = " U+3136 HANGUL LETTER NIEUN-HIEUH"]
+pub const XK_Hangul_NieunHieuh: Keysym = 0x0ea6;
+#[doc = " U+3137 HANGUL LETTER TIKEUT"]
+pub const XK_Hangul_Dikeud: Keysym = 0x0ea7;
+#[doc = " U+3138 HANGUL LETTER SSANGTIKEUT"]
+pub const XK_Hangul_SsangDikeud: Keysym = 0x0ea8;
+#[doc = " U+3139 HANGUL LETTER RIEUL"]
+pub const XK_Hangul_Rieul: Keysym = 0x0ea9;
+#[doc = " U+313A HANGUL LETTER RIEUL-KIYEOK"]
+pub const XK_Hangul_RieulKiyeog: Keysym = 0x0eaa;
+#[doc = " U+313B HANGUL LETTER RIEUL-MIEUM"]
+pub const XK_Hangul_RieulMieum: Keysym = 0x0eab;
+#[doc = " U+313C HANGUL LETTER RIEUL-PIEUP"]
+pub const XK_Hangul_RieulPieub: Keysym = 0x0eac;
+#[doc = " U+313D HANGUL LETTER RIEUL-SIOS"]
+pub const XK_Hangul_RieulSios: Keysym = 0x0ead;
+#[doc = " U+313E HANGUL LETTER RIEUL-THIEUTH"]
+pub const XK_Hangul_RieulTieut: Keysym = 0x0eae;
+#[doc = " U+313F HANGUL LETTER RIEUL-PHIEUPH"]
+pub const XK_Hangul_RieulPhieuf: Keysym = 0x0eaf;
+#[doc = " U+3140 HANGUL LETTER RIEUL-HIEUH"]
+pub const XK_Hangul_RieulHieuh: Keysym = 0x0eb0;
+#[doc = " U+3141 HANGUL LETTER MIEUM"]
+pub const XK_Hangul_Mieum: Keysym = 0x0eb1;
+#[doc = " U+3142 HANGUL LETTER PIEUP"]
+pub const XK_Hangul_Pieub: Keysym = 0x0eb2;
+#[doc = " U+3143 HANGUL LETTER SSANGPIEUP"]
+pub const XK_Hangul_SsangPieub: Keysym = 0x0eb3;
+#[doc = " U+3144 HANGUL LETTER PIEUP-SIOS"]
+pub const XK_Hangul_PieubSios: Keysym = 0x0eb4;
+#[doc = " U+3145 HANGUL LETTER SIOS"]
+pub const XK_Hangul_Sios: Keysym = 0x0eb5;
+#[doc = " U+3146 HANGUL LETTER SSANGSIOS"]
+pub const XK_Hangul_SsangSios: Keysym = 0x0eb6;
+#[doc = " U+3147 HANGUL LETTER IEUNG"]
+pub const XK_Hangul_Ieung: Keysym = 0x0eb7;
+#[doc = " U+3148 HANGUL LETTER CIEUC"]
+pub const XK_Hangul_Jieuj: Keysym = 0x0eb8;
+#[doc = " U+3149 HANGUL LETTER SSANGCIEUC"]
+pub const XK_Hangul_SsangJieuj: Keysym = 0x0eb9;
+#[doc = " U+314A HANGUL LETTER CHIEUCH"]
+pub const XK_Hangul_Cieuc: Keysym = 0x0eba;
+#[doc = " U+314B HANGUL LETTER KHIEUKH"]
+pub const XK_Hangul_Khieuq: Keysym = 0x0ebb;
+#[doc = " U+314C HANGUL LETTER THIEUTH"]
+pub const XK_Hangul_Tieut: Keysym = 0x0ebc;
+#[doc = " U+314D HANGUL LETTER PHIEUPH"]
+pub const XK_Hangul_Phieuf: Keysym = 0x0ebd;
+#[doc = " U+314E HANGUL LETTER HIEUH"]
+pub const XK_Hangul_Hieuh: Keysym = 0x0ebe;
+#[doc = " U+314F HANGUL LETTER A"]
+pub const XK_Hangul_A: Keysym = 0x0ebf;
+#[doc = " U+3150 HANGUL LETTER AE"]
+pub const XK_Hangul_AE: Keysym = 0x0ec0;
+#[doc = " U+3151 HANGUL LETTER YA"]
+pub const XK_Hangul_YA: Keysym = 0x0ec1;
+#[doc = " U+3152 HANGUL LETTER YAE"]
+pub const XK_Hangul_YAE: Keysym = 0x0ec2;
+#[doc = " U+3153 HANGUL LETTER EO"]
+pub const XK_Hangul_EO: Keysym = 0x0ec3;
+#[doc = " U+3154 HANGUL LETTER E"]
+pub const XK_Hangul_E: Keysym = 0x0ec4;
+#[doc = " U+3155 HANGUL LETTER YEO"]
+pub const XK_Hangul_YEO: Keysym = 0x0ec5;
+#[doc = " U+3156 HANGUL LETTER YE"]
+pub const XK_Hangul_YE: Keysym = 0x0ec6;
+#[doc = " U+3157 HANGUL LETTER O"]
+pub const XK_Hangul_O: Keysym = 0x0ec7;
+#[doc = " U+3158 HANGUL LETTER WA"]
+pub const XK_Hangul_WA: Keysym = 0x0ec8;
+#[doc = " U+3159 HANGUL LETTER WAE"]
+pub const XK_Hangul_WAE: Keysym = 0x0ec9;
+#[doc = " U+315A HANGUL LETTER OE"]
+pub const XK_Hangul_OE: Keysym = 0x0eca;
+#[doc = " U+315B HANGUL LETTER YO"]
+pub const XK_Hangul_YO: Keysym = 0x0ecb;
+#[doc = " U+315C HANGUL LETTER U"]
+pub const XK_Hangul_U: Keysym = 0x0ecc;
+#[doc = " U+315D HANGUL LETTER WEO"]
+pub const XK_Hangul_WEO: Keysym = 0x0ecd;
+#[doc = " U+315E HANGUL LETTER WE"]
+pub const XK_Hangul_WE: Keysym = 0x0ece;
+#[doc = " U+315F HANGUL LETTER WI"]
+pub const XK_Hangul_WI: Keysym = 0x0ecf;
+#[doc = " U+3160 HANGUL LETTER YU"]
+pub const XK_Hangul_YU: Keysym = 0x0ed0;
+#[doc = " U+3161 HANGUL LETTER EU"]
+pub const XK_Hangul_EU: Keysym = 0x0ed1;
+#[doc = " U+3162 HANGUL LETTER YI"]
+pub const XK_Hangul_YI: Keysym = 0x0ed2;
+#[doc = " U+3163 HANGUL LETTER I"]
+pub const XK_Hangul_I: Keysym = 0x0ed3;
+#[doc = " U+11A8 HANGUL JONGSEONG KIYEOK"]
+pub const XK_Hangul_J_Kiyeog: Keysym = 0x0ed4;
+#[doc = " U+11A9 HANGUL JONGSEONG SSANGKIYEOK"]
+pub const XK_Hangul_J_SsangKiyeog: Keysym = 0x0ed5;
+#[doc = " U+11AA HANGUL JONGSEONG KIYEOK-SIOS"]
+pub const XK_Hangul_J_KiyeogSios: Keysym = 0x0ed6;
+#[doc = " U+11AB HANGUL JONGSEONG NIEUN"]
+pub const XK_Hangul_J_Nieun: Keysym = 0x0ed7;
+#[doc = " U+11AC HANGUL JONGSEONG NIEUN-CIEUC"]
+pub const XK_Hangul_J_NieunJieuj: Keysym = 0x0ed8;
+#[doc = " U+11AD HANGUL JONGSEONG NIEUN-HIEUH"]
+pub const XK_Hangul_J_NieunHieuh: Keysym = 0x0ed9;
+#[doc = " U+11AE HANGUL JONGSEONG TIKEUT"]
+pub const XK_Hangul_J_Dikeud: Keysym = 0x0eda;
+#[doc = " U+11AF HANGUL JONGSEONG RIEUL"]
+pub const XK_Hangul_J_Rieul: Keysym = 0x0edb;
+#[doc = " U+11B0 HANGUL JONGSEONG RIEUL-KIYEOK"]
+pub const XK_Hangul_J_RieulKiyeog: Keysym = 0x0edc;
+#[doc = " U+11B1 HANGUL JONGSEONG RIEUL-MIEUM"]
+pub const XK_Hangul_J_RieulMieum: Keysym = 0x0edd;
+#[doc = " U+11B2 HANGUL JONGSEONG RIEUL-PIEUP"]
+pub const XK_Hangul_J_RieulPieub: Keysym = 0x0ede;
+#[doc = " U+11B3 HANGUL JONGSEONG RIEUL-SIOS"]
+pub const XK_Hangul_J_RieulSios: Keysym = 0x0edf;
+#[doc = " U+11B4 HANGUL JONGSEONG RIEUL-THIEUTH"]
+pub const XK_Hangul_J_RieulTieut: Keysym = 0x0ee0;
+#[doc = " U+11B5 HANGUL JONGSEONG RIEUL-PHIEUPH"]
+pub const XK_Hangul_J_RieulPhieuf: Keysym = 0x0ee1;
+#[doc = " U+11B6 HANGUL JONGSEONG RIEUL-HIEUH"]
+pub const XK_Hangul_J_RieulHieuh: Keysym = 0x0ee2;
+#[doc = " U+11B7 HANGUL JONGSEONG MIEUM"]
+pub const XK_Hangul_J_Mieum: Keysym = 0x0ee3;
+#[doc = " U+11B8 HANGUL JONGSEONG PIEUP"]
+pub const XK_Hangul_J_Pieub: Keysym = 0x0ee4;
+#[doc = " U+11B9 HANGUL JONGSEONG PIEUP-SIOS"]
+pub const XK_Hangul_J_PieubSios: Keysym = 0x0ee5;
+#[doc = " U+11BA HANGUL JONGSEONG SIOS"]
+pub const XK_Hangul_J_Sios: Keysym = 0x0ee6;
+#[doc = " U+11BB HANGUL JONGSEONG SSANGSIOS"]
+pub const XK_Hangul_J_SsangSios: Keysym = 0x0ee7;
+#[doc = " U+11BC HANGUL JONGSEONG IEUNG"]
+pub const XK_Hangul_J_Ieung: Keysym = 0x0ee8;
+#[doc = " U+11BD HANGUL JONGSEONG CIEUC"]
+pub const XK_Hangul_J_Jieuj: Keysym = 0x0ee9;
+#[doc = " U+11BE HANGUL JONGSEONG CHIEUCH"]
+pub const XK_Hangul_J_Cieuc: Keysym = 0x0eea;
+#[doc = " U+11BF HANGUL JONGSEONG KHIEUKH"]
+pub const XK_Hangul_J_Khieuq: Keysym = 0x0eeb;
+#[doc = " U+11C0 HANGUL JONGSEONG THIEUTH"]
+pub const XK_Hangul_J_Tieut: Keysym = 0x0eec;
+#[doc = " U+11C1 HANGUL JONGSEONG PHIEUPH"]
+pub const XK_Hangul_J_Phieuf: Keysym = 0x0eed;
+#[doc = " U+11C2 HANGUL JONGSEONG HIEUH"]
+pub const XK_Hangul_J_Hieuh: Keysym = 0x0eee;
+#[doc = " U+316D HANGUL LETTER RIEUL-YEORINHIEUH"]
+pub const XK_Hangul_RieulYeorinHieuh: Keysym = 0x0eef;
+#[doc = " U+3171 HANGUL LETTER KAPYEOUNMIEUM"]
+pub const XK_Hangul_SunkyeongeumMieum: Keysym = 0x0ef0;
+#[doc = " U+3178 HANGUL LETTER KAPYEOUNPIEUP"]
+pub const XK_Hangul_SunkyeongeumPieub: Keysym = 0x0ef1;
+#[doc = " U+317F HANGUL LETTER PANSIOS"]
+pub const XK_Hangul_PanSios: Keysym = 0x0ef2;
+#[doc = " U+3181 HANGUL LETTER YESIEUNG"]
+pub const XK_Hangul_KkogjiDalrinIeung: Keysym = 0x0ef3;
+#[doc = " U+3184 HANGUL LETTER KAPYEOUNPHIEUPH"]
+pub const XK_Hangul_SunkyeongeumPhieuf: Keysym = 0x0ef4;
+#[doc = " U+3186 HANGUL LETTER YEORINHIEUH"]
+pub const XK_Hangul_YeorinHieuh: Keysym = 0x0ef5;
+#[doc = " U+318D HANGUL LETTER ARAEA"]
+pub const XK_Hangul_AraeA: Keysym = 0x0ef6;
+#[doc = " U+318E HANGUL LETTER ARAEAE"]
+pub const XK_Hangul_AraeAE: Keysym = 0x0ef7;
+#[doc = " U+11EB HANGUL JONGSEONG PANSIOS"]
+pub const XK_Hangul_J_PanSios: Keysym = 0x0ef8;
+#[doc = " U+11F0 HANGUL JONGSEONG YESIEUNG"]
+pub const XK_Hangul_J_KkogjiDalrinIeung: Keysym = 0x0ef9;
+#[doc = " U+11F9 HANGUL JONGSEONG YEORINHIEUH"]
+pub const XK_Hangul_J_YeorinHieuh: Keysym = 0x0efa;
+#[doc = " (U+20A9 WON SIGN)"]
+pub const XK_Korean_Won: Keysym = 0x0eff;
+#[doc = " U+0587 ARMENIAN SMALL LIGATURE ECH YIWN"]
+pub const XK_Armenian_ligature_ew: Keysym = 0x1000587;
+#[doc = " U+0589 ARMENIAN FULL STOP"]
+pub const XK_Armenian_full_stop: Keysym = 0x1000589;
+#[doc = " U+0589 ARMENIAN FULL STOP"]
+pub const XK_Armenian_verjaket: Keysym = 0x1000589;
+#[doc = " U+055D ARMENIAN COMMA"]
+pub const XK_Armenian_separation_mark: Keysym = 0x100055d;
+#[doc = " U+055D ARMENIAN COMMA"]
+pub const XK_Armenian_but: Keysym = 0x100055d;
+#[doc = " U+058A ARMENIAN HYPHEN"]
+pub const XK_Armenian_hyphen: Keysym = 0x100058a;
+#[doc = " U+058A ARMENIAN HYPHEN"]
+pub const XK_Armenian_yentamna: Keysym = 0x100058a;
+#[doc = " U+055C ARMENIAN EXCLAMATION MARK"]
+pub const XK_Armenian_exclam: Keysym = 0x100055c;
+#[doc = " U+055C ARMENIAN EXCLAMATION MARK"]
+pub const XK_Armenian_amanak: Keysym = 0x100055c;
+#[doc = " U+055B ARMENIAN EMPHASIS MARK"]
+pub const XK_Armenian_accent: Keysym = 0x100055b;
+#[doc = " U+055B ARMENIAN EMPHASIS MARK"]
+pub const XK_Armenian_shesht: Keysym = 0x100055b;
+#[doc = " U+055E ARMENIAN QUESTION MARK"]
+pub const XK_Armenian_question: Keysym = 0x100055e;
+#[doc = " U+055E ARMENIAN QUESTION MARK"]
+pub const XK_Armenian_paruyk: Keysym = 0x100055e;
+#[doc = " U+0531 ARMENIAN CAPITAL LETTER AYB"]
+pub const XK_Armenian_AYB: Keysym = 0x1000531;
+#[doc = " U+0561 ARMENIAN SMALL LETTER AYB"]
+pub const XK_Armenian_ayb: Keysym = 0x1000561;
+#[doc = " U+0532 ARMENIAN CAPITAL LETTER BEN"]
+pub const XK_Armenian_BEN: Keysym = 0x1000532;
+#[doc = " U+0562 ARMENIAN SMALL LETTER BEN"]
+pub const XK_Armenian_ben: Keysym = 0x1000562;
+#[doc = " U+0533 ARMENIAN CAPITAL LETTER GIM"]
+pub const XK_Armenian_GIM: Keysym = 0x1000533;
+#[doc = " U+0563 ARMENIAN SMALL LETTER GIM"]
+pub const XK_Armenian_gim: Keysym = 0x1000563;
+#[doc = " U+0534 ARMENIAN CAPITAL LETTER DA"]
+pub const XK_Armenian_DA: Keysym = 0x1000534;
+#[doc = " U+0564 ARMENIAN SMALL LETTER DA"]
+pub const XK_Armenian_da: Keysym = 0x1000564;
+#[doc = " U+0535 ARMENIAN CAPITAL LETTER ECH"]
+pub const XK_Armenian_YECH: Keysym = 0x1000535;
+#[doc = " U+0565 ARMENIAN SMALL LETTER ECH"]
+pub const XK_Armenian_yech: Keysym = 0x1000565;
+#[doc = " U+0536 ARMENIAN CAPITAL LETTER ZA"]
+pub const XK_Armenian_ZA: Keysym = 0x1000536;
+#[doc = " U+0566 ARMENIAN SMALL LETTER ZA"]
+pub const XK_Armenian_za: Keysym = 0x1000566;
+#[doc = " U+0537 ARMENIAN CAPITAL LETTER EH"]
+pub const XK_Armenian_E: Keysym = 0x1000537;
+#[doc = " U+0567 ARMENIAN SMALL LETTER EH"]
+pub const XK_Armenian_e: Keysym = 0x1000567;
+#[doc = " U+0538 ARMENIAN CAPITAL LETTER ET"]
+pub const XK_Armenian_AT: Keysym = 0x1000538;
+#[doc = " U+0568 ARMENIAN SMALL LETTER ET"]
+pub const XK_Armenian_at: Keysym = 0x1000568;
+#[doc = " U+0539 ARMENIAN CAPITAL LETTER TO"]
+pub const XK_Armenian_TO: Keysym = 0x1000539;
+#[doc = " U+0569 ARMENIAN SMALL LETTER TO"]
+pub const XK_Armenian_to: Keysym = 0x1000569;
+#[doc = " U+053A ARMENIAN CAPITAL LETTER ZHE"]
+pub const XK_Armenian_ZHE: Keysym = 0x100053a;
+#[doc = " U+056A ARMENIAN SMALL LETTER ZHE"]
+pub const XK_Armenian_zhe: Keysym = 0x100056a;
+#[doc = " U+053B ARMENIAN CAPITAL LETTER INI"]
+pub const XK_Armenian_INI: Keysym = 0x100053b;
+#[doc = " U+056B ARMENIAN SMALL LETTER INI"]
+pub const XK_Armenian_ini: Keysym = 0x100056b;
+#[doc = " U+053C ARMENIAN CAPITAL LETTER LIWN"]
+pub const XK_Armenian_LYUN: Keysym = 0x100053c;
+#[doc = " U+056C ARMENIAN SMALL LETTER LIWN"]
+pub const XK_Armenian_lyun: Keysym = 0x100056c;
+#[doc = " U+053D ARMENIAN CAPITAL LETTER XEH"]
+pub const XK_Armenian_KHE: Keysym = 0x100053d;
+#[doc = " U+056D ARMENIAN SMALL LETTER XEH"]
+pub const XK_Armenian_khe: Keysym = 0x100056d;
+#[doc = " U+053E ARMENIAN CAPITAL LETTER CA"]
+pub const XK_Armenian_TSA: Keysym = 0x100053e;
+#[doc = " U+056E ARMENIAN SMALL LETTER CA"]
+pub const XK_Armenian_tsa: Keysym = 0x100056e;
+#[doc = " U+053F ARMENIAN CAPITAL LETTER KEN"]
+pub const XK_Armenian_KEN: Keysym = 0x100053f;
+#[doc = " U+056F ARMENIAN SMALL LETTER KEN"]
+pub const XK_Armenian_ken: Keysym = 0x100056f;
+#[doc = " U+0540 ARMENIAN CAPITAL LETTER HO"]
+pub const XK_Armenian_HO: Keysym = 0x1000540;
+#[doc = " U+0570 ARMENIAN SMALL LETTER HO"]
+pub const XK_Armenian_ho: Keysym = 0x1000570;
+#[doc = " U+0541 ARMENIAN CAPITAL LETTER JA"]
+pub const XK_Armenian_DZA: Keysym = 0x1000541;
+#[doc = " U+0571 ARMENIAN SMALL LETTER JA"]
+pub const XK_Armenian_dza: Keysym = 0x1000571;
+#[doc = " U+0542 ARMENIAN CAPITAL LETTER GHAD"]
+pub const XK_Armenian_GHAT: Keysym = 0x1000542;
+#[doc = " U+0572 ARMENIAN SMALL LETTER GHAD"]
+pub const XK_Armenian_ghat: Keysym = 0x1000572;
+#[doc = " U+0543 ARMENIAN CAPITAL LETTER CHEH"]
+pub const XK_Armenian_TCHE: Keysym = 0x1000543;
+#[doc = " U+0573 ARMENIAN SMALL LETTER CHEH"]
+pub const XK_Armenian_tche: Keysym = 0x1000573;
+#[doc = " U+0544 ARMENIAN CAPITAL LETTER MEN"]
+pub const XK_Armenian_MEN: Keysym = 0x1000544;
+#[doc = " U+0574 ARMENIAN SMALL LETTER MEN"]
+pub const XK_Armenian_men: Keysym = 0x1000574;
+#[doc = " U+0545 ARMENIAN CAPITAL LETTER YI"]
+pub const XK_Armenian_HI: Keysym = 0x1000545;
+#[doc = " U+0575 ARMENIAN SMALL LETTER YI"]
+pub const XK_Armenian_hi: Keysym = 0x1000575;
+#[doc = " U+0546 ARMENIAN CAPITAL LETTER NOW"]
+pub const XK_Armenian_NU: Keysym = 0x1000546;
+#[doc = " U+0576 ARMENIAN SMALL LETTER NOW"]
+pub const XK_Armenian_nu: Keysym = 0x1000576;
+#[doc = " U+0547 ARMENIAN CAPITAL LETTER SHA"]
+pub const XK_Armenian_SHA: Keysym = 0x1000547;
+#[doc = " U+0577 ARMENIAN SMALL LETTER SHA"]
+pub const XK_Armenian_sha: Keysym = 0x1000577;
+#[doc = " U+0548 ARMENIAN CAPITAL LETTER VO"]
+pub const XK_Armenian_VO: Keysym = 0x1000548;
+#[doc = " U+0578 ARMENIAN SMALL LETTER VO"]
+pub const XK_Armenian_vo: Keysym = 0x1000578;
+#[doc = " U+0549 ARMENIAN CAPITAL LETTER CHA"]
+pub const XK_Armenian_CHA: Keysym = 0x1000549;
+#[doc = " U+0579 ARMENIAN SMALL LETTER CHA"]
+pub const XK_Armenian_cha: Keysym = 0x1000579;
+#[doc = " U+054A ARMENIAN CAPITAL LETTER PEH"]
+pub const XK_Armenian_PE: Keysym = 0x100054a;
+#[doc = " U+057A ARMENIAN SMALL LETTER PEH"]
+pub const XK_Armenian_pe: Keysym = 0x100057a;
+#[doc = " U+054B ARMENIAN CAPITAL LETTER JHEH"]
+pub const XK_Armenian_JE: Keysym = 0x100054b;
+#[doc = " U+057B ARMENIAN SMALL LETTER JHEH"]
+pub const XK_Armenian_je: Keysym = 0x100057b;
+#[doc = " U+054C ARMENIAN CAPITAL LETTER RA"]
+pub const XK_Armenian_RA: Keysym = 0x100054c;
+#[doc = " U+057C ARMENIAN SMALL LETTER RA"]
+pub const XK_Armenian_ra: Keysym = 0x100057c;
+#[doc = " U+054D ARMENIAN CAPITAL LETTER SEH"]
+pub const XK_Armenian_SE: Keysym = 0x100054d;
+#[doc = " U+057D ARMENIAN SMALL LETTER SEH"]
+pub const XK_Armenian_se: Keysym = 0x100057d;
+#[doc = " U+054E ARMENIAN CAPITAL LETTER VEW"]
+pub const XK_Armenian_VEV: Keysym = 0x100054e;
+#[doc = " U+057E ARMENIAN SMALL LETTER VEW"]
+pub const XK_Armenian_vev: Keysym = 0x100057e;
+#[doc = " U+054F ARMENIAN CAPITAL LETTER TIWN"]
+pub const XK_Armenian_TYUN: Keysym = 0x100054f;
+#[doc = " U+057F ARMENIAN SMALL LETTER TIWN"]
+pub const XK_Armenian_tyun: Keysym = 0x100057f;
+#[doc = " U+0550 ARMENIAN CAPITAL LETTER REH"]
+pub const XK_Armenian_RE: Keysym = 0x1000550;
+#[doc = " U+0580 ARMENIAN SMALL LETTER REH"]
+pub const XK_Armenian_re: Keysym = 0x1000580;
+#[doc = " U+0551 ARMENIAN CAPITAL LETTER CO"]
+pub const XK_Armenian_TSO: Keysym = 0x1000551;
+#[doc = " U+0581 ARMENIAN SMALL LETTER CO"]
+pub const XK_Armenian_tso: Keysym = 0x1000581;
+#[doc = " U+0552 ARMENIAN CAPITAL LETTER YIWN"]
+pub const XK_Armenian_VYUN: Keysym = 0x1000552;
+#[doc = " U+0582 ARMENIAN SMALL LETTER YIWN"]
+pub const XK_Armenian_vyun: Keysym = 0x1000582;
+#[doc = " U+0553 ARMENIAN CAPITAL LETTER PIWR"]
+pub const XK_Armenian_PYUR: Keysym = 0x1000553;
+#[doc = " U+0583 ARMENIAN SMALL LETTER PIWR"]
+pub const XK_Armenian_pyur: Keysym = 0x1000583;
+#[doc = " U+0554 ARMENIAN CAPITAL LETTER KEH"]
+pub const XK_Armenian_KE: Keysym = 0x1000554;
+#[doc = " U+0584 ARMENIAN SMALL LETTER KEH"]
+pub const XK_Armenian_ke: Keysym = 0x1000584;
+#[doc = " U+0555 ARMENIAN CAPITAL LETTER OH"]
+pub const XK_Armenian_O: Keysym = 0x1000555;
+#[doc = " U+0585 ARMENIAN SMALL LETTER OH"]
+pub const XK_Armenian_o: Keysym = 0x1000585;
+#[doc = " U+0556 ARMENIAN CAPITAL LETTER FEH"]
+pub const XK_Armenian_FE: Keysym = 0x1000556;
+#[doc = " U+0586 ARMENIAN SMALL LETTER FEH"]
+pub const XK_Armenian_fe: Keysym = 0x1000586;
+#[doc = " U+055A ARMENIAN APOSTROPHE"]
+pub const XK_Armenian_apostrophe: Keysym = 0x100055a;
+#[doc = " U+10D0 GEORGIAN LETTER AN"]
+pub const XK_Georgian_an: Keysym = 0x10010d0;
+#[doc = " U+10D1 GEORGIAN LETTER BAN"]
+pub const XK_Georgian_ban: Keysym = 0x10010d1;
+#[doc = " U+10D2 GEORGIAN LETTER GAN"]
+pub const XK_Georgian_gan: Keysym = 0x10010d2;
+#[doc = " U+10D3 GEORGIAN LETTER DON"]
+pub const XK_Georgian_don: Keysym = 0x10010d3;
+#[doc = " U+10D4 GEORGIAN LETTER EN"]
+pub const XK_Georgian_en: Keysym = 0x10010d4;
+#[doc = " U+10D5 GEORGIAN LETTER VIN"]
+pub const XK_Georgian_vin: Keysym = 0x10010d5;
+#[doc = " U+10D6 GEORGIAN LETTER ZEN"]
+pub const XK_Georgian_zen: Keysym = 0x10010d6;
+#[doc = " U+10D7 GEORGIAN LETTER TAN"]
+pub const XK_Georgian_tan: Keysym = 0x10010d7;
+#[doc = " U+10D8 GEORGIAN LETTER IN"]
+pub const XK_Georgian_in: Keysym = 0x10010d8;
+#[doc = " U+10D9 GEORGIAN LETTER KAN"]
+pub const XK_Georgian_kan: Keysym = 0x10010d9;
+#[doc = " U+10DA GEORGIAN LETTER LAS"]
+pub const XK_Georgian_las: Keysym = 0x10010da;
+#[doc = " U+10DB GEORGIAN LETTER MAN"]
+pub const XK_Georgian_man: Keysym = 0x10010db;
+#[doc = " U+10DC GEORGIAN LETTER NAR"]
+pub const XK_Georgian_nar: Keysym = 0x10010dc;
+#[doc = " U+10DD GEORGIAN LETTER ON"]
+pub const XK_Georgian_on: Keysym = 0x10010dd;
+#[doc = " U+10DE GEORGIAN LETTER PAR"]
+pub const XK_Georgian_par: Keysym = 0x10010de;
+#[doc = " U+10DF GEORGIAN LETTER ZHAR"]
+pub const XK_Georgian_zhar: Keysym = 0x10010df;
+#[doc = " U+10E0 GEORGIAN LETTER RAE"]
+pub const XK_Georgian_rae: Keysym = 0x10010e0;
+#[doc = " U+10E1 GEORGIAN LETTER SAN"]
+pub const XK_Georgian_san: Keysym = 0x10010e1;
+#[doc = " U+10E2 GEORGIAN LETTER TAR"]
+pub const XK_Georgian_tar: Keysym = 0x10010e2;
+#[doc = " U+10E3 GEORGIAN LETTER UN"]
+pub const XK_Georgian_un: Keysym = 0x10010e3;
+#[doc = " U+10E4 GEORGIAN LETTER PHAR"]
+pub const XK_Georgian_phar: Keysym = 0x10010e4;
+#[doc = " U+10E5 GEORGIAN LETTER KHAR"]
+pub const XK_Georgian_khar: Keysym = 0x10010e5;
+#[doc = " U+10E6 GEORGIAN LETTER GHAN"]
+pub const XK_Georgian_ghan: Keysym = 0x10010e6;
+#[doc = " U+10E7 GEORGIAN LETTER QAR"]
+pub const XK_Georgian_qar: Keysym = 0x10010e7;
+#[doc = " U+10E8 GEORGIAN LETTER SHIN"]
+pub const XK_Georgian_shin: Keysym = 0x10010e8;
+#[doc = " U+10E9 GEORGIAN LETTER CHIN"]
+pub const XK_Georgian_chin: Keysym = 0x10010e9;
+#[doc = " U+10EA GEORGIAN LETTER CAN"]
+pub const XK_Georgian_can: Keysym = 0x10010ea;
+#[doc = " U+10EB GEORGIAN LETTER JIL"]
+pub const XK_Georgian_jil: Keysym = 0x10010eb;
+#[doc = " U+10EC GEORGIAN LETTER CIL"]
+pub const XK_Georgian_cil: Keysym = 0x10010ec;
+#[doc = " U+10ED GEORGIAN LETTER CHAR"]
+pub const XK_Georgian_char: Keysym = 0x10010ed;
+#[doc = " U+10EE GEORGIAN LETTER XAN"]
+pub const XK_Georgian_xan: Keysym = 0x10010ee;
+#[doc = " U+10EF GEORGIAN LETTER JHAN"]
+pub const XK_Georgian_jhan: Keysym = 0x10010ef;
+#[doc = " U+10F0 GEORGIAN LETTER HAE"]
+pub const XK_Georgian_hae: Keysym = 0x10010f0;
+#[doc = " U+10F1 GEORGIAN LETTER HE"]
+pub const XK_Georgian_he: Keysym = 0x10010f1;
+#[doc = " U+10F2 GEORGIAN LETTER HIE"]
+pub const XK_Georgian_hie: Keysym = 0x10010f2;
+#[doc = " U+10F3 GEORGIAN LETTER WE"]
+pub const XK_Georgian_we: Keysym = 0x10010f3;
+#[doc = " U+10F4 GEORGIAN LETTER HAR"]
+pub const XK_Georgian_har: Keysym = 0x10010f4;
+#[doc = " U+10F5 GEORGIAN LETTER HOE"]
+pub const XK_Georgian_hoe: Keysym = 0x10010f5;
+#[doc = " U+10F6 GEORGIAN LETTER FI"]
+pub const XK_Georgian_fi: Keysym = 0x10010f6;
+#[doc = " U+1E8A LATIN CAPITAL LETTER X WITH DOT ABOVE"]
+pub const XK_Xabovedot: Keysym = 0x1001e8a;
+#[doc = " U+012C LATIN CAPITAL LETTER I WITH BREVE"]
+pub const XK_Ibreve: Keysym = 0x100012c;
+#[doc = " U+01B5 LATIN CAPITAL LETTER Z WITH STROKE"]
+pub const XK_Zstroke: Keysym = 0x10001b5;
+#[doc = " U+01E6 LATIN CAPITAL LETTER G WITH CARON"]
+pub const XK_Gcaron: Keysym = 0x10001e6;
+#[doc = " U+01D1 LATIN CAPITAL LETTER O WITH CARON"]
+pub const XK_Ocaron: Keysym = 0x10001d1;
+#[doc = " U+019F LATIN CAPITAL LETTER O WITH MIDDLE TILDE"]
+pub const XK_Obarred: Keysym = 0x100019f;
+#[doc = " U+1E8B LATIN SMALL LETTER X WITH DOT ABOVE"]
+pub const XK_xabovedot: Keysym = 0x1001e8b;
+#[doc = " U+012D LATIN SMALL LETTER I WITH BREVE"]
+pub const XK_ibreve: Keysym = 0x100012d;
+#[doc = " U+01B6 LATIN SMALL LETTER Z WITH STROKE"]
+pub const XK_zstroke: Keysym = 0x10001b6;
+#[doc = " U+01E7 LATIN SMALL LETTER G WITH CARON"]
+pub const XK_gcaron: Keysym = 0x10001e7;
+#[doc = " U+01D2 LATIN SMALL LETTER O WITH CARON"]
+pub const XK_ocaron: Keysym = 0x10001d2;
+#[doc = " U+0275 LATIN SMALL LETTER BARRED O"]
+pub const XK_obarred: Keysym = 0x1000275;
+#[doc = " U+018F LATIN CAPITAL LETTER SCHWA"]
+pub const XK_SCHWA: Keysym = 0x100018f;
+#[doc = " U+0259 LATIN SMALL LETTER SCHWA"]
+pub const XK_schwa: Keysym = 0x1000259;
+#[doc = " U+01B7 LATIN CAPITAL LETTER EZH"]
+pub const XK_EZH: Keysym = 0x10001b7;
+#[doc = " U+0292 LATIN SMALL LETTER EZH"]
+pub const XK_ezh: Keysym = 0x1000292;
+#[doc = " U+1E36 LATIN CAPITAL LETTER L WITH DOT BELOW"]
+pub const XK_Lbelowdot: Keysym = 0x1001e36;
+#[doc = " U+1E37 LATIN SMALL LETTER L WITH DOT BELOW"]
+pub const XK_lbelowdot: Keysym = 0x1001e37;
+#[doc = " U+1EA0 LATIN CAPITAL LETTER A WITH DOT BELOW"]
+pub const XK_Abelowdot: Keysym = 0x1001ea0;
+#[doc = " U+1EA1 LATIN SMALL LETTER A WITH DOT BELOW"]
+pub const XK_abelowdot: Keysym = 0x1001ea1;
+#[doc = " U+1EA2 LATIN CAPITAL LETTER A WITH HOOK ABOVE"]
+pub const XK_Ahook: Keysym = 0x1001ea2;
+#[doc = " U+1EA3 LATIN SMALL LETTER A WITH HOOK ABOVE"]
+pub const XK_ahook: Keysym = 0x1001ea3;
+#[doc = " U+1EA4 LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND ACUTE"]
+pub const XK_Acircumflexacute: Keysym = 0x1001ea4;
+#[doc = " U+1EA5 LATIN SMALL LETTER A WITH CIRCUMFLEX AND ACUTE"]
+pub const XK_acircumflexacute: Keysym = 0x1001ea5;
+#[doc = " U+1EA6 LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND GRAVE"]
+pub const XK_Acircumflexgrave: Keysym = 0x1001ea6;
+#[doc = " U+1EA7 LATIN SMALL LETTER A WITH CIRCUMFLEX AND GRAVE"]
+pub const XK_acircumflexgrave: Keysym = 0x1001ea7;
+#[doc = " U+1EA8 LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE"]
+pub const XK_Acircumflexhook: Keysym = 0x1001ea8;
+#[doc = " U+1EA9 LATIN SMALL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE"]
+pub const XK_acircumflexhook: Keysym = 0x1001ea9;
+#[doc = " U+1EAA LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND TILDE"]
+pub const XK_Acircumflextilde: Keysym = 0x1001eaa;
+#[doc = " U+1EAB LATIN SMALL LETTER A WITH CIRCUMFLEX AND TILDE"]
+pub const XK_acircumflextilde: Keysym = 0x1001eab;
+#[doc = " U+1EAC LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND DOT BELOW"]
+pub const XK_Acircumflexbelowdot: Keysym = 0x1001eac;
+#[doc = " U+1EAD LATIN SMALL LETTER A WITH CIRCUMFLEX AND DOT BELOW"]
+pub const XK_acircumflexbelowdot: Keysym = 0x1001ead;
+#[doc = " U+1EAE LATIN CAPITAL LETTER A WITH BREVE AND ACUTE"]
+pub const XK_Abreveacute: Keysym = 0x1001eae;
+#[doc = " U+1EAF LATIN SMALL LETTER A WITH BREVE AND ACUTE"]
+pub const XK_abreveacute: Keysym = 0x1001eaf;
+#[doc = " U+1EB0 LATIN CAPITAL LETTER A WITH BREVE AND GRAVE"]
+pub const XK_Abrevegrave: Keysym = 0x1001eb0;
+#[doc = " U+1EB1 LATIN SMALL LETTER A WITH BREVE AND GRAVE"]
+pub const XK_abrevegrave: Keysym = 0x1001eb1;
+#[doc = " U+1EB2 LATIN CAPITAL LETTER A WITH BREVE AND HOOK ABOVE"]
+pub const XK_Abrevehook: Keysym = 0x1001eb2;
+#[doc = " U+1EB3 LATIN SMALL LETTER A WITH BREVE AND HOOK ABOVE"]
+pub const XK_abrevehook: Keysym = 0x1001eb3;
+#[doc = " U+1EB4 LATIN CAPITAL LETTER A WITH BREVE AND TILDE"]
+pub const XK_Abrevetilde: Keysym = 0x1001eb4;
+#[doc = " U+1EB5 LATIN SMALL LETTER A WITH BREVE AND TILDE"]
+pub const XK_abrevetilde: Keysym = 0x1001eb5;
+#[doc = " U+1EB6 LATIN CAPITAL LETTER A WITH BREVE AND DOT BELOW"]
+pub const XK_Abrevebelowdot: Keysym = 0x1001eb6;
+#[doc = " U+1EB7 LATIN SMALL LETTER A WITH BREVE AND DOT BELOW"]
+pub const XK_abrevebelowdot: Keysym = 0x1001eb7;
+#[doc = " U+1EB8 LATIN CAPITAL LETTER E WITH DOT BELOW"]
+pub const XK_Ebelowdot: Keysym = 0x1001eb8;
+#[doc = " U+1EB9 LATIN SMALL LETTER E WITH DOT BELOW"]
+pub const XK_ebelowdot: Keysym = 0x1001eb9;
+#[doc = " U+1EBA LATIN CAPITAL LETTER E WITH HOOK ABOVE"]
+pub const XK_Ehook: Keysym = 0x1001eba;
+#[doc = " U+1EBB LATIN SMALL LETTER E WITH HOOK ABOVE"]
+pub const XK_ehook: Keysym = 0x1001ebb;
+#[doc = " U+1EBC LATIN CAPITAL LETTER E WITH TILDE"]
+pub const XK_Etilde: Keysym = 0x1001ebc;
+#[doc = " U+1EBD LATIN SMALL LETTER E WITH TILDE"]
+pub const XK_etilde: Keysym = 0x1001ebd;
+#[doc = " U+1EBE LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND ACUTE"]
+pub const XK_Ecircumflexacute: Keysym = 0x1001ebe;
+#[doc = " U+1EBF LATIN SMALL LETTER E WITH CIRCUMFLEX AND ACUTE"]
+pub const XK_ecircumflexacute: Keysym = 0x1001ebf;
+#[doc = " U+1EC0 LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND GRAVE"]
+pub const XK_Ecircumflexgrave: Keysym = 0x1001ec0;
+#[doc = " U+1EC1 LATIN SMALL LETTER E WITH CIRCUMFLEX AND GRAVE"]
+pub const XK_ecircumflexgrave: Keysym = 0x1001ec1;
+#[doc = " U+1EC2 LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE"]
+pub const XK_Ecircumflexhook: Keysym = 0x1001ec2;
+#[doc = " U+1EC3 LATIN SMALL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE"]
+pub const XK_ecircumflexhook: Keysym = 0x1001ec3;
+#[doc = " U+1EC4 LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND TILDE"]
+pub const XK_Ecircumflextilde: Keysym = 0x1001ec4;
+#[doc = " U+1EC5 LATIN SMALL LETTER E WITH CIRCUMFLEX AND TILDE"]
+pub const XK_ecircumflextilde: Keysym = 0x1001ec5;
+#[doc = " U+1EC6 LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND DOT BELOW"]
+pub const XK_Ecircumflexbelowdot: Keysym = 0x1001ec6;
+#[doc = " U+1EC7 LATIN SMALL LETTER E WITH CIRCUMFLEX AND DOT BELOW"]
+pub const XK_ecircumflexbelowdot: Keysym = 0x1001ec7;
+#[doc = " U+1EC8 LATIN CAPITAL LETTER I WITH HOOK ABOVE"]
+pub const XK_Ihook: Keysym = 0x1001ec8;
+#[doc = " U+1EC9 LATIN SMALL LETTER I WITH HOOK ABOVE"]
+pub const XK_ihook: Keysym = 0x1001ec9;
+#[doc = " U+1ECA LATIN CAPITAL LETTER I WITH DOT BELOW"]
+pub const XK_Ibelowdot: Keysym = 0x1001eca;
+#[doc = " U+1ECB LATIN SMALL LETTER I WITH DOT BELOW"]
+pub const XK_ibelowdot: Keysym = 0x1001ecb;
+#[doc = " U+1ECC LATIN CAPITAL LETTER O WITH DOT BELOW"]
+pub const XK_Obelowdot: Keysym = 0x1001ecc;
+#[doc = " U+1ECD LATIN SMALL LETTER O WITH DOT BELOW"]
+pub const XK_obelowdot: Keysym = 0x1001ecd;
+#[doc = " U+1ECE LATIN CAPITAL LETTER O WITH HOOK ABOVE"]
+pub const XK_Ohook: Keysym = 0x1001ece;
+#[doc = " U+1ECF LATIN SMALL LETTER O WITH HOOK ABOVE"]
+pub const XK_ohook: Keysym = 0x1001ecf;
+#[doc = " U+1ED0 LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND ACUTE"]
+pub const XK_Ocircumflexacute: Keysym = 0x1001ed0;
+#[doc = " U+1ED1 LATIN SMALL LETTER O WITH CIRCUMFLEX AND ACUTE"]
+pub const XK_ocircumflexacute: Keysym = 0x1001ed1;
+#[doc = " U+1ED2 LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND GRAVE"]
+pub const XK_Ocircumflexgrave: Keysym = 0x1001ed2;
+#[doc = " U+1ED3 LATIN SMALL LETTER O WITH CIRCUMFLEX AND GRAVE"]
+pub const XK_ocircumflexgrave: Keysym = 0x1001ed3;
+#[doc = " U+1ED4 LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE"]
+pub const XK_Ocircumflexhook: Keysym = 0x1001ed4;
+#[doc = " U+1ED5 LATIN SMALL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE"]
+pub const XK_ocircumflexhook: Keysym = 0x1001ed5;
+#[doc = " U+1ED6 LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND TILDE"]
+pub const XK_Ocircumflextilde: Keysym = 0x1001ed6;
+#[doc = " U+1ED7 LATIN SMALL LETTER O WITH CIRCUMFLEX AND TILDE"]
+pub const XK_ocircumflextilde: Keysym = 0x1001ed7;
+#[doc = " U+1ED8 LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND DOT BELOW"]
+pub const XK_Ocircumflexbelowdot: Keysym = 0x1001ed8;
+#[doc = " U+1ED9 LATIN SMALL LETTER O WITH CIRCUMFLEX AND DOT BELOW"]
+pub const XK_ocircumflexbelowdot: Keysym = 0x1001ed9;
+#[doc = " U+1EDA LATIN CAPITAL LETTER O WITH HORN AND ACUTE"]
+pub const XK_Ohornacute: Keysym = 0x1001eda;
+#[doc = " U+1EDB LATIN SMALL LETTER O WITH HORN AND ACUTE"]
+pub const XK_ohornacute: Keysym = 0x1001edb;
+#[doc = " U+1EDC LATIN CAPITAL LETTER O WITH HORN AND GRAVE"]
+pub const XK_Ohorngrave: Keysym = 0x1001edc;
+#[doc = " U+1EDD LATIN SMALL LETTER O WITH HORN AND GRAVE"]
+pub const XK_ohorngrave: Keysym = 0x1001edd;
+#[doc = " U+1EDE LATIN CAPITAL LETTER O WITH HORN AND HOOK ABOVE"]
+pub const XK_Ohornhook: Keysym = 0x1001ede;
+#[doc = " U+1EDF LATIN SMALL LETTER O WITH HORN AND HOOK ABOVE"]
+pub const XK_ohornhook: Keysym = 0x1001edf;
+#[doc = " U+1EE0 LATIN CAPITAL LETTER O WITH HORN AND TILDE"]
+pub const XK_Ohorntilde: Keysym = 0x1001ee0;
+#[doc = " U+1EE1 LATIN SMALL LETTER O WITH HORN AND TILDE"]
+pub const XK_ohorntilde: Keysym = 0x1001ee1;
+#[doc = " U+1EE2 LATIN CAPITAL LETTER O WITH HORN AND DOT BELOW"]
+pub const XK_Ohornbelowdot: Keysym = 0x1001ee2;
+#[doc = " U+1EE3 LATIN SMALL LETTER O WITH HORN AND DOT BELOW"]
+pub const XK_ohornbelowdot: Keysym = 0x1001ee3;
+#[doc = " U+1EE4 LATIN CAPITAL LETTER U WITH DOT BELOW"]
+pub const XK_Ubelowdot: Keysym = 0x1001ee4;
+#[doc = " U+1EE5 LATIN SMALL LETTER U WITH DOT BELOW"]
+pub const XK_ubelowdot: Keysym = 0x1001ee5;
+#[doc = " U+1EE6 LATIN CAPITAL LETTER U WITH HOOK ABOVE"]
+pub const XK_Uhook: Keysym = 0x1001ee6;
+#[doc = " U+1EE7 LATIN SMALL LETTER U WITH HOOK ABOVE"]
+pub const XK_uhook: Keysym = 0x1001ee7;
+#[doc = " U+1EE8 LATIN CAPITAL LETTER U WITH HORN AND ACUTE"]
+pub const XK_Uhornacute: Keysym = 0x1001ee8;
+#[doc = " U+1EE9 LATIN SMALL LETTER U WITH HORN AND ACUTE"]
+pub const XK_uhornacute: Keysym = 0x1001ee9;
+#[doc = " U+1EEA LATIN CAPITAL LETTER U WITH HORN AND GRAVE"]
+pub const XK_Uhorngrave: Keysym = 0x1001eea;
+#[doc = " U+1EEB LATIN SMALL LETTER U WITH HORN AND GRAVE"]
+pub const XK_uhorngrave: Keysym = 0x1001eeb;
+#[doc = " U+1EEC LATIN CAPITAL LETTER U WITH HORN AND HOOK ABOVE"]
+pub const XK_Uhornhook: Keysym = 0x1001eec;
+#[doc = " U+1EED LATIN SMALL LETTER U WITH HORN AND HOOK ABOVE"]
+pub const XK_uhornhook: Keysym = 0x1001eed;
+#[doc = " U+1EEE LATIN CAPITAL LETTER U WITH HORN AND TILDE"]
+pub const XK_Uhorntilde: Keysym = 0x1001eee;
+#[doc = " U+1EEF LATIN SMALL LETTER U WITH HORN AND TILDE"]
+pub const XK_uhorntilde: Keysym = 0x1001eef;
+#[doc = " U+1EF0 LATIN CAPITAL LETTER U WITH HORN AND DOT BELOW"]
+pub const XK_Uhornbelowdot: Keysym = 0x1001ef0;
+#[doc = " U+1EF1 LATIN SMALL LETTER U WITH HORN AND DOT BELOW"]
+pub const XK_uhornbelowdot: Keysym = 0x1001ef1;
+#[doc = " U+1EF4 LATIN CAPITAL LETTER Y WITH DOT BELOW"]
+pub const XK_Ybelowdot: Keysym = 0x1001ef4;
+#[doc = " U+1EF5 LATIN SMALL LETTER Y WITH DOT BELOW"]
+pub const XK_ybelowdot: Keysym = 0x1001ef5;
+#[doc = " U+1EF6 LATIN CAPITAL LETTER Y WITH HOOK ABOVE"]
+pub const XK_Yhook: Keysym = 0x1001ef6;
+#[doc = " U+1EF7 LATIN SMALL LETTER Y WITH HOOK ABOVE"]
+pub const XK_yhook: Keysym = 0x1001ef7;
+#[doc = " U+1EF8 LATIN CAPITAL LETTER Y WITH TILDE"]
+pub const XK_Ytilde: Keysym = 0x1001ef8;
+#[doc = " U+1EF9 LATIN SMALL LETTER Y WITH TILDE"]
+pub const XK_ytilde: Keysym = 0x1001ef9;
+#[doc = " U+01A0 LATIN CAPITAL LETTER O WITH HORN"]
+pub const XK_Ohorn: Keysym = 0x10001a0;
+#[doc = " U+01A1 LATIN SMALL LETTER O WITH HORN"]
+pub const XK_ohorn: Keysym = 0x10001a1;
+#[doc = " U+01AF LATIN CAPITAL LETTER U WITH HORN"]
+pub const XK_Uhorn: Keysym = 0x10001af;
+#[doc = " U+01B0 LATIN SMALL LETTER U WITH HORN"]
+pub const XK_uhorn: Keysym = 0x10001b0;
+#[doc = " U+0303 COMBINING TILDE"]
+pub const XK_combining_tilde: Keysym = 0x1000303;
+#[doc = " U+0300 COMBINING GRAVE ACCENT"]
+pub const XK_combining_grave: Keysym = 0x1000300;
+#[doc = " U+0301 COMBINING ACUTE ACCENT"]
+pub const XK_combining_acute: Keysym = 0x1000301;
+#[doc = " U+0309 COMBINING HOOK ABOVE"]
+pub const XK_combining_hook: Keysym = 0x1000309;
+#[doc = " U+0323 COMBINING DOT BELOW"]
+pub const XK_combining_belowdot: Keysym = 0x1000323;
+#[doc = " U+20A0 EURO-CURRENCY SIGN"]
+pub const XK_EcuSign: Keysym = 0x10020a0;
+#[doc = " U+20A1 COLON SIGN"]
+pub const XK_ColonSign: Keysym = 0x10020a1;
+#[doc = " U+20A2 CRUZEIRO SIGN"]
+pub const XK_CruzeiroSign: Keysym = 0x10020a2;
+#[doc = " U+20A3 FRENCH FRANC SIGN"]
+pub const XK_FFrancSign: Keysym = 0x10020a3;
+#[doc = " U+20A4 LIRA SIGN"]
+pub const XK_LiraSign: Keysym = 0x10020a4;
+#[doc = " U+20A5 MILL SIGN"]
+pub const XK_MillSign: Keysym = 0x10020a5;
+#[doc = " U+20A6 NAIRA SIGN"]
+pub const XK_NairaSign: Keysym = 0x10020a6;
+#[doc = " U+20A7 PESETA SIGN"]
+pub const XK_PesetaSign: Keysym = 0x10020a7;
+#[doc = " U+20A8 RUPEE SIGN"]
+pub const XK_RupeeSign: Keysym = 0x10020a8;
+#[doc = " U+20A9 WON SIGN"]
+pub const XK_WonSign: Keysym = 0x10020a9;
+#[doc = " U+20AA NEW SHEQEL SIGN"]
+pub const XK_NewSheqelSign: Keysym = 0x10020aa;
+#[doc = " U+20AB DONG SIGN"]
+pub const XK_DongSign: Keysym = 0x10020ab;
+#[doc = " U+20AC EURO SIGN"]
+pub const XK_EuroSign: Keysym = 0x20ac;
+#[doc = " U+2070 SUPERSCRIPT ZERO"]
+pub const XK_zerosuperior: Keysym = 0x1002070;
+#[doc = " U+2074 SUPERSCRIPT FOUR"]
+pub const XK_foursuperior: Keysym = 0x1002074;
+#[doc = " U+2075 SUPERSCRIPT FIVE"]
+pub const XK_fivesuperior: Keysym = 0x1002075;
+#[doc = " U+2076 SUPERSCRIPT SIX"]
+pub const XK_sixsuperior: Keysym = 0x1002076;
+#[doc = " U+2077 SUPERSCRIPT SEVEN"]
+pub const XK_sevensuperior: Keysym = 0x1002077;
+#[doc = " U+2078 SUPERSCRIPT EIGHT"]
+pub const XK_eightsuperior: Keysym = 0x1002078;
+#[doc = " U+2079 SUPERSCRIPT NINE"]
+pub const XK_ninesuperior: Keysym = 0x1002079;
+#[doc = " U+2080 SUBSCRIPT ZERO"]
+pub const XK_zerosubscript: Keysym = 0x1002080;
+#[doc = " U+2081 SUBSCRIPT ONE"]
+pub const XK_onesubscript: Keysym = 0x1002081;
+#[doc = " U+2082 SUBSCRIPT TWO"]
+pub const XK_twosubscript: Keysym = 0x1002082;
+#[doc = " U+2083 SUBSCRIPT THREE"]
+pub const XK_threesubscript: Keysym = 0x1002083;
+#[doc = " U+2084 SUBSCRIPT FOUR"]
+pub const XK_foursubscript: Keysym = 0x1002084;
+#[doc = " U+2085 SUBSCRIPT FIVE"]
+pub const XK_fivesubscript: Keysym = 0x1002085;
+#[doc = " U+2086 SUBSCRIPT SIX"]
+pub const XK_sixsubscript: Keysym = 0x1002086;
+#[doc = " U+2087 SUBSCRIPT SEVEN"]
+pub const XK_sevensubscript: Keysym = 0x1002087;
+#[doc = " U+2088 SUBSCRIPT EIGHT"]
+pub const XK_eightsubscript: Keysym = 0x1002088;
+#[doc = " U+2089 SUBSCRIPT NINE"]
+pub const XK_ninesubscript: Keysym = 0x1002089;
+#[doc = " U+2202 PARTIAL DIFFERENTIAL"]
+pub const XK_partdifferential: Keysym = 0x1002202;
+#[doc = " U+2205 NULL SET"]
+pub const XK_emptyset: Keysym = 0x1002205;
+#[doc = " U+2208 ELEMENT OF"]
+pub const XK_elementof: Keysym = 0x1002208;
+#[doc = " U+2209 NOT AN ELEMENT OF"]
+pub const XK_notelementof: Keysym = 0x1002209;
+#[doc = " U+220B CONTAINS AS MEMBER"]
+pub const XK_containsas: Keysym = 0x100220b;
+#[doc = " U+221A SQUARE ROOT"]
+pub const XK_squareroot: Keysym = 0x100221a;
+#[doc = " U+221B CUBE ROOT"]
+pub const XK_cuberoot: Keysym = 0x100221b;
+#[doc = " U+221C FOURTH ROOT"]
+pub const XK_fourthroot: Keysym = 0x100221c;
+#[doc = " U+222C DOUBLE INTEGRAL"]
+pub const XK_dintegral: Keysym = 0x100222c;
+#[doc = " U+222D TRIPLE INTEGRAL"]
+pub const XK_tintegral: Keysym = 0x100222d;
+#[doc = " U+2235 BECAUSE"]
+pub const XK_because: Keysym = 0x1002235;
+#[doc = " (U+2248 ALMOST EQUAL TO)"]
+pub const XK_approxeq: Keysym = 0x1002248;
+#[doc = " (U+2247 NEITHER APPROXIMATELY NOR ACTUALLY EQUAL TO)"]
+pub const XK_notapproxeq: Keysym = 0x1002247;
+#[doc = " U+2262 NOT IDENTICAL TO"]
+pub const XK_notidentical: Keysym = 0x1002262;
+#[doc = " U+2263 STRICTLY EQUIVALENT TO"]
+pub const XK_stricteq: Keysym = 0x1002263;
+pub const XK_braille_dot_1: Keysym = 0xfff1;
+pub const XK_braille_dot_2: Keysym = 0xfff2;
+pub const XK_braille_dot_3: Keysym = 0xfff3;
+pub const XK_braille_dot_4: Keysym = 0xfff4;
+pub const XK_braille_dot_5: Keysym = 0xfff5;
+pub const XK_braille_dot_6: Keysym = 0xfff6;
+pub const XK_braille_dot_7: Keysym = 0xfff7;
+pub const XK_braille_dot_8: Keysym = 0xfff8;
+pub const XK_braille_dot_9: Keysym = 0xfff9;
+pub const XK_braille_dot_10: Keysym = 0xfffa;
+#[doc = " U+2800 BRAILLE PATTERN BLANK"]
+pub const XK_braille_blank: Keysym = 0x1002800;
+#[doc = " U+2801 BRAILLE PATTERN DOTS-1"]
+pub const XK_braille_dots_1: Keysym = 0x1002801;
+#[doc = " U+2802 BRAILLE PATTERN DOTS-2"]
+pub const XK_braille_dots_2: Keysym = 0x1002802;
+#[doc = " U+2803 BRAILLE PATTERN DOTS-12"]
+pub const XK_braille_dots_12: Keysym = 0x1002803;
+#[doc = " U+2804 BRAILLE PATTERN DOTS-3"]
+pub const XK_braille_dots_3: Keysym = 0x1002804;
+#[doc = " U+2805 BRAILLE PATTERN DOTS-13"]
+pub const XK_braille_dots_13: Keysym = 0x1002805;
+#[doc = " U+2806 BRAILLE PATTERN DOTS-23"]
+pub const XK_braille_dots_23: Keysym = 0x1002806;
+#[doc = " U+2807 BRAILLE PATTERN DOTS-123"]
+pub const XK_braille_dots_123: Keysym = 0x1002807;
+#[doc = " U+2808 BRAILLE PATTERN DOTS-4"]
+pub const XK_braille_dots_4: Keysym = 0x1002808;
+#[doc = " U+2809 BRAILLE PATTERN DOTS-14"]
+pub const XK_braille_dots_14: Keysym = 0x1002809;
+#[doc = " U+280a BRAILLE PATTERN DOTS-24"]
+pub const XK_braille_dots_24: Keysym = 0x100280a;
+#[doc = " U+280b BRAILLE PATTERN DOTS-124"]
+pub const XK_braille_dots_124: Keysym = 0x100280b;
+#[doc = " U+280c BRAILLE PATTERN DOTS-34"]
+pub const XK_braille_dots_34: Keysym = 0x100280c;
+#[doc = " U+280d BRAILLE PATTERN DOTS-134"]
+pub const XK_braille_dots_134: Keysym = 0x100280d;
+#[doc = " U+280e BRAILLE PATTERN DOTS-234"]
+pub const XK_braille_dots_234: Keysym = 0x100280e;
+#[doc = " U+280f BRAILLE PATTERN DOTS-1234"]
+pub const XK_braille_dots_1234: Keysym = 0x100280f;
+#[doc = " U+2810 BRAILLE PATTERN DOTS-5"]
+pub const XK_braille_dots_5: Keysym = 0x1002810;
+#[doc = " U+2811 BRAILLE PATTERN DOTS-15"]
+pub const XK_braille_dots_15: Keysym = 0x1002811;
+#[doc = " U+2812 BRAILLE PATTERN DOTS-25"]
+pub const XK_braille_dots_25: Keysym = 0x1002812;
+#[doc = " U+2813 BRAILLE PATTERN DOTS-125"]
+pub const XK_braille_dots_125: Keysym = 0x1002813;
+#[doc = " U+2814 BRAILLE PATTERN DOTS-35"]
+pub const XK_braille_dots_35: Keysym = 0x1002814;
+#[doc = " U+2815 BRAILLE PATTERN DOTS-135"]
+pub const XK_braille_dots_135: Keysym = 0x1002815;
+#[doc = " U+2816 BRAILLE PATTERN DOTS-235"]
+pub const XK_braille_dots_235: Keysym = 0x1002816;
+#[doc = " U+2817 BRAILLE PATTERN DOTS-1235"]
+pub const XK_braille_dots_1235: Keysym = 0x1002817;
+#[doc = " U+2818 BRAILLE PATTERN DOTS-45"]
+pub const XK_braille_dots_45: Keysym = 0x1002818;
+#[doc = " U+2819 BRAILLE PATTERN DOTS-145"]
+pub const XK_braille_dots_145: Keysym = 0x1002819;
+#[doc = " U+281a BRAILLE PATTERN DOTS-245"]
+pub const XK_braille_dots_245: Keysym = 0x100281a;
+#[doc = " U+281b BRAILLE PATTERN DOTS-1245"]
+pub const XK_braille_dots_1245: Keysym = 0x100281b;
+#[doc = " U+281c BRAILLE PATTERN DOTS-345"]
+pub const XK_braille_dots_345: Keysym = 0x100281c;
+#[doc = " U+281d BRAILLE PATTERN DOTS-1345"]
+pub const XK_braille_dots_1345: Keysym = 0x100281d;
+#[doc = " U+281e BRAILLE PATTERN DOTS-2345"]
+pub const XK_braille_dots_2345: Keysym = 0x100281e;
+#[doc = " U+281f BRAILLE PATTERN DOTS-12345"]
+pub const XK_braille_dots_12345: Keysym = 0x100281f;
+#[doc = " U+2820 BRAILLE PATTERN DOTS-6"]
+pub const XK_braille_dots_6: Keysym = 0x1002820;
+#[doc = " U+2821 BRAILLE PATTERN DOTS-16"]
+pub const XK_braille_dots_16: Keysym = 0x1002821;
+#[doc = " U+2822 BRAILLE PATTERN DOTS-26"]
+pub const XK_braille_dots_26: Keysym = 0x1002822;
+#[doc = " U+2823 BRAILLE PATTERN DOTS-126"]
+pub const XK_braille_dots_126: Keysym = 0x1002823;
+#[doc = " U+2824 BRAILLE PATTERN DOTS-36"]
+pub const XK_braille_dots_36: Keysym = 0x1002824;
+#[doc = " U+2825 BRAILLE PATTERN DOTS-136"]
+pub const XK_braille_dots_136: Keysym = 0x1002825;
+#[doc = " U+2826 BRAILLE PATTERN DOTS-236"]
+pub const XK_braille_dots_236: Keysym = 0x1002826;
+#[doc = " U+2827 BRAILLE PATTERN DOTS-1236"]
+pub const XK_braille_dots_1236: Keysym = 0x1002827;
+#[doc = " U+2828 BRAILLE PATTERN DOTS-46"]
+pub const XK_braille_dots_46: Keysym = 0x1002828;
+#[doc = " U+2829 BRAILLE PATTERN DOTS-146"]
+pub const XK_braille_dots_146: Keysym = 0x1002829;
+#[doc = " U+282a BRAILLE PATTERN DOTS-246"]
+pub const XK_braille_dots_246: Keysym = 0x100282a;
+#[doc = " U+282b BRAILLE PATTERN DOTS-1246"]
+pub const XK_braille_dots_1246: Keysym = 0x100282b;
+#[doc = " U+282c BRAILLE PATTERN DOTS-346"]
+pub const XK_braille_dots_346: Keysym = 0x100282c;
+#[doc = " U+282d BRAILLE PATTERN DOTS-1346"]
+pub const XK_braille_dots_1346: Keysym = 0x100282d;
+#[doc = " U+282e BRAILLE PATTERN DOTS-2346"]
+pub const XK_braille_dots_2346: Keysym = 0x100282e;
+#[doc = " U+282f BRAILLE PATTERN DOTS-12346"]
+pub const XK_braille_dots_12346: Keysym = 0x100282f;
+#[doc = " U+2830 BRAILLE PATTERN DOTS-56"]
+pub const XK_braille_dots_56: Keysym = 0x1002830;
+#[doc = " U+2831 BRAILLE PATTERN DOTS-156"]
+pub const XK_braille_dots_156: Keysym = 0x1002831;
+#[doc = " U+2832 BRAILLE PATTERN DOTS-256"]
+pub const XK_braille_dots_256: Keysym = 0x1002832;
+#[doc = " U+2833 BRAILLE PATTERN DOTS-1256"]
+pub const XK_braille_dots_1256: Keysym = 0x1002833;
+#[doc = " U+2834 BRAILLE PATTERN DOTS-356"]
+pub const XK_braille_dots_356: Keysym = 0x1002834;
+#[doc = " U+2835 BRAILLE PATTERN DOTS-1356"]
+pub const XK_braille_dots_1356: Keysym = 0x1002835;
+#[doc = " U+2836 BRAILLE PATTERN DOTS-2356"]
+pub const XK_braille_dots_2356: Keysym = 0x1002836;
+#[doc = " U+2837 BRAILLE PATTERN DOTS-12356"]
+pub const XK_braille_dots_12356: Keysym = 0x1002837;
+#[doc = " U+2838 BRAILLE PATTERN DOTS-456"]
+pub const XK_braille_dots_456: Keysym = 0x1002838;
+#[doc = " U+2839 BRAILLE PATTERN DOTS-1456"]
+pub const XK_braille_dots_1456: Keysym = 0x1002839;
+#[doc = " U+283a BRAILLE PATTERN DOTS-2456"]
+pub const XK_braille_dots_2456: Keysym = 0x100283a;
+#[doc = " U+283b BRAILLE PATTERN DOTS-12456"]
+pub const XK_braille_dots_12456: Keysym = 0x100283b;
+#[doc = " U+283c BRAILLE PATTERN DOTS-3456"]
+pub const XK_braille_dots_3456: Keysym = 0x100283c;
+#[doc = " U+283d BRAILLE PATTERN DOTS-13456"]
+pub const XK_braille_dots_13456: Keysym = 0x100283d;
+#[doc = " U+283e BRAILLE PATTERN DOTS-23456"]
+pub const XK_braille_dots_23456: Keysym = 0x100283e;
+#[doc = " U+283f BRAILLE PATTERN DOTS-123456"]
+pub const XK_braille_dots_123456: Keysym = 0x100283f;
+#[doc = " U+2840 BRAILLE PATTERN DOTS-7"]
+pub const XK_braille_dots_7: Keysym = 0x1002840;
+#[doc = " U+2841 BRAILLE PATTERN DOTS-17"]
+pub const XK_braille_dots_17: Keysym = 0x1002841;
+#[doc = " U+2842 BRAILLE PATTERN DOTS-27"]
+pub const XK_braille_dots_27: Keysym = 0x1002842;
+#[doc = " U+2843 BRAILLE PATTERN DOTS-127"]
+pub const XK_braille_dots_127: Keysym = 0x1002843;
+#[doc = " U+2844 BRAILLE PATTERN DOTS-37"]
+pub const XK_braille_dots_37: Keysym = 0x1002844;
+#[doc = " U+2845 BRAILLE PATTERN DOTS-137"]
+pub const XK_braille_dots_137: Keysym = 0x1002845;
+#[doc = " U+2846 BRAILLE PATTERN DOTS-237"]
+pub const XK_braille_dots_237: Keysym = 0x1002846;
+#[doc = " U+2847 BRAILLE PATTERN DOTS-1237"]
+pub const XK_braille_dots_1237: Keysym = 0x1002847;
+#[doc = " U+2848 BRAILLE PATTERN DOTS-47"]
+pub const XK_braille_dots_47: Keysym = 0x1002848;
+#[doc = " U+2849 BRAILLE PATTERN DOTS-147"]
+pub const XK_braille_dots_147: Keysym = 0x1002849;
+#[doc = " U+284a BRAILLE PATTERN DOTS-247"]
+pub const XK_braille_dots_247: Keysym = 0x100284a;
+#[doc = " U+284b BRAILLE PATTERN DOTS-1247"]
+pub const XK_braille_dots_1247: Keysym = 0x100284b;
+#[doc = " U+284c BRAILLE PATTERN DOTS-347"]
+pub const XK_braille_dots_347: Keysym = 0x100284c;
+#[doc = " U+284d BRAILLE PATTERN DOTS-1347"]
+pub const XK_braille_dots_1347: Keysym = 0x100284d;
+#[doc = " U+284e BRAILLE PATTERN DOTS-2347"]
+pub const XK_braille_dots_2347: Keysym = 0x100284e;
+#[doc = " U+284f BRAILLE PATTERN DOTS-12347"]
+pub const XK_braille_dots_12347: Keysym = 0x100284f;
+#[doc = " U+2850 BRAILLE PATTERN DOTS-57"]
+pub const XK_braille_dots_57: Keysym = 0x1002850;
+#[doc = " U+2851 BRAILLE PATTERN DOTS-157"]
+pub const XK_braille_dots_157: Keysym = 0x1002851;
+#[doc = " U+2852 BRAILLE PATTERN DOTS-257"]
+pub const XK_braille_dots_257: Keysym = 0x1002852;
+#[doc = " U+2853 BRAILLE PATTERN DOTS-1257"]
+pub const XK_braille_dots_1257: Keysym = 0x1002853;
+#[doc = " U+2854 BRAILLE PATTERN DOTS-357"]
+pub const XK_braille_dots_357: Keysym = 0x1002854;
+#[doc = " U+2855 BRAILLE PATTERN DOTS-1357"]
+pub const XK_braille_dots_1357: Keysym = 0x1002855;
+#[doc = " U+2856 BRAILLE PATTERN DOTS-2357"]
+pub const XK_braille_dots_2357: Keysym = 0x1002856;
+#[doc = " U+2857 BRAILLE PATTERN DOTS-12357"]
+pub const XK_braille_dots_12357: Keysym = 0x1002857;
+#[doc = " U+2858 BRAILLE PATTERN DOTS-457"]
+pub const XK_braille_dots_457: Keysym = 0x1002858;
+#[doc = " U+2859 BRAILLE PATTERN DOTS-1457"]
+pub const XK_braille_dots_1457: Keysym = 0x1002859;
+#[doc = " U+285a BRAILLE PATTERN DOTS-2457"]
+pub const XK_braille_dots_2457: Keysym = 0x100285a;
+#[doc = " U+285b BRAILLE PATTERN DOTS-12457"]
+pub const XK_braille_dots_12457: Keysym = 0x100285b;
+#[doc = " U+285c BRAILLE PATTERN DOTS-3457"]
+pub const XK_braille_dots_3457: Keysym = 0x100285c;
+#[doc = " U+285d BRAILLE PATTERN DOTS-13457"]
+pub const XK_braille_dots_13457: Keysym = 0x100285d;
+#[doc = " U+285e BRAILLE PATTERN DOTS-23457"]
+pub const XK_braille_dots_23457: Keysym = 0x100285e;
+#[doc = " U+285f BRAILLE PATTERN DOTS-123457"]
+pub const XK_braille_dots_123457: Keysym = 0x100285f;
+#[doc = " U+2860 BRAILLE PATTERN DOTS-67"]
+pub const XK_braille_dots_67: Keysym = 0x1002860;
+#[doc = " U+2861 BRAILLE PATTERN DOTS-167"]
+pub const XK_braille_dots_167: Keysym = 0x1002861;
+#[doc = " U+2862 BRAILLE PATTERN DOTS-267"]
+pub const XK_braille_dots_267: Keysym = 0x1002862;
+#[doc = " U+2863 BRAILLE PATTERN DOTS-1267"]
+pub const XK_braille_dots_1267: Keysym = 0x1002863;
+#[doc = " U+2864 BRAILLE PATTERN DOTS-367"]
+pub const XK_braille_dots_367: Keysym = 0x1002864;
+#[doc = " U+2865 BRAILLE PATTERN DOTS-1367"]
+pub const XK_braille_dots_1367: Keysym = 0x1002865;
+#[doc = " U+2866 BRAILLE PATTERN DOTS-2367"]
+pub const XK_braille_dots_2367: Keysym = 0x1002866;
+#[doc = " U+2867 BRAILLE PATTERN DOTS-12367"]
+pub const XK_braille_dots_12367: Keysym = 0x1002867;
+#[doc = " U+2868 BRAILLE PATTERN DOTS-467"]
+pub const XK_braille_dots_467: Keysym = 0x1002868;
+#[doc = " U+2869 BRAILLE PATTERN DOTS-1467"]
+pub const XK_braille_dots_1467: Keysym = 0x1002869;
+#[doc = " U+286a BRAILLE PATTERN DOTS-2467"]
+pub const XK_braille_dots_2467: Keysym = 0x100286a;
+#[doc = " U+286b BRAILLE PATTERN DOTS-12467"]
+pub const XK_braille_dots_12467: Keysym = 0x100286b;
+#[doc = " U+286c BRAILLE PATTERN DOTS-3467"]
+pub const XK_braille_dots_3467: Keysym = 0x100286c;
+#[doc = " U+286d BRAILLE PATTERN DOTS-13467"]
+pub const XK_braille_dots_13467: Keysym = 0x100286d;
+#[doc = " U+286e BRAILLE PATTERN DOTS-23467"]
+pub const XK_braille_dots_23467: Keysym = 0x100286e;
+#[doc = " U+286f BRAILLE PATTERN DOTS-123467"]
+pub const XK_braille_dots_123467: Keysym = 0x100286f;
+#[doc = " U+2870 BRAILLE PATTERN DOTS-567"]
+pub const XK_braille_dots_567: Keysym = 0x1002870;
+#[doc = " U+2871 BRAILLE PATTERN DOTS-1567"]
+pub const XK_braille_dots_1567: Keysym = 0x1002871;
+#[doc = " U+2872 BRAILLE PATTERN DOTS-2567"]
+pub const XK_braille_dots_2567: Keysym = 0x1002872;
+#[doc = " U+2873 BRAILLE PATTERN DOTS-12567"]
+pub const XK_braille_dots_12567: Keysym = 0x1002873;
+#[doc = " U+2874 BRAILLE PATTERN DOTS-3567"]
+pub const XK_braille_dots_3567: Keysym = 0x1002874;
+#[doc = " U+2875 BRAILLE PATTERN DOTS-13567"]
+pub const XK_braille_dots_13567: Keysym = 0x1002875;
+#[doc = " U+2876 BRAILLE PATTERN DOTS-23567"]
+pub const XK_braille_dots_23567: Keysym = 0x1002876;
+#[doc = " U+2877 BRAILLE PATTERN DOTS-123567"]
+pub const XK_braille_dots_123567: Keysym = 0x1002877;
+#[doc = " U+2878 BRAILLE PATTERN DOTS-4567"]
+pub const XK_braille_dots_4567: Keysym = 0x1002878;
+#[doc = " U+2879 BRAILLE PATTERN DOTS-14567"]
+pub const XK_braille_dots_14567: Keysym = 0x1002879;
+#[doc = " U+287a BRAILLE PATTERN DOTS-24567"]
+pub const XK_braille_dots_24567: Keysym = 0x100287a;
+#[doc = " U+287b BRAILLE PATTERN DOTS-124567"]
+pub const XK_braille_dots_124567: Keysym = 0x100287b;
+#[doc = " U+287c BRAILLE PATTERN DOTS-34567"]
+pub const XK_braille_dots_34567: Keysym = 0x100287c;
+#[doc = " U+287d BRAILLE PATTERN DOTS-134567"]
+pub const XK_braille_dots_134567: Keysym = 0x100287d;
+#[doc = " U+287e BRAILLE PATTERN DOTS-234567"]
+pub const XK_braille_dots_234567: Keysym = 0x100287e;
+#[doc = " U+287f BRAILLE PATTERN DOTS-1234567"]
+pub const XK_braille_dots_1234567: Keysym = 0x100287f;
+#[doc = " U+2880 BRAILLE PATTERN DOTS-8"]
+pub const XK_braille_dots_8: Keysym = 0x1002880;
+#[doc = " U+2881 BRAILLE PATTERN DOTS-18"]
+pub const XK_braille_dots_18: Keysym = 0x1002881;
+#[doc = " U+2882 BRAILLE PATTERN DOTS-28"]
+pub const XK_braille_dots_28: Keysym = 0x1002882;
+#[doc = " U+2883 BRAILLE PATTERN DOTS-128"]
+pub const XK_braille_dots_128: Keysym = 0x1002883;
+#[doc = " U+2884 BRAILLE PATTERN DOTS-38"]
+pub const XK_braille_dots_38: Keysym = 0x1002884;
+#[doc = " U+2885 BRAILLE PATTERN DOTS-138"]
+pub const XK_braille_dots_138: Keysym = 0x1002885;
+#[doc = " U+2886 BRAILLE PATTERN DOTS-238"]
+pub const XK_braille_dots_238: Keysym = 0x1002886;
+#[doc = " U+2887 BRAILLE PATTERN DOTS-1238"]
+pub const XK_braille_dots_1238: Keysym = 0x1002887;
+#[doc = " U+2888 BRAILLE PATTERN DOTS-48"]
+pub const XK_braille_dots_48: Keysym = 0x1002888;
+#[doc = " U+2889 BRAILLE PATTERN DOTS-148"]
+pub const XK_braille_dots_148: Keysym = 0x1002889;
+#[doc = " U+288a BRAILLE PATTERN DOTS-248"]
+pub const XK_braille_dots_248: Keysym = 0x100288a;
+#[doc = " U+288b BRAILLE PATTERN DOTS-1248"]
+pub const XK_braille_dots_1248: Keysym = 0x100288b;
+#[doc = " U+288c BRAILLE PATTERN DOTS-348"]
+pub const XK_braille_dots_348: Keysym = 0x100288c;
+#[doc = " U+288d BRAILLE PATTERN DOTS-1348"]
+pub const XK_braille_dots_1348: Keysym = 0x100288d;
+#[doc = " U+288e BRAILLE PATTERN DOTS-2348"]
+pub const XK_braille_dots_2348: Keysym = 0x100288e;
+#[doc = " U+288f BRAILLE PATTERN DOTS-12348"]
+pub const XK_braille_dots_12348: Keysym = 0x100288f;
+#[doc = " U+2890 BRAILLE PATTERN DOTS-58"]
+pub const XK_braille_dots_58: Keysym = 0x1002890;
+#[doc = " U+2891 BRAILLE PATTERN DOTS-158"]
+pub const XK_braille_dots_158: Keysym = 0x1002891;
+#[doc = " U+2892 BRAILLE PATTERN DOTS-258"]
+pub const XK_braille_dots_258: Keysym = 0x1002892;
+#[doc = " U+2893 BRAILLE PATTERN DOTS-1258"]
+pub const XK_braille_dots_1258: Keysym = 0x1002893;
+#[doc = " U+2894 BRAILLE PATTERN DOTS-358"]
+pub const XK_braille_dots_358: Keysym = 0x1002894;
+#[doc = " U+2895 BRAILLE PATTERN DOTS-1358"]
+pub const XK_braille_dots_1358: Keysym = 0x1002895;
+#[doc = " U+2896 BRAILLE PATTERN DOTS-2358"]
+pub const XK_braille_dots_2358: Keysym = 0x1002896;
+#[doc = " U+2897 BRAILLE PATTERN DOTS-12358"]
+pub const XK_braille_dots_12358: Keysym = 0x1002897;
+#[doc = " U+2898 BRAILLE PATTERN DOTS-458"]
+pub const XK_braille_dots_458: Keysym = 0x1002898;
+#[doc = " U+2899 BRAILLE PATTERN DOTS-1458"]
+pub const XK_braille_dots_1458: Keysym = 0x1002899;
+#[doc = " U+289a BRAILLE PATTERN DOTS-2458"]
+pub const XK_braille_dots_2458: Keysym = 0x100289a;
+#[doc = " U+289b BRAILLE PATTERN DOTS-12458"]
+pub const XK_braille_dots_12458: Keysym = 0x100289b;
+#[doc = " U+289c BRAILLE PATTERN DOTS-3458"]
+pub const XK_braille_dots_3458: Keysym = 0x100289c;
+#[doc = " U+289d BRAILLE PATTERN DOTS-13458"]
+pub const XK_braille_dots_13458: Keysym = 0x100289d;
+#[doc = " U+289e BRAILLE PATTERN DOTS-23458"]
+pub const XK_braille_dots_23458: Keysym = 0x100289e;
+#[doc = " U+289f BRAILLE PATTERN DOTS-123458"]
+pub const XK_braille_dots_123458: Keysym = 0x100289f;
+#[doc = " U+28a0 BRAILLE PATTERN DOTS-68"]
+pub const XK_braille_dots_68: Keysym = 0x10028a0;
+#[doc = " U+28a1 BRAILLE PATTERN DOTS-168"]
+pub const XK_braille_dots_168: Keysym = 0x10028a1;
+#[doc = " U+28a2 BRAILLE PATTERN DOTS-268"]
+pub const XK_braille_dots_268: Keysym = 0x10028a2;
+#[doc = " U+28a3 BRAILLE PATTERN DOTS-1268"]
+pub const XK_braille_dots_1268: Keysym = 0x10028a3;
+#[doc = " U+28a4 BRAILLE PATTERN DOTS-368"]
+pub const XK_braille_dots_368: Keysym = 0x10028a4;
+#[doc = " U+28a5 BRAILLE PATTERN DOTS-1368"]
+pub const XK_braille_dots_1368: Keysym = 0x10028a5;
+#[doc = " U+28a6 BRAILLE PATTERN DOTS-2368"]
+pub const XK_braille_dots_2368: Keysym = 0x10028a6;
+#[doc = " U+28a7 BRAILLE PATTERN DOTS-12368"]
+pub const XK_braille_dots_12368: Keysym = 0x10028a7;
+#[doc = " U+28a8 BRAILLE PATTERN DOTS-468"]
+pub const XK_braille_dots_468: Keysym = 0x10028a8;
+#[doc = " U+28a9 BRAILLE PATTERN DOTS-1468"]
+pub const XK_braille_dots_1468: Keysym = 0x10028a9;
+#[doc = " U+28aa BRAILLE PATTERN DOTS-2468"]
+pub const XK_braille_dots_2468: Keysym = 0x10028aa;
+#[doc = " U+28ab BRAILLE PATTERN DOTS-12468"]
+pub const XK_braille_dots_12468: Keysym = 0x10028ab;
+#[doc = " U+28ac BRAILLE PATTERN DOTS-3468"]
+pub const XK_braille_dots_3468: Keysym = 0x10028ac;
+#[doc = " U+28ad BRAILLE PATTERN DOTS-13468"]
+pub const XK_braille_dots_13468: Keysym = 0x10028ad;
+#[doc = " U+28ae BRAILLE PATTERN DOTS-23468"]
+pub const XK_braille_dots_23468: Keysym = 0x10028ae;
+#[doc = " U+28af BRAILLE PATTERN DOTS-123468"]
+pub const XK_braille_dots_123468: Keysym = 0x10028af;
+#[doc = " U+28b0 BRAILLE PATTERN DOTS-568"]
+pub const XK_braille_dots_568: Keysym = 0x10028b0;
+#[doc = " U+28b1 BRAILLE PATTERN DOTS-1568"]
+pub const XK_braille_dots_1568: Keysym = 0x10028b1;
+#[doc = " U+28b2 BRAILLE PATTERN DOTS-2568"]
+pub const XK_braille_dots_2568: Keysym = 0x10028b2;
+#[doc = " U+28b3 BRAILLE PATTERN DOTS-12568"]
+pub const XK_braille_dots_12568: Keysym = 0x10028b3;
+#[doc = " U+28b4 BRAILLE PATTERN DOTS-3568"]
+pub const XK_braille_dots_3568: Keysym = 0x10028b4;
+#[doc = " U+28b5 BRAILLE PATTERN DOTS-13568"]
+pub const XK_braille_dots_13568: Keysym = 0x10028b5;
+#[doc = " U+28b6 BRAILLE PATTERN DOTS-23568"]
+pub const XK_braille_dots_23568: Keysym = 0x10028b6;
+#[doc = " U+28b7 BRAILLE PATTERN DOTS-123568"]
+pub const XK_braille_dots_123568: Keysym = 0x10028b7;
+#[doc = " U+28b8 BRAILLE PATTERN DOTS-4568"]
+pub const XK_braille_dots_4568: Keysym = 0x10028b8;
+#[doc = " U+28b9 BRAILLE PATTERN DOTS-14568"]
+pub const XK_braille_dots_14568: Keysym = 0x10028b9;
+#[doc = " U+28ba BRAILLE PATTERN DOTS-24568"]
+pub const XK_braille_dots_24568: Keysym = 0x10028ba;
+#[doc = " U+28bb BRAILLE PATTERN DOTS-124568"]
+pub const XK_braille_dots_124568: Keysym = 0x10028bb;
+#[doc = " U+28bc BRAILLE PATTERN DOTS-34568"]
+pub const XK_braille_dots_34568: Keysym = 0x10028bc;
+#[doc = " U+28bd BRAILLE PATTERN DOTS-134568"]
+pub const XK_braille_dots_134568: Keysym = 0x10028bd;
+#[doc = " U+28be BRAILLE PATTERN DOTS-234568"]
+pub const XK_braille_dots_234568: Keysym = 0x10028be;
+#[doc = " U+28bf BRAILLE PATTERN DOTS-1234568"]
+pub const XK_braille_dots_1234568: Keysym = 0x10028bf;
+#[doc = " U+28c0 BRAILLE PATTERN DOTS-78"]
+pub const XK_braille_dots_78: Keysym = 0x10028c0;
+#[doc = " U+28c1 BRAILLE PATTERN DOTS-178"]
+pub const XK_braille_dots_178: Keysym = 0x10028c1;
+#[doc = " U+28c2 BRAILLE PATTERN DOTS-278"]
+pub const XK_braille_dots_278: Keysym = 0x10028c2;
+#[doc = " U+28c3 BRAILLE PATTERN DOTS-1278"]
+pub const XK_braille_dots_1278: Keysym = 0x10028c3;
+#[doc = " U+28c4 BRAILLE PATTERN DOTS-378"]
+pub const XK_braille_dots_378: Keysym = 0x10028c4;
+#[doc = " U+28c5 BRAILLE PATTERN DOTS-1378"]
+pub const XK_braille_dots_1378: Keysym = 0x10028c5;
+#[doc = " U+28c6 BRAILLE PATTERN DOTS-2378"]
+pub const XK_braille_dots_2378: Keysym = 0x10028c6;
+#[doc = " U+28c7 BRAILLE PATTERN DOTS-12378"]
+pub const XK_braille_dots_12378: Keysym = 0x10028c7;
+#[doc = " U+28c8 BRAILLE PATTERN DOTS-478"]
+pub const XK_braille_dots_478: Keysym = 0x10028c8;
+#[doc = " U+28c9 BRAILLE PATTERN DOTS-1478"]
+pub const XK_braille_dots_1478: Keysym = 0x10028c9;
+#[doc = " U+28ca BRAILLE PATTERN DOTS-2478"]
+pub const XK_braille_dots_2478: Keysym = 0x10028ca;
+#[doc = " U+28cb BRAILLE PATTERN DOTS-12478"]
+pub const XK_braille_dots_12478: Keysym = 0x10028cb;
+#[doc = " U+28cc BRAILLE PATTERN DOTS-3478"]
+pub const XK_braille_dots_3478: Keysym = 0x10028cc;
+#[doc = " U+28cd BRAILLE PATTERN DOTS-13478"]
+pub const XK_braille_dots_13478: Keysym = 0x10028cd;
+#[doc = " U+28ce BRAILLE PATTERN DOTS-23478"]
+pub const XK_braille_dots_23478: Keysym = 0x10028ce;
+#[doc = " U+28cf BRAILLE PATTERN DOTS-123478"]
+pub const XK_braille_dots_123478: Keysym = 0x10028cf;
+#[doc = " U+28d0 BRAILLE PATTERN DOTS-578"]
+pub const XK_braille_dots_578: Keysym = 0x10028d0;
+#[doc = " U+28d1 BRAILLE PATTERN DOTS-1578"]
+pub const XK_braille_dots_1578: Keysym = 0x10028d1;
+#[doc = " U+28d2 BRAILLE PATTERN DOTS-2578"]
+pub const XK_braille_dots_2578: Keysym = 0x10028d2;
+#[doc = " U+28d3 BRAILLE PATTERN DOTS-12578"]
+pub const XK_braille_dots_12578: Keysym = 0x10028d3;
+#[doc = " U+28d4 BRAILLE PATTERN DOTS-3578"]
+pub const XK_braille_dots_3578: Keysym = 0x10028d4;
+#[doc = " U+28d5 BRAILLE PATTERN DOTS-13578"]
+pub const XK_braille_dots_13578: Keysym = 0x10028d5;
+#[doc = " U+28d6 BRAILLE PATTERN DOTS-23578"]
+pub const XK_braille_dots_23578: Keysym = 0x10028d6;
+#[doc = " U+28d7 BRAILLE PATTERN DOTS-123578"]
+pub const XK_braille_dots_123578: Keysym = 0x10028d7;
+#[doc = " U+28d8 BRAILLE PATTERN DOTS-4578"]
+pub const XK_braille_dots_4578: Keysym = 0x10028d8;
+#[doc = " U+28d9 BRAILLE PATTERN DOTS-14578"]
+pub const XK_braille_dots_14578: Keysym = 0x10028d9;
+#[doc = " U+28da BRAILLE PATTERN DOTS-24578"]
+pub const XK_braille_dots_24578: Keysym = 0x10028da;
+#[doc = " U+28db BRAILLE PATTERN DOTS-124578"]
+pub const XK_braille_dots_124578: Keysym = 0x10028db;
+#[doc = " U+28dc BRAILLE PATTERN DOTS-34578"]
+pub const XK_braille_dots_34578: Keysym = 0x10028dc;
+#[doc = " U+28dd BRAILLE PATTERN DOTS-134578"]
+pub const XK_braille_dots_134578: Keysym = 0x10028dd;
+#[doc = " U+28de BRAILLE PATTERN DOTS-234578"]
+pub const XK_braille_dots_234578: Keysym = 0x10028de;
+#[doc = " U+28df BRAILLE PATTERN DOTS-1234578"]
+pub const XK_braille_dots_1234578: Keysym = 0x10028df;
+#[doc = " U+28e0 BRAILLE PATTERN DOTS-678"]
+pub const XK_braille_dots_678: Keysym = 0x10028e0;
+#[doc = " U+28e1 BRAILLE PATTERN DOTS-1678"]
+pub const XK_braille_dots_1678: Keysym = 0x10028e1;
+#[doc = " U+28e2 BRAILLE PATTERN DOTS-2678"]
+pub const XK_braille_dots_2678: Keysym = 0x10028e2;
+#[doc = " U+28e3 BRAILLE PATTERN DOTS-12678"]
+pub const XK_braille_dots_12678: Keysym = 0x10028e3;
+#[doc = " U+28e4 BRAILLE PATTERN DOTS-3678"]
+pub const XK_braille_dots_3678: Keysym = 0x10028e4;
+#[doc = " U+28e5 BRAILLE PATTERN DOTS-13678"]
+pub const XK_braille_dots_13678: Keysym = 0x10028e5;
+#[doc = " U+28e6 BRAILLE PATTERN DOTS-23678"]
+pub const XK_braille_dots_23678: Keysym = 0x10028e6;
+#[doc = " U+28e7 BRAILLE PATTERN DOTS-123678"]
+pub const XK_braille_dots_123678: Keysym = 0x10028e7;
+#[doc = " U+28e8 BRAILLE PATTERN DOTS-4678"]
+pub const XK_braille_dots_4678: Keysym = 0x10028e8;
+#[doc = " U+28e9 BRAILLE PATTERN DOTS-14678"]
+pub const XK_braille_dots_14678: Keysym = 0x10028e9;
+#[doc = " U+28ea BRAILLE PATTERN DOTS-24678"]
+pub const XK_braille_dots_24678: Keysym = 0x10028ea;
+#[doc = " U+28eb BRAILLE PATTERN DOTS-124678"]
+pub const XK_braille_dots_124678: Keysym = 0x10028eb;
+#[doc = " U+28ec BRAILLE PATTERN DOTS-34678"]
+pub const XK_braille_dots_34678: Keysym = 0x10028ec;
+#[doc = " U+28ed BRAILLE PATTERN DOTS-134678"]
+pub const XK_braille_dots_134678: Keysym = 0x10028ed;
+#[doc = " U+28ee BRAILLE PATTERN DOTS-234678"]
+pub const XK_braille_dots_234678: Keysym = 0x10028ee;
+#[doc = " U+28ef BRAILLE PATTERN DOTS-1234678"]
+pub const XK_braille_dots_1234678: Keysym = 0x10028ef;
+#[doc = " U+28f0 BRAILLE PATTERN DOTS-5678"]
+pub const XK_braille_dots_5678: Keysym = 0x10028f0;
+#[doc = " U+28f1 BRAILLE PATTERN DOTS-15678"]
+pub const XK_braille_dots_15678: Keysym = 0x10028f1;
+#[doc = " U+28f2 BRAILLE PATTERN DOTS-25678"]
+pub const XK_braille_dots_25678: Keysym = 0x10028f2;
+#[doc = " U+28f3 BRAILLE PATTERN DOTS-125678"]
+pub const XK_braille_dots_125678: Keysym = 0x10028f3;
+#[doc = " U+28f4 BRAILLE PATTERN DOTS-35678"]
+pub const XK_braille_dots_35678: Keysym = 0x10028f4;
+#[doc = " U+28f5 BRAILLE PATTERN DOTS-135678"]
+pub const XK_braille_dots_135678: Keysym = 0x10028f5;
+#[doc = " U+28f6 BRAILLE PATTERN DOTS-235678"]
+pub const XK_braille_dots_235678: Keysym = 0x10028f6;
+#[doc = " U+28f7 BRAILLE PATTERN DOTS-1235678"]
+pub const XK_braille_dots_1235678: Keysym = 0x10028f7;
+#[doc = " U+28f8 BRAILLE PATTERN DOTS-45678"]
+pub const XK_braille_dots_45678: Keysym = 0x10028f8;
+#[doc = " U+28f9 BRAILLE PATTERN DOTS-145678"]
+pub const XK_braille_dots_145678: Keysym = 0x10028f9;
+#[doc = " U+28fa BRAILLE PATTERN DOTS-245678"]
+pub const XK_braille_dots_245678: Keysym = 0x10028fa;
+#[doc = " U+28fb BRAILLE PATTERN DOTS-1245678"]
+pub const XK_braille_dots_1245678: Keysym = 0x10028fb;
+#[doc = " U+28fc BRAILLE PATTERN DOTS-345678"]
+pub const XK_braille_dots_345678: Keysym = 0x10028fc;
+#[doc = " U+28fd BRAILLE PATTERN DOTS-1345678"]
+pub const XK_braille_dots_1345678: Keysym = 0x10028fd;
+#[doc = " U+28fe BRAILLE PATTERN DOTS-2345678"]
+pub const XK_braille_dots_2345678: Keysym = 0x10028fe;
+#[doc = " U+28ff BRAILLE PATTERN DOTS-12345678"]
+pub const XK_braille_dots_12345678: Keysym = 0x10028ff;
+#[doc = " U+0D82 SINHALA ANUSVARAYA"]
+pub const XK_Sinh_ng: Keysym = 0x1000d82;
+#[doc = " U+0D83 SINHALA VISARGAYA"]
+pub const XK_Sinh_h2: Keysym = 0x1000d83;
+#[doc = " U+0D85 SINHALA AYANNA"]
+pub const XK_Sinh_a: Keysym = 0x1000d85;
+#[doc = " U+0D86 SINHALA AAYANNA"]
+pub const XK_Sinh_aa: Keysym = 0x1000d86;
+#[doc = " U+0D87 SINHALA AEYANNA"]
+pub const XK_Sinh_ae: Keysym = 0x1000d87;
+#[doc = " U+0D88 SINHALA AEEYANNA"]
+pub const XK_Sinh_aee: Keysym = 0x1000d88;
+#[doc = " U+0D89 SINHALA IYANNA"]
+pub const XK_Sinh_i: Keysym = 0x1000d89;
+#[doc = " U+0D8A SINHALA IIYANNA"]
+pub const XK_Sinh_ii: Keysym = 0x1000d8a;
+#[doc = " U+0D8B SINHALA UYANNA"]
+pub const XK_Sinh_u: Keysym = 0x1000d8b;
+#[doc = " U+0D8C SINHALA UUYANNA"]
+pub const XK_Sinh_uu: Keysym = 0x1000d8c;
+#[doc = " U+0D8D SINHALA IRUYANNA"]
+pub const XK_Sinh_ri: Keysym = 0x1000d8d;
+#[doc = " U+0D8E SINHALA IRUUYANNA"]
+pub const XK_Sinh_rii: Keysym = 0x1000d8e;
+#[doc = " U+0D8F SINHALA ILUYANNA"]
+pub const XK_Sinh_lu: Keysym = 0x1000d8f;
+#[doc = " U+0D90 SINHALA ILUUYANNA"]
+pub const XK_Sinh_luu: Keysym = 0x1000d90;
+#[doc = " U+0D91 SINHALA EYANNA"]
+pub const XK_Sinh_e: Keysym = 0x1000d91;
+#[doc = " U+0D92 SINHALA EEYANNA"]
+pub const XK_Sinh_ee: Keysym = 0x1000d92;
+#[doc = " U+0D93 SINHALA AIYANNA"]
+pub const XK_Sinh_ai: Keysym = 0x1000d93;
+#[doc = " U+0D94 SINHALA OYANNA"]
+pub const XK_Sinh_o: Keysym = 0x1000d94;
+#[doc = " U+0D95 SINHALA OOYANNA"]
+pub const XK_Sinh_oo: Keysym = 0x1000d95;
+#[doc = " U+0D96 SINHALA AUYANNA"]
+pub const XK_Sinh_au: Keysym = 0x1000d96;
+#[doc = " U+0D9A SINHALA KAYANNA"]
+pub const XK_Sinh_ka: Keysym = 0x1000d9a;
+#[doc = " U+0D9B SINHALA MAHA. KAYANNA"]
+pub const XK_Sinh_kha: Keysym = 0x1000d9b;
+#[doc = " U+0D9C SINHALA GAYANNA"]
+pub const XK_Sinh_ga: Keysym = 0x1000d9c;
+#[doc = " U+0D9D SINHALA MAHA. GAYANNA"]
+pub const XK_Sinh_gha: Keysym = 0x1000d9d;
+#[doc = " U+0D9E SINHALA KANTAJA NAASIKYAYA"]
+pub const XK_Sinh_ng2: Keysym = 0x1000d9e;
+#[doc = " U+0D9F SINHALA SANYAKA GAYANNA"]
+pub const XK_Sinh_nga: Keysym = 0x1000d9f;
+#[doc = " U+0DA0 SINHALA CAYANNA"]
+pub const XK_Sinh_ca: Keysym = 0x1000da0;
+#[doc = " U+0DA1 SINHALA MAHA. CAYANNA"]
+pub const XK_Sinh_cha: Keysym = 0x1000da1;
+#[doc = " U+0DA2 SINHALA JAYANNA"]
+pub const XK_Sinh_ja: Keysym = 0x1000da2;
+#[doc = " U+0DA3 SINHALA MAHA. JAYANNA"]
+pub const XK_Sinh_jha: Keysym = 0x1000da3;
+#[doc = " U+0DA4 SINHALA TAALUJA NAASIKYAYA"]
+pub const XK_Sinh_nya: Keysym = 0x1000da4;
+#[doc = " U+0DA5 SINHALA TAALUJA SANYOOGA NAASIKYAYA"]
+pub const XK_Sinh_jnya: Keysym = 0x1000da5;
+#[doc = " U+0DA6 SINHALA SANYAKA JAYANNA"]
+pub const XK_Sinh_nja: Keysym = 0x1000da6;
+#[doc = " U+0DA7 SINHALA TTAYANNA"]
+pub const XK_Sinh_tta: Keysym = 0x1000da7;
+#[doc = " U+0DA8 SINHALA MAHA. TTAYANNA"]
+pub const XK_Sinh_ttha: Keysym = 0x1000da8;
+#[doc = " U+0DA9 SINHALA DDAYANNA"]
+pub const XK_Sinh_dda: Keysym = 0x1000da9;
+#[doc = " U+0DAA SINHALA MAHA. DDAYANNA"]
+pub const XK_Sinh_ddha: Keysym = 0x1000daa;
+#[doc = " U+0DAB SINHALA MUURDHAJA NAYANNA"]
+pub const XK_Sinh_nna: Keysym = 0x1000dab;
+#[doc = " U+0DAC SINHALA SANYAKA DDAYANNA"]
+pub const XK_Sinh_ndda: Keysym = 0x1000dac;
+#[doc = " U+0DAD SINHALA TAYANNA"]
+pub const XK_Sinh_tha: Keysym = 0x1000dad;
+#[doc = " U+0DAE SINHALA MAHA. TAYANNA"]
+pub const XK_Sinh_thha: Keysym = 0x1000dae;
+#[doc = " U+0DAF SINHALA DAYANNA"]
+pub const XK_Sinh_dha: Keysym = 0x1000daf;
+#[doc = " U+0DB0 SINHALA MAHA. DAYANNA"]
+pub const XK_Sinh_dhha: Keysym = 0x1000db0;
+#[doc = " U+0DB1 SINHALA DANTAJA NAYANNA"]
+pub const XK_Sinh_na: Keysym = 0x1000db1;
+#[doc = " U+0DB3 SINHALA SANYAKA DAYANNA"]
+pub const XK_Sinh_ndha: Keysym = 0x1000db3;
+#[doc = " U+0DB4 SINHALA PAYANNA"]
+pub const XK_Sinh_pa: Keysym = 0x1000db4;
+#[doc = " U+0DB5 SINHALA MAHA. PAYANNA"]
+pub const XK_Sinh_pha: Keysym = 0x1000db5;
+#[doc = " U+0DB6 SINHALA BAYANNA"]
+pub const XK_Sinh_ba: Keysym = 0x1000db6;
+#[doc = " U+0DB7 SINHALA MAHA. BAYANNA"]
+pub const XK_Sinh_bha: Keysym = 0x1000db7;
+#[doc = " U+0DB8 SINHALA MAYANNA"]
+pub const XK_Sinh_ma: Keysym = 0x1000db8;
+#[doc = " U+0DB9 SINHALA AMBA BAYANNA"]
+pub const XK_Sinh_mba: Keysym = 0x1000db9;
+#[doc = " U+0DBA SINHALA YAYANNA"]
+pub const XK_Sinh_ya: Keysym = 0x1000dba;
+#[doc = " U+0DBB SINHALA RAYANNA"]
+pub const XK_Sinh_ra: Keysym = 0x1000dbb;
+#[doc = " U+0DBD SINHALA DANTAJA LAYANNA"]
+pub const XK_Sinh_la: Keysym = 0x1000dbd;
+#[doc = " U+0DC0 SINHALA VAYANNA"]
+pub const XK_Sinh_va: Keysym = 0x1000dc0;
+#[doc = " U+0DC1 SINHALA TAALUJA SAYANNA"]
+pub const XK_Sinh_sha: Keysym = 0x1000dc1;
+#[doc = " U+0DC2 SINHALA MUURDHAJA SAYANNA"]
+pub const XK_Sinh_ssha: Keysym = 0x1000dc2;
+#[doc = " U+0DC3 SINHALA DANTAJA SAYANNA"]
+pub const XK_Sinh_sa: Keysym = 0x1000dc3;
+#[doc = " U+0DC4 SINHALA HAYANNA"]
+pub const XK_Sinh_ha: Keysym = 0x1000dc4;
+#[doc = " U+0DC5 SINHALA MUURDHAJA LAYANNA"]
+pub const XK_Sinh_lla: Keysym = 0x1000dc5;
+#[doc = " U+0DC6 SINHALA FAYANNA"]
+pub const XK_Sinh_fa: Keysym = 0x1000dc6;
+#[doc = " U+0DCA SINHALA AL-LAKUNA"]
+pub const XK_Sinh_al: Keysym = 0x1000dca;
+#[doc = " U+0DCF SINHALA AELA-PILLA"]
+pub const XK_Sinh_aa2: Keysym = 0x1000dcf;
+#[doc = " U+0DD0 SINHALA AEDA-PILLA"]
+pub const XK_Sinh_ae2: Keysym = 0x1000dd0;
+#[doc = " U+0DD1 SINHALA DIGA AEDA-PILLA"]
+pub const XK_Sinh_aee2: Keysym = 0x1000dd1;
+#[doc = " U+0DD2 SINHALA IS-PILLA"]
+pub const XK_Sinh_i2: Keysym = 0x1000dd2;
+#[doc = " U+0DD3 SINHALA DIGA IS-PILLA"]
+pub const XK_Sinh_ii2: Keysym = 0x1000dd3;
+#[doc = " U+0DD4 SINHALA PAA-PILLA"]
+pub const XK_Sinh_u2: Keysym = 0x1000dd4;
+#[doc = " U+0DD6 SINHALA DIGA PAA-PILLA"]
+pub const XK_Sinh_uu2: Keysym = 0x1000dd6;
+#[doc = " U+0DD8 SINHALA GAETTA-PILLA"]
+pub const XK_Sinh_ru2: Keysym = 0x1000dd8;
+#[doc = " U+0DD9 SINHALA KOMBUVA"]
+pub const XK_Sinh_e2: Keysym = 0x1000dd9;
+#[doc = " U+0DDA SINHALA DIGA KOMBUVA"]
+pub const XK_Sinh_ee2: Keysym = 0x1000dda;
+#[doc = " U+0DDB SINHALA KOMBU DEKA"]
+pub const XK_Sinh_ai2: Keysym = 0x1000ddb;
+#[doc = " U+0DDC SINHALA KOMBUVA HAA AELA-PILLA"]
+pub const XK_Sinh_o2: Keysym = 0x1000ddc;
+#[doc = " U+0DDD SINHALA KOMBUVA HAA DIGA AELA-PILLA"]
+pub const XK_Sinh_oo2: Keysym = 0x1000ddd;
+#[doc = " U+0DDE SINHALA KOMBUVA HAA GAYANUKITTA"]
+pub const XK_Sinh_au2: Keysym = 0x1000dde;
+#[doc = " U+0DDF SINHALA GAYANUKITTA"]
+pub const XK_Sinh_lu2: Keysym = 0x1000ddf;
+#[doc = " U+0DF2 SINHALA DIGA GAETTA-PILLA"]
+pub const XK_Sinh_ruu2: Keysym = 0x1000df2;
+#[doc = " U+0DF3 SINHALA DIGA GAYANUKITTA"]
+pub const XK_Sinh_luu2: Keysym = 0x1000df3;
+#[doc = " U+0DF4 SINHALA KUNDDALIYA"]
+pub const XK_Sinh_kunddaliya: Keysym = 0x1000df4;
+#[doc = r" The preferred name of every keysym, without the `XK_` prefix, sorted by the"]
+#[doc = r" keysyms."]
+pub const KEYSYM_NAMES: &[(Keysym, &str)] = &[
+    (32, "space"),
+    (33, "exclam"),
+    (34, "quotedbl"),
+    (35, "numbersign"),
+    (36, "dollar"),
+    (37, "percent"),
+    (38, "ampersand"),
+    (39, "apostrophe"),
+    (40, "parenleft"),
+    (41, "parenright"),
+    (42, "asterisk"),
+    (43, "plus"),
+    (44, "comma"),
+    (45, "minus"),
+    (46, "period"),
+    (47, "slash"),
+    (48, "0"),
+    (49, "1"),
+    (50, "2"),
+    (51, "3"),
+    (52, "4"),
+    (53, "5"),
+    (54, "6"),
+    (55, "7"),
+    (56, "8"),
+    (57, "9"),
+    (58, "colon"),
+    (59, "semicolon"),
+    (60, "less"),
+    (61, "equal"),
+    (62, "greater"),
+    (63, "question"),
+    (64, "at"),
+    (65, "A"),
+    (66, "B"),
+    (67, "C"),
+    (68, "D"),
+    (69, "E"),
+    (70, "F"),
+    (71, "G"),
+    (72, "H"),
+    (73, "I"),
+    (74, "J"),
+    (75, "K"),
+    (76, "L"),
+    (77, "M"),
+    (78, "N"),
+    (79, "O"),
+    (80, "P"),
+    (81, "Q"),
+    (82, "R"),
+    (83, "S"),
+    (84, "T"),
+    (85, "U"),
+    (86, "V"),
+    (87, "W"),
+    (88, "X"),
+    (89, "Y"),
+    (90, "Z"),
+    (91, "bracketleft"),
+    (92, "backslash"),
+    (93, "bracketright"),
+    (94, "asciicircum"),
+    (95, "underscore"),
+    (96, "grave"),
+    (97, "a"),
+    (98, "b"),
+    (99, "c"),
+    (100, "d"),
+    (101, "e"),
+    (102, "f"),
+    (103, "g"),
+    (104, "h"),
+    (105, "i"),
+    (106, "j"),
+    (107, "k"),
+    (108, "l"),
+    (109, "m"),
+    (110, "n"),
+    (111, "o"),
+    (112, "p"),
+    (113, "q"),
+    (114, "r"),
+    (115, "s"),
+    (116, "t"),
+    (117, "u"),
+    (118, "v"),
+    (119, "w"),
+    (120, "x"),
+    (121, "y"),
+    (122, "z"),
+    (123, "braceleft"),
+    (124, "bar"),
+    (125, "braceright"),
+    (126, "asciitilde"),
+    (160, "nobreakspace"),
+    (161, "exclamdown"),
+    (162, "cent"),
+    (163, "sterling"),
+    (164, "currency"),
+    (165, "yen"),
+    (166, "brokenbar"),
+    (167, "section"),
+    (168, "diaeresis"),
+    (169, "copyright"),
+    (170, "ordfeminine"),
+    (171, "guillemotleft"),
+    (172, "notsign"),
+    (173, "hyphen"),
+    (174, "registered"),
+    (175, "macron"),
+    (176, "degree"),
+    (177, "plusminus"),
+    (178, "twosuperior"),
+    (179, "threesuperior"),
+    (180, "acute"),
+    (181, "mu"),
+    (182, "paragraph"),
+    (183, "periodcentered"),
+    (184, "cedilla"),
+    (185, "onesuperior"),
+    (186, "masculine"),
+    (187, "guillemotright"),
+    (188, "onequarter"),
+    (189, "onehalf"),
+    (190, "threequarters"),
+    (191, "questiondown"),
+    (192, "Agrave"),
+    (193, "Aacute"),
+    (194, "Acircumflex"),
+    (195, "Atilde"),
+    (196, "Adiaeresis"),
+    (197, "Aring"),
+    (198, "AE"),
+    (199, "Ccedilla"),
+    (200, "Egrave"),
+    (201, "Eacute"),
+    (202, "Ecircumflex"),
+    (203, "Ediaeresis"),
+    (204, "Igrave"),
+    (205, "Iacute"),
+    (206, "Icircumflex"),
+    (207, "Idiaeresis"),
+    (208, "ETH"),
+    (209, "Ntilde"),
+    (210, "Ograve"),
+    (211, "Oacute"),
+    (212, "Ocircumflex"),
+    (213, "Otilde"),
+    (214, "Odiaeresis"),
+    (215, "multiply"),
+    (216, "Oslash"),
+    (217, "Ugrave"),
+    (218, "Uacute"),
+    (219, "Ucircumflex"),
+    (220, "Udiaeresis"),
+    (221, "Yacute"),
+    (222, "THORN"),
+    (223, "ssharp"),
+    (224, "agrave"),
+    (225, "aacute"),
+    (226, "acircumflex"),
+    (227, "atilde"),
+    (228, "adiaeresis"),
+    (229, "aring"),
+    (230, "ae"),
+    (231, "ccedilla"),
+    (232, "egrave"),
+    (233, "eacute"),
+    (234, "ecircumflex"),
+    (235, "ediaeresis"),
+    (236, "igrave"),
+    (237, "iacute"),
+    (238, "icircumflex"),
+    (239, "idiaeresis"),
+    (240, "eth"),
+    (241, "ntilde"),
+    (242, "ograve"),
+    (243, "oacute"),
+    (244, "ocircumflex"),
+    (245, "otilde"),
+    (246, "odiaeresis"),
+    (247, "division"),
+    (248, "oslash"),
+    (249, "ugrave"),
+    (250, "uacute"),
+    (251, "ucircumflex"),
+    (252, "udiaeresis"),
+    (253, "yacute"),
+    (254, "thorn"),
+    (255, "ydiaeresis"),
+    (417, "Aogonek"),
+    (418, "breve"),
+    (419, "Lstroke"),
+    (421, "Lcaron"),
+    (422, "Sacute"),
+    (425, "Scaron"),
+    (426, "Scedilla"),
+    (427, "Tcaron"),
+    (428, "Zacute"),
+    (430, "Zcaron"),
+    (431, "Zabovedot"),
+    (433, "aogonek"),
+    (434, "ogonek"),
+    (435, "lstroke"),
+    (437, "lcaron"),
+    (438, "sacute"),
+    (439, "caron"),
+    (441, "scaron"),
+    (442, "scedilla"),
+    (443, "tcaron"),
+    (444, "zacute"),
+    (445, "doubleacute"),
+    (446, "zcaron"),
+    (447, "zabovedot"),
+    (448, "Racute"),
+    (451, "Abreve"),
+    (453, "Lacute"),
+    (454, "Cacute"),
+    (456, "Ccaron"),
+    (458, "Eogonek"),
+    (460, "Ecaron"),
+    (463, "Dcaron"),
+    (464, "Dstroke"),
+    (465, "Nacute"),
+    (466, "Ncaron"),
+    (469, "Odoubleacute"),
+    (472, "Rcaron"),
+    (473, "Uring"),
+    (475, "Udoubleacute"),
+    (478, "Tcedilla"),
+    (480, "racute"),
+    (483, "abreve"),
+    (485, "lacute"),
+    (486, "cacute"),
+    (488, "ccaron"),
+    (490, "eogonek"),
+    (492, "ecaron"),
+    (495, "dcaron"),
+    (496, "dstroke"),
+    (497, "nacute"),
+    (498, "ncaron"),
+    (501, "odoubleacute"),
+    (504, "rcaron"),
+    (505, "uring"),
+    (507, "udoubleacute"),
+    (510, "tcedilla"),
+    (511, "abovedot"),
+    (673, "Hstroke"),
+    (678, "Hcircumflex"),
+    (681, "Iabovedot"),
+    (683, "Gbreve"),
+    (684, "Jcircumflex"),
+    (689, "hstroke"),
+    (694, "hcircumflex"),
+    (697, "idotless"),
+    (699, "gbreve"),
+    (700, "jcircumflex"),
+    (709, "Cabovedot"),
+    (710, "Ccircumflex"),
+    (725, "Gabovedot"),
+    (728, "Gcircumflex"),
+    (733, "Ubreve"),
+    (734, "Scircumflex"),
+    (741, "cabovedot"),
+    (742, "ccircumflex"),
+    (757, "gabovedot"),
+    (760, "gcircumflex"),
+    (765, "ubreve"),
+    (766, "scircumflex"),
+    (930, "kra"),
+    (931, "Rcedilla"),
+    (933, "Itilde"),
+    (934, "Lcedilla"),
+    (938, "Emacron"),
+    (939, "Gcedilla"),
+    (940, "Tslash"),
+    (947, "rcedilla"),
+    (949, "itilde"),
+    (950, "lcedilla"),
+    (954, "emacron"),
+    (955, "gcedilla"),
+    (956, "tslash"),
+    (957, "ENG"),
+    (959, "eng"),
+    (960, "Amacron"),
+    (967, "Iogonek"),
+    (972, "Eabovedot"),
+    (975, "Imacron"),
+    (977, "Ncedilla"),
+    (978, "Omacron"),
+    (979, "Kcedilla"),
+    (985, "Uogonek"),
+    (989, "Utilde"),
+    (990, "Umacron"),
+    (992, "amacron"),
+    (999, "iogonek"),
+    (1004, "eabovedot"),
+    (1007, "imacron"),
+    (1009, "ncedilla"),
+    (1010, "omacron"),
+    (1011, "kcedilla"),
+    (1017, "uogonek"),
+    (1021, "utilde"),
+    (1022, "umacron"),
+    (1150, "overline"),
+    (1185, "kana_fullstop"),
+    (1186, "kana_openingbracket"),
+    (1187, "kana_closingbracket"),
+    (1188, "kana_comma"),
+    (1189, "kana_conjunctive"),
+    (1190, "kana_WO"),
+    (1191, "kana_a"),
+    (1192, "kana_i"),
+    (1193, "kana_u"),
+    (1194, "kana_e"),
+    (1195, "kana_o"),
+    (1196, "kana_ya"),
+    (1197, "kana_yu"),
+    (1198, "kana_yo"),
+    (1199, "kana_tsu"),
+    (1200, "prolongedsound"),
+    (1201, "kana_A"),
+    (1202, "kana_I"),
+    (1203, "kana_U"),
+    (1204, "kana_E"),
+    (1205, "kana_O"),
+    (1206, "kana_KA"),
+    (1207, "kana_KI"),
+    (1208, "kana_KU"),
+    (1209, "kana_KE"),
+    (1210, "kana_KO"),
+    (1211, "kana_SA"),
+    (1212, "kana_SHI"),
+    (1213, "kana_SU"),
+    (1214, "kana_SE"),
+    (1215, "kana_SO"),
+    (1216, "kana_TA"),
+    (1217, "kana_CHI"),
+    (1218, "kana_TSU"),
+    (1219, "kana_TE"),
+    (1220, "kana_TO"),
+    (1221, "kana_NA"),
+    (1222, "kana_NI"),
+    (1223, "kana_NU"),
+    (1224, "kana_NE"),
+    (1225, "kana_NO"),
+    (1226, "kana_HA"),
+    (1227, "kana_HI"),
+    (1228, "kana_FU"),
+    (1229, "kana_HE"),
+    (1230, "kana_HO"),
+    (1231, "kana_MA"),
+    (1232, "kana_MI"),
+    (1233, "kana_MU"),
+    (1234, "kana_ME"),
+    (1235, "kana_MO"),
+    (1236, "kana_YA"),
+    (1237, "kana_YU"),
+    (1238, "kana_YO"),
+    (1239, "kana_RA"),
+    (1240, "kana_RI"),
+    (1241, "kana_RU"),
+    (1242, "kana_RE"),
+    (1243, "kana_RO"),
+    (1244, "kana_WA"),
+    (1245, "kana_N"),
+    (1246, "voicedsound"),
+    (1247, "semivoicedsound"),
+    (1452, "Arabic_comma"),
+    (1467, "Arabic_semicolon"),
+    (1471, "Arabic_question_mark"),
+    (1473, "Arabic_hamza"),
+    (1474, "Arabic_maddaonalef"),
+    (1475, "Arabic_hamzaonalef"),
+    (1476, "Arabic_hamzaonwaw"),
+    (1477, "Arabic_hamzaunderalef"),
+    (1478, "Arabic_hamzaonyeh"),
+    (1479, "Arabic_alef"),
+    (1480, "Arabic_beh"),
+    (1481, "Arabic_tehmarbuta"),
+    (1482, "Arabic_teh"),
+    (1483, "Arabic_theh"),
+    (1484, "Arabic_jeem"),
+    (1485, "Arabic_hah"),
+    (1486, "Arabic_khah"),
+    (1487, "Arabic_dal"),
+    (1488, "Arabic_thal"),
+    (1489, "Arabic_ra"),
+    (1490, "Arabic_zain"),
+    (1491, "Arabic_seen"),
+    (1492, "Arabic_sheen"),
+    (1493, "Arabic_sad"),
+    (1494, "Arabic_dad"),
+    (1495, "Arabic_tah"),
+    (1496, "Arabic_zah"),
+    (1497, "Arabic_ain"),
+    (1498, "Arabic_ghain"),
+    (1504, "Arabic_tatweel"),
+    (1505, "Arabic_feh"),
+    (1506, "Arabic_qaf"),
+    (1507, "Arabic_kaf"),
+    (1508, "Arabic_lam"),
+    (1509, "Arabic_meem"),
+    (1510, "Arabic_noon"),
+    (1511, "Arabic_ha"),
+    (1512, "Arabic_waw"),
+    (1513, "Arabic_alefmaksura"),
+    (1514, "Arabic_yeh"),
+    (1515, "Arabic_fathatan"),
+    (1516, "Arabic_dammatan"),
+    (1517, "Arabic_kasratan"),
+    (1518, "Arabic_fatha"),
+    (1519, "Arabic_damma"),
+    (1520, "Arabic_kasra"),
+    (1521, "Arabic_shadda"),
+    (1522, "Arabic_sukun"),
+    (1697, "Serbian_dje"),
+    (1698, "Macedonia_gje"),
+    (1699, "Cyrillic_io"),
+    (1700, "Ukrainian_ie"),
+    (1701, "Macedonia_dse"),
+    (1702, "Ukrainian_i"),
+    (1703, "Ukrainian_yi"),
+    (1704, "Cyrillic_je"),
+    (1705, "Cyrillic_lje"),
+    (1706, "Cyrillic_nje"),
+    (1707, "Serbian_tshe"),
+    (1708, "Macedonia_kje"),
+    (1709, "Ukrainian_ghe_with_upturn"),
+    (1710, "Byelorussian_shortu"),
+    (1711, "Cyrillic_dzhe"),
+    (1712, "numerosign"),
+    (1713, "Serbian_DJE"),
+    (1714, "Macedonia_GJE"),
+    (1715, "Cyrillic_IO"),
+    (1716, "Ukrainian_IE"),
+    (1717, "Macedonia_DSE"),
+    (1718, "Ukrainian_I"),
+    (1719, "Ukrainian_YI"),
+    (1720, "Cyrillic_JE"),
+    (1721, "Cyrillic_LJE"),
+    (1722, "Cyrillic_NJE"),
+    (1723, "Serbian_TSHE"),
+    (1724, "Macedonia_KJE"),
+    (1725, "Ukrainian_GHE_WITH_UPTURN"),
+    (1726, "Byelorussian_SHORTU"),
+    (1727, "Cyrillic_DZHE"),
+    (1728, "Cyrillic_yu"),
+    (1729, "Cyrillic_a"),
+    (1730, "Cyrillic_be"),
+    (1731, "Cyrillic_tse"),
+    (1732, "Cyrillic_de"),
+    (1733, "Cyrillic_ie"),
+    (1734, "Cyrillic_ef"),
+    (1735, "Cyrillic_ghe"),
+    (1736, "Cyrillic_ha"),
+    (1737, "Cyrillic_i"),
+    (1738, "Cyrillic_shorti"),
+    (1739, "Cyrillic_ka"),
+    (1740, "Cyrillic_el"),
+    (1741, "Cyrillic_em"),
+    (1742, "Cyrillic_en"),
+    (1743, "Cyrillic_o"),
+    (1744, "Cyrillic_pe"),
+    (1745, "Cyrillic_ya"),
+    (1746, "Cyrillic_er"),
+    (1747, "Cyrillic_es"),
+    (1748, "Cyrillic_te"),
+    (1749, "Cyrillic_u"),
+    (1750, "Cyrillic_zhe"),
+    (1751, "Cyrillic_ve"),
+    (1752, "Cyrillic_softsign"),
+    (1753, "Cyrillic_yeru"),
+    (1754, "Cyrillic_ze"),
+    (1755, "Cyrillic_sha"),
+    (1756, "Cyrillic_e"),
+    (1757, "Cyrillic_shcha"),
+    (1758, "Cyrillic_che"),
+    (1759, "Cyrillic_hardsign"),
+    (1760, "Cyrillic_YU"),
+    (1761, "Cyrillic_A"),
+    (1762, "Cyrillic_BE"),
+    (1763, "Cyrillic_TSE"),
+    (1764, "Cyrillic_DE"),
+    (1765, "Cyrillic_IE"),
+    (1766, "Cyrillic_EF"),
+    (1767, "Cyrillic_GHE"),
+    (1768, "Cyrillic_HA"),
+    (1769, "Cyrillic_I"),
+    (1770, "Cyrillic_SHORTI"),
+    (1771, "Cyrillic_KA"),
+    (1772, "Cyrillic_EL"),
+    (1773, "Cyrillic_EM"),
+    (1774, "Cyrillic_EN"),
+    (1775, "Cyrillic_O"),
+    (1776, "Cyrillic_PE"),
+    (1777, "Cyrillic_YA"),
+    (1778, "Cyrillic_ER"),
+    (1779, "Cyrillic_ES"),
+    (1780, "Cyrillic_TE"),
+    (1781, "Cyrillic_U"),
+    (1782, "Cyrillic_ZHE"),
+    (1783, "Cyrillic_VE"),
+    (1784, "Cyrillic_SOFTSIGN"),
+    (1785, "Cyrillic_YERU"),
+    (1786, "Cyrillic_ZE"),
+    (1787, "Cyrillic_SHA"),
+    (1788, "Cyrillic_E"),
+    (1789, "Cyrillic_SHCHA"),
+    (1790, "Cyrillic_CHE"),
+    (1791, "Cyrillic_HARDSIGN"),
+    (1953, "Greek_ALPHAaccent"),
+    (1954, "Greek_EPSILONaccent"),
+    (1955, "Greek_ETAaccent"),
+    (1956, "Greek_IOTAaccent"),
+    (1957, "Greek_IOTAdieresis"),
+    (1959, "Greek_OMICRONaccent"),
+    (1960, "Greek_UPSILONaccent"),
+    (1961, "Greek_UPSILONdieresis"),
+    (1963, "Greek_OMEGAaccent"),
+    (1966, "Greek_accentdieresis"),
+    (1967, "Greek_horizbar"),
+    (1969, "Greek_alphaaccent"),
+    (1970, "Greek_epsilonaccent"),
+    (1971, "Greek_etaaccent"),
+    (1972, "Greek_iotaaccent"),
+    (1973, "Greek_iotadieresis"),
+    (1974, "Greek_iotaaccentdieresis"),
+    (1975, "Greek_omicronaccent"),
+    (1976, "Greek_upsilonaccent"),
+    (1977, "Greek_upsilondieresis"),
+    (1978, "Greek_upsilonaccentdieresis"),
+    (1979, "Greek_omegaaccent"),
+    (1985, "Greek_ALPHA"),
+    (1986, "Greek_BETA"),
+    (1987, "Greek_GAMMA"),
+    (1988, "Greek_DELTA"),
+    (1989, "Greek_EPSILON"),
+    (1990, "Greek_ZETA"),
+    (1991, "Greek_ETA"),
+    (1992, "Greek_THETA"),
+    (1993, "Greek_IOTA"),
+    (1994, "Greek_KAPPA"),
+    (1995, "Greek_LAMDA"),
+    (1996, "Greek_MU"),
+    (1997, "Greek_NU"),
+    (1998, "Greek_XI"),
+    (1999, "Greek_OMICRON"),
+    (2000, "Greek_PI"),
+    (2001, "Greek_RHO"),
+    (2002, "Greek_SIGMA"),
+    (2004, "Greek_TAU"),
+    (2005, "Greek_UPSILON"),
+    (2006, "Greek_PHI"),
+    (2007, "Greek_CHI"),
+    (2008, "Greek_PSI"),
+    (2009, "Greek_OMEGA"),
+    (2017, "Greek_alpha"),
+    (2018, "Greek_beta"),
+    (2019, "Greek_gamma"),
+    (2020, "Greek_delta"),
+    (2021, "Greek_epsilon"),
+    (2022, "Greek_zeta"),
+    (2023, "Greek_eta"),
+    (2024, "Greek_theta"),
+    (2025, "Greek_iota"),
+    (2026, "Greek_kappa"),
+    (2027, "Greek_lamda"),
+    (2028, "Greek_mu"),
+    (2029, "Greek_nu"),
+    (2030, "Greek_xi"),
+    (2031, "Greek_omicron"),
+    (2032, "Greek_pi"),
+    (2033, "Greek_rho"),
+    (2034, "Greek_sigma"),
+    (2035, "Greek_finalsmallsigma"),
+    (2036, "Greek_tau"),
+    (2037, "Greek_upsilon"),
+    (2038, "Greek_phi"),
+    (2039, "Greek_chi"),
+    (2040, "Greek_psi"),
+    (2041, "Greek_omega"),
+    (2209, "leftradical"),
+    (2210, "topleftradical"),
+    (2211, "horizconnector"),
+    (2212, "topintegral"),
+    (2213, "botintegral"),
+    (2214, "vertconnector"),
+    (2215, "topleftsqbracket"),
+    (2216, "botleftsqbracket"),
+    (2217, "toprightsqbracket"),
+    (2218, "botrightsqbracket"),
+    (2219, "topleftparens"),
+    (2220, "botleftparens"),
+    (2221, "toprightparens"),
+    (2222, "botrightparens"),
+    (2223, "leftmiddlecurlybrace"),
+    (2224, "rightmiddlecurlybrace"),
+    (2225, "topleftsummation"),
+    (2226, "botleftsummation"),
+    (2227, "topvertsummationconnector"),
+    (2228, "botvertsummationconnector"),
+    (2229, "toprightsummation"),
+    (2230, "botrightsummation"),
+    (2231, "rightmiddlesummation"),
+    (2236, "lessthanequal"),
+    (2237, "notequal"),
+    (2238, "greaterthanequal"),
+    (2239, "integral"),
+    (2240, "therefore"),
+    (2241, "variation"),
+    (2242, "infinity"),
+    (2245, "nabla"),
+    (2248, "approximate"),
+    (2249, "similarequal"),
+    (2253, "ifonlyif"),
+    (2254, "implies"),
+    (2255, "identical"),
+    (2262, "radical"),
+    (2266, "includedin"),
+    (2267, "includes"),
+    (2268, "intersection"),
+    (2269, "union"),
+    (2270, "logicaland"),
+    (2271, "logicalor"),
+    (2287, "partialderivative"),
+    (2294, "function"),
+    (2299, "leftarrow"),
+    (2300, "uparrow"),
+    (2301, "rightarrow"),
+    (2302, "downarrow"),
+    (2527, "blank"),
+    (2528, "soliddiamond"),
+    (2529, "checkerboard"),
+    (2530, "ht"),
+    (2531, "ff"),
+    (2532, "cr"),
+    (2533, "lf"),
+    (2536, "nl"),
+    (2537, "vt"),
+    (2538, "lowrightcorner"),
+    (2539, "uprightcorner"),
+    (2540, "upleftcorner"),
+    (2541, "lowleftcorner"),
+    (2542, "crossinglines"),
+    (2543, "horizlinescan1"),
+    (2544, "horizlinescan3"),
+    (2545, "horizlinescan5"),
+    (2546, "horizlinescan7"),
+    (2547, "horizlinescan9"),
+    (2548, "leftt"),
+    (2549, "rightt"),
+    (2550, "bott"),
+    (2551, "topt"),
+    (2552, "vertbar"),
+    (2721, "emspace"),
+    (2722, "enspace"),
+    (2723, "em3space"),
+    (2724, "em4space"),
+    (2725, "digitspace"),
+    (2726, "punctspace"),
+    (2727, "thinspace"),
+    (2728, "hairspace"),
+    (2729, "emdash"),
+    (2730, "endash"),
+    (2732, "signifblank"),
+    (2734, "ellipsis"),
+    (2735, "doubbaselinedot"),
+    (2736, "onethird"),
+    (2737, "twothirds"),
+    (2738, "onefifth"),
+    (2739, "twofifths"),
+    (2740, "threefifths"),
+    (2741, "fourfifths"),
+    (2742, "onesixth"),
+    (2743, "fivesixths"),
+    (2744, "careof"),
+    (2747, "figdash"),
+    (2748, "leftanglebracket"),
+    (2749, "decimalpoint"),
+    (2750, "rightanglebracket"),
+    (2751, "marker"),
+    (2755, "oneeighth"),
+    (2756, "threeeighths"),
+    (2757, "fiveeighths"),
+    (2758, "seveneighths"),
+    (2761, "trademark"),
+    (2762, "signaturemark"),
+    (2763, "trademarkincircle"),
+    (2764, "leftopentriangle"),
+    (2765, "rightopentriangle"),
+    (2766, "emopencircle"),
+    (2767, "emopenrectangle"),
+    (2768, "leftsinglequotemark"),
+    (2769, "rightsinglequotemark"),
+    (2770, "leftdoublequotemark"),
+    (2771, "rightdoublequotemark"),
+    (2772, "prescription"),
+    (2773, "permille"),
+    (2774, "minutes"),
+    (2775, "seconds"),
+    (2777, "latincross"),
+    (2778, "hexagram"),
+    (2779, "filledrectbullet"),
+    (2780, "filledlefttribullet"),
+    (2781, "filledrighttribullet"),
+    (2782, "emfilledcircle"),
+    (2783, "emfilledrect"),
+    (2784, "enopencircbullet"),
+    (2785, "enopensquarebullet"),
+    (2786, "openrectbullet"),
+    (2787, "opentribulletup"),
+    (2788, "opentribulletdown"),
+    (2789, "openstar"),
+    (2790, "enfilledcircbullet"),
+    (2791, "enfilledsqbullet"),
+    (2792, "filledtribulletup"),
+    (2793, "filledtribulletdown"),
+    (2794, "leftpointer"),
+    (2795, "rightpointer"),
+    (2796, "club"),
+    (2797, "diamond"),
+    (2798, "heart"),
+    (2800, "maltesecross"),
+    (2801, "dagger"),
+    (2802, "doubledagger"),
+    (2803, "checkmark"),
+    (2804, "ballotcross"),
+    (2805, "musicalsharp"),
+    (2806, "musicalflat"),
+    (2807, "malesymbol"),
+    (2808, "femalesymbol"),
+    (2809, "telephone"),
+    (2810, "telephonerecorder"),
+    (2811, "phonographcopyright"),
+    (2812, "caret"),
+    (2813, "singlelowquotemark"),
+    (2814, "doublelowquotemark"),
+    (2815, "cursor"),
+    (2979, "leftcaret"),
+    (2982, "rightcaret"),
+    (2984, "downcaret"),
+    (2985, "upcaret"),
+    (3008, "overbar"),
+    (3010, "downtack"),
+    (3011, "upshoe"),
+    (3012, "downstile"),
+    (3014, "underbar"),
+    (3018, "jot"),
+    (3020, "quad"),
+    (3022, "uptack"),
+    (3023, "circle"),
+    (3027, "upstile"),
+    (3030, "downshoe"),
+    (3032, "rightshoe"),
+    (3034, "leftshoe"),
+    (3036, "lefttack"),
+    (3068, "righttack"),
+    (3295, "hebrew_doublelowline"),
+    (3296, "hebrew_aleph"),
+    (3297, "hebrew_bet"),
+    (3298, "hebrew_gimel"),
+    (3299, "hebrew_dalet"),
+    (3300, "hebrew_he"),
+    (3301, "hebrew_waw"),
+    (3302, "hebrew_zain"),
+    (3303, "hebrew_chet"),
+    (3304, "hebrew_tet"),
+    (3305, "hebrew_yod"),
+    (3306, "hebrew_finalkaph"),
+    (3307, "hebrew_kaph"),
+    (3308, "hebrew_lamed"),
+    (3309, "hebrew_finalmem"),
+    (3310, "hebrew_mem"),
+    (3311, "hebrew_finalnun"),
+    (3312, "hebrew_nun"),
+    (3313, "hebrew_samech"),
+    (3314, "hebrew_ayin"),
+    (3315, "hebrew_finalpe"),
+    (3316, "hebrew_pe"),
+    (3317, "hebrew_finalzade"),
+    (3318, "hebrew_zade"),
+    (3319, "hebrew_qoph"),
+    (3320, "hebrew_resh"),
+    (3321, "hebrew_shin"),
+    (3322, "hebrew_taw"),
+    (3489, "Thai_kokai"),
+    (3490, "Thai_khokhai"),
+    (3491, "Thai_khokhuat"),
+    (3492, "Thai_khokhwai"),
+    (3493, "Thai_khokhon"),
+    (3494, "Thai_khorakhang"),
+    (3495, "Thai_ngongu"),
+    (3496, "Thai_chochan"),
+    (3497, "Thai_choching"),
+    (3498, "Thai_chochang"),
+    (3499, "Thai_soso"),
+    (3500, "Thai_chochoe"),
+    (3501, "Thai_yoying"),
+    (3502, "Thai_dochada"),
+    (3503, "Thai_topatak"),
+    (3504, "Thai_thothan"),
+    (3505, "Thai_thonangmontho"),
+    (3506, "Thai_thophuthao"),
+    (3507, "Thai_nonen"),
+    (3508, "Thai_dodek"),
+    (3509, "Thai_totao"),
+    (3510, "Thai_thothung"),
+    (3511, "Thai_thothahan"),
+    (3512, "Thai_thothong"),
+    (3513, "Thai_nonu"),
+    (3514, "Thai_bobaimai"),
+    (3515, "Thai_popla"),
+    (3516, "Thai_phophung"),
+    (3517, "Thai_fofa"),
+    (3518, "Thai_phophan"),
+    (3519, "Thai_fofan"),
+    (3520, "Thai_phosamphao"),
+    (3521, "Thai_moma"),
+    (3522, "Thai_yoyak"),
+    (3523, "Thai_rorua"),
+    (3524, "Thai_ru"),
+    (3525, "Thai_loling"),
+    (3526, "Thai_lu"),
+    (3527, "Thai_wowaen"),
+    (3528, "Thai_sosala"),
+    (3529, "Thai_sorusi"),
+    (3530, "Thai_sosua"),
+    (3531, "Thai_hohip"),
+    (3532, "Thai_lochula"),
+    (3533, "Thai_oang"),
+    (3534, "Thai_honokhuk"),
+    (3535, "Thai_paiyannoi"),
+    (3536, "Thai_saraa"),
+    (3537, "Thai_maihanakat"),
+    (3538, "Thai_saraaa"),
+    (3539, "Thai_saraam"),
+    (3540, "Thai_sarai"),
+    (3541, "Thai_saraii"),
+    (3542, "Thai_saraue"),
+    (3543, "Thai_sarauee"),
+    (3544, "Thai_sarau"),
+    (3545, "Thai_sarauu"),
+    (3546, "Thai_phinthu"),
+    (3550, "Thai_maihanakat_maitho"),
+    (3551, "Thai_baht"),
+    (3552, "Thai_sarae"),
+    (3553, "Thai_saraae"),
+    (3554, "Thai_sarao"),
+    (3555, "Thai_saraaimaimuan"),
+    (3556, "Thai_saraaimaimalai"),
+    (3557, "Thai_lakkhangyao"),
+    (3558, "Thai_maiyamok"),
+    (3559, "Thai_maitaikhu"),
+    (3560, "Thai_maiek"),
+    (3561, "Thai_maitho"),
+    (3562, "Thai_maitri"),
+    (3563, "Thai_maichattawa"),
+    (3564, "Thai_thanthakhat"),
+    (3565, "Thai_nikhahit"),
+    (3568, "Thai_leksun"),
+    (3569, "Thai_leknung"),
+    (3570, "Thai_leksong"),
+    (3571, "Thai_leksam"),
+    (3572, "Thai_leksi"),
+    (3573, "Thai_lekha"),
+    (3574, "Thai_lekhok"),
+    (3575, "Thai_lekchet"),
+    (3576, "Thai_lekpaet"),
+    (3577, "Thai_lekkao"),
+    (3745, "Hangul_Kiyeog"),
+    (3746, "Hangul_SsangKiyeog"),
+    (3747, "Hangul_KiyeogSios"),
+    (3748, "Hangul_Nieun"),
+    (3749, "Hangul_NieunJieuj"),
+    (3750, "Hangul_NieunHieuh"),
+    (3751, "Hangul_Dikeud"),
+    (3752, "Hangul_SsangDikeud"),
+    (3753, "Hangul_Rieul"),
+    (3754, "Hangul_RieulKiyeog"),
+    (3755, "Hangul_RieulMieum"),
+    (3756, "Hangul_RieulPieub"),
+    (3757, "Hangul_RieulSios"),
+    (3758, "Hangul_RieulTieut"),
+    (3759, "Hangul_RieulPhieuf"),
+    (3760, "Hangul_RieulHieuh"),
+    (3761, "Hangul_Mieum"),
+    (3762, "Hangul_Pieub"),
+    (3763, "Hangul_SsangPieub"),
+    (3764, "Hangul_PieubSios"),
+    (3765, "Hangul_Sios"),
+    (3766, "Hangul_SsangSios"),
+    (3767, "Hangul_Ieung"),
+    (3768, "Hangul_Jieuj"),
+    (3769, "Hangul_SsangJieuj"),
+    (3770, "Hangul_Cieuc"),
+    (3771, "Hangul_Khieuq"),
+    (3772, "Hangul_Tieut"),
+    (3773, "Hangul_Phieuf"),
+    (3774, "Hangul_Hieuh"),
+    (3775, "Hangul_A"),
+    (3776, "Hangul_AE"),
+    (3777, "Hangul_YA"),
+    (3778, "Hangul_YAE"),
+    (3779, "Hangul_EO"),
+    (3780, "Hangul_E"),
+    (3781, "Hangul_YEO"),
+    (3782, "Hangul_YE"),
+    (3783, "Hangul_O"),
+    (3784, "Hangul_WA"),
+    (3785, "Hangul_WAE"),
+    (3786, "Hangul_OE"),
+    (3787, "Hangul_YO"),
+    (3788, "Hangul_U"),
+    (3789, "Hangul_WEO"),
+    (3790, "Hangul_WE"),
+    (3791, "Hangul_WI"),
+    (3792, "Hangul_YU"),
+    (3793, "Hangul_EU"),
+    (3794, "Hangul_YI"),
+    (3795, "Hangul_I"),
+    (3796, "Hangul_J_Kiyeog"),
+    (3797, "Hangul_J_SsangKiyeog"),
+    (3798, "Hangul_J_KiyeogSios"),
+    (3799, "Hangul_J_Nieun"),
+    (3800, "Hangul_J_NieunJieuj"),
+    (3801, "Hangul_J_NieunHieuh"),
+    (3802, "Hangul_J_Dikeud"),
+    (3803, "Hangul_J_Rieul"),
+    (3804, "Hangul_J_RieulKiyeog"),
+    (3805, "Hangul_J_RieulMieum"),
+    (3806, "Hangul_J_RieulPieub"),
+    (3807, "Hangul_J_RieulSios"),
+    (3808, "Hangul_J_RieulTieut"),
+    (3809, "Hangul_J_RieulPhieuf"),
+    (3810, "Hangul_J_RieulHieuh"),
+    (3811, "Hangul_J_Mieum"),
+    (3812, "Hangul_J_Pieub"),
+    (3813, "Hangul_J_PieubSios"),
+    (3814, "Hangul_J_Sios"),
+    (3815, "Hangul_J_SsangSios"),
+    (3816, "Hangul_J_Ieung"),
+    (3817, "Hangul_J_Jieuj"),
+    (3818, "Hangul_J_Cieuc"),
+    (3819, "Hangul_J_Khieuq"),
+    (3820, "Hangul_J_Tieut"),
+    (3821, "Hangul_J_Phieuf"),
+    (3822, "Hangul_J_Hieuh"),
+    (3823, "Hangul_RieulYeorinHieuh"),
+    (3824, "Hangul_SunkyeongeumMieum"),
+    (3825, "Hangul_SunkyeongeumPieub"),
+    (3826, "Hangul_PanSios"),
+    (3827, "Hangul_KkogjiDalrinIeung"),
+    (3828, "Hangul_SunkyeongeumPhieuf"),
+    (3829, "Hangul_YeorinHieuh"),
+    (3830, "Hangul_AraeA"),
+    (3831, "Hangul_AraeAE"),
+    (3832, "Hangul_J_PanSios"),
+    (3833, "Hangul_J_KkogjiDalrinIeung"),
+    (3834, "Hangul_J_YeorinHieuh"),
+    (3839, "Korean_Won"),
+    (5052, "OE"),
+    (5053, "oe"),
+    (5054, "Ydiaeresis"),
+    (8364, "EuroSign"),
+    (64769, "3270_Duplicate"),
+    (64770, "3270_FieldMark"),
+    (64771, "3270_Right2"),
+    (64772, "3270_Left2"),
+    (64773, "3270_BackTab"),
+    (64774, "3270_EraseEOF"),
+    (64775, "3270_EraseInput"),
+    (64776, "3270_Reset"),
+    (64777, "3270_Quit"),
+    (64778, "3270_PA1"),
+    (64779, "3270_PA2"),
+    (64780, "3270_PA3"),
+    (64781, "3270_Test"),
+    (64782, "3270_Attn"),
+    (64783, "3270_CursorBlink"),
+    (64784, "3270_AltCursor"),
+    (64785, "3270_KeyClick"),
+    (64786, "3270_Jump"),
+    (64787, "3270_Ident"),
+    (64788, "3270_Rule"),
+    (64789, "3270_Copy"),
+    (64790, "3270_Play"),
+    (64791, "3270_Setup"),
+    (64792, "3270_Record"),
+    (64793, "3270_ChangeScreen"),
+    (64794, "3270_DeleteWord"),
+    (64795, "3270_ExSelect"),
+    (64796, "3270_CursorSelect"),
+    (64797, "3270_PrintScreen"),
+    (64798, "3270_Enter"),
+    (65025, "ISO_Lock"),
+    (65026, "ISO_Level2_Latch"),
+    (65027, "ISO_Level3_Shift"),
+    (65028, "ISO_Level3_Latch"),
+    (65029, "ISO_Level3_Lock"),
+    (65030, "ISO_Group_Latch"),
+    (65031, "ISO_Group_Lock"),
+    (65032, "ISO_Next_Group"),
+    (65033, "ISO_Next_Group_Lock"),
+    (65034, "ISO_Prev_Group"),
+    (65035, "ISO_Prev_Group_Lock"),
+    (65036, "ISO_First_Group"),
+    (65037, "ISO_First_Group_Lock"),
+    (65038, "ISO_Last_Group"),
+    (65039, "ISO_Last_Group_Lock"),
+    (65041, "ISO_Level5_Shift"),
+    (65042, "ISO_Level5_Latch"),
+    (65043, "ISO_Level5_Lock"),
+    (65056, "ISO_Left_Tab"),
+    (65057, "ISO_Move_Line_Up"),
+    (65058, "ISO_Move_Line_Down"),
+    (65059, "ISO_Partial_Line_Up"),
+    (65060, "ISO_Partial_Line_Down"),
+    (65061, "ISO_Partial_Space_Left"),
+    (65062, "ISO_Partial_Space_Right"),
+    (65063, "ISO_Set_Margin_Left"),
+    (65064, "ISO_Set_Margin_Right"),
+    (65065, "ISO_Release_Margin_Left"),
+    (65066, "ISO_Release_Margin_Right"),
+    (65067, "ISO_Release_Both_Margins"),
+    (65068, "ISO_Fast_Cursor_Left"),
+    (65069, "ISO_Fast_Cursor_Right"),
+    (65070, "ISO_Fast_Cursor_Up"),
+    (65071, "ISO_Fast_Cursor_Down"),
+    (65072, "ISO_Continuous_Underline"),
+    (65073, "ISO_Discontinuous_Underline"),
+    (65074, "ISO_Emphasize"),
+    (65075, "ISO_Center_Object"),
+    (65076, "ISO_Enter"),
+    (65104, "dead_grave"),
+    (65105, "dead_acute"),
+    (65106, "dead_circumflex"),
+    (65107, "dead_tilde"),
+    (65108, "dead_macron"),
+    (65109, "dead_breve"),
+    (65110, "dead_abovedot"),
+    (65111, "dead_diaeresis"),
+    (65112, "dead_abovering"),
+    (65113, "dead_doubleacute"),
+    (65114, "dead_caron"),
+    (65115, "dead_cedilla"),
+    (65116, "dead_ogonek"),
+    (65117, "dead_iota"),
+    (65118, "dead_voiced_sound"),
+    (65119, "dead_semivoiced_sound"),
+    (65120, "dead_belowdot"),
+    (65121, "dead_hook"),
+    (65122, "dead_horn"),
+    (65123, "dead_stroke"),
+    (65124, "dead_abovecomma"),
+    (65125, "dead_abovereversedcomma"),
+    (65126, "dead_doublegrave"),
+    (65127, "dead_belowring"),
+    (65128, "dead_belowmacron"),
+    (65129, "dead_belowcircumflex"),
+    (65130, "dead_belowtilde"),
+    (65131, "dead_belowbreve"),
+    (65132, "dead_belowdiaeresis"),
+    (65133, "dead_invertedbreve"),
+    (65134, "dead_belowcomma"),
+    (65135, "dead_currency"),
+    (65136, "AccessX_Enable"),
+    (65137, "AccessX_Feedback_Enable"),
+    (65138, "RepeatKeys_Enable"),
+    (65139, "SlowKeys_Enable"),
+    (65140, "BounceKeys_Enable"),
+    (65141, "StickyKeys_Enable"),
+    (65142, "MouseKeys_Enable"),
+    (65143, "MouseKeys_Accel_Enable"),
+    (65144, "Overlay1_Enable"),
+    (65145, "Overlay2_Enable"),
+    (65146, "AudibleBell_Enable"),
+    (65152, "dead_a"),
+    (65153, "dead_A"),
+    (65154, "dead_e"),
+    (65155, "dead_E"),
+    (65156, "dead_i"),
+    (65157, "dead_I"),
+    (65158, "dead_o"),
+    (65159, "dead_O"),
+    (65160, "dead_u"),
+    (65161, "dead_U"),
+    (65162, "dead_small_schwa"),
+    (65163, "dead_capital_schwa"),
+    (65164, "dead_greek"),
+    (65168, "dead_lowline"),
+    (65169, "dead_aboveverticalline"),
+    (65170, "dead_belowverticalline"),
+    (65171, "dead_longsolidusoverlay"),
+    (65184, "ch"),
+    (65185, "Ch"),
+    (65186, "CH"),
+    (65187, "c_h"),
+    (65188, "C_h"),
+    (65189, "C_H"),
+    (65232, "First_Virtual_Screen"),
+    (65233, "Prev_Virtual_Screen"),
+    (65234, "Next_Virtual_Screen"),
+    (65236, "Last_Virtual_Screen"),
+    (65237, "Terminate_Server"),
+    (65248, "Pointer_Left"),
+    (65249, "Pointer_Right"),
+    (65250, "Pointer_Up"),
+    (65251, "Pointer_Down"),
+    (65252, "Pointer_UpLeft"),
+    (65253, "Pointer_UpRight"),
+    (65254, "Pointer_DownLeft"),
+    (65255, "Pointer_DownRight"),
+    (65256, "Pointer_Button_Dflt"),
+    (65257, "Pointer_Button1"),
+    (65258, "Pointer_Button2"),
+    (65259, "Pointer_Button3"),
+    (65260, "Pointer_Button4"),
+    (65261, "Pointer_Button5"),
+    (65262, "Pointer_DblClick_Dflt"),
+    (65263, "Pointer_DblClick1"),
+    (65264, "Pointer_DblClick2"),
+    (65265, "Pointer_DblClick3"),
+    (65266, "Pointer_DblClick4"),
+    (65267, "Pointer_DblClick5"),
+    (65268, "Pointer_Drag_Dflt"),
+    (65269, "Pointer_Drag1"),
+    (65270, "Pointer_Drag2"),
+    (65271, "Pointer_Drag3"),
+    (65272, "Pointer_Drag4"),
+    (65273, "Pointer_EnableKeys"),
+    (65274, "Pointer_Accelerate"),
+    (65275, "Pointer_DfltBtnNext"),
+    (65276, "Pointer_DfltBtnPrev"),
+    (65277, "Pointer_Drag5"),
+    (65288, "BackSpace"),
+    (65289, "Tab"),
+    (65290, "Linefeed"),
+    (65291, "Clear"),
+    (65293, "Return"),
+    (65299, "Pause"),
+    (65300, "Scroll_Lock"),
+    (65301, "Sys_Req"),
+    (65307, "Escape"),
+    (65312, "Multi_key"),
+    (65313, "Kanji"),
+    (65314, "Muhenkan"),
+    (65315, "Henkan_Mode"),
+    (65316, "Romaji"),
+    (65317, "Hiragana"),
+    (65318, "Katakana"),
+    (65319, "Hiragana_Katakana"),
+    (65320, "Zenkaku"),
+    (65321, "Hankaku"),
+    (65322, "Zenkaku_Hankaku"),
+    (65323, "Touroku"),
+    (65324, "Massyo"),
+    (65325, "Kana_Lock"),
+    (65326, "Kana_Shift"),
+    (65327, "Eisu_Shift"),
+    (65328, "Eisu_toggle"),
+    (65329, "Hangul"),
+    (65330, "Hangul_Start"),
+    (65331, "Hangul_End"),
+    (65332, "Hangul_Hanja"),
+    (65333, "Hangul_Jamo"),
+    (65334, "Hangul_Romaja"),
+    (65335, "Codeinput"),
+    (65336, "Hangul_Jeonja"),
+    (65337, "Hangul_Banja"),
+    (65338, "Hangul_PreHanja"),
+    (65339, "Hangul_PostHanja"),
+    (65340, "SingleCandidate"),
+    (65341, "MultipleCandidate"),
+    (65342, "PreviousCandidate"),
+    (65343, "Hangul_Special"),
+    (65360, "Home"),
+    (65361, "Left"),
+    (65362, "Up"),
+    (65363, "Right"),
+    (65364, "Down"),
+    (65365, "Prior"),
+    (65366, "Next"),
+    (65367, "End"),
+    (65368, "Begin"),
+    (65376, "Select"),
+    (65377, "Print"),
+    (65378, "Execute"),
+    (65379, "Insert"),
+    (65381, "Undo"),
+    (65382, "Redo"),
+    (65383, "Menu"),
+    (65384, "Find"),
+    (65385, "Cancel"),
+    (65386, "Help"),
+    (65387, "Break"),
+    (65406, "Mode_switch"),
+    (65407, "Num_Lock"),
+    (65408, "KP_Space"),
+    (65417, "KP_Tab"),
+    (65421, "KP_Enter"),
+    (65425, "KP_F1"),
+    (65426, "KP_F2"),
+    (65427, "KP_F3"),
+    (65428, "KP_F4"),
+    (65429, "KP_Home"),
+    (65430, "KP_Left"),
+    (65431, "KP_Up"),
+    (65432, "KP_Right"),
+    (65433, "KP_Down"),
+    (65434, "KP_Prior"),
+    (65435, "KP_Next"),
+    (65436, "KP_End"),
+    (65437, "KP_Begin"),
+    (65438, "KP_Insert"),
+    (65439, "KP_Delete"),
+    (65450, "KP_Multiply"),
+    (65451, "KP_Add"),
+    (65452, "KP_Separator"),
+    (65453, "KP_Subtract"),
+    (65454, "KP_Decimal"),
+    (65455, "KP_Divide"),
+    (65456, "KP_0"),
+    (65457, "KP_1"),
+    (65458, "KP_2"),
+    (65459, "KP_3"),
+    (65460, "KP_4"),
+    (65461, "KP_5"),
+    (65462, "KP_6"),
+    (65463, "KP_7"),
+    (65464, "KP_8"),
+    (65465, "KP_9"),
+    (65469, "KP_Equal"),
+    (65470, "F1"),
+    (65471, "F2"),
+    (65472, "F3"),
+    (65473, "F4"),
+    (65474, "F5"),
+    (65475, "F6"),
+    (65476, "F7"),
+    (65477, "F8"),
+    (65478, "F9"),
+    (65479, "F10"),
+    (65480, "F11"),
+    (65481, "F12"),
+    (65482, "F13"),
+    (65483, "F14"),
+    (65484, "F15"),
+    (65485, "F16"),
+    (65486, "F17"),
+    (65487, "F18"),
+    (65488, "F19"),
+    (65489, "F20"),
+    (65490, "F21"),
+    (65491, "F22"),
+    (65492, "F23"),
+    (65493, "F24"),
+    (65494, "F25"),
+    (65495, "F26"),
+    (65496, "F27"),
+    (65497, "F28"),
+    (65498, "F29"),
+    (65499, "F30"),
+    (65500, "F31"),
+    (65501, "F32"),
+    (65502, "F33"),
+    (65503, "F34"),
+    (65504, "F35"),
+    (65505, "Shift_L"),
+    (65506, "Shift_R"),
+    (65507, "Control_L"),
+    (65508, "Control_R"),
+    (65509, "Caps_Lock"),
+    (65510, "Shift_Lock"),
+    (65511, "Meta_L"),
+    (65512, "Meta_R"),
+    (65513, "Alt_L"),
+    (65514, "Alt_R"),
+    (65515, "Super_L"),
+    (65516, "Super_R"),
+    (65517, "Hyper_L"),
+    (65518, "Hyper_R"),
+    (65521, "braille_dot_1"),
+    (65522, "braille_dot_2"),
+    (65523, "braille_dot_3"),
+    (65524, "braille_dot_4"),
+    (65525, "braille_dot_5"),
+    (65526, "braille_dot_6"),
+    (65527, "braille_dot_7"),
+    (65528, "braille_dot_8"),
+    (65529, "braille_dot_9"),
+    (65530, "braille_dot_10"),
+    (65535, "Delete"),
+    (16777215, "VoidSymbol"),
+    (16777516, "Ibreve"),
+    (16777517, "ibreve"),
+    (16777588, "Wcircumflex"),
+    (16777589, "wcircumflex"),
+    (16777590, "Ycircumflex"),
+    (16777591, "ycircumflex"),
+    (16777615, "SCHWA"),
+    (16777631, "Obarred"),
+    (16777632, "Ohorn"),
+    (16777633, "ohorn"),
+    (16777647, "Uhorn"),
+    (16777648, "uhorn"),
+    (16777653, "Zstroke"),
+    (16777654, "zstroke"),
+    (16777655, "EZH"),
+    (16777681, "Ocaron"),
+    (16777682, "ocaron"),
+    (16777702, "Gcaron"),
+    (16777703, "gcaron"),
+    (16777817, "schwa"),
+    (16777845, "obarred"),
+    (16777874, "ezh"),
+    (16777984, "combining_grave"),
+    (16777985, "combining_acute"),
+    (16777987, "combining_tilde"),
+    (16777993, "combining_hook"),
+    (16778019, "combining_belowdot"),
+    (16778386, "Cyrillic_GHE_bar"),
+    (16778387, "Cyrillic_ghe_bar"),
+    (16778390, "Cyrillic_ZHE_descender"),
+    (16778391, "Cyrillic_zhe_descender"),
+    (16778394, "Cyrillic_KA_descender"),
+    (16778395, "Cyrillic_ka_descender"),
+    (16778396, "Cyrillic_KA_vertstroke"),
+    (16778397, "Cyrillic_ka_vertstroke"),
+    (16778402, "Cyrillic_EN_descender"),
+    (16778403, "Cyrillic_en_descender"),
+    (16778414, "Cyrillic_U_straight"),
+    (16778415, "Cyrillic_u_straight"),
+    (16778416, "Cyrillic_U_straight_bar"),
+    (16778417, "Cyrillic_u_straight_bar"),
+    (16778418, "Cyrillic_HA_descender"),
+    (16778419, "Cyrillic_ha_descender"),
+    (16778422, "Cyrillic_CHE_descender"),
+    (16778423, "Cyrillic_che_descender"),
+    (16778424, "Cyrillic_CHE_vertstroke"),
+    (16778425, "Cyrillic_che_vertstroke"),
+    (16778426, "Cyrillic_SHHA"),
+    (16778427, "Cyrillic_shha"),
+    (16778456, "Cyrillic_SCHWA"),
+    (16778457, "Cyrillic_schwa"),
+    (16778466, "Cyrillic_I_macron"),
+    (16778467, "Cyrillic_i_macron"),
+    (16778472, "Cyrillic_O_bar"),
+    (16778473, "Cyrillic_o_bar"),
+    (16778478, "Cyrillic_U_macron"),
+    (16778479, "Cyrillic_u_macron"),
+    (16778545, "Armenian_AYB"),
+    (16778546, "Armenian_BEN"),
+    (16778547, "Armenian_GIM"),
+    (16778548, "Armenian_DA"),
+    (16778549, "Armenian_YECH"),
+    (16778550, "Armenian_ZA"),
+    (16778551, "Armenian_E"),
+    (16778552, "Armenian_AT"),
+    (16778553, "Armenian_TO"),
+    (16778554, "Armenian_ZHE"),
+    (16778555, "Armenian_INI"),
+    (16778556, "Armenian_LYUN"),
+    (16778557, "Armenian_KHE"),
+    (16778558, "Armenian_TSA"),
+    (16778559, "Armenian_KEN"),
+    (16778560, "Armenian_HO"),
+    (16778561, "Armenian_DZA"),
+    (16778562, "Armenian_GHAT"),
+    (16778563, "Armenian_TCHE"),
+    (16778564, "Armenian_MEN"),
+    (16778565, "Armenian_HI"),
+    (16778566, "Armenian_NU"),
+    (16778567, "Armenian_SHA"),
+    (16778568, "Armenian_VO"),
+    (16778569, "Armenian_CHA"),
+    (16778570, "Armenian_PE"),
+    (16778571, "Armenian_JE"),
+    (16778572, "Armenian_RA"),
+    (16778573, "Armenian_SE"),
+    (16778574, "Armenian_VEV"),
+    (16778575, "Armenian_TYUN"),
+    (16778576, "Armenian_RE"),
+    (16778577, "Armenian_TSO"),
+    (16778578, "Armenian_VYUN"),
+    (16778579, "Armenian_PYUR"),
+    (16778580, "Armenian_KE"),
+    (16778581, "Armenian_O"),
+    (16778582, "Armenian_FE"),
+    (16778586, "Armenian_apostrophe"),
+    (16778587, "Armenian_accent"),
+    (16778588, "Armenian_exclam"),
+    (16778589, "Armenian_separation_mark"),
+    (16778590, "Armenian_question"),
+    (16778593, "Armenian_ayb"),
+    (16778594, "Armenian_ben"),
+    (16778595, "Armenian_gim"),
+    (16778596, "Armenian_da"),
+    (16778597, "Armenian_yech"),
+    (16778598, "Armenian_za"),
+    (16778599, "Armenian_e"),
+    (16778600, "Armenian_at"),
+    (16778601, "Armenian_to"),
+    (16778602, "Armenian_zhe"),
+    (16778603, "Armenian_ini"),
+    (16778604, "Armenian_lyun"),
+    (16778605, "Armenian_khe"),
+    (16778606, "Armenian_tsa"),
+    (16778607, "Armenian_ken"),
+    (16778608, "Armenian_ho"),
+    (16778609, "Armenian_dza"),
+    (16778610, "Armenian_ghat"),
+    (16778611, "Armenian_tche"),
+    (16778612, "Armenian_men"),
+    (16778613, "Armenian_hi"),
+    (16778614, "Armenian_nu"),
+    (16778615, "Armenian_sha"),
+    (16778616, "Armenian_vo"),
+    (16778617, "Armenian_cha"),
+    (16778618, "Armenian_pe"),
+    (16778619, "Armenian_je"),
+    (16778620, "Armenian_ra"),
+    (16778621, "Armenian_se"),
+    (16778622, "Armenian_vev"),
+    (16778623, "Armenian_tyun"),
+    (16778624, "Armenian_re"),
+    (16778625, "Armenian_tso"),
+    (16778626, "Armenian_vyun"),
+    (16778627, "Armenian_pyur"),
+    (16778628, "Armenian_ke"),
+    (16778629, "Armenian_o"),
+    (16778630, "Armenian_fe"),
+    (16778631, "Armenian_ligature_ew"),
+    (16778633, "Armenian_full_stop"),
+    (16778634, "Armenian_hyphen"),
+    (16778835, "Arabic_madda_above"),
+    (16778836, "Arabic_hamza_above"),
+    (16778837, "Arabic_hamza_below"),
+    (16778848, "Arabic_0"),
+    (16778849, "Arabic_1"),
+    (16778850, "Arabic_2"),
+    (16778851, "Arabic_3"),
+    (16778852, "Arabic_4"),
+    (16778853, "Arabic_5"),
+    (16778854, "Arabic_6"),
+    (16778855, "Arabic_7"),
+    (16778856, "Arabic_8"),
+    (16778857, "Arabic_9"),
+    (16778858, "Arabic_percent"),
+    (16778864, "Arabic_superscript_alef"),
+    (16778873, "Arabic_tteh"),
+    (16778878, "Arabic_peh"),
+    (16778886, "Arabic_tcheh"),
+    (16778888, "Arabic_ddal"),
+    (16778897, "Arabic_rreh"),
+    (16778904, "Arabic_jeh"),
+    (16778916, "Arabic_veh"),
+    (16778921, "Arabic_keheh"),
+    (16778927, "Arabic_gaf"),
+    (16778938, "Arabic_noon_ghunna"),
+    (16778942, "Arabic_heh_doachashmee"),
+    (16778945, "Arabic_heh_goal"),
+    (16778956, "Farsi_yeh"),
+    (16778962, "Arabic_yeh_baree"),
+    (16778964, "Arabic_fullstop"),
+    (16778992, "Farsi_0"),
+    (16778993, "Farsi_1"),
+    (16778994, "Farsi_2"),
+    (16778995, "Farsi_3"),
+    (16778996, "Farsi_4"),
+    (16778997, "Farsi_5"),
+    (16778998, "Farsi_6"),
+    (16778999, "Farsi_7"),
+    (16779000, "Farsi_8"),
+    (16779001, "Farsi_9"),
+    (16780674, "Sinh_ng"),
+    (16780675, "Sinh_h2"),
+    (16780677, "Sinh_a"),
+    (16780678, "Sinh_aa"),
+    (16780679, "Sinh_ae"),
+    (16780680, "Sinh_aee"),
+    (16780681, "Sinh_i"),
+    (16780682, "Sinh_ii"),
+    (16780683, "Sinh_u"),
+    (16780684, "Sinh_uu"),
+    (16780685, "Sinh_ri"),
+    (16780686, "Sinh_rii"),
+    (16780687, "Sinh_lu"),
+    (16780688, "Sinh_luu"),
+    (16780689, "Sinh_e"),
+    (16780690, "Sinh_ee"),
+    (16780691, "Sinh_ai"),
+    (16780692, "Sinh_o"),
+    (16780693, "Sinh_oo"),
+    (16780694, "Sinh_au"),
+    (16780698, "Sinh_ka"),
+    (16780699, "Sinh_kha"),
+    (16780700, "Sinh_ga"),
+    (16780701, "Sinh_gha"),
+    (16780702, "Sinh_ng2"),
+    (16780703, "Sinh_nga"),
+    (16780704, "Sinh_ca"),
+    (16780705, "Sinh_cha"),
+    (16780706, "Sinh_ja"),
+    (16780707, "Sinh_jha"),
+    (16780708, "Sinh_nya"),
+    (16780709, "Sinh_jnya"),
+    (16780710, "Sinh_nja"),
+    (16780711, "Sinh_tta"),
+    (16780712, "Sinh_ttha"),
+    (16780713, "Sinh_dda"),
+    (16780714, "Sinh_ddha"),
+    (16780715, "Sinh_nna"),
+    (16780716, "Sinh_ndda"),
+    (16780717, "Sinh_tha"),
+    (16780718, "Sinh_thha"),
+    (16780719, "Sinh_dha"),
+    (16780720, "Sinh_dhha"),
+    (16780721, "Sinh_na"),
+    (16780723, "Sinh_ndha"),
+    (16780724, "Sinh_pa"),
+    (16780725, "Sinh_pha"),
+    (16780726, "Sinh_ba"),
+    (16780727, "Sinh_bha"),
+    (16780728, "Sinh_ma"),
+    (16780729, "Sinh_mba"),
+    (16780730, "Sinh_ya"),
+    (16780731, "Sinh_ra"),
+    (16780733, "Sinh_la"),
+    (16780736, "Sinh_va"),
+    (16780737, "Sinh_sha"),
+    (16780738, "Sinh_ssha"),
+    (16780739, "Sinh_sa"),
+    (16780740, "Sinh_ha"),
+    (16780741, "Sinh_lla"),
+    (16780742, "Sinh_fa"),
+    (16780746, "Sinh_al"),
+    (16780751, "Sinh_aa2"),
+    (16780752, "Sinh_ae2"),
+    (16780753, "Sinh_aee2"),
+    (16780754, "Sinh_i2"),
+    (16780755, "Sinh_ii2"),
+    (16780756, "Sinh_u2"),
+    (16780758, "Sinh_uu2"),
+    (16780760, "Sinh_ru2"),
+    (16780761, "Sinh_e2"),
+    (16780762, "Sinh_ee2"),
+    (16780763, "Sinh_ai2"),
+    (16780764, "Sinh_o2"),
+    (16780765, "Sinh_oo2"),
+    (16780766, "Sinh_au2"),
+    (16780767, "Sinh_lu2"),
+    (16780786, "Sinh_ruu2"),
+    (16780787, "Sinh_luu2"),
+    (16780788, "Sinh_kunddaliya"),
+    (16781520, "Georgian_an"),
+    (16781521, "Georgian_ban"),
+    (16781522, "Georgian_gan"),
+    (16781523, "Georgian_don"),
+    (16781524, "Georgian_en"),
+    (16781525, "Georgian_vin"),
+    (16781526, "Georgian_zen"),
+    (16781527, "Georgian_tan"),
+    (16781528, "Georgian_in"),
+    (16781529, "Georgian_kan"),
+    (16781530, "Georgian_las"),
+    (16781531, "Georgian_man"),
+    (16781532, "Georgian_nar"),
+    (16781533, "Georgian_on"),
+    (16781534, "Georgian_par"),
+    (16781535, "Georgian_zhar"),
+    (16781536, "Georgian_rae"),
+    (16781537, "Georgian_san"),
+    (16781538, "Georgian_tar"),
+    (16781539, "Georgian_un"),
+    (16781540, "Georgian_phar"),
+    (16781541, "Georgian_khar"),
+    (16781542, "Georgian_ghan"),
+    (16781543, "Georgian_qar"),
+    (16781544, "Georgian_shin"),
+    (16781545, "Georgian_chin"),
+    (16781546, "Georgian_can"),
+    (16781547, "Georgian_jil"),
+    (16781548, "Georgian_cil"),
+    (16781549, "Georgian_char"),
+    (16781550, "Georgian_xan"),
+    (16781551, "Georgian_jhan"),
+    (16781552, "Georgian_hae"),
+    (16781553, "Georgian_he"),
+    (16781554, "Georgian_hie"),
+    (16781555, "Georgian_we"),
+    (16781556, "Georgian_har"),
+    (16781557, "Georgian_hoe"),
+    (16781558, "Georgian_fi"),
+    (16784898, "Babovedot"),
+    (16784899, "babovedot"),
+    (16784906, "Dabovedot"),
+    (16784907, "dabovedot"),
+    (16784926, "Fabovedot"),
+    (16784927, "fabovedot"),
+    (16784950, "Lbelowdot"),
+    (16784951, "lbelowdot"),
+    (16784960, "Mabovedot"),
+    (16784961, "mabovedot"),
+    (16784982, "Pabovedot"),
+    (16784983, "pabovedot"),
+    (16784992, "Sabovedot"),
+    (16784993, "sabovedot"),
+    (16785002, "Tabovedot"),
+    (16785003, "tabovedot"),
+    (16785024, "Wgrave"),
+    (16785025, "wgrave"),
+    (16785026, "Wacute"),
+    (16785027, "wacute"),
+    (16785028, "Wdiaeresis"),
+    (16785029, "wdiaeresis"),
+    (16785034, "Xabovedot"),
+    (16785035, "xabovedot"),
+    (16785056, "Abelowdot"),
+    (16785057, "abelowdot"),
+    (16785058, "Ahook"),
+    (16785059, "ahook"),
+    (16785060, "Acircumflexacute"),
+    (16785061, "acircumflexacute"),
+    (16785062, "Acircumflexgrave"),
+    (16785063, "acircumflexgrave"),
+    (16785064, "Acircumflexhook"),
+    (16785065, "acircumflexhook"),
+    (16785066, "Acircumflextilde"),
+    (16785067, "acircumflextilde"),
+    (16785068, "Acircumflexbelowdot"),
+    (16785069, "acircumflexbelowdot"),
+    (16785070, "Abreveacute"),
+    (16785071, "abreveacute"),
+    (16785072, "Abrevegrave"),
+    (16785073, "abrevegrave"),
+    (16785074, "Abrevehook"),
+    (16785075, "abrevehook"),
+    (16785076, "Abrevetilde"),
+    (16785077, "abrevetilde"),
+    (16785078, "Abrevebelowdot"),
+    (16785079, "abrevebelowdot"),
+    (16785080, "Ebelowdot"),
+    (16785081, "ebelowdot"),
+    (16785082, "Ehook"),
+    (16785083, "ehook"),
+    (16785084, "Etilde"),
+    (16785085, "etilde"),
+    (16785086, "Ecircumflexacute"),
+    (16785087, "ecircumflexacute"),
+    (16785088, "Ecircumflexgrave"),
+    (16785089, "ecircumflexgrave"),
+    (16785090, "Ecircumflexhook"),
+    (16785091, "ecircumflexhook"),
+    (16785092, "Ecircumflextilde"),
+    (16785093, "ecircumflextilde"),
+    (16785094, "Ecircumflexbelowdot"),
+    (16785095, "ecircumflexbelowdot"),
+    (16785096, "Ihook"),
+    (16785097, "ihook"),
+    (16785098, "Ibelowdot"),
+    (16785099, "ibelowdot"),
+    (16785100, "Obelowdot"),
+    (16785101, "obelowdot"),
+    (16785102, "Ohook"),
+    (16785103, "ohook"),
+    (16785104, "Ocircumflexacute"),
+    (16785105, "ocircumflexacute"),
+    (16785106, "Ocircumflexgrave"),
+    (16785107, "ocircumflexgrave"),
+    (16785108, "Ocircumflexhook"),
+    (16785109, "ocircumflexhook"),
+    (16785110, "Ocircumflextilde"),
+    (16785111, "ocircumflextilde"),
+    (16785112, "Ocircumflexbelowdot"),
+    (16785113, "ocircumflexbelowdot"),
+    (16785114, "Ohornacute"),
+    (16785115, "ohornacute"),
+    (16785116, "Ohorngrave"),
+    (16785117, "ohorngrave"),
+    (16785118, "Ohornhook"),
+    (16785119, "ohornhook"),
+    (16785120, "Ohorntilde"),
+    (16785121, "ohorntilde"),
+    (16785122, "Ohornbelowdot"),
+    (16785123, "ohornbelowdot"),
+    (16785124, "Ubelowdot"),
+    (16785125, "ubelowdot"),
+    (16785126, "Uhook"),
+    (16785127, "uhook"),
+    (16785128, "Uhornacute"),
+    (16785129, "uhornacute"),
+    (16785130, "Uhorngrave"),
+    (16785131, "uhorngrave"),
+    (16785132, "Uhornhook"),
+    (16785133, "uhornhook"),
+    (16785134, "Uhorntilde"),
+    (16785135, "uhorntilde"),
+    (16785136, "Uhornbelowdot"),
+    (16785137, "uhornbelowdot"),
+    (16785138, "Ygrave"),
+    (16785139, "ygrave"),
+    (16785140, "Ybelowdot"),
+    (16785141, "ybelowdot"),
+    (16785142, "Yhook"),
+    (16785143, "yhook"),
+    (16785144, "Ytilde"),
+    (16785145, "ytilde"),
+    (16785520, "zerosuperior"),
+    (16785524, "foursuperior"),
+    (16785525, "fivesuperior"),
+    (16785526, "sixsuperior"),
+    (16785527, "sevensuperior"),
+    (16785528, "eightsuperior"),
+    (16785529, "ninesuperior"),
+    (16785536, "zerosubscript"),
+    (16785537, "onesubscript"),
+    (16785538, "twosubscript"),
+    (16785539, "threesubscript"),
+    (16785540, "foursubscript"),
+    (16785541, "fivesubscript"),
+    (16785542, "sixsubscript"),
+    (16785543, "sevensubscript"),
+    (16785544, "eightsubscript"),
+    (16785545, "ninesubscript"),
+    (16785568, "EcuSign"),
+    (16785569, "ColonSign"),
+    (16785570, "CruzeiroSign"),
+    (16785571, "FFrancSign"),
+    (16785572, "LiraSign"),
+    (16785573, "MillSign"),
+    (16785574, "NairaSign"),
+    (16785575, "PesetaSign"),
+    (16785576, "RupeeSign"),
+    (16785577, "WonSign"),
+    (16785578, "NewSheqelSign"),
+    (16785579, "DongSign"),
+    (16785922, "partdifferential"),
+    (16785925, "emptyset"),
+    (16785928, "elementof"),
+    (16785929, "notelementof"),
+    (16785931, "containsas"),
+    (16785946, "squareroot"),
+    (16785947, "cuberoot"),
+    (16785948, "fourthroot"),
+    (16785964, "dintegral"),
+    (16785965, "tintegral"),
+    (16785973, "because"),
+    (16785991, "notapproxeq"),
+    (16785992, "approxeq"),
+    (16786018, "notidentical"),
+    (16786019, "stricteq"),
+    (16787456, "braille_blank"),
+    (16787457, "braille_dots_1"),
+    (16787458, "braille_dots_2"),
+    (16787459, "braille_dots_12"),
+    (16787460, "braille_dots_3"),
+    (16787461, "braille_dots_13"),
+    (16787462, "braille_dots_23"),
+    (16787463, "braille_dots_123"),
+    (16787464, "braille_dots_4"),
+    (16787465, "braille_dots_14"),
+    (16787466, "braille_dots_24"),
+    (16787467, "braille_dots_124"),
+    (16787468, "braille_dots_34"),
+    (16787469, "braille_dots_134"),
+    (16787470, "braille_dots_234"),
+    (16787471, "braille_dots_1234"),
+    (16787472, "braille_dots_5"),
+    (16787473, "braille_dots_15"),
+    (16787474, "braille_dots_25"),
+    (16787475, "braille_dots_125"),
+    (16787476, "braille_dots_35"),
+    (16787477, "braille_dots_135"),
+    (16787478, "braille_dots_235"),
+    (16787479, "braille_dots_1235"),
+    (16787480, "braille_dots_45"),
+    (16787481, "braille_dots_145"),
+    (16787482, "braille_dots_245"),
+    (16787483, "braille_dots_1245"),
+    (16787484, "braille_dots_345"),
+    (16787485, "braille_dots_1345"),
+    (16787486, "braille_dots_2345"),
+    (16787487, "braille_dots_12345"),
+    (16787488, "braille_dots_6"),
+    (16787489, "braille_dots_16"),
+    (16787490, "braille_dots_26"),
+    (16787491, "braille_dots_126"),
+    (16787492, "braille_dots_36"),
+    (16787493, "braille_dots_136"),
+    (16787494, "braille_dots_236"),
+    (16787495, "braille_dots_1236"),
+    (16787496, "braille_dots_46"),
+    (16787497, "braille_dots_146"),
+    (16787498, "braille_dots_246"),
+    (16787499, "braille_dots_1246"),
+    (16787500, "braille_dots_346"),
+    (16787501, "braille_dots_1346"),
+    (16787502, "braille_dots_2346"),
+    (16787503, "braille_dots_12346"),
+    (16787504, "braille_dots_56"),
+    (16787505, "braille_dots_156"),
+    (16787506, "braille_dots_256"),
+    (16787507, "braille_dots_1256"),
+    (16787508, "braille_dots_356"),
+    (16787509, "braille_dots_1356"),
+    (16787510, "braille_dots_2356"),
+    (16787511, "braille_dots_12356"),
+    (16787512, "braille_dots_456"),
+    (16787513, "braille_dots_1456"),
+    (16787514, "braille_dots_2456"),
+    (16787515, "braille_dots_12456"),
+    (16787516, "braille_dots_3456"),
+    (16787517, "braille_dots_13456"),
+    (16787518, "braille_dots_23456"),
+    (16787519, "braille_dots_123456"),
+    (16787520, "braille_dots_7"),
+    (16787521, "braille_dots_17"),
+    (16787522, "braille_dots_27"),
+    (16787523, "braille_dots_127"),
+    (16787524, "braille_dots_37"),
+    (16787525, "braille_dots_137"),
+    (16787526, "braille_dots_237"),
+    (16787527, "braille_dots_1237"),
+    (16787528, "braille_dots_47"),
+    (16787529, "braille_dots_147"),
+    (16787530, "braille_dots_247"),
+    (16787531, "braille_dots_1247"),
+    (16787532, "braille_dots_347"),
+    (16787533, "braille_dots_1347"),
+    (16787534, "braille_dots_2347"),
+    (16787535, "braille_dots_12347"),
+    (16787536, "braille_dots_57"),
+    (16787537, "braille_dots_157"),
+    (16787538, "braille_dots_257"),
+    (16787539, "braille_dots_1257"),
+    (16787540, "braille_dots_357"),
+    (16787541, "braille_dots_1357"),
+    (16787542, "braille_dots_2357"),
+    (16787543, "braille_dots_12357"),
+    (16787544, "braille_dots_457"),
+    (16787545, "braille_dots_1457"),
+    (16787546, "braille_dots_2457"),
+    (16787547, "braille_dots_12457"),
+    (16787548, "braille_dots_3457"),
+    (16787549, "braille_dots_13457"),
+    (16787550, "braille_dots_23457"),
+    (16787551, "braille_dots_123457"),
+    (16787552, "braille_dots_67"),
+    (16787553, "braille_dots_167"),
+    (16787554, "braille_dots_267"),
+    (16787555, "braille_dots_1267"),
+    (16787556, "braille_dots_367"),
+    (16787557, "braille_dots_1367"),
+    (16787558, "braille_dots_2367"),
+    (16787559, "braille_dots_12367"),
+    (16787560, "braille_dots_467"),
+    (16787561, "braille_dots_1467"),
+    (16787562, "braille_dots_2467"),
+    (16787563, "braille_dots_12467"),
+    (16787564, "braille_dots_3467"),
+    (16787565, "braille_dots_13467"),
+    (16787566, "braille_dots_23467"),
+    (16787567, "braille_dots_123467"),
+    (16787568, "braille_dots_567"),
+    (16787569, "braille_dots_1567"),
+    (16787570, "braille_dots_2567"),
+    (16787571, "braille_dots_12567"),
+    (16787572, "braille_dots_3567"),
+    (16787573, "braille_dots_13567"),
+    (16787574, "braille_dots_23567"),
+    (16787575, "braille_dots_123567"),
+    (16787576, "braille_dots_4567"),
+    (16787577, "braille_dots_14567"),
+    (16787578, "braille_dots_24567"),
+    (16787579, "braille_dots_124567"),
+    (16787580, "braille_dots_34567"),
+    (16787581, "braille_dots_134567"),
+    (16787582, "braille_dots_234567"),
+    (16787583, "braille_dots_1234567"),
+    (16787584, "braille_dots_8"),
+    (16787585, "braille_dots_18"),
+    (16787586, "braille_dots_28"),
+    (16787587, "braille_dots_128"),
+    (16787588, "braille_dots_38"),
+    (16787589, "braille_dots_138"),
+    (16787590, "braille_dots_238"),
+    (16787591, "braille_dots_1238"),
+    (16787592, "braille_dots_48"),
+    (16787593, "braille_dots_148"),
+    (16787594, "braille_dots_248"),
+    (16787595, "braille_dots_1248"),
+    (16787596, "braille_dots_348"),
+    (16787597, "braille_dots_1348"),
+    (16787598, "braille_dots_2348"),
+    (16787599, "braille_dots_12348"),
+    (16787600, "braille_dots_58"),
+    (16787601, "braille_dots_158"),
+    (16787602, "braille_dots_258"),
+    (16787603, "braille_dots_1258"),
+    (16787604, "braille_dots_358"),
+    (16787605, "braille_dots_1358"),
+    (16787606, "braille_dots_2358"),
+    (16787607, "braille_dots_12358"),
+    (16787608, "braille_dots_458"),
+    (16787609, "braille_dots_1458"),
+    (16787610, "braille_dots_2458"),
+    (16787611, "braille_dots_12458"),
+    (16787612, "braille_dots_3458"),
+    (16787613, "braille_dots_13458"),
+    (16787614, "braille_dots_23458"),
+    (16787615, "braille_dots_123458"),
+    (16787616, "braille_dots_68"),
+    (16787617, "braille_dots_168"),
+    (16787618, "braille_dots_268"),
+    (16787619, "braille_dots_1268"),
+    (16787620, "braille_dots_368"),
+    (16787621, "braille_dots_1368"),
+    (16787622, "braille_dots_2368"),
+    (16787623, "braille_dots_12368"),
+    (16787624, "braille_dots_468"),
+    (16787625, "braille_dots_1468"),
+    (16787626, "braille_dots_2468"),
+    (16787627, "braille_dots_12468"),
+    (16787628, "braille_dots_3468"),
+    (16787629, "braille_dots_13468"),
+    (16787630, "braille_dots_23468"),
+    (16787631, "braille_dots_123468"),
+    (16787632, "braille_dots_568"),
+    (16787633, "braille_dots_1568"),
+    (16787634, "braille_dots_2568"),
+    (16787635, "braille_dots_12568"),
+    (16787636, "braille_dots_3568"),
+    (16787637, "braille_dots_13568"),
+    (16787638, "braille_dots_23568"),
+    (16787639, "braille_dots_123568"),
+    (16787640, "braille_dots_4568"),
+    (16787641, "braille_dots_14568"),
+    (16787642, "braille_dots_24568"),
+    (16787643, "braille_dots_124568"),
+    (16787644, "braille_dots_34568"),
+    (16787645, "braille_dots_134568"),
+    (16787646, "braille_dots_234568"),
+    (16787647, "braille_dots_1234568"),
+    (16787648, "braille_dots_78"),
+    (16787649, "braille_dots_178"),
+    (16787650, "braille_dots_278"),
+    (16787651, "braille_dots_1278"),
+    (16787652, "braille_dots_378"),
+    (16787653, "braille_dots_1378"),
+    (16787654, "braille_dots_2378"),
+    (16787655, "braille_dots_12378"),
+    (16787656, "braille_dots_478"),
+    (16787657, "braille_dots_1478"),
+    (16787658, "braille_dots_2478"),
+    (16787659, "braille_dots_12478"),
+    (16787660, "braille_dots_3478"),
+    (16787661, "braille_dots_13478"),
+    (16787662, "braille_dots_23478"),
+    (16787663, "braille_dots_123478"),
+    (16787664, "braille_dots_578"),
+    (16787665, "braille_dots_1578"),
+    (16787666, "braille_dots_2578"),
+    (16787667, "braille_dots_12578"),
+    (16787668, "braille_dots_3578"),
+    (16787669, "braille_dots_13578"),
+    (16787670, "braille_dots_23578"),
+    (16787671, "braille_dots_123578"),
+    (16787672, "braille_dots_4578"),
+    (16787673, "braille_dots_14578"),
+    (16787674, "braille_dots_24578"),
+    (16787675, "braille_dots_124578"),
+    (16787676, "braille_dots_34578"),
+    (16787677, "braille_dots_134578"),
+    (16787678, "braille_dots_234578"),
+    (16787679, "braille_dots_1234578"),
+    (16787680, "braille_dots_678"),
+    (16787681, "braille_dots_1678"),
+    (16787682, "braille_dots_2678"),
+    (16787683, "braille_dots_12678"),
+    (16787684, "braille_dots_3678"),
+    (16787685, "braille_dots_13678"),
+    (16787686, "braille_dots_23678"),
+    (16787687, "braille_dots_123678"),
+    (16787688, "braille_dots_4678"),
+    (16787689, "braille_dots_14678"),
+    (16787690, "braille_dots_24678"),
+    (16787691, "braille_dots_124678"),
+    (16787692, "braille_dots_34678"),
+    (16787693, "braille_dots_134678"),
+    (16787694, "braille_dots_234678"),
+    (16787695, "braille_dots_1234678"),
+    (16787696, "braille_dots_5678"),
+    (16787697, "braille_dots_15678"),
+    (16787698, "braille_dots_25678"),
+    (16787699, "braille_dots_125678"),
+    (16787700, "braille_dots_35678"),
+    (16787701, "braille_dots_135678"),
+    (16787702, "braille_dots_235678"),
+    (16787703, "braille_dots_1235678"),
+    (16787704, "braille_dots_45678"),
+    (16787705, "braille_dots_145678"),
+    (16787706, "braille_dots_245678"),
+    (16787707, "braille_dots_1245678"),
+    (16787708, "braille_dots_345678"),
+    (16787709, "braille_dots_1345678"),
+    (16787710, "braille_dots_2345678"),
+    (16787711, "braille_dots_12345678"),
+];
+#[doc = r" The name of a keysym, without the `XK_` prefix, if it is one of the defined"]
+#[doc = r" keysyms."]
+#[inline]
+#[must_use]
+pub fn keysym_name(keysym: Keysym) -> Option<&'static str> {
+    KEYSYM_NAMES
+        .binary_search_by_key(&keysym, |(keysym, _)| *keysym)
+        .ok()
+        .map(|index| KEYSYM_NAMES[index].1)
+}
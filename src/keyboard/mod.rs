@@ -24,6 +24,8 @@ use crate::display::AsyncConnection;
 mod convert;
 pub use convert::*;
 
+pub mod keysyms;
+
 mod xproto;
 pub use xproto::*;
 
@@ -84,3 +86,15 @@ impl<Km: Keymap + ?Sized> KeyboardState<Km> {
 pub trait Keymap {
     fn lookup_keysyms(&self, keycode: Keycode) -> &[Keysym];
 }
+
+#[test]
+fn name_keysyms() {
+    assert_eq!(keysyms::XK_Return, 0xff0d);
+    assert_eq!(keysyms::keysym_name(keysyms::XK_Return), Some("Return"));
+    // XK_quoteright is a deprecated alias of XK_apostrophe, which is listed first
+    assert_eq!(
+        keysyms::keysym_name(keysyms::XK_quoteright),
+        Some("apostrophe")
+    );
+    assert_eq!(keysyms::keysym_name(0x1234_5678), None);
+}
@@ -89,17 +89,30 @@
 //! # Features
 //!
 //! * `std` - Enabled by default. This enables the use of the standard library, and enables
-//!           `DisplayConnection` and `DisplayConnection::create`. This library can be used without
-//!           the standard library; however, it requires the programmer to provide a connection, rather
-//!           than building a connection itself.
+//!   `DisplayConnection` and `DisplayConnection::create`. This library can be used without
+//!   the standard library; however, it requires the programmer to provide a connection, rather
+//!   than building a connection itself. Without it, only `alloc` is needed, so the protocol types
+//!   can be used on bare-metal and WASM targets.
 //! * `async` - Enables the `_async` suffix family of functions. These functions and methods are similar
-//!             to their blocking variants, but they use non-blocking variants of network calls. This uses
-//!             the [`async_net`](https://crates.io/crates/async-net) crate to provide non-blocking calls.
-//!             However, it nearly triples the size of this package's dependency tree.
+//!   to their blocking variants, but they use non-blocking variants of network calls. This uses
+//!   the [`async_net`](https://crates.io/crates/async-net) crate to provide non-blocking calls.
+//!   However, it nearly triples the size of this package's dependency tree.
+//! * `fuzzing` - Implements [`arbitrary`](https://crates.io/crates/arbitrary)'s `Arbitrary` trait for the
+//!   protocol structures, so that they can be fuzzed. Lists are created with the lengths
+//!   that the other fields of their structures describe.
 //! * `image-support` - Coming soon.
 //! * `nightly-min-specialization` - Coming soon.
+//! * `pod` - Derives [`bytemuck`](https://crates.io/crates/bytemuck)'s `Pod` and `Zeroable` traits for the
+//!   protocol structures that are laid out exactly like their bytes, such as `Rectangle`. These
+//!   can be cast to and from bytes without being parsed.
 //! * `parallel` - Uses the [`rayon`](https://crates.io/crates/rayon) crate to parallelize computationally
-//!                expensive operations.
+//!   expensive operations.
+//! * `serde` - Implements [`serde`](https://crates.io/crates/serde)'s `Serialize` and `Deserialize`
+//!   traits for the protocol structures, which is useful for logging them.
+//!
+//! Each extension is compiled behind a feature of its own, such as `render` or `fixes`, which also
+//! enables the features of the extensions whose types it uses. `all-extensions` enables all of them.
+//! These features are generated from the protocol descriptions with `make features`.
 
 #![deny(deprecated)]
 #![forbid(unsafe_code)]
@@ -126,6 +139,7 @@ extern crate std;
 extern crate alloc;
 extern crate core;
 
+pub mod atom_names;
 mod auth_info;
 pub mod auto;
 pub mod client_message_data;
@@ -135,6 +149,7 @@ pub mod event;
 pub mod extension;
 pub mod image;
 pub(crate) mod paramatizer;
+pub mod property_data;
 pub(crate) mod util;
 mod xid;
 
@@ -159,18 +174,109 @@ pub use xid::*;
 pub type Fd = cty::c_int;
 
 /// A request that can be sent as an instruction to the X server.
+///
+/// Generic code can route a request through its associated constants, `R::OPCODE`, `R::EXTENSION`
+/// and `R::MINOR_OPCODE`, without matching on the request's type.
 pub trait Request: auto::AsByteSequence {
+    /// The reply that the server sends back for this request, or `()` if it doesn't send one.
+    /// `Display::resolve_request` returns this type, so it is inferred from the request cookie.
     type Reply: auto::AsByteSequence;
     // Excerpt from the X Window System Protocol:
     //
     // Every request contains an 8-bit major opcode
+    /// The major opcode of this request. Extension requests are given theirs by the server when
+    /// the extension is queried, so this is zero for them, and `MINOR_OPCODE` tells them apart.
     const OPCODE: u8;
 
-    /// The name of the extension that this request belongs to.
+    /// The name of the extension that this request belongs to, or `None` for core requests.
     const EXTENSION: Option<&'static str>;
 
+    /// The minor opcode of this request, which tells it apart from the other requests of its
+    /// extension. Core requests don't have one, so this is zero for them.
+    const MINOR_OPCODE: u16;
+
     /// Whether or not this request's reply includes file descriptors.
     const REPLY_EXPECTS_FDS: bool;
+
+    /// The name that the protocol gives this request, e.g. `"CreateWindow"`.
+    const NAME: &'static str;
+
+    /// Check that the lengths of this request's lists agree with the fields that describe them.
+    /// This is done before the request is sent, since the server would reject it otherwise.
+    #[inline]
+    fn validate(&self) -> core::result::Result<(), auto::ValidationError> {
+        Ok(())
+    }
+}
+
+/// A request whose type has been erased, so that requests of different types can be handled by the
+/// same code, e.g. as a `Box<dyn AnyRequest>`. Every `Request` that can be debugged is one. The
+/// reply is left to `Request`, since a trait with an associated type can only be made into an object
+/// by naming that type.
+pub trait AnyRequest: core::fmt::Debug {
+    /// The major opcode of this request, or `None` if it belongs to an extension. Extensions are
+    /// assigned their major opcodes by the server.
+    fn opcode(&self) -> Option<u8>;
+
+    /// The minor opcode of this request, if it belongs to an extension.
+    fn minor_opcode(&self) -> Option<u16>;
+
+    /// The name of the extension that this request belongs to.
+    fn extension(&self) -> Option<&'static str>;
+
+    /// The number of bytes that `serialize_into` writes.
+    fn serialized_size(&self) -> usize;
+
+    /// Write this request into a sequence of bytes, returning how many bytes were written, or an
+    /// error if there are fewer than `serialized_size` of them. The length and the major opcode are
+    /// written as they are held by the request; the connection fills them in before the request is
+    /// sent.
+    ///
+    /// `AsByteSequence` has a method of the same name, so call this one as
+    /// `AnyRequest::serialize_into` on a request whose type is known.
+    fn serialize_into(&self, bytes: &mut [u8])
+        -> core::result::Result<usize, auto::BufferTooSmall>;
+
+    /// The name that the protocol gives this request, e.g. `"CreateWindow"`.
+    fn debug_name(&self) -> &'static str;
+}
+
+impl<R: Request + core::fmt::Debug> AnyRequest for R {
+    #[inline]
+    fn opcode(&self) -> Option<u8> {
+        match R::EXTENSION {
+            Some(_) => None,
+            None => Some(R::OPCODE),
+        }
+    }
+
+    #[inline]
+    fn minor_opcode(&self) -> Option<u16> {
+        R::EXTENSION.map(|_| R::MINOR_OPCODE)
+    }
+
+    #[inline]
+    fn extension(&self) -> Option<&'static str> {
+        R::EXTENSION
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.size()
+    }
+
+    #[inline]
+    fn serialize_into(
+        &self,
+        bytes: &mut [u8],
+    ) -> core::result::Result<usize, auto::BufferTooSmall> {
+        auto::AsByteSequence::serialize_into(self, bytes)
+    }
+
+    #[inline]
+    fn debug_name(&self) -> &'static str {
+        R::NAME
+    }
 }
 
 //pub use display::*;
@@ -7,13 +7,15 @@ use super::auto::{
         CrtcChange, LeaseNotify, OutputChange, OutputProperty, ProviderChange, ProviderProperty,
         ResourceChange,
     },
-    AsByteSequence,
+    unreadable, AsByteSequence, ParseError,
 };
 
 const NOTIFY_DATA_LEN: usize = 28;
 
 /// Notification data.
 #[derive(Debug, Clone, Copy, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct NotifyData([u8; NOTIFY_DATA_LEN]);
 
@@ -30,17 +32,21 @@ impl AsByteSequence for NotifyData {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
         let mut data = Self([0; NOTIFY_DATA_LEN]);
-        data.0.copy_from_slice(&bytes[..NOTIFY_DATA_LEN]);
-        Some((data, NOTIFY_DATA_LEN))
+        data.0.copy_from_slice(
+            bytes
+                .get(..NOTIFY_DATA_LEN)
+                .ok_or_else(unreadable::<Self>)?,
+        );
+        Ok((data, NOTIFY_DATA_LEN))
     }
 }
 
 impl NotifyData {
     #[inline]
     pub fn as_crtc_change(&self) -> Option<CrtcChange> {
-        Some(CrtcChange::from_bytes(&self.0)?.0)
+        Some(CrtcChange::from_bytes(&self.0).ok()?.0)
     }
 
     #[inline]
@@ -50,7 +56,7 @@ impl NotifyData {
 
     #[inline]
     pub fn as_output_change(&self) -> Option<OutputChange> {
-        Some(OutputChange::from_bytes(&self.0)?.0)
+        Some(OutputChange::from_bytes(&self.0).ok()?.0)
     }
 
     #[inline]
@@ -60,7 +66,7 @@ impl NotifyData {
 
     #[inline]
     pub fn as_output_property(&self) -> Option<OutputProperty> {
-        Some(OutputProperty::from_bytes(&self.0)?.0)
+        Some(OutputProperty::from_bytes(&self.0).ok()?.0)
     }
 
     #[inline]
@@ -70,7 +76,7 @@ impl NotifyData {
 
     #[inline]
     pub fn as_provider_change(&self) -> Option<ProviderChange> {
-        Some(ProviderChange::from_bytes(&self.0)?.0)
+        Some(ProviderChange::from_bytes(&self.0).ok()?.0)
     }
 
     #[inline]
@@ -80,7 +86,7 @@ impl NotifyData {
 
     #[inline]
     pub fn as_provider_property(&self) -> Option<ProviderProperty> {
-        Some(ProviderProperty::from_bytes(&self.0)?.0)
+        Some(ProviderProperty::from_bytes(&self.0).ok()?.0)
     }
 
     #[inline]
@@ -90,7 +96,7 @@ impl NotifyData {
 
     #[inline]
     pub fn as_resource_change(&self) -> Option<ResourceChange> {
-        Some(ResourceChange::from_bytes(&self.0)?.0)
+        Some(ResourceChange::from_bytes(&self.0).ok()?.0)
     }
 
     #[inline]
@@ -100,7 +106,7 @@ impl NotifyData {
 
     #[inline]
     pub fn as_lease_notify(&self) -> Option<LeaseNotify> {
-        Some(LeaseNotify::from_bytes(&self.0)?.0)
+        Some(LeaseNotify::from_bytes(&self.0).ok()?.0)
     }
 
     #[inline]
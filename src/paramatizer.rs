@@ -24,7 +24,7 @@ macro_rules! create_paramaterizer {
                 $(
                     if let Some(ref t) = self.$field {
                         <$flags>::$setter(&mut flags, true);
-                        req.$var = t.clone();
+                        req.$var = Some(t.clone());
                     }
                 )*
 
@@ -0,0 +1,122 @@
+// MIT/Apache2 License
+
+//! This module provides the `PropertyData` enum, which is the data type of the values in the replies
+//! that return properties, such as `GetPropertyReply`.
+
+use crate::auto::{vector_as_bytes, vector_from_bytes};
+use alloc::vec::Vec;
+
+/// The value of a property. The X server stores properties as lists of 8-bit, 16-bit or 32-bit
+/// items, and the `format` field of the reply says which. The items are read with the width that
+/// the format gives them, so they don't need to be split up by hand.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropertyData {
+    /// Items that are 8 bits wide, like the bytes of a string. This is the format of `STRING` and
+    /// `UTF8_STRING` properties.
+    Eight(Vec<u8>),
+    /// Items that are 16 bits wide.
+    Sixteen(Vec<u16>),
+    /// Items that are 32 bits wide, like atoms, windows and `CARDINAL`s.
+    ThirtyTwo(Vec<u32>),
+}
+
+impl Default for PropertyData {
+    #[inline]
+    fn default() -> Self {
+        Self::Eight(Vec::new())
+    }
+}
+
+impl PropertyData {
+    /// The width of the items, in bits. This is the value of the `format` field that describes them.
+    #[inline]
+    #[must_use]
+    pub fn format(&self) -> u8 {
+        match self {
+            Self::Eight(_) => 8,
+            Self::Sixteen(_) => 16,
+            Self::ThirtyTwo(_) => 32,
+        }
+    }
+
+    /// The number of items.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Eight(items) => items.len(),
+            Self::Sixteen(items) => items.len(),
+            Self::ThirtyTwo(items) => items.len(),
+        }
+    }
+
+    /// Tell whether there are no items.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Take out the items if they are 8 bits wide.
+    #[inline]
+    #[must_use]
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            Self::Eight(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Take out the items if they are 16 bits wide.
+    #[inline]
+    #[must_use]
+    pub fn into_shorts(self) -> Option<Vec<u16>> {
+        match self {
+            Self::Sixteen(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Take out the items if they are 32 bits wide.
+    #[inline]
+    #[must_use]
+    pub fn into_longs(self) -> Option<Vec<u32>> {
+        match self {
+            Self::ThirtyTwo(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// The number of bytes the items take up.
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.len() * usize::from(self.format() / 8)
+    }
+
+    /// Write the items into `bytes`, returning the number of bytes written.
+    #[inline]
+    pub fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        match self {
+            Self::Eight(items) => vector_as_bytes(items, bytes),
+            Self::Sixteen(items) => vector_as_bytes(items, bytes),
+            Self::ThirtyTwo(items) => vector_as_bytes(items, bytes),
+        }
+    }
+
+    /// Read `len` items that are `format` bits wide. A format of zero is allowed if there are no
+    /// items, since the server uses it for properties that don't exist.
+    #[inline]
+    #[must_use]
+    pub fn from_bytes_with_format(bytes: &[u8], format: u8, len: usize) -> Option<(Self, usize)> {
+        match format {
+            0 if len == 0 => Some((Self::default(), 0)),
+            8 => vector_from_bytes(bytes, len).map(|(items, sz)| (Self::Eight(items), sz)),
+            16 => vector_from_bytes(bytes, len).map(|(items, sz)| (Self::Sixteen(items), sz)),
+            32 => vector_from_bytes(bytes, len).map(|(items, sz)| (Self::ThirtyTwo(items), sz)),
+            _ => None,
+        }
+    }
+}
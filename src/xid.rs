@@ -28,9 +28,9 @@ impl<T: XidType> auto::AsByteSequence for T {
     }
 
     #[inline]
-    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), auto::ParseError> {
         let (xid, len) = XID::from_bytes(bytes)?;
-        Some((Self::from_xid(xid), len))
+        Ok((Self::from_xid(xid), len))
     }
 }
 